@@ -0,0 +1,438 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{
+    fmt::{Debug, Formatter},
+    fs::{read_to_string, write},
+    path::Path,
+    time::Instant,
+};
+
+use lady_deirdre::{
+    lexis::{Column, Line, Position, PositionSpan, Site, SiteSpan, SourceCode, ToSite, ToSpan},
+    syntax::{Node, SyntaxTree},
+    units::Document,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{BenchError, BenchRecorder, BenchReport, BenchStep, BenchStepKind};
+
+/// A recorded stream of the text edit commands.
+///
+/// The object consists of the initial text of the document, followed by
+/// a series of text edits interleaved with the wait marks. You can iterate
+/// through the [commands](BenchCommand) using the [iter](Self::iter) function.
+///
+/// To create a new BenchData object, use the [BenchRecorder], or load
+/// the previously recorded data from JSON using the [load](Self::load) or
+/// the [from_json](Self::from_json) functions.
+///
+/// The [replay](Self::replay) function applies the commands to a mutable
+/// [Document] of the specified grammar and measures the reparsing time of
+/// each command.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct BenchData {
+    pub(crate) title: String,
+    #[serde(default)]
+    pub(crate) meta: String,
+    pub(crate) bytes: usize,
+    pub(crate) lines: usize,
+    pub(crate) text: String,
+    pub(crate) ops: Vec<Op>,
+}
+
+impl Debug for BenchData {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        formatter
+            .debug_struct("BenchData")
+            .field("title", &self.title())
+            .field("size", &self.size())
+            .field("lines", &self.lines())
+            .field("edits", &self.edits())
+            .field("ops", &self.ops())
+            .finish()
+    }
+}
+
+impl<'a> IntoIterator for &'a BenchData {
+    type Item = BenchCommand<'a>;
+    type IntoIter = BenchDataIterator<'a>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        BenchDataIterator {
+            data: self,
+            text_index: 0,
+            op_index: 0,
+        }
+    }
+}
+
+impl BenchData {
+    /// Deserializes the BenchData object from the JSON `string`.
+    #[inline(always)]
+    pub fn from_json(string: &str) -> Result<Self, BenchError> {
+        Ok(serde_json::from_str(string)?)
+    }
+
+    /// Serializes this object into a JSON string.
+    #[inline(always)]
+    pub fn to_json(&self) -> Result<String, BenchError> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Loads the BenchData object from the JSON file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, BenchError> {
+        let string = read_to_string(path)?;
+
+        Self::from_json(&string)
+    }
+
+    /// Stores this object into the JSON file.
+    ///
+    /// If the file already exists, the function overwrites it.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), BenchError> {
+        let string = self.to_json()?;
+
+        write(path, string)?;
+
+        Ok(())
+    }
+
+    /// Returns the title of the recorded session.
+    #[inline(always)]
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Returns the user-defined metadata of the recorded session.
+    ///
+    /// See [BenchRecorder::set_meta] for details.
+    #[inline(always)]
+    pub fn meta(&self) -> &str {
+        &self.meta
+    }
+
+    /// Returns a human-readable size of the initial text.
+    #[inline(always)]
+    pub fn size(&self) -> String {
+        Self::format_size(self.bytes)
+    }
+
+    /// Returns the length of the initial text in bytes.
+    #[inline(always)]
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// Returns the number of lines in the initial text.
+    #[inline(always)]
+    pub fn lines(&self) -> usize {
+        self.lines
+    }
+
+    /// Returns the number of the edit bursts: the number of
+    /// the [wait](BenchCommand::Wait) commands in the stream.
+    #[inline(always)]
+    pub fn edits(&self) -> usize {
+        self.ops.iter().filter(|op| matches!(op, Op::Wait)).count()
+    }
+
+    /// Returns the total number of commands in the stream.
+    #[inline(always)]
+    pub fn ops(&self) -> usize {
+        self.ops.len()
+    }
+
+    /// Returns an iterator over the recorded commands.
+    #[inline(always)]
+    pub fn iter(&self) -> BenchDataIterator {
+        self.into_iter()
+    }
+
+    /// Replays the recorded commands against a new mutable [Document]
+    /// of the `N` grammar.
+    ///
+    /// The returning [BenchReport] contains the time of each document write
+    /// and the syntax tree reuse statistics of each edit.
+    pub fn replay<N: Node>(&self) -> BenchReport {
+        let mut doc = Document::<N>::new_mutable("");
+        let mut steps = Vec::with_capacity(self.ops.len());
+
+        for (index, command) in self.iter().enumerate() {
+            let kind;
+            let start = Instant::now();
+
+            match command {
+                BenchCommand::Init { text } => {
+                    doc.write(.., text);
+                    kind = BenchStepKind::Init;
+                }
+
+                BenchCommand::Edit {
+                    site_span, text, ..
+                } => {
+                    doc.write(site_span, text);
+                    kind = BenchStepKind::Edit;
+                }
+
+                BenchCommand::Wait => {
+                    steps.push(BenchStep {
+                        index,
+                        kind: BenchStepKind::Wait,
+                        time: Default::default(),
+                        reparsed: 0,
+                        reused: 0,
+                    });
+
+                    continue;
+                }
+            }
+
+            let time = start.elapsed();
+
+            let (reparsed, reused) = match doc.last_reparse() {
+                Some(report) => (report.nodes.len(), report.reused),
+                None => (0, 0),
+            };
+
+            steps.push(BenchStep {
+                index,
+                kind,
+                time,
+                reparsed,
+                reused,
+            });
+        }
+
+        BenchReport { steps }
+    }
+
+    /// Checks the integrity of the recorded commands against the `N` grammar.
+    ///
+    /// The function replays the commands, and panics if the edit spans
+    /// of the commands are inconsistent with the document text, or if the
+    /// document has syntax errors at the beginning of the session or
+    /// at any wait mark.
+    pub fn check<N: Node>(&self) {
+        let mut doc = Document::<N>::new_mutable("");
+
+        for (index, command) in self.iter().enumerate() {
+            match command {
+                BenchCommand::Init { text } => {
+                    doc.write(.., text);
+
+                    let has_errors = doc.errors().next().is_some();
+
+                    if has_errors {
+                        for error in doc.errors() {
+                            println!("{:#}", error.display(&doc))
+                        }
+
+                        panic!(
+                            "{}: Command {index}. Initial data with syntax errors.",
+                            self.title,
+                        );
+                    }
+                }
+
+                BenchCommand::Edit {
+                    site_span,
+                    position_span,
+                    new_end_position,
+                    text,
+                } => {
+                    let Some(prototype) = site_span.to_position_span(&doc) else {
+                        panic!("{}: Command {index}. Invalid edit site span.", self.title);
+                    };
+
+                    if prototype != position_span {
+                        panic!(
+                            "{}: Command {index}. Site and Position spans mismatch.",
+                            self.title,
+                        );
+                    }
+
+                    doc.write(&site_span, text);
+
+                    let new_end_site = site_span.start + text.chars().count();
+
+                    let Some(prototype) = new_end_site.to_position(&doc) else {
+                        panic!("{}: Command {index}. Invalid edit site span.", self.title);
+                    };
+
+                    if prototype != new_end_position {
+                        panic!(
+                            "{}: Command {index}. Site and Position spans mismatch.",
+                            self.title,
+                        );
+                    }
+                }
+
+                BenchCommand::Wait => {
+                    let has_errors = doc.errors().next().is_some();
+
+                    if has_errors {
+                        for error in doc.errors() {
+                            println!("{:#}", error.display(&doc))
+                        }
+
+                        panic!("{}: Command {index}. Wait after syntax errors.", self.title);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Formats the number of `bytes` as a human-readable string.
+    pub fn format_size(bytes: usize) -> String {
+        if bytes < 1024 {
+            return format!("{} Bs", bytes);
+        }
+
+        if bytes < 1024 * 1024 {
+            return format!("{} KBs", bytes / 1024);
+        }
+
+        format!("{} MBs", bytes / 1024 / 1024)
+    }
+}
+
+/// A single command of the [BenchData] stream.
+pub enum BenchCommand<'a> {
+    /// Replaces the entire document text with the `text`.
+    Init {
+        /// The new text of the document.
+        text: &'a str,
+    },
+
+    /// Replaces a fragment of the document text.
+    Edit {
+        /// The span of the replaced fragment.
+        site_span: SiteSpan,
+
+        /// The same span as the `site_span`, expressed in lines and columns.
+        position_span: PositionSpan,
+
+        /// The position of the end of the inserted text after the edit.
+        new_end_position: Position,
+
+        /// The inserted text.
+        text: &'a str,
+    },
+
+    /// Denotes the end of the edits burst.
+    ///
+    /// At this point the end user stops typing, and the text is expected to
+    /// be syntactically correct.
+    Wait,
+}
+
+/// An iterator over the [BenchData] commands.
+///
+/// Created by the [BenchData::iter] function.
+pub struct BenchDataIterator<'a> {
+    data: &'a BenchData,
+    text_index: usize,
+    op_index: usize,
+}
+
+impl<'a> Iterator for BenchDataIterator<'a> {
+    type Item = BenchCommand<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let op = self.data.ops.get(self.op_index)?;
+
+        match op {
+            Op::Init { length } => {
+                let text = &self.data.text[self.text_index..(self.text_index + *length)];
+                self.text_index += *length;
+                self.op_index += 1;
+
+                Some(BenchCommand::Init { text })
+            }
+
+            Op::Edit {
+                length,
+                start_site,
+                start_line,
+                start_column,
+                end_site,
+                end_line,
+                end_column,
+                new_end_line,
+                new_end_column,
+            } => {
+                let text = &self.data.text[self.text_index..(self.text_index + length)];
+                self.text_index += length;
+                self.op_index += 1;
+
+                Some(BenchCommand::Edit {
+                    site_span: *start_site..*end_site,
+                    position_span: Position::new(*start_line, *start_column)
+                        ..Position::new(*end_line, *end_column),
+                    new_end_position: Position::new(*new_end_line, *new_end_column),
+                    text,
+                })
+            }
+
+            Op::Wait => {
+                self.op_index += 1;
+
+                Some(BenchCommand::Wait)
+            }
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub(crate) enum Op {
+    Init {
+        length: usize,
+    },
+
+    Edit {
+        length: usize,
+        start_site: Site,
+        start_line: Line,
+        start_column: Column,
+        end_site: Site,
+        end_line: Line,
+        end_column: Column,
+        new_end_line: Line,
+        new_end_column: Column,
+    },
+
+    Wait,
+}
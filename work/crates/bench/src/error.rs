@@ -0,0 +1,82 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+    io,
+};
+
+/// An error that may occur during loading or storing of
+/// the [BenchData](crate::BenchData) objects.
+#[derive(Debug)]
+pub enum BenchError {
+    /// The file system error.
+    Io(io::Error),
+
+    /// The JSON serialization or deserialization error.
+    Json(serde_json::Error),
+}
+
+impl Display for BenchError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => formatter.write_fmt(format_args!("IO error: {error}")),
+            Self::Json(error) => formatter.write_fmt(format_args!("JSON error: {error}")),
+        }
+    }
+}
+
+impl Error for BenchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Json(error) => Some(error),
+        }
+    }
+}
+
+impl From<io::Error> for BenchError {
+    #[inline(always)]
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for BenchError {
+    #[inline(always)]
+    fn from(error: serde_json::Error) -> Self {
+        Self::Json(error)
+    }
+}
@@ -0,0 +1,104 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+//TODO check warnings regularly
+#![allow(warnings)]
+#![deny(missing_docs)]
+
+//! # Lady Deirdre Benchmark Fixtures Crate
+//!
+//! This is a helper crate for the [main crate](https://docs.rs/lady-deirdre/latest/lady_deirdre/)
+//! of Lady Deirdre, compiler front-end foundation technology.
+//!
+//! The crate provides a reusable format of the recorded text edit sessions,
+//! and the tools to benchmark and test the incremental reparsing of
+//! the [Documents](lady_deirdre::units::Document) of your own grammar.
+//!
+//! The [BenchData] object is a stream of [commands](BenchCommand):
+//! the initial text of the document, followed by a series of text edits
+//! interleaved with the "wait" marks that denote the pauses between
+//! the bursts of edits (e.g., the end user stops typing).
+//!
+//! ```ignore
+//! // Captures an editor session.
+//! let mut recorder = BenchRecorder::<MyNode>::new("My Session");
+//!
+//! recorder.init("fn main() {}");
+//! recorder.edit(11..11, "\n    foo();\n");
+//! recorder.wait();
+//!
+//! let data = recorder.finish();
+//!
+//! // Stores the session in a JSON file.
+//! data.save("my-session.json").unwrap();
+//!
+//! // Loads the session and replays it against the Document.
+//! let data = BenchData::load("my-session.json").unwrap();
+//! let report = data.replay::<MyNode>();
+//!
+//! println!("{report}");
+//! ```
+//!
+//! ## Links
+//!
+//! - [Source Code](https://github.com/Eliah-Lakhin/lady-deirdre)
+//! - [Main Crate](https://crates.io/crates/lady-deirdre)
+//! - [API Documentation](https://docs.rs/lady-deirdre)
+//! - [User Guide](https://lady-deirdre.lakhin.com/)
+//! - [Benchmarks](https://github.com/Eliah-Lakhin/lady-deirdre/tree/master/work/crates/tests)
+//! - [License Agreement](https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md)
+//!
+//! ## Copyright
+//!
+//! This work is proprietary software with source-available code.
+//!
+//! To copy, use, distribute, or contribute to this work, you must agree to the
+//! terms and conditions of the [General License Agreement](https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md).
+//!
+//! For an explanation of the licensing terms, see the
+//! [F.A.Q.](https://github.com/Eliah-Lakhin/lady-deirdre/tree/master/FAQ.md)
+//!
+//! Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин). All rights reserved.
+
+mod data;
+mod error;
+mod recorder;
+mod report;
+
+pub use crate::{
+    data::{BenchCommand, BenchData, BenchDataIterator},
+    error::BenchError,
+    recorder::BenchRecorder,
+    report::{BenchReport, BenchStep, BenchStepKind},
+};
@@ -0,0 +1,171 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use lady_deirdre::{
+    lexis::{SourceCode, ToSite, ToSpan},
+    syntax::Node,
+    units::Document,
+};
+
+use crate::{data::Op, BenchData};
+
+/// A builder of the [BenchData] objects.
+///
+/// The recorder maintains a mutable [Document] that reflects the current
+/// state of the recorded text, and appends each command to the resulting
+/// BenchData stream.
+///
+/// You can attach the recorder to your editor integration to capture real
+/// editing sessions: call the [init](Self::init) function when the editor
+/// opens the file, the [edit](Self::edit) function on every text change, and
+/// the [wait](Self::wait) function when the end user pauses typing.
+///
+/// ```ignore
+/// let mut recorder = BenchRecorder::<MyNode>::new("My Session");
+///
+/// recorder.init("foo");
+/// recorder.edit(3..3, " bar");
+/// recorder.wait();
+///
+/// let data = recorder.finish();
+/// ```
+pub struct BenchRecorder<N: Node> {
+    result: BenchData,
+    doc: Document<N>,
+}
+
+impl<N: Node> BenchRecorder<N> {
+    /// Creates a new recorder of the session with the specified `title`.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            result: BenchData {
+                title: title.into(),
+                meta: String::new(),
+                bytes: 0,
+                lines: 0,
+                text: String::new(),
+                ops: Vec::new(),
+            },
+
+            doc: Document::new_mutable(""),
+        }
+    }
+
+    /// Sets the user-defined metadata of the recorded session.
+    ///
+    /// The recorder does not interpret this string. For instance, you can
+    /// store the configuration of the data generator to check if
+    /// the previously saved data is up to date.
+    #[inline(always)]
+    pub fn set_meta(&mut self, meta: impl Into<String>) {
+        self.result.meta = meta.into();
+    }
+
+    /// Provides access to the Document that reflects the current state of
+    /// the recorded text.
+    #[inline(always)]
+    pub fn document(&self) -> &Document<N> {
+        &self.doc
+    }
+
+    /// Records the [Init](crate::BenchCommand::Init) command that replaces
+    /// the entire text with the `text`.
+    pub fn init(&mut self, text: impl Into<String>) {
+        let text = text.into();
+        let length = text.len();
+
+        self.result.text.push_str(&text);
+        self.doc.write(.., text);
+
+        self.result.ops.push(Op::Init { length });
+
+        self.result.bytes = length;
+        self.result.lines = self.doc.lines().lines_count();
+    }
+
+    /// Records the [Edit](crate::BenchCommand::Edit) command that replaces
+    /// the `span` fragment of the text with the `text`.
+    ///
+    /// The `span` can be a site span, a position span, or any other
+    /// [ToSpan] object valid for the current state of the text.
+    ///
+    /// **Panic**
+    ///
+    /// Panics if the `span` is not valid.
+    pub fn edit(&mut self, span: impl ToSpan, text: impl Into<String>) {
+        let Some(span) = span.to_site_span(&self.doc) else {
+            panic!("Invalid edit span.");
+        };
+
+        let text = text.into();
+        let length = text.len();
+        let new_end_site = span.start + text.chars().count();
+
+        let Some(position_span) = span.to_position_span(&self.doc) else {
+            panic!("Invalid edit span.");
+        };
+
+        self.result.text.push_str(&text);
+        self.doc.write(&span, text);
+
+        let Some(new_end_position) = new_end_site.to_position(&self.doc) else {
+            panic!("Invalid edit span.");
+        };
+
+        self.result.ops.push(Op::Edit {
+            length,
+            start_site: span.start,
+            start_line: position_span.start.line,
+            start_column: position_span.start.column,
+            end_site: span.end,
+            end_line: position_span.end.line,
+            end_column: position_span.end.column,
+            new_end_line: new_end_position.line,
+            new_end_column: new_end_position.column,
+        });
+    }
+
+    /// Records the [Wait](crate::BenchCommand::Wait) command that denotes
+    /// the end of the edits burst.
+    #[inline(always)]
+    pub fn wait(&mut self) {
+        self.result.ops.push(Op::Wait);
+    }
+
+    /// Finishes recording and returns the recorded [BenchData].
+    #[inline(always)]
+    pub fn finish(self) -> BenchData {
+        self.result
+    }
+}
@@ -0,0 +1,191 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{
+    fmt::{Display, Formatter},
+    time::Duration,
+};
+
+/// A result of the [BenchData::replay](crate::BenchData::replay) function.
+///
+/// The report contains a [BenchStep] record for each replayed command.
+#[derive(Clone, Default, Debug)]
+pub struct BenchReport {
+    /// The records of the replayed commands in the order of the stream.
+    pub steps: Vec<BenchStep>,
+}
+
+impl Display for BenchReport {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        formatter.write_fmt(format_args!(
+            "Init time: {:?}\n\
+            Edits: {}\n\
+            Total edit time: {:?}\n\
+            Mean edit time: {:?}\n\
+            Max edit time: {:?}\n\
+            Mean reuse ratio: {:.2}",
+            self.init_time(),
+            self.edits().count(),
+            self.edit_time(),
+            self.mean_edit_time(),
+            self.max_edit_time(),
+            self.mean_reuse_ratio(),
+        ))
+    }
+}
+
+impl BenchReport {
+    /// Returns an iterator over the records of
+    /// the [Edit](crate::BenchCommand::Edit) commands.
+    #[inline(always)]
+    pub fn edits(&self) -> impl Iterator<Item = &BenchStep> + '_ {
+        self.steps
+            .iter()
+            .filter(|step| step.kind == BenchStepKind::Edit)
+    }
+
+    /// Returns the total time spent on
+    /// the [Init](crate::BenchCommand::Init) commands.
+    pub fn init_time(&self) -> Duration {
+        self.steps
+            .iter()
+            .filter(|step| step.kind == BenchStepKind::Init)
+            .map(|step| step.time)
+            .sum()
+    }
+
+    /// Returns the total time spent on
+    /// the [Edit](crate::BenchCommand::Edit) commands.
+    pub fn edit_time(&self) -> Duration {
+        self.edits().map(|step| step.time).sum()
+    }
+
+    /// Returns the average time of a single
+    /// [Edit](crate::BenchCommand::Edit) command.
+    ///
+    /// Returns zero duration if the report does not have edits.
+    pub fn mean_edit_time(&self) -> Duration {
+        let count = self.edits().count();
+
+        if count == 0 {
+            return Duration::ZERO;
+        }
+
+        self.edit_time() / count as u32
+    }
+
+    /// Returns the longest time of a single
+    /// [Edit](crate::BenchCommand::Edit) command.
+    ///
+    /// Returns zero duration if the report does not have edits.
+    pub fn max_edit_time(&self) -> Duration {
+        self.edits()
+            .map(|step| step.time)
+            .max()
+            .unwrap_or(Duration::ZERO)
+    }
+
+    /// Returns the average [reuse ratio](BenchStep::reuse_ratio) of
+    /// the [Edit](crate::BenchCommand::Edit) commands.
+    ///
+    /// Returns zero if the report does not have edits.
+    pub fn mean_reuse_ratio(&self) -> f64 {
+        let count = self.edits().count();
+
+        if count == 0 {
+            return 0.0;
+        }
+
+        self.edits().map(|step| step.reuse_ratio()).sum::<f64>() / count as f64
+    }
+}
+
+/// A record of a single replayed command.
+///
+/// See [BenchReport] for details.
+#[derive(Clone, Debug)]
+pub struct BenchStep {
+    /// The index of the command in the [BenchData](crate::BenchData) stream.
+    pub index: usize,
+
+    /// The kind of the command.
+    pub kind: BenchStepKind,
+
+    /// The time of the document write operation.
+    ///
+    /// This value is zero for the [Wait](BenchStepKind::Wait) commands.
+    pub time: Duration,
+
+    /// The number of syntax tree clusters that the document has parsed anew.
+    ///
+    /// See [ReparseReport](lady_deirdre::units::ReparseReport) for details.
+    pub reparsed: usize,
+
+    /// The number of syntax tree clusters that the document has reused
+    /// without reparsing.
+    ///
+    /// See [ReparseReport](lady_deirdre::units::ReparseReport) for details.
+    pub reused: usize,
+}
+
+impl BenchStep {
+    /// Returns a ratio of the reused clusters to the total number of
+    /// clusters visited by the reparser.
+    ///
+    /// The value is within the `0.0..=1.0` range. Returns zero if the reparser
+    /// did not visit any cluster.
+    #[inline(always)]
+    pub fn reuse_ratio(&self) -> f64 {
+        let total = self.reused + self.reparsed;
+
+        if total == 0 {
+            return 0.0;
+        }
+
+        self.reused as f64 / total as f64
+    }
+}
+
+/// A kind of the replayed command.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum BenchStepKind {
+    /// The [Init](crate::BenchCommand::Init) command.
+    Init,
+
+    /// The [Edit](crate::BenchCommand::Edit) command.
+    Edit,
+
+    /// The [Wait](crate::BenchCommand::Wait) command.
+    Wait,
+}
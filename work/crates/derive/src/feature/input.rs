@@ -50,18 +50,83 @@ use syn::{
     Visibility,
 };
 
-use crate::utils::{error, system_panic, Dump, PredictableCollection, Set};
+use crate::utils::{error, Dump, PredictableCollection, Set};
 
 pub struct FeatureInput {
     pub(super) ident: Ident,
     pub(super) generics: Generics,
     pub(super) vis: Visibility,
-    pub(super) fields: Fields,
+    pub(super) variants: Vec<FeatureVariant>,
+    pub(super) default: usize,
     pub(super) node: Type,
-    pub(super) invalidate: Set<usize>,
     pub(crate) dump: Dump,
 }
 
+pub(super) struct FeatureVariant {
+    pub(super) ident: Option<Ident>,
+    pub(super) fields: Fields,
+    pub(super) invalidate: Set<usize>,
+}
+
+impl FeatureVariant {
+    fn new(ident: Option<Ident>, fields: Fields) -> Result<Self> {
+        let mut invalidate = Set::with_capacity(fields.len());
+
+        for (index, field) in fields.iter().enumerate() {
+            let mut invalidate_flag = false;
+
+            for attr in &field.attrs {
+                match attr.style {
+                    AttrStyle::Inner(_) => continue,
+                    AttrStyle::Outer => (),
+                }
+
+                let name = match attr.meta.path().get_ident() {
+                    Some(ident) => ident,
+                    None => continue,
+                };
+
+                let span = attr.span();
+
+                match name.to_string().as_str() {
+                    "scoped" => {
+                        if invalidate_flag {
+                            return Err(error!(span, "Duplicate Scoped attribute.",));
+                        }
+
+                        invalidate_flag = true;
+                    }
+
+                    "dump" => {
+                        return Err(error!(span, "Dump attribute is not applicable here.",));
+                    }
+
+                    "default" => {
+                        return Err(error!(span, "Default attribute is not applicable here.",));
+                    }
+
+                    _ => continue,
+                }
+            }
+
+            if invalidate_flag {
+                let _ = invalidate.insert(index);
+            }
+        }
+
+        Ok(Self {
+            ident,
+            fields,
+            invalidate,
+        })
+    }
+
+    #[inline(always)]
+    pub(super) fn is_enum_variant(&self) -> bool {
+        self.ident.is_some()
+    }
+}
+
 impl Parse for FeatureInput {
     #[inline(always)]
     fn parse(input: ParseStream) -> Result<Self> {
@@ -79,25 +144,89 @@ impl TryFrom<DeriveInput> for FeatureInput {
         let generics = input.generics;
         let vis = input.vis;
 
-        let data = match input.data {
-            Data::Struct(data) => data,
+        let (variants, default) = match input.data {
+            Data::Struct(data) => (vec![FeatureVariant::new(None, data.fields)?], 0),
+
+            Data::Enum(data) => {
+                if data.variants.is_empty() {
+                    return Err(error!(
+                        ident.span(),
+                        "Feature enum must have at least one variant.",
+                    ));
+                }
+
+                let mut variants = Vec::with_capacity(data.variants.len());
+                let mut default = None;
+
+                for (index, variant) in data.variants.into_iter().enumerate() {
+                    for attr in &variant.attrs {
+                        match attr.style {
+                            AttrStyle::Inner(_) => continue,
+                            AttrStyle::Outer => (),
+                        }
+
+                        let name = match attr.meta.path().get_ident() {
+                            Some(ident) => ident,
+                            None => continue,
+                        };
+
+                        let span = attr.span();
+
+                        match name.to_string().as_str() {
+                            "default" => {
+                                if default.is_some() {
+                                    return Err(error!(span, "Duplicate Default attribute.",));
+                                }
+
+                                attr.meta.require_path_only()?;
+
+                                default = Some(index);
+                            }
+
+                            "scoped" => {
+                                return Err(error!(
+                                    span,
+                                    "Scoped attribute is not applicable here.",
+                                ));
+                            }
+
+                            "dump" => {
+                                return Err(
+                                    error!(span, "Dump attribute is not applicable here.",),
+                                );
+                            }
 
-            other => {
-                let span = match other {
-                    Data::Enum(data) => data.enum_token.span,
-                    Data::Union(data) => data.union_token.span,
-                    _ => system_panic!("Unsupported Item format."),
+                            _ => continue,
+                        }
+                    }
+
+                    variants.push(FeatureVariant::new(Some(variant.ident), variant.fields)?);
+                }
+
+                let default = match default {
+                    Some(index) => index,
+
+                    None => {
+                        return Err(error!(
+                            ident.span(),
+                            "Default variant was not specified.\nUse #[default] \
+                            attribute on the variant that the Feature::new \
+                            function should construct.",
+                        ));
+                    }
                 };
 
+                (variants, default)
+            }
+
+            Data::Union(data) => {
                 return Err(error!(
-                    span,
-                    "Feature must be derived from the struct type.",
+                    data.union_token.span,
+                    "Feature must be derived from the struct or enum type.",
                 ));
             }
         };
 
-        let fields = data.fields;
-
         let mut node = None;
         let mut dump = Dump::None;
 
@@ -151,60 +280,20 @@ impl TryFrom<DeriveInput> for FeatureInput {
             }
         };
 
-        let mut invalidate = Set::with_capacity(fields.len());
-
-        for (index, field) in fields.iter().enumerate() {
-            let mut invalidate_flag = false;
-
-            for attr in &field.attrs {
-                match attr.style {
-                    AttrStyle::Inner(_) => continue,
-                    AttrStyle::Outer => (),
-                }
-
-                let name = match attr.meta.path().get_ident() {
-                    Some(ident) => ident,
-                    None => continue,
-                };
-
-                let span = attr.span();
-
-                match name.to_string().as_str() {
-                    "scoped" => {
-                        if invalidate_flag {
-                            return Err(error!(span, "Duplicate Scoped attribute.",));
-                        }
-
-                        invalidate_flag = true;
-                    }
-
-                    "dump" => {
-                        return Err(error!(span, "Dump attribute is not applicable here.",));
-                    }
-
-                    _ => continue,
-                }
-            }
-
-            if invalidate_flag {
-                let _ = invalidate.insert(index);
-            }
-        }
-
         let result = Self {
             ident,
             generics,
             vis,
-            fields,
+            variants,
+            default,
             node,
-            invalidate,
             dump,
         };
 
         match dump {
             Dump::None | Dump::Dry(_) | Dump::Decl(_) => {}
 
-            Dump::Trivia(span) | Dump::Meta(span) => {
+            Dump::Trivia(span) | Dump::Meta(span) | Dump::Hash(span) | Dump::Ebnf(span) => {
                 return Err(error!(
                     span,
                     "This type of the dump mode is not applicable to the Feature macros.",
@@ -34,7 +34,7 @@
 
 use proc_macro2::TokenStream;
 use quote::ToTokens;
-use syn::{spanned::Spanned, Fields, LitStr};
+use syn::{spanned::Spanned, Fields, LitStr, WherePredicate};
 
 use crate::{
     feature::FeatureInput,
@@ -55,106 +55,159 @@ impl ToTokens for FeatureInput {
         let core = span.face_core();
         let result = span.face_result();
 
-        let mut getters = Vec::with_capacity(self.fields.len());
-        let mut keys = Vec::with_capacity(self.fields.len());
-        let mut constructors = Vec::with_capacity(self.fields.len());
-        let mut initializers = Vec::with_capacity(self.fields.len());
-        let mut invalidators = Vec::with_capacity(self.fields.len());
-
-        for (index, field) in self.fields.iter().enumerate() {
-            let ident = &field.ident;
-            let ty = &field.ty;
-
-            let span = ty.span();
-            let core = span.face_core();
-            let result = span.face_result();
-
-            let invalidate = self.invalidate.contains(&index);
-
-            match ident {
-                Some(ident) => {
-                    if &field.vis == vis {
-                        let span = ident.span();
-                        let core = ident.face_core();
-
-                        let literal = LitStr::new(ident.to_string().as_str(), span);
+        let is_generic = !self.generics.params.is_empty();
+
+        let mut getters = Vec::with_capacity(self.variants.len());
+        let mut keys = Vec::with_capacity(self.variants.len());
+        let mut constructor = None;
+        let mut initializers = Vec::with_capacity(self.variants.len());
+        let mut invalidators = Vec::with_capacity(self.variants.len());
+        let mut predicates = Vec::<WherePredicate>::new();
+
+        for (variant_index, variant) in self.variants.iter().enumerate() {
+            let path = match &variant.ident {
+                Some(variant_ident) => quote_spanned!(variant_ident.span()=> Self::#variant_ident),
+                None => quote_spanned!(span=> Self),
+            };
+
+            let is_default = variant_index == self.default;
+
+            let mut pattern = Vec::with_capacity(variant.fields.len());
+            let mut variant_getters = Vec::with_capacity(variant.fields.len());
+            let mut variant_keys = Vec::with_capacity(variant.fields.len());
+            let mut constructors = Vec::with_capacity(variant.fields.len());
+            let mut variant_initializers = Vec::with_capacity(variant.fields.len());
+            let mut variant_invalidators = Vec::with_capacity(variant.fields.len());
+
+            for (index, field) in variant.fields.iter().enumerate() {
+                let ty = &field.ty;
+
+                let span = ty.span();
+                let core = span.face_core();
+                let result = span.face_result();
+
+                let value = format_ident!("_{}", index, span = span);
+
+                // Enum variant fields inherit the visibility of the enum type.
+                let exposed = variant.is_enum_variant() || &field.vis == vis;
+
+                match &field.ident {
+                    Some(field_ident) => {
+                        let span = field_ident.span();
+                        let literal = LitStr::new(field_ident.to_string().as_str(), span);
+
+                        pattern.push(quote_spanned!(span=> #field_ident: #value));
+
+                        if exposed {
+                            let core = field_ident.face_core();
+
+                            variant_getters.push(quote_spanned!(span=>
+                                #core::syntax::Key::Index(#index)
+                                    | #core::syntax::Key::Name(#literal) => #result::Ok(#value)
+                            ));
+                            variant_keys
+                                .push(quote_spanned!(span=> &#core::syntax::Key::Name(#literal)));
+                        }
+
+                        if is_default {
+                            constructors.push(quote_spanned!(span=>
+                                #field_ident: <#ty as #core::analysis::Feature>::new(node_ref),
+                            ));
+                        }
+                    }
 
-                        getters.push(quote_spanned!(span=>
-                            #core::syntax::Key::Index(#index)
-                                | #core::syntax::Key::Name(#literal) => #result::Ok(&self.#ident)
-                        ));
-                        keys.push(quote_spanned!(span=> &#core::syntax::Key::Name(#literal)));
+                    None => {
+                        pattern.push(quote_spanned!(span=> #value));
+
+                        if exposed {
+                            variant_getters.push(quote_spanned!(span=>
+                                #core::syntax::Key::Index(#index) => #result::Ok(#value)
+                            ));
+                            variant_keys
+                                .push(quote_spanned!(span=> &#core::syntax::Key::Index(#index)));
+                        }
+
+                        if is_default {
+                            constructors.push(quote_spanned!(span=>
+                                <#ty as #core::analysis::Feature>::new(node_ref),
+                            ));
+                        }
                     }
+                }
 
-                    constructors.push(quote_spanned!(span=>
-                        #ident: <#ty as #core::analysis::Feature>::new(node_ref),
-                    ));
+                variant_initializers.push(quote_spanned!(span=>
+                    <#ty as #core::analysis::Feature>::init(#value, initializer);
+                ));
 
-                    initializers.push(quote_spanned!(span=>
-                        <#ty as #core::analysis::Feature>::init(
-                            &mut self.#ident,
-                            initializer,
-                        );
+                if variant.invalidate.contains(&index) {
+                    variant_invalidators.push(quote_spanned!(span=>
+                        <#ty as #core::analysis::Feature>::invalidate(#value, invalidator);
                     ));
+                }
 
-                    if invalidate {
-                        invalidators.push(quote_spanned!(span=>
-                            <#ty as #core::analysis::Feature>::invalidate(
-                                &self.#ident,
-                                invalidator,
-                            );
-                        ));
-                    }
+                if is_generic {
+                    predicates.push(parse_quote_spanned!(span=>
+                        #ty: #core::analysis::Feature<Node = #node>
+                    ));
                 }
+            }
 
-                None => {
-                    if &field.vis == vis {
-                        getters.push(quote_spanned!(span=>
-                            #core::syntax::Key::Index(#index) => #result::Ok(&self.#ident)
-                        ));
-                        keys.push(quote_spanned!(span=> &#core::syntax::Key::Index(#index)));
-                    }
+            let pattern = match &variant.fields {
+                Fields::Named(_) => quote_spanned!(span=> #path { #( #pattern, )* }),
+                Fields::Unnamed(_) => quote_spanned!(span=> #path( #( #pattern, )* )),
+                Fields::Unit => path.clone(),
+            };
 
-                    constructors.push(quote_spanned!(span=>
-                        <#ty as #core::analysis::Feature>::new(node_ref),
-                    ));
+            if is_default {
+                constructor = Some(match &variant.fields {
+                    Fields::Named(_) => quote_spanned!(span=> #path {
+                        #(
+                        #constructors
+                        )*
+                    }),
 
-                    initializers.push(quote_spanned!(span=>
-                        <#ty as #core::analysis::Feature>::init(
-                            &mut self.#index,
-                            initializer,
-                        );
-                    ));
+                    Fields::Unnamed(_) => quote_spanned!(span=> #path(
+                        #(
+                        #constructors
+                        )*
+                    )),
 
-                    if invalidate {
-                        invalidators.push(quote_spanned!(span=>
-                            <#ty as #core::analysis::Feature>::invalidate(
-                                &self.#index,
-                                invalidator,
-                            );
-                        ));
-                    }
-                }
+                    Fields::Unit => path.clone(),
+                });
             }
-        }
 
-        let constructor = match self.fields {
-            Fields::Named(_) => quote_spanned!(span=> Self {
+            getters.push(quote_spanned!(span=>
+                #pattern => match key {
+                    #(
+                    #variant_getters,
+                    )*
+
+                    _ => #result::Err(#core::analysis::AnalysisError::MissingFeature),
+                }
+            ));
+
+            keys.push(quote_spanned!(span=> #path { .. } => &[#( #variant_keys ),*]));
+
+            initializers.push(quote_spanned!(span=> #pattern => {
                 #(
-                #constructors
+                #variant_initializers
                 )*
-            }),
+            }));
 
-            Fields::Unnamed(_) => quote_spanned!(span=> Self(
+            invalidators.push(quote_spanned!(span=> #pattern => {
                 #(
-                #constructors
+                #variant_invalidators
                 )*
-            )),
+            }));
+        }
 
-            Fields::Unit => quote_spanned!(span=> Self),
-        };
+        let mut generics = self.generics.clone();
 
-        let (impl_generics, type_generics, where_clause) = self.generics.split_for_impl();
+        if !predicates.is_empty() {
+            generics.make_where_clause().predicates.extend(predicates);
+        }
+
+        let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
 
         quote_spanned!(span=>
             impl #impl_generics #core::analysis::AbstractFeature for #ident #type_generics
@@ -170,21 +223,24 @@ impl ToTokens for FeatureInput {
                     &#core::analysis::NIL_SLOT_REF
                 }
 
+                #[allow(unused_variables)]
                 fn feature(&self, key: #core::syntax::Key)
                     -> #core::analysis::AnalysisResult<&dyn #core::analysis::AbstractFeature>
                 {
-                    match key {
+                    match self {
                         #(
                         #getters,
                         )*
-
-                        _ => #result::Err(#core::analysis::AnalysisError::MissingFeature),
                     }
                 }
 
                 #[inline(always)]
-                fn feature_keys(&self) -> &'static [&'static #core::syntax::Key] {
-                    &[#( #keys ),*]
+                fn feature_keys(&self) -> &'static [&'static #core::syntax::Key<'static>] {
+                    match self {
+                        #(
+                        #keys,
+                        )*
+                    }
                 }
             }
 
@@ -208,9 +264,11 @@ impl ToTokens for FeatureInput {
                     &mut self,
                     initializer: &mut #core::analysis::Initializer<Self::Node, H, S>,
                 ) {
-                    #(
-                    #initializers
-                    )*
+                    match self {
+                        #(
+                        #initializers
+                        )*
+                    }
                 }
 
                 #[inline(always)]
@@ -222,9 +280,11 @@ impl ToTokens for FeatureInput {
                     &self,
                     #[allow(unused)] invalidator: &mut #core::analysis::Invalidator<Self::Node, H, S>,
                 ) {
-                    #(
-                    #invalidators
-                    )*
+                    match self {
+                        #(
+                        #invalidators
+                        )*
+                    }
                 }
             }
         )
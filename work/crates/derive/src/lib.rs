@@ -95,6 +95,13 @@ mod utils;
 /// The generated scanner is a minimal finite state machine that unions the
 /// specified regular expression rules.
 ///
+/// If all characters mentioned in the rules are ASCII characters, and
+/// the rules don't use Unicode character classes (such as `$alpha`),
+/// the scanner looks up the transitions in a byte-indexed table. The
+/// non-ASCII characters matched by the exclusion classes (`.` or `^[...]`)
+/// are handled by the generic transitions that read the entire
+/// Unicode character.
+///
 /// ## Macro Application Outline
 ///
 /// ```ignore
@@ -123,6 +130,23 @@ mod utils;
 /// //    Produces the normal output of the macro with all Rust spans erased.
 /// //    This is useful when the macro is being applied inside the declarative
 /// //    macro.
+/// //
+/// //  - The `hash` mode.
+/// //    Produces the normal output of the macro and the `DERIVE_HASH: u64`
+/// //    associated constant of the type. The constant is a stable hash of
+/// //    the generated implementation that does not depend on the Rust spans.
+/// //    Pin this value in a test with the `assert_derive_hash!` macro to
+/// //    detect the changes in the generated code early.
+/// //
+/// //  - The `ebnf` mode.
+/// //    Produces the normal output of the macro and writes the token rules
+/// //    in the W3C EBNF notation to the `<type name>.ebnf` file of
+/// //    the directory set by the `LADY_DEIRDRE_DUMP_DIR` environment
+/// //    variable. The inline expressions (`#[define(...)]`) are expanded in
+/// //    place, and the character sequences are joined into string literals.
+/// //    The Unicode property classes keep the `$alpha` notation.
+/// //    The priorities, guards, anchors, and blank tokens are noted in
+/// //    the comments.
 /// #[dump(<mode>)]
 ///
 /// // An optional instruction that sets the state machine optimization strategy.
@@ -146,9 +170,18 @@ mod utils;
 /// // This value denotes the number of Unicode characters the scanner needs
 /// // to step back to rescan a fragment of the source code text.
 /// //
-/// // When omitted, the value set to 1 by default.
+/// // When omitted, the value set to 1 by default, or to the maximum length
+/// // of the rules with the end-of-line anchor (`<expr> $`) if this length
+/// // is greater than 1.
 /// #[lookback(1)]
 ///
+/// // An optional list of the token variants that sets the Token::BLANK value.
+/// //
+/// // The scanner skips the tokens of these variants (e.g., whitespaces and
+/// // comments) when it determines the previous token for the
+/// // `#[after(...)]` and `#[not_after(...)]` guards.
+/// #[blank($Whitespace | $Comment)]
+///
 /// // Optional inline expressions that you can use inside other expressions
 /// // by name (specified before the "=" sign): `Foo | 'x' & Bar`.
 /// //
@@ -180,7 +213,9 @@ mod utils;
 ///     //
 ///     // The `<rust expr>` must return a valid token variant that corresponds
 ///     // to this fragment. The `fragment` variable of type `&str` can be used
-///     // inside the constructor expression.
+///     // inside the constructor expression. The substrings captured by
+///     // the `<key>: <expr>` operators of the rule expression are available
+///     // as the `&str` variables with the names of the capture keys.
 ///     #[constructor(<rust expr>)]
 ///
 ///     // Optional.
@@ -214,6 +249,23 @@ mod utils;
 ///     // The default priority is zero.
 ///     #[priority(<signed integer number>)]
 ///
+///     // Optional.
+///     //
+///     // Restricts the rule to the lexical context: the scanner matches this
+///     // rule only if the last non-blank token scanned before it is one of
+///     // the listed variants. The End-of-Input variant in this list denotes
+///     // the beginning of the source code.
+///     //
+///     // For example, in JavaScript-like languages, the `/` character starts
+///     // a regular expression literal unless it follows an identifier,
+///     // a literal, or a closing bracket.
+///     //
+///     // The `#[not_after(...)]` attribute is the inverted form of this guard:
+///     // the scanner matches the rule unless the previous token is one of
+///     // the listed variants. The variant may have at most one of these two
+///     // attributes.
+///     #[after($Ident | $CloseParen)]
+///
 ///     // The `= <num>` discriminant is optional but if specified,
 ///     // it will match the `Token::rule()` value.
 ///     ParsableVariant,
@@ -298,6 +350,11 @@ mod utils;
 ///
 ///  - Case-insensitive matching: `i("foo")`.
 ///    Matches "foo", "FOO", "Foo", and other case-insensitive variants.
+///    The case variants are computed using the Unicode simple case mappings
+///    before the state machine construction, so the resulting automata
+///    is minimized as usual. For string literals, the shorthand form
+///    `i "foo"` is available too (note the whitespace between `i` and the
+///    literal: the 2021 edition reserves the literal prefixes).
 ///
 ///  - Any Unicode uppercase character: `$upper`.
 ///
@@ -313,6 +370,13 @@ mod utils;
 ///
 ///  - Any Unicode identifier's continuation character: `$xid_continue`.
 ///
+///  - Any character of the specified Unicode script: `$script(Latin)`.
+///    The script is specified by its Unicode name (`Old_Italic`), by its
+///    ISO 15924 code (`Ital`), or by the name of the
+///    `Script` enum variant (`OldItalic`).
+///    A character belongs to the script if its `Script_Extensions` property
+///    contains this script.
+///
 ///  - A class of the character property combinations: `${alpha | num | space}`.
 ///    The property names can be any combination of the names listed above.
 ///
@@ -340,11 +404,50 @@ mod utils;
 ///  - Inline expression: `FOO`. Inlines the expression defined previously using
 ///    the `#[define(FOO = <expr>)]` macro attribute.
 ///
+///  - Fragment capture: `<key>: <expr>`. Matches `<expr>` and captures
+///    the matched substring under the `<key>` name. The captured substrings
+///    are passed to the variant's `#[constructor(...)]` expression as the `&str`
+///    variables named by the keys (e.g., `#[rule('"' body: ^['"']* '"')]` and
+///    `#[constructor(parse_string(fragment, body))]`). Like the other
+///    operands, the capture binds tighter than the unary operators: in
+///    `body: ^['"']*` the capture applies to each repeated character.
+///    If the capture matches several times (within a repetition, or when
+///    the same key is used in several places), the captured substring spans
+///    from the beginning of the first match to the end of the last match.
+///    If the capture never matches (e.g., zero repetitions or an omitted
+///    optional expression), the variable is an empty string. If the rule
+///    admits several ways to match the captures, the captures start as
+///    early and end as late as possible. The `fragment` key is reserved.
+///
+///  - Start-of-line anchor: `^ <expr>`. Matches `<expr>` only if the token
+///    starts at the beginning of the source code or right after the `\n`
+///    character. The anchor is allowed only in front of the rule expression.
+///    The `^[...]` form denotes the exclusion set; to anchor a set of
+///    characters, enclose it in parentheses: `^ (['a'..'z'])`.
+///
+///  - End-of-line anchor: `<expr> $`. Matches `<expr>` only if the token is
+///    followed by the `\n` or `\r` character, or by the end of the source code.
+///    The anchor is allowed only at the end of the rule expression. If
+///    the anchored rule matches strings of unlimited length, the enum type
+///    requires an explicit `#[lookback(...)]` attribute.
+///
 ///  - Debug dump: `dump(<expr>)`. Enforces the macro program to print the state
 ///    machine transitions of the `<expr>` rule to the terminal.
 #[proc_macro_derive(
     Token,
-    attributes(define, lookback, rule, priority, constructor, describe, opt, dump)
+    attributes(
+        define,
+        lookback,
+        blank,
+        rule,
+        priority,
+        after,
+        not_after,
+        constructor,
+        describe,
+        opt,
+        dump,
+    )
 )]
 pub fn token(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as TokenInput);
@@ -393,6 +496,13 @@ pub fn token(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 ///
 /// // Optional.
 /// //
+/// // Sets the cross-document node indexer type (`Grammar::CrossIndexer` type).
+/// //
+/// // When omitted, the indexer is set to the VoidCrossIndexer.
+/// #[cross_indexer(<cross indexer type>)]
+///
+/// // Optional.
+/// //
 /// // Specifies the semantic entry-point for the common semantics shared across
 /// // all documents in the Analyzer.
 /// //
@@ -429,7 +539,10 @@ pub fn token(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 /// // (the `Recovery::group` pairs).
 /// //
 /// // The <config> is a sequence of elements delimited by `,` comma, where each
-/// // element is either a halting `$Token`, or a group pair `[$Start, $End]`.
+/// // element is either a halting `$Token`, a group pair `[$Start, $End]`,
+/// // or a single-token recovery limit: `insert_limit = <number>` or
+/// // `delete_limit = <number>` (the `Recovery::insert_limit` and
+/// // the `Recovery::delete_limit` values).
 /// //
 /// // Example: `#[recovery($Semicolon, [$OpenBrace, $CloseBrace])]`.
 /// //
@@ -459,6 +572,22 @@ pub fn token(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 /// //    Produces the normal output of the macro with all Rust spans erased.
 /// //    This is useful when the macro is being applied inside the declarative
 /// //    macro.
+/// //
+/// //  - The `hash` mode.
+/// //    Produces the normal output of the macro and the `DERIVE_HASH: u64`
+/// //    associated constant of the type. The constant is a stable hash of
+/// //    the generated implementation that does not depend on the Rust spans.
+/// //    Pin this value in a test with the `assert_derive_hash!` macro to
+/// //    detect the changes in the generated code early.
+/// //
+/// //  - The `ebnf` mode.
+/// //    Produces the normal output of the macro and writes the parsing rules
+/// //    in the W3C EBNF notation to the `<type name>.ebnf` file of
+/// //    the directory set by the `LADY_DEIRDRE_DUMP_DIR` environment
+/// //    variable. The inline expressions (`#[define(...)]`) are expanded in
+/// //    place, the captures are omitted, and the tokens keep the `$Token`
+/// //    notation. The trivia expressions, recovery configurations, error
+/// //    rules, and binary operators are noted in the comments.
 /// #[dump(<mode>)]
 ///
 /// // Optional inline expressions that you can use inside other expressions
@@ -485,6 +614,39 @@ pub fn token(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 ///     // to reveal the leftmost set of tokens of the parsing rule.
 ///     #[rule(<parse expr>)]
 ///
+///     // Optional. Mutually exclusive with the #[rule(...)] attribute.
+///     //
+///     // Turns the variant into a binary operator node of the expression
+///     // whose operands are parsed by the "Operand" variant's rule.
+///     //
+///     // The "op" entry enumerates the operator tokens. The "precedence"
+///     // entry is a number in the 1..=100 range (a higher number binds
+///     // tighter). The "assoc" entry is either "left" (default) or "right".
+///     //
+///     // See "Binary Operators" section below for details.
+///     #[binary(op: $Plus | $Minus, left: Operand, right: Operand, precedence = 1, assoc = left)]
+///
+///     // Optional. Mutually exclusive with the #[rule(...)], #[binary(...)],
+///     // and #[root] attributes.
+///     //
+///     // Specifies the parsing rule of the error production variant.
+///     // The parser tries this rule when the primary alternatives of
+///     // the other rules fail to match the input, and reports a syntax error
+///     // with the specified message.
+///     //
+///     // See "Error Rules" section below for details.
+///     #[error_rule(<parse expr>, "message")]
+///
+///     // Optional. Only applicable when the variant has a #[rule(...)]
+///     // attribute, and does not have a #[parser(...)] attribute.
+///     //
+///     // Reinterprets the tokens of the first kind as the tokens of
+///     // the second kind when the token string matches the string literal,
+///     // and the parser of this variant expects the second kind of token.
+///     //
+///     // See "Contextual Keywords" section below for details.
+///     #[remap($Ident => $Get if "get", $Ident => $Set if "set")]
+///
 ///     // Optional.
 ///     //
 ///     // Overrides the parser generated by the macro with the user-defined
@@ -741,6 +903,137 @@ pub fn token(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 /// will be aware of how to descend into the node's parser. Usually, you can just
 /// enumerate the tokens with the union operator: `#[rule($TokenA | $TokenB | $TokenC)]`.
 ///
+/// The custom parser is responsible for capturing the consumed tokens into
+/// the node's children. If the parser consumes a token without capturing it
+/// (directly or through a child node), the token falls outside of the node's
+/// span. It is recommended to test such grammars with the
+/// `lady_deirdre::syntax::check_coverage` function that reports these gaps.
+///
+/// ### Binary Operators
+///
+/// LL(1) rules cannot express left-recursive infix expressions directly.
+/// Instead, you can describe each infix operator with the `#[binary(...)]`
+/// macro attribute, and the macro will generate a precedence climbing (Pratt)
+/// parser for all operators that share the same operand rule on top of
+/// the `lady_deirdre::syntax::PrattDriver` building blocks.
+///
+/// ```ignore
+/// #[derive(Node)]
+/// #[token(MyToken)]
+/// enum MyNode {
+///     #[root]
+///     #[rule(expr: Sum)]
+///     Root { #[child] expr: NodeRef },
+///
+///     #[rule(number: $Number | $ParenOpen & inner: Sum & $ParenClose)]
+///     Operand {
+///         #[child]
+///         number: TokenRef,
+///         #[child]
+///         inner: NodeRef,
+///     },
+///
+///     #[binary(op: $Plus | $Minus, left: Operand, right: Operand, precedence = 1)]
+///     Sum {
+///         #[child]
+///         left: NodeRef,
+///         #[child]
+///         op: TokenRef,
+///         #[child]
+///         right: NodeRef,
+///     },
+///
+///     #[binary(op: $Star, left: Operand, right: Operand, precedence = 2)]
+///     Mul {
+///         #[child]
+///         left: NodeRef,
+///         #[child]
+///         op: TokenRef,
+///         #[child]
+///         right: NodeRef,
+///     },
+///
+///     #[binary(op: $Caret, left: Operand, right: Operand, precedence = 3, assoc = right)]
+///     Pow {
+///         #[child]
+///         left: NodeRef,
+///         #[child]
+///         op: TokenRef,
+///         #[child]
+///         right: NodeRef,
+///     },
+/// }
+/// ```
+///
+/// Referring to an operator variant from another rule (`expr: Sum` above)
+/// parses an expression that consists of the operators of this variant's
+/// precedence level or higher. Usually, you would refer to the operator with
+/// the lowest precedence. The result of such descending is either an operator
+/// node or just the operand node if the expression has no operators.
+///
+/// The operator variants must have the `left`, `op`, and `right` fields
+/// annotated with the `#[child]` attribute. The `left` and the `right` entries
+/// must refer to the same operand variant, and the operand variant cannot be
+/// an operator itself.
+///
+/// The operators of the same operand must have distinct tokens, and
+/// the operators of the same precedence level must have the same
+/// associativity.
+///
+/// The operand nodes are parsed as usual, and they are cached by
+/// the incremental reparser unless the operand variant is `#[secondary]`.
+/// The operator nodes are always secondary: the reparser caches the operands,
+/// but not the operator nodes wrapping them.
+///
+/// The `#[recovery(...)]` attribute of the operator variant configures
+/// the recovery of this operator's right operand. The `#[rule(...)]`,
+/// `#[parser(...)]`, `#[remap(...)]`, `#[constructor(...)]`,
+/// `#[trivia(...)]`, `#[secondary]`, `#[root]` and `#[dump(...)]` attributes
+/// are not applicable to the operator variants.
+///
+/// ### Contextual Keywords
+///
+/// Some languages treat certain words as keywords only in particular syntax
+/// contexts, and as regular identifiers elsewhere. The lexical scanner cannot
+/// distinguish these cases, so the Token enum would have a variant without
+/// a `#[rule(...)]` attribute for each contextual keyword, and the lexer would
+/// scan such words as identifiers.
+///
+/// The `#[remap(...)]` attribute of the Node variant instructs the variant's
+/// parser to reinterpret the identifier token as a keyword token when
+/// the token's string matches the specified literal.
+///
+/// ```ignore
+/// #[rule(kind: ($Get | $Set) name: $Ident $ParenOpen $ParenClose)]
+/// #[remap($Ident => $Get if "get", $Ident => $Set if "set")]
+/// #[secondary]
+/// Accessor {
+///     #[child]
+///     kind: TokenRef,
+///     #[child]
+///     name: TokenRef,
+/// },
+/// ```
+///
+/// The parser reinterprets the token only in the parsing states that expect
+/// the keyword token. In such states, the keyword takes precedence over
+/// the original token. In the example above, the `name` position expects
+/// an identifier only, so the accessor named "get" is a valid input.
+///
+/// The rules that descend into the variant inherit its remapping in
+/// the states where the variant's leftmost tokens are expected. Therefore,
+/// a class body rule `(Accessor | Field)*` recognizes the "get" keyword in
+/// front of the Accessor, but the rules that do not descend into
+/// the Accessor keep treating this word as an identifier.
+///
+/// The remapping affects the parser's choices only. The captured TokenRef
+/// still refers to the identifier token of the token stream.
+///
+/// Since the parser's interpretation of a word depends on the enclosing rule,
+/// the remapped variants and their sibling variants should be `#[secondary]`
+/// such that the incremental reparser reparses the enclosing rule's node
+/// whenever they change.
+///
 /// ### Ascending Relations
 ///
 /// It is recommended that each denoted variant would have `#[node]` and
@@ -833,6 +1126,20 @@ pub fn token(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 /// built-in features, including syntax tree traversal
 /// (SyntaxTree::traverse_tree()), which rely on the children metadata.
 ///
+/// Additionally, for each `#[child]` field of the parsable variants, the macro
+/// generates an associated constant of the `TypedKey` type, which you can use
+/// to access the capture with the statically known type. The name of
+/// the constant is the variant name followed by the field name in the upper
+/// snake case:
+///
+/// ```ignore
+/// // The type of `baz` is `Option<&Vec<NodeRef>>`.
+/// let baz = my_node.typed_capture(&MyNode::VARIANT_BAZ);
+/// ```
+///
+/// The macro reports an error if the constant name clashes with
+/// a `#[denote(...)]` name or with another typed key constant.
+///
 /// ### Semantics
 ///
 /// The macro automatically implements the Grammar and AbstractFeature traits
@@ -882,6 +1189,71 @@ pub fn token(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 /// the panic recovery configurations for the entire grammar or
 /// per individual variants using the `#[recovery(<config>)]` attribute.
 ///
+/// Before entering panic mode, the parser can try two cheaper strategies
+/// if the recovery configuration enables them:
+///
+///  - With `insert_limit = N`, if the current token is unexpected, but it
+///    would be expected right after one of the expected tokens, the parser
+///    assumes that this expected token is missing, and continues parsing
+///    from the current token.
+///
+///  - With `delete_limit = N`, if the current token is unexpected, but the next
+///    significant token is expected, the parser skips the current token alone.
+///
+/// The `N` value limits the number of such recoveries per each invocation of
+/// the parsing rule. In both cases, the reported
+/// `lady_deirdre::syntax::SyntaxError` refers to the assumed inserted or
+/// the skipped token in its `recovery_token` field.
+///
+/// ```ignore
+/// #[recovery(
+///     $Semicolon,
+///     [$OpenBrace..$CloseBrace],
+///     insert_limit = 1,
+///     delete_limit = 1,
+/// )]
+/// ```
+///
+/// ### Error Rules
+///
+/// The generic error recovery reports that some tokens or nodes are missing
+/// or unexpected. For the common mistakes of the end-user, you can describe
+/// the erroneous input with a dedicated variant annotated with
+/// the `#[error_rule(<parse expr>, "message")]` attribute.
+///
+/// ```ignore
+/// #[error_rule(comma: $Comma, "Trailing comma is not allowed.")]
+/// #[secondary]
+/// TrailingComma {
+///     #[child]
+///     comma: TokenRef,
+/// },
+/// ```
+///
+/// The error rules are not referred from the other rules. Instead, every
+/// generated parser tries the error rules when its primary alternatives fail:
+///
+///  - If the parser does not expect the current token at all, it tries
+///    the error rule that starts with this token before the insertion or
+///    panic recovery.
+///
+///  - If the parser expects the current token, but the next significant token
+///    (skipping the trivia tokens) cannot continue the parsing rule, it tries
+///    the error rule that starts with the current token instead of consuming
+///    the token. In the example above, the comma followed by a closing
+///    bracket is a trailing comma.
+///
+/// The parser parses the error rule into a child node of the current node,
+/// reports a `lady_deirdre::syntax::SyntaxError` whose
+/// `custom_message` is the specified message and whose span is the span of
+/// the matched input, and then continues parsing from the same state as if
+/// the erroneous input was not there.
+///
+/// The error rules must have distinct leftmost tokens, and the error rules
+/// are not tried inside the error rules themselves. Since the parser's
+/// decision depends on the surrounding input, the error rule variants
+/// should be `#[secondary]`.
+///
 /// ## Parsing Expressions
 ///
 /// Parsing expressions are regex-like expressions that describe the parsing
@@ -1010,10 +1382,14 @@ pub fn token(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     attributes(
         token,
         classifier,
+        cross_indexer,
         define,
         trivia,
         recovery,
         rule,
+        error_rule,
+        binary,
+        remap,
         root,
         denote,
         constructor,
@@ -1041,8 +1417,8 @@ pub fn node(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 /// object.
 ///
 /// This macro implements the Feature and AbstractFeature traits on the struct
-/// types, making these types eligible for use as the generic parameter of
-/// the Semantics object and as field types of other Feature objects.
+/// and enum types, making these types eligible for use as the generic parameter
+/// of the Semantics object and as field types of other Feature objects.
 ///
 /// ## Macro Application Outline
 ///
@@ -1111,7 +1487,38 @@ pub fn node(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 ///     #[scoped] pub(super) Attr<InputFn>,
 /// );
 /// ```
-#[proc_macro_derive(Feature, attributes(node, scoped, dump))]
+///
+/// Enum types are derivable too. In this case, the `feature()`,
+/// `feature_keys()`, `init()`, and `invalidate()` functions dispatch on the
+/// active variant, and all fields of the active variant are exposed.
+///
+/// ```ignore
+/// #[derive(Feature)]
+/// #[node(MyNode)]
+/// pub(super) enum SomeFeature {
+///     // Required. The variant that the `Feature::new()` function constructs.
+///     #[default]
+///     Def {
+///         #[scoped]
+///         def: Attr<DefFn>,
+///     },
+///
+///     Use(Attr<UseFn>, BazFeature),
+/// }
+/// ```
+///
+/// The generic parameters and their bounds are carried over to the generated
+/// implementations. For the generic types, the macro additionally requires
+/// each field type to implement `Feature<Node = MyNode>`.
+///
+/// ```ignore
+/// #[derive(Feature)]
+/// #[node(MyNode)]
+/// pub(super) struct Shared<T: Default + Send + Sync + 'static>(
+///     pub(super) Slot<MyNode, T>,
+/// );
+/// ```
+#[proc_macro_derive(Feature, attributes(node, scoped, default, dump))]
 pub fn feature(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as FeatureInput);
 
@@ -0,0 +1,556 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use proc_macro2::{Ident, Span, TokenStream};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Attribute,
+    Error,
+    LitInt,
+    Result,
+};
+
+use crate::{
+    node::{
+        globals::{GlobalVar, Globals},
+        index::Index,
+        input::NodeInput,
+        regex::{Operand, Regex},
+        rule::Rule,
+        token::TokenLit,
+        variables::VariableMap,
+        variant::NodeVariant,
+    },
+    utils::{error, expect_some, Facade},
+};
+
+pub(super) struct Binary {
+    pub(super) span: Span,
+    pub(super) op: BTreeSet<TokenLit>,
+    pub(super) operand: Ident,
+    pub(super) precedence: u8,
+    pub(super) assoc: Assoc,
+    pub(super) variables: VariableMap,
+}
+
+impl TryFrom<Attribute> for Binary {
+    type Error = Error;
+
+    fn try_from(attr: Attribute) -> Result<Self> {
+        let span = attr.span();
+
+        attr.parse_args_with(|input: ParseStream| {
+            let entries = Punctuated::<SpecEntry, Token![,]>::parse_terminated(input)?;
+
+            let mut op = None;
+            let mut left = None;
+            let mut right = None;
+            let mut precedence = None;
+            let mut assoc = None;
+
+            for entry in entries {
+                match entry {
+                    SpecEntry::Op(key, lits) => {
+                        if op.is_some() {
+                            return Err(error!(key.span(), "Duplicate Op entry.",));
+                        }
+
+                        op = Some((key, lits));
+                    }
+
+                    SpecEntry::Left(key, ident) => {
+                        if left.is_some() {
+                            return Err(error!(key.span(), "Duplicate Left entry.",));
+                        }
+
+                        left = Some((key, ident));
+                    }
+
+                    SpecEntry::Right(key, ident) => {
+                        if right.is_some() {
+                            return Err(error!(key.span(), "Duplicate Right entry.",));
+                        }
+
+                        right = Some((key, ident));
+                    }
+
+                    SpecEntry::Precedence(span, value) => {
+                        if precedence.is_some() {
+                            return Err(error!(span, "Duplicate Precedence entry.",));
+                        }
+
+                        precedence = Some(value);
+                    }
+
+                    SpecEntry::Assoc(span, value) => {
+                        if assoc.is_some() {
+                            return Err(error!(span, "Duplicate Assoc entry.",));
+                        }
+
+                        assoc = Some(value);
+                    }
+                }
+            }
+
+            let Some((op_key, op)) = op else {
+                return Err(error!(
+                    span,
+                    "Missing operator tokens.\nSpecify the operator tokens \
+                    using the \"op: $A | $B | ...\" entry.",
+                ));
+            };
+
+            let Some((left_key, left)) = left else {
+                return Err(error!(
+                    span,
+                    "Missing left operand.\nSpecify the operand rule using \
+                    the \"left: <variant>\" entry.",
+                ));
+            };
+
+            let Some((right_key, right)) = right else {
+                return Err(error!(
+                    span,
+                    "Missing right operand.\nSpecify the operand rule using \
+                    the \"right: <variant>\" entry.",
+                ));
+            };
+
+            if left != right {
+                return Err(error!(
+                    right.span(),
+                    "The right operand must refer to the same variant as the \
+                    left operand \"{left}\".\nAll operators of the expression \
+                    share the same operand rule.",
+                ));
+            }
+
+            let Some(precedence) = precedence else {
+                return Err(error!(
+                    span,
+                    "Missing operator precedence.\nSpecify the precedence \
+                    using the \"precedence = <number>\" entry.",
+                ));
+            };
+
+            let mut variables = VariableMap::default();
+
+            variables.insert_node(left_key);
+            variables.insert_token(op_key);
+            variables.insert_node(right_key);
+
+            Ok(Self {
+                span,
+                op,
+                operand: left,
+                precedence,
+                assoc: assoc.unwrap_or(Assoc::Left),
+                variables,
+            })
+        })
+    }
+}
+
+impl Binary {
+    const PRECEDENCE_LIMIT: u8 = 100;
+
+    #[inline(always)]
+    pub(super) fn rule(&self) -> Rule {
+        Rule {
+            span: self.span,
+            regex: Regex::Operand(Operand::Rule(None, self.operand.clone())),
+            leftmost: None,
+            automata: None,
+            variables: None,
+        }
+    }
+
+    // Returns the left and the right binding powers of the operator.
+    #[inline(always)]
+    pub(super) fn powers(&self) -> (u8, u8) {
+        let power = self.precedence * 2;
+
+        match self.assoc {
+            Assoc::Left => (power, power + 1),
+            Assoc::Right => (power + 1, power),
+        }
+    }
+
+    pub(super) fn climb_fn_ident(&self) -> Ident {
+        let operand = &self.operand;
+
+        format_ident!("climb_{operand}", span = operand.span())
+    }
+
+    pub(super) fn compile_call(
+        &self,
+        span: Span,
+        context: &Index,
+        recovery_var: &GlobalVar,
+    ) -> TokenStream {
+        let ident = self.climb_fn_ident();
+        let recovery = recovery_var.compile(span);
+        let (left_power, _) = self.powers();
+
+        quote_spanned!(span=> #ident(session, #context, &#recovery, #left_power))
+    }
+}
+
+impl NodeInput {
+    pub(super) fn compile_climb_fns(
+        &self,
+        globals: &mut Globals,
+        allow_warnings: bool,
+    ) -> Vec<TokenStream> {
+        let mut groups = BTreeMap::<String, Vec<&NodeVariant>>::new();
+
+        for variant in self.variants.values() {
+            let Some(binary) = &variant.binary else {
+                continue;
+            };
+
+            groups
+                .entry(binary.operand.to_string())
+                .or_default()
+                .push(variant);
+        }
+
+        groups
+            .into_values()
+            .map(|mut operators| {
+                operators.sort_by(|a, b| a.ident.cmp(&b.ident));
+
+                self.compile_climb_fn(globals, operators, allow_warnings)
+            })
+            .collect()
+    }
+
+    fn compile_climb_fn(
+        &self,
+        globals: &mut Globals,
+        operators: Vec<&NodeVariant>,
+        allow_warnings: bool,
+    ) -> TokenStream {
+        let first = expect_some!(operators.first(), "Empty operators group.",);
+        let binary = expect_some!(first.binary.as_ref(), "Missing binary operator.",);
+
+        let span = binary.operand.span();
+        let core = span.face_core();
+
+        let operand = expect_some!(
+            self.variants.get(&binary.operand),
+            "Unresolved operand reference.",
+        );
+        let operand_index = expect_some!(operand.index.as_ref(), "Missing operand index.",);
+        let operand_rule = expect_some!(operand.rule.as_ref(), "Unparseable operand.",);
+        let leftmost = expect_some!(operand_rule.leftmost.as_ref(), "Missing leftmost.",);
+        let matches = expect_some!(leftmost.matches(), "Unresolved leftmost matches.",);
+
+        let operand_tokens = Rule::make_pattern(self, globals, matches.clone()).compile(span);
+        let operand_rules = globals
+            .rules([operand_index.clone()].into_iter())
+            .compile(span);
+
+        let descend = match operand.secondary.is_some() {
+            false => quote_spanned!(span=>
+                #core::syntax::SyntaxSession::descend(session, #operand_index)),
+
+            true => {
+                let ident = operand.parser_fn_ident();
+
+                quote_spanned!(span=> {
+                    #core::syntax::SyntaxSession::enter(session, #operand_index);
+                    let node = #ident(session);
+                    #core::syntax::SyntaxSession::leave(session, node)
+                })
+            }
+        };
+
+        let skip_trivia = match &self.trivia {
+            Some(_) => Some(quote_spanned!(span=> skip_trivia(session);)),
+            None => None,
+        };
+
+        let this = self.this();
+        let climb_ident = binary.climb_fn_ident();
+        let operands = format_ident!("operands", span = span);
+
+        let arms = operators.iter().map(|variant| {
+            let binary = expect_some!(variant.binary.as_ref(), "Missing binary operator.",);
+            let index = expect_some!(variant.index.as_ref(), "Missing operator index.",);
+            let constructor = expect_some!(
+                variant.constructor.as_ref(),
+                "Binary operator without constructor.",
+            );
+
+            let span = binary.span;
+            let core = span.face_core();
+            let (left_power, right_power) = binary.powers();
+
+            let recovery_var = match (&variant.recovery, &self.recovery) {
+                (Some(recovery), _) => globals.recovery(recovery.clone()),
+                (None, Some(recovery)) => globals.recovery(recovery.clone()),
+                _ => GlobalVar::UnlimitedRecovery,
+            };
+
+            let recovery = recovery_var.compile(span);
+
+            let condition = match binary.op.len() {
+                1 => {
+                    let lit = expect_some!(binary.op.iter().next(), "Empty operator tokens.",);
+                    let enum_variant =
+                        expect_some!(lit.as_enum_variant(&self.token), "Missing enum variant.",);
+
+                    quote_spanned!(span=> token == #enum_variant)
+                }
+
+                _ => {
+                    let set = globals
+                        .inclusive_tokens(binary.op.iter().cloned())
+                        .compile(span);
+
+                    quote_spanned!(span=> #core::lexis::TokenSet::contains(&#set, token as u8))
+                }
+            };
+
+            let variables = &binary.variables;
+
+            let capture_left = variables.get(&format_ident!("left"));
+            let capture_op = variables.get(&format_ident!("op"));
+            let capture_right = variables.get(&format_ident!("right"));
+
+            let constructor = constructor.compile(self, variables, allow_warnings);
+
+            quote_spanned!(span=>
+                if #condition {
+                    if #left_power < power {
+                        return accumulator;
+                    }
+
+                    accumulator = #core::syntax::PrattDriver::<#this>::operator(
+                        session,
+                        #index,
+                        accumulator,
+                        |session| {
+                            #skip_trivia
+
+                            #climb_ident(session, #index, &#recovery, #right_power)
+                        },
+                        |session, #operands| {
+                            let #capture_left = #operands.left;
+                            let #capture_op = #operands.operator;
+                            let #capture_right = #operands.right;
+
+                            #constructor
+                        },
+                    );
+
+                    continue;
+                }
+            )
+        });
+
+        let arms = arms.collect::<Vec<_>>();
+
+        // The operator position is not recovered: the expression ends on
+        // the first token that does not continue it, and the enclosing rule
+        // handles this token.
+        let body = quote_spanned!(span=>
+            let mut accumulator = loop {
+                let token = #core::lexis::TokenCursor::token(session, 0);
+
+                if #core::lexis::TokenSet::contains(&#operand_tokens, token as u8) {
+                    break #descend;
+                }
+
+                if !#core::syntax::PrattDriver::<#this>::recover(
+                    session,
+                    context,
+                    recovery,
+                    &#operand_tokens,
+                    &#core::lexis::EMPTY_TOKEN_SET,
+                    &#operand_rules,
+                ) {
+                    return #core::syntax::NodeRef::nil();
+                }
+            };
+
+            loop {
+                #skip_trivia
+
+                let token = #core::lexis::TokenCursor::token(session, 0);
+
+                #( #arms )*
+
+                return accumulator;
+            }
+        );
+
+        self.make_fn(
+            climb_ident,
+            false,
+            vec![
+                quote_spanned!(span=> context: #core::syntax::NodeRule),
+                quote_spanned!(span=> recovery: &'static #core::syntax::Recovery),
+                quote_spanned!(span=> power: u8),
+            ],
+            Some(quote_spanned!(span=> #core::syntax::NodeRef)),
+            body,
+            allow_warnings,
+        )
+        .1
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(super) enum Assoc {
+    Left,
+    Right,
+}
+
+impl Assoc {
+    #[inline(always)]
+    pub(super) fn as_str(&self) -> &'static str {
+        match self {
+            Self::Left => "left",
+            Self::Right => "right",
+        }
+    }
+}
+
+enum SpecEntry {
+    Op(Ident, BTreeSet<TokenLit>),
+    Left(Ident, Ident),
+    Right(Ident, Ident),
+    Precedence(Span, u8),
+    Assoc(Span, Assoc),
+}
+
+impl Parse for SpecEntry {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let lookahead = input.lookahead1();
+
+        if lookahead.peek(binary_kw::op) {
+            let key = input.parse::<binary_kw::op>()?;
+            let _ = input.parse::<Token![:]>()?;
+
+            let sequence = Punctuated::<TokenLit, Token![|]>::parse_separated_nonempty(input)?;
+
+            let mut lits = BTreeSet::new();
+
+            for token_lit in sequence {
+                if !matches!(&token_lit, TokenLit::Ident(..)) {
+                    return Err(error!(
+                        token_lit.span(),
+                        "Operator token must refer to a particular token variant.",
+                    ));
+                }
+
+                if lits.contains(&token_lit) {
+                    return Err(error!(token_lit.span(), "Duplicate token.",));
+                }
+
+                let _ = lits.insert(token_lit);
+            }
+
+            return Ok(Self::Op(Ident::new("op", key.span), lits));
+        }
+
+        if lookahead.peek(binary_kw::left) {
+            let key = input.parse::<binary_kw::left>()?;
+            let _ = input.parse::<Token![:]>()?;
+
+            return Ok(Self::Left(Ident::new("left", key.span), input.parse()?));
+        }
+
+        if lookahead.peek(binary_kw::right) {
+            let key = input.parse::<binary_kw::right>()?;
+            let _ = input.parse::<Token![:]>()?;
+
+            return Ok(Self::Right(Ident::new("right", key.span), input.parse()?));
+        }
+
+        if lookahead.peek(binary_kw::precedence) {
+            let key = input.parse::<binary_kw::precedence>()?;
+            let _ = input.parse::<Token![=]>()?;
+
+            let literal = input.parse::<LitInt>()?;
+            let value = literal.base10_parse::<u8>()?;
+
+            if value == 0 || value > Binary::PRECEDENCE_LIMIT {
+                let limit = Binary::PRECEDENCE_LIMIT;
+
+                return Err(error!(
+                    literal.span(),
+                    "Operator precedence must be in range 1..={limit}.",
+                ));
+            }
+
+            return Ok(Self::Precedence(key.span, value));
+        }
+
+        if lookahead.peek(binary_kw::assoc) {
+            let key = input.parse::<binary_kw::assoc>()?;
+            let _ = input.parse::<Token![=]>()?;
+
+            let lookahead = input.lookahead1();
+
+            if lookahead.peek(binary_kw::left) {
+                let _ = input.parse::<binary_kw::left>()?;
+                return Ok(Self::Assoc(key.span, Assoc::Left));
+            }
+
+            if lookahead.peek(binary_kw::right) {
+                let _ = input.parse::<binary_kw::right>()?;
+                return Ok(Self::Assoc(key.span, Assoc::Right));
+            }
+
+            return Err(lookahead.error());
+        }
+
+        Err(lookahead.error())
+    }
+}
+
+mod binary_kw {
+    syn::custom_keyword!(op);
+    syn::custom_keyword!(left);
+    syn::custom_keyword!(right);
+    syn::custom_keyword!(precedence);
+    syn::custom_keyword!(assoc);
+}
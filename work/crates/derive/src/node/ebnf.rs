@@ -0,0 +1,211 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use proc_macro2::{Ident, Span};
+use syn::Result;
+
+use crate::{
+    node::{
+        input::{NodeInput, VariantMap},
+        recovery::Recovery,
+        regex::{Operand, Operator, Regex},
+        rule::Rule,
+        variant::VariantTrivia,
+    },
+    utils::{expect_some, system_panic, Ebnf, EbnfExpr},
+};
+
+impl NodeInput {
+    // Writes the syntax grammar description in the `#[dump(ebnf)]` mode.
+    //
+    // The rules are described after the inline expressions expansion, but
+    // before the token exclusions expansion. The productions follow
+    // the declaration `order` of the enum variants.
+    pub(super) fn write_ebnf(
+        span: Span,
+        ident: &Ident,
+        order: &[Ident],
+        trivia: Option<&Rule>,
+        recovery: Option<&Recovery>,
+        variants: &VariantMap,
+    ) -> Result<()> {
+        let mut ebnf = Ebnf::default();
+
+        ebnf.comment(format!("Syntax grammar of the \"{ident}\" nodes."));
+        ebnf.comment("Inline expressions are expanded in place.");
+
+        if let Some(trivia) = trivia {
+            ebnf.comment(format!("Trivia: {}", trivia.regex.ebnf()));
+        }
+
+        if let Some(recovery) = recovery {
+            ebnf.comment(format!("Recovery: {recovery}"));
+        }
+
+        ebnf.blank_line();
+
+        for variant_ident in order {
+            let variant = expect_some!(variants.get(variant_ident), "Missing variant.",);
+
+            let Some(rule) = &variant.rule else {
+                continue;
+            };
+
+            if variant.root.is_some() {
+                ebnf.comment("Root rule. The $ token denotes the end of input.");
+            }
+
+            if let Some(message) = &variant.error {
+                ebnf.comment(format!("Error rule: {:?}", message.value()));
+            }
+
+            if let Some(binary) = &variant.binary {
+                ebnf.comment(format!(
+                    "Binary operator of the {} expression. Precedence: {}, \
+                    {} associativity.",
+                    binary.operand,
+                    binary.precedence,
+                    binary.assoc.as_str(),
+                ));
+            }
+
+            if variant.parser.is_some() {
+                ebnf.comment("Parsed by the overridden parser function.");
+            }
+
+            match &variant.trivia {
+                VariantTrivia::Inherited => (),
+                VariantTrivia::Empty(_) => ebnf.comment("Trivia: none"),
+                VariantTrivia::Rule(trivia) => {
+                    ebnf.comment(format!("Trivia: {}", trivia.regex.ebnf()))
+                }
+            }
+
+            if let Some(recovery) = &variant.recovery {
+                match recovery.is_empty() {
+                    true => ebnf.comment("Recovery: none"),
+                    false => ebnf.comment(format!("Recovery: {recovery}")),
+                }
+            }
+
+            let expr = match &variant.binary {
+                None => rule.regex.ebnf(),
+
+                Some(binary) => EbnfExpr::concat([
+                    EbnfExpr::primary(binary.operand.to_string()),
+                    EbnfExpr::union(
+                        binary
+                            .op
+                            .iter()
+                            .map(|lit| EbnfExpr::primary(lit.to_string())),
+                    ),
+                    EbnfExpr::primary(binary.operand.to_string()),
+                ]),
+            };
+
+            ebnf.production(variant_ident, expr);
+        }
+
+        ebnf.write(span, ident)
+    }
+}
+
+trait RegexEbnf {
+    fn ebnf(&self) -> EbnfExpr;
+}
+
+impl RegexEbnf for Regex {
+    fn ebnf(&self) -> EbnfExpr {
+        match self {
+            Self::Operand(Operand::Unresolved(..)) => system_panic!("Unresolved operand."),
+
+            Self::Operand(Operand::Dump(_, inner)) => inner.ebnf(),
+
+            Self::Operand(Operand::Token(_, lit)) => EbnfExpr::primary(lit.to_string()),
+
+            Self::Operand(Operand::Rule(_, name)) => EbnfExpr::primary(name.to_string()),
+
+            Self::Operand(Operand::Exclusion(_, _, lits)) => {
+                if lits.is_empty() {
+                    return EbnfExpr::primary(".");
+                }
+
+                let mut lits = lits.iter().collect::<Vec<_>>();
+
+                lits.sort();
+
+                let lits = lits
+                    .into_iter()
+                    .map(|lit| lit.to_string())
+                    .collect::<Vec<_>>()
+                    .join(" | ");
+
+                EbnfExpr::primary(format!("^[{lits}]"))
+            }
+
+            Self::Binary(left, Operator::Union, right) => {
+                EbnfExpr::union([left.ebnf(), right.ebnf()])
+            }
+
+            Self::Binary(left, Operator::Concat, right) => {
+                EbnfExpr::concat([left.ebnf(), right.ebnf()])
+            }
+
+            Self::Binary(..) => system_panic!("Unsupported Binary operator."),
+
+            Self::Unary(Operator::Optional, inner) => inner.ebnf().optional(),
+
+            Self::Unary(Operator::OneOrMore(None), inner) => inner.ebnf().one_or_more(),
+
+            Self::Unary(Operator::ZeroOrMore(None), inner) => inner.ebnf().zero_or_more(),
+
+            // The W3C notation does not have separated repetitions.
+            Self::Unary(Operator::OneOrMore(Some(separator)), inner) => separated(inner, separator),
+
+            Self::Unary(Operator::ZeroOrMore(Some(separator)), inner) => {
+                separated(inner, separator).optional()
+            }
+
+            Self::Unary(..) => system_panic!("Unsupported Unary operator."),
+        }
+    }
+}
+
+#[inline(always)]
+fn separated(inner: &Regex, separator: &Regex) -> EbnfExpr {
+    EbnfExpr::concat([
+        inner.ebnf(),
+        EbnfExpr::concat([separator.ebnf(), inner.ebnf()]).zero_or_more(),
+    ])
+}
@@ -32,8 +32,9 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
+use convert_case::{Case, Casing};
 use proc_macro2::{Ident, TokenStream};
-use syn::{spanned::Spanned, AttrStyle, Error, LitStr, Result, Type, Variant};
+use syn::{spanned::Spanned, AttrStyle, Error, LitStr, Result, Type, Variant, Visibility};
 
 use crate::utils::{error, expect_some, Facade};
 
@@ -41,7 +42,7 @@ pub(super) struct Inheritance {
     ident: Ident,
     node: Option<Ident>,
     parent: Option<Ident>,
-    children: Vec<Ident>,
+    children: Vec<(Ident, Type)>,
     semantics: Option<(Ident, Type)>,
 }
 
@@ -166,7 +167,7 @@ impl<'a> TryFrom<&'a Variant> for Inheritance {
 
                         is_child = true;
 
-                        children.push(ident.clone());
+                        children.push((ident.clone(), field.ty.clone()));
                     }
 
                     "semantics" => {
@@ -273,7 +274,7 @@ impl Inheritance {
         let mut pattern = Vec::with_capacity(children.len());
         let mut body = Vec::with_capacity(children.len());
 
-        for (index, child) in children.iter().enumerate() {
+        for (index, (child, _)) in children.iter().enumerate() {
             let span = child.span();
             let core = span.face_core();
             let option = span.face_option();
@@ -314,7 +315,7 @@ impl Inheritance {
 
         let mut keys = Vec::with_capacity(children.len());
 
-        for child in children.iter() {
+        for (child, _) in children.iter() {
             let span = child.span();
             let core = span.face_core();
 
@@ -329,6 +330,58 @@ impl Inheritance {
         Some(quote_spanned!(span=> Self::#ident { .. } => &[#(#keys),*],))
     }
 
+    // Returns the captures of the variant paired with the names of their
+    // typed key constants.
+    pub(super) fn typed_key_names(&self) -> Vec<(&Ident, Ident)> {
+        self.children
+            .iter()
+            .map(|(child, _)| (child, self.typed_key_name(child)))
+            .collect()
+    }
+
+    pub(super) fn compile_typed_keys(&self, vis: &Visibility) -> Vec<TokenStream> {
+        let ident = &self.ident;
+
+        self.children
+            .iter()
+            .map(|(child, ty)| {
+                let span = child.span();
+                let core = span.face_core();
+                let option = span.face_option();
+
+                let key = LitStr::new(child.to_string().as_str(), span);
+
+                let name = self.typed_key_name(child);
+
+                let doc = LitStr::new(
+                    &format!(
+                        "A typed key of the [{ident}](Self::{ident}) variant's `{child}` capture."
+                    ),
+                    span,
+                );
+
+                quote_spanned!(span=>
+                    #[doc = #doc]
+                    #vis const #name: #core::syntax::TypedKey<Self, #ty> =
+                        #core::syntax::TypedKey::new(#key, |node: &Self| match node {
+                            Self::#ident { #child, .. } => #option::Some(#child),
+                            #[allow(unreachable_patterns)]
+                            _ => #option::None,
+                        });
+                )
+            })
+            .collect()
+    }
+
+    fn typed_key_name(&self, child: &Ident) -> Ident {
+        format_ident!(
+            "{}_{}",
+            self.ident.to_string().to_case(Case::UpperSnake),
+            child.to_string().to_case(Case::UpperSnake),
+            span = child.span(),
+        )
+    }
+
     pub(super) fn compile_initializer(&self) -> Option<TokenStream> {
         let (field_ident, field_ty) = self.semantics.as_ref()?;
 
@@ -73,6 +73,7 @@ pub struct NodeInput {
     pub(super) generics: ParserGenerics,
     pub(super) token: Type,
     pub(super) classifier: Option<Type>,
+    pub(super) cross_indexer: Option<Type>,
     pub(super) common: Option<Type>,
     pub(super) trivia: Option<Rule>,
     pub(super) recovery: Option<Recovery>,
@@ -118,6 +119,12 @@ impl TryFrom<DeriveInput> for NodeInput {
             }
         };
 
+        let order = data
+            .variants
+            .iter()
+            .map(|variant| variant.ident.clone())
+            .collect::<Vec<_>>();
+
         let mut variants = data
             .variants
             .into_iter()
@@ -132,6 +139,7 @@ impl TryFrom<DeriveInput> for NodeInput {
 
         let mut token = None;
         let mut classifier = None;
+        let mut cross_indexer = None;
         let mut common = None;
         let mut trivia = None;
         let mut recovery = None;
@@ -167,6 +175,14 @@ impl TryFrom<DeriveInput> for NodeInput {
                     classifier = Some(attr.parse_args::<Type>()?);
                 }
 
+                "cross_indexer" => {
+                    if cross_indexer.is_some() {
+                        return Err(error!(span, "Duplicate CrossIndexer attribute.",));
+                    }
+
+                    cross_indexer = Some(attr.parse_args::<Type>()?);
+                }
+
                 "semantics" => {
                     if common.is_some() {
                         return Err(error!(span, "Duplicate Semantics attribute.",));
@@ -277,6 +293,14 @@ impl TryFrom<DeriveInput> for NodeInput {
                 alphabet = alphabet.merge(rule.regex.alphabet());
             }
 
+            if let Some(binary) = &variant.binary {
+                alphabet = alphabet.merge(binary.op.iter().cloned().collect());
+            }
+
+            if let Some(remap) = &variant.remap {
+                alphabet = alphabet.merge(remap.alphabet());
+            }
+
             if let Some(trivia) = variant.trivia.rule_mut() {
                 trivia.regex.inline(&inlines)?;
                 alphabet = alphabet.merge(trivia.regex.alphabet());
@@ -291,11 +315,25 @@ impl TryFrom<DeriveInput> for NodeInput {
             ));
         };
 
-        let mut scope = Scope::default();
-
         if let Some(trivia) = &mut trivia {
             trivia.regex.inline(&inlines)?;
             alphabet = alphabet.merge(trivia.regex.alphabet());
+        }
+
+        if let Dump::Ebnf(span) = dump {
+            Self::write_ebnf(
+                span,
+                &ident,
+                &order,
+                trivia.as_ref(),
+                recovery.as_ref(),
+                &variants,
+            )?;
+        }
+
+        let mut scope = Scope::default();
+
+        if let Some(trivia) = &mut trivia {
             trivia.regex.expand(&alphabet);
             trivia.encode(&mut scope)?;
         }
@@ -327,15 +365,29 @@ impl TryFrom<DeriveInput> for NodeInput {
                 let _ = pending.push(variant.ident.clone());
             }
 
+            // Error rules are not referred from the other rules, but the parser
+            // descends into them on syntax errors.
+            if variant.error.is_some() {
+                let _ = pending.push(variant.ident.clone());
+            }
+
             match &mut variant.rule {
                 None => continue,
                 Some(rule) => {
                     rule.regex.expand(&alphabet);
                     rule.encode(&mut scope)?;
 
+                    if let Some(remap) = &variant.remap {
+                        let leftmost = expect_some!(rule.leftmost.as_mut(), "Missing leftmost.",);
+
+                        leftmost.set_remap(remap.entries.clone());
+                    }
+
                     if let Some(constructor) = &variant.constructor {
-                        let variables =
-                            expect_some!(rule.variables.as_ref(), "Missing variable map.",);
+                        let variables = match &variant.binary {
+                            Some(binary) => &binary.variables,
+                            None => expect_some!(rule.variables.as_ref(), "Missing variable map.",),
+                        };
 
                         constructor.fits(variables)?;
                     }
@@ -354,6 +406,57 @@ impl TryFrom<DeriveInput> for NodeInput {
             }
         }
 
+        for (_, variant) in &variants {
+            let Some(binary) = &variant.binary else {
+                continue;
+            };
+
+            if let Some(operand) = variants.get(&binary.operand) {
+                if operand.binary.is_some() {
+                    return Err(error!(
+                        binary.operand.span(),
+                        "Binary operator's operand cannot refer to another \
+                        binary operator.\nThe operand must be a primary \
+                        expression rule.",
+                    ));
+                }
+            }
+
+            for (_, peer) in &variants {
+                let Some(peer_binary) = &peer.binary else {
+                    continue;
+                };
+
+                if peer_binary.operand != binary.operand || peer.ident >= variant.ident {
+                    continue;
+                }
+
+                if let Some(lit) = binary.op.intersection(&peer_binary.op).next() {
+                    return Err(error!(
+                        lit.span(),
+                        "Operator token {lit} is already used by the \"{}\" \
+                        operator.\nOperators of the same operand must have \
+                        distinct tokens.",
+                        peer.ident,
+                    ));
+                }
+
+                if peer_binary.precedence == binary.precedence && peer_binary.assoc != binary.assoc
+                {
+                    return Err(error!(
+                        binary.span,
+                        "This operator has the same precedence as the \"{}\" \
+                        operator,\nbut a different associativity: \"{}\" \
+                        and \"{}\" respectively.\nOperators of the same \
+                        precedence level must have the same associativity.",
+                        peer.ident,
+                        binary.assoc.as_str(),
+                        peer_binary.assoc.as_str(),
+                    ));
+                }
+            }
+        }
+
         let mut visited = Set::empty();
         let mut next_index = 1;
 
@@ -369,6 +472,19 @@ impl TryFrom<DeriveInput> for NodeInput {
                     pending.push(reference);
                 }
 
+                // Operators of the same operand are parsed by the same
+                // precedence climbing function.
+                if let Some(binary) = &variant.binary {
+                    for (peer, variant) in &variants {
+                        match &variant.binary {
+                            Some(peer_binary) if peer_binary.operand == binary.operand => {
+                                pending.push(peer.clone())
+                            }
+                            _ => (),
+                        }
+                    }
+                }
+
                 if let Some(trivia) = variant.trivia.rule() {
                     for reference in trivia.regex.refs(true, &variants)? {
                         pending.push(reference);
@@ -418,6 +534,46 @@ impl TryFrom<DeriveInput> for NodeInput {
             let _ = visited.insert(ident);
         }
 
+        let mut consts = Map::empty();
+
+        for ident in &order {
+            let variant = expect_some!(variants.get(ident), "Missing variant.",);
+
+            if let Some(Index::Named(name, _)) = &variant.index {
+                let _ = consts.insert(
+                    name.to_string(),
+                    format!("the denotation name of the \"{ident}\" variant"),
+                );
+            }
+        }
+
+        for ident in &order {
+            let variant = expect_some!(variants.get(ident), "Missing variant.",);
+
+            if variant.index.is_none() {
+                continue;
+            }
+
+            for (child, name) in variant.inheritance.typed_key_names() {
+                let name = name.to_string();
+
+                if let Some(previous) = consts.get(&name) {
+                    return Err(error!(
+                        child.span(),
+                        "The typed key constant \"{name}\" of this capture \
+                        clashes with {previous}.\nTyped key constant names \
+                        must be unique in the type's associated items.\n\n\
+                        Consider renaming this field.",
+                    ));
+                }
+
+                let _ = consts.insert(
+                    name,
+                    format!("the typed key of the \"{ident}\" variant's \"{child}\" capture"),
+                );
+            }
+        }
+
         for (_, variant) in &variants {
             if variant.root.is_some() {
                 continue;
@@ -508,6 +664,44 @@ impl TryFrom<DeriveInput> for NodeInput {
             rule.leftmost = Some(leftmost);
         }
 
+        for (_, variant) in &variants {
+            let Some(message) = &variant.error else {
+                continue;
+            };
+
+            let rule = expect_some!(variant.rule.as_ref(), "Missing error rule.",);
+            let leftmost = expect_some!(rule.leftmost.as_ref(), "Missing leftmost.",);
+            let matches = expect_some!(leftmost.matches(), "Unresolved leftmost matches.",);
+
+            if message.value().is_empty() {
+                return Err(error!(
+                    message.span(),
+                    "Error rule message cannot be empty.",
+                ));
+            }
+
+            for (_, peer) in &variants {
+                if peer.error.is_none() || peer.ident >= variant.ident {
+                    continue;
+                }
+
+                let peer_rule = expect_some!(peer.rule.as_ref(), "Missing error rule.",);
+                let peer_leftmost = expect_some!(peer_rule.leftmost.as_ref(), "Missing leftmost.",);
+                let peer_matches =
+                    expect_some!(peer_leftmost.matches(), "Unresolved leftmost matches.",);
+
+                if let Some(lit) = matches.intersection(peer_matches).next() {
+                    return Err(error!(
+                        rule.span,
+                        "Token {lit} is already used in the leftmost position \
+                        of the \"{}\" error rule.\nError rules must have \
+                        distinct leftmost tokens.",
+                        peer.ident,
+                    ));
+                }
+            }
+        }
+
         for (_, variant) in &variants {
             let rule = match &variant.rule {
                 None => continue,
@@ -572,6 +766,7 @@ impl TryFrom<DeriveInput> for NodeInput {
             generics,
             token,
             classifier,
+            cross_indexer,
             common,
             trivia,
             recovery,
@@ -713,3 +908,108 @@ impl TryFrom<DeriveInput> for NodeInput {
         Ok(result)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+    use syn::parse2;
+
+    use crate::node::NodeInput;
+
+    #[test]
+    fn test_typed_key_clash() {
+        let result = parse2::<NodeInput>(quote! {
+            #[token(MyToken)]
+            enum MyNode {
+                #[root]
+                #[rule(foo_bar: FooBar)]
+                Root {
+                    #[child]
+                    foo_bar: NodeRef,
+                },
+
+                #[rule(value: $Value)]
+                #[denote(ROOT_FOO_BAR)]
+                FooBar {
+                    #[child]
+                    value: TokenRef,
+                },
+            }
+        });
+
+        let Err(error) = result else {
+            panic!("Typed key clash is not detected.");
+        };
+
+        assert!(error.to_string().contains(
+            "The typed key constant \"ROOT_FOO_BAR\" of this capture clashes \
+            with the denotation name of the \"FooBar\" variant."
+        ));
+
+        let result = parse2::<NodeInput>(quote! {
+            #[token(MyToken)]
+            enum MyNode {
+                #[root]
+                #[rule(bar_baz: FooBar)]
+                Foo {
+                    #[child]
+                    bar_baz: NodeRef,
+                },
+
+                #[rule(baz: $Value)]
+                FooBar {
+                    #[child]
+                    baz: TokenRef,
+                },
+            }
+        });
+
+        let Err(error) = result else {
+            panic!("Typed key clash is not detected.");
+        };
+
+        assert!(error.to_string().contains(
+            "The typed key constant \"FOO_BAR_BAZ\" of this capture clashes \
+            with the typed key of the \"Foo\" variant's \"bar_baz\" capture."
+        ));
+    }
+
+    #[test]
+    fn test_binary_generics() {
+        let result = parse2::<NodeInput>(quote! {
+            #[token(MyToken)]
+            enum MyNode<T> {
+                #[root]
+                #[rule(expr: Sum)]
+                Root {
+                    #[child]
+                    expr: NodeRef,
+                    #[default]
+                    marker: PhantomData<T>,
+                },
+
+                #[rule(value: $Value)]
+                Operand {
+                    #[child]
+                    value: TokenRef,
+                },
+
+                #[binary(op: $Plus, left: Operand, right: Operand, precedence = 1)]
+                Sum {
+                    #[child]
+                    left: NodeRef,
+                    #[child]
+                    op: TokenRef,
+                    #[child]
+                    right: NodeRef,
+                    #[default(MyToken::Plus)]
+                    next: MyToken,
+                },
+            }
+        });
+
+        if let Err(error) = result {
+            panic!("Generic binary operator is rejected: {error}");
+        }
+    }
+}
@@ -43,6 +43,7 @@ use crate::{
     node::{
         input::VariantMap,
         regex::{Operand, Operator, Regex},
+        remap::RemapEntry,
         token::TokenLit,
     },
     utils::{expect_some, system_panic, PredictableCollection, Set, SetImpl},
@@ -54,6 +55,7 @@ pub(super) struct Leftmost {
     matches: Option<Set<TokenLit>>,
     tokens: Set<TokenLit>,
     nodes: Set<Ident>,
+    remap: Vec<RemapEntry>,
 }
 
 impl Display for Leftmost {
@@ -87,6 +89,7 @@ impl From<TokenLit> for Leftmost {
             matches: None,
             tokens: Set::new([lit]),
             nodes: Set::empty(),
+            remap: Vec::new(),
         }
     }
 }
@@ -99,6 +102,7 @@ impl From<Ident> for Leftmost {
             matches: None,
             tokens: Set::empty(),
             nodes: Set::new([rule]),
+            remap: Vec::new(),
         }
     }
 }
@@ -198,6 +202,23 @@ impl Leftmost {
         self.optional
     }
 
+    // The token remapping entries that the parent rules should apply
+    // before descending into this rule.
+    //
+    // Before the matches resolution, these are the entries of the rule's own
+    // #[remap(...)] attribute. The resolution inherits the entries of
+    // the rules in the leftmost position, and retains only the entries
+    // that remap to the leftmost tokens.
+    #[inline(always)]
+    pub(super) fn remap(&self) -> &[RemapEntry] {
+        &self.remap
+    }
+
+    #[inline(always)]
+    pub(super) fn set_remap(&mut self, remap: Vec<RemapEntry>) {
+        self.remap = remap;
+    }
+
     pub(super) fn is_self_recursive<'a>(
         &'a self,
         map: &'a VariantMap,
@@ -243,6 +264,7 @@ impl Leftmost {
 
             if let Some(node_matches) = &leftmost.matches {
                 matches.append(node_matches.clone());
+                self.remap.extend(leftmost.remap.iter().cloned());
                 continue;
             }
 
@@ -252,12 +274,15 @@ impl Leftmost {
             let node_matches =
                 expect_some!(leftmost.matches.as_ref(), "Missing leftmost matches.",);
             matches.append(node_matches.clone());
+            self.remap.extend(leftmost.remap.iter().cloned());
 
             let variant = expect_some!(map.get_mut(ident), "Unresolved reference.",);
             let rule = expect_some!(variant.rule.as_mut(), "Missing rule.",);
             rule.leftmost = Some(leftmost);
         }
 
+        self.remap.retain(|entry| matches.contains(&entry.to));
+
         self.matches = Some(matches);
     }
 
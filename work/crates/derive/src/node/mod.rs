@@ -33,7 +33,9 @@
 ////////////////////////////////////////////////////////////////////////////////
 
 mod automata;
+mod binary;
 mod constructor;
+mod ebnf;
 mod generics;
 mod globals;
 mod index;
@@ -43,6 +45,7 @@ mod leftmost;
 mod output;
 mod recovery;
 mod regex;
+mod remap;
 mod rule;
 mod token;
 mod variables;
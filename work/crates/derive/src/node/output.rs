@@ -44,7 +44,7 @@ use crate::{
         token::TokenLit,
         NodeInput,
     },
-    utils::{expect_some, Description, Dump, Facade},
+    utils::{compile_hash_impl, expect_some, stable_hash, Description, Dump, Facade},
 };
 
 impl NodeInput {
@@ -133,9 +133,13 @@ impl NodeInput {
             self,
             globals,
             context,
+            None,
+            None,
             &GlobalVar::UnlimitedRecovery,
             false,
             false,
+            false,
+            None,
             output_comments,
         );
 
@@ -216,7 +220,7 @@ impl NodeInput {
                 }
 
                 #[allow(unused_variables)]
-                fn feature_keys(&self) -> &'static [&'static #core::syntax::Key] {
+                fn feature_keys(&self) -> &'static [&'static #core::syntax::Key<'static>] {
                     match self {
                         #( #feature_keys )*
 
@@ -239,6 +243,11 @@ impl NodeInput {
             None => quote_spanned!(span=> #core::analysis::VoidClassifier::<Self>),
         };
 
+        let cross_indexer = match &self.cross_indexer {
+            Some(ty) => ty.to_token_stream(),
+            None => quote_spanned!(span=> #core::analysis::VoidCrossIndexer::<Self>),
+        };
+
         let common = match &self.common {
             Some(ty) => ty.to_token_stream(),
             None => quote_spanned!(span=> #core::analysis::VoidFeature::<Self>),
@@ -280,6 +289,8 @@ impl NodeInput {
             {
                 type Classifier = #classifier;
 
+                type CrossIndexer = #cross_indexer;
+
                 type CommonSemantics = #common;
 
                 #[allow(unused_variables)]
@@ -531,6 +542,11 @@ impl NodeInput {
                 continue;
             }
 
+            // Binary operators are parsed by the climbing functions only.
+            if variant.binary.is_some() {
+                continue;
+            }
+
             let index = expect_some!(variant.index.as_ref(), "Parsable rule without index.",);
 
             let function = expect_some!(
@@ -545,6 +561,8 @@ impl NodeInput {
             cases.push(quote_spanned!(span=> #index => #ident(session),));
         }
 
+        functions.append(&mut self.compile_climb_fns(&mut globals, true));
+
         let trivia = match &self.trivia {
             None => None,
             Some(trivia) => Some(self.compile_skip_fn(
@@ -631,7 +649,7 @@ impl NodeInput {
         let vis = &self.vis;
         let span = ident.span();
 
-        let (_, type_generics, where_clause) = self.generics.ty.split_for_impl();
+        let (impl_generics, type_generics, where_clause) = self.generics.ty.split_for_impl();
 
         let indices = self
             .variants
@@ -650,16 +668,26 @@ impl NodeInput {
             })
             .collect::<Vec<_>>();
 
-        if indices.is_empty() {
+        let typed_keys = self
+            .variants
+            .values()
+            .filter(|variant| variant.index.is_some())
+            .flat_map(|variant| variant.inheritance.compile_typed_keys(vis))
+            .collect::<Vec<_>>();
+
+        if indices.is_empty() && typed_keys.is_empty() {
             return None;
         }
 
         Some(quote_spanned!(span=>
-            impl #ident #type_generics #where_clause
+            impl #impl_generics #ident #type_generics #where_clause
             {
             #(
                 #indices
             )*
+            #(
+                #typed_keys
+            )*
             }
         ))
     }
@@ -672,15 +700,27 @@ impl ToTokens for NodeInput {
         }
 
         let output_comments = match self.dump {
-            Dump::None | Dump::Decl(..) => false,
+            Dump::None | Dump::Decl(..) | Dump::Hash(..) | Dump::Ebnf(..) => false,
             Dump::Dry(..) => return,
             _ => true,
         };
 
-        self.compile_abstract_feature_impl().to_tokens(tokens);
-        self.compile_grammar_impl().to_tokens(tokens);
-        self.compile_abstract_node_impl().to_tokens(tokens);
-        self.compile_node_impl(output_comments).to_tokens(tokens);
-        self.compile_consts_impl().to_tokens(tokens);
+        let mut output = TokenStream::new();
+
+        self.compile_abstract_feature_impl().to_tokens(&mut output);
+        self.compile_grammar_impl().to_tokens(&mut output);
+        self.compile_abstract_node_impl().to_tokens(&mut output);
+        self.compile_node_impl(output_comments)
+            .to_tokens(&mut output);
+        self.compile_consts_impl().to_tokens(&mut output);
+
+        if let Dump::Hash(span) = self.dump {
+            let hash = stable_hash(&output);
+
+            compile_hash_impl(span, &self.ident, &self.generics.ty, &self.vis, hash)
+                .to_tokens(&mut output);
+        }
+
+        output.to_tokens(tokens);
     }
 }
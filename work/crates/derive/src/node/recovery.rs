@@ -32,13 +32,18 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::{cmp::Ordering, collections::BTreeSet};
+use std::{
+    cmp::Ordering,
+    collections::BTreeSet,
+    fmt::{Display, Formatter},
+};
 
 use proc_macro2::{Span, TokenStream};
 use syn::{
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
     token::Bracket,
+    LitInt,
     Result,
     Type,
 };
@@ -53,6 +58,8 @@ pub(super) struct Recovery {
     span: Span,
     groups: BTreeSet<(TokenLit, TokenLit)>,
     unexpected: BTreeSet<TokenLit>,
+    insert_limit: u8,
+    delete_limit: u8,
 }
 
 impl PartialEq for Recovery {
@@ -65,6 +72,14 @@ impl PartialEq for Recovery {
             return false;
         }
 
+        if self.insert_limit != other.insert_limit {
+            return false;
+        }
+
+        if self.delete_limit != other.delete_limit {
+            return false;
+        }
+
         true
     }
 }
@@ -79,7 +94,13 @@ impl Ord for Recovery {
             return ordering;
         }
 
-        self.unexpected.cmp(&other.unexpected)
+        let ordering = self.unexpected.cmp(&other.unexpected);
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+
+        (self.insert_limit, self.delete_limit).cmp(&(other.insert_limit, other.delete_limit))
     }
 }
 
@@ -90,6 +111,48 @@ impl PartialOrd for Recovery {
     }
 }
 
+impl Display for Recovery {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut first = true;
+
+        for token in &self.unexpected {
+            match first {
+                true => first = false,
+                false => formatter.write_str(", ")?,
+            }
+
+            Display::fmt(token, formatter)?;
+        }
+
+        for (open, close) in &self.groups {
+            match first {
+                true => first = false,
+                false => formatter.write_str(", ")?,
+            }
+
+            formatter.write_fmt(format_args!("[{open}..{close}]"))?;
+        }
+
+        for (key, limit) in [
+            ("insert_limit", self.insert_limit),
+            ("delete_limit", self.delete_limit),
+        ] {
+            if limit == 0 {
+                continue;
+            }
+
+            match first {
+                true => first = false,
+                false => formatter.write_str(", ")?,
+            }
+
+            formatter.write_fmt(format_args!("{key} = {limit}"))?;
+        }
+
+        Ok(())
+    }
+}
+
 impl Parse for Recovery {
     fn parse(input: ParseStream) -> Result<Self> {
         let span = input.span();
@@ -97,6 +160,8 @@ impl Parse for Recovery {
 
         let mut groups = BTreeSet::new();
         let mut unexpected = BTreeSet::new();
+        let mut insert_limit = None;
+        let mut delete_limit = None;
 
         for entry in entries {
             match entry {
@@ -140,6 +205,22 @@ impl Parse for Recovery {
 
                     unexpected.insert(token);
                 }
+
+                SpecEntry::InsertLimit(span, limit) => {
+                    if insert_limit.is_some() {
+                        return Err(error!(span, "Duplicate Insert Limit.",));
+                    }
+
+                    insert_limit = Some(limit);
+                }
+
+                SpecEntry::DeleteLimit(span, limit) => {
+                    if delete_limit.is_some() {
+                        return Err(error!(span, "Duplicate Delete Limit.",));
+                    }
+
+                    delete_limit = Some(limit);
+                }
             }
         }
 
@@ -147,6 +228,8 @@ impl Parse for Recovery {
             span,
             groups,
             unexpected,
+            insert_limit: insert_limit.unwrap_or(0),
+            delete_limit: delete_limit.unwrap_or(0),
         })
     }
 }
@@ -160,6 +243,8 @@ impl Recovery {
             span,
             groups: Default::default(),
             unexpected: Default::default(),
+            insert_limit: 0,
+            delete_limit: 0,
         }
     }
 
@@ -168,8 +253,21 @@ impl Recovery {
         self.span
     }
 
+    #[inline(always)]
+    pub(super) fn insert_limit(&self) -> u8 {
+        self.insert_limit
+    }
+
+    #[inline(always)]
+    pub(super) fn delete_limit(&self) -> u8 {
+        self.delete_limit
+    }
+
     pub(super) fn is_empty(&self) -> bool {
-        self.groups.is_empty() && self.unexpected.is_empty()
+        self.groups.is_empty()
+            && self.unexpected.is_empty()
+            && self.insert_limit == 0
+            && self.delete_limit == 0
     }
 
     pub(super) fn compile(&self, token_type: &Type) -> TokenStream {
@@ -214,16 +312,30 @@ impl Recovery {
             quote_spanned!(span=> #open, #close)
         });
 
+        let insert_limit = match self.insert_limit {
+            0 => None,
+            limit => Some(quote_spanned!(span=> .insert_limit(#limit))),
+        };
+
+        let delete_limit = match self.delete_limit {
+            0 => None,
+            limit => Some(quote_spanned!(span=> .delete_limit(#limit))),
+        };
+
         quote_spanned!(span=>
             #core::syntax::Recovery::unlimited()
             #unexpected
-            #(.group(#groups))*)
+            #(.group(#groups))*
+            #insert_limit
+            #delete_limit)
     }
 }
 
 enum SpecEntry {
     Group(Span, TokenLit, TokenLit),
     Unexpected(TokenLit),
+    InsertLimit(Span, u8),
+    DeleteLimit(Span, u8),
 }
 
 impl Parse for SpecEntry {
@@ -261,6 +373,31 @@ impl Parse for SpecEntry {
             return Ok(SpecEntry::Group(span, open, close));
         }
 
+        if lookahead.peek(recovery_kw::insert_limit) {
+            let key = input.parse::<recovery_kw::insert_limit>()?;
+            let _ = input.parse::<Token![=]>()?;
+
+            return Ok(SpecEntry::InsertLimit(
+                key.span,
+                input.parse::<LitInt>()?.base10_parse()?,
+            ));
+        }
+
+        if lookahead.peek(recovery_kw::delete_limit) {
+            let key = input.parse::<recovery_kw::delete_limit>()?;
+            let _ = input.parse::<Token![=]>()?;
+
+            return Ok(SpecEntry::DeleteLimit(
+                key.span,
+                input.parse::<LitInt>()?.base10_parse()?,
+            ));
+        }
+
         Err(lookahead.error())
     }
 }
+
+mod recovery_kw {
+    syn::custom_keyword!(insert_limit);
+    syn::custom_keyword!(delete_limit);
+}
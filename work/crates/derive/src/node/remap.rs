@@ -0,0 +1,183 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::collections::BTreeMap;
+
+use proc_macro2::{Span, TokenStream};
+use quote::ToTokens;
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Attribute,
+    Error,
+    LitStr,
+    Result,
+};
+
+use crate::{
+    node::{input::NodeInput, token::TokenLit},
+    utils::{error, expect_some, Facade, PredictableCollection, Set},
+};
+
+pub(super) struct Remap {
+    pub(super) span: Span,
+    pub(super) entries: Vec<RemapEntry>,
+}
+
+impl TryFrom<Attribute> for Remap {
+    type Error = Error;
+
+    fn try_from(attr: Attribute) -> Result<Self> {
+        let span = attr.span();
+
+        attr.parse_args_with(|input: ParseStream| {
+            let entries = Punctuated::<RemapEntry, Token![,]>::parse_terminated(input)?;
+
+            if entries.is_empty() {
+                return Err(error!(
+                    span,
+                    "Missing remapping entries.\nSpecify the entries using \
+                    the \"$From => $To if \\\"string\\\"\" syntax.",
+                ));
+            }
+
+            let mut keys = Set::with_capacity(entries.len());
+
+            for entry in &entries {
+                if entry.from == entry.to {
+                    return Err(error!(entry.to.span(), "The token is remapped to itself.",));
+                }
+
+                if !keys.insert((entry.from.clone(), entry.string.value())) {
+                    return Err(error!(
+                        entry.string.span(),
+                        "Duplicate remapping of the {} token with the same \
+                        string.",
+                        entry.from,
+                    ));
+                }
+            }
+
+            Ok(Self {
+                span,
+                entries: entries.into_iter().collect(),
+            })
+        })
+    }
+}
+
+impl Remap {
+    pub(super) fn alphabet(&self) -> Set<TokenLit> {
+        let mut alphabet = Set::with_capacity(self.entries.len() * 2);
+
+        for entry in &self.entries {
+            let _ = alphabet.insert(entry.from.clone());
+            let _ = alphabet.insert(entry.to.clone());
+        }
+
+        alphabet
+    }
+}
+
+#[derive(Clone)]
+pub(super) struct RemapEntry {
+    pub(super) from: TokenLit,
+    pub(super) to: TokenLit,
+    pub(super) string: LitStr,
+}
+
+impl Parse for RemapEntry {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let from = input.parse::<TokenLit>()?;
+        let _ = input.parse::<Token![=>]>()?;
+        let to = input.parse::<TokenLit>()?;
+        let _ = input.parse::<Token![if]>()?;
+        let string = input.parse::<LitStr>()?;
+
+        Ok(Self { from, to, string })
+    }
+}
+
+impl RemapEntry {
+    // Generates a statement that reinterprets the "token" variable of
+    // the parser's step according to the specified entries.
+    //
+    // The first entry wins if several entries remap the same token with
+    // the same string.
+    pub(super) fn compile<'a>(
+        input: &NodeInput,
+        span: Span,
+        entries: impl IntoIterator<Item = &'a RemapEntry>,
+    ) -> TokenStream {
+        let core = span.face_core();
+        let option = span.face_option();
+
+        let mut keys = Set::empty();
+        let mut by_token = BTreeMap::<TokenLit, Vec<&'a RemapEntry>>::new();
+
+        for entry in entries {
+            if !keys.insert((entry.from.clone(), entry.string.value())) {
+                continue;
+            }
+
+            by_token.entry(entry.from.clone()).or_default().push(entry);
+        }
+
+        let mut stream = TokenStream::new();
+
+        for (from, entries) in by_token {
+            let from = expect_some!(from.as_enum_variant(&input.token), "Non-ident token.",);
+
+            let arms = entries.into_iter().map(|entry| {
+                let string = &entry.string;
+                let to = expect_some!(entry.to.as_enum_variant(&input.token), "Non-ident token.",);
+
+                quote_spanned!(span=> #option::Some(#string) => token = #to,)
+            });
+
+            quote_spanned!(span=>
+                if token == #from {
+                    match #core::lexis::TokenCursor::string(session, 0) {
+                        #( #arms )*
+                        _ => (),
+                    }
+                }
+            )
+            .to_tokens(&mut stream);
+        }
+
+        stream
+    }
+}
@@ -45,9 +45,12 @@ use crate::{
         index::Index,
         input::NodeInput,
         leftmost::Leftmost,
+        recovery::Recovery,
         regex::{Operand, Operator, Regex, RegexImpl},
+        remap::{Remap, RemapEntry},
         token::TokenLit,
         variables::VariableMap,
+        variant::NodeVariant,
     },
     utils::{
         expect_some,
@@ -93,6 +96,32 @@ impl TryFrom<Attribute> for Rule {
 }
 
 impl Rule {
+    pub(super) fn try_from_error_rule(attr: Attribute) -> Result<(Self, LitStr)> {
+        let span = attr.span();
+
+        attr.parse_args_with(|input: ParseStream| {
+            let regex = input.parse::<Regex>()?;
+
+            let _ = input.parse::<Token![,]>()?;
+
+            let message = input.parse::<LitStr>()?;
+
+            if input.peek(Token![,]) {
+                let _ = input.parse::<Token![,]>()?;
+            }
+
+            let rule = Self {
+                span,
+                regex,
+                leftmost: None,
+                automata: None,
+                variables: None,
+            };
+
+            Ok((rule, message))
+        })
+    }
+
     #[inline]
     pub(super) fn zero_or_more(mut self) -> Self {
         self.regex = Regex::Unary(Operator::ZeroOrMore(None), Box::new(self.regex));
@@ -134,9 +163,13 @@ impl Rule {
         input: &NodeInput,
         globals: &mut Globals,
         context: &Index,
+        remap: Option<&Remap>,
+        recovery: Option<&Recovery>,
         recovery_var: &GlobalVar,
         with_trivia: bool,
         surround_trivia: bool,
+        error_rules: bool,
+        trivia: Option<&Rule>,
         output_comments: bool,
     ) -> TokenStream {
         let automata = expect_some!(self.automata.as_ref(), "Missing automata.",);
@@ -156,6 +189,14 @@ impl Rule {
         let start = automata.start();
 
         let init_vars = variables.init();
+
+        let mut token_recovery = TokenRecovery {
+            insert_limit: recovery.map(Recovery::insert_limit).unwrap_or(0),
+            delete_limit: recovery.map(Recovery::delete_limit).unwrap_or(0),
+            inserts: false,
+            deletes: false,
+        };
+
         let init_first;
         let init_step;
 
@@ -202,7 +243,11 @@ impl Rule {
                     &automata,
                     &variables,
                     delimiter,
+                    remap,
+                    &mut token_recovery,
                     recovery_var,
+                    error_rules,
+                    trivia,
                     output_comments,
                     *from,
                 );
@@ -220,10 +265,22 @@ impl Rule {
 
         let transitions = transitions.into_iter().map(|(_, stream)| stream);
 
+        let init_insertions = match token_recovery.inserts {
+            false => None,
+            true => Some(quote_spanned!(span=> let mut insertions = 0u8;)),
+        };
+
+        let init_deletions = match token_recovery.deletes {
+            false => None,
+            true => Some(quote_spanned!(span=> let mut deletions = 0u8;)),
+        };
+
         quote_spanned!(span=>
             let mut state = #start;
             #init_first
             #init_vars
+            #init_insertions
+            #init_deletions
 
             loop {
                 #init_step
@@ -246,7 +303,11 @@ impl Rule {
         automata: &NodeAutomata,
         variables: &VariableMap,
         delimiter: Option<&TokenLit>,
+        remap: Option<&Remap>,
+        token_recovery: &mut TokenRecovery,
         recovery_var: &GlobalVar,
+        error_rules: bool,
+        trivia: Option<&Rule>,
         output_comments: bool,
         from: State,
     ) -> TokenStream {
@@ -261,6 +322,7 @@ impl Rule {
 
         let span = self.span;
         let core = span.face_core();
+        let option = span.face_option();
 
         let total_alphabet_len = input.alphabet.len() + 2;
         let mut covered = Set::with_capacity(total_alphabet_len);
@@ -268,6 +330,7 @@ impl Rule {
         let mut expected_tokens = Set::with_capacity(input.alphabet.len());
         let mut expected_nodes = Set::with_capacity(input.variants.len());
         let mut by_token = Map::with_capacity(input.alphabet.len());
+        let mut descend_remap = Vec::new();
 
         for (through, to) in outgoing {
             match through {
@@ -310,6 +373,8 @@ impl Rule {
                         expect_some!(rule.leftmost.as_ref(), "Missing leftmost of rule.",);
                     let matches = expect_some!(leftmost.matches(), "Unresolved leftmost matches.",);
 
+                    descend_remap.extend(leftmost.remap());
+
                     for lit in matches {
                         if !covered.insert(lit.clone()) {
                             system_panic!("Duplicate covered token.");
@@ -381,6 +446,25 @@ impl Rule {
             }
         };
 
+        let error_rules = match error_rules {
+            false => Vec::new(),
+            true => Self::error_rules(input),
+        };
+
+        // The tokens of the error rules that the parser tries when this state
+        // does not expect the token at all.
+        let mut error_lits = Set::empty();
+
+        if !halts && !covered.iter().any(|lit| matches!(lit, TokenLit::Other(..))) {
+            for (_, lits) in &error_rules {
+                for lit in lits {
+                    if !covered.contains(lit) {
+                        let _ = error_lits.insert(lit.clone());
+                    }
+                }
+            }
+        }
+
         let mut insert_map = Map::with_capacity(total_alphabet_len - covered.len());
 
         'outer: for (insert, to) in outgoing {
@@ -465,6 +549,9 @@ impl Rule {
             }
         }
 
+        // The error rules take priority over the insertion recovery.
+        insert_map.retain(|lit, _| !error_lits.contains(lit));
+
         for (lit, action) in insert_map {
             let previous = by_token.insert(lit, action);
 
@@ -473,6 +560,76 @@ impl Rule {
             }
         }
 
+        // The tokens of the error rules that the parser tries when this state
+        // expects the token, but the state the token leads to does not expect
+        // the next significant token.
+        let mut lookahead_errors = Vec::new();
+
+        for (lit, action) in &by_token {
+            let TokenLit::Ident(..) = lit else {
+                continue;
+            };
+
+            if action.insert.is_some() || action.descend.is_some() {
+                continue;
+            }
+
+            let Some(to) = action.transition else {
+                continue;
+            };
+
+            if automata.finish().contains(&to) {
+                continue;
+            }
+
+            let Some((variant, _)) = error_rules.iter().find(|(_, lits)| lits.contains(lit)) else {
+                continue;
+            };
+
+            let Some(first) = Self::first_tokens(input, automata, remap, to) else {
+                continue;
+            };
+
+            lookahead_errors.push((lit.clone(), first, *variant));
+        }
+
+        lookahead_errors.sort_by(|(a, _, _), (b, _, _)| a.cmp(b));
+
+        // The tokens that the parser may assume missing when this state does
+        // not expect the next token, and the tokens that the states following
+        // the missing tokens expect.
+        let mut insertions = Vec::new();
+
+        if token_recovery.insert_limit > 0 && !halts {
+            for (through, to) in outgoing {
+                let Terminal::Token(capture, lit @ TokenLit::Ident(..)) = through else {
+                    continue;
+                };
+
+                let mut first = match automata.transitions().outgoing(to).is_some() {
+                    false => Set::empty(),
+                    true => match Self::first_tokens(input, automata, remap, *to) {
+                        Some(first) => first,
+                        None => continue,
+                    },
+                };
+
+                if automata.finish().contains(to) {
+                    let _ = first.insert(TokenLit::EOI(span));
+                }
+
+                first.retain(|lit| !by_token.contains_key(lit) && !error_lits.contains(lit));
+
+                if first.is_empty() {
+                    continue;
+                }
+
+                insertions.push((lit.clone(), capture.clone(), *to, first));
+            }
+
+            insertions.sort_by(|(a, _, _, _), (b, _, _, _)| a.cmp(b));
+        }
+
         let mut by_action = BTreeMap::<Action, Set<TokenLit>>::new();
 
         for (lit, action) in by_token {
@@ -484,10 +641,72 @@ impl Rule {
                 .or_insert_with(|| Set::new([lit]));
         }
 
-        quote_spanned!(span=>
-            let token = #core::lexis::TokenCursor::token(session, 0);
-        )
-        .to_tokens(&mut stream);
+        // The rule's own remapping takes priority over the remapping
+        // inherited from the rules this state descends into.
+        let remap = remap
+            .into_iter()
+            .flat_map(|remap| remap.entries.iter())
+            .chain(descend_remap)
+            .filter(|entry| covered.contains(&entry.to))
+            .collect::<Vec<_>>();
+
+        // The tokens that the parser may encounter right after the skipped
+        // unexpected token.
+        let deletion_lits = match token_recovery.delete_limit > 0 && !halts {
+            false => None,
+            true => Some(
+                covered
+                    .iter()
+                    .cloned()
+                    .chain(remap.iter().map(|entry| entry.from.clone()))
+                    .collect::<Set<_>>(),
+            ),
+        };
+
+        match remap.is_empty() {
+            true => quote_spanned!(span=>
+                let token = #core::lexis::TokenCursor::token(session, 0);
+            )
+            .to_tokens(&mut stream),
+
+            false => {
+                let remap = RemapEntry::compile(input, span, remap);
+
+                quote_spanned!(span=>
+                    let mut token = #core::lexis::TokenCursor::token(session, 0);
+                    #remap
+                )
+                .to_tokens(&mut stream)
+            }
+        }
+
+        if !lookahead_errors.is_empty() {
+            let next = Self::compile_next(span, globals, trivia);
+
+            for (lit, first, variant) in lookahead_errors {
+                let enum_variant =
+                    expect_some!(lit.as_enum_variant(&input.token), "Missing enum variant.",);
+
+                let first = globals.inclusive_tokens(first.into_iter()).compile(span);
+
+                let production = Self::compile_error_production(
+                    span,
+                    context,
+                    variant,
+                    &expected_tokens_var,
+                    &expected_nodes_var,
+                );
+
+                quote_spanned!(span=> if token == #enum_variant {
+                    #next
+
+                    if !#core::lexis::TokenSet::contains(&#first, next as u8) {
+                        #production
+                    }
+                })
+                .to_tokens(&mut stream);
+            }
+        }
 
         for (action, set) in by_action {
             let mut body = TokenStream::new();
@@ -501,6 +720,9 @@ impl Rule {
                             variables.get(variable).write_nil().to_tokens(&mut body);
                         }
 
+                        let rule =
+                            expect_some!(lit.as_token_index(&input.token), "Missing token index.",);
+
                         let var = globals
                             .inclusive_tokens([lit.clone()].into_iter())
                             .compile(span);
@@ -508,16 +730,17 @@ impl Rule {
                         quote_spanned!(span=>
                             let step_end_ref = #core::lexis::TokenCursor::site_ref(session, 0);
 
-                            #core::syntax::SyntaxSession::failure(
-                                session,
-                                #core::syntax::SyntaxError {
-                                    span: step_start_ref..step_end_ref,
-                                    context: #context,
-                                    recovery: #core::syntax::RecoveryResult::InsertRecover,
-                                    expected_tokens: &#var,
-                                    expected_nodes: &#core::syntax::EMPTY_NODE_SET,
-                                },
+                            let mut error = #core::syntax::SyntaxError::new(
+                                step_start_ref..step_end_ref,
+                                #context,
+                                #core::syntax::RecoveryResult::InsertRecover,
+                                &#var,
+                                &#core::syntax::EMPTY_NODE_SET,
                             );
+
+                            error.recovery_token = #option::Some(#rule);
+
+                            #core::syntax::SyntaxSession::failure(session, error);
                         )
                         .to_tokens(&mut body);
                     }
@@ -540,16 +763,15 @@ impl Rule {
                         quote_spanned!(span=>
                             let step_end_ref = #core::lexis::TokenCursor::site_ref(session, 0);
 
-                            #core::syntax::SyntaxSession::failure(
-                                session,
-                                #core::syntax::SyntaxError {
-                                    span: step_start_ref..step_end_ref,
-                                    context: #context,
-                                    recovery: #core::syntax::RecoveryResult::InsertRecover,
-                                    expected_tokens: &#core::lexis::EMPTY_TOKEN_SET,
-                                    expected_nodes: &#var,
-                                },
+                            let error = #core::syntax::SyntaxError::new(
+                                step_start_ref..step_end_ref,
+                                #context,
+                                #core::syntax::RecoveryResult::InsertRecover,
+                                &#core::lexis::EMPTY_TOKEN_SET,
+                                &#var,
                             );
+
+                            #core::syntax::SyntaxSession::failure(session, error);
                         )
                         .to_tokens(&mut body);
                     }
@@ -592,13 +814,17 @@ impl Rule {
                         }
                     };
 
-                    let descend = match variant.secondary.is_some() {
-                        false => quote_spanned!(span=> #core::syntax::SyntaxSession::descend(
-                            session,
-                            #index,
-                        )),
+                    let descend = match (&variant.binary, variant.secondary.is_some()) {
+                        (Some(binary), _) => binary.compile_call(span, context, recovery_var),
 
-                        true => quote_spanned!(span=> {
+                        (None, false) => {
+                            quote_spanned!(span=> #core::syntax::SyntaxSession::descend(
+                                session,
+                                #index,
+                            ))
+                        }
+
+                        (None, true) => quote_spanned!(span=> {
                             #core::syntax::SyntaxSession::enter(session, #index);
                             let node = #ident(session);
                             #core::syntax::SyntaxSession::leave(session, node)
@@ -674,31 +900,155 @@ impl Rule {
                     _ => None,
                 };
 
+                for (variant, lits) in &error_rules {
+                    let lits = lits
+                        .iter()
+                        .filter(|lit| error_lits.contains(lit))
+                        .cloned()
+                        .collect::<Set<_>>();
+
+                    if lits.is_empty() {
+                        continue;
+                    }
+
+                    let pattern = globals.inclusive_tokens(lits.into_iter()).compile(span);
+
+                    let production = Self::compile_error_production(
+                        span,
+                        context,
+                        variant,
+                        &expected_tokens_var,
+                        &expected_nodes_var,
+                    );
+
+                    quote_spanned!(span=>
+                        if #core::lexis::TokenSet::contains(&#pattern, token as u8) {
+                            #production
+                        }
+                    )
+                    .to_tokens(&mut stream);
+                }
+
+                for (lit, capture, to, first) in insertions {
+                    token_recovery.inserts = true;
+
+                    let rule =
+                        expect_some!(lit.as_token_index(&input.token), "Missing token index.",);
+
+                    let var = globals.inclusive_tokens([lit].into_iter()).compile(span);
+                    let first = Self::make_pattern(input, globals, first).compile(span);
+
+                    let nil = capture.map(|variable| variables.get(&variable).write_nil());
+
+                    let transition = match automata.transitions().outgoing(&to).is_some() {
+                        false => quote_spanned!(span=> break;),
+                        true if from == to => quote_spanned!(span=> continue;),
+                        true => quote_spanned!(span=> state = #to; continue;),
+                    };
+
+                    quote_spanned!(span=>
+                        if insertions < #core::syntax::Recovery::get_insert_limit(&#recovery)
+                            && #core::lexis::TokenSet::contains(&#first, token as u8)
+                        {
+                            insertions += 1;
+
+                            #nil
+
+                            let step_end_ref = #core::lexis::TokenCursor::site_ref(session, 0);
+
+                            let mut error = #core::syntax::SyntaxError::new(
+                                step_start_ref..step_end_ref,
+                                #context,
+                                #core::syntax::RecoveryResult::InsertRecover,
+                                &#var,
+                                &#core::syntax::EMPTY_NODE_SET,
+                            );
+
+                            error.recovery_token = #option::Some(#rule);
+
+                            #core::syntax::SyntaxSession::failure(session, error);
+
+                            #transition
+                        }
+                    )
+                    .to_tokens(&mut stream);
+                }
+
+                if let Some(lits) = deletion_lits {
+                    token_recovery.deletes = true;
+
+                    let lits = Self::make_pattern(input, globals, lits).compile(span);
+                    let next = Self::compile_next(span, globals, trivia);
+
+                    let eoi = expect_some!(
+                        TokenLit::EOI(span).as_enum_variant(&input.token),
+                        "Missing EOI variant.",
+                    );
+
+                    quote_spanned!(span=>
+                        if deletions < #core::syntax::Recovery::get_delete_limit(&#recovery)
+                            && token != #eoi
+                        {
+                            #next
+
+                            if #core::lexis::TokenSet::contains(&#lits, next as u8) {
+                                deletions += 1;
+
+                                let deleted = #core::lexis::Token::rule(
+                                    #core::lexis::TokenCursor::token(session, 0),
+                                );
+
+                                #core::lexis::TokenCursor::advance(session);
+
+                                let step_end_ref = #core::lexis::TokenCursor::site_ref(session, 0);
+
+                                let mut error = #core::syntax::SyntaxError::new(
+                                    step_start_ref..step_end_ref,
+                                    #context,
+                                    #core::syntax::RecoveryResult::DeleteRecover,
+                                    &#expected_tokens_var,
+                                    &#expected_nodes_var,
+                                );
+
+                                error.recovery_token = #option::Some(deleted);
+
+                                #core::syntax::SyntaxSession::failure(session, error);
+
+                                continue;
+                            }
+                        }
+                    )
+                    .to_tokens(&mut stream);
+                }
+
                 let expectations = Self::make_pattern(input, globals, covered).compile(span);
 
                 quote_spanned!(span=>
-                    let recovery = #core::syntax::Recovery::recover(
+                    let recovery_details = #core::syntax::Recovery::recover_with_details(
                         &#recovery,
                         session,
                         &#expectations,
                     );
 
+                    let recovery = #core::syntax::RecoveryDetails::result(&recovery_details);
+
                     let mut recovered = #core::syntax::RecoveryResult::recovered(
                         &recovery,
                     );
 
                     let step_end_ref = #core::lexis::TokenCursor::site_ref(session, 0);
 
-                    #core::syntax::SyntaxSession::failure(
-                        session,
-                        #core::syntax::SyntaxError {
-                            span: step_start_ref..step_end_ref,
-                            context: #context,
-                            recovery,
-                            expected_tokens: &#expected_tokens_var,
-                            expected_nodes: &#expected_nodes_var,
-                        },
+                    let mut error = #core::syntax::SyntaxError::new(
+                        step_start_ref..step_end_ref,
+                        #context,
+                        recovery,
+                        &#expected_tokens_var,
+                        &#expected_nodes_var,
                     );
+
+                    error.recovery_details = #option::Some(recovery_details);
+
+                    #core::syntax::SyntaxSession::failure(session, error);
                 )
                 .to_tokens(&mut stream);
 
@@ -733,7 +1083,182 @@ impl Rule {
         stream
     }
 
-    fn make_pattern(input: &NodeInput, globals: &mut Globals, mut set: Set<TokenLit>) -> GlobalVar {
+    // Looks ahead for the next significant token after the current one.
+    fn compile_next(span: Span, globals: &mut Globals, trivia: Option<&Rule>) -> TokenStream {
+        let core = span.face_core();
+
+        let trivia = trivia
+            .and_then(|trivia| trivia.leftmost.as_ref())
+            .map(|leftmost| {
+                leftmost
+                    .tokens()
+                    .iter()
+                    .filter(|lit| matches!(lit, TokenLit::Ident(..)))
+                    .cloned()
+                    .collect::<Set<_>>()
+            })
+            .filter(|tokens| !tokens.is_empty());
+
+        match trivia {
+            None => quote_spanned!(span=>
+                let next = #core::lexis::TokenCursor::token(session, 1);
+            ),
+
+            Some(trivia) => {
+                let trivia = globals.inclusive_tokens(trivia.into_iter()).compile(span);
+
+                quote_spanned!(span=>
+                    let mut distance = 1;
+
+                    let next = loop {
+                        let next = #core::lexis::TokenCursor::token(session, distance);
+
+                        if !#core::lexis::TokenSet::contains(&#trivia, next as u8) {
+                            break next;
+                        }
+
+                        distance += 1;
+                    };
+                )
+            }
+        }
+    }
+
+    // The error rules of the grammar, and their leftmost tokens.
+    fn error_rules(input: &NodeInput) -> Vec<(&NodeVariant, Set<TokenLit>)> {
+        let mut result = input
+            .variants
+            .values()
+            .filter(|variant| variant.error.is_some())
+            .map(|variant| {
+                let rule = expect_some!(variant.rule.as_ref(), "Missing error rule.",);
+                let leftmost = expect_some!(rule.leftmost.as_ref(), "Missing leftmost of rule.",);
+                let matches = expect_some!(leftmost.matches(), "Unresolved leftmost matches.",);
+
+                let lits = matches
+                    .iter()
+                    .filter(|lit| matches!(lit, TokenLit::Ident(..)))
+                    .cloned()
+                    .collect::<Set<_>>();
+
+                (variant, lits)
+            })
+            .collect::<Vec<_>>();
+
+        result.sort_by(|(a, _), (b, _)| a.ident.cmp(&b.ident));
+
+        result
+    }
+
+    // The tokens that the parser accepts in the specified state without
+    // the error recovery, or None if the state accepts any token.
+    fn first_tokens(
+        input: &NodeInput,
+        automata: &NodeAutomata,
+        remap: Option<&Remap>,
+        state: State,
+    ) -> Option<Set<TokenLit>> {
+        let outgoing = automata.transitions().outgoing(&state)?;
+
+        let mut result = Set::empty();
+        let mut descend_remap = Vec::new();
+
+        for (through, _) in outgoing {
+            match through {
+                Terminal::Null => null!(),
+
+                Terminal::Token(_, lit) => {
+                    let _ = result.insert(lit.clone());
+                }
+
+                Terminal::Node(_, ident) => {
+                    let variant = expect_some!(input.variants.get(ident), "Unresolved reference.",);
+                    let rule =
+                        expect_some!(variant.rule.as_ref(), "Reference to unparseable variant .",);
+                    let leftmost =
+                        expect_some!(rule.leftmost.as_ref(), "Missing leftmost of rule.",);
+                    let matches = expect_some!(leftmost.matches(), "Unresolved leftmost matches.",);
+
+                    result.extend(matches.iter().cloned());
+                    descend_remap.extend(leftmost.remap());
+                }
+            }
+        }
+
+        if result.iter().any(|lit| matches!(lit, TokenLit::Other(..))) {
+            return None;
+        }
+
+        let remapped = remap
+            .into_iter()
+            .flat_map(|remap| remap.entries.iter())
+            .chain(descend_remap)
+            .filter(|entry| result.contains(&entry.to))
+            .map(|entry| entry.from.clone())
+            .collect::<Vec<_>>();
+
+        result.extend(remapped);
+
+        Some(result)
+    }
+
+    fn compile_error_production(
+        span: Span,
+        context: &Index,
+        variant: &NodeVariant,
+        expected_tokens_var: &TokenStream,
+        expected_nodes_var: &TokenStream,
+    ) -> TokenStream {
+        let core = span.face_core();
+        let option = span.face_option();
+
+        let index = expect_some!(variant.index.as_ref(), "Missing parsable variant index.",);
+        let message = expect_some!(variant.error.as_ref(), "Missing error rule message.",);
+
+        let descend = match variant.secondary.is_some() {
+            false => quote_spanned!(span=>
+                #core::syntax::SyntaxSession::descend(session, #index);
+            ),
+
+            true => {
+                let ident = variant.parser_fn_ident();
+
+                quote_spanned!(span=>
+                    #core::syntax::SyntaxSession::enter(session, #index);
+                    let node = #ident(session);
+                    #core::syntax::SyntaxSession::leave(session, node);
+                )
+            }
+        };
+
+        quote_spanned!(span=>
+            let error_start_ref = #core::lexis::TokenCursor::site_ref(session, 0);
+
+            #descend
+
+            let error_end_ref = #core::lexis::TokenCursor::site_ref(session, 0);
+
+            let mut error = #core::syntax::SyntaxError::new(
+                error_start_ref..error_end_ref,
+                #context,
+                #core::syntax::RecoveryResult::ErrorProduction,
+                &#expected_tokens_var,
+                &#expected_nodes_var,
+            );
+
+            error.custom_message = #option::Some(#message);
+
+            #core::syntax::SyntaxSession::failure(session, error);
+
+            continue;
+        )
+    }
+
+    pub(super) fn make_pattern(
+        input: &NodeInput,
+        globals: &mut Globals,
+        mut set: Set<TokenLit>,
+    ) -> GlobalVar {
         let mut exclusive = false;
 
         set.retain(|lit| match lit {
@@ -760,6 +1285,15 @@ impl Rule {
     }
 }
 
+// The single-token recovery limits of the rule, and whether the rule's
+// states make use of these recovery strategies.
+struct TokenRecovery {
+    insert_limit: u8,
+    delete_limit: u8,
+    inserts: bool,
+    deletes: bool,
+}
+
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
 struct Action {
     transition: Option<State>,
@@ -185,6 +185,16 @@ impl VariableMap {
         expect_some!(self.map.get(variable), "Missing variable \"{variable}\".",)
     }
 
+    #[inline(always)]
+    pub(super) fn insert_node(&mut self, name: Ident) {
+        self.insert(name, VariableKind::NodeRef);
+    }
+
+    #[inline(always)]
+    pub(super) fn insert_token(&mut self, name: Ident) {
+        self.insert(name, VariableKind::TokenRef);
+    }
+
     pub(super) fn init(&self) -> TokenStream {
         let mut tokens = TokenStream::new();
 
@@ -194,6 +204,18 @@ impl VariableMap {
 
         tokens
     }
+
+    #[inline(always)]
+    fn insert(&mut self, name: Ident, kind: VariableKind) {
+        let _ = self.map.insert(
+            name.clone(),
+            VariableMeta {
+                name,
+                kind,
+                repetition: VariableRepetition::Single,
+            },
+        );
+    }
 }
 
 pub(super) struct VariableMeta {
@@ -37,16 +37,18 @@ use std::mem::take;
 use convert_case::{Case, Casing};
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::ToTokens;
-use syn::{spanned::Spanned, AttrStyle, Error, Expr, Fields, Meta, Result, Variant};
+use syn::{spanned::Spanned, AttrStyle, Error, Expr, Fields, LitStr, Meta, Result, Variant};
 
 use crate::{
     node::{
+        binary::Binary,
         constructor::Constructor,
         globals::{GlobalVar, Globals},
         index::Index,
         inheritance::Inheritance,
         input::NodeInput,
         recovery::Recovery,
+        remap::Remap,
         rule::Rule,
     },
     utils::{error, expect_some, Description, Dump},
@@ -57,8 +59,11 @@ pub(super) struct NodeVariant {
     pub(super) root: Option<Span>,
     pub(super) index: Option<Index>,
     pub(super) rule: Option<Rule>,
+    pub(super) error: Option<LitStr>,
+    pub(super) binary: Option<Binary>,
     pub(super) trivia: VariantTrivia,
     pub(super) recovery: Option<Recovery>,
+    pub(super) remap: Option<Remap>,
     pub(super) inheritance: Inheritance,
     pub(super) constructor: Option<Constructor>,
     pub(super) parser: Option<Expr>,
@@ -88,8 +93,11 @@ impl TryFrom<Variant> for NodeVariant {
         let mut root = None;
         let mut index = None;
         let mut rule = None;
+        let mut error_rule = None;
+        let mut binary = None;
         let mut trivia = VariantTrivia::Inherited;
         let mut recovery = None;
+        let mut remap = None;
         let mut constructor = None;
         let mut parser = None;
         let mut secondary = None;
@@ -135,6 +143,22 @@ impl TryFrom<Variant> for NodeVariant {
                     rule = Some(Rule::try_from(attr)?);
                 }
 
+                "error_rule" => {
+                    if error_rule.is_some() {
+                        return Err(error!(span, "Duplicate Error Rule attribute.",));
+                    }
+
+                    error_rule = Some(Rule::try_from_error_rule(attr)?);
+                }
+
+                "binary" => {
+                    if binary.is_some() {
+                        return Err(error!(span, "Duplicate Binary attribute.",));
+                    }
+
+                    binary = Some(Binary::try_from(attr)?);
+                }
+
                 "trivia" => {
                     if trivia.span().is_some() {
                         return Err(error!(span, "Duplicate Trivia attribute.",));
@@ -157,6 +181,14 @@ impl TryFrom<Variant> for NodeVariant {
                     };
                 }
 
+                "remap" => {
+                    if remap.is_some() {
+                        return Err(error!(span, "Duplicate Remap attribute.",));
+                    }
+
+                    remap = Some(Remap::try_from(attr)?);
+                }
+
                 "constructor" => {
                     if constructor.is_some() {
                         return Err(error!(span, "Duplicate Constructor attribute.",));
@@ -209,6 +241,57 @@ impl TryFrom<Variant> for NodeVariant {
             }
         }
 
+        let mut error = None;
+
+        if let Some((error_rule, message)) = error_rule {
+            let conflict = [
+                (rule.is_some(), "Rule"),
+                (binary.is_some(), "Binary"),
+                (root.is_some(), "Root"),
+            ]
+            .into_iter()
+            .find_map(|(conflicts, name)| conflicts.then_some(name));
+
+            if let Some(name) = conflict {
+                return Err(error!(
+                    error_rule.span,
+                    "{name} attribute is not applicable to error rule \
+                    variants.\nThe #[error_rule(...)] attribute specifies \
+                    the parsing rule of the error production.",
+                ));
+            }
+
+            rule = Some(error_rule);
+            error = Some(message);
+        }
+
+        if let Some(binary) = &binary {
+            let conflict = [
+                (rule.is_some(), "Rule"),
+                (root.is_some(), "Root"),
+                (trivia.span().is_some(), "Trivia"),
+                (constructor.is_some(), "Constructor"),
+                (parser.is_some(), "Parser"),
+                (remap.is_some(), "Remap"),
+                (secondary.is_some(), "Secondary"),
+                (dump.span().is_some(), "Dump"),
+            ]
+            .into_iter()
+            .find_map(|(conflicts, name)| conflicts.then_some(name));
+
+            if let Some(name) = conflict {
+                return Err(error!(
+                    binary.span,
+                    "{name} attribute is not applicable to binary operator \
+                    variants.\nThe #[binary(...)] attribute fully specifies \
+                    the operator's syntax,\nand the operator nodes are always \
+                    secondary.",
+                ));
+            }
+
+            rule = Some(binary.rule());
+        }
+
         if let Some(index) = &index {
             if rule.is_none() && !description.is_set() {
                 return Err(error!(
@@ -282,6 +365,26 @@ impl TryFrom<Variant> for NodeVariant {
             }
         };
 
+        if let Some(remap) = &remap {
+            if rule.is_none() {
+                return Err(error!(
+                    remap.span,
+                    "Remap attribute is not applicable to unparseable \
+                    variants.\nTo make the variant parsable annotate this \
+                    variant with the #[rule(...)] attribute.",
+                ));
+            }
+
+            if parser.is_some() {
+                return Err(error!(
+                    remap.span,
+                    "Remap attribute is not applicable to variants with \
+                    overridden parser.\nThe overridden Parser's function \
+                    supposed to interpret the tokens explicitly.",
+                ));
+            }
+        }
+
         let inheritance = Inheritance::try_from(&variant)?;
 
         let constructor = match (rule.is_some(), parser.is_some(), constructor) {
@@ -408,6 +511,20 @@ impl TryFrom<Variant> for NodeVariant {
                 ));
             }
 
+            if let Dump::Hash(_) = &dump {
+                return Err(error!(
+                    span,
+                    "Hash dump is not applicable to individual rules.",
+                ));
+            }
+
+            if let Dump::Ebnf(_) = &dump {
+                return Err(error!(
+                    span,
+                    "EBNF dump is not applicable to individual rules.",
+                ));
+            }
+
             if let Dump::Trivia(_) = &dump {
                 match &trivia {
                     VariantTrivia::Rule(..) => (),
@@ -433,8 +550,11 @@ impl TryFrom<Variant> for NodeVariant {
             root,
             index,
             rule,
+            error,
+            binary,
             trivia,
             recovery,
+            remap,
             inheritance,
             constructor,
             parser,
@@ -483,10 +603,11 @@ impl NodeVariant {
 
         let span = rule.span;
 
-        let recovery_var = match (&self.recovery, &input.recovery) {
-            (Some(recovery), _) => globals.recovery(recovery.clone()),
-            (None, Some(recovery)) => globals.recovery(recovery.clone()),
-            _ => GlobalVar::UnlimitedRecovery,
+        let recovery = self.recovery.as_ref().or(input.recovery.as_ref());
+
+        let recovery_var = match recovery {
+            Some(recovery) => globals.recovery(recovery.clone()),
+            None => GlobalVar::UnlimitedRecovery,
         };
 
         let with_trivia = match &self.trivia {
@@ -497,13 +618,27 @@ impl NodeVariant {
 
         let surround_trivia = self.root.is_some();
 
+        let trivia = match &self.trivia {
+            VariantTrivia::Inherited => input.trivia.as_ref(),
+            VariantTrivia::Empty(..) => None,
+            VariantTrivia::Rule(rule) => Some(rule),
+        };
+
+        // Error productions are the recovery means themselves, so the error
+        // rules are not applied inside them.
+        let error_rules = self.error.is_none();
+
         let body = rule.compile(
             input,
             globals,
             context,
+            self.remap.as_ref(),
+            recovery,
             &recovery_var,
             with_trivia,
             surround_trivia,
+            error_rules,
+            trivia,
             output_comments,
         );
 
@@ -34,7 +34,7 @@
 
 use std::fmt::{Display, Formatter};
 
-use proc_macro2::Span;
+use proc_macro2::{Ident, Span};
 use syn::Result;
 
 use crate::{
@@ -101,16 +101,38 @@ impl AutomataImpl for TokenAutomata {
                     ));
                 }
 
-                if products.len() > 1 {
-                    has_changes = true;
-                }
-
                 let product = products.iter().max_by_key(|(priority, _)| *priority);
 
-                if let Some((_, (index, to))) = product {
+                let Some((priority, (index, to))) = product else {
+                    return Ok(());
+                };
+
+                let _ = transitions.insert((Terminal::Product(*index), *to));
+
+                // The end-of-line anchored product matches depending on
+                // the next character. The product of the lower priority
+                // rule without this anchor remains as a fallback.
+                let line_end = |index: &TokenRule| {
+                    expect_some!(variants.get(*index as usize), "Missing variant.",).line_end
+                };
+
+                let fallback = match line_end(index) {
+                    false => None,
+
+                    true => products
+                        .iter()
+                        .filter(|(other, (index, _))| *other < priority && !line_end(index))
+                        .max_by_key(|(priority, _)| *priority),
+                };
+
+                if let Some((_, (index, to))) = fallback {
                     let _ = transitions.insert((Terminal::Product(*index), *to));
                 }
 
+                if products.len() > 1 + fallback.is_some() as usize {
+                    has_changes = true;
+                }
+
                 Ok(())
             })?;
 
@@ -124,14 +146,24 @@ impl AutomataImpl for TokenAutomata {
         Ok(())
     }
 
-    fn filter_out(&mut self, variants: &Variants) -> Result<ProductMap> {
-        let mut products = ProductMap::with_capacity(variants.len());
-        let mut matched = Set::with_capacity(variants.len());
-
+    fn filter_out(
+        &mut self,
+        variants: &Variants,
+        products: &mut ProductMap,
+        anchored: &mut ProductMap,
+        matched: &mut Set<usize>,
+    ) {
         self.retain(|from, through, _| match through {
             Terminal::Product(index) => {
                 let index = *index as usize;
 
+                let variant = expect_some!(variants.get(index), "Missing variant.",);
+
+                let products = match variant.line_end {
+                    false => &mut *products,
+                    true => &mut *anchored,
+                };
+
                 if products.insert(*from, index).is_some() {
                     system_panic!("Unresolved ambiguity.",);
                 }
@@ -143,28 +175,6 @@ impl AutomataImpl for TokenAutomata {
 
             _ => true,
         });
-
-        for (index, variant) in variants.iter().enumerate() {
-            if variant.rule.is_none() {
-                continue;
-            }
-
-            if !matched.contains(&index) {
-                let ident = &variant.ident;
-
-                return Err(error!(
-                    ident.span(),
-                    "Parsable rule {ident} is overlapping by other \
-                    parsable rules due to a low priority. This rule never \
-                    matches.\nTry to increase rule's priority using \
-                    #[priority(<number>)] attribute.\nDefault priority is \
-                    0. Rules with higher priority value have precedence \
-                    over the rules with lower priority value.",
-                ));
-            }
-        }
-
-        Ok(products)
     }
 
     fn check_property_conflicts(&self, span: Span) -> Result<()> {
@@ -235,7 +245,13 @@ impl AutomataImpl for TokenAutomata {
 pub(super) trait AutomataImpl {
     fn merge(&mut self, scope: &mut Scope, variants: &Variants) -> Result<()>;
 
-    fn filter_out(&mut self, variants: &Variants) -> Result<ProductMap>;
+    fn filter_out(
+        &mut self,
+        variants: &Variants,
+        products: &mut ProductMap,
+        anchored: &mut ProductMap,
+        matched: &mut Set<usize>,
+    );
 
     fn check_property_conflicts(&self, span: Span) -> Result<()>;
 }
@@ -243,6 +259,7 @@ pub(super) trait AutomataImpl {
 pub(super) struct Scope {
     state: State,
     strategy: Strategy,
+    tags: Vec<Ident>,
 }
 
 impl AutomataContext for Scope {
@@ -269,6 +286,7 @@ impl Scope {
         Self {
             state: 1,
             strategy: Strategy::CANONICALIZE,
+            tags: Vec::new(),
         }
     }
 
@@ -281,13 +299,33 @@ impl Scope {
     pub(super) fn set_strategy(&mut self, strategy: Strategy) {
         self.strategy = strategy;
     }
+
+    // Enables encoding of the `keys` captures boundaries as the Open and
+    // Close terminals. The captures not listed in `keys` are encoded as
+    // their inner expressions.
+    #[inline(always)]
+    pub(super) fn set_tags(&mut self, keys: Vec<Ident>) {
+        self.tags = keys;
+    }
+
+    #[inline(always)]
+    pub(super) fn tag(&self, key: &Ident) -> Option<Tag> {
+        self.tags
+            .iter()
+            .position(|tag| tag == key)
+            .map(|index| index as Tag)
+    }
 }
 
+pub(super) type Tag = u8;
+
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub(super) enum Terminal {
     Null,
     Class(Class),
     Product(TokenRule),
+    Open(Tag),
+    Close(Tag),
 }
 
 impl AutomataTerminal for Terminal {
@@ -313,6 +351,10 @@ impl Display for Terminal {
             Self::Class(class) => Display::fmt(class, formatter),
 
             Self::Product(ident) => formatter.write_fmt(format_args!("Token({ident})")),
+
+            Self::Open(tag) => formatter.write_fmt(format_args!("Open({tag})")),
+
+            Self::Close(tag) => formatter.write_fmt(format_args!("Close({tag})")),
         }
     }
 }
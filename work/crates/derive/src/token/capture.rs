@@ -0,0 +1,340 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use proc_macro2::{Ident, Span, TokenStream};
+
+use crate::{
+    token::{
+        automata::{Terminal, TokenAutomata},
+        chars::Class,
+        output::Output,
+        TokenInput,
+    },
+    utils::{expect_some, null, system_panic, Facade, Map, PredictableCollection, Set},
+};
+
+// A deterministic automata of the variant's rule with the capture boundaries
+// encoded as the Open and Close tag terminals.
+//
+// The tag index is the index of the capture key in the `keys` vector.
+pub(super) struct TokenCaptures {
+    pub(super) keys: Vec<Ident>,
+    pub(super) automata: TokenAutomata,
+}
+
+impl TokenCaptures {
+    // Generates the "__capture" function that receives the matched fragment
+    // and returns an array of the captured substrings in the order of `keys`.
+    //
+    // The function walks the tagged automata over the fragment's characters
+    // searching for a path that reaches the finish state at the end of
+    // the fragment. At each step it prefers the Open tags over the character
+    // transitions, and the character transitions over the Close tags, such
+    // that the captures start as early and end as late as possible.
+    // The configurations (state and site pairs) that have been visited
+    // before are never revisited, which bounds the search by the number of
+    // states times the fragment length.
+    //
+    // Each capture spans from the beginning of its first match to the end
+    // of its last match along the found path. If the capture has never been
+    // matched, the function returns an empty string for this capture.
+    pub(super) fn compile(&self, input: &TokenInput, span: Span) -> TokenStream {
+        let option = span.face_option();
+        let vec = span.face_vec();
+        let panic = span.face_panic();
+
+        let mut states = BTreeSet::new();
+
+        let _ = states.insert(self.automata.start());
+
+        for (from, outgoing) in self.automata.transitions().view() {
+            let _ = states.insert(*from);
+
+            for (_, to) in outgoing {
+                let _ = states.insert(*to);
+            }
+        }
+
+        let indices = states
+            .iter()
+            .enumerate()
+            .map(|(index, state)| (*state, index))
+            .collect::<Map<_, _>>();
+
+        let index_of = |state: &usize| *expect_some!(indices.get(state), "Unknown state.",);
+
+        let mut steps = Vec::new();
+        let mut tags = Vec::new();
+
+        for state in &states {
+            let Some(outgoing) = self.automata.transitions().outgoing(state) else {
+                continue;
+            };
+
+            let from = index_of(state);
+
+            let mut chars = BTreeMap::<usize, Set<char>>::new();
+            let mut props = Vec::new();
+            let mut other = None;
+            let mut open = Vec::new();
+            let mut close = Vec::new();
+
+            for (through, to) in outgoing {
+                let to = index_of(to);
+
+                match through {
+                    Terminal::Null => null!(),
+
+                    Terminal::Product(index) => system_panic!("Unexpected product {index}.",),
+
+                    Terminal::Class(Class::Char(ch)) => {
+                        let _ = chars.entry(to).or_insert_with(Set::empty).insert(*ch);
+                    }
+
+                    Terminal::Class(Class::Props(properties)) => props.push((*properties, to)),
+
+                    Terminal::Class(Class::Other) => other = Some(to),
+
+                    Terminal::Open(tag) => open.push((*tag as usize, to)),
+
+                    Terminal::Close(tag) => close.push((*tag as usize, to)),
+                }
+            }
+
+            if !open.is_empty() || !close.is_empty() {
+                open.sort();
+                close.sort();
+
+                let open = open
+                    .into_iter()
+                    .map(|(tag, to)| quote_spanned!(span=> (#tag, #to)));
+                let close = close
+                    .into_iter()
+                    .map(|(tag, to)| quote_spanned!(span=> (#tag, #to)));
+
+                tags.push(quote_spanned!(span=>
+                    #from => (&[#( #open ),*], &[#( #close ),*]),
+                ));
+            }
+
+            if chars.is_empty() && props.is_empty() && other.is_none() {
+                continue;
+            }
+
+            let excluded = match props.is_empty() && other.is_none() {
+                true => Set::empty(),
+
+                false => input
+                    .alphabet
+                    .iter()
+                    .filter(|ch| !chars.values().any(|set| set.contains(ch)))
+                    .copied()
+                    .collect::<Set<char>>(),
+            };
+
+            let mut cases = chars
+                .into_iter()
+                .map(|(to, set)| {
+                    let pattern = Output::pattern(set);
+
+                    quote_spanned!(span=> #pattern => #option::Some(#to),)
+                })
+                .collect::<Vec<_>>();
+
+            if !excluded.is_empty() {
+                let pattern = Output::pattern(excluded);
+
+                cases.push(quote_spanned!(span=> #pattern => #option::None,));
+            }
+
+            for (properties, to) in props {
+                let matcher = Output::properties_matcher(span, properties);
+
+                cases.push(quote_spanned!(span=> _ if #matcher => #option::Some(#to),));
+            }
+
+            match other {
+                Some(to) => cases.push(quote_spanned!(span=> _ => #option::Some(#to),)),
+                None => cases.push(quote_spanned!(span=> _ => #option::None,)),
+            }
+
+            steps.push(quote_spanned!(span=>
+                #from => match ch {
+                    #( #cases )*
+                },
+            ));
+        }
+
+        let start = index_of(&self.automata.start());
+
+        let finish = self.automata.finish().iter().map(|state| index_of(state));
+
+        let count = self.keys.len();
+        let total = states.len();
+
+        quote_spanned!(span=>
+            #[allow(unused)]
+            fn __capture(fragment: &str) -> [&str; #count] {
+                #[inline(always)]
+                fn step(state: usize, ch: char) -> #option<usize> {
+                    match state {
+                        #( #steps )*
+
+                        _ => #option::None,
+                    }
+                }
+
+                #[inline(always)]
+                fn tags(state: usize) -> (&'static [(usize, usize)], &'static [(usize, usize)]) {
+                    match state {
+                        #( #tags )*
+
+                        _ => (&[], &[]),
+                    }
+                }
+
+                #[inline(always)]
+                fn finish(state: usize) -> bool {
+                    match state {
+                        #( #finish )|* => true,
+                        _ => false,
+                    }
+                }
+
+                let sites = fragment.len() + 1;
+
+                let mut visited = #vec::<bool>::new();
+                visited.resize(#total * sites, false);
+                visited[#start * sites] = true;
+
+                let mut stack = #vec::<(usize, usize, usize, bool)>::new();
+                stack.push((#start, 0, 0, false));
+
+                let mut path = #vec::<(usize, bool, usize)>::new();
+                let mut matched = false;
+
+                while let #option::Some((state, site, alternative, _)) = stack.last().copied() {
+                    if let #option::Some(frame) = stack.last_mut() {
+                        frame.2 += 1;
+                    }
+
+                    let (open, close) = tags(state);
+
+                    let next = if alternative < open.len() {
+                        let (tag, to) = open[alternative];
+
+                        #option::Some((to, site, #option::Some((tag, false))))
+                    } else if alternative == open.len() {
+                        match fragment[site..].chars().next() {
+                            #option::None => {
+                                if finish(state) {
+                                    matched = true;
+                                    break;
+                                }
+
+                                #option::None
+                            }
+
+                            #option::Some(ch) => step(state, ch)
+                                .map(|to| (to, site + ch.len_utf8(), #option::None)),
+                        }
+                    } else if alternative - open.len() - 1 < close.len() {
+                        let (tag, to) = close[alternative - open.len() - 1];
+
+                        #option::Some((to, site, #option::Some((tag, true))))
+                    } else {
+                        if let #option::Some((_, _, _, true)) = stack.pop() {
+                            let _ = path.pop();
+                        }
+
+                        continue;
+                    };
+
+                    let #option::Some((to, next_site, tag)) = next else {
+                        continue;
+                    };
+
+                    let configuration = to * sites + next_site;
+
+                    if visited[configuration] {
+                        continue;
+                    }
+
+                    visited[configuration] = true;
+
+                    if let #option::Some((tag, close)) = tag {
+                        path.push((tag, close, site));
+                    }
+
+                    stack.push((to, next_site, 0, tag.is_some()));
+                }
+
+                if !matched {
+                    #[cfg(debug_assertions)]
+                    #panic("Capture resolution failure.");
+
+                    path.clear();
+                }
+
+                let mut spans = [(usize::MAX, 0); #count];
+
+                for (tag, close, site) in path {
+                    let span = &mut spans[tag];
+
+                    match close {
+                        false => {
+                            if span.0 == usize::MAX {
+                                span.0 = site;
+                            }
+                        }
+
+                        true => span.1 = site,
+                    }
+                }
+
+                let mut result = [""; #count];
+
+                for (capture, (start, end)) in result.iter_mut().zip(spans) {
+                    if start != usize::MAX {
+                        *capture = &fragment[start..end];
+                    }
+                }
+
+                result
+            }
+        )
+    }
+}
@@ -39,6 +39,7 @@ use syn::{
     parse::{Lookahead1, Parse, ParseStream},
     punctuated::Punctuated,
     spanned::Spanned,
+    Ident,
     LitChar,
     Result,
 };
@@ -46,7 +47,7 @@ use syn::{
 use crate::{
     token::{
         regex::{Operand, Operator, Regex},
-        ucd::{Char, CharProperties},
+        ucd::{Char, CharProperties, Script},
     },
     utils::{error, PredictableCollection, Set, SetImpl},
 };
@@ -249,6 +250,7 @@ enum CharProp {
     Upper(Span),
     XidContinue(Span),
     XidStart(Span),
+    Script(Span, Script),
 }
 
 impl Parse for CharProp {
@@ -297,6 +299,22 @@ impl CharProp {
             return Ok(Self::XidStart(span));
         }
 
+        if lookahead.peek(char_kw::script) {
+            let _ = input.parse::<char_kw::script>()?;
+
+            let content;
+            parenthesized!(content in input);
+
+            let name = content.parse::<Ident>()?;
+            let span = name.span();
+
+            let Some(script) = Script::from_name(&name.to_string()) else {
+                return Err(error!(span, "Unknown Unicode script \"{}\".", name));
+            };
+
+            return Ok(Self::Script(span, script));
+        }
+
         Err(lookahead.error())
     }
 
@@ -310,6 +328,7 @@ impl CharProp {
             Self::Upper(_) => props.upper = true,
             Self::XidContinue(_) => props.xid_continue = true,
             Self::XidStart(_) => props.xid_start = true,
+            Self::Script(_, script) => props.scripts = props.scripts.with(script),
         }
     }
 
@@ -323,6 +342,7 @@ impl CharProp {
             Self::Upper(span) => *span,
             Self::XidContinue(span) => *span,
             Self::XidStart(span) => *span,
+            Self::Script(span, _) => *span,
         }
     }
 }
@@ -335,4 +355,5 @@ mod char_kw {
     syn::custom_keyword!(upper);
     syn::custom_keyword!(xid_continue);
     syn::custom_keyword!(xid_start);
+    syn::custom_keyword!(script);
 }
@@ -0,0 +1,347 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::mem::take;
+
+use proc_macro2::{Ident, Span};
+use syn::Result;
+
+use crate::{
+    token::{
+        chars::Class,
+        input::{TokenInput, Variants},
+        regex::{Operand, Operator, Regex},
+    },
+    utils::{system_panic, Ebnf, EbnfExpr},
+};
+
+const ANY_CHAR: &str = "[#x0-#x10FFFF]";
+
+impl TokenInput {
+    // Writes the lexical grammar description in the `#[dump(ebnf)]` mode.
+    //
+    // The rules are described after the inline expressions expansion and
+    // the anchors stripping, but before the character classes expansion.
+    pub(super) fn write_ebnf(
+        span: Span,
+        ident: &Ident,
+        variants: &Variants,
+        blank: &[Ident],
+    ) -> Result<()> {
+        let mut ebnf = Ebnf::default();
+
+        ebnf.comment(format!("Lexical grammar of the \"{ident}\" tokens."));
+        ebnf.comment("Inline expressions are expanded in place.");
+        ebnf.blank_line();
+
+        for variant in variants {
+            let Some((_, rule)) = &variant.rule else {
+                continue;
+            };
+
+            if blank.contains(&variant.ident) {
+                ebnf.comment("Blank token.");
+            }
+
+            if variant.priority != 0 {
+                ebnf.comment(format!("Priority: {}.", variant.priority));
+            }
+
+            if let Some(guard) = &variant.guard {
+                let rules = guard
+                    .rules
+                    .iter()
+                    .map(|rule| format!("${rule}"))
+                    .collect::<Vec<_>>()
+                    .join(" | ");
+
+                match guard.negative {
+                    false => ebnf.comment(format!("After: {rules}.")),
+                    true => ebnf.comment(format!("Not after: {rules}.")),
+                }
+            }
+
+            if variant.line_start {
+                ebnf.comment("Matches at the start of line only.");
+            }
+
+            if variant.line_end {
+                ebnf.comment("Matches at the end of line only.");
+            }
+
+            ebnf.production(&variant.ident, rule.ebnf());
+        }
+
+        ebnf.write(span, ident)
+    }
+}
+
+trait RegexEbnf {
+    fn ebnf(&self) -> EbnfExpr;
+
+    fn leaves<'a>(&'a self, union: bool, leaves: &mut Vec<&'a Regex>);
+
+    fn char(&self) -> Option<char>;
+}
+
+impl RegexEbnf for Regex {
+    fn ebnf(&self) -> EbnfExpr {
+        match self {
+            Self::Operand(Operand::Unresolved(_)) => system_panic!("Unresolved operand."),
+
+            Self::Operand(Operand::Dump(_, inner)) => inner.ebnf(),
+
+            Self::Operand(Operand::Capture(_, inner)) => inner.ebnf(),
+
+            Self::Operand(Operand::Transform(_, _)) => {
+                system_panic!("Unresolved transformation.");
+            }
+
+            Self::Operand(Operand::Anchor(_, _)) => system_panic!("Unresolved anchor."),
+
+            Self::Operand(Operand::Class(_, Class::Char(ch))) => literal(&[*ch]),
+
+            Self::Operand(Operand::Class(_, class)) => EbnfExpr::primary(class.to_string()),
+
+            Self::Operand(Operand::Exclusion(set)) => {
+                let chars = set
+                    .classes
+                    .iter()
+                    .filter_map(|class| match class {
+                        Class::Char(ch) => Some(*ch),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+
+                match chars.is_empty() {
+                    true => EbnfExpr::primary(ANY_CHAR),
+                    false => EbnfExpr::primary(format!("[^{}]", ranges(chars))),
+                }
+            }
+
+            // Single characters of the union are gathered into a character
+            // class placed where the first of them occurs.
+            Self::Binary(_, Operator::Union, _) => {
+                let mut leaves = Vec::new();
+
+                self.leaves(true, &mut leaves);
+
+                let mut items = Vec::with_capacity(leaves.len());
+                let mut chars = Vec::new();
+                let mut position = None;
+
+                for leaf in leaves {
+                    let Some(ch) = leaf.char() else {
+                        items.push(leaf.ebnf());
+                        continue;
+                    };
+
+                    if position.is_none() {
+                        position = Some(items.len());
+                    }
+
+                    chars.push(ch);
+                }
+
+                if let Some(position) = position {
+                    let class = match chars.len() {
+                        1 => literal(&chars),
+                        _ => EbnfExpr::primary(format!("[{}]", ranges(chars))),
+                    };
+
+                    items.insert(position, class);
+                }
+
+                EbnfExpr::union(items)
+            }
+
+            // Sequences of characters are joined into string literals.
+            Self::Binary(_, Operator::Concat, _) => {
+                let mut leaves = Vec::new();
+
+                self.leaves(false, &mut leaves);
+
+                let mut items = Vec::with_capacity(leaves.len());
+                let mut chars = Vec::new();
+
+                for leaf in leaves {
+                    if let Some(ch) = leaf.char() {
+                        chars.push(ch);
+                        continue;
+                    }
+
+                    if !chars.is_empty() {
+                        items.push(literal(&take(&mut chars)));
+                    }
+
+                    items.push(leaf.ebnf());
+                }
+
+                if !chars.is_empty() {
+                    items.push(literal(&chars));
+                }
+
+                EbnfExpr::concat(items)
+            }
+
+            Self::Binary(_, _, _) => system_panic!("Unsupported Binary operator."),
+
+            Self::Unary(Operator::OneOrMore, inner) => inner.ebnf().one_or_more(),
+
+            Self::Unary(Operator::ZeroOrMore, inner) => inner.ebnf().zero_or_more(),
+
+            Self::Unary(Operator::Optional, inner) => inner.ebnf().optional(),
+
+            Self::Unary(_, _) => system_panic!("Unsupported Unary operator."),
+        }
+    }
+
+    fn leaves<'a>(&'a self, union: bool, leaves: &mut Vec<&'a Regex>) {
+        match self {
+            Self::Binary(left, Operator::Union, right) if union => {
+                left.leaves(union, leaves);
+                right.leaves(union, leaves);
+            }
+
+            Self::Binary(left, Operator::Concat, right) if !union => {
+                left.leaves(union, leaves);
+                right.leaves(union, leaves);
+            }
+
+            _ => leaves.push(self),
+        }
+    }
+
+    fn char(&self) -> Option<char> {
+        match self {
+            Self::Operand(Operand::Class(_, Class::Char(ch))) => Some(*ch),
+            Self::Operand(Operand::Dump(_, inner)) => inner.char(),
+            Self::Operand(Operand::Capture(_, inner)) => inner.char(),
+            _ => None,
+        }
+    }
+}
+
+// A sequence of string literals and character codes matching the `chars`.
+fn literal(chars: &[char]) -> EbnfExpr {
+    fn quote(string: String) -> EbnfExpr {
+        match string.contains('"') {
+            true => EbnfExpr::primary(format!("'{string}'")),
+            false => EbnfExpr::primary(format!("\"{string}\"")),
+        }
+    }
+
+    let mut items = Vec::new();
+    let mut string = String::new();
+
+    for ch in chars.iter().copied() {
+        if !is_printable(ch) {
+            if !string.is_empty() {
+                items.push(quote(take(&mut string)));
+            }
+
+            items.push(EbnfExpr::primary(code(ch)));
+            continue;
+        }
+
+        // A string literal cannot contain both quotation marks.
+        let conflicts = match ch {
+            '"' => string.contains('\''),
+            '\'' => string.contains('"'),
+            _ => false,
+        };
+
+        if conflicts {
+            items.push(quote(take(&mut string)));
+        }
+
+        string.push(ch);
+    }
+
+    if !string.is_empty() {
+        items.push(quote(string));
+    }
+
+    EbnfExpr::concat(items)
+}
+
+// The content of the character class with consecutive characters joined
+// into ranges.
+fn ranges(mut chars: Vec<char>) -> String {
+    fn class_char(ch: char) -> String {
+        match is_printable(ch) && !matches!(ch, ' ' | ']' | '^' | '-') {
+            true => String::from(ch),
+            false => code(ch),
+        }
+    }
+
+    chars.sort();
+    chars.dedup();
+
+    let mut result = String::new();
+    let mut chars = chars.into_iter().peekable();
+
+    while let Some(first) = chars.next() {
+        let mut last = first;
+        let mut length = 1;
+
+        while let Some(next) = chars.next_if(|next| *next as u32 == last as u32 + 1) {
+            last = next;
+            length += 1;
+        }
+
+        result += &class_char(first);
+
+        match length {
+            1 => (),
+            2 => result += &class_char(last),
+            _ => {
+                result.push('-');
+                result += &class_char(last);
+            }
+        }
+    }
+
+    result
+}
+
+#[inline(always)]
+fn code(ch: char) -> String {
+    format!("#x{:X}", ch as u32)
+}
+
+#[inline(always)]
+fn is_printable(ch: char) -> bool {
+    !ch.is_control() && (ch == ' ' || !ch.is_whitespace())
+}
@@ -0,0 +1,96 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use proc_macro2::{Ident, Span};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Attribute,
+    Result,
+};
+
+use crate::utils::error;
+
+pub(super) struct Guard {
+    pub(super) span: Span,
+    pub(super) negative: bool,
+    pub(super) rules: Vec<Ident>,
+}
+
+impl Guard {
+    pub(super) fn parse(attr: Attribute, negative: bool) -> Result<Self> {
+        let span = attr.span();
+        let rules = attr.parse_args::<RuleList>()?.0;
+
+        Ok(Self {
+            span,
+            negative,
+            rules,
+        })
+    }
+
+    #[inline(always)]
+    pub(super) fn admits(&self, previous: &Ident) -> bool {
+        self.rules.contains(previous) != self.negative
+    }
+}
+
+pub(super) struct RuleList(pub(super) Vec<Ident>);
+
+impl Parse for RuleList {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let rules = Punctuated::<RuleIdent, Token![|]>::parse_separated_nonempty(input)?;
+
+        if !input.is_empty() {
+            return Err(error!(
+                input.span(),
+                "Expected a list of token variants: $A | $B | $C.",
+            ));
+        }
+
+        Ok(Self(rules.into_iter().map(|rule| rule.0).collect()))
+    }
+}
+
+struct RuleIdent(Ident);
+
+impl Parse for RuleIdent {
+    #[inline(always)]
+    fn parse(input: ParseStream) -> Result<Self> {
+        let _ = input.parse::<Token![$]>()?;
+
+        Ok(Self(input.parse::<Ident>()?))
+    }
+}
@@ -34,7 +34,7 @@
 
 use std::{cmp::Ordering, mem::take, time::Instant};
 
-use proc_macro2::Ident;
+use proc_macro2::{Ident, Literal};
 use quote::ToTokens;
 use syn::{
     parse::{Parse, ParseStream},
@@ -52,9 +52,11 @@ use syn::{
 
 use crate::{
     token::{
-        automata::{AutomataImpl, Scope, Terminal, TokenAutomata},
+        automata::{AutomataImpl, Scope, Tag, Terminal, TokenAutomata},
+        capture::TokenCaptures,
+        guard::RuleList,
         opt::Opt,
-        regex::{Regex, RegexImpl, TransformConfig},
+        regex::{Anchor, Regex, RegexImpl, TransformConfig},
         variant::{TokenVariant, EOI, MISMATCH},
     },
     utils::{
@@ -84,13 +86,26 @@ pub struct TokenInput {
     pub(super) lookback: Option<Expr>,
     pub(super) eoi: Ident,
     pub(super) mismatch: Ident,
-    pub(super) automata: TokenAutomata,
+    pub(super) blank: Vec<Ident>,
+    pub(super) contexts: Vec<TokenContext>,
     pub(super) variants: Variants,
     pub(super) products: ProductMap,
+    pub(super) anchored: ProductMap,
     pub(super) alphabet: Alphabet,
     pub(crate) dump: Dump,
 }
 
+// A scanning automata of the rules admitted after the `previous` tokens.
+//
+// Each previous token is paired with the line start flag of the scanning
+// token. The flag is None if the context does not depend on the line start.
+//
+// The automata is None if none of the rules admitted in this context.
+pub(super) struct TokenContext {
+    pub(super) previous: Vec<(Ident, Option<bool>)>,
+    pub(super) automata: Option<TokenAutomata>,
+}
+
 impl Parse for TokenInput {
     #[inline(always)]
     fn parse(input: ParseStream) -> Result<Self> {
@@ -141,6 +156,7 @@ impl TryFrom<DeriveInput> for TokenInput {
 
         let mut inline_map = InlineMap::empty();
         let mut lookback = None;
+        let mut blank = None;
         let mut opt = None;
         let mut dump = Dump::None;
         let mut repr = false;
@@ -219,6 +235,14 @@ impl TryFrom<DeriveInput> for TokenInput {
                     lookback = Some(attr.parse_args::<Expr>()?);
                 }
 
+                "blank" => {
+                    if blank.is_some() {
+                        return Err(error!(span, "Duplicate Blank attribute.",));
+                    }
+
+                    blank = Some(attr.parse_args::<RuleList>()?.0);
+                }
+
                 "opt" => {
                     if opt.is_some() {
                         return Err(error!(span, "Duplicate Opt attribute.",));
@@ -276,11 +300,39 @@ impl TryFrom<DeriveInput> for TokenInput {
                 }
             }
 
-            if let Some((_, rule)) = &mut variant.rule {
+            if let Some((span, mut rule)) = take(&mut variant.rule) {
                 parsable += 1;
                 rule.inline(&inline_map, &variant_map)?;
                 rule.transform(&TransformConfig::default());
+
+                let (rule, line_start) = rule.strip_anchor(Anchor::LineStart);
+                let (rule, line_end) = rule.strip_anchor(Anchor::LineEnd);
+
+                match rule.anchor() {
+                    None => (),
+
+                    Some((span, Anchor::LineStart)) => {
+                        return Err(error!(
+                            span,
+                            "The start-of-line anchor is only allowed in \
+                            front of the rule expression: ^ <expr>.",
+                        ));
+                    }
+
+                    Some((span, Anchor::LineEnd)) => {
+                        return Err(error!(
+                            span,
+                            "The end-of-line anchor is only allowed at the \
+                            end of the rule expression: <expr> $.",
+                        ));
+                    }
+                }
+
                 alphabet.append(rule.alphabet());
+
+                variant.line_start = line_start;
+                variant.line_end = line_end;
+                variant.rule = Some((span, rule));
             }
         }
 
@@ -323,6 +375,144 @@ impl TryFrom<DeriveInput> for TokenInput {
             ));
         }
 
+        // A token that ends before the end of line depends on the character
+        // that follows the token. If this character changes, the scanner has
+        // to rescan the entire token, which could be up to the rule's maximum
+        // length characters behind.
+        if lookback.is_none() {
+            let mut inferred = 1;
+
+            for variant in &variants {
+                if !variant.line_end {
+                    continue;
+                }
+
+                let Some((span, rule)) = &variant.rule else {
+                    continue;
+                };
+
+                let Some(length) = rule.length() else {
+                    let ident = &variant.ident;
+
+                    return Err(error!(
+                        *span,
+                        "Rule {ident} with the end-of-line anchor matches \
+                        strings of unlimited length, and the lookback value \
+                        cannot be inferred.\nAnnotate the enum type with \
+                        #[lookback(<number>)] attribute to specify the number \
+                        of characters the scanner steps back to rescan \
+                        the edited text.",
+                    ));
+                };
+
+                inferred = inferred.max(length);
+            }
+
+            if inferred > 1 {
+                let inferred = Literal::usize_unsuffixed(inferred);
+
+                lookback = Some(parse_quote!(#inferred));
+            }
+        }
+
+        let blank = blank.unwrap_or_default();
+
+        for rule in &blank {
+            if !variant_map.contains_key(rule) {
+                return Err(error!(rule.span(), "Unknown token variant.",));
+            }
+
+            if rule == &eoi {
+                return Err(error!(rule.span(), "End-of-Input variant cannot be blank.",));
+            }
+        }
+
+        for variant in &variants {
+            let Some(guard) = &variant.guard else {
+                continue;
+            };
+
+            for rule in &guard.rules {
+                if !variant_map.contains_key(rule) {
+                    return Err(error!(rule.span(), "Unknown token variant.",));
+                }
+
+                if blank.contains(rule) {
+                    return Err(error!(
+                        rule.span(),
+                        "Blank token {rule} is never the previous token.\n\
+                        The scanner skips the tokens listed in the \
+                        #[blank(...)] attribute when it determines the \
+                        previous token.",
+                    ));
+                }
+            }
+        }
+
+        let mut contexts = Vec::<(Vec<usize>, Vec<(Ident, Option<bool>)>)>::new();
+
+        for previous in &variants {
+            if blank.contains(&previous.ident) {
+                continue;
+            }
+
+            let admitted = |line_start: bool| {
+                variants
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, variant)| line_start || !variant.line_start)
+                    .filter(|(_, variant)| match (&variant.rule, &variant.guard) {
+                        (None, _) => false,
+                        (Some(_), None) => true,
+                        (Some(_), Some(guard)) => guard.admits(&previous.ident),
+                    })
+                    .map(|(index, _)| index)
+                    .collect::<Vec<_>>()
+            };
+
+            let rules = admitted(false);
+            let line_rules = admitted(true);
+
+            let mut insert = |rules: Vec<usize>, line_start: Option<bool>| {
+                let previous = (previous.ident.clone(), line_start);
+
+                match contexts.iter_mut().find(|(context, _)| context == &rules) {
+                    Some((_, context)) => context.push(previous),
+                    None => contexts.push((rules, vec![previous])),
+                }
+            };
+
+            match rules == line_rules {
+                true => insert(rules, None),
+
+                false => {
+                    insert(rules, Some(false));
+                    insert(line_rules, Some(true));
+                }
+            }
+        }
+
+        for (index, variant) in variants.iter().enumerate() {
+            let Some(guard) = &variant.guard else {
+                continue;
+            };
+
+            if !contexts.iter().any(|(rules, _)| rules.contains(&index)) {
+                let ident = &variant.ident;
+
+                return Err(error!(
+                    guard.span,
+                    "Parsable rule {ident} never matches.\nThis guard \
+                    excludes all non-blank tokens that could precede \
+                    the rule.",
+                ));
+            }
+        }
+
+        if let Dump::Ebnf(span) = dump {
+            Self::write_ebnf(span, &ident, &variants, &blank)?;
+        }
+
         let mut scope = Scope::new();
 
         for variant in &mut variants {
@@ -356,64 +546,99 @@ impl TryFrom<DeriveInput> for TokenInput {
                 scope.concatenate(rule, product)
             };
 
+            let mut keys = Vec::new();
+
+            regex.captures(&mut keys);
+
+            if let Some(key) = keys.first() {
+                if variant.constructor.is_none() {
+                    return Err(error!(
+                        key.span(),
+                        "Captures are not applicable to the variants without \
+                        constructor.\nTo receive the captured fragments \
+                        annotate this variant with #[constructor(...)] \
+                        attribute.",
+                    ));
+                }
+
+                if keys.len() > Tag::MAX as usize + 1 {
+                    return Err(error!(span, "Too many captures in the rule.",));
+                }
+
+                scope.set_strategy(Strategy::CANONICALIZE);
+                scope.set_tags(keys.clone());
+
+                let mut automata = regex.encode(&mut scope)?;
+
+                scope.set_tags(Vec::new());
+                scope.optimize(&mut automata);
+
+                variant.captures = Some(TokenCaptures { keys, automata });
+            }
+
             variant.time += start.elapsed();
 
             variant.automata = Some(automata);
         }
 
-        let mut automata = {
-            scope.set_strategy(Strategy::DETERMINIZE);
+        let contexts = contexts
+            .into_iter()
+            .map(|(rules, previous)| {
+                let automata = match rules.is_empty() {
+                    true => None,
+                    false => Some(Self::compile_context(
+                        &mut scope,
+                        &mut variants,
+                        rules,
+                        &ident,
+                        opt,
+                    )?),
+                };
 
-            let mut ordered = variants
-                .iter_mut()
-                .filter_map(|variant| match variant.automata.is_some() {
-                    false => None,
-                    true => Some(variant),
-                })
-                .collect::<Vec<_>>();
+                Ok((previous, automata))
+            })
+            .collect::<Result<Vec<_>>>()?;
 
-            ordered.sort_by(|a, b| match a.priority.cmp(&b.priority) {
-                Ordering::Less => Ordering::Greater,
-                Ordering::Greater => Ordering::Less,
-                Ordering::Equal => {
-                    let a_automata = expect_some!(a.automata.as_ref(), "Automata is missing.",);
-                    let b_automata = expect_some!(b.automata.as_ref(), "Automata is missing.",);
+        scope.reset();
 
-                    a_automata
-                        .transitions()
-                        .len()
-                        .cmp(&b_automata.transitions().len())
-                }
-            });
-
-            let automata = ordered.into_iter().fold(None, |acc, next| {
-                let automata = expect_some!(take(&mut next.automata), "Automata is missing",);
-
-                match acc {
-                    None => Some(automata),
-                    Some(acc) => {
-                        let start = Instant::now();
-                        let result = Some(scope.union(acc, automata));
-                        next.time += start.elapsed();
-                        result
-                    }
-                }
-            });
+        let mut products = ProductMap::with_capacity(variants.len());
+        let mut anchored = ProductMap::empty();
+        let mut matched = Set::with_capacity(variants.len());
 
-            expect_some!(automata, "Empty automata.",)
-        };
+        let contexts = contexts
+            .into_iter()
+            .map(|(previous, automata)| {
+                let automata = automata.map(|automata| {
+                    let mut automata = scope.copy(&automata);
 
-        automata.merge(&mut scope, &variants)?;
+                    automata.filter_out(&variants, &mut products, &mut anchored, &mut matched);
 
-        automata.check_property_conflicts(ident.span())?;
+                    automata
+                });
 
-        scope.set_strategy(opt.into_strategy());
-        scope.optimize(&mut automata);
+                TokenContext { previous, automata }
+            })
+            .collect::<Vec<_>>();
 
-        scope.reset();
-        automata = scope.copy(&automata);
+        for (index, variant) in variants.iter().enumerate() {
+            if variant.rule.is_none() {
+                continue;
+            }
+
+            if !matched.contains(&index) {
+                let ident = &variant.ident;
 
-        let products = automata.filter_out(&variants)?;
+                return Err(error!(
+                    ident.span(),
+                    "Parsable rule {ident} is overlapping by other \
+                    parsable rules due to a low priority. This rule never \
+                    matches.\nTry to increase rule's priority using \
+                    #[priority(<number>)] attribute.\nDefault priority is \
+                    0. Rules with higher priority value have precedence \
+                    over the rules with lower priority value.",
+                ));
+            }
+        }
 
         let analysis = start.elapsed();
 
@@ -423,9 +648,11 @@ impl TryFrom<DeriveInput> for TokenInput {
             lookback,
             eoi,
             mismatch,
-            automata,
+            blank,
+            contexts,
             variants,
             products,
+            anchored,
             alphabet,
             dump,
         };
@@ -441,12 +668,21 @@ impl TryFrom<DeriveInput> for TokenInput {
             };
 
             let lines = output_string.lines().count();
-            let transitions = result.automata.transitions().len();
-            let states = result
-                .automata
-                .transitions()
-                .view()
+            let automata = result
+                .contexts
                 .iter()
+                .filter_map(|context| context.automata.as_ref())
+                .collect::<Vec<_>>();
+
+            let transitions = automata
+                .iter()
+                .map(|automata| automata.transitions().len())
+                .sum::<usize>();
+
+            let states = automata
+                .iter()
+                .map(|automata| automata.transitions().view().iter())
+                .flatten()
                 .map(|(from, to)| to.iter().map(|(_, to)| [*from, *to].into_iter()))
                 .flatten()
                 .flatten()
@@ -460,6 +696,7 @@ impl TryFrom<DeriveInput> for TokenInput {
             report += " -- Macro Debug Dump --\n\n";
             report += &format!("Token \"{ident}\" analysis:\n");
             report += &format!("    Optimization: {opt:?}.\n");
+            report += &format!("    Lexical contexts: {}.\n", result.contexts.len());
             report += &format!("    Total analysis time: {analysis:?}.\n");
             report += &format!("    Total code generation time: {build:?}.\n");
             report += &format!("    States count: {states}.\n");
@@ -518,3 +755,63 @@ impl TryFrom<DeriveInput> for TokenInput {
         Ok(result)
     }
 }
+
+impl TokenInput {
+    // Builds a union automata of the `rules` that the scanner admits
+    // in a particular lexical context.
+    fn compile_context(
+        scope: &mut Scope,
+        variants: &mut Variants,
+        mut rules: Vec<usize>,
+        ident: &Ident,
+        opt: Opt,
+    ) -> Result<TokenAutomata> {
+        scope.set_strategy(Strategy::DETERMINIZE);
+
+        rules.sort_by(|a, b| {
+            let a = expect_some!(variants.get(*a), "Missing variant.",);
+            let b = expect_some!(variants.get(*b), "Missing variant.",);
+
+            match a.priority.cmp(&b.priority) {
+                Ordering::Less => Ordering::Greater,
+                Ordering::Greater => Ordering::Less,
+                Ordering::Equal => {
+                    let a_automata = expect_some!(a.automata.as_ref(), "Automata is missing.",);
+                    let b_automata = expect_some!(b.automata.as_ref(), "Automata is missing.",);
+
+                    a_automata
+                        .transitions()
+                        .len()
+                        .cmp(&b_automata.transitions().len())
+                }
+            }
+        });
+
+        let automata = rules.into_iter().fold(None, |acc, index| {
+            let next = expect_some!(variants.get_mut(index), "Missing variant.",);
+            let automata = expect_some!(next.automata.as_ref(), "Automata is missing",);
+            let automata = scope.copy(automata);
+
+            match acc {
+                None => Some(automata),
+                Some(acc) => {
+                    let start = Instant::now();
+                    let result = Some(scope.union(acc, automata));
+                    next.time += start.elapsed();
+                    result
+                }
+            }
+        });
+
+        let mut automata = expect_some!(automata, "Empty automata.",);
+
+        automata.merge(scope, variants)?;
+
+        automata.check_property_conflicts(ident.span())?;
+
+        scope.set_strategy(opt.into_strategy());
+        scope.optimize(&mut automata);
+
+        Ok(automata)
+    }
+}
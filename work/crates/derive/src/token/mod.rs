@@ -33,7 +33,10 @@
 ////////////////////////////////////////////////////////////////////////////////
 
 mod automata;
+mod capture;
 mod chars;
+mod ebnf;
+mod guard;
 mod input;
 mod opt;
 mod output;
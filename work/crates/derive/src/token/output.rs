@@ -38,15 +38,24 @@ use std::{
     ops::RangeInclusive,
 };
 
-use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro2::{Ident, Literal, Span, TokenStream};
 use quote::ToTokens;
 use syn::{spanned::Spanned, LitByte, LitStr};
 
 use crate::{
-    token::{automata::Terminal, chars::Class, ucd::CharProperties, TokenInput},
+    token::{
+        automata::{Terminal, TokenAutomata},
+        chars::Class,
+        input::TokenContext,
+        ucd::CharProperties,
+        variant::TokenVariant,
+        TokenInput,
+    },
     utils::{
+        compile_hash_impl,
         expect_some,
         null,
+        stable_hash,
         system_panic,
         Dump,
         Facade,
@@ -57,9 +66,12 @@ use crate::{
     },
 };
 
+const ASCII_FALLBACK: u8 = 0xFE;
+const ASCII_BREAK: u8 = 0xFF;
+
 pub(super) struct Output<'a> {
-    ident: &'a Ident,
     input: &'a TokenInput,
+    automata: &'a TokenAutomata,
     buffering: bool,
     pending: BTreeSet<State>,
     handled: Set<State>,
@@ -72,14 +84,18 @@ pub(super) struct Output<'a> {
 }
 
 impl<'a> Output<'a> {
-    pub(super) fn compile(input: &'a TokenInput, buffer: bool) -> Vec<TokenStream> {
+    pub(super) fn compile(
+        input: &'a TokenInput,
+        automata: &'a TokenAutomata,
+        buffer: bool,
+    ) -> Vec<TokenStream> {
         let mut output = Output {
-            ident: &input.ident,
             input,
+            automata,
             buffering: buffer,
             pending: BTreeSet::new(),
             handled: Set::empty(),
-            transitions: Vec::with_capacity(input.automata.transitions().len()),
+            transitions: Vec::with_capacity(automata.transitions().len()),
             from: 0,
             ascii: BTreeMap::new(),
             unicode: BTreeMap::new(),
@@ -87,7 +103,7 @@ impl<'a> Output<'a> {
             other: None,
         };
 
-        let _ = output.pending.insert(input.automata.start());
+        let _ = output.pending.insert(automata.start());
 
         while output.pop() {}
 
@@ -105,7 +121,7 @@ impl<'a> Output<'a> {
         }
 
         let outgoing = expect_some!(
-            self.input.automata.transitions().outgoing(&self.from),
+            self.automata.transitions().outgoing(&self.from),
             "Missing outgoing.",
         );
 
@@ -120,10 +136,29 @@ impl<'a> Output<'a> {
                 Terminal::Null => null!(),
                 Terminal::Product(index) => system_panic!("Unfiltered product {index}.",),
                 Terminal::Class(class) => self.register_class(class, *to),
+                Terminal::Open(tag) | Terminal::Close(tag) => {
+                    system_panic!("Unresolved capture tag {tag}.",)
+                }
             };
         }
 
-        let base = take(&mut self.ascii)
+        // The Other class matches only the characters outside of the alphabet.
+        // The alphabet characters without explicit transitions from this
+        // state must not fall into the Other's fallback.
+        let excluded = match self.other.is_some() {
+            false => Set::empty(),
+
+            true => self
+                .input
+                .alphabet
+                .iter()
+                .filter(|ch| ch.is_ascii())
+                .map(|ch| *ch as u8)
+                .filter(|byte| !self.ascii.values().any(|bytes| bytes.contains(byte)))
+                .collect::<Set<u8>>(),
+        };
+
+        let mut base = take(&mut self.ascii)
             .into_iter()
             .map(|(to, set)| {
                 let pattern = match self.input.dump {
@@ -140,6 +175,18 @@ impl<'a> Output<'a> {
             })
             .collect::<Vec<_>>();
 
+        if !excluded.is_empty() {
+            let pattern = match self.input.dump {
+                Dump::Output(..) => {
+                    Self::pattern(excluded.into_iter().map(|byte| byte as char).collect())
+                }
+
+                _ => Self::pattern(excluded),
+            };
+
+            base.push(quote!(#pattern => break,));
+        }
+
         let fallback = self.fallback();
 
         let from = self.from;
@@ -197,7 +244,20 @@ impl<'a> Output<'a> {
             #core::lexis::LexisSession::read(session)
         }));
 
-        let unicode_cases = take(&mut self.unicode)
+        let excluded = match self.other.is_some() {
+            false => Set::empty(),
+
+            true => self
+                .input
+                .alphabet
+                .iter()
+                .filter(|ch| !ch.is_ascii())
+                .filter(|ch| !self.unicode.values().any(|chars| chars.contains(ch)))
+                .copied()
+                .collect::<Set<char>>(),
+        };
+
+        let mut unicode_cases = take(&mut self.unicode)
             .into_iter()
             .map(|(to, set)| {
                 let pattern = Self::pattern(set);
@@ -207,6 +267,12 @@ impl<'a> Output<'a> {
             })
             .collect::<Vec<_>>();
 
+        if !excluded.is_empty() {
+            let pattern = Self::pattern(excluded);
+
+            unicode_cases.push(quote!(#pattern => break,));
+        }
+
         if !unicode_cases.is_empty() {
             statements.push_branching(quote!(
                 match ch {
@@ -250,21 +316,29 @@ impl<'a> Output<'a> {
             return true;
         }
 
+        if self.other.is_some() && self.input.alphabet.iter().any(|ch| !ch.is_ascii()) {
+            return true;
+        }
+
         false
     }
 
     fn handle(&mut self, to: State, unicode: bool, force_continue: bool) -> Statements {
         let transit = self
-            .input
             .automata
             .transitions()
             .outgoing(&to)
             .filter(|view| !view.is_empty())
             .is_some();
 
+        let product = self.input.products.get(&to);
+        let anchored = self.input.anchored.get(&to);
+
         let mut statements = Statements::default();
 
-        if transit && self.buffering {
+        // The constructors receive the buffered fragment up to the product
+        // state, including the character that leads to this state.
+        if (transit || product.is_some() || anchored.is_some()) && self.buffering {
             let string = self.input.ident.span().face_string();
 
             match unicode {
@@ -273,7 +347,7 @@ impl<'a> Output<'a> {
             }
         }
 
-        if let Some(index) = self.input.products.get(&to) {
+        if let Some(index) = product {
             let core = self.input.ident.span().face_core();
 
             let variant =
@@ -285,28 +359,30 @@ impl<'a> Output<'a> {
                 #core::lexis::LexisSession::submit(session)
             }));
 
-            match &variant.constructor {
-                None => {
-                    statements.push(quote!(token = Self::#ident));
-                }
+            statements.push(quote!(token = Self::#ident));
 
-                Some(constructor) => {
-                    let span = constructor.span();
-                    let string = span.face_string();
-                    let ident = self.ident;
+            // The constructor is called once the scanner finishes, because
+            // the scanner may submit several products of the same variant
+            // while looking for the longest match.
+            if variant.constructor.is_some() {
+                let string = self.input.ident.span().face_string();
 
-                    statements.push(quote_spanned!(span=>
-                        token = {
-                            #[allow(unused)]
-                            #[inline(always)]
-                            fn __construct(fragment: &str) -> #ident {
-                                #constructor
-                            }
+                statements.push(quote!(length = #string::len(&buffer)));
+            }
+        }
 
-                            __construct(#string::as_str(&buffer))
-                        }
-                    ))
-                }
+        if let Some(index) = anchored {
+            let variant =
+                expect_some!(self.input.variants.get(*index), "Missing product variant.",);
+
+            let (pending, lookahead) =
+                self.input
+                    .compile_line_end(variant, self.buffering, transit);
+
+            statements.push(pending);
+
+            if let Some(lookahead) = lookahead {
+                statements.push_branching(lookahead);
             }
         }
 
@@ -394,7 +470,7 @@ impl<'a> Output<'a> {
         self.other = None;
     }
 
-    fn pattern<T: Copy + Ord + Continuous>(set: Set<T>) -> TokenStream {
+    pub(super) fn pattern<T: Copy + Ord + Continuous>(set: Set<T>) -> TokenStream {
         enum Group<T: Continuous> {
             Single(T),
             Range(RangeInclusive<T>),
@@ -453,7 +529,7 @@ impl<'a> Output<'a> {
         quote!(#( #groups )|*)
     }
 
-    fn properties_matcher(span: Span, properties: CharProperties) -> TokenStream {
+    pub(super) fn properties_matcher(span: Span, properties: CharProperties) -> TokenStream {
         let core = span.face_core();
 
         let mut setters = Vec::new();
@@ -494,6 +570,18 @@ impl<'a> Output<'a> {
             single = Some(quote_spanned!(span=> #core::lexis::Char::is_xid_start(ch)));
         }
 
+        for script in properties.scripts.iter() {
+            let script = Ident::new(&format!("{script:?}"), span);
+
+            setters.push(quote_spanned!(span=>
+                props.scripts = props.scripts.with(#core::lexis::Script::#script);
+            ));
+
+            single = Some(quote_spanned!(span=>
+                #core::lexis::Char::has_script(ch, #core::lexis::Script::#script)
+            ));
+        }
+
         if setters.len() == 1 {
             return expect_some!(single, "Missing single matcher.",);
         }
@@ -508,7 +596,7 @@ impl<'a> Output<'a> {
     }
 }
 
-trait Continuous {
+pub(super) trait Continuous {
     fn continuous_to(&self, next: &Self) -> bool;
 
     fn represent(&self) -> TokenStream;
@@ -606,7 +694,6 @@ impl TokenInput {
         let panic = span.face_panic();
 
         let mismatch = &self.mismatch;
-        let start = self.automata.start();
 
         let buffer = match self
             .variants
@@ -620,14 +707,47 @@ impl TokenInput {
                 Some(quote_spanned!(span =>
                     #[allow(unused_mut)]
                     let mut buffer = #string::new();
+
+                    #[allow(unused_mut)]
+                    let mut length = 0;
                 ))
             }
         };
 
-        let transitions = Output::compile(self, buffer.is_some());
+        let constructors = self.compile_constructors();
+
+        let transitions = self
+            .contexts
+            .iter()
+            .filter_map(|context| context.automata.as_ref())
+            .map(|automata| Output::compile(self, automata, buffer.is_some()))
+            .flatten()
+            .collect::<Vec<_>>();
+
+        let start = self.compile_start();
+
+        let (line_end, line_end_check) = match self.compile_line_end_check(buffer.is_some()) {
+            Some((declarations, check)) => (Some(declarations), Some(check)),
+            None => (None, None),
+        };
+
+        let (table, step) = match self.compile_ascii_table(buffer.is_some()) {
+            Some((table, step)) => (Some(table), step),
+
+            None => (
+                None,
+                quote_spanned!(span=>
+                    if byte == 0xFF {
+                        break;
+                    }
+                ),
+            ),
+        };
 
         quote_spanned!(span=>
             fn scan(session: &mut impl #core::lexis::LexisSession) -> Self {
+                #table
+
                 #[allow(unused_mut)]
                 let mut state = #start;
 
@@ -636,12 +756,14 @@ impl TokenInput {
 
                 #buffer
 
+                #line_end
+
                 loop {
                     let byte = #core::lexis::LexisSession::advance(session);
 
-                    if byte == 0xFF {
-                        break;
-                    }
+                    #line_end_check
+
+                    #step
 
                     match state {
                         #(
@@ -656,11 +778,557 @@ impl TokenInput {
                     }
                 }
 
+                #constructors
+
                 token
             }
         )
     }
 
+    // If the scanner's alphabet consists of ASCII characters only, builds
+    // a byte-indexed table of the transitions between the automata states,
+    // and a statement of the scanner's loop that looks up this table.
+    //
+    // A table cell is either the next state, the ASCII_BREAK value
+    // that stops the scanner, or the ASCII_FALLBACK value. The latter is
+    // used for the non-ASCII bytes matched by the Other class only, and
+    // the scanner handles such bytes through the generic per-state
+    // transitions that read the entire Unicode character.
+    fn compile_ascii_table(&self, buffering: bool) -> Option<(TokenStream, TokenStream)> {
+        if self.alphabet.iter().any(|ch| !ch.is_ascii()) {
+            return None;
+        }
+
+        let automata = self
+            .contexts
+            .iter()
+            .filter_map(|context| context.automata.as_ref())
+            .collect::<Vec<_>>();
+
+        let mut states = 0;
+
+        for automata in &automata {
+            for (from, outgoing) in automata.transitions().view() {
+                states = states.max(*from + 1);
+
+                for (through, to) in outgoing {
+                    if let Terminal::Class(Class::Props(..)) = through {
+                        return None;
+                    }
+
+                    states = states.max(*to + 1);
+                }
+            }
+        }
+
+        if states == 0 || states > ASCII_FALLBACK as usize {
+            return None;
+        }
+
+        let mut rows = vec![[ASCII_BREAK; 256]; states];
+
+        for automata in &automata {
+            let transitions = automata.transitions();
+
+            // The states without outgoing transitions and products would
+            // stop the scanner anyway.
+            let target = |to: &State| {
+                let transit = transitions
+                    .outgoing(to)
+                    .filter(|view| !view.is_empty())
+                    .is_some();
+
+                match transit || self.products.contains_key(to) || self.anchored.contains_key(to) {
+                    true => *to as u8,
+                    false => ASCII_BREAK,
+                }
+            };
+
+            for (from, outgoing) in transitions.view() {
+                let row = expect_some!(rows.get_mut(*from), "Missing table row.",);
+
+                let mut other = None;
+
+                for (through, to) in outgoing {
+                    match through {
+                        Terminal::Class(Class::Char(ch)) => row[*ch as usize] = target(to),
+                        Terminal::Class(Class::Other) => other = Some(target(to)),
+                        _ => system_panic!("Unexpected terminal {through}."),
+                    }
+                }
+
+                if other.is_none() {
+                    continue;
+                }
+
+                for byte in 0u8..0x80 {
+                    if self.alphabet.contains(&(byte as char)) {
+                        continue;
+                    }
+
+                    row[byte as usize] = expect_some!(other, "Missing Other class.",);
+                }
+
+                // The 0xFF byte denotes the end of input, and is never
+                // a part of the UTF-8 encoding.
+                for cell in &mut row[0x80..0xFF] {
+                    *cell = ASCII_FALLBACK;
+                }
+            }
+        }
+
+        let span = self.ident.span();
+        let core = span.face_core();
+
+        let rows = rows.iter().map(|row| {
+            let cells = row.iter().map(|cell| Literal::u8_unsuffixed(*cell));
+
+            quote_spanned!(span=> [#( #cells ),*])
+        });
+
+        let table = quote_spanned!(span=>
+            static ASCII: [[u8; 256]; #states] = [#(
+                #rows,
+            )*];
+        );
+
+        let buffering = match buffering {
+            false => None,
+
+            true => {
+                let string = span.face_string();
+
+                Some(quote_spanned!(span=> #string::push(&mut buffer, byte as char);))
+            }
+        };
+
+        let mut products = self
+            .products
+            .keys()
+            .chain(self.anchored.keys())
+            .copied()
+            .collect::<Vec<_>>();
+
+        products.sort();
+        products.dedup();
+
+        let products = products.into_iter().map(|state| {
+            let transit = automata.iter().any(|automata| {
+                automata
+                    .transitions()
+                    .outgoing(&state)
+                    .filter(|view| !view.is_empty())
+                    .is_some()
+            });
+
+            let submit = self.products.get(&state).map(|index| {
+                let variant = expect_some!(self.variants.get(*index), "Missing product variant.",);
+
+                let ident = &variant.ident;
+
+                let length = variant.constructor.as_ref().map(|_| {
+                    let string = span.face_string();
+
+                    quote_spanned!(span=> length = #string::len(&buffer);)
+                });
+
+                quote_spanned!(span=>
+                    unsafe {
+                        #core::lexis::LexisSession::submit(session);
+                    }
+
+                    token = Self::#ident;
+
+                    #length
+                )
+            });
+
+            let line_end = self.anchored.get(&state).map(|index| {
+                let variant = expect_some!(self.variants.get(*index), "Missing product variant.",);
+
+                let (pending, lookahead) =
+                    self.compile_line_end(variant, buffering.is_some(), transit);
+
+                quote_spanned!(span=>
+                    #pending;
+
+                    #lookahead
+                )
+            });
+
+            let state = Literal::u8_unsuffixed(state as u8);
+
+            let stop = match transit {
+                false => Some(quote_spanned!(span=> break;)),
+                true => None,
+            };
+
+            quote_spanned!(span=>
+                #state => {
+                    #submit
+
+                    #line_end
+
+                    #stop
+                }
+            )
+        });
+
+        let fallback = Literal::u8_unsuffixed(ASCII_FALLBACK);
+        let stop = Literal::u8_unsuffixed(ASCII_BREAK);
+
+        let step = quote_spanned!(span=>
+            match ASCII[state][byte as usize] {
+                #fallback => (),
+
+                #stop => break,
+
+                next => {
+                    #buffering
+
+                    match next {
+                        #(
+                        #products
+                        )*
+
+                        _ => (),
+                    }
+
+                    state = next as usize;
+
+                    continue;
+                }
+            }
+        );
+
+        Some((table, step))
+    }
+
+    // The product of the end-of-line anchored rule is submitted only if
+    // the next character is a line break or the end of input.
+    //
+    // If the scanner may continue from the product state, the product
+    // becomes pending, and the scanner's loop checks the next byte on
+    // the next iteration. Otherwise, the scanner reads the next byte
+    // immediately.
+    //
+    // Returns a statement that either makes the product pending or reads
+    // the next byte, and an optional statement that submits the product.
+    fn compile_line_end(
+        &self,
+        variant: &TokenVariant,
+        buffering: bool,
+        transit: bool,
+    ) -> (TokenStream, Option<TokenStream>) {
+        let span = self.ident.span();
+        let core = span.face_core();
+        let string = span.face_string();
+        let ident = &variant.ident;
+
+        if transit {
+            let pending = match buffering {
+                false => quote_spanned!(span=> pending = Some((Self::#ident, count))),
+
+                true => quote_spanned!(span=>
+                    pending = Some((Self::#ident, count, #string::len(&buffer)))
+                ),
+            };
+
+            return (pending, None);
+        }
+
+        let length = buffering.then(|| quote_spanned!(span=> length = #string::len(&buffer);));
+
+        (
+            quote_spanned!(span=> let next = #core::lexis::LexisSession::advance(session)),
+            Some(quote_spanned!(span=>
+                if __line_end(session, next, count) {
+                    token = Self::#ident;
+
+                    #length
+                }
+            )),
+        )
+    }
+
+    // Declares the state of the end-of-line anchored products, and
+    // the statement of the scanner's loop that submits the pending product.
+    fn compile_line_end_check(&self, buffering: bool) -> Option<(TokenStream, TokenStream)> {
+        if !self.variants.iter().any(|variant| variant.line_end) {
+            return None;
+        }
+
+        let span = self.ident.span();
+        let core = span.face_core();
+
+        let (pending, binding, commit) = match buffering {
+            false => (quote_spanned!(span=> (Self, usize)), None, None),
+
+            true => (
+                quote_spanned!(span=> (Self, usize, usize)),
+                Some(quote_spanned!(span=> , pending_length)),
+                Some(quote_spanned!(span=> length = pending_length;)),
+            ),
+        };
+
+        let declarations = quote_spanned!(span=>
+            // Submits the token of `chars` characters if the `byte`
+            // following the token is a line break or the end of input.
+            #[inline(always)]
+            fn __line_end(
+                session: &mut impl #core::lexis::LexisSession,
+                byte: u8,
+                chars: usize,
+            ) -> bool {
+                if byte != b'\n' && byte != b'\r' && byte != 0xFF {
+                    return false;
+                }
+
+                #core::lexis::LexisSession::rewind(session);
+
+                for _ in 0..chars {
+                    if #core::lexis::LexisSession::advance(session) >= 0x80 {
+                        unsafe { #core::lexis::LexisSession::consume(session) }
+                    }
+                }
+
+                unsafe { #core::lexis::LexisSession::submit(session) }
+
+                true
+            }
+
+            #[allow(unused_mut)]
+            let mut count = 0usize;
+
+            #[allow(unused_mut)]
+            let mut pending = None::<#pending>;
+        );
+
+        let check = quote_spanned!(span=>
+            if let Some((pending_token, pending_count #binding)) = pending.take() {
+                if __line_end(session, byte, pending_count) {
+                    token = pending_token;
+
+                    #commit
+
+                    if byte != 0xFF {
+                        let _ = #core::lexis::LexisSession::advance(session);
+                    }
+                }
+            }
+
+            count += 1;
+        );
+
+        Some((declarations, check))
+    }
+
+    fn compile_constructors(&self) -> Option<TokenStream> {
+        let cases = self
+            .variants
+            .iter()
+            .filter_map(|variant| {
+                let constructor = variant.constructor.as_ref()?;
+
+                let span = constructor.span();
+                let string = span.face_string();
+                let ident = &self.ident;
+                let variant_ident = &variant.ident;
+
+                let keys = match &variant.captures {
+                    None => &[][..],
+                    Some(captures) => captures.keys.as_slice(),
+                };
+
+                let captures = variant.captures.as_ref().map(|captures| {
+                    let function = captures.compile(self, span);
+
+                    quote_spanned!(span=>
+                        #function
+
+                        let [#( #keys ),*] = __capture(fragment);
+                    )
+                });
+
+                Some(quote_spanned!(span=>
+                    Self::#variant_ident => {
+                        #[allow(unused)]
+                        #[inline(always)]
+                        fn __construct(fragment: &str #(, #keys: &str)*) -> #ident {
+                            #constructor
+                        }
+
+                        let fragment = &#string::as_str(&buffer)[..length];
+
+                        #captures
+
+                        token = __construct(fragment #(, #keys)*);
+                    }
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        if cases.is_empty() {
+            return None;
+        }
+
+        let span = self.ident.span();
+
+        Some(quote_spanned!(span=>
+            match token {
+                #(
+                #cases
+                )*
+
+                _ => (),
+            }
+        ))
+    }
+
+    fn compile_start(&self) -> TokenStream {
+        let span = self.ident.span();
+        let mismatch = &self.mismatch;
+
+        let start = |context: &TokenContext| match &context.automata {
+            Some(automata) => {
+                let start = automata.start();
+
+                quote_spanned!(span=> #start)
+            }
+
+            None => quote_spanned!(span=> return Self::#mismatch),
+        };
+
+        if let [context] = self.contexts.as_slice() {
+            return start(context);
+        }
+
+        let core = span.face_core();
+
+        let anchored = self.contexts.iter().any(|context| {
+            context
+                .previous
+                .iter()
+                .any(|(_, line_start)| line_start.is_some())
+        });
+
+        if !anchored {
+            let mut fallback = None;
+            let mut cases = Vec::with_capacity(self.contexts.len());
+
+            for context in &self.contexts {
+                if context
+                    .previous
+                    .iter()
+                    .any(|(previous, _)| previous == &self.eoi)
+                {
+                    fallback = Some(start(context));
+                    continue;
+                }
+
+                let previous = context.previous.iter().map(|(previous, _)| previous);
+                let target = start(context);
+
+                cases.push(quote_spanned!(span=>
+                    rule if #(rule == Self::#previous as u8)||* => #target,
+                ));
+            }
+
+            let fallback = expect_some!(fallback, "Missing End-of-Input context.",);
+
+            return quote_spanned!(span=>
+                match #core::lexis::LexisSession::previous(session) {
+                    #(
+                    #cases
+                    )*
+
+                    _ => #fallback,
+                }
+            );
+        }
+
+        // The contexts that depend on the line start are matched against
+        // the pair of the previous token and the line start flag.
+        let mut fallback = None;
+        let mut line_fallback = [None, None];
+        let mut cases = Vec::with_capacity(self.contexts.len() * 2);
+
+        for context in &self.contexts {
+            let target = start(context);
+
+            for line_start in [None, Some(false), Some(true)] {
+                let previous = context
+                    .previous
+                    .iter()
+                    .filter(|(_, flag)| flag == &line_start)
+                    .map(|(previous, _)| previous)
+                    .collect::<Vec<_>>();
+
+                if previous.is_empty() {
+                    continue;
+                }
+
+                if previous.contains(&&self.eoi) {
+                    match line_start {
+                        None => fallback = Some(target.clone()),
+                        Some(flag) => line_fallback[flag as usize] = Some(target.clone()),
+                    }
+
+                    continue;
+                }
+
+                let flag = match line_start {
+                    None => quote_spanned!(span=> _),
+                    Some(flag) => quote_spanned!(span=> #flag),
+                };
+
+                cases.push(quote_spanned!(span=>
+                    (rule, #flag) if #(rule == Self::#previous as u8)||* => #target,
+                ));
+            }
+        }
+
+        let fallback = match (fallback, line_fallback) {
+            (Some(fallback), _) => quote_spanned!(span=> _ => #fallback,),
+
+            (None, [Some(other), Some(line_start)]) => quote_spanned!(span=>
+                (_, true) => #line_start,
+                _ => #other,
+            ),
+
+            _ => system_panic!("Missing End-of-Input context."),
+        };
+
+        quote_spanned!(span=>
+            match (
+                #core::lexis::LexisSession::previous(session),
+                #core::lexis::LexisSession::line_start(session),
+            ) {
+                #(
+                #cases
+                )*
+
+                #fallback
+            }
+        )
+    }
+
+    fn compile_blank(&self) -> Option<TokenStream> {
+        if self.blank.is_empty() {
+            return None;
+        }
+
+        let span = self.ident.span();
+        let core = span.face_core();
+        let blank = self.blank.iter();
+
+        Some(quote_spanned!(span=>
+            const BLANK: #core::lexis::TokenSet = #core::lexis::TokenSet::inclusive(&[
+                #(Self::#blank as u8),*
+            ]);
+        ))
+    }
+
     fn compile_eoi_fn(&self) -> TokenStream {
         let eoi = &self.eoi;
         let span = eoi.span();
@@ -738,6 +1406,32 @@ impl TokenInput {
         )
     }
 
+    fn compile_from_rule_fn(&self) -> TokenStream {
+        let span = self.ident.span();
+        let core = span.face_core();
+        let option = span.face_option();
+
+        let variants = self.variants.iter().map(|variant| {
+            let ident = &variant.ident;
+            let span = ident.span();
+            let option = span.face_option();
+
+            quote_spanned!(span=>
+                if Self::#ident as u8 == rule {
+                    return #option::Some(Self::#ident);
+                }
+            )
+        });
+
+        quote_spanned!(span=>
+            fn from_rule(rule: #core::lexis::TokenRule) -> #option<Self> {
+                #(#variants)*
+
+                None
+            }
+        )
+    }
+
     fn compile_description_fn(&self) -> TokenStream {
         let span = self.ident.span();
         let core = span.face_core();
@@ -793,26 +1487,38 @@ impl ToTokens for TokenInput {
         let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
 
         let lookback = self.compile_lookback();
+        let blank = self.compile_blank();
         let parse = self.compile_parse_fn();
         let eoi = self.compile_eoi_fn();
         let mismatch = self.compile_mismatch_fn();
         let rule = self.compile_rule_fn();
         let name = self.compile_name_fn();
         let description = self.compile_description_fn();
+        let from_rule = self.compile_from_rule_fn();
 
-        quote_spanned!(span=>
+        let mut output = quote_spanned!(span=>
             impl #impl_generics #core::lexis::Token for #ident #ty_generics
             #where_clause
             {
                 #lookback
+                #blank
                 #parse
                 #eoi
                 #mismatch
                 #rule
                 #name
                 #description
+                #from_rule
             }
-        )
-        .to_tokens(tokens)
+        );
+
+        if let Dump::Hash(span) = self.dump {
+            let hash = stable_hash(&output);
+
+            compile_hash_impl(span, ident, &self.generics, &quote!(pub), hash)
+                .to_tokens(&mut output);
+        }
+
+        output.to_tokens(tokens)
     }
 }
@@ -46,6 +46,7 @@ use crate::{
         automata::{Scope, Terminal, TokenAutomata},
         chars::{CharSet, Class},
         input::{Alphabet, InlineMap, VariantMap},
+        ucd::Char,
     },
     utils::{
         dump_kw,
@@ -77,6 +78,10 @@ impl RegexImpl for Regex {
 
             Self::Operand(Operand::Dump(_, inner)) => inner.name(),
 
+            Self::Operand(Operand::Capture(_, inner)) => inner.name(),
+
+            Self::Operand(Operand::Anchor(_, _)) => Some(String::new()),
+
             Self::Operand(Operand::Transform(_, inner)) => {
                 let inner = expect_some!(inner, "Empty transformation.",);
 
@@ -106,6 +111,8 @@ impl RegexImpl for Regex {
 
             Self::Operand(Operand::Dump(_, inner)) => inner.transform(&TransformConfig::default()),
 
+            Self::Operand(Operand::Capture(_, inner)) => inner.transform(config),
+
             Self::Operand(Operand::Transform(feature, inner)) => {
                 let mut inner = expect_some!(take(inner), "Empty transformation.",);
 
@@ -126,6 +133,8 @@ impl RegexImpl for Regex {
                 config.transform_char_set(set);
             }
 
+            Self::Operand(Operand::Anchor(_, _)) => (),
+
             Self::Binary(left, _, right) => {
                 left.transform(config);
                 right.transform(config);
@@ -141,6 +150,8 @@ impl RegexImpl for Regex {
 
             Self::Operand(Operand::Dump(_, inner)) => inner.alphabet(),
 
+            Self::Operand(Operand::Capture(_, inner)) => inner.alphabet(),
+
             Self::Operand(Operand::Transform(_, _)) => {
                 system_panic!("Unresolved transformation.");
             }
@@ -149,6 +160,8 @@ impl RegexImpl for Regex {
 
             Self::Operand(Operand::Class(_, _)) => Set::empty(),
 
+            Self::Operand(Operand::Anchor(_, _)) => Set::empty(),
+
             Self::Operand(Operand::Exclusion(set)) => set
                 .classes
                 .clone()
@@ -171,6 +184,8 @@ impl RegexImpl for Regex {
 
             Self::Operand(Operand::Dump(_, inner)) => inner.expand(alphabet),
 
+            Self::Operand(Operand::Capture(_, inner)) => inner.expand(alphabet),
+
             Self::Operand(Operand::Transform(_, _)) => {
                 system_panic!("Unresolved transformation.");
             }
@@ -233,6 +248,8 @@ impl RegexImpl for Regex {
                 }
             }
 
+            Self::Operand(Operand::Anchor(_, _)) => (),
+
             Self::Binary(left, _, right) => {
                 left.expand(alphabet);
                 right.expand(alphabet);
@@ -339,6 +356,10 @@ impl RegexImpl for Regex {
                 inner.inline(inline_map, variant_map)?;
             }
 
+            Self::Operand(Operand::Capture(_, inner)) => {
+                inner.inline(inline_map, variant_map)?;
+            }
+
             Self::Operand(Operand::Transform(_, inner)) => {
                 let inner = expect_some!(inner, "Empty transformation.",);
 
@@ -349,6 +370,8 @@ impl RegexImpl for Regex {
 
             Self::Operand(Operand::Exclusion(_)) => (),
 
+            Self::Operand(Operand::Anchor(_, _)) => (),
+
             Self::Binary(left, _, right) => {
                 left.inline(inline_map, variant_map)?;
                 right.inline(inline_map, variant_map)?;
@@ -372,6 +395,10 @@ impl RegexImpl for Regex {
                 inner.set_span(span);
             }
 
+            Self::Operand(Operand::Capture(_, inner)) => {
+                inner.set_span(span);
+            }
+
             Self::Operand(Operand::Transform(_, inner)) => {
                 let inner = expect_some!(inner, "Empty transformation.",);
 
@@ -384,6 +411,8 @@ impl RegexImpl for Regex {
 
             Self::Operand(Operand::Exclusion(set)) => set.span = span,
 
+            Self::Operand(Operand::Anchor(op_span, _)) => *op_span = span,
+
             Self::Binary(left, _, right) => {
                 left.set_span(span);
                 right.set_span(span);
@@ -395,12 +424,43 @@ impl RegexImpl for Regex {
         }
     }
 
+    fn captures(&self, keys: &mut Vec<Ident>) {
+        match self {
+            Self::Operand(Operand::Capture(key, inner)) => {
+                if !keys.contains(key) {
+                    keys.push(key.clone());
+                }
+
+                inner.captures(keys);
+            }
+
+            Self::Operand(Operand::Dump(_, inner)) => inner.captures(keys),
+
+            Self::Operand(Operand::Transform(_, inner)) => {
+                if let Some(inner) = inner {
+                    inner.captures(keys);
+                }
+            }
+
+            Self::Operand(_) => (),
+
+            Self::Binary(left, _, right) => {
+                left.captures(keys);
+                right.captures(keys);
+            }
+
+            Self::Unary(_, inner) => inner.captures(keys),
+        }
+    }
+
     fn encode(&self, scope: &mut Scope) -> Result<TokenAutomata> {
         match self {
             Self::Operand(Operand::Unresolved(_)) => system_panic!("Unresolved operand."),
 
             Self::Operand(Operand::Exclusion(_)) => system_panic!("Unresolved exclusion."),
 
+            Self::Operand(Operand::Anchor(_, _)) => system_panic!("Unresolved anchor."),
+
             Self::Operand(Operand::Class(_, class)) => {
                 Ok(scope.terminal(Set::new([Terminal::Class(*class)])))
             }
@@ -422,6 +482,21 @@ impl RegexImpl for Regex {
                 system_panic!("Unresolved transformation.");
             }
 
+            Self::Operand(Operand::Capture(key, inner)) => {
+                let inner = inner.encode(scope)?;
+
+                let Some(tag) = scope.tag(key) else {
+                    return Ok(inner);
+                };
+
+                let open = scope.terminal(Set::new([Terminal::Open(tag)]));
+                let close = scope.terminal(Set::new([Terminal::Close(tag)]));
+
+                let inner = scope.concatenate(open, inner);
+
+                Ok(scope.concatenate(inner, close))
+            }
+
             Self::Binary(left, op, right) => {
                 let left = left.encode(scope)?;
                 let right = right.encode(scope)?;
@@ -445,6 +520,86 @@ impl RegexImpl for Regex {
             }
         }
     }
+
+    fn strip_anchor(self, anchor: Anchor) -> (Self, bool) {
+        let Self::Binary(left, Operator::Concat, right) = self else {
+            return (self, false);
+        };
+
+        match anchor {
+            Anchor::LineStart => match *left {
+                Self::Operand(Operand::Anchor(_, Anchor::LineStart)) => (*right, true),
+
+                left => {
+                    let (left, stripped) = left.strip_anchor(anchor);
+
+                    (
+                        Self::Binary(Box::new(left), Operator::Concat, right),
+                        stripped,
+                    )
+                }
+            },
+
+            Anchor::LineEnd => match *right {
+                Self::Operand(Operand::Anchor(_, Anchor::LineEnd)) => (*left, true),
+
+                right => {
+                    let (right, stripped) = right.strip_anchor(anchor);
+
+                    (
+                        Self::Binary(left, Operator::Concat, Box::new(right)),
+                        stripped,
+                    )
+                }
+            },
+        }
+    }
+
+    fn anchor(&self) -> Option<(Span, Anchor)> {
+        match self {
+            Self::Operand(Operand::Anchor(span, anchor)) => Some((*span, *anchor)),
+
+            Self::Operand(Operand::Dump(_, inner)) => inner.anchor(),
+
+            Self::Operand(Operand::Capture(_, inner)) => inner.anchor(),
+
+            Self::Operand(Operand::Transform(_, inner)) => inner.as_ref()?.anchor(),
+
+            Self::Operand(_) => None,
+
+            Self::Binary(left, _, right) => left.anchor().or_else(|| right.anchor()),
+
+            Self::Unary(_, inner) => inner.anchor(),
+        }
+    }
+
+    fn length(&self) -> Option<usize> {
+        match self {
+            Self::Operand(Operand::Unresolved(_)) => system_panic!("Unresolved operand."),
+
+            Self::Operand(Operand::Transform(_, _)) => {
+                system_panic!("Unresolved transformation.");
+            }
+
+            Self::Operand(Operand::Dump(_, inner)) => inner.length(),
+
+            Self::Operand(Operand::Capture(_, inner)) => inner.length(),
+
+            Self::Operand(Operand::Anchor(_, _)) => Some(0),
+
+            Self::Operand(Operand::Class(_, _) | Operand::Exclusion(_)) => Some(1),
+
+            Self::Binary(left, Operator::Concat, right) => {
+                left.length()?.checked_add(right.length()?)
+            }
+
+            Self::Binary(left, _, right) => Some(left.length()?.max(right.length()?)),
+
+            Self::Unary(Operator::Optional, inner) => inner.length(),
+
+            Self::Unary(_, _) => None,
+        }
+    }
 }
 
 pub(super) trait RegexImpl {
@@ -460,7 +615,30 @@ pub(super) trait RegexImpl {
 
     fn set_span(&mut self, span: Span);
 
+    // Collects the keys of the `<key>: <expr>` captures in the order of
+    // their first appearance.
+    fn captures(&self, keys: &mut Vec<Ident>);
+
     fn encode(&self, scope: &mut Scope) -> Result<TokenAutomata>;
+
+    // Removes the `anchor` from the beginning (or from the end) of
+    // the concatenation chain. Returns true if the anchor has been removed.
+    fn strip_anchor(self, anchor: Anchor) -> (Self, bool)
+    where
+        Self: Sized;
+
+    // Returns the first anchor found in the expression.
+    fn anchor(&self) -> Option<(Span, Anchor)>;
+
+    // Returns the maximum number of characters the expression could match,
+    // or None if the expression matches strings of unlimited length.
+    fn length(&self) -> Option<usize>;
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(super) enum Anchor {
+    LineStart,
+    LineEnd,
 }
 
 #[derive(Clone)]
@@ -535,18 +713,21 @@ impl TransformConfig {
             return Set::empty();
         }
 
-        let mut uppercase = ch.to_uppercase().collect::<Set<char>>();
-        let lowercase = ch.to_lowercase().collect::<Set<char>>();
+        // The full case mappings may map a character to several characters,
+        // which cannot be matched by a single character class. Only
+        // the simple one-to-one mappings are taken into account.
+        let variants = Set::new([
+            ch,
+            ch.to_lower_simple(),
+            ch.to_title_simple(),
+            ch.to_upper_simple(),
+        ]);
 
-        if uppercase == lowercase {
+        if variants.len() == 1 {
             return Set::empty();
         }
 
-        uppercase.append(lowercase);
-
-        let _ = uppercase.insert(ch);
-
-        uppercase
+        variants
     }
 }
 
@@ -634,12 +815,23 @@ pub(super) enum Operand {
     Transform(TransformFeature, Option<Box<Regex>>),
     Class(Span, Class),
     Exclusion(CharSet),
+    Capture(Ident, Box<Regex>),
+    Anchor(Span, Anchor),
 }
 
 impl ExpressionOperand<Operator> for Operand {
     fn parse(input: ParseStream) -> Result<Regex> {
         let lookahead = input.lookahead1();
 
+        // A standalone dollar sign is the end-of-line anchor. Otherwise,
+        // the dollar sign starts a property class (e.g., `$alpha`).
+        if lookahead.peek(Token![$]) && !input.peek2(syn::Ident) && !input.peek2(syn::token::Brace)
+        {
+            let span = input.parse::<Token![$]>()?.span;
+
+            return Ok(Regex::Operand(Operand::Anchor(span, Anchor::LineEnd)));
+        }
+
         if CharSet::peek(&lookahead) {
             let set = input.parse::<CharSet>()?;
 
@@ -649,23 +841,7 @@ impl ExpressionOperand<Operator> for Operand {
         }
 
         if lookahead.peek(syn::LitStr) {
-            let lit = input.parse::<LitStr>()?;
-            let string = lit.value();
-            let span = lit.span();
-
-            return string
-                .chars()
-                .fold(None, |accumulator, ch| {
-                    let right = Regex::Operand(Operand::Class(span, Class::Char(ch)));
-
-                    Some(match accumulator {
-                        None => right,
-                        Some(left) => {
-                            Regex::Binary(Box::new(left), Operator::Concat, Box::new(right))
-                        }
-                    })
-                })
-                .ok_or_else(|| error!(lit.span(), "Empty strings forbidden.",));
+            return Self::parse_string(input);
         }
 
         if lookahead.peek(Token![.]) {
@@ -683,7 +859,14 @@ impl ExpressionOperand<Operator> for Operand {
         }
 
         if lookahead.peek(Token![^]) {
-            let _ = input.parse::<Token![^]>()?;
+            let span = input.parse::<Token![^]>()?.span;
+
+            // The caret in front of the brackets is an exclusion set.
+            // Otherwise, the caret is the start-of-line anchor.
+            if !input.peek(syn::token::Bracket) {
+                return Ok(Regex::Operand(Operand::Anchor(span, Anchor::LineStart)));
+            }
+
             let set = CharSet::parse_brackets(input, true)?;
 
             return Ok(Regex::Operand(Operand::Exclusion(set)));
@@ -708,14 +891,23 @@ impl ExpressionOperand<Operator> for Operand {
         if lookahead.peek(functions_kw::i) {
             let _ = input.parse::<functions_kw::i>()?;
 
-            let content;
-            parenthesized!(content in input);
+            // The `i "foo"` form is a shorthand for `i("foo")`.
+            let inner = match input.peek(syn::LitStr) {
+                true => Self::parse_string(input)?,
 
-            let inner = content.parse::<Regex>()?;
+                false => {
+                    let content;
+                    parenthesized!(content in input);
 
-            if !content.is_empty() {
-                return Err(content.error("Unexpected expression end."));
-            }
+                    let inner = content.parse::<Regex>()?;
+
+                    if !content.is_empty() {
+                        return Err(content.error("Unexpected expression end."));
+                    }
+
+                    inner
+                }
+            };
 
             return Ok(Regex::Operand(Operand::Transform(
                 TransformFeature::CaseInsensitive,
@@ -726,6 +918,18 @@ impl ExpressionOperand<Operator> for Operand {
         if lookahead.peek(syn::Ident) {
             let ident = input.parse::<Ident>()?;
 
+            if input.peek(Token![:]) && !input.peek(Token![::]) {
+                let _ = input.parse::<Token![:]>()?;
+
+                if ident == "fragment" {
+                    return Err(error!(ident.span(), "This word is reserved.",));
+                }
+
+                let inner = Self::parse(input)?;
+
+                return Ok(Regex::Operand(Operand::Capture(ident, Box::new(inner))));
+            }
+
             return Ok(Regex::Operand(Operand::Unresolved(ident)));
         }
 
@@ -784,6 +988,26 @@ impl ExpressionOperand<Operator> for Operand {
     }
 }
 
+impl Operand {
+    fn parse_string(input: ParseStream) -> Result<Regex> {
+        let lit = input.parse::<LitStr>()?;
+        let string = lit.value();
+        let span = lit.span();
+
+        string
+            .chars()
+            .fold(None, |accumulator, ch| {
+                let right = Regex::Operand(Operand::Class(span, Class::Char(ch)));
+
+                Some(match accumulator {
+                    None => right,
+                    Some(left) => Regex::Binary(Box::new(left), Operator::Concat, Box::new(right)),
+                })
+            })
+            .ok_or_else(|| error!(lit.span(), "Empty strings forbidden.",))
+    }
+}
+
 mod functions_kw {
     syn::custom_keyword!(i);
 }
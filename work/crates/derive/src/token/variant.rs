@@ -44,6 +44,8 @@ use syn::{spanned::Spanned, AttrStyle, Error, Expr, ExprLit, Lit, LitInt, Result
 use crate::{
     token::{
         automata::TokenAutomata,
+        capture::TokenCaptures,
+        guard::Guard,
         regex::{Regex, RegexImpl},
     },
     utils::{error, Description},
@@ -59,8 +61,12 @@ pub(super) struct TokenVariant {
     pub(super) index: Option<u8>,
     pub(super) rule: Option<(Span, Regex)>,
     pub(super) automata: Option<TokenAutomata>,
+    pub(super) captures: Option<TokenCaptures>,
     pub(super) constructor: Option<Expr>,
     pub(super) priority: isize,
+    pub(super) guard: Option<Guard>,
+    pub(super) line_start: bool,
+    pub(super) line_end: bool,
     pub(super) description: Description,
     pub(super) time: Duration,
 }
@@ -100,6 +106,7 @@ impl TryFrom<Variant> for TokenVariant {
         let mut constructor = None;
         let mut description = Description::Unset;
         let mut priority = None;
+        let mut guard = None;
         let mut time = Duration::default();
 
         for attr in take(&mut variant.attrs) {
@@ -150,6 +157,16 @@ impl TryFrom<Variant> for TokenVariant {
                     priority = Some((span, attr.parse_args::<LitInt>()?.base10_parse::<isize>()?));
                 }
 
+                "after" | "not_after" => {
+                    if guard.is_some() {
+                        return Err(error!(span, "Duplicate Guard attribute.",));
+                    }
+
+                    let negative = name == "not_after";
+
+                    guard = Some(Guard::parse(attr, negative)?);
+                }
+
                 "dump" => {
                     return Err(error!(span, "Dump attribute is not applicable here.",));
                 }
@@ -225,13 +242,28 @@ impl TryFrom<Variant> for TokenVariant {
             }
         };
 
+        if let Some(guard) = &guard {
+            if rule.is_none() {
+                return Err(error!(
+                    guard.span,
+                    "Guard attribute is not applicable to unparseable \
+                    variants.\nTo make the variant parsable annotate this \
+                    variant with #[rule(...)] attribute.",
+                ));
+            }
+        }
+
         Ok(Self {
             ident,
             index,
             rule,
             automata: None,
+            captures: None,
             constructor,
             priority,
+            guard,
+            line_start: false,
+            line_end: false,
             description,
             time,
         })
@@ -46,6 +46,8 @@ pub enum Dump {
     Meta(Span),
     Dry(Span),
     Decl(Span),
+    Hash(Span),
+    Ebnf(Span),
 }
 
 impl TryFrom<Attribute> for Dump {
@@ -85,6 +87,14 @@ impl TryFrom<Attribute> for Dump {
                 return Ok(Self::Decl(input.parse::<dump_kw::decl>()?.span()));
             }
 
+            if lookahead.peek(dump_kw::hash) {
+                return Ok(Self::Hash(input.parse::<dump_kw::hash>()?.span()));
+            }
+
+            if lookahead.peek(dump_kw::ebnf) {
+                return Ok(Self::Ebnf(input.parse::<dump_kw::ebnf>()?.span()));
+            }
+
             return Err(lookahead.error());
         })
     }
@@ -100,6 +110,8 @@ impl Dump {
             Self::Meta(span) => Some(span),
             Self::Dry(span) => Some(span),
             Self::Decl(span) => Some(span),
+            Self::Hash(span) => Some(span),
+            Self::Ebnf(span) => Some(span),
         }
     }
 
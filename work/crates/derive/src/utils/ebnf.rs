@@ -0,0 +1,205 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{
+    env::var_os,
+    fmt::{Display, Formatter},
+    fs::{create_dir_all, write},
+    path::PathBuf,
+};
+
+use proc_macro2::{Ident, Span};
+use syn::Result;
+
+use crate::utils::error;
+
+// The environment variable that points to the directory where the macros
+// write the grammar descriptions in the `#[dump(ebnf)]` mode.
+const DUMP_DIR: &str = "LADY_DEIRDRE_DUMP_DIR";
+
+// A grammar description in the W3C EBNF notation.
+#[derive(Default)]
+pub struct Ebnf {
+    text: String,
+}
+
+impl Ebnf {
+    pub fn comment(&mut self, comment: impl Display) {
+        self.text += &format!("/* {comment} */\n");
+    }
+
+    pub fn production(&mut self, name: impl Display, expr: EbnfExpr) {
+        self.text += &format!("{name} ::= {expr}\n\n");
+    }
+
+    #[inline(always)]
+    pub fn blank_line(&mut self) {
+        self.text.push('\n');
+    }
+
+    // Writes the description to the "<ident>.ebnf" file of the dump directory.
+    pub fn write(self, span: Span, ident: &Ident) -> Result<()> {
+        let Some(dir) = var_os(DUMP_DIR) else {
+            return Err(error!(
+                span,
+                "EBNF dump requires the {DUMP_DIR} environment \
+                variable.\nSet this variable to the directory where the macro \
+                should write the \"{ident}.ebnf\" file.",
+            ));
+        };
+
+        let dir = PathBuf::from(dir);
+
+        if let Err(io_error) = create_dir_all(&dir) {
+            return Err(error!(
+                span,
+                "Failed to create EBNF dump directory {}: {io_error}.",
+                dir.display(),
+            ));
+        }
+
+        let path = dir.join(format!("{ident}.ebnf"));
+
+        let mut text = self.text;
+
+        text.truncate(text.trim_end().len());
+        text.push('\n');
+
+        if let Err(io_error) = write(&path, text) {
+            return Err(error!(
+                span,
+                "Failed to write EBNF dump file {}: {io_error}.",
+                path.display(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+// A right-hand side expression of the EBNF production.
+pub struct EbnfExpr {
+    text: String,
+    precedence: Precedence,
+}
+
+impl Display for EbnfExpr {
+    #[inline(always)]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(&self.text)
+    }
+}
+
+impl EbnfExpr {
+    #[inline(always)]
+    pub fn primary(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            precedence: Precedence::Primary,
+        }
+    }
+
+    pub fn union(items: impl IntoIterator<Item = Self>) -> Self {
+        Self::join(items, " | ", Precedence::Union)
+    }
+
+    pub fn concat(items: impl IntoIterator<Item = Self>) -> Self {
+        Self::join(items, " ", Precedence::Concat)
+    }
+
+    #[inline(always)]
+    pub fn optional(self) -> Self {
+        self.postfix('?')
+    }
+
+    #[inline(always)]
+    pub fn one_or_more(self) -> Self {
+        self.postfix('+')
+    }
+
+    #[inline(always)]
+    pub fn zero_or_more(self) -> Self {
+        self.postfix('*')
+    }
+
+    fn join(
+        items: impl IntoIterator<Item = Self>,
+        separator: &str,
+        precedence: Precedence,
+    ) -> Self {
+        let mut items = items.into_iter().collect::<Vec<_>>();
+
+        if items.len() == 1 {
+            if let Some(item) = items.pop() {
+                return item;
+            }
+        }
+
+        let text = items
+            .into_iter()
+            .map(|item| item.group(precedence))
+            .collect::<Vec<_>>()
+            .join(separator);
+
+        Self { text, precedence }
+    }
+
+    // The W3C notation does not allow repetition of the repetition (`a+?`),
+    // so the operand of the postfix operator must be a primary expression.
+    fn postfix(self, operator: char) -> Self {
+        let mut text = self.group(Precedence::Primary);
+
+        text.push(operator);
+
+        Self {
+            text,
+            precedence: Precedence::Postfix,
+        }
+    }
+
+    fn group(self, precedence: Precedence) -> String {
+        match self.precedence >= precedence {
+            true => self.text,
+            false => format!("({})", self.text),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Precedence {
+    Union,
+    Concat,
+    Postfix,
+    Primary,
+}
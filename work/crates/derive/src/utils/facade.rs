@@ -47,63 +47,63 @@ pub trait Facade: Spanned {
     fn face_string(&self) -> TokenStream {
         let span = self.span();
 
-        quote_spanned!(span=> ::std::string::String)
+        quote_spanned!(span=> ::lady_deirdre::export::String)
     }
 
     #[inline(always)]
     fn face_vec(&self) -> TokenStream {
         let span = self.span();
 
-        quote_spanned!(span=> ::std::vec::Vec)
+        quote_spanned!(span=> ::lady_deirdre::export::Vec)
     }
 
     #[inline(always)]
     fn face_option(&self) -> TokenStream {
         let span = self.span();
 
-        quote_spanned!(span=> ::std::option::Option)
+        quote_spanned!(span=> ::core::option::Option)
     }
 
     #[inline(always)]
     fn face_result(&self) -> TokenStream {
         let span = self.span();
 
-        quote_spanned!(span=> ::std::result::Result)
+        quote_spanned!(span=> ::core::result::Result)
     }
 
     #[inline(always)]
     fn face_from(&self) -> TokenStream {
         let span = self.span();
 
-        quote_spanned!(span=> ::std::convert::From)
+        quote_spanned!(span=> ::core::convert::From)
     }
 
     #[inline(always)]
     fn face_default(&self) -> TokenStream {
         let span = self.span();
 
-        quote_spanned!(span=> ::std::default::Default)
+        quote_spanned!(span=> ::core::default::Default)
     }
 
     #[inline(always)]
     fn face_unimplemented(&self) -> TokenStream {
         let span = self.span();
 
-        quote_spanned!(span=> ::std::unimplemented!)
+        quote_spanned!(span=> ::core::unimplemented!)
     }
 
     #[inline(always)]
     fn face_unreachable(&self) -> TokenStream {
         let span = self.span();
 
-        quote_spanned!(span=> ::std::unreachable!)
+        quote_spanned!(span=> ::core::unreachable!)
     }
 
     #[inline(always)]
     fn face_panic(&self) -> TokenStream {
         let span = self.span();
 
-        quote_spanned!(span=> ::std::panic!)
+        quote_spanned!(span=> ::core::panic!)
     }
 }
 
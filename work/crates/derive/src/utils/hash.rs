@@ -0,0 +1,128 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use proc_macro2::{Delimiter, Literal, Spacing, Span, TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::Generics;
+
+const FNV_OFFSET: u64 = 0xCBF29CE484222325;
+const FNV_PRIME: u64 = 0x100000001B3;
+
+// Computes a content hash of the macro output that does not depend on
+// the Rust spans, the compiler version, or the platform.
+//
+// The stream is canonicalized by walking the token trees and writing their
+// textual forms with explicit group delimiters and punctuation spacing.
+// The generator itself produces the items in a predictable order
+// (see PredictableHasher), so the equal macro inputs produce equal hashes.
+pub fn stable_hash(stream: &TokenStream) -> u64 {
+    let mut hasher = StableHasher(FNV_OFFSET);
+
+    hasher.write_stream(stream);
+
+    hasher.0
+}
+
+// Generates an inherent implementation with the DERIVE_HASH constant
+// that holds the `hash` value.
+pub fn compile_hash_impl(
+    span: Span,
+    ident: &impl ToTokens,
+    generics: &Generics,
+    vis: &impl ToTokens,
+    hash: u64,
+) -> TokenStream {
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+    let hash = Literal::u64_suffixed(hash);
+
+    quote_spanned!(span=>
+        impl #impl_generics #ident #type_generics #where_clause {
+            /// A stable hash of the derive macro's generated implementation.
+            ///
+            /// The value changes whenever the macro generates different code
+            /// for this type.
+            #vis const DERIVE_HASH: u64 = #hash;
+        }
+    )
+}
+
+struct StableHasher(u64);
+
+impl StableHasher {
+    fn write_stream(&mut self, stream: &TokenStream) {
+        for tree in stream.clone() {
+            match tree {
+                TokenTree::Group(group) => {
+                    let (open, close) = match group.delimiter() {
+                        Delimiter::Parenthesis => ("(", ")"),
+                        Delimiter::Brace => ("{", "}"),
+                        Delimiter::Bracket => ("[", "]"),
+                        Delimiter::None => ("\u{2}", "\u{3}"),
+                    };
+
+                    self.write_str(open);
+                    self.write_stream(&group.stream());
+                    self.write_str(close);
+                }
+
+                TokenTree::Ident(ident) => {
+                    self.write_str(&ident.to_string());
+                    self.write_str(" ");
+                }
+
+                TokenTree::Punct(punct) => {
+                    self.write_str(punct.as_char().encode_utf8(&mut [0; 4]));
+
+                    if let Spacing::Alone = punct.spacing() {
+                        self.write_str(" ");
+                    }
+                }
+
+                TokenTree::Literal(literal) => {
+                    self.write_str(&literal.to_string());
+                    self.write_str(" ");
+                }
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn write_str(&mut self, string: &str) {
+        for byte in string.as_bytes() {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+}
@@ -37,8 +37,10 @@ mod context;
 mod description;
 mod deterministic;
 mod dump;
+mod ebnf;
 mod expression;
 mod facade;
+mod hash;
 mod map;
 mod predictable;
 mod report;
@@ -52,8 +54,10 @@ pub use crate::utils::{
     context::{AutomataContext, AutomataTerminal, State, Strategy},
     description::Description,
     dump::Dump,
+    ebnf::{Ebnf, EbnfExpr},
     expression::{Applicability, Expression, ExpressionOperand, ExpressionOperator},
     facade::Facade,
+    hash::{compile_hash_impl, stable_hash},
     map::Map,
     predictable::PredictableCollection,
     set::{Set, SetImpl},
@@ -65,5 +69,7 @@ pub mod dump_kw {
     syn::custom_keyword!(meta);
     syn::custom_keyword!(dry);
     syn::custom_keyword!(decl);
+    syn::custom_keyword!(hash);
+    syn::custom_keyword!(ebnf);
     syn::custom_keyword!(dump);
 }
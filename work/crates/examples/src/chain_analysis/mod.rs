@@ -39,28 +39,41 @@ pub mod syntax;
 #[cfg(test)]
 mod tests {
     use std::{
+        any::type_name,
+        borrow::Cow,
         fmt::{Display, Formatter},
-        ops::Deref,
+        iter,
+        ops::{Deref, Range},
+        sync::Mutex,
+        thread,
+        time::Instant,
     };
 
     use lady_deirdre::{
         analysis::{
             AbstractTask,
+            AnalysisError,
             AnalysisTask,
             Analyzer,
             AnalyzerConfig,
+            Event,
             MutationAccess,
             TriggerHandle,
+            CUSTOM_EVENT_START_RANGE,
+            DOC_ERRORS_EVENT,
+            DOC_REMOVED_EVENT,
+            DOC_UPDATED_EVENT,
         },
-        arena::Identifiable,
+        arena::{Id, Identifiable},
         format::{AnnotationPriority, SnippetFormatter},
-        lexis::Position,
-        syntax::{PolyRef, SyntaxTree},
-        units::Document,
+        lexis::{Position, SourceCode, ToSpan},
+        sync::Shared,
+        syntax::{NodeRef, PolyRef, SyntaxTree},
+        units::{CompilationUnit, Document, Instrumentation, InstrumentationSink},
     };
 
     use crate::chain_analysis::{
-        semantics::{ChainNodeClass, GlobalResolution},
+        semantics::{ChainNodeClass, GlobalResolution, LocalResolution},
         syntax::ChainNode,
     };
 
@@ -147,6 +160,629 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_chain_batch_write() {
+        static INPUT: &'static str = r#"
+        {
+            x = 100;
+
+            {
+                y = x;
+            }
+        }"#;
+
+        let site = INPUT.find("100").unwrap();
+
+        let edits = (0..100)
+            .map(|index| (index % 10, site..site + 3, format!("{}", 100 + index)))
+            .collect::<Vec<_>>();
+
+        let single = Analyzer::<ChainNode>::new(AnalyzerConfig::default());
+        let batched = Analyzer::<ChainNode>::new(AnalyzerConfig::default());
+
+        let single_docs;
+        let batched_docs;
+
+        {
+            let handle = TriggerHandle::new();
+
+            let mut single_task = single.mutate(&handle, 1).unwrap();
+            let mut batched_task = batched.mutate(&handle, 1).unwrap();
+
+            single_docs = (0..10)
+                .map(|_| single_task.add_mutable_doc(INPUT))
+                .collect::<Vec<_>>();
+
+            batched_docs = (0..10)
+                .map(|_| batched_task.add_mutable_doc(INPUT))
+                .collect::<Vec<_>>();
+
+            let time = Instant::now();
+
+            for (doc, span, text) in &edits {
+                single_task
+                    .write_to_doc(single_docs[*doc], span.clone(), text)
+                    .unwrap();
+            }
+
+            let single_time = time.elapsed();
+
+            let time = Instant::now();
+
+            batched_task
+                .write_batch(edits.iter().map(|(doc, span, text)| {
+                    (
+                        batched_docs[*doc],
+                        span.clone(),
+                        Cow::Borrowed(text.as_str()),
+                    )
+                }))
+                .unwrap();
+
+            let batched_time = time.elapsed();
+
+            println!("100 single writes: {single_time:?}. One batched write: {batched_time:?}.");
+
+            let immutable = batched_task.add_immutable_doc(INPUT);
+
+            assert_eq!(
+                batched_task.write_batch([(immutable, 0..0, Cow::Borrowed(" "))]),
+                Err(AnalysisError::ImmutableDocument),
+            );
+        }
+
+        let handle = TriggerHandle::new();
+
+        let single_task = single.analyze(&handle, 1).unwrap();
+        let batched_task = batched.analyze(&handle, 1).unwrap();
+
+        for (single_doc, batched_doc) in single_docs.into_iter().zip(batched_docs) {
+            let single_read = single_task.read_doc(single_doc).unwrap();
+            let batched_read = batched_task.read_doc(batched_doc).unwrap();
+
+            assert_eq!(single_read.substring(..), batched_read.substring(..));
+
+            assert_eq!(
+                DisplayValues {
+                    doc: single_read.deref(),
+                    task: &single_task,
+                }
+                .to_string(),
+                DisplayValues {
+                    doc: batched_read.deref(),
+                    task: &batched_task,
+                }
+                .to_string(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_chain_subscription() {
+        static INPUT: &'static str = r#"
+        {
+            x = 100;
+        }"#;
+
+        const STEPS: Event = 100;
+
+        let analyzer = Analyzer::<ChainNode>::new(AnalyzerConfig::default());
+
+        let doc_id = {
+            let handle = TriggerHandle::new();
+
+            let mut task = analyzer.mutate(&handle, 1).unwrap();
+
+            task.add_mutable_doc(INPUT)
+        };
+
+        let receiver = analyzer.subscribe(doc_id);
+        let everything = analyzer.subscribe(Id::nil());
+
+        assert!(!receiver.is_closed());
+        assert!(analyzer.subscribe(Id::new()).is_closed());
+
+        let site = INPUT.find("100").unwrap();
+
+        let observed = thread::scope(|scope| {
+            let _ = scope.spawn(|| {
+                let handle = TriggerHandle::new();
+
+                let mut task = analyzer.mutate(&handle, 1).unwrap();
+
+                // Each write toggles the syntax error of the variable's
+                // initializer, and is followed by a custom checkpoint event.
+                for step in 0..STEPS {
+                    match step % 2 == 0 {
+                        true => task.write_to_doc(doc_id, site..site + 3, "").unwrap(),
+                        false => task.write_to_doc(doc_id, site..site, "100").unwrap(),
+                    }
+
+                    task.trigger_event(doc_id, CUSTOM_EVENT_START_RANGE + step);
+                }
+
+                assert!(task.remove_doc(doc_id));
+            });
+
+            let mut observed = Vec::new();
+
+            while let Some(item) = receiver.recv() {
+                observed.push(item);
+            }
+
+            observed
+        });
+
+        assert!(receiver.is_closed());
+        assert!(receiver.try_recv().is_none());
+
+        assert!(observed.iter().all(|(id, _, _)| *id == doc_id));
+        assert!(matches!(observed.last(), Some((_, DOC_REMOVED_EVENT, _))));
+
+        // The checkpoints are distinct events, so none of them is coalesced.
+
+        let checkpoints = observed
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, event, _))| *event >= CUSTOM_EVENT_START_RANGE)
+            .map(|(index, (_, event, revision))| {
+                (index, *event - CUSTOM_EVENT_START_RANGE, *revision)
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(checkpoints.len(), STEPS as usize);
+
+        // Every write precedes its checkpoint, and follows the previous
+        // checkpoint. Therefore, the receiver observes each write's events
+        // before the write's checkpoint with the revision not older than
+        // the write's revision.
+
+        let mut previous = 0;
+
+        for (step, (index, checkpoint, revision)) in checkpoints.into_iter().enumerate() {
+            assert_eq!(checkpoint as usize, step);
+
+            for expected in [DOC_UPDATED_EVENT, DOC_ERRORS_EVENT] {
+                let covered = observed[..index]
+                    .iter()
+                    .any(|(_, event, observed)| *event == expected && *observed > previous);
+
+                assert!(covered);
+            }
+
+            previous = revision;
+        }
+
+        // The nil receiver observes the events of all documents.
+
+        let mut everything = iter::from_fn(|| everything.try_recv()).collect::<Vec<_>>();
+
+        everything.sort();
+        everything.dedup_by_key(|(id, event, _)| (*id, *event));
+
+        assert_eq!(everything.len(), STEPS as usize + 3);
+    }
+
+    #[test]
+    fn test_chain_classification() {
+        struct ClassifySink(Mutex<Vec<NodeRef>>);
+
+        impl InstrumentationSink for ClassifySink {
+            fn on_classify(&self, node_ref: &NodeRef, skipped: bool) {
+                if !skipped {
+                    self.0.lock().unwrap().push(*node_ref);
+                }
+            }
+        }
+
+        static SINK: ClassifySink = ClassifySink(Mutex::new(Vec::new()));
+
+        static INPUT: &'static str = r#"
+        {
+            {
+                a = 1;
+                b = a;
+            }
+
+            {
+                c = 2;
+                d = c;
+            }
+        }"#;
+
+        let mut config = AnalyzerConfig::new();
+
+        config.instrumentation = Instrumentation::new(&SINK);
+
+        let analyzer = Analyzer::<ChainNode>::new(config);
+
+        let handle = TriggerHandle::new();
+
+        let mut task = analyzer.mutate(&handle, 1).unwrap();
+
+        let doc_id = task.add_mutable_doc(INPUT);
+
+        let stats = task.classification_stats(doc_id).unwrap();
+
+        assert_eq!(stats.classified, SINK.0.lock().unwrap().len());
+        assert_eq!(stats.skipped, 0);
+        assert_eq!(stats.added.get(&ChainNodeClass::AllKeys), Some(&4));
+        assert_eq!(stats.total_removed(), 0);
+
+        SINK.0.lock().unwrap().clear();
+
+        task.write_to_doc(doc_id, Position::new(9, 21)..Position::new(9, 22), "3")
+            .unwrap();
+
+        let stats = task.classification_stats(doc_id).unwrap();
+        let classified = SINK.0.lock().unwrap().clone();
+
+        assert!(stats.classified > 0);
+        assert_eq!(stats.classified, classified.len());
+        assert_eq!(stats.total_added(), stats.total_removed());
+
+        let doc_read = task.read_doc(doc_id).unwrap();
+
+        let second_block = doc_read.substring(..).rfind('{').unwrap();
+
+        for node_ref in classified {
+            let Some(span) = node_ref.span(doc_read.deref()) else {
+                continue;
+            };
+
+            assert!(
+                span.start >= second_block,
+                "Node {:#} outside of the edited block has been reclassified.",
+                node_ref.display(doc_read.deref()),
+            );
+        }
+
+        let all_keys = task
+            .snapshot_class(doc_id, &ChainNodeClass::AllKeys)
+            .unwrap();
+
+        assert_eq!(all_keys.as_ref().len(), 4);
+    }
+
+    #[test]
+    fn test_chain_profile() {
+        static INPUT: &'static str = r#"
+        {
+            x = 1;
+
+            {
+                y = x;
+            }
+        }"#;
+
+        let mut config = AnalyzerConfig::new();
+
+        config.profile = true;
+
+        let analyzer = Analyzer::<ChainNode>::new(config);
+
+        let doc_id;
+
+        {
+            let handle = TriggerHandle::new();
+
+            let mut task = analyzer.mutate(&handle, 1).unwrap();
+
+            doc_id = task.add_mutable_doc(INPUT);
+        }
+
+        assert!(analyzer.profile_report().is_empty());
+
+        for _ in 0..2 {
+            let handle = TriggerHandle::new();
+
+            let task = analyzer.analyze(&handle, 1).unwrap();
+
+            let doc_read = task.read_doc(doc_id).unwrap();
+
+            let _ = DisplayValues {
+                doc: doc_read.deref(),
+                task: &task,
+            }
+            .to_string();
+        }
+
+        let report = analyzer.profile_report();
+
+        let global = report
+            .entries
+            .iter()
+            .find(|entry| entry.name == type_name::<GlobalResolution>())
+            .unwrap();
+
+        assert_eq!(global.calls, 2);
+        assert!(global.own <= global.total);
+        assert!(global.max <= global.total);
+
+        let local = report
+            .edges
+            .iter()
+            .find(|edge| {
+                edge.parent == type_name::<GlobalResolution>()
+                    && edge.child == type_name::<Shared<LocalResolution>>()
+            })
+            .unwrap();
+
+        assert_eq!(local.calls, 2);
+
+        assert!(report
+            .entries
+            .windows(2)
+            .all(|pair| pair[0].total >= pair[1].total));
+
+        assert!(report.stacks.iter().any(|stack| {
+            stack.frames
+                == [
+                    type_name::<GlobalResolution>(),
+                    type_name::<Shared<LocalResolution>>(),
+                ]
+        }));
+
+        let folded = report.folded();
+
+        assert_eq!(folded.lines().count(), report.stacks.len());
+
+        for line in folded.lines() {
+            let (frames, time) = line.rsplit_once(' ').unwrap();
+
+            assert!(!frames.is_empty());
+            assert!(time.parse::<u128>().is_ok());
+        }
+
+        let analyzer = Analyzer::<ChainNode>::new(AnalyzerConfig::new());
+
+        {
+            let handle = TriggerHandle::new();
+
+            let mut task = analyzer.mutate(&handle, 1).unwrap();
+
+            let _ = task.add_mutable_doc(INPUT);
+        }
+
+        assert!(analyzer.profile_report().is_empty());
+    }
+
+    #[test]
+    fn test_chain_shrink() {
+        static INPUT: &'static str = r#"
+        {
+            x = 1;
+
+            {
+                y = x;
+
+                {
+                    z = y;
+                }
+            }
+        }"#;
+
+        const DOCS: usize = 8;
+        const CAPACITY: usize = 10;
+
+        let mut config = AnalyzerConfig::new();
+
+        config.profile = true;
+        config.memo_capacity = Some(CAPACITY);
+
+        let analyzer = Analyzer::<ChainNode>::new(config);
+
+        let mut doc_ids = Vec::with_capacity(DOCS);
+
+        {
+            let handle = TriggerHandle::new();
+
+            let mut task = analyzer.mutate(&handle, 1).unwrap();
+
+            for _ in 0..DOCS {
+                doc_ids.push(task.add_mutable_doc(INPUT));
+            }
+        }
+
+        let display_all = || {
+            let handle = TriggerHandle::new();
+
+            let task = analyzer.analyze(&handle, 1).unwrap();
+
+            doc_ids
+                .iter()
+                .map(|doc_id| {
+                    let doc_read = task.read_doc(*doc_id).unwrap();
+
+                    DisplayValues {
+                        doc: doc_read.deref(),
+                        task: &task,
+                    }
+                    .to_string()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let calls = || {
+            analyzer
+                .profile_report()
+                .entries
+                .iter()
+                .map(|entry| entry.calls)
+                .sum::<usize>()
+        };
+
+        let expected = display_all();
+        let computed = calls();
+
+        assert!(computed > CAPACITY);
+
+        let evicted = analyzer.shrink();
+
+        assert_eq!(evicted, computed - CAPACITY);
+        assert_eq!(analyzer.shrink(), 0);
+
+        assert_eq!(display_all(), expected);
+
+        let recomputed = calls() - computed;
+
+        assert!(recomputed > 0);
+        assert!(recomputed <= evicted);
+
+        assert!(analyzer.shrink() > 0);
+
+        {
+            let handle = TriggerHandle::new();
+
+            let mut task = analyzer.mutate(&handle, 1).unwrap();
+
+            task.write_to_doc(doc_ids[0], Position::new(3, 17)..Position::new(3, 18), "5")
+                .unwrap();
+        }
+
+        let actual = display_all();
+
+        assert_eq!(&actual[1..], &expected[1..]);
+
+        let reference = Analyzer::<ChainNode>::new(AnalyzerConfig::new());
+
+        let reference_id = {
+            let handle = TriggerHandle::new();
+
+            let mut task = reference.mutate(&handle, 1).unwrap();
+
+            task.add_mutable_doc(INPUT.replace("x = 1;", "x = 5;"))
+        };
+
+        {
+            let handle = TriggerHandle::new();
+
+            let task = reference.analyze(&handle, 1).unwrap();
+
+            let doc_read = task.read_doc(reference_id).unwrap();
+
+            let reference_display = DisplayValues {
+                doc: doc_read.deref(),
+                task: &task,
+            }
+            .to_string();
+
+            assert_ne!(actual[0], expected[0]);
+            assert_eq!(actual[0], reference_display);
+        }
+    }
+
+    #[test]
+    fn test_chain_errors_in() {
+        const BLOCKS: usize = 100;
+        const BLOCK: &'static str = " { x = ; }";
+
+        let mut text = String::from("{");
+
+        for _ in 0..BLOCKS {
+            text.push_str(BLOCK);
+        }
+
+        text.push_str(" }");
+
+        let doc = Document::<ChainNode>::new_mutable(text);
+
+        assert_eq!(doc.errors().count(), BLOCKS);
+
+        // The span of the 50th block from its opening brace to
+        // the closing brace.
+        let start = 1 + 50 * BLOCK.len() + 1;
+        let span = start..(start + BLOCK.len() - 1);
+
+        let mut visited = 0;
+
+        doc.visit_errors_in(&span, &mut |_| {
+            visited += 1;
+            true
+        });
+
+        assert_eq!(visited, 1);
+
+        assert_eq!(doc.errors_in(span.clone()).count(), 1);
+        assert!(doc.has_errors_in(span.clone()));
+        assert!(!doc.has_errors_in(start..(start + 4)));
+
+        let error = doc.errors_in(span.clone()).next().unwrap();
+
+        assert_eq!(
+            error.span.to_site_span(&doc),
+            Some((start + 5)..(start + 6))
+        );
+
+        let immutable = Document::<ChainNode>::new_immutable(doc.substring(..));
+
+        let mut visited = 0;
+
+        immutable.visit_errors_in(&span, &mut |_| {
+            visited += 1;
+            true
+        });
+
+        assert_eq!(visited, BLOCKS);
+        assert_eq!(immutable.errors_in(span.clone()).count(), 1);
+        assert!(!immutable.has_errors_in(start..(start + 4)));
+    }
+
+    #[test]
+    fn test_chain_errors_in_nested() {
+        const BLOCKS: usize = 50;
+        const BLOCK: &'static str = " { x = ; }";
+        const NESTED: &'static str = " { a = ; { b = ; } c = ; }";
+
+        let mut text = String::from("{");
+
+        for _ in 0..BLOCKS {
+            text.push_str(BLOCK);
+        }
+
+        let nested_start = text.len();
+
+        text.push_str(NESTED);
+
+        let nested_end = text.len();
+
+        for _ in 0..BLOCKS {
+            text.push_str(BLOCK);
+        }
+
+        text.push_str(" }");
+
+        let doc = Document::<ChainNode>::new_mutable(text.as_str());
+        let immutable = Document::<ChainNode>::new_immutable(text.as_str());
+
+        assert_eq!(doc.errors().count(), 2 * BLOCKS + 3);
+
+        let collect = |doc: &Document<ChainNode>, span: Range<usize>| {
+            doc.errors_in(span)
+                .map(|error| error.span.to_site_span(doc).unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        for start in (nested_start - BLOCK.len())..=(nested_end + BLOCK.len()) {
+            for end in start..=(nested_end + BLOCK.len()) {
+                let mut actual = collect(&doc, start..end);
+                let mut expected = collect(&immutable, start..end);
+
+                actual.sort_by_key(|span| (span.start, span.end));
+                expected.sort_by_key(|span| (span.start, span.end));
+
+                assert_eq!(actual, expected, "{start}..{end}");
+                assert_eq!(doc.has_errors_in(start..end), !expected.is_empty());
+            }
+        }
+
+        // The inner block together with the errors of the enclosing block
+        // located before and after it.
+        let span = (nested_start + 2)..(nested_end - 2);
+
+        assert_eq!(collect(&doc, span).len(), 3);
+    }
+
     struct DisplayValues<'a> {
         doc: &'a Document<ChainNode>,
         task: &'a AnalysisTask<'a, ChainNode>,
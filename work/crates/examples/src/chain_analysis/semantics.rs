@@ -438,6 +438,19 @@ impl Classifier for ChainNodeClassifier {
 
         result
     }
+
+    fn denounce<S: SyncBuildHasher>(
+        doc: &Document<Self::Node>,
+        node_ref: &NodeRef,
+        classes: &HashSet<Self::Class, S>,
+    ) -> bool {
+        let is_key = match node_ref.deref(doc) {
+            Some(ChainNode::Key { .. }) => true,
+            _ => false,
+        };
+
+        is_key != classes.contains(&ChainNodeClass::AllKeys)
+    }
 }
 
 fn log_attr<C: Any, H: TaskHandle, S: SyncBuildHasher>(
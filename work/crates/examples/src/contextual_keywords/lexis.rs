@@ -0,0 +1,91 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use lady_deirdre::lexis::Token;
+
+#[derive(Token, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ClassToken {
+    EOI = 0,
+
+    Mismatch = 1,
+
+    #[rule("class")]
+    #[priority(1)]
+    #[describe("keyword", "class")]
+    Class,
+
+    #[rule(['a'..'z', 'A'..'Z', '_'] ['a'..'z', 'A'..'Z', '0'..'9', '_']*)]
+    #[describe("ident")]
+    Ident,
+
+    #[rule(['0'..'9']+)]
+    #[describe("number")]
+    Number,
+
+    // The lexer never produces the contextual keyword tokens. The parser
+    // reinterprets the Ident tokens as these keywords inside the class bodies.
+    #[describe("keyword", "get")]
+    Get,
+
+    #[describe("keyword", "set")]
+    Set,
+
+    #[rule('{')]
+    #[describe("{")]
+    BraceOpen,
+
+    #[rule('}')]
+    #[describe("}")]
+    BraceClose,
+
+    #[rule('(')]
+    #[describe("(")]
+    ParenOpen,
+
+    #[rule(')')]
+    #[describe(")")]
+    ParenClose,
+
+    #[rule('=')]
+    #[describe("=")]
+    Assign,
+
+    #[rule(';')]
+    #[describe(";")]
+    Semicolon,
+
+    #[rule([' ', '\t', '\n', '\x0c', '\r']+)]
+    Whitespace,
+}
@@ -0,0 +1,128 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+pub mod lexis;
+pub mod syntax;
+
+#[cfg(test)]
+mod tests {
+    use lady_deirdre::{
+        assert_tree,
+        lexis::SourceCode,
+        syntax::SyntaxTree,
+        testing::check_reparse,
+        units::{CompilationUnit, Document},
+    };
+
+    use crate::contextual_keywords::syntax::ClassNode;
+
+    #[test]
+    fn test_contextual_keywords() {
+        let doc = Document::<ClassNode>::new_immutable(
+            "get = 1; class A { get x() {} set get() {} y = 2; } set = 3;",
+        );
+
+        assert_eq!(doc.errors().count(), 0);
+
+        // Outside of the class body, and in the member name positions,
+        // "get" and "set" are regular identifiers.
+
+        assert_tree!(
+            doc,
+            r#"
+            Root
+              items: Field
+                name: $Ident "get"
+                value: $Number "1"
+              items: Class
+                name: $Ident "A"
+                members: Accessor
+                  kind: $Ident "get"
+                  name: $Ident "x"
+                members: Accessor
+                  kind: $Ident "set"
+                  name: $Ident "get"
+                members: Field
+                  name: $Ident "y"
+                  value: $Number "2"
+              items: Field
+                name: $Ident "set"
+                value: $Number "3"
+            "#,
+        );
+    }
+
+    #[test]
+    fn test_contextual_keywords_reparse() {
+        let mut doc = Document::<ClassNode>::new_mutable("class A { get x() {} y = 2; } set = 3;");
+
+        let edits = [
+            (14..15, "set"),
+            (23..29, "set y() {}"),
+            (0..0, "get = 1; "),
+            (19..22, "gett"),
+            (22..23, ""),
+        ];
+
+        for (span, text) in edits {
+            doc.write(span, text);
+
+            check_reparse(&doc);
+        }
+
+        assert_eq!(
+            doc.substring(..),
+            "get = 1; class A { get set() {} set y() {} } set = 3;"
+        );
+    }
+
+    #[test]
+    fn test_contextual_keywords_recovery_reparse() {
+        let mut doc = Document::<ClassNode>::new_mutable(
+            "get = 1; class A { get x() {} set get() {} y = 2; } set = 3; class B { z = 4; }",
+        );
+
+        // The first edit makes the error recovery of the leading field look
+        // through the rest of the text for the closing brace. The second edit
+        // changes the tokens inside the class that this recovery looked at.
+
+        let edits = [(4..5, "{"), (39..41, ";")];
+
+        for (span, text) in edits {
+            doc.write(span, text);
+
+            check_reparse(&doc);
+        }
+    }
+}
@@ -0,0 +1,108 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use lady_deirdre::{
+    lexis::TokenRef,
+    syntax::{Node, NodeRef},
+};
+
+use crate::contextual_keywords::lexis::ClassToken;
+
+#[derive(Node)]
+#[token(ClassToken)]
+#[trivia($Whitespace)]
+#[recovery(
+    $BraceClose,
+    [$BraceOpen..$BraceClose],
+    [$ParenOpen..$ParenClose],
+)]
+pub enum ClassNode {
+    #[root]
+    #[rule(items: (Class | Field)*)]
+    Root {
+        #[node]
+        node: NodeRef,
+        #[parent]
+        parent: NodeRef,
+        #[child]
+        items: Vec<NodeRef>,
+    },
+
+    // The class body is the context of the "get" and "set" keywords.
+    //
+    // The Accessor rule remaps the leading Ident tokens, and this rule
+    // inherits the remapping in the position where it descends into
+    // the Accessor rule.
+    #[rule($Class name: $Ident $BraceOpen members: (Accessor | Field)* $BraceClose)]
+    Class {
+        #[node]
+        node: NodeRef,
+        #[parent]
+        parent: NodeRef,
+        #[child]
+        name: TokenRef,
+        #[child]
+        members: Vec<NodeRef>,
+    },
+
+    // The members are secondary such that the incremental reparser
+    // reparses the entire class body whenever the member changes, and
+    // reconsiders the keywords in the class context.
+    #[rule(kind: ($Get | $Set) name: $Ident $ParenOpen $ParenClose $BraceOpen $BraceClose)]
+    #[remap($Ident => $Get if "get", $Ident => $Set if "set")]
+    #[secondary]
+    Accessor {
+        #[node]
+        node: NodeRef,
+        #[parent]
+        parent: NodeRef,
+        #[child]
+        kind: TokenRef,
+        #[child]
+        name: TokenRef,
+    },
+
+    #[rule(name: $Ident $Assign value: $Number $Semicolon)]
+    #[secondary]
+    Field {
+        #[node]
+        node: NodeRef,
+        #[parent]
+        parent: NodeRef,
+        #[child]
+        name: TokenRef,
+        #[child]
+        value: TokenRef,
+    },
+}
@@ -39,7 +39,23 @@ pub mod syntax;
 #[cfg(test)]
 mod tests {
     use lady_deirdre::{
-        syntax::SyntaxTree,
+        assert_tree,
+        lexis::{SourceCode, TokenBuffer, TokenRef},
+        syntax::{
+            check_coverage,
+            validate,
+            AbstractNode,
+            CoverageGapKind,
+            ImmutableSyntaxTree,
+            NodeRef,
+            NodeRule,
+            ParseEvent,
+            PolyRef,
+            SyntaxTree,
+            TraceObserver,
+            VisitControl,
+            Visitor,
+        },
         units::{CompilationUnit, Document},
     };
 
@@ -49,9 +65,55 @@ mod tests {
     fn test_expression_parser() {
         let doc = Document::<BoolNode>::new_immutable("true & false & (true | false) & true");
 
-        println!("{:#?}", doc.display(&doc.root_node_ref()));
+        assert_tree!(
+            doc,
+            r#"
+            Root
+              expr: Expr
+                content: And
+                  left: And
+                    left: And
+                      left: True
+                        token: $True "true"
+                      right: False
+                        token: $False "false"
+                    right: Expr
+                      content: Or
+                        left: True
+                          token: $True "true"
+                        right: False
+                          token: $False "false"
+                  right: True
+                    token: $True "true"
+            "#,
+        );
 
-        assert!(doc.errors().next().is_none());
+        #[cfg(debug_assertions)]
+        assert_eq!(validate(&doc), Vec::new());
+    }
+
+    #[test]
+    fn test_expression_coverage() {
+        let doc = Document::<BoolNode>::new_immutable("true & false | true");
+
+        assert_eq!(check_coverage(&doc), Vec::new());
+
+        // The group parentheses are consumed by the operand parser, but they
+        // are not captured by any node.
+
+        let doc = Document::<BoolNode>::new_immutable("true & (false | true)");
+
+        let gaps = check_coverage(&doc);
+
+        assert!(!gaps.is_empty());
+
+        for gap in gaps {
+            assert_eq!(gap.kind, CoverageGapKind::Dropped);
+
+            let text = doc.substring(&gap.span);
+
+            assert!(text.chars().all(|ch| ch == '(' || ch == ')' || ch == ' '));
+        }
     }
 
     #[test]
@@ -63,5 +125,203 @@ mod tests {
         for error in doc.errors() {
             println!("{:#}", error.display(&doc));
         }
+
+        #[cfg(debug_assertions)]
+        assert_eq!(validate(&doc), Vec::new());
+    }
+
+    #[test]
+    fn test_expression_trace() {
+        let code = TokenBuffer::from("(false  true) & tru | false");
+
+        let mut observer = TraceObserver::new(&code);
+        let tree =
+            ImmutableSyntaxTree::<BoolNode>::parse_with_observer(code.cursor(..), &mut observer);
+        let trace = observer.into_trace();
+
+        println!("{}", trace.render());
+
+        assert!(trace.check(&tree));
+
+        let mut errors = 0;
+
+        for (index, event) in trace.events().iter().enumerate() {
+            let ParseEvent::SyntaxError { site, .. } = event else {
+                continue;
+            };
+
+            errors += 1;
+
+            let Some(ParseEvent::Recover {
+                site: recover_site, ..
+            }) = index
+                .checked_sub(1)
+                .and_then(|index| trace.events().get(index))
+            else {
+                panic!("Missing recovery event before the syntax error.");
+            };
+
+            assert_eq!(recover_site, site);
+        }
+
+        assert_eq!(errors, tree.errors().count());
+
+        let mut observer = TraceObserver::new(&code);
+        let _ =
+            ImmutableSyntaxTree::<BoolNode>::parse_with_observer(code.cursor(..), &mut observer);
+        let other = observer.into_trace();
+
+        assert_eq!(trace.diff(&other), None);
+
+        let other_code = TokenBuffer::from("(false  true) & true | false");
+
+        let mut observer = TraceObserver::new(&other_code);
+        let _ = ImmutableSyntaxTree::<BoolNode>::parse_with_observer(
+            other_code.cursor(..),
+            &mut observer,
+        );
+        let other = observer.into_trace();
+
+        assert!(trace.diff(&other).is_some());
+
+        struct CountVisitor {
+            nodes: usize,
+            tokens: usize,
+        }
+
+        impl Visitor for CountVisitor {
+            fn visit_token(&mut self, _token_ref: &TokenRef) {
+                self.tokens += 1;
+            }
+
+            fn enter_node(&mut self, _node_ref: &NodeRef) -> bool {
+                self.nodes += 1;
+                true
+            }
+
+            fn leave_node(&mut self, _node_ref: &NodeRef) {}
+        }
+
+        let mut visitor = CountVisitor {
+            nodes: 0,
+            tokens: 0,
+        };
+
+        trace.replay(&mut visitor);
+
+        assert_eq!(visitor.nodes, tree.nodes().count());
+        assert_eq!(visitor.tokens, code.tokens());
+    }
+
+    #[test]
+    fn test_traverse_control() {
+        let doc = Document::<BoolNode>::new_immutable("true & false & (true | false) & true");
+
+        struct SkipVisitor<'a> {
+            doc: &'a Document<BoolNode>,
+            rule: NodeRule,
+            control: VisitControl,
+            visited: Vec<NodeRef>,
+            left: usize,
+        }
+
+        impl<'a> Visitor for SkipVisitor<'a> {
+            fn visit_token(&mut self, _token_ref: &TokenRef) {}
+
+            fn visit_node(&mut self, node_ref: &NodeRef) -> VisitControl {
+                self.visited.push(*node_ref);
+
+                match node_ref.rule(self.doc) == self.rule {
+                    true => self.control,
+                    false => VisitControl::Continue,
+                }
+            }
+
+            fn leave_node(&mut self, _node_ref: &NodeRef) {
+                self.left += 1;
+            }
+        }
+
+        let mut visitor = SkipVisitor {
+            doc: &doc,
+            rule: BoolNode::AND,
+            control: VisitControl::SkipChildren,
+            visited: Vec::new(),
+            left: 0,
+        };
+
+        doc.traverse_tree(&mut visitor);
+
+        assert!(visitor.visited.len() < doc.nodes().count());
+        assert_eq!(visitor.visited.len(), visitor.left);
+
+        for node_ref in &visitor.visited {
+            let mut parent = node_ref.parent(&doc);
+
+            while !parent.is_nil() {
+                assert_ne!(parent.rule(&doc), BoolNode::AND);
+                parent = parent.parent(&doc);
+            }
+        }
+
+        let mut visitor = SkipVisitor {
+            doc: &doc,
+            rule: BoolNode::TRUE,
+            control: VisitControl::Stop,
+            visited: Vec::new(),
+            left: 0,
+        };
+
+        doc.traverse_tree(&mut visitor);
+
+        let last = visitor.visited.last().unwrap();
+
+        assert_eq!(last.rule(&doc), BoolNode::TRUE);
+        assert_eq!(
+            visitor
+                .visited
+                .iter()
+                .filter(|node_ref| node_ref.rule(&doc) == BoolNode::TRUE)
+                .count(),
+            1,
+        );
+        assert_eq!(visitor.left, 0);
+    }
+
+    #[test]
+    fn test_tree_stats() {
+        let doc = Document::<BoolNode>::new_immutable("true & (false | true)");
+
+        let stats = doc.stats();
+
+        println!("{stats}");
+
+        assert_eq!(stats.nodes, doc.nodes().count());
+        assert_eq!(stats.errors, 0);
+        assert_eq!(stats.rule_count(BoolNode::TRUE), 2);
+        assert_eq!(stats.rule_count(BoolNode::FALSE), 1);
+        assert_eq!(stats.rule_count(BoolNode::OR), 1);
+        assert_eq!(stats.rule_count(BoolNode::AND), 1);
+        assert!(stats.max_depth >= 4);
+    }
+
+    #[test]
+    fn test_expression_precedence() {
+        let doc = Document::<BoolNode>::new_immutable("true | false & true | false");
+
+        assert!(doc.errors().next().is_none());
+
+        let top = doc.root_node_ref().first_child(&doc).first_child(&doc);
+
+        assert_eq!(top.rule(&doc), BoolNode::OR);
+
+        let left = top.get_child(&doc, "left");
+
+        assert_eq!(left.rule(&doc), BoolNode::OR);
+        assert_eq!(left.get_child(&doc, "right").rule(&doc), BoolNode::AND);
+        assert_eq!(top.get_child(&doc, "right").rule(&doc), BoolNode::FALSE);
+
+        #[cfg(debug_assertions)]
+        assert_eq!(validate(&doc), Vec::new());
     }
 }
@@ -34,7 +34,7 @@
 
 use lady_deirdre::{
     lexis::TokenSet,
-    syntax::{NodeRef, NodeRule, PolyRef, Recovery, SyntaxError, SyntaxSession, EMPTY_NODE_SET},
+    syntax::{NodeRef, PrattDriver, Recovery, SyntaxError, SyntaxSession, EMPTY_NODE_SET},
 };
 
 use crate::expr_parser::{lexis::BoolToken, syntax::BoolNode};
@@ -42,7 +42,7 @@ use crate::expr_parser::{lexis::BoolToken, syntax::BoolNode};
 pub fn parse_expr<'a>(session: &mut impl SyntaxSession<'a, Node = BoolNode>) -> BoolNode {
     let node = session.node_ref();
     let parent = session.parent_ref();
-    let content = parse_operator(session, BoolNode::EXPR, 0);
+    let content = EXPR.parse(session, parse_operand);
 
     BoolNode::Expr {
         node,
@@ -51,180 +51,83 @@ pub fn parse_expr<'a>(session: &mut impl SyntaxSession<'a, Node = BoolNode>) ->
     }
 }
 
-static OPERAND_RECOVERY: Recovery = Recovery::unlimited()
-    .group(BoolToken::ParenOpen as u8, BoolToken::ParenClose as u8)
-    .unexpected(BoolToken::ParenClose as u8);
-
-static OPERAND_TOKENS: TokenSet = TokenSet::inclusive(&[
-    BoolToken::True as u8,
-    BoolToken::False as u8,
-    BoolToken::ParenOpen as u8,
-]);
+static EXPR: PrattDriver<BoolNode> = PrattDriver::new(BoolNode::EXPR)
+    .trivia(TokenSet::inclusive(&[BoolToken::Whitespace as u8]))
+    .operands(TokenSet::inclusive(&[
+        BoolToken::True as u8,
+        BoolToken::False as u8,
+        BoolToken::ParenOpen as u8,
+    ]))
+    .terminators(TokenSet::inclusive(&[BoolToken::ParenClose as u8]))
+    .operand_recovery(
+        Recovery::unlimited()
+            .group(BoolToken::ParenOpen as u8, BoolToken::ParenClose as u8)
+            .unexpected(BoolToken::ParenClose as u8),
+    )
+    .operator_recovery(
+        Recovery::unlimited().group(BoolToken::ParenOpen as u8, BoolToken::ParenClose as u8),
+    )
+    .infix(BoolToken::Or as u8, BoolNode::OR, 1, 2, |operands| {
+        BoolNode::Or {
+            node: operands.node,
+            parent: operands.parent,
+            left: operands.left,
+            right: operands.right,
+        }
+    })
+    .infix(BoolToken::And as u8, BoolNode::AND, 3, 4, |operands| {
+        BoolNode::And {
+            node: operands.node,
+            parent: operands.parent,
+            left: operands.left,
+            right: operands.right,
+        }
+    });
 
 static GROUP_RECOVERY: Recovery = Recovery::unlimited().unexpected(BoolToken::ParenOpen as u8);
 
 static GROUP_TOKENS: TokenSet = TokenSet::inclusive(&[BoolToken::ParenClose as u8]);
 
-static OPERATOR_RECOVERY: Recovery =
-    Recovery::unlimited().group(BoolToken::ParenOpen as u8, BoolToken::ParenClose as u8);
-
-static OPERATOR_TOKENS: TokenSet = TokenSet::inclusive(&[
-    BoolToken::And as u8,
-    BoolToken::Or as u8,
-    BoolToken::ParenClose as u8,
-]);
-
-fn parse_operator<'a>(
-    session: &mut impl SyntaxSession<'a, Node = BoolNode>,
-    context: NodeRule,
-    binding: u8,
-) -> NodeRef {
-    let mut accumulator = parse_operand(session, context);
-
-    loop {
-        skip_trivia(session);
-
-        let token = session.token(0);
-
-        match token {
-            BoolToken::And => {
-                if binding >= 2 {
-                    return accumulator;
-                }
-
-                let left = accumulator;
-
-                let node = session.enter(BoolNode::AND);
-
-                if !left.is_nil() {
-                    session.lift(&left);
-                }
-
-                let parent = session.parent_ref();
-
-                session.advance();
-                skip_trivia(session);
-
-                let right = parse_operator(session, BoolNode::AND, 2);
-
-                accumulator = session.leave(BoolNode::And {
-                    node,
-                    parent,
-                    left,
-                    right,
-                });
-            }
-
-            BoolToken::Or => {
-                if binding >= 10 {
-                    return accumulator;
-                }
-
-                let left = accumulator;
-
-                let node = session.enter(BoolNode::OR);
-
-                if !left.is_nil() {
-                    session.lift(&left);
-                }
-
-                let parent = session.parent_ref();
-
-                session.advance();
-                skip_trivia(session);
-
-                let right = parse_operator(session, BoolNode::OR, 1);
-
-                accumulator = session.leave(BoolNode::Or {
-                    node,
-                    parent,
-                    left,
-                    right,
-                });
-            }
-
-            BoolToken::ParenClose | BoolToken::EOI => return accumulator,
-
-            _ => {
-                let start_site_ref = session.site_ref(0);
-
-                let result = OPERATOR_RECOVERY.recover(session, &OPERATOR_TOKENS);
-
-                let end_site_ref = session.site_ref(0);
-
-                session.failure(SyntaxError {
-                    span: start_site_ref..end_site_ref,
-                    context,
-                    recovery: result,
-                    expected_tokens: &OPERATOR_TOKENS,
-                    expected_nodes: &EMPTY_NODE_SET,
-                });
-
-                if !result.recovered() {
-                    return accumulator;
-                }
-            }
-        }
-    }
-}
-
-fn parse_operand<'a>(
-    session: &mut impl SyntaxSession<'a, Node = BoolNode>,
-    context: NodeRule,
-) -> NodeRef {
-    loop {
-        let token = session.token(0);
-
-        match token {
-            BoolToken::True => return parse_true_operand(session),
-
-            BoolToken::False => return parse_false_operand(session),
-
-            BoolToken::ParenOpen => return parse_group(session),
-
-            _ => {
-                let start_site_ref = session.site_ref(0);
-
-                let result = OPERAND_RECOVERY.recover(session, &OPERAND_TOKENS);
-
-                let end_site_ref = session.site_ref(0);
-
-                session.failure(SyntaxError {
-                    span: start_site_ref..end_site_ref,
-                    context,
-                    recovery: result,
-                    expected_tokens: &OPERAND_TOKENS,
-                    expected_nodes: &EMPTY_NODE_SET,
-                });
-
-                if !result.recovered() {
-                    return NodeRef::nil();
-                }
-            }
-        }
+fn parse_operand<'a>(session: &mut impl SyntaxSession<'a, Node = BoolNode>) -> NodeRef {
+    match session.token(0) {
+        BoolToken::True => parse_true_operand(session),
+        BoolToken::False => parse_false_operand(session),
+        _ => parse_group(session),
     }
 }
 
 fn parse_true_operand<'a>(session: &mut impl SyntaxSession<'a, Node = BoolNode>) -> NodeRef {
     session.enter(BoolNode::TRUE);
 
+    let token = session.token_ref(0);
+
     session.advance();
 
     let node = session.node_ref();
     let parent = session.parent_ref();
 
-    return session.leave(BoolNode::True { node, parent });
+    return session.leave(BoolNode::True {
+        node,
+        parent,
+        token,
+    });
 }
 
 fn parse_false_operand<'a>(session: &mut impl SyntaxSession<'a, Node = BoolNode>) -> NodeRef {
     session.enter(BoolNode::FALSE);
 
+    let token = session.token_ref(0);
+
     session.advance();
 
     let node = session.node_ref();
     let parent = session.parent_ref();
 
-    return session.leave(BoolNode::False { node, parent });
+    return session.leave(BoolNode::False {
+        node,
+        parent,
+        token,
+    });
 }
 
 fn parse_group<'a>(session: &mut impl SyntaxSession<'a, Node = BoolNode>) -> NodeRef {
@@ -248,17 +151,22 @@ fn parse_group<'a>(session: &mut impl SyntaxSession<'a, Node = BoolNode>) -> Nod
             _ => {
                 let start_site_ref = session.site_ref(0);
 
-                let result = GROUP_RECOVERY.recover(session, &GROUP_TOKENS);
+                let details = GROUP_RECOVERY.recover_with_details(session, &GROUP_TOKENS);
+                let result = details.result();
 
                 let end_site_ref = session.site_ref(0);
 
-                session.failure(SyntaxError {
-                    span: start_site_ref..end_site_ref,
-                    context: BoolNode::EXPR,
-                    recovery: result,
-                    expected_tokens: &GROUP_TOKENS,
-                    expected_nodes: &EMPTY_NODE_SET,
-                });
+                let mut error = SyntaxError::new(
+                    start_site_ref..end_site_ref,
+                    BoolNode::EXPR,
+                    result,
+                    &GROUP_TOKENS,
+                    &EMPTY_NODE_SET,
+                );
+
+                error.recovery_details = Some(details);
+
+                session.failure(error);
 
                 if !result.recovered() {
                     return inner;
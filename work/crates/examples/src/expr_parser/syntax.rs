@@ -32,7 +32,10 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use lady_deirdre::syntax::{Node, NodeRef};
+use lady_deirdre::{
+    lexis::TokenRef,
+    syntax::{Node, NodeRef},
+};
 
 use crate::expr_parser::{lexis::BoolToken, parser::parse_expr};
 
@@ -71,6 +74,8 @@ pub enum BoolNode {
         node: NodeRef,
         #[parent]
         parent: NodeRef,
+        #[child]
+        token: TokenRef,
     },
 
     #[denote(FALSE)]
@@ -80,6 +85,8 @@ pub enum BoolNode {
         node: NodeRef,
         #[parent]
         parent: NodeRef,
+        #[child]
+        token: TokenRef,
     },
 
     #[denote(AND)]
@@ -0,0 +1,181 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+pub mod syntax;
+
+#[cfg(test)]
+mod tests {
+    use lady_deirdre::{
+        assert_tree,
+        lexis::SourceCode,
+        syntax::{RecoveryResult, SyntaxTree},
+        testing::check_reparse,
+        units::{CompilationUnit, Document},
+    };
+
+    use crate::json_error_rules::syntax::JsonErrorNode;
+
+    static MESSAGE: &'static str = "Trailing comma is not allowed.";
+
+    #[test]
+    fn test_trailing_comma() {
+        let doc = Document::<JsonErrorNode>::new_immutable(
+            r#"{"a": [1, 2, ], "b": {"c": null,}, "d": true,}"#,
+        );
+
+        let errors = doc.errors().collect::<Vec<_>>();
+
+        assert_eq!(errors.len(), 3);
+
+        for error in errors {
+            assert_eq!(error.recovery, RecoveryResult::ErrorProduction);
+            assert_eq!(error.custom_message, Some(MESSAGE));
+            assert_eq!(doc.substring(&error.span), ",");
+            assert_eq!(error.message::<JsonErrorNode>(&doc).to_string(), MESSAGE);
+        }
+
+        assert_eq!(
+            doc.errors()
+                .map(|error| error.display(&doc).to_string())
+                .collect::<Vec<_>>(),
+            [
+                format!("1:12 (1 char): {MESSAGE}"),
+                format!("1:32 (1 char): {MESSAGE}"),
+                format!("1:45 (1 char): {MESSAGE}"),
+            ],
+        );
+
+        // The parser continues normally after the error productions: all
+        // entries and items are in place, and the brackets are closed.
+
+        assert_tree!(
+            doc,
+            r#"
+            Root
+              object: Object
+                start: $BraceOpen "{"
+                entries: Entry
+                  key: String
+                    value: $String "\"a\""
+                  value: Array
+                    start: $BracketOpen "["
+                    items: Number
+                      value: $Number "1"
+                    items: Number
+                      value: $Number "2"
+                    end: $BracketClose "]"
+                entries: Entry
+                  key: String
+                    value: $String "\"b\""
+                  value: Object
+                    start: $BraceOpen "{"
+                    entries: Entry
+                      key: String
+                        value: $String "\"c\""
+                      value: Null
+                        token: $Null "null"
+                    end: $BraceClose "}"
+                entries: Entry
+                  key: String
+                    value: $String "\"d\""
+                  value: True
+                    token: $True "true"
+                end: $BraceClose "}"
+            ! Trailing comma is not allowed. [11..12]
+            ! Trailing comma is not allowed. [31..32]
+            ! Trailing comma is not allowed. [44..45]
+            "#,
+        );
+    }
+
+    #[test]
+    fn test_trailing_comma_trivia() {
+        let doc = Document::<JsonErrorNode>::new_immutable("{\"a\": [1, // comment\n], \"b\": 2}");
+
+        let errors = doc.errors().collect::<Vec<_>>();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].custom_message, Some(MESSAGE));
+        assert_eq!(doc.substring(&errors[0].span), ",");
+    }
+
+    #[test]
+    fn test_trailing_comma_fallback() {
+        let doc = Document::<JsonErrorNode>::new_immutable(r#"{"a": [1, 2], "b": 3}"#);
+
+        assert_eq!(doc.errors().count(), 0);
+
+        // The syntax errors that do not match the error rule are reported
+        // by the regular recovery.
+
+        let doc = Document::<JsonErrorNode>::new_immutable(r#"{"a": [1 2], "b" 3}"#);
+
+        let errors = doc.errors().collect::<Vec<_>>();
+
+        assert_eq!(errors.len(), 2);
+
+        for error in errors {
+            assert_eq!(error.custom_message, None);
+            assert_ne!(error.recovery, RecoveryResult::ErrorProduction);
+        }
+    }
+
+    #[test]
+    fn test_trailing_comma_reparse() {
+        let mut doc = Document::<JsonErrorNode>::new_mutable(r#"{"a": [1, 2], "b": 3}"#);
+
+        let edits = [
+            (11..11, ","),
+            (21..21, ","),
+            (11..12, ""),
+            (7..7, "0, "),
+            (23..23, " "),
+        ];
+
+        for (span, text) in edits {
+            doc.write(span, text);
+
+            check_reparse(&doc);
+        }
+
+        assert_eq!(doc.substring(..), r#"{"a": [0, 1, 2], "b": 3 ,}"#);
+
+        assert_eq!(
+            doc.errors()
+                .map(|error| error.custom_message)
+                .collect::<Vec<_>>(),
+            [Some(MESSAGE)],
+        );
+    }
+}
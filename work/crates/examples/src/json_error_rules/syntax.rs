@@ -0,0 +1,175 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use lady_deirdre::{
+    lexis::TokenRef,
+    syntax::{Node, NodeRef},
+};
+
+use crate::json_grammar::lexis::JsonToken;
+
+#[derive(Node)]
+#[token(JsonToken)]
+#[trivia($Whitespace | $Comment)]
+#[define(ANY = Object | Array | True | False | String | Number | Null)]
+#[recovery(
+    $BraceClose,
+    $BracketClose,
+    [$BraceOpen..$BraceClose],
+    [$BracketOpen..$BracketClose],
+)]
+pub enum JsonErrorNode {
+    #[root]
+    #[rule(object: Object)]
+    Root {
+        #[node]
+        node: NodeRef,
+        #[parent]
+        parent: NodeRef,
+        #[child]
+        object: NodeRef,
+    },
+
+    #[rule(start: $BraceOpen (entries: Entry)*{$Comma} end: $BraceClose)]
+    Object {
+        #[node]
+        node: NodeRef,
+        #[parent]
+        parent: NodeRef,
+        #[child]
+        start: TokenRef,
+        #[child]
+        entries: Vec<NodeRef>,
+        #[child]
+        end: TokenRef,
+    },
+
+    #[rule(key: String $Colon value: ANY)]
+    Entry {
+        #[node]
+        node: NodeRef,
+        #[parent]
+        parent: NodeRef,
+        #[child]
+        key: NodeRef,
+        #[child]
+        value: NodeRef,
+    },
+
+    #[rule(start: $BracketOpen (items: ANY)*{$Comma} end: $BracketClose)]
+    Array {
+        #[node]
+        node: NodeRef,
+        #[parent]
+        parent: NodeRef,
+        #[child]
+        start: TokenRef,
+        #[child]
+        items: Vec<NodeRef>,
+        #[child]
+        end: TokenRef,
+    },
+
+    // The parser tries this rule when the comma is followed by a token that
+    // cannot start the next entry or item (e.g., by the closing brace).
+    //
+    // The node is secondary because the parser's decision depends on
+    // the tokens that follow the comma, so the incremental reparser should
+    // reparse the enclosing object or array instead.
+    #[error_rule(comma: $Comma, "Trailing comma is not allowed.")]
+    #[secondary]
+    TrailingComma {
+        #[node]
+        node: NodeRef,
+        #[parent]
+        parent: NodeRef,
+        #[child]
+        comma: TokenRef,
+    },
+
+    #[rule(value: $String)]
+    #[secondary]
+    String {
+        #[node]
+        node: NodeRef,
+        #[parent]
+        parent: NodeRef,
+        #[child]
+        value: TokenRef,
+    },
+
+    #[rule(value: $Number)]
+    #[secondary]
+    Number {
+        #[node]
+        node: NodeRef,
+        #[parent]
+        parent: NodeRef,
+        #[child]
+        value: TokenRef,
+    },
+
+    #[rule(token: $True)]
+    #[secondary]
+    True {
+        #[node]
+        node: NodeRef,
+        #[parent]
+        parent: NodeRef,
+        #[child]
+        token: TokenRef,
+    },
+
+    #[rule(token: $False)]
+    #[secondary]
+    False {
+        #[node]
+        node: NodeRef,
+        #[parent]
+        parent: NodeRef,
+        #[child]
+        token: TokenRef,
+    },
+
+    #[rule(token: $Null)]
+    #[secondary]
+    Null {
+        #[node]
+        node: NodeRef,
+        #[parent]
+        parent: NodeRef,
+        #[child]
+        token: TokenRef,
+    },
+}
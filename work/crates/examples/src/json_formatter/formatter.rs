@@ -33,7 +33,7 @@
 ////////////////////////////////////////////////////////////////////////////////
 
 use lady_deirdre::{
-    format::{PrettyPrintConfig, PrettyPrinter},
+    format::{LayoutReport, PrettyPrintConfig, PrettyPrinter, Trivia, TriviaChild},
     lexis::{SourceCode, TokenBuffer},
     syntax::{ParseNode, ParseNodeChild, ParseTree},
 };
@@ -41,19 +41,49 @@ use lady_deirdre::{
 use crate::json_grammar::{lexis::JsonToken, syntax::JsonNode};
 
 pub fn format_json(text: impl Into<TokenBuffer<JsonToken>>) -> String {
+    print_json(text, PrettyPrintConfig::new(), None).finish()
+}
+
+// The `object_width` parameter overrides the line width limit of the objects.
+pub fn format_json_with(
+    text: impl Into<TokenBuffer<JsonToken>>,
+    config: PrettyPrintConfig,
+    object_width: Option<u16>,
+) -> String {
+    print_json(text, config, object_width).finish()
+}
+
+pub fn measure_json(
+    text: impl Into<TokenBuffer<JsonToken>>,
+    config: PrettyPrintConfig,
+) -> LayoutReport {
+    print_json(text, config, None).measure()
+}
+
+fn print_json(
+    text: impl Into<TokenBuffer<JsonToken>>,
+    config: PrettyPrintConfig,
+    object_width: Option<u16>,
+) -> PrettyPrinter {
     let token_buffer = text.into();
     let parse_tree = ParseTree::<JsonNode, _>::new(&token_buffer, ..);
-    let mut printer = PrettyPrinter::new(PrettyPrintConfig::new());
+    let mut printer = PrettyPrinter::new(config);
 
-    format_json_node(&mut printer, &parse_tree, parse_tree.parse_tree_root());
+    format_json_node(
+        &mut printer,
+        &parse_tree,
+        parse_tree.parse_tree_root(),
+        object_width,
+    );
 
-    printer.finish()
+    printer
 }
 
 fn format_json_node(
     printer: &mut PrettyPrinter,
     tree: &ParseTree<JsonNode, TokenBuffer<JsonToken>>,
     parse_node: &ParseNode,
+    object_width: Option<u16>,
 ) {
     if !parse_node.well_formed {
         let node_source_code = tree.substring(&parse_node.site_span);
@@ -72,11 +102,44 @@ fn format_json_node(
         return;
     }
 
-    for child in &parse_node.children {
+    // The keys of the object entries are aligned such that the values
+    // start at the same column when the object is broken into lines.
+    if parse_node.rule == JsonNode::ENTRY {
+        printer.align_begin();
+    }
+
+    let width = match parse_node.rule == JsonNode::OBJECT {
+        true => object_width,
+        false => None,
+    };
+
+    if let Some(width) = width {
+        printer.width_begin(width);
+    }
+
+    let split = Trivia::split(parse_node, tree, |child| match child {
+        ParseNodeChild::Token(token) => token.rule == JsonToken::Comment as u8,
+        _ => false,
+    });
+
+    for TriviaChild {
+        leading,
+        child,
+        trailing,
+    } in &split.children
+    {
+        printer.print_leading_trivia(leading);
+
         match child {
             ParseNodeChild::Blank(_) => (),
 
             ParseNodeChild::Token(child) => {
+                if let Some(text) = child.text() {
+                    printer.word(text);
+                    printer.print_trailing_trivia(trailing);
+                    continue;
+                }
+
                 let Some(token) = child.token_ref.deref(tree) else {
                     continue;
                 };
@@ -84,6 +147,7 @@ fn format_json_node(
                 match token {
                     JsonToken::BraceOpen => {
                         printer.word("{");
+                        printer.print_trailing_trivia(trailing);
                         printer.cbox(1);
                         printer.blank();
                     }
@@ -92,29 +156,39 @@ fn format_json_node(
                         printer.blank();
                         printer.indent(-1);
                         printer.end();
-                        printer.word("}")
+                        printer.word("}");
+                        printer.print_trailing_trivia(trailing);
                     }
 
                     JsonToken::BracketOpen => {
                         printer.word("[");
-                        printer.ibox(1);
+                        printer.print_trailing_trivia(trailing);
+                        printer.fill_begin(1);
                         printer.softbreak();
                     }
 
                     JsonToken::BracketClose => {
                         printer.softbreak();
                         printer.indent(-1);
-                        printer.end();
-                        printer.word("]")
+                        printer.fill_end();
+                        printer.word("]");
+                        printer.print_trailing_trivia(trailing);
                     }
 
                     JsonToken::Comma => {
                         printer.word(",");
+                        printer.print_trailing_trivia(trailing);
                         printer.blank();
                     }
 
                     JsonToken::Colon => {
-                        printer.word(": ");
+                        printer.word(":");
+                        printer.align_end();
+
+                        match trailing.has_comments() {
+                            true => printer.print_trailing_trivia(trailing),
+                            false => printer.word(" "),
+                        }
                     }
 
                     JsonToken::String
@@ -127,13 +201,23 @@ fn format_json_node(
                         };
 
                         printer.word(string);
+                        printer.print_trailing_trivia(trailing);
                     }
 
                     _ => (),
                 }
             }
 
-            ParseNodeChild::Node(child) => format_json_node(printer, tree, child),
+            ParseNodeChild::Node(child) => {
+                format_json_node(printer, tree, child, object_width);
+                printer.print_trailing_trivia(trailing);
+            }
         }
     }
+
+    printer.print_leading_trivia(&split.rest);
+
+    if width.is_some() {
+        printer.width_end();
+    }
 }
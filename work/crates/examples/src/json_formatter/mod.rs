@@ -36,7 +36,9 @@ pub mod formatter;
 
 #[cfg(test)]
 mod tests {
-    use crate::json_formatter::formatter::format_json;
+    use lady_deirdre::format::PrettyPrintConfig;
+
+    use crate::json_formatter::formatter::{format_json, format_json_with, measure_json};
 
     #[test]
     fn test_json_formatter() {
@@ -56,4 +58,179 @@ mod tests {
 
         assert_eq!(format_json(INPUT), OUTPUT);
     }
+
+    #[test]
+    fn test_json_formatter_fill_and_align() {
+        let mut input = String::from(r#"{"numbers": ["#);
+
+        for number in 1..=40 {
+            if number > 1 {
+                input.push_str(", ");
+            }
+
+            input.push_str(&number.to_string());
+        }
+
+        input.push_str(r#"], "a": true, "long_key": null}"#);
+
+        static OUTPUT: &'static str = r#"{
+    "numbers": [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18,
+        19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36,
+        37, 38, 39, 40],
+    "a":        true,
+    "long_key": null
+}"#;
+
+        assert_eq!(format_json(input), OUTPUT);
+    }
+
+    #[test]
+    fn test_json_formatter_comments() {
+        static INPUT: &'static str = r#"
+// Leading comment.
+
+{ "a": 1, // Trailing comment.
+
+
+    // Entry comment.
+    "b": [1, 2], "c": null
+    // Closing comment.
+}
+// Final comment.
+"#;
+
+        static OUTPUT: &'static str = r#"// Leading comment.
+
+{
+    "a": 1, // Trailing comment.
+
+    // Entry comment.
+    "b": [1, 2],
+    "c": null
+    // Closing comment.
+}
+// Final comment."#;
+
+        assert_eq!(format_json(INPUT), OUTPUT);
+    }
+
+    #[test]
+    fn test_json_formatter_comment_positions() {
+        static INPUT: &'static str = r#"{
+    "a": [1, 2, // After two.
+        3],
+    "b": // Value.
+        true,
+
+
+    "c": [
+        // First item.
+        1,
+        2
+        // Last item.
+    ],
+    "d": {} // Empty.
+}"#;
+
+        static OUTPUT: &'static str = r#"{
+    "a": [1, 2, // After two.
+        3],
+    "b": // Value.
+    true,
+
+    "c": [
+        // First item.
+        1, 2
+        // Last item.
+    ],
+    "d": { } // Empty.
+}"#;
+
+        assert_eq!(format_json(INPUT), OUTPUT);
+
+        let mut config = PrettyPrintConfig::new();
+
+        config.blank_lines = 2;
+
+        static OUTPUT_2: &'static str = r#"{
+    "a": [1, 2, // After two.
+        3],
+    "b": // Value.
+    true,
+
+
+    "c": [
+        // First item.
+        1, 2
+        // Last item.
+    ],
+    "d": { } // Empty.
+}"#;
+
+        assert_eq!(format_json_with(INPUT, config, None), OUTPUT_2);
+
+        let mut config = PrettyPrintConfig::new();
+
+        config.blank_lines = 0;
+
+        static OUTPUT_0: &'static str = r#"{
+    "a": [1, 2, // After two.
+        3],
+    "b": // Value.
+    true,
+    "c": [
+        // First item.
+        1, 2
+        // Last item.
+    ],
+    "d": { } // Empty.
+}"#;
+
+        assert_eq!(format_json_with(INPUT, config, None), OUTPUT_0);
+    }
+
+    #[test]
+    fn test_json_formatter_measure() {
+        static INPUT: &'static str = r#"{"short": [1, 2, 3], "long": ["a long string value", "another long string value"], "unbreakable": "a string that is longer than the margin"}"#;
+
+        let mut config = PrettyPrintConfig::new();
+
+        config.margin = 40;
+        config.inline = 20;
+
+        let report = measure_json(INPUT, config);
+
+        assert_eq!(report.lines, 6);
+        assert_eq!(report.max_width, 60);
+
+        let broken = report
+            .groups
+            .iter()
+            .map(|group| group.broken)
+            .collect::<Vec<_>>();
+
+        assert_eq!(broken, [true, false, true]);
+    }
+
+    #[test]
+    fn test_json_formatter_tabs_and_width() {
+        static INPUT: &'static str = r#"{"numbers": [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20], "object": {"a": true, "b": false, "c": null}}"#;
+
+        let mut config = PrettyPrintConfig::new();
+
+        config.tabs = true;
+
+        static OUTPUT: &'static str = "{
+\t\"numbers\": [1, 2, 3, 4, 5, 6, 7, 8, 9,
+\t\t10, 11, 12, 13, 14, 15, 16, 17, 18, 19,
+\t\t20],
+\t\"object\": {
+\t\t\"a\": true,
+\t\t\"b\": false,
+\t\t\"c\": null
+\t}
+}";
+
+        assert_eq!(format_json_with(INPUT, config, Some(40)), OUTPUT);
+    }
 }
@@ -84,4 +84,7 @@ pub enum JsonToken {
 
     #[rule([' ', '\t', '\n', '\x0c', '\r']+)]
     Whitespace,
+
+    #[rule("//" ^['\n']*)]
+    Comment,
 }
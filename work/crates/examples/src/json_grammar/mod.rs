@@ -37,12 +37,37 @@ pub mod syntax;
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+
     use lady_deirdre::{
-        syntax::{Node, SyntaxTree},
-        units::Document,
+        assert_tree,
+        lexis::{Site, SourceCode, ToSpan, TokenBuffer, TokenRef},
+        syntax::{
+            check_coverage,
+            validate,
+            AbstractNode,
+            DebugObserver,
+            ImmutableSyntaxTree,
+            Key,
+            Node,
+            NodeRef,
+            NodeRule,
+            PolyRef,
+            RecoveryHalt,
+            RecoveryResult,
+            StdoutSink,
+            SyntaxTree,
+            ViolationKind,
+            VisitControl,
+            Visitor,
+            NON_RULE,
+            ROOT_RULE,
+        },
+        testing::TreeSnapshot,
+        units::{CompilationUnit, Document, ImmutableUnit, Transplant},
     };
 
-    use crate::json_grammar::syntax::JsonNode;
+    use crate::json_grammar::{lexis::JsonToken, syntax::JsonNode};
 
     #[test]
     fn test_json_parser() {
@@ -56,10 +81,823 @@ mod tests {
             }
         }"#;
 
-        JsonNode::debug(INPUT);
+        let doc = Document::<JsonNode>::new_immutable(INPUT);
+
+        assert_tree!(
+            doc,
+            r#"
+            Root
+              object: Object
+                start: $BraceOpen "{"
+                entries: Entry
+                  key: String
+                    value: $String "\"foo\""
+                  value: Array
+                    start: $BracketOpen "["
+                    items: Number
+                      value: $Number "1"
+                    items: Number
+                      value: $Number "2"
+                    items: Number
+                      value: $Number "3"
+                    end: $BracketClose "]"
+                entries: Entry
+                  key: String
+                    value: $String "\"bar\""
+                  value: Object
+                    start: $BraceOpen "{"
+                    entries: Entry
+                      key: String
+                        value: $String "\"a\""
+                      value: True
+                        token: $True "true"
+                    entries: Entry
+                      key: String
+                        value: $String "\"b\""
+                      value: False
+                        token: $False "false"
+                    entries: Entry
+                      key: String
+                        value: $String "\"c\""
+                      value: Null
+                        token: $Null "null"
+                    entries: Entry
+                      key: String
+                        value: $String "\"d\""
+                      value: String
+                        value: $String "\"string\""
+                    end: $BraceClose "}"
+                end: $BraceClose "}"
+            "#,
+        );
+
+        #[cfg(debug_assertions)]
+        assert_eq!(validate(&doc), Vec::new());
+    }
+
+    #[test]
+    fn test_json_coverage() {
+        static INPUT: &'static str = r#"{
+            "foo": [1, 2, 3],
+            "bar": {"a": true, "b": false, "c": null}
+        }"#;
 
         let doc = Document::<JsonNode>::new_immutable(INPUT);
 
+        assert_eq!(check_coverage(&doc), Vec::new());
+    }
+
+    #[test]
+    fn test_json_traverse() {
+        struct JsonVisitor<'a> {
+            doc: &'a Document<JsonNode>,
+            skip: NodeRule,
+            halt: NodeRule,
+            tokens: Vec<Site>,
+            nodes: Vec<NodeRef>,
+            left: usize,
+        }
+
+        impl<'a> JsonVisitor<'a> {
+            fn new(doc: &'a Document<JsonNode>, skip: NodeRule, halt: NodeRule) -> Self {
+                Self {
+                    doc,
+                    skip,
+                    halt,
+                    tokens: Vec::new(),
+                    nodes: Vec::new(),
+                    left: 0,
+                }
+            }
+        }
+
+        impl<'a> Visitor for JsonVisitor<'a> {
+            fn visit_token(&mut self, token_ref: &TokenRef) {
+                self.tokens.push(token_ref.site(self.doc).unwrap());
+            }
+
+            fn visit_node(&mut self, node_ref: &NodeRef) -> VisitControl {
+                self.nodes.push(*node_ref);
+
+                let rule = node_ref.rule(self.doc);
+
+                if rule == self.halt {
+                    return VisitControl::Stop;
+                }
+
+                // The top-level object is never skipped.
+                let nested = node_ref.parent(self.doc).rule(self.doc) != ROOT_RULE;
+
+                match rule == self.skip && nested {
+                    true => VisitControl::SkipChildren,
+                    false => VisitControl::Continue,
+                }
+            }
+
+            fn leave_node(&mut self, _node_ref: &NodeRef) {
+                self.left += 1;
+            }
+        }
+
+        static INPUT: &'static str = r#"{"a": {"b": 1}, "c": [2, {"d": null}], "e": 3}"#;
+
+        let doc = Document::<JsonNode>::new_immutable(INPUT);
+
+        let root = doc.root_node_ref();
+
+        let mut visitor = JsonVisitor::new(&doc, NON_RULE, NON_RULE);
+
+        doc.traverse_in_order(&root, &mut visitor);
+
+        let captured = doc
+            .chunks(..)
+            .filter(|chunk| {
+                !matches!(
+                    chunk.token,
+                    JsonToken::Whitespace | JsonToken::Comma | JsonToken::Colon,
+                )
+            })
+            .map(|chunk| chunk.site)
+            .collect::<Vec<_>>();
+
+        assert_eq!(visitor.tokens, captured);
+        assert_eq!(visitor.nodes.len(), doc.nodes().count());
+        assert_eq!(visitor.left, visitor.nodes.len());
+
+        let starts = visitor
+            .nodes
+            .iter()
+            .map(|node_ref| node_ref.span(&doc).unwrap().start)
+            .collect::<Vec<_>>();
+
+        assert!(starts.windows(2).all(|pair| pair[0] <= pair[1]));
+
+        let mut visitor = JsonVisitor::new(&doc, JsonNode::OBJECT, NON_RULE);
+
+        doc.traverse_in_order(&root, &mut visitor);
+
+        let rules = visitor
+            .nodes
+            .iter()
+            .map(|node_ref| node_ref.rule(&doc))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            rules,
+            [
+                ROOT_RULE,
+                JsonNode::OBJECT,
+                JsonNode::ENTRY,
+                JsonNode::STRING,
+                JsonNode::OBJECT,
+                JsonNode::ENTRY,
+                JsonNode::STRING,
+                JsonNode::ARRAY,
+                JsonNode::NUMBER,
+                JsonNode::OBJECT,
+                JsonNode::ENTRY,
+                JsonNode::STRING,
+                JsonNode::NUMBER,
+            ],
+        );
+        assert_eq!(visitor.left, visitor.nodes.len());
+
+        let mut visitor = JsonVisitor::new(&doc, NON_RULE, JsonNode::NULL);
+
+        doc.traverse_in_order(&root, &mut visitor);
+
+        assert_eq!(visitor.nodes.last().unwrap().rule(&doc), JsonNode::NULL);
+        assert_eq!(visitor.tokens.last(), INPUT.find(r#""d""#).as_ref());
+        assert_eq!(visitor.left, 9);
+    }
+
+    #[test]
+    fn test_json_siblings() {
+        let doc = Document::<JsonNode>::new_immutable(r#"{"a": [1, "two", null]}"#);
+
+        let root = doc.root_node_ref();
+
+        assert!(root.prev_sibling(&doc).is_nil());
+        assert!(root.next_sibling(&doc).is_nil());
+        assert_eq!(root.child_index_in_parent(&doc), None);
+
+        let object = root.first_child(&doc);
+        let entry = object.first_child(&doc);
+
+        assert_eq!(entry.rule(&doc), JsonNode::ENTRY);
+        assert_eq!(object.child_index_in_parent(&doc), Some(0));
+        assert_eq!(entry.child_index_in_parent(&doc), Some(0));
+
+        let array = entry.get_child(&doc, "value");
+
+        assert_eq!(array.rule(&doc), JsonNode::ARRAY);
+        assert_eq!(array.child_index_in_parent(&doc), Some(1));
+        assert_eq!(array.prev_sibling(&doc), entry.get_child(&doc, "key"));
+
+        let first = array.first_child(&doc);
+        let second = first.next_sibling(&doc);
+        let third = second.next_sibling(&doc);
+
+        assert_eq!(first.rule(&doc), JsonNode::NUMBER);
+        assert_eq!(second.rule(&doc), JsonNode::STRING);
+        assert_eq!(third.rule(&doc), JsonNode::NULL);
+
+        // The array's start and end token captures are not counted.
+        assert_eq!(first.child_index_in_parent(&doc), Some(0));
+        assert_eq!(second.child_index_in_parent(&doc), Some(1));
+        assert_eq!(third.child_index_in_parent(&doc), Some(2));
+
+        assert!(first.prev_sibling(&doc).is_nil());
+        assert_eq!(second.prev_sibling(&doc), first);
+        assert_eq!(third.prev_sibling(&doc), second);
+        assert!(third.next_sibling(&doc).is_nil());
+
+        assert_eq!(NodeRef::nil().child_index_in_parent(&doc), None);
+    }
+
+    #[test]
+    fn test_json_ancestors() {
+        static INPUTS: [&'static str; 4] = [
+            r#"{"a": [1, {"b": [true, null]}], "c": "d"}"#,
+            r#"{"a": }"#,
+            r#"{"a": [1, } ]}"#,
+            r#"{"a": , "b": 1}"#,
+        ];
+
+        for input in INPUTS {
+            let doc = Document::<JsonNode>::new_immutable(input);
+
+            let root = doc.root_node_ref();
+
+            assert_eq!(root.ancestors(&doc).count(), 0);
+            assert_eq!(root.depth(&doc), 0);
+
+            for node_ref in doc.node_refs() {
+                let mut sibling = node_ref.first_child(&doc);
+                let mut siblings = Vec::new();
+
+                while !sibling.is_nil() {
+                    if let Some(last) = siblings.last() {
+                        assert_eq!(sibling.prev_sibling(&doc), *last);
+                        assert!(last.span(&doc).unwrap().end <= sibling.span(&doc).unwrap().start);
+                    }
+
+                    siblings.push(sibling);
+                    sibling = sibling.next_sibling(&doc);
+                }
+
+                let children = node_ref
+                    .deref(&doc)
+                    .unwrap()
+                    .children_iter()
+                    .filter(|child| child.kind().is_node())
+                    .map(|child| *child.as_node_ref())
+                    .filter(|child| !child.is_nil())
+                    .collect::<Vec<_>>();
+
+                assert_eq!(siblings, children);
+
+                if node_ref == root {
+                    continue;
+                }
+
+                let ancestors = node_ref.ancestors(&doc).collect::<Vec<_>>();
+
+                assert_eq!(ancestors.first(), Some(&node_ref.parent(&doc)));
+                assert_eq!(ancestors.last(), Some(&root));
+                assert_eq!(node_ref.depth(&doc), ancestors.len());
+            }
+        }
+
+        let doc = Document::<JsonNode>::new_immutable(INPUTS[0]);
+
+        let null = doc
+            .node_refs()
+            .find(|node_ref| node_ref.rule(&doc) == JsonNode::NULL)
+            .unwrap();
+
+        let array = null.find_ancestor(&doc, JsonNode::ARRAY);
+        let object = null.find_ancestor(&doc, JsonNode::OBJECT);
+
+        assert_eq!(doc.substring(array.span(&doc).unwrap()), "[true, null]");
+        assert_eq!(
+            doc.substring(object.span(&doc).unwrap()),
+            r#"{"b": [true, null]}"#,
+        );
+        assert_eq!(null.find_ancestor(&doc, ROOT_RULE), doc.root_node_ref());
+        assert!(null.find_ancestor(&doc, JsonNode::TRUE).is_nil());
+        assert_eq!(null.depth(&doc), 7);
+
+        assert_eq!(NodeRef::nil().ancestors(&doc).count(), 0);
+        assert!(NodeRef::nil().find_ancestor(&doc, ROOT_RULE).is_nil());
+
+        // The entry's value is missing and is captured as a nil reference.
+        let doc = Document::<JsonNode>::new_immutable(INPUTS[1]);
+
+        let entry = doc.root_node_ref().first_child(&doc).first_child(&doc);
+        let key = entry.get_child(&doc, "key");
+
+        assert!(entry.get_child(&doc, "value").is_nil());
+        assert!(key.prev_sibling(&doc).is_nil());
+        assert!(key.next_sibling(&doc).is_nil());
+        assert_eq!(key.depth(&doc), 3);
+    }
+
+    #[test]
+    fn test_json_validation() {
+        let mut doc = Document::<JsonNode>::new_mutable(r#"{"a": [1, "two", null]}"#);
+
+        assert_eq!(validate(&doc), Vec::new());
+
+        let root = doc.root_node_ref();
+        let entry = root.first_child(&doc).first_child(&doc);
+        let key = entry.get_child(&doc, "key");
+
+        key.deref_mut(&mut doc).unwrap().set_parent_ref(root);
+
+        let violations = validate(&doc);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].node_ref, key);
+        assert_eq!(
+            violations[0].kind,
+            ViolationKind::ParentMismatch {
+                expected: entry,
+                actual: root,
+            },
+        );
+    }
+
+    #[test]
+    fn test_json_reparse() {
+        let mut doc =
+            Document::<JsonNode>::new_mutable(r#"{"a": [{"x": 1}, {"y": 2}, {"z": 3}, {"w": 4}]}"#);
+
+        assert_eq!(doc.last_reparse().unwrap().nodes.len(), 0);
+
+        doc.write(33..34, "30");
+
+        let report = doc.last_reparse().unwrap();
+
+        assert_eq!(report.nodes.len(), 1);
+        assert_eq!(report.nodes[0].rule(&doc), JsonNode::ENTRY);
+        assert_eq!(report.reused, 0);
+        assert!(report.span.contains(&33));
+        assert!(report.rescanned.start <= 33 && report.rescanned.end >= 35);
         assert!(doc.errors().next().is_none());
+
+        let damage = doc.last_damage().unwrap();
+
+        assert!(damage.start <= report.span.start && damage.end >= report.span.end);
+        assert!(damage.start <= report.rescanned.start);
+
+        // Inner objects are reused when the enclosing array is reparsed.
+        doc.write(7..7, " ");
+
+        let report = doc.last_reparse().unwrap();
+
+        assert!(report.reused >= 4);
+        assert!(report.reuse_ratio() > 0.5);
+        assert!(doc.substring(..).contains("30"));
+        assert!(doc.errors().next().is_none());
+
+        for node_ref in &report.nodes {
+            assert!(node_ref.is_valid_ref(&doc));
+        }
+
+        doc.write(0..0, "");
+
+        assert_eq!(doc.last_reparse().unwrap(), &Default::default());
+        assert_eq!(doc.last_damage(), Some(0..0));
+
+        doc.write(.., "[1, 2]");
+
+        assert_eq!(doc.last_damage(), Some(0..doc.length()));
+
+        let doc = Document::<JsonNode>::new_immutable("{}");
+
+        assert!(doc.last_reparse().is_none());
+        assert!(doc.last_damage().is_none());
+    }
+
+    #[test]
+    fn test_json_reparse_lookahead_to_end() {
+        // The parser looks ahead past the cached object cluster up to the end
+        // of input.
+        let mut doc = Document::<JsonNode>::new_mutable("[1,\n\n\r\n2]\n\r\n😀{\"a\": 1}");
+
+        doc.write(7..10, "\r");
+
+        assert_eq!(doc.substring(..), "[1,\n\n\r\n\r\r\n😀{\"a\": 1}");
+
+        let expected = Document::<JsonNode>::new_immutable(doc.substring(..));
+
+        assert_eq!(TreeSnapshot::new(&doc), TreeSnapshot::new(&expected));
+    }
+
+    #[test]
+    fn test_json_parse_rule() {
+        let unit = ImmutableUnit::<JsonNode>::parse_rule(JsonNode::ARRAY, "[1, true, {}]");
+
+        assert!(unit.errors().next().is_none());
+
+        let root = unit.root_node_ref();
+
+        assert_eq!(root.rule(&unit), JsonNode::ARRAY);
+        assert!(root.parent(&unit).is_nil());
+        assert_eq!(root.first_child(&unit).rule(&unit), JsonNode::NUMBER);
+        assert_eq!(root.last_child(&unit).rule(&unit), JsonNode::OBJECT);
+
+        #[cfg(debug_assertions)]
+        assert_eq!(validate(&unit), Vec::new());
+
+        let unit = ImmutableUnit::<JsonNode>::parse_rule(JsonNode::ARRAY, "{}");
+
+        assert!(unit.errors().next().is_some());
+        assert_eq!(unit.root_node_ref().rule(&unit), JsonNode::ARRAY);
+    }
+
+    #[test]
+    fn test_json_errors_in() {
+        let doc = Document::<JsonNode>::new_immutable(r#"{"a": 1, "b": [2 3], "c": 4, "d": }"#);
+
+        assert_eq!(doc.errors().count(), 2);
+        assert_eq!(doc.errors_in(..).count(), 2);
+        assert_eq!(doc.errors_in(14..20).count(), 1);
+        assert_eq!(doc.errors_in(10..35).count(), 2);
+
+        assert!(!doc.has_errors_in(0..10));
+        assert!(!doc.has_errors_in(21..29));
+        assert!(doc.has_errors_in(30..35));
+        assert!(doc.has_errors_in(33..33));
+    }
+
+    #[test]
+    fn test_json_typed_captures() {
+        let doc = Document::<JsonNode>::new_immutable(r#"{"a": [1, 2], "b": true}"#);
+
+        let object = doc.root().typed_capture(&JsonNode::ROOT_OBJECT).unwrap();
+        let object = object.deref(&doc).unwrap();
+
+        let entries = object.typed_capture(&JsonNode::OBJECT_ENTRIES).unwrap();
+
+        assert_eq!(entries.len(), 2);
+        assert!(object.typed_capture(&JsonNode::ARRAY_ITEMS).is_none());
+        assert_eq!(JsonNode::OBJECT_ENTRIES.key(), Key::Name("entries"));
+
+        let entry = entries[0].deref(&doc).unwrap();
+        let value = entry.typed_capture(&JsonNode::ENTRY_VALUE).unwrap();
+        let items = value
+            .deref(&doc)
+            .unwrap()
+            .typed_capture(&JsonNode::ARRAY_ITEMS);
+
+        assert_eq!(items.unwrap().len(), 2);
+
+        let start = object.typed_capture(&JsonNode::OBJECT_START).unwrap();
+
+        assert_eq!(start.string(&doc), Some("{"));
+    }
+
+    #[test]
+    fn test_json_recovery_details() {
+        let doc = Document::<JsonNode>::new_immutable(r#"{"a": [1 : : 2], "b": 3}"#);
+
+        let details = doc
+            .errors()
+            .filter_map(|error| error.recovery_details.clone())
+            .collect::<Vec<_>>();
+
+        assert_eq!(details.len(), 1);
+        // The ": : 2" fragment: three significant tokens and two whitespaces.
+        assert_eq!(details[0].skipped, 5);
+        assert_eq!(details[0].halt, RecoveryHalt::Expected);
+        assert_eq!(details[0].result(), RecoveryResult::PanicRecover);
+        assert_eq!(details[0].span.to_site_span(&doc), Some(9..14));
+
+        let doc = Document::<JsonNode>::new_immutable(r#"{"a": : : }"#);
+
+        let details = doc
+            .errors()
+            .filter_map(|error| error.recovery_details.clone())
+            .collect::<Vec<_>>();
+
+        assert_eq!(details.len(), 1);
+        // The ": : " fragment: two colons and two whitespaces.
+        assert_eq!(details[0].skipped, 4);
+        assert_eq!(details[0].halt, RecoveryHalt::GroupBoundary);
+        assert_eq!(details[0].result(), RecoveryResult::UnexpectedToken);
+    }
+
+    #[test]
+    fn test_json_token_recovery() {
+        let doc = Document::<JsonNode>::new_immutable(r#"{"a" 1}"#);
+
+        assert_tree!(
+            doc,
+            r#"
+            Root
+              object: Object
+                start: $BraceOpen "{"
+                entries: Entry
+                  key: String
+                    value: $String "\"a\""
+                  value: Number
+                    value: $Number "1"
+                end: $BraceClose "}"
+            ! missing ':' in Entry [4..5]
+            "#,
+        );
+
+        let error = doc.errors().next().unwrap();
+
+        assert_eq!(error.context, JsonNode::ENTRY);
+        assert_eq!(error.recovery, RecoveryResult::InsertRecover);
+        assert_eq!(error.recovery_token, Some(JsonToken::Colon as u8));
+
+        let doc = Document::<JsonNode>::new_immutable(r#"{"a": : 1}"#);
+
+        let errors = doc.errors().collect::<Vec<_>>();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].recovery, RecoveryResult::DeleteRecover);
+        assert_eq!(errors[0].recovery_token, Some(JsonToken::Colon as u8));
+        assert_eq!(errors[0].recovery_details, None);
+        assert_eq!(
+            errors[0].message::<JsonNode>(&doc).to_string(),
+            "unexpected ':' in Entry",
+        );
+
+        let doc = Document::<JsonNode>::new_immutable(r#"{"a": 1"#);
+
+        let errors = doc.errors().collect::<Vec<_>>();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].context, JsonNode::OBJECT);
+        assert_eq!(errors[0].recovery, RecoveryResult::InsertRecover);
+        assert_eq!(errors[0].recovery_token, Some(JsonToken::BraceClose as u8));
+
+        // Two unexpected tokens in a row exceed the single-token strategies.
+        let doc = Document::<JsonNode>::new_immutable(r#"{"a": : : 1}"#);
+
+        let errors = doc.errors().collect::<Vec<_>>();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].recovery, RecoveryResult::PanicRecover);
+        assert_eq!(errors[0].recovery_token, None);
+    }
+
+    #[test]
+    fn test_json_transplants() {
+        let mut doc = Document::<JsonNode>::new_mutable(r#"{"a": {"x": 1}}"#);
+
+        let entry = doc.root_node_ref().first_child(&doc).first_child(&doc);
+        let object = entry.get_child(&doc, "value");
+
+        assert_eq!(object.rule(&doc), JsonNode::OBJECT);
+
+        doc.write(14..14, "]");
+        doc.write(6..6, "[");
+
+        assert!(doc.errors().next().is_none());
+
+        let array = entry.get_child(&doc, "value");
+
+        assert_eq!(array.rule(&doc), JsonNode::ARRAY);
+        assert_eq!(array.first_child(&doc), object);
+        assert_eq!(object.parent(&doc), array);
+
+        assert_eq!(
+            doc.last_transplants(),
+            &[Transplant {
+                node_ref: object,
+                old_parent_ref: entry,
+                new_parent_ref: array,
+            }],
+        );
+
+        doc.write(0..0, " ");
+
+        assert!(doc.last_transplants().is_empty());
+    }
+
+    #[test]
+    fn test_json_transplants_without_parent() {
+        // The nodes of this grammar do not store their parent references.
+        #[derive(Node)]
+        #[token(JsonToken)]
+        #[trivia($Whitespace)]
+        enum ListNode {
+            #[root]
+            #[rule(value: (List | Tuple))]
+            Root {
+                #[child]
+                value: NodeRef,
+            },
+
+            #[rule($BracketOpen (items: (List | Tuple | Item))*{$Comma} $BracketClose)]
+            #[denote(LIST)]
+            List {
+                #[child]
+                items: Vec<NodeRef>,
+            },
+
+            #[rule($BraceOpen (items: (List | Tuple | Item))*{$Comma} $BraceClose)]
+            #[denote(TUPLE)]
+            Tuple {
+                #[child]
+                items: Vec<NodeRef>,
+            },
+
+            #[rule(value: $Number)]
+            #[secondary]
+            Item {
+                #[child]
+                value: TokenRef,
+            },
+        }
+
+        let mut doc = Document::<ListNode>::new_mutable("[{[1]}]");
+
+        let outer = doc.root_node_ref().first_child(&doc);
+        let tuple = outer.first_child(&doc);
+
+        assert_eq!(tuple.rule(&doc), ListNode::TUPLE);
+        assert!(tuple.parent(&doc).is_nil());
+
+        doc.write(6..6, "]");
+        doc.write(1..1, "[");
+
+        assert_eq!(doc.substring(..), "[[{[1]}]]");
+        assert!(doc.errors().next().is_none());
+
+        let wrapper = outer.first_child(&doc);
+
+        assert_eq!(wrapper.rule(&doc), ListNode::LIST);
+        assert_eq!(wrapper.first_child(&doc), tuple);
+        assert!(tuple.parent(&doc).is_nil());
+        assert!(doc.last_transplants().is_empty());
+    }
+
+    #[test]
+    fn test_json_reparse_root_errors() {
+        let mut doc = Document::<JsonNode>::new_mutable(r#"{"a": [1], "c": "d"}"#);
+
+        doc.write(1..1, "}");
+
+        assert_eq!(doc.substring(..), r#"{}"a": [1], "c": "d"}"#);
+        assert!(doc.errors().count() > 0);
+
+        doc.write(1..2, "");
+
+        assert_eq!(doc.substring(..), r#"{"a": [1], "c": "d"}"#);
+        assert_eq!(doc.errors().count(), 0);
+    }
+
+    #[test]
+    fn test_json_tree_stats() {
+        fn check(doc: &Document<JsonNode>) {
+            let mut stats = doc.stats();
+
+            let mut rules = BTreeMap::new();
+            let mut children = 0;
+
+            for node in doc.nodes() {
+                *rules.entry(node.rule()).or_default() += 1;
+                children += node.children_iter().count();
+            }
+
+            let max_depth = doc
+                .node_refs()
+                .map(|node_ref| node_ref.depth(doc) + 1)
+                .max()
+                .unwrap_or_default();
+
+            assert_eq!(stats.nodes, doc.nodes().count());
+            assert_eq!(stats.rules, rules);
+            assert_eq!(stats.children, children);
+            assert_eq!(stats.max_depth, max_depth);
+            assert_eq!(stats.errors, doc.errors().count());
+            assert_eq!(stats.tokens, doc.chunks(..).count());
+            assert_eq!(stats.text_bytes, doc.substring(..).len());
+            assert!(stats.approx_heap_bytes >= stats.text_bytes);
+
+            let mut expected = Document::<JsonNode>::new_immutable(doc.substring(..)).stats();
+
+            expected.approx_heap_bytes = 0;
+            stats.approx_heap_bytes = 0;
+
+            assert_eq!(stats, expected);
+        }
+
+        let mut doc =
+            Document::<JsonNode>::new_mutable(r#"{"a": [1, {"b": [true, null]}], "c": "d"}"#);
+
+        check(&doc);
+
+        assert_eq!(doc.stats().nodes, 15);
+        assert_eq!(doc.stats().max_depth, 8);
+        assert_eq!(doc.stats().rule_count(JsonNode::ENTRY), 3);
+
+        doc.write(27..27, ", [[[0]]]");
+
+        check(&doc);
+
+        assert_eq!(doc.stats().max_depth, 11);
+
+        doc.write(32..32, r#""ёж", "#);
+
+        check(&doc);
+
+        assert_eq!(doc.stats().text_bytes, doc.length() + 2);
+
+        doc.write(8..44, "");
+
+        check(&doc);
+
+        assert_eq!(doc.substring(..), r#"{"a": [1], "c": "d"}"#);
+        assert_eq!(doc.stats().max_depth, 5);
+
+        doc.write(1..1, "}");
+
+        check(&doc);
+
+        assert!(doc.stats().errors > 0);
+
+        doc.write(1..2, "");
+
+        check(&doc);
+
+        assert_eq!(doc.stats().errors, 0);
+
+        doc.write(.., "");
+
+        check(&doc);
+
+        assert_eq!(doc.stats().tokens, 0);
+        assert_eq!(doc.stats().text_bytes, 0);
+    }
+
+    #[test]
+    fn test_json_debug_output() {
+        let steps = JsonNode::debug_string(r#"{"a": 1 "b"}"#);
+
+        assert_eq!(
+            steps,
+            concat!(
+                " Root {\n",
+                "     Object {\n",
+                "         $BraceOpen\n",
+                "         Entry {\n",
+                "             String {\n",
+                "                 $String\n",
+                "             } String\n",
+                "             $Colon\n",
+                "             $Whitespace\n",
+                "             Number {\n",
+                "                 $Number\n",
+                "             } Number\n",
+                "         } Entry\n",
+                "         $Whitespace\n",
+                "         --- error ---\n",
+                "         Entry {\n",
+                "             String {\n",
+                "                 $String\n",
+                "             } String\n",
+                "             --- error ---\n",
+                "         } Entry\n",
+                "         $BraceClose\n",
+                "     } Object\n",
+                " } Root\n",
+            ),
+        );
+
+        assert_eq!(
+            JsonNode::debug_display(r#"{"a": 1 "b"}"#).to_string(),
+            steps
+        );
+
+        let doc = Document::<JsonNode>::new_immutable(r#"{"a": 1}"#);
+        let entry = doc.root_node_ref().first_child(&doc).first_child(&doc);
+
+        assert_eq!(
+            doc.display(&entry.get_child(&doc, "value")).to_string(),
+            r#"Number { value: $Number { string: "1", length: 1, site_span: 6..7, position_span: 1:7 (1 char) } }"#,
+        );
+    }
+
+    #[test]
+    fn test_json_debug_observer_default() {
+        let tokens = TokenBuffer::<JsonToken>::from(r#"{"a": 1}"#);
+
+        let mut observer = DebugObserver::default();
+
+        let tree =
+            ImmutableSyntaxTree::<JsonNode>::parse_with_observer(tokens.cursor(..), &mut observer);
+
+        assert_eq!(tree.errors().count(), 0);
+        assert!(observer.result().is_ok());
+
+        let _: StdoutSink = observer.into_output();
     }
 }
@@ -40,14 +40,17 @@ use lady_deirdre::{
 use crate::json_grammar::lexis::JsonToken;
 
 #[derive(Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[token(JsonToken)]
-#[trivia($Whitespace)]
+#[trivia($Whitespace | $Comment)]
 #[define(ANY = Object | Array | True | False | String | Number | Null)]
 #[recovery(
     $BraceClose,
     $BracketClose,
     [$BraceOpen..$BraceClose],
     [$BracketOpen..$BracketClose],
+    insert_limit = 1,
+    delete_limit = 1,
 )]
 pub enum JsonNode {
     #[root]
@@ -66,6 +69,8 @@ pub enum JsonNode {
     #[recovery(
         [$BraceOpen..$BraceClose],
         [$BracketOpen..$BracketClose],
+        insert_limit = 1,
+        delete_limit = 1,
     )]
     Object {
         #[node]
@@ -98,6 +103,8 @@ pub enum JsonNode {
     #[recovery(
         [$BraceOpen..$BraceClose],
         [$BracketOpen..$BracketClose],
+        insert_limit = 1,
+        delete_limit = 1,
     )]
     Array {
         #[node]
@@ -36,7 +36,8 @@ use std::fmt::{Display, Formatter};
 
 use lady_deirdre::{
     format::{AnnotationPriority, Highlighter, SnippetFormatter, Style},
-    lexis::PositionSpan,
+    lexis::{PositionSpan, SiteSpan},
+    syntax::{PolyRef, SyntaxTree},
     units::Document,
 };
 
@@ -54,7 +55,8 @@ impl<'a> Display for JsonSnippet<'a> {
         snippet
             .set_caption("Header text")
             .set_summary("Footer text.")
-            .set_highlighter(JsonHighlighter);
+            .set_highlighter(JsonHighlighter)
+            .set_span_styles(key_styles(self.doc));
 
         for (span, priority, message) in &self.annotation {
             snippet.annotate(span, *priority, *message);
@@ -64,22 +66,52 @@ impl<'a> Display for JsonSnippet<'a> {
     }
 }
 
+// Unlike the JsonHighlighter that stylizes individual tokens, this function
+// stylizes the object keys based on the syntax tree structure.
+pub fn key_styles(doc: &Document<JsonNode>) -> Vec<(SiteSpan, Style)> {
+    doc.nodes()
+        .filter_map(|node| match node {
+            JsonNode::Entry { key, .. } => Some((key.span(doc)?, THEME.key)),
+            _ => None,
+        })
+        .collect()
+}
+
+// A statically defined color theme of the JSON snippets.
+pub struct JsonTheme {
+    pub keyword: Style,
+    pub keyword_dim: Style,
+    pub string: Style,
+    pub string_dim: Style,
+    pub brace: Style,
+    pub key: Style,
+}
+
+pub static THEME: JsonTheme = JsonTheme {
+    keyword: Style::new().blue(),
+    keyword_dim: Style::new().bright_blue(),
+    string: Style::new().green(),
+    string_dim: Style::new().bright_green(),
+    brace: Style::new().bold(),
+    key: Style::new().magenta(),
+};
+
 pub struct JsonHighlighter;
 
 impl Highlighter<JsonToken> for JsonHighlighter {
     fn token_style(&mut self, dim: bool, token: JsonToken) -> Option<Style> {
         match token {
             JsonToken::True | JsonToken::False | JsonToken::Null => Some(match dim {
-                false => Style::new().blue(),
-                true => Style::new().bright_blue(),
+                false => THEME.keyword,
+                true => THEME.keyword_dim,
             }),
 
             JsonToken::String => Some(match dim {
-                false => Style::new().green(),
-                true => Style::new().bright_green(),
+                false => THEME.string,
+                true => THEME.string_dim,
             }),
 
-            JsonToken::BraceOpen | JsonToken::BraceClose => Some(Style::new().bold()),
+            JsonToken::BraceOpen | JsonToken::BraceClose => Some(THEME.brace),
 
             _ => None,
         }
@@ -36,9 +36,28 @@ pub mod highlighter;
 
 #[cfg(test)]
 mod tests {
-    use lady_deirdre::{format::AnnotationPriority, lexis::Position, units::Document};
+    use lady_deirdre::{
+        arena::Identifiable,
+        format::{
+            AnnotationLayout,
+            AnnotationPriority,
+            DiagnosticReport,
+            DiffLayout,
+            DiffSide,
+            DiffSnippet,
+            Snippet,
+            SnippetConfig,
+            Style,
+            TerminalString,
+        },
+        lexis::{Position, SourceCode},
+        units::Document,
+    };
 
-    use crate::json_highlight::highlighter::JsonSnippet;
+    use crate::{
+        json_grammar::syntax::JsonNode,
+        json_highlight::highlighter::{key_styles, JsonHighlighter, JsonSnippet},
+    };
 
     #[test]
     fn test_snippet() {
@@ -83,4 +102,578 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_standalone_snippet() {
+        let doc = Document::new_immutable(r#"{"a": [1, 2 3]}"#);
+
+        let annotation = Position::new(1, 13)..Position::new(1, 14);
+
+        let mut snippet = Snippet::new(&doc);
+
+        snippet
+            .set_caption("Header text")
+            .set_summary("Footer text.")
+            .set_highlighter(JsonHighlighter)
+            .annotate(&annotation, AnnotationPriority::Primary, "Message.");
+
+        let string = snippet.render_to_string();
+
+        assert_eq!(
+            string,
+            JsonSnippet {
+                doc: &doc,
+                annotation: vec![(annotation.clone(), AnnotationPriority::Primary, "Message.")],
+            }
+            .to_string(),
+        );
+
+        let mut bytes = Vec::new();
+
+        snippet.render_to_io(&mut bytes).unwrap();
+
+        assert_eq!(String::from_utf8(bytes).unwrap(), string);
+        assert!(snippet.finish().is_err());
+    }
+
+    #[test]
+    fn test_diff_snippet() {
+        let old =
+            Document::<JsonNode>::new_immutable("{\n    \"a\": 1,\n    \"b\": 2,\n    \"c\": 3\n}");
+        let new = Document::<JsonNode>::new_immutable(
+            "{\n    \"a\": 1,\n    \"b\": [2],\n    \"c\": 3\n}",
+        );
+
+        let mut config = SnippetConfig::verbose();
+
+        config.style = false;
+        config.draw_frame = false;
+
+        let mut snippet = DiffSnippet::new(&old, &new);
+
+        snippet.set_config(&config).annotate(
+            DiffSide::New,
+            23..26,
+            AnnotationPriority::Primary,
+            "Array.",
+        );
+
+        assert_eq!(
+            snippet.render_to_string(),
+            concat!(
+                " 1 1 │   {\n",
+                " 2 2 │       \"a\": 1,\n",
+                " 3   │ -     \"b\": 2,\n",
+                "   3 │ +     \"b\": [2],\n",
+                "     │            ╰╴ Array.\n",
+                " 4 4 │       \"c\": 3\n",
+                " 5 5 │   }",
+            ),
+        );
+
+        snippet.set_layout(DiffLayout::SideBySide);
+
+        let side_by_side = snippet.render_to_string();
+
+        assert!(side_by_side.contains(" 3 │ -     \"b\": 2, "));
+        assert!(side_by_side.contains("│ 3 │ +     \"b\": [2],"));
+
+        let old = Document::<JsonNode>::new_immutable("[\n1,\n2,\n3,\n4,\n5,\n6,\n7,\n8\n]");
+        let new = Document::<JsonNode>::new_immutable("[\n1,\n2,\n3,\n4,\n5,\n6,\n7,\n9\n]");
+
+        let mut snippet = DiffSnippet::new(&old, &new);
+
+        snippet.set_config(&config);
+
+        assert_eq!(
+            snippet.render_to_string(),
+            concat!(
+                "     … │\n",
+                "  7  7 │   6,\n",
+                "  8  8 │   7,\n",
+                "  9    │ - 8\n",
+                "     9 │ + 9\n",
+                " 10 10 │   ]",
+            ),
+        );
+    }
+
+    #[test]
+    fn test_snippet_links() {
+        let doc = Document::<JsonNode>::new_immutable(r#"{"a": [1, 2 3]}"#);
+
+        let config = SnippetConfig::verbose();
+        let mut no_links = config;
+
+        no_links.links = false;
+
+        let mut snippet = Snippet::new(&doc);
+
+        snippet
+            .set_config(&config)
+            .set_caption("a.json:1:13")
+            .set_caption_link("file:///a.json")
+            .annotate_with_link(
+                12..13,
+                AnnotationPriority::Primary,
+                "Missing comma.",
+                "https://www.json.org",
+            );
+
+        let string = snippet.render_to_string();
+
+        assert!(string.contains("\x1B]8;;file:///a.json\x1B\\a.json:1:13\x1B]8;;\x1B\\"));
+        assert!(string.contains("\x1B]8;;https://www.json.org\x1B\\Missing comma."));
+
+        let widths = string
+            .sanitize()
+            .lines()
+            .map(|line| line.chars().count())
+            .collect::<Vec<_>>();
+
+        assert!(widths.iter().all(|width| *width == widths[0]));
+
+        let string = snippet.set_config(&no_links).render_to_string();
+
+        assert!(!string.contains("\x1B]"));
+        assert!(string.contains("a.json:1:13"));
+    }
+
+    #[test]
+    fn test_snippet_tabs_and_line_numbers() {
+        let doc = Document::<JsonNode>::new_immutable("{\n\t\"a\": 1\n}");
+
+        let mut config = SnippetConfig::minimal();
+
+        config.show_numbers = true;
+        config.tab_width = 8;
+        config.first_line_number = 41;
+
+        let mut snippet = Snippet::new(&doc);
+
+        snippet
+            .set_config(&config)
+            .annotate(3..6, AnnotationPriority::Primary, "Key.");
+
+        assert_eq!(
+            snippet.render_to_string(),
+            concat!(
+                " 41 │ {\n",
+                " 42 │         \"a\": 1\n",
+                "    │         ╰╴ Key.\n",
+                " 43 │ }",
+            ),
+        );
+    }
+
+    #[test]
+    fn test_snippet_tab_stops() {
+        let doc = Document::<JsonNode>::new_immutable("{\n\t\"ab\":\t\t1\n}");
+
+        let render = |tab_width| {
+            let mut config = SnippetConfig::minimal();
+
+            config.show_numbers = true;
+            config.tab_width = tab_width;
+
+            let mut snippet = Snippet::new(&doc);
+
+            snippet
+                .set_config(&config)
+                .annotate(10..11, AnnotationPriority::Primary, "Value.");
+
+            snippet.render_to_string()
+        };
+
+        assert_eq!(doc.lines().visual_column(10, 2), 11);
+        assert_eq!(
+            render(2),
+            concat!(
+                " 1 │ {\n",
+                " 2 │   \"ab\":   1\n",
+                "   │           ╰╴ Value.\n",
+                " 3 │ }",
+            ),
+        );
+
+        assert_eq!(doc.lines().visual_column(10, 4), 17);
+        assert_eq!(
+            render(4),
+            concat!(
+                " 1 │ {\n",
+                " 2 │     \"ab\":       1\n",
+                "   │                 ╰╴ Value.\n",
+                " 3 │ }",
+            ),
+        );
+
+        assert_eq!(doc.lines().visual_column(10, 8), 25);
+        assert_eq!(
+            render(8),
+            concat!(
+                " 1 │ {\n",
+                " 2 │         \"ab\":           1\n",
+                "   │                         ╰╴ Value.\n",
+                " 3 │ }",
+            ),
+        );
+    }
+
+    #[test]
+    fn test_snippet_truncation() {
+        let mut text = String::from("[");
+
+        for item in 1..=20 {
+            text.push_str(&format!("\n    {item},"));
+        }
+
+        text.push_str("\n    0\n]");
+
+        let doc = Document::<JsonNode>::new_immutable(text);
+
+        let start = doc.lines().line_start(3);
+        let end = doc.lines().line_start(20) - 1;
+
+        let mut config = SnippetConfig::minimal();
+
+        config.show_numbers = true;
+
+        let mut total_config = config;
+
+        config.max_region_lines = Some(4);
+        total_config.max_lines = Some(5);
+
+        let mut snippet = Snippet::new(&doc);
+
+        snippet
+            .set_config(&config)
+            .annotate(start..end, AnnotationPriority::Primary, "Region.");
+
+        assert_eq!(
+            snippet.render_to_string(),
+            concat!(
+                "  1 │ [\n",
+                "  2 │     1,\n",
+                "  3 │     2,\n",
+                "    │ ╰╴ Region.\n",
+                "  4 │     3,\n",
+                "  … │ … 13 lines omitted …\n",
+                " 18 │     17,\n",
+                " 19 │     18,\n",
+                " 20 │     19,\n",
+                " 21 │     20,\n",
+                " 22 │ ",
+            ),
+        );
+
+        let mut snippet = Snippet::new(&doc);
+
+        snippet
+            .set_config(&total_config)
+            .annotate(start..start + 1, AnnotationPriority::Primary, "First.")
+            .annotate(end - 1..end, AnnotationPriority::Primary, "Last.");
+
+        assert_eq!(
+            snippet.render_to_string(),
+            concat!(
+                "  1 │ [\n",
+                "  2 │     1,\n",
+                "  3 │     2,\n",
+                "    │ ╰╴ First.\n",
+                "  … │ … 15 lines omitted …\n",
+                " 19 │     18,\n",
+                "    │       ╰╴ Last.\n",
+                "  … │ … 1 line omitted …\n",
+                " 21 │     20,\n",
+                " 22 │ ",
+            ),
+        );
+    }
+
+    #[test]
+    fn test_snippet_footnotes() {
+        let doc = Document::<JsonNode>::new_immutable("{\"a\": 1, \"b\": 2,\n\"c\": 3}");
+
+        let mut config = SnippetConfig::minimal();
+
+        config.show_numbers = true;
+        config.annotation_layout = AnnotationLayout::Mixed(1);
+
+        let mut snippet = Snippet::new(&doc);
+
+        snippet
+            .set_config(&config)
+            .annotate(1..4, AnnotationPriority::Note, "First key.")
+            .annotate(9..12, AnnotationPriority::Primary, "Second key.")
+            .annotate(17..20, AnnotationPriority::Secondary, "Third key.");
+
+        assert_eq!(
+            snippet.render_to_string(),
+            concat!(
+                " 1 │ {[1]\"a\": 1, [2]\"b\": 2,\n",
+                " 2 │ \"c\": 3}\n",
+                "   │ ╰╴ Third key.\n",
+                "   │ [2] Second key.\n",
+                "   │ [1] First key.",
+            ),
+        );
+    }
+
+    #[test]
+    fn test_snippet_multiline_messages() {
+        let doc = Document::<JsonNode>::new_immutable(r#"{"a": [1, 2], "b": 3}"#);
+
+        let mut config = SnippetConfig::minimal();
+
+        config.show_numbers = true;
+
+        let mut footnotes_config = config;
+
+        footnotes_config.annotation_layout = AnnotationLayout::Footnotes;
+
+        let mut snippet = Snippet::new(&doc);
+
+        snippet.set_config(&config).annotate(
+            6..12,
+            AnnotationPriority::Primary,
+            "Expected type Object.\nFound type Array.",
+        );
+
+        assert_eq!(
+            snippet.render_to_string(),
+            concat!(
+                " 1 │ {\"a\": [1, 2], \"b\": 3}\n",
+                "   │       ╰╴ Expected type Object.\n",
+                "   │          Found type Array.",
+            ),
+        );
+
+        let mut snippet = Snippet::new(&doc);
+
+        snippet
+            .set_config(&config)
+            .annotate(
+                1..4,
+                AnnotationPriority::Secondary,
+                "Key \"a\".\nFirst entry.",
+            )
+            .annotate(6..12, AnnotationPriority::Primary, "Array.\nTwo items.")
+            .annotate(20..21, AnnotationPriority::Note, "Number.");
+
+        assert_eq!(
+            snippet.render_to_string(),
+            concat!(
+                "   │  ╭╴ Key \"a\".\n",
+                "   │  │  First entry.\n",
+                " 1 │ {\"a\": [1, 2], \"b\": 3}\n",
+                "   │       ╰╴ Array.     ╰╴ Number.\n",
+                "   │          Two items.",
+            ),
+        );
+
+        let mut snippet = Snippet::new(&doc);
+
+        snippet.set_config(&footnotes_config).annotate(
+            6..12,
+            AnnotationPriority::Primary,
+            "Array.\nTwo items.",
+        );
+
+        assert_eq!(
+            snippet.render_to_string(),
+            concat!(
+                " 1 │ {\"a\": [1][1, 2], \"b\": 3}\n",
+                "   │ [1] Array.\n",
+                "   │     Two items.",
+            ),
+        );
+    }
+
+    #[test]
+    fn test_snippet_span_styles() {
+        let doc = Document::<JsonNode>::new_immutable(r#"{"a": "b"}"#);
+
+        let mut config = SnippetConfig::minimal();
+
+        config.style = true;
+
+        let mut snippet = Snippet::new(&doc);
+
+        snippet
+            .set_config(&config)
+            .set_highlighter(JsonHighlighter)
+            .set_span_styles(key_styles(&doc));
+
+        assert_eq!(
+            snippet.render_to_string(),
+            concat!(
+                "\u{1b}[1m\u{1b}[1m{\u{1b}[0m",
+                "\u{1b}[38;5;5m\"a\"\u{1b}[39m: ",
+                "\u{1b}[38;5;2m\"b\"\u{1b}[1m\u{1b}[39m}\u{1b}[0m",
+            ),
+        );
+
+        snippet.annotate(1..4, AnnotationPriority::Primary, "");
+
+        assert!(!snippet.render_to_string().contains("\u{1b}[38;5;5m"));
+    }
+
+    #[test]
+    fn test_snippet_palette() {
+        let doc = Document::<JsonNode>::new_immutable(r#"{"a": "b"}"#);
+
+        let mut config = SnippetConfig::minimal();
+
+        config.style = true;
+        config.show_numbers = true;
+        config.palette.primary = Style::new().invert().bright_cyan();
+        config.palette.numbers = Style::new().bright_yellow();
+
+        let mut snippet = Snippet::new(&doc);
+
+        snippet
+            .set_config(&config)
+            .annotate(1..4, AnnotationPriority::Primary, "");
+
+        assert_eq!(
+            snippet.render_to_string(),
+            concat!(
+                "\u{1b}[38;5;11m 1 \u{1b}[39m│ {",
+                "\u{1b}[7m\u{1b}[38;5;14m\"a\"\u{1b}[0m: \"b\"}",
+            ),
+        );
+    }
+
+    #[test]
+    fn test_snippet_related() {
+        let main = Document::<JsonNode>::new_immutable("{\"a\": 1,\n\"a\": 2}");
+        let other = Document::<JsonNode>::new_immutable("{\n\"x\": true,\n\"a\": 3\n}");
+
+        other.id().set_name("other.json");
+
+        let mut config = SnippetConfig::minimal();
+
+        config.show_numbers = true;
+        config.summary = true;
+
+        let mut snippet = Snippet::new(&main);
+
+        snippet
+            .set_config(&config)
+            .set_summary("See [1].")
+            .annotate(10..13, AnnotationPriority::Primary, "Duplicate key.")
+            .related(&other, 13..16, AnnotationPriority::Note, "Defined here.");
+
+        assert_eq!(
+            snippet.render_to_string(),
+            concat!(
+                "   ╭──\n",
+                " 1 │ {\"a\": 1,\n",
+                " 2 │ \"a\": 2}\n",
+                "   │  ╰╴ Duplicate key.\n",
+                "   ╞══\n",
+                "   │ [1] other.json:3:1\n",
+                " 1 │ {\n",
+                " 2 │ \"x\": true,\n",
+                " 3 │ \"a\": 3\n",
+                "   │ ╰╴ Defined here.\n",
+                " 4 │ }\n",
+                "   ╞══\n",
+                "   │ See [1].\n",
+                "   ╰──",
+            ),
+        );
+    }
+
+    #[test]
+    fn test_diagnostic_report() {
+        let doc = Document::<JsonNode>::new_immutable("{\"a\": 1,\n\"a\": 2}");
+
+        doc.id().set_name("main.json");
+
+        let mut config = SnippetConfig::minimal();
+
+        config.show_numbers = true;
+
+        let mut snippet = Snippet::new(&doc);
+
+        snippet
+            .set_config(&config)
+            .annotate(10..13, AnnotationPriority::Primary, "Duplicate key.")
+            .annotate(2..3, AnnotationPriority::Note, "Defined here.");
+
+        let rendered = snippet.render_to_string();
+
+        let mut report = DiagnosticReport::new(&doc);
+
+        report
+            .set_caption("Duplicate \"a\" keys.")
+            .annotate(10..13, AnnotationPriority::Primary, "Duplicate key.")
+            .annotate(2..3, AnnotationPriority::Note, "Defined here.");
+
+        // Each annotation's marker row follows the numbered row of the line
+        // where the annotated span starts, and the marker points to the start
+        // column.
+        let mut rendered_positions = Vec::new();
+        let mut line = 0;
+
+        for row in rendered.lines() {
+            let (gutter, text) = row.split_once(" │ ").unwrap();
+
+            if let Ok(number) = gutter.trim().parse::<usize>() {
+                line = number;
+                continue;
+            }
+
+            let (indent, message) = text.split_once("╰╴ ").unwrap();
+
+            rendered_positions.push((message, Position::new(line, indent.chars().count() + 1)));
+        }
+
+        let report_positions = report
+            .diagnostics()
+            .iter()
+            .map(|diagnostic| (diagnostic.message.as_str(), diagnostic.span.start))
+            .collect::<Vec<_>>();
+
+        rendered_positions.sort();
+
+        assert_eq!(
+            rendered_positions,
+            [
+                ("Defined here.", report_positions[1].1),
+                ("Duplicate key.", report_positions[0].1),
+            ],
+        );
+
+        let file = format!(
+            r#"{{"file":{{"id":{},"name":"main.json"}},"#,
+            doc.id().into_inner(),
+        );
+
+        assert_eq!(
+            report.to_json_string(),
+            file + concat!(
+                r#""caption":"Duplicate \"a\" keys.","summary":"","diagnostics":["#,
+                r#"{"severity":"error","message":"Duplicate key.","#,
+                r#""range":{"start":{"line":2,"column":2},"end":{"line":2,"column":5}}},"#,
+                r#"{"severity":"hint","message":"Defined here.","#,
+                r#""range":{"start":{"line":1,"column":3},"end":{"line":1,"column":4}}}]}"#,
+            ),
+        );
+
+        assert_eq!(
+            report.to_sarif_string("json-lint"),
+            concat!(
+                r#"{"$schema":"https://json.schemastore.org/sarif-2.1.0.json","#,
+                r#""version":"2.1.0","runs":[{"tool":{"driver":{"name":"json-lint"}},"#,
+                r#""results":[{"level":"error","message":{"text":"Duplicate key."},"#,
+                r#""locations":[{"physicalLocation":{"artifactLocation":{"uri":"main.json"},"#,
+                r#""region":{"startLine":2,"startColumn":2,"endLine":2,"endColumn":5}}}]},"#,
+                r#"{"level":"none","message":{"text":"Defined here."},"#,
+                r#""locations":[{"physicalLocation":{"artifactLocation":{"uri":"main.json"},"#,
+                r#""region":{"startLine":1,"startColumn":3,"endLine":1,"endColumn":4}}}]}]}]}"#,
+            ),
+        );
+    }
 }
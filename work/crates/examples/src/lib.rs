@@ -36,8 +36,11 @@
 #![allow(warnings)]
 
 pub mod chain_analysis;
+pub mod contextual_keywords;
 pub mod expr_parser;
+pub mod json_error_rules;
 pub mod json_formatter;
 pub mod json_grammar;
 pub mod json_highlight;
+pub mod logos_json;
 pub mod shared_semantics;
@@ -0,0 +1,154 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use lady_deirdre::lexis::{ForeignScanner, Length, TokenRule};
+use logos::Logos;
+
+#[derive(Logos, Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum LogosJsonToken {
+    EOI = 0,
+
+    Mismatch = 1,
+
+    #[token("true")]
+    True,
+
+    #[token("false")]
+    False,
+
+    #[token("null")]
+    Null,
+
+    #[token("{")]
+    BraceOpen,
+
+    #[token("}")]
+    BraceClose,
+
+    #[token("[")]
+    BracketOpen,
+
+    #[token("]")]
+    BracketClose,
+
+    #[token(",")]
+    Comma,
+
+    #[token(":")]
+    Colon,
+
+    #[regex(r#""([^"\\]|\\["\\/bfnrt]|\\u[0-9A-F]{4})*""#)]
+    String,
+
+    #[regex(r"-?(0|[1-9][0-9]*)(\.[0-9]+)?([eE][-+]?[0-9]+)?")]
+    Number,
+
+    #[regex(r"[ \t\n\x0c\r]+")]
+    Whitespace,
+
+    #[regex(r"//[^\n]*")]
+    Comment,
+}
+
+impl ForeignScanner for LogosJsonToken {
+    const LOOKBACK: Length = 2;
+
+    // The Number regex needs to see "e+1" after "1" to decide on the token's
+    // length.
+    const LOOKAHEAD: Length = 3;
+
+    const EOI: Self = Self::EOI;
+
+    const MISMATCH: Self = Self::Mismatch;
+
+    fn scan_str(text: &str) -> Option<(Self, usize)> {
+        let mut lexer = Self::lexer(text);
+
+        let token = lexer.next()?.ok()?;
+
+        Some((token, lexer.span().end))
+    }
+
+    #[inline(always)]
+    fn to_rule(self) -> TokenRule {
+        self as TokenRule
+    }
+
+    #[inline(always)]
+    fn token_name(rule: TokenRule) -> Option<&'static str> {
+        static NAMES: [&str; 15] = [
+            "EOI",
+            "Mismatch",
+            "True",
+            "False",
+            "Null",
+            "BraceOpen",
+            "BraceClose",
+            "BracketOpen",
+            "BracketClose",
+            "Comma",
+            "Colon",
+            "String",
+            "Number",
+            "Whitespace",
+            "Comment",
+        ];
+
+        NAMES.get(rule as usize).copied()
+    }
+
+    #[inline(always)]
+    fn token_description(rule: TokenRule, _verbose: bool) -> Option<&'static str> {
+        static DESCRIPTIONS: [&str; 15] = [
+            "<eoi>",
+            "<mismatch>",
+            "true",
+            "false",
+            "null",
+            "{",
+            "}",
+            "[",
+            "]",
+            ",",
+            ":",
+            "<string>",
+            "<number>",
+            "<whitespace>",
+            "<comment>",
+        ];
+
+        DESCRIPTIONS.get(rule as usize).copied()
+    }
+}
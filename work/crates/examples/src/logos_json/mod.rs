@@ -0,0 +1,112 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+pub mod lexis;
+pub mod syntax;
+
+#[cfg(test)]
+mod tests {
+    use lady_deirdre::{
+        lexis::{SourceCode, Token},
+        syntax::SyntaxTree,
+        testing::TreeSnapshot,
+        units::{CompilationUnit, Document},
+    };
+
+    use crate::{json_grammar::syntax::JsonNode, logos_json::syntax::LogosJsonNode};
+
+    static INPUT: &'static str = r#"{
+        "foo": [1, -2.5e+3, 3],
+        "bar": {
+            "a": true,
+            "b": false, // comment
+            "c": null,
+            "d": "str\"ing«"
+        },
+        "baz": [1 : 2 #]
+    }"#;
+
+    fn assert_same_tokens(left: &Document<LogosJsonNode>, right: &Document<JsonNode>) {
+        let left = left
+            .chunks(..)
+            .map(|chunk| (chunk.token.name(), chunk.string))
+            .collect::<Vec<_>>();
+
+        let right = right
+            .chunks(..)
+            .map(|chunk| (chunk.token.name(), chunk.string))
+            .collect::<Vec<_>>();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn test_logos_json_parser() {
+        let logos = Document::<LogosJsonNode>::new_immutable(INPUT);
+        let derived = Document::<JsonNode>::new_immutable(INPUT);
+
+        assert_same_tokens(&logos, &derived);
+        assert_eq!(TreeSnapshot::new(&logos), TreeSnapshot::new(&derived));
+        assert_eq!(logos.errors().count(), derived.errors().count());
+    }
+
+    #[test]
+    fn test_logos_json_incremental() {
+        let mut logos = Document::<LogosJsonNode>::new_mutable(INPUT);
+        let mut derived = Document::<JsonNode>::new_mutable(INPUT);
+
+        let edits = [
+            (23..24, "1.5"),
+            (24..24, "e"),
+            (25..25, "-7"),
+            (2..2, "\"x\": \"unterminated, "),
+            (3..3, "\""),
+            (0..0, "// leading\n"),
+            (40..60, ""),
+            (10..10, "tru"),
+            (13..13, "e"),
+        ];
+
+        for (span, text) in edits {
+            logos.write(span.clone(), text);
+            derived.write(span, text);
+
+            let fresh = Document::<JsonNode>::new_immutable(logos.substring(..));
+
+            assert_same_tokens(&logos, &fresh);
+            assert_same_tokens(&logos, &derived);
+            assert_eq!(TreeSnapshot::new(&logos), TreeSnapshot::new(&derived));
+        }
+    }
+}
@@ -52,12 +52,15 @@ mod tests {
             MutationAccess,
             TriggerHandle,
         },
-        arena::Identifiable,
+        arena::{Id, Identifiable},
         format::{AnnotationPriority, SnippetFormatter},
         syntax::{PolyRef, SyntaxTree},
     };
 
-    use crate::shared_semantics::{semantics::KeyResolution, syntax::SharedSemanticsNode};
+    use crate::shared_semantics::{
+        semantics::{KeyResolution, RefKey},
+        syntax::SharedSemanticsNode,
+    };
 
     #[test]
     fn test_multi_modules() {
@@ -108,6 +111,149 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cross_index() {
+        fn add_module(analyzer: &Analyzer<SharedSemanticsNode>, name: &str, text: &str) -> Id {
+            let handle = TriggerHandle::new();
+
+            let mut task = analyzer.mutate(&handle, 1).unwrap();
+
+            let doc_id = task.add_mutable_doc(text);
+
+            doc_id.set_name(name);
+
+            task.common()
+                .modules
+                .mutate(&task, |modules| {
+                    let _ = modules.insert(String::from(name), doc_id);
+
+                    true
+                })
+                .unwrap();
+
+            doc_id
+        }
+
+        fn usages(analyzer: &Analyzer<SharedSemanticsNode>, id: Id, key: &str) -> Vec<String> {
+            let handle = TriggerHandle::new();
+            let task = analyzer.analyze(&handle, 1).unwrap();
+
+            let doc_read = task.read_doc(id).unwrap();
+            let doc = doc_read.deref();
+
+            let SharedSemanticsNode::Root { defs, .. } = doc.root() else {
+                unreachable!("Malformed root");
+            };
+
+            for def_ref in defs {
+                let Some(SharedSemanticsNode::Def { key: key_ref, .. }) = def_ref.deref(doc) else {
+                    continue;
+                };
+
+                let Some(SharedSemanticsNode::Key {
+                    token, semantics, ..
+                }) = key_ref.deref(doc)
+                else {
+                    continue;
+                };
+
+                if token.string(doc) != Some(key) {
+                    continue;
+                }
+
+                let (_, usages) = semantics.get().unwrap().usages.snapshot(&task).unwrap();
+
+                return usages
+                    .refs
+                    .iter()
+                    .map(|node_ref| node_ref.id.name())
+                    .collect();
+            }
+
+            panic!("Missing key {key:?}.");
+        }
+
+        let analyzer = Analyzer::<SharedSemanticsNode>::new(AnalyzerConfig::default());
+
+        let module_1_text = "x = 10; y = module_2::b; z = module_2::c;";
+        let module_1 = add_module(&analyzer, "module_1", module_1_text);
+        let module_2 = add_module(
+            &analyzer,
+            "module_2",
+            "a = module_1::x; b = module_2::c; c = 20;",
+        );
+
+        assert_eq!(usages(&analyzer, module_2, "c").len(), 2);
+        assert_eq!(usages(&analyzer, module_2, "b"), ["module_1"]);
+        assert_eq!(usages(&analyzer, module_1, "x"), ["module_2"]);
+        assert!(usages(&analyzer, module_1, "y").is_empty());
+
+        {
+            let handle = TriggerHandle::new();
+            let task = analyzer.analyze(&handle, 1).unwrap();
+
+            let refs = task.query_index(&RefKey {
+                module: String::from("module_2"),
+                key: String::from("c"),
+            });
+
+            assert_eq!(refs.as_ref().len(), 2);
+            assert!(refs.as_ref().iter().any(|node_ref| node_ref.id == module_1));
+            assert!(refs.as_ref().iter().any(|node_ref| node_ref.id == module_2));
+        }
+
+        {
+            let handle = TriggerHandle::new();
+            let mut task = analyzer.mutate(&handle, 1).unwrap();
+
+            let site = module_1_text.rfind('c').unwrap();
+
+            task.write_to_doc(module_1, site..(site + 1), "b").unwrap();
+        }
+
+        assert_eq!(usages(&analyzer, module_2, "c"), ["module_2"]);
+        assert_eq!(usages(&analyzer, module_2, "b"), ["module_1", "module_1"]);
+
+        {
+            let handle = TriggerHandle::new();
+            let mut task = analyzer.mutate(&handle, 1).unwrap();
+
+            task.write_to_doc(module_2, 0..0, "d = module_2::c; ")
+                .unwrap();
+        }
+
+        assert_eq!(usages(&analyzer, module_2, "c"), ["module_2", "module_2"]);
+
+        {
+            let handle = TriggerHandle::new();
+            let mut task = analyzer.mutate(&handle, 1).unwrap();
+
+            task.write_to_doc(module_2, 0..0, "e = module_1::y; ")
+                .unwrap();
+        }
+
+        assert_eq!(usages(&analyzer, module_1, "y"), ["module_2"]);
+
+        {
+            let handle = TriggerHandle::new();
+            let mut task = analyzer.mutate(&handle, 1).unwrap();
+
+            task.write_to_doc(module_2, 0..17, "").unwrap();
+        }
+
+        assert!(usages(&analyzer, module_1, "y").is_empty());
+
+        {
+            let handle = TriggerHandle::new();
+            let mut task = analyzer.mutate(&handle, 1).unwrap();
+
+            assert!(task.remove_doc(module_1));
+        }
+
+        assert!(usages(&analyzer, module_2, "b").is_empty());
+        assert_eq!(usages(&analyzer, module_2, "c"), ["module_2", "module_2"]);
+    }
+
     struct DisplayModules<'a>(&'a AnalysisTask<'a, SharedSemanticsNode>);
 
     impl<'a> Display for DisplayModules<'a> {
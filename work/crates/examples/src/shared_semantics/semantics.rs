@@ -45,6 +45,7 @@ use lady_deirdre::{
         Attr,
         AttrContext,
         Computable,
+        CrossIndexer,
         Feature,
         Slot,
         TaskHandle,
@@ -52,6 +53,7 @@ use lady_deirdre::{
     arena::{Id, Identifiable},
     sync::SyncBuildHasher,
     syntax::{NodeRef, PolyRef, SyntaxTree},
+    units::Document,
 };
 use log::debug;
 
@@ -162,6 +164,7 @@ pub enum Definition {
 #[node(SharedSemanticsNode)]
 pub struct KeySemantics {
     pub resolution: Attr<KeyResolution>,
+    pub usages: Attr<KeyUsages>,
 }
 
 #[derive(Clone, PartialEq, Eq)]
@@ -248,6 +251,92 @@ impl Computable for KeyResolution {
     }
 }
 
+// A set of references (`module::key`) to the key across all modules.
+#[derive(Default, Clone, PartialEq, Eq)]
+pub struct KeyUsages {
+    pub refs: Vec<NodeRef>,
+}
+
+impl Computable for KeyUsages {
+    type Node = SharedSemanticsNode;
+
+    fn compute<H: TaskHandle, S: SyncBuildHasher>(
+        context: &mut AttrContext<Self::Node, H, S>,
+    ) -> AnalysisResult<Self> {
+        log_attr::<Self, H, S>(context)?;
+
+        let key_ref = context.node_ref();
+        let doc_read = context.read_doc(key_ref.id).unwrap_abnormal()?;
+        let doc = doc_read.deref();
+
+        let Some(SharedSemanticsNode::Key { token, .. }) = key_ref.deref(doc) else {
+            return Ok(Self::default());
+        };
+
+        let Some(key) = token.string(doc) else {
+            return Ok(Self::default());
+        };
+
+        let modules = context.common().modules.read(context).unwrap_abnormal()?;
+
+        let Some((module, _)) = modules.iter().find(|(_, id)| **id == key_ref.id) else {
+            return Ok(Self::default());
+        };
+
+        let ref_key = RefKey {
+            module: module.clone(),
+            key: key.to_string(),
+        };
+
+        let mut refs = context
+            .query_index(&ref_key)
+            .as_ref()
+            .iter()
+            .copied()
+            .collect::<Vec<_>>();
+
+        refs.sort();
+
+        Ok(Self { refs })
+    }
+}
+
+// Indexes the Ref nodes by the module and the key they refer to.
+pub struct SharedSemanticsIndexer;
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct RefKey {
+    pub module: String,
+    pub key: String,
+}
+
+impl CrossIndexer for SharedSemanticsIndexer {
+    type Node = SharedSemanticsNode;
+    type Key = RefKey;
+
+    fn index<S: SyncBuildHasher>(
+        doc: &Document<Self::Node>,
+        node_ref: &NodeRef,
+    ) -> HashSet<Self::Key, S> {
+        let mut result = HashSet::default();
+
+        let Some(SharedSemanticsNode::Ref { module, ident, .. }) = node_ref.deref(doc) else {
+            return result;
+        };
+
+        let (Some(module), Some(key)) = (module.string(doc), ident.string(doc)) else {
+            return result;
+        };
+
+        let _ = result.insert(RefKey {
+            module: module.to_string(),
+            key: key.to_string(),
+        });
+
+        result
+    }
+}
+
 fn log_attr<C: Any, H: TaskHandle, S: SyncBuildHasher>(
     context: &mut AttrContext<SharedSemanticsNode, H, S>,
 ) -> AnalysisResult<()> {
@@ -40,13 +40,14 @@ use lady_deirdre::{
 
 use crate::shared_semantics::{
     lexis::SharedSemanticsToken,
-    semantics::{CommonSemantics, KeySemantics, ModuleSemantics},
+    semantics::{CommonSemantics, KeySemantics, ModuleSemantics, SharedSemanticsIndexer},
 };
 
 #[derive(Node)]
 #[token(SharedSemanticsToken)]
 #[trivia($Whitespace)]
 #[semantics(CommonSemantics)]
+#[cross_indexer(SharedSemanticsIndexer)]
 pub enum SharedSemanticsNode {
     #[root]
     #[rule(defs: Def*)]
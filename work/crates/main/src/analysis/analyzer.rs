@@ -34,24 +34,28 @@
 
 use std::{
     collections::HashMap,
-    hash::RandomState,
-    sync::{Arc, Weak},
+    hash::{Hash, RandomState},
+    sync::{atomic::AtomicU64, Arc, Weak},
     time::Duration,
 };
 
 use crate::{
     analysis::{
         database::{Database, DocRecords},
-        entry::DocEntry,
+        entry::{DocEntry, KeyToNodes},
         manager::{TaskKind, TaskManager},
+        receiver::EventSender,
         AnalysisResult,
         AnalysisTask,
+        CrossIndexer,
         Event,
+        EventReceiver,
         ExclusiveTask,
         Feature,
         Grammar,
         Initializer,
         MutationTask,
+        ProfileReport,
         Revision,
         TaskHandle,
         TaskPriority,
@@ -60,6 +64,7 @@ use crate::{
     arena::Id,
     sync::{SyncBuildHasher, Table},
     syntax::NodeRef,
+    units::Instrumentation,
 };
 
 /// An initial configuration of the [Analyzer].
@@ -99,6 +104,79 @@ pub struct AnalyzerConfig {
     /// the timeout event may indicate an existence of a cycle inside the
     /// semantic graph.
     pub analysis_timeout: Duration,
+
+    /// Specifies the maximum amount of time that a single attribute
+    /// computation may take.
+    ///
+    /// When the deadline is exceeded, the next
+    /// [proceed](crate::analysis::AttrContext::proceed) check inside
+    /// the [computable function](crate::analysis::Computable::compute)
+    /// returns an [Interrupted](crate::analysis::AnalysisError::Interrupted)
+    /// error, as if the task handle has been triggered. The Analyzer performs
+    /// this check automatically whenever the computable function reads
+    /// another attribute, but long-running computations that don't read
+    /// attributes should call the proceed function manually.
+    ///
+    /// The interrupted attribute remains uncomputed, and the Analyzer will
+    /// attempt to compute it again on the next request.
+    ///
+    /// Note that this value is ignored under the wasm targets.
+    ///
+    /// The default value is None, which means that the attribute computation
+    /// time is unlimited.
+    pub attr_deadline: Option<Duration>,
+
+    /// Overrides the amount of shards of the Analyzer's inner concurrent
+    /// [tables](Table) of the documents and the semantic graph records.
+    ///
+    /// The value is rounded up to the nearest power of two.
+    ///
+    /// Higher values reduce contention between the concurrent tasks that
+    /// access distinct documents, whereas lower values reduce memory
+    /// footprint.
+    ///
+    /// The default value is None, which means that the amount of shards is
+    /// one if the [single_document](Self::single_document) option is set, and
+    /// is estimated based on the number of CPUs otherwise.
+    pub table_shards: Option<usize>,
+
+    /// Specifies the [InstrumentationSink](crate::units::InstrumentationSink)
+    /// that receives the internal events of the Analyzer, such as
+    /// the attribute computations.
+    ///
+    /// The Analyzer also installs this handle into the mutable documents
+    /// added to the Analyzer unless the document has its own
+    /// [instrumentation](crate::units::Document::set_instrumentation).
+    ///
+    /// The default value is [Instrumentation::none].
+    pub instrumentation: Instrumentation,
+
+    /// When set to true, the Analyzer collects the computation time of
+    /// the attributes together with the dependencies between them.
+    ///
+    /// The collected statistics are available through
+    /// the [Analyzer::profile_report] function.
+    ///
+    /// When the profiler is disabled, the attribute computation
+    /// procedure bypasses it entirely.
+    ///
+    /// The default value is false.
+    pub profile: bool,
+
+    /// Specifies the maximum number of attribute values that the Analyzer
+    /// keeps memoized after the [shrink](Analyzer::shrink) call.
+    ///
+    /// When the number of memoized attribute values exceeds this capacity,
+    /// the shrink function evicts the values that have been verified least
+    /// recently. The evicted attributes remain in the semantic graph, and
+    /// the Analyzer recomputes their values on the next read.
+    ///
+    /// The Analyzer does not evict the values on its own; this option only
+    /// takes effect when you call the shrink function.
+    ///
+    /// The default value is None, which means that the number of memoized
+    /// values is unlimited.
+    pub memo_capacity: Option<usize>,
 }
 
 impl Default for AnalyzerConfig {
@@ -127,6 +205,20 @@ impl AnalyzerConfig {
         Self {
             single_document: false,
             analysis_timeout: Duration::from_millis(attributes_timeout),
+            attr_deadline: None,
+            table_shards: None,
+            instrumentation: Instrumentation::none(),
+            profile: false,
+            memo_capacity: None,
+        }
+    }
+
+    #[inline(always)]
+    pub(super) fn table<K: Hash + Eq, V, S: SyncBuildHasher>(&self) -> Table<K, V, S> {
+        match (self.table_shards, self.single_document) {
+            (Some(shards), _) => Table::with_shards(shards),
+            (None, true) => Table::with_capacity_and_hasher_and_shards(1, S::default(), 1),
+            (None, false) => Table::new(),
         }
     }
 }
@@ -349,6 +441,10 @@ impl AnalyzerConfig {
 ///    [reads a class of nodes](crate::analysis::AttrContext::read_class),
 ///    and the class has changed during the incremental reparsing.
 ///
+///  - The computable function
+///    [queries the cross-document index](crate::analysis::AttrContext::query_index),
+///    and the set of nodes under the queried key has changed.
+///
 ///  - The [invalidate](crate::analysis::AttrRef::invalidate) function of
 ///    the [AttrRef](crate::analysis::AttrRef) object that points to this
 ///    attribute was called explicitly.
@@ -410,6 +506,24 @@ impl AnalyzerConfig {
 /// The Analyzer maintains node index automatically during document mutations.
 /// Therefore, the node classification function should be relatively simple.
 ///
+/// ## Cross-Document Index
+///
+/// Similarly to the node classification, the Analyzer maintains
+/// an Analyzer-wide index of the syntax tree nodes across all managed
+/// documents. The [CrossIndexer](crate::analysis::CrossIndexer) specified in
+/// the Grammar using the `#[cross_indexer(...)]` attribute maps each node to
+/// a set of user-defined keys, and the Analyzer keeps a map from each key to
+/// the set of nodes of all documents associated with this key.
+///
+/// The index is updated on each document creation, edit, and removal.
+///
+/// This mechanism is useful to answer the questions like "which documents
+/// refer to the symbol X" without subscribing to the document events
+/// manually. The computable function that
+/// [queries](crate::analysis::AttrContext::query_index) the index by key will
+/// be recomputed whenever the set of nodes under this key changes, regardless
+/// of which document caused the change.
+///
 /// ## Events
 ///
 /// The Analyzer provides a mechanism for mass invalidation of semantic graph
@@ -432,6 +546,12 @@ impl AnalyzerConfig {
 /// subscribe the attributes of one Analyzer to changes in the state of Another
 /// analyzer that manages a distinct subset of the compiled project.
 ///
+/// Outside of the semantic graph, you can observe the events of a particular
+/// document through the [EventReceiver] queue created by
+/// the [Analyzer::subscribe] function. For instance, a language server could
+/// drain this queue in a dedicated thread to publish the document's
+/// diagnostics whenever the document changes.
+///
 /// ## Semantic Design Considerations
 ///
 ///  1. [Reading](crate::analysis::Attr::snapshot) of the values of attributes
@@ -639,6 +759,16 @@ impl AnalyzerConfig {
 /// priority will be signaled for interruption if the acquiring task requires
 /// switching the Analyzer to another mode.
 ///
+/// While the acquiring task is waiting in the queue, the Analyzer does not
+/// grant access to the new tasks with lower priority, even if these tasks fit
+/// the current mode. The queued tasks with equal priority are granted
+/// in the order of their requests. As such, a mutation task would not be
+/// starved by the continuous flood of analysis tasks of lower priority.
+/// A nested task requested with the handle of an active task is an exception
+/// (see [TaskHandle::is_same]): it is granted without waiting if it fits
+/// the current mode, because the queued tasks could not be granted until
+/// the outer task is released anyway.
+///
 /// The analyze, mutate, and exclusive functions can block the current thread
 /// that calls them if the requested task access cannot be granted yet.
 ///
@@ -647,8 +777,11 @@ impl AnalyzerConfig {
 /// if the specified access cannot be granted instantly.
 pub struct Analyzer<N: Grammar, H: TaskHandle = TriggerHandle, S: SyncBuildHasher = RandomState> {
     pub(super) docs: Table<Id, DocEntry<N, S>, S>,
+    pub(super) index: Table<<N::CrossIndexer as CrossIndexer>::Key, KeyToNodes<S>, S>,
+    pub(super) index_cleanup: AtomicU64,
     pub(super) common: N::CommonSemantics,
     pub(super) events: Table<Id, HashMap<Event, Revision>, S>,
+    pub(super) subscribers: Table<Id, Vec<EventSender>, S>,
     pub(super) db: Arc<Database<N, H, S>>,
     pub(super) tasks: TaskManager<H, S>,
 }
@@ -665,16 +798,16 @@ impl<N: Grammar, H: TaskHandle, S: SyncBuildHasher> Analyzer<N, H, S> {
     ///
     /// Initially, the Analyzer does not hold any document.
     pub fn new(config: AnalyzerConfig) -> Self {
-        let docs = match config.single_document {
-            true => Table::with_capacity_and_hasher_and_shards(1, S::default(), 1),
-            false => Table::new(),
-        };
+        let docs = config.table();
+        let index = config.table();
 
         let events = match config.single_document {
             true => Table::with_capacity_and_hasher_and_shards(1, S::default(), 1),
             false => Table::with_capacity_and_hasher_and_shards(1, S::default(), 1),
         };
 
+        let subscribers = Table::with_capacity_and_hasher_and_shards(1, S::default(), 1);
+
         let db = Arc::new(Database::new(&config));
 
         let mut common = <N::CommonSemantics as Feature>::new(NodeRef::nil());
@@ -701,8 +834,11 @@ impl<N: Grammar, H: TaskHandle, S: SyncBuildHasher> Analyzer<N, H, S> {
 
         Self {
             docs,
+            index,
+            index_cleanup: AtomicU64::new(0),
             common,
             events,
+            subscribers,
             db,
             tasks,
         }
@@ -916,4 +1052,97 @@ impl<N: Grammar, H: TaskHandle, S: SyncBuildHasher> Analyzer<N, H, S> {
     pub fn get_access_level(&self) -> TaskPriority {
         self.tasks.get_access_level()
     }
+
+    /// Returns the number of tasks waiting in the Analyzer's queue for
+    /// access to be granted.
+    ///
+    /// The returning value is a snapshot of the queue state that may become
+    /// outdated immediately if other threads concurrently request or release
+    /// tasks. This function is intended for the observability purposes (e.g.,
+    /// logging and metrics).
+    #[inline(always)]
+    pub fn queue_depth(&self) -> usize {
+        self.tasks.queue_depth()
+    }
+
+    /// Creates a queue of the events related to the document with
+    /// the `id` identifier.
+    ///
+    /// The returned [EventReceiver] receives
+    /// the [DOC_UPDATED_EVENT](crate::analysis::DOC_UPDATED_EVENT),
+    /// the [DOC_ERRORS_EVENT](crate::analysis::DOC_ERRORS_EVENT), and
+    /// the [DOC_REMOVED_EVENT](crate::analysis::DOC_REMOVED_EVENT) events of
+    /// the document, and the custom events
+    /// [triggered](crate::analysis::MutationAccess::trigger_event) with
+    /// the document's identifier.
+    ///
+    /// If the `id` is [nil](Id::nil), the receiver receives the events of all
+    /// documents, and the custom events triggered with any identifier
+    /// including the nil identifier.
+    ///
+    /// Unlike the [subscriptions](crate::analysis::AttrContext::subscribe)
+    /// of the attributes, this function does not require a task, and
+    /// the receiver can be drained from any thread outside of the semantic
+    /// computations.
+    ///
+    /// If the Analyzer does not have the document with the specified `id`,
+    /// the returned receiver is [closed](EventReceiver::is_closed) and empty.
+    pub fn subscribe(&self, id: Id) -> EventReceiver {
+        let (receiver, sender) = EventReceiver::new();
+
+        if id.is_nil() {
+            self.subscribers.entry(id).or_default().push(sender);
+            return receiver;
+        }
+
+        // The document's guard prevents concurrent removal of the document
+        // until the sender is registered.
+        let Some(_guard) = self.docs.get(&id) else {
+            return receiver;
+        };
+
+        self.subscribers.entry(id).or_default().push(sender);
+
+        receiver
+    }
+
+    /// Returns a report of the attributes computation time collected since
+    /// the creation of the Analyzer.
+    ///
+    /// The report is empty unless the [profile](AnalyzerConfig::profile)
+    /// configuration option is enabled.
+    ///
+    /// See [ProfileReport] for details.
+    #[inline(always)]
+    pub fn profile_report(&self) -> ProfileReport {
+        match &self.db.profiler {
+            Some(profiler) => profiler.report(),
+            None => ProfileReport::default(),
+        }
+    }
+
+    /// Evicts the least recently verified attribute values from the semantic
+    /// graph cache, such that the number of memoized values does not exceed
+    /// the [memo_capacity](AnalyzerConfig::memo_capacity) configuration
+    /// option.
+    ///
+    /// Returns the number of evicted values. If the memo capacity is not
+    /// specified, the function does nothing and returns zero.
+    ///
+    /// Eviction does not break the semantic graph consistency: the next
+    /// read of an evicted attribute recomputes its value, and the attributes
+    /// that depend on the evicted one will be revalidated against the
+    /// recomputed value. This applies to the scoped attributes as well.
+    ///
+    /// The function does not require a task, and it does not block:
+    /// the attributes that are currently being computed or read by
+    /// concurrent tasks are skipped.
+    ///
+    /// A language server could call this function periodically to keep
+    /// the memory footprint of the documents that the end user does not
+    /// observe under control.
+    #[inline(always)]
+    pub fn shrink(&self) -> usize {
+        self.db.shrink()
+    }
 }
@@ -208,7 +208,7 @@ impl<C: Computable> AbstractFeature for Attr<C> {
     }
 
     #[inline(always)]
-    fn feature_keys(&self) -> &'static [&'static Key] {
+    fn feature_keys(&self) -> &'static [&'static Key<'static>] {
         &[]
     }
 }
@@ -385,7 +385,13 @@ impl<C: Computable> Attr<C> {
 /// The [nil](AttrRef::nil) AttrRefs are special references that are considered
 /// to be always invalid. They intentionally don't refer any attribute within
 /// any Analyzer.
+///
+/// When the `serde` feature of this crate is enabled, AttrRef implements
+/// serde's Serialize and Deserialize traits. A deserialized AttrRef is
+/// meaningful only against the same Analyzer instance of the same process.
+/// Use the [AttrRef::is_valid_ref] function to check its validity.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AttrRef {
     /// An identifier of the document managed by the Analyzer to which
     /// the attribute belongs.
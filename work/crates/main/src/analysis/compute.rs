@@ -38,17 +38,29 @@ use std::{
     hash::RandomState,
     mem::transmute,
     ops::{Deref, DerefMut},
+    sync::atomic::Ordering,
+    time::{Duration, Instant},
 };
 
 use crate::{
     analysis::{
-        database::{AttrRecordCache, AttrRecordData, CacheDeps, DocRecords, SlotRecordData},
+        database::{
+            AttrMemo,
+            AttrRecordCache,
+            AttrRecordData,
+            CacheDeps,
+            DocRecords,
+            Function,
+            SlotRecordData,
+        },
         lock::TimeoutRwLockReadGuard,
+        profile::ProfileFrame,
         AnalysisError,
         AnalysisResult,
         Analyzer,
         AttrRef,
         Classifier,
+        CrossIndexer,
         DocumentReadGuard,
         Event,
         Grammar,
@@ -188,7 +200,7 @@ impl<D: SharedComputable> Computable for Shared<D> {
 /// the changes trigger future recomputations of
 /// the [attribute](crate::analysis::Attr) values.
 ///
-/// The Analyzer is capable to subscribe the attribute on these three types
+/// The Analyzer is capable to subscribe the attribute on these four types
 /// of objects:
 ///
 ///  - Other attributes of the Analyzer's semantic graph. (subscribed by
@@ -196,6 +208,8 @@ impl<D: SharedComputable> Computable for Shared<D> {
 ///    functions).
 ///  - Changes in the classes. (subscribed by calling
 ///    the [AttrContext::read_class] function)
+///  - Changes in the cross-document index. (subscribed by calling
+///    the [AttrContext::query_index] function)
 ///  - Analyzer-wide event triggers. (subscribed by calling
 ///    the [AttrContext::subscribe] and related functions)
 ///
@@ -215,6 +229,8 @@ pub struct AttrContext<'a, N: Grammar, H: TaskHandle, S: SyncBuildHasher> {
     handle: &'a H,
     node_ref: &'a NodeRef,
     deps: CacheDeps<N, S>,
+    frame: Option<Shared<ProfileFrame>>,
+    deadline: Option<Instant>,
 }
 
 impl<'a, N: Grammar, H: TaskHandle, S: SyncBuildHasher> AttrContext<'a, N, H, S> {
@@ -226,6 +242,8 @@ impl<'a, N: Grammar, H: TaskHandle, S: SyncBuildHasher> AttrContext<'a, N, H, S>
             handle,
             node_ref: &NIL_NODE_REF,
             deps: CacheDeps::default(),
+            frame: None,
+            deadline: None,
         }
     }
 
@@ -310,6 +328,28 @@ impl<'a, N: Grammar, H: TaskHandle, S: SyncBuildHasher> AttrContext<'a, N, H, S>
         Ok(class_to_nodes.nodes.clone())
     }
 
+    /// Returns a snapshot of the set of node references across all documents
+    /// of the Analyzer that are associated with the specified `key` in
+    /// the cross-document index.
+    ///
+    /// The underlying attribute will be recomputed if the returning set
+    /// changes.
+    ///
+    /// See [CrossIndexer] for details.
+    #[inline(always)]
+    pub fn query_index(
+        &mut self,
+        key: &<N::CrossIndexer as CrossIndexer>::Key,
+    ) -> Shared<HashSet<NodeRef, S>> {
+        let _ = self.deps.keys.insert(key.clone());
+
+        let Some(key_to_nodes) = self.analyzer.index.get(key) else {
+            return Shared::default();
+        };
+
+        key_to_nodes.nodes.clone()
+    }
+
     /// Subscribes the attribute to the specified `event`.
     ///
     /// If the `id` parameter is not [nil](Id::nil), the underlying attribute
@@ -336,7 +376,9 @@ impl<'a, N: Grammar, H: TaskHandle, S: SyncBuildHasher> AttrContext<'a, N, H, S>
     }
 
     /// Returns Ok if the underlying task has not been
-    /// [signaled](TaskHandle::is_triggered) for graceful shutdown yet;
+    /// [signaled](TaskHandle::is_triggered) for graceful shutdown yet,
+    /// and the current attribute computation has not exceeded
+    /// the [attr_deadline](crate::analysis::AnalyzerConfig::attr_deadline);
     /// otherwise returns an [Interrupted](AnalysisError::Interrupted) error.
     ///
     /// If the function returns an interruption error, it is fine to return
@@ -346,26 +388,45 @@ impl<'a, N: Grammar, H: TaskHandle, S: SyncBuildHasher> AttrContext<'a, N, H, S>
     ///
     /// Usually, you don't need to call this function manually, because
     /// the Analyzer checks the interruption event in between of the attribute
-    /// computation bounds. However, if the computable function performs a
-    /// computation heavy procedure, it is worth calling this function manually
-    /// from time to time.
+    /// computation bounds and whenever the computable function reads another
+    /// attribute. However, if the computable function performs a computation
+    /// heavy procedure, it is worth calling this function manually from time
+    /// to time (e.g., on each iteration of a long loop). The check is cheap.
     #[inline(always)]
     pub fn proceed(&self) -> AnalysisResult<()> {
         if self.handle.is_triggered() {
             return Err(AnalysisError::Interrupted);
         }
 
+        if let Some(deadline) = &self.deadline {
+            if Instant::now() >= *deadline {
+                return Err(AnalysisError::Interrupted);
+            }
+        }
+
         Ok(())
     }
 
     #[inline(always)]
     pub(super) fn fork(&self, node_ref: &'a NodeRef) -> AttrContext<'a, N, H, S> {
+        #[cfg(not(target_family = "wasm"))]
+        let deadline = self
+            .analyzer
+            .db
+            .deadline
+            .and_then(|deadline| Instant::now().checked_add(deadline));
+
+        #[cfg(target_family = "wasm")]
+        let deadline = None;
+
         AttrContext {
             analyzer: self.analyzer,
             revision: self.revision,
             handle: self.handle,
             node_ref,
             deps: CacheDeps::default(),
+            frame: self.frame.clone(),
+            deadline,
         }
     }
 
@@ -458,6 +519,8 @@ impl AttrRef {
         &self,
         context: &mut AttrContext<'a, C::Node, H, S>,
     ) -> AnalysisResult<AttrReadGuard<'a, C, H, S>> {
+        context.proceed()?;
+
         loop {
             let Some(records_guard) = context.analyzer.db.records.get(&self.id) else {
                 return Err(AnalysisError::MissingDocument);
@@ -547,7 +610,7 @@ impl AttrRef {
 
             let Some(cache) = &mut record_data.cache else {
                 let mut forked = context.fork(&record_data.node_ref);
-                let memo = record_data.function.invoke(&mut forked)?;
+                let memo = self.invoke(record_data.function, &mut forked)?;
                 let deps = forked.into_deps();
 
                 record_data.cache = Some(AttrRecordCache {
@@ -600,6 +663,29 @@ impl AttrRef {
                 }
             }
 
+            if !cache.dirty && !cache.deps.as_ref().keys.is_empty() {
+                for key in &cache.deps.as_ref().keys {
+                    // The index does not keep the entries of the keys without
+                    // nodes. If any entry has been removed since the last
+                    // verification, the attribute could observe its nodes.
+                    let Some(key_to_nodes) = context.analyzer.index.get(key) else {
+                        let cleanup = context.analyzer.index_cleanup.load(Ordering::Relaxed);
+
+                        if cleanup > record_data.verified_at {
+                            cache.dirty = true;
+                            break;
+                        }
+
+                        continue;
+                    };
+
+                    if key_to_nodes.revision > record_data.verified_at {
+                        cache.dirty = true;
+                        break;
+                    }
+                }
+            }
+
             if !cache.dirty && !cache.deps.as_ref().slots.is_empty() {
                 for slot_ref in &cache.deps.as_ref().slots {
                     let Some(dep_records) = context.analyzer.db.records.get(&slot_ref.id) else {
@@ -682,7 +768,7 @@ impl AttrRef {
             }
 
             let mut forked = context.fork(&record_data.node_ref);
-            let new_memo = record_data.function.invoke(&mut forked)?;
+            let new_memo = self.invoke(record_data.function, &mut forked)?;
             let new_deps = forked.into_deps();
 
             // Safety: New and previous values produced by the same Cell function.
@@ -701,6 +787,48 @@ impl AttrRef {
             return Ok(());
         }
     }
+
+    #[inline(always)]
+    fn invoke<N: Grammar, H: TaskHandle, S: SyncBuildHasher>(
+        &self,
+        function: &'static dyn Function<N, H, S>,
+        context: &mut AttrContext<N, H, S>,
+    ) -> AnalysisResult<Box<dyn AttrMemo>> {
+        let analyzer = context.analyzer;
+
+        if !analyzer.db.timing {
+            return function.invoke(context);
+        }
+
+        let profiler = analyzer.db.profiler.as_ref().map(|profiler| {
+            let frame = ProfileFrame::new(function.name(), context.frame.take());
+
+            context.frame = Some(frame.clone());
+
+            (profiler, frame)
+        });
+
+        #[cfg(not(target_family = "wasm"))]
+        let time = Instant::now();
+
+        let memo = function.invoke(context)?;
+
+        #[cfg(not(target_family = "wasm"))]
+        let duration = time.elapsed();
+
+        #[cfg(target_family = "wasm")]
+        let duration = Duration::ZERO;
+
+        if let Some((profiler, frame)) = profiler {
+            profiler.record(frame.as_ref(), duration);
+        }
+
+        if let Some(sink) = analyzer.db.instrumentation.sink() {
+            sink.on_attr_computed(self, duration);
+        }
+
+        Ok(memo)
+    }
 }
 
 /// A RAII guard that provides read-only access to
@@ -33,7 +33,7 @@
 ////////////////////////////////////////////////////////////////////////////////
 
 use std::{
-    any::TypeId,
+    any::{type_name, TypeId},
     collections::HashSet,
     ops::{Deref, DerefMut},
     sync::atomic::{AtomicU64, Ordering},
@@ -43,6 +43,7 @@ use std::{
 use crate::{
     analysis::{
         lock::TimeoutRwLock,
+        profile::Profiler,
         AnalysisError,
         AnalysisResult,
         AnalyzerConfig,
@@ -50,6 +51,7 @@ use crate::{
         AttrRef,
         Classifier,
         Computable,
+        CrossIndexer,
         Event,
         Grammar,
         SlotRef,
@@ -59,6 +61,7 @@ use crate::{
     report::ld_unreachable,
     sync::{Shared, SyncBuildHasher, Table},
     syntax::NodeRef,
+    units::Instrumentation,
 };
 
 /// A version of the [Analyzer](crate::analysis::Analyzer)'s state.
@@ -70,19 +73,29 @@ pub type Revision = u64;
 pub(super) struct Database<N: Grammar, H: TaskHandle, S: SyncBuildHasher> {
     pub(super) records: Table<Id, DocRecords<N, H, S>, S>,
     pub(super) timeout: Duration,
+    pub(super) deadline: Option<Duration>,
     pub(super) revision: AtomicU64,
+    pub(super) instrumentation: Instrumentation,
+    pub(super) profiler: Option<Profiler<S>>,
+    pub(super) timing: bool,
+    pub(super) memo_capacity: Option<usize>,
 }
 
 impl<N: Grammar, H: TaskHandle, S: SyncBuildHasher> Database<N, H, S> {
     #[inline(always)]
     pub(super) fn new(config: &AnalyzerConfig) -> Self {
         Self {
-            records: match config.single_document {
-                true => Table::with_capacity_and_hasher_and_shards(0, S::default(), 1),
-                false => Table::new(),
-            },
+            records: config.table(),
             timeout: config.analysis_timeout,
+            deadline: config.attr_deadline,
             revision: AtomicU64::new(0),
+            instrumentation: config.instrumentation,
+            profiler: match config.profile {
+                true => Some(Profiler::new()),
+                false => None,
+            },
+            timing: config.profile || config.instrumentation.sink().is_some(),
+            memo_capacity: config.memo_capacity,
         }
     }
 
@@ -95,6 +108,74 @@ impl<N: Grammar, H: TaskHandle, S: SyncBuildHasher> Database<N, H, S> {
     pub(super) fn commit_revision(&self) -> Revision {
         self.revision.fetch_add(1, Ordering::Relaxed) + 1
     }
+
+    pub(super) fn shrink(&self) -> usize {
+        let Some(capacity) = self.memo_capacity else {
+            return 0;
+        };
+
+        let mut memos = Vec::new();
+
+        for id in self.records.keys_snapshot() {
+            let Some(records) = self.records.get(&id) else {
+                continue;
+            };
+
+            for (entry, record) in records.attrs.enumerate() {
+                let Ok(record_read_guard) = record.read(&Duration::ZERO) else {
+                    continue;
+                };
+
+                if record_read_guard.cache.is_none() {
+                    continue;
+                }
+
+                memos.push((record_read_guard.verified_at, id, entry));
+            }
+        }
+
+        if memos.len() <= capacity {
+            return 0;
+        }
+
+        memos.sort_unstable_by_key(|(verified_at, _, _)| *verified_at);
+        memos.truncate(memos.len() - capacity);
+
+        let mut evicted = 0;
+
+        for (verified_at, id, entry) in memos {
+            let Some(records) = self.records.get(&id) else {
+                continue;
+            };
+
+            let Some(record) = records.attrs.get(&entry) else {
+                continue;
+            };
+
+            let Ok(mut record_write_guard) = record.write(&Duration::ZERO) else {
+                continue;
+            };
+
+            // The record has been verified by a concurrent task since
+            // the snapshot, and is no longer the least recent one.
+            if record_write_guard.verified_at > verified_at {
+                continue;
+            }
+
+            // The dependent attributes that will be validated later observe
+            // the missing cache and recompute their values, so none of them
+            // could rely on a value inferred from the evicted one.
+            if record_write_guard.cache.take().is_none() {
+                continue;
+            }
+
+            record_write_guard.verified_at = 0;
+
+            evicted += 1;
+        }
+
+        evicted
+    }
 }
 
 pub(super) trait AbstractDatabase: Send + Sync + 'static {
@@ -226,6 +307,7 @@ pub(super) struct CacheDeps<N: Grammar, S: SyncBuildHasher> {
     pub(super) slots: HashSet<SlotRef, S>,
     pub(super) events: HashSet<(Id, Event), S>,
     pub(super) classes: HashSet<(Id, <N::Classifier as Classifier>::Class), S>,
+    pub(super) keys: HashSet<<N::CrossIndexer as CrossIndexer>::Key, S>,
 }
 
 impl<N: Grammar, S: SyncBuildHasher> Default for CacheDeps<N, S> {
@@ -236,6 +318,7 @@ impl<N: Grammar, S: SyncBuildHasher> Default for CacheDeps<N, S> {
             slots: HashSet::default(),
             events: HashSet::default(),
             classes: HashSet::default(),
+            keys: HashSet::default(),
         }
     }
 }
@@ -353,6 +436,8 @@ pub(super) trait Function<N: Grammar, H: TaskHandle, S: SyncBuildHasher>:
     Send + Sync + 'static
 {
     fn invoke(&self, task: &mut AttrContext<N, H, S>) -> AnalysisResult<Box<dyn AttrMemo>>;
+
+    fn name(&self) -> &'static str;
 }
 
 impl<T, N, H, S> Function<N, H, S> for fn(&mut AttrContext<N, H, S>) -> AnalysisResult<T>
@@ -365,4 +450,9 @@ where
     fn invoke(&self, context: &mut AttrContext<N, H, S>) -> AnalysisResult<Box<dyn AttrMemo>> {
         Ok(Box::new(self(context)?))
     }
+
+    #[inline(always)]
+    fn name(&self) -> &'static str {
+        type_name::<T>()
+    }
 }
@@ -33,10 +33,12 @@
 ////////////////////////////////////////////////////////////////////////////////
 
 use std::{
+    borrow::Cow,
     collections::{hash_map, HashMap, HashSet},
-    hash::RandomState,
+    fmt::{Debug, Formatter},
+    hash::{Hash, RandomState},
     ops::{Deref, DerefMut},
-    sync::{Arc, Weak},
+    sync::{atomic::Ordering, Arc, Weak},
 };
 
 use crate::{
@@ -46,6 +48,7 @@ use crate::{
         AnalysisResult,
         Analyzer,
         Classifier,
+        CrossIndexer,
         Feature,
         Grammar,
         Initializer,
@@ -55,9 +58,9 @@ use crate::{
         TaskHandle,
     },
     arena::{Entry, Id, Identifiable},
-    lexis::ToSpan,
+    lexis::{SiteSpan, ToSpan},
     report::ld_unreachable,
-    sync::{Shared, SyncBuildHasher, TableReadGuard},
+    sync::{Shared, SyncBuildHasher, TableEntry, TableReadGuard},
     syntax::{ErrorRef, NodeRef, PolyRef, SyntaxTree},
     units::{Document, Watcher},
 };
@@ -126,10 +129,139 @@ impl<'a, N: Grammar, S: SyncBuildHasher> From<TableReadGuard<'a, Id, DocEntry<N,
     }
 }
 
+/// Statistics of the document's nodes classification.
+///
+/// The [Analyzer] classifies the nodes of the document using
+/// the grammar's [Classifier] when the document is added to the Analyzer,
+/// and then reclassifies the created and updated nodes on each
+/// [edit](crate::analysis::MutationAccess::write_to_doc) of the document.
+///
+/// This object describes the most recent classification pass of
+/// the document, and can be obtained using
+/// the [classification_stats](crate::analysis::AbstractTask::classification_stats)
+/// function.
+///
+/// The `C` generic parameter is a type of the
+/// [classes](Classifier::Class).
+pub struct ClassificationStats<C, S = RandomState> {
+    /// The Analyzer's revision at which the classification pass took place.
+    pub revision: Revision,
+
+    /// The number of nodes that have been classified using
+    /// the [Classifier::classify] function.
+    pub classified: usize,
+
+    /// The number of updated nodes for which the [Classifier::denounce]
+    /// function returned false, such that the Analyzer skipped their
+    /// classification.
+    pub skipped: usize,
+
+    /// The number of nodes added to each class.
+    pub added: HashMap<C, usize, S>,
+
+    /// The number of nodes removed from each class.
+    pub removed: HashMap<C, usize, S>,
+}
+
+impl<C: Clone, S: Clone> Clone for ClassificationStats<C, S> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            revision: self.revision,
+            classified: self.classified,
+            skipped: self.skipped,
+            added: self.added.clone(),
+            removed: self.removed.clone(),
+        }
+    }
+}
+
+impl<C: Debug, S> Debug for ClassificationStats<C, S> {
+    #[inline]
+    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter
+            .debug_struct("ClassificationStats")
+            .field("revision", &self.revision)
+            .field("classified", &self.classified)
+            .field("skipped", &self.skipped)
+            .field("added", &self.added)
+            .field("removed", &self.removed)
+            .finish()
+    }
+}
+
+impl<C: Eq + Hash, S: SyncBuildHasher> ClassificationStats<C, S> {
+    #[inline(always)]
+    fn new(revision: Revision) -> Self {
+        Self {
+            revision,
+            classified: 0,
+            skipped: 0,
+            added: HashMap::default(),
+            removed: HashMap::default(),
+        }
+    }
+
+    /// Returns the total number of nodes added to all classes.
+    #[inline(always)]
+    pub fn total_added(&self) -> usize {
+        self.added.values().sum()
+    }
+
+    /// Returns the total number of nodes removed from all classes.
+    #[inline(always)]
+    pub fn total_removed(&self) -> usize {
+        self.removed.values().sum()
+    }
+
+    #[inline(always)]
+    fn add(&mut self, class: &C)
+    where
+        C: Clone,
+    {
+        *self.added.entry(class.clone()).or_default() += 1;
+    }
+
+    #[inline(always)]
+    fn remove(&mut self, class: &C)
+    where
+        C: Clone,
+    {
+        *self.removed.entry(class.clone()).or_default() += 1;
+    }
+}
+
 pub(super) struct DocEntry<N: Grammar, S: SyncBuildHasher> {
     pub(super) doc: Document<N>,
     pub(super) classes_to_nodes: HashMap<<N::Classifier as Classifier>::Class, ClassToNodes<S>, S>,
     pub(super) nodes_to_classes: HashMap<Entry, NodeToClasses<N, S>, S>,
+    pub(super) stats: ClassificationStats<<N::Classifier as Classifier>::Class, S>,
+    pub(super) nodes_to_keys: HashMap<Entry, HashSet<<N::CrossIndexer as CrossIndexer>::Key, S>, S>,
+}
+
+#[derive(Default)]
+struct DocWatcher<S> {
+    node_refs: HashSet<NodeRef, S>,
+    errors_signal: bool,
+}
+
+impl<S: SyncBuildHasher> Watcher for DocWatcher<S> {
+    #[inline(always)]
+    fn report_node(&mut self, node_ref: &NodeRef) {
+        let _ = self.node_refs.insert(*node_ref);
+    }
+
+    #[inline(always)]
+    fn report_error(&mut self, _error_ref: &ErrorRef) {
+        self.errors_signal = true
+    }
+}
+
+impl<S> DocWatcher<S> {
+    #[inline(always)]
+    fn is_empty(&self) -> bool {
+        self.node_refs.is_empty() && !self.errors_signal
+    }
 }
 
 pub(super) struct ClassToNodes<S> {
@@ -141,18 +273,41 @@ pub(super) struct NodeToClasses<N: Grammar, S> {
     pub(super) classes: HashSet<<N::Classifier as Classifier>::Class, S>,
 }
 
+pub(super) struct KeyToNodes<S> {
+    pub(super) nodes: Shared<HashSet<NodeRef, S>>,
+    pub(super) revision: Revision,
+}
+
+impl<S: SyncBuildHasher> Default for KeyToNodes<S> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            nodes: Shared::default(),
+            revision: 0,
+        }
+    }
+}
+
 impl<N: Grammar, H: TaskHandle, S: SyncBuildHasher> Analyzer<N, H, S> {
     pub(super) fn register_doc(&self, mut doc: Document<N>) -> Id {
         let id = doc.id();
 
+        if doc.instrumentation().is_none() {
+            doc.set_instrumentation(self.db.instrumentation);
+        }
+
         let node_refs = doc.node_refs().collect::<Vec<_>>();
         let mut records = DocRecords::with_capacity(node_refs.len());
         let mut classes_to_nodes =
             HashMap::<<N::Classifier as Classifier>::Class, ClassToNodes<S>, S>::default();
         let mut nodes_to_classes = HashMap::<Entry, NodeToClasses<N, S>, S>::default();
+        let mut nodes_to_keys = HashMap::default();
 
         let revision = self.db.commit_revision();
 
+        let sink = self.db.instrumentation.sink();
+        let mut stats = ClassificationStats::new(revision);
+
         if !node_refs.is_empty() {
             let mut initializer = Initializer {
                 id,
@@ -170,13 +325,29 @@ impl<N: Grammar, H: TaskHandle, S: SyncBuildHasher> Analyzer<N, H, S> {
             }
 
             for node_ref in node_refs {
+                let keys = <N::CrossIndexer as CrossIndexer>::index(&doc, &node_ref);
+
+                if !keys.is_empty() {
+                    self.reindex_node(&node_ref, &HashSet::default(), &keys, revision);
+
+                    let _ = nodes_to_keys.insert(node_ref.entry, keys);
+                }
+
                 let classes = <N::Classifier as Classifier>::classify(&doc, &node_ref);
 
+                stats.classified += 1;
+
+                if let Some(sink) = sink {
+                    sink.on_classify(&node_ref, false);
+                }
+
                 if classes.is_empty() {
                     continue;
                 }
 
                 for class in &classes {
+                    stats.add(class);
+
                     match classes_to_nodes.entry(class.clone()) {
                         hash_map::Entry::Occupied(mut entry) => {
                             let Some(nodes) = entry.get_mut().nodes.get_mut() else {
@@ -212,6 +383,8 @@ impl<N: Grammar, H: TaskHandle, S: SyncBuildHasher> Analyzer<N, H, S> {
                 doc,
                 classes_to_nodes,
                 nodes_to_classes,
+                stats,
+                nodes_to_keys,
             },
         );
 
@@ -229,34 +402,82 @@ impl<N: Grammar, H: TaskHandle, S: SyncBuildHasher> Analyzer<N, H, S> {
         span: impl ToSpan,
         text: impl AsRef<str>,
     ) -> AnalysisResult<()> {
-        #[derive(Default)]
-        struct DocWatcher<S> {
-            node_refs: HashSet<NodeRef, S>,
-            errors_signal: bool,
+        let Some(mut guard) = self.docs.get_mut(&id) else {
+            return Err(AnalysisError::MissingDocument);
+        };
+
+        let mut report = DocWatcher::<S>::default();
+
+        Self::apply_write(guard.deref_mut(), span, text, &mut report)?;
+
+        if report.is_empty() {
+            return Ok(());
         }
 
-        impl<S: SyncBuildHasher> Watcher for DocWatcher<S> {
-            #[inline(always)]
-            fn report_node(&mut self, node_ref: &NodeRef) {
-                let _ = self.node_refs.insert(*node_ref);
-            }
+        let revision = self.db.commit_revision();
+
+        self.refresh_doc(handle, id, guard.deref_mut(), &report, revision)
+    }
+
+    pub(super) fn write_batch<'a>(
+        &self,
+        handle: &H,
+        edits: impl IntoIterator<Item = (Id, SiteSpan, Cow<'a, str>)>,
+    ) -> AnalysisResult<()> {
+        let mut reports = Vec::<(Id, DocWatcher<S>)>::new();
+        let mut indices = HashMap::<Id, usize, S>::default();
+        let mut result = Ok(());
+
+        for (id, span, text) in edits {
+            let Some(mut guard) = self.docs.get_mut(&id) else {
+                result = Err(AnalysisError::MissingDocument);
+                break;
+            };
 
-            #[inline(always)]
-            fn report_error(&mut self, _error_ref: &ErrorRef) {
-                self.errors_signal = true
+            let index = *indices.entry(id).or_insert_with(|| {
+                reports.push((id, DocWatcher::default()));
+
+                reports.len() - 1
+            });
+
+            let (_, report) = &mut reports[index];
+
+            if let Err(error) = Self::apply_write(guard.deref_mut(), span, text, report) {
+                result = Err(error);
+                break;
             }
         }
-        let Some(mut guard) = self.docs.get_mut(&id) else {
-            return Err(AnalysisError::MissingDocument);
-        };
 
-        let DocEntry {
-            doc,
-            classes_to_nodes,
-            nodes_to_classes,
-        } = guard.deref_mut();
+        // Even if some edit failed, the previously applied edits must be
+        // reflected in the semantic graph.
+
+        reports.retain(|(_, report)| !report.is_empty());
+
+        if reports.is_empty() {
+            return result;
+        }
 
-        let Document::Mutable(unit) = doc else {
+        let revision = self.db.commit_revision();
+
+        for (id, report) in &reports {
+            let Some(mut guard) = self.docs.get_mut(id) else {
+                // The document has been removed by a concurrent task.
+                continue;
+            };
+
+            self.refresh_doc(handle, *id, guard.deref_mut(), report, revision)?;
+        }
+
+        result
+    }
+
+    fn apply_write(
+        entry: &mut DocEntry<N, S>,
+        span: impl ToSpan,
+        text: impl AsRef<str>,
+        report: &mut DocWatcher<S>,
+    ) -> AnalysisResult<()> {
+        let Document::Mutable(unit) = &mut entry.doc else {
             return Err(AnalysisError::ImmutableDocument);
         };
 
@@ -264,17 +485,32 @@ impl<N: Grammar, H: TaskHandle, S: SyncBuildHasher> Analyzer<N, H, S> {
             return Err(AnalysisError::InvalidSpan);
         };
 
-        let mut report = DocWatcher::<S>::default();
+        unit.write_and_watch(span, text, report);
 
-        unit.write_and_watch(span, text, &mut report);
+        Ok(())
+    }
 
-        if report.node_refs.is_empty() && !report.errors_signal {
-            return Ok(());
-        }
+    fn refresh_doc(
+        &self,
+        handle: &H,
+        id: Id,
+        entry: &mut DocEntry<N, S>,
+        report: &DocWatcher<S>,
+        revision: Revision,
+    ) -> AnalysisResult<()> {
+        self.trigger_event(id, DOC_UPDATED_EVENT, revision);
 
-        let revision = self.db.commit_revision();
+        let DocEntry {
+            doc,
+            classes_to_nodes,
+            nodes_to_classes,
+            stats,
+            nodes_to_keys,
+        } = entry;
 
-        self.trigger_event(id, DOC_UPDATED_EVENT, revision);
+        let sink = self.db.instrumentation.sink();
+
+        *stats = ClassificationStats::new(revision);
 
         if report.errors_signal {
             self.trigger_event(id, DOC_ERRORS_EVENT, revision);
@@ -296,6 +532,10 @@ impl<N: Grammar, H: TaskHandle, S: SyncBuildHasher> Analyzer<N, H, S> {
 
         for node_ref in &report.node_refs {
             let Some(node) = node_ref.deref_mut(doc) else {
+                if let Some(keys) = nodes_to_keys.remove(&node_ref.entry) {
+                    self.reindex_node(node_ref, &keys, &HashSet::default(), revision);
+                }
+
                 let Some(node_to_classes) = nodes_to_classes.remove(&node_ref.entry) else {
                     continue;
                 };
@@ -322,6 +562,8 @@ impl<N: Grammar, H: TaskHandle, S: SyncBuildHasher> Analyzer<N, H, S> {
                             ld_unreachable!("Nodes and classes resynchronization.");
                         }
                     }
+
+                    stats.remove(&class);
                 }
 
                 continue;
@@ -344,13 +586,77 @@ impl<N: Grammar, H: TaskHandle, S: SyncBuildHasher> Analyzer<N, H, S> {
 
             scope_attr.invalidate(&mut invalidator);
 
-            if nodes_to_classes.contains_key(&node_ref.entry) {
-                continue;
+            let keys = <N as Grammar>::CrossIndexer::index(doc, node_ref);
+
+            match nodes_to_keys.remove(&node_ref.entry) {
+                Some(previous) => self.reindex_node(node_ref, &previous, &keys, revision),
+                None => self.reindex_node(node_ref, &HashSet::default(), &keys, revision),
+            }
+
+            if !keys.is_empty() {
+                let _ = nodes_to_keys.insert(node_ref.entry, keys);
+            }
+
+            if let Some(node_to_classes) = nodes_to_classes.get(&node_ref.entry) {
+                if !<N as Grammar>::Classifier::denounce(doc, node_ref, &node_to_classes.classes) {
+                    stats.skipped += 1;
+
+                    if let Some(sink) = sink {
+                        sink.on_classify(node_ref, true);
+                    }
+
+                    continue;
+                }
             }
 
             let classes = <N as Grammar>::Classifier::classify(doc, node_ref);
 
+            stats.classified += 1;
+
+            if let Some(sink) = sink {
+                sink.on_classify(node_ref, false);
+            }
+
+            let previous = match nodes_to_classes.remove(&node_ref.entry) {
+                Some(node_to_classes) => node_to_classes.classes,
+                None => HashSet::default(),
+            };
+
+            for class in &previous {
+                if classes.contains(class) {
+                    continue;
+                }
+
+                let Some(class_to_nodes) = classes_to_nodes.get_mut(class) else {
+                    // Safety
+                    //   1. Nodes and classes are always in sync.
+                    //   2. Both collections locked.
+                    unsafe {
+                        ld_unreachable!("Nodes and classes resynchronization.");
+                    }
+                };
+
+                class_to_nodes.revision = class_to_nodes.revision.max(revision);
+
+                if !class_to_nodes.nodes.make_mut().remove(node_ref) {
+                    // Safety
+                    //   1. Nodes and classes are always in sync.
+                    //   2. Both collections locked.
+                    unsafe {
+                        ld_unreachable!("Nodes and classes resynchronization.");
+                    }
+                }
+
+                stats.remove(class);
+            }
+
             for class in &classes {
+                if previous.contains(class) {
+                    continue;
+                }
+
+                stats.add(class);
+
                 let Some(class_to_nodes) = classes_to_nodes.get_mut(class) else {
                     let mut nodes = HashSet::default();
 
@@ -397,7 +703,7 @@ impl<N: Grammar, H: TaskHandle, S: SyncBuildHasher> Analyzer<N, H, S> {
                 .insert(node_ref.entry, NodeToClasses { classes })
                 .is_some()
             {
-                // Safety: Existence checked above.
+                // Safety: The entry has been removed above.
                 unsafe {
                     ld_unreachable!("Duplicate entry.");
                 }
@@ -443,9 +749,9 @@ impl<N: Grammar, H: TaskHandle, S: SyncBuildHasher> Analyzer<N, H, S> {
     }
 
     pub(super) fn remove_doc(&self, id: Id) -> bool {
-        if self.docs.remove(&id).is_none() {
+        let Some(entry) = self.docs.remove(&id) else {
             return false;
-        }
+        };
 
         if self.db.records.remove(&id).is_none() {
             // Safety: records are always in sync with documents.
@@ -454,11 +760,77 @@ impl<N: Grammar, H: TaskHandle, S: SyncBuildHasher> Analyzer<N, H, S> {
 
         let revision = self.db.commit_revision();
 
+        for (entry, keys) in entry.nodes_to_keys {
+            self.reindex_node(&NodeRef { id, entry }, &keys, &HashSet::default(), revision);
+        }
+
         self.trigger_event(id, DOC_REMOVED_EVENT, revision);
 
+        // Dropping the senders closes the receivers of the removed document.
+        let _ = self.subscribers.remove(&id);
+
         true
     }
 
+    fn reindex_node(
+        &self,
+        node_ref: &NodeRef,
+        previous: &HashSet<<N::CrossIndexer as CrossIndexer>::Key, S>,
+        keys: &HashSet<<N::CrossIndexer as CrossIndexer>::Key, S>,
+        revision: Revision,
+    ) {
+        for key in previous {
+            if keys.contains(key) {
+                continue;
+            }
+
+            let TableEntry::Occupied(mut entry) = self.index.entry(key.clone()) else {
+                // Safety: Nodes and keys are always in sync.
+                unsafe {
+                    ld_unreachable!("Nodes and keys resynchronization.");
+                }
+            };
+
+            let key_to_nodes = entry.get_mut();
+
+            key_to_nodes.revision = key_to_nodes.revision.max(revision);
+
+            let nodes = key_to_nodes.nodes.make_mut();
+
+            if !nodes.remove(node_ref) {
+                // Safety: Nodes and keys are always in sync.
+                unsafe {
+                    ld_unreachable!("Nodes and keys resynchronization.");
+                }
+            }
+
+            // The attributes that observed this key's nodes would be
+            // invalidated by the cleanup revision instead.
+            if nodes.is_empty() {
+                let _ = entry.remove();
+
+                let _ = self.index_cleanup.fetch_max(revision, Ordering::Relaxed);
+            }
+        }
+
+        for key in keys {
+            if previous.contains(key) {
+                continue;
+            }
+
+            let mut key_to_nodes = self.index.entry(key.clone()).or_default();
+
+            key_to_nodes.revision = key_to_nodes.revision.max(revision);
+
+            if !key_to_nodes.nodes.make_mut().insert(*node_ref) {
+                // Safety: Nodes and keys are always in sync.
+                unsafe {
+                    ld_unreachable!("Nodes and keys resynchronization.");
+                }
+            }
+        }
+    }
+
     pub(super) fn trigger_event(&self, id: Id, event: Event, revision: Revision) {
         {
             let mut guard = self.events.entry(Id::nil()).or_default();
@@ -475,5 +847,19 @@ impl<N: Grammar, H: TaskHandle, S: SyncBuildHasher> Analyzer<N, H, S> {
 
             *event_revision = revision.max(*event_revision);
         }
+
+        self.notify_subscribers(Id::nil(), id, event, revision);
+
+        if !id.is_nil() {
+            self.notify_subscribers(id, id, event, revision);
+        }
+    }
+
+    fn notify_subscribers(&self, key: Id, id: Id, event: Event, revision: Revision) {
+        let Some(mut guard) = self.subscribers.get_mut(&key) else {
+            return;
+        };
+
+        guard.retain(|sender| sender.send(id, event, revision));
     }
 }
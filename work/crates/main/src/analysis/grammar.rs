@@ -99,6 +99,22 @@ pub trait Grammar: Node + AbstractFeature {
     /// ```
     type Classifier: Classifier<Node = Self>;
 
+    /// A syntax tree node indexer that maintains the Analyzer-wide index of
+    /// the nodes across all documents.
+    ///
+    /// When using the [Node](lady_deirdre_derive::Node) macro, this value is
+    /// set to [VoidCrossIndexer] by default, or could be overridden using
+    /// the `#[cross_indexer(...)]` attribute:
+    ///
+    /// ```ignore
+    /// #[derive(Node)]
+    /// #[cross_indexer(MyCrossIndexer)]
+    /// enum MyNode {
+    ///     // ...
+    /// }
+    /// ```
+    type CrossIndexer: CrossIndexer<Node = Self>;
+
     /// A special semantic feature that describes the semantics of the entire
     /// Analyzer and is instantiated during the Analyzer's creation.
     /// This feature does not belong to any specific document and is shared
@@ -290,6 +306,33 @@ pub trait Classifier {
         doc: &Document<Self::Node>,
         node_ref: &NodeRef,
     ) -> HashSet<Self::Class, S>;
+
+    /// Decides whether the Analyzer should reclassify the node that has been
+    /// updated during the incremental reparsing.
+    ///
+    /// The `classes` parameter is a set of the node's classes computed
+    /// by the previous call of the [classify](Self::classify) function.
+    ///
+    /// If the function returns false, the Analyzer considers the node's
+    /// classes unchanged, and it skips both the [classify](Self::classify)
+    /// call and the update of the class indices. Otherwise, the Analyzer
+    /// reclassifies the node.
+    ///
+    /// You can override this function if the classification procedure is
+    /// expensive, but the implementation can cheaply tell that the node's
+    /// classes remain the same (e.g., by comparing a hash of the node's
+    /// classified content that the node stores).
+    ///
+    /// The default implementation always returns true.
+    #[inline(always)]
+    #[allow(unused_variables)]
+    fn denounce<S: SyncBuildHasher>(
+        doc: &Document<Self::Node>,
+        node_ref: &NodeRef,
+        classes: &HashSet<Self::Class, S>,
+    ) -> bool {
+        true
+    }
 }
 
 /// A node [Classifier] which is a noop.
@@ -308,6 +351,72 @@ impl<N: Node> Classifier for VoidClassifier<N> {
     }
 }
 
+/// An indexer of the syntax tree nodes across all documents of
+/// the [Analyzer](crate::analysis::Analyzer).
+///
+/// In contrast to the [Classifier], which partitions the nodes of each
+/// [document](Document) independently, the CrossIndexer maps the nodes to
+/// the keys of the global index that spans all documents managed by
+/// the Analyzer. For example, the keys could be the names of the symbols that
+/// the nodes declare or refer to, such that the index answers the question
+/// "which documents refer to the symbol X".
+///
+/// The Analyzer calls the [index](Self::index) function on each created or
+/// updated node during the initial parsing and incremental reparsing, and
+/// removes the document's nodes from the index when the document is
+/// [removed](crate::analysis::MutationAccess::remove_doc).
+///
+/// You can fetch a set of all nodes associated with the specific key using
+/// the [query_index](crate::analysis::AbstractTask::query_index) function of
+/// the task, or using
+/// the [query_index](crate::analysis::AttrContext::query_index) function in
+/// the attribute's [compute](Computable::compute) implementation that also
+/// subscribes this attribute to changes in the key's node set.
+///
+/// If you don't need a cross-document index, you can use
+/// the [VoidCrossIndexer] which is a noop.
+pub trait CrossIndexer {
+    /// A type of the syntax tree node this indexer intends to index.
+    ///
+    /// This type should match the [Grammar] type.
+    type Node: Node;
+
+    /// A type of the keys of the index.
+    type Key: Clone + Eq + Hash + Send + Sync;
+
+    /// Returns a set of keys under which the specified node should be indexed.
+    ///
+    /// The `node_ref` parameter points to the syntax tree node inside
+    /// the `doc` that needs to be indexed.
+    ///
+    /// The function returns an empty set if the node should not be indexed
+    /// or if the node referred to by the `node_ref` does not exist in this
+    /// document.
+    ///
+    /// Similarly to the [Classifier::classify] function, this function should
+    /// make a decision based on the inner structure of the node only.
+    fn index<S: SyncBuildHasher>(
+        doc: &Document<Self::Node>,
+        node_ref: &NodeRef,
+    ) -> HashSet<Self::Key, S>;
+}
+
+/// A node [CrossIndexer] which is a noop.
+pub struct VoidCrossIndexer<N: Node>(PhantomData<N>);
+
+impl<N: Node> CrossIndexer for VoidCrossIndexer<N> {
+    type Node = N;
+    type Key = ();
+
+    #[inline(always)]
+    fn index<S: SyncBuildHasher>(
+        _doc: &Document<Self::Node>,
+        _node_ref: &NodeRef,
+    ) -> HashSet<Self::Key, S> {
+        HashSet::default()
+    }
+}
+
 /// A composition of the semantics objects.
 ///
 /// The semantics of a particular syntax tree node is a composition of
@@ -480,7 +589,7 @@ pub trait AbstractFeature {
     fn feature(&self, key: Key) -> AnalysisResult<&dyn AbstractFeature>;
 
     /// Returns all valid keys of the [feature](Self::feature) function.
-    fn feature_keys(&self) -> &'static [&'static Key];
+    fn feature_keys(&self) -> &'static [&'static Key<'static>];
 }
 
 /// An initializer of the [Feature].
@@ -552,7 +661,7 @@ impl<N: Grammar> AbstractFeature for VoidFeature<N> {
     }
 
     #[inline(always)]
-    fn feature_keys(&self) -> &'static [&'static Key] {
+    fn feature_keys(&self) -> &'static [&'static Key<'static>] {
         &[]
     }
 }
@@ -756,7 +865,7 @@ impl<F: Feature> AbstractFeature for Semantics<F> {
     }
 
     #[inline(always)]
-    fn feature_keys(&self) -> &'static [&'static Key] {
+    fn feature_keys(&self) -> &'static [&'static Key<'static>] {
         let Ok(inner) = self.get() else {
             return &[];
         };
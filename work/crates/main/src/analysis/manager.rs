@@ -82,6 +82,25 @@ pub trait TaskHandle: Default + Clone + Send + Sync + 'static {
     /// Once the trigger function is called, the TaskHandle triggering state
     /// cannot be unset.
     fn trigger(&self);
+
+    /// Returns true if this TaskHandle and the `other` handle are clones of
+    /// the same handle.
+    ///
+    /// The task manager uses this function to recognize the nested tasks
+    /// requested with a clone of the handle of the task that is currently
+    /// active. Such tasks are granted without waiting behind the queued tasks
+    /// of higher priority, because the queued tasks cannot be granted until
+    /// the outer task is released.
+    ///
+    /// The default implementation always returns false, meaning that
+    /// the nested tasks are not recognized and are granted by the general
+    /// priority rules.
+    #[inline(always)]
+    fn is_same(&self, other: &Self) -> bool {
+        let _ = other;
+
+        false
+    }
 }
 
 /// A default implementation of the [TaskHandle] backed by the [Trigger] object.
@@ -111,6 +130,11 @@ impl TaskHandle for TriggerHandle {
     fn trigger(&self) {
         self.0.activate();
     }
+
+    #[inline(always)]
+    fn is_same(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
 }
 
 impl TriggerHandle {
@@ -183,7 +207,20 @@ impl<H: TaskHandle, S: SyncBuildHasher> TaskManager<H, S> {
 
         let mode_fits = active_mode_fits(active_mode, kind);
 
-        if mode_fits && state.pending_priority() <= priority {
+        // The waiting tasks of higher priority are served first even if
+        // the requested task fits the active mode. Otherwise, the continuous
+        // flood of the active mode's tasks would starve the waiting tasks of
+        // the other kinds.
+        //
+        // The nested task requested with the handle of an active task is
+        // an exception. The waiting tasks cannot be granted until the outer
+        // task is released, so the queued nested task would never be granted.
+        let queue_fits = match state.pending_priority() {
+            Some(pending) => pending <= priority || state.is_active_handle(handle),
+            None => true,
+        };
+
+        if mode_fits && queue_fits {
             let task_id = state.gen_task_id();
 
             state.insert_active_task(task_id, handle.clone(), priority);
@@ -316,6 +353,12 @@ impl<H: TaskHandle, S: SyncBuildHasher> TaskManager<H, S> {
         state.cancel_threshold
     }
 
+    pub(super) fn queue_depth(&self) -> usize {
+        let state = self.lock_state();
+
+        state.sleep_tasks.len()
+    }
+
     #[inline(always)]
     fn lock_state(&self) -> MutexGuard<ManagerState<H, S>> {
         self.state
@@ -355,6 +398,13 @@ impl<H: TaskHandle, S: SyncBuildHasher> ManagerState<H, S> {
         }
     }
 
+    #[inline(always)]
+    fn is_active_handle(&self, handle: &H) -> bool {
+        self.active_tasks
+            .values()
+            .any(|task_info| task_info.shutdown.is_same(handle))
+    }
+
     #[inline(always)]
     fn interrupt_active_tasks(&mut self, threshold: TaskPriority) {
         if threshold == 0 {
@@ -415,12 +465,8 @@ impl<H: TaskHandle, S: SyncBuildHasher> ManagerState<H, S> {
     }
 
     #[inline(always)]
-    fn pending_priority(&self) -> TaskPriority {
-        let Some(peek) = self.sleep_tasks.peek() else {
-            return 0;
-        };
-
-        peek.priority
+    fn pending_priority(&self) -> Option<TaskPriority> {
+        Some(self.sleep_tasks.peek()?.priority)
     }
 
     #[inline(always)]
@@ -457,7 +503,7 @@ struct SleepTaskInfo<H> {
 impl<H: TaskHandle> PartialEq for SleepTaskInfo<H> {
     #[inline(always)]
     fn eq(&self, other: &Self) -> bool {
-        self.priority.eq(&other.priority)
+        self.priority.eq(&other.priority) && self.id.eq(&other.id)
     }
 }
 
@@ -473,7 +519,11 @@ impl<H: TaskHandle> PartialOrd for SleepTaskInfo<H> {
 impl<H: TaskHandle> Ord for SleepTaskInfo<H> {
     #[inline(always)]
     fn cmp(&self, other: &Self) -> Ordering {
-        self.priority.cmp(&other.priority)
+        // The tasks of equal priority are served in the order of their
+        // requests, and the task ids are increasing.
+        self.priority
+            .cmp(&other.priority)
+            .then_with(|| other.id.cmp(&self.id))
     }
 }
 
@@ -41,6 +41,8 @@ mod error;
 mod grammar;
 mod lock;
 mod manager;
+mod profile;
+mod receiver;
 mod scope;
 mod slot;
 mod tasks;
@@ -51,6 +53,7 @@ pub use crate::analysis::{
     compute::{AttrContext, AttrReadGuard, Computable, SharedComputable, SlotReadGuard},
     database::Revision,
     entry::{
+        ClassificationStats,
         DocumentReadGuard,
         Event,
         CUSTOM_EVENT_START_RANGE,
@@ -63,15 +66,19 @@ pub use crate::analysis::{
     grammar::{
         AbstractFeature,
         Classifier,
+        CrossIndexer,
         Feature,
         Grammar,
         Initializer,
         Invalidator,
         Semantics,
         VoidClassifier,
+        VoidCrossIndexer,
         VoidFeature,
     },
     manager::{TaskHandle, TaskPriority, TriggerHandle},
+    profile::{ProfileEdge, ProfileEntry, ProfileReport, ProfileStack},
+    receiver::EventReceiver,
     scope::{Scope, ScopeAttr},
     slot::{Slot, SlotRef, NIL_SLOT_REF},
     tasks::{
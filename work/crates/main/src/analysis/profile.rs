@@ -0,0 +1,301 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{
+    collections::HashMap,
+    fmt::Write,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+use crate::sync::{Shared, SyncBuildHasher};
+
+/// A report of the attributes computation time collected by
+/// the [Analyzer](crate::analysis::Analyzer)'s profiler.
+///
+/// The profiler is disabled by default. To enable it, set
+/// the [profile](crate::analysis::AnalyzerConfig::profile) configuration
+/// option, and then obtain the report using
+/// the [profile_report](crate::analysis::Analyzer::profile_report) function.
+///
+/// The report aggregates all computations of the attributes'
+/// [Computable](crate::analysis::Computable) functions since the creation of
+/// the Analyzer. The Computable types are identified by their
+/// [type names](std::any::type_name).
+///
+/// Under the wasm targets, the profiler records the computation counts only,
+/// and all durations are zero.
+#[derive(Clone, Default, Debug)]
+pub struct ProfileReport {
+    /// Per-Computable computation statistics sorted by
+    /// the [total](ProfileEntry::total) time in descending order.
+    pub entries: Vec<ProfileEntry>,
+
+    /// Dependencies between the Computables sorted by
+    /// the [total](ProfileEdge::total) time in descending order.
+    ///
+    /// Each edge denotes that the computation of the
+    /// [parent](ProfileEdge::parent) attribute triggered the computation of
+    /// the [child](ProfileEdge::child) attribute.
+    pub edges: Vec<ProfileEdge>,
+
+    /// The exclusive computation time of each distinct stack of nested
+    /// computations sorted by the [own](ProfileStack::own) time in descending
+    /// order.
+    pub stacks: Vec<ProfileStack>,
+}
+
+impl ProfileReport {
+    /// Returns true if the report does not have any records.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Renders the [stacks](Self::stacks) in the folded-stack text format.
+    ///
+    /// Each line of the output consists of the semicolon-separated stack
+    /// frames (from the outermost to the innermost Computable name) followed by
+    /// a space and the exclusive time of this stack in microseconds:
+    ///
+    /// ```text
+    /// my_crate::ScopeData;my_crate::LocalType 1250
+    /// ```
+    ///
+    /// This format is accepted by the common flamegraph rendering tools
+    /// (e.g., [inferno](https://github.com/jonhoo/inferno)).
+    pub fn folded(&self) -> String {
+        let mut result = String::new();
+
+        for stack in &self.stacks {
+            let _ = writeln!(
+                result,
+                "{} {}",
+                stack.frames.join(";"),
+                stack.own.as_micros(),
+            );
+        }
+
+        result
+    }
+}
+
+/// Computation statistics of a single [Computable](crate::analysis::Computable)
+/// type.
+///
+/// See [ProfileReport] for details.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ProfileEntry {
+    /// The type name of the Computable.
+    pub name: &'static str,
+
+    /// The number of times the Computable function has been called.
+    pub calls: usize,
+
+    /// The total time spent in the Computable function, including the time
+    /// spent computing the attributes it has read.
+    pub total: Duration,
+
+    /// The total time spent in the Computable function, excluding the time
+    /// spent computing the attributes it has read.
+    pub own: Duration,
+
+    /// The longest single call of the Computable function, including
+    /// the time spent computing the attributes it has read.
+    pub max: Duration,
+}
+
+/// A dependency between two [Computable](crate::analysis::Computable) types.
+///
+/// See [ProfileReport] for details.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ProfileEdge {
+    /// The type name of the Computable that triggered the computation.
+    pub parent: &'static str,
+
+    /// The type name of the triggered Computable.
+    pub child: &'static str,
+
+    /// The number of computations of the child triggered by the parent.
+    pub calls: usize,
+
+    /// The total time of the child computations triggered by the parent.
+    pub total: Duration,
+}
+
+/// An exclusive computation time of a stack of nested
+/// [Computable](crate::analysis::Computable) calls.
+///
+/// See [ProfileReport] for details.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ProfileStack {
+    /// The type names of the Computables from the outermost to the innermost
+    /// call.
+    pub frames: Vec<&'static str>,
+
+    /// The total time spent in the innermost Computable of this stack,
+    /// excluding the time spent computing the attributes it has read.
+    pub own: Duration,
+}
+
+pub(super) struct ProfileFrame {
+    name: &'static str,
+    parent: Option<Shared<ProfileFrame>>,
+    nested: AtomicU64,
+}
+
+impl ProfileFrame {
+    #[inline(always)]
+    pub(super) fn new(name: &'static str, parent: Option<Shared<ProfileFrame>>) -> Shared<Self> {
+        Shared::new(Self {
+            name,
+            parent,
+            nested: AtomicU64::new(0),
+        })
+    }
+}
+
+pub(super) struct Profiler<S: SyncBuildHasher> {
+    data: Mutex<ProfileData<S>>,
+}
+
+impl<S: SyncBuildHasher> Profiler<S> {
+    #[inline(always)]
+    pub(super) fn new() -> Self {
+        Self {
+            data: Mutex::new(ProfileData {
+                entries: HashMap::default(),
+                edges: HashMap::default(),
+                stacks: HashMap::default(),
+            }),
+        }
+    }
+
+    pub(super) fn record(&self, frame: &ProfileFrame, duration: Duration) {
+        let nested = Duration::from_nanos(frame.nested.load(Ordering::Relaxed));
+        let own = duration.saturating_sub(nested);
+
+        if let Some(parent) = &frame.parent {
+            let nanos = u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX);
+
+            let _ = parent.as_ref().nested.fetch_add(nanos, Ordering::Relaxed);
+        }
+
+        let mut frames = Vec::new();
+        let mut next = Some(frame);
+
+        while let Some(frame) = next {
+            frames.push(frame.name);
+            next = frame.parent.as_ref().map(|parent| parent.as_ref());
+        }
+
+        frames.reverse();
+
+        let mut data = self.data.lock().unwrap_or_else(|error| error.into_inner());
+
+        let entry = data.entries.entry(frame.name).or_insert(ProfileEntry {
+            name: frame.name,
+            calls: 0,
+            total: Duration::ZERO,
+            own: Duration::ZERO,
+            max: Duration::ZERO,
+        });
+
+        entry.calls += 1;
+        entry.total += duration;
+        entry.own += own;
+        entry.max = entry.max.max(duration);
+
+        if let Some(parent) = &frame.parent {
+            let parent = parent.as_ref().name;
+
+            let edge = data
+                .edges
+                .entry((parent, frame.name))
+                .or_insert(ProfileEdge {
+                    parent,
+                    child: frame.name,
+                    calls: 0,
+                    total: Duration::ZERO,
+                });
+
+            edge.calls += 1;
+            edge.total += duration;
+        }
+
+        *data.stacks.entry(frames).or_default() += own;
+    }
+
+    pub(super) fn report(&self) -> ProfileReport {
+        let data = self.data.lock().unwrap_or_else(|error| error.into_inner());
+
+        let mut entries = data.entries.values().cloned().collect::<Vec<_>>();
+        let mut edges = data.edges.values().cloned().collect::<Vec<_>>();
+
+        let mut stacks = data
+            .stacks
+            .iter()
+            .map(|(frames, own)| ProfileStack {
+                frames: frames.clone(),
+                own: *own,
+            })
+            .collect::<Vec<_>>();
+
+        entries.sort_by(|a, b| b.total.cmp(&a.total).then(a.name.cmp(b.name)));
+
+        edges.sort_by(|a, b| {
+            b.total
+                .cmp(&a.total)
+                .then(a.parent.cmp(b.parent))
+                .then(a.child.cmp(b.child))
+        });
+
+        stacks.sort_by(|a, b| b.own.cmp(&a.own).then(a.frames.cmp(&b.frames)));
+
+        ProfileReport {
+            entries,
+            edges,
+            stacks,
+        }
+    }
+}
+
+struct ProfileData<S> {
+    entries: HashMap<&'static str, ProfileEntry, S>,
+    edges: HashMap<(&'static str, &'static str), ProfileEdge, S>,
+    stacks: HashMap<Vec<&'static str>, Duration, S>,
+}
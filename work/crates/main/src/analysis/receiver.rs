@@ -0,0 +1,266 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::{Debug, Formatter},
+    sync::{Condvar, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    analysis::{Event, Revision},
+    arena::Id,
+    report::ld_unreachable,
+    sync::{SharedWeak, SharedWeakable},
+};
+
+/// A queue of the [Analyzer](crate::analysis::Analyzer)'s events related to
+/// a particular document.
+///
+/// You can obtain this object using
+/// the [Analyzer::subscribe](crate::analysis::Analyzer::subscribe) function.
+///
+/// Each item of the queue is a triple of the document's [identifier](Id),
+/// the [event](Event), and the Analyzer's [revision](Revision) at which
+/// the event has been triggered.
+///
+/// The receiver does not grow indefinitely if the events are triggered faster
+/// than you drain them: if the queue already has a pending item with the same
+/// document identifier and event, the queue updates the revision of this item
+/// instead of enqueueing a new one. Therefore, each event is observed at least
+/// once, and the observed revision is the latest revision at which the event
+/// has been triggered.
+///
+/// The EventReceiver becomes closed when the subscribed document is
+/// [removed](crate::analysis::MutationAccess::remove_doc) from the Analyzer,
+/// or when the Analyzer is dropped. The closed receiver still yields
+/// the items that remain in the queue (including the
+/// [DOC_REMOVED_EVENT](crate::analysis::DOC_REMOVED_EVENT) event), and then
+/// the [recv](Self::recv) function returns None without blocking.
+///
+/// Dropping the EventReceiver unsubscribes it from the Analyzer.
+pub struct EventReceiver {
+    queue: SharedWeakable<EventQueue>,
+}
+
+impl Debug for EventReceiver {
+    #[inline]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        let state = self.queue.as_ref().lock();
+
+        formatter
+            .debug_struct("EventReceiver")
+            .field("pending", &state.order.len())
+            .field("closed", &state.closed)
+            .finish()
+    }
+}
+
+impl EventReceiver {
+    /// Takes the next item from the queue without blocking.
+    ///
+    /// Returns None if the queue is empty.
+    #[inline]
+    pub fn try_recv(&self) -> Option<(Id, Event, Revision)> {
+        self.queue.as_ref().lock().pop()
+    }
+
+    /// Takes the next item from the queue, blocking the current thread until
+    /// an item is available.
+    ///
+    /// Returns None if the queue is empty and the receiver is
+    /// [closed](Self::is_closed).
+    #[inline(always)]
+    pub fn recv(&self) -> Option<(Id, Event, Revision)> {
+        self.recv_until(None)
+    }
+
+    /// Takes the next item from the queue, blocking the current thread until
+    /// an item is available or until the `timeout` expires.
+    ///
+    /// Returns None if the timeout expired, or if the queue is empty and
+    /// the receiver is [closed](Self::is_closed).
+    #[inline(always)]
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<(Id, Event, Revision)> {
+        self.recv_until(Instant::now().checked_add(timeout))
+    }
+
+    /// Returns the number of pending items in the queue.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.queue.as_ref().lock().order.len()
+    }
+
+    /// Returns true if the queue does not have pending items.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns true if the Analyzer will not send new items to this receiver.
+    ///
+    /// The closed receiver may still have pending items in the queue.
+    #[inline]
+    pub fn is_closed(&self) -> bool {
+        self.queue.as_ref().lock().closed
+    }
+
+    #[inline(always)]
+    pub(super) fn new() -> (Self, EventSender) {
+        let queue = SharedWeakable::new(EventQueue {
+            state: Mutex::new(QueueState {
+                order: VecDeque::new(),
+                pending: HashMap::new(),
+                closed: false,
+            }),
+            condvar: Condvar::new(),
+        });
+
+        let sender = EventSender {
+            queue: queue.downgrade(),
+        };
+
+        (Self { queue }, sender)
+    }
+
+    fn recv_until(&self, deadline: Option<Instant>) -> Option<(Id, Event, Revision)> {
+        let queue = self.queue.as_ref();
+
+        let mut state = queue.lock();
+
+        loop {
+            if let Some(item) = state.pop() {
+                return Some(item);
+            }
+
+            if state.closed {
+                return None;
+            }
+
+            state = match deadline {
+                None => queue
+                    .condvar
+                    .wait(state)
+                    .unwrap_or_else(|poison| poison.into_inner()),
+
+                Some(deadline) => {
+                    let timeout = deadline.checked_duration_since(Instant::now())?;
+
+                    queue
+                        .condvar
+                        .wait_timeout(state, timeout)
+                        .unwrap_or_else(|poison| poison.into_inner())
+                        .0
+                }
+            };
+        }
+    }
+}
+
+pub(super) struct EventSender {
+    queue: SharedWeak<EventQueue>,
+}
+
+impl Drop for EventSender {
+    fn drop(&mut self) {
+        let Some(queue) = self.queue.upgrade() else {
+            return;
+        };
+
+        let queue = queue.as_ref();
+
+        queue.lock().closed = true;
+        queue.condvar.notify_all();
+    }
+}
+
+impl EventSender {
+    // Returns false if the receiver has been dropped.
+    pub(super) fn send(&self, id: Id, event: Event, revision: Revision) -> bool {
+        let Some(queue) = self.queue.upgrade() else {
+            return false;
+        };
+
+        let queue = queue.as_ref();
+
+        queue.lock().push(id, event, revision);
+        queue.condvar.notify_one();
+
+        true
+    }
+}
+
+struct EventQueue {
+    state: Mutex<QueueState>,
+    condvar: Condvar,
+}
+
+impl EventQueue {
+    #[inline(always)]
+    fn lock(&self) -> MutexGuard<'_, QueueState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poison| poison.into_inner())
+    }
+}
+
+struct QueueState {
+    order: VecDeque<(Id, Event)>,
+    pending: HashMap<(Id, Event), Revision>,
+    closed: bool,
+}
+
+impl QueueState {
+    fn push(&mut self, id: Id, event: Event, revision: Revision) {
+        if let Some(pending) = self.pending.get_mut(&(id, event)) {
+            *pending = revision.max(*pending);
+            return;
+        }
+
+        let _ = self.pending.insert((id, event), revision);
+        self.order.push_back((id, event));
+    }
+
+    fn pop(&mut self) -> Option<(Id, Event, Revision)> {
+        let (id, event) = self.order.pop_front()?;
+
+        let Some(revision) = self.pending.remove(&(id, event)) else {
+            // Safety: The order queue and the pending map are always in sync.
+            unsafe { ld_unreachable!("Missing pending event.") }
+        };
+
+        Some((id, event, revision))
+    }
+}
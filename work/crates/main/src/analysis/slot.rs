@@ -248,7 +248,7 @@ where
     }
 
     #[inline(always)]
-    fn feature_keys(&self) -> &'static [&'static Key] {
+    fn feature_keys(&self) -> &'static [&'static Key<'static>] {
         &[]
     }
 }
@@ -435,7 +435,13 @@ where
 /// The [nil](SlotRef::nil) SlotRefs are special references that are considered
 /// to be always invalid. They intentionally don't refer any slot within
 /// any Analyzer.
+///
+/// When the `serde` feature of this crate is enabled, SlotRef implements
+/// serde's Serialize and Deserialize traits. A deserialized SlotRef is
+/// meaningful only against the same Analyzer instance of the same process.
+/// Use the [SlotRef::is_valid_ref] function to check its validity.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SlotRef {
     /// An identifier of the document managed by the Analyzer to which
     /// the slot belongs.
@@ -32,7 +32,7 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::{collections::HashSet, hash::RandomState};
+use std::{borrow::Cow, collections::HashSet, hash::RandomState};
 
 use crate::{
     analysis::{
@@ -40,7 +40,9 @@ use crate::{
         AnalysisError,
         AnalysisResult,
         Analyzer,
+        ClassificationStats,
         Classifier,
+        CrossIndexer,
         DocumentReadGuard,
         Event,
         Grammar,
@@ -49,7 +51,7 @@ use crate::{
         TriggerHandle,
     },
     arena::Id,
-    lexis::{ToSpan, TokenBuffer},
+    lexis::{SiteSpan, ToSpan, TokenBuffer},
     sync::{Shared, SyncBuildHasher},
     syntax::NodeRef,
     units::{CompilationUnit, Document},
@@ -343,6 +345,36 @@ pub trait MutationAccess<N: Grammar, H: TaskHandle, S: SyncBuildHasher>:
         self.analyzer().write_to_doc(self.handle(), id, span, text)
     }
 
+    /// Writes a batch of user-input edits into the documents managed by
+    /// the [Analyzer].
+    ///
+    /// Each item of the `edits` iterator is a triple of the document's
+    /// [identifier](Id), the [span](SiteSpan) of the text to be rewritten,
+    /// and the text to be written in place of the spanned fragment. The edits
+    /// are applied in the iteration order, such that the span of each edit
+    /// refers to the document's text after all previous edits of the same
+    /// document.
+    ///
+    /// Unlike a series of the [write_to_doc](Self::write_to_doc) calls, this
+    /// function reparses the documents first, and then invalidates
+    /// the analyzer's semantic graph in one pass at the end. In particular,
+    /// the [DOC_UPDATED_EVENT](crate::analysis::DOC_UPDATED_EVENT) and
+    /// the [DOC_ERRORS_EVENT](crate::analysis::DOC_ERRORS_EVENT) events are
+    /// triggered at most once per each edited document. This is beneficial
+    /// when the editor applies a workspace-wide change that touches many
+    /// documents at once.
+    ///
+    /// The function returns the same errors as the write_to_doc function.
+    /// If an edit fails, the remaining edits are not applied, but the edits
+    /// applied before the failure are still reflected in the semantic graph.
+    #[inline(always)]
+    fn write_batch<'a>(
+        &mut self,
+        edits: impl IntoIterator<Item = (Id, SiteSpan, Cow<'a, str>)>,
+    ) -> AnalysisResult<()> {
+        self.analyzer().write_batch(self.handle(), edits)
+    }
+
     /// Removes a document managed by the [Analyzer].
     ///
     /// The `id` parameter specifies the document's [identifier](Id).
@@ -511,6 +543,53 @@ pub trait AbstractTask<N: Grammar, H: TaskHandle, S: SyncBuildHasher>: TaskSeale
         Ok(class_to_nodes.nodes.clone())
     }
 
+    /// Returns a snapshot of the [node references](NodeRef) set across all
+    /// documents of the analyzer that are associated with the specified `key`
+    /// in the cross-document index.
+    ///
+    /// Each node reference includes the [identifier](Id) of the document to
+    /// which the node belongs.
+    ///
+    /// The returning object is a clone of the already precomputed [Shared] set.
+    /// Therefore, it is relatively cheap to call this function.
+    ///
+    /// If there are no nodes associated with the `key`, the function returns
+    /// an empty set.
+    ///
+    /// See [CrossIndexer] for details.
+    #[inline(always)]
+    fn query_index(
+        &self,
+        key: &<N::CrossIndexer as CrossIndexer>::Key,
+    ) -> Shared<HashSet<NodeRef, S>> {
+        let Some(key_to_nodes) = self.analyzer().index.get(key) else {
+            return Shared::default();
+        };
+
+        key_to_nodes.nodes.clone()
+    }
+
+    /// Returns the statistics of the most recent classification pass of
+    /// the document addressed by the `id` parameter.
+    ///
+    /// The classification pass takes place when the document is added to
+    /// the Analyzer, and on each document edit that changes the document's
+    /// syntax tree.
+    ///
+    /// If the document addressed by the `id` parameter does not exist in the
+    /// analyzer, the function returns
+    /// a [MissingDocument](AnalysisError::MissingDocument) error.
+    fn classification_stats(
+        &self,
+        id: Id,
+    ) -> AnalysisResult<ClassificationStats<<N::Classifier as Classifier>::Class, S>> {
+        let Some(guard) = self.analyzer().docs.get(&id) else {
+            return Err(AnalysisError::MissingDocument);
+        };
+
+        Ok(guard.stats.clone())
+    }
+
     /// Provides access to the Analyzer's
     /// [common semantics](Grammar::CommonSemantics), a special semantic
     /// feature that is instantiated during the Analyzer's creation. It does
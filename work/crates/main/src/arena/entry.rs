@@ -32,7 +32,14 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::fmt::{Debug, Formatter};
+use core::{
+    cmp::Ordering,
+    fmt::{Debug, Formatter},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
+use crate::arena::Repo;
 
 /// A [versioned index](Entry) that does not address any value within any
 /// possible storage.
@@ -70,7 +77,13 @@ pub type EntryVersion = usize;
 /// A pair of [usize::MAX] values of the Entry's index and version numbers
 /// denotes an Entry which is intentionally invalid; an Entry that does not
 /// address any value within any possible type of storage.
+///
+/// When the `serde` feature of this crate is enabled, the Entry is serialized
+/// as a pair of its index and version numbers. Both numbers round-trip exactly,
+/// such that the deserialized Entry of a removed value remains invalid for
+/// the repository.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Entry {
     /// A non-versioned part of the index.
     pub index: EntryIndex,
@@ -89,7 +102,7 @@ impl Default for Entry {
 
 impl Debug for Entry {
     #[inline]
-    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
         if self.is_nil() {
             return formatter.write_str("Nil");
         }
@@ -123,3 +136,228 @@ impl Entry {
         self.index == EntryIndex::MAX && self.version == EntryVersion::MAX
     }
 }
+
+/// A [versioned index](Entry) branded by the type of the values of
+/// the [Repo] that issued this index.
+///
+/// The TypedEntry is a zero-cost wrapper of the [Entry] object that prevents
+/// accidental use of the entry created by one repository with a repository
+/// of a different type of values. For example, an attempt to borrow a value
+/// of the `Repo<Node>` by the TypedEntry issued by the `Repo<Token>` is
+/// a compile-time error.
+///
+/// You can obtain the TypedEntry using the [Repo::insert_typed] function, or
+/// by branding an existing Entry with the [TypedEntry::new] function.
+/// The [untyped](TypedEntry::untyped) function and the [From] implementation
+/// convert the TypedEntry back into the ordinary Entry.
+///
+/// Note that the brand does not distinguish between distinct repositories
+/// of the same value type.
+#[repr(transparent)]
+pub struct TypedEntry<T> {
+    entry: Entry,
+    _type: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for TypedEntry<T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for TypedEntry<T> {}
+
+impl<T> PartialEq for TypedEntry<T> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.entry.eq(&other.entry)
+    }
+}
+
+impl<T> Eq for TypedEntry<T> {}
+
+impl<T> PartialOrd for TypedEntry<T> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for TypedEntry<T> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.entry.cmp(&other.entry)
+    }
+}
+
+impl<T> Hash for TypedEntry<T> {
+    #[inline(always)]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.entry.hash(state)
+    }
+}
+
+impl<T> Default for TypedEntry<T> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::nil()
+    }
+}
+
+impl<T> Debug for TypedEntry<T> {
+    #[inline(always)]
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        Debug::fmt(&self.entry, formatter)
+    }
+}
+
+impl<T> AsRef<Entry> for TypedEntry<T> {
+    #[inline(always)]
+    fn as_ref(&self) -> &Entry {
+        &self.entry
+    }
+}
+
+impl<T> From<TypedEntry<T>> for Entry {
+    #[inline(always)]
+    fn from(entry: TypedEntry<T>) -> Self {
+        entry.entry
+    }
+}
+
+impl<T> TypedEntry<T> {
+    /// Brands the untyped [versioned index](Entry) by the `T` type.
+    ///
+    /// It is up to the caller to ensure that the `entry` has been issued by
+    /// a repository of `T` values. Otherwise, the TypedEntry would not address
+    /// any value, or would address a wrong value of the same type.
+    #[inline(always)]
+    pub const fn new(entry: Entry) -> Self {
+        Self {
+            entry,
+            _type: PhantomData,
+        }
+    }
+
+    /// Returns a typed versioned index that intentionally does not address
+    /// any value within any possible storage.
+    #[inline(always)]
+    pub const fn nil() -> Self {
+        Self::new(Entry::nil())
+    }
+
+    /// Returns true, if this versioned index intentionally does not address
+    /// any value within any possible storage.
+    #[inline(always)]
+    pub const fn is_nil(&self) -> bool {
+        self.entry.is_nil()
+    }
+
+    /// Returns the underlying untyped [versioned index](Entry).
+    #[inline(always)]
+    pub const fn untyped(&self) -> Entry {
+        self.entry
+    }
+
+    /// Returns true if the `repo` contains an Occupied entry addressed
+    /// by this versioned index.
+    ///
+    /// See [Repo::contains] for details.
+    #[inline(always)]
+    pub fn contains(&self, repo: &Repo<T>) -> bool {
+        repo.contains(&self.entry)
+    }
+
+    /// Immutably borrows a value of the Occupied entry of the `repo`
+    /// addressed by this versioned index.
+    ///
+    /// See [Repo::get] for details.
+    #[inline(always)]
+    pub fn get<'a>(&self, repo: &'a Repo<T>) -> Option<&'a T> {
+        repo.get(&self.entry)
+    }
+
+    /// Mutably borrows a value of the Occupied entry of the `repo`
+    /// addressed by this versioned index.
+    ///
+    /// See [Repo::get_mut] for details.
+    #[inline(always)]
+    pub fn get_mut<'a>(&self, repo: &'a mut Repo<T>) -> Option<&'a mut T> {
+        repo.get_mut(&self.entry)
+    }
+
+    /// Removes an Occupied entry of the `repo` addressed by this versioned
+    /// index.
+    ///
+    /// See [Repo::remove] for details.
+    #[inline(always)]
+    pub fn remove(&self, repo: &mut Repo<T>) -> Option<T> {
+        repo.remove(&self.entry)
+    }
+}
+
+/// A weak handle of the Occupied entry within the [Repo].
+///
+/// The WeakEntry captures the index and version numbers of the
+/// [versioned index](Entry), but does not provide direct access to the value.
+/// To access the value, the handle must be upgraded back to the Entry using
+/// the [WeakEntry::upgrade] function that checks if the repository still
+/// contains the same entry.
+///
+/// The upgrade fails if the entry has been removed from the repository, even
+/// if the repository reused the inner slot of the removed entry for a new
+/// value. Therefore, the WeakEntry is a convenient key for the data derived
+/// from the repository values and cached outside of the repository:
+/// a successful upgrade guarantees that the cached data relates to the same
+/// value.
+///
+/// Created by the [Repo::downgrade] function.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct WeakEntry {
+    entry: Entry,
+}
+
+impl Debug for WeakEntry {
+    #[inline(always)]
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("Weak(")?;
+        Debug::fmt(&self.entry, formatter)?;
+        formatter.write_str(")")
+    }
+}
+
+impl WeakEntry {
+    /// Returns a weak handle that never upgrades to a versioned index.
+    #[inline(always)]
+    pub const fn nil() -> Self {
+        Self {
+            entry: Entry::nil(),
+        }
+    }
+
+    /// Returns true, if this handle intentionally does not address any entry.
+    #[inline(always)]
+    pub const fn is_nil(&self) -> bool {
+        self.entry.is_nil()
+    }
+
+    #[inline(always)]
+    pub(super) const fn from_entry(entry: Entry) -> Self {
+        Self { entry }
+    }
+
+    /// Returns the [versioned index](Entry) of the entry if the `repo`
+    /// still contains the Occupied entry captured by this handle.
+    ///
+    /// Returns None if the entry has been removed from the repository,
+    /// including the case when the repository reused the entry's slot for
+    /// another value.
+    #[inline(always)]
+    pub fn upgrade<T>(&self, repo: &Repo<T>) -> Option<Entry> {
+        match repo.contains(&self.entry) {
+            true => Some(self.entry),
+            false => None,
+        }
+    }
+}
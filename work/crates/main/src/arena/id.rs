@@ -32,13 +32,18 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::{
+use alloc::string::String;
+use core::{
     borrow::Borrow,
     fmt::{Debug, Display, Formatter},
     ops::Deref,
     sync::atomic::{AtomicU64, Ordering},
 };
 
+#[cfg(all(feature = "serde", feature = "std"))]
+use std::cell::Cell;
+
+#[cfg(feature = "std")]
 use crate::sync::{Lazy, Table};
 
 /// A globally unique identifier of a compilation unit (or a similar object).
@@ -82,7 +87,23 @@ use crate::sync::{Lazy, Table};
 ///
 /// However, all built-in compilation unit types within this crate automatically
 /// clean up their Id names.
+///
+/// The names registry is available only when the `std` feature of this crate
+/// is enabled. Without this feature, the identifiers do not have names.
+///
+/// When the `serde` feature of this crate is enabled, the Id is serialized as
+/// its inner [u64] number. The name of the Id is not a part of the serialized
+/// data. Note that the deserialized Id denotes the same compilation unit only
+/// within the process that created the original Id instance. In another process,
+/// the same number may denote an unrelated compilation unit or nothing.
+///
+/// The exception is the Id of a deserialized [Document](crate::units::Document):
+/// while the Document deserializer restores the syntax tree nodes,
+/// the references to the original Document inside the nodes are deserialized
+/// with the Id of the restored Document.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 #[repr(transparent)]
 pub struct Id {
     inner: u64,
@@ -96,7 +117,7 @@ impl Identifiable for Id {
 }
 
 impl Debug for Id {
-    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
         if self.is_nil() {
             return formatter.write_str("Nil");
         }
@@ -116,7 +137,7 @@ impl Debug for Id {
 }
 
 impl Display for Id {
-    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
         if self.is_nil() {
             return formatter.write_str("Nil");
         }
@@ -194,16 +215,27 @@ impl Id {
     ///
     /// The returning string is empty if there is no name associated with this
     /// identifier.
+    ///
+    /// Without the `std` feature of this crate, this function always returns
+    /// an empty string.
     #[inline(always)]
     pub fn name(&self) -> String {
         if self.is_nil() {
             return String::new();
         }
 
-        ID_NAMES
-            .get(self)
-            .map(|name_guard| name_guard.deref().clone())
-            .unwrap_or_default()
+        #[cfg(feature = "std")]
+        {
+            ID_NAMES
+                .get(self)
+                .map(|name_guard| name_guard.deref().clone())
+                .unwrap_or_default()
+        }
+
+        #[cfg(not(feature = "std"))]
+        {
+            String::new()
+        }
     }
 
     /// Associates this identifier (and all copies of it) with a user-facing
@@ -219,6 +251,7 @@ impl Id {
     /// **Panic**
     ///
     /// This function panics if the id is [nil](Self::nil).
+    #[cfg(feature = "std")]
     #[inline(always)]
     pub fn set_name(&self, name: impl Into<String>) {
         if self.is_nil() {
@@ -240,6 +273,7 @@ impl Id {
     /// **Panic**
     ///
     /// This function panics if the id is [nil](Self::nil).
+    #[cfg(feature = "std")]
     #[inline(always)]
     pub fn clear_name(&self) -> bool {
         if self.is_nil() {
@@ -248,6 +282,78 @@ impl Id {
 
         ID_NAMES.remove(self).is_some()
     }
+
+    /// Returns all identifiers that currently have names, together with
+    /// clones of their names.
+    ///
+    /// The returning vector is sorted by the identifiers, which is the order
+    /// of the identifiers creation.
+    ///
+    /// The built-in compilation units remove their identifier names when
+    /// the unit is dropped, so the identifiers of the dropped
+    /// [Documents](crate::units::Document) do not appear in this list.
+    /// However, the names of the identifiers created manually remain in
+    /// the list until [cleared](Self::clear_name) explicitly.
+    ///
+    /// This function is useful for debugging purposes, e.g. to correlate
+    /// the log output with the compilation units of the process.
+    ///
+    /// The function observes a consistent snapshot of the names registry:
+    /// concurrent [set_name](Self::set_name) and
+    /// [clear_name](Self::clear_name) calls are either fully visible in
+    /// the result or not visible at all.
+    #[cfg(feature = "std")]
+    pub fn enumerate() -> Vec<(Id, String)> {
+        let shards = ID_NAMES
+            .shards()
+            .iter()
+            .map(|shard| shard.read().unwrap_or_else(|poison| poison.into_inner()))
+            .collect::<Vec<_>>();
+
+        let mut result = shards
+            .iter()
+            .flat_map(|shard| shard.iter())
+            .map(|(id, name)| (*id, name.clone()))
+            .collect::<Vec<_>>();
+
+        drop(shards);
+
+        result.sort_by_key(|(id, _)| *id);
+
+        result
+    }
+
+    /// Returns all identifiers that currently have the specified `name`.
+    ///
+    /// Since the identifier names are not necessarily unique, the function
+    /// returns a vector of the identifiers sorted in the order of their
+    /// creation.
+    ///
+    /// See [enumerate](Self::enumerate) for details.
+    #[cfg(feature = "std")]
+    pub fn find_by_name(name: &str) -> Vec<Id> {
+        if name.is_empty() {
+            return Vec::new();
+        }
+
+        let mut result = ID_NAMES
+            .shards()
+            .iter()
+            .flat_map(|shard| {
+                shard
+                    .read()
+                    .unwrap_or_else(|poison| poison.into_inner())
+                    .iter()
+                    .filter(|(_, candidate)| candidate.as_str() == name)
+                    .map(|(id, _)| *id)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        result.sort();
+
+        result
+    }
 }
 
 /// A helper trait that denotes a compilation unit to which this object belongs.
@@ -270,8 +376,51 @@ pub trait Identifiable {
     fn id(&self) -> Id;
 }
 
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Id {
+    #[inline]
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let inner = <u64 as serde::Deserialize>::deserialize(deserializer)?;
+
+        #[cfg(feature = "std")]
+        if let Some((source, target)) = ID_REMAP.get() {
+            if source.inner == inner {
+                return Ok(target);
+            }
+        }
+
+        Ok(Self { inner })
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "std"))]
+impl Id {
+    // Deserializes the `source` identifier as the `target` identifier on
+    // the current thread while the `scope` function is running.
+    pub(crate) fn remap<R>(source: Id, target: Id, scope: impl FnOnce() -> R) -> R {
+        struct Guard(Option<(Id, Id)>);
+
+        impl Drop for Guard {
+            #[inline(always)]
+            fn drop(&mut self) {
+                ID_REMAP.set(self.0);
+            }
+        }
+
+        let _guard = Guard(ID_REMAP.replace(Some((source, target))));
+
+        scope()
+    }
+}
+
+#[cfg(feature = "std")]
 static ID_NAMES: Lazy<Table<Id, String>> = Lazy::new(|| Table::new());
 
+#[cfg(all(feature = "serde", feature = "std"))]
+std::thread_local! {
+    static ID_REMAP: Cell<Option<(Id, Id)>> = const { Cell::new(None) };
+}
+
 pub(crate) enum SubId {
     Own(Id),
     Fork(Id),
@@ -302,6 +451,7 @@ impl Drop for SubId {
             return;
         };
 
+        #[cfg(feature = "std")]
         id.clear_name();
     }
 }
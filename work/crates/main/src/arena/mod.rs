@@ -38,7 +38,7 @@ mod repo;
 
 pub(crate) use crate::arena::id::SubId;
 pub use crate::arena::{
-    entry::{Entry, EntryIndex, EntryVersion, NIL_ENTRY},
+    entry::{Entry, EntryIndex, EntryVersion, TypedEntry, WeakEntry, NIL_ENTRY},
     id::{Id, Identifiable},
     repo::{
         Repo,
@@ -50,5 +50,7 @@ pub use crate::arena::{
         RepoIntoIter,
         RepoIter,
         RepoIterMut,
+        RepoSlice,
+        RepoSliceIter,
     },
 };
@@ -32,16 +32,16 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::{
+use alloc::vec::{IntoIter, Vec};
+use core::{
     fmt::{Debug, Formatter},
     iter::{Enumerate, FilterMap},
-    mem::replace,
+    mem::{replace, size_of, take},
     slice::{Iter, IterMut},
-    vec::IntoIter,
 };
 
 use crate::{
-    arena::{Entry, EntryIndex, EntryVersion},
+    arena::{Entry, EntryIndex, EntryVersion, TypedEntry, WeakEntry},
     report::ld_unreachable,
 };
 
@@ -78,13 +78,24 @@ use crate::{
 ///
 /// The third kind of entry is a reserved entry, which is treated as occupied
 /// but does not have a value yet.
+///
+/// The repository version never wraps around. When the version reaches its
+/// maximum value ([EntryVersion::MAX] minus one), the repository stops
+/// increasing the version and retires all vacant entries: the entries removed
+/// from this moment will never be reused, and the new values always occupy
+/// new entries at the end of the inner vector. As such, a versioned index of
+/// a removed entry never becomes spuriously valid, but the memory of
+/// the retired entries is not reclaimed until the repository is dropped.
 pub struct Repo<T> {
     entries: Vec<RepoEntry<T>>,
     next: EntryIndex,
     version: EntryVersion,
     modified: bool,
+    occupied: usize,
 }
 
+const MAX_VERSION: EntryVersion = EntryVersion::MAX - 1;
+
 impl<T> Default for Repo<T> {
     #[inline]
     fn default() -> Self {
@@ -94,7 +105,7 @@ impl<T> Default for Repo<T> {
 
 impl<T> Debug for Repo<T> {
     #[inline]
-    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
         formatter.write_str("Repository")
     }
 }
@@ -150,10 +161,131 @@ pub type RepoEnumIterMut<'a, T> = FilterMap<
 /// A type of the owning iterator over the indexed keys and values of occupied
 /// entries in the [Repo].
 ///
-/// Created by the [Repo::into_enumeration] function.
+/// Created by the [Repo::into_enumeration] and [Repo::drain] functions.
 pub type RepoEnumIntoIter<T> =
     FilterMap<Enumerate<IntoIter<RepoEntry<T>>>, fn((usize, RepoEntry<T>)) -> Option<(Entry, T)>>;
 
+/// A read-only view into a contiguous range of the [Repo] entries.
+///
+/// Created by the [Repo::chunks] function.
+///
+/// The RepoSlice borrows the repository immutably. Therefore, while any
+/// RepoSlice exists, the repository cannot be modified, and the slices of
+/// the same repository are safe to read concurrently. If the values type is
+/// [Sync], the RepoSlice is [Send] and [Sync], and you can move each slice
+/// into a separate worker thread (e.g., within the `std::thread::scope`).
+pub struct RepoSlice<'a, T> {
+    entries: &'a [RepoEntry<T>],
+    offset: EntryIndex,
+}
+
+impl<'a, T> Clone for RepoSlice<'a, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for RepoSlice<'a, T> {}
+
+impl<'a, T> Debug for RepoSlice<'a, T> {
+    #[inline]
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_fmt(format_args!(
+            "RepoSlice({}..{})",
+            self.offset,
+            self.offset + self.entries.len(),
+        ))
+    }
+}
+
+impl<'a, T> IntoIterator for RepoSlice<'a, T> {
+    type Item = (Entry, &'a T);
+    type IntoIter = RepoSliceIter<'a, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.enumerate()
+    }
+}
+
+impl<'a, T> RepoSlice<'a, T> {
+    /// Returns the inner index of the first entry of this slice within
+    /// the repository.
+    #[inline(always)]
+    pub fn offset(&self) -> EntryIndex {
+        self.offset
+    }
+
+    /// Returns the number of entries covered by this slice, including
+    /// the Vacant and Reserved entries.
+    #[inline(always)]
+    pub fn span(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns an iterator over the Occupied entries of this slice, yielding
+    /// pairs of the [versioned index](Entry) and an immutable reference to
+    /// the value of the entry.
+    ///
+    /// The entries are yielded in the order of their inner indices.
+    #[inline(always)]
+    pub fn enumerate(&self) -> RepoSliceIter<'a, T> {
+        RepoSliceIter {
+            inner: self.entries.iter().enumerate(),
+            offset: self.offset,
+        }
+    }
+
+    /// Returns an iterator over immutable references of the values of
+    /// the Occupied entries of this slice.
+    ///
+    /// The values are yielded in the order of the entries' inner indices.
+    #[inline(always)]
+    pub fn iter(&self) -> RepoIter<'a, T> {
+        self.entries.iter().filter_map(|entry| match entry {
+            RepoEntry::Occupied { data, .. } => Some(data),
+            _ => None,
+        })
+    }
+}
+
+/// An iterator over the indexed keys and immutable references to the values
+/// of occupied entries in the [RepoSlice].
+///
+/// Created by the [RepoSlice::enumerate] function.
+pub struct RepoSliceIter<'a, T> {
+    inner: Enumerate<Iter<'a, RepoEntry<T>>>,
+    offset: EntryIndex,
+}
+
+impl<'a, T> Iterator for RepoSliceIter<'a, T> {
+    type Item = (Entry, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (index, entry) = self.inner.next()?;
+
+            let RepoEntry::Occupied { data, version } = entry else {
+                continue;
+            };
+
+            return Some((
+                Entry {
+                    index: self.offset + index,
+                    version: *version,
+                },
+                data,
+            ));
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
 impl<'a, T> IntoIterator for &'a Repo<T> {
     type Item = &'a T;
     type IntoIter = RepoIter<'a, T>;
@@ -202,6 +334,7 @@ impl<T> FromIterator<T> for Repo<T> {
             next,
             version: 1,
             modified: false,
+            occupied: next,
         }
     }
 }
@@ -215,6 +348,7 @@ impl<T> Repo<T> {
             next: 0,
             version: 1,
             modified: false,
+            occupied: 0,
         }
     }
 
@@ -227,7 +361,63 @@ impl<T> Repo<T> {
             next: 0,
             version: 1,
             modified: false,
+            occupied: 0,
+        }
+    }
+
+    /// Returns the number of entries the repository can hold without
+    /// reallocation.
+    ///
+    /// The number includes the Occupied, Reserved, and Vacant entries.
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.entries.capacity()
+    }
+
+    /// Reserves capacity for at least `additional` more entries to be
+    /// inserted into the repository without reallocation.
+    #[inline(always)]
+    pub fn reserve(&mut self, additional: usize) {
+        self.entries.reserve(additional);
+    }
+
+    /// Shrinks the allocation capacity of the repository as much as possible.
+    ///
+    /// This function releases the trailing Vacant entries of the inner vector
+    /// and the unused capacity. The Occupied and Reserved entries retain their
+    /// indices, such that all existing [versioned indices](Entry) remain valid.
+    /// The Vacant entries that precede the last Occupied or Reserved entry
+    /// cannot be released, but they will be reused by the subsequent
+    /// insertions in the order of their indices.
+    ///
+    /// If the repository is [saturated](Self::is_saturated), the trailing
+    /// Vacant entries are retired and cannot be released.
+    ///
+    /// Returns an estimated number of bytes released by this function.
+    pub fn shrink_to_fit(&mut self) -> usize {
+        let capacity = self.entries.capacity();
+
+        if !self.is_saturated() {
+            while let Some(RepoEntry::Vacant(..)) = self.entries.last() {
+                let _ = self.entries.pop();
+            }
+        }
+
+        self.next = self.entries.len();
+
+        for index in (0..self.entries.len()).rev() {
+            // Safety: `index` is within the inner vector bounds.
+            let entry = unsafe { self.entries.get_unchecked_mut(index) };
+
+            if let RepoEntry::Vacant(next) = entry {
+                *next = self.next;
+                self.next = index;
+            }
         }
+
+        self.entries.shrink_to_fit();
+
+        (capacity - self.entries.capacity()) * size_of::<RepoEntry<T>>()
     }
 
     /// Inserts a new value into the repository.
@@ -240,6 +430,71 @@ impl<T> Repo<T> {
         unsafe { self.entry_of_unchecked(index) }
     }
 
+    /// Inserts all values of the `iter` iterator into the repository.
+    ///
+    /// Returns the [versioned indices](Entry) of the Occupied entries in
+    /// the order of the values yielded by the iterator.
+    ///
+    /// This function is more efficient than the sequential calls of
+    /// the [insert](Self::insert) function, because it reserves the memory
+    /// for the new entries ahead of time, and if the repository does not have
+    /// vacant entries, it writes the values contiguously at the end of
+    /// the inner vector without per-value bookkeeping.
+    pub fn extend(&mut self, iter: impl IntoIterator<Item = T>) -> Vec<Entry> {
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+
+        self.commit(false);
+        self.entries.reserve(lower);
+
+        let mut result = Vec::with_capacity(lower);
+
+        if self.next < self.entries.len() && !self.is_saturated() {
+            for data in iter {
+                let index = self.insert_raw(data);
+
+                result.push(Entry {
+                    index,
+                    version: self.version,
+                });
+            }
+
+            return result;
+        }
+
+        let version = self.version;
+        let start = self.entries.len();
+
+        self.entries.extend(iter.map(|data| {
+            result.push(Entry {
+                index: start + result.len(),
+                version,
+            });
+
+            RepoEntry::Occupied { data, version }
+        }));
+
+        self.occupied += result.len();
+
+        if !self.is_saturated() {
+            self.next = self.entries.len();
+        }
+
+        result
+    }
+
+    /// Inserts a new value into the repository.
+    ///
+    /// Returns a [typed versioned index](TypedEntry) of the Occupied entry.
+    ///
+    /// Unlike the [insert](Self::insert) function, the returning index
+    /// cannot be accidentally used with a repository of a different value
+    /// type.
+    #[inline(always)]
+    pub fn insert_typed(&mut self, data: T) -> TypedEntry<T> {
+        TypedEntry::new(self.insert(data))
+    }
+
     /// Inserts a new value into the repository.
     ///
     /// Returns an index of the Occupied entry.
@@ -251,6 +506,17 @@ impl<T> Repo<T> {
 
         self.commit(false);
 
+        self.occupied += 1;
+
+        if self.is_saturated() {
+            self.entries.push(RepoEntry::Occupied {
+                data,
+                version: self.version,
+            });
+
+            return self.entries.len() - 1;
+        }
+
         let Some(vacant) = self.entries.get_mut(self.next) else {
             self.entries.push(RepoEntry::Occupied {
                 data,
@@ -262,6 +528,11 @@ impl<T> Repo<T> {
             return index;
         };
 
+        debug_assert!(
+            !self.modified,
+            "An attempt to reuse vacant entry under the version of its removal.",
+        );
+
         self.next = match replace(
             vacant,
             RepoEntry::Occupied {
@@ -292,6 +563,14 @@ impl<T> Repo<T> {
 
         self.commit(false);
 
+        if self.is_saturated() {
+            self.entries.push(RepoEntry::Reserved {
+                version: self.version,
+            });
+
+            return self.entries.len() - 1;
+        }
+
         let Some(vacant) = self.entries.get_mut(self.next) else {
             self.entries.push(RepoEntry::Reserved {
                 version: self.version,
@@ -302,6 +581,11 @@ impl<T> Repo<T> {
             return index;
         };
 
+        debug_assert!(
+            !self.modified,
+            "An attempt to reuse vacant entry under the version of its removal.",
+        );
+
         self.next = match replace(
             vacant,
             RepoEntry::Reserved {
@@ -341,6 +625,7 @@ impl<T> Repo<T> {
 
         self.modified = true;
         self.next = entry.index;
+        self.occupied -= 1;
 
         Some(data)
     }
@@ -358,23 +643,142 @@ impl<T> Repo<T> {
     ///
     /// If the `force` flag is false, the version increase is up to
     /// the repository's decision.
+    ///
+    /// If the version has already reached its maximum value, this function
+    /// does nothing. See the [Repo] specification for details.
     #[inline(always)]
     pub fn commit(&mut self, force: bool) {
-        if force || self.modified {
+        if (force || self.modified) && !self.is_saturated() {
             self.version += 1;
             self.modified = false;
         }
     }
 
+    /// Returns true if the repository version has reached its maximum value.
+    ///
+    /// In this state, the repository does not reuse vacant entries.
+    /// See the [Repo] specification for details.
+    #[inline(always)]
+    pub fn is_saturated(&self) -> bool {
+        self.version >= MAX_VERSION
+    }
+
     /// Removes all entries from the repository while preserving allocated
     /// memory and the current version of the repository.
+    ///
+    /// If the repository is [saturated](Self::is_saturated), the entries are
+    /// retired instead.
     #[inline(always)]
     pub fn clear(&mut self) {
         self.modified = true;
+        self.occupied = 0;
+
+        if self.is_saturated() {
+            self.retire_all();
+            return;
+        }
+
         self.next = 0;
         self.entries.clear();
     }
 
+    /// Removes all entries from the repository, returning an iterator over
+    /// the removed Occupied entries in the order of their inner indices.
+    ///
+    /// The iterator yields pairs of the [versioned index](Entry) of the entry
+    /// and the owned value of the entry.
+    ///
+    /// Unlike the [clear](Self::clear) function, this function releases
+    /// the allocated memory of the repository. The current version of
+    /// the repository is preserved, and the next insertion receives a new
+    /// version number, such that the yielded versioned indices will never
+    /// address the new values of this repository.
+    ///
+    /// If the repository is [saturated](Self::is_saturated), the entries are
+    /// retired, and the memory of the retired entries is not released.
+    #[inline]
+    pub fn drain(&mut self) -> RepoEnumIntoIter<T> {
+        self.modified = true;
+        self.occupied = 0;
+
+        let entries = match self.is_saturated() {
+            true => self.retire_all(),
+            false => {
+                self.next = 0;
+                take(&mut self.entries)
+            }
+        };
+
+        entries
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, entry)| match entry {
+                RepoEntry::Occupied { data, version } => Some((Entry { index, version }, data)),
+                _ => None,
+            })
+    }
+
+    /// Retains only the Occupied entries for which the `keep` predicate
+    /// returns true, removing all other entries from the repository.
+    ///
+    /// The predicate receives the [versioned index](Entry) of the entry and
+    /// a mutable reference to the entry's value. The entries are visited in
+    /// the order of their inner indices. Reserved entries are not visited and
+    /// are kept as is.
+    ///
+    /// The versioned indices of the removed entries become invalid
+    /// immediately, and the versioned indices of the retained entries remain
+    /// valid. Similarly to the [remove](Self::remove) function, the vacant
+    /// entries will be reused by the subsequent insertions, but under a new
+    /// repository version, such that the versioned indices of the removed
+    /// entries will not address the new values.
+    pub fn retain(&mut self, mut keep: impl FnMut(&Entry, &mut T) -> bool) {
+        for index in 0..self.entries.len() {
+            // Safety: `index` is within the inner vector bounds.
+            let repo_entry = unsafe { self.entries.get_unchecked_mut(index) };
+
+            let RepoEntry::Occupied { data, version } = repo_entry else {
+                continue;
+            };
+
+            let entry = Entry {
+                index,
+                version: *version,
+            };
+
+            if keep(&entry, data) {
+                continue;
+            }
+
+            *repo_entry = RepoEntry::Vacant(self.next);
+
+            self.modified = true;
+            self.next = index;
+            self.occupied -= 1;
+        }
+    }
+
+    /// Returns the number of Occupied entries within the repository.
+    ///
+    /// Reserved entries are not counted until they become initialized.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.occupied
+    }
+
+    /// Returns true if the repository does not have Occupied entries.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.occupied == 0
+    }
+
+    // Returns the number of bytes allocated by the repository's entries
+    // (excluding the heap data owned by the entries' values).
+    #[inline(always)]
+    pub(crate) fn heap_size(&self) -> usize {
+        self.entries.capacity() * size_of::<RepoEntry<T>>()
+    }
+
     /// Returns true if the repository contains an Occupied entry addressed
     /// by the specified [versioned index](Entry).
     #[inline]
@@ -386,6 +790,21 @@ impl<T> Repo<T> {
         *version == entry.version
     }
 
+    /// Creates a [weak handle](WeakEntry) of the Occupied entry addressed
+    /// by the specified [versioned index](Entry).
+    ///
+    /// Use the [WeakEntry::upgrade] function to check if the repository
+    /// still contains this entry, and to restore the versioned index.
+    ///
+    /// Returns [nil](WeakEntry::nil) handle, if there is no such entry.
+    #[inline]
+    pub fn downgrade(&self, entry: &Entry) -> WeakEntry {
+        match self.contains(entry) {
+            true => WeakEntry::from_entry(*entry),
+            false => WeakEntry::nil(),
+        }
+    }
+
     /// Creates a [versioned index](Entry) of the Occupied or Reserved entry
     /// within this repository by the (non-versioned) `index` of the entry.
     ///
@@ -444,6 +863,12 @@ impl<T> Repo<T> {
     /// Returns an iterator over immutable references of the values of
     /// all Occupied entries within the repository.
     ///
+    /// The values are yielded in the order of the entries' inner indices,
+    /// which is not necessarily the insertion order, because the repository
+    /// reuses vacant entries. To iterate the values together with their
+    /// [versioned indices](Entry), use the [enumerate](Self::enumerate)
+    /// function.
+    ///
     /// This function does not consume the repository instance.
     #[inline(always)]
     pub fn iter(&self) -> RepoIter<T> {
@@ -456,6 +881,11 @@ impl<T> Repo<T> {
     /// Returns an iterator over mutable references of the values of
     /// all Occupied entries within the repository.
     ///
+    /// The values are yielded in the order of the entries' inner indices.
+    /// To iterate the values together with their
+    /// [versioned indices](Entry), use the
+    /// [enumerate_mut](Self::enumerate_mut) function.
+    ///
     /// This function does not consume the repository instance.
     #[inline(always)]
     pub fn iter_mut(&mut self) -> RepoIterMut<T> {
@@ -465,6 +895,38 @@ impl<T> Repo<T> {
         })
     }
 
+    /// Splits the repository storage into at most `count` disjoint read-only
+    /// [views](RepoSlice) of approximately equal size.
+    ///
+    /// The returning slices cover all entries of the repository in the order
+    /// of their inner indices. The function returns an empty vector if
+    /// the repository does not have entries.
+    ///
+    /// The function is useful for read-only processing of the repository
+    /// values in parallel: each slice could be sent to a distinct worker
+    /// thread. Since the slices borrow the repository immutably, no writer
+    /// can modify the repository while the slices exist.
+    ///
+    /// **Panic**
+    ///
+    /// Panics if `count` is zero.
+    pub fn chunks(&self, count: usize) -> Vec<RepoSlice<T>> {
+        if count == 0 {
+            panic!("Zero chunks count.");
+        }
+
+        let size = self.entries.len().div_ceil(count).max(1);
+
+        self.entries
+            .chunks(size)
+            .enumerate()
+            .map(|(chunk, entries)| RepoSlice {
+                entries,
+                offset: chunk * size,
+            })
+            .collect()
+    }
+
     /// Returns an iterator that yields the [versioned indices](Entry) of all
     /// Occupied entries within the repository.
     ///
@@ -504,6 +966,10 @@ impl<T> Repo<T> {
     /// 1. The [versioned index](Entry) of the entry.
     /// 2. An immutable reference to the value of the entry.
     ///
+    /// The entries are yielded in the order of their inner indices, which is
+    /// not necessarily the insertion order, because the repository reuses
+    /// vacant entries.
+    ///
     /// This function does not consume the repository instance.
     #[inline(always)]
     pub fn enumerate(&self) -> RepoEnumIter<T> {
@@ -528,6 +994,8 @@ impl<T> Repo<T> {
     /// 1. The [versioned index](Entry) of the entry.
     /// 2. A mutable reference to the value of the entry.
     ///
+    /// The entries are yielded in the order of their inner indices.
+    ///
     /// This function does not consume the repository instance.
     #[inline(always)]
     pub fn enumerate_mut(&mut self) -> RepoEnumIterMut<T> {
@@ -653,15 +1121,21 @@ impl<T> Repo<T> {
             unsafe { ld_unreachable!("Index out of bounds.") }
         };
 
-        let (RepoEntry::Occupied { version, .. } | RepoEntry::Reserved { version, .. }) = entry
-        else {
-            unsafe { ld_unreachable!("An attempt to write into vacant entry.") }
-        };
+        let version = match entry {
+            RepoEntry::Occupied { version, .. } => *version,
 
-        *entry = RepoEntry::Occupied {
-            data,
-            version: *version,
+            RepoEntry::Reserved { version } => {
+                self.occupied += 1;
+                *version
+            }
+
+            // Safety: Upheld by the caller.
+            RepoEntry::Vacant(..) => unsafe {
+                ld_unreachable!("An attempt to write into vacant entry.")
+            },
         };
+
+        *entry = RepoEntry::Occupied { data, version };
     }
 
     /// Removes Occupied or Reserved entry by the non-versioned index
@@ -685,9 +1159,18 @@ impl<T> Repo<T> {
 
         let occupied = replace(entry, RepoEntry::Vacant(self.next));
 
-        let (RepoEntry::Occupied { version, .. } | RepoEntry::Reserved { version, .. }) = occupied
-        else {
-            unsafe { ld_unreachable!("An attempt to remove vacant entry.") }
+        let version = match occupied {
+            RepoEntry::Occupied { version, .. } => {
+                self.occupied -= 1;
+                version
+            }
+
+            RepoEntry::Reserved { version } => version,
+
+            // Safety: Upheld by the caller.
+            RepoEntry::Vacant(..) => unsafe {
+                ld_unreachable!("An attempt to remove vacant entry.")
+            },
         };
 
         self.modified = true;
@@ -731,9 +1214,334 @@ impl<T> Repo<T> {
     }
 }
 
+impl<T> Repo<T> {
+    // Turns the Vacant entries addressed by the `entries` indices into
+    // Reserved entries under the versions of these indices, such that
+    // the subsequent insertions would not occupy them.
+    //
+    // Returns the indices of the reserved entries. The entries that are not
+    // Vacant are skipped.
+    //
+    // Reserving under the previous version makes the versioned index of
+    // the removed entry valid again once the entry is initialized. The caller
+    // is responsible for initializing it with an equivalent value, or for
+    // removing the reserved entry otherwise.
+    pub(crate) fn reserve_vacant(
+        &mut self,
+        entries: impl IntoIterator<Item = Entry>,
+    ) -> Vec<Entry> {
+        if self.is_saturated() {
+            return Vec::new();
+        }
+
+        let mut candidates = entries
+            .into_iter()
+            .filter(|entry| {
+                entry.version > 0
+                    && matches!(self.entries.get(entry.index), Some(RepoEntry::Vacant(..)))
+            })
+            .collect::<Vec<_>>();
+
+        if candidates.is_empty() {
+            return candidates;
+        }
+
+        candidates.sort();
+        candidates.dedup_by_key(|entry| entry.index);
+
+        let mut previous = None;
+        let mut next = self.next;
+
+        while let Some(RepoEntry::Vacant(following)) = self.entries.get(next) {
+            let following = *following;
+
+            match candidates.binary_search_by_key(&next, |entry| entry.index) {
+                Ok(_) => match previous {
+                    None => self.next = following,
+
+                    Some(previous) => {
+                        // Safety: `previous` refers to a Vacant entry.
+                        *unsafe { self.entries.get_unchecked_mut(previous) } =
+                            RepoEntry::Vacant(following)
+                    }
+                },
+
+                Err(_) => previous = Some(next),
+            }
+
+            next = following;
+        }
+
+        for entry in &candidates {
+            // Safety: Candidates refer to the existing entries.
+            *unsafe { self.entries.get_unchecked_mut(entry.index) } = RepoEntry::Reserved {
+                version: entry.version,
+            };
+        }
+
+        candidates
+    }
+
+    // Creates a repository that stores the values under the specified
+    // versioned indices.
+    //
+    // Returns None if the indices are not unique, or if some version is out of
+    // the range of the repository versions.
+    #[cfg(feature = "serde")]
+    pub(crate) fn restore(values: impl IntoIterator<Item = (Entry, T)>) -> Option<Self> {
+        let mut entries = Vec::new();
+        let mut version = 1;
+        let mut occupied = 0;
+
+        for (entry, data) in values {
+            if entry.version == 0 || entry.version > MAX_VERSION {
+                return None;
+            }
+
+            if entry.index >= entries.len() {
+                entries.resize_with(entry.index + 1, || RepoEntry::Vacant(EntryIndex::MAX));
+            }
+
+            // Safety: The vector has been extended to the index.
+            let slot = unsafe { entries.get_unchecked_mut(entry.index) };
+
+            if !matches!(slot, RepoEntry::Vacant(..)) {
+                return None;
+            }
+
+            *slot = RepoEntry::Occupied {
+                data,
+                version: entry.version,
+            };
+
+            version = version.max(entry.version);
+            occupied += 1;
+        }
+
+        let mut next = entries.len();
+
+        for index in (0..entries.len()).rev() {
+            // Safety: The index is within the vector bounds.
+            if let RepoEntry::Vacant(following) = unsafe { entries.get_unchecked_mut(index) } {
+                *following = next;
+                next = index;
+            }
+        }
+
+        let mut repo = Self {
+            entries,
+            next,
+            version,
+            modified: false,
+            occupied,
+        };
+
+        // The new entries must not reuse the versions of the removed entries
+        // of the original repository.
+        repo.commit(true);
+
+        Some(repo)
+    }
+
+    // Turns the Vacant entry addressed by the `entry` index into a Reserved
+    // entry under the version of this index.
+    //
+    // Returns false if the entry is neither Vacant nor Reserved under
+    // the same version.
+    //
+    // Unlike the `reserve_vacant` function, this function stops walking
+    // the list of the Vacant entries once it finds the entry. The recently
+    // removed entries are at the beginning of this list.
+    pub(crate) fn claim(&mut self, entry: &Entry) -> bool {
+        match self.entries.get(entry.index) {
+            Some(RepoEntry::Reserved { version }) => return *version == entry.version,
+            Some(RepoEntry::Vacant(..)) if entry.version > 0 && !self.is_saturated() => (),
+            _ => return false,
+        }
+
+        let mut previous = None;
+        let mut next = self.next;
+
+        while let Some(RepoEntry::Vacant(following)) = self.entries.get(next) {
+            let following = *following;
+
+            if next != entry.index {
+                previous = Some(next);
+                next = following;
+                continue;
+            }
+
+            match previous {
+                None => self.next = following,
+
+                Some(previous) => {
+                    // Safety: `previous` refers to a Vacant entry.
+                    *unsafe { self.entries.get_unchecked_mut(previous) } =
+                        RepoEntry::Vacant(following)
+                }
+            }
+
+            // Safety: `next` refers to a Vacant entry.
+            *unsafe { self.entries.get_unchecked_mut(next) } = RepoEntry::Reserved {
+                version: entry.version,
+            };
+
+            return true;
+        }
+
+        false
+    }
+
+    // Returns true if the entry addressed by the versioned index is
+    // a Reserved entry.
+    #[inline(always)]
+    pub(crate) fn is_reserved(&self, entry: &Entry) -> bool {
+        let Some(RepoEntry::Reserved { version }) = self.entries.get(entry.index) else {
+            return false;
+        };
+
+        *version == entry.version
+    }
+
+    // Assigns the specified `version` to the Occupied or Reserved entry by
+    // the non-versioned index.
+    //
+    // Safety: An Occupied or Reserved entry addressed by the `index` parameter
+    //         exists in the repository.
+    #[inline(always)]
+    pub(crate) unsafe fn set_version(&mut self, index: EntryIndex, version: EntryVersion) {
+        let Some(entry) = self.entries.get_mut(index) else {
+            unsafe { ld_unreachable!("Index out of bounds.") }
+        };
+
+        let (RepoEntry::Occupied {
+            version: current, ..
+        }
+        | RepoEntry::Reserved { version: current }) = entry
+        else {
+            unsafe { ld_unreachable!("An attempt to set version of vacant entry.") }
+        };
+
+        *current = version;
+    }
+
+    // Turns all entries into Vacant entries that will never be reused,
+    // and returns the previous entries.
+    fn retire_all(&mut self) -> Vec<RepoEntry<T>> {
+        let retired = (0..self.entries.len())
+            .map(|_| RepoEntry::Vacant(EntryIndex::MAX))
+            .collect();
+
+        replace(&mut self.entries, retired)
+    }
+}
+
 #[doc(hidden)]
 pub enum RepoEntry<T> {
     Vacant(EntryIndex),
     Occupied { data: T, version: EntryVersion },
     Reserved { version: EntryVersion },
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::arena::{repo::MAX_VERSION, Entry, Repo};
+
+    #[test]
+    fn test_repo_saturation() {
+        let mut repo = Repo::new();
+
+        // Fast-forwards the repository close to the saturation point.
+        repo.version = MAX_VERSION - 3;
+
+        let mut stale = Vec::new();
+
+        for value in 0..10 {
+            let entry = repo.insert(value);
+
+            assert_eq!(repo.remove(&entry), Some(value));
+
+            stale.push(entry);
+        }
+
+        assert!(repo.is_saturated());
+        assert_eq!(repo.version(), MAX_VERSION);
+
+        let fresh = (0..3).map(|value| repo.insert(value)).collect::<Vec<_>>();
+
+        for entry in &stale {
+            assert!(!repo.contains(entry));
+            assert!(fresh.iter().all(|fresh| fresh.index != entry.index));
+        }
+
+        repo.clear();
+        repo.commit(true);
+
+        assert_eq!(repo.version(), MAX_VERSION);
+
+        let entry = repo.insert(100);
+
+        assert!(fresh.iter().all(|fresh| !repo.contains(fresh)));
+        assert!(stale.iter().all(|stale| stale.index != entry.index));
+        assert_eq!(repo.drain().count(), 1);
+        assert_eq!(repo.shrink_to_fit(), 0);
+        assert!(!repo.contains(&entry));
+        assert!(repo.insert(200).index > entry.index);
+    }
+
+    #[test]
+    fn test_repo_reserve_vacant() {
+        let mut repo = Repo::new();
+
+        let entries = (0..5).map(|value| repo.insert(value)).collect::<Vec<_>>();
+
+        for entry in &entries {
+            assert!(repo.remove(entry).is_some());
+        }
+
+        let reserved = repo.reserve_vacant([entries[1], entries[3], entries[3]]);
+
+        assert_eq!(reserved, [entries[1], entries[3]]);
+        assert!(repo.is_reserved(&entries[1]));
+        assert!(!repo.is_reserved(&entries[2]));
+
+        let inserted = (0..4).map(|value| repo.insert(value)).collect::<Vec<_>>();
+
+        assert!(inserted
+            .iter()
+            .all(|entry| entry.index != 1 && entry.index != 3));
+
+        unsafe { repo.set_unchecked(1, 10) };
+        let _ = unsafe { repo.remove_unchecked(3) };
+
+        assert_eq!(repo.get(&entries[1]), Some(&10));
+        assert!(!repo.contains(&entries[3]));
+        assert_eq!(repo.insert(20).index, 3);
+    }
+
+    #[test]
+    fn test_repo_claim() {
+        let mut repo = Repo::new();
+
+        let entries = (0..3).map(|value| repo.insert(value)).collect::<Vec<_>>();
+
+        for entry in &entries {
+            assert!(repo.remove(entry).is_some());
+        }
+
+        assert!(repo.claim(&entries[1]));
+        assert!(repo.claim(&entries[1]));
+        assert!(!repo.claim(&Entry {
+            index: 1,
+            version: entries[1].version + 1,
+        }));
+
+        unsafe { repo.set_unchecked(1, 10) };
+
+        assert!(!repo.claim(&entries[1]));
+        assert_eq!(repo.get(&entries[1]), Some(&10));
+        assert_ne!(repo.insert(20).index, 1);
+        assert_ne!(repo.insert(30).index, 1);
+        assert_eq!(repo.insert(40).index, 3);
+    }
+}
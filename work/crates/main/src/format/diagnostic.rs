@@ -0,0 +1,651 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::fmt::Write;
+
+#[cfg(feature = "lsp")]
+use crate::lsp::{LspDiagnostic, PositionEncoding};
+use crate::{
+    arena::Identifiable,
+    format::AnnotationPriority,
+    lexis::{Position, PositionSpan, SourceCode, ToSpan},
+};
+
+/// A machine-readable representation of the source code annotation.
+///
+/// The Diagnostic object is built from the same inputs as
+/// the [Snippet::annotate](crate::format::Snippet::annotate) function:
+/// the annotation span, the [AnnotationPriority], and the message.
+/// Unlike the [Snippet](crate::format::Snippet), which renders annotations for
+/// the end user, the Diagnostic is intended for the tools such as
+/// the language servers and the CI pipelines.
+///
+/// When the `lsp` feature of this crate is enabled, you can convert
+/// the Diagnostic into the LSP
+/// [Diagnostic](https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#diagnostic)
+/// object using the [Diagnostic::to_lsp] function. When the `serde` feature
+/// is enabled, you can serialize the Diagnostic into
+/// the [SARIF](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html)
+/// result object shape using the [Diagnostic::to_sarif] function.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Diagnostic {
+    /// A line-column span of the annotated source code fragment.
+    pub span: PositionSpan,
+
+    /// A degree of the diagnostic importance.
+    pub severity: DiagnosticSeverity,
+
+    /// A message of the diagnostic.
+    pub message: String,
+
+    /// A set of additional source code fragments related to this diagnostic.
+    pub related: Vec<DiagnosticRelated>,
+}
+
+impl Diagnostic {
+    /// Creates a diagnostic without related fragments.
+    ///
+    /// The `code` parameter specifies a [SourceCode] of the annotated text.
+    ///
+    /// The `span` parameter specifies the annotated fragment.
+    ///
+    /// The `priority` parameter specifies the importance of the annotation,
+    /// which turns into the diagnostic [severity](DiagnosticSeverity).
+    ///
+    /// The `message` parameter specifies the diagnostic message.
+    ///
+    /// **Panic**
+    ///
+    /// Panics if the specified span is not valid for the `code`.
+    pub fn new(
+        code: &impl SourceCode,
+        span: impl ToSpan,
+        priority: AnnotationPriority,
+        message: impl Into<String>,
+    ) -> Self {
+        let span = match span.to_position_span(code) {
+            Some(span) => span,
+
+            None => panic!("Invalid diagnostic span."),
+        };
+
+        Self {
+            span,
+            severity: DiagnosticSeverity::from(priority),
+            message: message.into(),
+            related: Vec::new(),
+        }
+    }
+
+    /// Adds a related source code fragment to this diagnostic.
+    ///
+    /// The `code` parameter specifies a [SourceCode] of the related text.
+    ///
+    /// The `span` parameter specifies the related fragment.
+    ///
+    /// The `message` parameter specifies a message of the related fragment.
+    ///
+    /// **Panic**
+    ///
+    /// Panics if the specified span is not valid for the `code`.
+    pub fn relate(
+        &mut self,
+        code: &impl SourceCode,
+        span: impl ToSpan,
+        message: impl Into<String>,
+    ) -> &mut Self {
+        let span = match span.to_position_span(code) {
+            Some(span) => span,
+
+            None => panic!("Invalid diagnostic span."),
+        };
+
+        self.related.push(DiagnosticRelated {
+            span,
+            message: message.into(),
+        });
+
+        self
+    }
+
+    /// Converts this diagnostic into the LSP Diagnostic object.
+    ///
+    /// The `code` parameter specifies a [SourceCode] of the annotated text.
+    ///
+    /// The `uri` parameter specifies the document URI of the related
+    /// information locations.
+    ///
+    /// The `encoding` parameter specifies the measurement unit of
+    /// the character offsets negotiated with the LSP client. Note that
+    /// the default encoding of the LSP specification is
+    /// [UTF-16](PositionEncoding::Utf16).
+    ///
+    /// This function is a shortcut for
+    /// the [LspDiagnostic::from_diagnostic] function.
+    ///
+    /// **Panic**
+    ///
+    /// Panics if the diagnostic spans are not valid for the `code`.
+    #[cfg(feature = "lsp")]
+    #[inline(always)]
+    pub fn to_lsp(
+        &self,
+        code: &impl SourceCode,
+        uri: &str,
+        encoding: PositionEncoding,
+    ) -> LspDiagnostic {
+        LspDiagnostic::from_diagnostic(code, self, uri, encoding)
+    }
+
+    /// Returns a serializable object in the shape of the SARIF result.
+    ///
+    /// The `uri` parameter specifies the artifact URI of the diagnostic
+    /// locations.
+    ///
+    /// The SARIF regions are one-based, and the end column is exclusive.
+    #[cfg(feature = "serde")]
+    #[inline(always)]
+    pub fn to_sarif<'a>(&'a self, uri: &'a str) -> impl serde::Serialize + 'a {
+        SarifResult::new(self, uri)
+    }
+}
+
+/// An additional source code fragment related to the [Diagnostic].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DiagnosticRelated {
+    /// A line-column span of the related source code fragment.
+    pub span: PositionSpan,
+
+    /// A message of the related fragment.
+    pub message: String,
+}
+
+/// A degree of importance of the [Diagnostic].
+///
+/// The severity is derived from the [AnnotationPriority]:
+///
+/// | Annotation Priority               | Diagnostic Severity                     |
+/// |-----------------------------------|-----------------------------------------|
+/// | [AnnotationPriority::Primary]     | [DiagnosticSeverity::Error]             |
+/// | [AnnotationPriority::Secondary]   | [DiagnosticSeverity::Warning]           |
+/// | [AnnotationPriority::Default]     | [DiagnosticSeverity::Information]       |
+/// | [AnnotationPriority::Note]        | [DiagnosticSeverity::Hint]              |
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum DiagnosticSeverity {
+    /// A diagnostic that reports an error.
+    Error,
+
+    /// A diagnostic that reports a warning.
+    Warning,
+
+    /// An informational diagnostic.
+    Information,
+
+    /// A diagnostic that hints the user.
+    Hint,
+}
+
+impl From<AnnotationPriority> for DiagnosticSeverity {
+    #[inline(always)]
+    fn from(value: AnnotationPriority) -> Self {
+        match value {
+            AnnotationPriority::Primary => Self::Error,
+            AnnotationPriority::Secondary => Self::Warning,
+            AnnotationPriority::Default => Self::Information,
+            AnnotationPriority::Note => Self::Hint,
+        }
+    }
+}
+
+impl DiagnosticSeverity {
+    /// Returns a numeric code of the severity as defined by the LSP
+    /// specification.
+    #[inline(always)]
+    pub fn lsp_code(&self) -> u8 {
+        match self {
+            Self::Error => 1,
+            Self::Warning => 2,
+            Self::Information => 3,
+            Self::Hint => 4,
+        }
+    }
+
+    /// Returns a lowercase name of the severity as defined by the LSP
+    /// specification: "error", "warning", "information", or "hint".
+    #[inline(always)]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+            Self::Information => "information",
+            Self::Hint => "hint",
+        }
+    }
+
+    /// Returns a result level string of the severity as defined by the SARIF
+    /// specification.
+    #[inline(always)]
+    pub fn sarif_level(&self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+            Self::Information => "note",
+            Self::Hint => "none",
+        }
+    }
+}
+
+/// A machine-readable report of the source code annotations.
+///
+/// The DiagnosticReport is a counterpart of the [Snippet](crate::format::Snippet)
+/// builder: it accepts the same caption, summary, and annotations, but instead
+/// of rendering them for the end user, it turns each annotation into
+/// a [Diagnostic] and serializes the report into a JSON string using
+/// the [to_json_string](Self::to_json_string) function, or into a
+/// [SARIF](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html)
+/// log using the [to_sarif_string](Self::to_sarif_string) function.
+///
+/// Unlike the [Diagnostic::to_sarif] function, these functions do not
+/// require the `serde` feature of this crate.
+///
+/// ```ignore
+/// let buffer = TokenBuffer::<MyToken>::from("foo\nbar");
+///
+/// let mut report = DiagnosticReport::new(&buffer);
+///
+/// report
+///     .set_caption("Example")
+///     .annotate(4..7, AnnotationPriority::Primary, "Unexpected \"bar\".");
+///
+/// assert!(report.to_json_string().contains(concat!(
+///     r#""severity":"error","message":"Unexpected \"bar\".","#,
+///     r#""range":{"start":{"line":2,"column":1},"end":{"line":2,"column":4}}"#,
+/// )));
+/// ```
+pub struct DiagnosticReport<'a, C: SourceCode> {
+    code: &'a C,
+    caption: String,
+    summary: String,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a, C: SourceCode> DiagnosticReport<'a, C> {
+    /// Creates a report builder without annotations.
+    ///
+    /// The `code` parameter specifies a [SourceCode] of the annotated text.
+    /// The [Id](crate::arena::Id) of the `code` and its
+    /// [name](crate::arena::Id::name) identify the annotated file in
+    /// the serialized report.
+    #[inline(always)]
+    pub fn new(code: &'a C) -> Self {
+        Self {
+            code,
+            caption: String::new(),
+            summary: String::new(),
+            diagnostics: Vec::new(),
+        }
+    }
+
+    /// Sets report's caption.
+    ///
+    /// This text has the same meaning as
+    /// the [Snippet::set_caption](crate::format::Snippet::set_caption) caption.
+    #[inline(always)]
+    pub fn set_caption(&mut self, caption: impl Into<String>) -> &mut Self {
+        self.caption = caption.into();
+
+        self
+    }
+
+    /// Sets report's summary text.
+    ///
+    /// This text has the same meaning as
+    /// the [Snippet::set_summary](crate::format::Snippet::set_summary) summary.
+    #[inline(always)]
+    pub fn set_summary(&mut self, summary: impl Into<String>) -> &mut Self {
+        self.summary = summary.into();
+
+        self
+    }
+
+    /// Adds an annotation to the report.
+    ///
+    /// The parameters have the same meaning as in
+    /// the [Snippet::annotate](crate::format::Snippet::annotate) function.
+    /// The annotation turns into a [Diagnostic] with the severity derived
+    /// from the `priority`.
+    ///
+    /// **Panic**
+    ///
+    /// Panics if the span is not valid for the report's source code.
+    #[inline(always)]
+    pub fn annotate(
+        &mut self,
+        span: impl ToSpan,
+        priority: AnnotationPriority,
+        message: impl Into<String>,
+    ) -> &mut Self {
+        self.diagnostics
+            .push(Diagnostic::new(self.code, span, priority, message));
+
+        self
+    }
+
+    /// Adds a prebuilt diagnostic to the report.
+    ///
+    /// Unlike the [annotate](Self::annotate) function, this function preserves
+    /// the [related](Diagnostic::related) fragments of the diagnostic.
+    ///
+    /// The diagnostic spans are expected to be valid for the report's source
+    /// code.
+    #[inline(always)]
+    pub fn add(&mut self, diagnostic: Diagnostic) -> &mut Self {
+        self.diagnostics.push(diagnostic);
+
+        self
+    }
+
+    /// Returns the diagnostics of the report in the order of
+    /// the [annotate](Self::annotate) and the [add](Self::add) function
+    /// calls.
+    #[inline(always)]
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
+    /// Serializes the report into a JSON string.
+    ///
+    /// The output is a compact JSON object with the following fields:
+    ///
+    ///  - `file`: an object with the numeric `id` and the `name` of
+    ///    the annotated file.
+    ///  - `caption` and `summary`: the report's caption and summary strings.
+    ///  - `diagnostics`: an array of the annotations. Each annotation is
+    ///    an object with the `severity` string ("error", "warning",
+    ///    "information", or "hint"), the `message` string, and the `range`
+    ///    object. The `start` and `end` positions of the range are one-based
+    ///    `line` and `column` numbers, and the end position is exclusive.
+    pub fn to_json_string(&self) -> String {
+        let id = self.code.id();
+
+        let mut output = String::new();
+
+        output.push_str("{\"file\":{\"id\":");
+        let _ = write!(output, "{}", id.into_inner());
+        output.push_str(",\"name\":");
+        write_json_string(&mut output, &id.name());
+        output.push_str("},\"caption\":");
+        write_json_string(&mut output, &self.caption);
+        output.push_str(",\"summary\":");
+        write_json_string(&mut output, &self.summary);
+        output.push_str(",\"diagnostics\":[");
+
+        for (index, diagnostic) in self.diagnostics.iter().enumerate() {
+            if index > 0 {
+                output.push(',');
+            }
+
+            output.push_str("{\"severity\":");
+            write_json_string(&mut output, diagnostic.severity.name());
+            output.push_str(",\"message\":");
+            write_json_string(&mut output, &diagnostic.message);
+            output.push_str(",\"range\":{\"start\":");
+            write_json_position(&mut output, &diagnostic.span.start);
+            output.push_str(",\"end\":");
+            write_json_position(&mut output, &diagnostic.span.end);
+            output.push_str("}}");
+        }
+
+        output.push_str("]}");
+
+        output
+    }
+
+    /// Serializes the report into a SARIF 2.1.0 log JSON string.
+    ///
+    /// The log consists of a single run of the `tool` driver, and each
+    /// diagnostic of the report turns into a SARIF result with the physical
+    /// location in the annotated file, and the related locations of
+    /// the diagnostic's [related](Diagnostic::related) fragments.
+    ///
+    /// The results are the same as the ones serialized by
+    /// the [Diagnostic::to_sarif] function. The file [name](crate::arena::Id::name)
+    /// is used as the artifact URI.
+    ///
+    /// The SARIF regions are one-based, and the end column is exclusive.
+    pub fn to_sarif_string(&self, tool: &str) -> String {
+        let name = self.code.id().name();
+
+        let mut output = String::new();
+
+        output.push_str(
+            "{\"$schema\":\"https://json.schemastore.org/sarif-2.1.0.json\",\
+            \"version\":\"2.1.0\",\"runs\":[{\"tool\":{\"driver\":{\"name\":",
+        );
+        write_json_string(&mut output, tool);
+        output.push_str("}},\"results\":[");
+
+        for (index, diagnostic) in self.diagnostics.iter().enumerate() {
+            if index > 0 {
+                output.push(',');
+            }
+
+            SarifResult::new(diagnostic, &name).write_json(&mut output);
+        }
+
+        output.push_str("]}]}");
+
+        output
+    }
+}
+
+fn write_json_position(output: &mut String, position: &Position) {
+    let _ = write!(
+        output,
+        "{{\"line\":{},\"column\":{}}}",
+        position.line, position.column,
+    );
+}
+
+fn write_json_string(output: &mut String, string: &str) {
+    output.push('"');
+
+    for ch in string.chars() {
+        match ch {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            ch if ch.is_control() => {
+                let _ = write!(output, "\\u{:04x}", ch as u32);
+            }
+            ch => output.push(ch),
+        }
+    }
+
+    output.push('"');
+}
+
+// A SARIF result object of the Diagnostic.
+//
+// This object is the only SARIF encoder of the crate: it serializes through
+// serde in the Diagnostic::to_sarif function, and through the write_json
+// function in the DiagnosticReport::to_sarif_string function. Both ways
+// produce the same fields in the same order.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+struct SarifResult<'a> {
+    level: &'static str,
+    message: SarifMessage<'a>,
+    locations: [SarifLocation<'a>; 1],
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    related_locations: Vec<SarifLocation<'a>>,
+}
+
+impl<'a> SarifResult<'a> {
+    fn new(diagnostic: &'a Diagnostic, uri: &'a str) -> Self {
+        Self {
+            level: diagnostic.severity.sarif_level(),
+            message: SarifMessage {
+                text: diagnostic.message.as_str(),
+            },
+            locations: [SarifLocation::new(None, &diagnostic.span, uri, None)],
+            related_locations: diagnostic
+                .related
+                .iter()
+                .enumerate()
+                .map(|(id, related)| {
+                    SarifLocation::new(Some(id), &related.span, uri, Some(&related.message))
+                })
+                .collect(),
+        }
+    }
+
+    fn write_json(&self, output: &mut String) {
+        output.push_str("{\"level\":");
+        write_json_string(output, self.level);
+        output.push_str(",\"message\":");
+        self.message.write_json(output);
+        output.push_str(",\"locations\":[");
+        self.locations[0].write_json(output);
+        output.push(']');
+
+        if !self.related_locations.is_empty() {
+            output.push_str(",\"relatedLocations\":[");
+
+            for (index, location) in self.related_locations.iter().enumerate() {
+                if index > 0 {
+                    output.push(',');
+                }
+
+                location.write_json(output);
+            }
+
+            output.push(']');
+        }
+
+        output.push('}');
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+struct SarifLocation<'a> {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    id: Option<usize>,
+    physical_location: SarifPhysicalLocation<'a>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    message: Option<SarifMessage<'a>>,
+}
+
+impl<'a> SarifLocation<'a> {
+    fn new(id: Option<usize>, span: &PositionSpan, uri: &'a str, message: Option<&'a str>) -> Self {
+        Self {
+            id,
+            physical_location: SarifPhysicalLocation {
+                artifact_location: SarifArtifactLocation { uri },
+                region: SarifRegion {
+                    start_line: span.start.line,
+                    start_column: span.start.column,
+                    end_line: span.end.line,
+                    end_column: span.end.column,
+                },
+            },
+            message: message.map(|text| SarifMessage { text }),
+        }
+    }
+
+    fn write_json(&self, output: &mut String) {
+        output.push('{');
+
+        if let Some(id) = self.id {
+            let _ = write!(output, "\"id\":{id},");
+        }
+
+        let location = &self.physical_location;
+        let region = &location.region;
+
+        output.push_str("\"physicalLocation\":{\"artifactLocation\":{\"uri\":");
+        write_json_string(output, location.artifact_location.uri);
+        let _ = write!(
+            output,
+            "}},\"region\":{{\"startLine\":{},\"startColumn\":{},\
+            \"endLine\":{},\"endColumn\":{}}}}}",
+            region.start_line, region.start_column, region.end_line, region.end_column,
+        );
+
+        if let Some(message) = &self.message {
+            output.push_str(",\"message\":");
+            message.write_json(output);
+        }
+
+        output.push('}');
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+struct SarifPhysicalLocation<'a> {
+    artifact_location: SarifArtifactLocation<'a>,
+    region: SarifRegion,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+struct SarifArtifactLocation<'a> {
+    uri: &'a str,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+struct SarifRegion {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+struct SarifMessage<'a> {
+    text: &'a str,
+}
+
+impl<'a> SarifMessage<'a> {
+    #[inline(always)]
+    fn write_json(&self, output: &mut String) {
+        output.push_str("{\"text\":");
+        write_json_string(output, self.text);
+        output.push('}');
+    }
+}
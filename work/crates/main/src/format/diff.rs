@@ -0,0 +1,1275 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{borrow::Cow, fmt, io, ops::Range};
+
+use crate::{
+    format::{
+        snippet::{PrintString, StyleString},
+        AnnotationPriority,
+        SnippetConfig,
+        Style,
+    },
+    lexis::{Length, Line, Site, SiteSpan, SourceCode, ToSpan},
+    report::ld_unreachable,
+};
+
+/// A builder of the side-by-side or unified source code difference snippet.
+///
+/// The DiffSnippet takes two versions of the source code text, computes
+/// a line-level difference between them, and renders the changed lines
+/// together with a few surrounding unchanged lines using the same framing,
+/// line numbering, and styling as the [Snippet](crate::format::Snippet).
+///
+/// Through the methods of the builder, you can configure snippet's rendering
+/// features, choose the [layout](DiffLayout), and annotate the source code
+/// fragments of either [side](DiffSide) of the difference.
+///
+/// The [render_to_string](DiffSnippet::render_to_string),
+/// [render_to_io](DiffSnippet::render_to_io), and
+/// [render_to](DiffSnippet::render_to) methods render the snippet.
+///
+/// Note that the line difference algorithm has quadratic complexity in
+/// the number of the changed lines. The DiffSnippet is intended for
+/// the previews of relatively small changes.
+///
+/// Also note that the exact representation of the snippet rendering is not
+/// specified and is a subject to changes and improvements in future minor
+/// versions of this crate.
+pub struct DiffSnippet<'a, O: SourceCode, N: SourceCode> {
+    old: &'a O,
+    new: &'a N,
+    config: &'a SnippetConfig,
+    layout: DiffLayout,
+    caption: PrintString<'a>,
+    summary: PrintString<'a>,
+    removed_style: Style,
+    inserted_style: Style,
+    annotations: Vec<DiffAnnotation<'a>>,
+}
+
+impl<'a, O: SourceCode, N: SourceCode> DiffSnippet<'a, O, N> {
+    /// Creates a difference snippet builder.
+    ///
+    /// The `old` parameter specifies the original version of the source code.
+    ///
+    /// The `new` parameter specifies the changed version of the source code.
+    ///
+    /// By default, the snippet uses [minimal](SnippetConfig::minimal)
+    /// rendering configuration and the [unified](DiffLayout::Unified) layout.
+    #[inline(always)]
+    pub fn new(old: &'a O, new: &'a N) -> Self {
+        static MINIMAL: SnippetConfig = SnippetConfig::minimal();
+
+        Self {
+            old,
+            new,
+            config: &MINIMAL,
+            layout: DiffLayout::Unified,
+            caption: PrintString::empty(),
+            summary: PrintString::empty(),
+            removed_style: Style::new().red(),
+            inserted_style: Style::new().green(),
+            annotations: Vec::new(),
+        }
+    }
+
+    /// Sets snippet's general look and feel configuration options.
+    #[inline(always)]
+    pub fn set_config(&mut self, config: &'a SnippetConfig) -> &mut Self {
+        self.config = config;
+
+        self
+    }
+
+    /// Sets the layout of the difference rendering.
+    #[inline(always)]
+    pub fn set_layout(&mut self, layout: DiffLayout) -> &mut Self {
+        self.layout = layout;
+
+        self
+    }
+
+    /// Sets snippet's header caption.
+    ///
+    /// **Panic**
+    ///
+    /// Panics if the caption contains more than one line (delimited by `\n`).
+    #[inline(always)]
+    pub fn set_caption(&mut self, caption: impl Into<Cow<'a, str>>) -> &mut Self {
+        let caption = caption.into();
+
+        if caption.contains('\n') {
+            panic!("Multiline captions not supported.");
+        }
+
+        self.caption = PrintString::from_cow(caption);
+
+        self
+    }
+
+    /// Sets snippet's footer summary text.
+    #[inline(always)]
+    pub fn set_summary(&mut self, summary: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.summary = PrintString::from_cow(summary.into());
+
+        self
+    }
+
+    /// Sets the style of the lines removed from the old version of
+    /// the source code.
+    ///
+    /// The style is applied only if the [SnippetConfig::style] option is
+    /// enabled. By default, the removed lines are red.
+    #[inline(always)]
+    pub fn set_removed_style(&mut self, style: Style) -> &mut Self {
+        self.removed_style = style;
+
+        self
+    }
+
+    /// Sets the style of the lines inserted into the new version of
+    /// the source code.
+    ///
+    /// The style is applied only if the [SnippetConfig::style] option is
+    /// enabled. By default, the inserted lines are green.
+    #[inline(always)]
+    pub fn set_inserted_style(&mut self, style: Style) -> &mut Self {
+        self.inserted_style = style;
+
+        self
+    }
+
+    /// Adds an annotation to the source code of the specified `side`.
+    ///
+    /// The annotated lines are always shown in the snippet, even if they
+    /// are unchanged.
+    ///
+    /// The `span` parameter specifies the annotation span within the source
+    /// code of the `side`.
+    ///
+    /// The `priority` parameter specifies the importance of the annotation.
+    ///
+    /// The `message` parameter specifies a message that will be shown below
+    /// the annotated line. This parameter can be omitted (set to an empty
+    /// string), but the message string must be one line (it should not contain
+    /// `\n` chars).
+    ///
+    /// **Panic**
+    ///
+    /// Panics if the message has `\n` characters, or if the span is not valid
+    /// for the source code of the `side`.
+    pub fn annotate(
+        &mut self,
+        side: DiffSide,
+        span: impl ToSpan,
+        priority: AnnotationPriority,
+        message: impl Into<Cow<'a, str>>,
+    ) -> &mut Self {
+        let message = message.into();
+
+        if message.contains('\n') {
+            panic!("Multiline annotation messages not supported.");
+        }
+
+        let span = match side {
+            DiffSide::Old => span.to_site_span(self.old),
+            DiffSide::New => span.to_site_span(self.new),
+        };
+
+        let span = match span {
+            Some(span) => span,
+
+            None => panic!("Invalid annotation span."),
+        };
+
+        self.annotations.push(DiffAnnotation {
+            side,
+            span,
+            priority,
+            message: PrintString::from_cow(message),
+        });
+
+        self
+    }
+
+    /// Renders the snippet into a String.
+    pub fn render_to_string(&self) -> String {
+        let mut output = String::new();
+
+        if self.render_to(&mut output).is_err() {
+            // Safety: Writing into a String is infallible.
+            unsafe { ld_unreachable!("String write failure.") }
+        }
+
+        output
+    }
+
+    /// Renders the snippet into the `output` byte stream.
+    ///
+    /// This function returns an error if the `output` stream fails to write.
+    pub fn render_to_io(&self, output: &mut impl io::Write) -> io::Result<()> {
+        output.write_all(self.render_to_string().as_bytes())
+    }
+
+    /// Renders the snippet into an arbitrary text `output` sink.
+    ///
+    /// This function returns a format result with any format errors that may
+    /// occur during interactions with the `output` sink.
+    pub fn render_to(&self, output: &mut dyn fmt::Write) -> fmt::Result {
+        let config = self.config;
+
+        let old_lines = DiffLine::split(config, self.old);
+        let new_lines = DiffLine::split(config, self.new);
+
+        let rows = self.rows(&old_lines, &new_lines);
+
+        let numbers_length = (config
+            .line_number(old_lines.len().max(new_lines.len()))
+            .checked_ilog10()
+            .unwrap_or(0) as usize
+            + 1)
+        .max(config.etc().length);
+
+        let header_length = match (self.layout, config.show_numbers) {
+            (DiffLayout::Unified, true) => numbers_length * 2 + 1,
+            _ => numbers_length,
+        };
+
+        let caption = match config.caption {
+            false => StyleString::empty(),
+            true => StyleString::from_str(config, self.caption.as_str()),
+        };
+
+        let summary = match config.summary {
+            false => Vec::new(),
+            true => self
+                .summary
+                .as_str()
+                .lines()
+                .map(|line| StyleString::from_str(config, line))
+                .collect::<Vec<_>>(),
+        };
+
+        let has_caption = caption.length > 0;
+        let has_summary = !summary.is_empty();
+
+        let mut old_length = 0;
+        let mut new_length = 0;
+
+        for row in &rows {
+            if let DiffRow::Line { old, new } = row {
+                if let Some(old) = old {
+                    old_length = old_length.max(old.line.length());
+                }
+
+                if let Some(new) = new {
+                    new_length = new_length.max(new.line.length());
+                }
+            }
+        }
+
+        let mut margin: usize = config.margin();
+
+        if config.draw_frame {
+            margin = margin
+                .checked_sub(2 + config.box_vertical().length * 2)
+                .unwrap_or_default();
+        }
+
+        if config.show_numbers {
+            margin = margin.checked_sub(header_length + 2).unwrap_or_default();
+        }
+
+        let mut code_length = match self.layout {
+            DiffLayout::Unified => old_length.max(new_length) + GUTTER_LENGTH,
+
+            DiffLayout::SideBySide => {
+                let half = margin
+                    .checked_sub(self.separator_length(numbers_length))
+                    .unwrap_or_default()
+                    / 2;
+
+                old_length = (old_length + GUTTER_LENGTH).max(half);
+                new_length = (new_length + GUTTER_LENGTH).max(half);
+
+                old_length + new_length + self.separator_length(numbers_length)
+            }
+        };
+
+        if config.draw_frame && has_caption {
+            code_length = code_length
+                .max(caption.length + config.caption_start().length + config.caption_end().length);
+        }
+
+        if config.draw_frame {
+            for summary_line in &summary {
+                code_length = code_length.max(summary_line.length);
+            }
+        }
+
+        code_length = code_length.max(margin);
+
+        if let DiffLayout::SideBySide = self.layout {
+            new_length = code_length - old_length - self.separator_length(numbers_length);
+        }
+
+        let mut is_first = true;
+
+        if config.draw_frame || has_caption || has_summary {
+            StyleString::start(is_first)
+                .with_header_blank(config, header_length)
+                .with_caption(config, code_length, caption)
+                .end(&mut is_first, output)?;
+        }
+
+        for row in rows {
+            match row {
+                DiffRow::Etc => {
+                    StyleString::start(is_first)
+                        .with_header_etc(config, header_length)
+                        .with_code_blank(config, true, has_caption, has_summary, code_length)
+                        .end(&mut is_first, output)?;
+                }
+
+                DiffRow::Line { old, new } => {
+                    let (header, code, messages) = match self.layout {
+                        DiffLayout::Unified => {
+                            self.unified_row(numbers_length, old.as_ref().or(new.as_ref()))
+                        }
+
+                        DiffLayout::SideBySide => self.side_by_side_row(
+                            numbers_length,
+                            old_length,
+                            new_length,
+                            old.as_ref(),
+                            new.as_ref(),
+                        ),
+                    };
+
+                    StyleString::start(is_first)
+                        .with_header(config, header_length, header.as_str())
+                        .with_code(config, true, has_caption, has_summary, code_length, code)
+                        .end(&mut is_first, output)?;
+
+                    for message in messages {
+                        StyleString::start(is_first)
+                            .with_header_blank(config, header_length)
+                            .with_code(config, true, has_caption, has_summary, code_length, message)
+                            .end(&mut is_first, output)?;
+                    }
+                }
+            }
+        }
+
+        if has_summary {
+            StyleString::start(is_first)
+                .with_header_blank(config, header_length)
+                .with_delimiter(config, code_length)
+                .end(&mut is_first, output)?;
+
+            for summary in summary {
+                StyleString::start(is_first)
+                    .with_header_blank(config, header_length)
+                    .with_summary(config, code_length, summary)
+                    .end(&mut is_first, output)?;
+            }
+        }
+
+        if config.draw_frame || has_caption || has_summary {
+            StyleString::start(is_first)
+                .with_header_blank(config, header_length)
+                .with_footer(config, code_length)
+                .end(&mut is_first, output)?;
+        }
+
+        Ok(())
+    }
+
+    fn rows<'l>(&self, old_lines: &'l [DiffLine], new_lines: &'l [DiffLine]) -> Vec<DiffRow<'l>> {
+        let operations = DiffOperation::compute(old_lines, new_lines);
+
+        let mut pairs = Vec::with_capacity(operations.len());
+        let mut removed = Vec::new();
+        let mut inserted = Vec::new();
+
+        let flush = |pairs: &mut Vec<(Option<RowLine<'l>>, Option<RowLine<'l>>, bool)>,
+                     removed: &mut Vec<&'l DiffLine>,
+                     inserted: &mut Vec<&'l DiffLine>| {
+            match self.layout {
+                DiffLayout::Unified => {
+                    for line in removed.drain(..) {
+                        pairs.push((Some(RowLine::new(DiffSide::Old, line)), None, true));
+                    }
+
+                    for line in inserted.drain(..) {
+                        pairs.push((None, Some(RowLine::new(DiffSide::New, line)), true));
+                    }
+                }
+
+                DiffLayout::SideBySide => {
+                    let count = removed.len().max(inserted.len());
+
+                    let mut removed = removed.drain(..);
+                    let mut inserted = inserted.drain(..);
+
+                    for _ in 0..count {
+                        pairs.push((
+                            removed.next().map(|line| RowLine::new(DiffSide::Old, line)),
+                            inserted
+                                .next()
+                                .map(|line| RowLine::new(DiffSide::New, line)),
+                            true,
+                        ));
+                    }
+                }
+            }
+        };
+
+        for operation in operations {
+            match operation {
+                DiffOperation::Removed(old) => removed.push(&old_lines[old]),
+
+                DiffOperation::Inserted(new) => inserted.push(&new_lines[new]),
+
+                DiffOperation::Equal(old, new) => {
+                    flush(&mut pairs, &mut removed, &mut inserted);
+
+                    let old_line = &old_lines[old];
+                    let new_line = &new_lines[new];
+
+                    let old = RowLine::equal(DiffSide::Old, old_line, new_line.number);
+                    let new = RowLine::equal(DiffSide::New, new_line, old_line.number);
+
+                    match self.layout {
+                        DiffLayout::Unified => pairs.push((Some(old), None, false)),
+                        DiffLayout::SideBySide => pairs.push((Some(old), Some(new), false)),
+                    }
+                }
+            }
+        }
+
+        flush(&mut pairs, &mut removed, &mut inserted);
+
+        for (old, new, changed) in &mut pairs {
+            for annotation in &self.annotations {
+                let line = match annotation.side {
+                    DiffSide::Old => old.as_ref().filter(|line| line.side == DiffSide::Old),
+                    DiffSide::New => new.as_ref().filter(|line| line.side == DiffSide::New),
+                };
+
+                if let Some(line) = line {
+                    if line.line.intersects(&annotation.span) {
+                        *changed = true;
+                    }
+                }
+            }
+        }
+
+        let mut distances = Vec::with_capacity(pairs.len());
+        let mut back_distance = usize::MAX;
+
+        for (_, _, changed) in &pairs {
+            back_distance = match changed {
+                true => 0,
+                false => back_distance.saturating_add(1),
+            };
+
+            distances.push(back_distance);
+        }
+
+        let mut forward_distance = usize::MAX;
+
+        for (index, (_, _, changed)) in pairs.iter().enumerate().rev() {
+            forward_distance = match changed {
+                true => 0,
+                false => forward_distance.saturating_add(1),
+            };
+
+            distances[index] = distances[index].min(forward_distance);
+        }
+
+        let has_changes = pairs.iter().any(|(_, _, changed)| *changed);
+
+        let mut rows = Vec::with_capacity(pairs.len());
+        let mut skipped = 0;
+
+        for ((old, new, _), distance) in pairs.into_iter().zip(distances) {
+            if has_changes && distance > self.config.cover() {
+                skipped += 1;
+                continue;
+            }
+
+            if skipped > 0 {
+                rows.push(DiffRow::Etc);
+                skipped = 0;
+            }
+
+            rows.push(DiffRow::Line { old, new });
+        }
+
+        if skipped > 0 {
+            rows.push(DiffRow::Etc);
+        }
+
+        rows
+    }
+
+    fn unified_row(
+        &self,
+        numbers_length: Length,
+        line: Option<&RowLine>,
+    ) -> (String, StyleString, Vec<StyleString>) {
+        let mut code = StyleString::new();
+
+        let Some(line) = line else {
+            return (String::new(), code, Vec::new());
+        };
+
+        let header = match (line.changed, line.side) {
+            (false, _) => format!(
+                "{: >2$} {: >2$}",
+                line.line.number, line.pair_number, numbers_length
+            ),
+
+            (true, DiffSide::Old) => {
+                format!("{: >2$} {: >2$}", line.line.number, "", numbers_length)
+            }
+
+            (true, DiffSide::New) => {
+                format!("{: >2$} {: >2$}", "", line.line.number, numbers_length)
+            }
+        };
+
+        self.write_gutter(&mut code, Some(line));
+
+        let messages = self
+            .write_line(&mut code, line)
+            .into_iter()
+            .map(|message| {
+                let mut indented = StyleString::new();
+
+                indented.write_blanks(GUTTER_LENGTH);
+                indented.append(message);
+
+                indented
+            })
+            .collect();
+
+        (header, code, messages)
+    }
+
+    fn side_by_side_row(
+        &self,
+        numbers_length: Length,
+        old_length: Length,
+        new_length: Length,
+        old: Option<&RowLine>,
+        new: Option<&RowLine>,
+    ) -> (String, StyleString, Vec<StyleString>) {
+        let config = self.config;
+
+        let mut code = StyleString::new();
+        let mut messages = Vec::new();
+
+        let header = match old {
+            Some(old) => old.line.number.to_string(),
+            None => String::new(),
+        };
+
+        self.write_gutter(&mut code, old);
+
+        if let Some(old) = old {
+            for message in self.write_line(&mut code, old) {
+                messages.push((Some(message), None));
+            }
+        }
+
+        code.style = config.code_style(true);
+        code.write_blanks(old_length - code.length);
+
+        let right_start = code.length;
+
+        self.write_separator(&mut code, numbers_length, new.map(|new| new.line.number));
+        self.write_gutter(&mut code, new);
+
+        if let Some(new) = new {
+            for (index, message) in self.write_line(&mut code, new).into_iter().enumerate() {
+                match messages.get_mut(index) {
+                    Some((_, right)) => *right = Some(message),
+                    None => messages.push((None, Some(message))),
+                }
+            }
+        }
+
+        code.style = config.code_style(true);
+        code.write_blanks(
+            (right_start + self.separator_length(numbers_length) + new_length)
+                .checked_sub(code.length)
+                .unwrap_or_default(),
+        );
+
+        let messages = messages
+            .into_iter()
+            .map(|(left, right)| {
+                let mut message = StyleString::new();
+
+                message.write_blanks(GUTTER_LENGTH);
+
+                if let Some(left) = left {
+                    message.append(left);
+                }
+
+                message.write_blanks(old_length - message.length);
+                self.write_separator(&mut message, numbers_length, None);
+                message.write_blanks(GUTTER_LENGTH);
+
+                if let Some(right) = right {
+                    message.append(right);
+                }
+
+                message
+            })
+            .collect();
+
+        (header, code, messages)
+    }
+
+    fn write_line(&self, code: &mut StyleString, line: &RowLine) -> Vec<StyleString> {
+        let config = self.config;
+        let line_style = self.line_style(line);
+        let offset = code.length;
+
+        let mut messages = Vec::new();
+        let mut site = line.line.site;
+
+        for ch in line.line.text.chars() {
+            code.style = line_style;
+
+            for annotation in &self.annotations {
+                if annotation.side != line.side {
+                    continue;
+                }
+
+                if annotation.span.start == site && !annotation.message.is_empty() {
+                    messages.push((annotation.priority, code.length - offset, annotation));
+                }
+
+                if annotation.span.contains(&site) {
+                    code.style = config.annotation_style(annotation.priority);
+                }
+            }
+
+            match ch {
+                '\t' => code.write_tab(config, code.length - offset),
+                _ => code.write_code_char(config, ch),
+            }
+
+            site += 1;
+        }
+
+        for annotation in &self.annotations {
+            if annotation.side != line.side {
+                continue;
+            }
+
+            let empty_at_end = annotation.span.start == site && annotation.span.end == site;
+
+            if empty_at_end || (annotation.span.start == site && line.line.last) {
+                code.style = config.annotation_style(annotation.priority);
+                code.write_placeholder(config);
+
+                if !annotation.message.is_empty() {
+                    messages.push((annotation.priority, code.length - offset - 1, annotation));
+                }
+            }
+        }
+
+        code.style = line_style;
+
+        messages.sort_by_key(|(priority, _, _)| priority.order());
+
+        messages
+            .into_iter()
+            .map(|(priority, column, annotation)| {
+                let mut message = StyleString::new();
+
+                message.write_blanks(column);
+                message.style = config.annotation_style(priority).no_emphasis();
+                message.write_sanitized(config.arrow_down_right());
+                message.append(StyleString::from_str(config, annotation.message.as_str()));
+                message.style = Style::new();
+
+                message
+            })
+            .collect()
+    }
+
+    fn write_gutter(&self, code: &mut StyleString, line: Option<&RowLine>) {
+        let Some(line) = line else {
+            code.write_blanks(GUTTER_LENGTH);
+            return;
+        };
+
+        code.style = self.line_style(line);
+
+        let gutter = match (line.changed, line.side) {
+            (false, _) => " ",
+            (true, DiffSide::Old) => "-",
+            (true, DiffSide::New) => "+",
+        };
+
+        code.write_sanitized(&PrintString::borrowed(gutter));
+        code.write_blanks(GUTTER_LENGTH - 1);
+    }
+
+    fn write_separator(
+        &self,
+        code: &mut StyleString,
+        numbers_length: Length,
+        number: Option<Line>,
+    ) {
+        let config = self.config;
+
+        code.style = Style::new();
+        code.write_blanks(1);
+        code.style = config.frame_style();
+        code.write_sanitized(config.box_vertical());
+
+        if config.show_numbers {
+            let number = match number {
+                Some(number) => number.to_string(),
+                None => String::new(),
+            };
+
+            code.style = config.numbers_style();
+            code.write_sanitized(&PrintString::owned(format!(
+                " {: >1$} ",
+                number, numbers_length
+            )));
+            code.style = config.frame_style();
+            code.write_sanitized(config.box_vertical());
+        }
+
+        code.style = Style::new();
+        code.write_blanks(1);
+    }
+
+    #[inline(always)]
+    fn separator_length(&self, numbers_length: Length) -> Length {
+        let vertical = self.config.box_vertical().length;
+
+        match self.config.show_numbers {
+            true => 2 + vertical * 2 + numbers_length + 2,
+            false => 2 + vertical,
+        }
+    }
+
+    #[inline(always)]
+    fn line_style(&self, line: &RowLine) -> Style {
+        if !line.changed {
+            return self.config.code_style(true);
+        }
+
+        if !self.config.style {
+            return Style::new();
+        }
+
+        self.config.adapt_style(match line.side {
+            DiffSide::Old => self.removed_style,
+            DiffSide::New => self.inserted_style,
+        })
+    }
+}
+
+const GUTTER_LENGTH: Length = 2;
+
+/// A layout of the [DiffSnippet] rendering.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum DiffLayout {
+    /// The removed and the inserted lines are rendered in a single column
+    /// with `-` and `+` gutters.
+    #[default]
+    Unified,
+
+    /// The old version of the source code is rendered in the left column,
+    /// and the new version is rendered in the right column.
+    SideBySide,
+}
+
+/// A side of the [DiffSnippet] difference.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum DiffSide {
+    /// The original version of the source code.
+    Old,
+
+    /// The changed version of the source code.
+    New,
+}
+
+struct DiffAnnotation<'a> {
+    side: DiffSide,
+    span: SiteSpan,
+    priority: AnnotationPriority,
+    message: PrintString<'a>,
+}
+
+struct DiffLine {
+    number: Line,
+    site: Site,
+    text: String,
+    last: bool,
+}
+
+impl DiffLine {
+    fn split(config: &SnippetConfig, code: &impl SourceCode) -> Vec<Self> {
+        let text = code.substring(..);
+
+        let mut result = Vec::new();
+        let mut site = 0;
+
+        for (index, line) in text.split('\n').enumerate() {
+            let length = line.chars().count();
+
+            result.push(Self {
+                number: config.line_number(index + 1),
+                site,
+                text: String::from(line.strip_suffix('\r').unwrap_or(line)),
+                last: false,
+            });
+
+            site += length + 1;
+        }
+
+        if let Some(last) = result.last_mut() {
+            last.last = true;
+        }
+
+        result
+    }
+
+    #[inline(always)]
+    fn length(&self) -> Length {
+        self.text.chars().count() + 1
+    }
+
+    #[inline(always)]
+    fn intersects(&self, span: &SiteSpan) -> bool {
+        let end = self.site + self.text.chars().count();
+
+        span.start <= end && span.end >= self.site
+    }
+}
+
+struct RowLine<'l> {
+    side: DiffSide,
+    line: &'l DiffLine,
+    pair_number: Line,
+    changed: bool,
+}
+
+impl<'l> RowLine<'l> {
+    #[inline(always)]
+    fn new(side: DiffSide, line: &'l DiffLine) -> Self {
+        Self {
+            side,
+            line,
+            pair_number: line.number,
+            changed: true,
+        }
+    }
+
+    #[inline(always)]
+    fn equal(side: DiffSide, line: &'l DiffLine, pair_number: Line) -> Self {
+        Self {
+            side,
+            line,
+            pair_number,
+            changed: false,
+        }
+    }
+}
+
+enum DiffRow<'l> {
+    Etc,
+    Line {
+        old: Option<RowLine<'l>>,
+        new: Option<RowLine<'l>>,
+    },
+}
+
+enum DiffOperation {
+    Equal(usize, usize),
+    Removed(usize),
+    Inserted(usize),
+}
+
+impl DiffOperation {
+    // The maximum edit distance that the middle snake search explores
+    // before giving up on the minimal diff of the block.
+    const COST_LIMIT: usize = 2048;
+
+    // Computes the line diff using the linear space variant of Myers'
+    // O(ND) algorithm.
+    //
+    // If the middle snake of a block cannot be found within the cost limit,
+    // the entire block is reported as replaced.
+    fn compute(old: &[DiffLine], new: &[DiffLine]) -> Vec<Self> {
+        let limit = (old.len() + new.len() + 1) / 2 + 1;
+
+        let mut forward = vec![0; 2 * limit + 2];
+        let mut backward = vec![0; 2 * limit + 2];
+
+        let mut result = Vec::with_capacity(old.len().max(new.len()));
+
+        Self::conquer(
+            old,
+            0..old.len(),
+            new,
+            0..new.len(),
+            &mut forward,
+            &mut backward,
+            &mut result,
+        );
+
+        result
+    }
+
+    fn conquer(
+        old: &[DiffLine],
+        mut old_range: Range<usize>,
+        new: &[DiffLine],
+        mut new_range: Range<usize>,
+        forward: &mut [usize],
+        backward: &mut [usize],
+        result: &mut Vec<Self>,
+    ) {
+        while old_range.start < old_range.end
+            && new_range.start < new_range.end
+            && old[old_range.start].text == new[new_range.start].text
+        {
+            result.push(Self::Equal(old_range.start, new_range.start));
+            old_range.start += 1;
+            new_range.start += 1;
+        }
+
+        let mut suffix = 0;
+
+        while old_range.start < old_range.end
+            && new_range.start < new_range.end
+            && old[old_range.end - 1].text == new[new_range.end - 1].text
+        {
+            old_range.end -= 1;
+            new_range.end -= 1;
+            suffix += 1;
+        }
+
+        let snake = match old_range.is_empty() || new_range.is_empty() {
+            true => None,
+            false => Self::middle_snake(
+                old,
+                old_range.clone(),
+                new,
+                new_range.clone(),
+                forward,
+                backward,
+            ),
+        };
+
+        match snake {
+            Some((old_split, new_split)) => {
+                Self::conquer(
+                    old,
+                    old_range.start..old_split,
+                    new,
+                    new_range.start..new_split,
+                    forward,
+                    backward,
+                    result,
+                );
+
+                Self::conquer(
+                    old,
+                    old_split..old_range.end,
+                    new,
+                    new_split..new_range.end,
+                    forward,
+                    backward,
+                    result,
+                );
+            }
+
+            None => {
+                result.extend(old_range.clone().map(Self::Removed));
+                result.extend(new_range.clone().map(Self::Inserted));
+            }
+        }
+
+        for index in 0..suffix {
+            result.push(Self::Equal(old_range.end + index, new_range.end + index));
+        }
+    }
+
+    // Searches for the middle snake of the optimal path between the blocks.
+    //
+    // Returns the start of the snake in the old and new line indices, or None
+    // if the edit distance between the blocks exceeds the cost limit.
+    //
+    // Both blocks are non-empty, and their first and last lines differ.
+    fn middle_snake(
+        old: &[DiffLine],
+        old_range: Range<usize>,
+        new: &[DiffLine],
+        new_range: Range<usize>,
+        forward: &mut [usize],
+        backward: &mut [usize],
+    ) -> Option<(usize, usize)> {
+        let n = old_range.len() as isize;
+        let m = new_range.len() as isize;
+        let delta = n - m;
+        let odd = delta & 1 == 1;
+        let offset = (forward.len() / 2) as isize;
+        let limit = ((n + m + 1) / 2 + 1).min(Self::COST_LIMIT as isize);
+
+        let at = |k: isize| (k + offset) as usize;
+
+        forward[at(1)] = 0;
+        backward[at(1)] = 0;
+
+        for d in 0..limit {
+            let mut k = -d;
+
+            while k <= d {
+                let mut x = match k == -d || (k != d && forward[at(k - 1)] < forward[at(k + 1)]) {
+                    true => forward[at(k + 1)] as isize,
+                    false => forward[at(k - 1)] as isize + 1,
+                };
+
+                let mut y = x - k;
+                let (x0, y0) = (x, y);
+
+                while x < n
+                    && y < m
+                    && old[old_range.start + x as usize].text
+                        == new[new_range.start + y as usize].text
+                {
+                    x += 1;
+                    y += 1;
+                }
+
+                forward[at(k)] = x as usize;
+
+                if odd && (k - delta).abs() <= d - 1 && x + backward[at(delta - k)] as isize >= n {
+                    return Some((old_range.start + x0 as usize, new_range.start + y0 as usize));
+                }
+
+                k += 2;
+            }
+
+            let mut k = -d;
+
+            while k <= d {
+                let mut x = match k == -d || (k != d && backward[at(k - 1)] < backward[at(k + 1)]) {
+                    true => backward[at(k + 1)] as isize,
+                    false => backward[at(k - 1)] as isize + 1,
+                };
+
+                let mut y = x - k;
+
+                while x < n
+                    && y < m
+                    && old[old_range.end - 1 - x as usize].text
+                        == new[new_range.end - 1 - y as usize].text
+                {
+                    x += 1;
+                    y += 1;
+                }
+
+                backward[at(k)] = x as usize;
+
+                if !odd && (k - delta).abs() <= d && x + forward[at(delta - k)] as isize >= n {
+                    return Some((
+                        old_range.start + (n - x) as usize,
+                        new_range.start + (m - y) as usize,
+                    ));
+                }
+
+                k += 2;
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::format::diff::{DiffLine, DiffOperation};
+
+    fn lines<'a>(texts: impl IntoIterator<Item = &'a str>) -> Vec<DiffLine> {
+        texts
+            .into_iter()
+            .enumerate()
+            .map(|(index, text)| DiffLine {
+                number: index + 1,
+                site: 0,
+                text: String::from(text),
+                last: false,
+            })
+            .collect()
+    }
+
+    // Checks that the operations cover both sides in order, and returns
+    // the number of the Equal operations.
+    fn check(old: &[DiffLine], new: &[DiffLine], operations: &[DiffOperation]) -> usize {
+        let mut old_index = 0;
+        let mut new_index = 0;
+        let mut equal = 0;
+
+        for operation in operations {
+            match operation {
+                DiffOperation::Equal(old_line, new_line) => {
+                    assert_eq!(*old_line, old_index);
+                    assert_eq!(*new_line, new_index);
+                    assert_eq!(old[old_index].text, new[new_index].text);
+                    old_index += 1;
+                    new_index += 1;
+                    equal += 1;
+                }
+
+                DiffOperation::Removed(old_line) => {
+                    assert_eq!(*old_line, old_index);
+                    old_index += 1;
+                }
+
+                DiffOperation::Inserted(new_line) => {
+                    assert_eq!(*new_line, new_index);
+                    new_index += 1;
+                }
+            }
+        }
+
+        assert_eq!(old_index, old.len());
+        assert_eq!(new_index, new.len());
+
+        equal
+    }
+
+    #[test]
+    fn test_diff_operations() {
+        let old = lines(["a", "b", "c", "a", "b", "b", "a"]);
+        let new = lines(["c", "b", "a", "b", "a", "c"]);
+
+        let operations = DiffOperation::compute(&old, &new);
+
+        assert_eq!(check(&old, &new, &operations), 4);
+
+        let old = lines(["x", "1", "2", "3", "y"]);
+        let new = lines(["z", "1", "3", "w"]);
+
+        let operations = DiffOperation::compute(&old, &new);
+
+        assert_eq!(check(&old, &new, &operations), 2);
+
+        let empty = lines([]);
+
+        assert_eq!(
+            check(&old, &empty, &DiffOperation::compute(&old, &empty)),
+            0
+        );
+        assert_eq!(
+            check(&empty, &new, &DiffOperation::compute(&empty, &new)),
+            0
+        );
+    }
+
+    #[test]
+    fn test_diff_minimal() {
+        let mut seed = 1u64;
+
+        let mut random = |bound: u64| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+
+            (seed >> 33) % bound
+        };
+
+        for _ in 0..500 {
+            let old = (0..random(12)).map(|_| ["a", "b", "c"][random(3) as usize]);
+            let old = lines(old.collect::<Vec<_>>());
+            let new = (0..random(12)).map(|_| ["a", "b", "c"][random(3) as usize]);
+            let new = lines(new.collect::<Vec<_>>());
+
+            let mut table = vec![vec![0; new.len() + 1]; old.len() + 1];
+
+            for i in (0..old.len()).rev() {
+                for j in (0..new.len()).rev() {
+                    table[i][j] = match old[i].text == new[j].text {
+                        true => table[i + 1][j + 1] + 1,
+                        false => table[i + 1][j].max(table[i][j + 1]),
+                    };
+                }
+            }
+
+            let operations = DiffOperation::compute(&old, &new);
+
+            assert_eq!(check(&old, &new, &operations), table[0][0]);
+        }
+    }
+
+    #[test]
+    fn test_diff_large_distinct() {
+        let old_texts = (0..10_000)
+            .map(|index| match index % 1000 == 0 {
+                true => format!("common {index}"),
+                false => format!("old {index}"),
+            })
+            .collect::<Vec<_>>();
+
+        let new_texts = (0..10_000)
+            .map(|index| match index % 1000 == 0 {
+                true => format!("common {index}"),
+                false => format!("new {index}"),
+            })
+            .collect::<Vec<_>>();
+
+        let old = lines(old_texts.iter().map(String::as_str));
+        let new = lines(new_texts.iter().map(String::as_str));
+
+        let operations = DiffOperation::compute(&old, &new);
+
+        let _ = check(&old, &new, &operations);
+    }
+}
@@ -32,12 +32,26 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
+mod diagnostic;
+mod diff;
 mod printer;
 mod snippet;
 mod terminal;
+mod trivia;
 
 pub use crate::format::{
-    printer::{PrettyPrintConfig, PrettyPrinter},
-    snippet::{AnnotationPriority, Highlighter, Snippet, SnippetConfig, SnippetFormatter},
-    terminal::{Color, Style, TerminalString},
+    diagnostic::{Diagnostic, DiagnosticRelated, DiagnosticReport, DiagnosticSeverity},
+    diff::{DiffLayout, DiffSide, DiffSnippet},
+    printer::{GroupLayout, LayoutReport, PrettyPrintConfig, PrettyPrinter},
+    snippet::{
+        AnnotationLayout,
+        AnnotationPriority,
+        Highlighter,
+        Snippet,
+        SnippetConfig,
+        SnippetFormatter,
+        SnippetPalette,
+    },
+    terminal::{Color, ColorDepth, Style, TerminalCaps, TerminalString},
+    trivia::{Trivia, TriviaChild, TriviaItem, TriviaSplit},
 };
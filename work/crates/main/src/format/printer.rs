@@ -69,9 +69,10 @@
 // distinct from those pertaining to the original "prettyplease" work.                   //
 ///////////////////////////////////////////////////////////////////////////////////////////
 
-use std::collections::VecDeque;
+use std::{collections::VecDeque, iter::repeat, mem::take};
 
 use crate::{
+    format::{Trivia, TriviaItem},
     lexis::Length,
     report::{ld_unreachable, system_panic},
 };
@@ -101,6 +102,24 @@ pub struct PrettyPrintConfig {
     /// The default value is 4.
     pub indent: u16,
 
+    /// If set to true, the printer indents the lines with the tab characters
+    /// instead of the whitespaces: each full indentation step receives
+    /// a single tab character, and the remaining columns receive whitespaces.
+    ///
+    /// The printer assumes that a tab character is `indent` columns wide.
+    ///
+    /// The default value is false.
+    pub tabs: bool,
+
+    /// The maximum number of consecutive blank lines that the printer
+    /// preserves when it prints the line breaks of the source code
+    /// [Trivia]. Longer runs of the blank lines collapse to this number.
+    ///
+    /// If set to zero, the printer does not preserve the blank lines.
+    ///
+    /// The default value is 1.
+    pub blank_lines: u16,
+
     /// If set to true, the printer prints debug symbols directly into the output.
     ///
     /// The default value is false.
@@ -122,6 +141,8 @@ impl PrettyPrintConfig {
             margin: 80,
             inline: 60,
             indent: 4,
+            tabs: false,
+            blank_lines: 1,
             debug: false,
         }
     }
@@ -150,6 +171,8 @@ pub struct PrettyPrinter {
     margin: LengthSigned,
     inline: LengthSigned,
     step: LengthSigned,
+    tabs: bool,
+    blank_lines: usize,
     space: LengthSigned,
     right: LengthSigned,
     left: LengthSigned,
@@ -158,8 +181,16 @@ pub struct PrettyPrinter {
     scan_consumed: usize,
     print_stack: Vec<PrintFrame>,
     indent: LengthSigned,
+    pending_tabs: usize,
     pending_whitespace: usize,
     whitespaces: Vec<String>,
+    line: usize,
+    force_break: bool,
+    after_blank: bool,
+    align_stack: Vec<AlignCell>,
+    align_cells: Vec<AlignCell>,
+    width_stack: Vec<LengthSigned>,
+    groups: Vec<GroupLayout>,
 }
 
 impl PrettyPrinter {
@@ -178,6 +209,8 @@ impl PrettyPrinter {
             margin,
             inline,
             step,
+            tabs: config.tabs,
+            blank_lines: config.blank_lines as usize,
             space: margin,
             right: 0,
             left: 0,
@@ -186,8 +219,16 @@ impl PrettyPrinter {
             scan_consumed: 0,
             print_stack: Vec::new(),
             indent: 0,
+            pending_tabs: 0,
             pending_whitespace: 0,
             whitespaces: Vec::new(),
+            line: 0,
+            force_break: false,
+            after_blank: true,
+            align_stack: Vec::new(),
+            align_cells: Vec::new(),
+            width_stack: Vec::new(),
+            groups: Vec::new(),
         }
     }
 
@@ -238,6 +279,107 @@ impl PrettyPrinter {
         self.scan_end();
     }
 
+    /// Starts a new **fill** word group.
+    ///
+    /// The fill group packs as many items as fit in line, and breaks
+    /// the line only before the items that don't fit. Each blank token of
+    /// this group is measured together with the content that follows it up
+    /// to the next blank token of this group, such that the nested groups
+    /// are treated as a whole items: if the nested group fits in the line
+    /// after the break, the printer breaks the preceding blank token rather
+    /// than the nested group.
+    ///
+    /// The breaking strategy of the fill group is the same as the strategy
+    /// of the [inconsistent](Self::ibox) group, but the fill group is meant
+    /// for the lists of items separated by the blank tokens (e.g., array
+    /// items `[1, 2, 3]`), and the nested groups preserve their own
+    /// breaking modes.
+    ///
+    /// The `indent` parameter specifies the indentation of the lines that
+    /// the group breaks relative to the current indentation.
+    ///
+    /// The group **must be finished** by calling the [fill_end](Self::fill_end)
+    /// function.
+    #[inline(always)]
+    pub fn fill_begin(&mut self, indent: isize) {
+        let step = self.step;
+
+        self.scan_begin(Group {
+            mode: Mode::Fill,
+            indent: step * indent,
+        });
+    }
+
+    /// Finishes the previously started [fill](Self::fill_begin) word group.
+    #[inline(always)]
+    pub fn fill_end(&mut self) {
+        self.scan_end();
+    }
+
+    /// Starts a new alignment cell.
+    ///
+    /// The content between the align_begin and the [align_end](Self::align_end)
+    /// calls forms a cell of the first column of the aligned items. When
+    /// the printer finishes, it pads the cells that start at the same column
+    /// of the consecutive lines with whitespaces such that the content after
+    /// the cells (the second column) is aligned vertically.
+    ///
+    /// For example, the cells around the struct field names align the field
+    /// values:
+    ///
+    /// ```text
+    /// foo:    1,
+    /// foobar: 2,
+    /// ```
+    ///
+    /// The alignment applies only to the cells that the printer placed on
+    /// separate lines: if the enclosing group stays inline, or the cell content
+    /// is broken into multiple lines, the cell is printed as it is.
+    /// The printer does not take the alignment padding into account when it
+    /// decides on the line breaks.
+    ///
+    /// Alignment cells are not groups, and they do not affect the breaking
+    /// modes or the indentation of the enclosing groups. However, every
+    /// group nested in the cell must be finished before the cell ends.
+    ///
+    /// The cell **must be finished** by calling the [align_end](Self::align_end)
+    /// function.
+    #[inline(always)]
+    pub fn align_begin(&mut self) {
+        self.scan_token(ScanToken::AlignBegin);
+    }
+
+    /// Finishes the previously started [alignment cell](Self::align_begin).
+    #[inline(always)]
+    pub fn align_end(&mut self) {
+        self.scan_token(ScanToken::AlignEnd);
+    }
+
+    /// Overrides the line width limit for the enclosed content.
+    ///
+    /// The `width` parameter is the absolute margin (similar to
+    /// the [PrettyPrintConfig::margin]) that the printer uses to decide on
+    /// the line breaks of the groups that start between the width_begin and
+    /// the [width_end](Self::width_end) calls. The override may be either
+    /// narrower or wider than the enclosing limit.
+    ///
+    /// The override does not affect the decisions of the enclosing groups:
+    /// if the enclosing group fits the enclosing limit, its inline content
+    /// stays inline regardless of the override.
+    ///
+    /// The overrides can be nested, and they **must be finished** by calling
+    /// the [width_end](Self::width_end) function.
+    #[inline(always)]
+    pub fn width_begin(&mut self, width: u16) {
+        self.scan_token(ScanToken::WidthBegin(width as LengthSigned));
+    }
+
+    /// Finishes the previously started [width override](Self::width_begin).
+    #[inline(always)]
+    pub fn width_end(&mut self) {
+        self.scan_token(ScanToken::WidthEnd);
+    }
+
     /// Feeds a word into the input stream.
     ///
     /// The `word` parameter is a string that should be treated as a whole
@@ -247,6 +389,11 @@ impl PrettyPrinter {
     /// [hardbreaks](Self::hardbreak) between them.
     #[inline(always)]
     pub fn word(&mut self, word: impl Into<String>) {
+        if self.force_break {
+            self.hardbreak();
+        }
+
+        self.after_blank = false;
         self.scan_string(word.into());
     }
 
@@ -368,14 +515,242 @@ impl PrettyPrinter {
         }
     }
 
+    /// Feeds a word into the input stream that will be printed only if
+    /// the nearest enclosing group is broken into multiple lines.
+    ///
+    /// The algorithm resolves the condition during the layout, and
+    /// the word does not contribute to the length of the group's content.
+    ///
+    /// For example, you can submit `if_broken(",")` after the last item
+    /// of the list to print a trailing comma only when the list is split
+    /// into lines.
+    #[inline(always)]
+    pub fn if_broken(&mut self, word: impl Into<String>) {
+        self.scan_conditional(word.into(), true);
+    }
+
+    /// Feeds a word into the input stream that will be printed only if
+    /// the nearest enclosing group stays in line.
+    ///
+    /// The algorithm resolves the condition during the layout, and the word
+    /// contributes to the length of the group's content.
+    ///
+    /// For example, you can submit `if_flat(" ")` before the closing brace
+    /// to separate the brace from the content only when the content stays
+    /// in line.
+    #[inline(always)]
+    pub fn if_flat(&mut self, word: impl Into<String>) {
+        self.scan_conditional(word.into(), false);
+    }
+
+    /// Prints a comment that precedes the next word.
+    ///
+    /// The comment always starts a new line: if the comment follows a blank
+    /// token, this token receives a line break; otherwise, the printer inserts
+    /// a line break before the comment. The next [word](Self::word) or
+    /// [blank](Self::blank) token receives a line break too. As a result,
+    /// the comment occupies its own line right before the next word, and
+    /// the enclosing groups are broken into multiple lines.
+    ///
+    /// The `comment` parameter is the comment text as it is in the source
+    /// code (e.g., `// comment`).
+    ///
+    /// This function should be called before printing the commented
+    /// construct's words.
+    pub fn print_leading_comment(&mut self, comment: impl Into<String>) {
+        self.break_line();
+        self.word(comment);
+        self.force_break = true;
+    }
+
+    /// Prints a comment that follows the previous word in the same line.
+    ///
+    /// The comment is separated from the previous word by a whitespace, and
+    /// the next [word](Self::word) or [blank](Self::blank) token receives
+    /// a line break. Since the line break is unconditional, the group that
+    /// encloses the comment and the next blank token is always broken into
+    /// multiple lines.
+    ///
+    /// The `comment` parameter is the comment text as it is in the source
+    /// code (e.g., `// comment`).
+    ///
+    /// This function should be called immediately after printing
+    /// the commented construct's words, and before submitting any blank
+    /// tokens that separate the construct from the next one.
+    pub fn print_trailing_comment(&mut self, comment: impl Into<String>) {
+        self.word(" ");
+        self.word(comment);
+        self.force_break = true;
+    }
+
+    /// Prints the comments of the leading [Trivia] of the formatted construct.
+    ///
+    /// Each comment that starts a new line in the source code starts a new
+    /// line in the output too, and the comments that are followed by
+    /// the line breaks in the source code enforce a line break before
+    /// the next [word](Self::word) or [blank](Self::blank) token. The comments
+    /// that are followed by the meaningful content in the same line receive
+    /// a whitespace.
+    ///
+    /// The runs of the blank lines between the comments and the constructs
+    /// collapse to at most [PrettyPrintConfig::blank_lines] blank lines.
+    ///
+    /// This function should be called before printing the construct's words.
+    pub fn print_leading_trivia(&mut self, trivia: &Trivia) {
+        let mut after_comment = false;
+        let mut items = trivia.items.iter().peekable();
+
+        while let Some(item) = items.next() {
+            match item {
+                TriviaItem::Comment(comment) => {
+                    if after_comment {
+                        self.word(" ");
+                    }
+
+                    self.word(comment.as_str());
+                    after_comment = true;
+                }
+
+                TriviaItem::Breaks(breaks) => {
+                    if after_comment {
+                        self.blank_lines(*breaks);
+                        self.force_break = true;
+                    } else if let Some(TriviaItem::Comment(..)) = items.peek() {
+                        self.break_line();
+                        self.blank_lines(*breaks);
+                    } else if self.after_blank {
+                        self.blank_lines(*breaks);
+                    }
+
+                    after_comment = false;
+                }
+            }
+        }
+
+        if after_comment {
+            self.word(" ");
+        }
+    }
+
+    /// Prints the comments of the trailing [Trivia] of the formatted construct.
+    ///
+    /// The comments are printed in the same line after the construct, and
+    /// the next [word](Self::word) or [blank](Self::blank) token receives
+    /// a line break such that the content that follows the trailing comments
+    /// starts from the next line.
+    ///
+    /// This function should be called immediately after printing
+    /// the construct's words, and before submitting any blank tokens that
+    /// separate the construct from the next one.
+    pub fn print_trailing_trivia(&mut self, trivia: &Trivia) {
+        if !trivia.has_comments() {
+            return;
+        }
+
+        for item in &trivia.items {
+            if let TriviaItem::Comment(comment) = item {
+                self.word(" ");
+                self.word(comment.as_str());
+            }
+        }
+
+        self.force_break = true;
+    }
+
     /// Finishes content formatting and returns a final output string.
+    #[inline(always)]
     pub fn finish(mut self) -> String {
+        self.flush();
+
+        self.output
+    }
+
+    /// Finishes content formatting and returns a report of the layout
+    /// decisions instead of the output string.
+    ///
+    /// The printer makes exactly the same decisions as the [finish](Self::finish)
+    /// function would make for the same input stream. The report describes
+    /// which groups have been broken into multiple lines, the total number of
+    /// the output lines, and the maximum line width.
+    ///
+    /// This function is useful for the code editor features that need to
+    /// examine whether the content fits in line, and for testing
+    /// the formatters.
+    pub fn measure(mut self) -> LayoutReport {
+        self.flush();
+
+        let mut max_width = 0;
+        let mut lines = 0;
+
+        let tab_width = self.step.max(0) as usize;
+
+        for line in self.output.split('\n') {
+            let width = line
+                .chars()
+                .map(|ch| match ch {
+                    '\t' => tab_width,
+                    _ => 1,
+                })
+                .sum::<usize>();
+
+            max_width = max_width.max(width);
+            lines += 1;
+        }
+
+        LayoutReport {
+            groups: self.groups,
+            max_width,
+            lines,
+        }
+    }
+
+    fn flush(&mut self) {
         if !self.scan_stack.is_empty() {
             self.handle_scan_stack();
             self.consume();
         }
 
-        self.output
+        self.align();
+    }
+
+    // Ensures that the next word starts a new line.
+    //
+    // If the last submitted token is a blank token, turns this token into
+    // a hardbreak. Since the blank token's length has not been measured yet,
+    // it is enough to update the token's space and the total length of
+    // the scanned content.
+    fn break_line(&mut self) {
+        let right = self.right;
+
+        let Some(blank) = self.blank_token() else {
+            if !self.after_blank {
+                self.hardbreak();
+            }
+
+            return;
+        };
+
+        let space = SIZE_INFINITY as Length;
+
+        if blank.space == space {
+            return;
+        }
+
+        let extra = (space - blank.space) as LengthSigned;
+
+        blank.space = space;
+        blank.neverbreak = false;
+
+        self.right = right + extra;
+    }
+
+    // Submits the hardbreaks of the blank lines between the `breaks`
+    // consecutive line breaks of the source code.
+    #[inline(always)]
+    fn blank_lines(&mut self, breaks: usize) {
+        for _ in 1..breaks.min(self.blank_lines + 1) {
+            self.hardbreak();
+        }
     }
 
     #[inline(always)]
@@ -425,7 +800,15 @@ impl PrettyPrinter {
         self.scan_stack.push_back(index);
     }
 
-    fn scan_blank(&mut self, blank: Blank) {
+    fn scan_blank(&mut self, mut blank: Blank) {
+        if self.force_break {
+            self.force_break = false;
+            blank.space = SIZE_INFINITY as Length;
+            blank.neverbreak = false;
+        }
+
+        self.after_blank = true;
+
         if self.scan_stack.is_empty() {
             self.left = 1;
             self.right = 1;
@@ -452,6 +835,15 @@ impl PrettyPrinter {
         self.right += space as LengthSigned;
     }
 
+    fn scan_token(&mut self, token: ScanToken) {
+        if self.scan_stack.is_empty() {
+            self.print_token(token);
+            return;
+        }
+
+        self.scan_queue.push_back(ScanEntry { token, size: 0 });
+    }
+
     fn scan_string(&mut self, string: String) {
         if self.scan_stack.is_empty() {
             self.print_string(string);
@@ -470,6 +862,33 @@ impl PrettyPrinter {
         self.handle_stream();
     }
 
+    fn scan_conditional(&mut self, string: String, broken: bool) {
+        if self.force_break {
+            self.hardbreak();
+        }
+
+        self.after_blank = false;
+
+        if self.scan_stack.is_empty() {
+            self.print_conditional(string, broken);
+            return;
+        }
+
+        let size = match broken {
+            true => 0,
+            false => string.len() as LengthSigned,
+        };
+
+        self.scan_queue.push_back(ScanEntry {
+            token: ScanToken::Conditional(string, broken),
+            size,
+        });
+
+        self.right += size;
+
+        self.handle_stream();
+    }
+
     fn handle_stream(&mut self) {
         while self.right - self.left > self.space {
             if let Some(index) = self.scan_stack.front() {
@@ -521,9 +940,16 @@ impl PrettyPrinter {
                     self.print_blank(blank, entry.size);
                 }
 
+                ScanToken::Conditional(string, broken) => {
+                    self.left += entry.size;
+                    self.print_conditional(string, broken);
+                }
+
                 ScanToken::Begin(group) => self.print_begin(&group, entry.size),
 
                 ScanToken::End => self.print_end(),
+
+                token => self.print_token(token),
             }
         }
     }
@@ -574,7 +1000,14 @@ impl PrettyPrinter {
                     }
                 }
 
-                ScanToken::String(..) => system_panic!("Inconsistent scan stack."),
+                ScanToken::String(..)
+                | ScanToken::Conditional(..)
+                | ScanToken::AlignBegin
+                | ScanToken::AlignEnd
+                | ScanToken::WidthBegin(..)
+                | ScanToken::WidthEnd => {
+                    system_panic!("Inconsistent scan stack.")
+                }
             }
         }
     }
@@ -584,6 +1017,7 @@ impl PrettyPrinter {
             self.output.push(match group.mode {
                 Mode::Consistent => '«',
                 Mode::Inconsistent => '‹',
+                Mode::Fill => '⟨',
             });
 
             self.output
@@ -599,7 +1033,14 @@ impl PrettyPrinter {
                 }));
         }
 
-        if size <= self.space {
+        let broken = size > self.space;
+
+        self.groups.push(GroupLayout {
+            line: self.line,
+            broken,
+        });
+
+        if !broken {
             self.print_stack.push(PrintFrame::Inline(group.mode));
             return;
         }
@@ -622,6 +1063,7 @@ impl PrettyPrinter {
             self.output.push(match mode {
                 Mode::Consistent => '»',
                 Mode::Inconsistent => '›',
+                Mode::Fill => '⟩',
             });
         }
     }
@@ -631,7 +1073,7 @@ impl PrettyPrinter {
             || match self.frame() {
                 PrintFrame::Inline(..) => true,
                 PrintFrame::Break(Mode::Consistent, ..) => false,
-                PrintFrame::Break(Mode::Inconsistent, ..) => size <= self.space,
+                PrintFrame::Break(Mode::Inconsistent | Mode::Fill, ..) => size <= self.space,
             };
 
         if self.debug {
@@ -643,6 +1085,7 @@ impl PrettyPrinter {
                 self.print_string(pre)
             }
 
+            self.pending_tabs = 0;
             self.pending_whitespace = blank.space;
             self.space -= blank.space as LengthSigned;
 
@@ -655,10 +1098,152 @@ impl PrettyPrinter {
         }
 
         self.output.push('\n');
+        self.line += 1;
 
         let indent = self.indent + blank.indent;
-        self.pending_whitespace = usize::try_from(indent).unwrap_or(0);
-        self.space = self.inline.max(self.margin - indent);
+        let columns = usize::try_from(indent).unwrap_or(0);
+
+        match self.tabs && self.step > 0 {
+            false => self.pending_whitespace = columns,
+
+            true => {
+                let step = self.step as usize;
+
+                self.pending_tabs = columns / step;
+                self.pending_whitespace = columns % step;
+            }
+        }
+
+        self.space = self.inline.min(self.margin).max(self.margin - indent);
+    }
+
+    fn print_token(&mut self, token: ScanToken) {
+        match token {
+            ScanToken::AlignBegin => {
+                self.print_whitespace();
+
+                self.align_stack.push(AlignCell {
+                    line: self.line,
+                    start: self.output.len(),
+                    end: self.output.len(),
+                    multiline: false,
+                });
+            }
+
+            ScanToken::AlignEnd => {
+                let Some(mut cell) = self.align_stack.pop() else {
+                    system_panic!("Unbalanced alignment cell.");
+                    return;
+                };
+
+                cell.end = self.output.len();
+                cell.multiline = cell.line != self.line;
+
+                self.align_cells.push(cell);
+            }
+
+            ScanToken::WidthBegin(width) => {
+                self.width_stack.push(self.margin);
+                self.space += width - self.margin;
+                self.margin = width;
+            }
+
+            ScanToken::WidthEnd => {
+                let Some(margin) = self.width_stack.pop() else {
+                    system_panic!("Unbalanced width override.");
+                    return;
+                };
+
+                self.space += margin - self.margin;
+                self.margin = margin;
+            }
+
+            _ => system_panic!("Unexpected print token."),
+        }
+    }
+
+    // Pads the alignment cells that start at the same column of
+    // the consecutive lines.
+    fn align(&mut self) {
+        let mut cells = take(&mut self.align_cells);
+
+        cells.sort_by_key(|cell| cell.start);
+
+        let mut padding = Vec::with_capacity(cells.len());
+        let mut run = Vec::<&AlignCell>::new();
+        let mut run_column = 0;
+
+        for cell in &cells {
+            let column = self.column(cell.start);
+
+            let continues = match run.last() {
+                Some(last) => !cell.multiline && cell.line == last.line + 1 && column == run_column,
+                None => false,
+            };
+
+            if !continues {
+                Self::pad_run(&mut padding, &run);
+                run.clear();
+
+                if cell.multiline {
+                    continue;
+                }
+
+                run_column = column;
+            }
+
+            run.push(cell);
+        }
+
+        Self::pad_run(&mut padding, &run);
+
+        if padding.is_empty() {
+            return;
+        }
+
+        let mut output = String::with_capacity(
+            self.output.len() + padding.iter().map(|(_, pad)| *pad).sum::<usize>(),
+        );
+
+        let mut cursor = 0;
+
+        for (offset, pad) in padding {
+            output.push_str(&self.output[cursor..offset]);
+            output.extend(repeat(' ').take(pad));
+            cursor = offset;
+        }
+
+        output.push_str(&self.output[cursor..]);
+
+        self.output = output;
+    }
+
+    fn pad_run(padding: &mut Vec<(usize, usize)>, run: &[&AlignCell]) {
+        if run.len() < 2 {
+            return;
+        }
+
+        let width = run
+            .iter()
+            .map(|cell| cell.end - cell.start)
+            .max()
+            .unwrap_or_default();
+
+        for cell in run {
+            let pad = width - (cell.end - cell.start);
+
+            if pad > 0 {
+                padding.push((cell.end, pad));
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn column(&self, offset: usize) -> usize {
+        match self.output[..offset].rfind('\n') {
+            Some(line_start) => offset - line_start - 1,
+            None => offset,
+        }
     }
 
     fn print_string(&mut self, string: String) {
@@ -669,7 +1254,25 @@ impl PrettyPrinter {
         self.space -= string_length as LengthSigned;
     }
 
+    fn print_conditional(&mut self, string: String, broken: bool) {
+        let frame_broken = match self.frame() {
+            PrintFrame::Inline(..) => false,
+            PrintFrame::Break(..) => true,
+        };
+
+        if frame_broken != broken {
+            return;
+        }
+
+        self.print_string(string);
+    }
+
     fn print_whitespace(&mut self) {
+        if self.pending_tabs > 0 {
+            self.output.extend(repeat('\t').take(self.pending_tabs));
+            self.pending_tabs = 0;
+        }
+
         if self.pending_whitespace == 0 {
             return;
         }
@@ -704,6 +1307,44 @@ impl PrettyPrinter {
     }
 }
 
+/// A report of the [PrettyPrinter] layout decisions.
+///
+/// Returned by the [PrettyPrinter::measure] function.
+#[derive(Clone, Default, PartialEq, Eq, Hash, Debug)]
+#[non_exhaustive]
+pub struct LayoutReport {
+    /// The layout decisions for each word group in the order of the group
+    /// openings in the input stream.
+    ///
+    /// This vector includes all kinds of groups: consistent, inconsistent, and
+    /// fill groups.
+    pub groups: Vec<GroupLayout>,
+
+    /// The maximum number of characters in the output lines, including
+    /// the indentation.
+    ///
+    /// The tab characters are counted as the [PrettyPrintConfig::indent]
+    /// columns.
+    pub max_width: usize,
+
+    /// The total number of the output lines.
+    pub lines: usize,
+}
+
+/// A layout decision of a single word group in the [LayoutReport].
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Debug)]
+#[non_exhaustive]
+pub struct GroupLayout {
+    /// A zero-based number of the output line where the group starts.
+    pub line: usize,
+
+    /// True if the printer has broken the group into multiple lines.
+    ///
+    /// Note that the broken group does not necessarily receive line breaks
+    /// (e.g., when the group does not have blank tokens).
+    pub broken: bool,
+}
+
 type LengthSigned = isize;
 
 const SIZE_INFINITY: LengthSigned = 0x10000;
@@ -718,9 +1359,14 @@ struct Blank {
 
 enum ScanToken {
     String(String),
+    Conditional(String, bool),
     Blank(Blank),
     Begin(Group),
     End,
+    AlignBegin,
+    AlignEnd,
+    WidthBegin(LengthSigned),
+    WidthEnd,
 }
 
 struct Group {
@@ -737,9 +1383,135 @@ enum PrintFrame {
 enum Mode {
     Consistent,
     Inconsistent,
+    Fill,
+}
+
+struct AlignCell {
+    line: usize,
+    start: usize,
+    end: usize,
+    multiline: bool,
 }
 
 struct ScanEntry {
     token: ScanToken,
     size: LengthSigned,
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::format::{PrettyPrintConfig, PrettyPrinter};
+
+    fn print_list(printer: &mut PrettyPrinter, items: &[&str], nested: Option<&[&str]>) {
+        printer.word("[");
+        printer.cbox(1);
+        printer.softbreak();
+
+        for (index, item) in items.iter().enumerate() {
+            if index > 0 {
+                printer.word(",");
+                printer.blank();
+            }
+
+            printer.word(*item);
+        }
+
+        if let Some(nested) = nested {
+            printer.word(",");
+            printer.blank();
+            print_list(printer, nested, None);
+        }
+
+        printer.if_broken(",");
+        printer.softbreak();
+        printer.indent(-1);
+        printer.end();
+        printer.word("]");
+    }
+
+    fn format(margin: u16, items: &[&str], nested: Option<&[&str]>) -> String {
+        let mut config = PrettyPrintConfig::new();
+
+        config.margin = margin;
+        config.inline = margin;
+
+        let mut printer = PrettyPrinter::new(config);
+
+        print_list(&mut printer, items, nested);
+
+        printer.finish()
+    }
+
+    #[test]
+    fn test_conditional_words() {
+        assert_eq!(format(80, &["1", "2"], None), "[1, 2]");
+        assert_eq!(format(5, &["1", "2"], None), "[\n    1,\n    2,\n]");
+
+        assert_eq!(
+            format(12, &["1", "2"], Some(&["3", "4"])),
+            "[\n    1,\n    2,\n    [3, 4],\n]",
+        );
+
+        let mut printer = PrettyPrinter::new(PrettyPrintConfig::new());
+
+        printer.word("{");
+        printer.cbox(1);
+        printer.if_flat(" ");
+        printer.softbreak();
+        printer.word("foo");
+        printer.if_flat(" ");
+        printer.softbreak();
+        printer.indent(-1);
+        printer.end();
+        printer.word("}");
+
+        assert_eq!(printer.finish(), "{ foo }");
+    }
+
+    #[test]
+    fn test_comments() {
+        let mut printer = PrettyPrinter::new(PrettyPrintConfig::new());
+
+        printer.word("[");
+        printer.cbox(1);
+        printer.softbreak();
+        printer.word("1");
+        printer.word(",");
+        printer.print_trailing_comment("// one");
+        printer.blank();
+        printer.print_leading_comment("// two");
+        printer.word("2");
+        printer.softbreak();
+        printer.indent(-1);
+        printer.end();
+        printer.word("]");
+
+        assert_eq!(printer.finish(), "[\n    1, // one\n    // two\n    2\n]");
+
+        let mut printer = PrettyPrinter::new(PrettyPrintConfig::new());
+
+        printer.word("foo");
+        printer.print_leading_comment("// bar");
+        printer.print_leading_comment("// baz");
+        printer.word("qux");
+
+        assert_eq!(printer.finish(), "foo\n// bar\n// baz\nqux");
+
+        let mut printer = PrettyPrinter::new(PrettyPrintConfig::new());
+
+        printer.word("[");
+        printer.fill_begin(1);
+        printer.softbreak();
+        printer.print_leading_comment("// first");
+        printer.word("1");
+        printer.word(",");
+        printer.blank();
+        printer.word("2");
+        printer.softbreak();
+        printer.indent(-1);
+        printer.fill_end();
+        printer.word("]");
+
+        assert_eq!(printer.finish(), "[\n    // first\n    1, 2]");
+    }
+}
@@ -34,17 +34,24 @@
 
 use std::{
     borrow::Cow,
-    fmt::{Display, Formatter},
+    fmt::{self, Display, Formatter},
+    io,
     iter::repeat,
     mem::{replace, take},
 };
 
 use crate::{
-    format::{terminal::Escaped, Style},
+    format::{
+        terminal::{close_link, open_link, Escaped},
+        ColorDepth,
+        Style,
+        TerminalCaps,
+    },
     lexis::{
         Column,
         Length,
         Line,
+        LineIndex,
         Position,
         PositionSpan,
         Site,
@@ -92,6 +99,75 @@ pub struct SnippetConfig {
 
     /// Whether the snippet summary (footer) shall be rendered or disabled.
     pub summary: bool,
+
+    /// Whether the hyperlinks shall be rendered.
+    ///
+    /// The hyperlinks are rendered using OSC 8 escape sequences. Disable this
+    /// option for the terminals that print these sequences literally.
+    ///
+    /// This option has effect only if the [style](Self::style) option is
+    /// enabled.
+    pub links: bool,
+
+    /// The distance between the tab stops that the renderer uses to display
+    /// tab characters.
+    ///
+    /// Each tab character is displayed as the number of spaces that moves
+    /// the following code to the next tab stop of the source code line, which
+    /// is consistent with the [LineIndex::visual_column] function.
+    /// The annotation messages are aligned with respect to the expanded tabs.
+    pub tab_width: usize,
+
+    /// The line number that the renderer displays for the first line of
+    /// the source code.
+    ///
+    /// By default, this value is 1. You can change this value if the rendered
+    /// source code is an excerpt of a larger file (e.g., a code block extracted
+    /// from a markdown file) such that the displayed line numbers would
+    /// correspond to the lines of the larger file.
+    pub first_line_number: Line,
+
+    /// The maximum number of the code lines that the renderer displays for
+    /// each continuous annotated region of the source code.
+    ///
+    /// If the annotated region is longer than this value, the renderer elides
+    /// the middle part of the region, keeping the first and the last lines of
+    /// the region visible.
+    ///
+    /// If set to None, the annotated regions are rendered without truncation.
+    pub max_region_lines: Option<usize>,
+
+    /// The maximum number of the code lines that the renderer displays for
+    /// the entire snippet.
+    ///
+    /// If the snippet is longer than this value, the renderer elides
+    /// the middle part of the snippet. The first and the last annotated
+    /// lines always remain visible regardless of this limit.
+    ///
+    /// If set to None, the snippet is rendered without truncation.
+    pub max_lines: Option<usize>,
+
+    /// Specifies how the renderer presents the annotation messages.
+    ///
+    /// See [AnnotationLayout] for details.
+    pub annotation_layout: AnnotationLayout,
+
+    /// The number of colors that the terminal is capable to display.
+    ///
+    /// The renderer downgrades the colors of the user-defined styles (e.g.,
+    /// the styles of the [Highlighter]) that exceed this depth to the closest
+    /// colors of this depth.
+    ///
+    /// If set to None, the renderer uses the process-wide
+    /// [ColorDepth::global] setting.
+    pub color_depth: Option<ColorDepth>,
+
+    /// The styles of the annotations and the snippet's chrome.
+    ///
+    /// The palette has effect only if the [style](Self::style) option is
+    /// enabled. The renderer [downgrades](Style::downgrade) the palette
+    /// colors according to the [color_depth](Self::color_depth).
+    pub palette: SnippetPalette,
 }
 
 impl Default for SnippetConfig {
@@ -113,6 +189,14 @@ impl SnippetConfig {
             style: true,
             caption: true,
             summary: true,
+            links: true,
+            tab_width: 4,
+            first_line_number: 1,
+            max_region_lines: None,
+            max_lines: None,
+            annotation_layout: AnnotationLayout::Inline,
+            color_depth: None,
+            palette: SnippetPalette::new(),
         }
     }
 
@@ -130,11 +214,52 @@ impl SnippetConfig {
             style: false,
             caption: false,
             summary: false,
+            links: false,
+            tab_width: 4,
+            first_line_number: 1,
+            max_region_lines: None,
+            max_lines: None,
+            annotation_layout: AnnotationLayout::Inline,
+            color_depth: None,
+            palette: SnippetPalette::new(),
+        }
+    }
+
+    /// Returns a snippet configuration with all visual features being enabled
+    /// according to the terminal capabilities `caps`.
+    ///
+    /// In particular, the styles are disabled if the terminal does not
+    /// support colors, and the box drawing characters are rendered using
+    /// ASCII symbols if the terminal does not support Unicode.
+    #[inline(always)]
+    pub fn for_terminal(caps: &TerminalCaps) -> Self {
+        let mut config = Self::verbose();
+
+        config.style = caps.color_depth.is_some();
+        config.color_depth = caps.color_depth;
+        config.ascii_drawing = !caps.unicode;
+        config.links = caps.links;
+
+        config
+    }
+
+    #[inline(always)]
+    pub(super) fn has_links(&self) -> bool {
+        self.style && self.links
+    }
+
+    // Adapts the user-defined style to the rendering capabilities.
+    #[inline(always)]
+    pub(super) fn adapt_style(&self, mut style: Style) -> Style {
+        if !self.has_links() {
+            style = style.no_link();
         }
+
+        style.downgrade(self.color_depth.unwrap_or_else(ColorDepth::global))
     }
 
     #[inline(always)]
-    fn cover(&self) -> usize {
+    pub(super) fn cover(&self) -> usize {
         2
     }
 
@@ -144,30 +269,43 @@ impl SnippetConfig {
     }
 
     #[inline(always)]
-    fn margin(&self) -> Length {
+    pub(super) fn margin(&self) -> Length {
         80
     }
 
     #[inline(always)]
-    fn code_style(&self, dim: bool) -> Style {
+    pub(super) fn code_style(&self, dim: bool) -> Style {
         match self.style && self.dim_code && dim {
             false => Style::default(),
-            true => Style::default().bright_black(),
+            true => self.adapt_style(self.palette.dim),
         }
     }
 
     #[inline(always)]
-    fn annotation_style(&self, priority: AnnotationPriority) -> Style {
+    pub(super) fn annotation_style(&self, priority: AnnotationPriority) -> Style {
         if !self.style {
             return Style::default();
         }
 
-        match priority {
-            AnnotationPriority::Default => Style::default().invert(),
-            AnnotationPriority::Primary => Style::default().invert().red(),
-            AnnotationPriority::Secondary => Style::default().invert().blue(),
-            AnnotationPriority::Note => Style::default().invert().yellow(),
+        self.adapt_style(self.palette.annotation(priority))
+    }
+
+    #[inline(always)]
+    pub(super) fn frame_style(&self) -> Style {
+        if !self.style {
+            return Style::default();
+        }
+
+        self.adapt_style(self.palette.frame)
+    }
+
+    #[inline(always)]
+    pub(super) fn numbers_style(&self) -> Style {
+        if !self.style {
+            return Style::default();
         }
+
+        self.adapt_style(self.palette.numbers)
     }
 
     #[inline(always)]
@@ -187,7 +325,7 @@ impl SnippetConfig {
     }
 
     #[inline(always)]
-    fn etc(&self) -> &'static PrintString<'static> {
+    pub(super) fn etc(&self) -> &'static PrintString<'static> {
         static ASCII: PrintString<'static> = PrintString::borrowed("...");
         static NON_ASCII: PrintString<'static> = PrintString::borrowed("…");
 
@@ -197,15 +335,31 @@ impl SnippetConfig {
         }
     }
 
-    #[inline(always)]
-    fn tab(&self) -> &'static PrintString<'static> {
-        static STRING: PrintString<'static> = PrintString::borrowed("    ");
+    fn omitted(&self, dim: bool, omitted: usize) -> StyleString {
+        let mut string = StyleString::new();
+
+        string.style = self.code_style(dim);
+
+        string.write_sanitized(&PrintString::owned(match omitted {
+            1 => format!("{etc} 1 line omitted {etc}", etc = self.etc().as_str()),
+            _ => format!(
+                "{etc} {omitted} lines omitted {etc}",
+                etc = self.etc().as_str()
+            ),
+        }));
 
-        &STRING
+        string
+    }
+
+    #[inline(always)]
+    pub(super) fn line_number(&self, line: Line) -> Line {
+        line.saturating_add(self.first_line_number)
+            .checked_sub(1)
+            .unwrap_or_default()
     }
 
     #[inline(always)]
-    fn box_vertical(&self) -> &'static PrintString<'static> {
+    pub(super) fn box_vertical(&self) -> &'static PrintString<'static> {
         static ASCII: PrintString<'static> = PrintString::borrowed("|");
         static NON_ASCII: PrintString<'static> = PrintString::borrowed("│");
 
@@ -308,7 +462,7 @@ impl SnippetConfig {
     }
 
     #[inline(always)]
-    fn caption_start(&self) -> &'static PrintString<'static> {
+    pub(super) fn caption_start(&self) -> &'static PrintString<'static> {
         static ASCII: PrintString<'static> = PrintString::borrowed("-[ ");
         static NON_ASCII: PrintString<'static> = PrintString::borrowed("─╢ ");
 
@@ -319,7 +473,7 @@ impl SnippetConfig {
     }
 
     #[inline(always)]
-    fn caption_end(&self) -> &'static PrintString<'static> {
+    pub(super) fn caption_end(&self) -> &'static PrintString<'static> {
         static ASCII: PrintString<'static> = PrintString::borrowed(" ]");
         static NON_ASCII: PrintString<'static> = PrintString::borrowed(" ╟");
         static NON_ASCII_ALONE: PrintString<'static> = PrintString::borrowed(" ║");
@@ -345,7 +499,7 @@ impl SnippetConfig {
     }
 
     #[inline(always)]
-    fn arrow_down_right(&self) -> &'static PrintString<'static> {
+    pub(super) fn arrow_down_right(&self) -> &'static PrintString<'static> {
         static ASCII: PrintString<'static> = PrintString::borrowed("|- ");
         static NON_ASCII: PrintString<'static> = PrintString::borrowed("╰╴ ");
 
@@ -400,13 +554,16 @@ impl<'f> SnippetFormatter<'f> for Formatter<'f> {
         };
 
         Snippet {
-            formatter: self,
+            formatter: Some(self),
             code,
             config,
             caption: PrintString::empty(),
+            caption_link: None,
             summary: PrintString::empty(),
             highlighter: None,
+            span_styles: Vec::new(),
             annotations: Vec::with_capacity(4),
+            related: Vec::new(),
         }
     }
 }
@@ -425,17 +582,60 @@ impl<'f> SnippetFormatter<'f> for Formatter<'f> {
 /// The [finish](Snippet::finish) method finishes the builder and renders
 /// the snippet into the Formatter's output.
 ///
+/// Alternatively, you can create a standalone snippet outside of the
+/// formatting context using the [Snippet::new] constructor, and render it
+/// using the [render_to_string](Snippet::render_to_string),
+/// [render_to_io](Snippet::render_to_io), or [render_to](Snippet::render_to)
+/// functions.
+///
 /// Note that the exact representation of the snippet rendering is not specified
 /// and is a subject to changes and improvements in future minor versions
 /// of this crate.
 pub struct Snippet<'a, 'f, C: SourceCode> {
-    formatter: &'a mut Formatter<'f>,
+    formatter: Option<&'a mut Formatter<'f>>,
     code: &'a C,
     config: &'a SnippetConfig,
     caption: PrintString<'a>,
+    caption_link: Option<PrintString<'a>>,
     summary: PrintString<'a>,
     highlighter: Option<Box<dyn Highlighter<C::Token> + 'a>>,
+    span_styles: Vec<(SiteSpan, Style)>,
     annotations: Vec<Annotation<'a>>,
+    related: Vec<Box<dyn Fn(&SnippetConfig) -> Related + 'a>>,
+}
+
+impl<'a, C: SourceCode> Snippet<'a, 'static, C> {
+    /// Creates a standalone snippet builder that is not bound to
+    /// any [Formatter].
+    ///
+    /// The `code` parameter specifies a [SourceCode] that needs to be printed.
+    ///
+    /// By default, the snippet uses [minimal](SnippetConfig::minimal)
+    /// rendering configuration.
+    ///
+    /// To render the standalone snippet, use
+    /// the [render_to_string](Snippet::render_to_string),
+    /// [render_to_io](Snippet::render_to_io), or [render_to](Snippet::render_to)
+    /// functions. The [finish](Snippet::finish) function of the standalone
+    /// snippet returns an error, because the snippet does not have
+    /// an associated Formatter.
+    #[inline(always)]
+    pub fn new(code: &'a C) -> Self {
+        static MINIMAL: SnippetConfig = SnippetConfig::minimal();
+
+        Self {
+            formatter: None,
+            code,
+            config: &MINIMAL,
+            caption: PrintString::empty(),
+            caption_link: None,
+            summary: PrintString::empty(),
+            highlighter: None,
+            span_styles: Vec::new(),
+            annotations: Vec::with_capacity(4),
+            related: Vec::new(),
+        }
+    }
 }
 
 impl<'a, 'f, C: SourceCode> Snippet<'a, 'f, C> {
@@ -465,6 +665,17 @@ impl<'a, 'f, C: SourceCode> Snippet<'a, 'f, C> {
         self
     }
 
+    /// Turns snippet's header caption into a clickable hyperlink to the `url`.
+    ///
+    /// The hyperlink is rendered only if the [SnippetConfig::links] option is
+    /// enabled.
+    #[inline(always)]
+    pub fn set_caption_link(&mut self, url: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.caption_link = Some(PrintString::from_cow(url.into()));
+
+        self
+    }
+
     /// Sets snippet's footer summary text.
     #[inline(always)]
     pub fn set_summary(&mut self, summary: impl Into<Cow<'a, str>>) -> &mut Self {
@@ -487,6 +698,44 @@ impl<'a, 'f, C: SourceCode> Snippet<'a, 'f, C> {
         self
     }
 
+    /// Sets the styles of the source code spans.
+    ///
+    /// This function is an addition to the [Highlighter] that lets you
+    /// stylize the source code fragments based on the semantic information
+    /// (e.g., to highlight the identifiers that refer to types) rather than
+    /// the individual tokens.
+    ///
+    /// Within the covered spans, these styles take precedence over the token
+    /// styles of the [Highlighter], but the [annotations](Self::annotate)
+    /// take precedence over these styles.
+    ///
+    /// If the spans overlap, the style of the span that comes later in
+    /// the `styles` iterator wins.
+    ///
+    /// Each call of this function replaces the previously set span styles.
+    ///
+    /// **Panic**
+    ///
+    /// Panics if any span is not valid for the snippet's source code.
+    pub fn set_span_styles(
+        &mut self,
+        styles: impl IntoIterator<Item = (impl ToSpan, Style)>,
+    ) -> &mut Self {
+        self.span_styles.clear();
+
+        for (span, style) in styles {
+            let span = match span.to_site_span(self.code) {
+                Some(span) => span,
+
+                None => panic!("Invalid style span."),
+            };
+
+            self.span_styles.push((span, style));
+        }
+
+        self
+    }
+
     /// Adds an annotation to the source code.
     ///
     /// Annotations are the [spans](ToSpan) of source code that you want
@@ -498,9 +747,10 @@ impl<'a, 'f, C: SourceCode> Snippet<'a, 'f, C> {
     /// The `priority` parameter specifies the importance of the annotation.
     ///
     /// The `message` parameter specifies a message that will be shown near the
-    /// annotated span. This parameter can be omitted (set to an empty string),
-    /// but the message string must be one line (it should not contain
-    /// `\n` chars).
+    /// annotated span. This parameter can be omitted (set to an empty string).
+    /// The message can span several lines (delimited by `\n`). In this case,
+    /// the renderer stacks the continuation lines under the first line of
+    /// the message, aligned to its text.
     ///
     /// When the snippet has annotations, the renderer will only show the source
     /// code lines where the annotations are present, plus a few lines
@@ -508,7 +758,7 @@ impl<'a, 'f, C: SourceCode> Snippet<'a, 'f, C> {
     ///
     /// **Panic**
     ///
-    /// Panics if the message has `\n` characters.
+    /// Panics if the span is not valid for the snippet's source code.
     pub fn annotate(
         &mut self,
         span: impl ToSpan,
@@ -517,10 +767,6 @@ impl<'a, 'f, C: SourceCode> Snippet<'a, 'f, C> {
     ) -> &mut Self {
         let message = message.into();
 
-        if message.contains('\n') {
-            panic!("Multiline annotation messages not supported.");
-        }
-
         let span = match span.to_site_span(self.code) {
             Some(span) => span,
 
@@ -531,39 +777,236 @@ impl<'a, 'f, C: SourceCode> Snippet<'a, 'f, C> {
             span,
             priority,
             message: PrintString::from_cow(message),
+            link: None,
         });
 
         self
     }
 
+    /// Adds an annotation to the source code with a message that links to
+    /// the `url`.
+    ///
+    /// This function is similar to the [annotate](Self::annotate) function,
+    /// except that the annotation message is rendered as a clickable
+    /// hyperlink if the [SnippetConfig::links] option is enabled.
+    ///
+    /// **Panic**
+    ///
+    /// Panics if the span is not valid for the snippet's source code.
+    pub fn annotate_with_link(
+        &mut self,
+        span: impl ToSpan,
+        priority: AnnotationPriority,
+        message: impl Into<Cow<'a, str>>,
+        url: impl Into<Cow<'a, str>>,
+    ) -> &mut Self {
+        self.annotate(span, priority, message);
+
+        if let Some(annotation) = self.annotations.last_mut() {
+            annotation.link = Some(PrintString::from_cow(url.into()));
+        }
+
+        self
+    }
+
+    /// Adds a related location from another source code document.
+    ///
+    /// Related locations are useful for the diagnostics that refer to
+    /// several places, such as "the previous definition was here".
+    ///
+    /// The renderer displays each related location as a numbered block after
+    /// the main code and before the summary. The block consists of a header
+    /// line with the number of the location, the [name](crate::arena::Id::name)
+    /// of the `code` document, and the span's start position, followed by
+    /// the annotated lines of the `code` with a few surrounding lines.
+    /// The related blocks share the frame and the width of the main snippet.
+    ///
+    /// The numbers of the related locations start from 1 in the order of
+    /// this function calls, so the snippet's [summary](Self::set_summary)
+    /// can refer to them (e.g., "see [1]").
+    ///
+    /// The `code` parameter specifies a [SourceCode] of the related location.
+    /// It may or may not be the snippet's own source code.
+    ///
+    /// The `span`, `priority`, and `message` parameters have the same meaning
+    /// as in the [annotate](Self::annotate) function.
+    ///
+    /// **Panic**
+    ///
+    /// Panics if the span is not valid for the `code`.
+    pub fn related<R: SourceCode>(
+        &mut self,
+        code: &'a R,
+        span: impl ToSpan,
+        priority: AnnotationPriority,
+        message: impl Into<Cow<'a, str>>,
+    ) -> &mut Self {
+        let message = message.into();
+
+        let span = match span.to_site_span(code) {
+            Some(span) => span,
+
+            None => panic!("Invalid related span."),
+        };
+
+        let position = match span.start.to_position(code) {
+            Some(position) => position,
+
+            // Safety: Site spans are always valid to resolve.
+            None => unsafe { ld_unreachable!("Invalid site span.") },
+        };
+
+        let name = code.id().name();
+
+        let header = match name.is_empty() {
+            true => position.to_string(),
+            false => format!("{name}:{position}"),
+        };
+
+        let marker = PrintString::owned(format!("[{}]", self.related.len() + 1));
+
+        self.related.push(Box::new(move |config| {
+            let mut config = *config;
+
+            config.annotation_layout = AnnotationLayout::Inline;
+            config.first_line_number = 1;
+
+            let mut snippet = Snippet::new(code);
+
+            snippet
+                .set_config(&config)
+                .annotate(span.clone(), priority, message.clone());
+
+            let (cover, lines, _) = snippet.scan();
+
+            let mut rows = snippet.plan(true, lines);
+
+            if let Some(max_lines) = config.max_region_lines {
+                rows = PrintRow::truncate_regions(rows, max_lines);
+            }
+
+            let mut string = StyleString::new();
+
+            string.style = config.annotation_style(priority).no_emphasis();
+            string.write_sanitized(&marker);
+
+            string.style = Style::new();
+            string.write_blanks(1);
+            string.write_sanitized(&PrintString::owned(header.clone()));
+
+            Related {
+                config,
+                header: string,
+                last_line: cover.end.line,
+                rows,
+            }
+        }));
+
+        self
+    }
+
     /// Finishes the snippet builder and renders the snippet into
     /// the Formatter's output.
     ///
     /// This function returns a format result with any format errors that may
     /// occur during interactions with the Formatter. Normally, this function
     /// returns an Ok result.
+    ///
+    /// If the snippet was created using the [Snippet::new] constructor,
+    /// the snippet does not have an associated Formatter, and this function
+    /// returns an error.
     pub fn finish(&mut self) -> std::fmt::Result {
+        let Some(formatter) = self.formatter.take() else {
+            return Err(fmt::Error);
+        };
+
+        let result = self.render_to(formatter);
+
+        self.formatter = Some(formatter);
+
+        result
+    }
+
+    /// Renders the snippet into a String.
+    ///
+    /// The output is the same as the output of the [finish](Self::finish)
+    /// function, but the function does not require a Formatter.
+    pub fn render_to_string(&mut self) -> String {
+        let mut output = String::new();
+
+        if self.render_to(&mut output).is_err() {
+            // Safety: Writing into a String is infallible.
+            unsafe { ld_unreachable!("String write failure.") }
+        }
+
+        output
+    }
+
+    /// Renders the snippet into the `output` byte stream.
+    ///
+    /// The output is the same as the output of the [finish](Self::finish)
+    /// function, but the function does not require a Formatter.
+    ///
+    /// This function returns an error if the `output` stream fails to write.
+    pub fn render_to_io(&mut self, output: &mut impl io::Write) -> io::Result<()> {
+        output.write_all(self.render_to_string().as_bytes())
+    }
+
+    /// Renders the snippet into an arbitrary text `output` sink.
+    ///
+    /// The output is the same as the output of the [finish](Self::finish)
+    /// function, but the function does not require a Formatter.
+    ///
+    /// This function returns a format result with any format errors that may
+    /// occur during interactions with the `output` sink.
+    pub fn render_to(&mut self, output: &mut dyn fmt::Write) -> std::fmt::Result {
         // PREPARE
 
-        let (cover, mut lines) = self.scan();
+        let (cover, lines, legend) = self.scan();
 
-        let mut code_length = 0;
+        let dim = !self.annotations.is_empty();
 
-        for print_line in &mut lines {
-            for string in &print_line.before {
-                code_length = code_length.max(string.length);
-            }
+        let mut rows = self.plan(dim, lines);
+
+        if let Some(max_lines) = self.config.max_region_lines {
+            rows = PrintRow::truncate_regions(rows, max_lines);
+        }
+
+        if let Some(max_lines) = self.config.max_lines {
+            rows = PrintRow::truncate_total(rows, max_lines);
+        }
 
-            code_length = code_length.max(print_line.code.length);
+        let related = self
+            .related
+            .iter()
+            .map(|related| related(self.config))
+            .collect::<Vec<_>>();
+
+        let mut code_length = PrintRow::length(self.config, dim, &rows);
 
-            for string in &print_line.after {
+        for footnote in &legend {
+            code_length = code_length.max(footnote.string.length);
+
+            for string in &footnote.continuation {
                 code_length = code_length.max(string.length);
             }
         }
 
+        for related in &related {
+            code_length = code_length.max(related.header.length).max(PrintRow::length(
+                self.config,
+                true,
+                &related.rows,
+            ));
+        }
+
         let caption = match self.config.caption {
             false => StyleString::empty(),
-            true => StyleString::from_str(self.config, self.caption.as_str()),
+            true => StyleString::from_linked_str(
+                self.config,
+                self.caption.as_str(),
+                self.caption_link.as_ref(),
+            ),
         };
 
         let summary = match self.config.summary {
@@ -596,8 +1039,13 @@ impl<'a, 'f, C: SourceCode> Snippet<'a, 'f, C> {
             }
         }
 
-        let numbers_length = (cover.end.line.checked_ilog10().unwrap_or(0) as usize + 1)
-            .max(self.config.etc().length);
+        let last_line = related
+            .iter()
+            .map(|related| related.last_line)
+            .fold(self.config.line_number(cover.end.line), Line::max);
+
+        let numbers_length =
+            (last_line.checked_ilog10().unwrap_or(0) as usize + 1).max(self.config.etc().length);
 
         let mut margin: usize = self.config.margin();
 
@@ -615,7 +1063,6 @@ impl<'a, 'f, C: SourceCode> Snippet<'a, 'f, C> {
 
         // RENDER
 
-        let dim = !self.annotations.is_empty();
         let has_caption = caption.length > 0;
         let has_summary = !summary.is_empty();
         let mut is_first = true;
@@ -624,9 +1071,94 @@ impl<'a, 'f, C: SourceCode> Snippet<'a, 'f, C> {
             StyleString::start(is_first)
                 .with_header_blank(self.config, numbers_length)
                 .with_caption(self.config, code_length, caption)
-                .end(&mut is_first, self.formatter)?;
+                .end(&mut is_first, output)?;
+        }
+
+        PrintRow::render(
+            self.config,
+            dim,
+            rows,
+            numbers_length,
+            code_length,
+            has_caption,
+            has_summary,
+            &mut is_first,
+            output,
+        )?;
+
+        for footnote in legend {
+            for string in [footnote.string].into_iter().chain(footnote.continuation) {
+                StyleString::start(is_first)
+                    .with_header_blank(self.config, numbers_length)
+                    .with_code(
+                        self.config,
+                        dim,
+                        has_caption,
+                        has_summary,
+                        code_length,
+                        string,
+                    )
+                    .end(&mut is_first, output)?;
+            }
+        }
+
+        for related in related {
+            StyleString::start(is_first)
+                .with_header_blank(self.config, numbers_length)
+                .with_delimiter(self.config, code_length)
+                .end(&mut is_first, output)?;
+
+            StyleString::start(is_first)
+                .with_header_blank(self.config, numbers_length)
+                .with_code(
+                    self.config,
+                    true,
+                    has_caption,
+                    has_summary,
+                    code_length,
+                    related.header,
+                )
+                .end(&mut is_first, output)?;
+
+            PrintRow::render(
+                &related.config,
+                true,
+                related.rows,
+                numbers_length,
+                code_length,
+                has_caption,
+                has_summary,
+                &mut is_first,
+                output,
+            )?;
+        }
+
+        if has_summary {
+            StyleString::start(is_first)
+                .with_header_blank(self.config, numbers_length)
+                .with_delimiter(self.config, code_length)
+                .end(&mut is_first, output)?;
+
+            for summary in summary {
+                StyleString::start(is_first)
+                    .with_header_blank(self.config, numbers_length)
+                    .with_summary(self.config, code_length, summary)
+                    .end(&mut is_first, output)?;
+            }
+        }
+
+        if self.config.draw_frame || has_caption || has_summary {
+            StyleString::start(is_first)
+                .with_header_blank(self.config, numbers_length)
+                .with_footer(self.config, code_length)
+                .end(&mut is_first, output)?;
         }
 
+        Ok(())
+    }
+
+    fn plan(&self, dim: bool, lines: Vec<ScanLine>) -> Vec<PrintRow> {
+        let mut rows = Vec::with_capacity(lines.len());
         let mut back_distance: usize = 0;
         let mut skip = false;
         let mut distances = Vec::with_capacity(lines.len());
@@ -646,53 +1178,13 @@ impl<'a, 'f, C: SourceCode> Snippet<'a, 'f, C> {
             if line.annotated || !self.config.show_numbers || !dim {
                 back_distance = 0;
                 skip = false;
+                rows.push(PrintRow::Line(line));
+                continue;
+            }
 
-                for string in line.before {
-                    StyleString::start(is_first)
-                        .with_header_blank(self.config, numbers_length)
-                        .with_code(
-                            self.config,
-                            dim,
-                            has_caption,
-                            has_summary,
-                            code_length,
-                            string,
-                        )
-                        .end(&mut is_first, self.formatter)?;
-                }
+            back_distance += 1;
 
-                StyleString::start(is_first)
-                    .with_header_number(self.config, numbers_length, line.number)
-                    .with_code(
-                        self.config,
-                        dim,
-                        has_caption,
-                        has_summary,
-                        code_length,
-                        line.code,
-                    )
-                    .end(&mut is_first, self.formatter)?;
-
-                for string in line.after {
-                    StyleString::start(is_first)
-                        .with_header_blank(self.config, numbers_length)
-                        .with_code(
-                            self.config,
-                            dim,
-                            has_caption,
-                            has_summary,
-                            code_length,
-                            string,
-                        )
-                        .end(&mut is_first, self.formatter)?;
-                }
-
-                continue;
-            }
-
-            back_distance += 1;
-
-            let min_distance = forward_distance.min(back_distance);
+            let min_distance = forward_distance.min(back_distance);
 
             if skip {
                 match min_distance <= self.config.cover() {
@@ -703,53 +1195,73 @@ impl<'a, 'f, C: SourceCode> Snippet<'a, 'f, C> {
 
             if min_distance > self.config.cover() {
                 if forward_distance >= self.config.continuation() {
-                    StyleString::start(is_first)
-                        .with_header_etc(self.config, numbers_length)
-                        .with_code_blank(self.config, dim, has_caption, has_summary, code_length)
-                        .end(&mut is_first, self.formatter)?;
+                    rows.push(PrintRow::Etc);
                     skip = true;
                     continue;
                 }
             }
 
-            StyleString::start(is_first)
-                .with_header_number(self.config, numbers_length, line.number)
-                .with_code(
-                    self.config,
-                    dim,
-                    has_caption,
-                    has_summary,
-                    code_length,
-                    line.code,
-                )
-                .end(&mut is_first, self.formatter)?;
+            rows.push(PrintRow::Line(line));
         }
 
-        if has_summary {
-            StyleString::start(is_first)
-                .with_header_blank(self.config, numbers_length)
-                .with_delimiter(self.config, code_length)
-                .end(&mut is_first, self.formatter)?;
+        rows
+    }
 
-            for summary in summary {
-                StyleString::start(is_first)
-                    .with_header_blank(self.config, numbers_length)
-                    .with_summary(self.config, code_length, summary)
-                    .end(&mut is_first, self.formatter)?;
-            }
+    // Returns the style of the last span style that covers the `site`.
+    #[inline]
+    fn span_style(&self, site: Site) -> Option<Style> {
+        if !self.config.style {
+            return None;
         }
 
-        if self.config.draw_frame || has_caption || has_summary {
-            StyleString::start(is_first)
-                .with_header_blank(self.config, numbers_length)
-                .with_footer(self.config, code_length)
-                .end(&mut is_first, self.formatter)?;
-        }
+        let (_, style) = self
+            .span_styles
+            .iter()
+            .rev()
+            .find(|(span, _)| span.start <= site && site < span.end)?;
 
-        Ok(())
+        Some(self.config.adapt_style(*style))
     }
 
-    fn scan(&mut self) -> (PositionSpan, Vec<ScanLine>) {
+    // Returns a vector of flags that specify which annotations shall be
+    // rendered as footnotes.
+    fn footnotes(&self) -> Vec<bool> {
+        let threshold = match self.config.annotation_layout {
+            AnnotationLayout::Inline => return vec![false; self.annotations.len()],
+            AnnotationLayout::Footnotes => return vec![true; self.annotations.len()],
+            AnnotationLayout::Mixed(threshold) => threshold,
+        };
+
+        let lines = self
+            .annotations
+            .iter()
+            .map(|annotation| {
+                if annotation.message.is_empty() {
+                    return None;
+                }
+
+                match annotation.span.start.to_position(self.code) {
+                    Some(position) => Some(position.line),
+
+                    // Safety: Sites are always valid to resolve.
+                    None => unsafe { ld_unreachable!("Invalid annotation site.") },
+                }
+            })
+            .collect::<Vec<_>>();
+
+        lines
+            .iter()
+            .map(|line| {
+                let Some(line) = line else {
+                    return false;
+                };
+
+                lines.iter().filter(|probe| *probe == &Some(*line)).count() > threshold
+            })
+            .collect()
+    }
+
+    fn scan(&mut self) -> (PositionSpan, Vec<ScanLine>, Vec<Footnote>) {
         struct Scanner {
             position_cover: PositionSpan,
             site_cover: SiteSpan,
@@ -758,6 +1270,8 @@ impl<'a, 'f, C: SourceCode> Snippet<'a, 'f, C> {
             line: Line,
             pending: ScanLine,
             stack: Vec<usize>,
+            footnotes: Vec<bool>,
+            legend: Vec<Footnote>,
         }
 
         impl Scanner {
@@ -813,6 +1327,7 @@ impl<'a, 'f, C: SourceCode> Snippet<'a, 'f, C> {
                 let line = position_cover.start.line;
                 let pending = ScanLine::new(line);
                 let stack = Vec::with_capacity(snippet.annotations.len());
+                let footnotes = snippet.footnotes();
                 let site_cover = match position_cover.to_site_span(snippet.code) {
                     Some(span) => span,
                     // Safety: Position spans are always valid to resolve.
@@ -827,7 +1342,31 @@ impl<'a, 'f, C: SourceCode> Snippet<'a, 'f, C> {
                     line,
                     pending,
                     stack,
+                    footnotes,
+                    legend: Vec::new(),
+                }
+            }
+
+            fn annotate(&mut self, config: &SnippetConfig, index: usize, annotation: &Annotation) {
+                if annotation.message.is_empty() {
+                    return;
+                }
+
+                if !self.footnotes.get(index).copied().unwrap_or_default() {
+                    self.pending
+                        .messages
+                        .push(annotation.message(config, self.pending.code.length));
+
+                    return;
                 }
+
+                let marker = PrintString::owned(format!("[{}]", self.legend.len() + 1));
+
+                self.pending.code.style =
+                    config.annotation_style(annotation.priority).no_emphasis();
+                self.pending.code.write_sanitized(&marker);
+
+                self.legend.push(annotation.footnote(config, &marker));
             }
 
             #[inline(always)]
@@ -863,7 +1402,9 @@ impl<'a, 'f, C: SourceCode> Snippet<'a, 'f, C> {
 
             if self.config.style {
                 if let Some(highlighter) = &mut self.highlighter {
-                    token_style = highlighter.token_style(dim, chunk.token);
+                    token_style = highlighter
+                        .token_style(dim, chunk.token)
+                        .map(|style| self.config.adapt_style(style));
                 }
             }
 
@@ -886,12 +1427,7 @@ impl<'a, 'f, C: SourceCode> Snippet<'a, 'f, C> {
                         continue;
                     }
 
-                    if !annotation.message.is_empty() {
-                        scanner
-                            .pending
-                            .messages
-                            .push(annotation.message(self.config, scanner.pending.code.length));
-                    }
+                    scanner.annotate(self.config, index, annotation);
 
                     match annotation.span.end == site {
                         true => {
@@ -914,120 +1450,468 @@ impl<'a, 'f, C: SourceCode> Snippet<'a, 'f, C> {
                 }
 
                 scanner.pending.code.style = match scanner.top() {
-                    None => token_style.unwrap_or(code_style),
+                    None => match self.span_style(site) {
+                        Some(style) => style,
+                        None => token_style.unwrap_or(code_style),
+                    },
+
+                    Some(top) => {
+                        let priority = match self.annotations.get(top) {
+                            Some(annotation) => annotation.priority,
+
+                            // Safety: Annotation stack is well-formed.
+                            None => unsafe { ld_unreachable!("Missing annotation.") },
+                        };
+
+                        scanner.pending.annotated = true;
+
+                        self.config.annotation_style(priority)
+                    }
+                };
+
+                scanner.empty = false;
+
+                match ch {
+                    '\n' => scanner.submit(self.config),
+                    '\t' => scanner.pending.write_tab(self.config),
+                    _ => scanner.pending.write_code_char(self.config, ch),
+                }
+
+                site += 1;
+
+                if site >= scanner.site_cover.end {
+                    break 'chunk_loop;
+                }
+            }
+        }
+
+        for (index, annotation) in self.annotations.iter().enumerate() {
+            if annotation.span.start != scanner.site_cover.end {
+                continue;
+            }
+
+            if !annotation.span.is_empty() {
+                continue;
+            }
+
+            scanner.annotate(self.config, index, annotation);
+
+            scanner.pending.annotated = true;
+            scanner.pending.code.style = self.config.annotation_style(annotation.priority);
+            scanner.pending.code.write_placeholder(self.config);
+
+            scanner.empty = false;
+        }
+
+        if !scanner.empty {
+            scanner.submit(self.config);
+        }
+
+        scanner
+            .legend
+            .sort_by_key(|footnote| footnote.priority.order());
+
+        (scanner.position_cover, scanner.buffer, scanner.legend)
+    }
+}
+
+/// A syntax highlighter for the [Snippet]'s source code.
+///
+/// The Snippet's renderer sequentially feeds tokens to the Highlighter, and
+/// the Highlighter decides how this token should be stylized.
+///
+/// The implementor could be a stateful object that makes decisions based on
+/// the prior token's context.
+pub trait Highlighter<T: Token> {
+    /// Returns a [style](Style) of the token.
+    ///
+    /// The `dim` flag specifies if the token style is assumed to be dimmed,
+    /// with lesser contrast than usual. In other words, if the end user
+    /// attention should not be focused on this token.
+    ///
+    /// The `token` parameter specifies a token that needs to be stylized.
+    ///
+    /// This function can take into account the previous tokens based on the
+    /// implementor's inner state, and the function can change the inner state
+    /// for the future token styles.
+    ///
+    /// If the function returns None, the token style is left to the renderer's
+    /// defaults.
+    fn token_style(&mut self, dim: bool, token: T) -> Option<Style>;
+}
+
+/// A degree of importance of the [Snippet]'s annotation.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AnnotationPriority {
+    /// An annotation without extra user attention priority.
+    #[default]
+    Default,
+
+    /// An annotation with the highest user attention priority.
+    Primary,
+
+    /// An annotation with moderate user attention priority.
+    Secondary,
+
+    /// An annotation with the lowest user attention priority.
+    Note,
+}
+
+/// A set of styles that the [Snippet] renderer uses to display
+/// the annotations and the snippet's chrome.
+///
+/// The palette is a part of the [SnippetConfig]. You can override
+/// the default palette to adapt the snippet to the user's color theme
+/// (e.g., a high-contrast theme).
+///
+/// This structure is non-exhaustive; new styles may be added in future minor
+/// versions of this crate.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[non_exhaustive]
+pub struct SnippetPalette {
+    /// The style of the [Default](AnnotationPriority::Default) annotations.
+    pub default: Style,
+
+    /// The style of the [Primary](AnnotationPriority::Primary) annotations.
+    pub primary: Style,
+
+    /// The style of the [Secondary](AnnotationPriority::Secondary) annotations.
+    pub secondary: Style,
+
+    /// The style of the [Note](AnnotationPriority::Note) annotations.
+    pub note: Style,
+
+    /// The style of the boxed frame and the caption delimiters.
+    pub frame: Style,
+
+    /// The style of the line numbers gutter.
+    pub numbers: Style,
+
+    /// The style of the non-annotated code when the
+    /// [dim_code](SnippetConfig::dim_code) option is enabled.
+    pub dim: Style,
+}
+
+impl Default for SnippetPalette {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SnippetPalette {
+    /// Returns the default palette.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            default: Style::new().invert(),
+            primary: Style::new().invert().red(),
+            secondary: Style::new().invert().blue(),
+            note: Style::new().invert().yellow(),
+            frame: Style::new(),
+            numbers: Style::new(),
+            dim: Style::new().bright_black(),
+        }
+    }
+
+    /// Returns the style of the annotations with the specified `priority`.
+    #[inline(always)]
+    pub fn annotation(&self, priority: AnnotationPriority) -> Style {
+        match priority {
+            AnnotationPriority::Default => self.default,
+            AnnotationPriority::Primary => self.primary,
+            AnnotationPriority::Secondary => self.secondary,
+            AnnotationPriority::Note => self.note,
+        }
+    }
+}
+
+/// A presentation mode of the [Snippet]'s annotation messages.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum AnnotationLayout {
+    /// The annotation messages are rendered inline, under the annotated
+    /// code lines.
+    #[default]
+    Inline,
+
+    /// The annotated code fragments are marked with the numbered markers
+    /// (`[1]`, `[2]`, etc.), and the annotation messages are listed in
+    /// the legend section after the code content in the order of their
+    /// priorities.
+    ///
+    /// This mode is useful when several annotations land on the same dense
+    /// code line such that their inline messages would overlap.
+    Footnotes,
+
+    /// The annotation messages are rendered inline, except for the code lines
+    /// that have more than the specified number of annotation messages.
+    ///
+    /// The messages of such lines are rendered as
+    /// [footnotes](AnnotationLayout::Footnotes).
+    Mixed(usize),
+}
+
+impl AnnotationPriority {
+    #[inline(always)]
+    pub(super) fn order(&self) -> usize {
+        match self {
+            Self::Primary => 1,
+            Self::Secondary => 2,
+            Self::Note => 3,
+            Self::Default => 4,
+        }
+    }
+}
+
+enum PrintRow {
+    Line(ScanLine),
+    Etc,
+    Omitted(usize),
+}
+
+impl PrintRow {
+    // Returns the maximum length of the code strings of the rows.
+    fn length(config: &SnippetConfig, dim: bool, rows: &[Self]) -> Length {
+        let mut length = 0;
+
+        for row in rows {
+            match row {
+                PrintRow::Line(print_line) => {
+                    for string in &print_line.before {
+                        length = length.max(string.length);
+                    }
+
+                    length = length.max(print_line.code.length);
+
+                    for string in &print_line.after {
+                        length = length.max(string.length);
+                    }
+                }
+
+                PrintRow::Etc => (),
+
+                PrintRow::Omitted(omitted) => {
+                    length = length.max(config.omitted(dim, *omitted).length);
+                }
+            }
+        }
+
+        length
+    }
+
+    fn render(
+        config: &SnippetConfig,
+        dim: bool,
+        rows: Vec<Self>,
+        numbers_length: Length,
+        code_length: Length,
+        has_caption: bool,
+        has_summary: bool,
+        is_first: &mut bool,
+        output: &mut dyn fmt::Write,
+    ) -> std::fmt::Result {
+        for row in rows {
+            let line = match row {
+                PrintRow::Line(line) => line,
+
+                PrintRow::Etc => {
+                    StyleString::start(*is_first)
+                        .with_header_etc(config, numbers_length)
+                        .with_code_blank(config, dim, has_caption, has_summary, code_length)
+                        .end(is_first, output)?;
+
+                    continue;
+                }
+
+                PrintRow::Omitted(omitted) => {
+                    StyleString::start(*is_first)
+                        .with_header_etc(config, numbers_length)
+                        .with_code(
+                            config,
+                            dim,
+                            has_caption,
+                            has_summary,
+                            code_length,
+                            config.omitted(dim, omitted),
+                        )
+                        .end(is_first, output)?;
+
+                    continue;
+                }
+            };
+
+            for string in line.before {
+                StyleString::start(*is_first)
+                    .with_header_blank(config, numbers_length)
+                    .with_code(config, dim, has_caption, has_summary, code_length, string)
+                    .end(is_first, output)?;
+            }
+
+            StyleString::start(*is_first)
+                .with_header_number(config, numbers_length, line.number)
+                .with_code(
+                    config,
+                    dim,
+                    has_caption,
+                    has_summary,
+                    code_length,
+                    line.code,
+                )
+                .end(is_first, output)?;
+
+            for string in line.after {
+                StyleString::start(*is_first)
+                    .with_header_blank(config, numbers_length)
+                    .with_code(config, dim, has_caption, has_summary, code_length, string)
+                    .end(is_first, output)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Elides the middle parts of the long runs of annotated lines keeping
+    // the first and the last lines of each run.
+    fn truncate_regions(rows: Vec<Self>, max_lines: usize) -> Vec<Self> {
+        let max_lines = max_lines.max(2);
+
+        let mut result = Vec::with_capacity(rows.len());
+        let mut region = Vec::new();
+
+        for row in rows {
+            if let Self::Line(line) = &row {
+                if line.annotated {
+                    region.push(row);
+                    continue;
+                }
+            }
+
+            Self::flush_region(&mut result, &mut region, max_lines);
+
+            result.push(row);
+        }
+
+        Self::flush_region(&mut result, &mut region, max_lines);
 
-                    Some(top) => {
-                        let priority = match self.annotations.get(top) {
-                            Some(annotation) => annotation.priority,
+        result
+    }
 
-                            // Safety: Annotation stack is well-formed.
-                            None => unsafe { ld_unreachable!("Missing annotation.") },
-                        };
+    fn flush_region(result: &mut Vec<Self>, region: &mut Vec<Self>, max_lines: usize) {
+        let length = region.len();
 
-                        scanner.pending.annotated = true;
+        if length <= max_lines {
+            result.append(region);
+            return;
+        }
 
-                        self.config.annotation_style(priority)
-                    }
-                };
+        let head = (max_lines + 1) / 2;
 
-                scanner.empty = false;
+        let mut rows = region.drain(..);
 
-                match ch {
-                    '\n' => scanner.submit(self.config),
-                    '\t' => scanner.pending.code.write_tab(self.config),
-                    _ => scanner.pending.code.write_code_char(self.config, ch),
-                }
+        result.extend(rows.by_ref().take(head));
+        result.push(Self::Omitted(length - max_lines));
+        result.extend(rows.skip(length - max_lines));
+    }
 
-                site += 1;
+    // Elides the middle part of the snippet such that the number of
+    // the rendered code lines does not exceed `max_lines`, except for
+    // the first and the last annotated lines that always remain visible.
+    fn truncate_total(rows: Vec<Self>, max_lines: usize) -> Vec<Self> {
+        let total = rows
+            .iter()
+            .filter(|row| matches!(row, Self::Line(..)))
+            .count();
 
-                if site >= scanner.site_cover.end {
-                    break 'chunk_loop;
-                }
-            }
+        if total <= max_lines {
+            return rows;
         }
 
-        for annotation in self.annotations.iter() {
-            if annotation.span.start != scanner.site_cover.end {
-                continue;
-            }
+        let head = (max_lines + 1) / 2;
+        let tail = max_lines / 2;
 
-            if !annotation.span.is_empty() {
+        let mut first_annotated = None;
+        let mut last_annotated = None;
+        let mut index = 0;
+
+        for row in &rows {
+            let Self::Line(line) = row else {
                 continue;
-            }
+            };
 
-            if !annotation.message.is_empty() {
-                scanner
-                    .pending
-                    .messages
-                    .push(annotation.message(self.config, scanner.pending.code.length));
-            }
+            if line.annotated {
+                if first_annotated.is_none() {
+                    first_annotated = Some(index);
+                }
 
-            scanner.pending.annotated = true;
-            scanner.pending.code.style = self.config.annotation_style(annotation.priority);
-            scanner.pending.code.write_placeholder(self.config);
+                last_annotated = Some(index);
+            }
 
-            scanner.empty = false;
+            index += 1;
         }
 
-        if !scanner.empty {
-            scanner.submit(self.config);
-        }
+        let mut result = Vec::with_capacity(max_lines + 4);
+        let mut gap = Vec::new();
+        let mut gap_omitted = 0;
+        let mut dropped = false;
+        let mut last_number = None;
+        let mut index = 0;
 
-        (scanner.position_cover, scanner.buffer)
-    }
-}
+        for row in rows {
+            let line = match row {
+                Self::Line(line) => line,
 
-/// A syntax highlighter for the [Snippet]'s source code.
-///
-/// The Snippet's renderer sequentially feeds tokens to the Highlighter, and
-/// the Highlighter decides how this token should be stylized.
-///
-/// The implementor could be a stateful object that makes decisions based on
-/// the prior token's context.
-pub trait Highlighter<T: Token> {
-    /// Returns a [style](Style) of the token.
-    ///
-    /// The `dim` flag specifies if the token style is assumed to be dimmed,
-    /// with lesser contrast than usual. In other words, if the end user
-    /// attention should not be focused on this token.
-    ///
-    /// The `token` parameter specifies a token that needs to be stylized.
-    ///
-    /// This function can take into account the previous tokens based on the
-    /// implementor's inner state, and the function can change the inner state
-    /// for the future token styles.
-    ///
-    /// If the function returns None, the token style is left to the renderer's
-    /// defaults.
-    fn token_style(&mut self, dim: bool, token: T) -> Option<Style>;
-}
+                Self::Etc => {
+                    gap.push(Self::Etc);
+                    continue;
+                }
 
-/// A degree of importance of the [Snippet]'s annotation.
-#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
-pub enum AnnotationPriority {
-    /// An annotation without extra user attention priority.
-    #[default]
-    Default,
+                Self::Omitted(omitted) => {
+                    gap_omitted += omitted;
+                    gap.push(Self::Omitted(omitted));
+                    continue;
+                }
+            };
 
-    /// An annotation with the highest user attention priority.
-    Primary,
+            let keep = index < head
+                || index >= total - tail
+                || Some(index) == first_annotated
+                || Some(index) == last_annotated;
 
-    /// An annotation with moderate user attention priority.
-    Secondary,
+            index += 1;
 
-    /// An annotation with the lowest user attention priority.
-    Note,
-}
+            if !keep {
+                dropped = true;
+                gap_omitted += 1;
+                gap.clear();
+                continue;
+            }
 
-impl AnnotationPriority {
-    #[inline(always)]
-    fn order(&self) -> usize {
-        match self {
-            Self::Primary => 1,
-            Self::Secondary => 2,
-            Self::Note => 3,
-            Self::Default => 4,
+            match dropped {
+                false => result.append(&mut gap),
+
+                true => {
+                    let omitted = match last_number {
+                        Some(last_number) => line.number - last_number - 1,
+                        None => gap_omitted,
+                    };
+
+                    result.push(Self::Omitted(omitted));
+                    gap.clear();
+                }
+            }
+
+            dropped = false;
+            gap_omitted = 0;
+            last_number = Some(line.number);
+            result.push(Self::Line(line));
+        }
+
+        match dropped {
+            false => result.append(&mut gap),
+            true => result.push(Self::Omitted(gap_omitted)),
         }
+
+        result
     }
 }
 
@@ -1035,6 +1919,10 @@ struct ScanLine {
     number: Line,
     before: Vec<StyleString>,
     code: StyleString,
+    // The visual offset of the source code characters written to the `code`
+    // string so far. Unlike the `code` length, this offset does not include
+    // the footnote markers and the placeholders.
+    column: Column,
     after: Vec<StyleString>,
     messages: Vec<Message>,
     annotated: bool,
@@ -1047,12 +1935,25 @@ impl ScanLine {
             number,
             before: Vec::new(),
             code: StyleString::new(),
+            column: 0,
             after: Vec::new(),
             messages: Vec::new(),
             annotated: false,
         }
     }
 
+    #[inline(always)]
+    fn write_code_char(&mut self, config: &SnippetConfig, ch: char) {
+        self.code.write_code_char(config, ch);
+        self.column += 1;
+    }
+
+    #[inline(always)]
+    fn write_tab(&mut self, config: &SnippetConfig) {
+        self.code.write_tab(config, self.column);
+        self.column = LineIndex::tab_stop(self.column, config.tab_width);
+    }
+
     fn expand(&mut self, config: &SnippetConfig) {
         enum Segment {
             End(Message),
@@ -1060,6 +1961,10 @@ impl ScanLine {
                 offset: Column,
                 priority: AnnotationPriority,
             },
+            Continuation {
+                offset: Column,
+                string: StyleString,
+            },
         }
 
         impl Segment {
@@ -1068,10 +1973,19 @@ impl ScanLine {
                 match self {
                     Self::Middle { offset, .. } => Message::span_down_middle(config, *offset),
                     Self::End(message) => message.span_down_right(config),
+                    Self::Continuation { offset, string } => *offset..(*offset + string.length),
                 }
             }
         }
 
+        // The continuation lines of the multiline messages that have been
+        // rendered already. The lines of each item are stored in reverse
+        // order.
+        struct Tail {
+            offset: Column,
+            lines: Vec<StyleString>,
+        }
+
         let mut pending = take(&mut self.messages)
             .into_iter()
             .map(|message| Some(message))
@@ -1109,13 +2023,34 @@ impl ScanLine {
 
                 self.before.push(string);
 
+                for line in message.continuation {
+                    let mut string = StyleString::new();
+
+                    string.style = config.code_style(true);
+                    string.write_blanks(message.offset);
+
+                    let drawing = config.arrow_down_middle();
+
+                    string.style = config.annotation_style(message.priority).no_emphasis();
+                    string.write_sanitized(drawing);
+
+                    string.style = config.code_style(true);
+                    string.write_blanks(config.arrow_up_right().length - drawing.length);
+
+                    string.style = Style::new();
+                    string.append(line);
+
+                    self.before.push(string);
+                }
+
                 break;
             }
         }
 
         let mut segments = Vec::<Segment>::with_capacity(left);
+        let mut tails = Vec::<Tail>::new();
 
-        while left > 0 {
+        while left > 0 || !tails.is_empty() {
             'outer: for pending in pending.iter_mut().rev() {
                 let message = match pending {
                     Some(pending) => pending,
@@ -1182,6 +2117,53 @@ impl ScanLine {
                 );
             }
 
+            // The continuation lines have the lowest precedence: if the next
+            // line of the tail collides with other segments, the line waits
+            // for the next row. Unlike the arrows, the continuation text
+            // requires at least one blank column on both sides to stay
+            // distinguishable from the neighboring messages.
+            'outer: for tail in tails.iter_mut() {
+                let Some(line) = tail.lines.last() else {
+                    continue;
+                };
+
+                let mut index = 0;
+
+                let span = tail.offset.saturating_sub(1)..(tail.offset + line.length + 1);
+
+                for probe in segments.iter() {
+                    let probe_span = probe.span(config);
+
+                    if span.end > probe_span.start && span.start < probe_span.end {
+                        continue 'outer;
+                    }
+
+                    if span.start >= probe_span.end {
+                        index += 1;
+                        continue;
+                    }
+
+                    break;
+                }
+
+                let string = match tail.lines.pop() {
+                    Some(string) => string,
+
+                    // Safety: Existence checked above.
+                    None => unsafe { ld_unreachable!("Missing tail line.") },
+                };
+
+                segments.insert(
+                    index,
+                    Segment::Continuation {
+                        offset: tail.offset,
+                        string,
+                    },
+                );
+            }
+
+            tails.retain(|tail| !tail.lines.is_empty());
+
             let mut string = StyleString::new();
 
             let mut cursor = 0;
@@ -1210,8 +2192,33 @@ impl ScanLine {
 
                         string.append(message.string);
 
+                        if !message.continuation.is_empty() {
+                            let mut lines = message.continuation;
+
+                            lines.reverse();
+
+                            tails.push(Tail {
+                                offset: span.start + config.arrow_down_right().length,
+                                lines,
+                            });
+                        }
+
                         cursor = span.end;
                     }
+
+                    Segment::Continuation {
+                        offset,
+                        string: line,
+                    } => {
+                        string.style = config.code_style(true);
+                        string.write_blanks(offset - cursor);
+
+                        string.style = Style::new();
+
+                        cursor = offset + line.length;
+
+                        string.append(line);
+                    }
                 }
             }
 
@@ -1224,23 +2231,82 @@ struct Annotation<'a> {
     span: SiteSpan,
     priority: AnnotationPriority,
     message: PrintString<'a>,
+    link: Option<PrintString<'a>>,
 }
 
 impl<'a> Annotation<'a> {
     #[inline(always)]
     fn message(&self, config: &SnippetConfig, offset: Column) -> Message {
+        let mut lines = self.lines(config);
+
+        let string = lines.next().unwrap_or_else(StyleString::empty);
+
         Message {
             offset,
             priority: self.priority,
-            string: StyleString::from_str(config, self.message.as_str()),
+            string,
+            continuation: lines.collect(),
+        }
+    }
+
+    #[inline(always)]
+    fn footnote(&self, config: &SnippetConfig, marker: &PrintString) -> Footnote {
+        let mut string = StyleString::new();
+
+        string.style = config.annotation_style(self.priority).no_emphasis();
+        string.write_sanitized(marker);
+
+        let mut lines = self.lines(config);
+
+        string.style = Style::new();
+        string.write_blanks(1);
+        string.append(lines.next().unwrap_or_else(StyleString::empty));
+
+        let continuation = lines
+            .map(|line| {
+                let mut string = StyleString::new();
+
+                string.write_blanks(marker.length + 1);
+                string.append(line);
+
+                string
+            })
+            .collect();
+
+        Footnote {
+            priority: self.priority,
+            string,
+            continuation,
         }
     }
+
+    #[inline(always)]
+    fn lines<'b>(&'b self, config: &'b SnippetConfig) -> impl Iterator<Item = StyleString> + 'b {
+        self.message
+            .as_str()
+            .lines()
+            .map(|line| StyleString::from_linked_str(config, line, self.link.as_ref()))
+    }
+}
+
+struct Related {
+    config: SnippetConfig,
+    header: StyleString,
+    last_line: Line,
+    rows: Vec<PrintRow>,
+}
+
+struct Footnote {
+    priority: AnnotationPriority,
+    string: StyleString,
+    continuation: Vec<StyleString>,
 }
 
 struct Message {
     offset: Column,
     priority: AnnotationPriority,
     string: StyleString,
+    continuation: Vec<StyleString>,
 }
 
 impl Message {
@@ -1267,12 +2333,12 @@ impl Message {
     }
 }
 
-struct StyleString {
+pub(super) struct StyleString {
     text: String,
-    length: Length,
+    pub(super) length: Length,
     start_style: Style,
     end_style: Style,
-    style: Style,
+    pub(super) style: Style,
 }
 
 impl Display for StyleString {
@@ -1284,7 +2350,7 @@ impl Display for StyleString {
 
 impl StyleString {
     #[inline(always)]
-    fn new() -> Self {
+    pub(super) fn new() -> Self {
         Self {
             text: String::with_capacity(120),
             length: 0,
@@ -1295,7 +2361,7 @@ impl StyleString {
     }
 
     #[inline(always)]
-    fn empty() -> Self {
+    pub(super) fn empty() -> Self {
         Self {
             text: String::new(),
             length: 0,
@@ -1305,7 +2371,7 @@ impl StyleString {
         }
     }
 
-    fn from_str(config: &SnippetConfig, source: impl AsRef<str>) -> Self {
+    pub(super) fn from_str(config: &SnippetConfig, source: impl AsRef<str>) -> Self {
         let source = source.as_ref();
 
         let mut target = Self::new();
@@ -1319,6 +2385,12 @@ impl StyleString {
                         continue;
                     }
                 }
+
+                Escaped::OSC => {
+                    if !config.has_links() {
+                        continue;
+                    }
+                }
                 _ => target.length += chunk.length,
             }
 
@@ -1329,7 +2401,26 @@ impl StyleString {
     }
 
     #[inline]
-    fn start(is_first: bool) -> Self {
+    fn from_linked_str(
+        config: &SnippetConfig,
+        source: impl AsRef<str>,
+        link: Option<&PrintString>,
+    ) -> Self {
+        let Some(link) = link.filter(|_| config.has_links()) else {
+            return Self::from_str(config, source);
+        };
+
+        let mut linked = String::new();
+
+        open_link(link.as_str(), &mut linked);
+        linked.push_str(source.as_ref());
+        close_link(&mut linked);
+
+        Self::from_str(config, linked)
+    }
+
+    #[inline]
+    pub(super) fn start(is_first: bool) -> Self {
         let mut string = Self::new();
 
         if !is_first {
@@ -1339,24 +2430,34 @@ impl StyleString {
         string
     }
 
-    fn with_header_blank(self, config: &SnippetConfig, alignment: Length) -> Self {
+    pub(super) fn with_header_blank(self, config: &SnippetConfig, alignment: Length) -> Self {
         self.with_header(config, alignment, "")
     }
 
-    fn with_header_etc(self, config: &SnippetConfig, alignment: Length) -> Self {
+    pub(super) fn with_header_etc(self, config: &SnippetConfig, alignment: Length) -> Self {
         self.with_header(config, alignment, config.etc().as_str())
     }
 
     fn with_header_number(self, config: &SnippetConfig, alignment: Length, number: Line) -> Self {
-        self.with_header(config, alignment, number.to_string().as_str())
+        self.with_header(
+            config,
+            alignment,
+            config.line_number(number).to_string().as_str(),
+        )
     }
 
     #[inline]
-    fn with_header(mut self, config: &SnippetConfig, alignment: Length, text: &str) -> Self {
+    pub(super) fn with_header(
+        mut self,
+        config: &SnippetConfig,
+        alignment: Length,
+        text: &str,
+    ) -> Self {
         if !config.show_numbers {
             return self;
         }
 
+        self.style = config.numbers_style();
         self.write_blanks(1);
         self.write_sanitized(&PrintString::owned(format!("{: >1$}", text, alignment)));
         self.write_blanks(1);
@@ -1364,12 +2465,15 @@ impl StyleString {
         self
     }
 
-    fn with_caption(
+    pub(super) fn with_caption(
         mut self,
         config: &SnippetConfig,
         mut alignment: Length,
         caption: Self,
     ) -> Self {
+        let frame_style = config.frame_style();
+
+        self.style = frame_style;
         self.write_sanitized(config.box_top_left());
         self.write_sanitized(config.box_horizontal());
 
@@ -1382,6 +2486,7 @@ impl StyleString {
             alignment -= caption.length;
             self.append(caption);
 
+            self.style = frame_style;
             alignment -= config.caption_end().length;
             self.write_sanitized(config.caption_end());
         }
@@ -1402,7 +2507,7 @@ impl StyleString {
         self
     }
 
-    fn with_code(
+    pub(super) fn with_code(
         mut self,
         config: &SnippetConfig,
         dim: bool,
@@ -1414,6 +2519,7 @@ impl StyleString {
         let code_style = config.code_style(dim);
 
         if config.draw_frame || config.show_numbers || has_caption || has_summary {
+            self.style = config.frame_style();
             self.write_sanitized(config.box_vertical());
             self.style = code_style;
             self.write_blanks(1);
@@ -1426,14 +2532,14 @@ impl StyleString {
             self.style = code_style;
             self.write_blanks(alignment + 1);
 
-            self.style = Style::new();
+            self.style = config.frame_style();
             self.write_sanitized(config.box_vertical());
         }
 
         self
     }
 
-    fn with_code_blank(
+    pub(super) fn with_code_blank(
         mut self,
         config: &SnippetConfig,
         dim: bool,
@@ -1442,6 +2548,7 @@ impl StyleString {
         alignment: Length,
     ) -> Self {
         if config.draw_frame || config.show_numbers || has_caption || has_summary {
+            self.style = config.frame_style();
             self.write_sanitized(config.box_vertical());
         }
 
@@ -1449,7 +2556,7 @@ impl StyleString {
             self.style = config.code_style(dim);
             self.write_blanks(alignment + 2);
 
-            self.style = Style::new();
+            self.style = config.frame_style();
             self.write_sanitized(config.box_vertical());
         }
 
@@ -1460,7 +2567,9 @@ impl StyleString {
         self
     }
 
-    fn with_delimiter(mut self, config: &SnippetConfig, alignment: Length) -> Self {
+    pub(super) fn with_delimiter(mut self, config: &SnippetConfig, alignment: Length) -> Self {
+        self.style = config.frame_style();
+
         match config.draw_frame {
             true => {
                 self.write_sanitized(config.box_middle_left());
@@ -1476,27 +2585,32 @@ impl StyleString {
         self
     }
 
-    fn with_summary(
+    pub(super) fn with_summary(
         mut self,
         config: &SnippetConfig,
         mut alignment: Length,
         summary: Self,
     ) -> Self {
+        self.style = config.frame_style();
         self.write_sanitized(config.box_vertical());
+        self.style = Style::new();
         self.write_blanks(1);
 
         alignment -= summary.length;
         self.append(summary);
 
         if config.draw_frame {
+            self.style = Style::new();
             self.write_blanks(alignment + 1);
+            self.style = config.frame_style();
             self.write_sanitized(config.box_vertical());
         }
 
         self
     }
 
-    fn with_footer(mut self, config: &SnippetConfig, alignment: Length) -> Self {
+    pub(super) fn with_footer(mut self, config: &SnippetConfig, alignment: Length) -> Self {
+        self.style = config.frame_style();
         self.write_sanitized(config.box_bottom_left());
         self.write_sanitized(config.box_horizontal());
 
@@ -1515,7 +2629,11 @@ impl StyleString {
     }
 
     #[inline]
-    fn end(mut self, is_first: &mut bool, formatter: &mut Formatter) -> std::fmt::Result {
+    pub(super) fn end(
+        mut self,
+        is_first: &mut bool,
+        output: &mut dyn fmt::Write,
+    ) -> std::fmt::Result {
         if self.length == 0 {
             return Ok(());
         }
@@ -1525,11 +2643,11 @@ impl StyleString {
         self.style = Style::new();
         self.submit_style();
 
-        Display::fmt(&self, formatter)
+        output.write_str(&self.text)
     }
 
     #[inline(always)]
-    fn write_code_char(&mut self, config: &SnippetConfig, mut ch: char) {
+    pub(super) fn write_code_char(&mut self, config: &SnippetConfig, mut ch: char) {
         if ch.is_control() {
             ch = config.control();
         }
@@ -1541,7 +2659,7 @@ impl StyleString {
     }
 
     #[inline(always)]
-    fn write_sanitized(&mut self, string: &PrintString) {
+    pub(super) fn write_sanitized(&mut self, string: &PrintString) {
         self.submit_style();
 
         self.text.push_str(string.as_str());
@@ -1564,20 +2682,22 @@ impl StyleString {
     }
 
     #[inline(always)]
-    fn write_placeholder(&mut self, config: &SnippetConfig) {
+    pub(super) fn write_placeholder(&mut self, config: &SnippetConfig) {
         self.submit_style();
 
         self.text.push(config.placeholder());
         self.length += 1;
     }
 
+    // Writes the blanks of the tab character that starts at the visual
+    // `offset` of the source code line.
     #[inline(always)]
-    fn write_tab(&mut self, config: &SnippetConfig) {
-        self.write_sanitized(config.tab());
+    pub(super) fn write_tab(&mut self, config: &SnippetConfig, offset: Column) {
+        self.write_blanks(LineIndex::tab_stop(offset, config.tab_width) - offset);
     }
 
     #[inline(always)]
-    fn write_blanks(&mut self, count: Length) {
+    pub(super) fn write_blanks(&mut self, count: Length) {
         if count == 0 {
             return;
         }
@@ -1588,7 +2708,7 @@ impl StyleString {
         self.length += count;
     }
 
-    fn append(&mut self, other: StyleString) {
+    pub(super) fn append(&mut self, other: StyleString) {
         self.style = other.start_style;
 
         if !other.text.is_empty() {
@@ -1617,14 +2737,14 @@ impl StyleString {
     }
 }
 
-struct PrintString<'a> {
+pub(super) struct PrintString<'a> {
     string: Cow<'a, str>,
-    length: Length,
+    pub(super) length: Length,
 }
 
 impl<'a> PrintString<'a> {
     #[inline(always)]
-    const fn empty() -> Self {
+    pub(super) const fn empty() -> Self {
         Self {
             string: Cow::Borrowed(""),
             length: 0,
@@ -1632,7 +2752,7 @@ impl<'a> PrintString<'a> {
     }
 
     #[inline(always)]
-    fn owned(string: String) -> Self {
+    pub(super) fn owned(string: String) -> Self {
         Self {
             length: string.chars().count(),
             string: Cow::from(string),
@@ -1640,7 +2760,7 @@ impl<'a> PrintString<'a> {
     }
 
     #[inline(always)]
-    const fn borrowed(string: &'a str) -> Self {
+    pub(super) const fn borrowed(string: &'a str) -> Self {
         Self {
             length: Self::length_of(string.as_bytes()),
             string: Cow::Borrowed(string),
@@ -1648,7 +2768,7 @@ impl<'a> PrintString<'a> {
     }
 
     #[inline(always)]
-    fn from_cow(string: Cow<'a, str>) -> Self {
+    pub(super) fn from_cow(string: Cow<'a, str>) -> Self {
         Self {
             length: string.chars().count(),
             string,
@@ -1656,12 +2776,12 @@ impl<'a> PrintString<'a> {
     }
 
     #[inline(always)]
-    fn as_str(&self) -> &str {
+    pub(super) fn as_str(&self) -> &str {
         self.string.as_ref()
     }
 
     #[inline(always)]
-    fn is_empty(&self) -> bool {
+    pub(super) fn is_empty(&self) -> bool {
         self.string.is_empty()
     }
 
@@ -1699,7 +2819,15 @@ impl<'a> PrintString<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::format::{snippet::StyleString, SnippetConfig, Style, TerminalString};
+    use crate::format::{
+        snippet::StyleString,
+        Color,
+        ColorDepth,
+        SnippetConfig,
+        Style,
+        TerminalCaps,
+        TerminalString,
+    };
 
     #[test]
     fn test_csi_detection() {
@@ -1727,4 +2855,112 @@ mod tests {
         assert_eq!(string.length, 11);
         assert_eq!(string.text.len(), 11);
     }
+
+    #[test]
+    fn test_osc_detection() {
+        let link = "world".apply(Style::new().link("https://example.com"));
+
+        assert_eq!(link, "\x1B]8;;https://example.com\x1B\\world\x1B]8;;\x1B\\");
+        assert_eq!(link.sanitize(), "world");
+
+        let string = StyleString::from_str(&SnippetConfig::verbose(), &format!("hello {link}"));
+        assert_eq!(string.length, 11);
+        assert_ne!(string.text.len(), 11);
+
+        let mut config = SnippetConfig::verbose();
+
+        config.links = false;
+
+        let string = StyleString::from_str(&config, &format!("hello {link}"));
+        assert_eq!(string.length, 11);
+        assert_eq!(string.text, "hello world");
+
+        let mut text = String::new();
+
+        Style::change(&Style::new(), &Style::new().link("a"), &mut text);
+        Style::change(&Style::new().link("a"), &Style::new().link("b"), &mut text);
+        Style::change(&Style::new().link("b"), &Style::new(), &mut text);
+
+        assert_eq!(
+            text,
+            "\x1B]8;;a\x1B\\\x1B]8;;\x1B\\\x1B]8;;b\x1B\\\x1B]8;;\x1B\\",
+        );
+    }
+
+    #[test]
+    fn test_color_depth() {
+        let style = Style::new().fg_rgb(255, 0, 0).bg_indexed(1);
+
+        assert_eq!(style.downgrade(ColorDepth::TrueColor), style);
+        assert_eq!(
+            style.downgrade(ColorDepth::Ansi256),
+            Style::new().fg_indexed(196).bg_indexed(1),
+        );
+        assert_eq!(
+            style.downgrade(ColorDepth::Basic),
+            Style::new().bright_red().bg(Color::Red),
+        );
+        assert_eq!(
+            Style::new()
+                .fg_rgb(128, 128, 128)
+                .downgrade(ColorDepth::Ansi256),
+            Style::new().fg_indexed(244),
+        );
+
+        let mut string = StyleString::new();
+
+        string.style = Style::new().fg_rgb(10, 20, 30);
+        string.write_blanks(1);
+        string.style = Style::new().fg_rgb(10, 20, 30);
+        string.write_blanks(1);
+
+        assert_eq!(string.text.matches("\x1B[38;2;10;20;30m").count(), 1);
+    }
+
+    #[test]
+    fn test_terminal_caps() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+
+        let caps = TerminalCaps::from_env(
+            true,
+            env(&[("TERM", "xterm-256color"), ("LANG", "en_US.UTF-8")]),
+        );
+
+        assert_eq!(caps.color_depth, Some(ColorDepth::Ansi256));
+        assert!(caps.unicode);
+        assert!(!caps.links);
+
+        let caps = TerminalCaps::from_env(
+            true,
+            env(&[
+                ("TERM", "xterm-256color"),
+                ("COLORTERM", "truecolor"),
+                ("TERM_PROGRAM", "WezTerm"),
+            ]),
+        );
+
+        assert_eq!(caps.color_depth, Some(ColorDepth::TrueColor));
+        assert!(caps.links);
+
+        let caps =
+            TerminalCaps::from_env(true, env(&[("TERM", "xterm-256color"), ("NO_COLOR", "1")]));
+
+        assert_eq!(caps.color_depth, None);
+        assert!(!SnippetConfig::for_terminal(&caps).style);
+
+        let caps = TerminalCaps::from_env(true, env(&[("TERM", "dumb")]));
+
+        assert_eq!(caps.color_depth, None);
+        assert!(SnippetConfig::for_terminal(&caps).ascii_drawing);
+
+        let caps = TerminalCaps::from_env(false, env(&[("TERM", "xterm")]));
+
+        assert_eq!(caps.color_depth, None);
+    }
 }
@@ -32,7 +32,14 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::cmp::Ordering;
+use std::{
+    cmp::Ordering,
+    env::var,
+    hash::{Hash, Hasher},
+    io::IsTerminal,
+    mem::discriminant,
+    sync::atomic::{AtomicU8, Ordering as AtomicOrdering},
+};
 
 use crate::lexis::{SourceCode, Token, TokenBuffer};
 
@@ -47,20 +54,36 @@ macro_rules! escape {
 /// style sequence.
 ///
 /// In particular, through this object, you can specify text background and
-/// foreground colors, and text emphasis such as bold, italic, underlined or
-/// inverted style.
+/// foreground colors, text emphasis such as bold, italic, underlined or
+/// inverted style, and a hyperlink of the text.
 ///
 /// The Style API implemented as a builder to be used in a call-chain style,
 /// such as each function consumes the instance of this object and returns
 /// a new instance with the applied configuration option.
 ///
 /// Since Style methods are const functions, you can construct and store an
-/// instance of Style in static.
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+/// instance of Style in static:
+///
+/// ```
+/// use lady_deirdre::format::Style;
+///
+/// static KEYWORD: Style = Style::new().bold().blue();
+/// ```
+///
+/// When the `serde` feature of this crate is enabled, the Style implements
+/// Serialize and Deserialize traits using a human-friendly representation
+/// such as `{"fg": "red", "bold": true}`. The basic colors are represented by
+/// their names in snake case (e.g., `"bright_red"`), the indexed colors are
+/// represented by numbers, the true colors are represented by the hex strings
+/// (e.g., `"#ff8000"`), and the RGB and the Grayscale colors are represented
+/// by the `{"rgb": [1.0, 0.5, 0.0]}` and the `{"grayscale": 0.5}` objects
+/// correspondingly. The hyperlinks are not serializable.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub struct Style {
     fg: Option<Color>,
     bg: Option<Color>,
     emphasis: Emphasis,
+    link: Option<&'static str>,
 }
 
 impl Default for Style {
@@ -78,6 +101,7 @@ impl Style {
             fg: None,
             bg: None,
             emphasis: Emphasis::none(),
+            link: None,
         }
     }
 
@@ -253,6 +277,42 @@ impl Style {
         self.fg(Color::Grayscale(shade))
     }
 
+    /// Sets the text foreground color to 24-bit true color.
+    ///
+    /// If the output [color depth](ColorDepth) is lower than the true color,
+    /// the color will be downgraded to the closest color of that depth.
+    #[inline(always)]
+    pub const fn fg_rgb(self, red: u8, green: u8, blue: u8) -> Self {
+        self.fg(Color::TrueColor { red, green, blue })
+    }
+
+    /// Sets the text background color to 24-bit true color.
+    ///
+    /// If the output [color depth](ColorDepth) is lower than the true color,
+    /// the color will be downgraded to the closest color of that depth.
+    #[inline(always)]
+    pub const fn bg_rgb(self, red: u8, green: u8, blue: u8) -> Self {
+        self.bg(Color::TrueColor { red, green, blue })
+    }
+
+    /// Sets the text foreground color to the color of the 256-color palette.
+    ///
+    /// See [Ansi Color Table](https://en.wikipedia.org/wiki/ANSI_escape_code#8-bit)
+    /// for details.
+    #[inline(always)]
+    pub const fn fg_indexed(self, index: u8) -> Self {
+        self.fg(Color::Indexed(index))
+    }
+
+    /// Sets the text background color to the color of the 256-color palette.
+    ///
+    /// See [Ansi Color Table](https://en.wikipedia.org/wiki/ANSI_escape_code#8-bit)
+    /// for details.
+    #[inline(always)]
+    pub const fn bg_indexed(self, index: u8) -> Self {
+        self.bg(Color::Indexed(index))
+    }
+
     /// Enables bold emphasis of the text.
     #[inline(always)]
     pub const fn bold(mut self) -> Self {
@@ -301,7 +361,30 @@ impl Style {
         self
     }
 
+    /// Turns the text into a clickable hyperlink to the `url`.
+    ///
+    /// The hyperlink is rendered using
+    /// the [OSC 8](https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda)
+    /// escape sequences, which are supported by most modern terminals.
+    /// The terminals that do not support these sequences usually ignore them.
+    #[inline(always)]
+    pub const fn link(mut self, url: &'static str) -> Self {
+        self.link = Some(url);
+
+        self
+    }
+
     pub(super) fn change(from: &Self, to: &Self, target: &mut String) {
+        if from.link != to.link {
+            if from.link.is_some() {
+                close_link(target);
+            }
+
+            if let Some(url) = to.link {
+                open_link(url, target);
+            }
+        }
+
         if Emphasis::change(&from.emphasis, &to.emphasis, target) {
             match (&from.fg, &to.fg) {
                 (Some(_), None) => Color::reset_fg(target),
@@ -330,11 +413,179 @@ impl Style {
     }
 
     #[inline(always)]
-    pub(super) fn no_emphasis(mut self) -> Self {
+    pub(super) const fn no_emphasis(mut self) -> Self {
         self.emphasis = Emphasis::none();
 
         self
     }
+
+    #[inline(always)]
+    pub(super) const fn no_link(mut self) -> Self {
+        self.link = None;
+
+        self
+    }
+
+    /// Returns a copy of this style with the colors downgraded to
+    /// the specified color `depth`.
+    #[inline(always)]
+    pub fn downgrade(mut self, depth: ColorDepth) -> Self {
+        self.fg = self.fg.map(|color| color.downgrade(depth));
+        self.bg = self.bg.map(|color| color.downgrade(depth));
+
+        self
+    }
+}
+
+static GLOBAL_COLOR_DEPTH: AtomicU8 = AtomicU8::new(ColorDepth::TrueColor as u8);
+
+/// A number of colors that the terminal is capable to display.
+///
+/// The renderers downgrade the [Style] colors that exceed the configured
+/// depth to the closest colors of this depth.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[repr(u8)]
+pub enum ColorDepth {
+    /// The 16 colors of the 3-bit and 4-bit ANSI palette.
+    Basic = 0,
+
+    /// The 256 colors of the 8-bit ANSI palette.
+    Ansi256 = 1,
+
+    /// The 24-bit true colors.
+    TrueColor = 2,
+}
+
+impl ColorDepth {
+    /// Returns the process-wide color depth.
+    ///
+    /// By default, the process-wide color depth is
+    /// [TrueColor](Self::TrueColor).
+    ///
+    /// The process-wide depth is used by the [TerminalString::apply] function
+    /// and by the [Snippet](crate::format::Snippet) renderers that do not
+    /// specify the depth explicitly in
+    /// the [SnippetConfig::color_depth](crate::format::SnippetConfig::color_depth)
+    /// option.
+    #[inline(always)]
+    pub fn global() -> Self {
+        match GLOBAL_COLOR_DEPTH.load(AtomicOrdering::Relaxed) {
+            0 => Self::Basic,
+            1 => Self::Ansi256,
+            _ => Self::TrueColor,
+        }
+    }
+
+    /// Sets the process-wide color depth.
+    ///
+    /// See [global](Self::global) for details.
+    #[inline(always)]
+    pub fn set_global(depth: Self) {
+        GLOBAL_COLOR_DEPTH.store(depth as u8, AtomicOrdering::Relaxed);
+    }
+}
+
+/// A set of the terminal display capabilities.
+///
+/// You can detect the capabilities of the terminal using the
+/// [detect](Self::detect) function, and then configure the snippets
+/// using the [SnippetConfig::for_terminal](crate::format::SnippetConfig::for_terminal)
+/// function.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct TerminalCaps {
+    /// The number of colors that the terminal is capable to display.
+    ///
+    /// If None, the terminal output should not be stylized at all.
+    pub color_depth: Option<ColorDepth>,
+
+    /// Whether the terminal is capable to display Unicode box drawing
+    /// characters.
+    pub unicode: bool,
+
+    /// Whether the terminal supports OSC 8 hyperlinks.
+    pub links: bool,
+}
+
+impl TerminalCaps {
+    /// Detects the capabilities of the terminal attached to the `stream`
+    /// (e.g., [Stdout](std::io::Stdout)).
+    ///
+    /// The function relies on the environment variables and on whether
+    /// the `stream` is a terminal. In particular, the function disables
+    /// the styles if the stream is not a terminal, if the
+    /// [NO_COLOR](https://no-color.org/) variable is set, or if
+    /// the `TERM` variable is `dumb`.
+    ///
+    /// The detection is heuristic, and the result is not guaranteed to
+    /// be accurate.
+    #[inline(always)]
+    pub fn detect(stream: &impl IsTerminal) -> Self {
+        Self::from_env(stream.is_terminal(), |name| var(name).ok())
+    }
+
+    pub(super) fn from_env(is_terminal: bool, env: impl Fn(&str) -> Option<String>) -> Self {
+        let term = env("TERM").unwrap_or_default();
+        let dumb = term == "dumb";
+        let no_color = env("NO_COLOR").filter(|value| !value.is_empty()).is_some();
+
+        let color_depth = match is_terminal && !dumb && !no_color {
+            false => None,
+
+            true => {
+                let color_term = env("COLORTERM").unwrap_or_default();
+
+                match color_term.as_str() {
+                    "truecolor" | "24bit" => Some(ColorDepth::TrueColor),
+
+                    _ => match term.contains("256color") {
+                        true => Some(ColorDepth::Ansi256),
+                        false => Some(ColorDepth::Basic),
+                    },
+                }
+            }
+        };
+
+        let unicode = match dumb {
+            true => false,
+
+            false => match ["LC_ALL", "LC_CTYPE", "LANG"]
+                .into_iter()
+                .find_map(|name| env(name).filter(|value| !value.is_empty()))
+            {
+                Some(locale) => {
+                    let locale = locale.to_ascii_lowercase();
+
+                    locale.contains("utf-8") || locale.contains("utf8")
+                }
+
+                None => true,
+            },
+        };
+
+        let links = color_depth.is_some() && {
+            let term_program = env("TERM_PROGRAM").unwrap_or_default();
+
+            let vte_version = env("VTE_VERSION")
+                .and_then(|version| version.parse::<u32>().ok())
+                .unwrap_or_default();
+
+            matches!(
+                term_program.as_str(),
+                "iTerm.app" | "WezTerm" | "vscode" | "Hyper" | "ghostty"
+            ) || vte_version >= 5000
+                || env("WT_SESSION").is_some()
+                || env("KITTY_WINDOW_ID").is_some()
+                || term.contains("kitty")
+                || term.contains("alacritty")
+                || term.contains("foot")
+        };
+
+        Self {
+            color_depth,
+            unicode,
+            links,
+        }
+    }
 }
 
 /// An extension of a string with functions that apply or erase
@@ -347,6 +598,7 @@ pub trait TerminalString: AsRef<str> {
     /// that apply the specified `style` at the beginning of the string and
     /// erase these styles in the end of the string.
     fn apply(&self, style: Style) -> String {
+        let style = style.downgrade(ColorDepth::global());
         let source = self.as_ref();
         let mut target = String::with_capacity(source.len() + 20);
 
@@ -360,8 +612,16 @@ pub trait TerminalString: AsRef<str> {
 
         style.emphasis.apply(&mut target);
 
+        if let Some(url) = style.link {
+            open_link(url, &mut target);
+        }
+
         target.push_str(source);
 
+        if style.link.is_some() {
+            close_link(&mut target);
+        }
+
         if style.fg.is_some() || style.bg.is_some() || style.emphasis.is_some() {
             reset_all(&mut target);
         }
@@ -369,8 +629,8 @@ pub trait TerminalString: AsRef<str> {
         target
     }
 
-    /// Returns a new string from this one, removing any valid CSI sequence from
-    /// the string content.
+    /// Returns a new string from this one, removing any valid CSI sequence
+    /// and OSC sequence (such as hyperlinks) from the string content.
     fn sanitize(&self) -> String {
         let mut target = String::with_capacity(self.as_ref().len());
 
@@ -392,7 +652,7 @@ impl<S: AsRef<str>> TerminalString for S {}
 
 /// An ANSI terminal color.
 ///
-/// This object is capable of addressing 3-bit, 4-bit, and 8-bit
+/// This object is capable of addressing 3-bit, 4-bit, 8-bit, and 24-bit
 /// [ANSI colors](https://en.wikipedia.org/wiki/ANSI_escape_code#Colors).
 ///
 /// This enum is a part of the [Style] interface.
@@ -470,28 +730,68 @@ pub enum Color {
     /// the grayscale color will be inferred to be as close to
     /// the floating-point shade as possible.
     Grayscale(f64),
+
+    /// A 8-bit color addressed by the index in the 256-color palette.
+    Indexed(u8),
+
+    /// A 24-bit true color.
+    TrueColor {
+        /// A red component of the true color.
+        red: u8,
+
+        /// A green component of the true color.
+        green: u8,
+
+        /// A blue component of the true color.
+        blue: u8,
+    },
+}
+
+impl Hash for Color {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        discriminant(self).hash(state);
+
+        match self {
+            Self::RGB { red, green, blue } => {
+                red.to_bits().hash(state);
+                green.to_bits().hash(state);
+                blue.to_bits().hash(state);
+            }
+
+            Self::Grayscale(shade) => shade.to_bits().hash(state),
+
+            Self::Indexed(index) => index.hash(state),
+
+            Self::TrueColor { red, green, blue } => {
+                red.hash(state);
+                green.hash(state);
+                blue.hash(state);
+            }
+
+            _ => (),
+        }
+    }
 }
 
 impl PartialEq for Color {
+    #[inline(always)]
     fn eq(&self, other: &Self) -> bool {
-        match (self, other) {
-            (Self::Black, Self::Black) => true,
-            (Self::Red, Self::Red) => true,
-            (Self::Green, Self::Green) => true,
-            (Self::Yellow, Self::Yellow) => true,
-            (Self::Blue, Self::Blue) => true,
-            (Self::Magenta, Self::Magenta) => true,
-            (Self::Cyan, Self::Cyan) => true,
-            (Self::White, Self::White) => true,
-            (Self::BrightBlack, Self::BrightBlack) => true,
-            (Self::BrightRed, Self::BrightRed) => true,
-            (Self::BrightGreen, Self::BrightGreen) => true,
-            (Self::BrightYellow, Self::BrightYellow) => true,
-            (Self::BrightBlue, Self::BrightBlue) => true,
-            (Self::BrightMagenta, Self::BrightMagenta) => true,
-            (Self::BrightCyan, Self::BrightCyan) => true,
-            (Self::BrightWhite, Self::BrightWhite) => true,
+        self.cmp(other) == Ordering::Equal
+    }
+}
 
+impl Eq for Color {}
+
+impl PartialOrd for Color {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Color {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
             (
                 Self::RGB {
                     red: this_red,
@@ -503,29 +803,204 @@ impl PartialEq for Color {
                     green: other_green,
                     blue: other_blue,
                 },
-            ) => match (
-                this_red.partial_cmp(other_red),
-                this_green.partial_cmp(other_green),
-                this_blue.partial_cmp(other_blue),
-            ) {
-                (Some(Ordering::Equal), Some(Ordering::Equal), Some(Ordering::Equal))
-                | (None, None, None) => true,
-                _ => false,
-            },
+            ) => this_red
+                .total_cmp(other_red)
+                .then_with(|| this_green.total_cmp(other_green))
+                .then_with(|| this_blue.total_cmp(other_blue)),
 
-            (Self::Grayscale(this), Self::Grayscale(other)) => match this.partial_cmp(other) {
-                Some(Ordering::Equal) | None => true,
-                _ => false,
-            },
+            (Self::Grayscale(this), Self::Grayscale(other)) => this.total_cmp(other),
+
+            (Self::Indexed(this), Self::Indexed(other)) => this.cmp(other),
 
-            _ => false,
+            (
+                Self::TrueColor {
+                    red: this_red,
+                    green: this_green,
+                    blue: this_blue,
+                },
+                Self::TrueColor {
+                    red: other_red,
+                    green: other_green,
+                    blue: other_blue,
+                },
+            ) => (this_red, this_green, this_blue).cmp(&(other_red, other_green, other_blue)),
+
+            _ => self.rank().cmp(&other.rank()),
         }
     }
 }
 
-impl Eq for Color {}
-
 impl Color {
+    // The position of the color kind in the Ord order.
+    #[inline(always)]
+    const fn rank(&self) -> u8 {
+        match self {
+            Self::Black => 0,
+            Self::Red => 1,
+            Self::Green => 2,
+            Self::Yellow => 3,
+            Self::Blue => 4,
+            Self::Magenta => 5,
+            Self::Cyan => 6,
+            Self::White => 7,
+            Self::BrightBlack => 8,
+            Self::BrightRed => 9,
+            Self::BrightGreen => 10,
+            Self::BrightYellow => 11,
+            Self::BrightBlue => 12,
+            Self::BrightMagenta => 13,
+            Self::BrightCyan => 14,
+            Self::BrightWhite => 15,
+            Self::RGB { .. } => 16,
+            Self::Grayscale(..) => 17,
+            Self::Indexed(..) => 18,
+            Self::TrueColor { .. } => 19,
+        }
+    }
+
+    const BASIC: [Self; 16] = [
+        Self::Black,
+        Self::Red,
+        Self::Green,
+        Self::Yellow,
+        Self::Blue,
+        Self::Magenta,
+        Self::Cyan,
+        Self::White,
+        Self::BrightBlack,
+        Self::BrightRed,
+        Self::BrightGreen,
+        Self::BrightYellow,
+        Self::BrightBlue,
+        Self::BrightMagenta,
+        Self::BrightCyan,
+        Self::BrightWhite,
+    ];
+
+    /// Returns the closest color of the specified color `depth`.
+    ///
+    /// If this color does not exceed the `depth`, the function returns
+    /// this color as it is.
+    pub fn downgrade(self, depth: ColorDepth) -> Self {
+        match (depth, self) {
+            (ColorDepth::TrueColor, _) => self,
+
+            (_, Self::TrueColor { red, green, blue }) => {
+                Self::Indexed(Self::rgb_to_indexed(red, green, blue)).downgrade(depth)
+            }
+
+            (ColorDepth::Ansi256, _) => self,
+
+            (ColorDepth::Basic, Self::RGB { .. } | Self::Grayscale(..) | Self::Indexed(..)) => {
+                let Some(index) = self.index() else {
+                    return self;
+                };
+
+                if let Some(basic) = Self::BASIC.get(index as usize) {
+                    return *basic;
+                }
+
+                let (red, green, blue) = Self::indexed_to_rgb(index);
+
+                Self::rgb_to_basic(red, green, blue)
+            }
+
+            (ColorDepth::Basic, _) => self,
+        }
+    }
+
+    fn index(&self) -> Option<u8> {
+        match self {
+            Self::RGB { red, green, blue } => {
+                let red = ((red.clamp(0.0, 1.0) * 5.0) as u8).min(5);
+                let green = ((green.clamp(0.0, 1.0) * 5.0) as u8).min(5);
+                let blue = ((blue.clamp(0.0, 1.0) * 5.0) as u8).min(5);
+
+                Some(36 * red + 6 * green + blue + 16)
+            }
+
+            Self::Grayscale(shade) => {
+                let shade = ((shade.clamp(0.0, 1.0) * 23.0) as u8).min(23);
+
+                Some(shade + 232)
+            }
+
+            Self::Indexed(index) => Some(*index),
+
+            _ => None,
+        }
+    }
+
+    fn rgb_to_indexed(red: u8, green: u8, blue: u8) -> u8 {
+        if red == green && green == blue {
+            return match red {
+                0..=7 => 16,
+                249..=255 => 231,
+                _ => 232 + ((red - 8) / 10).min(23),
+            };
+        }
+
+        let scale = |component: u8| ((component as u16 * 5 + 127) / 255) as u8;
+
+        16 + 36 * scale(red) + 6 * scale(green) + scale(blue)
+    }
+
+    // Returns the RGB components of the 256-color palette color except for
+    // the first 16 colors that are terminal-specific.
+    fn indexed_to_rgb(index: u8) -> (u8, u8, u8) {
+        match index {
+            0..=231 => {
+                let component = |value: u8| match value {
+                    0 => 0,
+                    _ => 55 + value * 40,
+                };
+
+                let index = index.saturating_sub(16);
+
+                (
+                    component(index / 36),
+                    component(index / 6 % 6),
+                    component(index % 6),
+                )
+            }
+
+            _ => {
+                let shade = 8 + (index - 232) * 10;
+
+                (shade, shade, shade)
+            }
+        }
+    }
+
+    fn rgb_to_basic(red: u8, green: u8, blue: u8) -> Self {
+        let value = (red.max(green).max(blue) as u16 * 2 + 127) / 255;
+
+        if value == 0 {
+            return Self::Black;
+        }
+
+        let bit = |component: u8| component >= 128;
+
+        match (value == 2, bit(red), bit(green), bit(blue)) {
+            (false, false, false, false) => Self::BrightBlack,
+            (false, true, false, false) => Self::Red,
+            (false, false, true, false) => Self::Green,
+            (false, true, true, false) => Self::Yellow,
+            (false, false, false, true) => Self::Blue,
+            (false, true, false, true) => Self::Magenta,
+            (false, false, true, true) => Self::Cyan,
+            (false, true, true, true) => Self::White,
+            (true, false, false, false) => Self::BrightBlack,
+            (true, true, false, false) => Self::BrightRed,
+            (true, false, true, false) => Self::BrightGreen,
+            (true, true, true, false) => Self::BrightYellow,
+            (true, false, false, true) => Self::BrightBlue,
+            (true, true, false, true) => Self::BrightMagenta,
+            (true, false, true, true) => Self::BrightCyan,
+            (true, true, true, true) => Self::BrightWhite,
+        }
+    }
+
     #[inline]
     fn apply_fg(&self, target: &mut String) {
         macro_rules! escape_fg {
@@ -552,18 +1027,14 @@ impl Color {
             Self::BrightCyan => target.push_str(escape_fg!(14)),
             Self::BrightWhite => target.push_str(escape_fg!(15)),
 
-            Self::RGB { red, green, blue } => {
-                let red = ((red.clamp(0.0, 1.0) * 5.0) as u8).min(5);
-                let green = ((green.clamp(0.0, 1.0) * 5.0) as u8).min(5);
-                let blue = ((blue.clamp(0.0, 1.0) * 5.0) as u8).min(5);
-
-                target.push_str(&format!("\x1B[38;5;{}m", 36 * red + 6 * green + blue + 16));
+            Self::TrueColor { red, green, blue } => {
+                target.push_str(&format!("\x1B[38;2;{red};{green};{blue}m"));
             }
 
-            Self::Grayscale(shade) => {
-                let shade = ((shade.clamp(0.0, 1.0) * 23.0) as u8).min(23);
-
-                target.push_str(&format!("\x1B[38;5;{}m", shade + 232));
+            _ => {
+                if let Some(index) = self.index() {
+                    target.push_str(&format!("\x1B[38;5;{index}m"));
+                }
             }
         }
     }
@@ -594,18 +1065,14 @@ impl Color {
             Self::BrightCyan => target.push_str(escape_bg!(14)),
             Self::BrightWhite => target.push_str(escape_bg!(15)),
 
-            Self::RGB { red, green, blue } => {
-                let red = ((red.clamp(0.0, 1.0) * 5.0) as u8).min(5);
-                let green = ((green.clamp(0.0, 1.0) * 5.0) as u8).min(5);
-                let blue = ((blue.clamp(0.0, 1.0) * 5.0) as u8).min(5);
-
-                target.push_str(&format!("\x1B[48;5;{}m", 36 * red + 6 * green + blue + 16));
+            Self::TrueColor { red, green, blue } => {
+                target.push_str(&format!("\x1B[48;2;{red};{green};{blue}m"));
             }
 
-            Self::Grayscale(shade) => {
-                let shade = ((shade.clamp(0.0, 1.0) * 23.0) as u8).min(23);
-
-                target.push_str(&format!("\x1B[48;5;{}m", shade + 232));
+            _ => {
+                if let Some(index) = self.index() {
+                    target.push_str(&format!("\x1B[48;5;{index}m"));
+                }
             }
         }
     }
@@ -628,9 +1095,11 @@ pub(super) enum Escaped {
     Text = 1,
     #[rule("\x1B[" ['\x30'..'\x4F']* ['\x20'..'\x2F']* ['\x40'..'\x7E'])]
     CSI,
+    #[rule("\x1B]" ^['\x07', '\x1B']* ('\x07' | "\x1B\\"))]
+    OSC,
 }
 
-#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 struct Emphasis {
     bold: bool,
     italic: bool,
@@ -710,3 +1179,168 @@ impl Emphasis {
 fn reset_all(target: &mut String) {
     target.push_str(escape!(0));
 }
+
+#[inline(always)]
+pub(super) fn open_link(url: &str, target: &mut String) {
+    target.push_str("\x1B]8;;");
+    target.push_str(url);
+    target.push_str("\x1B\\");
+}
+
+#[inline(always)]
+pub(super) fn close_link(target: &mut String) {
+    target.push_str("\x1B]8;;\x1B\\");
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::format::{terminal::Emphasis, Color, Style};
+
+    impl Serialize for Style {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            StyleRepr {
+                fg: self.fg,
+                bg: self.bg,
+                bold: self.emphasis.bold,
+                italic: self.emphasis.italic,
+                underline: self.emphasis.underline,
+                invert: self.emphasis.invert,
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Style {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let repr = StyleRepr::deserialize(deserializer)?;
+
+            Ok(Self {
+                fg: repr.fg,
+                bg: repr.bg,
+                emphasis: Emphasis {
+                    bold: repr.bold,
+                    italic: repr.italic,
+                    underline: repr.underline,
+                    invert: repr.invert,
+                },
+                link: None,
+            })
+        }
+    }
+
+    impl Serialize for Color {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let repr = match self {
+                Self::RGB { red, green, blue } => ColorRepr::Rgb {
+                    rgb: [*red, *green, *blue],
+                },
+
+                Self::Grayscale(shade) => ColorRepr::Grayscale { grayscale: *shade },
+
+                Self::Indexed(index) => ColorRepr::Indexed(*index),
+
+                Self::TrueColor { red, green, blue } => {
+                    ColorRepr::Named(format!("#{red:02x}{green:02x}{blue:02x}"))
+                }
+
+                basic => {
+                    let name = NAMES
+                        .iter()
+                        .find(|(_, color)| color == basic)
+                        .map(|(name, _)| *name)
+                        .unwrap_or_default();
+
+                    ColorRepr::Named(String::from(name))
+                }
+            };
+
+            repr.serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Color {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            match ColorRepr::deserialize(deserializer)? {
+                ColorRepr::Indexed(index) => Ok(Self::Indexed(index)),
+
+                ColorRepr::Rgb {
+                    rgb: [red, green, blue],
+                } => Ok(Self::RGB { red, green, blue }),
+
+                ColorRepr::Grayscale { grayscale } => Ok(Self::Grayscale(grayscale)),
+
+                ColorRepr::Named(name) => {
+                    if let Some(hex) = name.strip_prefix('#') {
+                        return match (hex.len(), u32::from_str_radix(hex, 16)) {
+                            (6, Ok(value)) => Ok(Self::TrueColor {
+                                red: (value >> 16) as u8,
+                                green: (value >> 8) as u8,
+                                blue: value as u8,
+                            }),
+
+                            _ => Err(D::Error::custom(format!("invalid hex color {name:?}"))),
+                        };
+                    }
+
+                    NAMES
+                        .iter()
+                        .find(|(candidate, _)| *candidate == name)
+                        .map(|(_, color)| *color)
+                        .ok_or_else(|| D::Error::custom(format!("unknown color {name:?}")))
+                }
+            }
+        }
+    }
+
+    static NAMES: [(&str, Color); 16] = [
+        ("black", Color::Black),
+        ("red", Color::Red),
+        ("green", Color::Green),
+        ("yellow", Color::Yellow),
+        ("blue", Color::Blue),
+        ("magenta", Color::Magenta),
+        ("cyan", Color::Cyan),
+        ("white", Color::White),
+        ("bright_black", Color::BrightBlack),
+        ("bright_red", Color::BrightRed),
+        ("bright_green", Color::BrightGreen),
+        ("bright_yellow", Color::BrightYellow),
+        ("bright_blue", Color::BrightBlue),
+        ("bright_magenta", Color::BrightMagenta),
+        ("bright_cyan", Color::BrightCyan),
+        ("bright_white", Color::BrightWhite),
+    ];
+
+    #[derive(Default, Serialize, Deserialize)]
+    #[serde(default, deny_unknown_fields)]
+    struct StyleRepr {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        fg: Option<Color>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        bg: Option<Color>,
+        #[serde(skip_serializing_if = "is_false")]
+        bold: bool,
+        #[serde(skip_serializing_if = "is_false")]
+        italic: bool,
+        #[serde(skip_serializing_if = "is_false")]
+        underline: bool,
+        #[serde(skip_serializing_if = "is_false")]
+        invert: bool,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(untagged)]
+    enum ColorRepr {
+        Indexed(u8),
+        Named(String),
+        Rgb { rgb: [f64; 3] },
+        Grayscale { grayscale: f64 },
+    }
+
+    #[inline(always)]
+    fn is_false(value: &bool) -> bool {
+        !*value
+    }
+}
@@ -0,0 +1,198 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::mem::take;
+
+use crate::{
+    lexis::SourceCode,
+    syntax::{ParseNode, ParseNodeChild},
+};
+
+/// A sequence of comments and line breaks of the source code that precede or
+/// follow a meaningful child of the [ParseNode].
+///
+/// Code formatters built on top of the [PrettyPrinter](crate::format::PrettyPrinter)
+/// use this object to re-attach the comments to the formatted constructs.
+///
+/// You can split the parse node children into the meaningful children and
+/// their trivia using the [Trivia::split] function, and then print the trivia
+/// using the [PrettyPrinter::print_leading_trivia](crate::format::PrettyPrinter::print_leading_trivia)
+/// and the [PrettyPrinter::print_trailing_trivia](crate::format::PrettyPrinter::print_trailing_trivia)
+/// functions.
+#[derive(Clone, Default, PartialEq, Eq, Hash, Debug)]
+pub struct Trivia {
+    /// The comments and the line breaks in the source code order.
+    pub items: Vec<TriviaItem>,
+}
+
+/// An item of the [Trivia] sequence.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum TriviaItem {
+    /// A comment text as it is in the source code.
+    Comment(String),
+
+    /// A number of consecutive line breaks between the comments or
+    /// the meaningful parts of the source code.
+    Breaks(usize),
+}
+
+impl Trivia {
+    /// Splits the children of the parse `node` into the meaningful children
+    /// and their leading and trailing trivia.
+    ///
+    /// The `code` parameter is the source code from which the parse tree
+    /// has been created.
+    ///
+    /// The `is_comment` predicate tells whether the child of the parse node
+    /// is a comment (e.g., a comment token or a comment node).
+    ///
+    /// The comment that starts in the same line where the previous meaningful
+    /// child ends, and that is followed by a line break, becomes
+    /// the **trailing** trivia of the previous child. Other comments and
+    /// the line breaks become the **leading** trivia of the next meaningful
+    /// child, or the [rest](TriviaSplit::rest) trivia of the node if there
+    /// are no meaningful children after them.
+    ///
+    /// The line breaks before the first comment of the node are omitted.
+    pub fn split<'a>(
+        node: &'a ParseNode,
+        code: &impl SourceCode,
+        is_comment: impl Fn(&ParseNodeChild) -> bool,
+    ) -> TriviaSplit<'a> {
+        let mut children = Vec::<TriviaChild>::with_capacity(node.children.len());
+        let mut leading = Trivia::default();
+        let mut pending = Vec::new();
+        let mut same_line = false;
+
+        for child in &node.children {
+            if let ParseNodeChild::Blank(blank) = child {
+                let breaks = blank.breaks();
+
+                if breaks == 0 {
+                    continue;
+                }
+
+                if let Some(last) = children.last_mut() {
+                    last.trailing.items.append(&mut pending);
+                }
+
+                same_line = false;
+
+                if children.is_empty() && leading.items.is_empty() {
+                    continue;
+                }
+
+                leading.push_breaks(breaks);
+
+                continue;
+            }
+
+            if is_comment(child) {
+                let comment = TriviaItem::Comment(child.render(code));
+
+                match same_line && !children.is_empty() {
+                    true => pending.push(comment),
+                    false => leading.items.push(comment),
+                }
+
+                if child.breaks() > 0 {
+                    same_line = false;
+                }
+
+                continue;
+            }
+
+            leading.items.append(&mut pending);
+
+            children.push(TriviaChild {
+                leading: take(&mut leading),
+                child,
+                trailing: Trivia::default(),
+            });
+
+            same_line = true;
+        }
+
+        if let Some(last) = children.last_mut() {
+            last.trailing.items.append(&mut pending);
+        }
+
+        TriviaSplit {
+            children,
+            rest: leading,
+        }
+    }
+
+    /// Returns true if this trivia sequence has at least one comment.
+    #[inline(always)]
+    pub fn has_comments(&self) -> bool {
+        self.items
+            .iter()
+            .any(|item| matches!(item, TriviaItem::Comment(..)))
+    }
+
+    #[inline(always)]
+    fn push_breaks(&mut self, breaks: usize) {
+        if let Some(TriviaItem::Breaks(previous)) = self.items.last_mut() {
+            *previous += breaks;
+            return;
+        }
+
+        self.items.push(TriviaItem::Breaks(breaks));
+    }
+}
+
+/// A result of the [Trivia::split] function.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TriviaSplit<'a> {
+    /// The meaningful children of the parse node with their trivia.
+    pub children: Vec<TriviaChild<'a>>,
+
+    /// The trivia after the last meaningful child of the parse node.
+    pub rest: Trivia,
+}
+
+/// A meaningful child of the parse node with its leading and trailing
+/// [Trivia].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TriviaChild<'a> {
+    /// The comments and the line breaks before the child.
+    pub leading: Trivia,
+
+    /// The meaningful child of the parse node.
+    pub child: &'a ParseNodeChild,
+
+    /// The comments that follow the child in the same line.
+    pub trailing: Trivia,
+}
@@ -32,19 +32,21 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::{
-    borrow::Cow,
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use core::{
     fmt::{Debug, Display, Formatter},
     iter::{FusedIterator, Peekable, Take},
+    mem::size_of,
     slice::Iter,
     str::Chars,
 };
 
+#[cfg(feature = "std")]
+use crate::format::SnippetFormatter;
 use crate::{
     arena::{Entry, Id, Identifiable},
-    format::SnippetFormatter,
     lexis::{
-        cursor::TokenBufferCursor,
+        cursor::{TokenBufferBackCursor, TokenBufferCursor},
         session::{BufferLexisSession, Cursor},
         ByteIndex,
         Chunk,
@@ -55,6 +57,7 @@ use crate::{
         ToSpan,
         Token,
         TokenCount,
+        TokenRule,
         CHUNK_SIZE,
     },
     report::{ld_assert, ld_unreachable},
@@ -87,7 +90,7 @@ pub struct TokenBuffer<T: Token> {
 
 impl<T: Token> Debug for TokenBuffer<T> {
     #[inline]
-    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
         formatter
             .debug_struct("TokenBuffer")
             .field("id", &self.id)
@@ -98,11 +101,19 @@ impl<T: Token> Debug for TokenBuffer<T> {
 
 impl<T: Token> Display for TokenBuffer<T> {
     #[inline(always)]
-    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
-        formatter
-            .snippet(self)
-            .set_caption(format!("TokenBuffer({})", self.id))
-            .finish()
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        #[cfg(feature = "std")]
+        {
+            formatter
+                .snippet(self)
+                .set_caption(format!("TokenBuffer({})", self.id))
+                .finish()
+        }
+
+        #[cfg(not(feature = "std"))]
+        {
+            formatter.write_str(self.substring(..).as_ref())
+        }
     }
 }
 
@@ -123,6 +134,7 @@ impl<T: Token, S: AsRef<str>> From<S> for TokenBuffer<T> {
 
 impl<T: Token> Drop for TokenBuffer<T> {
     fn drop(&mut self) {
+        #[cfg(feature = "std")]
         self.id.clear_name();
     }
 }
@@ -139,6 +151,8 @@ impl<T: Token> SourceCode for TokenBuffer<T> {
 
     type Cursor<'code> = TokenBufferCursor<'code, Self::Token>;
 
+    type BackCursor<'code> = TokenBufferBackCursor<'code, Self::Token>;
+
     type CharIterator<'code> = Take<Chars<'code>>;
 
     fn chars(&self, span: impl ToSpan) -> Self::CharIterator<'_> {
@@ -290,6 +304,16 @@ impl<T: Token> SourceCode for TokenBuffer<T> {
         Self::Cursor::new(self, span)
     }
 
+    #[inline(always)]
+    fn cursor_back(&self, span: impl ToSpan) -> TokenBufferBackCursor<'_, Self::Token> {
+        let span = match span.to_site_span(self) {
+            None => panic!("Specified span is invalid."),
+            Some(span) => span,
+        };
+
+        Self::BackCursor::new(self, span)
+    }
+
     #[inline(always)]
     fn length(&self) -> Length {
         self.lines.code_length()
@@ -415,7 +439,9 @@ impl<T: Token> TokenBuffer<T> {
         self.text.push_str(text);
         self.lines.append(text);
 
-        BufferLexisSession::run(self, byte, site);
+        let limit = self.text.len();
+
+        let _ = BufferLexisSession::run(self, byte, site, limit);
     }
 
     /// Reserves capacity to store at least `tokens` number of the source code
@@ -454,6 +480,90 @@ impl<T: Token> TokenBuffer<T> {
         self.id = Id::new();
     }
 
+    pub(crate) fn heap_size(&self) -> usize {
+        self.tokens.capacity() * size_of::<T>()
+            + self.sites.capacity() * size_of::<Site>()
+            + self.spans.capacity() * size_of::<Length>()
+            + self.indices.capacity() * size_of::<ByteIndex>()
+            + self.text.capacity()
+            + self.lines.heap_size()
+    }
+
+    // Restores the buffer from the source code `text` and the sequence of
+    // the token rules and lengths without rescanning the text.
+    //
+    // Returns None if the chunks do not cover the text exactly, or if any
+    // rule number does not denote a token of type T.
+    #[cfg(feature = "serde")]
+    pub(crate) fn restore(
+        text: String,
+        chunks: impl ExactSizeIterator<Item = (TokenRule, Length)>,
+    ) -> Option<Self> {
+        let mut buffer = Self::with_capacity(chunks.len(), 0);
+
+        let mut site = 0;
+        let mut characters = text.char_indices();
+
+        for (rule, length) in chunks {
+            let token = T::from_rule(rule)?;
+
+            if length == 0 {
+                return None;
+            }
+
+            let (byte, _) = characters.next()?;
+
+            if length > 1 {
+                let _ = characters.nth(length - 2)?;
+            }
+
+            buffer.tokens.push(token);
+            buffer.sites.push(site);
+            buffer.spans.push(length);
+            buffer.indices.push(byte);
+
+            site += length;
+        }
+
+        if characters.next().is_some() {
+            return None;
+        }
+
+        buffer.text = text;
+        buffer.update_line_index();
+
+        Some(buffer)
+    }
+
+    // Appends the `text` to the end of the buffer without scanning it.
+    //
+    // The buffer remains inconsistent until the `scan_step` function scans
+    // the appended text to the end.
+    #[inline(always)]
+    pub(crate) fn append_unscanned(&mut self, text: &str) {
+        self.text.push_str(text);
+        self.lines.append(text);
+    }
+
+    // Scans the tokens of the unscanned text starting from the `byte` index,
+    // which is the end of the last scanned token, until the end of a token
+    // reaches the `limit` byte index. Since the scanner sees the entire text,
+    // the scanned tokens are the same as if the text had been scanned at once.
+    //
+    // Returns the byte index of the end of the last scanned token.
+    pub(crate) fn scan_step(&mut self, byte: ByteIndex, limit: ByteIndex) -> ByteIndex {
+        if byte >= self.text.len() {
+            return self.text.len();
+        }
+
+        let site = match (self.sites.last(), self.spans.last()) {
+            (Some(site), Some(span)) => *site + *span,
+            _ => 0,
+        };
+
+        BufferLexisSession::run(self, byte, site, limit)
+    }
+
     #[inline(always)]
     pub(crate) fn update_line_index(&mut self) {
         self.lines.clear();
@@ -32,11 +32,23 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::{borrow::Cow, iter::FusedIterator, marker::PhantomData};
+use alloc::{borrow::Cow, string::String};
+use core::{iter::FusedIterator, marker::PhantomData};
 
 use crate::{
     arena::{Entry, Identifiable},
-    lexis::{Chunk, Length, LineIndex, Site, SiteRef, ToSpan, Token, TokenCount, TokenCursor},
+    lexis::{
+        Chunk,
+        GraphemeSpans,
+        Length,
+        LineIndex,
+        Site,
+        SiteRef,
+        ToSpan,
+        Token,
+        TokenCount,
+        TokenCursor,
+    },
 };
 
 /// An object that provides access to the source code text and the lexical
@@ -66,6 +78,12 @@ pub trait SourceCode: Identifiable {
     where
         Self: 'code;
 
+    /// Specifies the type of the iterator that iterates through the source
+    /// code tokens [metadata](Chunk) in reverse order.
+    type BackCursor<'code>: Iterator<Item = Chunk<'code, Self::Token>> + FusedIterator + 'code
+    where
+        Self: 'code;
+
     /// Specifies the type of the iterator that iterates through the unicode
     /// characters of the source code text substrings.
     type CharIterator<'code>: Iterator<Item = char> + FusedIterator + 'code
@@ -120,6 +138,35 @@ pub trait SourceCode: Identifiable {
     /// [valid](ToSpan::is_valid_span) for this source code.
     fn chars(&self, span: impl ToSpan) -> Self::CharIterator<'_>;
 
+    /// Returns an iterator over the [site spans](crate::lexis::SiteSpan) of
+    /// the extended grapheme clusters of the source code text substring in
+    /// the specified `span`.
+    ///
+    /// For example, in the text `e\u{301}\r\n` the span `..` will yield
+    /// the `0..2` and `2..4` spans, because the combining acute accent belongs
+    /// to the preceding letter, and the CRLF sequence is a single cluster.
+    ///
+    /// The clusters are computed within the specified `span` only. See
+    /// [Graphemes](crate::lexis::Graphemes) for details on the segmentation
+    /// rules.
+    ///
+    /// **Panic**
+    ///
+    /// This function may panic if the specified `span` is not
+    /// [valid](ToSpan::is_valid_span) for this source code.
+    #[inline(always)]
+    fn graphemes(&self, span: impl ToSpan) -> GraphemeSpans<Self::CharIterator<'_>>
+    where
+        Self: Sized,
+    {
+        let span = match span.to_site_span(self) {
+            None => panic!("Specified span is invalid."),
+            Some(span) => span,
+        };
+
+        GraphemeSpans::new(span.start, self.chars(span))
+    }
+
     /// Returns a borrowed or an owned substring of the source code text in
     /// the specified `span`.
     ///
@@ -198,6 +245,23 @@ pub trait SourceCode: Identifiable {
     /// [valid](ToSpan::is_valid_span) for this source code.
     fn cursor(&self, span: impl ToSpan) -> Self::Cursor<'_>;
 
+    /// Returns an iterator of the source code tokens [metadata](Chunk)
+    /// in the specified `span` in reverse order.
+    ///
+    /// The iterator yields the same token chunks as
+    /// the [chunks](Self::chunks) function, but starting from the last one.
+    ///
+    /// This function is useful to walk the token stream backward from
+    /// a particular site. For example, the span `..10` iterates
+    /// through the tokens that precede the site `10`, starting from the token
+    /// that covers this site.
+    ///
+    /// **Panic**
+    ///
+    /// This function may panic if the specified `span` is not
+    /// [valid](ToSpan::is_valid_span) for this source code.
+    fn cursor_back(&self, span: impl ToSpan) -> Self::BackCursor<'_>;
+
     /// Returns a [SiteRef] that points to the end of this source code.
     #[inline(always)]
     fn end_site_ref(&self) -> SiteRef {
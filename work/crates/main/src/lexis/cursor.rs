@@ -32,9 +32,12 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
+use core::iter::FusedIterator;
+
 use crate::{
     arena::{Entry, EntryIndex, Id, Identifiable},
     lexis::{
+        Chunk,
         Length,
         Site,
         SiteRef,
@@ -343,3 +346,57 @@ impl<'code, T: Token> TokenBufferCursor<'code, T> {
         }
     }
 }
+
+pub struct TokenBufferBackCursor<'code, T: Token> {
+    buffer: &'code TokenBuffer<T>,
+    first: EntryIndex,
+    end: EntryIndex,
+}
+
+impl<'code, T: Token> Iterator for TokenBufferBackCursor<'code, T> {
+    type Item = Chunk<'code, T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.end <= self.first {
+            return None;
+        }
+
+        self.end -= 1;
+
+        let index = self.end;
+        let text = self.buffer.text.as_str();
+
+        let start = *unsafe { self.buffer.indices.get_unchecked(index) };
+        let end = self
+            .buffer
+            .indices
+            .get(index + 1)
+            .copied()
+            .unwrap_or(text.len());
+
+        Some(Chunk {
+            token: *unsafe { self.buffer.tokens.get_unchecked(index) },
+            site: *unsafe { self.buffer.sites.get_unchecked(index) },
+            length: *unsafe { self.buffer.spans.get_unchecked(index) },
+            string: unsafe { text.get_unchecked(start..end) },
+        })
+    }
+}
+
+impl<'code, T: Token> FusedIterator for TokenBufferBackCursor<'code, T> {}
+
+impl<'code, T: Token> TokenBufferBackCursor<'code, T> {
+    pub(super) fn new(buffer: &'code TokenBuffer<T>, span: SiteSpan) -> Self {
+        // The token that precedes the first token starting at or after
+        // the span start ends at or after the span start too.
+        let first = buffer
+            .sites
+            .partition_point(|site| *site < span.start)
+            .saturating_sub(1);
+
+        let end = buffer.sites.partition_point(|site| *site <= span.end);
+
+        Self { buffer, first, end }
+    }
+}
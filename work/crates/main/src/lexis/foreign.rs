@@ -0,0 +1,396 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use alloc::string::String;
+
+use crate::lexis::{Length, LexisSession, Token, TokenRule};
+
+/// An adapter of a third-party lexical scanner to the [Token] interface.
+///
+/// The [Token] derive macro and the hand-written [Token::scan] functions read
+/// the source code text byte by byte through the [LexisSession] interface.
+/// Most external lexer libraries (e.g., [Logos](https://docs.rs/logos))
+/// work differently: they expect a contiguous string and return the kind
+/// and the byte length of the token at the beginning of this string.
+///
+/// By implementing the ForeignScanner trait on the token enum, you
+/// automatically implement the [Token] trait on this type. The [Token::scan]
+/// function of this implementation collects the text ahead of the scanning
+/// position into a temporary string, invokes
+/// the [scan_str](ForeignScanner::scan_str) function on it, and then submits
+/// the recognized token to the [LexisSession]. Because the resulting type is
+/// an ordinary [Token], it works with all kinds of compilation units,
+/// including the incrementally rescanned [Document](crate::units::Document).
+///
+/// ```ignore
+/// #[derive(Clone, Copy, PartialEq, Eq, Logos)]
+/// #[repr(u8)]
+/// enum MyToken {
+///     EOI = 0,
+///     Mismatch = 1,
+///
+///     #[regex(r"[a-z]+")]
+///     Ident,
+///
+///     #[regex(r"[ \t\n]+")]
+///     Whitespace,
+/// }
+///
+/// impl ForeignScanner for MyToken {
+///     const EOI: Self = Self::EOI;
+///     const MISMATCH: Self = Self::Mismatch;
+///
+///     fn scan_str(text: &str) -> Option<(Self, usize)> {
+///         let mut lexer = Self::lexer(text);
+///         let token = lexer.next()?.ok()?;
+///
+///         Some((token, lexer.span().end))
+///     }
+///
+///     fn to_rule(self) -> TokenRule {
+///         self as TokenRule
+///     }
+/// }
+/// ```
+///
+/// ## Scanner constraints
+///
+/// The adapter calls the foreign scanner for each token independently, and
+/// the Document may call it from any token boundary when it rescans an edited
+/// fragment of the text. Therefore, the foreign scanner must satisfy
+/// the following requirements:
+///
+///  1. The result must depend on the `text` argument only. The scanner must
+///     not keep interior state between the tokens (such as the lexer modes,
+///     nesting counters, or the previously scanned token). If the language
+///     lexis is context-dependent, this context should be resolved
+///     on the syntax level instead.
+///
+///  2. The scanner must not look further than
+///     [LOOKAHEAD](ForeignScanner::LOOKAHEAD) characters past the end of
+///     the recognized token to decide on the token's kind and length.
+///
+///  3. The token that the scanner recognizes should not be ambiguous with
+///     respect to more than [LOOKBACK](ForeignScanner::LOOKBACK) preceding
+///     tokens, in the same sense as the [Token::LOOKBACK] constant.
+///
+/// The adapter reads the text ahead of the scanning position in chunks
+/// of growing size. If the recognized token, together with the lookahead
+/// characters, does not fit into the chunk, or if the scanner fails to
+/// recognize a token in the chunk, the adapter repeats the attempt with a
+/// twice larger chunk until it reaches the end of the input. As such, the
+/// bridge is generally slower than the scanners generated by the [Token]
+/// derive macro, and an unrecognizable fragment of the text costs a scan
+/// of the remaining input.
+pub trait ForeignScanner: Copy + Eq + Send + Sync + Sized + 'static {
+    /// The value of the [Token::LOOKBACK] constant of the derived Token
+    /// implementation.
+    ///
+    /// The default value is 1.
+    const LOOKBACK: Length = 1;
+
+    /// The maximum number of Unicode chars past the end of the token that
+    /// the [scan_str](ForeignScanner::scan_str) function may inspect.
+    ///
+    /// For instance, a scanner that recognizes the `1.5` number literal but
+    /// splits `1.` into two tokens needs at least 2 lookahead characters.
+    ///
+    /// The default value is 1.
+    const LOOKAHEAD: Length = 1;
+
+    /// The token instance that denotes the end-of-input.
+    ///
+    /// See [Token::eoi] for details.
+    const EOI: Self;
+
+    /// The token instance that denotes the unrecognized fragment of the text.
+    ///
+    /// See [Token::mismatch] for details.
+    const MISMATCH: Self;
+
+    /// Recognizes a token at the beginning of the `text`.
+    ///
+    /// Returns the token kind together with the token length in bytes, or
+    /// None if the text does not start with a known token.
+    ///
+    /// The `text` argument is a fragment of the source code text that starts
+    /// at the scanning position, but it does not necessarily span to the end
+    /// of the input.
+    ///
+    /// The returning length must be greater than zero, must not exceed
+    /// the `text` length, and must point to the char boundary of the `text`.
+    fn scan_str(text: &str) -> Option<(Self, usize)>;
+
+    /// Returns a numeric representation of this token.
+    ///
+    /// See [Token::rule] for details.
+    fn to_rule(self) -> TokenRule;
+
+    /// A debug name of the token rule.
+    ///
+    /// See [Token::rule_name] for details.
+    ///
+    /// The default implementation returns None.
+    #[inline(always)]
+    fn token_name(rule: TokenRule) -> Option<&'static str> {
+        let _ = rule;
+
+        None
+    }
+
+    /// An end-user display description of the token rule.
+    ///
+    /// See [Token::rule_description] for details.
+    ///
+    /// The default implementation returns None.
+    #[inline(always)]
+    fn token_description(rule: TokenRule, verbose: bool) -> Option<&'static str> {
+        let _ = (rule, verbose);
+
+        None
+    }
+}
+
+impl<S: ForeignScanner> Token for S {
+    const LOOKBACK: Length = <S as ForeignScanner>::LOOKBACK;
+
+    fn scan(session: &mut impl LexisSession) -> Self {
+        const INITIAL_WINDOW: usize = 64;
+
+        let mut window = String::new();
+        let mut limit = INITIAL_WINDOW;
+        let mut exhausted = false;
+
+        let (token, length) = loop {
+            while !exhausted && window.len() < limit {
+                if session.advance() == 0xFF {
+                    exhausted = true;
+                    break;
+                }
+
+                // Safety: The window is always filled by the whole code points.
+                window.push(unsafe { session.read() });
+            }
+
+            match S::scan_str(&window) {
+                Some((token, length)) if length > 0 => {
+                    if length > window.len() || !window.is_char_boundary(length) {
+                        panic!(
+                            "ForeignScanner::scan_str returned a length that \
+                            does not point to a char boundary of the text."
+                        );
+                    }
+
+                    if exhausted {
+                        break (token, length);
+                    }
+
+                    let lookahead = window[length..].chars().count();
+
+                    if lookahead >= <S as ForeignScanner>::LOOKAHEAD {
+                        break (token, length);
+                    }
+                }
+
+                _ => {
+                    if exhausted {
+                        return S::MISMATCH;
+                    }
+                }
+            }
+
+            limit *= 2;
+        };
+
+        session.rewind();
+
+        for _ in window[..length].chars() {
+            let _ = session.advance();
+
+            // Safety: The cursor follows the same code points as the window.
+            unsafe { session.consume() };
+        }
+
+        // Safety: The cursor is at the char boundary after a non-empty token.
+        unsafe { session.submit() };
+
+        token
+    }
+
+    #[inline(always)]
+    fn eoi() -> Self {
+        S::EOI
+    }
+
+    #[inline(always)]
+    fn mismatch() -> Self {
+        S::MISMATCH
+    }
+
+    #[inline(always)]
+    fn rule(self) -> TokenRule {
+        self.to_rule()
+    }
+
+    #[inline(always)]
+    fn rule_name(rule: TokenRule) -> Option<&'static str> {
+        <S as ForeignScanner>::token_name(rule)
+    }
+
+    #[inline(always)]
+    fn rule_description(rule: TokenRule, verbose: bool) -> Option<&'static str> {
+        <S as ForeignScanner>::token_description(rule, verbose)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{
+        format,
+        string::{String, ToString},
+        vec::Vec,
+    };
+
+    use crate::{
+        lexis::{ForeignScanner, Length, SourceCode, TokenRule},
+        syntax::VoidSyntax,
+        units::Document,
+    };
+
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    #[repr(u8)]
+    enum TestForeign {
+        EOI = 0,
+        Mismatch = 1,
+        Word,
+        Number,
+        Space,
+    }
+
+    impl ForeignScanner for TestForeign {
+        const LOOKBACK: Length = 2;
+        const LOOKAHEAD: Length = 2;
+        const EOI: Self = Self::EOI;
+        const MISMATCH: Self = Self::Mismatch;
+
+        fn scan_str(text: &str) -> Option<(Self, usize)> {
+            let bytes = text.as_bytes();
+
+            let count = |from: usize, predicate: fn(&u8) -> bool| {
+                bytes[from..]
+                    .iter()
+                    .take_while(|byte| predicate(byte))
+                    .count()
+            };
+
+            let first = *bytes.first()?;
+
+            if first.is_ascii_lowercase() {
+                return Some((Self::Word, count(0, u8::is_ascii_lowercase)));
+            }
+
+            if first == b' ' {
+                return Some((Self::Space, count(0, |byte| *byte == b' ')));
+            }
+
+            if !first.is_ascii_digit() {
+                return None;
+            }
+
+            let mut length = count(0, u8::is_ascii_digit);
+
+            if bytes.get(length) == Some(&b'.') {
+                let fraction = count(length + 1, u8::is_ascii_digit);
+
+                if fraction > 0 {
+                    length += 1 + fraction;
+                }
+            }
+
+            Some((Self::Number, length))
+        }
+
+        fn to_rule(self) -> TokenRule {
+            self as TokenRule
+        }
+    }
+
+    fn tokens(doc: &Document<VoidSyntax<TestForeign>>) -> Vec<(TestForeign, String)> {
+        doc.chunks(..)
+            .map(|chunk| (chunk.token, chunk.string.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_foreign_scanner() {
+        let long = "x".repeat(100);
+        let text = format!("foo 12.5 {long} 3. ф 7.25");
+
+        let doc = Document::<VoidSyntax<TestForeign>>::new_immutable(&text);
+
+        assert_eq!(
+            tokens(&doc),
+            [
+                (TestForeign::Word, "foo".to_string()),
+                (TestForeign::Space, " ".to_string()),
+                (TestForeign::Number, "12.5".to_string()),
+                (TestForeign::Space, " ".to_string()),
+                (TestForeign::Word, long.clone()),
+                (TestForeign::Space, " ".to_string()),
+                (TestForeign::Number, "3".to_string()),
+                (TestForeign::Mismatch, ".".to_string()),
+                (TestForeign::Space, " ".to_string()),
+                (TestForeign::Mismatch, "ф".to_string()),
+                (TestForeign::Space, " ".to_string()),
+                (TestForeign::Number, "7.25".to_string()),
+            ],
+        );
+
+        let mut doc = Document::<VoidSyntax<TestForeign>>::new_mutable(&text);
+
+        doc.write(112..112, "5");
+
+        assert!(tokens(&doc).contains(&(TestForeign::Number, "3.5".to_string())));
+
+        let edits = [(6..7, ""), (50..50, " "), (0..0, "ф"), (112..117, "1")];
+
+        for (span, edit) in edits {
+            doc.write(span, edit);
+
+            let fresh = Document::<VoidSyntax<TestForeign>>::new_immutable(doc.substring(..));
+
+            assert_eq!(tokens(&doc), tokens(&fresh));
+        }
+    }
+}
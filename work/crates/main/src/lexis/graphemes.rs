@@ -0,0 +1,347 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use core::iter::FusedIterator;
+
+use crate::lexis::{Char, GraphemeClusterBreak, IndicConjunctBreak, Site, SiteSpan};
+
+/// An iterator over the extended grapheme clusters of a string.
+///
+/// Each item is a substring of the original string that represents
+/// a user-perceived character, such as a letter with combining marks,
+/// a CRLF line break, a Hangul syllable, or an emoji sequence.
+///
+/// The boundaries are computed according to the default extended grapheme
+/// cluster rules of the [UAX #29](https://www.unicode.org/reports/tr29/)
+/// specification based on the [Char::grapheme_cluster_break] and
+/// the [Char::indic_conjunct_break] properties.
+///
+/// ```rust
+/// use lady_deirdre::lexis::Graphemes;
+///
+/// let clusters = Graphemes::new("e\u{301}\r\n🇺🇦").collect::<Vec<_>>();
+///
+/// assert_eq!(clusters, ["e\u{301}", "\r\n", "🇺🇦"]);
+/// ```
+///
+/// To iterate over the grapheme clusters of the [SourceCode](crate::lexis::SourceCode)
+/// text, use the [SourceCode::graphemes](crate::lexis::SourceCode::graphemes)
+/// function.
+#[derive(Clone)]
+pub struct Graphemes<'a> {
+    string: &'a str,
+}
+
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chars = self.string.char_indices();
+
+        let (_, first) = chars.next()?;
+
+        let mut rules = GraphemeRules::new(first);
+        let mut end = self.string.len();
+
+        for (index, ch) in chars {
+            if rules.is_boundary(ch) {
+                end = index;
+                break;
+            }
+        }
+
+        let (cluster, rest) = self.string.split_at(end);
+
+        self.string = rest;
+
+        Some(cluster)
+    }
+}
+
+impl<'a> FusedIterator for Graphemes<'a> {}
+
+impl<'a> Graphemes<'a> {
+    /// Creates an iterator over the extended grapheme clusters
+    /// of the `string`.
+    #[inline(always)]
+    pub fn new(string: &'a str) -> Self {
+        Self { string }
+    }
+
+    /// Returns the part of the original string that has not been iterated
+    /// yet.
+    #[inline(always)]
+    pub fn as_str(&self) -> &'a str {
+        self.string
+    }
+}
+
+/// An iterator over the [site spans](SiteSpan) of the extended grapheme
+/// clusters of the source code text.
+///
+/// Created by the [SourceCode::graphemes](crate::lexis::SourceCode::graphemes)
+/// function. See [Graphemes] for details on the segmentation rules.
+#[derive(Clone)]
+pub struct GraphemeSpans<I> {
+    chars: I,
+    next: Option<char>,
+    site: Site,
+}
+
+impl<I: Iterator<Item = char> + FusedIterator> Iterator for GraphemeSpans<I> {
+    type Item = SiteSpan;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = match self.next.take() {
+            Some(ch) => ch,
+            None => self.chars.next()?,
+        };
+
+        let start = self.site;
+
+        self.site += 1;
+
+        let mut rules = GraphemeRules::new(first);
+
+        while let Some(ch) = self.chars.next() {
+            if rules.is_boundary(ch) {
+                self.next = Some(ch);
+                break;
+            }
+
+            self.site += 1;
+        }
+
+        Some(start..self.site)
+    }
+}
+
+impl<I: Iterator<Item = char> + FusedIterator> FusedIterator for GraphemeSpans<I> {}
+
+impl<I: Iterator<Item = char> + FusedIterator> GraphemeSpans<I> {
+    #[inline(always)]
+    pub(super) fn new(site: Site, chars: I) -> Self {
+        Self {
+            chars,
+            next: None,
+            site,
+        }
+    }
+}
+
+// The state of the boundary rules within a single grapheme cluster.
+struct GraphemeRules {
+    prev: GraphemeClusterBreak,
+    // The cluster ends with an Extended_Pictographic character followed
+    // by zero or more Extend characters (GB11).
+    pictographic: bool,
+    // The cluster ends with an Extended_Pictographic Extend* ZWJ sequence
+    // (GB11).
+    pictographic_zwj: bool,
+    // The cluster ends with an odd number of Regional_Indicator
+    // characters (GB12, GB13).
+    odd_regional: bool,
+    // The cluster ends with an InCB=Consonant character followed by zero or
+    // more InCB=Extend or InCB=Linker characters (GB9c).
+    conjunct: bool,
+    // The cluster ends with an InCB=Consonant character followed by
+    // InCB=Extend or InCB=Linker characters including at least one
+    // InCB=Linker character (GB9c).
+    conjunct_linker: bool,
+}
+
+impl GraphemeRules {
+    #[inline(always)]
+    fn new(first: char) -> Self {
+        let prev = first.grapheme_cluster_break();
+
+        Self {
+            prev,
+            pictographic: first.is_ext_pict(),
+            pictographic_zwj: false,
+            odd_regional: prev == GraphemeClusterBreak::RegionalIndicator,
+            conjunct: first.indic_conjunct_break() == IndicConjunctBreak::Consonant,
+            conjunct_linker: false,
+        }
+    }
+
+    // Returns true if there is a boundary between the previous character
+    // and the `next` character.
+    fn is_boundary(&mut self, next: char) -> bool {
+        use GraphemeClusterBreak::*;
+
+        let next_break = next.grapheme_cluster_break();
+        let next_pictographic = next.is_ext_pict();
+        let next_conjunct = next.indic_conjunct_break();
+
+        let boundary = match (self.prev, next_break) {
+            // GB3
+            (CR, LF) => false,
+
+            // GB4
+            (Control | CR | LF, _) => true,
+
+            // GB5
+            (_, Control | CR | LF) => true,
+
+            // GB6
+            (L, L | V | LV | LVT) => false,
+
+            // GB7
+            (LV | V, V | T) => false,
+
+            // GB8
+            (LVT | T, T) => false,
+
+            // GB9, GB9a
+            (_, Extend | ZWJ | SpacingMark) => false,
+
+            // GB9b
+            (Prepend, _) => false,
+
+            // GB9c
+            _ if self.conjunct_linker && next_conjunct == IndicConjunctBreak::Consonant => false,
+
+            // GB11
+            (ZWJ, _) if self.pictographic_zwj && next_pictographic => false,
+
+            // GB12, GB13
+            (RegionalIndicator, RegionalIndicator) => !self.odd_regional,
+
+            // GB999
+            _ => true,
+        };
+
+        self.pictographic_zwj = self.pictographic && next_break == ZWJ;
+        self.pictographic = next_pictographic || (self.pictographic && next_break == Extend);
+
+        match next_conjunct {
+            IndicConjunctBreak::Consonant => {
+                self.conjunct = true;
+                self.conjunct_linker = false;
+            }
+
+            IndicConjunctBreak::Linker => self.conjunct_linker = self.conjunct,
+
+            IndicConjunctBreak::Extend => (),
+
+            _ => {
+                self.conjunct = false;
+                self.conjunct_linker = false;
+            }
+        }
+
+        self.odd_regional = match next_break {
+            RegionalIndicator => boundary || !self.odd_regional,
+            _ => false,
+        };
+
+        self.prev = next_break;
+
+        boundary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lexis::Graphemes;
+
+    fn split(string: &str) -> Vec<&str> {
+        Graphemes::new(string).collect()
+    }
+
+    #[test]
+    fn test_graphemes() {
+        assert!(split("").is_empty());
+        assert_eq!(split("abc"), ["a", "b", "c"]);
+
+        // GB3, GB4, GB5
+        assert_eq!(split("a\r\n\n\rb"), ["a", "\r\n", "\n", "\r", "b"]);
+        assert_eq!(split("\r\u{301}"), ["\r", "\u{301}"]);
+
+        // GB6, GB7, GB8
+        assert_eq!(
+            split("\u{1100}\u{1161}\u{11A8}"),
+            ["\u{1100}\u{1161}\u{11A8}"]
+        );
+        assert_eq!(
+            split("\u{AC00}\u{11A8}\u{1100}"),
+            ["\u{AC00}\u{11A8}", "\u{1100}"]
+        );
+        assert_eq!(split("\u{AC01}\u{1161}"), ["\u{AC01}", "\u{1161}"]);
+
+        // GB9, GB9a, GB9b
+        assert_eq!(split("e\u{301}\u{302}x"), ["e\u{301}\u{302}", "x"]);
+        assert_eq!(split("\u{915}\u{93F}"), ["\u{915}\u{93F}"]);
+        assert_eq!(split("\u{600}1"), ["\u{600}1"]);
+
+        // GB9c
+        assert_eq!(split("\u{915}\u{94D}\u{937}"), ["\u{915}\u{94D}\u{937}"]);
+        assert_eq!(
+            split("\u{915}\u{93C}\u{94D}\u{200D}\u{937}"),
+            ["\u{915}\u{93C}\u{94D}\u{200D}\u{937}"],
+        );
+        assert_eq!(
+            split("\u{915}\u{93C}\u{937}"),
+            ["\u{915}\u{93C}", "\u{937}"]
+        );
+        assert_eq!(split("a\u{94D}\u{937}"), ["a\u{94D}", "\u{937}"]);
+
+        // GB11
+        assert_eq!(
+            split("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}!"),
+            ["\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}", "!"],
+        );
+        assert_eq!(
+            split("\u{1F44D}\u{1F3FD}\u{200D}\u{1F525}"),
+            ["\u{1F44D}\u{1F3FD}\u{200D}\u{1F525}"],
+        );
+        assert_eq!(split("a\u{200D}\u{1F525}"), ["a\u{200D}", "\u{1F525}"]);
+
+        // GB12, GB13
+        assert_eq!(
+            split("\u{1F1FA}\u{1F1E6}\u{1F1FA}\u{1F1E6}\u{1F1FA}"),
+            ["\u{1F1FA}\u{1F1E6}", "\u{1F1FA}\u{1F1E6}", "\u{1F1FA}"],
+        );
+        assert_eq!(
+            split("a\u{1F1FA}\u{1F1E6}\u{200D}\u{1F1FA}"),
+            ["a", "\u{1F1FA}\u{1F1E6}\u{200D}", "\u{1F1FA}"],
+        );
+
+        let mut graphemes = Graphemes::new("e\u{301}x");
+
+        assert_eq!(graphemes.next(), Some("e\u{301}"));
+        assert_eq!(graphemes.as_str(), "x");
+    }
+}
@@ -32,10 +32,16 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::fmt::{Debug, Formatter};
+use alloc::{format, vec::Vec};
+use core::{
+    fmt::{Debug, Formatter},
+    iter::{from_fn, repeat},
+    mem::size_of,
+    ops::Range,
+};
 
 use crate::{
-    lexis::{Length, Line, Site, SiteSpan},
+    lexis::{ByteIndex, Column, Length, Line, Site, SiteSpan},
     mem::{slice_copy_to, slice_shift},
     report::{ld_assert, ld_unreachable},
 };
@@ -52,6 +58,17 @@ const CAPACITY_THRESHOLD: usize = 100;
 /// The object provides functions to quickly fetch the line of the character
 /// site, and the line start and end sites (line spans) by the line number.
 ///
+/// Additionally, the index tracks the UTF-8 widths of the non-ASCII
+/// characters, such that the object can map the sites to the UTF-8 byte
+/// offsets ([byte_of](LineIndex::byte_of)), and to the UTF-16 line columns
+/// ([to_utf16_position](LineIndex::to_utf16_position)) as required by
+/// the Language Server Protocol.
+///
+/// The index also tracks the tab characters, such that the object can map
+/// the sites to the tab-expanded line columns
+/// ([visual_column](LineIndex::visual_column)) as they appear in the text
+/// editors and in the terminal.
+///
 /// The [write](LineIndex::write) function provides a way to modify the index.
 ///
 /// The inner algorithm considers the `\n` character as the line delimiter
@@ -59,11 +76,37 @@ const CAPACITY_THRESHOLD: usize = 100;
 #[derive(Clone)]
 pub struct LineIndex {
     index: Vec<Site>,
+    widths: Vec<WidthRun>,
+    tabs: Vec<Site>,
+    length: Length,
+}
+
+// A continuous sequence of the non-ASCII characters of the same UTF-8 width.
+#[derive(Clone, Copy)]
+struct WidthRun {
+    start: Site,
     length: Length,
+    // The number of UTF-8 bytes of each character in this run (2, 3 or 4).
+    width: usize,
+    // The total number of the extra bytes (beyond the first byte of each
+    // character) of all previous runs.
+    extra: ByteIndex,
+}
+
+impl WidthRun {
+    #[inline(always)]
+    fn end(&self) -> Site {
+        self.start + self.length
+    }
+
+    #[inline(always)]
+    fn extra_end(&self) -> ByteIndex {
+        self.extra + (self.width - 1) * self.length
+    }
 }
 
 impl Debug for LineIndex {
-    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
         let width = (self.index.len().ilog10() + 1) as usize;
 
         let total = self.index.len();
@@ -119,7 +162,12 @@ impl LineIndex {
 
         index.push(from);
 
-        Self { index, length }
+        Self {
+            index,
+            widths: Vec::new(),
+            tabs: Vec::new(),
+            length,
+        }
     }
 
     /// Returns the start [site](Site) of the `line`.
@@ -191,6 +239,212 @@ impl LineIndex {
         self.index.len()
     }
 
+    /// Returns the UTF-8 byte index of a character addressed by the `site`
+    /// parameter.
+    ///
+    /// If the `site` exceeds the text content length, this function returns
+    /// the total byte length of the text.
+    #[inline(always)]
+    pub fn byte_of(&self, mut site: Site) -> ByteIndex {
+        site = site.min(self.length);
+
+        let run = self.widths.partition_point(|run| run.start < site);
+
+        let Some(run) = run.checked_sub(1).map(|run| &self.widths[run]) else {
+            return site;
+        };
+
+        site + run.extra + (run.width - 1) * (site - run.start).min(run.length)
+    }
+
+    /// Returns a UTF-8 byte range of the line.
+    ///
+    /// The returning value equals the range between the [byte indices](ByteIndex)
+    /// of the [line_start](Self::line_start) and the [line_end](Self::line_end)
+    /// sites.
+    ///
+    /// See [Line] specification for details.
+    #[inline(always)]
+    pub fn line_byte_range(&self, line: Line) -> Range<ByteIndex> {
+        let span = self.line_span(line);
+
+        self.byte_of(span.start)..self.byte_of(span.end)
+    }
+
+    /// Returns the [line index](Line) of a character addressed by the `site`
+    /// parameter, and the zero-based UTF-16 column of this character within
+    /// the line.
+    ///
+    /// The UTF-16 column is the number of the UTF-16 code units between
+    /// the line start and the character. The characters outside of the Basic
+    /// Multilingual Plane (such as emojis) are encoded as surrogate pairs, and
+    /// occupy two code units each.
+    ///
+    /// If the `site` exceeds the text content length, this function returns
+    /// the end of the last line.
+    pub fn to_utf16_position(&self, mut site: Site) -> (Line, usize) {
+        site = site.min(self.length);
+
+        let line = self.line_of(site);
+        let start = self.line_start(line);
+
+        let mut column = site - start;
+
+        let first = self.widths.partition_point(|run| run.end() <= start);
+
+        for run in &self.widths[first..] {
+            if run.start >= site {
+                break;
+            }
+
+            if run.width == 4 {
+                column += run.end().min(site) - run.start.max(start);
+            }
+        }
+
+        (line, column)
+    }
+
+    /// Returns a [site](Site) of a character addressed by the [line](Line)
+    /// index and the zero-based UTF-16 `column` within this line.
+    ///
+    /// This function is the inverse of
+    /// the [to_utf16_position](Self::to_utf16_position) function.
+    ///
+    /// If the `column` points in between of the surrogate pair, the function
+    /// returns the site of the character of this pair.
+    ///
+    /// If the `column` exceeds the line length, the function returns
+    /// the site of the line's `\n` delimiter, or the text content end if
+    /// the line is the last one.
+    pub fn from_utf16_position(&self, line: Line, mut column: usize) -> Site {
+        let span = self.line_span(line);
+
+        let end = match line.max(1) >= self.index.len() {
+            true => span.end,
+            false => span.end - 1,
+        };
+
+        let mut site = span.start;
+
+        let first = self.widths.partition_point(|run| run.end() <= site);
+
+        for run in &self.widths[first..] {
+            if run.start >= end {
+                break;
+            }
+
+            let gap = run.start.saturating_sub(site);
+
+            if column <= gap {
+                return site.saturating_add(column).min(end);
+            }
+
+            site += gap;
+            column -= gap;
+
+            let length = run.end() - site;
+
+            let units = match run.width == 4 {
+                true => 2,
+                false => 1,
+            };
+
+            if column < length * units {
+                return (site + column / units).min(end);
+            }
+
+            site += length;
+            column -= length * units;
+        }
+
+        site.saturating_add(column).min(end)
+    }
+
+    /// Returns the tab-expanded [column](Column) of a character addressed by
+    /// the `site` parameter within its line.
+    ///
+    /// Each tab character moves the column to the next tab stop, where the tab
+    /// stops are placed every `tab_width` columns. Any other character occupies
+    /// a single column. The `tab_width` of zero is treated as one.
+    ///
+    /// Similarly to the [Position](crate::lexis::Position) columns, the visual
+    /// column numeration starts from 1. If the line does not contain tab
+    /// characters before the `site`, the visual column equals the Position
+    /// column.
+    ///
+    /// If the `site` exceeds the text content length, this function returns
+    /// the column of the last line's end.
+    pub fn visual_column(&self, mut site: Site, tab_width: usize) -> Column {
+        site = site.min(self.length);
+
+        let mut current = self.line_start(self.line_of(site));
+        let mut offset = 0;
+
+        let first = self.tabs.partition_point(|tab| *tab < current);
+
+        for tab in &self.tabs[first..] {
+            if *tab >= site {
+                break;
+            }
+
+            offset = Self::tab_stop(offset + tab - current, tab_width);
+            current = tab + 1;
+        }
+
+        offset + site - current + 1
+    }
+
+    /// Returns a [site](Site) of a character addressed by the [line](Line)
+    /// index and the tab-expanded `column` within this line.
+    ///
+    /// This function is the inverse of
+    /// the [visual_column](Self::visual_column) function.
+    ///
+    /// If the `column` points inside the tab character's expansion,
+    /// the function returns the site of this tab character.
+    ///
+    /// If the `column` exceeds the line length, the function returns
+    /// the site of the line's `\n` delimiter, or the text content end if
+    /// the line is the last one.
+    pub fn from_visual_column(&self, line: Line, column: Column, tab_width: usize) -> Site {
+        let span = self.line_span(line);
+
+        let end = match line.max(1) >= self.index.len() {
+            true => span.end,
+            false => span.end - 1,
+        };
+
+        let target = column.saturating_sub(1);
+
+        let mut current = span.start;
+        let mut offset = 0;
+
+        let first = self.tabs.partition_point(|tab| *tab < current);
+
+        for tab in &self.tabs[first..] {
+            if *tab >= end {
+                break;
+            }
+
+            offset += tab - current;
+
+            if target < offset {
+                return tab - (offset - target);
+            }
+
+            offset = Self::tab_stop(offset, tab_width);
+
+            if target < offset {
+                return *tab;
+            }
+
+            current = tab + 1;
+        }
+
+        current.saturating_add(target - offset).min(end)
+    }
+
     /// Reserves capacity for the inner index to address the lines of a text of
     /// at least `additional` length.
     ///
@@ -224,16 +478,43 @@ impl LineIndex {
     #[inline(always)]
     pub fn shrink_to_fit(&mut self) {
         self.index.shrink_to_fit();
+        self.widths.shrink_to_fit();
+        self.tabs.shrink_to_fit();
     }
 
     /// Clears the LineIndex while preserving allocated memory.
     #[inline(always)]
     pub fn clear(&mut self) {
         unsafe { self.index.set_len(1) }
+        self.widths.clear();
+        self.tabs.clear();
         self.length = 0;
     }
 
+    #[inline(always)]
+    pub(crate) fn heap_size(&self) -> usize {
+        self.index.capacity() * size_of::<Site>()
+            + self.widths.capacity() * size_of::<WidthRun>()
+            + self.tabs.capacity() * size_of::<Site>()
+    }
+
+    // Returns the zero-based visual offset that follows the tab character
+    // written at the zero-based visual `offset`.
+    #[inline(always)]
+    pub(crate) fn tab_stop(offset: usize, tab_width: usize) -> usize {
+        let tab_width = tab_width.max(1);
+
+        offset + tab_width - offset % tab_width
+    }
+
+    #[inline(always)]
     pub(crate) fn append(&mut self, text: &str) {
+        self.append_widths(self.length, text);
+        self.append_tabs(self.length, text);
+        self.append_lines(text);
+    }
+
+    fn append_lines(&mut self, text: &str) {
         for byte in text.as_bytes() {
             match byte & 0xC0 {
                 0x80 => continue,
@@ -259,6 +540,9 @@ impl LineIndex {
     pub(crate) unsafe fn shrink_unchecked(&mut self, span: Length) {
         ld_assert!(span <= self.length, "Shrink overflow.");
 
+        self.write_widths((self.length - span)..self.length, "");
+        self.write_tabs((self.length - span)..self.length, "");
+
         self.length -= span;
 
         loop {
@@ -286,8 +570,11 @@ impl LineIndex {
             "Invalid span.",
         );
 
+        self.write_widths(span.clone(), text);
+        self.write_tabs(span.clone(), text);
+
         if span.start == self.length {
-            self.append(text);
+            self.append_lines(text);
             return;
         }
 
@@ -299,8 +586,12 @@ impl LineIndex {
         ld_assert!(start_line <= self.index.len(), "Invalid index.");
 
         if start_line == self.index.len() {
-            self.length -= remove_length;
-            self.append(text);
+            let tail = self.length - span.end;
+
+            self.length = span.start;
+            self.append_lines(text);
+            self.length += tail;
+
             return;
         }
 
@@ -339,10 +630,13 @@ impl LineIndex {
         );
 
         if start_line + remove_lines - 1 == self.index.len() {
-            self.length -= remove_length;
+            let tail = self.length - span.end;
+
+            self.length = span.start;
             unsafe { self.index.set_len(start_line) };
 
-            self.append(text);
+            self.append_lines(text);
+            self.length += tail;
 
             return;
         }
@@ -350,7 +644,7 @@ impl LineIndex {
         let start_line_site = self.line_start(start_line);
 
         let mut replacement = Self::with_capacity_from(text.len(), start_line_site, span.start);
-        replacement.append(text);
+        replacement.append_lines(text);
 
         let replace_length = replacement.length - span.start;
         let replace_lines = replacement.index.len();
@@ -431,6 +725,233 @@ impl LineIndex {
     pub(crate) fn code_length(&self) -> Length {
         self.length
     }
+
+    fn append_widths(&mut self, mut site: Site, text: &str) {
+        if text.is_ascii() {
+            return;
+        }
+
+        for character in text.chars() {
+            let width = character.len_utf8();
+
+            if width > 1 {
+                self.push_width_run(site, 1, width);
+            }
+
+            site += 1;
+        }
+    }
+
+    fn write_widths(&mut self, span: SiteSpan, text: &str) {
+        let first = self.widths.partition_point(|run| run.end() <= span.start);
+
+        if first == self.widths.len() && text.is_ascii() {
+            return;
+        }
+
+        let last = self.widths.partition_point(|run| run.start < span.end);
+
+        let insert_length = text.chars().count();
+        let remove_length = span.end - span.start;
+
+        let mut left = None;
+        let mut right = None;
+
+        if first < last {
+            let run = self.widths[first];
+
+            if run.start < span.start {
+                left = Some(WidthRun {
+                    start: run.start,
+                    length: span.start - run.start,
+                    width: run.width,
+                    extra: 0,
+                });
+            }
+
+            let run = self.widths[last - 1];
+
+            if run.end() > span.end {
+                right = Some(WidthRun {
+                    start: span.start + insert_length,
+                    length: run.end() - span.end,
+                    width: run.width,
+                    extra: 0,
+                });
+            }
+        }
+
+        let replace = left.is_some() as usize
+            + Self::width_runs(span.start, text).count()
+            + right.is_some() as usize;
+
+        // The placeholders are overwritten right below. The exact size of
+        // the replacement iterator lets the splice shift the tail in place.
+        drop(self.widths.splice(
+            first..last,
+            repeat(WidthRun {
+                start: 0,
+                length: 0,
+                width: 1,
+                extra: 0,
+            })
+            .take(replace),
+        ));
+
+        let mut slot = first;
+
+        for run in left
+            .into_iter()
+            .chain(Self::width_runs(span.start, text))
+            .chain(right)
+        {
+            self.widths[slot] = run;
+            slot += 1;
+        }
+
+        // Shifts the tail runs, joins the adjacent runs of the same width
+        // on the replacement bounds, and recomputes the extra bytes
+        // in a single pass.
+        let mut length = first;
+
+        for index in first..self.widths.len() {
+            let mut run = self.widths[index];
+
+            if index >= slot {
+                run.start = run.start - remove_length + insert_length;
+            }
+
+            run.extra = match length.checked_sub(1) {
+                Some(previous) => {
+                    let previous = &mut self.widths[previous];
+
+                    if previous.end() == run.start && previous.width == run.width {
+                        previous.length += run.length;
+                        continue;
+                    }
+
+                    previous.extra_end()
+                }
+
+                None => 0,
+            };
+
+            self.widths[length] = run;
+            length += 1;
+        }
+
+        self.widths.truncate(length);
+    }
+
+    // Yields the runs of the non-ASCII characters of the text, assuming that
+    // the text starts at the `site`.
+    fn width_runs(mut site: Site, text: &str) -> impl Iterator<Item = WidthRun> + '_ {
+        let mut characters = text.chars();
+        let mut pending = None::<WidthRun>;
+
+        from_fn(move || loop {
+            let Some(character) = characters.next() else {
+                return pending.take();
+            };
+
+            let start = site;
+            let width = character.len_utf8();
+
+            site += 1;
+
+            if width == 1 {
+                match pending.is_some() {
+                    true => return pending.take(),
+                    false => continue,
+                }
+            }
+
+            match &mut pending {
+                Some(run) if run.width == width => run.length += 1,
+
+                _ => {
+                    let next = WidthRun {
+                        start,
+                        length: 1,
+                        width,
+                        extra: 0,
+                    };
+
+                    if let Some(run) = pending.replace(next) {
+                        return Some(run);
+                    }
+                }
+            }
+        })
+    }
+
+    fn append_tabs(&mut self, mut site: Site, text: &str) {
+        if !text.contains('\t') {
+            return;
+        }
+
+        for character in text.chars() {
+            if character == '\t' {
+                self.tabs.push(site);
+            }
+
+            site += 1;
+        }
+    }
+
+    fn write_tabs(&mut self, span: SiteSpan, text: &str) {
+        let first = self.tabs.partition_point(|tab| *tab < span.start);
+
+        if first == self.tabs.len() && !text.contains('\t') {
+            return;
+        }
+
+        let last = self.tabs.partition_point(|tab| *tab < span.end);
+
+        let insert_length = text.chars().count();
+        let remove_length = span.end - span.start;
+
+        // The placeholders are overwritten right below. The exact size of
+        // the replacement iterator lets the splice shift the tail in place.
+        drop(self.tabs.splice(
+            first..last,
+            repeat(0).take(text.bytes().filter(|byte| *byte == b'\t').count()),
+        ));
+
+        let mut slot = first;
+
+        for (offset, character) in text.chars().enumerate() {
+            if character == '\t' {
+                self.tabs[slot] = span.start + offset;
+                slot += 1;
+            }
+        }
+
+        for tab in &mut self.tabs[slot..] {
+            *tab = *tab - remove_length + insert_length;
+        }
+    }
+
+    #[inline]
+    fn push_width_run(&mut self, start: Site, length: Length, width: usize) {
+        let extra = match self.widths.last_mut() {
+            Some(last) if last.end() == start && last.width == width => {
+                last.length += length;
+                return;
+            }
+
+            Some(last) => last.extra_end(),
+
+            None => 0,
+        };
+
+        self.widths.push(WidthRun {
+            start,
+            length,
+            width,
+            extra,
+        });
+    }
 }
 
 #[cfg(test)]
@@ -509,4 +1030,129 @@ mod tests {
         assert_eq!(index.index, [0]);
         assert_eq!(index.length, 0);
     }
+
+    #[test]
+    fn test_line_index_last_line_write() {
+        let mut index = LineIndex::new();
+
+        index.append("{abc}");
+        index.write(1..1, "\n");
+
+        assert_eq!(index.index, [0, 2]);
+        assert_eq!(index.length, 6);
+
+        index.write(4..5, "\n\n");
+
+        assert_eq!(index.index, [0, 2, 5, 6]);
+        assert_eq!(index.length, 7);
+
+        index.write(1..6, "");
+
+        assert_eq!(index.index, [0]);
+        assert_eq!(index.length, 2);
+    }
+
+    #[test]
+    fn test_line_index_encodings() {
+        let mut index = LineIndex::new();
+
+        index.append("a𝕏b\n😀щ\r\n字");
+
+        assert_eq!(index.byte_of(0), 0);
+        assert_eq!(index.byte_of(2), 5);
+        assert_eq!(index.byte_of(5), 11);
+        assert_eq!(index.byte_of(9), 18);
+        assert_eq!(index.byte_of(100), 18);
+
+        assert_eq!(index.line_byte_range(1), 0..7);
+        assert_eq!(index.line_byte_range(2), 7..15);
+        assert_eq!(index.line_byte_range(3), 15..18);
+
+        assert_eq!(index.to_utf16_position(2), (1, 3));
+        assert_eq!(index.to_utf16_position(3), (1, 4));
+        assert_eq!(index.to_utf16_position(5), (2, 2));
+        assert_eq!(index.to_utf16_position(9), (3, 1));
+
+        assert_eq!(index.from_utf16_position(1, 1), 1);
+        assert_eq!(index.from_utf16_position(1, 2), 1);
+        assert_eq!(index.from_utf16_position(1, 3), 2);
+        assert_eq!(index.from_utf16_position(1, 100), 3);
+        assert_eq!(index.from_utf16_position(2, 2), 5);
+        assert_eq!(index.from_utf16_position(2, 3), 6);
+        assert_eq!(index.from_utf16_position(3, 0), 8);
+        assert_eq!(index.from_utf16_position(3, 100), 9);
+
+        index.write(1..5, "字字");
+
+        assert_eq!(index.widths.len(), 3);
+        assert_eq!(index.byte_of(3), 7);
+        assert_eq!(index.line_byte_range(1), 0..11);
+        assert_eq!(index.to_utf16_position(4), (1, 4));
+
+        index.write(3..4, "字");
+
+        assert_eq!(index.widths.len(), 2);
+        assert_eq!(index.line_byte_range(1), 0..12);
+        assert_eq!(index.from_utf16_position(2, 0), 6);
+        assert_eq!(index.to_utf16_position(7), (2, 1));
+
+        index.write(0..7, "𝕏");
+
+        assert_eq!(index.widths.len(), 1);
+        assert_eq!(index.byte_of(1), 4);
+        assert_eq!(index.to_utf16_position(1), (1, 2));
+    }
+
+    #[test]
+    fn test_line_index_tabs() {
+        let mut index = LineIndex::new();
+
+        index.append("\tab\n\t\tc\td\nx");
+
+        assert_eq!(index.tabs, [0, 4, 5, 7]);
+
+        assert_eq!(index.visual_column(0, 4), 1);
+        assert_eq!(index.visual_column(1, 4), 5);
+        assert_eq!(index.visual_column(3, 4), 7);
+        assert_eq!(index.visual_column(5, 4), 5);
+        assert_eq!(index.visual_column(6, 4), 9);
+        assert_eq!(index.visual_column(7, 4), 10);
+        assert_eq!(index.visual_column(8, 4), 13);
+        assert_eq!(index.visual_column(9, 4), 14);
+        assert_eq!(index.visual_column(10, 4), 1);
+        assert_eq!(index.visual_column(100, 4), 2);
+
+        assert_eq!(index.visual_column(6, 2), 5);
+        assert_eq!(index.visual_column(8, 2), 7);
+        assert_eq!(index.visual_column(6, 8), 17);
+        assert_eq!(index.visual_column(8, 8), 25);
+        assert_eq!(index.visual_column(8, 0), 5);
+
+        assert_eq!(index.from_visual_column(1, 0, 4), 0);
+        assert_eq!(index.from_visual_column(1, 4, 4), 0);
+        assert_eq!(index.from_visual_column(1, 5, 4), 1);
+        assert_eq!(index.from_visual_column(2, 3, 4), 4);
+        assert_eq!(index.from_visual_column(2, 5, 4), 5);
+        assert_eq!(index.from_visual_column(2, 9, 4), 6);
+        assert_eq!(index.from_visual_column(2, 11, 4), 7);
+        assert_eq!(index.from_visual_column(2, 13, 4), 8);
+        assert_eq!(index.from_visual_column(2, 100, 4), 9);
+        assert_eq!(index.from_visual_column(3, 100, 4), 11);
+
+        index.write(5..6, "");
+
+        assert_eq!(index.tabs, [0, 4, 6]);
+        assert_eq!(index.visual_column(5, 4), 5);
+        assert_eq!(index.visual_column(7, 4), 9);
+
+        index.write(0..0, "\t");
+
+        assert_eq!(index.tabs, [0, 1, 5, 7]);
+        assert_eq!(index.visual_column(2, 4), 9);
+
+        index.write(1..3, "x");
+
+        assert_eq!(index.tabs, [0, 4, 6]);
+        assert_eq!(index.visual_column(2, 4), 6);
+    }
 }
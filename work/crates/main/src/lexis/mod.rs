@@ -36,15 +36,23 @@ mod buffer;
 mod chunks;
 mod code;
 mod cursor;
+mod foreign;
+mod graphemes;
 mod lines;
 mod position;
 mod rule;
+mod scripts;
 mod session;
 mod site;
 mod span;
 mod token;
 mod ucd;
 
+// An estimated average length of a token in bytes.
+//
+// The crate uses this value to preallocate the token and text buffers based on
+// the source code length. It does not limit the token length, and it does not
+// configure the storage structure of the compilation units.
 pub(crate) const CHUNK_SIZE: Length = 5;
 
 pub(crate) use crate::lexis::site::SiteRefInner;
@@ -53,12 +61,26 @@ pub use crate::lexis::{
     chunks::Chunk,
     code::{ChunkIter, SourceCode},
     cursor::TokenCursor,
+    foreign::ForeignScanner,
+    graphemes::{GraphemeSpans, Graphemes},
     lines::LineIndex,
     position::{Column, Line, Position},
     rule::{TokenRule, TokenSet, EMPTY_TOKEN_SET, EOI, FULL_TOKEN_SET, MISMATCH},
+    scripts::is_single_script,
     session::LexisSession,
     site::{ByteIndex, Length, Site, SiteRef, ToSite, NIL_SITE_REF},
     span::{PositionSpan, SiteRefSpan, SiteSpan, ToSpan},
     token::{Token, TokenCount, TokenRef, NIL_TOKEN_REF},
-    ucd::{Char, CharProperties},
+    ucd::{
+        ucd_version,
+        CaseMapping,
+        Char,
+        CharProperties,
+        GraphemeClusterBreak,
+        IndicConjunctBreak,
+        Script,
+        ScriptSet,
+        UCD_CHECKSUMS,
+        UNICODE_VERSION,
+    },
 };
@@ -32,7 +32,7 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::{
+use core::{
     cmp::Ordering,
     fmt::{Display, Formatter},
     ops::AddAssign,
@@ -63,6 +63,11 @@ pub type Line = usize;
 ///
 /// Note that the line delimiters (`\n` and `\r` chars) are parts of the line
 /// tail.
+///
+/// Each tab character counts as a single column. To compute the column of
+/// the character as it appears in the text editor with the tabs expanded,
+/// use the [LineIndex::visual_column](crate::lexis::LineIndex::visual_column)
+/// function.
 pub type Column = usize;
 
 /// A line-column index of the Unicode character within the source code text.
@@ -111,7 +116,7 @@ impl Default for Position {
 
 impl Display for Position {
     #[inline(always)]
-    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
         formatter.write_fmt(format_args!("{}:{}", self.line, self.column))
     }
 }
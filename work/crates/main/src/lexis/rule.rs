@@ -32,7 +32,8 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::{
+use alloc::vec::Vec;
+use core::{
     fmt::{Debug, Display, Formatter},
     iter::FusedIterator,
     marker::PhantomData,
@@ -86,7 +87,7 @@ pub struct TokenSet {
 
 impl Debug for TokenSet {
     #[inline(always)]
-    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
         formatter.debug_set().entries(self.into_iter()).finish()
     }
 }
@@ -358,13 +359,13 @@ impl TokenSet {
 
         impl<'a, T: Token> Debug for DisplayTokenSet<'a, T> {
             #[inline(always)]
-            fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+            fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
                 Display::fmt(self, formatter)
             }
         }
 
         impl<'a, T: Token> Display for DisplayTokenSet<'a, T> {
-            fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+            fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
                 let mut vector = Vec::with_capacity(0xFF);
 
                 for token in self.set {
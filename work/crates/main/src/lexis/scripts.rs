@@ -0,0 +1,118 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use crate::lexis::{Char, Script, ScriptSet};
+
+/// Returns true if all characters of the `string` belong to
+/// a single Unicode script.
+///
+/// This function implements the "single-script" check of the
+/// [UTS #39](https://www.unicode.org/reports/tr39/#Mixed_Script_Detection)
+/// specification based on the [Char::script_extensions] property: the
+/// string is single-script if the intersection of the script extensions
+/// of all its characters is not empty. The characters of the
+/// [Common](Script::Common) and [Inherited](Script::Inherited) scripts
+/// (digits, punctuation, combining marks, etc.) are compatible with any
+/// script.
+///
+/// The function is useful for detecting confusable identifiers, such as
+/// a Latin identifier that contains a Cyrillic letter:
+///
+/// ```rust
+/// use lady_deirdre::lexis::is_single_script;
+///
+/// assert!(is_single_script("foo_bar1"));
+/// assert!(is_single_script("привет"));
+/// assert!(!is_single_script("p\u{430}ypal"));
+/// ```
+///
+/// The augmented script sets of UTS #39 (e.g. treating Han, Hiragana,
+/// and Katakana as a single Japanese script) are not applied.
+///
+/// An empty string is considered single-script.
+pub fn is_single_script(string: &str) -> bool {
+    let mut common = None::<ScriptSet>;
+
+    for ch in string.chars() {
+        let extensions = ch.script_extensions();
+
+        if extensions
+            .iter()
+            .any(|script| *script == Script::Common || *script == Script::Inherited)
+        {
+            continue;
+        }
+
+        let scripts = extensions.iter().copied().collect::<ScriptSet>();
+
+        let intersection = match common {
+            Some(common) => common.intersect(scripts),
+            None => scripts,
+        };
+
+        if intersection.is_empty() {
+            return false;
+        }
+
+        common = Some(intersection);
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lexis::is_single_script;
+
+    #[test]
+    fn test_single_script() {
+        assert!(is_single_script(""));
+        assert!(is_single_script("123 + 456"));
+        assert!(is_single_script("hello_world"));
+        assert!(is_single_script("e\u{301}t\u{E9}"));
+        assert!(is_single_script("\u{3B1}\u{3B2}\u{3B3}"));
+        assert!(is_single_script(
+            "\u{43F}\u{440}\u{438}\u{432}\u{435}\u{442}"
+        ));
+
+        // Cyrillic "а" inside a Latin identifier.
+        assert!(!is_single_script("p\u{430}ypal"));
+        assert!(!is_single_script("a\u{3B1}"));
+
+        // U+0640 ARABIC TATWEEL is used by Arabic and Syriac.
+        assert!(is_single_script("\u{628}\u{640}"));
+        assert!(is_single_script("\u{710}\u{640}"));
+        assert!(!is_single_script("\u{628}\u{640}\u{710}"));
+    }
+}
@@ -35,7 +35,7 @@
 #[cfg(debug_assertions)]
 use crate::report::system_panic;
 use crate::{
-    lexis::{ByteIndex, Site, Token, TokenBuffer},
+    lexis::{ByteIndex, Site, Token, TokenBuffer, TokenRule, EOI},
     report::{ld_assert, ld_assert_ne},
 };
 
@@ -214,6 +214,58 @@ pub unsafe trait LexisSession {
     /// 2. The caller side has successfully read at least one code point from
     ///    the input stream.
     unsafe fn submit(&mut self);
+
+    /// Moves the byte-cursor back to the beginning of the scanning token.
+    ///
+    /// After this call, the next [advance](LexisSession::advance) call returns
+    /// the first byte of the token again. The previously
+    /// [submitted](LexisSession::submit) position remains unchanged.
+    ///
+    /// This function is useful for the scanning algorithms that need to
+    /// inspect the input ahead of the token end before consuming the token
+    /// (see [ForeignScanner](crate::lexis::ForeignScanner)).
+    ///
+    /// The default implementation panics. The sessions of the compilation
+    /// units provided by this crate support rewinding.
+    #[inline(always)]
+    fn rewind(&mut self) {
+        panic!("This LexisSession does not support rewinding.");
+    }
+
+    /// Returns the rule of the last token preceding the scanning token,
+    /// skipping the tokens of the [blank](Token::BLANK) rules.
+    ///
+    /// If there are no such tokens (e.g., the scanning token is the first
+    /// non-blank token of the source code), this function returns
+    /// the [EOI](crate::lexis::EOI) rule.
+    ///
+    /// The scanning algorithm may use this value to restrict the rules
+    /// depending on the lexical context. For example, in JavaScript-like
+    /// languages, the `/` character starts a regular expression literal
+    /// unless it follows an identifier, a literal, or a closing bracket.
+    ///
+    /// The default implementation always returns EOI, as if the scanning
+    /// token were the first token of the source code.
+    #[inline(always)]
+    fn previous(&self) -> TokenRule {
+        EOI
+    }
+
+    /// Returns true if the scanning token starts at the beginning of a line:
+    /// either at the beginning of the source code, or right after
+    /// the `\n` character.
+    ///
+    /// The scanning algorithm may use this value to restrict the rules
+    /// that are valid at the beginning of a line only (e.g., the `#include`
+    /// preprocessor directives in C-like languages).
+    ///
+    /// The default implementation always returns true, as if the scanning
+    /// token started at the beginning of the source code, consistently with
+    /// the default [previous](Self::previous) implementation.
+    #[inline(always)]
+    fn line_start(&self) -> bool {
+        true
+    }
 }
 
 pub(super) struct BufferLexisSession<'code, T: Token> {
@@ -221,6 +273,9 @@ pub(super) struct BufferLexisSession<'code, T: Token> {
     pub(super) begin: Cursor,
     pub(super) end: Cursor,
     pub(super) current: Cursor,
+    pub(super) previous: TokenRule,
+    pub(super) line_start: bool,
+    pub(super) limit: ByteIndex,
 }
 
 unsafe impl<'code, T: Token> LexisSession for BufferLexisSession<'code, T> {
@@ -256,21 +311,64 @@ unsafe impl<'code, T: Token> LexisSession for BufferLexisSession<'code, T> {
 
         self.end = self.current;
     }
+
+    #[inline(always)]
+    fn rewind(&mut self) {
+        self.current = self.begin;
+    }
+
+    #[inline(always)]
+    fn previous(&self) -> TokenRule {
+        self.previous
+    }
+
+    #[inline(always)]
+    fn line_start(&self) -> bool {
+        self.line_start
+    }
 }
 
 impl<'code, T: Token> BufferLexisSession<'code, T> {
+    // Scans the buffer's text starting from the `byte` and `site` position
+    // until the end of a token reaches the `limit` byte index, or until
+    // the end of the text.
+    //
+    // Returns the byte index of the end of the last scanned token.
     #[inline]
-    pub(super) fn run(buffer: &'code mut TokenBuffer<T>, byte: ByteIndex, site: Site)
+    pub(super) fn run(
+        buffer: &'code mut TokenBuffer<T>,
+        byte: ByteIndex,
+        site: Site,
+        limit: ByteIndex,
+    ) -> ByteIndex
     where
         T: Token,
     {
         let cursor = Cursor { byte, site };
 
+        let previous = buffer
+            .tokens
+            .iter()
+            .rev()
+            .map(|token| token.rule())
+            .find(|rule| !T::BLANK.contains(*rule))
+            .unwrap_or(EOI);
+
+        let line_start = match byte.checked_sub(1) {
+            None => true,
+            Some(last) => buffer.text.as_bytes()[last] == b'\n',
+        };
+
+        let buffer_len = buffer.text.len();
+
         let mut session = Self {
             buffer,
             begin: cursor,
             end: cursor,
             current: cursor,
+            previous,
+            line_start,
+            limit: limit.min(buffer_len),
         };
 
         loop {
@@ -278,8 +376,10 @@ impl<'code, T: Token> BufferLexisSession<'code, T> {
 
             if session.begin.byte != session.end.byte {
                 session.buffer.push(token, &session.begin, &session.end);
+                session.track(token);
+                session.track_line_start();
 
-                if session.end.byte == session.buffer.text.len() {
+                if session.end.byte >= session.limit {
                     break;
                 }
 
@@ -293,6 +393,8 @@ impl<'code, T: Token> BufferLexisSession<'code, T> {
                 break;
             }
         }
+
+        session.end.byte
     }
 
     // Returns true if the parsing process supposed to stop
@@ -303,14 +405,21 @@ impl<'code, T: Token> BufferLexisSession<'code, T> {
     {
         let mismatch = self.begin;
 
+        self.track(T::mismatch());
+
         loop {
-            if self.begin.advance(self.buffer) == 0xFF {
+            let byte = self.begin.advance(self.buffer);
+
+            if byte == 0xFF {
                 self.buffer.push(T::mismatch(), &mismatch, &self.begin);
+                self.end = self.begin;
                 return true;
             }
 
             self.begin.consume(self.buffer);
 
+            self.line_start = byte == b'\n';
+
             self.end = self.begin;
             self.current = self.begin;
 
@@ -322,8 +431,10 @@ impl<'code, T: Token> BufferLexisSession<'code, T> {
 
             self.buffer.push(T::mismatch(), &mismatch, &self.begin);
             self.buffer.push(token, &self.begin, &self.end);
+            self.track(token);
+            self.track_line_start();
 
-            if self.end.byte == self.buffer.text.len() {
+            if self.end.byte >= self.limit {
                 return true;
             }
 
@@ -333,6 +444,21 @@ impl<'code, T: Token> BufferLexisSession<'code, T> {
             return false;
         }
     }
+
+    #[inline(always)]
+    fn track(&mut self, token: T) {
+        let rule = token.rule();
+
+        if !T::BLANK.contains(rule) {
+            self.previous = rule;
+        }
+    }
+
+    // The `end` cursor must be ahead of the beginning of the text.
+    #[inline(always)]
+    fn track_line_start(&mut self) {
+        self.line_start = self.buffer.text.as_bytes()[self.end.byte - 1] == b'\n';
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -428,3 +554,180 @@ impl Cursor {
         ch
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::{
+        string::{String, ToString},
+        vec::Vec,
+    };
+
+    use crate::{
+        lexis::{Length, LexisSession, SourceCode, Token, TokenBuffer, TokenRule, TokenSet},
+        syntax::VoidSyntax,
+        units::Document,
+    };
+
+    // A slash is a division operator after a word, and the start of
+    // a slash-terminated literal otherwise.
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    #[repr(u8)]
+    enum ContextToken {
+        EOI = 0,
+        Mismatch = 1,
+        Word,
+        Space,
+        Div,
+        Literal,
+    }
+
+    impl Token for ContextToken {
+        const LOOKBACK: Length = 1;
+        const BLANK: TokenSet = TokenSet::inclusive(&[Self::Space as u8]);
+
+        fn scan(session: &mut impl LexisSession) -> Self {
+            let previous = session.previous();
+
+            match session.advance() {
+                b'a'..=b'z' => {
+                    unsafe { session.submit() };
+
+                    while let b'a'..=b'z' = session.advance() {
+                        unsafe { session.submit() };
+                    }
+
+                    Self::Word
+                }
+
+                b' ' => {
+                    unsafe { session.submit() };
+
+                    while session.advance() == b' ' {
+                        unsafe { session.submit() };
+                    }
+
+                    Self::Space
+                }
+
+                b'/' if previous == Self::Word as u8 => {
+                    unsafe { session.submit() };
+
+                    Self::Div
+                }
+
+                b'/' => loop {
+                    match session.advance() {
+                        b'/' => {
+                            unsafe { session.submit() };
+
+                            break Self::Literal;
+                        }
+
+                        b'a'..=b'z' | b' ' => (),
+
+                        _ => break Self::Mismatch,
+                    }
+                },
+
+                _ => Self::Mismatch,
+            }
+        }
+
+        #[inline(always)]
+        fn eoi() -> Self {
+            Self::EOI
+        }
+
+        #[inline(always)]
+        fn mismatch() -> Self {
+            Self::Mismatch
+        }
+
+        #[inline(always)]
+        fn rule(self) -> TokenRule {
+            self as TokenRule
+        }
+
+        fn rule_name(_rule: TokenRule) -> Option<&'static str> {
+            None
+        }
+
+        fn rule_description(_rule: TokenRule, _verbose: bool) -> Option<&'static str> {
+            None
+        }
+    }
+
+    fn tokens(code: &impl SourceCode<Token = ContextToken>) -> Vec<(ContextToken, String)> {
+        code.chunks(..)
+            .map(|chunk| (chunk.token, chunk.string.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_previous_token() {
+        let buffer = TokenBuffer::<ContextToken>::parse("a /b c/ d / e");
+
+        assert_eq!(
+            tokens(&buffer),
+            [
+                (ContextToken::Word, "a".to_string()),
+                (ContextToken::Space, " ".to_string()),
+                (ContextToken::Div, "/".to_string()),
+                (ContextToken::Word, "b".to_string()),
+                (ContextToken::Space, " ".to_string()),
+                (ContextToken::Word, "c".to_string()),
+                (ContextToken::Div, "/".to_string()),
+                (ContextToken::Space, " ".to_string()),
+                (ContextToken::Word, "d".to_string()),
+                (ContextToken::Space, " ".to_string()),
+                (ContextToken::Div, "/".to_string()),
+                (ContextToken::Space, " ".to_string()),
+                (ContextToken::Word, "e".to_string()),
+            ],
+        );
+
+        let mut buffer = TokenBuffer::<ContextToken>::parse("/a b/");
+
+        assert_eq!(
+            tokens(&buffer),
+            [(ContextToken::Literal, "/a b/".to_string())]
+        );
+
+        buffer.append(" /c/");
+
+        assert_eq!(
+            tokens(&buffer),
+            tokens(&TokenBuffer::<ContextToken>::parse("/a b/ /c/")),
+        );
+
+        let mut doc = Document::<VoidSyntax<ContextToken>>::new_mutable("x  /a b/ /c d/ e");
+
+        let edits = [
+            (0..1, "/"),
+            (0..1, "x"),
+            (0..2, ""),
+            (0..0, "y"),
+            (2..2, "z"),
+            (1..3, "/ab"),
+            (13..13, "/"),
+            (0..0, "w /"),
+        ];
+
+        for (span, text) in edits {
+            doc.write(span, text);
+
+            let fresh = TokenBuffer::<ContextToken>::parse(doc.substring(..));
+
+            assert_eq!(tokens(&doc), tokens(&fresh), "{:?}", doc.substring(..));
+        }
+
+        let mut doc = Document::<VoidSyntax<ContextToken>>::new_mutable("ab / c");
+
+        doc.write(4..4, "x");
+
+        assert_eq!(
+            tokens(&doc),
+            tokens(&TokenBuffer::<ContextToken>::parse("ab /x c")),
+        );
+    }
+}
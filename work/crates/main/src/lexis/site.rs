@@ -32,12 +32,13 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::fmt::{Debug, Display, Formatter};
+use core::fmt::{Debug, Display, Formatter};
 
+#[cfg(feature = "std")]
+use crate::format::{AnnotationPriority, SnippetFormatter};
 use crate::{
     arena::{Id, Identifiable},
-    format::{AnnotationPriority, SnippetFormatter},
-    lexis::{Position, SourceCode, TokenCursor, TokenRef, NIL_TOKEN_REF},
+    lexis::{Line, Position, SourceCode, TokenCursor, TokenRef, NIL_TOKEN_REF},
     report::ld_unreachable,
     syntax::PolyRef,
 };
@@ -112,7 +113,7 @@ pub struct SiteRef(SiteRefInner);
 
 impl Debug for SiteRef {
     #[inline]
-    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
         match &self.0 {
             SiteRefInner::CodeEnd(id) => formatter.write_fmt(format_args!("SiteRef({:?})", id)),
             SiteRefInner::ChunkStart(reference) => match reference.is_nil() {
@@ -342,54 +343,24 @@ pub unsafe trait ToSite {
     ///
     /// Returns None, if the index object is not [valid](Self::is_valid_site).
     fn to_byte_index(&self, code: &impl SourceCode) -> Option<ByteIndex> {
-        let mut site = match self.to_site(code) {
-            None => return None,
-            Some(site) => site,
-        };
+        let site = self.to_site(code)?;
 
-        if site == 0 {
-            return Some(0);
-        }
+        Some(code.lines().byte_of(site))
+    }
 
-        let mut cursor = code.cursor(..);
-        let mut byte_index = 0;
-        let mut token_index = 0;
-
-        loop {
-            let length = match cursor.length(token_index) {
-                None => break,
-                Some(length) => length,
-            };
-
-            let string = match cursor.string(token_index) {
-                None => break,
-                Some(string) => string,
-            };
-
-            if site > length {
-                site -= length;
-                byte_index += string.len();
-                token_index += 1;
-                continue;
-            }
-
-            if site == 0 {
-                break;
-            }
-
-            for character in string.chars() {
-                site -= 1;
-                byte_index += character.len_utf8();
-
-                if site == 0 {
-                    break;
-                }
-            }
-
-            break;
-        }
+    /// Returns the [line index](Line) of the character to which this index
+    /// object points in the `code`'s text, and the zero-based UTF-16 column
+    /// of this character within the line.
+    ///
+    /// See [LineIndex::to_utf16_position](crate::lexis::LineIndex::to_utf16_position)
+    /// for details.
+    ///
+    /// Returns None, if the index object is not [valid](Self::is_valid_site).
+    #[inline(always)]
+    fn to_utf16_position(&self, code: &impl SourceCode) -> Option<(Line, usize)> {
+        let site = self.to_site(code)?;
 
-        Some(byte_index)
+        Some(code.lines().to_utf16_position(site))
     }
 
     /// Returns true if this index object considered valid within the `code`
@@ -419,7 +390,7 @@ where
     Code: SourceCode,
 {
     #[inline(always)]
-    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
         Display::fmt(self, formatter)
     }
 }
@@ -428,7 +399,7 @@ impl<'a, Code> Display for DisplaySite<'a, Code>
 where
     Code: SourceCode,
 {
-    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
         let site = match &self.site {
             None => return formatter.write_str("?"),
             Some(site) => *site,
@@ -441,15 +412,16 @@ where
             None => unsafe { ld_unreachable!("Invalid site.") },
         };
 
-        if !formatter.alternate() {
-            return formatter.write_fmt(format_args!("{}", position));
+        #[cfg(feature = "std")]
+        if formatter.alternate() {
+            return formatter
+                .snippet(self.code)
+                .set_caption(format!("Unit({})", self.code.id()))
+                .set_summary(format!("Site: {site}\nPosition: {position}"))
+                .annotate(site..site, AnnotationPriority::Default, "")
+                .finish();
         }
 
-        formatter
-            .snippet(self.code)
-            .set_caption(format!("Unit({})", self.code.id()))
-            .set_summary(format!("Site: {site}\nPosition: {position}"))
-            .annotate(site..site, AnnotationPriority::Default, "")
-            .finish()
+        formatter.write_fmt(format_args!("{}", position))
     }
 }
@@ -32,14 +32,15 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::{
+use core::{
     fmt::{Debug, Display, Formatter},
     ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive},
 };
 
+#[cfg(feature = "std")]
+use crate::format::{AnnotationPriority, SnippetFormatter};
 use crate::{
     arena::{Id, Identifiable},
-    format::{AnnotationPriority, SnippetFormatter},
     lexis::{Position, Site, SiteRef, SourceCode, ToSite},
     report::ld_unreachable,
 };
@@ -351,7 +352,7 @@ where
     Code: SourceCode,
 {
     #[inline(always)]
-    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
         Display::fmt(self, formatter)
     }
 }
@@ -360,55 +361,56 @@ impl<'a, Code> Display for DisplaySpan<'a, Code>
 where
     Code: SourceCode,
 {
-    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
         let span = match &self.span {
             None => return formatter.write_str("?"),
             Some(span) => span.clone(),
         };
 
-        if !formatter.alternate() {
-            let chars = span.end - span.start;
-            let breaks = self.code.chars(&span).filter(|ch| *ch == '\n').count();
+        #[cfg(feature = "std")]
+        if formatter.alternate() {
+            return formatter
+                .snippet(self.code)
+                .set_caption(format!("Unit({})", self.code.id()))
+                .set_summary(format!(
+                    "Site span: {}..{}\nPosition span: {}",
+                    span.start,
+                    span.end,
+                    span.display(self.code),
+                ))
+                .annotate(span, AnnotationPriority::Default, "")
+                .finish();
+        }
 
-            let span = match span.to_position_span(self.code) {
-                Some(span) => span,
+        let chars = span.end - span.start;
+        let breaks = self.code.chars(&span).filter(|ch| *ch == '\n').count();
 
-                // Safety: Site spans are always valid to resolve.
-                None => unsafe { ld_unreachable!("Invalid position span.") },
-            };
+        let span = match span.to_position_span(self.code) {
+            Some(span) => span,
 
-            formatter.write_fmt(format_args!("{}", span.start))?;
+            // Safety: Site spans are always valid to resolve.
+            None => unsafe { ld_unreachable!("Invalid position span.") },
+        };
 
-            if chars > 0 {
-                formatter.write_str(" (")?;
+        formatter.write_fmt(format_args!("{}", span.start))?;
 
-                match chars > 1 {
-                    false => formatter.write_str("1 char")?,
-                    true => formatter.write_fmt(format_args!("{chars} chars"))?,
-                }
+        if chars > 0 {
+            formatter.write_str(" (")?;
 
-                match breaks {
-                    0 => (),
-                    1 => formatter.write_str(", 1 line break")?,
-                    _ => formatter.write_fmt(format_args!(", {breaks} line breaks"))?,
-                }
+            match chars > 1 {
+                false => formatter.write_str("1 char")?,
+                true => formatter.write_fmt(format_args!("{chars} chars"))?,
+            }
 
-                formatter.write_str(")")?;
+            match breaks {
+                0 => (),
+                1 => formatter.write_str(", 1 line break")?,
+                _ => formatter.write_fmt(format_args!(", {breaks} line breaks"))?,
             }
 
-            return Ok(());
+            formatter.write_str(")")?;
         }
 
-        formatter
-            .snippet(self.code)
-            .set_caption(format!("Unit({})", self.code.id()))
-            .set_summary(format!(
-                "Site span: {}..{}\nPosition span: {}",
-                span.start,
-                span.end,
-                span.display(self.code),
-            ))
-            .annotate(span, AnnotationPriority::Default, "")
-            .finish()
+        Ok(())
     }
 }
@@ -34,7 +34,7 @@
 
 extern crate lady_deirdre_derive;
 
-use std::fmt::{Debug, Formatter};
+use core::fmt::{Debug, Formatter};
 
 pub use lady_deirdre_derive::Token;
 
@@ -50,6 +50,7 @@ use crate::{
         SourceCode,
         ToSpan,
         TokenRule,
+        TokenSet,
         EOI,
     },
     syntax::{NodeRef, PolyRef, PolyVariant, RefKind, NIL_NODE_REF},
@@ -94,7 +95,8 @@ pub trait Token: Copy + Eq + Send + Sync + Sized + 'static {
     /// there are no tokens of zero length.
     ///
     /// When using the [Token](lady_deirdre_derive::Token) macro, this value
-    /// is either set to 1 as default or overridden by
+    /// is either set to 1 as default (or to the maximum length of the rules
+    /// with the end-of-line anchor), or overridden by
     /// the `#[lookback(...)]` attriubute:
     ///
     /// ```ignore
@@ -104,6 +106,26 @@ pub trait Token: Copy + Eq + Send + Sync + Sized + 'static {
     /// ```
     const LOOKBACK: Length;
 
+    /// A set of token rules that do not affect the lexical context of
+    /// the subsequent tokens (e.g., whitespaces and comments).
+    ///
+    /// The scanning environment skips the tokens of these rules when it
+    /// determines the [previous](LexisSession::previous) token rule of
+    /// the scanning token.
+    ///
+    /// The default value is an empty set, meaning that the previous token
+    /// is always the token directly preceding the scanning token.
+    ///
+    /// When using the [Token](lady_deirdre_derive::Token) macro, this value
+    /// can be overridden by the `#[blank(...)]` attribute:
+    ///
+    /// ```ignore
+    /// #[derive(Token)]
+    /// #[blank($Whitespace | $Comment)]
+    /// enum MyToken {}
+    /// ```
+    const BLANK: TokenSet = TokenSet::empty();
+
     /// Scans a single token from the beginning of the input text.
     ///
     /// The `session` parameter of type [LexisSession] provides access
@@ -260,6 +282,24 @@ pub trait Token: Copy + Eq + Send + Sync + Sized + 'static {
     ///
     /// See [describe](Self::describe) for details.
     fn rule_description(rule: TokenRule, verbose: bool) -> Option<&'static str>;
+
+    /// Returns a token instance of the specified token rule.
+    ///
+    /// Returns None if the `rule` does not denote any token of this type, or
+    /// if this feature is disabled for this token type.
+    ///
+    /// The returning token's [rule](Self::rule) equals to the `rule`
+    /// parameter. The serialization functions use this function to restore
+    /// the token stream without rescanning the source code text.
+    ///
+    /// When using the [Token](lady_deirdre_derive::Token) macro, this function
+    /// returns the enum variant with the discriminant equal to `rule`.
+    #[inline(always)]
+    fn from_rule(rule: TokenRule) -> Option<Self> {
+        let _ = rule;
+
+        None
+    }
 }
 
 /// A globally unique reference of the [token](Token) in the source code.
@@ -286,7 +326,15 @@ pub trait Token: Copy + Eq + Send + Sync + Sized + 'static {
 /// any source code).
 ///
 /// Two distinct instances of the nil TokenRef are always equal.
+///
+/// When the `serde` feature of this crate is enabled, TokenRef implements
+/// serde's Serialize and Deserialize traits. Similarly to the
+/// [NodeRef](crate::syntax::NodeRef), a deserialized TokenRef addresses a token
+/// only within the same source code instance of the same process. Use the
+/// [TokenRef::is_valid_ref] function to check if the deserialized reference
+/// is still valid for the source code.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TokenRef {
     /// An identifier of the source code.
     pub id: Id,
@@ -297,7 +345,7 @@ pub struct TokenRef {
 
 impl Debug for TokenRef {
     #[inline]
-    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
         match self.is_nil() {
             false => formatter.write_fmt(format_args!(
                 "TokenRef(id: {:?}, entry: {:?})",
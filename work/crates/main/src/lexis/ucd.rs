@@ -112,6 +112,9 @@ pub struct CharProperties {
     /// Includes `Alphabetic` character property.
     pub alpha: bool,
 
+    /// Includes `Extended_Pictographic` character property.
+    pub ext_pict: bool,
+
     /// Includes `Lowercase` character property.
     pub lower: bool,
 
@@ -129,62 +132,58 @@ pub struct CharProperties {
 
     /// Includes `XID_Start` character property.
     pub xid_start: bool,
+
+    /// Includes characters of the specified scripts. A character belongs
+    /// to a script if its `Script_Extensions` property contains this script.
+    pub scripts: ScriptSet,
 }
 
-impl std::fmt::Display for CharProperties {
+impl core::fmt::Display for CharProperties {
     #[inline(always)]
-    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         formatter.write_str("$")?;
 
-        let mut props = Vec::new();
-
-        if self.alpha {
-            props.push("alpha");
-        }
-
-        if self.lower {
-            props.push("lower");
-        }
-
-        if self.num {
-            props.push("num");
-        }
+        let props = [
+            (self.alpha, "alpha"),
+            (self.ext_pict, "ext_pict"),
+            (self.lower, "lower"),
+            (self.num, "num"),
+            (self.space, "space"),
+            (self.upper, "upper"),
+            (self.xid_continue, "xid_continue"),
+            (self.xid_start, "xid_start"),
+        ];
 
-        if self.space {
-            props.push("space");
-        }
+        let count =
+            props.iter().filter(|(enabled, _)| *enabled).count() + self.scripts.iter().count();
 
-        if self.upper {
-            props.push("upper");
+        if count != 1 {
+            formatter.write_str("{")?;
         }
 
-        if self.xid_continue {
-            props.push("xid_continue");
-        }
+        let mut first = true;
 
-        if self.xid_start {
-            props.push("xid_start");
-        }
+        for (_, prop) in props.iter().filter(|(enabled, _)| *enabled) {
+            match first {
+                true => first = false,
+                false => formatter.write_str(" | ")?,
+            }
 
-        if props.len() == 1 {
-            formatter.write_str(props[0])?;
-            return Ok(());
+            formatter.write_str(prop)?;
         }
 
-        formatter.write_str("{")?;
-
-        let mut first = true;
-
-        for prop in props {
+        for script in self.scripts.iter() {
             match first {
                 true => first = false,
                 false => formatter.write_str(" | ")?,
             }
 
-            formatter.write_str(prop)?;
+            formatter.write_fmt(format_args!("script({})", script.name()))?;
         }
 
-        formatter.write_str("}")?;
+        if count != 1 {
+            formatter.write_str("}")?;
+        }
 
         Ok(())
     }
@@ -204,12 +203,14 @@ impl CharProperties {
     pub const fn new() -> Self {
         Self {
             alpha: false,
+            ext_pict: false,
             lower: false,
             num: false,
             space: false,
             upper: false,
             xid_continue: false,
             xid_start: false,
+            scripts: ScriptSet::new(),
         }
     }
 
@@ -221,6 +222,14 @@ impl CharProperties {
         self
     }
 
+    /// Includes `Extended_Pictographic` character property.
+    #[inline(always)]
+    pub const fn with_ext_pict(mut self) -> Self {
+        self.ext_pict = true;
+
+        self
+    }
+
     /// Includes `Lowercase` character property.
     #[inline(always)]
     pub const fn with_lower(mut self) -> Self {
@@ -269,6 +278,16 @@ impl CharProperties {
         self
     }
 
+    /// Includes characters of the specified script.
+    ///
+    /// See [CharProperties::scripts] for details.
+    #[inline(always)]
+    pub const fn with_script(mut self, script: Script) -> Self {
+        self.scripts = self.scripts.with(script);
+
+        self
+    }
+
     /// Creates a union of two property configurations.
     ///
     /// The resulting configuration includes a property if at least one of
@@ -276,12 +295,14 @@ impl CharProperties {
     #[inline(always)]
     pub const fn union(mut self, other: Self) -> Self {
         self.alpha = self.alpha || other.alpha;
+        self.ext_pict = self.ext_pict || other.ext_pict;
         self.lower = self.lower || other.lower;
         self.num = self.num || other.num;
         self.space = self.space || other.space;
         self.upper = self.upper || other.upper;
         self.xid_continue = self.xid_continue || other.xid_continue;
         self.xid_start = self.xid_start || other.xid_start;
+        self.scripts = self.scripts.union(other.scripts);
 
         self
     }
@@ -293,12 +314,14 @@ impl CharProperties {
     #[inline(always)]
     pub const fn intersect(mut self, other: Self) -> Self {
         self.alpha = self.alpha && other.alpha;
+        self.ext_pict = self.ext_pict && other.ext_pict;
         self.lower = self.lower && other.lower;
         self.num = self.num && other.num;
         self.space = self.space && other.space;
         self.upper = self.upper && other.upper;
         self.xid_continue = self.xid_continue && other.xid_continue;
         self.xid_start = self.xid_start && other.xid_start;
+        self.scripts = self.scripts.intersect(other.scripts);
 
         self
     }
@@ -315,6 +338,9 @@ pub trait Char {
     /// Returns true if the character has `Alphabetic` property.
     fn is_alpha(self) -> bool;
 
+    /// Returns true if the character has `Extended_Pictographic` property.
+    fn is_ext_pict(self) -> bool;
+
     /// Returns true if the character has `Lowercase` property.
     fn is_lower(self) -> bool;
 
@@ -336,6 +362,79 @@ pub trait Char {
     /// Returns true if the character has at least one of the specified
     /// properties. See [CharProperties] for details.
     fn has_properties(self, props: &CharProperties) -> bool;
+
+    /// Returns the `Simple_Lowercase_Mapping` of the character.
+    ///
+    /// If the character does not have a lowercase mapping, the function
+    /// returns the character itself.
+    fn to_lower_simple(self) -> char;
+
+    /// Returns an iterator over the characters of the `Lowercase_Mapping`
+    /// of the character.
+    ///
+    /// Unlike [Char::to_lower_simple], the full mapping may map a
+    /// character to several characters. The conditional (language-sensitive
+    /// and context-sensitive) mappings of the SpecialCasing.txt file
+    /// are not applied.
+    fn to_lower_full(self) -> CaseMapping;
+
+    /// Returns the `Simple_Titlecase_Mapping` of the character.
+    ///
+    /// If the character does not have a titlecase mapping, the function
+    /// returns the character itself.
+    fn to_title_simple(self) -> char;
+
+    /// Returns an iterator over the characters of the `Titlecase_Mapping`
+    /// of the character.
+    ///
+    /// Unlike [Char::to_title_simple], the full mapping may map a
+    /// character to several characters. The conditional (language-sensitive
+    /// and context-sensitive) mappings of the SpecialCasing.txt file
+    /// are not applied.
+    fn to_title_full(self) -> CaseMapping;
+
+    /// Returns the `Simple_Uppercase_Mapping` of the character.
+    ///
+    /// If the character does not have a uppercase mapping, the function
+    /// returns the character itself.
+    fn to_upper_simple(self) -> char;
+
+    /// Returns an iterator over the characters of the `Uppercase_Mapping`
+    /// of the character.
+    ///
+    /// Unlike [Char::to_upper_simple], the full mapping may map a
+    /// character to several characters. The conditional (language-sensitive
+    /// and context-sensitive) mappings of the SpecialCasing.txt file
+    /// are not applied.
+    fn to_upper_full(self) -> CaseMapping;
+
+    /// Returns the `Grapheme_Cluster_Break` property value of the character.
+    ///
+    /// If the character does not have any specific value, the function
+    /// returns [GraphemeClusterBreak::Other].
+    fn grapheme_cluster_break(self) -> GraphemeClusterBreak;
+
+    /// Returns the `Indic_Conjunct_Break` property value of the character.
+    ///
+    /// If the character does not have any specific value, the function
+    /// returns [IndicConjunctBreak::None].
+    fn indic_conjunct_break(self) -> IndicConjunctBreak;
+
+    /// Returns the `Script` property value of the character.
+    ///
+    /// If the character is not assigned to any script, the function
+    /// returns [Script::Unknown].
+    fn script(self) -> Script;
+
+    /// Returns the `Script_Extensions` property value of the character.
+    ///
+    /// If the character does not have explicit script extensions,
+    /// the function returns a single [Char::script] value.
+    fn script_extensions(self) -> &'static [Script];
+
+    /// Returns true if the `Script_Extensions` property value of
+    /// the character contains the specified `script`.
+    fn has_script(self, script: Script) -> bool;
 }
 
 impl Char for char {
@@ -344,6 +443,11 @@ impl Char for char {
         ALPHABETIC_TABLE.lookup(self as usize)
     }
 
+    #[inline(always)]
+    fn is_ext_pict(self) -> bool {
+        EXT_PICT_TABLE.lookup(self as usize)
+    }
+
     #[inline(always)]
     fn is_lower(self) -> bool {
         LOWERCASE_TABLE.lookup(self as usize)
@@ -379,6 +483,10 @@ impl Char for char {
             return true;
         }
 
+        if props.ext_pict && self.is_ext_pict() {
+            return true;
+        }
+
         if props.lower && self.is_lower() {
             return true;
         }
@@ -403,8 +511,1262 @@ impl Char for char {
             return true;
         }
 
+        if !props.scripts.is_empty() {
+            for script in self.script_extensions() {
+                if props.scripts.contains(*script) {
+                    return true;
+                }
+            }
+        }
+
         false
     }
+
+    #[inline(always)]
+    fn to_lower_simple(self) -> char {
+        LOWER_CASE_TABLE.simple(self)
+    }
+
+    #[inline(always)]
+    fn to_lower_full(self) -> CaseMapping {
+        LOWER_CASE_TABLE.full(self)
+    }
+
+    #[inline(always)]
+    fn to_title_simple(self) -> char {
+        TITLE_CASE_TABLE.simple(self)
+    }
+
+    #[inline(always)]
+    fn to_title_full(self) -> CaseMapping {
+        TITLE_CASE_TABLE.full(self)
+    }
+
+    #[inline(always)]
+    fn to_upper_simple(self) -> char {
+        UPPER_CASE_TABLE.simple(self)
+    }
+
+    #[inline(always)]
+    fn to_upper_full(self) -> CaseMapping {
+        UPPER_CASE_TABLE.full(self)
+    }
+
+    #[inline(always)]
+    fn grapheme_cluster_break(self) -> GraphemeClusterBreak {
+        match lookup_range(GRAPHEME_CLUSTER_BREAK_TABLE, self) {
+            Some(value) => value,
+            None => GraphemeClusterBreak::Other,
+        }
+    }
+
+    #[inline(always)]
+    fn indic_conjunct_break(self) -> IndicConjunctBreak {
+        match lookup_range(INDIC_CONJUNCT_BREAK_TABLE, self) {
+            Some(value) => value,
+            None => IndicConjunctBreak::None,
+        }
+    }
+
+    #[inline(always)]
+    fn script(self) -> Script {
+        match lookup_range(SCRIPT_TABLE, self) {
+            Some(script) => script,
+            None => Script::Unknown,
+        }
+    }
+
+    #[inline(always)]
+    fn script_extensions(self) -> &'static [Script] {
+        match lookup_range(SCRIPT_EXTENSIONS_TABLE, self) {
+            Some(scripts) => scripts,
+            None => core::slice::from_ref(&SCRIPTS[self.script() as usize]),
+        }
+    }
+
+    #[inline(always)]
+    fn has_script(self, script: Script) -> bool {
+        self.script_extensions().contains(&script)
+    }
+}
+
+#[inline(always)]
+fn lookup_range<T: Copy>(table: &[(char, char, T)], ch: char) -> Option<T> {
+    let index = table.binary_search_by(|(from, to, _)| {
+        if *to < ch {
+            return core::cmp::Ordering::Less;
+        }
+
+        if *from > ch {
+            return core::cmp::Ordering::Greater;
+        }
+
+        core::cmp::Ordering::Equal
+    });
+
+    match index {
+        Ok(index) => Some(table[index].2),
+        Err(_) => None,
+    }
+}
+
+/// An iterator over the characters of a full case mapping.
+///
+/// This object is created by the [Char::to_lower_full],
+/// [Char::to_title_full], and [Char::to_upper_full] functions.
+///
+/// The [Display](core::fmt::Display) implementation of this object prints
+/// the remaining characters of the mapping:
+/// `assert_eq!('\u{DF}'.to_upper_full().to_string(), "SS")`.
+#[derive(Clone, Debug)]
+pub struct CaseMapping {
+    chars: [char; 3],
+    next: u8,
+    len: u8,
+}
+
+impl Iterator for CaseMapping {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.len {
+            return None;
+        }
+
+        let ch = self.chars[self.next as usize];
+
+        self.next += 1;
+
+        Some(ch)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.len - self.next) as usize;
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for CaseMapping {}
+
+impl core::iter::FusedIterator for CaseMapping {}
+
+impl core::fmt::Display for CaseMapping {
+    #[inline]
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for ch in self.clone() {
+            core::fmt::Write::write_char(formatter, ch)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl CaseMapping {
+    #[inline(always)]
+    fn new(chars: [char; 3]) -> Self {
+        let len = 1 + chars[1..].iter().take_while(|ch| **ch != '\0').count();
+
+        Self {
+            chars,
+            next: 0,
+            len: len as u8,
+        }
+    }
+}
+
+/// A value of the `Grapheme_Cluster_Break` Unicode character property.
+///
+/// This value is returned by the [Char::grapheme_cluster_break] function,
+/// and is used to split text into extended grapheme clusters according
+/// to the [UAX #29](https://www.unicode.org/reports/tr29/) rules.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[non_exhaustive]
+pub enum GraphemeClusterBreak {
+    /// The `Other` value of characters without any specific value.
+    Other,
+
+    /// The `CR` value.
+    CR,
+
+    /// The `LF` value.
+    LF,
+
+    /// The `Control` value.
+    Control,
+
+    /// The `Extend` value.
+    Extend,
+
+    /// The `ZWJ` value.
+    ZWJ,
+
+    /// The `Regional_Indicator` value.
+    RegionalIndicator,
+
+    /// The `Prepend` value.
+    Prepend,
+
+    /// The `SpacingMark` value.
+    SpacingMark,
+
+    /// The `L` value.
+    L,
+
+    /// The `V` value.
+    V,
+
+    /// The `T` value.
+    T,
+
+    /// The `LV` value.
+    LV,
+
+    /// The `LVT` value.
+    LVT,
+}
+
+/// A value of the `Indic_Conjunct_Break` Unicode character property.
+///
+/// This value is returned by the [Char::indic_conjunct_break] function,
+/// and is used by the [UAX #29](https://www.unicode.org/reports/tr29/)
+/// rule GB9c that keeps the Indic conjuncts in a single grapheme cluster.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[non_exhaustive]
+pub enum IndicConjunctBreak {
+    /// The `None` value of characters without any specific value.
+    None,
+
+    /// The `Consonant` value.
+    Consonant,
+
+    /// The `Extend` value.
+    Extend,
+
+    /// The `Linker` value.
+    Linker,
+}
+
+/// A value of the `Script` Unicode character property.
+///
+/// This value is returned by the [Char::script] function. The
+/// [Char::script_extensions] function returns the set of scripts
+/// a character is commonly used with.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum Script {
+    /// The `Unknown` script (`Zzzz`).
+    Unknown,
+
+    /// The `Common` script (`Zyyy`).
+    Common,
+
+    /// The `Inherited` script (`Zinh`).
+    Inherited,
+
+    /// The `Adlam` script (`Adlm`).
+    Adlam,
+
+    /// The `Ahom` script (`Ahom`).
+    Ahom,
+
+    /// The `Anatolian_Hieroglyphs` script (`Hluw`).
+    AnatolianHieroglyphs,
+
+    /// The `Arabic` script (`Arab`).
+    Arabic,
+
+    /// The `Armenian` script (`Armn`).
+    Armenian,
+
+    /// The `Avestan` script (`Avst`).
+    Avestan,
+
+    /// The `Balinese` script (`Bali`).
+    Balinese,
+
+    /// The `Bamum` script (`Bamu`).
+    Bamum,
+
+    /// The `Bassa_Vah` script (`Bass`).
+    BassaVah,
+
+    /// The `Batak` script (`Batk`).
+    Batak,
+
+    /// The `Bengali` script (`Beng`).
+    Bengali,
+
+    /// The `Beria_Erfe` script (`Berf`).
+    BeriaErfe,
+
+    /// The `Bhaiksuki` script (`Bhks`).
+    Bhaiksuki,
+
+    /// The `Bopomofo` script (`Bopo`).
+    Bopomofo,
+
+    /// The `Brahmi` script (`Brah`).
+    Brahmi,
+
+    /// The `Braille` script (`Brai`).
+    Braille,
+
+    /// The `Buginese` script (`Bugi`).
+    Buginese,
+
+    /// The `Buhid` script (`Buhd`).
+    Buhid,
+
+    /// The `Canadian_Aboriginal` script (`Cans`).
+    CanadianAboriginal,
+
+    /// The `Carian` script (`Cari`).
+    Carian,
+
+    /// The `Caucasian_Albanian` script (`Aghb`).
+    CaucasianAlbanian,
+
+    /// The `Chakma` script (`Cakm`).
+    Chakma,
+
+    /// The `Cham` script (`Cham`).
+    Cham,
+
+    /// The `Cherokee` script (`Cher`).
+    Cherokee,
+
+    /// The `Chorasmian` script (`Chrs`).
+    Chorasmian,
+
+    /// The `Coptic` script (`Copt`).
+    Coptic,
+
+    /// The `Cuneiform` script (`Xsux`).
+    Cuneiform,
+
+    /// The `Cypriot` script (`Cprt`).
+    Cypriot,
+
+    /// The `Cypro_Minoan` script (`Cpmn`).
+    CyproMinoan,
+
+    /// The `Cyrillic` script (`Cyrl`).
+    Cyrillic,
+
+    /// The `Deseret` script (`Dsrt`).
+    Deseret,
+
+    /// The `Devanagari` script (`Deva`).
+    Devanagari,
+
+    /// The `Dives_Akuru` script (`Diak`).
+    DivesAkuru,
+
+    /// The `Dogra` script (`Dogr`).
+    Dogra,
+
+    /// The `Duployan` script (`Dupl`).
+    Duployan,
+
+    /// The `Egyptian_Hieroglyphs` script (`Egyp`).
+    EgyptianHieroglyphs,
+
+    /// The `Elbasan` script (`Elba`).
+    Elbasan,
+
+    /// The `Elymaic` script (`Elym`).
+    Elymaic,
+
+    /// The `Ethiopic` script (`Ethi`).
+    Ethiopic,
+
+    /// The `Garay` script (`Gara`).
+    Garay,
+
+    /// The `Georgian` script (`Geor`).
+    Georgian,
+
+    /// The `Glagolitic` script (`Glag`).
+    Glagolitic,
+
+    /// The `Gothic` script (`Goth`).
+    Gothic,
+
+    /// The `Grantha` script (`Gran`).
+    Grantha,
+
+    /// The `Greek` script (`Grek`).
+    Greek,
+
+    /// The `Gujarati` script (`Gujr`).
+    Gujarati,
+
+    /// The `Gunjala_Gondi` script (`Gong`).
+    GunjalaGondi,
+
+    /// The `Gurmukhi` script (`Guru`).
+    Gurmukhi,
+
+    /// The `Gurung_Khema` script (`Gukh`).
+    GurungKhema,
+
+    /// The `Han` script (`Hani`).
+    Han,
+
+    /// The `Hangul` script (`Hang`).
+    Hangul,
+
+    /// The `Hanifi_Rohingya` script (`Rohg`).
+    HanifiRohingya,
+
+    /// The `Hanunoo` script (`Hano`).
+    Hanunoo,
+
+    /// The `Hatran` script (`Hatr`).
+    Hatran,
+
+    /// The `Hebrew` script (`Hebr`).
+    Hebrew,
+
+    /// The `Hiragana` script (`Hira`).
+    Hiragana,
+
+    /// The `Imperial_Aramaic` script (`Armi`).
+    ImperialAramaic,
+
+    /// The `Inscriptional_Pahlavi` script (`Phli`).
+    InscriptionalPahlavi,
+
+    /// The `Inscriptional_Parthian` script (`Prti`).
+    InscriptionalParthian,
+
+    /// The `Javanese` script (`Java`).
+    Javanese,
+
+    /// The `Kaithi` script (`Kthi`).
+    Kaithi,
+
+    /// The `Kannada` script (`Knda`).
+    Kannada,
+
+    /// The `Katakana` script (`Kana`).
+    Katakana,
+
+    /// The `Kawi` script (`Kawi`).
+    Kawi,
+
+    /// The `Kayah_Li` script (`Kali`).
+    KayahLi,
+
+    /// The `Kharoshthi` script (`Khar`).
+    Kharoshthi,
+
+    /// The `Khitan_Small_Script` script (`Kits`).
+    KhitanSmallScript,
+
+    /// The `Khmer` script (`Khmr`).
+    Khmer,
+
+    /// The `Khojki` script (`Khoj`).
+    Khojki,
+
+    /// The `Khudawadi` script (`Sind`).
+    Khudawadi,
+
+    /// The `Kirat_Rai` script (`Krai`).
+    KiratRai,
+
+    /// The `Lao` script (`Laoo`).
+    Lao,
+
+    /// The `Latin` script (`Latn`).
+    Latin,
+
+    /// The `Lepcha` script (`Lepc`).
+    Lepcha,
+
+    /// The `Limbu` script (`Limb`).
+    Limbu,
+
+    /// The `Linear_A` script (`Lina`).
+    LinearA,
+
+    /// The `Linear_B` script (`Linb`).
+    LinearB,
+
+    /// The `Lisu` script (`Lisu`).
+    Lisu,
+
+    /// The `Lycian` script (`Lyci`).
+    Lycian,
+
+    /// The `Lydian` script (`Lydi`).
+    Lydian,
+
+    /// The `Mahajani` script (`Mahj`).
+    Mahajani,
+
+    /// The `Makasar` script (`Maka`).
+    Makasar,
+
+    /// The `Malayalam` script (`Mlym`).
+    Malayalam,
+
+    /// The `Mandaic` script (`Mand`).
+    Mandaic,
+
+    /// The `Manichaean` script (`Mani`).
+    Manichaean,
+
+    /// The `Marchen` script (`Marc`).
+    Marchen,
+
+    /// The `Masaram_Gondi` script (`Gonm`).
+    MasaramGondi,
+
+    /// The `Medefaidrin` script (`Medf`).
+    Medefaidrin,
+
+    /// The `Meetei_Mayek` script (`Mtei`).
+    MeeteiMayek,
+
+    /// The `Mende_Kikakui` script (`Mend`).
+    MendeKikakui,
+
+    /// The `Meroitic_Cursive` script (`Merc`).
+    MeroiticCursive,
+
+    /// The `Meroitic_Hieroglyphs` script (`Mero`).
+    MeroiticHieroglyphs,
+
+    /// The `Miao` script (`Plrd`).
+    Miao,
+
+    /// The `Modi` script (`Modi`).
+    Modi,
+
+    /// The `Mongolian` script (`Mong`).
+    Mongolian,
+
+    /// The `Mro` script (`Mroo`).
+    Mro,
+
+    /// The `Multani` script (`Mult`).
+    Multani,
+
+    /// The `Myanmar` script (`Mymr`).
+    Myanmar,
+
+    /// The `Nabataean` script (`Nbat`).
+    Nabataean,
+
+    /// The `Nag_Mundari` script (`Nagm`).
+    NagMundari,
+
+    /// The `Nandinagari` script (`Nand`).
+    Nandinagari,
+
+    /// The `New_Tai_Lue` script (`Talu`).
+    NewTaiLue,
+
+    /// The `Newa` script (`Newa`).
+    Newa,
+
+    /// The `Nko` script (`Nkoo`).
+    Nko,
+
+    /// The `Nushu` script (`Nshu`).
+    Nushu,
+
+    /// The `Nyiakeng_Puachue_Hmong` script (`Hmnp`).
+    NyiakengPuachueHmong,
+
+    /// The `Ogham` script (`Ogam`).
+    Ogham,
+
+    /// The `Ol_Chiki` script (`Olck`).
+    OlChiki,
+
+    /// The `Ol_Onal` script (`Onao`).
+    OlOnal,
+
+    /// The `Old_Hungarian` script (`Hung`).
+    OldHungarian,
+
+    /// The `Old_Italic` script (`Ital`).
+    OldItalic,
+
+    /// The `Old_North_Arabian` script (`Narb`).
+    OldNorthArabian,
+
+    /// The `Old_Permic` script (`Perm`).
+    OldPermic,
+
+    /// The `Old_Persian` script (`Xpeo`).
+    OldPersian,
+
+    /// The `Old_Sogdian` script (`Sogo`).
+    OldSogdian,
+
+    /// The `Old_South_Arabian` script (`Sarb`).
+    OldSouthArabian,
+
+    /// The `Old_Turkic` script (`Orkh`).
+    OldTurkic,
+
+    /// The `Old_Uyghur` script (`Ougr`).
+    OldUyghur,
+
+    /// The `Oriya` script (`Orya`).
+    Oriya,
+
+    /// The `Osage` script (`Osge`).
+    Osage,
+
+    /// The `Osmanya` script (`Osma`).
+    Osmanya,
+
+    /// The `Pahawh_Hmong` script (`Hmng`).
+    PahawhHmong,
+
+    /// The `Palmyrene` script (`Palm`).
+    Palmyrene,
+
+    /// The `Pau_Cin_Hau` script (`Pauc`).
+    PauCinHau,
+
+    /// The `Phags_Pa` script (`Phag`).
+    PhagsPa,
+
+    /// The `Phoenician` script (`Phnx`).
+    Phoenician,
+
+    /// The `Psalter_Pahlavi` script (`Phlp`).
+    PsalterPahlavi,
+
+    /// The `Rejang` script (`Rjng`).
+    Rejang,
+
+    /// The `Runic` script (`Runr`).
+    Runic,
+
+    /// The `Samaritan` script (`Samr`).
+    Samaritan,
+
+    /// The `Saurashtra` script (`Saur`).
+    Saurashtra,
+
+    /// The `Sharada` script (`Shrd`).
+    Sharada,
+
+    /// The `Shavian` script (`Shaw`).
+    Shavian,
+
+    /// The `Siddham` script (`Sidd`).
+    Siddham,
+
+    /// The `Sidetic` script (`Sidt`).
+    Sidetic,
+
+    /// The `SignWriting` script (`Sgnw`).
+    SignWriting,
+
+    /// The `Sinhala` script (`Sinh`).
+    Sinhala,
+
+    /// The `Sogdian` script (`Sogd`).
+    Sogdian,
+
+    /// The `Sora_Sompeng` script (`Sora`).
+    SoraSompeng,
+
+    /// The `Soyombo` script (`Soyo`).
+    Soyombo,
+
+    /// The `Sundanese` script (`Sund`).
+    Sundanese,
+
+    /// The `Sunuwar` script (`Sunu`).
+    Sunuwar,
+
+    /// The `Syloti_Nagri` script (`Sylo`).
+    SylotiNagri,
+
+    /// The `Syriac` script (`Syrc`).
+    Syriac,
+
+    /// The `Tagalog` script (`Tglg`).
+    Tagalog,
+
+    /// The `Tagbanwa` script (`Tagb`).
+    Tagbanwa,
+
+    /// The `Tai_Le` script (`Tale`).
+    TaiLe,
+
+    /// The `Tai_Tham` script (`Lana`).
+    TaiTham,
+
+    /// The `Tai_Viet` script (`Tavt`).
+    TaiViet,
+
+    /// The `Tai_Yo` script (`Tayo`).
+    TaiYo,
+
+    /// The `Takri` script (`Takr`).
+    Takri,
+
+    /// The `Tamil` script (`Taml`).
+    Tamil,
+
+    /// The `Tangsa` script (`Tnsa`).
+    Tangsa,
+
+    /// The `Tangut` script (`Tang`).
+    Tangut,
+
+    /// The `Telugu` script (`Telu`).
+    Telugu,
+
+    /// The `Thaana` script (`Thaa`).
+    Thaana,
+
+    /// The `Thai` script (`Thai`).
+    Thai,
+
+    /// The `Tibetan` script (`Tibt`).
+    Tibetan,
+
+    /// The `Tifinagh` script (`Tfng`).
+    Tifinagh,
+
+    /// The `Tirhuta` script (`Tirh`).
+    Tirhuta,
+
+    /// The `Todhri` script (`Todr`).
+    Todhri,
+
+    /// The `Tolong_Siki` script (`Tols`).
+    TolongSiki,
+
+    /// The `Toto` script (`Toto`).
+    Toto,
+
+    /// The `Tulu_Tigalari` script (`Tutg`).
+    TuluTigalari,
+
+    /// The `Ugaritic` script (`Ugar`).
+    Ugaritic,
+
+    /// The `Vai` script (`Vaii`).
+    Vai,
+
+    /// The `Vithkuqi` script (`Vith`).
+    Vithkuqi,
+
+    /// The `Wancho` script (`Wcho`).
+    Wancho,
+
+    /// The `Warang_Citi` script (`Wara`).
+    WarangCiti,
+
+    /// The `Yezidi` script (`Yezi`).
+    Yezidi,
+
+    /// The `Yi` script (`Yiii`).
+    Yi,
+
+    /// The `Zanabazar_Square` script (`Zanb`).
+    ZanabazarSquare,
+}
+
+impl core::fmt::Display for Script {
+    #[inline(always)]
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        formatter.write_str(self.name())
+    }
+}
+
+impl Script {
+    /// Returns the long name of the script as specified in the Unicode
+    /// Character Database (e.g. `Old_Italic`).
+    #[inline(always)]
+    pub const fn name(self) -> &'static str {
+        SCRIPT_NAMES[self as usize].0
+    }
+
+    /// Returns the four-letter [ISO 15924](https://www.unicode.org/iso15924/)
+    /// code of the script (e.g. `Ital`).
+    #[inline(always)]
+    pub const fn code(self) -> &'static str {
+        SCRIPT_NAMES[self as usize].1
+    }
+
+    /// Looks up a script by its long name (`Old_Italic`), by its
+    /// [code](Self::code) (`Ital`), or by the name of the enum variant
+    /// (`OldItalic`).
+    ///
+    /// Returns None if there is no script with the specified name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        SCRIPTS.iter().copied().find(|script| {
+            let (long, code) = SCRIPT_NAMES[*script as usize];
+
+            long == name
+                || code == name
+                || long.bytes().filter(|byte| *byte != b'_').eq(name.bytes())
+        })
+    }
+}
+
+static SCRIPTS: [Script; 175] = [
+    Script::Unknown,
+    Script::Common,
+    Script::Inherited,
+    Script::Adlam,
+    Script::Ahom,
+    Script::AnatolianHieroglyphs,
+    Script::Arabic,
+    Script::Armenian,
+    Script::Avestan,
+    Script::Balinese,
+    Script::Bamum,
+    Script::BassaVah,
+    Script::Batak,
+    Script::Bengali,
+    Script::BeriaErfe,
+    Script::Bhaiksuki,
+    Script::Bopomofo,
+    Script::Brahmi,
+    Script::Braille,
+    Script::Buginese,
+    Script::Buhid,
+    Script::CanadianAboriginal,
+    Script::Carian,
+    Script::CaucasianAlbanian,
+    Script::Chakma,
+    Script::Cham,
+    Script::Cherokee,
+    Script::Chorasmian,
+    Script::Coptic,
+    Script::Cuneiform,
+    Script::Cypriot,
+    Script::CyproMinoan,
+    Script::Cyrillic,
+    Script::Deseret,
+    Script::Devanagari,
+    Script::DivesAkuru,
+    Script::Dogra,
+    Script::Duployan,
+    Script::EgyptianHieroglyphs,
+    Script::Elbasan,
+    Script::Elymaic,
+    Script::Ethiopic,
+    Script::Garay,
+    Script::Georgian,
+    Script::Glagolitic,
+    Script::Gothic,
+    Script::Grantha,
+    Script::Greek,
+    Script::Gujarati,
+    Script::GunjalaGondi,
+    Script::Gurmukhi,
+    Script::GurungKhema,
+    Script::Han,
+    Script::Hangul,
+    Script::HanifiRohingya,
+    Script::Hanunoo,
+    Script::Hatran,
+    Script::Hebrew,
+    Script::Hiragana,
+    Script::ImperialAramaic,
+    Script::InscriptionalPahlavi,
+    Script::InscriptionalParthian,
+    Script::Javanese,
+    Script::Kaithi,
+    Script::Kannada,
+    Script::Katakana,
+    Script::Kawi,
+    Script::KayahLi,
+    Script::Kharoshthi,
+    Script::KhitanSmallScript,
+    Script::Khmer,
+    Script::Khojki,
+    Script::Khudawadi,
+    Script::KiratRai,
+    Script::Lao,
+    Script::Latin,
+    Script::Lepcha,
+    Script::Limbu,
+    Script::LinearA,
+    Script::LinearB,
+    Script::Lisu,
+    Script::Lycian,
+    Script::Lydian,
+    Script::Mahajani,
+    Script::Makasar,
+    Script::Malayalam,
+    Script::Mandaic,
+    Script::Manichaean,
+    Script::Marchen,
+    Script::MasaramGondi,
+    Script::Medefaidrin,
+    Script::MeeteiMayek,
+    Script::MendeKikakui,
+    Script::MeroiticCursive,
+    Script::MeroiticHieroglyphs,
+    Script::Miao,
+    Script::Modi,
+    Script::Mongolian,
+    Script::Mro,
+    Script::Multani,
+    Script::Myanmar,
+    Script::Nabataean,
+    Script::NagMundari,
+    Script::Nandinagari,
+    Script::NewTaiLue,
+    Script::Newa,
+    Script::Nko,
+    Script::Nushu,
+    Script::NyiakengPuachueHmong,
+    Script::Ogham,
+    Script::OlChiki,
+    Script::OlOnal,
+    Script::OldHungarian,
+    Script::OldItalic,
+    Script::OldNorthArabian,
+    Script::OldPermic,
+    Script::OldPersian,
+    Script::OldSogdian,
+    Script::OldSouthArabian,
+    Script::OldTurkic,
+    Script::OldUyghur,
+    Script::Oriya,
+    Script::Osage,
+    Script::Osmanya,
+    Script::PahawhHmong,
+    Script::Palmyrene,
+    Script::PauCinHau,
+    Script::PhagsPa,
+    Script::Phoenician,
+    Script::PsalterPahlavi,
+    Script::Rejang,
+    Script::Runic,
+    Script::Samaritan,
+    Script::Saurashtra,
+    Script::Sharada,
+    Script::Shavian,
+    Script::Siddham,
+    Script::Sidetic,
+    Script::SignWriting,
+    Script::Sinhala,
+    Script::Sogdian,
+    Script::SoraSompeng,
+    Script::Soyombo,
+    Script::Sundanese,
+    Script::Sunuwar,
+    Script::SylotiNagri,
+    Script::Syriac,
+    Script::Tagalog,
+    Script::Tagbanwa,
+    Script::TaiLe,
+    Script::TaiTham,
+    Script::TaiViet,
+    Script::TaiYo,
+    Script::Takri,
+    Script::Tamil,
+    Script::Tangsa,
+    Script::Tangut,
+    Script::Telugu,
+    Script::Thaana,
+    Script::Thai,
+    Script::Tibetan,
+    Script::Tifinagh,
+    Script::Tirhuta,
+    Script::Todhri,
+    Script::TolongSiki,
+    Script::Toto,
+    Script::TuluTigalari,
+    Script::Ugaritic,
+    Script::Vai,
+    Script::Vithkuqi,
+    Script::Wancho,
+    Script::WarangCiti,
+    Script::Yezidi,
+    Script::Yi,
+    Script::ZanabazarSquare,
+];
+
+const SCRIPT_NAMES: [(&str, &str); 175] = [
+    ("Unknown", "Zzzz"),
+    ("Common", "Zyyy"),
+    ("Inherited", "Zinh"),
+    ("Adlam", "Adlm"),
+    ("Ahom", "Ahom"),
+    ("Anatolian_Hieroglyphs", "Hluw"),
+    ("Arabic", "Arab"),
+    ("Armenian", "Armn"),
+    ("Avestan", "Avst"),
+    ("Balinese", "Bali"),
+    ("Bamum", "Bamu"),
+    ("Bassa_Vah", "Bass"),
+    ("Batak", "Batk"),
+    ("Bengali", "Beng"),
+    ("Beria_Erfe", "Berf"),
+    ("Bhaiksuki", "Bhks"),
+    ("Bopomofo", "Bopo"),
+    ("Brahmi", "Brah"),
+    ("Braille", "Brai"),
+    ("Buginese", "Bugi"),
+    ("Buhid", "Buhd"),
+    ("Canadian_Aboriginal", "Cans"),
+    ("Carian", "Cari"),
+    ("Caucasian_Albanian", "Aghb"),
+    ("Chakma", "Cakm"),
+    ("Cham", "Cham"),
+    ("Cherokee", "Cher"),
+    ("Chorasmian", "Chrs"),
+    ("Coptic", "Copt"),
+    ("Cuneiform", "Xsux"),
+    ("Cypriot", "Cprt"),
+    ("Cypro_Minoan", "Cpmn"),
+    ("Cyrillic", "Cyrl"),
+    ("Deseret", "Dsrt"),
+    ("Devanagari", "Deva"),
+    ("Dives_Akuru", "Diak"),
+    ("Dogra", "Dogr"),
+    ("Duployan", "Dupl"),
+    ("Egyptian_Hieroglyphs", "Egyp"),
+    ("Elbasan", "Elba"),
+    ("Elymaic", "Elym"),
+    ("Ethiopic", "Ethi"),
+    ("Garay", "Gara"),
+    ("Georgian", "Geor"),
+    ("Glagolitic", "Glag"),
+    ("Gothic", "Goth"),
+    ("Grantha", "Gran"),
+    ("Greek", "Grek"),
+    ("Gujarati", "Gujr"),
+    ("Gunjala_Gondi", "Gong"),
+    ("Gurmukhi", "Guru"),
+    ("Gurung_Khema", "Gukh"),
+    ("Han", "Hani"),
+    ("Hangul", "Hang"),
+    ("Hanifi_Rohingya", "Rohg"),
+    ("Hanunoo", "Hano"),
+    ("Hatran", "Hatr"),
+    ("Hebrew", "Hebr"),
+    ("Hiragana", "Hira"),
+    ("Imperial_Aramaic", "Armi"),
+    ("Inscriptional_Pahlavi", "Phli"),
+    ("Inscriptional_Parthian", "Prti"),
+    ("Javanese", "Java"),
+    ("Kaithi", "Kthi"),
+    ("Kannada", "Knda"),
+    ("Katakana", "Kana"),
+    ("Kawi", "Kawi"),
+    ("Kayah_Li", "Kali"),
+    ("Kharoshthi", "Khar"),
+    ("Khitan_Small_Script", "Kits"),
+    ("Khmer", "Khmr"),
+    ("Khojki", "Khoj"),
+    ("Khudawadi", "Sind"),
+    ("Kirat_Rai", "Krai"),
+    ("Lao", "Laoo"),
+    ("Latin", "Latn"),
+    ("Lepcha", "Lepc"),
+    ("Limbu", "Limb"),
+    ("Linear_A", "Lina"),
+    ("Linear_B", "Linb"),
+    ("Lisu", "Lisu"),
+    ("Lycian", "Lyci"),
+    ("Lydian", "Lydi"),
+    ("Mahajani", "Mahj"),
+    ("Makasar", "Maka"),
+    ("Malayalam", "Mlym"),
+    ("Mandaic", "Mand"),
+    ("Manichaean", "Mani"),
+    ("Marchen", "Marc"),
+    ("Masaram_Gondi", "Gonm"),
+    ("Medefaidrin", "Medf"),
+    ("Meetei_Mayek", "Mtei"),
+    ("Mende_Kikakui", "Mend"),
+    ("Meroitic_Cursive", "Merc"),
+    ("Meroitic_Hieroglyphs", "Mero"),
+    ("Miao", "Plrd"),
+    ("Modi", "Modi"),
+    ("Mongolian", "Mong"),
+    ("Mro", "Mroo"),
+    ("Multani", "Mult"),
+    ("Myanmar", "Mymr"),
+    ("Nabataean", "Nbat"),
+    ("Nag_Mundari", "Nagm"),
+    ("Nandinagari", "Nand"),
+    ("New_Tai_Lue", "Talu"),
+    ("Newa", "Newa"),
+    ("Nko", "Nkoo"),
+    ("Nushu", "Nshu"),
+    ("Nyiakeng_Puachue_Hmong", "Hmnp"),
+    ("Ogham", "Ogam"),
+    ("Ol_Chiki", "Olck"),
+    ("Ol_Onal", "Onao"),
+    ("Old_Hungarian", "Hung"),
+    ("Old_Italic", "Ital"),
+    ("Old_North_Arabian", "Narb"),
+    ("Old_Permic", "Perm"),
+    ("Old_Persian", "Xpeo"),
+    ("Old_Sogdian", "Sogo"),
+    ("Old_South_Arabian", "Sarb"),
+    ("Old_Turkic", "Orkh"),
+    ("Old_Uyghur", "Ougr"),
+    ("Oriya", "Orya"),
+    ("Osage", "Osge"),
+    ("Osmanya", "Osma"),
+    ("Pahawh_Hmong", "Hmng"),
+    ("Palmyrene", "Palm"),
+    ("Pau_Cin_Hau", "Pauc"),
+    ("Phags_Pa", "Phag"),
+    ("Phoenician", "Phnx"),
+    ("Psalter_Pahlavi", "Phlp"),
+    ("Rejang", "Rjng"),
+    ("Runic", "Runr"),
+    ("Samaritan", "Samr"),
+    ("Saurashtra", "Saur"),
+    ("Sharada", "Shrd"),
+    ("Shavian", "Shaw"),
+    ("Siddham", "Sidd"),
+    ("Sidetic", "Sidt"),
+    ("SignWriting", "Sgnw"),
+    ("Sinhala", "Sinh"),
+    ("Sogdian", "Sogd"),
+    ("Sora_Sompeng", "Sora"),
+    ("Soyombo", "Soyo"),
+    ("Sundanese", "Sund"),
+    ("Sunuwar", "Sunu"),
+    ("Syloti_Nagri", "Sylo"),
+    ("Syriac", "Syrc"),
+    ("Tagalog", "Tglg"),
+    ("Tagbanwa", "Tagb"),
+    ("Tai_Le", "Tale"),
+    ("Tai_Tham", "Lana"),
+    ("Tai_Viet", "Tavt"),
+    ("Tai_Yo", "Tayo"),
+    ("Takri", "Takr"),
+    ("Tamil", "Taml"),
+    ("Tangsa", "Tnsa"),
+    ("Tangut", "Tang"),
+    ("Telugu", "Telu"),
+    ("Thaana", "Thaa"),
+    ("Thai", "Thai"),
+    ("Tibetan", "Tibt"),
+    ("Tifinagh", "Tfng"),
+    ("Tirhuta", "Tirh"),
+    ("Todhri", "Todr"),
+    ("Tolong_Siki", "Tols"),
+    ("Toto", "Toto"),
+    ("Tulu_Tigalari", "Tutg"),
+    ("Ugaritic", "Ugar"),
+    ("Vai", "Vaii"),
+    ("Vithkuqi", "Vith"),
+    ("Wancho", "Wcho"),
+    ("Warang_Citi", "Wara"),
+    ("Yezidi", "Yezi"),
+    ("Yi", "Yiii"),
+    ("Zanabazar_Square", "Zanb"),
+];
+
+/// A set of [scripts](Script).
+///
+/// This object is used by the [CharProperties::scripts] configuration.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ScriptSet {
+    bits: [u64; 4],
+}
+
+impl core::fmt::Debug for ScriptSet {
+    #[inline(always)]
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        formatter.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl FromIterator<Script> for ScriptSet {
+    #[inline(always)]
+    fn from_iter<I: IntoIterator<Item = Script>>(iter: I) -> Self {
+        iter.into_iter()
+            .fold(Self::new(), |set, script| set.with(script))
+    }
+}
+
+impl ScriptSet {
+    /// Returns an empty set.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self { bits: [0; 4] }
+    }
+
+    /// Returns a copy of this set with the `script` included.
+    #[inline(always)]
+    pub const fn with(mut self, script: Script) -> Self {
+        let index = script as usize;
+
+        self.bits[index >> 6] |= 1 << (index & 63);
+
+        self
+    }
+
+    /// Returns true if the set includes the `script`.
+    #[inline(always)]
+    pub const fn contains(&self, script: Script) -> bool {
+        let index = script as usize;
+
+        (self.bits[index >> 6] >> (index & 63)) & 1 != 0
+    }
+
+    /// Returns true if the set does not include any script.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.bits[0] == 0 && self.bits[1] == 0 && self.bits[2] == 0 && self.bits[3] == 0
+    }
+
+    /// Creates a union of two sets.
+    #[inline(always)]
+    pub const fn union(mut self, other: Self) -> Self {
+        let mut index = 0;
+
+        while index < 4 {
+            self.bits[index] |= other.bits[index];
+            index += 1;
+        }
+
+        self
+    }
+
+    /// Creates an intersection of two sets.
+    #[inline(always)]
+    pub const fn intersect(mut self, other: Self) -> Self {
+        let mut index = 0;
+
+        while index < 4 {
+            self.bits[index] &= other.bits[index];
+            index += 1;
+        }
+
+        self
+    }
+
+    /// Returns an iterator over the scripts of this set in the order
+    /// of the [Script] enum variants.
+    #[inline(always)]
+    pub fn iter(&self) -> impl Iterator<Item = Script> + '_ {
+        SCRIPTS
+            .iter()
+            .copied()
+            .filter(|script| self.contains(*script))
+    }
 }
 
 struct UCDTrie {
@@ -447,7 +1809,7 @@ static ALPHABETIC_TABLE: UCDTrie = UCDTrie {
         0x0000000000000000, 0x07FFFFFE07FFFFFE, 0x0420040000000000, 0xFF7FFFFFFF7FFFFF,
         0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF,
         0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, 0x0000501F0003FFC3,
-        0x0000000000000000, 0xBCDF000000000020, 0xFFFFFFFBFFFFD740, 0xFFBFFFFFFFFFFFFF,
+        0x0000000000000000, 0xBCDFFFF800000020, 0xFFFFFFFBFFFFD740, 0xFFBFFFFFFFFFFFFF,
         0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFC03, 0xFFFFFFFFFFFFFFFF,
         0xFFFEFFFFFFFFFFFF, 0xFFFFFFFF027FFFFF, 0xBFFF0000000001FF, 0x000787FFFFFF00B6,
         0xFFFFFFFF07FF0000, 0xFFFFC000FEFFFFFF, 0xFFFFFFFFFFFFFFFF, 0x9C00E1FE1FEFFFFF,
@@ -556,11 +1918,11 @@ static ALPHABETIC_TABLE: UCDTrie = UCDTrie {
         0x0070, 0x0084,
     ],
     r3: &[
-        0x00001FFFFCFFFFFF, 0xFFFF07FF01FFFFFF, 0xFFFFFFFF00007EFF, 0xFFFF03F8FFF003FF,
+        0x00001FFFFCFFFFFF, 0xFFFF07FF01FFFFFF, 0xFFFFFFFF0080FEFF, 0xFFFF03F8FFF003FF,
         0xEFFFFFFFFFFFFFFF, 0xFFFE000FFFE1DFFF, 0xE3C5FDFFFFF99FEF, 0x1003000FB080599F,
         0xC36DFDFFFFF987EE, 0x003F00005E021987, 0xE3EDFDFFFFFBBFEE, 0x1E00000F00011BBF,
         0xE3EDFDFFFFF99FEE, 0x0002000FB0C0199F, 0xC3FFC718D63DC7EC, 0x0000000000811DC7,
-        0xE3FFFDFFFFFDDFFF, 0x0000000F27601DDF, 0xE3EFFDFFFFFDDFEF, 0x000E000F60601DDF,
+        0xE3FFFDFFFFFDDFFF, 0x0000000F37601DDF, 0xE3EFFDFFFFFDDFEF, 0x000E000F70601DDF,
         0xE7FFFFFFFFFDDFFF, 0xFC00000F80F05DDF, 0x2FFBFFFFFC7FFFEE, 0x000C0000FF5F807F,
         0x07FFFFFFFFFFFFFE, 0x000000000000207F, 0x3BFFFFAFFFFFF7D6, 0x00000000F000205F,
         0x0000000000000001, 0xFFFE1FFFFFFFFEFF, 0x1FFFFFFFFEFFFF0F, 0x0000000000000000,
@@ -573,15 +1935,15 @@ static ALPHABETIC_TABLE: UCDTrie = UCDTrie {
         0x01FF0FFF7FFFFFFF, 0x001F3FFFFFFF0000, 0xFFFF0FFFFFFFFFFF, 0x00000000000003FF,
         0xFFFFFFFF0FFFFFFF, 0x001FFFFE7FFFFFFF, 0x8000008000000000, 0x0000000000007001,
         0xFFEFFFFFFFFFFFFF, 0x0000000000001FEF, 0xFC00F3FFFFFFFFFF, 0x0003FFBFFFFFFFFF,
-        0x007FFFFFFFFFFFFF, 0x3FFFFFFFFC00E000, 0xE7FFFFFFFFFF01FF, 0x046FDE0000000000,
-        0x001FFF8000000000, 0xFFFFFFFF3F3FFFFF, 0x3FFFFFFFAAFF3F3F, 0x5FDFFFFFFFFFFFFF,
+        0x007FFFFFFFFFFFFF, 0x3FFFFFFFFC00E000, 0xE7FFFFFFFFFF07FF, 0x046FDE0000000000,
+        0x001FFFFFFFF80000, 0xFFFFFFFF3F3FFFFF, 0x3FFFFFFFAAFF3F3F, 0x5FDFFFFFFFFFFFFF,
         0x1FDC1FFF0FCF1FDC, 0x8002000000000000, 0x000000001FFF0000, 0xF3FFBD503E2FFC84,
         0xFFFFFFFF000043E0, 0x00000000000001FF, 0xFFC0000000000000, 0x000003FFFFFFFFFF,
         0x000C781FFFFFFFFF, 0xFFFF20BFFFFFFFFF, 0x000080FFFFFFFFFF, 0x7F7F7F7F007FFFFF,
         0xFFFFFFFF7F7F7F7F, 0x0000800000000000, 0x1F3E03FE000000E0, 0xFFFFFFFEE07FFFFF,
         0xF7FFFFFFFFFFFFFF, 0xFFFEFFFFFFFFFFE0, 0xFFFFFFFF00007FFF, 0xFFFF000000000000,
         0x0000000000001FFF, 0x3FFFFFFFFFFF0000, 0x00000C00FFFF1FFF, 0x8FF07FFFFFFFFFFF,
-        0x0000FFFFFFFFFFFF, 0xFFFFFFFCFF800000, 0xFFFFFFFFFFFFF9FF, 0xFFFC000003EB07FF,
+        0x0000FFFFFFFFFFFF, 0xFFFFFFFCFF800000, 0xFFFFFFFFFFFFF9FF, 0xFFFE00001FFFFFFF,
         0x000000FFFFFFFFBF, 0x000FFFFFFFFFFFFF, 0xE8FC00000000002F, 0xFFFF07FFFFFFFC00,
         0x1FFFFFFF0007FFFF, 0xFFF7FFFFFFFFFFFF, 0x7C00FFFF00008000, 0xFC7FFFFF00003FFF,
         0x7FFFFFFFFFFFFFFF, 0x003CFFFF38000005, 0xFFFF7F7F007E7E7E, 0xFFFF03FFF7FFFFFF,
@@ -595,7 +1957,7 @@ static ALPHABETIC_TABLE: UCDTrie = UCDTrie {
         0x0000, 0x0001, 0x0002, 0x0003, 0x0004, 0x0005, 0x0006, 0x0007, 0x0008, 0x0005,
         0x0009, 0x000A, 0x0005, 0x000B, 0x000C, 0x000D, 0x0007, 0x0007, 0x0007, 0x0007,
         0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x000E, 0x000F, 0x0010, 0x0007,
-        0x0011, 0x0012, 0x0007, 0x0013, 0x0014, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005,
+        0x0011, 0x0012, 0x0007, 0x0013, 0x0007, 0x0014, 0x0005, 0x0005, 0x0005, 0x0005,
         0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005,
         0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005,
         0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005,
@@ -622,34 +1984,33 @@ static ALPHABETIC_TABLE: UCDTrie = UCDTrie {
     r5: &[
         0x0000, 0x0001, 0x0002, 0x0003, 0x0004, 0x0005, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0006, 0x0007, 0x0008, 0x0009, 0x000A, 0x000B, 0x0002, 0x0002, 0x000C, 0x000D,
-        0x000E, 0x000F, 0x0010, 0x0004, 0x0002, 0x0002, 0x0002, 0x0002, 0x0011, 0x0012,
-        0x0013, 0x0004, 0x0014, 0x0015, 0x0016, 0x0017, 0x0018, 0x0004, 0x0019, 0x0004,
-        0x001A, 0x001B, 0x001C, 0x001D, 0x001E, 0x001F, 0x0020, 0x0004, 0x0002, 0x0021,
-        0x0022, 0x0022, 0x0023, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0024, 0x0004,
-        0x0025, 0x0026, 0x0027, 0x0028, 0x0002, 0x0029, 0x002A, 0x002B, 0x0022, 0x002C,
-        0x0002, 0x002D, 0x002E, 0x002F, 0x0030, 0x0031, 0x0032, 0x0033, 0x0004, 0x0004,
-        0x0002, 0x0034, 0x0002, 0x0035, 0x0004, 0x0004, 0x0036, 0x0037, 0x0038, 0x0039,
-        0x003A, 0x0004, 0x003B, 0x003C, 0x0004, 0x0004, 0x002A, 0x0004, 0x003D, 0x003E,
-        0x003F, 0x0040, 0x0041, 0x0042, 0x0043, 0x0044, 0x0045, 0x002A, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0046, 0x0047, 0x0048, 0x0004, 0x0049, 0x004A, 0x004B, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x004C, 0x004D, 0x004E, 0x004F, 0x0004, 0x0002, 0x0002,
+        0x000E, 0x000F, 0x0010, 0x0011, 0x0002, 0x0002, 0x0002, 0x0002, 0x0012, 0x0013,
+        0x0014, 0x0004, 0x0015, 0x0016, 0x0017, 0x0018, 0x0019, 0x001A, 0x001B, 0x0004,
+        0x001C, 0x001D, 0x001E, 0x001F, 0x0020, 0x0021, 0x0022, 0x0004, 0x0002, 0x0023,
+        0x0024, 0x0024, 0x0025, 0x0026, 0x0027, 0x0004, 0x0004, 0x0004, 0x0028, 0x0029,
+        0x002A, 0x002B, 0x002C, 0x002D, 0x0002, 0x002E, 0x002F, 0x0030, 0x0024, 0x0031,
+        0x0002, 0x0032, 0x0033, 0x0034, 0x0035, 0x0036, 0x0037, 0x0038, 0x0039, 0x003A,
+        0x0002, 0x003B, 0x0002, 0x003C, 0x0004, 0x0004, 0x003D, 0x003E, 0x003F, 0x0040,
+        0x0041, 0x0004, 0x0042, 0x0043, 0x0004, 0x0004, 0x002F, 0x0004, 0x0044, 0x0045,
+        0x0046, 0x0047, 0x0048, 0x0049, 0x004A, 0x004B, 0x004C, 0x002F, 0x0004, 0x004D,
+        0x0004, 0x004E, 0x004F, 0x0050, 0x0051, 0x0004, 0x0052, 0x0053, 0x0054, 0x0055,
+        0x0004, 0x0004, 0x0004, 0x0056, 0x0057, 0x0058, 0x0059, 0x0004, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0050, 0x0004, 0x0002, 0x0051, 0x0002, 0x0002, 0x0002, 0x0052,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0044, 0x0053, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0054, 0x0055,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x0002, 0x0002, 0x001A, 0x0004, 0x0002, 0x005A, 0x0002, 0x0002, 0x0002, 0x005B,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x003C, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x004B, 0x005C, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x005D, 0x005E,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0003, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0043, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
@@ -659,12 +2020,14 @@ static ALPHABETIC_TABLE: UCDTrie = UCDTrie {
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x005A, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0002, 0x0002, 0x002A, 0x0056, 0x0038, 0x0057, 0x0054, 0x0058,
-        0x0059, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0002, 0x0004, 0x0004, 0x0002, 0x005A, 0x005B, 0x005C, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x002F, 0x005F, 0x003F, 0x0060, 0x005D, 0x0061,
+        0x0062, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0063, 0x0004, 0x0004,
+        0x0004, 0x0002, 0x0064, 0x0065, 0x0002, 0x0066, 0x0067, 0x0068, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
@@ -674,36 +2037,35 @@ static ALPHABETIC_TABLE: UCDTrie = UCDTrie {
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0002, 0x005D, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0002, 0x005E, 0x0021, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x0002, 0x0002, 0x0002, 0x0069, 0x0017, 0x0004, 0x0002, 0x0024, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x005F,
-        0x0002, 0x0002, 0x0002, 0x0002, 0x0060, 0x0061, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0062, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x006A,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x006B, 0x006C, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x006D, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0002, 0x0063,
-        0x0064, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0002, 0x006E,
+        0x006F, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0002, 0x0065, 0x0066, 0x0067, 0x0068, 0x0069, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x006A, 0x006B, 0x006C, 0x006D, 0x006E, 0x006F, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x0002, 0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0075, 0x0076, 0x0077, 0x0078, 0x0079, 0x007A, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0070, 0x0004, 0x0004, 0x0004, 0x0071, 0x0072,
-        0x0073, 0x0004, 0x0074, 0x0075, 0x0004, 0x0004, 0x0004, 0x0004, 0x0057, 0x0076,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0077, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0078,
-        0x0002, 0x0002, 0x0002, 0x0079, 0x0002, 0x007A, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x0004, 0x0004, 0x0004, 0x0004, 0x007B, 0x0004, 0x0004, 0x0004, 0x007C, 0x007D,
+        0x007E, 0x0004, 0x007F, 0x0080, 0x0004, 0x0004, 0x0004, 0x0004, 0x0060, 0x0081,
+        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0082, 0x0004, 0x0004,
+        0x0004, 0x0083, 0x0004, 0x0004, 0x0004, 0x0084, 0x0004, 0x0004, 0x0004, 0x0085,
+        0x0002, 0x0002, 0x0002, 0x0086, 0x0002, 0x0087, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x007B, 0x007C, 0x007D, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x007E, 0x007F, 0x0080, 0x0004,
+        0x0004, 0x0004, 0x0004, 0x0004, 0x0088, 0x0089, 0x008A, 0x0004, 0x0004, 0x0004,
+        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x008B, 0x008C, 0x008D, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
@@ -712,13 +2074,13 @@ static ALPHABETIC_TABLE: UCDTrie = UCDTrie {
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0002, 0x0081, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x008E, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0082, 0x0002,
         0x0002, 0x0002, 0x000A, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
@@ -728,28 +2090,28 @@ static ALPHABETIC_TABLE: UCDTrie = UCDTrie {
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0083, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x008F, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0084, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0085, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0090, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0091, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0085, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x0002, 0x0002, 0x0091, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0086,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0092,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0002, 0x0002, 0x0054, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0093, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
@@ -757,40 +2119,219 @@ static ALPHABETIC_TABLE: UCDTrie = UCDTrie {
         0x0004, 0x0004, 0x0004, 0x0004,
     ],
     r6: &[
-        0xB7FFFF7FFFFFEFFF, 0x000000003FFF3FFF, 0xFFFFFFFFFFFFFFFF, 0x07FFFFFFFFFFFFFF,
-        0x0000000000000000, 0x001FFFFFFFFFFFFF, 0xFFFFFFFF1FFFFFFF, 0x000000000001FFFF,
-        0xFFFFE000FFFFFFFF, 0x07FFFFFFFFFF07FF, 0xFFFFFFFF3FFFFFFF, 0x00000000003EFF0F,
-        0xFFFF00003FFFFFFF, 0x0FFFFFFFFF0FFFFF, 0xFFFF00FFFFFFFFFF, 0xF7FF000FFFFFFFFF,
-        0x1BFBFFFBFFB7F7FF, 0x007FFFFFFFFFFFFF, 0x000000FF003FFFFF, 0x07FDFFFFFFFFFFBF,
-        0x91BFFFFFFFFFFD3F, 0x007FFFFF003FFFFF, 0x000000007FFFFFFF, 0x0037FFFF00000000,
-        0x03FFFFFF003FFFFF, 0xC0FFFFFFFFFFFFFF, 0x003FFFFFFEEFF06F, 0x1FFFFFFF00000000,
-        0x000000001FFFFFFF, 0x0000001FFFFFFEFF, 0x003FFFFFFFFFFFFF, 0x0007FFFF003FFFFF,
-        0x000000000003FFFF, 0x00000000000001FF, 0x0007FFFFFFFFFFFF, 0x000000FFFFFFFFFF,
-        0x00031BFFFFFFFFFF, 0xFFFF00801FFFFFFF, 0xFFFF00000000003F, 0xFFFF000000000003,
-        0x007FFFFF0000001F, 0x003E00000000003F, 0x01FFFFFFFFFFFFFF, 0x000001FFFFFF0004,
-        0x0047FFFFFFFF00F0, 0x000000001400C01E, 0xC09FFFFFFFFBFFFF, 0x0000000000000003,
-        0xFFFF01FFBFFFBD7F, 0x000001FFFFFFFFFF, 0xE3EDFDFFFFF99FEF, 0x0000000FE081199F,
-        0x00000003800007BB, 0x00000000000000B3, 0x7F3FFFFFFFFFFFFF, 0x000000003F000000,
-        0x7FFFFFFFFFFFFFFF, 0x0000000000000011, 0x013FFFFFFFFFFFFF, 0x000007FFE7FFFFFF,
-        0x000000000000007F, 0xFFFFFFFF00000000, 0x80000000FFFFFFFF, 0x99BFFFFFFF6FF27F,
-        0x0000000000000007, 0xFFFFFCFF00000000, 0x0000001AFCFFFFFF, 0x7FE7FFFFFFFFFFFF,
-        0xFFFFFFFFFFFF0000, 0xFFFF000020FFFFFF, 0x7F7FFFFFFFFFFDFF, 0xFFFC000000000001,
-        0x007FFEFFFFFCFFFF, 0xB47FFFFFFFFFFB7F, 0xFFFFFDBF000000CB, 0x00000000017B7FFF,
-        0x007FFFFF00000000, 0xC7FFFFFFFFFDFFFF, 0x0000000000000001, 0x0001000000000000,
-        0x0000000003FFFFFF, 0x00007FFFFFFFFFFF, 0x000000000000000F, 0x0001FFFFFFFFFFFF,
-        0x0000FFFFFFFFFFFF, 0x000000000000007E, 0xFFFF00007FFFFFFF, 0x00003FFFFFFF0000,
-        0xE0FFFFF80000000F, 0x000000000000FFFF, 0xFFFFFFFFFFFF87FF, 0x00000000FFFF80FF,
-        0x0003000B00000000, 0x00FFFFFFFFFFFFFF, 0x00000000003FFFFF, 0x6FEF000000000000,
-        0x00040007FFFFFFFF, 0xFFFF00F000270000, 0x0FFFFFFFFFFFFFFF, 0x1FFF07FFFFFFFFFF,
-        0x0000000043FF01FF, 0xFFFFFFFFFFDFFFFF, 0xEBFFDE64DFFFFFFF, 0xFFFFFFFFFFFFFFEF,
-        0x7BFFFFFFDFDFE7BF, 0xFFFFFFFFFFFDFC5F, 0xFFFFFF3FFFFFFFFF, 0xF7FFFFFFF7FFFFFD,
-        0xFFDFFFFFFFDFFFFF, 0xFFFF7FFFFFFF7FFF, 0xFFFFFDFFFFFFFDFF, 0x0000000000000FF7,
-        0x000007E07FFFFFFF, 0xFFFF07DBF9FFFF7F, 0x00003FFFFFFFFFFF, 0x0000000000008000,
-        0x3F801FFFFFFFFFFF, 0x0000000000004000, 0x00000FFFFFFFFFFF, 0x00000FFFFFFF0000,
-        0x7FFF6F7F00000000, 0x000000000000001F, 0x000000000000088F, 0x0AF7FE96FFFFFFEF,
-        0x5EF7F796AA96EA84, 0x0FFFFBEE0FFFFBFF, 0xFFFF000000000000, 0xFFFF03FFFFFF03FF,
-        0x00000000000003FF, 0x00000000FFFFFFFF, 0x03FFFFFFFFFFFFFF, 0xFFFF0003FFFFFFFF,
-        0xFFFF0001FFFFFFFF, 0x000000003FFFFFFF, 0xFFFFFFFFFFFF07FF,
+        0xB7FFFF7FFFFFEFFF, 0x000000003FFF3FFF, 0xFFFFFFFFFFFFFFFF, 0x07FFFFFFFFFFFFFF,
+        0x0000000000000000, 0x001FFFFFFFFFFFFF, 0xFFFFFFFF1FFFFFFF, 0x000000000001FFFF,
+        0xFFFFE000FFFFFFFF, 0x07FFFFFFFFFF07FF, 0xFFFFFFFF3FFFFFFF, 0x00000000003EFF0F,
+        0xFFFF00003FFFFFFF, 0x0FFFFFFFFF0FFFFF, 0xFFFF00FFFFFFFFFF, 0xF7FF000FFFFFFFFF,
+        0x1BFBFFFBFFB7F7FF, 0x000FFFFFFFFFFFFF, 0x007FFFFFFFFFFFFF, 0x000000FF003FFFFF,
+        0x07FDFFFFFFFFFFBF, 0x91BFFFFFFFFFFD3F, 0x007FFFFF003FFFFF, 0x000000007FFFFFFF,
+        0x0037FFFF00000000, 0x03FFFFFF003FFFFF, 0x0000000003FFFFFF, 0xC0FFFFFFFFFFFFFF,
+        0x003FFFFFFEEFF06F, 0x1FFFFFFF00000000, 0x000000001FFFFFFF, 0x0000001FFFFFFEFF,
+        0x003FFFFFFFFFFFFF, 0x0007FFFF003FFFFF, 0x000000000003FFFF, 0x00000000000001FF,
+        0x0007FFFFFFFFFFFF, 0x000000FFFFFFFFFF, 0xFFFF823FFFFFFC00, 0x000000000000003F,
+        0x00031BFFFFFFFFFF, 0x1C000000000000FC, 0xFFFF00801FFFFFFF, 0xFFFF00000000003F,
+        0xFFFF000000000003, 0x007FFFFF0000001F, 0x003E00000000003F, 0x01FFFFFFFFFFFFFF,
+        0x000001FFFFFF0004, 0x0047FFFFFFFF00F0, 0x000000001400C01E, 0xC09FFFFFFFFBFFFF,
+        0x0000000000000003, 0xFFFF01FFBFFFBD7F, 0x000001FFFFFFFFFF, 0xE3EDFDFFFFF99FEF,
+        0x0000000FE081199F, 0xFFBFFFFFFFFF4BFF, 0x00000000000A37A5, 0x00000003800007BB,
+        0x00000000000000B3, 0x7F3FFFFFFFFFFFFF, 0x000000003F000000, 0x7FFFFFFFFFFFFFFF,
+        0x0000000000000011, 0x013FFFFFFFFFFFFF, 0x000007FFE7FFFFFF, 0x000000000000007F,
+        0xFFFFFFFF00000000, 0x80000000FFFFFFFF, 0x99BFFFFFFF6FF27F, 0x0000000000000007,
+        0xFFFFFCFF00000000, 0x0000001AFCFFFFFF, 0x7FE7FFFFFFFFFFFF, 0xFFFFFFFFFFFF0000,
+        0xFFFF000020FFFFFF, 0x000000FF00000000, 0x00000001FFFFFFFF, 0x7F7FFFFFFFFFFDFF,
+        0xFFFC000000000001, 0x007FFEFFFFFCFFFF, 0xB47FFFFFFFFFFB7F, 0xFFFFFDBF000000CB,
+        0xFFFF0000017B7FFF, 0x000000000FFFFFFF, 0x007FFFFF00000000, 0xC7FFFFFFFFFDFFFF,
+        0x0000000000000001, 0x0001000000000000, 0x00007FFFFFFFFFFF, 0x000000000000000F,
+        0x0001FFFFFFFFFFFF, 0x0000FFFFFFFFFFFF, 0xFFFFFFFF0000007E, 0xFFFF00007FFFFFFF,
+        0x00003FFFFFFF0000, 0xE0FFFFF80000000F, 0x000000000000FFFF, 0x00001FFFFFFFFFFF,
+        0xF9FFFFFF00000000, 0x00000000000FFFFF, 0xFFFFFFFFFFFF87FF, 0x00000000FFFF80FF,
+        0x007F000B00000000, 0x80000000003FFFFF, 0x6FEF000000000000, 0x00040007FFFFFFFF,
+        0xFFFF00F000270000, 0x0FFFFFFFFFFFFFFF, 0x1FFF07FFFFFFFFFF, 0x0000000043FF01FF,
+        0xFFFFFFFFFFDFFFFF, 0xEBFFDE64DFFFFFFF, 0xFFFFFFFFFFFFFFEF, 0x7BFFFFFFDFDFE7BF,
+        0xFFFFFFFFFFFDFC5F, 0xFFFFFF3FFFFFFFFF, 0xF7FFFFFFF7FFFFFD, 0xFFDFFFFFFFDFFFFF,
+        0xFFFF7FFFFFFF7FFF, 0xFFFFFDFFFFFFFDFF, 0x0000000000000FF7, 0x000007E07FFFFFFF,
+        0xFFFF07DBF9FFFF7F, 0x00003FFFFFFFFFFF, 0x0000000000008000, 0x3F801FFFFFFFFFFF,
+        0x0000000000004000, 0x00000FFFFFFFFFFF, 0x00000FFFFFFF0000, 0x00013FFFFFFF0000,
+        0xC03FFFFF7FFFFFFF, 0x7FFF6F7F00000000, 0x000000000000001F, 0x000000000000088F,
+        0x0AF7FE96FFFFFFEF, 0x5EF7F796AA96EA84, 0x0FFFFBEE0FFFFBFF, 0xFFFF000000000000,
+        0xFFFF03FFFFFF03FF, 0x00000000000003FF, 0x00000000FFFFFFFF, 0xFFFF3FFFFFFFFFFF,
+        0xFFFF0001FFFFFFFF, 0x000000003FFFFFFF, 0xFFFFFFFFFFFF07FF, 0x03FFFFFFFFFFFFFF,
+    ],
+};
+
+#[rustfmt::skip]
+static EXT_PICT_TABLE: UCDTrie = UCDTrie {
+    r1: [
+        0x0000000000000000, 0x0000000000000000, 0x0000420000000000, 0x0000000000000000,
+        0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+        0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+        0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+        0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+        0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+        0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+        0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    ],
+    r2: [
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0001, 0x0002, 0x0000, 0x0000,
+        0x0003, 0x0000, 0x0004, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0005, 0x0000,
+        0x0000, 0x0006, 0x0000, 0x0000, 0x0000, 0x0007, 0x0000, 0x0000, 0x0008, 0x0009,
+        0x000A, 0x000B, 0x000C, 0x000D, 0x000E, 0x000F, 0x0010, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0011, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0012, 0x0013, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0014, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0015, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000,
+    ],
+    r3: &[
+        0x0000000000000000, 0x1000000000000000, 0x0000000000000200, 0x0200000400000000,
+        0x0000060003F00000, 0x000001000C000000, 0x070FFE0000008000, 0x0000000000000004,
+        0x00400C0000000000, 0x7800000000000001, 0x0700C44D2132401F, 0xC8000169800FFF05,
+        0x60030C831AFC0000, 0x27BF0600001AC130, 0x001801022054BF24, 0x0000001800B85090,
+        0x8001000200E00000, 0x0030000000000000, 0x00000000180000E0, 0x0000000000210000,
+        0x2001000000000000, 0x0000000002800000,
+    ],
+    r4: [
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0001, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    ],
+    r5: &[
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0001, 0x0000, 0x0002, 0x0003, 0x0000, 0x0004,
+        0x0005, 0x0006, 0x0007, 0x0008, 0x0009, 0x0009, 0x000A, 0x0009, 0x000B, 0x000C,
+        0x0009, 0x0009, 0x0009, 0x000D, 0x000E, 0x000F, 0x0010, 0x0011, 0x0009, 0x0012,
+        0x0009, 0x0013, 0x0000, 0x0000, 0x0000, 0x0014, 0x0015, 0x0016, 0x0017, 0x0018,
+        0x0019, 0x001A, 0x0009, 0x0009, 0x0000, 0x001B, 0x0009, 0x0009, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009,
+        0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x000E,
+    ],
+    r6: &[
+        0x0000000000000000, 0x0000F00000000010, 0x00018000FFF00000, 0xFFC0000000018001,
+        0xC003000000000000, 0xFFFFC00007FE4000, 0x0000003FFFFFFFFF, 0xF7FC80000400FFFE,
+        0xFFFFFFC0FFFFFE00, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFF3FFFFFFFF, 0xFFFFFFFFCECFFFFF,
+        0x07B9FFFFFFFFFFFF, 0xBFFFFFFFFFFFFFFF, 0x3FFFFFFFFFFFFFFF, 0x07F980FFFFFF7E00,
+        0x1006013000613C80, 0xFC08810A700E001C, 0x000000000000FFFF, 0xFFF9FA3FFFE7F83F,
+        0xFFFFFFFFFC000000, 0x000000000000F000, 0x00000000FC00FF00, 0xF000C0000000FF00,
+        0xFFFFFFFFFE00FFFC, 0xF7FFFFFFFFFFF000, 0xFFFFFFFFFFFFFFBF, 0xFFFFC000FF000000,
     ],
 };
 
@@ -799,7 +2340,7 @@ static LOWERCASE_TABLE: UCDTrie = UCDTrie {
     r1: [
         0x0000000000000000, 0x07FFFFFE00000000, 0x0420040000000000, 0xFF7FFFFF80000000,
         0x55AAAAAAAAAAAAAA, 0xD4AAAAAAAAAAAB55, 0xE6512D2A4E243129, 0xAA29AAAAB5555240,
-        0x93FAAAAAAAAAAAAA, 0xFFFFFFFFFFFFAA85, 0x01FFFFFFFFEFFFFF, 0x0000001F00000003,
+        0x93FAAAAAAAAAAAAA, 0xFFFFFFFFFFFFAA85, 0x01FFFFFFFFCFFFFF, 0x0000001F00000003,
         0x0000000000000000, 0x3C8A000000000020, 0xFFFFF00000010000, 0x192FAAAAAAE37FFF,
         0xFFFF000000000000, 0xAAAAAAAAFFFFFFFF, 0xAAAAAAAAAAAAA802, 0xAAAAAAAAAAAAD554,
         0x0000AAAAAAAAAAAA, 0xFFFFFFFF00000000, 0x00000000000001FF, 0x0000000000000000,
@@ -909,13 +2450,13 @@ static LOWERCASE_TABLE: UCDTrie = UCDTrie {
         0x0000, 0x0000,
     ],
     r3: &[
-        0x0000000000000000, 0xF7FFFFFFFFFF0000, 0x3F00000000000000, 0x00000000000001FF,
+        0x0000000000000000, 0xF7FFFFFFFFFF0000, 0x3F00000000000000, 0x00000000000005FF,
         0xFFFFFFFFFFFFFFFF, 0xAAAAAAAAAAAAAAAA, 0xAAAAAAAABFEAAAAA, 0x00FF00FF003F00FF,
         0x3FFF00FF00FF003F, 0x40DF00FF00FF00FF, 0x00DC00FF00CF00DC, 0x8002000000000000,
         0x000000001FFF0000, 0x321080000008C400, 0xFFFF0000000043C0, 0x0000000000000010,
         0x000003FFFFFF0000, 0xFFFF000000000000, 0x3FDA1562FFFFFFFF, 0x0008501AAAAAAAAA,
         0x000020BFFFFFFFFF, 0x00002AAAAAAAAAAA, 0x000000003AAAAAAA, 0xAAABAAA800000000,
-        0x95FFAAAAAAAAAAAA, 0xAAA082AAAABA50AA, 0x075C000002AA050A, 0xFFFF03FFF7FFFFFF,
+        0x95FFAAAAAAAAAAAA, 0xAAA082AAAABA50AA, 0x075E00000AAAA50A, 0xFFFF03FFF7FFFFFF,
         0x0000000000F8007F, 0x0000000007FFFFFE,
     ],
     r4: [
@@ -952,11 +2493,11 @@ static LOWERCASE_TABLE: UCDTrie = UCDTrie {
         0x0000, 0x0000, 0x0004, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0005, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-        0x0000, 0x0006, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0006, 0x0000, 0x0007, 0x0008, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0007,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0009,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
@@ -971,31 +2512,32 @@ static LOWERCASE_TABLE: UCDTrie = UCDTrie {
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0008,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x000A,
+        0x000B, 0x000C, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x000D, 0x000E, 0x000F, 0x0010, 0x0011, 0x0012, 0x0013, 0x0014,
+        0x0015, 0x0016, 0x0017, 0x0018, 0x0019, 0x001A, 0x001B, 0x001C, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-        0x0000, 0x0000, 0x0009, 0x000A, 0x000B, 0x000C, 0x000D, 0x000E, 0x000F, 0x0010,
-        0x0011, 0x0012, 0x0013, 0x0014, 0x0015, 0x0016, 0x0017, 0x0018, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x001D, 0x0000, 0x0000, 0x0000,
+        0x0007, 0x001E, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0019, 0x0000, 0x0000, 0x0000,
-        0x001A, 0x001B, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x001C, 0x001D, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x001F, 0x0020, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000,
     ],
     r6: &[
         0x0000000000000000, 0xFFFFFF0000000000, 0x000000000000FFFF, 0x0FFFFFFFFF000000,
-        0x1BFBFFFBFF800000, 0x07FDFFFFFFFFFFB9, 0x0007FFFFFFFFFFFF, 0x00000000FFFFFFFF,
-        0xFFFFFFFF00000000, 0x000FFFFFFC000000, 0x000000FFFFDFC000, 0xEBC000000FFFFFFC,
+        0x1BFBFFFBFF800000, 0x07FDFFFFFFFFFFB9, 0x0007FFFFFFFFFFFF, 0xFFFF000000000000,
+        0x000000000000003F, 0x00000000FFFFFFFF, 0xFFFFFFFF00000000, 0xF800000000000000,
+        0x00000000000FFFFF, 0x000FFFFFFC000000, 0x000000FFFFDFC000, 0xEBC000000FFFFFFC,
         0xFFFFFC000000FFEF, 0x00FFFFFFC000000F, 0x00000FFFFFFC0000, 0xFC000000FFFFFFC0,
         0xFFFFC000000FFFFF, 0x0FFFFFFC000000FF, 0x0000FFFFFFC00000, 0x0000003FFFFFFC00,
         0xF0000003F7FFFFFC, 0xFFC000000FDFFFFF, 0xFFFF0000003F7FFF, 0xFFFFFC000000FDFF,
-        0x0000000000000BF7, 0x000007E07FFFFBFF, 0xFFFF000000000000, 0x00003FFFFFFFFFFF,
-        0xFFFFFFFC00000000, 0x000000000000000F,
+        0x0000000000000BF7, 0x000007E07FFFFBFF, 0x00003FFFFFFFFFFF, 0xFFFFFFFC00000000,
+        0x000000000000000F,
     ],
 };
 
@@ -1126,7 +2668,7 @@ static NUM_TABLE: UCDTrie = UCDTrie {
     ],
     r4: [
         0x0000, 0x0001, 0x0002, 0x0003, 0x0003, 0x0003, 0x0004, 0x0003, 0x0003, 0x0003,
-        0x0003, 0x0003, 0x0003, 0x0005, 0x0006, 0x0007, 0x0003, 0x0003, 0x0003, 0x0003,
+        0x0003, 0x0003, 0x0005, 0x0006, 0x0007, 0x0008, 0x0003, 0x0003, 0x0003, 0x0003,
         0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003,
         0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003,
         0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003,
@@ -1158,16 +2700,16 @@ static NUM_TABLE: UCDTrie = UCDTrie {
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0009, 0x000A, 0x000B, 0x000C, 0x0000, 0x000D, 0x000E,
         0x0000, 0x000F, 0x0010, 0x0011, 0x0000, 0x0012, 0x0013, 0x0000, 0x0000, 0x0000,
-        0x0000, 0x0014, 0x0015, 0x0000, 0x0000, 0x0000, 0x0000, 0x0016, 0x0000, 0x0000,
-        0x0017, 0x0018, 0x0000, 0x0019, 0x0000, 0x001A, 0x0000, 0x0015, 0x001B, 0x0000,
-        0x0000, 0x001C, 0x0000, 0x0000, 0x0000, 0x0015, 0x0000, 0x0000, 0x0000, 0x0000,
-        0x0000, 0x001D, 0x0000, 0x001D, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x001D,
-        0x0000, 0x001E, 0x001F, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0020,
-        0x0000, 0x001D, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-        0x0000, 0x0000, 0x0000, 0x0021, 0x0000, 0x0000, 0x0000, 0x001D, 0x0008, 0x0000,
-        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x001D, 0x0000, 0x0022, 0x0000, 0x0000,
+        0x0000, 0x0014, 0x0015, 0x0016, 0x0000, 0x0000, 0x0000, 0x0017, 0x0000, 0x0000,
+        0x0018, 0x0019, 0x0000, 0x001A, 0x0000, 0x001B, 0x0000, 0x0015, 0x001C, 0x0000,
+        0x0000, 0x001D, 0x0000, 0x0000, 0x0000, 0x0015, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x001E, 0x0000, 0x001E, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x001E,
+        0x0000, 0x001F, 0x0020, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0021,
+        0x0000, 0x001E, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0015, 0x0000, 0x0022, 0x0000, 0x0000, 0x0000, 0x001E, 0x0008, 0x0008,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x001E, 0x0000, 0x0023, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-        0x0000, 0x0000, 0x0000, 0x0000, 0x0023, 0x0024, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0024, 0x0025, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
@@ -1179,32 +2721,38 @@ static NUM_TABLE: UCDTrie = UCDTrie {
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0015, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0008, 0x0000, 0x0016,
+        0x0000, 0x0026, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0015,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0027, 0x0000, 0x0000, 0x0000, 0x0000, 0x0028,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0008, 0x0000, 0x001E,
-        0x0000, 0x0025, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-        0x0000, 0x0000, 0x0000, 0x0000, 0x0026, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-        0x0000, 0x0027, 0x0000, 0x0028, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-        0x0000, 0x0029, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x001E,
-        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0015, 0x0000, 0x0000, 0x0000, 0x0000,
-        0x0000, 0x0000, 0x0000, 0x0015, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x002A,
-        0x0000, 0x001D, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-        0x0000, 0x0000, 0x0000, 0x002B, 0x002C, 0x0000, 0x002D, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0015, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-        0x0000, 0x0000, 0x002E, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0029, 0x0000, 0x002A, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x002B, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0015, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-        0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0016, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0015,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0015, 0x0000, 0x0000,
+        0x0000, 0x002C, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x002D, 0x0000, 0x001E, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x002E, 0x002F, 0x0000,
+        0x0030, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0031, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0015,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
     ],
     r6: &[
         0x0000000000000000, 0x000FFFFFFFFFFF80, 0x01FFFFFFFFFFFFFF, 0x0000000000000C00,
@@ -1212,13 +2760,14 @@ static NUM_TABLE: UCDTrie = UCDTrie {
         0x000003FF00000000, 0xFE000000FF000000, 0x0000FF8000000000, 0xF800000000000000,
         0x000000000FC00000, 0x3000000000000000, 0xFFFFFFFFFFFCFFFF, 0x60000000000001FF,
         0x00000000E0000000, 0x0000F80000000000, 0xFF000000FF000000, 0x0000FE0000000000,
-        0xFC00000000000000, 0x03FF000000000000, 0x7FFFFFFF00000000, 0x0000007FE0000000,
-        0x00000000001E0000, 0x0000000000000FE0, 0x0000FFFFFFFC0000, 0xFFC0000000000000,
-        0x001FFFFE03FF0000, 0x0000000003FF0000, 0x00000000000003FF, 0x0FFF000000000000,
-        0x0007FFFF00000000, 0x00001FFFFFFF0000, 0x00000000001FFFFF, 0xFFFFFFFFFFFFFFFF,
-        0x00007FFFFFFFFFFF, 0x00000003FBFF0000, 0x00000000007FFFFF, 0x000FFFFF000FFFFF,
-        0x01FFFFFF00000000, 0xFFFFFFFFFFFFC000, 0x000000000000FF80, 0xFFFE000000000000,
-        0x001EEFFFFFFFFFFF, 0x3FFFBFFFFFFFFFFE, 0x0000000000001FFF,
+        0xFC00000000000000, 0x03FF000000000000, 0x00000000000003FF, 0x7FFFFFFF00000000,
+        0x0000007FE0000000, 0x00000000001E0000, 0x0000000000000FE0, 0x0000FFFFFFFC0000,
+        0xFFC0000000000000, 0x001FFFFE03FF0000, 0x0000000003FF0000, 0x0000000FFFFF03FF,
+        0x0FFF000000000000, 0x0007FFFF00000000, 0x00001FFFFFFF0000, 0x00000000001FFFFF,
+        0xFFFFFFFFFFFFFFFF, 0x00007FFFFFFFFFFF, 0x00000003FBFF0000, 0x00000000007FFFFF,
+        0x0070000000000000, 0x000FFFFF000FFFFF, 0x01FFFFFF00000000, 0xFFFFFFFFFFFFC000,
+        0x07FE000000000000, 0x000000000000FF80, 0xFFFE000000000000, 0x001EEFFFFFFFFFFF,
+        0x3FFFBFFFFFFFFFFE, 0x0000000000001FFF,
     ],
 };
 
@@ -1497,12 +3046,12 @@ static UPPERCASE_TABLE: UCDTrie = UCDTrie {
     ],
     r3: &[
         0x0000000000000000, 0xFFFFFFFF00000000, 0x00000000000020BF, 0x003FFFFFFFFFFFFF,
-        0xE7FFFFFFFFFF0000, 0x5555555555555555, 0x5555555540155555, 0xFF00FF003F00FF00,
+        0xE7FFFFFFFFFF0200, 0x5555555555555555, 0x5555555540155555, 0xFF00FF003F00FF00,
         0x0000FF00AA003F00, 0x0F00000000000000, 0x0F001F000F000F00, 0xC00F3D503E273884,
         0x0000FFFF00000020, 0x0000000000000008, 0xFFC0000000000000, 0x000000000000FFFF,
         0x0000FFFFFFFFFFFF, 0xC025EA9D00000000, 0x0004280555555555, 0x0000155555555555,
         0x0000000005555555, 0x5554555400000000, 0x6A00555555555555, 0x555F7D5555452855,
-        0x00200000014102F5, 0x07FFFFFE00000000,
+        0x0020000015555AF5, 0x07FFFFFE00000000,
     ],
     r4: [
         0x0000, 0x0001, 0x0002, 0x0002, 0x0002, 0x0002, 0x0003, 0x0002, 0x0002, 0x0002,
@@ -1538,12 +3087,11 @@ static UPPERCASE_TABLE: UCDTrie = UCDTrie {
         0x0000, 0x0004, 0x0005, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-        0x0006, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0006, 0x0000, 0x0000, 0x0007, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0007, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0008, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
@@ -1557,22 +3105,23 @@ static UPPERCASE_TABLE: UCDTrie = UCDTrie {
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0008,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0009,
+        0x000A, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-        0x0000, 0x0000, 0x0009, 0x000A, 0x000B, 0x000C, 0x000D, 0x000E, 0x000F, 0x0010,
-        0x0011, 0x0012, 0x0013, 0x0014, 0x0015, 0x0016, 0x0017, 0x0018, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x000B, 0x000C, 0x000D, 0x000E, 0x000F, 0x0010, 0x0011, 0x0012,
+        0x0013, 0x0014, 0x0015, 0x0016, 0x0017, 0x0018, 0x0019, 0x001A, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0019, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x001B, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0002, 0x001A,
-        0x001B, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0002, 0x001C,
+        0x001D, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
         0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
@@ -1581,12 +3130,13 @@ static UPPERCASE_TABLE: UCDTrie = UCDTrie {
     ],
     r6: &[
         0x0000000000000000, 0x000000FFFFFFFFFF, 0xFFFF000000000000, 0x00000000000FFFFF,
-        0xF7FF000000000000, 0x000000000037F7FF, 0x0007FFFFFFFFFFFF, 0xFFFFFFFF00000000,
-        0x00000000FFFFFFFF, 0xFFF0000003FFFFFF, 0xFFFFFF0000003FFF, 0x003FDE64D0000003,
-        0x000003FFFFFF0000, 0x7B0000001FDFE7B0, 0xFFFFF0000001FC5F, 0x03FFFFFF0000003F,
-        0x00003FFFFFF00000, 0xF0000003FFFFFF00, 0xFFFF0000003FFFFF, 0xFFFFFF00000003FF,
-        0x07FFFFFC00000001, 0x001FFFFFF0000000, 0x00007FFFFFC00000, 0x000001FFFFFF0000,
-        0x0000000000000400, 0x00000003FFFFFFFF, 0xFFFF03FFFFFF03FF, 0x00000000000003FF,
+        0xF7FF000000000000, 0x000000000037F7FF, 0x0007FFFFFFFFFFFF, 0x0000003FFFFF0000,
+        0xFFFFFFFF00000000, 0x00000000FFFFFFFF, 0x01FFFFFF00000000, 0xFFF0000003FFFFFF,
+        0xFFFFFF0000003FFF, 0x003FDE64D0000003, 0x000003FFFFFF0000, 0x7B0000001FDFE7B0,
+        0xFFFFF0000001FC5F, 0x03FFFFFF0000003F, 0x00003FFFFFF00000, 0xF0000003FFFFFF00,
+        0xFFFF0000003FFFFF, 0xFFFFFF00000003FF, 0x07FFFFFC00000001, 0x001FFFFFF0000000,
+        0x00007FFFFFC00000, 0x000001FFFFFF0000, 0x0000000000000400, 0x00000003FFFFFFFF,
+        0xFFFF03FFFFFF03FF, 0x00000000000003FF,
     ],
 };
 
@@ -1705,11 +3255,11 @@ static XID_CONTINUE_TABLE: UCDTrie = UCDTrie {
         0x007C, 0x007D,
     ],
     r3: &[
-        0x00003FFFFFFFFFFF, 0xFFFF07FF0FFFFFFF, 0xFFFFFFFFFF007EFF, 0xFFFFFFFBFFFFFFFF,
+        0x00003FFFFFFFFFFF, 0xFFFF07FF0FFFFFFF, 0xFFFFFFFFFF80FEFF, 0xFFFFFFFBFFFFFFFF,
         0xFFFFFFFFFFFFFFFF, 0xFFFEFFCFFFFFFFFF, 0xF3C5FDFFFFF99FEF, 0x5003FFCFB080799F,
         0xD36DFDFFFFF987EE, 0x003FFFC05E023987, 0xF3EDFDFFFFFBBFEE, 0xFE00FFCF00013BBF,
         0xF3EDFDFFFFF99FEE, 0x0002FFCFB0E0399F, 0xC3FFC718D63DC7EC, 0x0000FFC000813DC7,
-        0xF3FFFDFFFFFDDFFF, 0x0000FFCF27603DDF, 0xF3EFFDFFFFFDDFEF, 0x000EFFCF60603DDF,
+        0xF3FFFDFFFFFDDFFF, 0x0000FFCF37603DDF, 0xF3EFFDFFFFFDDFEF, 0x000EFFCF70603DDF,
         0xFFFFFFFFFFFDDFFF, 0xFC00FFCF80F07DDF, 0x2FFBFFFFFC7FFFEE, 0x000CFFC0FF5F847F,
         0x07FFFFFFFFFFFFFE, 0x0000000003FF7FFF, 0x3FFFFFAFFFFFF7D6, 0x00000000F3FF7F5F,
         0xC2A003FF03000001, 0xFFFE1FFFFFFFFEFF, 0x1FFFFFFFFEFFFFDF, 0x0000000000000040,
@@ -1720,8 +3270,8 @@ static XID_CONTINUE_TABLE: UCDTrie = UCDTrie {
         0x000003FF308FFFFF, 0xFFFFFFFF03FFB800, 0x01FFFFFFFFFFFFFF, 0xFFFF07FFFFFFFFFF,
         0x003FFFFFFFFFFFFF, 0x0FFF0FFF7FFFFFFF, 0x001F3FFFFFFFFFC0, 0xFFFF0FFFFFFFFFFF,
         0x0000000007FF03FF, 0xFFFFFFFF0FFFFFFF, 0x9FFFFFFF7FFFFFFF, 0xBFFF008003FF03FF,
-        0x0000000000007FFF, 0x000FF80003FF1FFF, 0x000FFFFFFFFFFFFF, 0x00FFFFFFFFFFFFFF,
-        0x3FFFFFFFFFFFE3FF, 0xE7FFFFFFFFFF01FF, 0x07FFFFFFFFF70000, 0xFFFFFFFF3F3FFFFF,
+        0x00000FFF3FFFFFFF, 0x000FF80003FF1FFF, 0x000FFFFFFFFFFFFF, 0x00FFFFFFFFFFFFFF,
+        0x3FFFFFFFFFFFE3FF, 0xE7FFFFFFFFFF07FF, 0x07FFFFFFFFF70000, 0xFFFFFFFF3F3FFFFF,
         0x3FFFFFFFAAFF3F3F, 0x5FDFFFFFFFFFFFFF, 0x1FDC1FFF0FCF1FDC, 0x8000000000003000,
         0x8002000000100001, 0x000000001FFF0000, 0x0001FFE21FFF0000, 0xF3FFFD503F2FFC84,
         0xFFFFFFFF000043E0, 0x00000000000001FF, 0x0000000000000000, 0x000FF81FFFFFFFFF,
@@ -1729,7 +3279,7 @@ static XID_CONTINUE_TABLE: UCDTrie = UCDTrie {
         0x1F3EFFFE000000E0, 0xFFFFFFFEE67FFFFF, 0xFFFEFFFFFFFFFFE0, 0xFFFFFFFF00007FFF,
         0xFFFF000000000000, 0x0000000000001FFF, 0x3FFFFFFFFFFF0000, 0x00000FFFFFFF1FFF,
         0xBFF0FFFFFFFFFFFF, 0x0003FFFFFFFFFFFF, 0xFFFFFFFCFF800000, 0xFFFFFFFFFFFFF9FF,
-        0xFFFC000003EB07FF, 0x000010FFFFFFFFFF, 0xE8FFFFFF03FF003F, 0xFFFF3FFFFFFFFFFF,
+        0xFFFE00001FFFFFFF, 0x000010FFFFFFFFFF, 0xE8FFFFFF03FF003F, 0xFFFF3FFFFFFFFFFF,
         0x1FFFFFFF000FFFFF, 0x7FFFFFFF03FF8001, 0x007FFFFFFFFFFFFF, 0xFC7FFFFF03FF3FFF,
         0x007CFFFF38000007, 0xFFFF7F7F007E7E7E, 0xFFFF03FFF7FFFFFF, 0x03FF37FFFFFFFFFF,
         0xFFFF000FFFFFFFFF, 0x0FFFFFFFFFFFF87F, 0x0000000003FFFFFF, 0x5F7FFDFFE0F8007F,
@@ -1742,7 +3292,7 @@ static XID_CONTINUE_TABLE: UCDTrie = UCDTrie {
         0x0000, 0x0001, 0x0002, 0x0003, 0x0004, 0x0005, 0x0006, 0x0007, 0x0008, 0x0005,
         0x0009, 0x000A, 0x000B, 0x000C, 0x000D, 0x000E, 0x0007, 0x0007, 0x0007, 0x0007,
         0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x000F, 0x0010, 0x0011, 0x0007,
-        0x0012, 0x0013, 0x0007, 0x0014, 0x0015, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005,
+        0x0012, 0x0013, 0x0007, 0x0014, 0x0007, 0x0015, 0x0005, 0x0005, 0x0005, 0x0005,
         0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005,
         0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005,
         0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005,
@@ -1769,34 +3319,33 @@ static XID_CONTINUE_TABLE: UCDTrie = UCDTrie {
     r5: &[
         0x0000, 0x0001, 0x0002, 0x0003, 0x0004, 0x0005, 0x0004, 0x0006, 0x0004, 0x0004,
         0x0007, 0x0008, 0x0009, 0x000A, 0x000B, 0x000C, 0x0002, 0x0002, 0x000D, 0x000E,
-        0x000F, 0x0010, 0x0011, 0x0004, 0x0002, 0x0002, 0x0002, 0x0002, 0x0012, 0x0013,
-        0x0014, 0x0004, 0x0015, 0x0016, 0x0017, 0x0018, 0x0019, 0x0004, 0x001A, 0x0004,
-        0x001B, 0x001C, 0x001D, 0x001E, 0x001F, 0x0020, 0x0021, 0x0004, 0x0002, 0x0022,
-        0x0023, 0x0023, 0x0024, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0025, 0x0026,
-        0x0027, 0x0028, 0x0029, 0x002A, 0x0002, 0x002B, 0x0003, 0x002C, 0x002D, 0x002E,
-        0x0002, 0x002F, 0x0030, 0x0031, 0x0032, 0x0033, 0x0034, 0x0035, 0x0004, 0x0004,
-        0x0002, 0x0036, 0x0002, 0x0037, 0x0004, 0x0004, 0x0038, 0x0039, 0x0002, 0x003A,
-        0x003B, 0x003C, 0x003D, 0x003E, 0x0004, 0x0004, 0x0003, 0x0004, 0x003F, 0x0040,
-        0x0041, 0x0042, 0x0043, 0x0044, 0x0045, 0x0046, 0x0047, 0x003B, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0048, 0x0049, 0x004A, 0x0004, 0x004B, 0x004C, 0x004D, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x004E, 0x004F, 0x0050, 0x0051, 0x0004, 0x0002, 0x0002,
+        0x000F, 0x0010, 0x0011, 0x0012, 0x0002, 0x0002, 0x0002, 0x0002, 0x0013, 0x0014,
+        0x0015, 0x0004, 0x0016, 0x0017, 0x0018, 0x0019, 0x001A, 0x001B, 0x001C, 0x0004,
+        0x001D, 0x001E, 0x001F, 0x0020, 0x0021, 0x0022, 0x0023, 0x0004, 0x0002, 0x0024,
+        0x0025, 0x0025, 0x0026, 0x0027, 0x0028, 0x0004, 0x0004, 0x0004, 0x0029, 0x002A,
+        0x002B, 0x002C, 0x002D, 0x002E, 0x0002, 0x002F, 0x0003, 0x0030, 0x0031, 0x0032,
+        0x0002, 0x0033, 0x0034, 0x0035, 0x0036, 0x0037, 0x0038, 0x0039, 0x003A, 0x003B,
+        0x0002, 0x003C, 0x0002, 0x003D, 0x0004, 0x0004, 0x003E, 0x003F, 0x0002, 0x0040,
+        0x0041, 0x0042, 0x0043, 0x0044, 0x0004, 0x0004, 0x0003, 0x0004, 0x0045, 0x0046,
+        0x0047, 0x0048, 0x0049, 0x004A, 0x004B, 0x004C, 0x004D, 0x0041, 0x0004, 0x004E,
+        0x0004, 0x004F, 0x0050, 0x0051, 0x0052, 0x0004, 0x0053, 0x0054, 0x0055, 0x0056,
+        0x0004, 0x0004, 0x0004, 0x0057, 0x0058, 0x0059, 0x005A, 0x0004, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0052, 0x0004, 0x0002, 0x0053, 0x0002, 0x0002, 0x0002, 0x0054,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0055, 0x0056, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0057, 0x0058,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x0002, 0x0002, 0x001B, 0x0004, 0x0002, 0x005B, 0x0002, 0x0002, 0x0002, 0x005C,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x003E, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x005D, 0x005E, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x005F, 0x0060,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0003, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0044, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
@@ -1806,12 +3355,13 @@ static XID_CONTINUE_TABLE: UCDTrie = UCDTrie {
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0061, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0002, 0x0002, 0x003B, 0x0059, 0x0045, 0x005A, 0x0012, 0x005B,
-        0x005C, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0002, 0x0004, 0x0004, 0x0002, 0x005D, 0x005E, 0x005F, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0041, 0x0062, 0x004B, 0x0063, 0x0013, 0x0064,
+        0x0065, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0066, 0x0004, 0x0004,
+        0x0004, 0x0002, 0x0067, 0x0068, 0x0002, 0x0069, 0x006A, 0x006B, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
@@ -1821,58 +3371,58 @@ static XID_CONTINUE_TABLE: UCDTrie = UCDTrie {
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0002, 0x0060, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0002, 0x0058, 0x0022, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x006C, 0x0018, 0x0004, 0x0002, 0x0025, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0061,
-        0x0002, 0x0002, 0x0002, 0x0002, 0x0062, 0x0063, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0064, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x006D,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x006E, 0x006F, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0070, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0002, 0x0065,
-        0x0066, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0002, 0x0071,
+        0x0072, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0067, 0x003E, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0068, 0x0069, 0x0004, 0x0004, 0x006A, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0002, 0x006B, 0x006C, 0x006D, 0x006E, 0x006F,
-        0x0002, 0x0002, 0x0002, 0x0002, 0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0075,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0076, 0x0077,
-        0x0078, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0079, 0x0004,
-        0x0004, 0x0004, 0x007A, 0x007B, 0x007C, 0x0004, 0x007D, 0x007E, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x007F, 0x0080, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0081, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0082, 0x0002, 0x0002, 0x0002, 0x0083, 0x0002, 0x0084,
+        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0073, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x0004, 0x0004, 0x0004, 0x0004, 0x0074, 0x0044, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x0004, 0x0004, 0x0004, 0x0075, 0x0076, 0x0004, 0x0004, 0x0077, 0x0004, 0x0004,
+        0x0004, 0x0004, 0x0004, 0x0004, 0x0002, 0x0078, 0x0079, 0x007A, 0x007B, 0x007C,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x007D, 0x007E, 0x007F, 0x0080, 0x0081, 0x0082,
+        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0083, 0x0084,
+        0x0085, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0086, 0x0004,
+        0x0004, 0x0004, 0x0087, 0x0088, 0x0089, 0x0004, 0x008A, 0x008B, 0x0004, 0x0004,
+        0x0004, 0x0004, 0x008C, 0x0061, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x0004, 0x008D, 0x0004, 0x0004, 0x0004, 0x008E, 0x0004, 0x0004, 0x0004, 0x008F,
+        0x0004, 0x0004, 0x0004, 0x0090, 0x0002, 0x0002, 0x0002, 0x0091, 0x0002, 0x0092,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0085, 0x0086,
-        0x0087, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0093, 0x0094,
+        0x0095, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0088, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x0004, 0x0004, 0x0004, 0x0073, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0089, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0096, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0080, 0x0002, 0x0002, 0x0002, 0x000B, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x000B, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
@@ -1881,34 +3431,34 @@ static XID_CONTINUE_TABLE: UCDTrie = UCDTrie {
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x008A, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0074, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x008B,
-        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x008C,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0097,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0098,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x008C, 0x0004, 0x0004, 0x0004,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0098, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0002, 0x008D, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x0099, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0057, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0061, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0002, 0x0002, 0x0002, 0x0057, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x0004, 0x0004, 0x0002, 0x0002, 0x0002, 0x005F, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
@@ -1921,37 +3471,40 @@ static XID_CONTINUE_TABLE: UCDTrie = UCDTrie {
         0x0000000000000000, 0x001FFFFFFFFFFFFF, 0x2000000000000000, 0xFFFFFFFF1FFFFFFF,
         0x000000010001FFFF, 0xFFFFE000FFFFFFFF, 0x07FFFFFFFFFF07FF, 0xFFFFFFFF3FFFFFFF,
         0x00000000003EFF0F, 0xFFFF03FF3FFFFFFF, 0x0FFFFFFFFF0FFFFF, 0xFFFF00FFFFFFFFFF,
-        0xF7FF000FFFFFFFFF, 0x1BFBFFFBFFB7F7FF, 0x007FFFFFFFFFFFFF, 0x000000FF003FFFFF,
-        0x07FDFFFFFFFFFFBF, 0x91BFFFFFFFFFFD3F, 0x007FFFFF003FFFFF, 0x000000007FFFFFFF,
-        0x0037FFFF00000000, 0x03FFFFFF003FFFFF, 0xC0FFFFFFFFFFFFFF, 0x873FFFFFFEEFF06F,
-        0x1FFFFFFF00000000, 0x000000001FFFFFFF, 0x0000007FFFFFFEFF, 0x003FFFFFFFFFFFFF,
-        0x0007FFFF003FFFFF, 0x000000000003FFFF, 0x00000000000001FF, 0x0007FFFFFFFFFFFF,
-        0x03FF00FFFFFFFFFF, 0x00031BFFFFFFFFFF, 0xE000000000000000, 0xFFFF00801FFFFFFF,
+        0xF7FF000FFFFFFFFF, 0x1BFBFFFBFFB7F7FF, 0x000FFFFFFFFFFFFF, 0x007FFFFFFFFFFFFF,
+        0x000000FF003FFFFF, 0x07FDFFFFFFFFFFBF, 0x91BFFFFFFFFFFD3F, 0x007FFFFF003FFFFF,
+        0x000000007FFFFFFF, 0x0037FFFF00000000, 0x03FFFFFF003FFFFF, 0x0000000003FFFFFF,
+        0xC0FFFFFFFFFFFFFF, 0x873FFFFFFEEFF06F, 0x1FFFFFFF00000000, 0x000000001FFFFFFF,
+        0x0000007FFFFFFEFF, 0x003FFFFFFFFFFFFF, 0x0007FFFF003FFFFF, 0x000000000003FFFF,
+        0x00000000000001FF, 0x0007FFFFFFFFFFFF, 0x03FF00FFFFFFFFFF, 0xFFFFBE3FFFFFFFFF,
+        0x000000000000003F, 0x00031BFFFFFFFFFF, 0xFC000000000000FC, 0xFFFF00801FFFFFFF,
         0xFFFF00000001FFFF, 0xFFFF00000000003F, 0x007FFFFF0000001F, 0x803FFFC00000007F,
         0x03FF01FFFFFF0004, 0xFFDFFFFFFFFFFFFF, 0x004FFFFFFFFF00F0, 0x0000000017FFDE1F,
         0xC0FFFFFFFFFBFFFF, 0x0000000000000003, 0xFFFF01FFBFFFBD7F, 0x03FF07FFFFFFFFFF,
-        0xFBEDFDFFFFF99FEF, 0x001F1FCFE081399F, 0x00000003C3FF07FF, 0x0000000003FF00BF,
-        0xFF3FFFFFFFFFFFFF, 0x000000003F000001, 0x0000000003FF0011, 0x01FFFFFFFFFFFFFF,
-        0x00000000000003FF, 0x03FF0FFFE7FFFFFF, 0x000000000000007F, 0xFFFFFFFF00000000,
-        0x800003FFFFFFFFFF, 0xF9BFFFFFFF6FF27F, 0x0000000003FF000F, 0xFFFFFCFF00000000,
-        0x0000001BFCFFFFFF, 0x7FFFFFFFFFFFFFFF, 0xFFFFFFFFFFFF0080, 0xFFFF000023FFFFFF,
+        0xFBEDFDFFFFF99FEF, 0x001F1FCFE081399F, 0xFFBFFFFFFFFF4BFF, 0x00000006000FF7A5,
+        0x00000003C3FF07FF, 0x0000000003FF00BF, 0xFF3FFFFFFFFFFFFF, 0x000000003F000001,
+        0x0000000003FF0011, 0x01FFFFFFFFFFFFFF, 0x0000000FFFFF03FF, 0x03FF0FFFE7FFFFFF,
+        0x000000000000007F, 0xFFFFFFFF00000000, 0x800003FFFFFFFFFF, 0xF9BFFFFFFF6FF27F,
+        0x0000000003FF000F, 0xFFFFFCFF00000000, 0x0000001BFCFFFFFF, 0x7FFFFFFFFFFFFFFF,
+        0xFFFFFFFFFFFF0080, 0xFFFF000023FFFFFF, 0x000000FF00000000, 0x03FF0001FFFFFFFF,
         0xFF7FFFFFFFFFFDFF, 0xFFFC000003FF0001, 0x007FFEFFFFFCFFFF, 0xB47FFFFFFFFFFB7F,
-        0xFFFFFDBF03FF00FF, 0x000003FF01FB7FFF, 0x007FFFFF00000000, 0xC7FFFFFFFFFDFFFF,
-        0x0000000003FF0007, 0x0001000000000000, 0x0000000003FFFFFF, 0x00007FFFFFFFFFFF,
+        0xFFFFFDBF03FF00FF, 0xFFFF03FF01FB7FFF, 0x000003FF0FFFFFFF, 0x007FFFFF00000000,
+        0xC7FFFFFFFFFDFFFF, 0x0000000007FF0007, 0x0001000000000000, 0x00007FFFFFFFFFFF,
         0x000000000000000F, 0xFFFFFFFFFFFF0000, 0x0001FFFFFFFFFFFF, 0x0000FFFFFFFFFFFF,
-        0x00000000003FFFFF, 0xFFFF03FF7FFFFFFF, 0x001F3FFFFFFF03FF, 0xE0FFFFF803FF000F,
-        0x000000000000FFFF, 0xFFFFFFFFFFFF87FF, 0x00000000FFFF80FF, 0x0003001B00000000,
-        0x00FFFFFFFFFFFFFF, 0x6FEF000000000000, 0x00040007FFFFFFFF, 0xFFFF00F000270000,
-        0x0FFFFFFFFFFFFFFF, 0x1FFF07FFFFFFFFFF, 0x0000000063FF01FF, 0xFFFF3FFFFFFFFFFF,
-        0xF807E3E000000000, 0x00003C0000000FE7, 0x000000000000001C, 0xFFFFFFFFFFDFFFFF,
-        0xEBFFDE64DFFFFFFF, 0xFFFFFFFFFFFFFFEF, 0x7BFFFFFFDFDFE7BF, 0xFFFFFFFFFFFDFC5F,
-        0xFFFFFF3FFFFFFFFF, 0xF7FFFFFFF7FFFFFD, 0xFFDFFFFFFFDFFFFF, 0xFFFF7FFFFFFF7FFF,
-        0xFFFFFDFFFFFFFDFF, 0xFFFFFFFFFFFFCFF7, 0xF87FFFFFFFFFFFFF, 0x00201FFFFFFFFFFF,
-        0x0000FFFEF8000010, 0x000007E07FFFFFFF, 0xFFFF07DBF9FFFF7F, 0x00003FFFFFFFFFFF,
-        0x0000000000008000, 0x3FFF1FFFFFFFFFFF, 0x00000000000043FF, 0x00007FFFFFFF0000,
-        0x03FFFFFFFFFFFFFF, 0x03FFFFFFFFFF0000, 0x7FFF6F7F00000000, 0x00000000007F001F,
-        0x0000000003FF0FFF, 0x0AF7FE96FFFFFFEF, 0x5EF7F796AA96EA84, 0x0FFFFBEE0FFFFBFF,
-        0x03FF000000000000, 0x00000000FFFFFFFF, 0xFFFF0003FFFFFFFF, 0xFFFF0001FFFFFFFF,
+        0xFFFFFFFF003FFFFF, 0x03FFFFFFFFFFFFFF, 0xFFFF03FF7FFFFFFF, 0x001F3FFFFFFF03FF,
+        0xE0FFFFF803FF000F, 0x000000000000FFFF, 0x03FF1FFFFFFFFFFF, 0xF9FFFFFF00000000,
+        0x00000000000FFFFF, 0xFFFFFFFFFFFF87FF, 0x00000000FFFF80FF, 0x007F001B00000000,
+        0x80000000003FFFFF, 0x6FEF000000000000, 0x00040007FFFFFFFF, 0xFFFF00F000270000,
+        0x0FFFFFFFFFFFFFFF, 0x1FFF07FFFFFFFFFF, 0x0000000063FF01FF, 0x03FF000000000000,
+        0xFFFF3FFFFFFFFFFF, 0xF807E3E000000000, 0x00003C0000000FE7, 0x000000000000001C,
+        0xFFFFFFFFFFDFFFFF, 0xEBFFDE64DFFFFFFF, 0xFFFFFFFFFFFFFFEF, 0x7BFFFFFFDFDFE7BF,
+        0xFFFFFFFFFFFDFC5F, 0xFFFFFF3FFFFFFFFF, 0xF7FFFFFFF7FFFFFD, 0xFFDFFFFFFFDFFFFF,
+        0xFFFF7FFFFFFF7FFF, 0xFFFFFDFFFFFFFDFF, 0xFFFFFFFFFFFFCFF7, 0xF87FFFFFFFFFFFFF,
+        0x00201FFFFFFFFFFF, 0x0000FFFEF8000010, 0x000007E07FFFFFFF, 0xFFFF07DBF9FFFF7F,
+        0x00003FFFFFFFFFFF, 0x0000000000008000, 0x3FFF1FFFFFFFFFFF, 0x00000000000043FF,
+        0x00007FFFFFFF0000, 0x03FFFFFFFFFF0000, 0x07FFFFFFFFFF0000, 0xC03FFFFF7FFFFFFF,
+        0x7FFF6F7F00000000, 0x00000000007F001F, 0x0000000003FF0FFF, 0x0AF7FE96FFFFFFEF,
+        0x5EF7F796AA96EA84, 0x0FFFFBEE0FFFFBFF, 0x00000000FFFFFFFF, 0xFFFF0001FFFFFFFF,
         0x000000003FFFFFFF, 0xFFFFFFFFFFFF07FF,
     ],
 };
@@ -2071,11 +3624,11 @@ static XID_START_TABLE: UCDTrie = UCDTrie {
         0x0081, 0x0082,
     ],
     r3: &[
-        0x00000110043FFFFF, 0xFFFF07FF01FFFFFF, 0xFFFFFFFF00007EFF, 0x00000000000003FF,
+        0x00000110043FFFFF, 0xFFFF07FF01FFFFFF, 0xFFFFFFFF0000FEFF, 0x00000000000003FF,
         0x23FFFFFFFFFFFFF0, 0xFFFE0003FF010000, 0x23C5FDFFFFF99FE1, 0x10030003B0004000,
         0x036DFDFFFFF987E0, 0x001C00005E000000, 0x23EDFDFFFFFBBFE0, 0x0200000300010000,
         0x23EDFDFFFFF99FE0, 0x00020003B0000000, 0x03FFC718D63DC7E8, 0x0000000000010000,
-        0x23FFFDFFFFFDDFE0, 0x0000000327000000, 0x23EFFDFFFFFDDFE1, 0x0006000360000000,
+        0x23FFFDFFFFFDDFE0, 0x0000000337000000, 0x23EFFDFFFFFDDFE1, 0x0006000370000000,
         0x27FFFFFFFFFDDFF0, 0xFC00000380704000, 0x2FFBFFFFFC7FFFE0, 0x000000000000007F,
         0x0005FFFFFFFFFFFE, 0x2005FFAFFFFFF7D6, 0x00000000F000005F, 0x0000000000000001,
         0x00001FFFFFFFFEFF, 0x0000000000001F00, 0x0000000000000000, 0x800007FFFFFFFFFF,
@@ -2087,7 +3640,7 @@ static XID_START_TABLE: UCDTrie = UCDTrie {
         0x01FFFFFFFFFFFFFF, 0xFFFF05FFFFFFFFFF, 0x003FFFFFFFFFFFFF, 0x000000007FFFFFFF,
         0x001F3FFFFFFF0000, 0xFFFF0FFFFFFFFFFF, 0xFFFFFFFF007FFFFF, 0x00000000001FFFFF,
         0x0000008000000000, 0x000FFFFFFFFFFFE0, 0x0000000000001FE0, 0xFC00C001FFFFFFF8,
-        0x0000003FFFFFFFFF, 0x0000000FFFFFFFFF, 0x3FFFFFFFFC00E000, 0xE7FFFFFFFFFF01FF,
+        0x0000003FFFFFFFFF, 0x0000000FFFFFFFFF, 0x3FFFFFFFFC00E000, 0xE7FFFFFFFFFF07FF,
         0x046FDE0000000000, 0xFFFFFFFF3F3FFFFF, 0x3FFFFFFFAAFF3F3F, 0x5FDFFFFFFFFFFFFF,
         0x1FDC1FFF0FCF1FDC, 0x8002000000000000, 0x000000001FFF0000, 0xF3FFFD503F2FFC84,
         0xFFFFFFFF000043E0, 0x00000000000001FF, 0x000C781FFFFFFFFF, 0xFFFF20BFFFFFFFFF,
@@ -2095,7 +3648,7 @@ static XID_START_TABLE: UCDTrie = UCDTrie {
         0xFFFFFFFEE07FFFFF, 0xF7FFFFFFFFFFFFFF, 0xFFFEFFFFFFFFFFE0, 0xFFFFFFFF00007FFF,
         0xFFFF000000000000, 0x0000000000001FFF, 0x3FFFFFFFFFFF0000, 0x00000C00FFFF1FFF,
         0x80007FFFFFFFFFFF, 0xFFFFFFFF3FFFFFFF, 0x0000FFFFFFFFFFFF, 0xFFFFFFFCFF800000,
-        0xFFFFFFFFFFFFF9FF, 0xFFFC000003EB07FF, 0x00000007FFFFF7BB, 0x000FFFFFFFFFFFFC,
+        0xFFFFFFFFFFFFF9FF, 0xFFFE00001FFFFFFF, 0x00000007FFFFF7BB, 0x000FFFFFFFFFFFFC,
         0x68FC000000000000, 0xFFFF003FFFFFFC00, 0x1FFFFFFF0000007F, 0x0007FFFFFFFFFFF0,
         0x7C00FFDF00008000, 0x000001FFFFFFFFFF, 0xC47FFFFF00000FF7, 0x3E62FFFFFFFFFFFF,
         0x001C07FF38000005, 0xFFFF7F7F007E7E7E, 0xFFFF03FFF7FFFFFF, 0x00000007FFFFFFFF,
@@ -2109,7 +3662,7 @@ static XID_START_TABLE: UCDTrie = UCDTrie {
         0x0000, 0x0001, 0x0002, 0x0003, 0x0004, 0x0005, 0x0006, 0x0007, 0x0008, 0x0005,
         0x0009, 0x000A, 0x0005, 0x000B, 0x000C, 0x0005, 0x0007, 0x0007, 0x0007, 0x0007,
         0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x000D, 0x000E, 0x000F, 0x0007,
-        0x0010, 0x0011, 0x0007, 0x0012, 0x0013, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005,
+        0x0010, 0x0011, 0x0007, 0x0012, 0x0007, 0x0013, 0x0005, 0x0005, 0x0005, 0x0005,
         0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005,
         0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005,
         0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005,
@@ -2136,34 +3689,33 @@ static XID_START_TABLE: UCDTrie = UCDTrie {
     r5: &[
         0x0000, 0x0001, 0x0002, 0x0003, 0x0004, 0x0005, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0006, 0x0007, 0x0008, 0x0009, 0x000A, 0x000B, 0x0002, 0x0002, 0x000C, 0x000D,
-        0x000E, 0x000F, 0x0010, 0x0004, 0x0002, 0x0002, 0x0002, 0x0002, 0x0011, 0x0012,
-        0x0013, 0x0004, 0x0014, 0x0015, 0x0016, 0x0017, 0x0018, 0x0004, 0x0019, 0x0004,
-        0x001A, 0x001B, 0x001C, 0x001D, 0x001E, 0x001F, 0x0020, 0x0004, 0x0002, 0x0021,
-        0x0022, 0x0022, 0x0023, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0024, 0x0004,
-        0x0025, 0x0026, 0x0027, 0x0028, 0x0029, 0x002A, 0x002B, 0x002C, 0x002D, 0x002E,
-        0x002F, 0x0030, 0x0031, 0x0032, 0x0033, 0x0016, 0x0034, 0x0035, 0x0004, 0x0004,
-        0x0005, 0x0036, 0x0037, 0x0038, 0x0004, 0x0004, 0x0039, 0x003A, 0x0037, 0x003B,
-        0x003C, 0x0004, 0x003D, 0x003E, 0x0004, 0x0004, 0x003F, 0x0004, 0x0040, 0x0041,
-        0x0042, 0x0043, 0x0044, 0x0045, 0x0046, 0x0047, 0x0048, 0x0049, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x004A, 0x004B, 0x004C, 0x0004, 0x004D, 0x004E, 0x004F, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0050, 0x0051, 0x0004, 0x0052, 0x0004, 0x0002, 0x0002,
+        0x000E, 0x000F, 0x0010, 0x0011, 0x0002, 0x0002, 0x0002, 0x0002, 0x0012, 0x0013,
+        0x0014, 0x0004, 0x0015, 0x0016, 0x0017, 0x0018, 0x0019, 0x001A, 0x001B, 0x0004,
+        0x001C, 0x001D, 0x001E, 0x001F, 0x0020, 0x0021, 0x0022, 0x0004, 0x0002, 0x0023,
+        0x0024, 0x0024, 0x0025, 0x0026, 0x0027, 0x0004, 0x0004, 0x0004, 0x0028, 0x0029,
+        0x002A, 0x002B, 0x002C, 0x002D, 0x002E, 0x002F, 0x0030, 0x0031, 0x0032, 0x0033,
+        0x0034, 0x0035, 0x0036, 0x0037, 0x0038, 0x0017, 0x0039, 0x003A, 0x003B, 0x003C,
+        0x0005, 0x003D, 0x003E, 0x003F, 0x0004, 0x0004, 0x0040, 0x0041, 0x003E, 0x0042,
+        0x0043, 0x0004, 0x0044, 0x0045, 0x0004, 0x0004, 0x0046, 0x0004, 0x0047, 0x0048,
+        0x0049, 0x004A, 0x004B, 0x004C, 0x004D, 0x004E, 0x004F, 0x0050, 0x0004, 0x0004,
+        0x0004, 0x0051, 0x0052, 0x0053, 0x0054, 0x0004, 0x0055, 0x0056, 0x0057, 0x0058,
+        0x0004, 0x0004, 0x0004, 0x0059, 0x005A, 0x0004, 0x005B, 0x0004, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0053, 0x0004, 0x0002, 0x0039, 0x0002, 0x0002, 0x0002, 0x0054,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x0002, 0x0002, 0x001A, 0x0004, 0x0002, 0x0040, 0x0002, 0x0002, 0x0002, 0x005C,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0055, 0x0056, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0037, 0x0057,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x003E, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x005D, 0x005E, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x003E, 0x005F,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0003, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0045, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
@@ -2173,12 +3725,14 @@ static XID_START_TABLE: UCDTrie = UCDTrie {
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0060, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0002, 0x0002, 0x0049, 0x0058, 0x0059, 0x005A, 0x0037, 0x005B,
-        0x004C, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0002, 0x0004, 0x0004, 0x0002, 0x005C, 0x005D, 0x005E, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0050, 0x0061, 0x0062, 0x0063, 0x003E, 0x0064,
+        0x0054, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0065, 0x0004, 0x0004,
+        0x0004, 0x0002, 0x0066, 0x0067, 0x0002, 0x0068, 0x0069, 0x006A, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
@@ -2188,45 +3742,44 @@ static XID_START_TABLE: UCDTrie = UCDTrie {
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0002, 0x005F, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0002, 0x0060, 0x0021, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x0002, 0x0002, 0x0002, 0x006B, 0x0017, 0x0004, 0x0002, 0x0024, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0061,
-        0x0002, 0x0002, 0x0002, 0x0002, 0x0062, 0x0063, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0064, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x006C,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x006D, 0x006E, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x006F, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0002, 0x0065,
-        0x0066, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0002, 0x0070,
+        0x0071, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0002, 0x0067, 0x0068, 0x0069, 0x006A, 0x006B, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x006C, 0x006D, 0x006E, 0x006F, 0x0070, 0x0071, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x0002, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0077, 0x0078, 0x0079, 0x007A, 0x007B, 0x007C, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0072, 0x0004, 0x0004, 0x0004, 0x0073, 0x0074,
-        0x0004, 0x0004, 0x0075, 0x0076, 0x0004, 0x0004, 0x0004, 0x0004, 0x005A, 0x003F,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0077, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0078,
-        0x0002, 0x0002, 0x0002, 0x0079, 0x0002, 0x007A, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x0004, 0x0004, 0x0004, 0x0004, 0x007D, 0x0004, 0x0004, 0x0004, 0x007E, 0x007F,
+        0x0004, 0x0004, 0x0080, 0x0081, 0x0004, 0x0004, 0x0004, 0x0004, 0x0063, 0x0046,
+        0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0082, 0x0004, 0x0004,
+        0x0004, 0x0083, 0x0004, 0x0004, 0x0004, 0x0084, 0x0004, 0x0004, 0x0004, 0x0085,
+        0x0002, 0x0002, 0x0002, 0x0086, 0x0002, 0x0087, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0004, 0x0004, 0x0004, 0x0004, 0x007B, 0x007C, 0x007D, 0x0004, 0x0004, 0x0004,
+        0x0004, 0x0004, 0x0004, 0x0004, 0x0088, 0x0089, 0x008A, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x007E,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x008B,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0002, 0x0002, 0x007F, 0x0002, 0x0002, 0x0002, 0x000A, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x000A, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
@@ -2235,29 +3788,29 @@ static XID_START_TABLE: UCDTrie = UCDTrie {
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0080, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x008C, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0081, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0082, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x0002, 0x008D, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0060, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
-        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0082, 0x0004,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0060, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0083, 0x0002, 0x0002, 0x0002, 0x0002,
+        0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x008E, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
         0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
-        0x0037, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+        0x0002, 0x0002, 0x0002, 0x008F, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
         0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
@@ -2268,41 +3821,3246 @@ static XID_START_TABLE: UCDTrie = UCDTrie {
         0x0000000000000000, 0x001FFFFFFFFFFFFF, 0xFFFFFFFF1FFFFFFF, 0x000000000001FFFF,
         0xFFFFE000FFFFFFFF, 0x003FFFFFFFFF07FF, 0xFFFFFFFF3FFFFFFF, 0x00000000003EFF0F,
         0xFFFF00003FFFFFFF, 0x0FFFFFFFFF0FFFFF, 0xFFFF00FFFFFFFFFF, 0xF7FF000FFFFFFFFF,
-        0x1BFBFFFBFFB7F7FF, 0x007FFFFFFFFFFFFF, 0x000000FF003FFFFF, 0x07FDFFFFFFFFFFBF,
-        0x91BFFFFFFFFFFD3F, 0x007FFFFF003FFFFF, 0x000000007FFFFFFF, 0x0037FFFF00000000,
-        0x03FFFFFF003FFFFF, 0xC0FFFFFFFFFFFFFF, 0x003FFFFFFEEF0001, 0x1FFFFFFF00000000,
-        0x000000001FFFFFFF, 0x0000001FFFFFFEFF, 0x003FFFFFFFFFFFFF, 0x0007FFFF003FFFFF,
-        0x000000000003FFFF, 0x00000000000001FF, 0x0007FFFFFFFFFFFF, 0x0000000FFFFFFFFF,
-        0x000303FFFFFFFFFF, 0xFFFF00801FFFFFFF, 0xFFFF00000000003F, 0xFFFF000000000003,
-        0x007FFFFF0000001F, 0x00FFFFFFFFFFFFF8, 0x0026000000000000, 0x0000FFFFFFFFFFF8,
-        0x000001FFFFFF0000, 0x0000007FFFFFFFF8, 0x0047FFFFFFFF0090, 0x0007FFFFFFFFFFF8,
-        0x000000001400001E, 0x80000FFFFFFBFFFF, 0x0000000000000001, 0xFFFF01FFBFFFBD7F,
-        0x23EDFDFFFFF99FE0, 0x00000003E0010000, 0x0000000380000780, 0x0000FFFFFFFFFFFF,
-        0x00000000000000B0, 0x00007FFFFFFFFFFF, 0x000000000F000000, 0x0000000000000010,
-        0x010007FFFFFFFFFF, 0x0000000007FFFFFF, 0x000000000000007F, 0x00000FFFFFFFFFFF,
-        0xFFFFFFFF00000000, 0x80000000FFFFFFFF, 0x8000FFFFFF6FF27F, 0x0000000000000002,
-        0xFFFFFCFF00000000, 0x0000000A0001FFFF, 0x0407FFFFFFFFF801, 0xFFFFFFFFF0010000,
-        0xFFFF0000200003FF, 0x01FFFFFFFFFFFFFF, 0x00007FFFFFFFFDFF, 0xFFFC000000000001,
-        0x000000000000FFFF, 0x0001FFFFFFFFFB7F, 0xFFFFFDBF00000040, 0x00000000010003FF,
-        0x0007FFFF00000000, 0x000FFFFFFFFDFFF4, 0x0001000000000000, 0x0000000003FFFFFF,
-        0x000000000000000F, 0xFFFFFFFFFFFF0000, 0x0001FFFFFFFFFFFF, 0x000000000000007E,
-        0xFFFF00007FFFFFFF, 0x7FFFFFFFFFFFFFFF, 0x00003FFFFFFF0000, 0xE0FFFFF80000000F,
-        0x00000000000107FF, 0x00000000FFF80000, 0x0000000B00000000, 0x00FFFFFFFFFFFFFF,
-        0x00000000003FFFFF, 0x6FEF000000000000, 0x00040007FFFFFFFF, 0xFFFF00F000270000,
-        0x0FFFFFFFFFFFFFFF, 0x1FFF07FFFFFFFFFF, 0x0000000003FF01FF, 0xFFFFFFFFFFDFFFFF,
-        0xEBFFDE64DFFFFFFF, 0xFFFFFFFFFFFFFFEF, 0x7BFFFFFFDFDFE7BF, 0xFFFFFFFFFFFDFC5F,
-        0xFFFFFF3FFFFFFFFF, 0xF7FFFFFFF7FFFFFD, 0xFFDFFFFFFFDFFFFF, 0xFFFF7FFFFFFF7FFF,
-        0xFFFFFDFFFFFFFDFF, 0x0000000000000FF7, 0x000007E07FFFFFFF, 0xFFFF000000000000,
-        0x00003FFFFFFFFFFF, 0x3F801FFFFFFFFFFF, 0x0000000000004000, 0x00000FFFFFFF0000,
-        0x7FFF6F7F00000000, 0x000000000000001F, 0x000000000000080F, 0x0AF7FE96FFFFFFEF,
-        0x5EF7F796AA96EA84, 0x0FFFFBEE0FFFFBFF, 0x00000000FFFFFFFF, 0x03FFFFFFFFFFFFFF,
-        0xFFFF0003FFFFFFFF, 0xFFFF0001FFFFFFFF, 0x000000003FFFFFFF, 0xFFFFFFFFFFFF07FF,
+        0x1BFBFFFBFFB7F7FF, 0x000FFFFFFFFFFFFF, 0x007FFFFFFFFFFFFF, 0x000000FF003FFFFF,
+        0x07FDFFFFFFFFFFBF, 0x91BFFFFFFFFFFD3F, 0x007FFFFF003FFFFF, 0x000000007FFFFFFF,
+        0x0037FFFF00000000, 0x03FFFFFF003FFFFF, 0x0000000003FFFFFF, 0xC0FFFFFFFFFFFFFF,
+        0x003FFFFFFEEF0001, 0x1FFFFFFF00000000, 0x000000001FFFFFFF, 0x0000001FFFFFFEFF,
+        0x003FFFFFFFFFFFFF, 0x0007FFFF003FFFFF, 0x000000000003FFFF, 0x00000000000001FF,
+        0x0007FFFFFFFFFFFF, 0x0000000FFFFFFFFF, 0xFFFF803FFFFFFC00, 0x000000000000003F,
+        0x000303FFFFFFFFFF, 0x00000000000000FC, 0xFFFF00801FFFFFFF, 0xFFFF00000000003F,
+        0xFFFF000000000003, 0x007FFFFF0000001F, 0x00FFFFFFFFFFFFF8, 0x0026000000000000,
+        0x0000FFFFFFFFFFF8, 0x000001FFFFFF0000, 0x0000007FFFFFFFF8, 0x0047FFFFFFFF0090,
+        0x0007FFFFFFFFFFF8, 0x000000001400001E, 0x80000FFFFFFBFFFF, 0x0000000000000001,
+        0xFFFF01FFBFFFBD7F, 0x23EDFDFFFFF99FE0, 0x00000003E0010000, 0x00BFFFFFFFFF4BFF,
+        0x00000000000A0000, 0x0000000380000780, 0x0000FFFFFFFFFFFF, 0x00000000000000B0,
+        0x00007FFFFFFFFFFF, 0x000000000F000000, 0x0000000000000010, 0x010007FFFFFFFFFF,
+        0x0000000007FFFFFF, 0x000000000000007F, 0x00000FFFFFFFFFFF, 0xFFFFFFFF00000000,
+        0x80000000FFFFFFFF, 0x8000FFFFFF6FF27F, 0x0000000000000002, 0xFFFFFCFF00000000,
+        0x0000000A0001FFFF, 0x0407FFFFFFFFF801, 0xFFFFFFFFF0010000, 0xFFFF0000200003FF,
+        0x01FFFFFFFFFFFFFF, 0x00000001FFFFFFFF, 0x00007FFFFFFFFDFF, 0xFFFC000000000001,
+        0x000000000000FFFF, 0x0001FFFFFFFFFB7F, 0xFFFFFDBF00000040, 0xFFFF0000010003FF,
+        0x000000000FFFFFFF, 0x0007FFFF00000000, 0x000FFFFFFFFDFFF4, 0x0001000000000000,
+        0x000000000000000F, 0xFFFFFFFFFFFF0000, 0x0001FFFFFFFFFFFF, 0xFFFFFFFF0000007E,
+        0x000000003FFFFFFF, 0xFFFF00007FFFFFFF, 0x7FFFFFFFFFFFFFFF, 0x00003FFFFFFF0000,
+        0xE0FFFFF80000000F, 0x00001FFFFFFFFFFF, 0xF9FFFFFF00000000, 0x00000000000FFFFF,
+        0x00000000000107FF, 0x00000000FFF80000, 0x007C000B00000000, 0x80000000003FFFFF,
+        0x6FEF000000000000, 0x00040007FFFFFFFF, 0xFFFF00F000270000, 0x0FFFFFFFFFFFFFFF,
+        0x1FFF07FFFFFFFFFF, 0x0000000003FF01FF, 0xFFFFFFFFFFDFFFFF, 0xEBFFDE64DFFFFFFF,
+        0xFFFFFFFFFFFFFFEF, 0x7BFFFFFFDFDFE7BF, 0xFFFFFFFFFFFDFC5F, 0xFFFFFF3FFFFFFFFF,
+        0xF7FFFFFFF7FFFFFD, 0xFFDFFFFFFFDFFFFF, 0xFFFF7FFFFFFF7FFF, 0xFFFFFDFFFFFFFDFF,
+        0x0000000000000FF7, 0x000007E07FFFFFFF, 0xFFFF000000000000, 0x00003FFFFFFFFFFF,
+        0x3F801FFFFFFFFFFF, 0x0000000000004000, 0x00000FFFFFFF0000, 0x00013FFFFFFF0000,
+        0xC01F3FB77FFFFFFF, 0x7FFF6F7F00000000, 0x000000000000001F, 0x000000000000080F,
+        0x0AF7FE96FFFFFFEF, 0x5EF7F796AA96EA84, 0x0FFFFBEE0FFFFBFF, 0x00000000FFFFFFFF,
+        0xFFFF3FFFFFFFFFFF, 0xFFFF0001FFFFFFFF, 0xFFFFFFFFFFFF07FF, 0x03FFFFFFFFFFFFFF,
+    ],
+};
+
+struct UCDCaseTable {
+    simple: &'static [(char, char)],
+    full: &'static [(char, [char; 3])],
+}
+
+impl UCDCaseTable {
+    #[inline(always)]
+    fn simple(&self, ch: char) -> char {
+        match self.simple.binary_search_by_key(&ch, |(key, _)| *key) {
+            Ok(index) => self.simple[index].1,
+            Err(_) => ch,
+        }
+    }
+
+    #[inline(always)]
+    fn full(&self, ch: char) -> CaseMapping {
+        match self.full.binary_search_by_key(&ch, |(key, _)| *key) {
+            Ok(index) => CaseMapping::new(self.full[index].1),
+            Err(_) => CaseMapping::new([self.simple(ch), '\0', '\0']),
+        }
+    }
+}
+
+#[rustfmt::skip]
+static LOWER_CASE_TABLE: UCDCaseTable = UCDCaseTable {
+    simple: &[
+        ('\u{0041}', '\u{0061}'), ('\u{0042}', '\u{0062}'), ('\u{0043}', '\u{0063}'),
+        ('\u{0044}', '\u{0064}'), ('\u{0045}', '\u{0065}'), ('\u{0046}', '\u{0066}'),
+        ('\u{0047}', '\u{0067}'), ('\u{0048}', '\u{0068}'), ('\u{0049}', '\u{0069}'),
+        ('\u{004A}', '\u{006A}'), ('\u{004B}', '\u{006B}'), ('\u{004C}', '\u{006C}'),
+        ('\u{004D}', '\u{006D}'), ('\u{004E}', '\u{006E}'), ('\u{004F}', '\u{006F}'),
+        ('\u{0050}', '\u{0070}'), ('\u{0051}', '\u{0071}'), ('\u{0052}', '\u{0072}'),
+        ('\u{0053}', '\u{0073}'), ('\u{0054}', '\u{0074}'), ('\u{0055}', '\u{0075}'),
+        ('\u{0056}', '\u{0076}'), ('\u{0057}', '\u{0077}'), ('\u{0058}', '\u{0078}'),
+        ('\u{0059}', '\u{0079}'), ('\u{005A}', '\u{007A}'), ('\u{00C0}', '\u{00E0}'),
+        ('\u{00C1}', '\u{00E1}'), ('\u{00C2}', '\u{00E2}'), ('\u{00C3}', '\u{00E3}'),
+        ('\u{00C4}', '\u{00E4}'), ('\u{00C5}', '\u{00E5}'), ('\u{00C6}', '\u{00E6}'),
+        ('\u{00C7}', '\u{00E7}'), ('\u{00C8}', '\u{00E8}'), ('\u{00C9}', '\u{00E9}'),
+        ('\u{00CA}', '\u{00EA}'), ('\u{00CB}', '\u{00EB}'), ('\u{00CC}', '\u{00EC}'),
+        ('\u{00CD}', '\u{00ED}'), ('\u{00CE}', '\u{00EE}'), ('\u{00CF}', '\u{00EF}'),
+        ('\u{00D0}', '\u{00F0}'), ('\u{00D1}', '\u{00F1}'), ('\u{00D2}', '\u{00F2}'),
+        ('\u{00D3}', '\u{00F3}'), ('\u{00D4}', '\u{00F4}'), ('\u{00D5}', '\u{00F5}'),
+        ('\u{00D6}', '\u{00F6}'), ('\u{00D8}', '\u{00F8}'), ('\u{00D9}', '\u{00F9}'),
+        ('\u{00DA}', '\u{00FA}'), ('\u{00DB}', '\u{00FB}'), ('\u{00DC}', '\u{00FC}'),
+        ('\u{00DD}', '\u{00FD}'), ('\u{00DE}', '\u{00FE}'), ('\u{0100}', '\u{0101}'),
+        ('\u{0102}', '\u{0103}'), ('\u{0104}', '\u{0105}'), ('\u{0106}', '\u{0107}'),
+        ('\u{0108}', '\u{0109}'), ('\u{010A}', '\u{010B}'), ('\u{010C}', '\u{010D}'),
+        ('\u{010E}', '\u{010F}'), ('\u{0110}', '\u{0111}'), ('\u{0112}', '\u{0113}'),
+        ('\u{0114}', '\u{0115}'), ('\u{0116}', '\u{0117}'), ('\u{0118}', '\u{0119}'),
+        ('\u{011A}', '\u{011B}'), ('\u{011C}', '\u{011D}'), ('\u{011E}', '\u{011F}'),
+        ('\u{0120}', '\u{0121}'), ('\u{0122}', '\u{0123}'), ('\u{0124}', '\u{0125}'),
+        ('\u{0126}', '\u{0127}'), ('\u{0128}', '\u{0129}'), ('\u{012A}', '\u{012B}'),
+        ('\u{012C}', '\u{012D}'), ('\u{012E}', '\u{012F}'), ('\u{0130}', '\u{0069}'),
+        ('\u{0132}', '\u{0133}'), ('\u{0134}', '\u{0135}'), ('\u{0136}', '\u{0137}'),
+        ('\u{0139}', '\u{013A}'), ('\u{013B}', '\u{013C}'), ('\u{013D}', '\u{013E}'),
+        ('\u{013F}', '\u{0140}'), ('\u{0141}', '\u{0142}'), ('\u{0143}', '\u{0144}'),
+        ('\u{0145}', '\u{0146}'), ('\u{0147}', '\u{0148}'), ('\u{014A}', '\u{014B}'),
+        ('\u{014C}', '\u{014D}'), ('\u{014E}', '\u{014F}'), ('\u{0150}', '\u{0151}'),
+        ('\u{0152}', '\u{0153}'), ('\u{0154}', '\u{0155}'), ('\u{0156}', '\u{0157}'),
+        ('\u{0158}', '\u{0159}'), ('\u{015A}', '\u{015B}'), ('\u{015C}', '\u{015D}'),
+        ('\u{015E}', '\u{015F}'), ('\u{0160}', '\u{0161}'), ('\u{0162}', '\u{0163}'),
+        ('\u{0164}', '\u{0165}'), ('\u{0166}', '\u{0167}'), ('\u{0168}', '\u{0169}'),
+        ('\u{016A}', '\u{016B}'), ('\u{016C}', '\u{016D}'), ('\u{016E}', '\u{016F}'),
+        ('\u{0170}', '\u{0171}'), ('\u{0172}', '\u{0173}'), ('\u{0174}', '\u{0175}'),
+        ('\u{0176}', '\u{0177}'), ('\u{0178}', '\u{00FF}'), ('\u{0179}', '\u{017A}'),
+        ('\u{017B}', '\u{017C}'), ('\u{017D}', '\u{017E}'), ('\u{0181}', '\u{0253}'),
+        ('\u{0182}', '\u{0183}'), ('\u{0184}', '\u{0185}'), ('\u{0186}', '\u{0254}'),
+        ('\u{0187}', '\u{0188}'), ('\u{0189}', '\u{0256}'), ('\u{018A}', '\u{0257}'),
+        ('\u{018B}', '\u{018C}'), ('\u{018E}', '\u{01DD}'), ('\u{018F}', '\u{0259}'),
+        ('\u{0190}', '\u{025B}'), ('\u{0191}', '\u{0192}'), ('\u{0193}', '\u{0260}'),
+        ('\u{0194}', '\u{0263}'), ('\u{0196}', '\u{0269}'), ('\u{0197}', '\u{0268}'),
+        ('\u{0198}', '\u{0199}'), ('\u{019C}', '\u{026F}'), ('\u{019D}', '\u{0272}'),
+        ('\u{019F}', '\u{0275}'), ('\u{01A0}', '\u{01A1}'), ('\u{01A2}', '\u{01A3}'),
+        ('\u{01A4}', '\u{01A5}'), ('\u{01A6}', '\u{0280}'), ('\u{01A7}', '\u{01A8}'),
+        ('\u{01A9}', '\u{0283}'), ('\u{01AC}', '\u{01AD}'), ('\u{01AE}', '\u{0288}'),
+        ('\u{01AF}', '\u{01B0}'), ('\u{01B1}', '\u{028A}'), ('\u{01B2}', '\u{028B}'),
+        ('\u{01B3}', '\u{01B4}'), ('\u{01B5}', '\u{01B6}'), ('\u{01B7}', '\u{0292}'),
+        ('\u{01B8}', '\u{01B9}'), ('\u{01BC}', '\u{01BD}'), ('\u{01C4}', '\u{01C6}'),
+        ('\u{01C5}', '\u{01C6}'), ('\u{01C7}', '\u{01C9}'), ('\u{01C8}', '\u{01C9}'),
+        ('\u{01CA}', '\u{01CC}'), ('\u{01CB}', '\u{01CC}'), ('\u{01CD}', '\u{01CE}'),
+        ('\u{01CF}', '\u{01D0}'), ('\u{01D1}', '\u{01D2}'), ('\u{01D3}', '\u{01D4}'),
+        ('\u{01D5}', '\u{01D6}'), ('\u{01D7}', '\u{01D8}'), ('\u{01D9}', '\u{01DA}'),
+        ('\u{01DB}', '\u{01DC}'), ('\u{01DE}', '\u{01DF}'), ('\u{01E0}', '\u{01E1}'),
+        ('\u{01E2}', '\u{01E3}'), ('\u{01E4}', '\u{01E5}'), ('\u{01E6}', '\u{01E7}'),
+        ('\u{01E8}', '\u{01E9}'), ('\u{01EA}', '\u{01EB}'), ('\u{01EC}', '\u{01ED}'),
+        ('\u{01EE}', '\u{01EF}'), ('\u{01F1}', '\u{01F3}'), ('\u{01F2}', '\u{01F3}'),
+        ('\u{01F4}', '\u{01F5}'), ('\u{01F6}', '\u{0195}'), ('\u{01F7}', '\u{01BF}'),
+        ('\u{01F8}', '\u{01F9}'), ('\u{01FA}', '\u{01FB}'), ('\u{01FC}', '\u{01FD}'),
+        ('\u{01FE}', '\u{01FF}'), ('\u{0200}', '\u{0201}'), ('\u{0202}', '\u{0203}'),
+        ('\u{0204}', '\u{0205}'), ('\u{0206}', '\u{0207}'), ('\u{0208}', '\u{0209}'),
+        ('\u{020A}', '\u{020B}'), ('\u{020C}', '\u{020D}'), ('\u{020E}', '\u{020F}'),
+        ('\u{0210}', '\u{0211}'), ('\u{0212}', '\u{0213}'), ('\u{0214}', '\u{0215}'),
+        ('\u{0216}', '\u{0217}'), ('\u{0218}', '\u{0219}'), ('\u{021A}', '\u{021B}'),
+        ('\u{021C}', '\u{021D}'), ('\u{021E}', '\u{021F}'), ('\u{0220}', '\u{019E}'),
+        ('\u{0222}', '\u{0223}'), ('\u{0224}', '\u{0225}'), ('\u{0226}', '\u{0227}'),
+        ('\u{0228}', '\u{0229}'), ('\u{022A}', '\u{022B}'), ('\u{022C}', '\u{022D}'),
+        ('\u{022E}', '\u{022F}'), ('\u{0230}', '\u{0231}'), ('\u{0232}', '\u{0233}'),
+        ('\u{023A}', '\u{2C65}'), ('\u{023B}', '\u{023C}'), ('\u{023D}', '\u{019A}'),
+        ('\u{023E}', '\u{2C66}'), ('\u{0241}', '\u{0242}'), ('\u{0243}', '\u{0180}'),
+        ('\u{0244}', '\u{0289}'), ('\u{0245}', '\u{028C}'), ('\u{0246}', '\u{0247}'),
+        ('\u{0248}', '\u{0249}'), ('\u{024A}', '\u{024B}'), ('\u{024C}', '\u{024D}'),
+        ('\u{024E}', '\u{024F}'), ('\u{0370}', '\u{0371}'), ('\u{0372}', '\u{0373}'),
+        ('\u{0376}', '\u{0377}'), ('\u{037F}', '\u{03F3}'), ('\u{0386}', '\u{03AC}'),
+        ('\u{0388}', '\u{03AD}'), ('\u{0389}', '\u{03AE}'), ('\u{038A}', '\u{03AF}'),
+        ('\u{038C}', '\u{03CC}'), ('\u{038E}', '\u{03CD}'), ('\u{038F}', '\u{03CE}'),
+        ('\u{0391}', '\u{03B1}'), ('\u{0392}', '\u{03B2}'), ('\u{0393}', '\u{03B3}'),
+        ('\u{0394}', '\u{03B4}'), ('\u{0395}', '\u{03B5}'), ('\u{0396}', '\u{03B6}'),
+        ('\u{0397}', '\u{03B7}'), ('\u{0398}', '\u{03B8}'), ('\u{0399}', '\u{03B9}'),
+        ('\u{039A}', '\u{03BA}'), ('\u{039B}', '\u{03BB}'), ('\u{039C}', '\u{03BC}'),
+        ('\u{039D}', '\u{03BD}'), ('\u{039E}', '\u{03BE}'), ('\u{039F}', '\u{03BF}'),
+        ('\u{03A0}', '\u{03C0}'), ('\u{03A1}', '\u{03C1}'), ('\u{03A3}', '\u{03C3}'),
+        ('\u{03A4}', '\u{03C4}'), ('\u{03A5}', '\u{03C5}'), ('\u{03A6}', '\u{03C6}'),
+        ('\u{03A7}', '\u{03C7}'), ('\u{03A8}', '\u{03C8}'), ('\u{03A9}', '\u{03C9}'),
+        ('\u{03AA}', '\u{03CA}'), ('\u{03AB}', '\u{03CB}'), ('\u{03CF}', '\u{03D7}'),
+        ('\u{03D8}', '\u{03D9}'), ('\u{03DA}', '\u{03DB}'), ('\u{03DC}', '\u{03DD}'),
+        ('\u{03DE}', '\u{03DF}'), ('\u{03E0}', '\u{03E1}'), ('\u{03E2}', '\u{03E3}'),
+        ('\u{03E4}', '\u{03E5}'), ('\u{03E6}', '\u{03E7}'), ('\u{03E8}', '\u{03E9}'),
+        ('\u{03EA}', '\u{03EB}'), ('\u{03EC}', '\u{03ED}'), ('\u{03EE}', '\u{03EF}'),
+        ('\u{03F4}', '\u{03B8}'), ('\u{03F7}', '\u{03F8}'), ('\u{03F9}', '\u{03F2}'),
+        ('\u{03FA}', '\u{03FB}'), ('\u{03FD}', '\u{037B}'), ('\u{03FE}', '\u{037C}'),
+        ('\u{03FF}', '\u{037D}'), ('\u{0400}', '\u{0450}'), ('\u{0401}', '\u{0451}'),
+        ('\u{0402}', '\u{0452}'), ('\u{0403}', '\u{0453}'), ('\u{0404}', '\u{0454}'),
+        ('\u{0405}', '\u{0455}'), ('\u{0406}', '\u{0456}'), ('\u{0407}', '\u{0457}'),
+        ('\u{0408}', '\u{0458}'), ('\u{0409}', '\u{0459}'), ('\u{040A}', '\u{045A}'),
+        ('\u{040B}', '\u{045B}'), ('\u{040C}', '\u{045C}'), ('\u{040D}', '\u{045D}'),
+        ('\u{040E}', '\u{045E}'), ('\u{040F}', '\u{045F}'), ('\u{0410}', '\u{0430}'),
+        ('\u{0411}', '\u{0431}'), ('\u{0412}', '\u{0432}'), ('\u{0413}', '\u{0433}'),
+        ('\u{0414}', '\u{0434}'), ('\u{0415}', '\u{0435}'), ('\u{0416}', '\u{0436}'),
+        ('\u{0417}', '\u{0437}'), ('\u{0418}', '\u{0438}'), ('\u{0419}', '\u{0439}'),
+        ('\u{041A}', '\u{043A}'), ('\u{041B}', '\u{043B}'), ('\u{041C}', '\u{043C}'),
+        ('\u{041D}', '\u{043D}'), ('\u{041E}', '\u{043E}'), ('\u{041F}', '\u{043F}'),
+        ('\u{0420}', '\u{0440}'), ('\u{0421}', '\u{0441}'), ('\u{0422}', '\u{0442}'),
+        ('\u{0423}', '\u{0443}'), ('\u{0424}', '\u{0444}'), ('\u{0425}', '\u{0445}'),
+        ('\u{0426}', '\u{0446}'), ('\u{0427}', '\u{0447}'), ('\u{0428}', '\u{0448}'),
+        ('\u{0429}', '\u{0449}'), ('\u{042A}', '\u{044A}'), ('\u{042B}', '\u{044B}'),
+        ('\u{042C}', '\u{044C}'), ('\u{042D}', '\u{044D}'), ('\u{042E}', '\u{044E}'),
+        ('\u{042F}', '\u{044F}'), ('\u{0460}', '\u{0461}'), ('\u{0462}', '\u{0463}'),
+        ('\u{0464}', '\u{0465}'), ('\u{0466}', '\u{0467}'), ('\u{0468}', '\u{0469}'),
+        ('\u{046A}', '\u{046B}'), ('\u{046C}', '\u{046D}'), ('\u{046E}', '\u{046F}'),
+        ('\u{0470}', '\u{0471}'), ('\u{0472}', '\u{0473}'), ('\u{0474}', '\u{0475}'),
+        ('\u{0476}', '\u{0477}'), ('\u{0478}', '\u{0479}'), ('\u{047A}', '\u{047B}'),
+        ('\u{047C}', '\u{047D}'), ('\u{047E}', '\u{047F}'), ('\u{0480}', '\u{0481}'),
+        ('\u{048A}', '\u{048B}'), ('\u{048C}', '\u{048D}'), ('\u{048E}', '\u{048F}'),
+        ('\u{0490}', '\u{0491}'), ('\u{0492}', '\u{0493}'), ('\u{0494}', '\u{0495}'),
+        ('\u{0496}', '\u{0497}'), ('\u{0498}', '\u{0499}'), ('\u{049A}', '\u{049B}'),
+        ('\u{049C}', '\u{049D}'), ('\u{049E}', '\u{049F}'), ('\u{04A0}', '\u{04A1}'),
+        ('\u{04A2}', '\u{04A3}'), ('\u{04A4}', '\u{04A5}'), ('\u{04A6}', '\u{04A7}'),
+        ('\u{04A8}', '\u{04A9}'), ('\u{04AA}', '\u{04AB}'), ('\u{04AC}', '\u{04AD}'),
+        ('\u{04AE}', '\u{04AF}'), ('\u{04B0}', '\u{04B1}'), ('\u{04B2}', '\u{04B3}'),
+        ('\u{04B4}', '\u{04B5}'), ('\u{04B6}', '\u{04B7}'), ('\u{04B8}', '\u{04B9}'),
+        ('\u{04BA}', '\u{04BB}'), ('\u{04BC}', '\u{04BD}'), ('\u{04BE}', '\u{04BF}'),
+        ('\u{04C0}', '\u{04CF}'), ('\u{04C1}', '\u{04C2}'), ('\u{04C3}', '\u{04C4}'),
+        ('\u{04C5}', '\u{04C6}'), ('\u{04C7}', '\u{04C8}'), ('\u{04C9}', '\u{04CA}'),
+        ('\u{04CB}', '\u{04CC}'), ('\u{04CD}', '\u{04CE}'), ('\u{04D0}', '\u{04D1}'),
+        ('\u{04D2}', '\u{04D3}'), ('\u{04D4}', '\u{04D5}'), ('\u{04D6}', '\u{04D7}'),
+        ('\u{04D8}', '\u{04D9}'), ('\u{04DA}', '\u{04DB}'), ('\u{04DC}', '\u{04DD}'),
+        ('\u{04DE}', '\u{04DF}'), ('\u{04E0}', '\u{04E1}'), ('\u{04E2}', '\u{04E3}'),
+        ('\u{04E4}', '\u{04E5}'), ('\u{04E6}', '\u{04E7}'), ('\u{04E8}', '\u{04E9}'),
+        ('\u{04EA}', '\u{04EB}'), ('\u{04EC}', '\u{04ED}'), ('\u{04EE}', '\u{04EF}'),
+        ('\u{04F0}', '\u{04F1}'), ('\u{04F2}', '\u{04F3}'), ('\u{04F4}', '\u{04F5}'),
+        ('\u{04F6}', '\u{04F7}'), ('\u{04F8}', '\u{04F9}'), ('\u{04FA}', '\u{04FB}'),
+        ('\u{04FC}', '\u{04FD}'), ('\u{04FE}', '\u{04FF}'), ('\u{0500}', '\u{0501}'),
+        ('\u{0502}', '\u{0503}'), ('\u{0504}', '\u{0505}'), ('\u{0506}', '\u{0507}'),
+        ('\u{0508}', '\u{0509}'), ('\u{050A}', '\u{050B}'), ('\u{050C}', '\u{050D}'),
+        ('\u{050E}', '\u{050F}'), ('\u{0510}', '\u{0511}'), ('\u{0512}', '\u{0513}'),
+        ('\u{0514}', '\u{0515}'), ('\u{0516}', '\u{0517}'), ('\u{0518}', '\u{0519}'),
+        ('\u{051A}', '\u{051B}'), ('\u{051C}', '\u{051D}'), ('\u{051E}', '\u{051F}'),
+        ('\u{0520}', '\u{0521}'), ('\u{0522}', '\u{0523}'), ('\u{0524}', '\u{0525}'),
+        ('\u{0526}', '\u{0527}'), ('\u{0528}', '\u{0529}'), ('\u{052A}', '\u{052B}'),
+        ('\u{052C}', '\u{052D}'), ('\u{052E}', '\u{052F}'), ('\u{0531}', '\u{0561}'),
+        ('\u{0532}', '\u{0562}'), ('\u{0533}', '\u{0563}'), ('\u{0534}', '\u{0564}'),
+        ('\u{0535}', '\u{0565}'), ('\u{0536}', '\u{0566}'), ('\u{0537}', '\u{0567}'),
+        ('\u{0538}', '\u{0568}'), ('\u{0539}', '\u{0569}'), ('\u{053A}', '\u{056A}'),
+        ('\u{053B}', '\u{056B}'), ('\u{053C}', '\u{056C}'), ('\u{053D}', '\u{056D}'),
+        ('\u{053E}', '\u{056E}'), ('\u{053F}', '\u{056F}'), ('\u{0540}', '\u{0570}'),
+        ('\u{0541}', '\u{0571}'), ('\u{0542}', '\u{0572}'), ('\u{0543}', '\u{0573}'),
+        ('\u{0544}', '\u{0574}'), ('\u{0545}', '\u{0575}'), ('\u{0546}', '\u{0576}'),
+        ('\u{0547}', '\u{0577}'), ('\u{0548}', '\u{0578}'), ('\u{0549}', '\u{0579}'),
+        ('\u{054A}', '\u{057A}'), ('\u{054B}', '\u{057B}'), ('\u{054C}', '\u{057C}'),
+        ('\u{054D}', '\u{057D}'), ('\u{054E}', '\u{057E}'), ('\u{054F}', '\u{057F}'),
+        ('\u{0550}', '\u{0580}'), ('\u{0551}', '\u{0581}'), ('\u{0552}', '\u{0582}'),
+        ('\u{0553}', '\u{0583}'), ('\u{0554}', '\u{0584}'), ('\u{0555}', '\u{0585}'),
+        ('\u{0556}', '\u{0586}'), ('\u{10A0}', '\u{2D00}'), ('\u{10A1}', '\u{2D01}'),
+        ('\u{10A2}', '\u{2D02}'), ('\u{10A3}', '\u{2D03}'), ('\u{10A4}', '\u{2D04}'),
+        ('\u{10A5}', '\u{2D05}'), ('\u{10A6}', '\u{2D06}'), ('\u{10A7}', '\u{2D07}'),
+        ('\u{10A8}', '\u{2D08}'), ('\u{10A9}', '\u{2D09}'), ('\u{10AA}', '\u{2D0A}'),
+        ('\u{10AB}', '\u{2D0B}'), ('\u{10AC}', '\u{2D0C}'), ('\u{10AD}', '\u{2D0D}'),
+        ('\u{10AE}', '\u{2D0E}'), ('\u{10AF}', '\u{2D0F}'), ('\u{10B0}', '\u{2D10}'),
+        ('\u{10B1}', '\u{2D11}'), ('\u{10B2}', '\u{2D12}'), ('\u{10B3}', '\u{2D13}'),
+        ('\u{10B4}', '\u{2D14}'), ('\u{10B5}', '\u{2D15}'), ('\u{10B6}', '\u{2D16}'),
+        ('\u{10B7}', '\u{2D17}'), ('\u{10B8}', '\u{2D18}'), ('\u{10B9}', '\u{2D19}'),
+        ('\u{10BA}', '\u{2D1A}'), ('\u{10BB}', '\u{2D1B}'), ('\u{10BC}', '\u{2D1C}'),
+        ('\u{10BD}', '\u{2D1D}'), ('\u{10BE}', '\u{2D1E}'), ('\u{10BF}', '\u{2D1F}'),
+        ('\u{10C0}', '\u{2D20}'), ('\u{10C1}', '\u{2D21}'), ('\u{10C2}', '\u{2D22}'),
+        ('\u{10C3}', '\u{2D23}'), ('\u{10C4}', '\u{2D24}'), ('\u{10C5}', '\u{2D25}'),
+        ('\u{10C7}', '\u{2D27}'), ('\u{10CD}', '\u{2D2D}'), ('\u{13A0}', '\u{AB70}'),
+        ('\u{13A1}', '\u{AB71}'), ('\u{13A2}', '\u{AB72}'), ('\u{13A3}', '\u{AB73}'),
+        ('\u{13A4}', '\u{AB74}'), ('\u{13A5}', '\u{AB75}'), ('\u{13A6}', '\u{AB76}'),
+        ('\u{13A7}', '\u{AB77}'), ('\u{13A8}', '\u{AB78}'), ('\u{13A9}', '\u{AB79}'),
+        ('\u{13AA}', '\u{AB7A}'), ('\u{13AB}', '\u{AB7B}'), ('\u{13AC}', '\u{AB7C}'),
+        ('\u{13AD}', '\u{AB7D}'), ('\u{13AE}', '\u{AB7E}'), ('\u{13AF}', '\u{AB7F}'),
+        ('\u{13B0}', '\u{AB80}'), ('\u{13B1}', '\u{AB81}'), ('\u{13B2}', '\u{AB82}'),
+        ('\u{13B3}', '\u{AB83}'), ('\u{13B4}', '\u{AB84}'), ('\u{13B5}', '\u{AB85}'),
+        ('\u{13B6}', '\u{AB86}'), ('\u{13B7}', '\u{AB87}'), ('\u{13B8}', '\u{AB88}'),
+        ('\u{13B9}', '\u{AB89}'), ('\u{13BA}', '\u{AB8A}'), ('\u{13BB}', '\u{AB8B}'),
+        ('\u{13BC}', '\u{AB8C}'), ('\u{13BD}', '\u{AB8D}'), ('\u{13BE}', '\u{AB8E}'),
+        ('\u{13BF}', '\u{AB8F}'), ('\u{13C0}', '\u{AB90}'), ('\u{13C1}', '\u{AB91}'),
+        ('\u{13C2}', '\u{AB92}'), ('\u{13C3}', '\u{AB93}'), ('\u{13C4}', '\u{AB94}'),
+        ('\u{13C5}', '\u{AB95}'), ('\u{13C6}', '\u{AB96}'), ('\u{13C7}', '\u{AB97}'),
+        ('\u{13C8}', '\u{AB98}'), ('\u{13C9}', '\u{AB99}'), ('\u{13CA}', '\u{AB9A}'),
+        ('\u{13CB}', '\u{AB9B}'), ('\u{13CC}', '\u{AB9C}'), ('\u{13CD}', '\u{AB9D}'),
+        ('\u{13CE}', '\u{AB9E}'), ('\u{13CF}', '\u{AB9F}'), ('\u{13D0}', '\u{ABA0}'),
+        ('\u{13D1}', '\u{ABA1}'), ('\u{13D2}', '\u{ABA2}'), ('\u{13D3}', '\u{ABA3}'),
+        ('\u{13D4}', '\u{ABA4}'), ('\u{13D5}', '\u{ABA5}'), ('\u{13D6}', '\u{ABA6}'),
+        ('\u{13D7}', '\u{ABA7}'), ('\u{13D8}', '\u{ABA8}'), ('\u{13D9}', '\u{ABA9}'),
+        ('\u{13DA}', '\u{ABAA}'), ('\u{13DB}', '\u{ABAB}'), ('\u{13DC}', '\u{ABAC}'),
+        ('\u{13DD}', '\u{ABAD}'), ('\u{13DE}', '\u{ABAE}'), ('\u{13DF}', '\u{ABAF}'),
+        ('\u{13E0}', '\u{ABB0}'), ('\u{13E1}', '\u{ABB1}'), ('\u{13E2}', '\u{ABB2}'),
+        ('\u{13E3}', '\u{ABB3}'), ('\u{13E4}', '\u{ABB4}'), ('\u{13E5}', '\u{ABB5}'),
+        ('\u{13E6}', '\u{ABB6}'), ('\u{13E7}', '\u{ABB7}'), ('\u{13E8}', '\u{ABB8}'),
+        ('\u{13E9}', '\u{ABB9}'), ('\u{13EA}', '\u{ABBA}'), ('\u{13EB}', '\u{ABBB}'),
+        ('\u{13EC}', '\u{ABBC}'), ('\u{13ED}', '\u{ABBD}'), ('\u{13EE}', '\u{ABBE}'),
+        ('\u{13EF}', '\u{ABBF}'), ('\u{13F0}', '\u{13F8}'), ('\u{13F1}', '\u{13F9}'),
+        ('\u{13F2}', '\u{13FA}'), ('\u{13F3}', '\u{13FB}'), ('\u{13F4}', '\u{13FC}'),
+        ('\u{13F5}', '\u{13FD}'), ('\u{1C89}', '\u{1C8A}'), ('\u{1C90}', '\u{10D0}'),
+        ('\u{1C91}', '\u{10D1}'), ('\u{1C92}', '\u{10D2}'), ('\u{1C93}', '\u{10D3}'),
+        ('\u{1C94}', '\u{10D4}'), ('\u{1C95}', '\u{10D5}'), ('\u{1C96}', '\u{10D6}'),
+        ('\u{1C97}', '\u{10D7}'), ('\u{1C98}', '\u{10D8}'), ('\u{1C99}', '\u{10D9}'),
+        ('\u{1C9A}', '\u{10DA}'), ('\u{1C9B}', '\u{10DB}'), ('\u{1C9C}', '\u{10DC}'),
+        ('\u{1C9D}', '\u{10DD}'), ('\u{1C9E}', '\u{10DE}'), ('\u{1C9F}', '\u{10DF}'),
+        ('\u{1CA0}', '\u{10E0}'), ('\u{1CA1}', '\u{10E1}'), ('\u{1CA2}', '\u{10E2}'),
+        ('\u{1CA3}', '\u{10E3}'), ('\u{1CA4}', '\u{10E4}'), ('\u{1CA5}', '\u{10E5}'),
+        ('\u{1CA6}', '\u{10E6}'), ('\u{1CA7}', '\u{10E7}'), ('\u{1CA8}', '\u{10E8}'),
+        ('\u{1CA9}', '\u{10E9}'), ('\u{1CAA}', '\u{10EA}'), ('\u{1CAB}', '\u{10EB}'),
+        ('\u{1CAC}', '\u{10EC}'), ('\u{1CAD}', '\u{10ED}'), ('\u{1CAE}', '\u{10EE}'),
+        ('\u{1CAF}', '\u{10EF}'), ('\u{1CB0}', '\u{10F0}'), ('\u{1CB1}', '\u{10F1}'),
+        ('\u{1CB2}', '\u{10F2}'), ('\u{1CB3}', '\u{10F3}'), ('\u{1CB4}', '\u{10F4}'),
+        ('\u{1CB5}', '\u{10F5}'), ('\u{1CB6}', '\u{10F6}'), ('\u{1CB7}', '\u{10F7}'),
+        ('\u{1CB8}', '\u{10F8}'), ('\u{1CB9}', '\u{10F9}'), ('\u{1CBA}', '\u{10FA}'),
+        ('\u{1CBD}', '\u{10FD}'), ('\u{1CBE}', '\u{10FE}'), ('\u{1CBF}', '\u{10FF}'),
+        ('\u{1E00}', '\u{1E01}'), ('\u{1E02}', '\u{1E03}'), ('\u{1E04}', '\u{1E05}'),
+        ('\u{1E06}', '\u{1E07}'), ('\u{1E08}', '\u{1E09}'), ('\u{1E0A}', '\u{1E0B}'),
+        ('\u{1E0C}', '\u{1E0D}'), ('\u{1E0E}', '\u{1E0F}'), ('\u{1E10}', '\u{1E11}'),
+        ('\u{1E12}', '\u{1E13}'), ('\u{1E14}', '\u{1E15}'), ('\u{1E16}', '\u{1E17}'),
+        ('\u{1E18}', '\u{1E19}'), ('\u{1E1A}', '\u{1E1B}'), ('\u{1E1C}', '\u{1E1D}'),
+        ('\u{1E1E}', '\u{1E1F}'), ('\u{1E20}', '\u{1E21}'), ('\u{1E22}', '\u{1E23}'),
+        ('\u{1E24}', '\u{1E25}'), ('\u{1E26}', '\u{1E27}'), ('\u{1E28}', '\u{1E29}'),
+        ('\u{1E2A}', '\u{1E2B}'), ('\u{1E2C}', '\u{1E2D}'), ('\u{1E2E}', '\u{1E2F}'),
+        ('\u{1E30}', '\u{1E31}'), ('\u{1E32}', '\u{1E33}'), ('\u{1E34}', '\u{1E35}'),
+        ('\u{1E36}', '\u{1E37}'), ('\u{1E38}', '\u{1E39}'), ('\u{1E3A}', '\u{1E3B}'),
+        ('\u{1E3C}', '\u{1E3D}'), ('\u{1E3E}', '\u{1E3F}'), ('\u{1E40}', '\u{1E41}'),
+        ('\u{1E42}', '\u{1E43}'), ('\u{1E44}', '\u{1E45}'), ('\u{1E46}', '\u{1E47}'),
+        ('\u{1E48}', '\u{1E49}'), ('\u{1E4A}', '\u{1E4B}'), ('\u{1E4C}', '\u{1E4D}'),
+        ('\u{1E4E}', '\u{1E4F}'), ('\u{1E50}', '\u{1E51}'), ('\u{1E52}', '\u{1E53}'),
+        ('\u{1E54}', '\u{1E55}'), ('\u{1E56}', '\u{1E57}'), ('\u{1E58}', '\u{1E59}'),
+        ('\u{1E5A}', '\u{1E5B}'), ('\u{1E5C}', '\u{1E5D}'), ('\u{1E5E}', '\u{1E5F}'),
+        ('\u{1E60}', '\u{1E61}'), ('\u{1E62}', '\u{1E63}'), ('\u{1E64}', '\u{1E65}'),
+        ('\u{1E66}', '\u{1E67}'), ('\u{1E68}', '\u{1E69}'), ('\u{1E6A}', '\u{1E6B}'),
+        ('\u{1E6C}', '\u{1E6D}'), ('\u{1E6E}', '\u{1E6F}'), ('\u{1E70}', '\u{1E71}'),
+        ('\u{1E72}', '\u{1E73}'), ('\u{1E74}', '\u{1E75}'), ('\u{1E76}', '\u{1E77}'),
+        ('\u{1E78}', '\u{1E79}'), ('\u{1E7A}', '\u{1E7B}'), ('\u{1E7C}', '\u{1E7D}'),
+        ('\u{1E7E}', '\u{1E7F}'), ('\u{1E80}', '\u{1E81}'), ('\u{1E82}', '\u{1E83}'),
+        ('\u{1E84}', '\u{1E85}'), ('\u{1E86}', '\u{1E87}'), ('\u{1E88}', '\u{1E89}'),
+        ('\u{1E8A}', '\u{1E8B}'), ('\u{1E8C}', '\u{1E8D}'), ('\u{1E8E}', '\u{1E8F}'),
+        ('\u{1E90}', '\u{1E91}'), ('\u{1E92}', '\u{1E93}'), ('\u{1E94}', '\u{1E95}'),
+        ('\u{1E9E}', '\u{00DF}'), ('\u{1EA0}', '\u{1EA1}'), ('\u{1EA2}', '\u{1EA3}'),
+        ('\u{1EA4}', '\u{1EA5}'), ('\u{1EA6}', '\u{1EA7}'), ('\u{1EA8}', '\u{1EA9}'),
+        ('\u{1EAA}', '\u{1EAB}'), ('\u{1EAC}', '\u{1EAD}'), ('\u{1EAE}', '\u{1EAF}'),
+        ('\u{1EB0}', '\u{1EB1}'), ('\u{1EB2}', '\u{1EB3}'), ('\u{1EB4}', '\u{1EB5}'),
+        ('\u{1EB6}', '\u{1EB7}'), ('\u{1EB8}', '\u{1EB9}'), ('\u{1EBA}', '\u{1EBB}'),
+        ('\u{1EBC}', '\u{1EBD}'), ('\u{1EBE}', '\u{1EBF}'), ('\u{1EC0}', '\u{1EC1}'),
+        ('\u{1EC2}', '\u{1EC3}'), ('\u{1EC4}', '\u{1EC5}'), ('\u{1EC6}', '\u{1EC7}'),
+        ('\u{1EC8}', '\u{1EC9}'), ('\u{1ECA}', '\u{1ECB}'), ('\u{1ECC}', '\u{1ECD}'),
+        ('\u{1ECE}', '\u{1ECF}'), ('\u{1ED0}', '\u{1ED1}'), ('\u{1ED2}', '\u{1ED3}'),
+        ('\u{1ED4}', '\u{1ED5}'), ('\u{1ED6}', '\u{1ED7}'), ('\u{1ED8}', '\u{1ED9}'),
+        ('\u{1EDA}', '\u{1EDB}'), ('\u{1EDC}', '\u{1EDD}'), ('\u{1EDE}', '\u{1EDF}'),
+        ('\u{1EE0}', '\u{1EE1}'), ('\u{1EE2}', '\u{1EE3}'), ('\u{1EE4}', '\u{1EE5}'),
+        ('\u{1EE6}', '\u{1EE7}'), ('\u{1EE8}', '\u{1EE9}'), ('\u{1EEA}', '\u{1EEB}'),
+        ('\u{1EEC}', '\u{1EED}'), ('\u{1EEE}', '\u{1EEF}'), ('\u{1EF0}', '\u{1EF1}'),
+        ('\u{1EF2}', '\u{1EF3}'), ('\u{1EF4}', '\u{1EF5}'), ('\u{1EF6}', '\u{1EF7}'),
+        ('\u{1EF8}', '\u{1EF9}'), ('\u{1EFA}', '\u{1EFB}'), ('\u{1EFC}', '\u{1EFD}'),
+        ('\u{1EFE}', '\u{1EFF}'), ('\u{1F08}', '\u{1F00}'), ('\u{1F09}', '\u{1F01}'),
+        ('\u{1F0A}', '\u{1F02}'), ('\u{1F0B}', '\u{1F03}'), ('\u{1F0C}', '\u{1F04}'),
+        ('\u{1F0D}', '\u{1F05}'), ('\u{1F0E}', '\u{1F06}'), ('\u{1F0F}', '\u{1F07}'),
+        ('\u{1F18}', '\u{1F10}'), ('\u{1F19}', '\u{1F11}'), ('\u{1F1A}', '\u{1F12}'),
+        ('\u{1F1B}', '\u{1F13}'), ('\u{1F1C}', '\u{1F14}'), ('\u{1F1D}', '\u{1F15}'),
+        ('\u{1F28}', '\u{1F20}'), ('\u{1F29}', '\u{1F21}'), ('\u{1F2A}', '\u{1F22}'),
+        ('\u{1F2B}', '\u{1F23}'), ('\u{1F2C}', '\u{1F24}'), ('\u{1F2D}', '\u{1F25}'),
+        ('\u{1F2E}', '\u{1F26}'), ('\u{1F2F}', '\u{1F27}'), ('\u{1F38}', '\u{1F30}'),
+        ('\u{1F39}', '\u{1F31}'), ('\u{1F3A}', '\u{1F32}'), ('\u{1F3B}', '\u{1F33}'),
+        ('\u{1F3C}', '\u{1F34}'), ('\u{1F3D}', '\u{1F35}'), ('\u{1F3E}', '\u{1F36}'),
+        ('\u{1F3F}', '\u{1F37}'), ('\u{1F48}', '\u{1F40}'), ('\u{1F49}', '\u{1F41}'),
+        ('\u{1F4A}', '\u{1F42}'), ('\u{1F4B}', '\u{1F43}'), ('\u{1F4C}', '\u{1F44}'),
+        ('\u{1F4D}', '\u{1F45}'), ('\u{1F59}', '\u{1F51}'), ('\u{1F5B}', '\u{1F53}'),
+        ('\u{1F5D}', '\u{1F55}'), ('\u{1F5F}', '\u{1F57}'), ('\u{1F68}', '\u{1F60}'),
+        ('\u{1F69}', '\u{1F61}'), ('\u{1F6A}', '\u{1F62}'), ('\u{1F6B}', '\u{1F63}'),
+        ('\u{1F6C}', '\u{1F64}'), ('\u{1F6D}', '\u{1F65}'), ('\u{1F6E}', '\u{1F66}'),
+        ('\u{1F6F}', '\u{1F67}'), ('\u{1F88}', '\u{1F80}'), ('\u{1F89}', '\u{1F81}'),
+        ('\u{1F8A}', '\u{1F82}'), ('\u{1F8B}', '\u{1F83}'), ('\u{1F8C}', '\u{1F84}'),
+        ('\u{1F8D}', '\u{1F85}'), ('\u{1F8E}', '\u{1F86}'), ('\u{1F8F}', '\u{1F87}'),
+        ('\u{1F98}', '\u{1F90}'), ('\u{1F99}', '\u{1F91}'), ('\u{1F9A}', '\u{1F92}'),
+        ('\u{1F9B}', '\u{1F93}'), ('\u{1F9C}', '\u{1F94}'), ('\u{1F9D}', '\u{1F95}'),
+        ('\u{1F9E}', '\u{1F96}'), ('\u{1F9F}', '\u{1F97}'), ('\u{1FA8}', '\u{1FA0}'),
+        ('\u{1FA9}', '\u{1FA1}'), ('\u{1FAA}', '\u{1FA2}'), ('\u{1FAB}', '\u{1FA3}'),
+        ('\u{1FAC}', '\u{1FA4}'), ('\u{1FAD}', '\u{1FA5}'), ('\u{1FAE}', '\u{1FA6}'),
+        ('\u{1FAF}', '\u{1FA7}'), ('\u{1FB8}', '\u{1FB0}'), ('\u{1FB9}', '\u{1FB1}'),
+        ('\u{1FBA}', '\u{1F70}'), ('\u{1FBB}', '\u{1F71}'), ('\u{1FBC}', '\u{1FB3}'),
+        ('\u{1FC8}', '\u{1F72}'), ('\u{1FC9}', '\u{1F73}'), ('\u{1FCA}', '\u{1F74}'),
+        ('\u{1FCB}', '\u{1F75}'), ('\u{1FCC}', '\u{1FC3}'), ('\u{1FD8}', '\u{1FD0}'),
+        ('\u{1FD9}', '\u{1FD1}'), ('\u{1FDA}', '\u{1F76}'), ('\u{1FDB}', '\u{1F77}'),
+        ('\u{1FE8}', '\u{1FE0}'), ('\u{1FE9}', '\u{1FE1}'), ('\u{1FEA}', '\u{1F7A}'),
+        ('\u{1FEB}', '\u{1F7B}'), ('\u{1FEC}', '\u{1FE5}'), ('\u{1FF8}', '\u{1F78}'),
+        ('\u{1FF9}', '\u{1F79}'), ('\u{1FFA}', '\u{1F7C}'), ('\u{1FFB}', '\u{1F7D}'),
+        ('\u{1FFC}', '\u{1FF3}'), ('\u{2126}', '\u{03C9}'), ('\u{212A}', '\u{006B}'),
+        ('\u{212B}', '\u{00E5}'), ('\u{2132}', '\u{214E}'), ('\u{2160}', '\u{2170}'),
+        ('\u{2161}', '\u{2171}'), ('\u{2162}', '\u{2172}'), ('\u{2163}', '\u{2173}'),
+        ('\u{2164}', '\u{2174}'), ('\u{2165}', '\u{2175}'), ('\u{2166}', '\u{2176}'),
+        ('\u{2167}', '\u{2177}'), ('\u{2168}', '\u{2178}'), ('\u{2169}', '\u{2179}'),
+        ('\u{216A}', '\u{217A}'), ('\u{216B}', '\u{217B}'), ('\u{216C}', '\u{217C}'),
+        ('\u{216D}', '\u{217D}'), ('\u{216E}', '\u{217E}'), ('\u{216F}', '\u{217F}'),
+        ('\u{2183}', '\u{2184}'), ('\u{24B6}', '\u{24D0}'), ('\u{24B7}', '\u{24D1}'),
+        ('\u{24B8}', '\u{24D2}'), ('\u{24B9}', '\u{24D3}'), ('\u{24BA}', '\u{24D4}'),
+        ('\u{24BB}', '\u{24D5}'), ('\u{24BC}', '\u{24D6}'), ('\u{24BD}', '\u{24D7}'),
+        ('\u{24BE}', '\u{24D8}'), ('\u{24BF}', '\u{24D9}'), ('\u{24C0}', '\u{24DA}'),
+        ('\u{24C1}', '\u{24DB}'), ('\u{24C2}', '\u{24DC}'), ('\u{24C3}', '\u{24DD}'),
+        ('\u{24C4}', '\u{24DE}'), ('\u{24C5}', '\u{24DF}'), ('\u{24C6}', '\u{24E0}'),
+        ('\u{24C7}', '\u{24E1}'), ('\u{24C8}', '\u{24E2}'), ('\u{24C9}', '\u{24E3}'),
+        ('\u{24CA}', '\u{24E4}'), ('\u{24CB}', '\u{24E5}'), ('\u{24CC}', '\u{24E6}'),
+        ('\u{24CD}', '\u{24E7}'), ('\u{24CE}', '\u{24E8}'), ('\u{24CF}', '\u{24E9}'),
+        ('\u{2C00}', '\u{2C30}'), ('\u{2C01}', '\u{2C31}'), ('\u{2C02}', '\u{2C32}'),
+        ('\u{2C03}', '\u{2C33}'), ('\u{2C04}', '\u{2C34}'), ('\u{2C05}', '\u{2C35}'),
+        ('\u{2C06}', '\u{2C36}'), ('\u{2C07}', '\u{2C37}'), ('\u{2C08}', '\u{2C38}'),
+        ('\u{2C09}', '\u{2C39}'), ('\u{2C0A}', '\u{2C3A}'), ('\u{2C0B}', '\u{2C3B}'),
+        ('\u{2C0C}', '\u{2C3C}'), ('\u{2C0D}', '\u{2C3D}'), ('\u{2C0E}', '\u{2C3E}'),
+        ('\u{2C0F}', '\u{2C3F}'), ('\u{2C10}', '\u{2C40}'), ('\u{2C11}', '\u{2C41}'),
+        ('\u{2C12}', '\u{2C42}'), ('\u{2C13}', '\u{2C43}'), ('\u{2C14}', '\u{2C44}'),
+        ('\u{2C15}', '\u{2C45}'), ('\u{2C16}', '\u{2C46}'), ('\u{2C17}', '\u{2C47}'),
+        ('\u{2C18}', '\u{2C48}'), ('\u{2C19}', '\u{2C49}'), ('\u{2C1A}', '\u{2C4A}'),
+        ('\u{2C1B}', '\u{2C4B}'), ('\u{2C1C}', '\u{2C4C}'), ('\u{2C1D}', '\u{2C4D}'),
+        ('\u{2C1E}', '\u{2C4E}'), ('\u{2C1F}', '\u{2C4F}'), ('\u{2C20}', '\u{2C50}'),
+        ('\u{2C21}', '\u{2C51}'), ('\u{2C22}', '\u{2C52}'), ('\u{2C23}', '\u{2C53}'),
+        ('\u{2C24}', '\u{2C54}'), ('\u{2C25}', '\u{2C55}'), ('\u{2C26}', '\u{2C56}'),
+        ('\u{2C27}', '\u{2C57}'), ('\u{2C28}', '\u{2C58}'), ('\u{2C29}', '\u{2C59}'),
+        ('\u{2C2A}', '\u{2C5A}'), ('\u{2C2B}', '\u{2C5B}'), ('\u{2C2C}', '\u{2C5C}'),
+        ('\u{2C2D}', '\u{2C5D}'), ('\u{2C2E}', '\u{2C5E}'), ('\u{2C2F}', '\u{2C5F}'),
+        ('\u{2C60}', '\u{2C61}'), ('\u{2C62}', '\u{026B}'), ('\u{2C63}', '\u{1D7D}'),
+        ('\u{2C64}', '\u{027D}'), ('\u{2C67}', '\u{2C68}'), ('\u{2C69}', '\u{2C6A}'),
+        ('\u{2C6B}', '\u{2C6C}'), ('\u{2C6D}', '\u{0251}'), ('\u{2C6E}', '\u{0271}'),
+        ('\u{2C6F}', '\u{0250}'), ('\u{2C70}', '\u{0252}'), ('\u{2C72}', '\u{2C73}'),
+        ('\u{2C75}', '\u{2C76}'), ('\u{2C7E}', '\u{023F}'), ('\u{2C7F}', '\u{0240}'),
+        ('\u{2C80}', '\u{2C81}'), ('\u{2C82}', '\u{2C83}'), ('\u{2C84}', '\u{2C85}'),
+        ('\u{2C86}', '\u{2C87}'), ('\u{2C88}', '\u{2C89}'), ('\u{2C8A}', '\u{2C8B}'),
+        ('\u{2C8C}', '\u{2C8D}'), ('\u{2C8E}', '\u{2C8F}'), ('\u{2C90}', '\u{2C91}'),
+        ('\u{2C92}', '\u{2C93}'), ('\u{2C94}', '\u{2C95}'), ('\u{2C96}', '\u{2C97}'),
+        ('\u{2C98}', '\u{2C99}'), ('\u{2C9A}', '\u{2C9B}'), ('\u{2C9C}', '\u{2C9D}'),
+        ('\u{2C9E}', '\u{2C9F}'), ('\u{2CA0}', '\u{2CA1}'), ('\u{2CA2}', '\u{2CA3}'),
+        ('\u{2CA4}', '\u{2CA5}'), ('\u{2CA6}', '\u{2CA7}'), ('\u{2CA8}', '\u{2CA9}'),
+        ('\u{2CAA}', '\u{2CAB}'), ('\u{2CAC}', '\u{2CAD}'), ('\u{2CAE}', '\u{2CAF}'),
+        ('\u{2CB0}', '\u{2CB1}'), ('\u{2CB2}', '\u{2CB3}'), ('\u{2CB4}', '\u{2CB5}'),
+        ('\u{2CB6}', '\u{2CB7}'), ('\u{2CB8}', '\u{2CB9}'), ('\u{2CBA}', '\u{2CBB}'),
+        ('\u{2CBC}', '\u{2CBD}'), ('\u{2CBE}', '\u{2CBF}'), ('\u{2CC0}', '\u{2CC1}'),
+        ('\u{2CC2}', '\u{2CC3}'), ('\u{2CC4}', '\u{2CC5}'), ('\u{2CC6}', '\u{2CC7}'),
+        ('\u{2CC8}', '\u{2CC9}'), ('\u{2CCA}', '\u{2CCB}'), ('\u{2CCC}', '\u{2CCD}'),
+        ('\u{2CCE}', '\u{2CCF}'), ('\u{2CD0}', '\u{2CD1}'), ('\u{2CD2}', '\u{2CD3}'),
+        ('\u{2CD4}', '\u{2CD5}'), ('\u{2CD6}', '\u{2CD7}'), ('\u{2CD8}', '\u{2CD9}'),
+        ('\u{2CDA}', '\u{2CDB}'), ('\u{2CDC}', '\u{2CDD}'), ('\u{2CDE}', '\u{2CDF}'),
+        ('\u{2CE0}', '\u{2CE1}'), ('\u{2CE2}', '\u{2CE3}'), ('\u{2CEB}', '\u{2CEC}'),
+        ('\u{2CED}', '\u{2CEE}'), ('\u{2CF2}', '\u{2CF3}'), ('\u{A640}', '\u{A641}'),
+        ('\u{A642}', '\u{A643}'), ('\u{A644}', '\u{A645}'), ('\u{A646}', '\u{A647}'),
+        ('\u{A648}', '\u{A649}'), ('\u{A64A}', '\u{A64B}'), ('\u{A64C}', '\u{A64D}'),
+        ('\u{A64E}', '\u{A64F}'), ('\u{A650}', '\u{A651}'), ('\u{A652}', '\u{A653}'),
+        ('\u{A654}', '\u{A655}'), ('\u{A656}', '\u{A657}'), ('\u{A658}', '\u{A659}'),
+        ('\u{A65A}', '\u{A65B}'), ('\u{A65C}', '\u{A65D}'), ('\u{A65E}', '\u{A65F}'),
+        ('\u{A660}', '\u{A661}'), ('\u{A662}', '\u{A663}'), ('\u{A664}', '\u{A665}'),
+        ('\u{A666}', '\u{A667}'), ('\u{A668}', '\u{A669}'), ('\u{A66A}', '\u{A66B}'),
+        ('\u{A66C}', '\u{A66D}'), ('\u{A680}', '\u{A681}'), ('\u{A682}', '\u{A683}'),
+        ('\u{A684}', '\u{A685}'), ('\u{A686}', '\u{A687}'), ('\u{A688}', '\u{A689}'),
+        ('\u{A68A}', '\u{A68B}'), ('\u{A68C}', '\u{A68D}'), ('\u{A68E}', '\u{A68F}'),
+        ('\u{A690}', '\u{A691}'), ('\u{A692}', '\u{A693}'), ('\u{A694}', '\u{A695}'),
+        ('\u{A696}', '\u{A697}'), ('\u{A698}', '\u{A699}'), ('\u{A69A}', '\u{A69B}'),
+        ('\u{A722}', '\u{A723}'), ('\u{A724}', '\u{A725}'), ('\u{A726}', '\u{A727}'),
+        ('\u{A728}', '\u{A729}'), ('\u{A72A}', '\u{A72B}'), ('\u{A72C}', '\u{A72D}'),
+        ('\u{A72E}', '\u{A72F}'), ('\u{A732}', '\u{A733}'), ('\u{A734}', '\u{A735}'),
+        ('\u{A736}', '\u{A737}'), ('\u{A738}', '\u{A739}'), ('\u{A73A}', '\u{A73B}'),
+        ('\u{A73C}', '\u{A73D}'), ('\u{A73E}', '\u{A73F}'), ('\u{A740}', '\u{A741}'),
+        ('\u{A742}', '\u{A743}'), ('\u{A744}', '\u{A745}'), ('\u{A746}', '\u{A747}'),
+        ('\u{A748}', '\u{A749}'), ('\u{A74A}', '\u{A74B}'), ('\u{A74C}', '\u{A74D}'),
+        ('\u{A74E}', '\u{A74F}'), ('\u{A750}', '\u{A751}'), ('\u{A752}', '\u{A753}'),
+        ('\u{A754}', '\u{A755}'), ('\u{A756}', '\u{A757}'), ('\u{A758}', '\u{A759}'),
+        ('\u{A75A}', '\u{A75B}'), ('\u{A75C}', '\u{A75D}'), ('\u{A75E}', '\u{A75F}'),
+        ('\u{A760}', '\u{A761}'), ('\u{A762}', '\u{A763}'), ('\u{A764}', '\u{A765}'),
+        ('\u{A766}', '\u{A767}'), ('\u{A768}', '\u{A769}'), ('\u{A76A}', '\u{A76B}'),
+        ('\u{A76C}', '\u{A76D}'), ('\u{A76E}', '\u{A76F}'), ('\u{A779}', '\u{A77A}'),
+        ('\u{A77B}', '\u{A77C}'), ('\u{A77D}', '\u{1D79}'), ('\u{A77E}', '\u{A77F}'),
+        ('\u{A780}', '\u{A781}'), ('\u{A782}', '\u{A783}'), ('\u{A784}', '\u{A785}'),
+        ('\u{A786}', '\u{A787}'), ('\u{A78B}', '\u{A78C}'), ('\u{A78D}', '\u{0265}'),
+        ('\u{A790}', '\u{A791}'), ('\u{A792}', '\u{A793}'), ('\u{A796}', '\u{A797}'),
+        ('\u{A798}', '\u{A799}'), ('\u{A79A}', '\u{A79B}'), ('\u{A79C}', '\u{A79D}'),
+        ('\u{A79E}', '\u{A79F}'), ('\u{A7A0}', '\u{A7A1}'), ('\u{A7A2}', '\u{A7A3}'),
+        ('\u{A7A4}', '\u{A7A5}'), ('\u{A7A6}', '\u{A7A7}'), ('\u{A7A8}', '\u{A7A9}'),
+        ('\u{A7AA}', '\u{0266}'), ('\u{A7AB}', '\u{025C}'), ('\u{A7AC}', '\u{0261}'),
+        ('\u{A7AD}', '\u{026C}'), ('\u{A7AE}', '\u{026A}'), ('\u{A7B0}', '\u{029E}'),
+        ('\u{A7B1}', '\u{0287}'), ('\u{A7B2}', '\u{029D}'), ('\u{A7B3}', '\u{AB53}'),
+        ('\u{A7B4}', '\u{A7B5}'), ('\u{A7B6}', '\u{A7B7}'), ('\u{A7B8}', '\u{A7B9}'),
+        ('\u{A7BA}', '\u{A7BB}'), ('\u{A7BC}', '\u{A7BD}'), ('\u{A7BE}', '\u{A7BF}'),
+        ('\u{A7C0}', '\u{A7C1}'), ('\u{A7C2}', '\u{A7C3}'), ('\u{A7C4}', '\u{A794}'),
+        ('\u{A7C5}', '\u{0282}'), ('\u{A7C6}', '\u{1D8E}'), ('\u{A7C7}', '\u{A7C8}'),
+        ('\u{A7C9}', '\u{A7CA}'), ('\u{A7CB}', '\u{0264}'), ('\u{A7CC}', '\u{A7CD}'),
+        ('\u{A7CE}', '\u{A7CF}'), ('\u{A7D0}', '\u{A7D1}'), ('\u{A7D2}', '\u{A7D3}'),
+        ('\u{A7D4}', '\u{A7D5}'), ('\u{A7D6}', '\u{A7D7}'), ('\u{A7D8}', '\u{A7D9}'),
+        ('\u{A7DA}', '\u{A7DB}'), ('\u{A7DC}', '\u{019B}'), ('\u{A7F5}', '\u{A7F6}'),
+        ('\u{FF21}', '\u{FF41}'), ('\u{FF22}', '\u{FF42}'), ('\u{FF23}', '\u{FF43}'),
+        ('\u{FF24}', '\u{FF44}'), ('\u{FF25}', '\u{FF45}'), ('\u{FF26}', '\u{FF46}'),
+        ('\u{FF27}', '\u{FF47}'), ('\u{FF28}', '\u{FF48}'), ('\u{FF29}', '\u{FF49}'),
+        ('\u{FF2A}', '\u{FF4A}'), ('\u{FF2B}', '\u{FF4B}'), ('\u{FF2C}', '\u{FF4C}'),
+        ('\u{FF2D}', '\u{FF4D}'), ('\u{FF2E}', '\u{FF4E}'), ('\u{FF2F}', '\u{FF4F}'),
+        ('\u{FF30}', '\u{FF50}'), ('\u{FF31}', '\u{FF51}'), ('\u{FF32}', '\u{FF52}'),
+        ('\u{FF33}', '\u{FF53}'), ('\u{FF34}', '\u{FF54}'), ('\u{FF35}', '\u{FF55}'),
+        ('\u{FF36}', '\u{FF56}'), ('\u{FF37}', '\u{FF57}'), ('\u{FF38}', '\u{FF58}'),
+        ('\u{FF39}', '\u{FF59}'), ('\u{FF3A}', '\u{FF5A}'), ('\u{10400}', '\u{10428}'),
+        ('\u{10401}', '\u{10429}'), ('\u{10402}', '\u{1042A}'), ('\u{10403}', '\u{1042B}'),
+        ('\u{10404}', '\u{1042C}'), ('\u{10405}', '\u{1042D}'), ('\u{10406}', '\u{1042E}'),
+        ('\u{10407}', '\u{1042F}'), ('\u{10408}', '\u{10430}'), ('\u{10409}', '\u{10431}'),
+        ('\u{1040A}', '\u{10432}'), ('\u{1040B}', '\u{10433}'), ('\u{1040C}', '\u{10434}'),
+        ('\u{1040D}', '\u{10435}'), ('\u{1040E}', '\u{10436}'), ('\u{1040F}', '\u{10437}'),
+        ('\u{10410}', '\u{10438}'), ('\u{10411}', '\u{10439}'), ('\u{10412}', '\u{1043A}'),
+        ('\u{10413}', '\u{1043B}'), ('\u{10414}', '\u{1043C}'), ('\u{10415}', '\u{1043D}'),
+        ('\u{10416}', '\u{1043E}'), ('\u{10417}', '\u{1043F}'), ('\u{10418}', '\u{10440}'),
+        ('\u{10419}', '\u{10441}'), ('\u{1041A}', '\u{10442}'), ('\u{1041B}', '\u{10443}'),
+        ('\u{1041C}', '\u{10444}'), ('\u{1041D}', '\u{10445}'), ('\u{1041E}', '\u{10446}'),
+        ('\u{1041F}', '\u{10447}'), ('\u{10420}', '\u{10448}'), ('\u{10421}', '\u{10449}'),
+        ('\u{10422}', '\u{1044A}'), ('\u{10423}', '\u{1044B}'), ('\u{10424}', '\u{1044C}'),
+        ('\u{10425}', '\u{1044D}'), ('\u{10426}', '\u{1044E}'), ('\u{10427}', '\u{1044F}'),
+        ('\u{104B0}', '\u{104D8}'), ('\u{104B1}', '\u{104D9}'), ('\u{104B2}', '\u{104DA}'),
+        ('\u{104B3}', '\u{104DB}'), ('\u{104B4}', '\u{104DC}'), ('\u{104B5}', '\u{104DD}'),
+        ('\u{104B6}', '\u{104DE}'), ('\u{104B7}', '\u{104DF}'), ('\u{104B8}', '\u{104E0}'),
+        ('\u{104B9}', '\u{104E1}'), ('\u{104BA}', '\u{104E2}'), ('\u{104BB}', '\u{104E3}'),
+        ('\u{104BC}', '\u{104E4}'), ('\u{104BD}', '\u{104E5}'), ('\u{104BE}', '\u{104E6}'),
+        ('\u{104BF}', '\u{104E7}'), ('\u{104C0}', '\u{104E8}'), ('\u{104C1}', '\u{104E9}'),
+        ('\u{104C2}', '\u{104EA}'), ('\u{104C3}', '\u{104EB}'), ('\u{104C4}', '\u{104EC}'),
+        ('\u{104C5}', '\u{104ED}'), ('\u{104C6}', '\u{104EE}'), ('\u{104C7}', '\u{104EF}'),
+        ('\u{104C8}', '\u{104F0}'), ('\u{104C9}', '\u{104F1}'), ('\u{104CA}', '\u{104F2}'),
+        ('\u{104CB}', '\u{104F3}'), ('\u{104CC}', '\u{104F4}'), ('\u{104CD}', '\u{104F5}'),
+        ('\u{104CE}', '\u{104F6}'), ('\u{104CF}', '\u{104F7}'), ('\u{104D0}', '\u{104F8}'),
+        ('\u{104D1}', '\u{104F9}'), ('\u{104D2}', '\u{104FA}'), ('\u{104D3}', '\u{104FB}'),
+        ('\u{10570}', '\u{10597}'), ('\u{10571}', '\u{10598}'), ('\u{10572}', '\u{10599}'),
+        ('\u{10573}', '\u{1059A}'), ('\u{10574}', '\u{1059B}'), ('\u{10575}', '\u{1059C}'),
+        ('\u{10576}', '\u{1059D}'), ('\u{10577}', '\u{1059E}'), ('\u{10578}', '\u{1059F}'),
+        ('\u{10579}', '\u{105A0}'), ('\u{1057A}', '\u{105A1}'), ('\u{1057C}', '\u{105A3}'),
+        ('\u{1057D}', '\u{105A4}'), ('\u{1057E}', '\u{105A5}'), ('\u{1057F}', '\u{105A6}'),
+        ('\u{10580}', '\u{105A7}'), ('\u{10581}', '\u{105A8}'), ('\u{10582}', '\u{105A9}'),
+        ('\u{10583}', '\u{105AA}'), ('\u{10584}', '\u{105AB}'), ('\u{10585}', '\u{105AC}'),
+        ('\u{10586}', '\u{105AD}'), ('\u{10587}', '\u{105AE}'), ('\u{10588}', '\u{105AF}'),
+        ('\u{10589}', '\u{105B0}'), ('\u{1058A}', '\u{105B1}'), ('\u{1058C}', '\u{105B3}'),
+        ('\u{1058D}', '\u{105B4}'), ('\u{1058E}', '\u{105B5}'), ('\u{1058F}', '\u{105B6}'),
+        ('\u{10590}', '\u{105B7}'), ('\u{10591}', '\u{105B8}'), ('\u{10592}', '\u{105B9}'),
+        ('\u{10594}', '\u{105BB}'), ('\u{10595}', '\u{105BC}'), ('\u{10C80}', '\u{10CC0}'),
+        ('\u{10C81}', '\u{10CC1}'), ('\u{10C82}', '\u{10CC2}'), ('\u{10C83}', '\u{10CC3}'),
+        ('\u{10C84}', '\u{10CC4}'), ('\u{10C85}', '\u{10CC5}'), ('\u{10C86}', '\u{10CC6}'),
+        ('\u{10C87}', '\u{10CC7}'), ('\u{10C88}', '\u{10CC8}'), ('\u{10C89}', '\u{10CC9}'),
+        ('\u{10C8A}', '\u{10CCA}'), ('\u{10C8B}', '\u{10CCB}'), ('\u{10C8C}', '\u{10CCC}'),
+        ('\u{10C8D}', '\u{10CCD}'), ('\u{10C8E}', '\u{10CCE}'), ('\u{10C8F}', '\u{10CCF}'),
+        ('\u{10C90}', '\u{10CD0}'), ('\u{10C91}', '\u{10CD1}'), ('\u{10C92}', '\u{10CD2}'),
+        ('\u{10C93}', '\u{10CD3}'), ('\u{10C94}', '\u{10CD4}'), ('\u{10C95}', '\u{10CD5}'),
+        ('\u{10C96}', '\u{10CD6}'), ('\u{10C97}', '\u{10CD7}'), ('\u{10C98}', '\u{10CD8}'),
+        ('\u{10C99}', '\u{10CD9}'), ('\u{10C9A}', '\u{10CDA}'), ('\u{10C9B}', '\u{10CDB}'),
+        ('\u{10C9C}', '\u{10CDC}'), ('\u{10C9D}', '\u{10CDD}'), ('\u{10C9E}', '\u{10CDE}'),
+        ('\u{10C9F}', '\u{10CDF}'), ('\u{10CA0}', '\u{10CE0}'), ('\u{10CA1}', '\u{10CE1}'),
+        ('\u{10CA2}', '\u{10CE2}'), ('\u{10CA3}', '\u{10CE3}'), ('\u{10CA4}', '\u{10CE4}'),
+        ('\u{10CA5}', '\u{10CE5}'), ('\u{10CA6}', '\u{10CE6}'), ('\u{10CA7}', '\u{10CE7}'),
+        ('\u{10CA8}', '\u{10CE8}'), ('\u{10CA9}', '\u{10CE9}'), ('\u{10CAA}', '\u{10CEA}'),
+        ('\u{10CAB}', '\u{10CEB}'), ('\u{10CAC}', '\u{10CEC}'), ('\u{10CAD}', '\u{10CED}'),
+        ('\u{10CAE}', '\u{10CEE}'), ('\u{10CAF}', '\u{10CEF}'), ('\u{10CB0}', '\u{10CF0}'),
+        ('\u{10CB1}', '\u{10CF1}'), ('\u{10CB2}', '\u{10CF2}'), ('\u{10D50}', '\u{10D70}'),
+        ('\u{10D51}', '\u{10D71}'), ('\u{10D52}', '\u{10D72}'), ('\u{10D53}', '\u{10D73}'),
+        ('\u{10D54}', '\u{10D74}'), ('\u{10D55}', '\u{10D75}'), ('\u{10D56}', '\u{10D76}'),
+        ('\u{10D57}', '\u{10D77}'), ('\u{10D58}', '\u{10D78}'), ('\u{10D59}', '\u{10D79}'),
+        ('\u{10D5A}', '\u{10D7A}'), ('\u{10D5B}', '\u{10D7B}'), ('\u{10D5C}', '\u{10D7C}'),
+        ('\u{10D5D}', '\u{10D7D}'), ('\u{10D5E}', '\u{10D7E}'), ('\u{10D5F}', '\u{10D7F}'),
+        ('\u{10D60}', '\u{10D80}'), ('\u{10D61}', '\u{10D81}'), ('\u{10D62}', '\u{10D82}'),
+        ('\u{10D63}', '\u{10D83}'), ('\u{10D64}', '\u{10D84}'), ('\u{10D65}', '\u{10D85}'),
+        ('\u{118A0}', '\u{118C0}'), ('\u{118A1}', '\u{118C1}'), ('\u{118A2}', '\u{118C2}'),
+        ('\u{118A3}', '\u{118C3}'), ('\u{118A4}', '\u{118C4}'), ('\u{118A5}', '\u{118C5}'),
+        ('\u{118A6}', '\u{118C6}'), ('\u{118A7}', '\u{118C7}'), ('\u{118A8}', '\u{118C8}'),
+        ('\u{118A9}', '\u{118C9}'), ('\u{118AA}', '\u{118CA}'), ('\u{118AB}', '\u{118CB}'),
+        ('\u{118AC}', '\u{118CC}'), ('\u{118AD}', '\u{118CD}'), ('\u{118AE}', '\u{118CE}'),
+        ('\u{118AF}', '\u{118CF}'), ('\u{118B0}', '\u{118D0}'), ('\u{118B1}', '\u{118D1}'),
+        ('\u{118B2}', '\u{118D2}'), ('\u{118B3}', '\u{118D3}'), ('\u{118B4}', '\u{118D4}'),
+        ('\u{118B5}', '\u{118D5}'), ('\u{118B6}', '\u{118D6}'), ('\u{118B7}', '\u{118D7}'),
+        ('\u{118B8}', '\u{118D8}'), ('\u{118B9}', '\u{118D9}'), ('\u{118BA}', '\u{118DA}'),
+        ('\u{118BB}', '\u{118DB}'), ('\u{118BC}', '\u{118DC}'), ('\u{118BD}', '\u{118DD}'),
+        ('\u{118BE}', '\u{118DE}'), ('\u{118BF}', '\u{118DF}'), ('\u{16E40}', '\u{16E60}'),
+        ('\u{16E41}', '\u{16E61}'), ('\u{16E42}', '\u{16E62}'), ('\u{16E43}', '\u{16E63}'),
+        ('\u{16E44}', '\u{16E64}'), ('\u{16E45}', '\u{16E65}'), ('\u{16E46}', '\u{16E66}'),
+        ('\u{16E47}', '\u{16E67}'), ('\u{16E48}', '\u{16E68}'), ('\u{16E49}', '\u{16E69}'),
+        ('\u{16E4A}', '\u{16E6A}'), ('\u{16E4B}', '\u{16E6B}'), ('\u{16E4C}', '\u{16E6C}'),
+        ('\u{16E4D}', '\u{16E6D}'), ('\u{16E4E}', '\u{16E6E}'), ('\u{16E4F}', '\u{16E6F}'),
+        ('\u{16E50}', '\u{16E70}'), ('\u{16E51}', '\u{16E71}'), ('\u{16E52}', '\u{16E72}'),
+        ('\u{16E53}', '\u{16E73}'), ('\u{16E54}', '\u{16E74}'), ('\u{16E55}', '\u{16E75}'),
+        ('\u{16E56}', '\u{16E76}'), ('\u{16E57}', '\u{16E77}'), ('\u{16E58}', '\u{16E78}'),
+        ('\u{16E59}', '\u{16E79}'), ('\u{16E5A}', '\u{16E7A}'), ('\u{16E5B}', '\u{16E7B}'),
+        ('\u{16E5C}', '\u{16E7C}'), ('\u{16E5D}', '\u{16E7D}'), ('\u{16E5E}', '\u{16E7E}'),
+        ('\u{16E5F}', '\u{16E7F}'), ('\u{16EA0}', '\u{16EBB}'), ('\u{16EA1}', '\u{16EBC}'),
+        ('\u{16EA2}', '\u{16EBD}'), ('\u{16EA3}', '\u{16EBE}'), ('\u{16EA4}', '\u{16EBF}'),
+        ('\u{16EA5}', '\u{16EC0}'), ('\u{16EA6}', '\u{16EC1}'), ('\u{16EA7}', '\u{16EC2}'),
+        ('\u{16EA8}', '\u{16EC3}'), ('\u{16EA9}', '\u{16EC4}'), ('\u{16EAA}', '\u{16EC5}'),
+        ('\u{16EAB}', '\u{16EC6}'), ('\u{16EAC}', '\u{16EC7}'), ('\u{16EAD}', '\u{16EC8}'),
+        ('\u{16EAE}', '\u{16EC9}'), ('\u{16EAF}', '\u{16ECA}'), ('\u{16EB0}', '\u{16ECB}'),
+        ('\u{16EB1}', '\u{16ECC}'), ('\u{16EB2}', '\u{16ECD}'), ('\u{16EB3}', '\u{16ECE}'),
+        ('\u{16EB4}', '\u{16ECF}'), ('\u{16EB5}', '\u{16ED0}'), ('\u{16EB6}', '\u{16ED1}'),
+        ('\u{16EB7}', '\u{16ED2}'), ('\u{16EB8}', '\u{16ED3}'), ('\u{1E900}', '\u{1E922}'),
+        ('\u{1E901}', '\u{1E923}'), ('\u{1E902}', '\u{1E924}'), ('\u{1E903}', '\u{1E925}'),
+        ('\u{1E904}', '\u{1E926}'), ('\u{1E905}', '\u{1E927}'), ('\u{1E906}', '\u{1E928}'),
+        ('\u{1E907}', '\u{1E929}'), ('\u{1E908}', '\u{1E92A}'), ('\u{1E909}', '\u{1E92B}'),
+        ('\u{1E90A}', '\u{1E92C}'), ('\u{1E90B}', '\u{1E92D}'), ('\u{1E90C}', '\u{1E92E}'),
+        ('\u{1E90D}', '\u{1E92F}'), ('\u{1E90E}', '\u{1E930}'), ('\u{1E90F}', '\u{1E931}'),
+        ('\u{1E910}', '\u{1E932}'), ('\u{1E911}', '\u{1E933}'), ('\u{1E912}', '\u{1E934}'),
+        ('\u{1E913}', '\u{1E935}'), ('\u{1E914}', '\u{1E936}'), ('\u{1E915}', '\u{1E937}'),
+        ('\u{1E916}', '\u{1E938}'), ('\u{1E917}', '\u{1E939}'), ('\u{1E918}', '\u{1E93A}'),
+        ('\u{1E919}', '\u{1E93B}'), ('\u{1E91A}', '\u{1E93C}'), ('\u{1E91B}', '\u{1E93D}'),
+        ('\u{1E91C}', '\u{1E93E}'), ('\u{1E91D}', '\u{1E93F}'), ('\u{1E91E}', '\u{1E940}'),
+        ('\u{1E91F}', '\u{1E941}'), ('\u{1E920}', '\u{1E942}'), ('\u{1E921}', '\u{1E943}'),
+    ],
+    full: &[
+        ('\u{0130}', ['\u{0069}', '\u{0307}', '\u{0000}']),
+    ],
+};
+
+#[rustfmt::skip]
+static TITLE_CASE_TABLE: UCDCaseTable = UCDCaseTable {
+    simple: &[
+        ('\u{0061}', '\u{0041}'), ('\u{0062}', '\u{0042}'), ('\u{0063}', '\u{0043}'),
+        ('\u{0064}', '\u{0044}'), ('\u{0065}', '\u{0045}'), ('\u{0066}', '\u{0046}'),
+        ('\u{0067}', '\u{0047}'), ('\u{0068}', '\u{0048}'), ('\u{0069}', '\u{0049}'),
+        ('\u{006A}', '\u{004A}'), ('\u{006B}', '\u{004B}'), ('\u{006C}', '\u{004C}'),
+        ('\u{006D}', '\u{004D}'), ('\u{006E}', '\u{004E}'), ('\u{006F}', '\u{004F}'),
+        ('\u{0070}', '\u{0050}'), ('\u{0071}', '\u{0051}'), ('\u{0072}', '\u{0052}'),
+        ('\u{0073}', '\u{0053}'), ('\u{0074}', '\u{0054}'), ('\u{0075}', '\u{0055}'),
+        ('\u{0076}', '\u{0056}'), ('\u{0077}', '\u{0057}'), ('\u{0078}', '\u{0058}'),
+        ('\u{0079}', '\u{0059}'), ('\u{007A}', '\u{005A}'), ('\u{00B5}', '\u{039C}'),
+        ('\u{00E0}', '\u{00C0}'), ('\u{00E1}', '\u{00C1}'), ('\u{00E2}', '\u{00C2}'),
+        ('\u{00E3}', '\u{00C3}'), ('\u{00E4}', '\u{00C4}'), ('\u{00E5}', '\u{00C5}'),
+        ('\u{00E6}', '\u{00C6}'), ('\u{00E7}', '\u{00C7}'), ('\u{00E8}', '\u{00C8}'),
+        ('\u{00E9}', '\u{00C9}'), ('\u{00EA}', '\u{00CA}'), ('\u{00EB}', '\u{00CB}'),
+        ('\u{00EC}', '\u{00CC}'), ('\u{00ED}', '\u{00CD}'), ('\u{00EE}', '\u{00CE}'),
+        ('\u{00EF}', '\u{00CF}'), ('\u{00F0}', '\u{00D0}'), ('\u{00F1}', '\u{00D1}'),
+        ('\u{00F2}', '\u{00D2}'), ('\u{00F3}', '\u{00D3}'), ('\u{00F4}', '\u{00D4}'),
+        ('\u{00F5}', '\u{00D5}'), ('\u{00F6}', '\u{00D6}'), ('\u{00F8}', '\u{00D8}'),
+        ('\u{00F9}', '\u{00D9}'), ('\u{00FA}', '\u{00DA}'), ('\u{00FB}', '\u{00DB}'),
+        ('\u{00FC}', '\u{00DC}'), ('\u{00FD}', '\u{00DD}'), ('\u{00FE}', '\u{00DE}'),
+        ('\u{00FF}', '\u{0178}'), ('\u{0101}', '\u{0100}'), ('\u{0103}', '\u{0102}'),
+        ('\u{0105}', '\u{0104}'), ('\u{0107}', '\u{0106}'), ('\u{0109}', '\u{0108}'),
+        ('\u{010B}', '\u{010A}'), ('\u{010D}', '\u{010C}'), ('\u{010F}', '\u{010E}'),
+        ('\u{0111}', '\u{0110}'), ('\u{0113}', '\u{0112}'), ('\u{0115}', '\u{0114}'),
+        ('\u{0117}', '\u{0116}'), ('\u{0119}', '\u{0118}'), ('\u{011B}', '\u{011A}'),
+        ('\u{011D}', '\u{011C}'), ('\u{011F}', '\u{011E}'), ('\u{0121}', '\u{0120}'),
+        ('\u{0123}', '\u{0122}'), ('\u{0125}', '\u{0124}'), ('\u{0127}', '\u{0126}'),
+        ('\u{0129}', '\u{0128}'), ('\u{012B}', '\u{012A}'), ('\u{012D}', '\u{012C}'),
+        ('\u{012F}', '\u{012E}'), ('\u{0131}', '\u{0049}'), ('\u{0133}', '\u{0132}'),
+        ('\u{0135}', '\u{0134}'), ('\u{0137}', '\u{0136}'), ('\u{013A}', '\u{0139}'),
+        ('\u{013C}', '\u{013B}'), ('\u{013E}', '\u{013D}'), ('\u{0140}', '\u{013F}'),
+        ('\u{0142}', '\u{0141}'), ('\u{0144}', '\u{0143}'), ('\u{0146}', '\u{0145}'),
+        ('\u{0148}', '\u{0147}'), ('\u{014B}', '\u{014A}'), ('\u{014D}', '\u{014C}'),
+        ('\u{014F}', '\u{014E}'), ('\u{0151}', '\u{0150}'), ('\u{0153}', '\u{0152}'),
+        ('\u{0155}', '\u{0154}'), ('\u{0157}', '\u{0156}'), ('\u{0159}', '\u{0158}'),
+        ('\u{015B}', '\u{015A}'), ('\u{015D}', '\u{015C}'), ('\u{015F}', '\u{015E}'),
+        ('\u{0161}', '\u{0160}'), ('\u{0163}', '\u{0162}'), ('\u{0165}', '\u{0164}'),
+        ('\u{0167}', '\u{0166}'), ('\u{0169}', '\u{0168}'), ('\u{016B}', '\u{016A}'),
+        ('\u{016D}', '\u{016C}'), ('\u{016F}', '\u{016E}'), ('\u{0171}', '\u{0170}'),
+        ('\u{0173}', '\u{0172}'), ('\u{0175}', '\u{0174}'), ('\u{0177}', '\u{0176}'),
+        ('\u{017A}', '\u{0179}'), ('\u{017C}', '\u{017B}'), ('\u{017E}', '\u{017D}'),
+        ('\u{017F}', '\u{0053}'), ('\u{0180}', '\u{0243}'), ('\u{0183}', '\u{0182}'),
+        ('\u{0185}', '\u{0184}'), ('\u{0188}', '\u{0187}'), ('\u{018C}', '\u{018B}'),
+        ('\u{0192}', '\u{0191}'), ('\u{0195}', '\u{01F6}'), ('\u{0199}', '\u{0198}'),
+        ('\u{019A}', '\u{023D}'), ('\u{019B}', '\u{A7DC}'), ('\u{019E}', '\u{0220}'),
+        ('\u{01A1}', '\u{01A0}'), ('\u{01A3}', '\u{01A2}'), ('\u{01A5}', '\u{01A4}'),
+        ('\u{01A8}', '\u{01A7}'), ('\u{01AD}', '\u{01AC}'), ('\u{01B0}', '\u{01AF}'),
+        ('\u{01B4}', '\u{01B3}'), ('\u{01B6}', '\u{01B5}'), ('\u{01B9}', '\u{01B8}'),
+        ('\u{01BD}', '\u{01BC}'), ('\u{01BF}', '\u{01F7}'), ('\u{01C4}', '\u{01C5}'),
+        ('\u{01C6}', '\u{01C5}'), ('\u{01C7}', '\u{01C8}'), ('\u{01C9}', '\u{01C8}'),
+        ('\u{01CA}', '\u{01CB}'), ('\u{01CC}', '\u{01CB}'), ('\u{01CE}', '\u{01CD}'),
+        ('\u{01D0}', '\u{01CF}'), ('\u{01D2}', '\u{01D1}'), ('\u{01D4}', '\u{01D3}'),
+        ('\u{01D6}', '\u{01D5}'), ('\u{01D8}', '\u{01D7}'), ('\u{01DA}', '\u{01D9}'),
+        ('\u{01DC}', '\u{01DB}'), ('\u{01DD}', '\u{018E}'), ('\u{01DF}', '\u{01DE}'),
+        ('\u{01E1}', '\u{01E0}'), ('\u{01E3}', '\u{01E2}'), ('\u{01E5}', '\u{01E4}'),
+        ('\u{01E7}', '\u{01E6}'), ('\u{01E9}', '\u{01E8}'), ('\u{01EB}', '\u{01EA}'),
+        ('\u{01ED}', '\u{01EC}'), ('\u{01EF}', '\u{01EE}'), ('\u{01F1}', '\u{01F2}'),
+        ('\u{01F3}', '\u{01F2}'), ('\u{01F5}', '\u{01F4}'), ('\u{01F9}', '\u{01F8}'),
+        ('\u{01FB}', '\u{01FA}'), ('\u{01FD}', '\u{01FC}'), ('\u{01FF}', '\u{01FE}'),
+        ('\u{0201}', '\u{0200}'), ('\u{0203}', '\u{0202}'), ('\u{0205}', '\u{0204}'),
+        ('\u{0207}', '\u{0206}'), ('\u{0209}', '\u{0208}'), ('\u{020B}', '\u{020A}'),
+        ('\u{020D}', '\u{020C}'), ('\u{020F}', '\u{020E}'), ('\u{0211}', '\u{0210}'),
+        ('\u{0213}', '\u{0212}'), ('\u{0215}', '\u{0214}'), ('\u{0217}', '\u{0216}'),
+        ('\u{0219}', '\u{0218}'), ('\u{021B}', '\u{021A}'), ('\u{021D}', '\u{021C}'),
+        ('\u{021F}', '\u{021E}'), ('\u{0223}', '\u{0222}'), ('\u{0225}', '\u{0224}'),
+        ('\u{0227}', '\u{0226}'), ('\u{0229}', '\u{0228}'), ('\u{022B}', '\u{022A}'),
+        ('\u{022D}', '\u{022C}'), ('\u{022F}', '\u{022E}'), ('\u{0231}', '\u{0230}'),
+        ('\u{0233}', '\u{0232}'), ('\u{023C}', '\u{023B}'), ('\u{023F}', '\u{2C7E}'),
+        ('\u{0240}', '\u{2C7F}'), ('\u{0242}', '\u{0241}'), ('\u{0247}', '\u{0246}'),
+        ('\u{0249}', '\u{0248}'), ('\u{024B}', '\u{024A}'), ('\u{024D}', '\u{024C}'),
+        ('\u{024F}', '\u{024E}'), ('\u{0250}', '\u{2C6F}'), ('\u{0251}', '\u{2C6D}'),
+        ('\u{0252}', '\u{2C70}'), ('\u{0253}', '\u{0181}'), ('\u{0254}', '\u{0186}'),
+        ('\u{0256}', '\u{0189}'), ('\u{0257}', '\u{018A}'), ('\u{0259}', '\u{018F}'),
+        ('\u{025B}', '\u{0190}'), ('\u{025C}', '\u{A7AB}'), ('\u{0260}', '\u{0193}'),
+        ('\u{0261}', '\u{A7AC}'), ('\u{0263}', '\u{0194}'), ('\u{0264}', '\u{A7CB}'),
+        ('\u{0265}', '\u{A78D}'), ('\u{0266}', '\u{A7AA}'), ('\u{0268}', '\u{0197}'),
+        ('\u{0269}', '\u{0196}'), ('\u{026A}', '\u{A7AE}'), ('\u{026B}', '\u{2C62}'),
+        ('\u{026C}', '\u{A7AD}'), ('\u{026F}', '\u{019C}'), ('\u{0271}', '\u{2C6E}'),
+        ('\u{0272}', '\u{019D}'), ('\u{0275}', '\u{019F}'), ('\u{027D}', '\u{2C64}'),
+        ('\u{0280}', '\u{01A6}'), ('\u{0282}', '\u{A7C5}'), ('\u{0283}', '\u{01A9}'),
+        ('\u{0287}', '\u{A7B1}'), ('\u{0288}', '\u{01AE}'), ('\u{0289}', '\u{0244}'),
+        ('\u{028A}', '\u{01B1}'), ('\u{028B}', '\u{01B2}'), ('\u{028C}', '\u{0245}'),
+        ('\u{0292}', '\u{01B7}'), ('\u{029D}', '\u{A7B2}'), ('\u{029E}', '\u{A7B0}'),
+        ('\u{0345}', '\u{0399}'), ('\u{0371}', '\u{0370}'), ('\u{0373}', '\u{0372}'),
+        ('\u{0377}', '\u{0376}'), ('\u{037B}', '\u{03FD}'), ('\u{037C}', '\u{03FE}'),
+        ('\u{037D}', '\u{03FF}'), ('\u{03AC}', '\u{0386}'), ('\u{03AD}', '\u{0388}'),
+        ('\u{03AE}', '\u{0389}'), ('\u{03AF}', '\u{038A}'), ('\u{03B1}', '\u{0391}'),
+        ('\u{03B2}', '\u{0392}'), ('\u{03B3}', '\u{0393}'), ('\u{03B4}', '\u{0394}'),
+        ('\u{03B5}', '\u{0395}'), ('\u{03B6}', '\u{0396}'), ('\u{03B7}', '\u{0397}'),
+        ('\u{03B8}', '\u{0398}'), ('\u{03B9}', '\u{0399}'), ('\u{03BA}', '\u{039A}'),
+        ('\u{03BB}', '\u{039B}'), ('\u{03BC}', '\u{039C}'), ('\u{03BD}', '\u{039D}'),
+        ('\u{03BE}', '\u{039E}'), ('\u{03BF}', '\u{039F}'), ('\u{03C0}', '\u{03A0}'),
+        ('\u{03C1}', '\u{03A1}'), ('\u{03C2}', '\u{03A3}'), ('\u{03C3}', '\u{03A3}'),
+        ('\u{03C4}', '\u{03A4}'), ('\u{03C5}', '\u{03A5}'), ('\u{03C6}', '\u{03A6}'),
+        ('\u{03C7}', '\u{03A7}'), ('\u{03C8}', '\u{03A8}'), ('\u{03C9}', '\u{03A9}'),
+        ('\u{03CA}', '\u{03AA}'), ('\u{03CB}', '\u{03AB}'), ('\u{03CC}', '\u{038C}'),
+        ('\u{03CD}', '\u{038E}'), ('\u{03CE}', '\u{038F}'), ('\u{03D0}', '\u{0392}'),
+        ('\u{03D1}', '\u{0398}'), ('\u{03D5}', '\u{03A6}'), ('\u{03D6}', '\u{03A0}'),
+        ('\u{03D7}', '\u{03CF}'), ('\u{03D9}', '\u{03D8}'), ('\u{03DB}', '\u{03DA}'),
+        ('\u{03DD}', '\u{03DC}'), ('\u{03DF}', '\u{03DE}'), ('\u{03E1}', '\u{03E0}'),
+        ('\u{03E3}', '\u{03E2}'), ('\u{03E5}', '\u{03E4}'), ('\u{03E7}', '\u{03E6}'),
+        ('\u{03E9}', '\u{03E8}'), ('\u{03EB}', '\u{03EA}'), ('\u{03ED}', '\u{03EC}'),
+        ('\u{03EF}', '\u{03EE}'), ('\u{03F0}', '\u{039A}'), ('\u{03F1}', '\u{03A1}'),
+        ('\u{03F2}', '\u{03F9}'), ('\u{03F3}', '\u{037F}'), ('\u{03F5}', '\u{0395}'),
+        ('\u{03F8}', '\u{03F7}'), ('\u{03FB}', '\u{03FA}'), ('\u{0430}', '\u{0410}'),
+        ('\u{0431}', '\u{0411}'), ('\u{0432}', '\u{0412}'), ('\u{0433}', '\u{0413}'),
+        ('\u{0434}', '\u{0414}'), ('\u{0435}', '\u{0415}'), ('\u{0436}', '\u{0416}'),
+        ('\u{0437}', '\u{0417}'), ('\u{0438}', '\u{0418}'), ('\u{0439}', '\u{0419}'),
+        ('\u{043A}', '\u{041A}'), ('\u{043B}', '\u{041B}'), ('\u{043C}', '\u{041C}'),
+        ('\u{043D}', '\u{041D}'), ('\u{043E}', '\u{041E}'), ('\u{043F}', '\u{041F}'),
+        ('\u{0440}', '\u{0420}'), ('\u{0441}', '\u{0421}'), ('\u{0442}', '\u{0422}'),
+        ('\u{0443}', '\u{0423}'), ('\u{0444}', '\u{0424}'), ('\u{0445}', '\u{0425}'),
+        ('\u{0446}', '\u{0426}'), ('\u{0447}', '\u{0427}'), ('\u{0448}', '\u{0428}'),
+        ('\u{0449}', '\u{0429}'), ('\u{044A}', '\u{042A}'), ('\u{044B}', '\u{042B}'),
+        ('\u{044C}', '\u{042C}'), ('\u{044D}', '\u{042D}'), ('\u{044E}', '\u{042E}'),
+        ('\u{044F}', '\u{042F}'), ('\u{0450}', '\u{0400}'), ('\u{0451}', '\u{0401}'),
+        ('\u{0452}', '\u{0402}'), ('\u{0453}', '\u{0403}'), ('\u{0454}', '\u{0404}'),
+        ('\u{0455}', '\u{0405}'), ('\u{0456}', '\u{0406}'), ('\u{0457}', '\u{0407}'),
+        ('\u{0458}', '\u{0408}'), ('\u{0459}', '\u{0409}'), ('\u{045A}', '\u{040A}'),
+        ('\u{045B}', '\u{040B}'), ('\u{045C}', '\u{040C}'), ('\u{045D}', '\u{040D}'),
+        ('\u{045E}', '\u{040E}'), ('\u{045F}', '\u{040F}'), ('\u{0461}', '\u{0460}'),
+        ('\u{0463}', '\u{0462}'), ('\u{0465}', '\u{0464}'), ('\u{0467}', '\u{0466}'),
+        ('\u{0469}', '\u{0468}'), ('\u{046B}', '\u{046A}'), ('\u{046D}', '\u{046C}'),
+        ('\u{046F}', '\u{046E}'), ('\u{0471}', '\u{0470}'), ('\u{0473}', '\u{0472}'),
+        ('\u{0475}', '\u{0474}'), ('\u{0477}', '\u{0476}'), ('\u{0479}', '\u{0478}'),
+        ('\u{047B}', '\u{047A}'), ('\u{047D}', '\u{047C}'), ('\u{047F}', '\u{047E}'),
+        ('\u{0481}', '\u{0480}'), ('\u{048B}', '\u{048A}'), ('\u{048D}', '\u{048C}'),
+        ('\u{048F}', '\u{048E}'), ('\u{0491}', '\u{0490}'), ('\u{0493}', '\u{0492}'),
+        ('\u{0495}', '\u{0494}'), ('\u{0497}', '\u{0496}'), ('\u{0499}', '\u{0498}'),
+        ('\u{049B}', '\u{049A}'), ('\u{049D}', '\u{049C}'), ('\u{049F}', '\u{049E}'),
+        ('\u{04A1}', '\u{04A0}'), ('\u{04A3}', '\u{04A2}'), ('\u{04A5}', '\u{04A4}'),
+        ('\u{04A7}', '\u{04A6}'), ('\u{04A9}', '\u{04A8}'), ('\u{04AB}', '\u{04AA}'),
+        ('\u{04AD}', '\u{04AC}'), ('\u{04AF}', '\u{04AE}'), ('\u{04B1}', '\u{04B0}'),
+        ('\u{04B3}', '\u{04B2}'), ('\u{04B5}', '\u{04B4}'), ('\u{04B7}', '\u{04B6}'),
+        ('\u{04B9}', '\u{04B8}'), ('\u{04BB}', '\u{04BA}'), ('\u{04BD}', '\u{04BC}'),
+        ('\u{04BF}', '\u{04BE}'), ('\u{04C2}', '\u{04C1}'), ('\u{04C4}', '\u{04C3}'),
+        ('\u{04C6}', '\u{04C5}'), ('\u{04C8}', '\u{04C7}'), ('\u{04CA}', '\u{04C9}'),
+        ('\u{04CC}', '\u{04CB}'), ('\u{04CE}', '\u{04CD}'), ('\u{04CF}', '\u{04C0}'),
+        ('\u{04D1}', '\u{04D0}'), ('\u{04D3}', '\u{04D2}'), ('\u{04D5}', '\u{04D4}'),
+        ('\u{04D7}', '\u{04D6}'), ('\u{04D9}', '\u{04D8}'), ('\u{04DB}', '\u{04DA}'),
+        ('\u{04DD}', '\u{04DC}'), ('\u{04DF}', '\u{04DE}'), ('\u{04E1}', '\u{04E0}'),
+        ('\u{04E3}', '\u{04E2}'), ('\u{04E5}', '\u{04E4}'), ('\u{04E7}', '\u{04E6}'),
+        ('\u{04E9}', '\u{04E8}'), ('\u{04EB}', '\u{04EA}'), ('\u{04ED}', '\u{04EC}'),
+        ('\u{04EF}', '\u{04EE}'), ('\u{04F1}', '\u{04F0}'), ('\u{04F3}', '\u{04F2}'),
+        ('\u{04F5}', '\u{04F4}'), ('\u{04F7}', '\u{04F6}'), ('\u{04F9}', '\u{04F8}'),
+        ('\u{04FB}', '\u{04FA}'), ('\u{04FD}', '\u{04FC}'), ('\u{04FF}', '\u{04FE}'),
+        ('\u{0501}', '\u{0500}'), ('\u{0503}', '\u{0502}'), ('\u{0505}', '\u{0504}'),
+        ('\u{0507}', '\u{0506}'), ('\u{0509}', '\u{0508}'), ('\u{050B}', '\u{050A}'),
+        ('\u{050D}', '\u{050C}'), ('\u{050F}', '\u{050E}'), ('\u{0511}', '\u{0510}'),
+        ('\u{0513}', '\u{0512}'), ('\u{0515}', '\u{0514}'), ('\u{0517}', '\u{0516}'),
+        ('\u{0519}', '\u{0518}'), ('\u{051B}', '\u{051A}'), ('\u{051D}', '\u{051C}'),
+        ('\u{051F}', '\u{051E}'), ('\u{0521}', '\u{0520}'), ('\u{0523}', '\u{0522}'),
+        ('\u{0525}', '\u{0524}'), ('\u{0527}', '\u{0526}'), ('\u{0529}', '\u{0528}'),
+        ('\u{052B}', '\u{052A}'), ('\u{052D}', '\u{052C}'), ('\u{052F}', '\u{052E}'),
+        ('\u{0561}', '\u{0531}'), ('\u{0562}', '\u{0532}'), ('\u{0563}', '\u{0533}'),
+        ('\u{0564}', '\u{0534}'), ('\u{0565}', '\u{0535}'), ('\u{0566}', '\u{0536}'),
+        ('\u{0567}', '\u{0537}'), ('\u{0568}', '\u{0538}'), ('\u{0569}', '\u{0539}'),
+        ('\u{056A}', '\u{053A}'), ('\u{056B}', '\u{053B}'), ('\u{056C}', '\u{053C}'),
+        ('\u{056D}', '\u{053D}'), ('\u{056E}', '\u{053E}'), ('\u{056F}', '\u{053F}'),
+        ('\u{0570}', '\u{0540}'), ('\u{0571}', '\u{0541}'), ('\u{0572}', '\u{0542}'),
+        ('\u{0573}', '\u{0543}'), ('\u{0574}', '\u{0544}'), ('\u{0575}', '\u{0545}'),
+        ('\u{0576}', '\u{0546}'), ('\u{0577}', '\u{0547}'), ('\u{0578}', '\u{0548}'),
+        ('\u{0579}', '\u{0549}'), ('\u{057A}', '\u{054A}'), ('\u{057B}', '\u{054B}'),
+        ('\u{057C}', '\u{054C}'), ('\u{057D}', '\u{054D}'), ('\u{057E}', '\u{054E}'),
+        ('\u{057F}', '\u{054F}'), ('\u{0580}', '\u{0550}'), ('\u{0581}', '\u{0551}'),
+        ('\u{0582}', '\u{0552}'), ('\u{0583}', '\u{0553}'), ('\u{0584}', '\u{0554}'),
+        ('\u{0585}', '\u{0555}'), ('\u{0586}', '\u{0556}'), ('\u{13F8}', '\u{13F0}'),
+        ('\u{13F9}', '\u{13F1}'), ('\u{13FA}', '\u{13F2}'), ('\u{13FB}', '\u{13F3}'),
+        ('\u{13FC}', '\u{13F4}'), ('\u{13FD}', '\u{13F5}'), ('\u{1C80}', '\u{0412}'),
+        ('\u{1C81}', '\u{0414}'), ('\u{1C82}', '\u{041E}'), ('\u{1C83}', '\u{0421}'),
+        ('\u{1C84}', '\u{0422}'), ('\u{1C85}', '\u{0422}'), ('\u{1C86}', '\u{042A}'),
+        ('\u{1C87}', '\u{0462}'), ('\u{1C88}', '\u{A64A}'), ('\u{1C8A}', '\u{1C89}'),
+        ('\u{1D79}', '\u{A77D}'), ('\u{1D7D}', '\u{2C63}'), ('\u{1D8E}', '\u{A7C6}'),
+        ('\u{1E01}', '\u{1E00}'), ('\u{1E03}', '\u{1E02}'), ('\u{1E05}', '\u{1E04}'),
+        ('\u{1E07}', '\u{1E06}'), ('\u{1E09}', '\u{1E08}'), ('\u{1E0B}', '\u{1E0A}'),
+        ('\u{1E0D}', '\u{1E0C}'), ('\u{1E0F}', '\u{1E0E}'), ('\u{1E11}', '\u{1E10}'),
+        ('\u{1E13}', '\u{1E12}'), ('\u{1E15}', '\u{1E14}'), ('\u{1E17}', '\u{1E16}'),
+        ('\u{1E19}', '\u{1E18}'), ('\u{1E1B}', '\u{1E1A}'), ('\u{1E1D}', '\u{1E1C}'),
+        ('\u{1E1F}', '\u{1E1E}'), ('\u{1E21}', '\u{1E20}'), ('\u{1E23}', '\u{1E22}'),
+        ('\u{1E25}', '\u{1E24}'), ('\u{1E27}', '\u{1E26}'), ('\u{1E29}', '\u{1E28}'),
+        ('\u{1E2B}', '\u{1E2A}'), ('\u{1E2D}', '\u{1E2C}'), ('\u{1E2F}', '\u{1E2E}'),
+        ('\u{1E31}', '\u{1E30}'), ('\u{1E33}', '\u{1E32}'), ('\u{1E35}', '\u{1E34}'),
+        ('\u{1E37}', '\u{1E36}'), ('\u{1E39}', '\u{1E38}'), ('\u{1E3B}', '\u{1E3A}'),
+        ('\u{1E3D}', '\u{1E3C}'), ('\u{1E3F}', '\u{1E3E}'), ('\u{1E41}', '\u{1E40}'),
+        ('\u{1E43}', '\u{1E42}'), ('\u{1E45}', '\u{1E44}'), ('\u{1E47}', '\u{1E46}'),
+        ('\u{1E49}', '\u{1E48}'), ('\u{1E4B}', '\u{1E4A}'), ('\u{1E4D}', '\u{1E4C}'),
+        ('\u{1E4F}', '\u{1E4E}'), ('\u{1E51}', '\u{1E50}'), ('\u{1E53}', '\u{1E52}'),
+        ('\u{1E55}', '\u{1E54}'), ('\u{1E57}', '\u{1E56}'), ('\u{1E59}', '\u{1E58}'),
+        ('\u{1E5B}', '\u{1E5A}'), ('\u{1E5D}', '\u{1E5C}'), ('\u{1E5F}', '\u{1E5E}'),
+        ('\u{1E61}', '\u{1E60}'), ('\u{1E63}', '\u{1E62}'), ('\u{1E65}', '\u{1E64}'),
+        ('\u{1E67}', '\u{1E66}'), ('\u{1E69}', '\u{1E68}'), ('\u{1E6B}', '\u{1E6A}'),
+        ('\u{1E6D}', '\u{1E6C}'), ('\u{1E6F}', '\u{1E6E}'), ('\u{1E71}', '\u{1E70}'),
+        ('\u{1E73}', '\u{1E72}'), ('\u{1E75}', '\u{1E74}'), ('\u{1E77}', '\u{1E76}'),
+        ('\u{1E79}', '\u{1E78}'), ('\u{1E7B}', '\u{1E7A}'), ('\u{1E7D}', '\u{1E7C}'),
+        ('\u{1E7F}', '\u{1E7E}'), ('\u{1E81}', '\u{1E80}'), ('\u{1E83}', '\u{1E82}'),
+        ('\u{1E85}', '\u{1E84}'), ('\u{1E87}', '\u{1E86}'), ('\u{1E89}', '\u{1E88}'),
+        ('\u{1E8B}', '\u{1E8A}'), ('\u{1E8D}', '\u{1E8C}'), ('\u{1E8F}', '\u{1E8E}'),
+        ('\u{1E91}', '\u{1E90}'), ('\u{1E93}', '\u{1E92}'), ('\u{1E95}', '\u{1E94}'),
+        ('\u{1E9B}', '\u{1E60}'), ('\u{1EA1}', '\u{1EA0}'), ('\u{1EA3}', '\u{1EA2}'),
+        ('\u{1EA5}', '\u{1EA4}'), ('\u{1EA7}', '\u{1EA6}'), ('\u{1EA9}', '\u{1EA8}'),
+        ('\u{1EAB}', '\u{1EAA}'), ('\u{1EAD}', '\u{1EAC}'), ('\u{1EAF}', '\u{1EAE}'),
+        ('\u{1EB1}', '\u{1EB0}'), ('\u{1EB3}', '\u{1EB2}'), ('\u{1EB5}', '\u{1EB4}'),
+        ('\u{1EB7}', '\u{1EB6}'), ('\u{1EB9}', '\u{1EB8}'), ('\u{1EBB}', '\u{1EBA}'),
+        ('\u{1EBD}', '\u{1EBC}'), ('\u{1EBF}', '\u{1EBE}'), ('\u{1EC1}', '\u{1EC0}'),
+        ('\u{1EC3}', '\u{1EC2}'), ('\u{1EC5}', '\u{1EC4}'), ('\u{1EC7}', '\u{1EC6}'),
+        ('\u{1EC9}', '\u{1EC8}'), ('\u{1ECB}', '\u{1ECA}'), ('\u{1ECD}', '\u{1ECC}'),
+        ('\u{1ECF}', '\u{1ECE}'), ('\u{1ED1}', '\u{1ED0}'), ('\u{1ED3}', '\u{1ED2}'),
+        ('\u{1ED5}', '\u{1ED4}'), ('\u{1ED7}', '\u{1ED6}'), ('\u{1ED9}', '\u{1ED8}'),
+        ('\u{1EDB}', '\u{1EDA}'), ('\u{1EDD}', '\u{1EDC}'), ('\u{1EDF}', '\u{1EDE}'),
+        ('\u{1EE1}', '\u{1EE0}'), ('\u{1EE3}', '\u{1EE2}'), ('\u{1EE5}', '\u{1EE4}'),
+        ('\u{1EE7}', '\u{1EE6}'), ('\u{1EE9}', '\u{1EE8}'), ('\u{1EEB}', '\u{1EEA}'),
+        ('\u{1EED}', '\u{1EEC}'), ('\u{1EEF}', '\u{1EEE}'), ('\u{1EF1}', '\u{1EF0}'),
+        ('\u{1EF3}', '\u{1EF2}'), ('\u{1EF5}', '\u{1EF4}'), ('\u{1EF7}', '\u{1EF6}'),
+        ('\u{1EF9}', '\u{1EF8}'), ('\u{1EFB}', '\u{1EFA}'), ('\u{1EFD}', '\u{1EFC}'),
+        ('\u{1EFF}', '\u{1EFE}'), ('\u{1F00}', '\u{1F08}'), ('\u{1F01}', '\u{1F09}'),
+        ('\u{1F02}', '\u{1F0A}'), ('\u{1F03}', '\u{1F0B}'), ('\u{1F04}', '\u{1F0C}'),
+        ('\u{1F05}', '\u{1F0D}'), ('\u{1F06}', '\u{1F0E}'), ('\u{1F07}', '\u{1F0F}'),
+        ('\u{1F10}', '\u{1F18}'), ('\u{1F11}', '\u{1F19}'), ('\u{1F12}', '\u{1F1A}'),
+        ('\u{1F13}', '\u{1F1B}'), ('\u{1F14}', '\u{1F1C}'), ('\u{1F15}', '\u{1F1D}'),
+        ('\u{1F20}', '\u{1F28}'), ('\u{1F21}', '\u{1F29}'), ('\u{1F22}', '\u{1F2A}'),
+        ('\u{1F23}', '\u{1F2B}'), ('\u{1F24}', '\u{1F2C}'), ('\u{1F25}', '\u{1F2D}'),
+        ('\u{1F26}', '\u{1F2E}'), ('\u{1F27}', '\u{1F2F}'), ('\u{1F30}', '\u{1F38}'),
+        ('\u{1F31}', '\u{1F39}'), ('\u{1F32}', '\u{1F3A}'), ('\u{1F33}', '\u{1F3B}'),
+        ('\u{1F34}', '\u{1F3C}'), ('\u{1F35}', '\u{1F3D}'), ('\u{1F36}', '\u{1F3E}'),
+        ('\u{1F37}', '\u{1F3F}'), ('\u{1F40}', '\u{1F48}'), ('\u{1F41}', '\u{1F49}'),
+        ('\u{1F42}', '\u{1F4A}'), ('\u{1F43}', '\u{1F4B}'), ('\u{1F44}', '\u{1F4C}'),
+        ('\u{1F45}', '\u{1F4D}'), ('\u{1F51}', '\u{1F59}'), ('\u{1F53}', '\u{1F5B}'),
+        ('\u{1F55}', '\u{1F5D}'), ('\u{1F57}', '\u{1F5F}'), ('\u{1F60}', '\u{1F68}'),
+        ('\u{1F61}', '\u{1F69}'), ('\u{1F62}', '\u{1F6A}'), ('\u{1F63}', '\u{1F6B}'),
+        ('\u{1F64}', '\u{1F6C}'), ('\u{1F65}', '\u{1F6D}'), ('\u{1F66}', '\u{1F6E}'),
+        ('\u{1F67}', '\u{1F6F}'), ('\u{1F70}', '\u{1FBA}'), ('\u{1F71}', '\u{1FBB}'),
+        ('\u{1F72}', '\u{1FC8}'), ('\u{1F73}', '\u{1FC9}'), ('\u{1F74}', '\u{1FCA}'),
+        ('\u{1F75}', '\u{1FCB}'), ('\u{1F76}', '\u{1FDA}'), ('\u{1F77}', '\u{1FDB}'),
+        ('\u{1F78}', '\u{1FF8}'), ('\u{1F79}', '\u{1FF9}'), ('\u{1F7A}', '\u{1FEA}'),
+        ('\u{1F7B}', '\u{1FEB}'), ('\u{1F7C}', '\u{1FFA}'), ('\u{1F7D}', '\u{1FFB}'),
+        ('\u{1F80}', '\u{1F88}'), ('\u{1F81}', '\u{1F89}'), ('\u{1F82}', '\u{1F8A}'),
+        ('\u{1F83}', '\u{1F8B}'), ('\u{1F84}', '\u{1F8C}'), ('\u{1F85}', '\u{1F8D}'),
+        ('\u{1F86}', '\u{1F8E}'), ('\u{1F87}', '\u{1F8F}'), ('\u{1F90}', '\u{1F98}'),
+        ('\u{1F91}', '\u{1F99}'), ('\u{1F92}', '\u{1F9A}'), ('\u{1F93}', '\u{1F9B}'),
+        ('\u{1F94}', '\u{1F9C}'), ('\u{1F95}', '\u{1F9D}'), ('\u{1F96}', '\u{1F9E}'),
+        ('\u{1F97}', '\u{1F9F}'), ('\u{1FA0}', '\u{1FA8}'), ('\u{1FA1}', '\u{1FA9}'),
+        ('\u{1FA2}', '\u{1FAA}'), ('\u{1FA3}', '\u{1FAB}'), ('\u{1FA4}', '\u{1FAC}'),
+        ('\u{1FA5}', '\u{1FAD}'), ('\u{1FA6}', '\u{1FAE}'), ('\u{1FA7}', '\u{1FAF}'),
+        ('\u{1FB0}', '\u{1FB8}'), ('\u{1FB1}', '\u{1FB9}'), ('\u{1FB3}', '\u{1FBC}'),
+        ('\u{1FBE}', '\u{0399}'), ('\u{1FC3}', '\u{1FCC}'), ('\u{1FD0}', '\u{1FD8}'),
+        ('\u{1FD1}', '\u{1FD9}'), ('\u{1FE0}', '\u{1FE8}'), ('\u{1FE1}', '\u{1FE9}'),
+        ('\u{1FE5}', '\u{1FEC}'), ('\u{1FF3}', '\u{1FFC}'), ('\u{214E}', '\u{2132}'),
+        ('\u{2170}', '\u{2160}'), ('\u{2171}', '\u{2161}'), ('\u{2172}', '\u{2162}'),
+        ('\u{2173}', '\u{2163}'), ('\u{2174}', '\u{2164}'), ('\u{2175}', '\u{2165}'),
+        ('\u{2176}', '\u{2166}'), ('\u{2177}', '\u{2167}'), ('\u{2178}', '\u{2168}'),
+        ('\u{2179}', '\u{2169}'), ('\u{217A}', '\u{216A}'), ('\u{217B}', '\u{216B}'),
+        ('\u{217C}', '\u{216C}'), ('\u{217D}', '\u{216D}'), ('\u{217E}', '\u{216E}'),
+        ('\u{217F}', '\u{216F}'), ('\u{2184}', '\u{2183}'), ('\u{24D0}', '\u{24B6}'),
+        ('\u{24D1}', '\u{24B7}'), ('\u{24D2}', '\u{24B8}'), ('\u{24D3}', '\u{24B9}'),
+        ('\u{24D4}', '\u{24BA}'), ('\u{24D5}', '\u{24BB}'), ('\u{24D6}', '\u{24BC}'),
+        ('\u{24D7}', '\u{24BD}'), ('\u{24D8}', '\u{24BE}'), ('\u{24D9}', '\u{24BF}'),
+        ('\u{24DA}', '\u{24C0}'), ('\u{24DB}', '\u{24C1}'), ('\u{24DC}', '\u{24C2}'),
+        ('\u{24DD}', '\u{24C3}'), ('\u{24DE}', '\u{24C4}'), ('\u{24DF}', '\u{24C5}'),
+        ('\u{24E0}', '\u{24C6}'), ('\u{24E1}', '\u{24C7}'), ('\u{24E2}', '\u{24C8}'),
+        ('\u{24E3}', '\u{24C9}'), ('\u{24E4}', '\u{24CA}'), ('\u{24E5}', '\u{24CB}'),
+        ('\u{24E6}', '\u{24CC}'), ('\u{24E7}', '\u{24CD}'), ('\u{24E8}', '\u{24CE}'),
+        ('\u{24E9}', '\u{24CF}'), ('\u{2C30}', '\u{2C00}'), ('\u{2C31}', '\u{2C01}'),
+        ('\u{2C32}', '\u{2C02}'), ('\u{2C33}', '\u{2C03}'), ('\u{2C34}', '\u{2C04}'),
+        ('\u{2C35}', '\u{2C05}'), ('\u{2C36}', '\u{2C06}'), ('\u{2C37}', '\u{2C07}'),
+        ('\u{2C38}', '\u{2C08}'), ('\u{2C39}', '\u{2C09}'), ('\u{2C3A}', '\u{2C0A}'),
+        ('\u{2C3B}', '\u{2C0B}'), ('\u{2C3C}', '\u{2C0C}'), ('\u{2C3D}', '\u{2C0D}'),
+        ('\u{2C3E}', '\u{2C0E}'), ('\u{2C3F}', '\u{2C0F}'), ('\u{2C40}', '\u{2C10}'),
+        ('\u{2C41}', '\u{2C11}'), ('\u{2C42}', '\u{2C12}'), ('\u{2C43}', '\u{2C13}'),
+        ('\u{2C44}', '\u{2C14}'), ('\u{2C45}', '\u{2C15}'), ('\u{2C46}', '\u{2C16}'),
+        ('\u{2C47}', '\u{2C17}'), ('\u{2C48}', '\u{2C18}'), ('\u{2C49}', '\u{2C19}'),
+        ('\u{2C4A}', '\u{2C1A}'), ('\u{2C4B}', '\u{2C1B}'), ('\u{2C4C}', '\u{2C1C}'),
+        ('\u{2C4D}', '\u{2C1D}'), ('\u{2C4E}', '\u{2C1E}'), ('\u{2C4F}', '\u{2C1F}'),
+        ('\u{2C50}', '\u{2C20}'), ('\u{2C51}', '\u{2C21}'), ('\u{2C52}', '\u{2C22}'),
+        ('\u{2C53}', '\u{2C23}'), ('\u{2C54}', '\u{2C24}'), ('\u{2C55}', '\u{2C25}'),
+        ('\u{2C56}', '\u{2C26}'), ('\u{2C57}', '\u{2C27}'), ('\u{2C58}', '\u{2C28}'),
+        ('\u{2C59}', '\u{2C29}'), ('\u{2C5A}', '\u{2C2A}'), ('\u{2C5B}', '\u{2C2B}'),
+        ('\u{2C5C}', '\u{2C2C}'), ('\u{2C5D}', '\u{2C2D}'), ('\u{2C5E}', '\u{2C2E}'),
+        ('\u{2C5F}', '\u{2C2F}'), ('\u{2C61}', '\u{2C60}'), ('\u{2C65}', '\u{023A}'),
+        ('\u{2C66}', '\u{023E}'), ('\u{2C68}', '\u{2C67}'), ('\u{2C6A}', '\u{2C69}'),
+        ('\u{2C6C}', '\u{2C6B}'), ('\u{2C73}', '\u{2C72}'), ('\u{2C76}', '\u{2C75}'),
+        ('\u{2C81}', '\u{2C80}'), ('\u{2C83}', '\u{2C82}'), ('\u{2C85}', '\u{2C84}'),
+        ('\u{2C87}', '\u{2C86}'), ('\u{2C89}', '\u{2C88}'), ('\u{2C8B}', '\u{2C8A}'),
+        ('\u{2C8D}', '\u{2C8C}'), ('\u{2C8F}', '\u{2C8E}'), ('\u{2C91}', '\u{2C90}'),
+        ('\u{2C93}', '\u{2C92}'), ('\u{2C95}', '\u{2C94}'), ('\u{2C97}', '\u{2C96}'),
+        ('\u{2C99}', '\u{2C98}'), ('\u{2C9B}', '\u{2C9A}'), ('\u{2C9D}', '\u{2C9C}'),
+        ('\u{2C9F}', '\u{2C9E}'), ('\u{2CA1}', '\u{2CA0}'), ('\u{2CA3}', '\u{2CA2}'),
+        ('\u{2CA5}', '\u{2CA4}'), ('\u{2CA7}', '\u{2CA6}'), ('\u{2CA9}', '\u{2CA8}'),
+        ('\u{2CAB}', '\u{2CAA}'), ('\u{2CAD}', '\u{2CAC}'), ('\u{2CAF}', '\u{2CAE}'),
+        ('\u{2CB1}', '\u{2CB0}'), ('\u{2CB3}', '\u{2CB2}'), ('\u{2CB5}', '\u{2CB4}'),
+        ('\u{2CB7}', '\u{2CB6}'), ('\u{2CB9}', '\u{2CB8}'), ('\u{2CBB}', '\u{2CBA}'),
+        ('\u{2CBD}', '\u{2CBC}'), ('\u{2CBF}', '\u{2CBE}'), ('\u{2CC1}', '\u{2CC0}'),
+        ('\u{2CC3}', '\u{2CC2}'), ('\u{2CC5}', '\u{2CC4}'), ('\u{2CC7}', '\u{2CC6}'),
+        ('\u{2CC9}', '\u{2CC8}'), ('\u{2CCB}', '\u{2CCA}'), ('\u{2CCD}', '\u{2CCC}'),
+        ('\u{2CCF}', '\u{2CCE}'), ('\u{2CD1}', '\u{2CD0}'), ('\u{2CD3}', '\u{2CD2}'),
+        ('\u{2CD5}', '\u{2CD4}'), ('\u{2CD7}', '\u{2CD6}'), ('\u{2CD9}', '\u{2CD8}'),
+        ('\u{2CDB}', '\u{2CDA}'), ('\u{2CDD}', '\u{2CDC}'), ('\u{2CDF}', '\u{2CDE}'),
+        ('\u{2CE1}', '\u{2CE0}'), ('\u{2CE3}', '\u{2CE2}'), ('\u{2CEC}', '\u{2CEB}'),
+        ('\u{2CEE}', '\u{2CED}'), ('\u{2CF3}', '\u{2CF2}'), ('\u{2D00}', '\u{10A0}'),
+        ('\u{2D01}', '\u{10A1}'), ('\u{2D02}', '\u{10A2}'), ('\u{2D03}', '\u{10A3}'),
+        ('\u{2D04}', '\u{10A4}'), ('\u{2D05}', '\u{10A5}'), ('\u{2D06}', '\u{10A6}'),
+        ('\u{2D07}', '\u{10A7}'), ('\u{2D08}', '\u{10A8}'), ('\u{2D09}', '\u{10A9}'),
+        ('\u{2D0A}', '\u{10AA}'), ('\u{2D0B}', '\u{10AB}'), ('\u{2D0C}', '\u{10AC}'),
+        ('\u{2D0D}', '\u{10AD}'), ('\u{2D0E}', '\u{10AE}'), ('\u{2D0F}', '\u{10AF}'),
+        ('\u{2D10}', '\u{10B0}'), ('\u{2D11}', '\u{10B1}'), ('\u{2D12}', '\u{10B2}'),
+        ('\u{2D13}', '\u{10B3}'), ('\u{2D14}', '\u{10B4}'), ('\u{2D15}', '\u{10B5}'),
+        ('\u{2D16}', '\u{10B6}'), ('\u{2D17}', '\u{10B7}'), ('\u{2D18}', '\u{10B8}'),
+        ('\u{2D19}', '\u{10B9}'), ('\u{2D1A}', '\u{10BA}'), ('\u{2D1B}', '\u{10BB}'),
+        ('\u{2D1C}', '\u{10BC}'), ('\u{2D1D}', '\u{10BD}'), ('\u{2D1E}', '\u{10BE}'),
+        ('\u{2D1F}', '\u{10BF}'), ('\u{2D20}', '\u{10C0}'), ('\u{2D21}', '\u{10C1}'),
+        ('\u{2D22}', '\u{10C2}'), ('\u{2D23}', '\u{10C3}'), ('\u{2D24}', '\u{10C4}'),
+        ('\u{2D25}', '\u{10C5}'), ('\u{2D27}', '\u{10C7}'), ('\u{2D2D}', '\u{10CD}'),
+        ('\u{A641}', '\u{A640}'), ('\u{A643}', '\u{A642}'), ('\u{A645}', '\u{A644}'),
+        ('\u{A647}', '\u{A646}'), ('\u{A649}', '\u{A648}'), ('\u{A64B}', '\u{A64A}'),
+        ('\u{A64D}', '\u{A64C}'), ('\u{A64F}', '\u{A64E}'), ('\u{A651}', '\u{A650}'),
+        ('\u{A653}', '\u{A652}'), ('\u{A655}', '\u{A654}'), ('\u{A657}', '\u{A656}'),
+        ('\u{A659}', '\u{A658}'), ('\u{A65B}', '\u{A65A}'), ('\u{A65D}', '\u{A65C}'),
+        ('\u{A65F}', '\u{A65E}'), ('\u{A661}', '\u{A660}'), ('\u{A663}', '\u{A662}'),
+        ('\u{A665}', '\u{A664}'), ('\u{A667}', '\u{A666}'), ('\u{A669}', '\u{A668}'),
+        ('\u{A66B}', '\u{A66A}'), ('\u{A66D}', '\u{A66C}'), ('\u{A681}', '\u{A680}'),
+        ('\u{A683}', '\u{A682}'), ('\u{A685}', '\u{A684}'), ('\u{A687}', '\u{A686}'),
+        ('\u{A689}', '\u{A688}'), ('\u{A68B}', '\u{A68A}'), ('\u{A68D}', '\u{A68C}'),
+        ('\u{A68F}', '\u{A68E}'), ('\u{A691}', '\u{A690}'), ('\u{A693}', '\u{A692}'),
+        ('\u{A695}', '\u{A694}'), ('\u{A697}', '\u{A696}'), ('\u{A699}', '\u{A698}'),
+        ('\u{A69B}', '\u{A69A}'), ('\u{A723}', '\u{A722}'), ('\u{A725}', '\u{A724}'),
+        ('\u{A727}', '\u{A726}'), ('\u{A729}', '\u{A728}'), ('\u{A72B}', '\u{A72A}'),
+        ('\u{A72D}', '\u{A72C}'), ('\u{A72F}', '\u{A72E}'), ('\u{A733}', '\u{A732}'),
+        ('\u{A735}', '\u{A734}'), ('\u{A737}', '\u{A736}'), ('\u{A739}', '\u{A738}'),
+        ('\u{A73B}', '\u{A73A}'), ('\u{A73D}', '\u{A73C}'), ('\u{A73F}', '\u{A73E}'),
+        ('\u{A741}', '\u{A740}'), ('\u{A743}', '\u{A742}'), ('\u{A745}', '\u{A744}'),
+        ('\u{A747}', '\u{A746}'), ('\u{A749}', '\u{A748}'), ('\u{A74B}', '\u{A74A}'),
+        ('\u{A74D}', '\u{A74C}'), ('\u{A74F}', '\u{A74E}'), ('\u{A751}', '\u{A750}'),
+        ('\u{A753}', '\u{A752}'), ('\u{A755}', '\u{A754}'), ('\u{A757}', '\u{A756}'),
+        ('\u{A759}', '\u{A758}'), ('\u{A75B}', '\u{A75A}'), ('\u{A75D}', '\u{A75C}'),
+        ('\u{A75F}', '\u{A75E}'), ('\u{A761}', '\u{A760}'), ('\u{A763}', '\u{A762}'),
+        ('\u{A765}', '\u{A764}'), ('\u{A767}', '\u{A766}'), ('\u{A769}', '\u{A768}'),
+        ('\u{A76B}', '\u{A76A}'), ('\u{A76D}', '\u{A76C}'), ('\u{A76F}', '\u{A76E}'),
+        ('\u{A77A}', '\u{A779}'), ('\u{A77C}', '\u{A77B}'), ('\u{A77F}', '\u{A77E}'),
+        ('\u{A781}', '\u{A780}'), ('\u{A783}', '\u{A782}'), ('\u{A785}', '\u{A784}'),
+        ('\u{A787}', '\u{A786}'), ('\u{A78C}', '\u{A78B}'), ('\u{A791}', '\u{A790}'),
+        ('\u{A793}', '\u{A792}'), ('\u{A794}', '\u{A7C4}'), ('\u{A797}', '\u{A796}'),
+        ('\u{A799}', '\u{A798}'), ('\u{A79B}', '\u{A79A}'), ('\u{A79D}', '\u{A79C}'),
+        ('\u{A79F}', '\u{A79E}'), ('\u{A7A1}', '\u{A7A0}'), ('\u{A7A3}', '\u{A7A2}'),
+        ('\u{A7A5}', '\u{A7A4}'), ('\u{A7A7}', '\u{A7A6}'), ('\u{A7A9}', '\u{A7A8}'),
+        ('\u{A7B5}', '\u{A7B4}'), ('\u{A7B7}', '\u{A7B6}'), ('\u{A7B9}', '\u{A7B8}'),
+        ('\u{A7BB}', '\u{A7BA}'), ('\u{A7BD}', '\u{A7BC}'), ('\u{A7BF}', '\u{A7BE}'),
+        ('\u{A7C1}', '\u{A7C0}'), ('\u{A7C3}', '\u{A7C2}'), ('\u{A7C8}', '\u{A7C7}'),
+        ('\u{A7CA}', '\u{A7C9}'), ('\u{A7CD}', '\u{A7CC}'), ('\u{A7CF}', '\u{A7CE}'),
+        ('\u{A7D1}', '\u{A7D0}'), ('\u{A7D3}', '\u{A7D2}'), ('\u{A7D5}', '\u{A7D4}'),
+        ('\u{A7D7}', '\u{A7D6}'), ('\u{A7D9}', '\u{A7D8}'), ('\u{A7DB}', '\u{A7DA}'),
+        ('\u{A7F6}', '\u{A7F5}'), ('\u{AB53}', '\u{A7B3}'), ('\u{AB70}', '\u{13A0}'),
+        ('\u{AB71}', '\u{13A1}'), ('\u{AB72}', '\u{13A2}'), ('\u{AB73}', '\u{13A3}'),
+        ('\u{AB74}', '\u{13A4}'), ('\u{AB75}', '\u{13A5}'), ('\u{AB76}', '\u{13A6}'),
+        ('\u{AB77}', '\u{13A7}'), ('\u{AB78}', '\u{13A8}'), ('\u{AB79}', '\u{13A9}'),
+        ('\u{AB7A}', '\u{13AA}'), ('\u{AB7B}', '\u{13AB}'), ('\u{AB7C}', '\u{13AC}'),
+        ('\u{AB7D}', '\u{13AD}'), ('\u{AB7E}', '\u{13AE}'), ('\u{AB7F}', '\u{13AF}'),
+        ('\u{AB80}', '\u{13B0}'), ('\u{AB81}', '\u{13B1}'), ('\u{AB82}', '\u{13B2}'),
+        ('\u{AB83}', '\u{13B3}'), ('\u{AB84}', '\u{13B4}'), ('\u{AB85}', '\u{13B5}'),
+        ('\u{AB86}', '\u{13B6}'), ('\u{AB87}', '\u{13B7}'), ('\u{AB88}', '\u{13B8}'),
+        ('\u{AB89}', '\u{13B9}'), ('\u{AB8A}', '\u{13BA}'), ('\u{AB8B}', '\u{13BB}'),
+        ('\u{AB8C}', '\u{13BC}'), ('\u{AB8D}', '\u{13BD}'), ('\u{AB8E}', '\u{13BE}'),
+        ('\u{AB8F}', '\u{13BF}'), ('\u{AB90}', '\u{13C0}'), ('\u{AB91}', '\u{13C1}'),
+        ('\u{AB92}', '\u{13C2}'), ('\u{AB93}', '\u{13C3}'), ('\u{AB94}', '\u{13C4}'),
+        ('\u{AB95}', '\u{13C5}'), ('\u{AB96}', '\u{13C6}'), ('\u{AB97}', '\u{13C7}'),
+        ('\u{AB98}', '\u{13C8}'), ('\u{AB99}', '\u{13C9}'), ('\u{AB9A}', '\u{13CA}'),
+        ('\u{AB9B}', '\u{13CB}'), ('\u{AB9C}', '\u{13CC}'), ('\u{AB9D}', '\u{13CD}'),
+        ('\u{AB9E}', '\u{13CE}'), ('\u{AB9F}', '\u{13CF}'), ('\u{ABA0}', '\u{13D0}'),
+        ('\u{ABA1}', '\u{13D1}'), ('\u{ABA2}', '\u{13D2}'), ('\u{ABA3}', '\u{13D3}'),
+        ('\u{ABA4}', '\u{13D4}'), ('\u{ABA5}', '\u{13D5}'), ('\u{ABA6}', '\u{13D6}'),
+        ('\u{ABA7}', '\u{13D7}'), ('\u{ABA8}', '\u{13D8}'), ('\u{ABA9}', '\u{13D9}'),
+        ('\u{ABAA}', '\u{13DA}'), ('\u{ABAB}', '\u{13DB}'), ('\u{ABAC}', '\u{13DC}'),
+        ('\u{ABAD}', '\u{13DD}'), ('\u{ABAE}', '\u{13DE}'), ('\u{ABAF}', '\u{13DF}'),
+        ('\u{ABB0}', '\u{13E0}'), ('\u{ABB1}', '\u{13E1}'), ('\u{ABB2}', '\u{13E2}'),
+        ('\u{ABB3}', '\u{13E3}'), ('\u{ABB4}', '\u{13E4}'), ('\u{ABB5}', '\u{13E5}'),
+        ('\u{ABB6}', '\u{13E6}'), ('\u{ABB7}', '\u{13E7}'), ('\u{ABB8}', '\u{13E8}'),
+        ('\u{ABB9}', '\u{13E9}'), ('\u{ABBA}', '\u{13EA}'), ('\u{ABBB}', '\u{13EB}'),
+        ('\u{ABBC}', '\u{13EC}'), ('\u{ABBD}', '\u{13ED}'), ('\u{ABBE}', '\u{13EE}'),
+        ('\u{ABBF}', '\u{13EF}'), ('\u{FF41}', '\u{FF21}'), ('\u{FF42}', '\u{FF22}'),
+        ('\u{FF43}', '\u{FF23}'), ('\u{FF44}', '\u{FF24}'), ('\u{FF45}', '\u{FF25}'),
+        ('\u{FF46}', '\u{FF26}'), ('\u{FF47}', '\u{FF27}'), ('\u{FF48}', '\u{FF28}'),
+        ('\u{FF49}', '\u{FF29}'), ('\u{FF4A}', '\u{FF2A}'), ('\u{FF4B}', '\u{FF2B}'),
+        ('\u{FF4C}', '\u{FF2C}'), ('\u{FF4D}', '\u{FF2D}'), ('\u{FF4E}', '\u{FF2E}'),
+        ('\u{FF4F}', '\u{FF2F}'), ('\u{FF50}', '\u{FF30}'), ('\u{FF51}', '\u{FF31}'),
+        ('\u{FF52}', '\u{FF32}'), ('\u{FF53}', '\u{FF33}'), ('\u{FF54}', '\u{FF34}'),
+        ('\u{FF55}', '\u{FF35}'), ('\u{FF56}', '\u{FF36}'), ('\u{FF57}', '\u{FF37}'),
+        ('\u{FF58}', '\u{FF38}'), ('\u{FF59}', '\u{FF39}'), ('\u{FF5A}', '\u{FF3A}'),
+        ('\u{10428}', '\u{10400}'), ('\u{10429}', '\u{10401}'), ('\u{1042A}', '\u{10402}'),
+        ('\u{1042B}', '\u{10403}'), ('\u{1042C}', '\u{10404}'), ('\u{1042D}', '\u{10405}'),
+        ('\u{1042E}', '\u{10406}'), ('\u{1042F}', '\u{10407}'), ('\u{10430}', '\u{10408}'),
+        ('\u{10431}', '\u{10409}'), ('\u{10432}', '\u{1040A}'), ('\u{10433}', '\u{1040B}'),
+        ('\u{10434}', '\u{1040C}'), ('\u{10435}', '\u{1040D}'), ('\u{10436}', '\u{1040E}'),
+        ('\u{10437}', '\u{1040F}'), ('\u{10438}', '\u{10410}'), ('\u{10439}', '\u{10411}'),
+        ('\u{1043A}', '\u{10412}'), ('\u{1043B}', '\u{10413}'), ('\u{1043C}', '\u{10414}'),
+        ('\u{1043D}', '\u{10415}'), ('\u{1043E}', '\u{10416}'), ('\u{1043F}', '\u{10417}'),
+        ('\u{10440}', '\u{10418}'), ('\u{10441}', '\u{10419}'), ('\u{10442}', '\u{1041A}'),
+        ('\u{10443}', '\u{1041B}'), ('\u{10444}', '\u{1041C}'), ('\u{10445}', '\u{1041D}'),
+        ('\u{10446}', '\u{1041E}'), ('\u{10447}', '\u{1041F}'), ('\u{10448}', '\u{10420}'),
+        ('\u{10449}', '\u{10421}'), ('\u{1044A}', '\u{10422}'), ('\u{1044B}', '\u{10423}'),
+        ('\u{1044C}', '\u{10424}'), ('\u{1044D}', '\u{10425}'), ('\u{1044E}', '\u{10426}'),
+        ('\u{1044F}', '\u{10427}'), ('\u{104D8}', '\u{104B0}'), ('\u{104D9}', '\u{104B1}'),
+        ('\u{104DA}', '\u{104B2}'), ('\u{104DB}', '\u{104B3}'), ('\u{104DC}', '\u{104B4}'),
+        ('\u{104DD}', '\u{104B5}'), ('\u{104DE}', '\u{104B6}'), ('\u{104DF}', '\u{104B7}'),
+        ('\u{104E0}', '\u{104B8}'), ('\u{104E1}', '\u{104B9}'), ('\u{104E2}', '\u{104BA}'),
+        ('\u{104E3}', '\u{104BB}'), ('\u{104E4}', '\u{104BC}'), ('\u{104E5}', '\u{104BD}'),
+        ('\u{104E6}', '\u{104BE}'), ('\u{104E7}', '\u{104BF}'), ('\u{104E8}', '\u{104C0}'),
+        ('\u{104E9}', '\u{104C1}'), ('\u{104EA}', '\u{104C2}'), ('\u{104EB}', '\u{104C3}'),
+        ('\u{104EC}', '\u{104C4}'), ('\u{104ED}', '\u{104C5}'), ('\u{104EE}', '\u{104C6}'),
+        ('\u{104EF}', '\u{104C7}'), ('\u{104F0}', '\u{104C8}'), ('\u{104F1}', '\u{104C9}'),
+        ('\u{104F2}', '\u{104CA}'), ('\u{104F3}', '\u{104CB}'), ('\u{104F4}', '\u{104CC}'),
+        ('\u{104F5}', '\u{104CD}'), ('\u{104F6}', '\u{104CE}'), ('\u{104F7}', '\u{104CF}'),
+        ('\u{104F8}', '\u{104D0}'), ('\u{104F9}', '\u{104D1}'), ('\u{104FA}', '\u{104D2}'),
+        ('\u{104FB}', '\u{104D3}'), ('\u{10597}', '\u{10570}'), ('\u{10598}', '\u{10571}'),
+        ('\u{10599}', '\u{10572}'), ('\u{1059A}', '\u{10573}'), ('\u{1059B}', '\u{10574}'),
+        ('\u{1059C}', '\u{10575}'), ('\u{1059D}', '\u{10576}'), ('\u{1059E}', '\u{10577}'),
+        ('\u{1059F}', '\u{10578}'), ('\u{105A0}', '\u{10579}'), ('\u{105A1}', '\u{1057A}'),
+        ('\u{105A3}', '\u{1057C}'), ('\u{105A4}', '\u{1057D}'), ('\u{105A5}', '\u{1057E}'),
+        ('\u{105A6}', '\u{1057F}'), ('\u{105A7}', '\u{10580}'), ('\u{105A8}', '\u{10581}'),
+        ('\u{105A9}', '\u{10582}'), ('\u{105AA}', '\u{10583}'), ('\u{105AB}', '\u{10584}'),
+        ('\u{105AC}', '\u{10585}'), ('\u{105AD}', '\u{10586}'), ('\u{105AE}', '\u{10587}'),
+        ('\u{105AF}', '\u{10588}'), ('\u{105B0}', '\u{10589}'), ('\u{105B1}', '\u{1058A}'),
+        ('\u{105B3}', '\u{1058C}'), ('\u{105B4}', '\u{1058D}'), ('\u{105B5}', '\u{1058E}'),
+        ('\u{105B6}', '\u{1058F}'), ('\u{105B7}', '\u{10590}'), ('\u{105B8}', '\u{10591}'),
+        ('\u{105B9}', '\u{10592}'), ('\u{105BB}', '\u{10594}'), ('\u{105BC}', '\u{10595}'),
+        ('\u{10CC0}', '\u{10C80}'), ('\u{10CC1}', '\u{10C81}'), ('\u{10CC2}', '\u{10C82}'),
+        ('\u{10CC3}', '\u{10C83}'), ('\u{10CC4}', '\u{10C84}'), ('\u{10CC5}', '\u{10C85}'),
+        ('\u{10CC6}', '\u{10C86}'), ('\u{10CC7}', '\u{10C87}'), ('\u{10CC8}', '\u{10C88}'),
+        ('\u{10CC9}', '\u{10C89}'), ('\u{10CCA}', '\u{10C8A}'), ('\u{10CCB}', '\u{10C8B}'),
+        ('\u{10CCC}', '\u{10C8C}'), ('\u{10CCD}', '\u{10C8D}'), ('\u{10CCE}', '\u{10C8E}'),
+        ('\u{10CCF}', '\u{10C8F}'), ('\u{10CD0}', '\u{10C90}'), ('\u{10CD1}', '\u{10C91}'),
+        ('\u{10CD2}', '\u{10C92}'), ('\u{10CD3}', '\u{10C93}'), ('\u{10CD4}', '\u{10C94}'),
+        ('\u{10CD5}', '\u{10C95}'), ('\u{10CD6}', '\u{10C96}'), ('\u{10CD7}', '\u{10C97}'),
+        ('\u{10CD8}', '\u{10C98}'), ('\u{10CD9}', '\u{10C99}'), ('\u{10CDA}', '\u{10C9A}'),
+        ('\u{10CDB}', '\u{10C9B}'), ('\u{10CDC}', '\u{10C9C}'), ('\u{10CDD}', '\u{10C9D}'),
+        ('\u{10CDE}', '\u{10C9E}'), ('\u{10CDF}', '\u{10C9F}'), ('\u{10CE0}', '\u{10CA0}'),
+        ('\u{10CE1}', '\u{10CA1}'), ('\u{10CE2}', '\u{10CA2}'), ('\u{10CE3}', '\u{10CA3}'),
+        ('\u{10CE4}', '\u{10CA4}'), ('\u{10CE5}', '\u{10CA5}'), ('\u{10CE6}', '\u{10CA6}'),
+        ('\u{10CE7}', '\u{10CA7}'), ('\u{10CE8}', '\u{10CA8}'), ('\u{10CE9}', '\u{10CA9}'),
+        ('\u{10CEA}', '\u{10CAA}'), ('\u{10CEB}', '\u{10CAB}'), ('\u{10CEC}', '\u{10CAC}'),
+        ('\u{10CED}', '\u{10CAD}'), ('\u{10CEE}', '\u{10CAE}'), ('\u{10CEF}', '\u{10CAF}'),
+        ('\u{10CF0}', '\u{10CB0}'), ('\u{10CF1}', '\u{10CB1}'), ('\u{10CF2}', '\u{10CB2}'),
+        ('\u{10D70}', '\u{10D50}'), ('\u{10D71}', '\u{10D51}'), ('\u{10D72}', '\u{10D52}'),
+        ('\u{10D73}', '\u{10D53}'), ('\u{10D74}', '\u{10D54}'), ('\u{10D75}', '\u{10D55}'),
+        ('\u{10D76}', '\u{10D56}'), ('\u{10D77}', '\u{10D57}'), ('\u{10D78}', '\u{10D58}'),
+        ('\u{10D79}', '\u{10D59}'), ('\u{10D7A}', '\u{10D5A}'), ('\u{10D7B}', '\u{10D5B}'),
+        ('\u{10D7C}', '\u{10D5C}'), ('\u{10D7D}', '\u{10D5D}'), ('\u{10D7E}', '\u{10D5E}'),
+        ('\u{10D7F}', '\u{10D5F}'), ('\u{10D80}', '\u{10D60}'), ('\u{10D81}', '\u{10D61}'),
+        ('\u{10D82}', '\u{10D62}'), ('\u{10D83}', '\u{10D63}'), ('\u{10D84}', '\u{10D64}'),
+        ('\u{10D85}', '\u{10D65}'), ('\u{118C0}', '\u{118A0}'), ('\u{118C1}', '\u{118A1}'),
+        ('\u{118C2}', '\u{118A2}'), ('\u{118C3}', '\u{118A3}'), ('\u{118C4}', '\u{118A4}'),
+        ('\u{118C5}', '\u{118A5}'), ('\u{118C6}', '\u{118A6}'), ('\u{118C7}', '\u{118A7}'),
+        ('\u{118C8}', '\u{118A8}'), ('\u{118C9}', '\u{118A9}'), ('\u{118CA}', '\u{118AA}'),
+        ('\u{118CB}', '\u{118AB}'), ('\u{118CC}', '\u{118AC}'), ('\u{118CD}', '\u{118AD}'),
+        ('\u{118CE}', '\u{118AE}'), ('\u{118CF}', '\u{118AF}'), ('\u{118D0}', '\u{118B0}'),
+        ('\u{118D1}', '\u{118B1}'), ('\u{118D2}', '\u{118B2}'), ('\u{118D3}', '\u{118B3}'),
+        ('\u{118D4}', '\u{118B4}'), ('\u{118D5}', '\u{118B5}'), ('\u{118D6}', '\u{118B6}'),
+        ('\u{118D7}', '\u{118B7}'), ('\u{118D8}', '\u{118B8}'), ('\u{118D9}', '\u{118B9}'),
+        ('\u{118DA}', '\u{118BA}'), ('\u{118DB}', '\u{118BB}'), ('\u{118DC}', '\u{118BC}'),
+        ('\u{118DD}', '\u{118BD}'), ('\u{118DE}', '\u{118BE}'), ('\u{118DF}', '\u{118BF}'),
+        ('\u{16E60}', '\u{16E40}'), ('\u{16E61}', '\u{16E41}'), ('\u{16E62}', '\u{16E42}'),
+        ('\u{16E63}', '\u{16E43}'), ('\u{16E64}', '\u{16E44}'), ('\u{16E65}', '\u{16E45}'),
+        ('\u{16E66}', '\u{16E46}'), ('\u{16E67}', '\u{16E47}'), ('\u{16E68}', '\u{16E48}'),
+        ('\u{16E69}', '\u{16E49}'), ('\u{16E6A}', '\u{16E4A}'), ('\u{16E6B}', '\u{16E4B}'),
+        ('\u{16E6C}', '\u{16E4C}'), ('\u{16E6D}', '\u{16E4D}'), ('\u{16E6E}', '\u{16E4E}'),
+        ('\u{16E6F}', '\u{16E4F}'), ('\u{16E70}', '\u{16E50}'), ('\u{16E71}', '\u{16E51}'),
+        ('\u{16E72}', '\u{16E52}'), ('\u{16E73}', '\u{16E53}'), ('\u{16E74}', '\u{16E54}'),
+        ('\u{16E75}', '\u{16E55}'), ('\u{16E76}', '\u{16E56}'), ('\u{16E77}', '\u{16E57}'),
+        ('\u{16E78}', '\u{16E58}'), ('\u{16E79}', '\u{16E59}'), ('\u{16E7A}', '\u{16E5A}'),
+        ('\u{16E7B}', '\u{16E5B}'), ('\u{16E7C}', '\u{16E5C}'), ('\u{16E7D}', '\u{16E5D}'),
+        ('\u{16E7E}', '\u{16E5E}'), ('\u{16E7F}', '\u{16E5F}'), ('\u{16EBB}', '\u{16EA0}'),
+        ('\u{16EBC}', '\u{16EA1}'), ('\u{16EBD}', '\u{16EA2}'), ('\u{16EBE}', '\u{16EA3}'),
+        ('\u{16EBF}', '\u{16EA4}'), ('\u{16EC0}', '\u{16EA5}'), ('\u{16EC1}', '\u{16EA6}'),
+        ('\u{16EC2}', '\u{16EA7}'), ('\u{16EC3}', '\u{16EA8}'), ('\u{16EC4}', '\u{16EA9}'),
+        ('\u{16EC5}', '\u{16EAA}'), ('\u{16EC6}', '\u{16EAB}'), ('\u{16EC7}', '\u{16EAC}'),
+        ('\u{16EC8}', '\u{16EAD}'), ('\u{16EC9}', '\u{16EAE}'), ('\u{16ECA}', '\u{16EAF}'),
+        ('\u{16ECB}', '\u{16EB0}'), ('\u{16ECC}', '\u{16EB1}'), ('\u{16ECD}', '\u{16EB2}'),
+        ('\u{16ECE}', '\u{16EB3}'), ('\u{16ECF}', '\u{16EB4}'), ('\u{16ED0}', '\u{16EB5}'),
+        ('\u{16ED1}', '\u{16EB6}'), ('\u{16ED2}', '\u{16EB7}'), ('\u{16ED3}', '\u{16EB8}'),
+        ('\u{1E922}', '\u{1E900}'), ('\u{1E923}', '\u{1E901}'), ('\u{1E924}', '\u{1E902}'),
+        ('\u{1E925}', '\u{1E903}'), ('\u{1E926}', '\u{1E904}'), ('\u{1E927}', '\u{1E905}'),
+        ('\u{1E928}', '\u{1E906}'), ('\u{1E929}', '\u{1E907}'), ('\u{1E92A}', '\u{1E908}'),
+        ('\u{1E92B}', '\u{1E909}'), ('\u{1E92C}', '\u{1E90A}'), ('\u{1E92D}', '\u{1E90B}'),
+        ('\u{1E92E}', '\u{1E90C}'), ('\u{1E92F}', '\u{1E90D}'), ('\u{1E930}', '\u{1E90E}'),
+        ('\u{1E931}', '\u{1E90F}'), ('\u{1E932}', '\u{1E910}'), ('\u{1E933}', '\u{1E911}'),
+        ('\u{1E934}', '\u{1E912}'), ('\u{1E935}', '\u{1E913}'), ('\u{1E936}', '\u{1E914}'),
+        ('\u{1E937}', '\u{1E915}'), ('\u{1E938}', '\u{1E916}'), ('\u{1E939}', '\u{1E917}'),
+        ('\u{1E93A}', '\u{1E918}'), ('\u{1E93B}', '\u{1E919}'), ('\u{1E93C}', '\u{1E91A}'),
+        ('\u{1E93D}', '\u{1E91B}'), ('\u{1E93E}', '\u{1E91C}'), ('\u{1E93F}', '\u{1E91D}'),
+        ('\u{1E940}', '\u{1E91E}'), ('\u{1E941}', '\u{1E91F}'), ('\u{1E942}', '\u{1E920}'),
+        ('\u{1E943}', '\u{1E921}'),
+    ],
+    full: &[
+        ('\u{00DF}', ['\u{0053}', '\u{0073}', '\u{0000}']), ('\u{0149}', ['\u{02BC}', '\u{004E}', '\u{0000}']),
+        ('\u{01F0}', ['\u{004A}', '\u{030C}', '\u{0000}']), ('\u{0390}', ['\u{0399}', '\u{0308}', '\u{0301}']),
+        ('\u{03B0}', ['\u{03A5}', '\u{0308}', '\u{0301}']), ('\u{0587}', ['\u{0535}', '\u{0582}', '\u{0000}']),
+        ('\u{1E96}', ['\u{0048}', '\u{0331}', '\u{0000}']), ('\u{1E97}', ['\u{0054}', '\u{0308}', '\u{0000}']),
+        ('\u{1E98}', ['\u{0057}', '\u{030A}', '\u{0000}']), ('\u{1E99}', ['\u{0059}', '\u{030A}', '\u{0000}']),
+        ('\u{1E9A}', ['\u{0041}', '\u{02BE}', '\u{0000}']), ('\u{1F50}', ['\u{03A5}', '\u{0313}', '\u{0000}']),
+        ('\u{1F52}', ['\u{03A5}', '\u{0313}', '\u{0300}']), ('\u{1F54}', ['\u{03A5}', '\u{0313}', '\u{0301}']),
+        ('\u{1F56}', ['\u{03A5}', '\u{0313}', '\u{0342}']), ('\u{1FB2}', ['\u{1FBA}', '\u{0345}', '\u{0000}']),
+        ('\u{1FB4}', ['\u{0386}', '\u{0345}', '\u{0000}']), ('\u{1FB6}', ['\u{0391}', '\u{0342}', '\u{0000}']),
+        ('\u{1FB7}', ['\u{0391}', '\u{0342}', '\u{0345}']), ('\u{1FC2}', ['\u{1FCA}', '\u{0345}', '\u{0000}']),
+        ('\u{1FC4}', ['\u{0389}', '\u{0345}', '\u{0000}']), ('\u{1FC6}', ['\u{0397}', '\u{0342}', '\u{0000}']),
+        ('\u{1FC7}', ['\u{0397}', '\u{0342}', '\u{0345}']), ('\u{1FD2}', ['\u{0399}', '\u{0308}', '\u{0300}']),
+        ('\u{1FD3}', ['\u{0399}', '\u{0308}', '\u{0301}']), ('\u{1FD6}', ['\u{0399}', '\u{0342}', '\u{0000}']),
+        ('\u{1FD7}', ['\u{0399}', '\u{0308}', '\u{0342}']), ('\u{1FE2}', ['\u{03A5}', '\u{0308}', '\u{0300}']),
+        ('\u{1FE3}', ['\u{03A5}', '\u{0308}', '\u{0301}']), ('\u{1FE4}', ['\u{03A1}', '\u{0313}', '\u{0000}']),
+        ('\u{1FE6}', ['\u{03A5}', '\u{0342}', '\u{0000}']), ('\u{1FE7}', ['\u{03A5}', '\u{0308}', '\u{0342}']),
+        ('\u{1FF2}', ['\u{1FFA}', '\u{0345}', '\u{0000}']), ('\u{1FF4}', ['\u{038F}', '\u{0345}', '\u{0000}']),
+        ('\u{1FF6}', ['\u{03A9}', '\u{0342}', '\u{0000}']), ('\u{1FF7}', ['\u{03A9}', '\u{0342}', '\u{0345}']),
+        ('\u{FB00}', ['\u{0046}', '\u{0066}', '\u{0000}']), ('\u{FB01}', ['\u{0046}', '\u{0069}', '\u{0000}']),
+        ('\u{FB02}', ['\u{0046}', '\u{006C}', '\u{0000}']), ('\u{FB03}', ['\u{0046}', '\u{0066}', '\u{0069}']),
+        ('\u{FB04}', ['\u{0046}', '\u{0066}', '\u{006C}']), ('\u{FB05}', ['\u{0053}', '\u{0074}', '\u{0000}']),
+        ('\u{FB06}', ['\u{0053}', '\u{0074}', '\u{0000}']), ('\u{FB13}', ['\u{0544}', '\u{0576}', '\u{0000}']),
+        ('\u{FB14}', ['\u{0544}', '\u{0565}', '\u{0000}']), ('\u{FB15}', ['\u{0544}', '\u{056B}', '\u{0000}']),
+        ('\u{FB16}', ['\u{054E}', '\u{0576}', '\u{0000}']), ('\u{FB17}', ['\u{0544}', '\u{056D}', '\u{0000}']),
+    ],
+};
+
+#[rustfmt::skip]
+static UPPER_CASE_TABLE: UCDCaseTable = UCDCaseTable {
+    simple: &[
+        ('\u{0061}', '\u{0041}'), ('\u{0062}', '\u{0042}'), ('\u{0063}', '\u{0043}'),
+        ('\u{0064}', '\u{0044}'), ('\u{0065}', '\u{0045}'), ('\u{0066}', '\u{0046}'),
+        ('\u{0067}', '\u{0047}'), ('\u{0068}', '\u{0048}'), ('\u{0069}', '\u{0049}'),
+        ('\u{006A}', '\u{004A}'), ('\u{006B}', '\u{004B}'), ('\u{006C}', '\u{004C}'),
+        ('\u{006D}', '\u{004D}'), ('\u{006E}', '\u{004E}'), ('\u{006F}', '\u{004F}'),
+        ('\u{0070}', '\u{0050}'), ('\u{0071}', '\u{0051}'), ('\u{0072}', '\u{0052}'),
+        ('\u{0073}', '\u{0053}'), ('\u{0074}', '\u{0054}'), ('\u{0075}', '\u{0055}'),
+        ('\u{0076}', '\u{0056}'), ('\u{0077}', '\u{0057}'), ('\u{0078}', '\u{0058}'),
+        ('\u{0079}', '\u{0059}'), ('\u{007A}', '\u{005A}'), ('\u{00B5}', '\u{039C}'),
+        ('\u{00E0}', '\u{00C0}'), ('\u{00E1}', '\u{00C1}'), ('\u{00E2}', '\u{00C2}'),
+        ('\u{00E3}', '\u{00C3}'), ('\u{00E4}', '\u{00C4}'), ('\u{00E5}', '\u{00C5}'),
+        ('\u{00E6}', '\u{00C6}'), ('\u{00E7}', '\u{00C7}'), ('\u{00E8}', '\u{00C8}'),
+        ('\u{00E9}', '\u{00C9}'), ('\u{00EA}', '\u{00CA}'), ('\u{00EB}', '\u{00CB}'),
+        ('\u{00EC}', '\u{00CC}'), ('\u{00ED}', '\u{00CD}'), ('\u{00EE}', '\u{00CE}'),
+        ('\u{00EF}', '\u{00CF}'), ('\u{00F0}', '\u{00D0}'), ('\u{00F1}', '\u{00D1}'),
+        ('\u{00F2}', '\u{00D2}'), ('\u{00F3}', '\u{00D3}'), ('\u{00F4}', '\u{00D4}'),
+        ('\u{00F5}', '\u{00D5}'), ('\u{00F6}', '\u{00D6}'), ('\u{00F8}', '\u{00D8}'),
+        ('\u{00F9}', '\u{00D9}'), ('\u{00FA}', '\u{00DA}'), ('\u{00FB}', '\u{00DB}'),
+        ('\u{00FC}', '\u{00DC}'), ('\u{00FD}', '\u{00DD}'), ('\u{00FE}', '\u{00DE}'),
+        ('\u{00FF}', '\u{0178}'), ('\u{0101}', '\u{0100}'), ('\u{0103}', '\u{0102}'),
+        ('\u{0105}', '\u{0104}'), ('\u{0107}', '\u{0106}'), ('\u{0109}', '\u{0108}'),
+        ('\u{010B}', '\u{010A}'), ('\u{010D}', '\u{010C}'), ('\u{010F}', '\u{010E}'),
+        ('\u{0111}', '\u{0110}'), ('\u{0113}', '\u{0112}'), ('\u{0115}', '\u{0114}'),
+        ('\u{0117}', '\u{0116}'), ('\u{0119}', '\u{0118}'), ('\u{011B}', '\u{011A}'),
+        ('\u{011D}', '\u{011C}'), ('\u{011F}', '\u{011E}'), ('\u{0121}', '\u{0120}'),
+        ('\u{0123}', '\u{0122}'), ('\u{0125}', '\u{0124}'), ('\u{0127}', '\u{0126}'),
+        ('\u{0129}', '\u{0128}'), ('\u{012B}', '\u{012A}'), ('\u{012D}', '\u{012C}'),
+        ('\u{012F}', '\u{012E}'), ('\u{0131}', '\u{0049}'), ('\u{0133}', '\u{0132}'),
+        ('\u{0135}', '\u{0134}'), ('\u{0137}', '\u{0136}'), ('\u{013A}', '\u{0139}'),
+        ('\u{013C}', '\u{013B}'), ('\u{013E}', '\u{013D}'), ('\u{0140}', '\u{013F}'),
+        ('\u{0142}', '\u{0141}'), ('\u{0144}', '\u{0143}'), ('\u{0146}', '\u{0145}'),
+        ('\u{0148}', '\u{0147}'), ('\u{014B}', '\u{014A}'), ('\u{014D}', '\u{014C}'),
+        ('\u{014F}', '\u{014E}'), ('\u{0151}', '\u{0150}'), ('\u{0153}', '\u{0152}'),
+        ('\u{0155}', '\u{0154}'), ('\u{0157}', '\u{0156}'), ('\u{0159}', '\u{0158}'),
+        ('\u{015B}', '\u{015A}'), ('\u{015D}', '\u{015C}'), ('\u{015F}', '\u{015E}'),
+        ('\u{0161}', '\u{0160}'), ('\u{0163}', '\u{0162}'), ('\u{0165}', '\u{0164}'),
+        ('\u{0167}', '\u{0166}'), ('\u{0169}', '\u{0168}'), ('\u{016B}', '\u{016A}'),
+        ('\u{016D}', '\u{016C}'), ('\u{016F}', '\u{016E}'), ('\u{0171}', '\u{0170}'),
+        ('\u{0173}', '\u{0172}'), ('\u{0175}', '\u{0174}'), ('\u{0177}', '\u{0176}'),
+        ('\u{017A}', '\u{0179}'), ('\u{017C}', '\u{017B}'), ('\u{017E}', '\u{017D}'),
+        ('\u{017F}', '\u{0053}'), ('\u{0180}', '\u{0243}'), ('\u{0183}', '\u{0182}'),
+        ('\u{0185}', '\u{0184}'), ('\u{0188}', '\u{0187}'), ('\u{018C}', '\u{018B}'),
+        ('\u{0192}', '\u{0191}'), ('\u{0195}', '\u{01F6}'), ('\u{0199}', '\u{0198}'),
+        ('\u{019A}', '\u{023D}'), ('\u{019B}', '\u{A7DC}'), ('\u{019E}', '\u{0220}'),
+        ('\u{01A1}', '\u{01A0}'), ('\u{01A3}', '\u{01A2}'), ('\u{01A5}', '\u{01A4}'),
+        ('\u{01A8}', '\u{01A7}'), ('\u{01AD}', '\u{01AC}'), ('\u{01B0}', '\u{01AF}'),
+        ('\u{01B4}', '\u{01B3}'), ('\u{01B6}', '\u{01B5}'), ('\u{01B9}', '\u{01B8}'),
+        ('\u{01BD}', '\u{01BC}'), ('\u{01BF}', '\u{01F7}'), ('\u{01C5}', '\u{01C4}'),
+        ('\u{01C6}', '\u{01C4}'), ('\u{01C8}', '\u{01C7}'), ('\u{01C9}', '\u{01C7}'),
+        ('\u{01CB}', '\u{01CA}'), ('\u{01CC}', '\u{01CA}'), ('\u{01CE}', '\u{01CD}'),
+        ('\u{01D0}', '\u{01CF}'), ('\u{01D2}', '\u{01D1}'), ('\u{01D4}', '\u{01D3}'),
+        ('\u{01D6}', '\u{01D5}'), ('\u{01D8}', '\u{01D7}'), ('\u{01DA}', '\u{01D9}'),
+        ('\u{01DC}', '\u{01DB}'), ('\u{01DD}', '\u{018E}'), ('\u{01DF}', '\u{01DE}'),
+        ('\u{01E1}', '\u{01E0}'), ('\u{01E3}', '\u{01E2}'), ('\u{01E5}', '\u{01E4}'),
+        ('\u{01E7}', '\u{01E6}'), ('\u{01E9}', '\u{01E8}'), ('\u{01EB}', '\u{01EA}'),
+        ('\u{01ED}', '\u{01EC}'), ('\u{01EF}', '\u{01EE}'), ('\u{01F2}', '\u{01F1}'),
+        ('\u{01F3}', '\u{01F1}'), ('\u{01F5}', '\u{01F4}'), ('\u{01F9}', '\u{01F8}'),
+        ('\u{01FB}', '\u{01FA}'), ('\u{01FD}', '\u{01FC}'), ('\u{01FF}', '\u{01FE}'),
+        ('\u{0201}', '\u{0200}'), ('\u{0203}', '\u{0202}'), ('\u{0205}', '\u{0204}'),
+        ('\u{0207}', '\u{0206}'), ('\u{0209}', '\u{0208}'), ('\u{020B}', '\u{020A}'),
+        ('\u{020D}', '\u{020C}'), ('\u{020F}', '\u{020E}'), ('\u{0211}', '\u{0210}'),
+        ('\u{0213}', '\u{0212}'), ('\u{0215}', '\u{0214}'), ('\u{0217}', '\u{0216}'),
+        ('\u{0219}', '\u{0218}'), ('\u{021B}', '\u{021A}'), ('\u{021D}', '\u{021C}'),
+        ('\u{021F}', '\u{021E}'), ('\u{0223}', '\u{0222}'), ('\u{0225}', '\u{0224}'),
+        ('\u{0227}', '\u{0226}'), ('\u{0229}', '\u{0228}'), ('\u{022B}', '\u{022A}'),
+        ('\u{022D}', '\u{022C}'), ('\u{022F}', '\u{022E}'), ('\u{0231}', '\u{0230}'),
+        ('\u{0233}', '\u{0232}'), ('\u{023C}', '\u{023B}'), ('\u{023F}', '\u{2C7E}'),
+        ('\u{0240}', '\u{2C7F}'), ('\u{0242}', '\u{0241}'), ('\u{0247}', '\u{0246}'),
+        ('\u{0249}', '\u{0248}'), ('\u{024B}', '\u{024A}'), ('\u{024D}', '\u{024C}'),
+        ('\u{024F}', '\u{024E}'), ('\u{0250}', '\u{2C6F}'), ('\u{0251}', '\u{2C6D}'),
+        ('\u{0252}', '\u{2C70}'), ('\u{0253}', '\u{0181}'), ('\u{0254}', '\u{0186}'),
+        ('\u{0256}', '\u{0189}'), ('\u{0257}', '\u{018A}'), ('\u{0259}', '\u{018F}'),
+        ('\u{025B}', '\u{0190}'), ('\u{025C}', '\u{A7AB}'), ('\u{0260}', '\u{0193}'),
+        ('\u{0261}', '\u{A7AC}'), ('\u{0263}', '\u{0194}'), ('\u{0264}', '\u{A7CB}'),
+        ('\u{0265}', '\u{A78D}'), ('\u{0266}', '\u{A7AA}'), ('\u{0268}', '\u{0197}'),
+        ('\u{0269}', '\u{0196}'), ('\u{026A}', '\u{A7AE}'), ('\u{026B}', '\u{2C62}'),
+        ('\u{026C}', '\u{A7AD}'), ('\u{026F}', '\u{019C}'), ('\u{0271}', '\u{2C6E}'),
+        ('\u{0272}', '\u{019D}'), ('\u{0275}', '\u{019F}'), ('\u{027D}', '\u{2C64}'),
+        ('\u{0280}', '\u{01A6}'), ('\u{0282}', '\u{A7C5}'), ('\u{0283}', '\u{01A9}'),
+        ('\u{0287}', '\u{A7B1}'), ('\u{0288}', '\u{01AE}'), ('\u{0289}', '\u{0244}'),
+        ('\u{028A}', '\u{01B1}'), ('\u{028B}', '\u{01B2}'), ('\u{028C}', '\u{0245}'),
+        ('\u{0292}', '\u{01B7}'), ('\u{029D}', '\u{A7B2}'), ('\u{029E}', '\u{A7B0}'),
+        ('\u{0345}', '\u{0399}'), ('\u{0371}', '\u{0370}'), ('\u{0373}', '\u{0372}'),
+        ('\u{0377}', '\u{0376}'), ('\u{037B}', '\u{03FD}'), ('\u{037C}', '\u{03FE}'),
+        ('\u{037D}', '\u{03FF}'), ('\u{03AC}', '\u{0386}'), ('\u{03AD}', '\u{0388}'),
+        ('\u{03AE}', '\u{0389}'), ('\u{03AF}', '\u{038A}'), ('\u{03B1}', '\u{0391}'),
+        ('\u{03B2}', '\u{0392}'), ('\u{03B3}', '\u{0393}'), ('\u{03B4}', '\u{0394}'),
+        ('\u{03B5}', '\u{0395}'), ('\u{03B6}', '\u{0396}'), ('\u{03B7}', '\u{0397}'),
+        ('\u{03B8}', '\u{0398}'), ('\u{03B9}', '\u{0399}'), ('\u{03BA}', '\u{039A}'),
+        ('\u{03BB}', '\u{039B}'), ('\u{03BC}', '\u{039C}'), ('\u{03BD}', '\u{039D}'),
+        ('\u{03BE}', '\u{039E}'), ('\u{03BF}', '\u{039F}'), ('\u{03C0}', '\u{03A0}'),
+        ('\u{03C1}', '\u{03A1}'), ('\u{03C2}', '\u{03A3}'), ('\u{03C3}', '\u{03A3}'),
+        ('\u{03C4}', '\u{03A4}'), ('\u{03C5}', '\u{03A5}'), ('\u{03C6}', '\u{03A6}'),
+        ('\u{03C7}', '\u{03A7}'), ('\u{03C8}', '\u{03A8}'), ('\u{03C9}', '\u{03A9}'),
+        ('\u{03CA}', '\u{03AA}'), ('\u{03CB}', '\u{03AB}'), ('\u{03CC}', '\u{038C}'),
+        ('\u{03CD}', '\u{038E}'), ('\u{03CE}', '\u{038F}'), ('\u{03D0}', '\u{0392}'),
+        ('\u{03D1}', '\u{0398}'), ('\u{03D5}', '\u{03A6}'), ('\u{03D6}', '\u{03A0}'),
+        ('\u{03D7}', '\u{03CF}'), ('\u{03D9}', '\u{03D8}'), ('\u{03DB}', '\u{03DA}'),
+        ('\u{03DD}', '\u{03DC}'), ('\u{03DF}', '\u{03DE}'), ('\u{03E1}', '\u{03E0}'),
+        ('\u{03E3}', '\u{03E2}'), ('\u{03E5}', '\u{03E4}'), ('\u{03E7}', '\u{03E6}'),
+        ('\u{03E9}', '\u{03E8}'), ('\u{03EB}', '\u{03EA}'), ('\u{03ED}', '\u{03EC}'),
+        ('\u{03EF}', '\u{03EE}'), ('\u{03F0}', '\u{039A}'), ('\u{03F1}', '\u{03A1}'),
+        ('\u{03F2}', '\u{03F9}'), ('\u{03F3}', '\u{037F}'), ('\u{03F5}', '\u{0395}'),
+        ('\u{03F8}', '\u{03F7}'), ('\u{03FB}', '\u{03FA}'), ('\u{0430}', '\u{0410}'),
+        ('\u{0431}', '\u{0411}'), ('\u{0432}', '\u{0412}'), ('\u{0433}', '\u{0413}'),
+        ('\u{0434}', '\u{0414}'), ('\u{0435}', '\u{0415}'), ('\u{0436}', '\u{0416}'),
+        ('\u{0437}', '\u{0417}'), ('\u{0438}', '\u{0418}'), ('\u{0439}', '\u{0419}'),
+        ('\u{043A}', '\u{041A}'), ('\u{043B}', '\u{041B}'), ('\u{043C}', '\u{041C}'),
+        ('\u{043D}', '\u{041D}'), ('\u{043E}', '\u{041E}'), ('\u{043F}', '\u{041F}'),
+        ('\u{0440}', '\u{0420}'), ('\u{0441}', '\u{0421}'), ('\u{0442}', '\u{0422}'),
+        ('\u{0443}', '\u{0423}'), ('\u{0444}', '\u{0424}'), ('\u{0445}', '\u{0425}'),
+        ('\u{0446}', '\u{0426}'), ('\u{0447}', '\u{0427}'), ('\u{0448}', '\u{0428}'),
+        ('\u{0449}', '\u{0429}'), ('\u{044A}', '\u{042A}'), ('\u{044B}', '\u{042B}'),
+        ('\u{044C}', '\u{042C}'), ('\u{044D}', '\u{042D}'), ('\u{044E}', '\u{042E}'),
+        ('\u{044F}', '\u{042F}'), ('\u{0450}', '\u{0400}'), ('\u{0451}', '\u{0401}'),
+        ('\u{0452}', '\u{0402}'), ('\u{0453}', '\u{0403}'), ('\u{0454}', '\u{0404}'),
+        ('\u{0455}', '\u{0405}'), ('\u{0456}', '\u{0406}'), ('\u{0457}', '\u{0407}'),
+        ('\u{0458}', '\u{0408}'), ('\u{0459}', '\u{0409}'), ('\u{045A}', '\u{040A}'),
+        ('\u{045B}', '\u{040B}'), ('\u{045C}', '\u{040C}'), ('\u{045D}', '\u{040D}'),
+        ('\u{045E}', '\u{040E}'), ('\u{045F}', '\u{040F}'), ('\u{0461}', '\u{0460}'),
+        ('\u{0463}', '\u{0462}'), ('\u{0465}', '\u{0464}'), ('\u{0467}', '\u{0466}'),
+        ('\u{0469}', '\u{0468}'), ('\u{046B}', '\u{046A}'), ('\u{046D}', '\u{046C}'),
+        ('\u{046F}', '\u{046E}'), ('\u{0471}', '\u{0470}'), ('\u{0473}', '\u{0472}'),
+        ('\u{0475}', '\u{0474}'), ('\u{0477}', '\u{0476}'), ('\u{0479}', '\u{0478}'),
+        ('\u{047B}', '\u{047A}'), ('\u{047D}', '\u{047C}'), ('\u{047F}', '\u{047E}'),
+        ('\u{0481}', '\u{0480}'), ('\u{048B}', '\u{048A}'), ('\u{048D}', '\u{048C}'),
+        ('\u{048F}', '\u{048E}'), ('\u{0491}', '\u{0490}'), ('\u{0493}', '\u{0492}'),
+        ('\u{0495}', '\u{0494}'), ('\u{0497}', '\u{0496}'), ('\u{0499}', '\u{0498}'),
+        ('\u{049B}', '\u{049A}'), ('\u{049D}', '\u{049C}'), ('\u{049F}', '\u{049E}'),
+        ('\u{04A1}', '\u{04A0}'), ('\u{04A3}', '\u{04A2}'), ('\u{04A5}', '\u{04A4}'),
+        ('\u{04A7}', '\u{04A6}'), ('\u{04A9}', '\u{04A8}'), ('\u{04AB}', '\u{04AA}'),
+        ('\u{04AD}', '\u{04AC}'), ('\u{04AF}', '\u{04AE}'), ('\u{04B1}', '\u{04B0}'),
+        ('\u{04B3}', '\u{04B2}'), ('\u{04B5}', '\u{04B4}'), ('\u{04B7}', '\u{04B6}'),
+        ('\u{04B9}', '\u{04B8}'), ('\u{04BB}', '\u{04BA}'), ('\u{04BD}', '\u{04BC}'),
+        ('\u{04BF}', '\u{04BE}'), ('\u{04C2}', '\u{04C1}'), ('\u{04C4}', '\u{04C3}'),
+        ('\u{04C6}', '\u{04C5}'), ('\u{04C8}', '\u{04C7}'), ('\u{04CA}', '\u{04C9}'),
+        ('\u{04CC}', '\u{04CB}'), ('\u{04CE}', '\u{04CD}'), ('\u{04CF}', '\u{04C0}'),
+        ('\u{04D1}', '\u{04D0}'), ('\u{04D3}', '\u{04D2}'), ('\u{04D5}', '\u{04D4}'),
+        ('\u{04D7}', '\u{04D6}'), ('\u{04D9}', '\u{04D8}'), ('\u{04DB}', '\u{04DA}'),
+        ('\u{04DD}', '\u{04DC}'), ('\u{04DF}', '\u{04DE}'), ('\u{04E1}', '\u{04E0}'),
+        ('\u{04E3}', '\u{04E2}'), ('\u{04E5}', '\u{04E4}'), ('\u{04E7}', '\u{04E6}'),
+        ('\u{04E9}', '\u{04E8}'), ('\u{04EB}', '\u{04EA}'), ('\u{04ED}', '\u{04EC}'),
+        ('\u{04EF}', '\u{04EE}'), ('\u{04F1}', '\u{04F0}'), ('\u{04F3}', '\u{04F2}'),
+        ('\u{04F5}', '\u{04F4}'), ('\u{04F7}', '\u{04F6}'), ('\u{04F9}', '\u{04F8}'),
+        ('\u{04FB}', '\u{04FA}'), ('\u{04FD}', '\u{04FC}'), ('\u{04FF}', '\u{04FE}'),
+        ('\u{0501}', '\u{0500}'), ('\u{0503}', '\u{0502}'), ('\u{0505}', '\u{0504}'),
+        ('\u{0507}', '\u{0506}'), ('\u{0509}', '\u{0508}'), ('\u{050B}', '\u{050A}'),
+        ('\u{050D}', '\u{050C}'), ('\u{050F}', '\u{050E}'), ('\u{0511}', '\u{0510}'),
+        ('\u{0513}', '\u{0512}'), ('\u{0515}', '\u{0514}'), ('\u{0517}', '\u{0516}'),
+        ('\u{0519}', '\u{0518}'), ('\u{051B}', '\u{051A}'), ('\u{051D}', '\u{051C}'),
+        ('\u{051F}', '\u{051E}'), ('\u{0521}', '\u{0520}'), ('\u{0523}', '\u{0522}'),
+        ('\u{0525}', '\u{0524}'), ('\u{0527}', '\u{0526}'), ('\u{0529}', '\u{0528}'),
+        ('\u{052B}', '\u{052A}'), ('\u{052D}', '\u{052C}'), ('\u{052F}', '\u{052E}'),
+        ('\u{0561}', '\u{0531}'), ('\u{0562}', '\u{0532}'), ('\u{0563}', '\u{0533}'),
+        ('\u{0564}', '\u{0534}'), ('\u{0565}', '\u{0535}'), ('\u{0566}', '\u{0536}'),
+        ('\u{0567}', '\u{0537}'), ('\u{0568}', '\u{0538}'), ('\u{0569}', '\u{0539}'),
+        ('\u{056A}', '\u{053A}'), ('\u{056B}', '\u{053B}'), ('\u{056C}', '\u{053C}'),
+        ('\u{056D}', '\u{053D}'), ('\u{056E}', '\u{053E}'), ('\u{056F}', '\u{053F}'),
+        ('\u{0570}', '\u{0540}'), ('\u{0571}', '\u{0541}'), ('\u{0572}', '\u{0542}'),
+        ('\u{0573}', '\u{0543}'), ('\u{0574}', '\u{0544}'), ('\u{0575}', '\u{0545}'),
+        ('\u{0576}', '\u{0546}'), ('\u{0577}', '\u{0547}'), ('\u{0578}', '\u{0548}'),
+        ('\u{0579}', '\u{0549}'), ('\u{057A}', '\u{054A}'), ('\u{057B}', '\u{054B}'),
+        ('\u{057C}', '\u{054C}'), ('\u{057D}', '\u{054D}'), ('\u{057E}', '\u{054E}'),
+        ('\u{057F}', '\u{054F}'), ('\u{0580}', '\u{0550}'), ('\u{0581}', '\u{0551}'),
+        ('\u{0582}', '\u{0552}'), ('\u{0583}', '\u{0553}'), ('\u{0584}', '\u{0554}'),
+        ('\u{0585}', '\u{0555}'), ('\u{0586}', '\u{0556}'), ('\u{10D0}', '\u{1C90}'),
+        ('\u{10D1}', '\u{1C91}'), ('\u{10D2}', '\u{1C92}'), ('\u{10D3}', '\u{1C93}'),
+        ('\u{10D4}', '\u{1C94}'), ('\u{10D5}', '\u{1C95}'), ('\u{10D6}', '\u{1C96}'),
+        ('\u{10D7}', '\u{1C97}'), ('\u{10D8}', '\u{1C98}'), ('\u{10D9}', '\u{1C99}'),
+        ('\u{10DA}', '\u{1C9A}'), ('\u{10DB}', '\u{1C9B}'), ('\u{10DC}', '\u{1C9C}'),
+        ('\u{10DD}', '\u{1C9D}'), ('\u{10DE}', '\u{1C9E}'), ('\u{10DF}', '\u{1C9F}'),
+        ('\u{10E0}', '\u{1CA0}'), ('\u{10E1}', '\u{1CA1}'), ('\u{10E2}', '\u{1CA2}'),
+        ('\u{10E3}', '\u{1CA3}'), ('\u{10E4}', '\u{1CA4}'), ('\u{10E5}', '\u{1CA5}'),
+        ('\u{10E6}', '\u{1CA6}'), ('\u{10E7}', '\u{1CA7}'), ('\u{10E8}', '\u{1CA8}'),
+        ('\u{10E9}', '\u{1CA9}'), ('\u{10EA}', '\u{1CAA}'), ('\u{10EB}', '\u{1CAB}'),
+        ('\u{10EC}', '\u{1CAC}'), ('\u{10ED}', '\u{1CAD}'), ('\u{10EE}', '\u{1CAE}'),
+        ('\u{10EF}', '\u{1CAF}'), ('\u{10F0}', '\u{1CB0}'), ('\u{10F1}', '\u{1CB1}'),
+        ('\u{10F2}', '\u{1CB2}'), ('\u{10F3}', '\u{1CB3}'), ('\u{10F4}', '\u{1CB4}'),
+        ('\u{10F5}', '\u{1CB5}'), ('\u{10F6}', '\u{1CB6}'), ('\u{10F7}', '\u{1CB7}'),
+        ('\u{10F8}', '\u{1CB8}'), ('\u{10F9}', '\u{1CB9}'), ('\u{10FA}', '\u{1CBA}'),
+        ('\u{10FD}', '\u{1CBD}'), ('\u{10FE}', '\u{1CBE}'), ('\u{10FF}', '\u{1CBF}'),
+        ('\u{13F8}', '\u{13F0}'), ('\u{13F9}', '\u{13F1}'), ('\u{13FA}', '\u{13F2}'),
+        ('\u{13FB}', '\u{13F3}'), ('\u{13FC}', '\u{13F4}'), ('\u{13FD}', '\u{13F5}'),
+        ('\u{1C80}', '\u{0412}'), ('\u{1C81}', '\u{0414}'), ('\u{1C82}', '\u{041E}'),
+        ('\u{1C83}', '\u{0421}'), ('\u{1C84}', '\u{0422}'), ('\u{1C85}', '\u{0422}'),
+        ('\u{1C86}', '\u{042A}'), ('\u{1C87}', '\u{0462}'), ('\u{1C88}', '\u{A64A}'),
+        ('\u{1C8A}', '\u{1C89}'), ('\u{1D79}', '\u{A77D}'), ('\u{1D7D}', '\u{2C63}'),
+        ('\u{1D8E}', '\u{A7C6}'), ('\u{1E01}', '\u{1E00}'), ('\u{1E03}', '\u{1E02}'),
+        ('\u{1E05}', '\u{1E04}'), ('\u{1E07}', '\u{1E06}'), ('\u{1E09}', '\u{1E08}'),
+        ('\u{1E0B}', '\u{1E0A}'), ('\u{1E0D}', '\u{1E0C}'), ('\u{1E0F}', '\u{1E0E}'),
+        ('\u{1E11}', '\u{1E10}'), ('\u{1E13}', '\u{1E12}'), ('\u{1E15}', '\u{1E14}'),
+        ('\u{1E17}', '\u{1E16}'), ('\u{1E19}', '\u{1E18}'), ('\u{1E1B}', '\u{1E1A}'),
+        ('\u{1E1D}', '\u{1E1C}'), ('\u{1E1F}', '\u{1E1E}'), ('\u{1E21}', '\u{1E20}'),
+        ('\u{1E23}', '\u{1E22}'), ('\u{1E25}', '\u{1E24}'), ('\u{1E27}', '\u{1E26}'),
+        ('\u{1E29}', '\u{1E28}'), ('\u{1E2B}', '\u{1E2A}'), ('\u{1E2D}', '\u{1E2C}'),
+        ('\u{1E2F}', '\u{1E2E}'), ('\u{1E31}', '\u{1E30}'), ('\u{1E33}', '\u{1E32}'),
+        ('\u{1E35}', '\u{1E34}'), ('\u{1E37}', '\u{1E36}'), ('\u{1E39}', '\u{1E38}'),
+        ('\u{1E3B}', '\u{1E3A}'), ('\u{1E3D}', '\u{1E3C}'), ('\u{1E3F}', '\u{1E3E}'),
+        ('\u{1E41}', '\u{1E40}'), ('\u{1E43}', '\u{1E42}'), ('\u{1E45}', '\u{1E44}'),
+        ('\u{1E47}', '\u{1E46}'), ('\u{1E49}', '\u{1E48}'), ('\u{1E4B}', '\u{1E4A}'),
+        ('\u{1E4D}', '\u{1E4C}'), ('\u{1E4F}', '\u{1E4E}'), ('\u{1E51}', '\u{1E50}'),
+        ('\u{1E53}', '\u{1E52}'), ('\u{1E55}', '\u{1E54}'), ('\u{1E57}', '\u{1E56}'),
+        ('\u{1E59}', '\u{1E58}'), ('\u{1E5B}', '\u{1E5A}'), ('\u{1E5D}', '\u{1E5C}'),
+        ('\u{1E5F}', '\u{1E5E}'), ('\u{1E61}', '\u{1E60}'), ('\u{1E63}', '\u{1E62}'),
+        ('\u{1E65}', '\u{1E64}'), ('\u{1E67}', '\u{1E66}'), ('\u{1E69}', '\u{1E68}'),
+        ('\u{1E6B}', '\u{1E6A}'), ('\u{1E6D}', '\u{1E6C}'), ('\u{1E6F}', '\u{1E6E}'),
+        ('\u{1E71}', '\u{1E70}'), ('\u{1E73}', '\u{1E72}'), ('\u{1E75}', '\u{1E74}'),
+        ('\u{1E77}', '\u{1E76}'), ('\u{1E79}', '\u{1E78}'), ('\u{1E7B}', '\u{1E7A}'),
+        ('\u{1E7D}', '\u{1E7C}'), ('\u{1E7F}', '\u{1E7E}'), ('\u{1E81}', '\u{1E80}'),
+        ('\u{1E83}', '\u{1E82}'), ('\u{1E85}', '\u{1E84}'), ('\u{1E87}', '\u{1E86}'),
+        ('\u{1E89}', '\u{1E88}'), ('\u{1E8B}', '\u{1E8A}'), ('\u{1E8D}', '\u{1E8C}'),
+        ('\u{1E8F}', '\u{1E8E}'), ('\u{1E91}', '\u{1E90}'), ('\u{1E93}', '\u{1E92}'),
+        ('\u{1E95}', '\u{1E94}'), ('\u{1E9B}', '\u{1E60}'), ('\u{1EA1}', '\u{1EA0}'),
+        ('\u{1EA3}', '\u{1EA2}'), ('\u{1EA5}', '\u{1EA4}'), ('\u{1EA7}', '\u{1EA6}'),
+        ('\u{1EA9}', '\u{1EA8}'), ('\u{1EAB}', '\u{1EAA}'), ('\u{1EAD}', '\u{1EAC}'),
+        ('\u{1EAF}', '\u{1EAE}'), ('\u{1EB1}', '\u{1EB0}'), ('\u{1EB3}', '\u{1EB2}'),
+        ('\u{1EB5}', '\u{1EB4}'), ('\u{1EB7}', '\u{1EB6}'), ('\u{1EB9}', '\u{1EB8}'),
+        ('\u{1EBB}', '\u{1EBA}'), ('\u{1EBD}', '\u{1EBC}'), ('\u{1EBF}', '\u{1EBE}'),
+        ('\u{1EC1}', '\u{1EC0}'), ('\u{1EC3}', '\u{1EC2}'), ('\u{1EC5}', '\u{1EC4}'),
+        ('\u{1EC7}', '\u{1EC6}'), ('\u{1EC9}', '\u{1EC8}'), ('\u{1ECB}', '\u{1ECA}'),
+        ('\u{1ECD}', '\u{1ECC}'), ('\u{1ECF}', '\u{1ECE}'), ('\u{1ED1}', '\u{1ED0}'),
+        ('\u{1ED3}', '\u{1ED2}'), ('\u{1ED5}', '\u{1ED4}'), ('\u{1ED7}', '\u{1ED6}'),
+        ('\u{1ED9}', '\u{1ED8}'), ('\u{1EDB}', '\u{1EDA}'), ('\u{1EDD}', '\u{1EDC}'),
+        ('\u{1EDF}', '\u{1EDE}'), ('\u{1EE1}', '\u{1EE0}'), ('\u{1EE3}', '\u{1EE2}'),
+        ('\u{1EE5}', '\u{1EE4}'), ('\u{1EE7}', '\u{1EE6}'), ('\u{1EE9}', '\u{1EE8}'),
+        ('\u{1EEB}', '\u{1EEA}'), ('\u{1EED}', '\u{1EEC}'), ('\u{1EEF}', '\u{1EEE}'),
+        ('\u{1EF1}', '\u{1EF0}'), ('\u{1EF3}', '\u{1EF2}'), ('\u{1EF5}', '\u{1EF4}'),
+        ('\u{1EF7}', '\u{1EF6}'), ('\u{1EF9}', '\u{1EF8}'), ('\u{1EFB}', '\u{1EFA}'),
+        ('\u{1EFD}', '\u{1EFC}'), ('\u{1EFF}', '\u{1EFE}'), ('\u{1F00}', '\u{1F08}'),
+        ('\u{1F01}', '\u{1F09}'), ('\u{1F02}', '\u{1F0A}'), ('\u{1F03}', '\u{1F0B}'),
+        ('\u{1F04}', '\u{1F0C}'), ('\u{1F05}', '\u{1F0D}'), ('\u{1F06}', '\u{1F0E}'),
+        ('\u{1F07}', '\u{1F0F}'), ('\u{1F10}', '\u{1F18}'), ('\u{1F11}', '\u{1F19}'),
+        ('\u{1F12}', '\u{1F1A}'), ('\u{1F13}', '\u{1F1B}'), ('\u{1F14}', '\u{1F1C}'),
+        ('\u{1F15}', '\u{1F1D}'), ('\u{1F20}', '\u{1F28}'), ('\u{1F21}', '\u{1F29}'),
+        ('\u{1F22}', '\u{1F2A}'), ('\u{1F23}', '\u{1F2B}'), ('\u{1F24}', '\u{1F2C}'),
+        ('\u{1F25}', '\u{1F2D}'), ('\u{1F26}', '\u{1F2E}'), ('\u{1F27}', '\u{1F2F}'),
+        ('\u{1F30}', '\u{1F38}'), ('\u{1F31}', '\u{1F39}'), ('\u{1F32}', '\u{1F3A}'),
+        ('\u{1F33}', '\u{1F3B}'), ('\u{1F34}', '\u{1F3C}'), ('\u{1F35}', '\u{1F3D}'),
+        ('\u{1F36}', '\u{1F3E}'), ('\u{1F37}', '\u{1F3F}'), ('\u{1F40}', '\u{1F48}'),
+        ('\u{1F41}', '\u{1F49}'), ('\u{1F42}', '\u{1F4A}'), ('\u{1F43}', '\u{1F4B}'),
+        ('\u{1F44}', '\u{1F4C}'), ('\u{1F45}', '\u{1F4D}'), ('\u{1F51}', '\u{1F59}'),
+        ('\u{1F53}', '\u{1F5B}'), ('\u{1F55}', '\u{1F5D}'), ('\u{1F57}', '\u{1F5F}'),
+        ('\u{1F60}', '\u{1F68}'), ('\u{1F61}', '\u{1F69}'), ('\u{1F62}', '\u{1F6A}'),
+        ('\u{1F63}', '\u{1F6B}'), ('\u{1F64}', '\u{1F6C}'), ('\u{1F65}', '\u{1F6D}'),
+        ('\u{1F66}', '\u{1F6E}'), ('\u{1F67}', '\u{1F6F}'), ('\u{1F70}', '\u{1FBA}'),
+        ('\u{1F71}', '\u{1FBB}'), ('\u{1F72}', '\u{1FC8}'), ('\u{1F73}', '\u{1FC9}'),
+        ('\u{1F74}', '\u{1FCA}'), ('\u{1F75}', '\u{1FCB}'), ('\u{1F76}', '\u{1FDA}'),
+        ('\u{1F77}', '\u{1FDB}'), ('\u{1F78}', '\u{1FF8}'), ('\u{1F79}', '\u{1FF9}'),
+        ('\u{1F7A}', '\u{1FEA}'), ('\u{1F7B}', '\u{1FEB}'), ('\u{1F7C}', '\u{1FFA}'),
+        ('\u{1F7D}', '\u{1FFB}'), ('\u{1F80}', '\u{1F88}'), ('\u{1F81}', '\u{1F89}'),
+        ('\u{1F82}', '\u{1F8A}'), ('\u{1F83}', '\u{1F8B}'), ('\u{1F84}', '\u{1F8C}'),
+        ('\u{1F85}', '\u{1F8D}'), ('\u{1F86}', '\u{1F8E}'), ('\u{1F87}', '\u{1F8F}'),
+        ('\u{1F90}', '\u{1F98}'), ('\u{1F91}', '\u{1F99}'), ('\u{1F92}', '\u{1F9A}'),
+        ('\u{1F93}', '\u{1F9B}'), ('\u{1F94}', '\u{1F9C}'), ('\u{1F95}', '\u{1F9D}'),
+        ('\u{1F96}', '\u{1F9E}'), ('\u{1F97}', '\u{1F9F}'), ('\u{1FA0}', '\u{1FA8}'),
+        ('\u{1FA1}', '\u{1FA9}'), ('\u{1FA2}', '\u{1FAA}'), ('\u{1FA3}', '\u{1FAB}'),
+        ('\u{1FA4}', '\u{1FAC}'), ('\u{1FA5}', '\u{1FAD}'), ('\u{1FA6}', '\u{1FAE}'),
+        ('\u{1FA7}', '\u{1FAF}'), ('\u{1FB0}', '\u{1FB8}'), ('\u{1FB1}', '\u{1FB9}'),
+        ('\u{1FB3}', '\u{1FBC}'), ('\u{1FBE}', '\u{0399}'), ('\u{1FC3}', '\u{1FCC}'),
+        ('\u{1FD0}', '\u{1FD8}'), ('\u{1FD1}', '\u{1FD9}'), ('\u{1FE0}', '\u{1FE8}'),
+        ('\u{1FE1}', '\u{1FE9}'), ('\u{1FE5}', '\u{1FEC}'), ('\u{1FF3}', '\u{1FFC}'),
+        ('\u{214E}', '\u{2132}'), ('\u{2170}', '\u{2160}'), ('\u{2171}', '\u{2161}'),
+        ('\u{2172}', '\u{2162}'), ('\u{2173}', '\u{2163}'), ('\u{2174}', '\u{2164}'),
+        ('\u{2175}', '\u{2165}'), ('\u{2176}', '\u{2166}'), ('\u{2177}', '\u{2167}'),
+        ('\u{2178}', '\u{2168}'), ('\u{2179}', '\u{2169}'), ('\u{217A}', '\u{216A}'),
+        ('\u{217B}', '\u{216B}'), ('\u{217C}', '\u{216C}'), ('\u{217D}', '\u{216D}'),
+        ('\u{217E}', '\u{216E}'), ('\u{217F}', '\u{216F}'), ('\u{2184}', '\u{2183}'),
+        ('\u{24D0}', '\u{24B6}'), ('\u{24D1}', '\u{24B7}'), ('\u{24D2}', '\u{24B8}'),
+        ('\u{24D3}', '\u{24B9}'), ('\u{24D4}', '\u{24BA}'), ('\u{24D5}', '\u{24BB}'),
+        ('\u{24D6}', '\u{24BC}'), ('\u{24D7}', '\u{24BD}'), ('\u{24D8}', '\u{24BE}'),
+        ('\u{24D9}', '\u{24BF}'), ('\u{24DA}', '\u{24C0}'), ('\u{24DB}', '\u{24C1}'),
+        ('\u{24DC}', '\u{24C2}'), ('\u{24DD}', '\u{24C3}'), ('\u{24DE}', '\u{24C4}'),
+        ('\u{24DF}', '\u{24C5}'), ('\u{24E0}', '\u{24C6}'), ('\u{24E1}', '\u{24C7}'),
+        ('\u{24E2}', '\u{24C8}'), ('\u{24E3}', '\u{24C9}'), ('\u{24E4}', '\u{24CA}'),
+        ('\u{24E5}', '\u{24CB}'), ('\u{24E6}', '\u{24CC}'), ('\u{24E7}', '\u{24CD}'),
+        ('\u{24E8}', '\u{24CE}'), ('\u{24E9}', '\u{24CF}'), ('\u{2C30}', '\u{2C00}'),
+        ('\u{2C31}', '\u{2C01}'), ('\u{2C32}', '\u{2C02}'), ('\u{2C33}', '\u{2C03}'),
+        ('\u{2C34}', '\u{2C04}'), ('\u{2C35}', '\u{2C05}'), ('\u{2C36}', '\u{2C06}'),
+        ('\u{2C37}', '\u{2C07}'), ('\u{2C38}', '\u{2C08}'), ('\u{2C39}', '\u{2C09}'),
+        ('\u{2C3A}', '\u{2C0A}'), ('\u{2C3B}', '\u{2C0B}'), ('\u{2C3C}', '\u{2C0C}'),
+        ('\u{2C3D}', '\u{2C0D}'), ('\u{2C3E}', '\u{2C0E}'), ('\u{2C3F}', '\u{2C0F}'),
+        ('\u{2C40}', '\u{2C10}'), ('\u{2C41}', '\u{2C11}'), ('\u{2C42}', '\u{2C12}'),
+        ('\u{2C43}', '\u{2C13}'), ('\u{2C44}', '\u{2C14}'), ('\u{2C45}', '\u{2C15}'),
+        ('\u{2C46}', '\u{2C16}'), ('\u{2C47}', '\u{2C17}'), ('\u{2C48}', '\u{2C18}'),
+        ('\u{2C49}', '\u{2C19}'), ('\u{2C4A}', '\u{2C1A}'), ('\u{2C4B}', '\u{2C1B}'),
+        ('\u{2C4C}', '\u{2C1C}'), ('\u{2C4D}', '\u{2C1D}'), ('\u{2C4E}', '\u{2C1E}'),
+        ('\u{2C4F}', '\u{2C1F}'), ('\u{2C50}', '\u{2C20}'), ('\u{2C51}', '\u{2C21}'),
+        ('\u{2C52}', '\u{2C22}'), ('\u{2C53}', '\u{2C23}'), ('\u{2C54}', '\u{2C24}'),
+        ('\u{2C55}', '\u{2C25}'), ('\u{2C56}', '\u{2C26}'), ('\u{2C57}', '\u{2C27}'),
+        ('\u{2C58}', '\u{2C28}'), ('\u{2C59}', '\u{2C29}'), ('\u{2C5A}', '\u{2C2A}'),
+        ('\u{2C5B}', '\u{2C2B}'), ('\u{2C5C}', '\u{2C2C}'), ('\u{2C5D}', '\u{2C2D}'),
+        ('\u{2C5E}', '\u{2C2E}'), ('\u{2C5F}', '\u{2C2F}'), ('\u{2C61}', '\u{2C60}'),
+        ('\u{2C65}', '\u{023A}'), ('\u{2C66}', '\u{023E}'), ('\u{2C68}', '\u{2C67}'),
+        ('\u{2C6A}', '\u{2C69}'), ('\u{2C6C}', '\u{2C6B}'), ('\u{2C73}', '\u{2C72}'),
+        ('\u{2C76}', '\u{2C75}'), ('\u{2C81}', '\u{2C80}'), ('\u{2C83}', '\u{2C82}'),
+        ('\u{2C85}', '\u{2C84}'), ('\u{2C87}', '\u{2C86}'), ('\u{2C89}', '\u{2C88}'),
+        ('\u{2C8B}', '\u{2C8A}'), ('\u{2C8D}', '\u{2C8C}'), ('\u{2C8F}', '\u{2C8E}'),
+        ('\u{2C91}', '\u{2C90}'), ('\u{2C93}', '\u{2C92}'), ('\u{2C95}', '\u{2C94}'),
+        ('\u{2C97}', '\u{2C96}'), ('\u{2C99}', '\u{2C98}'), ('\u{2C9B}', '\u{2C9A}'),
+        ('\u{2C9D}', '\u{2C9C}'), ('\u{2C9F}', '\u{2C9E}'), ('\u{2CA1}', '\u{2CA0}'),
+        ('\u{2CA3}', '\u{2CA2}'), ('\u{2CA5}', '\u{2CA4}'), ('\u{2CA7}', '\u{2CA6}'),
+        ('\u{2CA9}', '\u{2CA8}'), ('\u{2CAB}', '\u{2CAA}'), ('\u{2CAD}', '\u{2CAC}'),
+        ('\u{2CAF}', '\u{2CAE}'), ('\u{2CB1}', '\u{2CB0}'), ('\u{2CB3}', '\u{2CB2}'),
+        ('\u{2CB5}', '\u{2CB4}'), ('\u{2CB7}', '\u{2CB6}'), ('\u{2CB9}', '\u{2CB8}'),
+        ('\u{2CBB}', '\u{2CBA}'), ('\u{2CBD}', '\u{2CBC}'), ('\u{2CBF}', '\u{2CBE}'),
+        ('\u{2CC1}', '\u{2CC0}'), ('\u{2CC3}', '\u{2CC2}'), ('\u{2CC5}', '\u{2CC4}'),
+        ('\u{2CC7}', '\u{2CC6}'), ('\u{2CC9}', '\u{2CC8}'), ('\u{2CCB}', '\u{2CCA}'),
+        ('\u{2CCD}', '\u{2CCC}'), ('\u{2CCF}', '\u{2CCE}'), ('\u{2CD1}', '\u{2CD0}'),
+        ('\u{2CD3}', '\u{2CD2}'), ('\u{2CD5}', '\u{2CD4}'), ('\u{2CD7}', '\u{2CD6}'),
+        ('\u{2CD9}', '\u{2CD8}'), ('\u{2CDB}', '\u{2CDA}'), ('\u{2CDD}', '\u{2CDC}'),
+        ('\u{2CDF}', '\u{2CDE}'), ('\u{2CE1}', '\u{2CE0}'), ('\u{2CE3}', '\u{2CE2}'),
+        ('\u{2CEC}', '\u{2CEB}'), ('\u{2CEE}', '\u{2CED}'), ('\u{2CF3}', '\u{2CF2}'),
+        ('\u{2D00}', '\u{10A0}'), ('\u{2D01}', '\u{10A1}'), ('\u{2D02}', '\u{10A2}'),
+        ('\u{2D03}', '\u{10A3}'), ('\u{2D04}', '\u{10A4}'), ('\u{2D05}', '\u{10A5}'),
+        ('\u{2D06}', '\u{10A6}'), ('\u{2D07}', '\u{10A7}'), ('\u{2D08}', '\u{10A8}'),
+        ('\u{2D09}', '\u{10A9}'), ('\u{2D0A}', '\u{10AA}'), ('\u{2D0B}', '\u{10AB}'),
+        ('\u{2D0C}', '\u{10AC}'), ('\u{2D0D}', '\u{10AD}'), ('\u{2D0E}', '\u{10AE}'),
+        ('\u{2D0F}', '\u{10AF}'), ('\u{2D10}', '\u{10B0}'), ('\u{2D11}', '\u{10B1}'),
+        ('\u{2D12}', '\u{10B2}'), ('\u{2D13}', '\u{10B3}'), ('\u{2D14}', '\u{10B4}'),
+        ('\u{2D15}', '\u{10B5}'), ('\u{2D16}', '\u{10B6}'), ('\u{2D17}', '\u{10B7}'),
+        ('\u{2D18}', '\u{10B8}'), ('\u{2D19}', '\u{10B9}'), ('\u{2D1A}', '\u{10BA}'),
+        ('\u{2D1B}', '\u{10BB}'), ('\u{2D1C}', '\u{10BC}'), ('\u{2D1D}', '\u{10BD}'),
+        ('\u{2D1E}', '\u{10BE}'), ('\u{2D1F}', '\u{10BF}'), ('\u{2D20}', '\u{10C0}'),
+        ('\u{2D21}', '\u{10C1}'), ('\u{2D22}', '\u{10C2}'), ('\u{2D23}', '\u{10C3}'),
+        ('\u{2D24}', '\u{10C4}'), ('\u{2D25}', '\u{10C5}'), ('\u{2D27}', '\u{10C7}'),
+        ('\u{2D2D}', '\u{10CD}'), ('\u{A641}', '\u{A640}'), ('\u{A643}', '\u{A642}'),
+        ('\u{A645}', '\u{A644}'), ('\u{A647}', '\u{A646}'), ('\u{A649}', '\u{A648}'),
+        ('\u{A64B}', '\u{A64A}'), ('\u{A64D}', '\u{A64C}'), ('\u{A64F}', '\u{A64E}'),
+        ('\u{A651}', '\u{A650}'), ('\u{A653}', '\u{A652}'), ('\u{A655}', '\u{A654}'),
+        ('\u{A657}', '\u{A656}'), ('\u{A659}', '\u{A658}'), ('\u{A65B}', '\u{A65A}'),
+        ('\u{A65D}', '\u{A65C}'), ('\u{A65F}', '\u{A65E}'), ('\u{A661}', '\u{A660}'),
+        ('\u{A663}', '\u{A662}'), ('\u{A665}', '\u{A664}'), ('\u{A667}', '\u{A666}'),
+        ('\u{A669}', '\u{A668}'), ('\u{A66B}', '\u{A66A}'), ('\u{A66D}', '\u{A66C}'),
+        ('\u{A681}', '\u{A680}'), ('\u{A683}', '\u{A682}'), ('\u{A685}', '\u{A684}'),
+        ('\u{A687}', '\u{A686}'), ('\u{A689}', '\u{A688}'), ('\u{A68B}', '\u{A68A}'),
+        ('\u{A68D}', '\u{A68C}'), ('\u{A68F}', '\u{A68E}'), ('\u{A691}', '\u{A690}'),
+        ('\u{A693}', '\u{A692}'), ('\u{A695}', '\u{A694}'), ('\u{A697}', '\u{A696}'),
+        ('\u{A699}', '\u{A698}'), ('\u{A69B}', '\u{A69A}'), ('\u{A723}', '\u{A722}'),
+        ('\u{A725}', '\u{A724}'), ('\u{A727}', '\u{A726}'), ('\u{A729}', '\u{A728}'),
+        ('\u{A72B}', '\u{A72A}'), ('\u{A72D}', '\u{A72C}'), ('\u{A72F}', '\u{A72E}'),
+        ('\u{A733}', '\u{A732}'), ('\u{A735}', '\u{A734}'), ('\u{A737}', '\u{A736}'),
+        ('\u{A739}', '\u{A738}'), ('\u{A73B}', '\u{A73A}'), ('\u{A73D}', '\u{A73C}'),
+        ('\u{A73F}', '\u{A73E}'), ('\u{A741}', '\u{A740}'), ('\u{A743}', '\u{A742}'),
+        ('\u{A745}', '\u{A744}'), ('\u{A747}', '\u{A746}'), ('\u{A749}', '\u{A748}'),
+        ('\u{A74B}', '\u{A74A}'), ('\u{A74D}', '\u{A74C}'), ('\u{A74F}', '\u{A74E}'),
+        ('\u{A751}', '\u{A750}'), ('\u{A753}', '\u{A752}'), ('\u{A755}', '\u{A754}'),
+        ('\u{A757}', '\u{A756}'), ('\u{A759}', '\u{A758}'), ('\u{A75B}', '\u{A75A}'),
+        ('\u{A75D}', '\u{A75C}'), ('\u{A75F}', '\u{A75E}'), ('\u{A761}', '\u{A760}'),
+        ('\u{A763}', '\u{A762}'), ('\u{A765}', '\u{A764}'), ('\u{A767}', '\u{A766}'),
+        ('\u{A769}', '\u{A768}'), ('\u{A76B}', '\u{A76A}'), ('\u{A76D}', '\u{A76C}'),
+        ('\u{A76F}', '\u{A76E}'), ('\u{A77A}', '\u{A779}'), ('\u{A77C}', '\u{A77B}'),
+        ('\u{A77F}', '\u{A77E}'), ('\u{A781}', '\u{A780}'), ('\u{A783}', '\u{A782}'),
+        ('\u{A785}', '\u{A784}'), ('\u{A787}', '\u{A786}'), ('\u{A78C}', '\u{A78B}'),
+        ('\u{A791}', '\u{A790}'), ('\u{A793}', '\u{A792}'), ('\u{A794}', '\u{A7C4}'),
+        ('\u{A797}', '\u{A796}'), ('\u{A799}', '\u{A798}'), ('\u{A79B}', '\u{A79A}'),
+        ('\u{A79D}', '\u{A79C}'), ('\u{A79F}', '\u{A79E}'), ('\u{A7A1}', '\u{A7A0}'),
+        ('\u{A7A3}', '\u{A7A2}'), ('\u{A7A5}', '\u{A7A4}'), ('\u{A7A7}', '\u{A7A6}'),
+        ('\u{A7A9}', '\u{A7A8}'), ('\u{A7B5}', '\u{A7B4}'), ('\u{A7B7}', '\u{A7B6}'),
+        ('\u{A7B9}', '\u{A7B8}'), ('\u{A7BB}', '\u{A7BA}'), ('\u{A7BD}', '\u{A7BC}'),
+        ('\u{A7BF}', '\u{A7BE}'), ('\u{A7C1}', '\u{A7C0}'), ('\u{A7C3}', '\u{A7C2}'),
+        ('\u{A7C8}', '\u{A7C7}'), ('\u{A7CA}', '\u{A7C9}'), ('\u{A7CD}', '\u{A7CC}'),
+        ('\u{A7CF}', '\u{A7CE}'), ('\u{A7D1}', '\u{A7D0}'), ('\u{A7D3}', '\u{A7D2}'),
+        ('\u{A7D5}', '\u{A7D4}'), ('\u{A7D7}', '\u{A7D6}'), ('\u{A7D9}', '\u{A7D8}'),
+        ('\u{A7DB}', '\u{A7DA}'), ('\u{A7F6}', '\u{A7F5}'), ('\u{AB53}', '\u{A7B3}'),
+        ('\u{AB70}', '\u{13A0}'), ('\u{AB71}', '\u{13A1}'), ('\u{AB72}', '\u{13A2}'),
+        ('\u{AB73}', '\u{13A3}'), ('\u{AB74}', '\u{13A4}'), ('\u{AB75}', '\u{13A5}'),
+        ('\u{AB76}', '\u{13A6}'), ('\u{AB77}', '\u{13A7}'), ('\u{AB78}', '\u{13A8}'),
+        ('\u{AB79}', '\u{13A9}'), ('\u{AB7A}', '\u{13AA}'), ('\u{AB7B}', '\u{13AB}'),
+        ('\u{AB7C}', '\u{13AC}'), ('\u{AB7D}', '\u{13AD}'), ('\u{AB7E}', '\u{13AE}'),
+        ('\u{AB7F}', '\u{13AF}'), ('\u{AB80}', '\u{13B0}'), ('\u{AB81}', '\u{13B1}'),
+        ('\u{AB82}', '\u{13B2}'), ('\u{AB83}', '\u{13B3}'), ('\u{AB84}', '\u{13B4}'),
+        ('\u{AB85}', '\u{13B5}'), ('\u{AB86}', '\u{13B6}'), ('\u{AB87}', '\u{13B7}'),
+        ('\u{AB88}', '\u{13B8}'), ('\u{AB89}', '\u{13B9}'), ('\u{AB8A}', '\u{13BA}'),
+        ('\u{AB8B}', '\u{13BB}'), ('\u{AB8C}', '\u{13BC}'), ('\u{AB8D}', '\u{13BD}'),
+        ('\u{AB8E}', '\u{13BE}'), ('\u{AB8F}', '\u{13BF}'), ('\u{AB90}', '\u{13C0}'),
+        ('\u{AB91}', '\u{13C1}'), ('\u{AB92}', '\u{13C2}'), ('\u{AB93}', '\u{13C3}'),
+        ('\u{AB94}', '\u{13C4}'), ('\u{AB95}', '\u{13C5}'), ('\u{AB96}', '\u{13C6}'),
+        ('\u{AB97}', '\u{13C7}'), ('\u{AB98}', '\u{13C8}'), ('\u{AB99}', '\u{13C9}'),
+        ('\u{AB9A}', '\u{13CA}'), ('\u{AB9B}', '\u{13CB}'), ('\u{AB9C}', '\u{13CC}'),
+        ('\u{AB9D}', '\u{13CD}'), ('\u{AB9E}', '\u{13CE}'), ('\u{AB9F}', '\u{13CF}'),
+        ('\u{ABA0}', '\u{13D0}'), ('\u{ABA1}', '\u{13D1}'), ('\u{ABA2}', '\u{13D2}'),
+        ('\u{ABA3}', '\u{13D3}'), ('\u{ABA4}', '\u{13D4}'), ('\u{ABA5}', '\u{13D5}'),
+        ('\u{ABA6}', '\u{13D6}'), ('\u{ABA7}', '\u{13D7}'), ('\u{ABA8}', '\u{13D8}'),
+        ('\u{ABA9}', '\u{13D9}'), ('\u{ABAA}', '\u{13DA}'), ('\u{ABAB}', '\u{13DB}'),
+        ('\u{ABAC}', '\u{13DC}'), ('\u{ABAD}', '\u{13DD}'), ('\u{ABAE}', '\u{13DE}'),
+        ('\u{ABAF}', '\u{13DF}'), ('\u{ABB0}', '\u{13E0}'), ('\u{ABB1}', '\u{13E1}'),
+        ('\u{ABB2}', '\u{13E2}'), ('\u{ABB3}', '\u{13E3}'), ('\u{ABB4}', '\u{13E4}'),
+        ('\u{ABB5}', '\u{13E5}'), ('\u{ABB6}', '\u{13E6}'), ('\u{ABB7}', '\u{13E7}'),
+        ('\u{ABB8}', '\u{13E8}'), ('\u{ABB9}', '\u{13E9}'), ('\u{ABBA}', '\u{13EA}'),
+        ('\u{ABBB}', '\u{13EB}'), ('\u{ABBC}', '\u{13EC}'), ('\u{ABBD}', '\u{13ED}'),
+        ('\u{ABBE}', '\u{13EE}'), ('\u{ABBF}', '\u{13EF}'), ('\u{FF41}', '\u{FF21}'),
+        ('\u{FF42}', '\u{FF22}'), ('\u{FF43}', '\u{FF23}'), ('\u{FF44}', '\u{FF24}'),
+        ('\u{FF45}', '\u{FF25}'), ('\u{FF46}', '\u{FF26}'), ('\u{FF47}', '\u{FF27}'),
+        ('\u{FF48}', '\u{FF28}'), ('\u{FF49}', '\u{FF29}'), ('\u{FF4A}', '\u{FF2A}'),
+        ('\u{FF4B}', '\u{FF2B}'), ('\u{FF4C}', '\u{FF2C}'), ('\u{FF4D}', '\u{FF2D}'),
+        ('\u{FF4E}', '\u{FF2E}'), ('\u{FF4F}', '\u{FF2F}'), ('\u{FF50}', '\u{FF30}'),
+        ('\u{FF51}', '\u{FF31}'), ('\u{FF52}', '\u{FF32}'), ('\u{FF53}', '\u{FF33}'),
+        ('\u{FF54}', '\u{FF34}'), ('\u{FF55}', '\u{FF35}'), ('\u{FF56}', '\u{FF36}'),
+        ('\u{FF57}', '\u{FF37}'), ('\u{FF58}', '\u{FF38}'), ('\u{FF59}', '\u{FF39}'),
+        ('\u{FF5A}', '\u{FF3A}'), ('\u{10428}', '\u{10400}'), ('\u{10429}', '\u{10401}'),
+        ('\u{1042A}', '\u{10402}'), ('\u{1042B}', '\u{10403}'), ('\u{1042C}', '\u{10404}'),
+        ('\u{1042D}', '\u{10405}'), ('\u{1042E}', '\u{10406}'), ('\u{1042F}', '\u{10407}'),
+        ('\u{10430}', '\u{10408}'), ('\u{10431}', '\u{10409}'), ('\u{10432}', '\u{1040A}'),
+        ('\u{10433}', '\u{1040B}'), ('\u{10434}', '\u{1040C}'), ('\u{10435}', '\u{1040D}'),
+        ('\u{10436}', '\u{1040E}'), ('\u{10437}', '\u{1040F}'), ('\u{10438}', '\u{10410}'),
+        ('\u{10439}', '\u{10411}'), ('\u{1043A}', '\u{10412}'), ('\u{1043B}', '\u{10413}'),
+        ('\u{1043C}', '\u{10414}'), ('\u{1043D}', '\u{10415}'), ('\u{1043E}', '\u{10416}'),
+        ('\u{1043F}', '\u{10417}'), ('\u{10440}', '\u{10418}'), ('\u{10441}', '\u{10419}'),
+        ('\u{10442}', '\u{1041A}'), ('\u{10443}', '\u{1041B}'), ('\u{10444}', '\u{1041C}'),
+        ('\u{10445}', '\u{1041D}'), ('\u{10446}', '\u{1041E}'), ('\u{10447}', '\u{1041F}'),
+        ('\u{10448}', '\u{10420}'), ('\u{10449}', '\u{10421}'), ('\u{1044A}', '\u{10422}'),
+        ('\u{1044B}', '\u{10423}'), ('\u{1044C}', '\u{10424}'), ('\u{1044D}', '\u{10425}'),
+        ('\u{1044E}', '\u{10426}'), ('\u{1044F}', '\u{10427}'), ('\u{104D8}', '\u{104B0}'),
+        ('\u{104D9}', '\u{104B1}'), ('\u{104DA}', '\u{104B2}'), ('\u{104DB}', '\u{104B3}'),
+        ('\u{104DC}', '\u{104B4}'), ('\u{104DD}', '\u{104B5}'), ('\u{104DE}', '\u{104B6}'),
+        ('\u{104DF}', '\u{104B7}'), ('\u{104E0}', '\u{104B8}'), ('\u{104E1}', '\u{104B9}'),
+        ('\u{104E2}', '\u{104BA}'), ('\u{104E3}', '\u{104BB}'), ('\u{104E4}', '\u{104BC}'),
+        ('\u{104E5}', '\u{104BD}'), ('\u{104E6}', '\u{104BE}'), ('\u{104E7}', '\u{104BF}'),
+        ('\u{104E8}', '\u{104C0}'), ('\u{104E9}', '\u{104C1}'), ('\u{104EA}', '\u{104C2}'),
+        ('\u{104EB}', '\u{104C3}'), ('\u{104EC}', '\u{104C4}'), ('\u{104ED}', '\u{104C5}'),
+        ('\u{104EE}', '\u{104C6}'), ('\u{104EF}', '\u{104C7}'), ('\u{104F0}', '\u{104C8}'),
+        ('\u{104F1}', '\u{104C9}'), ('\u{104F2}', '\u{104CA}'), ('\u{104F3}', '\u{104CB}'),
+        ('\u{104F4}', '\u{104CC}'), ('\u{104F5}', '\u{104CD}'), ('\u{104F6}', '\u{104CE}'),
+        ('\u{104F7}', '\u{104CF}'), ('\u{104F8}', '\u{104D0}'), ('\u{104F9}', '\u{104D1}'),
+        ('\u{104FA}', '\u{104D2}'), ('\u{104FB}', '\u{104D3}'), ('\u{10597}', '\u{10570}'),
+        ('\u{10598}', '\u{10571}'), ('\u{10599}', '\u{10572}'), ('\u{1059A}', '\u{10573}'),
+        ('\u{1059B}', '\u{10574}'), ('\u{1059C}', '\u{10575}'), ('\u{1059D}', '\u{10576}'),
+        ('\u{1059E}', '\u{10577}'), ('\u{1059F}', '\u{10578}'), ('\u{105A0}', '\u{10579}'),
+        ('\u{105A1}', '\u{1057A}'), ('\u{105A3}', '\u{1057C}'), ('\u{105A4}', '\u{1057D}'),
+        ('\u{105A5}', '\u{1057E}'), ('\u{105A6}', '\u{1057F}'), ('\u{105A7}', '\u{10580}'),
+        ('\u{105A8}', '\u{10581}'), ('\u{105A9}', '\u{10582}'), ('\u{105AA}', '\u{10583}'),
+        ('\u{105AB}', '\u{10584}'), ('\u{105AC}', '\u{10585}'), ('\u{105AD}', '\u{10586}'),
+        ('\u{105AE}', '\u{10587}'), ('\u{105AF}', '\u{10588}'), ('\u{105B0}', '\u{10589}'),
+        ('\u{105B1}', '\u{1058A}'), ('\u{105B3}', '\u{1058C}'), ('\u{105B4}', '\u{1058D}'),
+        ('\u{105B5}', '\u{1058E}'), ('\u{105B6}', '\u{1058F}'), ('\u{105B7}', '\u{10590}'),
+        ('\u{105B8}', '\u{10591}'), ('\u{105B9}', '\u{10592}'), ('\u{105BB}', '\u{10594}'),
+        ('\u{105BC}', '\u{10595}'), ('\u{10CC0}', '\u{10C80}'), ('\u{10CC1}', '\u{10C81}'),
+        ('\u{10CC2}', '\u{10C82}'), ('\u{10CC3}', '\u{10C83}'), ('\u{10CC4}', '\u{10C84}'),
+        ('\u{10CC5}', '\u{10C85}'), ('\u{10CC6}', '\u{10C86}'), ('\u{10CC7}', '\u{10C87}'),
+        ('\u{10CC8}', '\u{10C88}'), ('\u{10CC9}', '\u{10C89}'), ('\u{10CCA}', '\u{10C8A}'),
+        ('\u{10CCB}', '\u{10C8B}'), ('\u{10CCC}', '\u{10C8C}'), ('\u{10CCD}', '\u{10C8D}'),
+        ('\u{10CCE}', '\u{10C8E}'), ('\u{10CCF}', '\u{10C8F}'), ('\u{10CD0}', '\u{10C90}'),
+        ('\u{10CD1}', '\u{10C91}'), ('\u{10CD2}', '\u{10C92}'), ('\u{10CD3}', '\u{10C93}'),
+        ('\u{10CD4}', '\u{10C94}'), ('\u{10CD5}', '\u{10C95}'), ('\u{10CD6}', '\u{10C96}'),
+        ('\u{10CD7}', '\u{10C97}'), ('\u{10CD8}', '\u{10C98}'), ('\u{10CD9}', '\u{10C99}'),
+        ('\u{10CDA}', '\u{10C9A}'), ('\u{10CDB}', '\u{10C9B}'), ('\u{10CDC}', '\u{10C9C}'),
+        ('\u{10CDD}', '\u{10C9D}'), ('\u{10CDE}', '\u{10C9E}'), ('\u{10CDF}', '\u{10C9F}'),
+        ('\u{10CE0}', '\u{10CA0}'), ('\u{10CE1}', '\u{10CA1}'), ('\u{10CE2}', '\u{10CA2}'),
+        ('\u{10CE3}', '\u{10CA3}'), ('\u{10CE4}', '\u{10CA4}'), ('\u{10CE5}', '\u{10CA5}'),
+        ('\u{10CE6}', '\u{10CA6}'), ('\u{10CE7}', '\u{10CA7}'), ('\u{10CE8}', '\u{10CA8}'),
+        ('\u{10CE9}', '\u{10CA9}'), ('\u{10CEA}', '\u{10CAA}'), ('\u{10CEB}', '\u{10CAB}'),
+        ('\u{10CEC}', '\u{10CAC}'), ('\u{10CED}', '\u{10CAD}'), ('\u{10CEE}', '\u{10CAE}'),
+        ('\u{10CEF}', '\u{10CAF}'), ('\u{10CF0}', '\u{10CB0}'), ('\u{10CF1}', '\u{10CB1}'),
+        ('\u{10CF2}', '\u{10CB2}'), ('\u{10D70}', '\u{10D50}'), ('\u{10D71}', '\u{10D51}'),
+        ('\u{10D72}', '\u{10D52}'), ('\u{10D73}', '\u{10D53}'), ('\u{10D74}', '\u{10D54}'),
+        ('\u{10D75}', '\u{10D55}'), ('\u{10D76}', '\u{10D56}'), ('\u{10D77}', '\u{10D57}'),
+        ('\u{10D78}', '\u{10D58}'), ('\u{10D79}', '\u{10D59}'), ('\u{10D7A}', '\u{10D5A}'),
+        ('\u{10D7B}', '\u{10D5B}'), ('\u{10D7C}', '\u{10D5C}'), ('\u{10D7D}', '\u{10D5D}'),
+        ('\u{10D7E}', '\u{10D5E}'), ('\u{10D7F}', '\u{10D5F}'), ('\u{10D80}', '\u{10D60}'),
+        ('\u{10D81}', '\u{10D61}'), ('\u{10D82}', '\u{10D62}'), ('\u{10D83}', '\u{10D63}'),
+        ('\u{10D84}', '\u{10D64}'), ('\u{10D85}', '\u{10D65}'), ('\u{118C0}', '\u{118A0}'),
+        ('\u{118C1}', '\u{118A1}'), ('\u{118C2}', '\u{118A2}'), ('\u{118C3}', '\u{118A3}'),
+        ('\u{118C4}', '\u{118A4}'), ('\u{118C5}', '\u{118A5}'), ('\u{118C6}', '\u{118A6}'),
+        ('\u{118C7}', '\u{118A7}'), ('\u{118C8}', '\u{118A8}'), ('\u{118C9}', '\u{118A9}'),
+        ('\u{118CA}', '\u{118AA}'), ('\u{118CB}', '\u{118AB}'), ('\u{118CC}', '\u{118AC}'),
+        ('\u{118CD}', '\u{118AD}'), ('\u{118CE}', '\u{118AE}'), ('\u{118CF}', '\u{118AF}'),
+        ('\u{118D0}', '\u{118B0}'), ('\u{118D1}', '\u{118B1}'), ('\u{118D2}', '\u{118B2}'),
+        ('\u{118D3}', '\u{118B3}'), ('\u{118D4}', '\u{118B4}'), ('\u{118D5}', '\u{118B5}'),
+        ('\u{118D6}', '\u{118B6}'), ('\u{118D7}', '\u{118B7}'), ('\u{118D8}', '\u{118B8}'),
+        ('\u{118D9}', '\u{118B9}'), ('\u{118DA}', '\u{118BA}'), ('\u{118DB}', '\u{118BB}'),
+        ('\u{118DC}', '\u{118BC}'), ('\u{118DD}', '\u{118BD}'), ('\u{118DE}', '\u{118BE}'),
+        ('\u{118DF}', '\u{118BF}'), ('\u{16E60}', '\u{16E40}'), ('\u{16E61}', '\u{16E41}'),
+        ('\u{16E62}', '\u{16E42}'), ('\u{16E63}', '\u{16E43}'), ('\u{16E64}', '\u{16E44}'),
+        ('\u{16E65}', '\u{16E45}'), ('\u{16E66}', '\u{16E46}'), ('\u{16E67}', '\u{16E47}'),
+        ('\u{16E68}', '\u{16E48}'), ('\u{16E69}', '\u{16E49}'), ('\u{16E6A}', '\u{16E4A}'),
+        ('\u{16E6B}', '\u{16E4B}'), ('\u{16E6C}', '\u{16E4C}'), ('\u{16E6D}', '\u{16E4D}'),
+        ('\u{16E6E}', '\u{16E4E}'), ('\u{16E6F}', '\u{16E4F}'), ('\u{16E70}', '\u{16E50}'),
+        ('\u{16E71}', '\u{16E51}'), ('\u{16E72}', '\u{16E52}'), ('\u{16E73}', '\u{16E53}'),
+        ('\u{16E74}', '\u{16E54}'), ('\u{16E75}', '\u{16E55}'), ('\u{16E76}', '\u{16E56}'),
+        ('\u{16E77}', '\u{16E57}'), ('\u{16E78}', '\u{16E58}'), ('\u{16E79}', '\u{16E59}'),
+        ('\u{16E7A}', '\u{16E5A}'), ('\u{16E7B}', '\u{16E5B}'), ('\u{16E7C}', '\u{16E5C}'),
+        ('\u{16E7D}', '\u{16E5D}'), ('\u{16E7E}', '\u{16E5E}'), ('\u{16E7F}', '\u{16E5F}'),
+        ('\u{16EBB}', '\u{16EA0}'), ('\u{16EBC}', '\u{16EA1}'), ('\u{16EBD}', '\u{16EA2}'),
+        ('\u{16EBE}', '\u{16EA3}'), ('\u{16EBF}', '\u{16EA4}'), ('\u{16EC0}', '\u{16EA5}'),
+        ('\u{16EC1}', '\u{16EA6}'), ('\u{16EC2}', '\u{16EA7}'), ('\u{16EC3}', '\u{16EA8}'),
+        ('\u{16EC4}', '\u{16EA9}'), ('\u{16EC5}', '\u{16EAA}'), ('\u{16EC6}', '\u{16EAB}'),
+        ('\u{16EC7}', '\u{16EAC}'), ('\u{16EC8}', '\u{16EAD}'), ('\u{16EC9}', '\u{16EAE}'),
+        ('\u{16ECA}', '\u{16EAF}'), ('\u{16ECB}', '\u{16EB0}'), ('\u{16ECC}', '\u{16EB1}'),
+        ('\u{16ECD}', '\u{16EB2}'), ('\u{16ECE}', '\u{16EB3}'), ('\u{16ECF}', '\u{16EB4}'),
+        ('\u{16ED0}', '\u{16EB5}'), ('\u{16ED1}', '\u{16EB6}'), ('\u{16ED2}', '\u{16EB7}'),
+        ('\u{16ED3}', '\u{16EB8}'), ('\u{1E922}', '\u{1E900}'), ('\u{1E923}', '\u{1E901}'),
+        ('\u{1E924}', '\u{1E902}'), ('\u{1E925}', '\u{1E903}'), ('\u{1E926}', '\u{1E904}'),
+        ('\u{1E927}', '\u{1E905}'), ('\u{1E928}', '\u{1E906}'), ('\u{1E929}', '\u{1E907}'),
+        ('\u{1E92A}', '\u{1E908}'), ('\u{1E92B}', '\u{1E909}'), ('\u{1E92C}', '\u{1E90A}'),
+        ('\u{1E92D}', '\u{1E90B}'), ('\u{1E92E}', '\u{1E90C}'), ('\u{1E92F}', '\u{1E90D}'),
+        ('\u{1E930}', '\u{1E90E}'), ('\u{1E931}', '\u{1E90F}'), ('\u{1E932}', '\u{1E910}'),
+        ('\u{1E933}', '\u{1E911}'), ('\u{1E934}', '\u{1E912}'), ('\u{1E935}', '\u{1E913}'),
+        ('\u{1E936}', '\u{1E914}'), ('\u{1E937}', '\u{1E915}'), ('\u{1E938}', '\u{1E916}'),
+        ('\u{1E939}', '\u{1E917}'), ('\u{1E93A}', '\u{1E918}'), ('\u{1E93B}', '\u{1E919}'),
+        ('\u{1E93C}', '\u{1E91A}'), ('\u{1E93D}', '\u{1E91B}'), ('\u{1E93E}', '\u{1E91C}'),
+        ('\u{1E93F}', '\u{1E91D}'), ('\u{1E940}', '\u{1E91E}'), ('\u{1E941}', '\u{1E91F}'),
+        ('\u{1E942}', '\u{1E920}'), ('\u{1E943}', '\u{1E921}'),
+    ],
+    full: &[
+        ('\u{00DF}', ['\u{0053}', '\u{0053}', '\u{0000}']), ('\u{0149}', ['\u{02BC}', '\u{004E}', '\u{0000}']),
+        ('\u{01F0}', ['\u{004A}', '\u{030C}', '\u{0000}']), ('\u{0390}', ['\u{0399}', '\u{0308}', '\u{0301}']),
+        ('\u{03B0}', ['\u{03A5}', '\u{0308}', '\u{0301}']), ('\u{0587}', ['\u{0535}', '\u{0552}', '\u{0000}']),
+        ('\u{1E96}', ['\u{0048}', '\u{0331}', '\u{0000}']), ('\u{1E97}', ['\u{0054}', '\u{0308}', '\u{0000}']),
+        ('\u{1E98}', ['\u{0057}', '\u{030A}', '\u{0000}']), ('\u{1E99}', ['\u{0059}', '\u{030A}', '\u{0000}']),
+        ('\u{1E9A}', ['\u{0041}', '\u{02BE}', '\u{0000}']), ('\u{1F50}', ['\u{03A5}', '\u{0313}', '\u{0000}']),
+        ('\u{1F52}', ['\u{03A5}', '\u{0313}', '\u{0300}']), ('\u{1F54}', ['\u{03A5}', '\u{0313}', '\u{0301}']),
+        ('\u{1F56}', ['\u{03A5}', '\u{0313}', '\u{0342}']), ('\u{1F80}', ['\u{1F08}', '\u{0399}', '\u{0000}']),
+        ('\u{1F81}', ['\u{1F09}', '\u{0399}', '\u{0000}']), ('\u{1F82}', ['\u{1F0A}', '\u{0399}', '\u{0000}']),
+        ('\u{1F83}', ['\u{1F0B}', '\u{0399}', '\u{0000}']), ('\u{1F84}', ['\u{1F0C}', '\u{0399}', '\u{0000}']),
+        ('\u{1F85}', ['\u{1F0D}', '\u{0399}', '\u{0000}']), ('\u{1F86}', ['\u{1F0E}', '\u{0399}', '\u{0000}']),
+        ('\u{1F87}', ['\u{1F0F}', '\u{0399}', '\u{0000}']), ('\u{1F88}', ['\u{1F08}', '\u{0399}', '\u{0000}']),
+        ('\u{1F89}', ['\u{1F09}', '\u{0399}', '\u{0000}']), ('\u{1F8A}', ['\u{1F0A}', '\u{0399}', '\u{0000}']),
+        ('\u{1F8B}', ['\u{1F0B}', '\u{0399}', '\u{0000}']), ('\u{1F8C}', ['\u{1F0C}', '\u{0399}', '\u{0000}']),
+        ('\u{1F8D}', ['\u{1F0D}', '\u{0399}', '\u{0000}']), ('\u{1F8E}', ['\u{1F0E}', '\u{0399}', '\u{0000}']),
+        ('\u{1F8F}', ['\u{1F0F}', '\u{0399}', '\u{0000}']), ('\u{1F90}', ['\u{1F28}', '\u{0399}', '\u{0000}']),
+        ('\u{1F91}', ['\u{1F29}', '\u{0399}', '\u{0000}']), ('\u{1F92}', ['\u{1F2A}', '\u{0399}', '\u{0000}']),
+        ('\u{1F93}', ['\u{1F2B}', '\u{0399}', '\u{0000}']), ('\u{1F94}', ['\u{1F2C}', '\u{0399}', '\u{0000}']),
+        ('\u{1F95}', ['\u{1F2D}', '\u{0399}', '\u{0000}']), ('\u{1F96}', ['\u{1F2E}', '\u{0399}', '\u{0000}']),
+        ('\u{1F97}', ['\u{1F2F}', '\u{0399}', '\u{0000}']), ('\u{1F98}', ['\u{1F28}', '\u{0399}', '\u{0000}']),
+        ('\u{1F99}', ['\u{1F29}', '\u{0399}', '\u{0000}']), ('\u{1F9A}', ['\u{1F2A}', '\u{0399}', '\u{0000}']),
+        ('\u{1F9B}', ['\u{1F2B}', '\u{0399}', '\u{0000}']), ('\u{1F9C}', ['\u{1F2C}', '\u{0399}', '\u{0000}']),
+        ('\u{1F9D}', ['\u{1F2D}', '\u{0399}', '\u{0000}']), ('\u{1F9E}', ['\u{1F2E}', '\u{0399}', '\u{0000}']),
+        ('\u{1F9F}', ['\u{1F2F}', '\u{0399}', '\u{0000}']), ('\u{1FA0}', ['\u{1F68}', '\u{0399}', '\u{0000}']),
+        ('\u{1FA1}', ['\u{1F69}', '\u{0399}', '\u{0000}']), ('\u{1FA2}', ['\u{1F6A}', '\u{0399}', '\u{0000}']),
+        ('\u{1FA3}', ['\u{1F6B}', '\u{0399}', '\u{0000}']), ('\u{1FA4}', ['\u{1F6C}', '\u{0399}', '\u{0000}']),
+        ('\u{1FA5}', ['\u{1F6D}', '\u{0399}', '\u{0000}']), ('\u{1FA6}', ['\u{1F6E}', '\u{0399}', '\u{0000}']),
+        ('\u{1FA7}', ['\u{1F6F}', '\u{0399}', '\u{0000}']), ('\u{1FA8}', ['\u{1F68}', '\u{0399}', '\u{0000}']),
+        ('\u{1FA9}', ['\u{1F69}', '\u{0399}', '\u{0000}']), ('\u{1FAA}', ['\u{1F6A}', '\u{0399}', '\u{0000}']),
+        ('\u{1FAB}', ['\u{1F6B}', '\u{0399}', '\u{0000}']), ('\u{1FAC}', ['\u{1F6C}', '\u{0399}', '\u{0000}']),
+        ('\u{1FAD}', ['\u{1F6D}', '\u{0399}', '\u{0000}']), ('\u{1FAE}', ['\u{1F6E}', '\u{0399}', '\u{0000}']),
+        ('\u{1FAF}', ['\u{1F6F}', '\u{0399}', '\u{0000}']), ('\u{1FB2}', ['\u{1FBA}', '\u{0399}', '\u{0000}']),
+        ('\u{1FB3}', ['\u{0391}', '\u{0399}', '\u{0000}']), ('\u{1FB4}', ['\u{0386}', '\u{0399}', '\u{0000}']),
+        ('\u{1FB6}', ['\u{0391}', '\u{0342}', '\u{0000}']), ('\u{1FB7}', ['\u{0391}', '\u{0342}', '\u{0399}']),
+        ('\u{1FBC}', ['\u{0391}', '\u{0399}', '\u{0000}']), ('\u{1FC2}', ['\u{1FCA}', '\u{0399}', '\u{0000}']),
+        ('\u{1FC3}', ['\u{0397}', '\u{0399}', '\u{0000}']), ('\u{1FC4}', ['\u{0389}', '\u{0399}', '\u{0000}']),
+        ('\u{1FC6}', ['\u{0397}', '\u{0342}', '\u{0000}']), ('\u{1FC7}', ['\u{0397}', '\u{0342}', '\u{0399}']),
+        ('\u{1FCC}', ['\u{0397}', '\u{0399}', '\u{0000}']), ('\u{1FD2}', ['\u{0399}', '\u{0308}', '\u{0300}']),
+        ('\u{1FD3}', ['\u{0399}', '\u{0308}', '\u{0301}']), ('\u{1FD6}', ['\u{0399}', '\u{0342}', '\u{0000}']),
+        ('\u{1FD7}', ['\u{0399}', '\u{0308}', '\u{0342}']), ('\u{1FE2}', ['\u{03A5}', '\u{0308}', '\u{0300}']),
+        ('\u{1FE3}', ['\u{03A5}', '\u{0308}', '\u{0301}']), ('\u{1FE4}', ['\u{03A1}', '\u{0313}', '\u{0000}']),
+        ('\u{1FE6}', ['\u{03A5}', '\u{0342}', '\u{0000}']), ('\u{1FE7}', ['\u{03A5}', '\u{0308}', '\u{0342}']),
+        ('\u{1FF2}', ['\u{1FFA}', '\u{0399}', '\u{0000}']), ('\u{1FF3}', ['\u{03A9}', '\u{0399}', '\u{0000}']),
+        ('\u{1FF4}', ['\u{038F}', '\u{0399}', '\u{0000}']), ('\u{1FF6}', ['\u{03A9}', '\u{0342}', '\u{0000}']),
+        ('\u{1FF7}', ['\u{03A9}', '\u{0342}', '\u{0399}']), ('\u{1FFC}', ['\u{03A9}', '\u{0399}', '\u{0000}']),
+        ('\u{FB00}', ['\u{0046}', '\u{0046}', '\u{0000}']), ('\u{FB01}', ['\u{0046}', '\u{0049}', '\u{0000}']),
+        ('\u{FB02}', ['\u{0046}', '\u{004C}', '\u{0000}']), ('\u{FB03}', ['\u{0046}', '\u{0046}', '\u{0049}']),
+        ('\u{FB04}', ['\u{0046}', '\u{0046}', '\u{004C}']), ('\u{FB05}', ['\u{0053}', '\u{0054}', '\u{0000}']),
+        ('\u{FB06}', ['\u{0053}', '\u{0054}', '\u{0000}']), ('\u{FB13}', ['\u{0544}', '\u{0546}', '\u{0000}']),
+        ('\u{FB14}', ['\u{0544}', '\u{0535}', '\u{0000}']), ('\u{FB15}', ['\u{0544}', '\u{053B}', '\u{0000}']),
+        ('\u{FB16}', ['\u{054E}', '\u{0546}', '\u{0000}']), ('\u{FB17}', ['\u{0544}', '\u{053D}', '\u{0000}']),
     ],
 };
 
+#[rustfmt::skip]
+static GRAPHEME_CLUSTER_BREAK_TABLE: &[(char, char, GraphemeClusterBreak)] = &[
+    ('\u{0000}', '\u{0009}', GraphemeClusterBreak::Control), ('\u{000A}', '\u{000A}', GraphemeClusterBreak::LF),
+    ('\u{000B}', '\u{000C}', GraphemeClusterBreak::Control), ('\u{000D}', '\u{000D}', GraphemeClusterBreak::CR),
+    ('\u{000E}', '\u{001F}', GraphemeClusterBreak::Control), ('\u{007F}', '\u{009F}', GraphemeClusterBreak::Control),
+    ('\u{00AD}', '\u{00AD}', GraphemeClusterBreak::Control), ('\u{0300}', '\u{036F}', GraphemeClusterBreak::Extend),
+    ('\u{0483}', '\u{0489}', GraphemeClusterBreak::Extend), ('\u{0591}', '\u{05BD}', GraphemeClusterBreak::Extend),
+    ('\u{05BF}', '\u{05BF}', GraphemeClusterBreak::Extend), ('\u{05C1}', '\u{05C2}', GraphemeClusterBreak::Extend),
+    ('\u{05C4}', '\u{05C5}', GraphemeClusterBreak::Extend), ('\u{05C7}', '\u{05C7}', GraphemeClusterBreak::Extend),
+    ('\u{0600}', '\u{0605}', GraphemeClusterBreak::Prepend), ('\u{0610}', '\u{061A}', GraphemeClusterBreak::Extend),
+    ('\u{061C}', '\u{061C}', GraphemeClusterBreak::Control), ('\u{064B}', '\u{065F}', GraphemeClusterBreak::Extend),
+    ('\u{0670}', '\u{0670}', GraphemeClusterBreak::Extend), ('\u{06D6}', '\u{06DC}', GraphemeClusterBreak::Extend),
+    ('\u{06DD}', '\u{06DD}', GraphemeClusterBreak::Prepend), ('\u{06DF}', '\u{06E4}', GraphemeClusterBreak::Extend),
+    ('\u{06E7}', '\u{06E8}', GraphemeClusterBreak::Extend), ('\u{06EA}', '\u{06ED}', GraphemeClusterBreak::Extend),
+    ('\u{070F}', '\u{070F}', GraphemeClusterBreak::Prepend), ('\u{0711}', '\u{0711}', GraphemeClusterBreak::Extend),
+    ('\u{0730}', '\u{074A}', GraphemeClusterBreak::Extend), ('\u{07A6}', '\u{07B0}', GraphemeClusterBreak::Extend),
+    ('\u{07EB}', '\u{07F3}', GraphemeClusterBreak::Extend), ('\u{07FD}', '\u{07FD}', GraphemeClusterBreak::Extend),
+    ('\u{0816}', '\u{0819}', GraphemeClusterBreak::Extend), ('\u{081B}', '\u{0823}', GraphemeClusterBreak::Extend),
+    ('\u{0825}', '\u{0827}', GraphemeClusterBreak::Extend), ('\u{0829}', '\u{082D}', GraphemeClusterBreak::Extend),
+    ('\u{0859}', '\u{085B}', GraphemeClusterBreak::Extend), ('\u{0890}', '\u{0891}', GraphemeClusterBreak::Prepend),
+    ('\u{0897}', '\u{089F}', GraphemeClusterBreak::Extend), ('\u{08CA}', '\u{08E1}', GraphemeClusterBreak::Extend),
+    ('\u{08E2}', '\u{08E2}', GraphemeClusterBreak::Prepend), ('\u{08E3}', '\u{0902}', GraphemeClusterBreak::Extend),
+    ('\u{0903}', '\u{0903}', GraphemeClusterBreak::SpacingMark), ('\u{093A}', '\u{093A}', GraphemeClusterBreak::Extend),
+    ('\u{093B}', '\u{093B}', GraphemeClusterBreak::SpacingMark), ('\u{093C}', '\u{093C}', GraphemeClusterBreak::Extend),
+    ('\u{093E}', '\u{0940}', GraphemeClusterBreak::SpacingMark), ('\u{0941}', '\u{0948}', GraphemeClusterBreak::Extend),
+    ('\u{0949}', '\u{094C}', GraphemeClusterBreak::SpacingMark), ('\u{094D}', '\u{094D}', GraphemeClusterBreak::Extend),
+    ('\u{094E}', '\u{094F}', GraphemeClusterBreak::SpacingMark), ('\u{0951}', '\u{0957}', GraphemeClusterBreak::Extend),
+    ('\u{0962}', '\u{0963}', GraphemeClusterBreak::Extend), ('\u{0981}', '\u{0981}', GraphemeClusterBreak::Extend),
+    ('\u{0982}', '\u{0983}', GraphemeClusterBreak::SpacingMark), ('\u{09BC}', '\u{09BC}', GraphemeClusterBreak::Extend),
+    ('\u{09BE}', '\u{09BE}', GraphemeClusterBreak::Extend), ('\u{09BF}', '\u{09C0}', GraphemeClusterBreak::SpacingMark),
+    ('\u{09C1}', '\u{09C4}', GraphemeClusterBreak::Extend), ('\u{09C7}', '\u{09C8}', GraphemeClusterBreak::SpacingMark),
+    ('\u{09CB}', '\u{09CC}', GraphemeClusterBreak::SpacingMark), ('\u{09CD}', '\u{09CD}', GraphemeClusterBreak::Extend),
+    ('\u{09D7}', '\u{09D7}', GraphemeClusterBreak::Extend), ('\u{09E2}', '\u{09E3}', GraphemeClusterBreak::Extend),
+    ('\u{09FE}', '\u{09FE}', GraphemeClusterBreak::Extend), ('\u{0A01}', '\u{0A02}', GraphemeClusterBreak::Extend),
+    ('\u{0A03}', '\u{0A03}', GraphemeClusterBreak::SpacingMark), ('\u{0A3C}', '\u{0A3C}', GraphemeClusterBreak::Extend),
+    ('\u{0A3E}', '\u{0A40}', GraphemeClusterBreak::SpacingMark), ('\u{0A41}', '\u{0A42}', GraphemeClusterBreak::Extend),
+    ('\u{0A47}', '\u{0A48}', GraphemeClusterBreak::Extend), ('\u{0A4B}', '\u{0A4D}', GraphemeClusterBreak::Extend),
+    ('\u{0A51}', '\u{0A51}', GraphemeClusterBreak::Extend), ('\u{0A70}', '\u{0A71}', GraphemeClusterBreak::Extend),
+    ('\u{0A75}', '\u{0A75}', GraphemeClusterBreak::Extend), ('\u{0A81}', '\u{0A82}', GraphemeClusterBreak::Extend),
+    ('\u{0A83}', '\u{0A83}', GraphemeClusterBreak::SpacingMark), ('\u{0ABC}', '\u{0ABC}', GraphemeClusterBreak::Extend),
+    ('\u{0ABE}', '\u{0AC0}', GraphemeClusterBreak::SpacingMark), ('\u{0AC1}', '\u{0AC5}', GraphemeClusterBreak::Extend),
+    ('\u{0AC7}', '\u{0AC8}', GraphemeClusterBreak::Extend), ('\u{0AC9}', '\u{0AC9}', GraphemeClusterBreak::SpacingMark),
+    ('\u{0ACB}', '\u{0ACC}', GraphemeClusterBreak::SpacingMark), ('\u{0ACD}', '\u{0ACD}', GraphemeClusterBreak::Extend),
+    ('\u{0AE2}', '\u{0AE3}', GraphemeClusterBreak::Extend), ('\u{0AFA}', '\u{0AFF}', GraphemeClusterBreak::Extend),
+    ('\u{0B01}', '\u{0B01}', GraphemeClusterBreak::Extend), ('\u{0B02}', '\u{0B03}', GraphemeClusterBreak::SpacingMark),
+    ('\u{0B3C}', '\u{0B3C}', GraphemeClusterBreak::Extend), ('\u{0B3E}', '\u{0B3F}', GraphemeClusterBreak::Extend),
+    ('\u{0B40}', '\u{0B40}', GraphemeClusterBreak::SpacingMark), ('\u{0B41}', '\u{0B44}', GraphemeClusterBreak::Extend),
+    ('\u{0B47}', '\u{0B48}', GraphemeClusterBreak::SpacingMark), ('\u{0B4B}', '\u{0B4C}', GraphemeClusterBreak::SpacingMark),
+    ('\u{0B4D}', '\u{0B4D}', GraphemeClusterBreak::Extend), ('\u{0B55}', '\u{0B57}', GraphemeClusterBreak::Extend),
+    ('\u{0B62}', '\u{0B63}', GraphemeClusterBreak::Extend), ('\u{0B82}', '\u{0B82}', GraphemeClusterBreak::Extend),
+    ('\u{0BBE}', '\u{0BBE}', GraphemeClusterBreak::Extend), ('\u{0BBF}', '\u{0BBF}', GraphemeClusterBreak::SpacingMark),
+    ('\u{0BC0}', '\u{0BC0}', GraphemeClusterBreak::Extend), ('\u{0BC1}', '\u{0BC2}', GraphemeClusterBreak::SpacingMark),
+    ('\u{0BC6}', '\u{0BC8}', GraphemeClusterBreak::SpacingMark), ('\u{0BCA}', '\u{0BCC}', GraphemeClusterBreak::SpacingMark),
+    ('\u{0BCD}', '\u{0BCD}', GraphemeClusterBreak::Extend), ('\u{0BD7}', '\u{0BD7}', GraphemeClusterBreak::Extend),
+    ('\u{0C00}', '\u{0C00}', GraphemeClusterBreak::Extend), ('\u{0C01}', '\u{0C03}', GraphemeClusterBreak::SpacingMark),
+    ('\u{0C04}', '\u{0C04}', GraphemeClusterBreak::Extend), ('\u{0C3C}', '\u{0C3C}', GraphemeClusterBreak::Extend),
+    ('\u{0C3E}', '\u{0C40}', GraphemeClusterBreak::Extend), ('\u{0C41}', '\u{0C44}', GraphemeClusterBreak::SpacingMark),
+    ('\u{0C46}', '\u{0C48}', GraphemeClusterBreak::Extend), ('\u{0C4A}', '\u{0C4D}', GraphemeClusterBreak::Extend),
+    ('\u{0C55}', '\u{0C56}', GraphemeClusterBreak::Extend), ('\u{0C62}', '\u{0C63}', GraphemeClusterBreak::Extend),
+    ('\u{0C81}', '\u{0C81}', GraphemeClusterBreak::Extend), ('\u{0C82}', '\u{0C83}', GraphemeClusterBreak::SpacingMark),
+    ('\u{0CBC}', '\u{0CBC}', GraphemeClusterBreak::Extend), ('\u{0CBE}', '\u{0CBE}', GraphemeClusterBreak::SpacingMark),
+    ('\u{0CBF}', '\u{0CC0}', GraphemeClusterBreak::Extend), ('\u{0CC1}', '\u{0CC1}', GraphemeClusterBreak::SpacingMark),
+    ('\u{0CC2}', '\u{0CC2}', GraphemeClusterBreak::Extend), ('\u{0CC3}', '\u{0CC4}', GraphemeClusterBreak::SpacingMark),
+    ('\u{0CC6}', '\u{0CC8}', GraphemeClusterBreak::Extend), ('\u{0CCA}', '\u{0CCD}', GraphemeClusterBreak::Extend),
+    ('\u{0CD5}', '\u{0CD6}', GraphemeClusterBreak::Extend), ('\u{0CE2}', '\u{0CE3}', GraphemeClusterBreak::Extend),
+    ('\u{0CF3}', '\u{0CF3}', GraphemeClusterBreak::SpacingMark), ('\u{0D00}', '\u{0D01}', GraphemeClusterBreak::Extend),
+    ('\u{0D02}', '\u{0D03}', GraphemeClusterBreak::SpacingMark), ('\u{0D3B}', '\u{0D3C}', GraphemeClusterBreak::Extend),
+    ('\u{0D3E}', '\u{0D3E}', GraphemeClusterBreak::Extend), ('\u{0D3F}', '\u{0D40}', GraphemeClusterBreak::SpacingMark),
+    ('\u{0D41}', '\u{0D44}', GraphemeClusterBreak::Extend), ('\u{0D46}', '\u{0D48}', GraphemeClusterBreak::SpacingMark),
+    ('\u{0D4A}', '\u{0D4C}', GraphemeClusterBreak::SpacingMark), ('\u{0D4D}', '\u{0D4D}', GraphemeClusterBreak::Extend),
+    ('\u{0D4E}', '\u{0D4E}', GraphemeClusterBreak::Prepend), ('\u{0D57}', '\u{0D57}', GraphemeClusterBreak::Extend),
+    ('\u{0D62}', '\u{0D63}', GraphemeClusterBreak::Extend), ('\u{0D81}', '\u{0D81}', GraphemeClusterBreak::Extend),
+    ('\u{0D82}', '\u{0D83}', GraphemeClusterBreak::SpacingMark), ('\u{0DCA}', '\u{0DCA}', GraphemeClusterBreak::Extend),
+    ('\u{0DCF}', '\u{0DCF}', GraphemeClusterBreak::Extend), ('\u{0DD0}', '\u{0DD1}', GraphemeClusterBreak::SpacingMark),
+    ('\u{0DD2}', '\u{0DD4}', GraphemeClusterBreak::Extend), ('\u{0DD6}', '\u{0DD6}', GraphemeClusterBreak::Extend),
+    ('\u{0DD8}', '\u{0DDE}', GraphemeClusterBreak::SpacingMark), ('\u{0DDF}', '\u{0DDF}', GraphemeClusterBreak::Extend),
+    ('\u{0DF2}', '\u{0DF3}', GraphemeClusterBreak::SpacingMark), ('\u{0E31}', '\u{0E31}', GraphemeClusterBreak::Extend),
+    ('\u{0E33}', '\u{0E33}', GraphemeClusterBreak::SpacingMark), ('\u{0E34}', '\u{0E3A}', GraphemeClusterBreak::Extend),
+    ('\u{0E47}', '\u{0E4E}', GraphemeClusterBreak::Extend), ('\u{0EB1}', '\u{0EB1}', GraphemeClusterBreak::Extend),
+    ('\u{0EB3}', '\u{0EB3}', GraphemeClusterBreak::SpacingMark), ('\u{0EB4}', '\u{0EBC}', GraphemeClusterBreak::Extend),
+    ('\u{0EC8}', '\u{0ECE}', GraphemeClusterBreak::Extend), ('\u{0F18}', '\u{0F19}', GraphemeClusterBreak::Extend),
+    ('\u{0F35}', '\u{0F35}', GraphemeClusterBreak::Extend), ('\u{0F37}', '\u{0F37}', GraphemeClusterBreak::Extend),
+    ('\u{0F39}', '\u{0F39}', GraphemeClusterBreak::Extend), ('\u{0F3E}', '\u{0F3F}', GraphemeClusterBreak::SpacingMark),
+    ('\u{0F71}', '\u{0F7E}', GraphemeClusterBreak::Extend), ('\u{0F7F}', '\u{0F7F}', GraphemeClusterBreak::SpacingMark),
+    ('\u{0F80}', '\u{0F84}', GraphemeClusterBreak::Extend), ('\u{0F86}', '\u{0F87}', GraphemeClusterBreak::Extend),
+    ('\u{0F8D}', '\u{0F97}', GraphemeClusterBreak::Extend), ('\u{0F99}', '\u{0FBC}', GraphemeClusterBreak::Extend),
+    ('\u{0FC6}', '\u{0FC6}', GraphemeClusterBreak::Extend), ('\u{102D}', '\u{1030}', GraphemeClusterBreak::Extend),
+    ('\u{1031}', '\u{1031}', GraphemeClusterBreak::SpacingMark), ('\u{1032}', '\u{1037}', GraphemeClusterBreak::Extend),
+    ('\u{1039}', '\u{103A}', GraphemeClusterBreak::Extend), ('\u{103B}', '\u{103C}', GraphemeClusterBreak::SpacingMark),
+    ('\u{103D}', '\u{103E}', GraphemeClusterBreak::Extend), ('\u{1056}', '\u{1057}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1058}', '\u{1059}', GraphemeClusterBreak::Extend), ('\u{105E}', '\u{1060}', GraphemeClusterBreak::Extend),
+    ('\u{1071}', '\u{1074}', GraphemeClusterBreak::Extend), ('\u{1082}', '\u{1082}', GraphemeClusterBreak::Extend),
+    ('\u{1084}', '\u{1084}', GraphemeClusterBreak::SpacingMark), ('\u{1085}', '\u{1086}', GraphemeClusterBreak::Extend),
+    ('\u{108D}', '\u{108D}', GraphemeClusterBreak::Extend), ('\u{109D}', '\u{109D}', GraphemeClusterBreak::Extend),
+    ('\u{1100}', '\u{115F}', GraphemeClusterBreak::L), ('\u{1160}', '\u{11A7}', GraphemeClusterBreak::V),
+    ('\u{11A8}', '\u{11FF}', GraphemeClusterBreak::T), ('\u{135D}', '\u{135F}', GraphemeClusterBreak::Extend),
+    ('\u{1712}', '\u{1715}', GraphemeClusterBreak::Extend), ('\u{1732}', '\u{1734}', GraphemeClusterBreak::Extend),
+    ('\u{1752}', '\u{1753}', GraphemeClusterBreak::Extend), ('\u{1772}', '\u{1773}', GraphemeClusterBreak::Extend),
+    ('\u{17B4}', '\u{17B5}', GraphemeClusterBreak::Extend), ('\u{17B6}', '\u{17B6}', GraphemeClusterBreak::SpacingMark),
+    ('\u{17B7}', '\u{17BD}', GraphemeClusterBreak::Extend), ('\u{17BE}', '\u{17C5}', GraphemeClusterBreak::SpacingMark),
+    ('\u{17C6}', '\u{17C6}', GraphemeClusterBreak::Extend), ('\u{17C7}', '\u{17C8}', GraphemeClusterBreak::SpacingMark),
+    ('\u{17C9}', '\u{17D3}', GraphemeClusterBreak::Extend), ('\u{17DD}', '\u{17DD}', GraphemeClusterBreak::Extend),
+    ('\u{180B}', '\u{180D}', GraphemeClusterBreak::Extend), ('\u{180E}', '\u{180E}', GraphemeClusterBreak::Control),
+    ('\u{180F}', '\u{180F}', GraphemeClusterBreak::Extend), ('\u{1885}', '\u{1886}', GraphemeClusterBreak::Extend),
+    ('\u{18A9}', '\u{18A9}', GraphemeClusterBreak::Extend), ('\u{1920}', '\u{1922}', GraphemeClusterBreak::Extend),
+    ('\u{1923}', '\u{1926}', GraphemeClusterBreak::SpacingMark), ('\u{1927}', '\u{1928}', GraphemeClusterBreak::Extend),
+    ('\u{1929}', '\u{192B}', GraphemeClusterBreak::SpacingMark), ('\u{1930}', '\u{1931}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1932}', '\u{1932}', GraphemeClusterBreak::Extend), ('\u{1933}', '\u{1938}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1939}', '\u{193B}', GraphemeClusterBreak::Extend), ('\u{1A17}', '\u{1A18}', GraphemeClusterBreak::Extend),
+    ('\u{1A19}', '\u{1A1A}', GraphemeClusterBreak::SpacingMark), ('\u{1A1B}', '\u{1A1B}', GraphemeClusterBreak::Extend),
+    ('\u{1A55}', '\u{1A55}', GraphemeClusterBreak::SpacingMark), ('\u{1A56}', '\u{1A56}', GraphemeClusterBreak::Extend),
+    ('\u{1A57}', '\u{1A57}', GraphemeClusterBreak::SpacingMark), ('\u{1A58}', '\u{1A5E}', GraphemeClusterBreak::Extend),
+    ('\u{1A60}', '\u{1A60}', GraphemeClusterBreak::Extend), ('\u{1A62}', '\u{1A62}', GraphemeClusterBreak::Extend),
+    ('\u{1A65}', '\u{1A6C}', GraphemeClusterBreak::Extend), ('\u{1A6D}', '\u{1A72}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1A73}', '\u{1A7C}', GraphemeClusterBreak::Extend), ('\u{1A7F}', '\u{1A7F}', GraphemeClusterBreak::Extend),
+    ('\u{1AB0}', '\u{1ADD}', GraphemeClusterBreak::Extend), ('\u{1AE0}', '\u{1AEB}', GraphemeClusterBreak::Extend),
+    ('\u{1B00}', '\u{1B03}', GraphemeClusterBreak::Extend), ('\u{1B04}', '\u{1B04}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1B34}', '\u{1B3D}', GraphemeClusterBreak::Extend), ('\u{1B3E}', '\u{1B41}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1B42}', '\u{1B44}', GraphemeClusterBreak::Extend), ('\u{1B6B}', '\u{1B73}', GraphemeClusterBreak::Extend),
+    ('\u{1B80}', '\u{1B81}', GraphemeClusterBreak::Extend), ('\u{1B82}', '\u{1B82}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1BA1}', '\u{1BA1}', GraphemeClusterBreak::SpacingMark), ('\u{1BA2}', '\u{1BA5}', GraphemeClusterBreak::Extend),
+    ('\u{1BA6}', '\u{1BA7}', GraphemeClusterBreak::SpacingMark), ('\u{1BA8}', '\u{1BAD}', GraphemeClusterBreak::Extend),
+    ('\u{1BE6}', '\u{1BE6}', GraphemeClusterBreak::Extend), ('\u{1BE7}', '\u{1BE7}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1BE8}', '\u{1BE9}', GraphemeClusterBreak::Extend), ('\u{1BEA}', '\u{1BEC}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1BED}', '\u{1BED}', GraphemeClusterBreak::Extend), ('\u{1BEE}', '\u{1BEE}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1BEF}', '\u{1BF3}', GraphemeClusterBreak::Extend), ('\u{1C24}', '\u{1C2B}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1C2C}', '\u{1C33}', GraphemeClusterBreak::Extend), ('\u{1C34}', '\u{1C35}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1C36}', '\u{1C37}', GraphemeClusterBreak::Extend), ('\u{1CD0}', '\u{1CD2}', GraphemeClusterBreak::Extend),
+    ('\u{1CD4}', '\u{1CE0}', GraphemeClusterBreak::Extend), ('\u{1CE1}', '\u{1CE1}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1CE2}', '\u{1CE8}', GraphemeClusterBreak::Extend), ('\u{1CED}', '\u{1CED}', GraphemeClusterBreak::Extend),
+    ('\u{1CF4}', '\u{1CF4}', GraphemeClusterBreak::Extend), ('\u{1CF7}', '\u{1CF7}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1CF8}', '\u{1CF9}', GraphemeClusterBreak::Extend), ('\u{1DC0}', '\u{1DFF}', GraphemeClusterBreak::Extend),
+    ('\u{200B}', '\u{200B}', GraphemeClusterBreak::Control), ('\u{200C}', '\u{200C}', GraphemeClusterBreak::Extend),
+    ('\u{200D}', '\u{200D}', GraphemeClusterBreak::ZWJ), ('\u{200E}', '\u{200F}', GraphemeClusterBreak::Control),
+    ('\u{2028}', '\u{202E}', GraphemeClusterBreak::Control), ('\u{2060}', '\u{206F}', GraphemeClusterBreak::Control),
+    ('\u{20D0}', '\u{20F0}', GraphemeClusterBreak::Extend), ('\u{2CEF}', '\u{2CF1}', GraphemeClusterBreak::Extend),
+    ('\u{2D7F}', '\u{2D7F}', GraphemeClusterBreak::Extend), ('\u{2DE0}', '\u{2DFF}', GraphemeClusterBreak::Extend),
+    ('\u{302A}', '\u{302F}', GraphemeClusterBreak::Extend), ('\u{3099}', '\u{309A}', GraphemeClusterBreak::Extend),
+    ('\u{A66F}', '\u{A672}', GraphemeClusterBreak::Extend), ('\u{A674}', '\u{A67D}', GraphemeClusterBreak::Extend),
+    ('\u{A69E}', '\u{A69F}', GraphemeClusterBreak::Extend), ('\u{A6F0}', '\u{A6F1}', GraphemeClusterBreak::Extend),
+    ('\u{A802}', '\u{A802}', GraphemeClusterBreak::Extend), ('\u{A806}', '\u{A806}', GraphemeClusterBreak::Extend),
+    ('\u{A80B}', '\u{A80B}', GraphemeClusterBreak::Extend), ('\u{A823}', '\u{A824}', GraphemeClusterBreak::SpacingMark),
+    ('\u{A825}', '\u{A826}', GraphemeClusterBreak::Extend), ('\u{A827}', '\u{A827}', GraphemeClusterBreak::SpacingMark),
+    ('\u{A82C}', '\u{A82C}', GraphemeClusterBreak::Extend), ('\u{A880}', '\u{A881}', GraphemeClusterBreak::SpacingMark),
+    ('\u{A8B4}', '\u{A8C3}', GraphemeClusterBreak::SpacingMark), ('\u{A8C4}', '\u{A8C5}', GraphemeClusterBreak::Extend),
+    ('\u{A8E0}', '\u{A8F1}', GraphemeClusterBreak::Extend), ('\u{A8FF}', '\u{A8FF}', GraphemeClusterBreak::Extend),
+    ('\u{A926}', '\u{A92D}', GraphemeClusterBreak::Extend), ('\u{A947}', '\u{A951}', GraphemeClusterBreak::Extend),
+    ('\u{A952}', '\u{A952}', GraphemeClusterBreak::SpacingMark), ('\u{A953}', '\u{A953}', GraphemeClusterBreak::Extend),
+    ('\u{A960}', '\u{A97C}', GraphemeClusterBreak::L), ('\u{A980}', '\u{A982}', GraphemeClusterBreak::Extend),
+    ('\u{A983}', '\u{A983}', GraphemeClusterBreak::SpacingMark), ('\u{A9B3}', '\u{A9B3}', GraphemeClusterBreak::Extend),
+    ('\u{A9B4}', '\u{A9B5}', GraphemeClusterBreak::SpacingMark), ('\u{A9B6}', '\u{A9B9}', GraphemeClusterBreak::Extend),
+    ('\u{A9BA}', '\u{A9BB}', GraphemeClusterBreak::SpacingMark), ('\u{A9BC}', '\u{A9BD}', GraphemeClusterBreak::Extend),
+    ('\u{A9BE}', '\u{A9BF}', GraphemeClusterBreak::SpacingMark), ('\u{A9C0}', '\u{A9C0}', GraphemeClusterBreak::Extend),
+    ('\u{A9E5}', '\u{A9E5}', GraphemeClusterBreak::Extend), ('\u{AA29}', '\u{AA2E}', GraphemeClusterBreak::Extend),
+    ('\u{AA2F}', '\u{AA30}', GraphemeClusterBreak::SpacingMark), ('\u{AA31}', '\u{AA32}', GraphemeClusterBreak::Extend),
+    ('\u{AA33}', '\u{AA34}', GraphemeClusterBreak::SpacingMark), ('\u{AA35}', '\u{AA36}', GraphemeClusterBreak::Extend),
+    ('\u{AA43}', '\u{AA43}', GraphemeClusterBreak::Extend), ('\u{AA4C}', '\u{AA4C}', GraphemeClusterBreak::Extend),
+    ('\u{AA4D}', '\u{AA4D}', GraphemeClusterBreak::SpacingMark), ('\u{AA7C}', '\u{AA7C}', GraphemeClusterBreak::Extend),
+    ('\u{AAB0}', '\u{AAB0}', GraphemeClusterBreak::Extend), ('\u{AAB2}', '\u{AAB4}', GraphemeClusterBreak::Extend),
+    ('\u{AAB7}', '\u{AAB8}', GraphemeClusterBreak::Extend), ('\u{AABE}', '\u{AABF}', GraphemeClusterBreak::Extend),
+    ('\u{AAC1}', '\u{AAC1}', GraphemeClusterBreak::Extend), ('\u{AAEB}', '\u{AAEB}', GraphemeClusterBreak::SpacingMark),
+    ('\u{AAEC}', '\u{AAED}', GraphemeClusterBreak::Extend), ('\u{AAEE}', '\u{AAEF}', GraphemeClusterBreak::SpacingMark),
+    ('\u{AAF5}', '\u{AAF5}', GraphemeClusterBreak::SpacingMark), ('\u{AAF6}', '\u{AAF6}', GraphemeClusterBreak::Extend),
+    ('\u{ABE3}', '\u{ABE4}', GraphemeClusterBreak::SpacingMark), ('\u{ABE5}', '\u{ABE5}', GraphemeClusterBreak::Extend),
+    ('\u{ABE6}', '\u{ABE7}', GraphemeClusterBreak::SpacingMark), ('\u{ABE8}', '\u{ABE8}', GraphemeClusterBreak::Extend),
+    ('\u{ABE9}', '\u{ABEA}', GraphemeClusterBreak::SpacingMark), ('\u{ABEC}', '\u{ABEC}', GraphemeClusterBreak::SpacingMark),
+    ('\u{ABED}', '\u{ABED}', GraphemeClusterBreak::Extend), ('\u{AC00}', '\u{AC00}', GraphemeClusterBreak::LV),
+    ('\u{AC01}', '\u{AC1B}', GraphemeClusterBreak::LVT), ('\u{AC1C}', '\u{AC1C}', GraphemeClusterBreak::LV),
+    ('\u{AC1D}', '\u{AC37}', GraphemeClusterBreak::LVT), ('\u{AC38}', '\u{AC38}', GraphemeClusterBreak::LV),
+    ('\u{AC39}', '\u{AC53}', GraphemeClusterBreak::LVT), ('\u{AC54}', '\u{AC54}', GraphemeClusterBreak::LV),
+    ('\u{AC55}', '\u{AC6F}', GraphemeClusterBreak::LVT), ('\u{AC70}', '\u{AC70}', GraphemeClusterBreak::LV),
+    ('\u{AC71}', '\u{AC8B}', GraphemeClusterBreak::LVT), ('\u{AC8C}', '\u{AC8C}', GraphemeClusterBreak::LV),
+    ('\u{AC8D}', '\u{ACA7}', GraphemeClusterBreak::LVT), ('\u{ACA8}', '\u{ACA8}', GraphemeClusterBreak::LV),
+    ('\u{ACA9}', '\u{ACC3}', GraphemeClusterBreak::LVT), ('\u{ACC4}', '\u{ACC4}', GraphemeClusterBreak::LV),
+    ('\u{ACC5}', '\u{ACDF}', GraphemeClusterBreak::LVT), ('\u{ACE0}', '\u{ACE0}', GraphemeClusterBreak::LV),
+    ('\u{ACE1}', '\u{ACFB}', GraphemeClusterBreak::LVT), ('\u{ACFC}', '\u{ACFC}', GraphemeClusterBreak::LV),
+    ('\u{ACFD}', '\u{AD17}', GraphemeClusterBreak::LVT), ('\u{AD18}', '\u{AD18}', GraphemeClusterBreak::LV),
+    ('\u{AD19}', '\u{AD33}', GraphemeClusterBreak::LVT), ('\u{AD34}', '\u{AD34}', GraphemeClusterBreak::LV),
+    ('\u{AD35}', '\u{AD4F}', GraphemeClusterBreak::LVT), ('\u{AD50}', '\u{AD50}', GraphemeClusterBreak::LV),
+    ('\u{AD51}', '\u{AD6B}', GraphemeClusterBreak::LVT), ('\u{AD6C}', '\u{AD6C}', GraphemeClusterBreak::LV),
+    ('\u{AD6D}', '\u{AD87}', GraphemeClusterBreak::LVT), ('\u{AD88}', '\u{AD88}', GraphemeClusterBreak::LV),
+    ('\u{AD89}', '\u{ADA3}', GraphemeClusterBreak::LVT), ('\u{ADA4}', '\u{ADA4}', GraphemeClusterBreak::LV),
+    ('\u{ADA5}', '\u{ADBF}', GraphemeClusterBreak::LVT), ('\u{ADC0}', '\u{ADC0}', GraphemeClusterBreak::LV),
+    ('\u{ADC1}', '\u{ADDB}', GraphemeClusterBreak::LVT), ('\u{ADDC}', '\u{ADDC}', GraphemeClusterBreak::LV),
+    ('\u{ADDD}', '\u{ADF7}', GraphemeClusterBreak::LVT), ('\u{ADF8}', '\u{ADF8}', GraphemeClusterBreak::LV),
+    ('\u{ADF9}', '\u{AE13}', GraphemeClusterBreak::LVT), ('\u{AE14}', '\u{AE14}', GraphemeClusterBreak::LV),
+    ('\u{AE15}', '\u{AE2F}', GraphemeClusterBreak::LVT), ('\u{AE30}', '\u{AE30}', GraphemeClusterBreak::LV),
+    ('\u{AE31}', '\u{AE4B}', GraphemeClusterBreak::LVT), ('\u{AE4C}', '\u{AE4C}', GraphemeClusterBreak::LV),
+    ('\u{AE4D}', '\u{AE67}', GraphemeClusterBreak::LVT), ('\u{AE68}', '\u{AE68}', GraphemeClusterBreak::LV),
+    ('\u{AE69}', '\u{AE83}', GraphemeClusterBreak::LVT), ('\u{AE84}', '\u{AE84}', GraphemeClusterBreak::LV),
+    ('\u{AE85}', '\u{AE9F}', GraphemeClusterBreak::LVT), ('\u{AEA0}', '\u{AEA0}', GraphemeClusterBreak::LV),
+    ('\u{AEA1}', '\u{AEBB}', GraphemeClusterBreak::LVT), ('\u{AEBC}', '\u{AEBC}', GraphemeClusterBreak::LV),
+    ('\u{AEBD}', '\u{AED7}', GraphemeClusterBreak::LVT), ('\u{AED8}', '\u{AED8}', GraphemeClusterBreak::LV),
+    ('\u{AED9}', '\u{AEF3}', GraphemeClusterBreak::LVT), ('\u{AEF4}', '\u{AEF4}', GraphemeClusterBreak::LV),
+    ('\u{AEF5}', '\u{AF0F}', GraphemeClusterBreak::LVT), ('\u{AF10}', '\u{AF10}', GraphemeClusterBreak::LV),
+    ('\u{AF11}', '\u{AF2B}', GraphemeClusterBreak::LVT), ('\u{AF2C}', '\u{AF2C}', GraphemeClusterBreak::LV),
+    ('\u{AF2D}', '\u{AF47}', GraphemeClusterBreak::LVT), ('\u{AF48}', '\u{AF48}', GraphemeClusterBreak::LV),
+    ('\u{AF49}', '\u{AF63}', GraphemeClusterBreak::LVT), ('\u{AF64}', '\u{AF64}', GraphemeClusterBreak::LV),
+    ('\u{AF65}', '\u{AF7F}', GraphemeClusterBreak::LVT), ('\u{AF80}', '\u{AF80}', GraphemeClusterBreak::LV),
+    ('\u{AF81}', '\u{AF9B}', GraphemeClusterBreak::LVT), ('\u{AF9C}', '\u{AF9C}', GraphemeClusterBreak::LV),
+    ('\u{AF9D}', '\u{AFB7}', GraphemeClusterBreak::LVT), ('\u{AFB8}', '\u{AFB8}', GraphemeClusterBreak::LV),
+    ('\u{AFB9}', '\u{AFD3}', GraphemeClusterBreak::LVT), ('\u{AFD4}', '\u{AFD4}', GraphemeClusterBreak::LV),
+    ('\u{AFD5}', '\u{AFEF}', GraphemeClusterBreak::LVT), ('\u{AFF0}', '\u{AFF0}', GraphemeClusterBreak::LV),
+    ('\u{AFF1}', '\u{B00B}', GraphemeClusterBreak::LVT), ('\u{B00C}', '\u{B00C}', GraphemeClusterBreak::LV),
+    ('\u{B00D}', '\u{B027}', GraphemeClusterBreak::LVT), ('\u{B028}', '\u{B028}', GraphemeClusterBreak::LV),
+    ('\u{B029}', '\u{B043}', GraphemeClusterBreak::LVT), ('\u{B044}', '\u{B044}', GraphemeClusterBreak::LV),
+    ('\u{B045}', '\u{B05F}', GraphemeClusterBreak::LVT), ('\u{B060}', '\u{B060}', GraphemeClusterBreak::LV),
+    ('\u{B061}', '\u{B07B}', GraphemeClusterBreak::LVT), ('\u{B07C}', '\u{B07C}', GraphemeClusterBreak::LV),
+    ('\u{B07D}', '\u{B097}', GraphemeClusterBreak::LVT), ('\u{B098}', '\u{B098}', GraphemeClusterBreak::LV),
+    ('\u{B099}', '\u{B0B3}', GraphemeClusterBreak::LVT), ('\u{B0B4}', '\u{B0B4}', GraphemeClusterBreak::LV),
+    ('\u{B0B5}', '\u{B0CF}', GraphemeClusterBreak::LVT), ('\u{B0D0}', '\u{B0D0}', GraphemeClusterBreak::LV),
+    ('\u{B0D1}', '\u{B0EB}', GraphemeClusterBreak::LVT), ('\u{B0EC}', '\u{B0EC}', GraphemeClusterBreak::LV),
+    ('\u{B0ED}', '\u{B107}', GraphemeClusterBreak::LVT), ('\u{B108}', '\u{B108}', GraphemeClusterBreak::LV),
+    ('\u{B109}', '\u{B123}', GraphemeClusterBreak::LVT), ('\u{B124}', '\u{B124}', GraphemeClusterBreak::LV),
+    ('\u{B125}', '\u{B13F}', GraphemeClusterBreak::LVT), ('\u{B140}', '\u{B140}', GraphemeClusterBreak::LV),
+    ('\u{B141}', '\u{B15B}', GraphemeClusterBreak::LVT), ('\u{B15C}', '\u{B15C}', GraphemeClusterBreak::LV),
+    ('\u{B15D}', '\u{B177}', GraphemeClusterBreak::LVT), ('\u{B178}', '\u{B178}', GraphemeClusterBreak::LV),
+    ('\u{B179}', '\u{B193}', GraphemeClusterBreak::LVT), ('\u{B194}', '\u{B194}', GraphemeClusterBreak::LV),
+    ('\u{B195}', '\u{B1AF}', GraphemeClusterBreak::LVT), ('\u{B1B0}', '\u{B1B0}', GraphemeClusterBreak::LV),
+    ('\u{B1B1}', '\u{B1CB}', GraphemeClusterBreak::LVT), ('\u{B1CC}', '\u{B1CC}', GraphemeClusterBreak::LV),
+    ('\u{B1CD}', '\u{B1E7}', GraphemeClusterBreak::LVT), ('\u{B1E8}', '\u{B1E8}', GraphemeClusterBreak::LV),
+    ('\u{B1E9}', '\u{B203}', GraphemeClusterBreak::LVT), ('\u{B204}', '\u{B204}', GraphemeClusterBreak::LV),
+    ('\u{B205}', '\u{B21F}', GraphemeClusterBreak::LVT), ('\u{B220}', '\u{B220}', GraphemeClusterBreak::LV),
+    ('\u{B221}', '\u{B23B}', GraphemeClusterBreak::LVT), ('\u{B23C}', '\u{B23C}', GraphemeClusterBreak::LV),
+    ('\u{B23D}', '\u{B257}', GraphemeClusterBreak::LVT), ('\u{B258}', '\u{B258}', GraphemeClusterBreak::LV),
+    ('\u{B259}', '\u{B273}', GraphemeClusterBreak::LVT), ('\u{B274}', '\u{B274}', GraphemeClusterBreak::LV),
+    ('\u{B275}', '\u{B28F}', GraphemeClusterBreak::LVT), ('\u{B290}', '\u{B290}', GraphemeClusterBreak::LV),
+    ('\u{B291}', '\u{B2AB}', GraphemeClusterBreak::LVT), ('\u{B2AC}', '\u{B2AC}', GraphemeClusterBreak::LV),
+    ('\u{B2AD}', '\u{B2C7}', GraphemeClusterBreak::LVT), ('\u{B2C8}', '\u{B2C8}', GraphemeClusterBreak::LV),
+    ('\u{B2C9}', '\u{B2E3}', GraphemeClusterBreak::LVT), ('\u{B2E4}', '\u{B2E4}', GraphemeClusterBreak::LV),
+    ('\u{B2E5}', '\u{B2FF}', GraphemeClusterBreak::LVT), ('\u{B300}', '\u{B300}', GraphemeClusterBreak::LV),
+    ('\u{B301}', '\u{B31B}', GraphemeClusterBreak::LVT), ('\u{B31C}', '\u{B31C}', GraphemeClusterBreak::LV),
+    ('\u{B31D}', '\u{B337}', GraphemeClusterBreak::LVT), ('\u{B338}', '\u{B338}', GraphemeClusterBreak::LV),
+    ('\u{B339}', '\u{B353}', GraphemeClusterBreak::LVT), ('\u{B354}', '\u{B354}', GraphemeClusterBreak::LV),
+    ('\u{B355}', '\u{B36F}', GraphemeClusterBreak::LVT), ('\u{B370}', '\u{B370}', GraphemeClusterBreak::LV),
+    ('\u{B371}', '\u{B38B}', GraphemeClusterBreak::LVT), ('\u{B38C}', '\u{B38C}', GraphemeClusterBreak::LV),
+    ('\u{B38D}', '\u{B3A7}', GraphemeClusterBreak::LVT), ('\u{B3A8}', '\u{B3A8}', GraphemeClusterBreak::LV),
+    ('\u{B3A9}', '\u{B3C3}', GraphemeClusterBreak::LVT), ('\u{B3C4}', '\u{B3C4}', GraphemeClusterBreak::LV),
+    ('\u{B3C5}', '\u{B3DF}', GraphemeClusterBreak::LVT), ('\u{B3E0}', '\u{B3E0}', GraphemeClusterBreak::LV),
+    ('\u{B3E1}', '\u{B3FB}', GraphemeClusterBreak::LVT), ('\u{B3FC}', '\u{B3FC}', GraphemeClusterBreak::LV),
+    ('\u{B3FD}', '\u{B417}', GraphemeClusterBreak::LVT), ('\u{B418}', '\u{B418}', GraphemeClusterBreak::LV),
+    ('\u{B419}', '\u{B433}', GraphemeClusterBreak::LVT), ('\u{B434}', '\u{B434}', GraphemeClusterBreak::LV),
+    ('\u{B435}', '\u{B44F}', GraphemeClusterBreak::LVT), ('\u{B450}', '\u{B450}', GraphemeClusterBreak::LV),
+    ('\u{B451}', '\u{B46B}', GraphemeClusterBreak::LVT), ('\u{B46C}', '\u{B46C}', GraphemeClusterBreak::LV),
+    ('\u{B46D}', '\u{B487}', GraphemeClusterBreak::LVT), ('\u{B488}', '\u{B488}', GraphemeClusterBreak::LV),
+    ('\u{B489}', '\u{B4A3}', GraphemeClusterBreak::LVT), ('\u{B4A4}', '\u{B4A4}', GraphemeClusterBreak::LV),
+    ('\u{B4A5}', '\u{B4BF}', GraphemeClusterBreak::LVT), ('\u{B4C0}', '\u{B4C0}', GraphemeClusterBreak::LV),
+    ('\u{B4C1}', '\u{B4DB}', GraphemeClusterBreak::LVT), ('\u{B4DC}', '\u{B4DC}', GraphemeClusterBreak::LV),
+    ('\u{B4DD}', '\u{B4F7}', GraphemeClusterBreak::LVT), ('\u{B4F8}', '\u{B4F8}', GraphemeClusterBreak::LV),
+    ('\u{B4F9}', '\u{B513}', GraphemeClusterBreak::LVT), ('\u{B514}', '\u{B514}', GraphemeClusterBreak::LV),
+    ('\u{B515}', '\u{B52F}', GraphemeClusterBreak::LVT), ('\u{B530}', '\u{B530}', GraphemeClusterBreak::LV),
+    ('\u{B531}', '\u{B54B}', GraphemeClusterBreak::LVT), ('\u{B54C}', '\u{B54C}', GraphemeClusterBreak::LV),
+    ('\u{B54D}', '\u{B567}', GraphemeClusterBreak::LVT), ('\u{B568}', '\u{B568}', GraphemeClusterBreak::LV),
+    ('\u{B569}', '\u{B583}', GraphemeClusterBreak::LVT), ('\u{B584}', '\u{B584}', GraphemeClusterBreak::LV),
+    ('\u{B585}', '\u{B59F}', GraphemeClusterBreak::LVT), ('\u{B5A0}', '\u{B5A0}', GraphemeClusterBreak::LV),
+    ('\u{B5A1}', '\u{B5BB}', GraphemeClusterBreak::LVT), ('\u{B5BC}', '\u{B5BC}', GraphemeClusterBreak::LV),
+    ('\u{B5BD}', '\u{B5D7}', GraphemeClusterBreak::LVT), ('\u{B5D8}', '\u{B5D8}', GraphemeClusterBreak::LV),
+    ('\u{B5D9}', '\u{B5F3}', GraphemeClusterBreak::LVT), ('\u{B5F4}', '\u{B5F4}', GraphemeClusterBreak::LV),
+    ('\u{B5F5}', '\u{B60F}', GraphemeClusterBreak::LVT), ('\u{B610}', '\u{B610}', GraphemeClusterBreak::LV),
+    ('\u{B611}', '\u{B62B}', GraphemeClusterBreak::LVT), ('\u{B62C}', '\u{B62C}', GraphemeClusterBreak::LV),
+    ('\u{B62D}', '\u{B647}', GraphemeClusterBreak::LVT), ('\u{B648}', '\u{B648}', GraphemeClusterBreak::LV),
+    ('\u{B649}', '\u{B663}', GraphemeClusterBreak::LVT), ('\u{B664}', '\u{B664}', GraphemeClusterBreak::LV),
+    ('\u{B665}', '\u{B67F}', GraphemeClusterBreak::LVT), ('\u{B680}', '\u{B680}', GraphemeClusterBreak::LV),
+    ('\u{B681}', '\u{B69B}', GraphemeClusterBreak::LVT), ('\u{B69C}', '\u{B69C}', GraphemeClusterBreak::LV),
+    ('\u{B69D}', '\u{B6B7}', GraphemeClusterBreak::LVT), ('\u{B6B8}', '\u{B6B8}', GraphemeClusterBreak::LV),
+    ('\u{B6B9}', '\u{B6D3}', GraphemeClusterBreak::LVT), ('\u{B6D4}', '\u{B6D4}', GraphemeClusterBreak::LV),
+    ('\u{B6D5}', '\u{B6EF}', GraphemeClusterBreak::LVT), ('\u{B6F0}', '\u{B6F0}', GraphemeClusterBreak::LV),
+    ('\u{B6F1}', '\u{B70B}', GraphemeClusterBreak::LVT), ('\u{B70C}', '\u{B70C}', GraphemeClusterBreak::LV),
+    ('\u{B70D}', '\u{B727}', GraphemeClusterBreak::LVT), ('\u{B728}', '\u{B728}', GraphemeClusterBreak::LV),
+    ('\u{B729}', '\u{B743}', GraphemeClusterBreak::LVT), ('\u{B744}', '\u{B744}', GraphemeClusterBreak::LV),
+    ('\u{B745}', '\u{B75F}', GraphemeClusterBreak::LVT), ('\u{B760}', '\u{B760}', GraphemeClusterBreak::LV),
+    ('\u{B761}', '\u{B77B}', GraphemeClusterBreak::LVT), ('\u{B77C}', '\u{B77C}', GraphemeClusterBreak::LV),
+    ('\u{B77D}', '\u{B797}', GraphemeClusterBreak::LVT), ('\u{B798}', '\u{B798}', GraphemeClusterBreak::LV),
+    ('\u{B799}', '\u{B7B3}', GraphemeClusterBreak::LVT), ('\u{B7B4}', '\u{B7B4}', GraphemeClusterBreak::LV),
+    ('\u{B7B5}', '\u{B7CF}', GraphemeClusterBreak::LVT), ('\u{B7D0}', '\u{B7D0}', GraphemeClusterBreak::LV),
+    ('\u{B7D1}', '\u{B7EB}', GraphemeClusterBreak::LVT), ('\u{B7EC}', '\u{B7EC}', GraphemeClusterBreak::LV),
+    ('\u{B7ED}', '\u{B807}', GraphemeClusterBreak::LVT), ('\u{B808}', '\u{B808}', GraphemeClusterBreak::LV),
+    ('\u{B809}', '\u{B823}', GraphemeClusterBreak::LVT), ('\u{B824}', '\u{B824}', GraphemeClusterBreak::LV),
+    ('\u{B825}', '\u{B83F}', GraphemeClusterBreak::LVT), ('\u{B840}', '\u{B840}', GraphemeClusterBreak::LV),
+    ('\u{B841}', '\u{B85B}', GraphemeClusterBreak::LVT), ('\u{B85C}', '\u{B85C}', GraphemeClusterBreak::LV),
+    ('\u{B85D}', '\u{B877}', GraphemeClusterBreak::LVT), ('\u{B878}', '\u{B878}', GraphemeClusterBreak::LV),
+    ('\u{B879}', '\u{B893}', GraphemeClusterBreak::LVT), ('\u{B894}', '\u{B894}', GraphemeClusterBreak::LV),
+    ('\u{B895}', '\u{B8AF}', GraphemeClusterBreak::LVT), ('\u{B8B0}', '\u{B8B0}', GraphemeClusterBreak::LV),
+    ('\u{B8B1}', '\u{B8CB}', GraphemeClusterBreak::LVT), ('\u{B8CC}', '\u{B8CC}', GraphemeClusterBreak::LV),
+    ('\u{B8CD}', '\u{B8E7}', GraphemeClusterBreak::LVT), ('\u{B8E8}', '\u{B8E8}', GraphemeClusterBreak::LV),
+    ('\u{B8E9}', '\u{B903}', GraphemeClusterBreak::LVT), ('\u{B904}', '\u{B904}', GraphemeClusterBreak::LV),
+    ('\u{B905}', '\u{B91F}', GraphemeClusterBreak::LVT), ('\u{B920}', '\u{B920}', GraphemeClusterBreak::LV),
+    ('\u{B921}', '\u{B93B}', GraphemeClusterBreak::LVT), ('\u{B93C}', '\u{B93C}', GraphemeClusterBreak::LV),
+    ('\u{B93D}', '\u{B957}', GraphemeClusterBreak::LVT), ('\u{B958}', '\u{B958}', GraphemeClusterBreak::LV),
+    ('\u{B959}', '\u{B973}', GraphemeClusterBreak::LVT), ('\u{B974}', '\u{B974}', GraphemeClusterBreak::LV),
+    ('\u{B975}', '\u{B98F}', GraphemeClusterBreak::LVT), ('\u{B990}', '\u{B990}', GraphemeClusterBreak::LV),
+    ('\u{B991}', '\u{B9AB}', GraphemeClusterBreak::LVT), ('\u{B9AC}', '\u{B9AC}', GraphemeClusterBreak::LV),
+    ('\u{B9AD}', '\u{B9C7}', GraphemeClusterBreak::LVT), ('\u{B9C8}', '\u{B9C8}', GraphemeClusterBreak::LV),
+    ('\u{B9C9}', '\u{B9E3}', GraphemeClusterBreak::LVT), ('\u{B9E4}', '\u{B9E4}', GraphemeClusterBreak::LV),
+    ('\u{B9E5}', '\u{B9FF}', GraphemeClusterBreak::LVT), ('\u{BA00}', '\u{BA00}', GraphemeClusterBreak::LV),
+    ('\u{BA01}', '\u{BA1B}', GraphemeClusterBreak::LVT), ('\u{BA1C}', '\u{BA1C}', GraphemeClusterBreak::LV),
+    ('\u{BA1D}', '\u{BA37}', GraphemeClusterBreak::LVT), ('\u{BA38}', '\u{BA38}', GraphemeClusterBreak::LV),
+    ('\u{BA39}', '\u{BA53}', GraphemeClusterBreak::LVT), ('\u{BA54}', '\u{BA54}', GraphemeClusterBreak::LV),
+    ('\u{BA55}', '\u{BA6F}', GraphemeClusterBreak::LVT), ('\u{BA70}', '\u{BA70}', GraphemeClusterBreak::LV),
+    ('\u{BA71}', '\u{BA8B}', GraphemeClusterBreak::LVT), ('\u{BA8C}', '\u{BA8C}', GraphemeClusterBreak::LV),
+    ('\u{BA8D}', '\u{BAA7}', GraphemeClusterBreak::LVT), ('\u{BAA8}', '\u{BAA8}', GraphemeClusterBreak::LV),
+    ('\u{BAA9}', '\u{BAC3}', GraphemeClusterBreak::LVT), ('\u{BAC4}', '\u{BAC4}', GraphemeClusterBreak::LV),
+    ('\u{BAC5}', '\u{BADF}', GraphemeClusterBreak::LVT), ('\u{BAE0}', '\u{BAE0}', GraphemeClusterBreak::LV),
+    ('\u{BAE1}', '\u{BAFB}', GraphemeClusterBreak::LVT), ('\u{BAFC}', '\u{BAFC}', GraphemeClusterBreak::LV),
+    ('\u{BAFD}', '\u{BB17}', GraphemeClusterBreak::LVT), ('\u{BB18}', '\u{BB18}', GraphemeClusterBreak::LV),
+    ('\u{BB19}', '\u{BB33}', GraphemeClusterBreak::LVT), ('\u{BB34}', '\u{BB34}', GraphemeClusterBreak::LV),
+    ('\u{BB35}', '\u{BB4F}', GraphemeClusterBreak::LVT), ('\u{BB50}', '\u{BB50}', GraphemeClusterBreak::LV),
+    ('\u{BB51}', '\u{BB6B}', GraphemeClusterBreak::LVT), ('\u{BB6C}', '\u{BB6C}', GraphemeClusterBreak::LV),
+    ('\u{BB6D}', '\u{BB87}', GraphemeClusterBreak::LVT), ('\u{BB88}', '\u{BB88}', GraphemeClusterBreak::LV),
+    ('\u{BB89}', '\u{BBA3}', GraphemeClusterBreak::LVT), ('\u{BBA4}', '\u{BBA4}', GraphemeClusterBreak::LV),
+    ('\u{BBA5}', '\u{BBBF}', GraphemeClusterBreak::LVT), ('\u{BBC0}', '\u{BBC0}', GraphemeClusterBreak::LV),
+    ('\u{BBC1}', '\u{BBDB}', GraphemeClusterBreak::LVT), ('\u{BBDC}', '\u{BBDC}', GraphemeClusterBreak::LV),
+    ('\u{BBDD}', '\u{BBF7}', GraphemeClusterBreak::LVT), ('\u{BBF8}', '\u{BBF8}', GraphemeClusterBreak::LV),
+    ('\u{BBF9}', '\u{BC13}', GraphemeClusterBreak::LVT), ('\u{BC14}', '\u{BC14}', GraphemeClusterBreak::LV),
+    ('\u{BC15}', '\u{BC2F}', GraphemeClusterBreak::LVT), ('\u{BC30}', '\u{BC30}', GraphemeClusterBreak::LV),
+    ('\u{BC31}', '\u{BC4B}', GraphemeClusterBreak::LVT), ('\u{BC4C}', '\u{BC4C}', GraphemeClusterBreak::LV),
+    ('\u{BC4D}', '\u{BC67}', GraphemeClusterBreak::LVT), ('\u{BC68}', '\u{BC68}', GraphemeClusterBreak::LV),
+    ('\u{BC69}', '\u{BC83}', GraphemeClusterBreak::LVT), ('\u{BC84}', '\u{BC84}', GraphemeClusterBreak::LV),
+    ('\u{BC85}', '\u{BC9F}', GraphemeClusterBreak::LVT), ('\u{BCA0}', '\u{BCA0}', GraphemeClusterBreak::LV),
+    ('\u{BCA1}', '\u{BCBB}', GraphemeClusterBreak::LVT), ('\u{BCBC}', '\u{BCBC}', GraphemeClusterBreak::LV),
+    ('\u{BCBD}', '\u{BCD7}', GraphemeClusterBreak::LVT), ('\u{BCD8}', '\u{BCD8}', GraphemeClusterBreak::LV),
+    ('\u{BCD9}', '\u{BCF3}', GraphemeClusterBreak::LVT), ('\u{BCF4}', '\u{BCF4}', GraphemeClusterBreak::LV),
+    ('\u{BCF5}', '\u{BD0F}', GraphemeClusterBreak::LVT), ('\u{BD10}', '\u{BD10}', GraphemeClusterBreak::LV),
+    ('\u{BD11}', '\u{BD2B}', GraphemeClusterBreak::LVT), ('\u{BD2C}', '\u{BD2C}', GraphemeClusterBreak::LV),
+    ('\u{BD2D}', '\u{BD47}', GraphemeClusterBreak::LVT), ('\u{BD48}', '\u{BD48}', GraphemeClusterBreak::LV),
+    ('\u{BD49}', '\u{BD63}', GraphemeClusterBreak::LVT), ('\u{BD64}', '\u{BD64}', GraphemeClusterBreak::LV),
+    ('\u{BD65}', '\u{BD7F}', GraphemeClusterBreak::LVT), ('\u{BD80}', '\u{BD80}', GraphemeClusterBreak::LV),
+    ('\u{BD81}', '\u{BD9B}', GraphemeClusterBreak::LVT), ('\u{BD9C}', '\u{BD9C}', GraphemeClusterBreak::LV),
+    ('\u{BD9D}', '\u{BDB7}', GraphemeClusterBreak::LVT), ('\u{BDB8}', '\u{BDB8}', GraphemeClusterBreak::LV),
+    ('\u{BDB9}', '\u{BDD3}', GraphemeClusterBreak::LVT), ('\u{BDD4}', '\u{BDD4}', GraphemeClusterBreak::LV),
+    ('\u{BDD5}', '\u{BDEF}', GraphemeClusterBreak::LVT), ('\u{BDF0}', '\u{BDF0}', GraphemeClusterBreak::LV),
+    ('\u{BDF1}', '\u{BE0B}', GraphemeClusterBreak::LVT), ('\u{BE0C}', '\u{BE0C}', GraphemeClusterBreak::LV),
+    ('\u{BE0D}', '\u{BE27}', GraphemeClusterBreak::LVT), ('\u{BE28}', '\u{BE28}', GraphemeClusterBreak::LV),
+    ('\u{BE29}', '\u{BE43}', GraphemeClusterBreak::LVT), ('\u{BE44}', '\u{BE44}', GraphemeClusterBreak::LV),
+    ('\u{BE45}', '\u{BE5F}', GraphemeClusterBreak::LVT), ('\u{BE60}', '\u{BE60}', GraphemeClusterBreak::LV),
+    ('\u{BE61}', '\u{BE7B}', GraphemeClusterBreak::LVT), ('\u{BE7C}', '\u{BE7C}', GraphemeClusterBreak::LV),
+    ('\u{BE7D}', '\u{BE97}', GraphemeClusterBreak::LVT), ('\u{BE98}', '\u{BE98}', GraphemeClusterBreak::LV),
+    ('\u{BE99}', '\u{BEB3}', GraphemeClusterBreak::LVT), ('\u{BEB4}', '\u{BEB4}', GraphemeClusterBreak::LV),
+    ('\u{BEB5}', '\u{BECF}', GraphemeClusterBreak::LVT), ('\u{BED0}', '\u{BED0}', GraphemeClusterBreak::LV),
+    ('\u{BED1}', '\u{BEEB}', GraphemeClusterBreak::LVT), ('\u{BEEC}', '\u{BEEC}', GraphemeClusterBreak::LV),
+    ('\u{BEED}', '\u{BF07}', GraphemeClusterBreak::LVT), ('\u{BF08}', '\u{BF08}', GraphemeClusterBreak::LV),
+    ('\u{BF09}', '\u{BF23}', GraphemeClusterBreak::LVT), ('\u{BF24}', '\u{BF24}', GraphemeClusterBreak::LV),
+    ('\u{BF25}', '\u{BF3F}', GraphemeClusterBreak::LVT), ('\u{BF40}', '\u{BF40}', GraphemeClusterBreak::LV),
+    ('\u{BF41}', '\u{BF5B}', GraphemeClusterBreak::LVT), ('\u{BF5C}', '\u{BF5C}', GraphemeClusterBreak::LV),
+    ('\u{BF5D}', '\u{BF77}', GraphemeClusterBreak::LVT), ('\u{BF78}', '\u{BF78}', GraphemeClusterBreak::LV),
+    ('\u{BF79}', '\u{BF93}', GraphemeClusterBreak::LVT), ('\u{BF94}', '\u{BF94}', GraphemeClusterBreak::LV),
+    ('\u{BF95}', '\u{BFAF}', GraphemeClusterBreak::LVT), ('\u{BFB0}', '\u{BFB0}', GraphemeClusterBreak::LV),
+    ('\u{BFB1}', '\u{BFCB}', GraphemeClusterBreak::LVT), ('\u{BFCC}', '\u{BFCC}', GraphemeClusterBreak::LV),
+    ('\u{BFCD}', '\u{BFE7}', GraphemeClusterBreak::LVT), ('\u{BFE8}', '\u{BFE8}', GraphemeClusterBreak::LV),
+    ('\u{BFE9}', '\u{C003}', GraphemeClusterBreak::LVT), ('\u{C004}', '\u{C004}', GraphemeClusterBreak::LV),
+    ('\u{C005}', '\u{C01F}', GraphemeClusterBreak::LVT), ('\u{C020}', '\u{C020}', GraphemeClusterBreak::LV),
+    ('\u{C021}', '\u{C03B}', GraphemeClusterBreak::LVT), ('\u{C03C}', '\u{C03C}', GraphemeClusterBreak::LV),
+    ('\u{C03D}', '\u{C057}', GraphemeClusterBreak::LVT), ('\u{C058}', '\u{C058}', GraphemeClusterBreak::LV),
+    ('\u{C059}', '\u{C073}', GraphemeClusterBreak::LVT), ('\u{C074}', '\u{C074}', GraphemeClusterBreak::LV),
+    ('\u{C075}', '\u{C08F}', GraphemeClusterBreak::LVT), ('\u{C090}', '\u{C090}', GraphemeClusterBreak::LV),
+    ('\u{C091}', '\u{C0AB}', GraphemeClusterBreak::LVT), ('\u{C0AC}', '\u{C0AC}', GraphemeClusterBreak::LV),
+    ('\u{C0AD}', '\u{C0C7}', GraphemeClusterBreak::LVT), ('\u{C0C8}', '\u{C0C8}', GraphemeClusterBreak::LV),
+    ('\u{C0C9}', '\u{C0E3}', GraphemeClusterBreak::LVT), ('\u{C0E4}', '\u{C0E4}', GraphemeClusterBreak::LV),
+    ('\u{C0E5}', '\u{C0FF}', GraphemeClusterBreak::LVT), ('\u{C100}', '\u{C100}', GraphemeClusterBreak::LV),
+    ('\u{C101}', '\u{C11B}', GraphemeClusterBreak::LVT), ('\u{C11C}', '\u{C11C}', GraphemeClusterBreak::LV),
+    ('\u{C11D}', '\u{C137}', GraphemeClusterBreak::LVT), ('\u{C138}', '\u{C138}', GraphemeClusterBreak::LV),
+    ('\u{C139}', '\u{C153}', GraphemeClusterBreak::LVT), ('\u{C154}', '\u{C154}', GraphemeClusterBreak::LV),
+    ('\u{C155}', '\u{C16F}', GraphemeClusterBreak::LVT), ('\u{C170}', '\u{C170}', GraphemeClusterBreak::LV),
+    ('\u{C171}', '\u{C18B}', GraphemeClusterBreak::LVT), ('\u{C18C}', '\u{C18C}', GraphemeClusterBreak::LV),
+    ('\u{C18D}', '\u{C1A7}', GraphemeClusterBreak::LVT), ('\u{C1A8}', '\u{C1A8}', GraphemeClusterBreak::LV),
+    ('\u{C1A9}', '\u{C1C3}', GraphemeClusterBreak::LVT), ('\u{C1C4}', '\u{C1C4}', GraphemeClusterBreak::LV),
+    ('\u{C1C5}', '\u{C1DF}', GraphemeClusterBreak::LVT), ('\u{C1E0}', '\u{C1E0}', GraphemeClusterBreak::LV),
+    ('\u{C1E1}', '\u{C1FB}', GraphemeClusterBreak::LVT), ('\u{C1FC}', '\u{C1FC}', GraphemeClusterBreak::LV),
+    ('\u{C1FD}', '\u{C217}', GraphemeClusterBreak::LVT), ('\u{C218}', '\u{C218}', GraphemeClusterBreak::LV),
+    ('\u{C219}', '\u{C233}', GraphemeClusterBreak::LVT), ('\u{C234}', '\u{C234}', GraphemeClusterBreak::LV),
+    ('\u{C235}', '\u{C24F}', GraphemeClusterBreak::LVT), ('\u{C250}', '\u{C250}', GraphemeClusterBreak::LV),
+    ('\u{C251}', '\u{C26B}', GraphemeClusterBreak::LVT), ('\u{C26C}', '\u{C26C}', GraphemeClusterBreak::LV),
+    ('\u{C26D}', '\u{C287}', GraphemeClusterBreak::LVT), ('\u{C288}', '\u{C288}', GraphemeClusterBreak::LV),
+    ('\u{C289}', '\u{C2A3}', GraphemeClusterBreak::LVT), ('\u{C2A4}', '\u{C2A4}', GraphemeClusterBreak::LV),
+    ('\u{C2A5}', '\u{C2BF}', GraphemeClusterBreak::LVT), ('\u{C2C0}', '\u{C2C0}', GraphemeClusterBreak::LV),
+    ('\u{C2C1}', '\u{C2DB}', GraphemeClusterBreak::LVT), ('\u{C2DC}', '\u{C2DC}', GraphemeClusterBreak::LV),
+    ('\u{C2DD}', '\u{C2F7}', GraphemeClusterBreak::LVT), ('\u{C2F8}', '\u{C2F8}', GraphemeClusterBreak::LV),
+    ('\u{C2F9}', '\u{C313}', GraphemeClusterBreak::LVT), ('\u{C314}', '\u{C314}', GraphemeClusterBreak::LV),
+    ('\u{C315}', '\u{C32F}', GraphemeClusterBreak::LVT), ('\u{C330}', '\u{C330}', GraphemeClusterBreak::LV),
+    ('\u{C331}', '\u{C34B}', GraphemeClusterBreak::LVT), ('\u{C34C}', '\u{C34C}', GraphemeClusterBreak::LV),
+    ('\u{C34D}', '\u{C367}', GraphemeClusterBreak::LVT), ('\u{C368}', '\u{C368}', GraphemeClusterBreak::LV),
+    ('\u{C369}', '\u{C383}', GraphemeClusterBreak::LVT), ('\u{C384}', '\u{C384}', GraphemeClusterBreak::LV),
+    ('\u{C385}', '\u{C39F}', GraphemeClusterBreak::LVT), ('\u{C3A0}', '\u{C3A0}', GraphemeClusterBreak::LV),
+    ('\u{C3A1}', '\u{C3BB}', GraphemeClusterBreak::LVT), ('\u{C3BC}', '\u{C3BC}', GraphemeClusterBreak::LV),
+    ('\u{C3BD}', '\u{C3D7}', GraphemeClusterBreak::LVT), ('\u{C3D8}', '\u{C3D8}', GraphemeClusterBreak::LV),
+    ('\u{C3D9}', '\u{C3F3}', GraphemeClusterBreak::LVT), ('\u{C3F4}', '\u{C3F4}', GraphemeClusterBreak::LV),
+    ('\u{C3F5}', '\u{C40F}', GraphemeClusterBreak::LVT), ('\u{C410}', '\u{C410}', GraphemeClusterBreak::LV),
+    ('\u{C411}', '\u{C42B}', GraphemeClusterBreak::LVT), ('\u{C42C}', '\u{C42C}', GraphemeClusterBreak::LV),
+    ('\u{C42D}', '\u{C447}', GraphemeClusterBreak::LVT), ('\u{C448}', '\u{C448}', GraphemeClusterBreak::LV),
+    ('\u{C449}', '\u{C463}', GraphemeClusterBreak::LVT), ('\u{C464}', '\u{C464}', GraphemeClusterBreak::LV),
+    ('\u{C465}', '\u{C47F}', GraphemeClusterBreak::LVT), ('\u{C480}', '\u{C480}', GraphemeClusterBreak::LV),
+    ('\u{C481}', '\u{C49B}', GraphemeClusterBreak::LVT), ('\u{C49C}', '\u{C49C}', GraphemeClusterBreak::LV),
+    ('\u{C49D}', '\u{C4B7}', GraphemeClusterBreak::LVT), ('\u{C4B8}', '\u{C4B8}', GraphemeClusterBreak::LV),
+    ('\u{C4B9}', '\u{C4D3}', GraphemeClusterBreak::LVT), ('\u{C4D4}', '\u{C4D4}', GraphemeClusterBreak::LV),
+    ('\u{C4D5}', '\u{C4EF}', GraphemeClusterBreak::LVT), ('\u{C4F0}', '\u{C4F0}', GraphemeClusterBreak::LV),
+    ('\u{C4F1}', '\u{C50B}', GraphemeClusterBreak::LVT), ('\u{C50C}', '\u{C50C}', GraphemeClusterBreak::LV),
+    ('\u{C50D}', '\u{C527}', GraphemeClusterBreak::LVT), ('\u{C528}', '\u{C528}', GraphemeClusterBreak::LV),
+    ('\u{C529}', '\u{C543}', GraphemeClusterBreak::LVT), ('\u{C544}', '\u{C544}', GraphemeClusterBreak::LV),
+    ('\u{C545}', '\u{C55F}', GraphemeClusterBreak::LVT), ('\u{C560}', '\u{C560}', GraphemeClusterBreak::LV),
+    ('\u{C561}', '\u{C57B}', GraphemeClusterBreak::LVT), ('\u{C57C}', '\u{C57C}', GraphemeClusterBreak::LV),
+    ('\u{C57D}', '\u{C597}', GraphemeClusterBreak::LVT), ('\u{C598}', '\u{C598}', GraphemeClusterBreak::LV),
+    ('\u{C599}', '\u{C5B3}', GraphemeClusterBreak::LVT), ('\u{C5B4}', '\u{C5B4}', GraphemeClusterBreak::LV),
+    ('\u{C5B5}', '\u{C5CF}', GraphemeClusterBreak::LVT), ('\u{C5D0}', '\u{C5D0}', GraphemeClusterBreak::LV),
+    ('\u{C5D1}', '\u{C5EB}', GraphemeClusterBreak::LVT), ('\u{C5EC}', '\u{C5EC}', GraphemeClusterBreak::LV),
+    ('\u{C5ED}', '\u{C607}', GraphemeClusterBreak::LVT), ('\u{C608}', '\u{C608}', GraphemeClusterBreak::LV),
+    ('\u{C609}', '\u{C623}', GraphemeClusterBreak::LVT), ('\u{C624}', '\u{C624}', GraphemeClusterBreak::LV),
+    ('\u{C625}', '\u{C63F}', GraphemeClusterBreak::LVT), ('\u{C640}', '\u{C640}', GraphemeClusterBreak::LV),
+    ('\u{C641}', '\u{C65B}', GraphemeClusterBreak::LVT), ('\u{C65C}', '\u{C65C}', GraphemeClusterBreak::LV),
+    ('\u{C65D}', '\u{C677}', GraphemeClusterBreak::LVT), ('\u{C678}', '\u{C678}', GraphemeClusterBreak::LV),
+    ('\u{C679}', '\u{C693}', GraphemeClusterBreak::LVT), ('\u{C694}', '\u{C694}', GraphemeClusterBreak::LV),
+    ('\u{C695}', '\u{C6AF}', GraphemeClusterBreak::LVT), ('\u{C6B0}', '\u{C6B0}', GraphemeClusterBreak::LV),
+    ('\u{C6B1}', '\u{C6CB}', GraphemeClusterBreak::LVT), ('\u{C6CC}', '\u{C6CC}', GraphemeClusterBreak::LV),
+    ('\u{C6CD}', '\u{C6E7}', GraphemeClusterBreak::LVT), ('\u{C6E8}', '\u{C6E8}', GraphemeClusterBreak::LV),
+    ('\u{C6E9}', '\u{C703}', GraphemeClusterBreak::LVT), ('\u{C704}', '\u{C704}', GraphemeClusterBreak::LV),
+    ('\u{C705}', '\u{C71F}', GraphemeClusterBreak::LVT), ('\u{C720}', '\u{C720}', GraphemeClusterBreak::LV),
+    ('\u{C721}', '\u{C73B}', GraphemeClusterBreak::LVT), ('\u{C73C}', '\u{C73C}', GraphemeClusterBreak::LV),
+    ('\u{C73D}', '\u{C757}', GraphemeClusterBreak::LVT), ('\u{C758}', '\u{C758}', GraphemeClusterBreak::LV),
+    ('\u{C759}', '\u{C773}', GraphemeClusterBreak::LVT), ('\u{C774}', '\u{C774}', GraphemeClusterBreak::LV),
+    ('\u{C775}', '\u{C78F}', GraphemeClusterBreak::LVT), ('\u{C790}', '\u{C790}', GraphemeClusterBreak::LV),
+    ('\u{C791}', '\u{C7AB}', GraphemeClusterBreak::LVT), ('\u{C7AC}', '\u{C7AC}', GraphemeClusterBreak::LV),
+    ('\u{C7AD}', '\u{C7C7}', GraphemeClusterBreak::LVT), ('\u{C7C8}', '\u{C7C8}', GraphemeClusterBreak::LV),
+    ('\u{C7C9}', '\u{C7E3}', GraphemeClusterBreak::LVT), ('\u{C7E4}', '\u{C7E4}', GraphemeClusterBreak::LV),
+    ('\u{C7E5}', '\u{C7FF}', GraphemeClusterBreak::LVT), ('\u{C800}', '\u{C800}', GraphemeClusterBreak::LV),
+    ('\u{C801}', '\u{C81B}', GraphemeClusterBreak::LVT), ('\u{C81C}', '\u{C81C}', GraphemeClusterBreak::LV),
+    ('\u{C81D}', '\u{C837}', GraphemeClusterBreak::LVT), ('\u{C838}', '\u{C838}', GraphemeClusterBreak::LV),
+    ('\u{C839}', '\u{C853}', GraphemeClusterBreak::LVT), ('\u{C854}', '\u{C854}', GraphemeClusterBreak::LV),
+    ('\u{C855}', '\u{C86F}', GraphemeClusterBreak::LVT), ('\u{C870}', '\u{C870}', GraphemeClusterBreak::LV),
+    ('\u{C871}', '\u{C88B}', GraphemeClusterBreak::LVT), ('\u{C88C}', '\u{C88C}', GraphemeClusterBreak::LV),
+    ('\u{C88D}', '\u{C8A7}', GraphemeClusterBreak::LVT), ('\u{C8A8}', '\u{C8A8}', GraphemeClusterBreak::LV),
+    ('\u{C8A9}', '\u{C8C3}', GraphemeClusterBreak::LVT), ('\u{C8C4}', '\u{C8C4}', GraphemeClusterBreak::LV),
+    ('\u{C8C5}', '\u{C8DF}', GraphemeClusterBreak::LVT), ('\u{C8E0}', '\u{C8E0}', GraphemeClusterBreak::LV),
+    ('\u{C8E1}', '\u{C8FB}', GraphemeClusterBreak::LVT), ('\u{C8FC}', '\u{C8FC}', GraphemeClusterBreak::LV),
+    ('\u{C8FD}', '\u{C917}', GraphemeClusterBreak::LVT), ('\u{C918}', '\u{C918}', GraphemeClusterBreak::LV),
+    ('\u{C919}', '\u{C933}', GraphemeClusterBreak::LVT), ('\u{C934}', '\u{C934}', GraphemeClusterBreak::LV),
+    ('\u{C935}', '\u{C94F}', GraphemeClusterBreak::LVT), ('\u{C950}', '\u{C950}', GraphemeClusterBreak::LV),
+    ('\u{C951}', '\u{C96B}', GraphemeClusterBreak::LVT), ('\u{C96C}', '\u{C96C}', GraphemeClusterBreak::LV),
+    ('\u{C96D}', '\u{C987}', GraphemeClusterBreak::LVT), ('\u{C988}', '\u{C988}', GraphemeClusterBreak::LV),
+    ('\u{C989}', '\u{C9A3}', GraphemeClusterBreak::LVT), ('\u{C9A4}', '\u{C9A4}', GraphemeClusterBreak::LV),
+    ('\u{C9A5}', '\u{C9BF}', GraphemeClusterBreak::LVT), ('\u{C9C0}', '\u{C9C0}', GraphemeClusterBreak::LV),
+    ('\u{C9C1}', '\u{C9DB}', GraphemeClusterBreak::LVT), ('\u{C9DC}', '\u{C9DC}', GraphemeClusterBreak::LV),
+    ('\u{C9DD}', '\u{C9F7}', GraphemeClusterBreak::LVT), ('\u{C9F8}', '\u{C9F8}', GraphemeClusterBreak::LV),
+    ('\u{C9F9}', '\u{CA13}', GraphemeClusterBreak::LVT), ('\u{CA14}', '\u{CA14}', GraphemeClusterBreak::LV),
+    ('\u{CA15}', '\u{CA2F}', GraphemeClusterBreak::LVT), ('\u{CA30}', '\u{CA30}', GraphemeClusterBreak::LV),
+    ('\u{CA31}', '\u{CA4B}', GraphemeClusterBreak::LVT), ('\u{CA4C}', '\u{CA4C}', GraphemeClusterBreak::LV),
+    ('\u{CA4D}', '\u{CA67}', GraphemeClusterBreak::LVT), ('\u{CA68}', '\u{CA68}', GraphemeClusterBreak::LV),
+    ('\u{CA69}', '\u{CA83}', GraphemeClusterBreak::LVT), ('\u{CA84}', '\u{CA84}', GraphemeClusterBreak::LV),
+    ('\u{CA85}', '\u{CA9F}', GraphemeClusterBreak::LVT), ('\u{CAA0}', '\u{CAA0}', GraphemeClusterBreak::LV),
+    ('\u{CAA1}', '\u{CABB}', GraphemeClusterBreak::LVT), ('\u{CABC}', '\u{CABC}', GraphemeClusterBreak::LV),
+    ('\u{CABD}', '\u{CAD7}', GraphemeClusterBreak::LVT), ('\u{CAD8}', '\u{CAD8}', GraphemeClusterBreak::LV),
+    ('\u{CAD9}', '\u{CAF3}', GraphemeClusterBreak::LVT), ('\u{CAF4}', '\u{CAF4}', GraphemeClusterBreak::LV),
+    ('\u{CAF5}', '\u{CB0F}', GraphemeClusterBreak::LVT), ('\u{CB10}', '\u{CB10}', GraphemeClusterBreak::LV),
+    ('\u{CB11}', '\u{CB2B}', GraphemeClusterBreak::LVT), ('\u{CB2C}', '\u{CB2C}', GraphemeClusterBreak::LV),
+    ('\u{CB2D}', '\u{CB47}', GraphemeClusterBreak::LVT), ('\u{CB48}', '\u{CB48}', GraphemeClusterBreak::LV),
+    ('\u{CB49}', '\u{CB63}', GraphemeClusterBreak::LVT), ('\u{CB64}', '\u{CB64}', GraphemeClusterBreak::LV),
+    ('\u{CB65}', '\u{CB7F}', GraphemeClusterBreak::LVT), ('\u{CB80}', '\u{CB80}', GraphemeClusterBreak::LV),
+    ('\u{CB81}', '\u{CB9B}', GraphemeClusterBreak::LVT), ('\u{CB9C}', '\u{CB9C}', GraphemeClusterBreak::LV),
+    ('\u{CB9D}', '\u{CBB7}', GraphemeClusterBreak::LVT), ('\u{CBB8}', '\u{CBB8}', GraphemeClusterBreak::LV),
+    ('\u{CBB9}', '\u{CBD3}', GraphemeClusterBreak::LVT), ('\u{CBD4}', '\u{CBD4}', GraphemeClusterBreak::LV),
+    ('\u{CBD5}', '\u{CBEF}', GraphemeClusterBreak::LVT), ('\u{CBF0}', '\u{CBF0}', GraphemeClusterBreak::LV),
+    ('\u{CBF1}', '\u{CC0B}', GraphemeClusterBreak::LVT), ('\u{CC0C}', '\u{CC0C}', GraphemeClusterBreak::LV),
+    ('\u{CC0D}', '\u{CC27}', GraphemeClusterBreak::LVT), ('\u{CC28}', '\u{CC28}', GraphemeClusterBreak::LV),
+    ('\u{CC29}', '\u{CC43}', GraphemeClusterBreak::LVT), ('\u{CC44}', '\u{CC44}', GraphemeClusterBreak::LV),
+    ('\u{CC45}', '\u{CC5F}', GraphemeClusterBreak::LVT), ('\u{CC60}', '\u{CC60}', GraphemeClusterBreak::LV),
+    ('\u{CC61}', '\u{CC7B}', GraphemeClusterBreak::LVT), ('\u{CC7C}', '\u{CC7C}', GraphemeClusterBreak::LV),
+    ('\u{CC7D}', '\u{CC97}', GraphemeClusterBreak::LVT), ('\u{CC98}', '\u{CC98}', GraphemeClusterBreak::LV),
+    ('\u{CC99}', '\u{CCB3}', GraphemeClusterBreak::LVT), ('\u{CCB4}', '\u{CCB4}', GraphemeClusterBreak::LV),
+    ('\u{CCB5}', '\u{CCCF}', GraphemeClusterBreak::LVT), ('\u{CCD0}', '\u{CCD0}', GraphemeClusterBreak::LV),
+    ('\u{CCD1}', '\u{CCEB}', GraphemeClusterBreak::LVT), ('\u{CCEC}', '\u{CCEC}', GraphemeClusterBreak::LV),
+    ('\u{CCED}', '\u{CD07}', GraphemeClusterBreak::LVT), ('\u{CD08}', '\u{CD08}', GraphemeClusterBreak::LV),
+    ('\u{CD09}', '\u{CD23}', GraphemeClusterBreak::LVT), ('\u{CD24}', '\u{CD24}', GraphemeClusterBreak::LV),
+    ('\u{CD25}', '\u{CD3F}', GraphemeClusterBreak::LVT), ('\u{CD40}', '\u{CD40}', GraphemeClusterBreak::LV),
+    ('\u{CD41}', '\u{CD5B}', GraphemeClusterBreak::LVT), ('\u{CD5C}', '\u{CD5C}', GraphemeClusterBreak::LV),
+    ('\u{CD5D}', '\u{CD77}', GraphemeClusterBreak::LVT), ('\u{CD78}', '\u{CD78}', GraphemeClusterBreak::LV),
+    ('\u{CD79}', '\u{CD93}', GraphemeClusterBreak::LVT), ('\u{CD94}', '\u{CD94}', GraphemeClusterBreak::LV),
+    ('\u{CD95}', '\u{CDAF}', GraphemeClusterBreak::LVT), ('\u{CDB0}', '\u{CDB0}', GraphemeClusterBreak::LV),
+    ('\u{CDB1}', '\u{CDCB}', GraphemeClusterBreak::LVT), ('\u{CDCC}', '\u{CDCC}', GraphemeClusterBreak::LV),
+    ('\u{CDCD}', '\u{CDE7}', GraphemeClusterBreak::LVT), ('\u{CDE8}', '\u{CDE8}', GraphemeClusterBreak::LV),
+    ('\u{CDE9}', '\u{CE03}', GraphemeClusterBreak::LVT), ('\u{CE04}', '\u{CE04}', GraphemeClusterBreak::LV),
+    ('\u{CE05}', '\u{CE1F}', GraphemeClusterBreak::LVT), ('\u{CE20}', '\u{CE20}', GraphemeClusterBreak::LV),
+    ('\u{CE21}', '\u{CE3B}', GraphemeClusterBreak::LVT), ('\u{CE3C}', '\u{CE3C}', GraphemeClusterBreak::LV),
+    ('\u{CE3D}', '\u{CE57}', GraphemeClusterBreak::LVT), ('\u{CE58}', '\u{CE58}', GraphemeClusterBreak::LV),
+    ('\u{CE59}', '\u{CE73}', GraphemeClusterBreak::LVT), ('\u{CE74}', '\u{CE74}', GraphemeClusterBreak::LV),
+    ('\u{CE75}', '\u{CE8F}', GraphemeClusterBreak::LVT), ('\u{CE90}', '\u{CE90}', GraphemeClusterBreak::LV),
+    ('\u{CE91}', '\u{CEAB}', GraphemeClusterBreak::LVT), ('\u{CEAC}', '\u{CEAC}', GraphemeClusterBreak::LV),
+    ('\u{CEAD}', '\u{CEC7}', GraphemeClusterBreak::LVT), ('\u{CEC8}', '\u{CEC8}', GraphemeClusterBreak::LV),
+    ('\u{CEC9}', '\u{CEE3}', GraphemeClusterBreak::LVT), ('\u{CEE4}', '\u{CEE4}', GraphemeClusterBreak::LV),
+    ('\u{CEE5}', '\u{CEFF}', GraphemeClusterBreak::LVT), ('\u{CF00}', '\u{CF00}', GraphemeClusterBreak::LV),
+    ('\u{CF01}', '\u{CF1B}', GraphemeClusterBreak::LVT), ('\u{CF1C}', '\u{CF1C}', GraphemeClusterBreak::LV),
+    ('\u{CF1D}', '\u{CF37}', GraphemeClusterBreak::LVT), ('\u{CF38}', '\u{CF38}', GraphemeClusterBreak::LV),
+    ('\u{CF39}', '\u{CF53}', GraphemeClusterBreak::LVT), ('\u{CF54}', '\u{CF54}', GraphemeClusterBreak::LV),
+    ('\u{CF55}', '\u{CF6F}', GraphemeClusterBreak::LVT), ('\u{CF70}', '\u{CF70}', GraphemeClusterBreak::LV),
+    ('\u{CF71}', '\u{CF8B}', GraphemeClusterBreak::LVT), ('\u{CF8C}', '\u{CF8C}', GraphemeClusterBreak::LV),
+    ('\u{CF8D}', '\u{CFA7}', GraphemeClusterBreak::LVT), ('\u{CFA8}', '\u{CFA8}', GraphemeClusterBreak::LV),
+    ('\u{CFA9}', '\u{CFC3}', GraphemeClusterBreak::LVT), ('\u{CFC4}', '\u{CFC4}', GraphemeClusterBreak::LV),
+    ('\u{CFC5}', '\u{CFDF}', GraphemeClusterBreak::LVT), ('\u{CFE0}', '\u{CFE0}', GraphemeClusterBreak::LV),
+    ('\u{CFE1}', '\u{CFFB}', GraphemeClusterBreak::LVT), ('\u{CFFC}', '\u{CFFC}', GraphemeClusterBreak::LV),
+    ('\u{CFFD}', '\u{D017}', GraphemeClusterBreak::LVT), ('\u{D018}', '\u{D018}', GraphemeClusterBreak::LV),
+    ('\u{D019}', '\u{D033}', GraphemeClusterBreak::LVT), ('\u{D034}', '\u{D034}', GraphemeClusterBreak::LV),
+    ('\u{D035}', '\u{D04F}', GraphemeClusterBreak::LVT), ('\u{D050}', '\u{D050}', GraphemeClusterBreak::LV),
+    ('\u{D051}', '\u{D06B}', GraphemeClusterBreak::LVT), ('\u{D06C}', '\u{D06C}', GraphemeClusterBreak::LV),
+    ('\u{D06D}', '\u{D087}', GraphemeClusterBreak::LVT), ('\u{D088}', '\u{D088}', GraphemeClusterBreak::LV),
+    ('\u{D089}', '\u{D0A3}', GraphemeClusterBreak::LVT), ('\u{D0A4}', '\u{D0A4}', GraphemeClusterBreak::LV),
+    ('\u{D0A5}', '\u{D0BF}', GraphemeClusterBreak::LVT), ('\u{D0C0}', '\u{D0C0}', GraphemeClusterBreak::LV),
+    ('\u{D0C1}', '\u{D0DB}', GraphemeClusterBreak::LVT), ('\u{D0DC}', '\u{D0DC}', GraphemeClusterBreak::LV),
+    ('\u{D0DD}', '\u{D0F7}', GraphemeClusterBreak::LVT), ('\u{D0F8}', '\u{D0F8}', GraphemeClusterBreak::LV),
+    ('\u{D0F9}', '\u{D113}', GraphemeClusterBreak::LVT), ('\u{D114}', '\u{D114}', GraphemeClusterBreak::LV),
+    ('\u{D115}', '\u{D12F}', GraphemeClusterBreak::LVT), ('\u{D130}', '\u{D130}', GraphemeClusterBreak::LV),
+    ('\u{D131}', '\u{D14B}', GraphemeClusterBreak::LVT), ('\u{D14C}', '\u{D14C}', GraphemeClusterBreak::LV),
+    ('\u{D14D}', '\u{D167}', GraphemeClusterBreak::LVT), ('\u{D168}', '\u{D168}', GraphemeClusterBreak::LV),
+    ('\u{D169}', '\u{D183}', GraphemeClusterBreak::LVT), ('\u{D184}', '\u{D184}', GraphemeClusterBreak::LV),
+    ('\u{D185}', '\u{D19F}', GraphemeClusterBreak::LVT), ('\u{D1A0}', '\u{D1A0}', GraphemeClusterBreak::LV),
+    ('\u{D1A1}', '\u{D1BB}', GraphemeClusterBreak::LVT), ('\u{D1BC}', '\u{D1BC}', GraphemeClusterBreak::LV),
+    ('\u{D1BD}', '\u{D1D7}', GraphemeClusterBreak::LVT), ('\u{D1D8}', '\u{D1D8}', GraphemeClusterBreak::LV),
+    ('\u{D1D9}', '\u{D1F3}', GraphemeClusterBreak::LVT), ('\u{D1F4}', '\u{D1F4}', GraphemeClusterBreak::LV),
+    ('\u{D1F5}', '\u{D20F}', GraphemeClusterBreak::LVT), ('\u{D210}', '\u{D210}', GraphemeClusterBreak::LV),
+    ('\u{D211}', '\u{D22B}', GraphemeClusterBreak::LVT), ('\u{D22C}', '\u{D22C}', GraphemeClusterBreak::LV),
+    ('\u{D22D}', '\u{D247}', GraphemeClusterBreak::LVT), ('\u{D248}', '\u{D248}', GraphemeClusterBreak::LV),
+    ('\u{D249}', '\u{D263}', GraphemeClusterBreak::LVT), ('\u{D264}', '\u{D264}', GraphemeClusterBreak::LV),
+    ('\u{D265}', '\u{D27F}', GraphemeClusterBreak::LVT), ('\u{D280}', '\u{D280}', GraphemeClusterBreak::LV),
+    ('\u{D281}', '\u{D29B}', GraphemeClusterBreak::LVT), ('\u{D29C}', '\u{D29C}', GraphemeClusterBreak::LV),
+    ('\u{D29D}', '\u{D2B7}', GraphemeClusterBreak::LVT), ('\u{D2B8}', '\u{D2B8}', GraphemeClusterBreak::LV),
+    ('\u{D2B9}', '\u{D2D3}', GraphemeClusterBreak::LVT), ('\u{D2D4}', '\u{D2D4}', GraphemeClusterBreak::LV),
+    ('\u{D2D5}', '\u{D2EF}', GraphemeClusterBreak::LVT), ('\u{D2F0}', '\u{D2F0}', GraphemeClusterBreak::LV),
+    ('\u{D2F1}', '\u{D30B}', GraphemeClusterBreak::LVT), ('\u{D30C}', '\u{D30C}', GraphemeClusterBreak::LV),
+    ('\u{D30D}', '\u{D327}', GraphemeClusterBreak::LVT), ('\u{D328}', '\u{D328}', GraphemeClusterBreak::LV),
+    ('\u{D329}', '\u{D343}', GraphemeClusterBreak::LVT), ('\u{D344}', '\u{D344}', GraphemeClusterBreak::LV),
+    ('\u{D345}', '\u{D35F}', GraphemeClusterBreak::LVT), ('\u{D360}', '\u{D360}', GraphemeClusterBreak::LV),
+    ('\u{D361}', '\u{D37B}', GraphemeClusterBreak::LVT), ('\u{D37C}', '\u{D37C}', GraphemeClusterBreak::LV),
+    ('\u{D37D}', '\u{D397}', GraphemeClusterBreak::LVT), ('\u{D398}', '\u{D398}', GraphemeClusterBreak::LV),
+    ('\u{D399}', '\u{D3B3}', GraphemeClusterBreak::LVT), ('\u{D3B4}', '\u{D3B4}', GraphemeClusterBreak::LV),
+    ('\u{D3B5}', '\u{D3CF}', GraphemeClusterBreak::LVT), ('\u{D3D0}', '\u{D3D0}', GraphemeClusterBreak::LV),
+    ('\u{D3D1}', '\u{D3EB}', GraphemeClusterBreak::LVT), ('\u{D3EC}', '\u{D3EC}', GraphemeClusterBreak::LV),
+    ('\u{D3ED}', '\u{D407}', GraphemeClusterBreak::LVT), ('\u{D408}', '\u{D408}', GraphemeClusterBreak::LV),
+    ('\u{D409}', '\u{D423}', GraphemeClusterBreak::LVT), ('\u{D424}', '\u{D424}', GraphemeClusterBreak::LV),
+    ('\u{D425}', '\u{D43F}', GraphemeClusterBreak::LVT), ('\u{D440}', '\u{D440}', GraphemeClusterBreak::LV),
+    ('\u{D441}', '\u{D45B}', GraphemeClusterBreak::LVT), ('\u{D45C}', '\u{D45C}', GraphemeClusterBreak::LV),
+    ('\u{D45D}', '\u{D477}', GraphemeClusterBreak::LVT), ('\u{D478}', '\u{D478}', GraphemeClusterBreak::LV),
+    ('\u{D479}', '\u{D493}', GraphemeClusterBreak::LVT), ('\u{D494}', '\u{D494}', GraphemeClusterBreak::LV),
+    ('\u{D495}', '\u{D4AF}', GraphemeClusterBreak::LVT), ('\u{D4B0}', '\u{D4B0}', GraphemeClusterBreak::LV),
+    ('\u{D4B1}', '\u{D4CB}', GraphemeClusterBreak::LVT), ('\u{D4CC}', '\u{D4CC}', GraphemeClusterBreak::LV),
+    ('\u{D4CD}', '\u{D4E7}', GraphemeClusterBreak::LVT), ('\u{D4E8}', '\u{D4E8}', GraphemeClusterBreak::LV),
+    ('\u{D4E9}', '\u{D503}', GraphemeClusterBreak::LVT), ('\u{D504}', '\u{D504}', GraphemeClusterBreak::LV),
+    ('\u{D505}', '\u{D51F}', GraphemeClusterBreak::LVT), ('\u{D520}', '\u{D520}', GraphemeClusterBreak::LV),
+    ('\u{D521}', '\u{D53B}', GraphemeClusterBreak::LVT), ('\u{D53C}', '\u{D53C}', GraphemeClusterBreak::LV),
+    ('\u{D53D}', '\u{D557}', GraphemeClusterBreak::LVT), ('\u{D558}', '\u{D558}', GraphemeClusterBreak::LV),
+    ('\u{D559}', '\u{D573}', GraphemeClusterBreak::LVT), ('\u{D574}', '\u{D574}', GraphemeClusterBreak::LV),
+    ('\u{D575}', '\u{D58F}', GraphemeClusterBreak::LVT), ('\u{D590}', '\u{D590}', GraphemeClusterBreak::LV),
+    ('\u{D591}', '\u{D5AB}', GraphemeClusterBreak::LVT), ('\u{D5AC}', '\u{D5AC}', GraphemeClusterBreak::LV),
+    ('\u{D5AD}', '\u{D5C7}', GraphemeClusterBreak::LVT), ('\u{D5C8}', '\u{D5C8}', GraphemeClusterBreak::LV),
+    ('\u{D5C9}', '\u{D5E3}', GraphemeClusterBreak::LVT), ('\u{D5E4}', '\u{D5E4}', GraphemeClusterBreak::LV),
+    ('\u{D5E5}', '\u{D5FF}', GraphemeClusterBreak::LVT), ('\u{D600}', '\u{D600}', GraphemeClusterBreak::LV),
+    ('\u{D601}', '\u{D61B}', GraphemeClusterBreak::LVT), ('\u{D61C}', '\u{D61C}', GraphemeClusterBreak::LV),
+    ('\u{D61D}', '\u{D637}', GraphemeClusterBreak::LVT), ('\u{D638}', '\u{D638}', GraphemeClusterBreak::LV),
+    ('\u{D639}', '\u{D653}', GraphemeClusterBreak::LVT), ('\u{D654}', '\u{D654}', GraphemeClusterBreak::LV),
+    ('\u{D655}', '\u{D66F}', GraphemeClusterBreak::LVT), ('\u{D670}', '\u{D670}', GraphemeClusterBreak::LV),
+    ('\u{D671}', '\u{D68B}', GraphemeClusterBreak::LVT), ('\u{D68C}', '\u{D68C}', GraphemeClusterBreak::LV),
+    ('\u{D68D}', '\u{D6A7}', GraphemeClusterBreak::LVT), ('\u{D6A8}', '\u{D6A8}', GraphemeClusterBreak::LV),
+    ('\u{D6A9}', '\u{D6C3}', GraphemeClusterBreak::LVT), ('\u{D6C4}', '\u{D6C4}', GraphemeClusterBreak::LV),
+    ('\u{D6C5}', '\u{D6DF}', GraphemeClusterBreak::LVT), ('\u{D6E0}', '\u{D6E0}', GraphemeClusterBreak::LV),
+    ('\u{D6E1}', '\u{D6FB}', GraphemeClusterBreak::LVT), ('\u{D6FC}', '\u{D6FC}', GraphemeClusterBreak::LV),
+    ('\u{D6FD}', '\u{D717}', GraphemeClusterBreak::LVT), ('\u{D718}', '\u{D718}', GraphemeClusterBreak::LV),
+    ('\u{D719}', '\u{D733}', GraphemeClusterBreak::LVT), ('\u{D734}', '\u{D734}', GraphemeClusterBreak::LV),
+    ('\u{D735}', '\u{D74F}', GraphemeClusterBreak::LVT), ('\u{D750}', '\u{D750}', GraphemeClusterBreak::LV),
+    ('\u{D751}', '\u{D76B}', GraphemeClusterBreak::LVT), ('\u{D76C}', '\u{D76C}', GraphemeClusterBreak::LV),
+    ('\u{D76D}', '\u{D787}', GraphemeClusterBreak::LVT), ('\u{D788}', '\u{D788}', GraphemeClusterBreak::LV),
+    ('\u{D789}', '\u{D7A3}', GraphemeClusterBreak::LVT), ('\u{D7B0}', '\u{D7C6}', GraphemeClusterBreak::V),
+    ('\u{D7CB}', '\u{D7FB}', GraphemeClusterBreak::T), ('\u{FB1E}', '\u{FB1E}', GraphemeClusterBreak::Extend),
+    ('\u{FE00}', '\u{FE0F}', GraphemeClusterBreak::Extend), ('\u{FE20}', '\u{FE2F}', GraphemeClusterBreak::Extend),
+    ('\u{FEFF}', '\u{FEFF}', GraphemeClusterBreak::Control), ('\u{FF9E}', '\u{FF9F}', GraphemeClusterBreak::Extend),
+    ('\u{FFF0}', '\u{FFFB}', GraphemeClusterBreak::Control), ('\u{101FD}', '\u{101FD}', GraphemeClusterBreak::Extend),
+    ('\u{102E0}', '\u{102E0}', GraphemeClusterBreak::Extend), ('\u{10376}', '\u{1037A}', GraphemeClusterBreak::Extend),
+    ('\u{10A01}', '\u{10A03}', GraphemeClusterBreak::Extend), ('\u{10A05}', '\u{10A06}', GraphemeClusterBreak::Extend),
+    ('\u{10A0C}', '\u{10A0F}', GraphemeClusterBreak::Extend), ('\u{10A38}', '\u{10A3A}', GraphemeClusterBreak::Extend),
+    ('\u{10A3F}', '\u{10A3F}', GraphemeClusterBreak::Extend), ('\u{10AE5}', '\u{10AE6}', GraphemeClusterBreak::Extend),
+    ('\u{10D24}', '\u{10D27}', GraphemeClusterBreak::Extend), ('\u{10D69}', '\u{10D6D}', GraphemeClusterBreak::Extend),
+    ('\u{10EAB}', '\u{10EAC}', GraphemeClusterBreak::Extend), ('\u{10EFA}', '\u{10EFF}', GraphemeClusterBreak::Extend),
+    ('\u{10F46}', '\u{10F50}', GraphemeClusterBreak::Extend), ('\u{10F82}', '\u{10F85}', GraphemeClusterBreak::Extend),
+    ('\u{11000}', '\u{11000}', GraphemeClusterBreak::SpacingMark), ('\u{11001}', '\u{11001}', GraphemeClusterBreak::Extend),
+    ('\u{11002}', '\u{11002}', GraphemeClusterBreak::SpacingMark), ('\u{11038}', '\u{11046}', GraphemeClusterBreak::Extend),
+    ('\u{11070}', '\u{11070}', GraphemeClusterBreak::Extend), ('\u{11073}', '\u{11074}', GraphemeClusterBreak::Extend),
+    ('\u{1107F}', '\u{11081}', GraphemeClusterBreak::Extend), ('\u{11082}', '\u{11082}', GraphemeClusterBreak::SpacingMark),
+    ('\u{110B0}', '\u{110B2}', GraphemeClusterBreak::SpacingMark), ('\u{110B3}', '\u{110B6}', GraphemeClusterBreak::Extend),
+    ('\u{110B7}', '\u{110B8}', GraphemeClusterBreak::SpacingMark), ('\u{110B9}', '\u{110BA}', GraphemeClusterBreak::Extend),
+    ('\u{110BD}', '\u{110BD}', GraphemeClusterBreak::Prepend), ('\u{110C2}', '\u{110C2}', GraphemeClusterBreak::Extend),
+    ('\u{110CD}', '\u{110CD}', GraphemeClusterBreak::Prepend), ('\u{11100}', '\u{11102}', GraphemeClusterBreak::Extend),
+    ('\u{11127}', '\u{1112B}', GraphemeClusterBreak::Extend), ('\u{1112C}', '\u{1112C}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1112D}', '\u{11134}', GraphemeClusterBreak::Extend), ('\u{11145}', '\u{11146}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11173}', '\u{11173}', GraphemeClusterBreak::Extend), ('\u{11180}', '\u{11181}', GraphemeClusterBreak::Extend),
+    ('\u{11182}', '\u{11182}', GraphemeClusterBreak::SpacingMark), ('\u{111B3}', '\u{111B5}', GraphemeClusterBreak::SpacingMark),
+    ('\u{111B6}', '\u{111BE}', GraphemeClusterBreak::Extend), ('\u{111BF}', '\u{111BF}', GraphemeClusterBreak::SpacingMark),
+    ('\u{111C0}', '\u{111C0}', GraphemeClusterBreak::Extend), ('\u{111C2}', '\u{111C3}', GraphemeClusterBreak::Prepend),
+    ('\u{111C9}', '\u{111CC}', GraphemeClusterBreak::Extend), ('\u{111CE}', '\u{111CE}', GraphemeClusterBreak::SpacingMark),
+    ('\u{111CF}', '\u{111CF}', GraphemeClusterBreak::Extend), ('\u{1122C}', '\u{1122E}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1122F}', '\u{11231}', GraphemeClusterBreak::Extend), ('\u{11232}', '\u{11233}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11234}', '\u{11237}', GraphemeClusterBreak::Extend), ('\u{1123E}', '\u{1123E}', GraphemeClusterBreak::Extend),
+    ('\u{11241}', '\u{11241}', GraphemeClusterBreak::Extend), ('\u{112DF}', '\u{112DF}', GraphemeClusterBreak::Extend),
+    ('\u{112E0}', '\u{112E2}', GraphemeClusterBreak::SpacingMark), ('\u{112E3}', '\u{112EA}', GraphemeClusterBreak::Extend),
+    ('\u{11300}', '\u{11301}', GraphemeClusterBreak::Extend), ('\u{11302}', '\u{11303}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1133B}', '\u{1133C}', GraphemeClusterBreak::Extend), ('\u{1133E}', '\u{1133E}', GraphemeClusterBreak::Extend),
+    ('\u{1133F}', '\u{1133F}', GraphemeClusterBreak::SpacingMark), ('\u{11340}', '\u{11340}', GraphemeClusterBreak::Extend),
+    ('\u{11341}', '\u{11344}', GraphemeClusterBreak::SpacingMark), ('\u{11347}', '\u{11348}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1134B}', '\u{1134C}', GraphemeClusterBreak::SpacingMark), ('\u{1134D}', '\u{1134D}', GraphemeClusterBreak::Extend),
+    ('\u{11357}', '\u{11357}', GraphemeClusterBreak::Extend), ('\u{11362}', '\u{11363}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11366}', '\u{1136C}', GraphemeClusterBreak::Extend), ('\u{11370}', '\u{11374}', GraphemeClusterBreak::Extend),
+    ('\u{113B8}', '\u{113B8}', GraphemeClusterBreak::Extend), ('\u{113B9}', '\u{113BA}', GraphemeClusterBreak::SpacingMark),
+    ('\u{113BB}', '\u{113C0}', GraphemeClusterBreak::Extend), ('\u{113C2}', '\u{113C2}', GraphemeClusterBreak::Extend),
+    ('\u{113C5}', '\u{113C5}', GraphemeClusterBreak::Extend), ('\u{113C7}', '\u{113C9}', GraphemeClusterBreak::Extend),
+    ('\u{113CA}', '\u{113CA}', GraphemeClusterBreak::SpacingMark), ('\u{113CC}', '\u{113CD}', GraphemeClusterBreak::SpacingMark),
+    ('\u{113CE}', '\u{113D0}', GraphemeClusterBreak::Extend), ('\u{113D1}', '\u{113D1}', GraphemeClusterBreak::Prepend),
+    ('\u{113D2}', '\u{113D2}', GraphemeClusterBreak::Extend), ('\u{113E1}', '\u{113E2}', GraphemeClusterBreak::Extend),
+    ('\u{11435}', '\u{11437}', GraphemeClusterBreak::SpacingMark), ('\u{11438}', '\u{1143F}', GraphemeClusterBreak::Extend),
+    ('\u{11440}', '\u{11441}', GraphemeClusterBreak::SpacingMark), ('\u{11442}', '\u{11444}', GraphemeClusterBreak::Extend),
+    ('\u{11445}', '\u{11445}', GraphemeClusterBreak::SpacingMark), ('\u{11446}', '\u{11446}', GraphemeClusterBreak::Extend),
+    ('\u{1145E}', '\u{1145E}', GraphemeClusterBreak::Extend), ('\u{114B0}', '\u{114B0}', GraphemeClusterBreak::Extend),
+    ('\u{114B1}', '\u{114B2}', GraphemeClusterBreak::SpacingMark), ('\u{114B3}', '\u{114B8}', GraphemeClusterBreak::Extend),
+    ('\u{114B9}', '\u{114B9}', GraphemeClusterBreak::SpacingMark), ('\u{114BA}', '\u{114BA}', GraphemeClusterBreak::Extend),
+    ('\u{114BB}', '\u{114BC}', GraphemeClusterBreak::SpacingMark), ('\u{114BD}', '\u{114BD}', GraphemeClusterBreak::Extend),
+    ('\u{114BE}', '\u{114BE}', GraphemeClusterBreak::SpacingMark), ('\u{114BF}', '\u{114C0}', GraphemeClusterBreak::Extend),
+    ('\u{114C1}', '\u{114C1}', GraphemeClusterBreak::SpacingMark), ('\u{114C2}', '\u{114C3}', GraphemeClusterBreak::Extend),
+    ('\u{115AF}', '\u{115AF}', GraphemeClusterBreak::Extend), ('\u{115B0}', '\u{115B1}', GraphemeClusterBreak::SpacingMark),
+    ('\u{115B2}', '\u{115B5}', GraphemeClusterBreak::Extend), ('\u{115B8}', '\u{115BB}', GraphemeClusterBreak::SpacingMark),
+    ('\u{115BC}', '\u{115BD}', GraphemeClusterBreak::Extend), ('\u{115BE}', '\u{115BE}', GraphemeClusterBreak::SpacingMark),
+    ('\u{115BF}', '\u{115C0}', GraphemeClusterBreak::Extend), ('\u{115DC}', '\u{115DD}', GraphemeClusterBreak::Extend),
+    ('\u{11630}', '\u{11632}', GraphemeClusterBreak::SpacingMark), ('\u{11633}', '\u{1163A}', GraphemeClusterBreak::Extend),
+    ('\u{1163B}', '\u{1163C}', GraphemeClusterBreak::SpacingMark), ('\u{1163D}', '\u{1163D}', GraphemeClusterBreak::Extend),
+    ('\u{1163E}', '\u{1163E}', GraphemeClusterBreak::SpacingMark), ('\u{1163F}', '\u{11640}', GraphemeClusterBreak::Extend),
+    ('\u{116AB}', '\u{116AB}', GraphemeClusterBreak::Extend), ('\u{116AC}', '\u{116AC}', GraphemeClusterBreak::SpacingMark),
+    ('\u{116AD}', '\u{116AD}', GraphemeClusterBreak::Extend), ('\u{116AE}', '\u{116AF}', GraphemeClusterBreak::SpacingMark),
+    ('\u{116B0}', '\u{116B7}', GraphemeClusterBreak::Extend), ('\u{1171D}', '\u{1171D}', GraphemeClusterBreak::Extend),
+    ('\u{1171E}', '\u{1171E}', GraphemeClusterBreak::SpacingMark), ('\u{1171F}', '\u{1171F}', GraphemeClusterBreak::Extend),
+    ('\u{11722}', '\u{11725}', GraphemeClusterBreak::Extend), ('\u{11726}', '\u{11726}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11727}', '\u{1172B}', GraphemeClusterBreak::Extend), ('\u{1182C}', '\u{1182E}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1182F}', '\u{11837}', GraphemeClusterBreak::Extend), ('\u{11838}', '\u{11838}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11839}', '\u{1183A}', GraphemeClusterBreak::Extend), ('\u{11930}', '\u{11930}', GraphemeClusterBreak::Extend),
+    ('\u{11931}', '\u{11935}', GraphemeClusterBreak::SpacingMark), ('\u{11937}', '\u{11938}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1193B}', '\u{1193E}', GraphemeClusterBreak::Extend), ('\u{1193F}', '\u{1193F}', GraphemeClusterBreak::Prepend),
+    ('\u{11940}', '\u{11940}', GraphemeClusterBreak::SpacingMark), ('\u{11941}', '\u{11941}', GraphemeClusterBreak::Prepend),
+    ('\u{11942}', '\u{11942}', GraphemeClusterBreak::SpacingMark), ('\u{11943}', '\u{11943}', GraphemeClusterBreak::Extend),
+    ('\u{119D1}', '\u{119D3}', GraphemeClusterBreak::SpacingMark), ('\u{119D4}', '\u{119D7}', GraphemeClusterBreak::Extend),
+    ('\u{119DA}', '\u{119DB}', GraphemeClusterBreak::Extend), ('\u{119DC}', '\u{119DF}', GraphemeClusterBreak::SpacingMark),
+    ('\u{119E0}', '\u{119E0}', GraphemeClusterBreak::Extend), ('\u{119E4}', '\u{119E4}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11A01}', '\u{11A0A}', GraphemeClusterBreak::Extend), ('\u{11A33}', '\u{11A38}', GraphemeClusterBreak::Extend),
+    ('\u{11A39}', '\u{11A39}', GraphemeClusterBreak::SpacingMark), ('\u{11A3B}', '\u{11A3E}', GraphemeClusterBreak::Extend),
+    ('\u{11A47}', '\u{11A47}', GraphemeClusterBreak::Extend), ('\u{11A51}', '\u{11A56}', GraphemeClusterBreak::Extend),
+    ('\u{11A57}', '\u{11A58}', GraphemeClusterBreak::SpacingMark), ('\u{11A59}', '\u{11A5B}', GraphemeClusterBreak::Extend),
+    ('\u{11A84}', '\u{11A89}', GraphemeClusterBreak::Prepend), ('\u{11A8A}', '\u{11A96}', GraphemeClusterBreak::Extend),
+    ('\u{11A97}', '\u{11A97}', GraphemeClusterBreak::SpacingMark), ('\u{11A98}', '\u{11A99}', GraphemeClusterBreak::Extend),
+    ('\u{11B60}', '\u{11B60}', GraphemeClusterBreak::Extend), ('\u{11B61}', '\u{11B61}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11B62}', '\u{11B64}', GraphemeClusterBreak::Extend), ('\u{11B65}', '\u{11B65}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11B66}', '\u{11B66}', GraphemeClusterBreak::Extend), ('\u{11B67}', '\u{11B67}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11C2F}', '\u{11C2F}', GraphemeClusterBreak::SpacingMark), ('\u{11C30}', '\u{11C36}', GraphemeClusterBreak::Extend),
+    ('\u{11C38}', '\u{11C3D}', GraphemeClusterBreak::Extend), ('\u{11C3E}', '\u{11C3E}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11C3F}', '\u{11C3F}', GraphemeClusterBreak::Extend), ('\u{11C92}', '\u{11CA7}', GraphemeClusterBreak::Extend),
+    ('\u{11CA9}', '\u{11CA9}', GraphemeClusterBreak::SpacingMark), ('\u{11CAA}', '\u{11CB0}', GraphemeClusterBreak::Extend),
+    ('\u{11CB1}', '\u{11CB1}', GraphemeClusterBreak::SpacingMark), ('\u{11CB2}', '\u{11CB3}', GraphemeClusterBreak::Extend),
+    ('\u{11CB4}', '\u{11CB4}', GraphemeClusterBreak::SpacingMark), ('\u{11CB5}', '\u{11CB6}', GraphemeClusterBreak::Extend),
+    ('\u{11D31}', '\u{11D36}', GraphemeClusterBreak::Extend), ('\u{11D3A}', '\u{11D3A}', GraphemeClusterBreak::Extend),
+    ('\u{11D3C}', '\u{11D3D}', GraphemeClusterBreak::Extend), ('\u{11D3F}', '\u{11D45}', GraphemeClusterBreak::Extend),
+    ('\u{11D46}', '\u{11D46}', GraphemeClusterBreak::Prepend), ('\u{11D47}', '\u{11D47}', GraphemeClusterBreak::Extend),
+    ('\u{11D8A}', '\u{11D8E}', GraphemeClusterBreak::SpacingMark), ('\u{11D90}', '\u{11D91}', GraphemeClusterBreak::Extend),
+    ('\u{11D93}', '\u{11D94}', GraphemeClusterBreak::SpacingMark), ('\u{11D95}', '\u{11D95}', GraphemeClusterBreak::Extend),
+    ('\u{11D96}', '\u{11D96}', GraphemeClusterBreak::SpacingMark), ('\u{11D97}', '\u{11D97}', GraphemeClusterBreak::Extend),
+    ('\u{11EF3}', '\u{11EF4}', GraphemeClusterBreak::Extend), ('\u{11EF5}', '\u{11EF6}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11F00}', '\u{11F01}', GraphemeClusterBreak::Extend), ('\u{11F02}', '\u{11F02}', GraphemeClusterBreak::Prepend),
+    ('\u{11F03}', '\u{11F03}', GraphemeClusterBreak::SpacingMark), ('\u{11F34}', '\u{11F35}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11F36}', '\u{11F3A}', GraphemeClusterBreak::Extend), ('\u{11F3E}', '\u{11F3F}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11F40}', '\u{11F42}', GraphemeClusterBreak::Extend), ('\u{11F5A}', '\u{11F5A}', GraphemeClusterBreak::Extend),
+    ('\u{13430}', '\u{1343F}', GraphemeClusterBreak::Control), ('\u{13440}', '\u{13440}', GraphemeClusterBreak::Extend),
+    ('\u{13447}', '\u{13455}', GraphemeClusterBreak::Extend), ('\u{1611E}', '\u{16129}', GraphemeClusterBreak::Extend),
+    ('\u{1612A}', '\u{1612C}', GraphemeClusterBreak::SpacingMark), ('\u{1612D}', '\u{1612F}', GraphemeClusterBreak::Extend),
+    ('\u{16AF0}', '\u{16AF4}', GraphemeClusterBreak::Extend), ('\u{16B30}', '\u{16B36}', GraphemeClusterBreak::Extend),
+    ('\u{16D63}', '\u{16D63}', GraphemeClusterBreak::V), ('\u{16D67}', '\u{16D6A}', GraphemeClusterBreak::V),
+    ('\u{16F4F}', '\u{16F4F}', GraphemeClusterBreak::Extend), ('\u{16F51}', '\u{16F87}', GraphemeClusterBreak::SpacingMark),
+    ('\u{16F8F}', '\u{16F92}', GraphemeClusterBreak::Extend), ('\u{16FE4}', '\u{16FE4}', GraphemeClusterBreak::Extend),
+    ('\u{16FF0}', '\u{16FF1}', GraphemeClusterBreak::Extend), ('\u{1BC9D}', '\u{1BC9E}', GraphemeClusterBreak::Extend),
+    ('\u{1BCA0}', '\u{1BCA3}', GraphemeClusterBreak::Control), ('\u{1CF00}', '\u{1CF2D}', GraphemeClusterBreak::Extend),
+    ('\u{1CF30}', '\u{1CF46}', GraphemeClusterBreak::Extend), ('\u{1D165}', '\u{1D169}', GraphemeClusterBreak::Extend),
+    ('\u{1D16D}', '\u{1D172}', GraphemeClusterBreak::Extend), ('\u{1D173}', '\u{1D17A}', GraphemeClusterBreak::Control),
+    ('\u{1D17B}', '\u{1D182}', GraphemeClusterBreak::Extend), ('\u{1D185}', '\u{1D18B}', GraphemeClusterBreak::Extend),
+    ('\u{1D1AA}', '\u{1D1AD}', GraphemeClusterBreak::Extend), ('\u{1D242}', '\u{1D244}', GraphemeClusterBreak::Extend),
+    ('\u{1DA00}', '\u{1DA36}', GraphemeClusterBreak::Extend), ('\u{1DA3B}', '\u{1DA6C}', GraphemeClusterBreak::Extend),
+    ('\u{1DA75}', '\u{1DA75}', GraphemeClusterBreak::Extend), ('\u{1DA84}', '\u{1DA84}', GraphemeClusterBreak::Extend),
+    ('\u{1DA9B}', '\u{1DA9F}', GraphemeClusterBreak::Extend), ('\u{1DAA1}', '\u{1DAAF}', GraphemeClusterBreak::Extend),
+    ('\u{1E000}', '\u{1E006}', GraphemeClusterBreak::Extend), ('\u{1E008}', '\u{1E018}', GraphemeClusterBreak::Extend),
+    ('\u{1E01B}', '\u{1E021}', GraphemeClusterBreak::Extend), ('\u{1E023}', '\u{1E024}', GraphemeClusterBreak::Extend),
+    ('\u{1E026}', '\u{1E02A}', GraphemeClusterBreak::Extend), ('\u{1E08F}', '\u{1E08F}', GraphemeClusterBreak::Extend),
+    ('\u{1E130}', '\u{1E136}', GraphemeClusterBreak::Extend), ('\u{1E2AE}', '\u{1E2AE}', GraphemeClusterBreak::Extend),
+    ('\u{1E2EC}', '\u{1E2EF}', GraphemeClusterBreak::Extend), ('\u{1E4EC}', '\u{1E4EF}', GraphemeClusterBreak::Extend),
+    ('\u{1E5EE}', '\u{1E5EF}', GraphemeClusterBreak::Extend), ('\u{1E6E3}', '\u{1E6E3}', GraphemeClusterBreak::Extend),
+    ('\u{1E6E6}', '\u{1E6E6}', GraphemeClusterBreak::Extend), ('\u{1E6EE}', '\u{1E6EF}', GraphemeClusterBreak::Extend),
+    ('\u{1E6F5}', '\u{1E6F5}', GraphemeClusterBreak::Extend), ('\u{1E8D0}', '\u{1E8D6}', GraphemeClusterBreak::Extend),
+    ('\u{1E944}', '\u{1E94A}', GraphemeClusterBreak::Extend), ('\u{1F1E6}', '\u{1F1FF}', GraphemeClusterBreak::RegionalIndicator),
+    ('\u{1F3FB}', '\u{1F3FF}', GraphemeClusterBreak::Extend), ('\u{E0000}', '\u{E001F}', GraphemeClusterBreak::Control),
+    ('\u{E0020}', '\u{E007F}', GraphemeClusterBreak::Extend), ('\u{E0080}', '\u{E00FF}', GraphemeClusterBreak::Control),
+    ('\u{E0100}', '\u{E01EF}', GraphemeClusterBreak::Extend), ('\u{E01F0}', '\u{E0FFF}', GraphemeClusterBreak::Control),
+];
+
+#[rustfmt::skip]
+static INDIC_CONJUNCT_BREAK_TABLE: &[(char, char, IndicConjunctBreak)] = &[
+    ('\u{0300}', '\u{036F}', IndicConjunctBreak::Extend), ('\u{0483}', '\u{0489}', IndicConjunctBreak::Extend),
+    ('\u{0591}', '\u{05BD}', IndicConjunctBreak::Extend), ('\u{05BF}', '\u{05BF}', IndicConjunctBreak::Extend),
+    ('\u{05C1}', '\u{05C2}', IndicConjunctBreak::Extend), ('\u{05C4}', '\u{05C5}', IndicConjunctBreak::Extend),
+    ('\u{05C7}', '\u{05C7}', IndicConjunctBreak::Extend), ('\u{0610}', '\u{061A}', IndicConjunctBreak::Extend),
+    ('\u{064B}', '\u{065F}', IndicConjunctBreak::Extend), ('\u{0670}', '\u{0670}', IndicConjunctBreak::Extend),
+    ('\u{06D6}', '\u{06DC}', IndicConjunctBreak::Extend), ('\u{06DF}', '\u{06E4}', IndicConjunctBreak::Extend),
+    ('\u{06E7}', '\u{06E8}', IndicConjunctBreak::Extend), ('\u{06EA}', '\u{06ED}', IndicConjunctBreak::Extend),
+    ('\u{0711}', '\u{0711}', IndicConjunctBreak::Extend), ('\u{0730}', '\u{074A}', IndicConjunctBreak::Extend),
+    ('\u{07A6}', '\u{07B0}', IndicConjunctBreak::Extend), ('\u{07EB}', '\u{07F3}', IndicConjunctBreak::Extend),
+    ('\u{07FD}', '\u{07FD}', IndicConjunctBreak::Extend), ('\u{0816}', '\u{0819}', IndicConjunctBreak::Extend),
+    ('\u{081B}', '\u{0823}', IndicConjunctBreak::Extend), ('\u{0825}', '\u{0827}', IndicConjunctBreak::Extend),
+    ('\u{0829}', '\u{082D}', IndicConjunctBreak::Extend), ('\u{0859}', '\u{085B}', IndicConjunctBreak::Extend),
+    ('\u{0897}', '\u{089F}', IndicConjunctBreak::Extend), ('\u{08CA}', '\u{08E1}', IndicConjunctBreak::Extend),
+    ('\u{08E3}', '\u{0902}', IndicConjunctBreak::Extend), ('\u{0915}', '\u{0939}', IndicConjunctBreak::Consonant),
+    ('\u{093A}', '\u{093A}', IndicConjunctBreak::Extend), ('\u{093C}', '\u{093C}', IndicConjunctBreak::Extend),
+    ('\u{0941}', '\u{0948}', IndicConjunctBreak::Extend), ('\u{094D}', '\u{094D}', IndicConjunctBreak::Linker),
+    ('\u{0951}', '\u{0957}', IndicConjunctBreak::Extend), ('\u{0958}', '\u{095F}', IndicConjunctBreak::Consonant),
+    ('\u{0962}', '\u{0963}', IndicConjunctBreak::Extend), ('\u{0978}', '\u{097F}', IndicConjunctBreak::Consonant),
+    ('\u{0981}', '\u{0981}', IndicConjunctBreak::Extend), ('\u{0995}', '\u{09A8}', IndicConjunctBreak::Consonant),
+    ('\u{09AA}', '\u{09B0}', IndicConjunctBreak::Consonant), ('\u{09B2}', '\u{09B2}', IndicConjunctBreak::Consonant),
+    ('\u{09B6}', '\u{09B9}', IndicConjunctBreak::Consonant), ('\u{09BC}', '\u{09BC}', IndicConjunctBreak::Extend),
+    ('\u{09BE}', '\u{09BE}', IndicConjunctBreak::Extend), ('\u{09C1}', '\u{09C4}', IndicConjunctBreak::Extend),
+    ('\u{09CD}', '\u{09CD}', IndicConjunctBreak::Linker), ('\u{09D7}', '\u{09D7}', IndicConjunctBreak::Extend),
+    ('\u{09DC}', '\u{09DD}', IndicConjunctBreak::Consonant), ('\u{09DF}', '\u{09DF}', IndicConjunctBreak::Consonant),
+    ('\u{09E2}', '\u{09E3}', IndicConjunctBreak::Extend), ('\u{09F0}', '\u{09F1}', IndicConjunctBreak::Consonant),
+    ('\u{09FE}', '\u{09FE}', IndicConjunctBreak::Extend), ('\u{0A01}', '\u{0A02}', IndicConjunctBreak::Extend),
+    ('\u{0A3C}', '\u{0A3C}', IndicConjunctBreak::Extend), ('\u{0A41}', '\u{0A42}', IndicConjunctBreak::Extend),
+    ('\u{0A47}', '\u{0A48}', IndicConjunctBreak::Extend), ('\u{0A4B}', '\u{0A4D}', IndicConjunctBreak::Extend),
+    ('\u{0A51}', '\u{0A51}', IndicConjunctBreak::Extend), ('\u{0A70}', '\u{0A71}', IndicConjunctBreak::Extend),
+    ('\u{0A75}', '\u{0A75}', IndicConjunctBreak::Extend), ('\u{0A81}', '\u{0A82}', IndicConjunctBreak::Extend),
+    ('\u{0A95}', '\u{0AA8}', IndicConjunctBreak::Consonant), ('\u{0AAA}', '\u{0AB0}', IndicConjunctBreak::Consonant),
+    ('\u{0AB2}', '\u{0AB3}', IndicConjunctBreak::Consonant), ('\u{0AB5}', '\u{0AB9}', IndicConjunctBreak::Consonant),
+    ('\u{0ABC}', '\u{0ABC}', IndicConjunctBreak::Extend), ('\u{0AC1}', '\u{0AC5}', IndicConjunctBreak::Extend),
+    ('\u{0AC7}', '\u{0AC8}', IndicConjunctBreak::Extend), ('\u{0ACD}', '\u{0ACD}', IndicConjunctBreak::Linker),
+    ('\u{0AE2}', '\u{0AE3}', IndicConjunctBreak::Extend), ('\u{0AF9}', '\u{0AF9}', IndicConjunctBreak::Consonant),
+    ('\u{0AFA}', '\u{0AFF}', IndicConjunctBreak::Extend), ('\u{0B01}', '\u{0B01}', IndicConjunctBreak::Extend),
+    ('\u{0B15}', '\u{0B28}', IndicConjunctBreak::Consonant), ('\u{0B2A}', '\u{0B30}', IndicConjunctBreak::Consonant),
+    ('\u{0B32}', '\u{0B33}', IndicConjunctBreak::Consonant), ('\u{0B35}', '\u{0B39}', IndicConjunctBreak::Consonant),
+    ('\u{0B3C}', '\u{0B3C}', IndicConjunctBreak::Extend), ('\u{0B3E}', '\u{0B3F}', IndicConjunctBreak::Extend),
+    ('\u{0B41}', '\u{0B44}', IndicConjunctBreak::Extend), ('\u{0B4D}', '\u{0B4D}', IndicConjunctBreak::Linker),
+    ('\u{0B55}', '\u{0B57}', IndicConjunctBreak::Extend), ('\u{0B5C}', '\u{0B5D}', IndicConjunctBreak::Consonant),
+    ('\u{0B5F}', '\u{0B5F}', IndicConjunctBreak::Consonant), ('\u{0B62}', '\u{0B63}', IndicConjunctBreak::Extend),
+    ('\u{0B71}', '\u{0B71}', IndicConjunctBreak::Consonant), ('\u{0B82}', '\u{0B82}', IndicConjunctBreak::Extend),
+    ('\u{0BBE}', '\u{0BBE}', IndicConjunctBreak::Extend), ('\u{0BC0}', '\u{0BC0}', IndicConjunctBreak::Extend),
+    ('\u{0BCD}', '\u{0BCD}', IndicConjunctBreak::Extend), ('\u{0BD7}', '\u{0BD7}', IndicConjunctBreak::Extend),
+    ('\u{0C00}', '\u{0C00}', IndicConjunctBreak::Extend), ('\u{0C04}', '\u{0C04}', IndicConjunctBreak::Extend),
+    ('\u{0C15}', '\u{0C28}', IndicConjunctBreak::Consonant), ('\u{0C2A}', '\u{0C39}', IndicConjunctBreak::Consonant),
+    ('\u{0C3C}', '\u{0C3C}', IndicConjunctBreak::Extend), ('\u{0C3E}', '\u{0C40}', IndicConjunctBreak::Extend),
+    ('\u{0C46}', '\u{0C48}', IndicConjunctBreak::Extend), ('\u{0C4A}', '\u{0C4C}', IndicConjunctBreak::Extend),
+    ('\u{0C4D}', '\u{0C4D}', IndicConjunctBreak::Linker), ('\u{0C55}', '\u{0C56}', IndicConjunctBreak::Extend),
+    ('\u{0C58}', '\u{0C5A}', IndicConjunctBreak::Consonant), ('\u{0C62}', '\u{0C63}', IndicConjunctBreak::Extend),
+    ('\u{0C81}', '\u{0C81}', IndicConjunctBreak::Extend), ('\u{0CBC}', '\u{0CBC}', IndicConjunctBreak::Extend),
+    ('\u{0CBF}', '\u{0CC0}', IndicConjunctBreak::Extend), ('\u{0CC2}', '\u{0CC2}', IndicConjunctBreak::Extend),
+    ('\u{0CC6}', '\u{0CC8}', IndicConjunctBreak::Extend), ('\u{0CCA}', '\u{0CCD}', IndicConjunctBreak::Extend),
+    ('\u{0CD5}', '\u{0CD6}', IndicConjunctBreak::Extend), ('\u{0CE2}', '\u{0CE3}', IndicConjunctBreak::Extend),
+    ('\u{0D00}', '\u{0D01}', IndicConjunctBreak::Extend), ('\u{0D15}', '\u{0D3A}', IndicConjunctBreak::Consonant),
+    ('\u{0D3B}', '\u{0D3C}', IndicConjunctBreak::Extend), ('\u{0D3E}', '\u{0D3E}', IndicConjunctBreak::Extend),
+    ('\u{0D41}', '\u{0D44}', IndicConjunctBreak::Extend), ('\u{0D4D}', '\u{0D4D}', IndicConjunctBreak::Linker),
+    ('\u{0D57}', '\u{0D57}', IndicConjunctBreak::Extend), ('\u{0D62}', '\u{0D63}', IndicConjunctBreak::Extend),
+    ('\u{0D81}', '\u{0D81}', IndicConjunctBreak::Extend), ('\u{0DCA}', '\u{0DCA}', IndicConjunctBreak::Extend),
+    ('\u{0DCF}', '\u{0DCF}', IndicConjunctBreak::Extend), ('\u{0DD2}', '\u{0DD4}', IndicConjunctBreak::Extend),
+    ('\u{0DD6}', '\u{0DD6}', IndicConjunctBreak::Extend), ('\u{0DDF}', '\u{0DDF}', IndicConjunctBreak::Extend),
+    ('\u{0E31}', '\u{0E31}', IndicConjunctBreak::Extend), ('\u{0E34}', '\u{0E3A}', IndicConjunctBreak::Extend),
+    ('\u{0E47}', '\u{0E4E}', IndicConjunctBreak::Extend), ('\u{0EB1}', '\u{0EB1}', IndicConjunctBreak::Extend),
+    ('\u{0EB4}', '\u{0EBC}', IndicConjunctBreak::Extend), ('\u{0EC8}', '\u{0ECE}', IndicConjunctBreak::Extend),
+    ('\u{0F18}', '\u{0F19}', IndicConjunctBreak::Extend), ('\u{0F35}', '\u{0F35}', IndicConjunctBreak::Extend),
+    ('\u{0F37}', '\u{0F37}', IndicConjunctBreak::Extend), ('\u{0F39}', '\u{0F39}', IndicConjunctBreak::Extend),
+    ('\u{0F71}', '\u{0F7E}', IndicConjunctBreak::Extend), ('\u{0F80}', '\u{0F84}', IndicConjunctBreak::Extend),
+    ('\u{0F86}', '\u{0F87}', IndicConjunctBreak::Extend), ('\u{0F8D}', '\u{0F97}', IndicConjunctBreak::Extend),
+    ('\u{0F99}', '\u{0FBC}', IndicConjunctBreak::Extend), ('\u{0FC6}', '\u{0FC6}', IndicConjunctBreak::Extend),
+    ('\u{1000}', '\u{102A}', IndicConjunctBreak::Consonant), ('\u{102D}', '\u{1030}', IndicConjunctBreak::Extend),
+    ('\u{1032}', '\u{1037}', IndicConjunctBreak::Extend), ('\u{1039}', '\u{1039}', IndicConjunctBreak::Linker),
+    ('\u{103A}', '\u{103A}', IndicConjunctBreak::Extend), ('\u{103D}', '\u{103E}', IndicConjunctBreak::Extend),
+    ('\u{103F}', '\u{103F}', IndicConjunctBreak::Consonant), ('\u{1050}', '\u{1055}', IndicConjunctBreak::Consonant),
+    ('\u{1058}', '\u{1059}', IndicConjunctBreak::Extend), ('\u{105A}', '\u{105D}', IndicConjunctBreak::Consonant),
+    ('\u{105E}', '\u{1060}', IndicConjunctBreak::Extend), ('\u{1061}', '\u{1061}', IndicConjunctBreak::Consonant),
+    ('\u{1065}', '\u{1066}', IndicConjunctBreak::Consonant), ('\u{106E}', '\u{1070}', IndicConjunctBreak::Consonant),
+    ('\u{1071}', '\u{1074}', IndicConjunctBreak::Extend), ('\u{1075}', '\u{1081}', IndicConjunctBreak::Consonant),
+    ('\u{1082}', '\u{1082}', IndicConjunctBreak::Extend), ('\u{1085}', '\u{1086}', IndicConjunctBreak::Extend),
+    ('\u{108D}', '\u{108D}', IndicConjunctBreak::Extend), ('\u{108E}', '\u{108E}', IndicConjunctBreak::Consonant),
+    ('\u{109D}', '\u{109D}', IndicConjunctBreak::Extend), ('\u{135D}', '\u{135F}', IndicConjunctBreak::Extend),
+    ('\u{1712}', '\u{1715}', IndicConjunctBreak::Extend), ('\u{1732}', '\u{1734}', IndicConjunctBreak::Extend),
+    ('\u{1752}', '\u{1753}', IndicConjunctBreak::Extend), ('\u{1772}', '\u{1773}', IndicConjunctBreak::Extend),
+    ('\u{1780}', '\u{17B3}', IndicConjunctBreak::Consonant), ('\u{17B4}', '\u{17B5}', IndicConjunctBreak::Extend),
+    ('\u{17B7}', '\u{17BD}', IndicConjunctBreak::Extend), ('\u{17C6}', '\u{17C6}', IndicConjunctBreak::Extend),
+    ('\u{17C9}', '\u{17D1}', IndicConjunctBreak::Extend), ('\u{17D2}', '\u{17D2}', IndicConjunctBreak::Linker),
+    ('\u{17D3}', '\u{17D3}', IndicConjunctBreak::Extend), ('\u{17DD}', '\u{17DD}', IndicConjunctBreak::Extend),
+    ('\u{180B}', '\u{180D}', IndicConjunctBreak::Extend), ('\u{180F}', '\u{180F}', IndicConjunctBreak::Extend),
+    ('\u{1885}', '\u{1886}', IndicConjunctBreak::Extend), ('\u{18A9}', '\u{18A9}', IndicConjunctBreak::Extend),
+    ('\u{1920}', '\u{1922}', IndicConjunctBreak::Extend), ('\u{1927}', '\u{1928}', IndicConjunctBreak::Extend),
+    ('\u{1932}', '\u{1932}', IndicConjunctBreak::Extend), ('\u{1939}', '\u{193B}', IndicConjunctBreak::Extend),
+    ('\u{1A17}', '\u{1A18}', IndicConjunctBreak::Extend), ('\u{1A1B}', '\u{1A1B}', IndicConjunctBreak::Extend),
+    ('\u{1A20}', '\u{1A54}', IndicConjunctBreak::Consonant), ('\u{1A56}', '\u{1A56}', IndicConjunctBreak::Extend),
+    ('\u{1A58}', '\u{1A5E}', IndicConjunctBreak::Extend), ('\u{1A60}', '\u{1A60}', IndicConjunctBreak::Linker),
+    ('\u{1A62}', '\u{1A62}', IndicConjunctBreak::Extend), ('\u{1A65}', '\u{1A6C}', IndicConjunctBreak::Extend),
+    ('\u{1A73}', '\u{1A7C}', IndicConjunctBreak::Extend), ('\u{1A7F}', '\u{1A7F}', IndicConjunctBreak::Extend),
+    ('\u{1AB0}', '\u{1ADD}', IndicConjunctBreak::Extend), ('\u{1AE0}', '\u{1AEB}', IndicConjunctBreak::Extend),
+    ('\u{1B00}', '\u{1B03}', IndicConjunctBreak::Extend), ('\u{1B0B}', '\u{1B0C}', IndicConjunctBreak::Consonant),
+    ('\u{1B13}', '\u{1B33}', IndicConjunctBreak::Consonant), ('\u{1B34}', '\u{1B3D}', IndicConjunctBreak::Extend),
+    ('\u{1B42}', '\u{1B43}', IndicConjunctBreak::Extend), ('\u{1B44}', '\u{1B44}', IndicConjunctBreak::Linker),
+    ('\u{1B45}', '\u{1B4C}', IndicConjunctBreak::Consonant), ('\u{1B6B}', '\u{1B73}', IndicConjunctBreak::Extend),
+    ('\u{1B80}', '\u{1B81}', IndicConjunctBreak::Extend), ('\u{1B83}', '\u{1BA0}', IndicConjunctBreak::Consonant),
+    ('\u{1BA2}', '\u{1BA5}', IndicConjunctBreak::Extend), ('\u{1BA8}', '\u{1BAA}', IndicConjunctBreak::Extend),
+    ('\u{1BAB}', '\u{1BAB}', IndicConjunctBreak::Linker), ('\u{1BAC}', '\u{1BAD}', IndicConjunctBreak::Extend),
+    ('\u{1BAE}', '\u{1BAF}', IndicConjunctBreak::Consonant), ('\u{1BBB}', '\u{1BBD}', IndicConjunctBreak::Consonant),
+    ('\u{1BE6}', '\u{1BE6}', IndicConjunctBreak::Extend), ('\u{1BE8}', '\u{1BE9}', IndicConjunctBreak::Extend),
+    ('\u{1BED}', '\u{1BED}', IndicConjunctBreak::Extend), ('\u{1BEF}', '\u{1BF3}', IndicConjunctBreak::Extend),
+    ('\u{1C2C}', '\u{1C33}', IndicConjunctBreak::Extend), ('\u{1C36}', '\u{1C37}', IndicConjunctBreak::Extend),
+    ('\u{1CD0}', '\u{1CD2}', IndicConjunctBreak::Extend), ('\u{1CD4}', '\u{1CE0}', IndicConjunctBreak::Extend),
+    ('\u{1CE2}', '\u{1CE8}', IndicConjunctBreak::Extend), ('\u{1CED}', '\u{1CED}', IndicConjunctBreak::Extend),
+    ('\u{1CF4}', '\u{1CF4}', IndicConjunctBreak::Extend), ('\u{1CF8}', '\u{1CF9}', IndicConjunctBreak::Extend),
+    ('\u{1DC0}', '\u{1DFF}', IndicConjunctBreak::Extend), ('\u{200D}', '\u{200D}', IndicConjunctBreak::Extend),
+    ('\u{20D0}', '\u{20F0}', IndicConjunctBreak::Extend), ('\u{2CEF}', '\u{2CF1}', IndicConjunctBreak::Extend),
+    ('\u{2D7F}', '\u{2D7F}', IndicConjunctBreak::Extend), ('\u{2DE0}', '\u{2DFF}', IndicConjunctBreak::Extend),
+    ('\u{302A}', '\u{302F}', IndicConjunctBreak::Extend), ('\u{3099}', '\u{309A}', IndicConjunctBreak::Extend),
+    ('\u{A66F}', '\u{A672}', IndicConjunctBreak::Extend), ('\u{A674}', '\u{A67D}', IndicConjunctBreak::Extend),
+    ('\u{A69E}', '\u{A69F}', IndicConjunctBreak::Extend), ('\u{A6F0}', '\u{A6F1}', IndicConjunctBreak::Extend),
+    ('\u{A802}', '\u{A802}', IndicConjunctBreak::Extend), ('\u{A806}', '\u{A806}', IndicConjunctBreak::Extend),
+    ('\u{A80B}', '\u{A80B}', IndicConjunctBreak::Extend), ('\u{A825}', '\u{A826}', IndicConjunctBreak::Extend),
+    ('\u{A82C}', '\u{A82C}', IndicConjunctBreak::Extend), ('\u{A8C4}', '\u{A8C5}', IndicConjunctBreak::Extend),
+    ('\u{A8E0}', '\u{A8F1}', IndicConjunctBreak::Extend), ('\u{A8FF}', '\u{A8FF}', IndicConjunctBreak::Extend),
+    ('\u{A926}', '\u{A92D}', IndicConjunctBreak::Extend), ('\u{A947}', '\u{A951}', IndicConjunctBreak::Extend),
+    ('\u{A953}', '\u{A953}', IndicConjunctBreak::Extend), ('\u{A980}', '\u{A982}', IndicConjunctBreak::Extend),
+    ('\u{A989}', '\u{A98B}', IndicConjunctBreak::Consonant), ('\u{A98F}', '\u{A9B2}', IndicConjunctBreak::Consonant),
+    ('\u{A9B3}', '\u{A9B3}', IndicConjunctBreak::Extend), ('\u{A9B6}', '\u{A9B9}', IndicConjunctBreak::Extend),
+    ('\u{A9BC}', '\u{A9BD}', IndicConjunctBreak::Extend), ('\u{A9C0}', '\u{A9C0}', IndicConjunctBreak::Linker),
+    ('\u{A9E0}', '\u{A9E4}', IndicConjunctBreak::Consonant), ('\u{A9E5}', '\u{A9E5}', IndicConjunctBreak::Extend),
+    ('\u{A9E7}', '\u{A9EF}', IndicConjunctBreak::Consonant), ('\u{A9FA}', '\u{A9FE}', IndicConjunctBreak::Consonant),
+    ('\u{AA29}', '\u{AA2E}', IndicConjunctBreak::Extend), ('\u{AA31}', '\u{AA32}', IndicConjunctBreak::Extend),
+    ('\u{AA35}', '\u{AA36}', IndicConjunctBreak::Extend), ('\u{AA43}', '\u{AA43}', IndicConjunctBreak::Extend),
+    ('\u{AA4C}', '\u{AA4C}', IndicConjunctBreak::Extend), ('\u{AA60}', '\u{AA6F}', IndicConjunctBreak::Consonant),
+    ('\u{AA71}', '\u{AA73}', IndicConjunctBreak::Consonant), ('\u{AA7A}', '\u{AA7A}', IndicConjunctBreak::Consonant),
+    ('\u{AA7C}', '\u{AA7C}', IndicConjunctBreak::Extend), ('\u{AA7E}', '\u{AA7F}', IndicConjunctBreak::Consonant),
+    ('\u{AAB0}', '\u{AAB0}', IndicConjunctBreak::Extend), ('\u{AAB2}', '\u{AAB4}', IndicConjunctBreak::Extend),
+    ('\u{AAB7}', '\u{AAB8}', IndicConjunctBreak::Extend), ('\u{AABE}', '\u{AABF}', IndicConjunctBreak::Extend),
+    ('\u{AAC1}', '\u{AAC1}', IndicConjunctBreak::Extend), ('\u{AAE0}', '\u{AAEA}', IndicConjunctBreak::Consonant),
+    ('\u{AAEC}', '\u{AAED}', IndicConjunctBreak::Extend), ('\u{AAF6}', '\u{AAF6}', IndicConjunctBreak::Linker),
+    ('\u{ABC0}', '\u{ABDA}', IndicConjunctBreak::Consonant), ('\u{ABE5}', '\u{ABE5}', IndicConjunctBreak::Extend),
+    ('\u{ABE8}', '\u{ABE8}', IndicConjunctBreak::Extend), ('\u{ABED}', '\u{ABED}', IndicConjunctBreak::Extend),
+    ('\u{FB1E}', '\u{FB1E}', IndicConjunctBreak::Extend), ('\u{FE00}', '\u{FE0F}', IndicConjunctBreak::Extend),
+    ('\u{FE20}', '\u{FE2F}', IndicConjunctBreak::Extend), ('\u{FF9E}', '\u{FF9F}', IndicConjunctBreak::Extend),
+    ('\u{101FD}', '\u{101FD}', IndicConjunctBreak::Extend), ('\u{102E0}', '\u{102E0}', IndicConjunctBreak::Extend),
+    ('\u{10376}', '\u{1037A}', IndicConjunctBreak::Extend), ('\u{10A00}', '\u{10A00}', IndicConjunctBreak::Consonant),
+    ('\u{10A01}', '\u{10A03}', IndicConjunctBreak::Extend), ('\u{10A05}', '\u{10A06}', IndicConjunctBreak::Extend),
+    ('\u{10A0C}', '\u{10A0F}', IndicConjunctBreak::Extend), ('\u{10A10}', '\u{10A13}', IndicConjunctBreak::Consonant),
+    ('\u{10A15}', '\u{10A17}', IndicConjunctBreak::Consonant), ('\u{10A19}', '\u{10A35}', IndicConjunctBreak::Consonant),
+    ('\u{10A38}', '\u{10A3A}', IndicConjunctBreak::Extend), ('\u{10A3F}', '\u{10A3F}', IndicConjunctBreak::Linker),
+    ('\u{10AE5}', '\u{10AE6}', IndicConjunctBreak::Extend), ('\u{10D24}', '\u{10D27}', IndicConjunctBreak::Extend),
+    ('\u{10D69}', '\u{10D6D}', IndicConjunctBreak::Extend), ('\u{10EAB}', '\u{10EAC}', IndicConjunctBreak::Extend),
+    ('\u{10EFA}', '\u{10EFF}', IndicConjunctBreak::Extend), ('\u{10F46}', '\u{10F50}', IndicConjunctBreak::Extend),
+    ('\u{10F82}', '\u{10F85}', IndicConjunctBreak::Extend), ('\u{11001}', '\u{11001}', IndicConjunctBreak::Extend),
+    ('\u{11038}', '\u{11046}', IndicConjunctBreak::Extend), ('\u{11070}', '\u{11070}', IndicConjunctBreak::Extend),
+    ('\u{11073}', '\u{11074}', IndicConjunctBreak::Extend), ('\u{1107F}', '\u{11081}', IndicConjunctBreak::Extend),
+    ('\u{110B3}', '\u{110B6}', IndicConjunctBreak::Extend), ('\u{110B9}', '\u{110BA}', IndicConjunctBreak::Extend),
+    ('\u{110C2}', '\u{110C2}', IndicConjunctBreak::Extend), ('\u{11100}', '\u{11102}', IndicConjunctBreak::Extend),
+    ('\u{11103}', '\u{11126}', IndicConjunctBreak::Consonant), ('\u{11127}', '\u{1112B}', IndicConjunctBreak::Extend),
+    ('\u{1112D}', '\u{11132}', IndicConjunctBreak::Extend), ('\u{11133}', '\u{11133}', IndicConjunctBreak::Linker),
+    ('\u{11134}', '\u{11134}', IndicConjunctBreak::Extend), ('\u{11144}', '\u{11144}', IndicConjunctBreak::Consonant),
+    ('\u{11147}', '\u{11147}', IndicConjunctBreak::Consonant), ('\u{11173}', '\u{11173}', IndicConjunctBreak::Extend),
+    ('\u{11180}', '\u{11181}', IndicConjunctBreak::Extend), ('\u{111B6}', '\u{111BE}', IndicConjunctBreak::Extend),
+    ('\u{111C0}', '\u{111C0}', IndicConjunctBreak::Extend), ('\u{111C9}', '\u{111CC}', IndicConjunctBreak::Extend),
+    ('\u{111CF}', '\u{111CF}', IndicConjunctBreak::Extend), ('\u{1122F}', '\u{11231}', IndicConjunctBreak::Extend),
+    ('\u{11234}', '\u{11237}', IndicConjunctBreak::Extend), ('\u{1123E}', '\u{1123E}', IndicConjunctBreak::Extend),
+    ('\u{11241}', '\u{11241}', IndicConjunctBreak::Extend), ('\u{112DF}', '\u{112DF}', IndicConjunctBreak::Extend),
+    ('\u{112E3}', '\u{112EA}', IndicConjunctBreak::Extend), ('\u{11300}', '\u{11301}', IndicConjunctBreak::Extend),
+    ('\u{1133B}', '\u{1133C}', IndicConjunctBreak::Extend), ('\u{1133E}', '\u{1133E}', IndicConjunctBreak::Extend),
+    ('\u{11340}', '\u{11340}', IndicConjunctBreak::Extend), ('\u{1134D}', '\u{1134D}', IndicConjunctBreak::Extend),
+    ('\u{11357}', '\u{11357}', IndicConjunctBreak::Extend), ('\u{11366}', '\u{1136C}', IndicConjunctBreak::Extend),
+    ('\u{11370}', '\u{11374}', IndicConjunctBreak::Extend), ('\u{11380}', '\u{11389}', IndicConjunctBreak::Consonant),
+    ('\u{1138B}', '\u{1138B}', IndicConjunctBreak::Consonant), ('\u{1138E}', '\u{1138E}', IndicConjunctBreak::Consonant),
+    ('\u{11390}', '\u{113B5}', IndicConjunctBreak::Consonant), ('\u{113B8}', '\u{113B8}', IndicConjunctBreak::Extend),
+    ('\u{113BB}', '\u{113C0}', IndicConjunctBreak::Extend), ('\u{113C2}', '\u{113C2}', IndicConjunctBreak::Extend),
+    ('\u{113C5}', '\u{113C5}', IndicConjunctBreak::Extend), ('\u{113C7}', '\u{113C9}', IndicConjunctBreak::Extend),
+    ('\u{113CE}', '\u{113CF}', IndicConjunctBreak::Extend), ('\u{113D0}', '\u{113D0}', IndicConjunctBreak::Linker),
+    ('\u{113D2}', '\u{113D2}', IndicConjunctBreak::Extend), ('\u{113E1}', '\u{113E2}', IndicConjunctBreak::Extend),
+    ('\u{11438}', '\u{1143F}', IndicConjunctBreak::Extend), ('\u{11442}', '\u{11444}', IndicConjunctBreak::Extend),
+    ('\u{11446}', '\u{11446}', IndicConjunctBreak::Extend), ('\u{1145E}', '\u{1145E}', IndicConjunctBreak::Extend),
+    ('\u{114B0}', '\u{114B0}', IndicConjunctBreak::Extend), ('\u{114B3}', '\u{114B8}', IndicConjunctBreak::Extend),
+    ('\u{114BA}', '\u{114BA}', IndicConjunctBreak::Extend), ('\u{114BD}', '\u{114BD}', IndicConjunctBreak::Extend),
+    ('\u{114BF}', '\u{114C0}', IndicConjunctBreak::Extend), ('\u{114C2}', '\u{114C3}', IndicConjunctBreak::Extend),
+    ('\u{115AF}', '\u{115AF}', IndicConjunctBreak::Extend), ('\u{115B2}', '\u{115B5}', IndicConjunctBreak::Extend),
+    ('\u{115BC}', '\u{115BD}', IndicConjunctBreak::Extend), ('\u{115BF}', '\u{115C0}', IndicConjunctBreak::Extend),
+    ('\u{115DC}', '\u{115DD}', IndicConjunctBreak::Extend), ('\u{11633}', '\u{1163A}', IndicConjunctBreak::Extend),
+    ('\u{1163D}', '\u{1163D}', IndicConjunctBreak::Extend), ('\u{1163F}', '\u{11640}', IndicConjunctBreak::Extend),
+    ('\u{116AB}', '\u{116AB}', IndicConjunctBreak::Extend), ('\u{116AD}', '\u{116AD}', IndicConjunctBreak::Extend),
+    ('\u{116B0}', '\u{116B7}', IndicConjunctBreak::Extend), ('\u{1171D}', '\u{1171D}', IndicConjunctBreak::Extend),
+    ('\u{1171F}', '\u{1171F}', IndicConjunctBreak::Extend), ('\u{11722}', '\u{11725}', IndicConjunctBreak::Extend),
+    ('\u{11727}', '\u{1172B}', IndicConjunctBreak::Extend), ('\u{1182F}', '\u{11837}', IndicConjunctBreak::Extend),
+    ('\u{11839}', '\u{1183A}', IndicConjunctBreak::Extend), ('\u{11900}', '\u{11906}', IndicConjunctBreak::Consonant),
+    ('\u{11909}', '\u{11909}', IndicConjunctBreak::Consonant), ('\u{1190C}', '\u{11913}', IndicConjunctBreak::Consonant),
+    ('\u{11915}', '\u{11916}', IndicConjunctBreak::Consonant), ('\u{11918}', '\u{1192F}', IndicConjunctBreak::Consonant),
+    ('\u{11930}', '\u{11930}', IndicConjunctBreak::Extend), ('\u{1193B}', '\u{1193D}', IndicConjunctBreak::Extend),
+    ('\u{1193E}', '\u{1193E}', IndicConjunctBreak::Linker), ('\u{11943}', '\u{11943}', IndicConjunctBreak::Extend),
+    ('\u{119D4}', '\u{119D7}', IndicConjunctBreak::Extend), ('\u{119DA}', '\u{119DB}', IndicConjunctBreak::Extend),
+    ('\u{119E0}', '\u{119E0}', IndicConjunctBreak::Extend), ('\u{11A00}', '\u{11A00}', IndicConjunctBreak::Consonant),
+    ('\u{11A01}', '\u{11A0A}', IndicConjunctBreak::Extend), ('\u{11A0B}', '\u{11A32}', IndicConjunctBreak::Consonant),
+    ('\u{11A33}', '\u{11A38}', IndicConjunctBreak::Extend), ('\u{11A3B}', '\u{11A3E}', IndicConjunctBreak::Extend),
+    ('\u{11A47}', '\u{11A47}', IndicConjunctBreak::Linker), ('\u{11A50}', '\u{11A50}', IndicConjunctBreak::Consonant),
+    ('\u{11A51}', '\u{11A56}', IndicConjunctBreak::Extend), ('\u{11A59}', '\u{11A5B}', IndicConjunctBreak::Extend),
+    ('\u{11A5C}', '\u{11A83}', IndicConjunctBreak::Consonant), ('\u{11A8A}', '\u{11A96}', IndicConjunctBreak::Extend),
+    ('\u{11A98}', '\u{11A98}', IndicConjunctBreak::Extend), ('\u{11A99}', '\u{11A99}', IndicConjunctBreak::Linker),
+    ('\u{11B60}', '\u{11B60}', IndicConjunctBreak::Extend), ('\u{11B62}', '\u{11B64}', IndicConjunctBreak::Extend),
+    ('\u{11B66}', '\u{11B66}', IndicConjunctBreak::Extend), ('\u{11C30}', '\u{11C36}', IndicConjunctBreak::Extend),
+    ('\u{11C38}', '\u{11C3D}', IndicConjunctBreak::Extend), ('\u{11C3F}', '\u{11C3F}', IndicConjunctBreak::Extend),
+    ('\u{11C92}', '\u{11CA7}', IndicConjunctBreak::Extend), ('\u{11CAA}', '\u{11CB0}', IndicConjunctBreak::Extend),
+    ('\u{11CB2}', '\u{11CB3}', IndicConjunctBreak::Extend), ('\u{11CB5}', '\u{11CB6}', IndicConjunctBreak::Extend),
+    ('\u{11D31}', '\u{11D36}', IndicConjunctBreak::Extend), ('\u{11D3A}', '\u{11D3A}', IndicConjunctBreak::Extend),
+    ('\u{11D3C}', '\u{11D3D}', IndicConjunctBreak::Extend), ('\u{11D3F}', '\u{11D45}', IndicConjunctBreak::Extend),
+    ('\u{11D47}', '\u{11D47}', IndicConjunctBreak::Extend), ('\u{11D90}', '\u{11D91}', IndicConjunctBreak::Extend),
+    ('\u{11D95}', '\u{11D95}', IndicConjunctBreak::Extend), ('\u{11D97}', '\u{11D97}', IndicConjunctBreak::Extend),
+    ('\u{11EF3}', '\u{11EF4}', IndicConjunctBreak::Extend), ('\u{11F00}', '\u{11F01}', IndicConjunctBreak::Extend),
+    ('\u{11F04}', '\u{11F10}', IndicConjunctBreak::Consonant), ('\u{11F12}', '\u{11F33}', IndicConjunctBreak::Consonant),
+    ('\u{11F36}', '\u{11F3A}', IndicConjunctBreak::Extend), ('\u{11F40}', '\u{11F41}', IndicConjunctBreak::Extend),
+    ('\u{11F42}', '\u{11F42}', IndicConjunctBreak::Linker), ('\u{11F5A}', '\u{11F5A}', IndicConjunctBreak::Extend),
+    ('\u{13440}', '\u{13440}', IndicConjunctBreak::Extend), ('\u{13447}', '\u{13455}', IndicConjunctBreak::Extend),
+    ('\u{1611E}', '\u{16129}', IndicConjunctBreak::Extend), ('\u{1612D}', '\u{1612F}', IndicConjunctBreak::Extend),
+    ('\u{16AF0}', '\u{16AF4}', IndicConjunctBreak::Extend), ('\u{16B30}', '\u{16B36}', IndicConjunctBreak::Extend),
+    ('\u{16F4F}', '\u{16F4F}', IndicConjunctBreak::Extend), ('\u{16F8F}', '\u{16F92}', IndicConjunctBreak::Extend),
+    ('\u{16FE4}', '\u{16FE4}', IndicConjunctBreak::Extend), ('\u{16FF0}', '\u{16FF1}', IndicConjunctBreak::Extend),
+    ('\u{1BC9D}', '\u{1BC9E}', IndicConjunctBreak::Extend), ('\u{1CF00}', '\u{1CF2D}', IndicConjunctBreak::Extend),
+    ('\u{1CF30}', '\u{1CF46}', IndicConjunctBreak::Extend), ('\u{1D165}', '\u{1D169}', IndicConjunctBreak::Extend),
+    ('\u{1D16D}', '\u{1D172}', IndicConjunctBreak::Extend), ('\u{1D17B}', '\u{1D182}', IndicConjunctBreak::Extend),
+    ('\u{1D185}', '\u{1D18B}', IndicConjunctBreak::Extend), ('\u{1D1AA}', '\u{1D1AD}', IndicConjunctBreak::Extend),
+    ('\u{1D242}', '\u{1D244}', IndicConjunctBreak::Extend), ('\u{1DA00}', '\u{1DA36}', IndicConjunctBreak::Extend),
+    ('\u{1DA3B}', '\u{1DA6C}', IndicConjunctBreak::Extend), ('\u{1DA75}', '\u{1DA75}', IndicConjunctBreak::Extend),
+    ('\u{1DA84}', '\u{1DA84}', IndicConjunctBreak::Extend), ('\u{1DA9B}', '\u{1DA9F}', IndicConjunctBreak::Extend),
+    ('\u{1DAA1}', '\u{1DAAF}', IndicConjunctBreak::Extend), ('\u{1E000}', '\u{1E006}', IndicConjunctBreak::Extend),
+    ('\u{1E008}', '\u{1E018}', IndicConjunctBreak::Extend), ('\u{1E01B}', '\u{1E021}', IndicConjunctBreak::Extend),
+    ('\u{1E023}', '\u{1E024}', IndicConjunctBreak::Extend), ('\u{1E026}', '\u{1E02A}', IndicConjunctBreak::Extend),
+    ('\u{1E08F}', '\u{1E08F}', IndicConjunctBreak::Extend), ('\u{1E130}', '\u{1E136}', IndicConjunctBreak::Extend),
+    ('\u{1E2AE}', '\u{1E2AE}', IndicConjunctBreak::Extend), ('\u{1E2EC}', '\u{1E2EF}', IndicConjunctBreak::Extend),
+    ('\u{1E4EC}', '\u{1E4EF}', IndicConjunctBreak::Extend), ('\u{1E5EE}', '\u{1E5EF}', IndicConjunctBreak::Extend),
+    ('\u{1E6E3}', '\u{1E6E3}', IndicConjunctBreak::Extend), ('\u{1E6E6}', '\u{1E6E6}', IndicConjunctBreak::Extend),
+    ('\u{1E6EE}', '\u{1E6EF}', IndicConjunctBreak::Extend), ('\u{1E6F5}', '\u{1E6F5}', IndicConjunctBreak::Extend),
+    ('\u{1E8D0}', '\u{1E8D6}', IndicConjunctBreak::Extend), ('\u{1E944}', '\u{1E94A}', IndicConjunctBreak::Extend),
+    ('\u{1F3FB}', '\u{1F3FF}', IndicConjunctBreak::Extend), ('\u{E0020}', '\u{E007F}', IndicConjunctBreak::Extend),
+    ('\u{E0100}', '\u{E01EF}', IndicConjunctBreak::Extend),
+];
+
+#[rustfmt::skip]
+static SCRIPT_TABLE: &[(char, char, Script)] = &[
+    ('\u{0000}', '\u{0040}', Script::Common), ('\u{0041}', '\u{005A}', Script::Latin),
+    ('\u{005B}', '\u{0060}', Script::Common), ('\u{0061}', '\u{007A}', Script::Latin),
+    ('\u{007B}', '\u{00A9}', Script::Common), ('\u{00AA}', '\u{00AA}', Script::Latin),
+    ('\u{00AB}', '\u{00B9}', Script::Common), ('\u{00BA}', '\u{00BA}', Script::Latin),
+    ('\u{00BB}', '\u{00BF}', Script::Common), ('\u{00C0}', '\u{00D6}', Script::Latin),
+    ('\u{00D7}', '\u{00D7}', Script::Common), ('\u{00D8}', '\u{00F6}', Script::Latin),
+    ('\u{00F7}', '\u{00F7}', Script::Common), ('\u{00F8}', '\u{02B8}', Script::Latin),
+    ('\u{02B9}', '\u{02DF}', Script::Common), ('\u{02E0}', '\u{02E4}', Script::Latin),
+    ('\u{02E5}', '\u{02E9}', Script::Common), ('\u{02EA}', '\u{02EB}', Script::Bopomofo),
+    ('\u{02EC}', '\u{02FF}', Script::Common), ('\u{0300}', '\u{036F}', Script::Inherited),
+    ('\u{0370}', '\u{0373}', Script::Greek), ('\u{0374}', '\u{0374}', Script::Common),
+    ('\u{0375}', '\u{0377}', Script::Greek), ('\u{037A}', '\u{037D}', Script::Greek),
+    ('\u{037E}', '\u{037E}', Script::Common), ('\u{037F}', '\u{037F}', Script::Greek),
+    ('\u{0384}', '\u{0384}', Script::Greek), ('\u{0385}', '\u{0385}', Script::Common),
+    ('\u{0386}', '\u{0386}', Script::Greek), ('\u{0387}', '\u{0387}', Script::Common),
+    ('\u{0388}', '\u{038A}', Script::Greek), ('\u{038C}', '\u{038C}', Script::Greek),
+    ('\u{038E}', '\u{03A1}', Script::Greek), ('\u{03A3}', '\u{03E1}', Script::Greek),
+    ('\u{03E2}', '\u{03EF}', Script::Coptic), ('\u{03F0}', '\u{03FF}', Script::Greek),
+    ('\u{0400}', '\u{0484}', Script::Cyrillic), ('\u{0485}', '\u{0486}', Script::Inherited),
+    ('\u{0487}', '\u{052F}', Script::Cyrillic), ('\u{0531}', '\u{0556}', Script::Armenian),
+    ('\u{0559}', '\u{058A}', Script::Armenian), ('\u{058D}', '\u{058F}', Script::Armenian),
+    ('\u{0591}', '\u{05C7}', Script::Hebrew), ('\u{05D0}', '\u{05EA}', Script::Hebrew),
+    ('\u{05EF}', '\u{05F4}', Script::Hebrew), ('\u{0600}', '\u{0604}', Script::Arabic),
+    ('\u{0605}', '\u{0605}', Script::Common), ('\u{0606}', '\u{060B}', Script::Arabic),
+    ('\u{060C}', '\u{060C}', Script::Common), ('\u{060D}', '\u{061A}', Script::Arabic),
+    ('\u{061B}', '\u{061B}', Script::Common), ('\u{061C}', '\u{061E}', Script::Arabic),
+    ('\u{061F}', '\u{061F}', Script::Common), ('\u{0620}', '\u{063F}', Script::Arabic),
+    ('\u{0640}', '\u{0640}', Script::Common), ('\u{0641}', '\u{064A}', Script::Arabic),
+    ('\u{064B}', '\u{0655}', Script::Inherited), ('\u{0656}', '\u{066F}', Script::Arabic),
+    ('\u{0670}', '\u{0670}', Script::Inherited), ('\u{0671}', '\u{06DC}', Script::Arabic),
+    ('\u{06DD}', '\u{06DD}', Script::Common), ('\u{06DE}', '\u{06FF}', Script::Arabic),
+    ('\u{0700}', '\u{070D}', Script::Syriac), ('\u{070F}', '\u{074A}', Script::Syriac),
+    ('\u{074D}', '\u{074F}', Script::Syriac), ('\u{0750}', '\u{077F}', Script::Arabic),
+    ('\u{0780}', '\u{07B1}', Script::Thaana), ('\u{07C0}', '\u{07FA}', Script::Nko),
+    ('\u{07FD}', '\u{07FF}', Script::Nko), ('\u{0800}', '\u{082D}', Script::Samaritan),
+    ('\u{0830}', '\u{083E}', Script::Samaritan), ('\u{0840}', '\u{085B}', Script::Mandaic),
+    ('\u{085E}', '\u{085E}', Script::Mandaic), ('\u{0860}', '\u{086A}', Script::Syriac),
+    ('\u{0870}', '\u{0891}', Script::Arabic), ('\u{0897}', '\u{08E1}', Script::Arabic),
+    ('\u{08E2}', '\u{08E2}', Script::Common), ('\u{08E3}', '\u{08FF}', Script::Arabic),
+    ('\u{0900}', '\u{0950}', Script::Devanagari), ('\u{0951}', '\u{0954}', Script::Inherited),
+    ('\u{0955}', '\u{0963}', Script::Devanagari), ('\u{0964}', '\u{0965}', Script::Common),
+    ('\u{0966}', '\u{097F}', Script::Devanagari), ('\u{0980}', '\u{0983}', Script::Bengali),
+    ('\u{0985}', '\u{098C}', Script::Bengali), ('\u{098F}', '\u{0990}', Script::Bengali),
+    ('\u{0993}', '\u{09A8}', Script::Bengali), ('\u{09AA}', '\u{09B0}', Script::Bengali),
+    ('\u{09B2}', '\u{09B2}', Script::Bengali), ('\u{09B6}', '\u{09B9}', Script::Bengali),
+    ('\u{09BC}', '\u{09C4}', Script::Bengali), ('\u{09C7}', '\u{09C8}', Script::Bengali),
+    ('\u{09CB}', '\u{09CE}', Script::Bengali), ('\u{09D7}', '\u{09D7}', Script::Bengali),
+    ('\u{09DC}', '\u{09DD}', Script::Bengali), ('\u{09DF}', '\u{09E3}', Script::Bengali),
+    ('\u{09E6}', '\u{09FE}', Script::Bengali), ('\u{0A01}', '\u{0A03}', Script::Gurmukhi),
+    ('\u{0A05}', '\u{0A0A}', Script::Gurmukhi), ('\u{0A0F}', '\u{0A10}', Script::Gurmukhi),
+    ('\u{0A13}', '\u{0A28}', Script::Gurmukhi), ('\u{0A2A}', '\u{0A30}', Script::Gurmukhi),
+    ('\u{0A32}', '\u{0A33}', Script::Gurmukhi), ('\u{0A35}', '\u{0A36}', Script::Gurmukhi),
+    ('\u{0A38}', '\u{0A39}', Script::Gurmukhi), ('\u{0A3C}', '\u{0A3C}', Script::Gurmukhi),
+    ('\u{0A3E}', '\u{0A42}', Script::Gurmukhi), ('\u{0A47}', '\u{0A48}', Script::Gurmukhi),
+    ('\u{0A4B}', '\u{0A4D}', Script::Gurmukhi), ('\u{0A51}', '\u{0A51}', Script::Gurmukhi),
+    ('\u{0A59}', '\u{0A5C}', Script::Gurmukhi), ('\u{0A5E}', '\u{0A5E}', Script::Gurmukhi),
+    ('\u{0A66}', '\u{0A76}', Script::Gurmukhi), ('\u{0A81}', '\u{0A83}', Script::Gujarati),
+    ('\u{0A85}', '\u{0A8D}', Script::Gujarati), ('\u{0A8F}', '\u{0A91}', Script::Gujarati),
+    ('\u{0A93}', '\u{0AA8}', Script::Gujarati), ('\u{0AAA}', '\u{0AB0}', Script::Gujarati),
+    ('\u{0AB2}', '\u{0AB3}', Script::Gujarati), ('\u{0AB5}', '\u{0AB9}', Script::Gujarati),
+    ('\u{0ABC}', '\u{0AC5}', Script::Gujarati), ('\u{0AC7}', '\u{0AC9}', Script::Gujarati),
+    ('\u{0ACB}', '\u{0ACD}', Script::Gujarati), ('\u{0AD0}', '\u{0AD0}', Script::Gujarati),
+    ('\u{0AE0}', '\u{0AE3}', Script::Gujarati), ('\u{0AE6}', '\u{0AF1}', Script::Gujarati),
+    ('\u{0AF9}', '\u{0AFF}', Script::Gujarati), ('\u{0B01}', '\u{0B03}', Script::Oriya),
+    ('\u{0B05}', '\u{0B0C}', Script::Oriya), ('\u{0B0F}', '\u{0B10}', Script::Oriya),
+    ('\u{0B13}', '\u{0B28}', Script::Oriya), ('\u{0B2A}', '\u{0B30}', Script::Oriya),
+    ('\u{0B32}', '\u{0B33}', Script::Oriya), ('\u{0B35}', '\u{0B39}', Script::Oriya),
+    ('\u{0B3C}', '\u{0B44}', Script::Oriya), ('\u{0B47}', '\u{0B48}', Script::Oriya),
+    ('\u{0B4B}', '\u{0B4D}', Script::Oriya), ('\u{0B55}', '\u{0B57}', Script::Oriya),
+    ('\u{0B5C}', '\u{0B5D}', Script::Oriya), ('\u{0B5F}', '\u{0B63}', Script::Oriya),
+    ('\u{0B66}', '\u{0B77}', Script::Oriya), ('\u{0B82}', '\u{0B83}', Script::Tamil),
+    ('\u{0B85}', '\u{0B8A}', Script::Tamil), ('\u{0B8E}', '\u{0B90}', Script::Tamil),
+    ('\u{0B92}', '\u{0B95}', Script::Tamil), ('\u{0B99}', '\u{0B9A}', Script::Tamil),
+    ('\u{0B9C}', '\u{0B9C}', Script::Tamil), ('\u{0B9E}', '\u{0B9F}', Script::Tamil),
+    ('\u{0BA3}', '\u{0BA4}', Script::Tamil), ('\u{0BA8}', '\u{0BAA}', Script::Tamil),
+    ('\u{0BAE}', '\u{0BB9}', Script::Tamil), ('\u{0BBE}', '\u{0BC2}', Script::Tamil),
+    ('\u{0BC6}', '\u{0BC8}', Script::Tamil), ('\u{0BCA}', '\u{0BCD}', Script::Tamil),
+    ('\u{0BD0}', '\u{0BD0}', Script::Tamil), ('\u{0BD7}', '\u{0BD7}', Script::Tamil),
+    ('\u{0BE6}', '\u{0BFA}', Script::Tamil), ('\u{0C00}', '\u{0C0C}', Script::Telugu),
+    ('\u{0C0E}', '\u{0C10}', Script::Telugu), ('\u{0C12}', '\u{0C28}', Script::Telugu),
+    ('\u{0C2A}', '\u{0C39}', Script::Telugu), ('\u{0C3C}', '\u{0C44}', Script::Telugu),
+    ('\u{0C46}', '\u{0C48}', Script::Telugu), ('\u{0C4A}', '\u{0C4D}', Script::Telugu),
+    ('\u{0C55}', '\u{0C56}', Script::Telugu), ('\u{0C58}', '\u{0C5A}', Script::Telugu),
+    ('\u{0C5C}', '\u{0C5D}', Script::Telugu), ('\u{0C60}', '\u{0C63}', Script::Telugu),
+    ('\u{0C66}', '\u{0C6F}', Script::Telugu), ('\u{0C77}', '\u{0C7F}', Script::Telugu),
+    ('\u{0C80}', '\u{0C8C}', Script::Kannada), ('\u{0C8E}', '\u{0C90}', Script::Kannada),
+    ('\u{0C92}', '\u{0CA8}', Script::Kannada), ('\u{0CAA}', '\u{0CB3}', Script::Kannada),
+    ('\u{0CB5}', '\u{0CB9}', Script::Kannada), ('\u{0CBC}', '\u{0CC4}', Script::Kannada),
+    ('\u{0CC6}', '\u{0CC8}', Script::Kannada), ('\u{0CCA}', '\u{0CCD}', Script::Kannada),
+    ('\u{0CD5}', '\u{0CD6}', Script::Kannada), ('\u{0CDC}', '\u{0CDE}', Script::Kannada),
+    ('\u{0CE0}', '\u{0CE3}', Script::Kannada), ('\u{0CE6}', '\u{0CEF}', Script::Kannada),
+    ('\u{0CF1}', '\u{0CF3}', Script::Kannada), ('\u{0D00}', '\u{0D0C}', Script::Malayalam),
+    ('\u{0D0E}', '\u{0D10}', Script::Malayalam), ('\u{0D12}', '\u{0D44}', Script::Malayalam),
+    ('\u{0D46}', '\u{0D48}', Script::Malayalam), ('\u{0D4A}', '\u{0D4F}', Script::Malayalam),
+    ('\u{0D54}', '\u{0D63}', Script::Malayalam), ('\u{0D66}', '\u{0D7F}', Script::Malayalam),
+    ('\u{0D81}', '\u{0D83}', Script::Sinhala), ('\u{0D85}', '\u{0D96}', Script::Sinhala),
+    ('\u{0D9A}', '\u{0DB1}', Script::Sinhala), ('\u{0DB3}', '\u{0DBB}', Script::Sinhala),
+    ('\u{0DBD}', '\u{0DBD}', Script::Sinhala), ('\u{0DC0}', '\u{0DC6}', Script::Sinhala),
+    ('\u{0DCA}', '\u{0DCA}', Script::Sinhala), ('\u{0DCF}', '\u{0DD4}', Script::Sinhala),
+    ('\u{0DD6}', '\u{0DD6}', Script::Sinhala), ('\u{0DD8}', '\u{0DDF}', Script::Sinhala),
+    ('\u{0DE6}', '\u{0DEF}', Script::Sinhala), ('\u{0DF2}', '\u{0DF4}', Script::Sinhala),
+    ('\u{0E01}', '\u{0E3A}', Script::Thai), ('\u{0E3F}', '\u{0E3F}', Script::Common),
+    ('\u{0E40}', '\u{0E5B}', Script::Thai), ('\u{0E81}', '\u{0E82}', Script::Lao),
+    ('\u{0E84}', '\u{0E84}', Script::Lao), ('\u{0E86}', '\u{0E8A}', Script::Lao),
+    ('\u{0E8C}', '\u{0EA3}', Script::Lao), ('\u{0EA5}', '\u{0EA5}', Script::Lao),
+    ('\u{0EA7}', '\u{0EBD}', Script::Lao), ('\u{0EC0}', '\u{0EC4}', Script::Lao),
+    ('\u{0EC6}', '\u{0EC6}', Script::Lao), ('\u{0EC8}', '\u{0ECE}', Script::Lao),
+    ('\u{0ED0}', '\u{0ED9}', Script::Lao), ('\u{0EDC}', '\u{0EDF}', Script::Lao),
+    ('\u{0F00}', '\u{0F47}', Script::Tibetan), ('\u{0F49}', '\u{0F6C}', Script::Tibetan),
+    ('\u{0F71}', '\u{0F97}', Script::Tibetan), ('\u{0F99}', '\u{0FBC}', Script::Tibetan),
+    ('\u{0FBE}', '\u{0FCC}', Script::Tibetan), ('\u{0FCE}', '\u{0FD4}', Script::Tibetan),
+    ('\u{0FD5}', '\u{0FD8}', Script::Common), ('\u{0FD9}', '\u{0FDA}', Script::Tibetan),
+    ('\u{1000}', '\u{109F}', Script::Myanmar), ('\u{10A0}', '\u{10C5}', Script::Georgian),
+    ('\u{10C7}', '\u{10C7}', Script::Georgian), ('\u{10CD}', '\u{10CD}', Script::Georgian),
+    ('\u{10D0}', '\u{10FA}', Script::Georgian), ('\u{10FB}', '\u{10FB}', Script::Common),
+    ('\u{10FC}', '\u{10FF}', Script::Georgian), ('\u{1100}', '\u{11FF}', Script::Hangul),
+    ('\u{1200}', '\u{1248}', Script::Ethiopic), ('\u{124A}', '\u{124D}', Script::Ethiopic),
+    ('\u{1250}', '\u{1256}', Script::Ethiopic), ('\u{1258}', '\u{1258}', Script::Ethiopic),
+    ('\u{125A}', '\u{125D}', Script::Ethiopic), ('\u{1260}', '\u{1288}', Script::Ethiopic),
+    ('\u{128A}', '\u{128D}', Script::Ethiopic), ('\u{1290}', '\u{12B0}', Script::Ethiopic),
+    ('\u{12B2}', '\u{12B5}', Script::Ethiopic), ('\u{12B8}', '\u{12BE}', Script::Ethiopic),
+    ('\u{12C0}', '\u{12C0}', Script::Ethiopic), ('\u{12C2}', '\u{12C5}', Script::Ethiopic),
+    ('\u{12C8}', '\u{12D6}', Script::Ethiopic), ('\u{12D8}', '\u{1310}', Script::Ethiopic),
+    ('\u{1312}', '\u{1315}', Script::Ethiopic), ('\u{1318}', '\u{135A}', Script::Ethiopic),
+    ('\u{135D}', '\u{137C}', Script::Ethiopic), ('\u{1380}', '\u{1399}', Script::Ethiopic),
+    ('\u{13A0}', '\u{13F5}', Script::Cherokee), ('\u{13F8}', '\u{13FD}', Script::Cherokee),
+    ('\u{1400}', '\u{167F}', Script::CanadianAboriginal), ('\u{1680}', '\u{169C}', Script::Ogham),
+    ('\u{16A0}', '\u{16EA}', Script::Runic), ('\u{16EB}', '\u{16ED}', Script::Common),
+    ('\u{16EE}', '\u{16F8}', Script::Runic), ('\u{1700}', '\u{1715}', Script::Tagalog),
+    ('\u{171F}', '\u{171F}', Script::Tagalog), ('\u{1720}', '\u{1734}', Script::Hanunoo),
+    ('\u{1735}', '\u{1736}', Script::Common), ('\u{1740}', '\u{1753}', Script::Buhid),
+    ('\u{1760}', '\u{176C}', Script::Tagbanwa), ('\u{176E}', '\u{1770}', Script::Tagbanwa),
+    ('\u{1772}', '\u{1773}', Script::Tagbanwa), ('\u{1780}', '\u{17DD}', Script::Khmer),
+    ('\u{17E0}', '\u{17E9}', Script::Khmer), ('\u{17F0}', '\u{17F9}', Script::Khmer),
+    ('\u{1800}', '\u{1801}', Script::Mongolian), ('\u{1802}', '\u{1803}', Script::Common),
+    ('\u{1804}', '\u{1804}', Script::Mongolian), ('\u{1805}', '\u{1805}', Script::Common),
+    ('\u{1806}', '\u{1819}', Script::Mongolian), ('\u{1820}', '\u{1878}', Script::Mongolian),
+    ('\u{1880}', '\u{18AA}', Script::Mongolian), ('\u{18B0}', '\u{18F5}', Script::CanadianAboriginal),
+    ('\u{1900}', '\u{191E}', Script::Limbu), ('\u{1920}', '\u{192B}', Script::Limbu),
+    ('\u{1930}', '\u{193B}', Script::Limbu), ('\u{1940}', '\u{1940}', Script::Limbu),
+    ('\u{1944}', '\u{194F}', Script::Limbu), ('\u{1950}', '\u{196D}', Script::TaiLe),
+    ('\u{1970}', '\u{1974}', Script::TaiLe), ('\u{1980}', '\u{19AB}', Script::NewTaiLue),
+    ('\u{19B0}', '\u{19C9}', Script::NewTaiLue), ('\u{19D0}', '\u{19DA}', Script::NewTaiLue),
+    ('\u{19DE}', '\u{19DF}', Script::NewTaiLue), ('\u{19E0}', '\u{19FF}', Script::Khmer),
+    ('\u{1A00}', '\u{1A1B}', Script::Buginese), ('\u{1A1E}', '\u{1A1F}', Script::Buginese),
+    ('\u{1A20}', '\u{1A5E}', Script::TaiTham), ('\u{1A60}', '\u{1A7C}', Script::TaiTham),
+    ('\u{1A7F}', '\u{1A89}', Script::TaiTham), ('\u{1A90}', '\u{1A99}', Script::TaiTham),
+    ('\u{1AA0}', '\u{1AAD}', Script::TaiTham), ('\u{1AB0}', '\u{1ADD}', Script::Inherited),
+    ('\u{1AE0}', '\u{1AEB}', Script::Inherited), ('\u{1B00}', '\u{1B4C}', Script::Balinese),
+    ('\u{1B4E}', '\u{1B7F}', Script::Balinese), ('\u{1B80}', '\u{1BBF}', Script::Sundanese),
+    ('\u{1BC0}', '\u{1BF3}', Script::Batak), ('\u{1BFC}', '\u{1BFF}', Script::Batak),
+    ('\u{1C00}', '\u{1C37}', Script::Lepcha), ('\u{1C3B}', '\u{1C49}', Script::Lepcha),
+    ('\u{1C4D}', '\u{1C4F}', Script::Lepcha), ('\u{1C50}', '\u{1C7F}', Script::OlChiki),
+    ('\u{1C80}', '\u{1C8A}', Script::Cyrillic), ('\u{1C90}', '\u{1CBA}', Script::Georgian),
+    ('\u{1CBD}', '\u{1CBF}', Script::Georgian), ('\u{1CC0}', '\u{1CC7}', Script::Sundanese),
+    ('\u{1CD0}', '\u{1CD2}', Script::Inherited), ('\u{1CD3}', '\u{1CD3}', Script::Common),
+    ('\u{1CD4}', '\u{1CE0}', Script::Inherited), ('\u{1CE1}', '\u{1CE1}', Script::Common),
+    ('\u{1CE2}', '\u{1CE8}', Script::Inherited), ('\u{1CE9}', '\u{1CEC}', Script::Common),
+    ('\u{1CED}', '\u{1CED}', Script::Inherited), ('\u{1CEE}', '\u{1CF3}', Script::Common),
+    ('\u{1CF4}', '\u{1CF4}', Script::Inherited), ('\u{1CF5}', '\u{1CF7}', Script::Common),
+    ('\u{1CF8}', '\u{1CF9}', Script::Inherited), ('\u{1CFA}', '\u{1CFA}', Script::Common),
+    ('\u{1D00}', '\u{1D25}', Script::Latin), ('\u{1D26}', '\u{1D2A}', Script::Greek),
+    ('\u{1D2B}', '\u{1D2B}', Script::Cyrillic), ('\u{1D2C}', '\u{1D5C}', Script::Latin),
+    ('\u{1D5D}', '\u{1D61}', Script::Greek), ('\u{1D62}', '\u{1D65}', Script::Latin),
+    ('\u{1D66}', '\u{1D6A}', Script::Greek), ('\u{1D6B}', '\u{1D77}', Script::Latin),
+    ('\u{1D78}', '\u{1D78}', Script::Cyrillic), ('\u{1D79}', '\u{1DBE}', Script::Latin),
+    ('\u{1DBF}', '\u{1DBF}', Script::Greek), ('\u{1DC0}', '\u{1DFF}', Script::Inherited),
+    ('\u{1E00}', '\u{1EFF}', Script::Latin), ('\u{1F00}', '\u{1F15}', Script::Greek),
+    ('\u{1F18}', '\u{1F1D}', Script::Greek), ('\u{1F20}', '\u{1F45}', Script::Greek),
+    ('\u{1F48}', '\u{1F4D}', Script::Greek), ('\u{1F50}', '\u{1F57}', Script::Greek),
+    ('\u{1F59}', '\u{1F59}', Script::Greek), ('\u{1F5B}', '\u{1F5B}', Script::Greek),
+    ('\u{1F5D}', '\u{1F5D}', Script::Greek), ('\u{1F5F}', '\u{1F7D}', Script::Greek),
+    ('\u{1F80}', '\u{1FB4}', Script::Greek), ('\u{1FB6}', '\u{1FC4}', Script::Greek),
+    ('\u{1FC6}', '\u{1FD3}', Script::Greek), ('\u{1FD6}', '\u{1FDB}', Script::Greek),
+    ('\u{1FDD}', '\u{1FEF}', Script::Greek), ('\u{1FF2}', '\u{1FF4}', Script::Greek),
+    ('\u{1FF6}', '\u{1FFE}', Script::Greek), ('\u{2000}', '\u{200B}', Script::Common),
+    ('\u{200C}', '\u{200D}', Script::Inherited), ('\u{200E}', '\u{2064}', Script::Common),
+    ('\u{2066}', '\u{2070}', Script::Common), ('\u{2071}', '\u{2071}', Script::Latin),
+    ('\u{2074}', '\u{207E}', Script::Common), ('\u{207F}', '\u{207F}', Script::Latin),
+    ('\u{2080}', '\u{208E}', Script::Common), ('\u{2090}', '\u{209C}', Script::Latin),
+    ('\u{20A0}', '\u{20C1}', Script::Common), ('\u{20D0}', '\u{20F0}', Script::Inherited),
+    ('\u{2100}', '\u{2125}', Script::Common), ('\u{2126}', '\u{2126}', Script::Greek),
+    ('\u{2127}', '\u{2129}', Script::Common), ('\u{212A}', '\u{212B}', Script::Latin),
+    ('\u{212C}', '\u{2131}', Script::Common), ('\u{2132}', '\u{2132}', Script::Latin),
+    ('\u{2133}', '\u{214D}', Script::Common), ('\u{214E}', '\u{214E}', Script::Latin),
+    ('\u{214F}', '\u{215F}', Script::Common), ('\u{2160}', '\u{2188}', Script::Latin),
+    ('\u{2189}', '\u{218B}', Script::Common), ('\u{2190}', '\u{2429}', Script::Common),
+    ('\u{2440}', '\u{244A}', Script::Common), ('\u{2460}', '\u{27FF}', Script::Common),
+    ('\u{2800}', '\u{28FF}', Script::Braille), ('\u{2900}', '\u{2B73}', Script::Common),
+    ('\u{2B76}', '\u{2BFF}', Script::Common), ('\u{2C00}', '\u{2C5F}', Script::Glagolitic),
+    ('\u{2C60}', '\u{2C7F}', Script::Latin), ('\u{2C80}', '\u{2CF3}', Script::Coptic),
+    ('\u{2CF9}', '\u{2CFF}', Script::Coptic), ('\u{2D00}', '\u{2D25}', Script::Georgian),
+    ('\u{2D27}', '\u{2D27}', Script::Georgian), ('\u{2D2D}', '\u{2D2D}', Script::Georgian),
+    ('\u{2D30}', '\u{2D67}', Script::Tifinagh), ('\u{2D6F}', '\u{2D70}', Script::Tifinagh),
+    ('\u{2D7F}', '\u{2D7F}', Script::Tifinagh), ('\u{2D80}', '\u{2D96}', Script::Ethiopic),
+    ('\u{2DA0}', '\u{2DA6}', Script::Ethiopic), ('\u{2DA8}', '\u{2DAE}', Script::Ethiopic),
+    ('\u{2DB0}', '\u{2DB6}', Script::Ethiopic), ('\u{2DB8}', '\u{2DBE}', Script::Ethiopic),
+    ('\u{2DC0}', '\u{2DC6}', Script::Ethiopic), ('\u{2DC8}', '\u{2DCE}', Script::Ethiopic),
+    ('\u{2DD0}', '\u{2DD6}', Script::Ethiopic), ('\u{2DD8}', '\u{2DDE}', Script::Ethiopic),
+    ('\u{2DE0}', '\u{2DFF}', Script::Cyrillic), ('\u{2E00}', '\u{2E5D}', Script::Common),
+    ('\u{2E80}', '\u{2E99}', Script::Han), ('\u{2E9B}', '\u{2EF3}', Script::Han),
+    ('\u{2F00}', '\u{2FD5}', Script::Han), ('\u{2FF0}', '\u{3004}', Script::Common),
+    ('\u{3005}', '\u{3005}', Script::Han), ('\u{3006}', '\u{3006}', Script::Common),
+    ('\u{3007}', '\u{3007}', Script::Han), ('\u{3008}', '\u{3020}', Script::Common),
+    ('\u{3021}', '\u{3029}', Script::Han), ('\u{302A}', '\u{302D}', Script::Inherited),
+    ('\u{302E}', '\u{302F}', Script::Hangul), ('\u{3030}', '\u{3037}', Script::Common),
+    ('\u{3038}', '\u{303B}', Script::Han), ('\u{303C}', '\u{303F}', Script::Common),
+    ('\u{3041}', '\u{3096}', Script::Hiragana), ('\u{3099}', '\u{309A}', Script::Inherited),
+    ('\u{309B}', '\u{309C}', Script::Common), ('\u{309D}', '\u{309F}', Script::Hiragana),
+    ('\u{30A0}', '\u{30A0}', Script::Common), ('\u{30A1}', '\u{30FA}', Script::Katakana),
+    ('\u{30FB}', '\u{30FC}', Script::Common), ('\u{30FD}', '\u{30FF}', Script::Katakana),
+    ('\u{3105}', '\u{312F}', Script::Bopomofo), ('\u{3131}', '\u{318E}', Script::Hangul),
+    ('\u{3190}', '\u{319F}', Script::Common), ('\u{31A0}', '\u{31BF}', Script::Bopomofo),
+    ('\u{31C0}', '\u{31E5}', Script::Common), ('\u{31EF}', '\u{31EF}', Script::Common),
+    ('\u{31F0}', '\u{31FF}', Script::Katakana), ('\u{3200}', '\u{321E}', Script::Hangul),
+    ('\u{3220}', '\u{325F}', Script::Common), ('\u{3260}', '\u{327E}', Script::Hangul),
+    ('\u{327F}', '\u{32CF}', Script::Common), ('\u{32D0}', '\u{32FE}', Script::Katakana),
+    ('\u{32FF}', '\u{32FF}', Script::Common), ('\u{3300}', '\u{3357}', Script::Katakana),
+    ('\u{3358}', '\u{33FF}', Script::Common), ('\u{3400}', '\u{4DBF}', Script::Han),
+    ('\u{4DC0}', '\u{4DFF}', Script::Common), ('\u{4E00}', '\u{9FFF}', Script::Han),
+    ('\u{A000}', '\u{A48C}', Script::Yi), ('\u{A490}', '\u{A4C6}', Script::Yi), ('\u{A4D0}', '\u{A4FF}', Script::Lisu),
+    ('\u{A500}', '\u{A62B}', Script::Vai), ('\u{A640}', '\u{A69F}', Script::Cyrillic),
+    ('\u{A6A0}', '\u{A6F7}', Script::Bamum), ('\u{A700}', '\u{A721}', Script::Common),
+    ('\u{A722}', '\u{A787}', Script::Latin), ('\u{A788}', '\u{A78A}', Script::Common),
+    ('\u{A78B}', '\u{A7DC}', Script::Latin), ('\u{A7F1}', '\u{A7FF}', Script::Latin),
+    ('\u{A800}', '\u{A82C}', Script::SylotiNagri), ('\u{A830}', '\u{A839}', Script::Common),
+    ('\u{A840}', '\u{A877}', Script::PhagsPa), ('\u{A880}', '\u{A8C5}', Script::Saurashtra),
+    ('\u{A8CE}', '\u{A8D9}', Script::Saurashtra), ('\u{A8E0}', '\u{A8FF}', Script::Devanagari),
+    ('\u{A900}', '\u{A92D}', Script::KayahLi), ('\u{A92E}', '\u{A92E}', Script::Common),
+    ('\u{A92F}', '\u{A92F}', Script::KayahLi), ('\u{A930}', '\u{A953}', Script::Rejang),
+    ('\u{A95F}', '\u{A95F}', Script::Rejang), ('\u{A960}', '\u{A97C}', Script::Hangul),
+    ('\u{A980}', '\u{A9CD}', Script::Javanese), ('\u{A9CF}', '\u{A9CF}', Script::Common),
+    ('\u{A9D0}', '\u{A9D9}', Script::Javanese), ('\u{A9DE}', '\u{A9DF}', Script::Javanese),
+    ('\u{A9E0}', '\u{A9FE}', Script::Myanmar), ('\u{AA00}', '\u{AA36}', Script::Cham),
+    ('\u{AA40}', '\u{AA4D}', Script::Cham), ('\u{AA50}', '\u{AA59}', Script::Cham),
+    ('\u{AA5C}', '\u{AA5F}', Script::Cham), ('\u{AA60}', '\u{AA7F}', Script::Myanmar),
+    ('\u{AA80}', '\u{AAC2}', Script::TaiViet), ('\u{AADB}', '\u{AADF}', Script::TaiViet),
+    ('\u{AAE0}', '\u{AAF6}', Script::MeeteiMayek), ('\u{AB01}', '\u{AB06}', Script::Ethiopic),
+    ('\u{AB09}', '\u{AB0E}', Script::Ethiopic), ('\u{AB11}', '\u{AB16}', Script::Ethiopic),
+    ('\u{AB20}', '\u{AB26}', Script::Ethiopic), ('\u{AB28}', '\u{AB2E}', Script::Ethiopic),
+    ('\u{AB30}', '\u{AB5A}', Script::Latin), ('\u{AB5B}', '\u{AB5B}', Script::Common),
+    ('\u{AB5C}', '\u{AB64}', Script::Latin), ('\u{AB65}', '\u{AB65}', Script::Greek),
+    ('\u{AB66}', '\u{AB69}', Script::Latin), ('\u{AB6A}', '\u{AB6B}', Script::Common),
+    ('\u{AB70}', '\u{ABBF}', Script::Cherokee), ('\u{ABC0}', '\u{ABED}', Script::MeeteiMayek),
+    ('\u{ABF0}', '\u{ABF9}', Script::MeeteiMayek), ('\u{AC00}', '\u{D7A3}', Script::Hangul),
+    ('\u{D7B0}', '\u{D7C6}', Script::Hangul), ('\u{D7CB}', '\u{D7FB}', Script::Hangul),
+    ('\u{F900}', '\u{FA6D}', Script::Han), ('\u{FA70}', '\u{FAD9}', Script::Han),
+    ('\u{FB00}', '\u{FB06}', Script::Latin), ('\u{FB13}', '\u{FB17}', Script::Armenian),
+    ('\u{FB1D}', '\u{FB36}', Script::Hebrew), ('\u{FB38}', '\u{FB3C}', Script::Hebrew),
+    ('\u{FB3E}', '\u{FB3E}', Script::Hebrew), ('\u{FB40}', '\u{FB41}', Script::Hebrew),
+    ('\u{FB43}', '\u{FB44}', Script::Hebrew), ('\u{FB46}', '\u{FB4F}', Script::Hebrew),
+    ('\u{FB50}', '\u{FD3D}', Script::Arabic), ('\u{FD3E}', '\u{FD3F}', Script::Common),
+    ('\u{FD40}', '\u{FDCF}', Script::Arabic), ('\u{FDF0}', '\u{FDFF}', Script::Arabic),
+    ('\u{FE00}', '\u{FE0F}', Script::Inherited), ('\u{FE10}', '\u{FE19}', Script::Common),
+    ('\u{FE20}', '\u{FE2D}', Script::Inherited), ('\u{FE2E}', '\u{FE2F}', Script::Cyrillic),
+    ('\u{FE30}', '\u{FE52}', Script::Common), ('\u{FE54}', '\u{FE66}', Script::Common),
+    ('\u{FE68}', '\u{FE6B}', Script::Common), ('\u{FE70}', '\u{FE74}', Script::Arabic),
+    ('\u{FE76}', '\u{FEFC}', Script::Arabic), ('\u{FEFF}', '\u{FEFF}', Script::Common),
+    ('\u{FF01}', '\u{FF20}', Script::Common), ('\u{FF21}', '\u{FF3A}', Script::Latin),
+    ('\u{FF3B}', '\u{FF40}', Script::Common), ('\u{FF41}', '\u{FF5A}', Script::Latin),
+    ('\u{FF5B}', '\u{FF65}', Script::Common), ('\u{FF66}', '\u{FF6F}', Script::Katakana),
+    ('\u{FF70}', '\u{FF70}', Script::Common), ('\u{FF71}', '\u{FF9D}', Script::Katakana),
+    ('\u{FF9E}', '\u{FF9F}', Script::Common), ('\u{FFA0}', '\u{FFBE}', Script::Hangul),
+    ('\u{FFC2}', '\u{FFC7}', Script::Hangul), ('\u{FFCA}', '\u{FFCF}', Script::Hangul),
+    ('\u{FFD2}', '\u{FFD7}', Script::Hangul), ('\u{FFDA}', '\u{FFDC}', Script::Hangul),
+    ('\u{FFE0}', '\u{FFE6}', Script::Common), ('\u{FFE8}', '\u{FFEE}', Script::Common),
+    ('\u{FFF9}', '\u{FFFD}', Script::Common), ('\u{10000}', '\u{1000B}', Script::LinearB),
+    ('\u{1000D}', '\u{10026}', Script::LinearB), ('\u{10028}', '\u{1003A}', Script::LinearB),
+    ('\u{1003C}', '\u{1003D}', Script::LinearB), ('\u{1003F}', '\u{1004D}', Script::LinearB),
+    ('\u{10050}', '\u{1005D}', Script::LinearB), ('\u{10080}', '\u{100FA}', Script::LinearB),
+    ('\u{10100}', '\u{10102}', Script::Common), ('\u{10107}', '\u{10133}', Script::Common),
+    ('\u{10137}', '\u{1013F}', Script::Common), ('\u{10140}', '\u{1018E}', Script::Greek),
+    ('\u{10190}', '\u{1019C}', Script::Common), ('\u{101A0}', '\u{101A0}', Script::Greek),
+    ('\u{101D0}', '\u{101FC}', Script::Common), ('\u{101FD}', '\u{101FD}', Script::Inherited),
+    ('\u{10280}', '\u{1029C}', Script::Lycian), ('\u{102A0}', '\u{102D0}', Script::Carian),
+    ('\u{102E0}', '\u{102E0}', Script::Inherited), ('\u{102E1}', '\u{102FB}', Script::Common),
+    ('\u{10300}', '\u{10323}', Script::OldItalic), ('\u{1032D}', '\u{1032F}', Script::OldItalic),
+    ('\u{10330}', '\u{1034A}', Script::Gothic), ('\u{10350}', '\u{1037A}', Script::OldPermic),
+    ('\u{10380}', '\u{1039D}', Script::Ugaritic), ('\u{1039F}', '\u{1039F}', Script::Ugaritic),
+    ('\u{103A0}', '\u{103C3}', Script::OldPersian), ('\u{103C8}', '\u{103D5}', Script::OldPersian),
+    ('\u{10400}', '\u{1044F}', Script::Deseret), ('\u{10450}', '\u{1047F}', Script::Shavian),
+    ('\u{10480}', '\u{1049D}', Script::Osmanya), ('\u{104A0}', '\u{104A9}', Script::Osmanya),
+    ('\u{104B0}', '\u{104D3}', Script::Osage), ('\u{104D8}', '\u{104FB}', Script::Osage),
+    ('\u{10500}', '\u{10527}', Script::Elbasan), ('\u{10530}', '\u{10563}', Script::CaucasianAlbanian),
+    ('\u{1056F}', '\u{1056F}', Script::CaucasianAlbanian), ('\u{10570}', '\u{1057A}', Script::Vithkuqi),
+    ('\u{1057C}', '\u{1058A}', Script::Vithkuqi), ('\u{1058C}', '\u{10592}', Script::Vithkuqi),
+    ('\u{10594}', '\u{10595}', Script::Vithkuqi), ('\u{10597}', '\u{105A1}', Script::Vithkuqi),
+    ('\u{105A3}', '\u{105B1}', Script::Vithkuqi), ('\u{105B3}', '\u{105B9}', Script::Vithkuqi),
+    ('\u{105BB}', '\u{105BC}', Script::Vithkuqi), ('\u{105C0}', '\u{105F3}', Script::Todhri),
+    ('\u{10600}', '\u{10736}', Script::LinearA), ('\u{10740}', '\u{10755}', Script::LinearA),
+    ('\u{10760}', '\u{10767}', Script::LinearA), ('\u{10780}', '\u{10785}', Script::Latin),
+    ('\u{10787}', '\u{107B0}', Script::Latin), ('\u{107B2}', '\u{107BA}', Script::Latin),
+    ('\u{10800}', '\u{10805}', Script::Cypriot), ('\u{10808}', '\u{10808}', Script::Cypriot),
+    ('\u{1080A}', '\u{10835}', Script::Cypriot), ('\u{10837}', '\u{10838}', Script::Cypriot),
+    ('\u{1083C}', '\u{1083C}', Script::Cypriot), ('\u{1083F}', '\u{1083F}', Script::Cypriot),
+    ('\u{10840}', '\u{10855}', Script::ImperialAramaic), ('\u{10857}', '\u{1085F}', Script::ImperialAramaic),
+    ('\u{10860}', '\u{1087F}', Script::Palmyrene), ('\u{10880}', '\u{1089E}', Script::Nabataean),
+    ('\u{108A7}', '\u{108AF}', Script::Nabataean), ('\u{108E0}', '\u{108F2}', Script::Hatran),
+    ('\u{108F4}', '\u{108F5}', Script::Hatran), ('\u{108FB}', '\u{108FF}', Script::Hatran),
+    ('\u{10900}', '\u{1091B}', Script::Phoenician), ('\u{1091F}', '\u{1091F}', Script::Phoenician),
+    ('\u{10920}', '\u{10939}', Script::Lydian), ('\u{1093F}', '\u{1093F}', Script::Lydian),
+    ('\u{10940}', '\u{10959}', Script::Sidetic), ('\u{10980}', '\u{1099F}', Script::MeroiticHieroglyphs),
+    ('\u{109A0}', '\u{109B7}', Script::MeroiticCursive), ('\u{109BC}', '\u{109CF}', Script::MeroiticCursive),
+    ('\u{109D2}', '\u{109FF}', Script::MeroiticCursive), ('\u{10A00}', '\u{10A03}', Script::Kharoshthi),
+    ('\u{10A05}', '\u{10A06}', Script::Kharoshthi), ('\u{10A0C}', '\u{10A13}', Script::Kharoshthi),
+    ('\u{10A15}', '\u{10A17}', Script::Kharoshthi), ('\u{10A19}', '\u{10A35}', Script::Kharoshthi),
+    ('\u{10A38}', '\u{10A3A}', Script::Kharoshthi), ('\u{10A3F}', '\u{10A48}', Script::Kharoshthi),
+    ('\u{10A50}', '\u{10A58}', Script::Kharoshthi), ('\u{10A60}', '\u{10A7F}', Script::OldSouthArabian),
+    ('\u{10A80}', '\u{10A9F}', Script::OldNorthArabian), ('\u{10AC0}', '\u{10AE6}', Script::Manichaean),
+    ('\u{10AEB}', '\u{10AF6}', Script::Manichaean), ('\u{10B00}', '\u{10B35}', Script::Avestan),
+    ('\u{10B39}', '\u{10B3F}', Script::Avestan), ('\u{10B40}', '\u{10B55}', Script::InscriptionalParthian),
+    ('\u{10B58}', '\u{10B5F}', Script::InscriptionalParthian), ('\u{10B60}', '\u{10B72}', Script::InscriptionalPahlavi),
+    ('\u{10B78}', '\u{10B7F}', Script::InscriptionalPahlavi), ('\u{10B80}', '\u{10B91}', Script::PsalterPahlavi),
+    ('\u{10B99}', '\u{10B9C}', Script::PsalterPahlavi), ('\u{10BA9}', '\u{10BAF}', Script::PsalterPahlavi),
+    ('\u{10C00}', '\u{10C48}', Script::OldTurkic), ('\u{10C80}', '\u{10CB2}', Script::OldHungarian),
+    ('\u{10CC0}', '\u{10CF2}', Script::OldHungarian), ('\u{10CFA}', '\u{10CFF}', Script::OldHungarian),
+    ('\u{10D00}', '\u{10D27}', Script::HanifiRohingya), ('\u{10D30}', '\u{10D39}', Script::HanifiRohingya),
+    ('\u{10D40}', '\u{10D65}', Script::Garay), ('\u{10D69}', '\u{10D85}', Script::Garay),
+    ('\u{10D8E}', '\u{10D8F}', Script::Garay), ('\u{10E60}', '\u{10E7E}', Script::Arabic),
+    ('\u{10E80}', '\u{10EA9}', Script::Yezidi), ('\u{10EAB}', '\u{10EAD}', Script::Yezidi),
+    ('\u{10EB0}', '\u{10EB1}', Script::Yezidi), ('\u{10EC2}', '\u{10EC7}', Script::Arabic),
+    ('\u{10ED0}', '\u{10ED8}', Script::Arabic), ('\u{10EFA}', '\u{10EFF}', Script::Arabic),
+    ('\u{10F00}', '\u{10F27}', Script::OldSogdian), ('\u{10F30}', '\u{10F59}', Script::Sogdian),
+    ('\u{10F70}', '\u{10F89}', Script::OldUyghur), ('\u{10FB0}', '\u{10FCB}', Script::Chorasmian),
+    ('\u{10FE0}', '\u{10FF6}', Script::Elymaic), ('\u{11000}', '\u{1104D}', Script::Brahmi),
+    ('\u{11052}', '\u{11075}', Script::Brahmi), ('\u{1107F}', '\u{1107F}', Script::Brahmi),
+    ('\u{11080}', '\u{110C2}', Script::Kaithi), ('\u{110CD}', '\u{110CD}', Script::Kaithi),
+    ('\u{110D0}', '\u{110E8}', Script::SoraSompeng), ('\u{110F0}', '\u{110F9}', Script::SoraSompeng),
+    ('\u{11100}', '\u{11134}', Script::Chakma), ('\u{11136}', '\u{11147}', Script::Chakma),
+    ('\u{11150}', '\u{11176}', Script::Mahajani), ('\u{11180}', '\u{111DF}', Script::Sharada),
+    ('\u{111E1}', '\u{111F4}', Script::Sinhala), ('\u{11200}', '\u{11211}', Script::Khojki),
+    ('\u{11213}', '\u{11241}', Script::Khojki), ('\u{11280}', '\u{11286}', Script::Multani),
+    ('\u{11288}', '\u{11288}', Script::Multani), ('\u{1128A}', '\u{1128D}', Script::Multani),
+    ('\u{1128F}', '\u{1129D}', Script::Multani), ('\u{1129F}', '\u{112A9}', Script::Multani),
+    ('\u{112B0}', '\u{112EA}', Script::Khudawadi), ('\u{112F0}', '\u{112F9}', Script::Khudawadi),
+    ('\u{11300}', '\u{11303}', Script::Grantha), ('\u{11305}', '\u{1130C}', Script::Grantha),
+    ('\u{1130F}', '\u{11310}', Script::Grantha), ('\u{11313}', '\u{11328}', Script::Grantha),
+    ('\u{1132A}', '\u{11330}', Script::Grantha), ('\u{11332}', '\u{11333}', Script::Grantha),
+    ('\u{11335}', '\u{11339}', Script::Grantha), ('\u{1133B}', '\u{1133B}', Script::Inherited),
+    ('\u{1133C}', '\u{11344}', Script::Grantha), ('\u{11347}', '\u{11348}', Script::Grantha),
+    ('\u{1134B}', '\u{1134D}', Script::Grantha), ('\u{11350}', '\u{11350}', Script::Grantha),
+    ('\u{11357}', '\u{11357}', Script::Grantha), ('\u{1135D}', '\u{11363}', Script::Grantha),
+    ('\u{11366}', '\u{1136C}', Script::Grantha), ('\u{11370}', '\u{11374}', Script::Grantha),
+    ('\u{11380}', '\u{11389}', Script::TuluTigalari), ('\u{1138B}', '\u{1138B}', Script::TuluTigalari),
+    ('\u{1138E}', '\u{1138E}', Script::TuluTigalari), ('\u{11390}', '\u{113B5}', Script::TuluTigalari),
+    ('\u{113B7}', '\u{113C0}', Script::TuluTigalari), ('\u{113C2}', '\u{113C2}', Script::TuluTigalari),
+    ('\u{113C5}', '\u{113C5}', Script::TuluTigalari), ('\u{113C7}', '\u{113CA}', Script::TuluTigalari),
+    ('\u{113CC}', '\u{113D5}', Script::TuluTigalari), ('\u{113D7}', '\u{113D8}', Script::TuluTigalari),
+    ('\u{113E1}', '\u{113E2}', Script::TuluTigalari), ('\u{11400}', '\u{1145B}', Script::Newa),
+    ('\u{1145D}', '\u{11461}', Script::Newa), ('\u{11480}', '\u{114C7}', Script::Tirhuta),
+    ('\u{114D0}', '\u{114D9}', Script::Tirhuta), ('\u{11580}', '\u{115B5}', Script::Siddham),
+    ('\u{115B8}', '\u{115DD}', Script::Siddham), ('\u{11600}', '\u{11644}', Script::Modi),
+    ('\u{11650}', '\u{11659}', Script::Modi), ('\u{11660}', '\u{1166C}', Script::Mongolian),
+    ('\u{11680}', '\u{116B9}', Script::Takri), ('\u{116C0}', '\u{116C9}', Script::Takri),
+    ('\u{116D0}', '\u{116E3}', Script::Myanmar), ('\u{11700}', '\u{1171A}', Script::Ahom),
+    ('\u{1171D}', '\u{1172B}', Script::Ahom), ('\u{11730}', '\u{11746}', Script::Ahom),
+    ('\u{11800}', '\u{1183B}', Script::Dogra), ('\u{118A0}', '\u{118F2}', Script::WarangCiti),
+    ('\u{118FF}', '\u{118FF}', Script::WarangCiti), ('\u{11900}', '\u{11906}', Script::DivesAkuru),
+    ('\u{11909}', '\u{11909}', Script::DivesAkuru), ('\u{1190C}', '\u{11913}', Script::DivesAkuru),
+    ('\u{11915}', '\u{11916}', Script::DivesAkuru), ('\u{11918}', '\u{11935}', Script::DivesAkuru),
+    ('\u{11937}', '\u{11938}', Script::DivesAkuru), ('\u{1193B}', '\u{11946}', Script::DivesAkuru),
+    ('\u{11950}', '\u{11959}', Script::DivesAkuru), ('\u{119A0}', '\u{119A7}', Script::Nandinagari),
+    ('\u{119AA}', '\u{119D7}', Script::Nandinagari), ('\u{119DA}', '\u{119E4}', Script::Nandinagari),
+    ('\u{11A00}', '\u{11A47}', Script::ZanabazarSquare), ('\u{11A50}', '\u{11AA2}', Script::Soyombo),
+    ('\u{11AB0}', '\u{11ABF}', Script::CanadianAboriginal), ('\u{11AC0}', '\u{11AF8}', Script::PauCinHau),
+    ('\u{11B00}', '\u{11B09}', Script::Devanagari), ('\u{11B60}', '\u{11B67}', Script::Sharada),
+    ('\u{11BC0}', '\u{11BE1}', Script::Sunuwar), ('\u{11BF0}', '\u{11BF9}', Script::Sunuwar),
+    ('\u{11C00}', '\u{11C08}', Script::Bhaiksuki), ('\u{11C0A}', '\u{11C36}', Script::Bhaiksuki),
+    ('\u{11C38}', '\u{11C45}', Script::Bhaiksuki), ('\u{11C50}', '\u{11C6C}', Script::Bhaiksuki),
+    ('\u{11C70}', '\u{11C8F}', Script::Marchen), ('\u{11C92}', '\u{11CA7}', Script::Marchen),
+    ('\u{11CA9}', '\u{11CB6}', Script::Marchen), ('\u{11D00}', '\u{11D06}', Script::MasaramGondi),
+    ('\u{11D08}', '\u{11D09}', Script::MasaramGondi), ('\u{11D0B}', '\u{11D36}', Script::MasaramGondi),
+    ('\u{11D3A}', '\u{11D3A}', Script::MasaramGondi), ('\u{11D3C}', '\u{11D3D}', Script::MasaramGondi),
+    ('\u{11D3F}', '\u{11D47}', Script::MasaramGondi), ('\u{11D50}', '\u{11D59}', Script::MasaramGondi),
+    ('\u{11D60}', '\u{11D65}', Script::GunjalaGondi), ('\u{11D67}', '\u{11D68}', Script::GunjalaGondi),
+    ('\u{11D6A}', '\u{11D8E}', Script::GunjalaGondi), ('\u{11D90}', '\u{11D91}', Script::GunjalaGondi),
+    ('\u{11D93}', '\u{11D98}', Script::GunjalaGondi), ('\u{11DA0}', '\u{11DA9}', Script::GunjalaGondi),
+    ('\u{11DB0}', '\u{11DDB}', Script::TolongSiki), ('\u{11DE0}', '\u{11DE9}', Script::TolongSiki),
+    ('\u{11EE0}', '\u{11EF8}', Script::Makasar), ('\u{11F00}', '\u{11F10}', Script::Kawi),
+    ('\u{11F12}', '\u{11F3A}', Script::Kawi), ('\u{11F3E}', '\u{11F5A}', Script::Kawi),
+    ('\u{11FB0}', '\u{11FB0}', Script::Lisu), ('\u{11FC0}', '\u{11FF1}', Script::Tamil),
+    ('\u{11FFF}', '\u{11FFF}', Script::Tamil), ('\u{12000}', '\u{12399}', Script::Cuneiform),
+    ('\u{12400}', '\u{1246E}', Script::Cuneiform), ('\u{12470}', '\u{12474}', Script::Cuneiform),
+    ('\u{12480}', '\u{12543}', Script::Cuneiform), ('\u{12F90}', '\u{12FF2}', Script::CyproMinoan),
+    ('\u{13000}', '\u{13455}', Script::EgyptianHieroglyphs), ('\u{13460}', '\u{143FA}', Script::EgyptianHieroglyphs),
+    ('\u{14400}', '\u{14646}', Script::AnatolianHieroglyphs), ('\u{16100}', '\u{16139}', Script::GurungKhema),
+    ('\u{16800}', '\u{16A38}', Script::Bamum), ('\u{16A40}', '\u{16A5E}', Script::Mro),
+    ('\u{16A60}', '\u{16A69}', Script::Mro), ('\u{16A6E}', '\u{16A6F}', Script::Mro),
+    ('\u{16A70}', '\u{16ABE}', Script::Tangsa), ('\u{16AC0}', '\u{16AC9}', Script::Tangsa),
+    ('\u{16AD0}', '\u{16AED}', Script::BassaVah), ('\u{16AF0}', '\u{16AF5}', Script::BassaVah),
+    ('\u{16B00}', '\u{16B45}', Script::PahawhHmong), ('\u{16B50}', '\u{16B59}', Script::PahawhHmong),
+    ('\u{16B5B}', '\u{16B61}', Script::PahawhHmong), ('\u{16B63}', '\u{16B77}', Script::PahawhHmong),
+    ('\u{16B7D}', '\u{16B8F}', Script::PahawhHmong), ('\u{16D40}', '\u{16D79}', Script::KiratRai),
+    ('\u{16E40}', '\u{16E9A}', Script::Medefaidrin), ('\u{16EA0}', '\u{16EB8}', Script::BeriaErfe),
+    ('\u{16EBB}', '\u{16ED3}', Script::BeriaErfe), ('\u{16F00}', '\u{16F4A}', Script::Miao),
+    ('\u{16F4F}', '\u{16F87}', Script::Miao), ('\u{16F8F}', '\u{16F9F}', Script::Miao),
+    ('\u{16FE0}', '\u{16FE0}', Script::Tangut), ('\u{16FE1}', '\u{16FE1}', Script::Nushu),
+    ('\u{16FE2}', '\u{16FE3}', Script::Han), ('\u{16FE4}', '\u{16FE4}', Script::KhitanSmallScript),
+    ('\u{16FF0}', '\u{16FF6}', Script::Han), ('\u{17000}', '\u{18AFF}', Script::Tangut),
+    ('\u{18B00}', '\u{18CD5}', Script::KhitanSmallScript), ('\u{18CFF}', '\u{18CFF}', Script::KhitanSmallScript),
+    ('\u{18D00}', '\u{18D1E}', Script::Tangut), ('\u{18D80}', '\u{18DF2}', Script::Tangut),
+    ('\u{1AFF0}', '\u{1AFF3}', Script::Katakana), ('\u{1AFF5}', '\u{1AFFB}', Script::Katakana),
+    ('\u{1AFFD}', '\u{1AFFE}', Script::Katakana), ('\u{1B000}', '\u{1B000}', Script::Katakana),
+    ('\u{1B001}', '\u{1B11F}', Script::Hiragana), ('\u{1B120}', '\u{1B122}', Script::Katakana),
+    ('\u{1B132}', '\u{1B132}', Script::Hiragana), ('\u{1B150}', '\u{1B152}', Script::Hiragana),
+    ('\u{1B155}', '\u{1B155}', Script::Katakana), ('\u{1B164}', '\u{1B167}', Script::Katakana),
+    ('\u{1B170}', '\u{1B2FB}', Script::Nushu), ('\u{1BC00}', '\u{1BC6A}', Script::Duployan),
+    ('\u{1BC70}', '\u{1BC7C}', Script::Duployan), ('\u{1BC80}', '\u{1BC88}', Script::Duployan),
+    ('\u{1BC90}', '\u{1BC99}', Script::Duployan), ('\u{1BC9C}', '\u{1BC9F}', Script::Duployan),
+    ('\u{1BCA0}', '\u{1BCA3}', Script::Common), ('\u{1CC00}', '\u{1CCFC}', Script::Common),
+    ('\u{1CD00}', '\u{1CEB3}', Script::Common), ('\u{1CEBA}', '\u{1CED0}', Script::Common),
+    ('\u{1CEE0}', '\u{1CEF0}', Script::Common), ('\u{1CF00}', '\u{1CF2D}', Script::Inherited),
+    ('\u{1CF30}', '\u{1CF46}', Script::Inherited), ('\u{1CF50}', '\u{1CFC3}', Script::Common),
+    ('\u{1D000}', '\u{1D0F5}', Script::Common), ('\u{1D100}', '\u{1D126}', Script::Common),
+    ('\u{1D129}', '\u{1D166}', Script::Common), ('\u{1D167}', '\u{1D169}', Script::Inherited),
+    ('\u{1D16A}', '\u{1D17A}', Script::Common), ('\u{1D17B}', '\u{1D182}', Script::Inherited),
+    ('\u{1D183}', '\u{1D184}', Script::Common), ('\u{1D185}', '\u{1D18B}', Script::Inherited),
+    ('\u{1D18C}', '\u{1D1A9}', Script::Common), ('\u{1D1AA}', '\u{1D1AD}', Script::Inherited),
+    ('\u{1D1AE}', '\u{1D1EA}', Script::Common), ('\u{1D200}', '\u{1D245}', Script::Greek),
+    ('\u{1D2C0}', '\u{1D2D3}', Script::Common), ('\u{1D2E0}', '\u{1D2F3}', Script::Common),
+    ('\u{1D300}', '\u{1D356}', Script::Common), ('\u{1D360}', '\u{1D378}', Script::Common),
+    ('\u{1D400}', '\u{1D454}', Script::Common), ('\u{1D456}', '\u{1D49C}', Script::Common),
+    ('\u{1D49E}', '\u{1D49F}', Script::Common), ('\u{1D4A2}', '\u{1D4A2}', Script::Common),
+    ('\u{1D4A5}', '\u{1D4A6}', Script::Common), ('\u{1D4A9}', '\u{1D4AC}', Script::Common),
+    ('\u{1D4AE}', '\u{1D4B9}', Script::Common), ('\u{1D4BB}', '\u{1D4BB}', Script::Common),
+    ('\u{1D4BD}', '\u{1D4C3}', Script::Common), ('\u{1D4C5}', '\u{1D505}', Script::Common),
+    ('\u{1D507}', '\u{1D50A}', Script::Common), ('\u{1D50D}', '\u{1D514}', Script::Common),
+    ('\u{1D516}', '\u{1D51C}', Script::Common), ('\u{1D51E}', '\u{1D539}', Script::Common),
+    ('\u{1D53B}', '\u{1D53E}', Script::Common), ('\u{1D540}', '\u{1D544}', Script::Common),
+    ('\u{1D546}', '\u{1D546}', Script::Common), ('\u{1D54A}', '\u{1D550}', Script::Common),
+    ('\u{1D552}', '\u{1D6A5}', Script::Common), ('\u{1D6A8}', '\u{1D7CB}', Script::Common),
+    ('\u{1D7CE}', '\u{1D7FF}', Script::Common), ('\u{1D800}', '\u{1DA8B}', Script::SignWriting),
+    ('\u{1DA9B}', '\u{1DA9F}', Script::SignWriting), ('\u{1DAA1}', '\u{1DAAF}', Script::SignWriting),
+    ('\u{1DF00}', '\u{1DF1E}', Script::Latin), ('\u{1DF25}', '\u{1DF2A}', Script::Latin),
+    ('\u{1E000}', '\u{1E006}', Script::Glagolitic), ('\u{1E008}', '\u{1E018}', Script::Glagolitic),
+    ('\u{1E01B}', '\u{1E021}', Script::Glagolitic), ('\u{1E023}', '\u{1E024}', Script::Glagolitic),
+    ('\u{1E026}', '\u{1E02A}', Script::Glagolitic), ('\u{1E030}', '\u{1E06D}', Script::Cyrillic),
+    ('\u{1E08F}', '\u{1E08F}', Script::Cyrillic), ('\u{1E100}', '\u{1E12C}', Script::NyiakengPuachueHmong),
+    ('\u{1E130}', '\u{1E13D}', Script::NyiakengPuachueHmong), ('\u{1E140}', '\u{1E149}', Script::NyiakengPuachueHmong),
+    ('\u{1E14E}', '\u{1E14F}', Script::NyiakengPuachueHmong), ('\u{1E290}', '\u{1E2AE}', Script::Toto),
+    ('\u{1E2C0}', '\u{1E2F9}', Script::Wancho), ('\u{1E2FF}', '\u{1E2FF}', Script::Wancho),
+    ('\u{1E4D0}', '\u{1E4F9}', Script::NagMundari), ('\u{1E5D0}', '\u{1E5FA}', Script::OlOnal),
+    ('\u{1E5FF}', '\u{1E5FF}', Script::OlOnal), ('\u{1E6C0}', '\u{1E6DE}', Script::TaiYo),
+    ('\u{1E6E0}', '\u{1E6F5}', Script::TaiYo), ('\u{1E6FE}', '\u{1E6FF}', Script::TaiYo),
+    ('\u{1E7E0}', '\u{1E7E6}', Script::Ethiopic), ('\u{1E7E8}', '\u{1E7EB}', Script::Ethiopic),
+    ('\u{1E7ED}', '\u{1E7EE}', Script::Ethiopic), ('\u{1E7F0}', '\u{1E7FE}', Script::Ethiopic),
+    ('\u{1E800}', '\u{1E8C4}', Script::MendeKikakui), ('\u{1E8C7}', '\u{1E8D6}', Script::MendeKikakui),
+    ('\u{1E900}', '\u{1E94B}', Script::Adlam), ('\u{1E950}', '\u{1E959}', Script::Adlam),
+    ('\u{1E95E}', '\u{1E95F}', Script::Adlam), ('\u{1EC71}', '\u{1ECB4}', Script::Common),
+    ('\u{1ED01}', '\u{1ED3D}', Script::Common), ('\u{1EE00}', '\u{1EE03}', Script::Arabic),
+    ('\u{1EE05}', '\u{1EE1F}', Script::Arabic), ('\u{1EE21}', '\u{1EE22}', Script::Arabic),
+    ('\u{1EE24}', '\u{1EE24}', Script::Arabic), ('\u{1EE27}', '\u{1EE27}', Script::Arabic),
+    ('\u{1EE29}', '\u{1EE32}', Script::Arabic), ('\u{1EE34}', '\u{1EE37}', Script::Arabic),
+    ('\u{1EE39}', '\u{1EE39}', Script::Arabic), ('\u{1EE3B}', '\u{1EE3B}', Script::Arabic),
+    ('\u{1EE42}', '\u{1EE42}', Script::Arabic), ('\u{1EE47}', '\u{1EE47}', Script::Arabic),
+    ('\u{1EE49}', '\u{1EE49}', Script::Arabic), ('\u{1EE4B}', '\u{1EE4B}', Script::Arabic),
+    ('\u{1EE4D}', '\u{1EE4F}', Script::Arabic), ('\u{1EE51}', '\u{1EE52}', Script::Arabic),
+    ('\u{1EE54}', '\u{1EE54}', Script::Arabic), ('\u{1EE57}', '\u{1EE57}', Script::Arabic),
+    ('\u{1EE59}', '\u{1EE59}', Script::Arabic), ('\u{1EE5B}', '\u{1EE5B}', Script::Arabic),
+    ('\u{1EE5D}', '\u{1EE5D}', Script::Arabic), ('\u{1EE5F}', '\u{1EE5F}', Script::Arabic),
+    ('\u{1EE61}', '\u{1EE62}', Script::Arabic), ('\u{1EE64}', '\u{1EE64}', Script::Arabic),
+    ('\u{1EE67}', '\u{1EE6A}', Script::Arabic), ('\u{1EE6C}', '\u{1EE72}', Script::Arabic),
+    ('\u{1EE74}', '\u{1EE77}', Script::Arabic), ('\u{1EE79}', '\u{1EE7C}', Script::Arabic),
+    ('\u{1EE7E}', '\u{1EE7E}', Script::Arabic), ('\u{1EE80}', '\u{1EE89}', Script::Arabic),
+    ('\u{1EE8B}', '\u{1EE9B}', Script::Arabic), ('\u{1EEA1}', '\u{1EEA3}', Script::Arabic),
+    ('\u{1EEA5}', '\u{1EEA9}', Script::Arabic), ('\u{1EEAB}', '\u{1EEBB}', Script::Arabic),
+    ('\u{1EEF0}', '\u{1EEF1}', Script::Arabic), ('\u{1F000}', '\u{1F02B}', Script::Common),
+    ('\u{1F030}', '\u{1F093}', Script::Common), ('\u{1F0A0}', '\u{1F0AE}', Script::Common),
+    ('\u{1F0B1}', '\u{1F0BF}', Script::Common), ('\u{1F0C1}', '\u{1F0CF}', Script::Common),
+    ('\u{1F0D1}', '\u{1F0F5}', Script::Common), ('\u{1F100}', '\u{1F1AD}', Script::Common),
+    ('\u{1F1E6}', '\u{1F1FF}', Script::Common), ('\u{1F200}', '\u{1F200}', Script::Hiragana),
+    ('\u{1F201}', '\u{1F202}', Script::Common), ('\u{1F210}', '\u{1F23B}', Script::Common),
+    ('\u{1F240}', '\u{1F248}', Script::Common), ('\u{1F250}', '\u{1F251}', Script::Common),
+    ('\u{1F260}', '\u{1F265}', Script::Common), ('\u{1F300}', '\u{1F6D8}', Script::Common),
+    ('\u{1F6DC}', '\u{1F6EC}', Script::Common), ('\u{1F6F0}', '\u{1F6FC}', Script::Common),
+    ('\u{1F700}', '\u{1F7D9}', Script::Common), ('\u{1F7E0}', '\u{1F7EB}', Script::Common),
+    ('\u{1F7F0}', '\u{1F7F0}', Script::Common), ('\u{1F800}', '\u{1F80B}', Script::Common),
+    ('\u{1F810}', '\u{1F847}', Script::Common), ('\u{1F850}', '\u{1F859}', Script::Common),
+    ('\u{1F860}', '\u{1F887}', Script::Common), ('\u{1F890}', '\u{1F8AD}', Script::Common),
+    ('\u{1F8B0}', '\u{1F8BB}', Script::Common), ('\u{1F8C0}', '\u{1F8C1}', Script::Common),
+    ('\u{1F8D0}', '\u{1F8D8}', Script::Common), ('\u{1F900}', '\u{1FA57}', Script::Common),
+    ('\u{1FA60}', '\u{1FA6D}', Script::Common), ('\u{1FA70}', '\u{1FA7C}', Script::Common),
+    ('\u{1FA80}', '\u{1FA8A}', Script::Common), ('\u{1FA8E}', '\u{1FAC6}', Script::Common),
+    ('\u{1FAC8}', '\u{1FAC8}', Script::Common), ('\u{1FACD}', '\u{1FADC}', Script::Common),
+    ('\u{1FADF}', '\u{1FAEA}', Script::Common), ('\u{1FAEF}', '\u{1FAF8}', Script::Common),
+    ('\u{1FB00}', '\u{1FB92}', Script::Common), ('\u{1FB94}', '\u{1FBFA}', Script::Common),
+    ('\u{20000}', '\u{2A6DF}', Script::Han), ('\u{2A700}', '\u{2B81D}', Script::Han),
+    ('\u{2B820}', '\u{2CEAD}', Script::Han), ('\u{2CEB0}', '\u{2EBE0}', Script::Han),
+    ('\u{2EBF0}', '\u{2EE5D}', Script::Han), ('\u{2F800}', '\u{2FA1D}', Script::Han),
+    ('\u{30000}', '\u{3134A}', Script::Han), ('\u{31350}', '\u{33479}', Script::Han),
+    ('\u{E0001}', '\u{E0001}', Script::Common), ('\u{E0020}', '\u{E007F}', Script::Common),
+    ('\u{E0100}', '\u{E01EF}', Script::Inherited),
+];
+
+#[rustfmt::skip]
+static SCRIPT_EXTENSIONS_TABLE: &[(char, char, &[Script])] = &[
+    ('\u{00B7}', '\u{00B7}', &[Script::Avestan, Script::Carian, Script::Coptic, Script::Duployan, Script::Elbasan, Script::Georgian, Script::Glagolitic, Script::Gothic, Script::Greek, Script::GunjalaGondi, Script::Han, Script::Latin, Script::Lydian, Script::Mahajani, Script::OldPermic, Script::Shavian]),
+    ('\u{02BC}', '\u{02BC}', &[Script::Bengali, Script::Cyrillic, Script::Devanagari, Script::Latin, Script::Lisu, Script::Thai, Script::Toto]),
+    ('\u{02C7}', '\u{02C7}', &[Script::Bopomofo, Script::Latin]), ('\u{02C9}', '\u{02CB}', &[Script::Bopomofo, Script::Latin]),
+    ('\u{02CD}', '\u{02CD}', &[Script::Latin, Script::Lisu]), ('\u{02D7}', '\u{02D7}', &[Script::Latin, Script::Thai]),
+    ('\u{02D9}', '\u{02D9}', &[Script::Bopomofo, Script::Latin]), ('\u{0300}', '\u{0300}', &[Script::Cherokee, Script::Coptic, Script::Cyrillic, Script::Greek, Script::Latin, Script::OldPermic, Script::Sunuwar, Script::TaiLe]),
+    ('\u{0301}', '\u{0301}', &[Script::Cherokee, Script::Cyrillic, Script::Greek, Script::Latin, Script::Osage, Script::Sunuwar, Script::TaiLe, Script::Todhri]),
+    ('\u{0302}', '\u{0302}', &[Script::Cherokee, Script::Cyrillic, Script::Latin, Script::Tifinagh]),
+    ('\u{0303}', '\u{0303}', &[Script::Glagolitic, Script::Latin, Script::Sunuwar, Script::Syriac, Script::Thai]),
+    ('\u{0304}', '\u{0304}', &[Script::CaucasianAlbanian, Script::Cherokee, Script::Coptic, Script::Cyrillic, Script::Gothic, Script::Greek, Script::Latin, Script::Osage, Script::Syriac, Script::Tifinagh, Script::Todhri]),
+    ('\u{0305}', '\u{0305}', &[Script::Coptic, Script::Elbasan, Script::Glagolitic, Script::Gothic, Script::Katakana, Script::Latin]),
+    ('\u{0306}', '\u{0306}', &[Script::Cyrillic, Script::Greek, Script::Latin, Script::OldPermic, Script::Tifinagh]),
+    ('\u{0307}', '\u{0307}', &[Script::Coptic, Script::Duployan, Script::Hebrew, Script::Latin, Script::OldPermic, Script::Syriac, Script::TaiLe, Script::Tifinagh, Script::Todhri]),
+    ('\u{0308}', '\u{0308}', &[Script::Armenian, Script::Cyrillic, Script::Duployan, Script::Gothic, Script::Greek, Script::Hebrew, Script::Latin, Script::OldPermic, Script::Syriac, Script::TaiLe, Script::Tifinagh]),
+    ('\u{0309}', '\u{0309}', &[Script::Latin, Script::Tifinagh]), ('\u{030A}', '\u{030A}', &[Script::Duployan, Script::Latin, Script::Syriac]),
+    ('\u{030B}', '\u{030B}', &[Script::Cherokee, Script::Cyrillic, Script::Latin, Script::Osage]),
+    ('\u{030C}', '\u{030C}', &[Script::Cherokee, Script::Latin, Script::TaiLe]),
+    ('\u{030D}', '\u{030D}', &[Script::Latin, Script::Sunuwar]), ('\u{030E}', '\u{030E}', &[Script::Ethiopic, Script::Latin]),
+    ('\u{0310}', '\u{0310}', &[Script::Latin, Script::Sunuwar]), ('\u{0311}', '\u{0311}', &[Script::Cyrillic, Script::Latin, Script::Todhri]),
+    ('\u{0313}', '\u{0313}', &[Script::Greek, Script::Latin, Script::OldPermic, Script::Todhri]),
+    ('\u{0323}', '\u{0323}', &[Script::Cherokee, Script::Duployan, Script::Katakana, Script::Latin, Script::Syriac, Script::Tifinagh]),
+    ('\u{0324}', '\u{0324}', &[Script::Cherokee, Script::Duployan, Script::Latin, Script::Syriac]),
+    ('\u{0325}', '\u{0325}', &[Script::Latin, Script::Syriac]), ('\u{032D}', '\u{032D}', &[Script::Latin, Script::Sunuwar, Script::Syriac]),
+    ('\u{032E}', '\u{032E}', &[Script::Latin, Script::Syriac]), ('\u{0330}', '\u{0330}', &[Script::Cherokee, Script::Latin, Script::Syriac]),
+    ('\u{0331}', '\u{0331}', &[Script::CaucasianAlbanian, Script::Cherokee, Script::Gothic, Script::Latin, Script::Sunuwar, Script::Syriac, Script::Thai]),
+    ('\u{0342}', '\u{0342}', &[Script::Greek]), ('\u{0345}', '\u{0345}', &[Script::Greek]),
+    ('\u{0358}', '\u{0358}', &[Script::Latin, Script::Osage]), ('\u{035E}', '\u{035E}', &[Script::CaucasianAlbanian, Script::Latin, Script::Todhri]),
+    ('\u{0363}', '\u{036F}', &[Script::Latin]), ('\u{0374}', '\u{0375}', &[Script::Coptic, Script::Greek]),
+    ('\u{0483}', '\u{0483}', &[Script::Cyrillic, Script::OldPermic]), ('\u{0484}', '\u{0484}', &[Script::Cyrillic, Script::Glagolitic]),
+    ('\u{0485}', '\u{0486}', &[Script::Cyrillic, Script::Latin]), ('\u{0487}', '\u{0487}', &[Script::Cyrillic, Script::Glagolitic]),
+    ('\u{0589}', '\u{0589}', &[Script::Armenian, Script::Georgian, Script::Glagolitic]),
+    ('\u{060C}', '\u{060C}', &[Script::Arabic, Script::Garay, Script::HanifiRohingya, Script::Nko, Script::Syriac, Script::Thaana, Script::Yezidi]),
+    ('\u{061B}', '\u{061B}', &[Script::Arabic, Script::Garay, Script::HanifiRohingya, Script::Nko, Script::Syriac, Script::Thaana, Script::Yezidi]),
+    ('\u{061C}', '\u{061C}', &[Script::Arabic, Script::Syriac, Script::Thaana]),
+    ('\u{061F}', '\u{061F}', &[Script::Adlam, Script::Arabic, Script::Garay, Script::HanifiRohingya, Script::Nko, Script::Syriac, Script::Thaana, Script::Yezidi]),
+    ('\u{0640}', '\u{0640}', &[Script::Adlam, Script::Arabic, Script::HanifiRohingya, Script::Mandaic, Script::Manichaean, Script::OldUyghur, Script::PsalterPahlavi, Script::Sogdian, Script::Syriac]),
+    ('\u{064B}', '\u{0655}', &[Script::Arabic, Script::Syriac]), ('\u{0660}', '\u{0669}', &[Script::Arabic, Script::Thaana, Script::Yezidi]),
+    ('\u{0670}', '\u{0670}', &[Script::Arabic, Script::Syriac]), ('\u{06D4}', '\u{06D4}', &[Script::Arabic, Script::HanifiRohingya]),
+    ('\u{0951}', '\u{0951}', &[Script::Bengali, Script::Devanagari, Script::Grantha, Script::Gujarati, Script::Gurmukhi, Script::Kannada, Script::Latin, Script::Malayalam, Script::Nandinagari, Script::Newa, Script::Oriya, Script::Sharada, Script::Tamil, Script::Telugu, Script::Tirhuta]),
+    ('\u{0952}', '\u{0952}', &[Script::Bengali, Script::Devanagari, Script::Grantha, Script::Gujarati, Script::Gurmukhi, Script::Kannada, Script::Latin, Script::Malayalam, Script::Newa, Script::Oriya, Script::Tamil, Script::Telugu, Script::Tirhuta]),
+    ('\u{0964}', '\u{0964}', &[Script::Bengali, Script::Devanagari, Script::Dogra, Script::Grantha, Script::Gujarati, Script::GunjalaGondi, Script::Gurmukhi, Script::Kannada, Script::Khudawadi, Script::Mahajani, Script::Malayalam, Script::MasaramGondi, Script::Nandinagari, Script::OlOnal, Script::Oriya, Script::Sinhala, Script::SylotiNagri, Script::Takri, Script::Tamil, Script::Telugu, Script::Tirhuta]),
+    ('\u{0965}', '\u{0965}', &[Script::Bengali, Script::Devanagari, Script::Dogra, Script::Grantha, Script::Gujarati, Script::GunjalaGondi, Script::Gurmukhi, Script::GurungKhema, Script::Kannada, Script::Khudawadi, Script::Limbu, Script::Mahajani, Script::Malayalam, Script::MasaramGondi, Script::Nandinagari, Script::OlOnal, Script::Oriya, Script::Sinhala, Script::SylotiNagri, Script::Takri, Script::Tamil, Script::Telugu, Script::Tirhuta]),
+    ('\u{0966}', '\u{096F}', &[Script::Devanagari, Script::Dogra, Script::Kaithi, Script::Mahajani]),
+    ('\u{09E6}', '\u{09EF}', &[Script::Bengali, Script::Chakma, Script::SylotiNagri]),
+    ('\u{0A66}', '\u{0A6F}', &[Script::Gurmukhi, Script::Multani]), ('\u{0AE6}', '\u{0AEF}', &[Script::Gujarati, Script::Khojki]),
+    ('\u{0BE6}', '\u{0BF3}', &[Script::Grantha, Script::Tamil]), ('\u{0CE6}', '\u{0CEF}', &[Script::Kannada, Script::Nandinagari, Script::TuluTigalari]),
+    ('\u{1040}', '\u{1049}', &[Script::Chakma, Script::Myanmar, Script::TaiLe]),
+    ('\u{10FB}', '\u{10FB}', &[Script::Georgian, Script::Glagolitic, Script::Latin]),
+    ('\u{16EB}', '\u{16ED}', &[Script::Runic]), ('\u{1735}', '\u{1736}', &[Script::Buhid, Script::Hanunoo, Script::Tagalog, Script::Tagbanwa]),
+    ('\u{1802}', '\u{1803}', &[Script::Mongolian, Script::PhagsPa]), ('\u{1805}', '\u{1805}', &[Script::Mongolian, Script::PhagsPa]),
+    ('\u{1CD0}', '\u{1CD0}', &[Script::Bengali, Script::Devanagari, Script::Grantha, Script::Kannada]),
+    ('\u{1CD1}', '\u{1CD1}', &[Script::Devanagari]), ('\u{1CD2}', '\u{1CD2}', &[Script::Bengali, Script::Devanagari, Script::Grantha, Script::Kannada]),
+    ('\u{1CD3}', '\u{1CD3}', &[Script::Devanagari, Script::Grantha, Script::Kannada]),
+    ('\u{1CD4}', '\u{1CD4}', &[Script::Devanagari]), ('\u{1CD5}', '\u{1CD5}', &[Script::Bengali, Script::Devanagari, Script::Newa, Script::Telugu, Script::Tirhuta]),
+    ('\u{1CD6}', '\u{1CD6}', &[Script::Bengali, Script::Devanagari, Script::Telugu]),
+    ('\u{1CD7}', '\u{1CD7}', &[Script::Devanagari, Script::Newa, Script::Sharada]),
+    ('\u{1CD8}', '\u{1CD8}', &[Script::Bengali, Script::Devanagari, Script::Newa, Script::Telugu]),
+    ('\u{1CD9}', '\u{1CD9}', &[Script::Devanagari, Script::Sharada]), ('\u{1CDA}', '\u{1CDA}', &[Script::Devanagari, Script::Kannada, Script::Malayalam, Script::Oriya, Script::Tamil, Script::Telugu]),
+    ('\u{1CDB}', '\u{1CDB}', &[Script::Devanagari]), ('\u{1CDC}', '\u{1CDD}', &[Script::Devanagari, Script::Sharada]),
+    ('\u{1CDE}', '\u{1CDF}', &[Script::Devanagari]), ('\u{1CE0}', '\u{1CE0}', &[Script::Devanagari, Script::Sharada]),
+    ('\u{1CE1}', '\u{1CE1}', &[Script::Bengali, Script::Devanagari]), ('\u{1CE2}', '\u{1CE2}', &[Script::Devanagari, Script::Newa, Script::Tirhuta]),
+    ('\u{1CE3}', '\u{1CE8}', &[Script::Devanagari]), ('\u{1CE9}', '\u{1CE9}', &[Script::Devanagari, Script::Nandinagari, Script::Newa]),
+    ('\u{1CEA}', '\u{1CEA}', &[Script::Bengali, Script::Devanagari, Script::Sharada]),
+    ('\u{1CEB}', '\u{1CEB}', &[Script::Devanagari, Script::Newa]), ('\u{1CEC}', '\u{1CEC}', &[Script::Devanagari]),
+    ('\u{1CED}', '\u{1CED}', &[Script::Bengali, Script::Devanagari, Script::Newa, Script::Sharada]),
+    ('\u{1CEE}', '\u{1CF1}', &[Script::Devanagari]), ('\u{1CF2}', '\u{1CF2}', &[Script::Bengali, Script::Devanagari, Script::Grantha, Script::Kannada, Script::Malayalam, Script::Nandinagari, Script::Oriya, Script::Sinhala, Script::Telugu, Script::Tirhuta, Script::TuluTigalari]),
+    ('\u{1CF3}', '\u{1CF3}', &[Script::Devanagari, Script::Grantha]), ('\u{1CF4}', '\u{1CF4}', &[Script::Devanagari, Script::Grantha, Script::Kannada, Script::TuluTigalari]),
+    ('\u{1CF5}', '\u{1CF6}', &[Script::Bengali, Script::Devanagari]), ('\u{1CF7}', '\u{1CF7}', &[Script::Bengali]),
+    ('\u{1CF8}', '\u{1CF9}', &[Script::Devanagari, Script::Grantha]), ('\u{1CFA}', '\u{1CFA}', &[Script::Nandinagari]),
+    ('\u{1DC0}', '\u{1DC1}', &[Script::Greek]), ('\u{1DF8}', '\u{1DF8}', &[Script::Cyrillic, Script::Latin, Script::Syriac]),
+    ('\u{1DFA}', '\u{1DFA}', &[Script::Syriac]), ('\u{202F}', '\u{202F}', &[Script::Latin, Script::Mongolian, Script::PhagsPa]),
+    ('\u{204F}', '\u{204F}', &[Script::Adlam, Script::Arabic]), ('\u{205A}', '\u{205A}', &[Script::Carian, Script::Georgian, Script::Glagolitic, Script::Lycian, Script::OldHungarian, Script::OldTurkic]),
+    ('\u{205D}', '\u{205D}', &[Script::Carian, Script::Greek, Script::MeroiticHieroglyphs, Script::OldHungarian]),
+    ('\u{20F0}', '\u{20F0}', &[Script::Devanagari, Script::Grantha, Script::Latin]),
+    ('\u{2E17}', '\u{2E17}', &[Script::Coptic, Script::Latin]), ('\u{2E30}', '\u{2E30}', &[Script::Avestan, Script::OldTurkic]),
+    ('\u{2E31}', '\u{2E31}', &[Script::Avestan, Script::Carian, Script::Georgian, Script::Kaithi, Script::Lydian, Script::OldHungarian, Script::Samaritan]),
+    ('\u{2E3C}', '\u{2E3C}', &[Script::Duployan]), ('\u{2E41}', '\u{2E41}', &[Script::Adlam, Script::Arabic, Script::OldHungarian]),
+    ('\u{2E43}', '\u{2E43}', &[Script::Cyrillic, Script::Glagolitic]), ('\u{2FF0}', '\u{2FFF}', &[Script::Han, Script::Tangut]),
+    ('\u{3001}', '\u{3001}', &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana, Script::Mongolian, Script::Yi]),
+    ('\u{3002}', '\u{3002}', &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana, Script::Mongolian, Script::PhagsPa, Script::Yi]),
+    ('\u{3003}', '\u{3003}', &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana]),
+    ('\u{3006}', '\u{3006}', &[Script::Han]), ('\u{3008}', '\u{3009}', &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana, Script::Mongolian, Script::Tibetan, Script::Yi]),
+    ('\u{300A}', '\u{300B}', &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana, Script::Lisu, Script::Mongolian, Script::Tibetan, Script::Yi]),
+    ('\u{300C}', '\u{3011}', &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana, Script::Yi]),
+    ('\u{3013}', '\u{3013}', &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana]),
+    ('\u{3014}', '\u{301B}', &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana, Script::Yi]),
+    ('\u{301C}', '\u{301F}', &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana]),
+    ('\u{302A}', '\u{302D}', &[Script::Bopomofo, Script::Han]), ('\u{3030}', '\u{3030}', &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana]),
+    ('\u{3031}', '\u{3035}', &[Script::Hiragana, Script::Katakana]), ('\u{3037}', '\u{3037}', &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana]),
+    ('\u{303C}', '\u{303D}', &[Script::Han, Script::Hiragana, Script::Katakana]),
+    ('\u{303E}', '\u{303F}', &[Script::Han]), ('\u{3099}', '\u{309C}', &[Script::Hiragana, Script::Katakana]),
+    ('\u{30A0}', '\u{30A0}', &[Script::Hiragana, Script::Katakana]), ('\u{30FB}', '\u{30FB}', &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana, Script::Yi]),
+    ('\u{30FC}', '\u{30FC}', &[Script::Hiragana, Script::Katakana]), ('\u{3190}', '\u{319F}', &[Script::Han]),
+    ('\u{31C0}', '\u{31E5}', &[Script::Han]), ('\u{31EF}', '\u{31EF}', &[Script::Han, Script::Tangut]),
+    ('\u{3220}', '\u{3247}', &[Script::Han]), ('\u{3280}', '\u{32B0}', &[Script::Han]),
+    ('\u{32C0}', '\u{32CB}', &[Script::Han]), ('\u{32FF}', '\u{32FF}', &[Script::Han]),
+    ('\u{3358}', '\u{3370}', &[Script::Han]), ('\u{337B}', '\u{337F}', &[Script::Han]),
+    ('\u{33E0}', '\u{33FE}', &[Script::Han]), ('\u{A66F}', '\u{A66F}', &[Script::Cyrillic, Script::Glagolitic]),
+    ('\u{A700}', '\u{A707}', &[Script::Han, Script::Latin]), ('\u{A830}', '\u{A832}', &[Script::Devanagari, Script::Dogra, Script::Gujarati, Script::Gurmukhi, Script::Kaithi, Script::Kannada, Script::Khojki, Script::Khudawadi, Script::Mahajani, Script::Malayalam, Script::Modi, Script::Nandinagari, Script::Sharada, Script::Takri, Script::Tirhuta, Script::TuluTigalari]),
+    ('\u{A833}', '\u{A835}', &[Script::Devanagari, Script::Dogra, Script::Gujarati, Script::Gurmukhi, Script::Kaithi, Script::Kannada, Script::Khojki, Script::Khudawadi, Script::Mahajani, Script::Modi, Script::Nandinagari, Script::Sharada, Script::Takri, Script::Tirhuta, Script::TuluTigalari]),
+    ('\u{A836}', '\u{A837}', &[Script::Devanagari, Script::Dogra, Script::Gujarati, Script::Gurmukhi, Script::Kaithi, Script::Khojki, Script::Khudawadi, Script::Mahajani, Script::Modi, Script::Takri, Script::Tirhuta]),
+    ('\u{A838}', '\u{A838}', &[Script::Devanagari, Script::Dogra, Script::Gujarati, Script::Gurmukhi, Script::Kaithi, Script::Khojki, Script::Khudawadi, Script::Mahajani, Script::Modi, Script::Sharada, Script::Takri, Script::Tirhuta]),
+    ('\u{A839}', '\u{A839}', &[Script::Devanagari, Script::Dogra, Script::Gujarati, Script::Gurmukhi, Script::Kaithi, Script::Khojki, Script::Khudawadi, Script::Mahajani, Script::Modi, Script::Takri, Script::Tirhuta]),
+    ('\u{A8F1}', '\u{A8F1}', &[Script::Bengali, Script::Devanagari, Script::TuluTigalari]),
+    ('\u{A8F3}', '\u{A8F3}', &[Script::Devanagari, Script::Tamil]), ('\u{A92E}', '\u{A92E}', &[Script::KayahLi, Script::Latin, Script::Myanmar]),
+    ('\u{A9CF}', '\u{A9CF}', &[Script::Buginese, Script::Javanese]), ('\u{FD3E}', '\u{FD3F}', &[Script::Arabic, Script::Nko]),
+    ('\u{FDF2}', '\u{FDF2}', &[Script::Arabic, Script::Thaana]), ('\u{FDFD}', '\u{FDFD}', &[Script::Arabic, Script::Thaana]),
+    ('\u{FE45}', '\u{FE46}', &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana]),
+    ('\u{FF61}', '\u{FF65}', &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana, Script::Yi]),
+    ('\u{FF70}', '\u{FF70}', &[Script::Hiragana, Script::Katakana]), ('\u{FF9E}', '\u{FF9F}', &[Script::Hiragana, Script::Katakana]),
+    ('\u{10100}', '\u{10101}', &[Script::Cypriot, Script::CyproMinoan, Script::LinearB]),
+    ('\u{10102}', '\u{10102}', &[Script::Cypriot, Script::LinearB]), ('\u{10107}', '\u{10133}', &[Script::Cypriot, Script::LinearA, Script::LinearB]),
+    ('\u{10137}', '\u{1013F}', &[Script::Cypriot, Script::LinearB]), ('\u{102E0}', '\u{102FB}', &[Script::Arabic, Script::Coptic]),
+    ('\u{10AF2}', '\u{10AF2}', &[Script::Manichaean, Script::OldUyghur]), ('\u{11301}', '\u{11301}', &[Script::Grantha, Script::Tamil]),
+    ('\u{11303}', '\u{11303}', &[Script::Grantha, Script::Tamil]), ('\u{1133B}', '\u{1133C}', &[Script::Grantha, Script::Tamil]),
+    ('\u{11FD0}', '\u{11FD1}', &[Script::Grantha, Script::Tamil]), ('\u{11FD3}', '\u{11FD3}', &[Script::Grantha, Script::Tamil]),
+    ('\u{1BCA0}', '\u{1BCA3}', &[Script::Duployan]), ('\u{1D360}', '\u{1D371}', &[Script::Han]),
+    ('\u{1F250}', '\u{1F251}', &[Script::Han]),
+];
+
+/// The version of the [Unicode Character Database](https://www.unicode.org/ucd/)
+/// that the character tables of this module have been generated from.
+///
+/// The value is a triple of the major, minor, and update version numbers.
+pub const UNICODE_VERSION: (u8, u8, u8) = (17, 0, 0);
+
+/// The checksums of the character tables of this module.
+///
+/// Each entry is a pair of the table name and the checksum of the table's
+/// data. A checksum changes whenever the Unicode Character Database update
+/// changes the content of the corresponding table, even if
+/// the [UNICODE_VERSION] stays the same.
+pub const UCD_CHECKSUMS: &[(&str, u64)] = &[
+    ("ALPHABETIC_TABLE", 0x70476BD7BB12F438),
+    ("EXT_PICT_TABLE", 0xC9624A211177049B),
+    ("LOWERCASE_TABLE", 0x67CA4AF6239993DC),
+    ("NUM_TABLE", 0x60C7C98B1E5FB9B6),
+    ("WHITE_SPACE_TABLE", 0x928A95B72E75458E),
+    ("UPPERCASE_TABLE", 0x338D3EF3A0DBA010),
+    ("XID_CONTINUE_TABLE", 0xCDEB32E924E73EF3),
+    ("XID_START_TABLE", 0xAF5BA033738AF64B),
+    ("LOWER_CASE_TABLE", 0xFC4FCCD746434366),
+    ("TITLE_CASE_TABLE", 0xD79E93C9A8C609B9),
+    ("UPPER_CASE_TABLE", 0x3C4D08A71C9CB00C),
+    ("GRAPHEME_CLUSTER_BREAK_TABLE", 0x2D31A0E55241024D),
+    ("INDIC_CONJUNCT_BREAK_TABLE", 0x4FE94D0FE9493F6E),
+    ("SCRIPT_TABLE", 0xDFC7AC61F653D968),
+    ("SCRIPT_EXTENSIONS_TABLE", 0x9275A9CD0943EFB8),
+];
+
+/// Returns the version of the Unicode Character Database that
+/// the [Char] functions are based on.
+///
+/// This function returns the [UNICODE_VERSION] value.
+#[inline(always)]
+pub const fn ucd_version() -> (u8, u8, u8) {
+    UNICODE_VERSION
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Char;
+    use super::*;
 
     #[test]
     fn test_char_properties() {
@@ -2328,4 +7086,251 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_case_mappings() {
+        assert_eq!('a'.to_upper_simple(), 'A');
+        assert_eq!('A'.to_lower_simple(), 'a');
+        assert_eq!('1'.to_upper_simple(), '1');
+        assert_eq!('\u{1C6}'.to_title_simple(), '\u{1C5}');
+        assert_eq!('\u{1C6}'.to_upper_simple(), '\u{1C4}');
+        assert_eq!('\u{DF}'.to_upper_simple(), '\u{DF}');
+        assert_eq!('\u{DF}'.to_upper_full().to_string(), "SS");
+        assert_eq!('\u{DF}'.to_title_full().to_string(), "Ss");
+        assert_eq!('\u{DF}'.to_lower_full().to_string(), "\u{DF}");
+        assert_eq!('\u{130}'.to_lower_simple(), 'i');
+        assert_eq!('\u{130}'.to_lower_full().to_string(), "i\u{307}");
+        assert_eq!('\u{1F80}'.to_upper_full().len(), 2);
+        assert_eq!('\0'.to_lower_full().collect::<Vec<_>>(), vec!['\0']);
+
+        for ch in '\0'..'\u{10ffff}' {
+            let mut full = ch.to_upper_full();
+
+            if full.len() == 1 {
+                assert_eq!(full.next(), Some(ch.to_upper_simple()));
+            }
+
+            let mut full = ch.to_lower_full();
+
+            if full.len() == 1 {
+                assert_eq!(full.next(), Some(ch.to_lower_simple()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_grapheme_cluster_break() {
+        assert_eq!('a'.grapheme_cluster_break(), GraphemeClusterBreak::Other);
+        assert_eq!('\r'.grapheme_cluster_break(), GraphemeClusterBreak::CR);
+        assert_eq!('\n'.grapheme_cluster_break(), GraphemeClusterBreak::LF);
+        assert_eq!('\0'.grapheme_cluster_break(), GraphemeClusterBreak::Control);
+        assert_eq!(
+            '\u{301}'.grapheme_cluster_break(),
+            GraphemeClusterBreak::Extend,
+        );
+        assert_eq!(
+            '\u{200D}'.grapheme_cluster_break(),
+            GraphemeClusterBreak::ZWJ,
+        );
+        assert_eq!('\u{1100}'.grapheme_cluster_break(), GraphemeClusterBreak::L);
+        assert_eq!(
+            '\u{AC00}'.grapheme_cluster_break(),
+            GraphemeClusterBreak::LV,
+        );
+        assert_eq!(
+            '\u{AC01}'.grapheme_cluster_break(),
+            GraphemeClusterBreak::LVT,
+        );
+        assert_eq!(
+            '\u{1F1E6}'.grapheme_cluster_break(),
+            GraphemeClusterBreak::RegionalIndicator,
+        );
+        assert!('\u{1F600}'.is_ext_pict());
+        assert!(!'a'.is_ext_pict());
+    }
+
+    #[test]
+    fn test_indic_conjunct_break() {
+        assert_eq!('a'.indic_conjunct_break(), IndicConjunctBreak::None);
+        assert_eq!(
+            '\u{915}'.indic_conjunct_break(),
+            IndicConjunctBreak::Consonant,
+        );
+        assert_eq!('\u{94D}'.indic_conjunct_break(), IndicConjunctBreak::Linker);
+        assert_eq!('\u{93C}'.indic_conjunct_break(), IndicConjunctBreak::Extend);
+        assert_eq!(
+            '\u{200D}'.indic_conjunct_break(),
+            IndicConjunctBreak::Extend,
+        );
+    }
+
+    #[test]
+    fn test_scripts() {
+        assert_eq!('a'.script(), Script::Latin);
+        assert_eq!('\u{430}'.script(), Script::Cyrillic);
+        assert_eq!('1'.script(), Script::Common);
+        assert_eq!('\u{301}'.script(), Script::Inherited);
+        assert_eq!('\u{10FFFF}'.script(), Script::Unknown);
+        assert_eq!('a'.script_extensions(), &[Script::Latin]);
+        assert_eq!('\u{640}'.script(), Script::Common);
+        assert!('\u{640}'.has_script(Script::Arabic));
+        assert!('\u{640}'.has_script(Script::Syriac));
+        assert!(!'\u{640}'.has_script(Script::Latin));
+
+        assert_eq!(Script::OldItalic.name(), "Old_Italic");
+        assert_eq!(Script::OldItalic.code(), "Ital");
+        assert_eq!(Script::from_name("Old_Italic"), Some(Script::OldItalic));
+        assert_eq!(Script::from_name("OldItalic"), Some(Script::OldItalic));
+        assert_eq!(Script::from_name("Ital"), Some(Script::OldItalic));
+        assert_eq!(Script::from_name("Foo"), None);
+
+        for (index, script) in SCRIPTS.iter().enumerate() {
+            assert_eq!(*script as usize, index);
+        }
+
+        let props = CharProperties::new().with_script(Script::Cyrillic);
+
+        assert!('\u{430}'.has_properties(&props));
+        assert!(!'a'.has_properties(&props));
+        assert_eq!(props.to_string(), "$script(Cyrillic)");
+    }
+
+    #[test]
+    fn test_ucd_checksums() {
+        let tables = [
+            ("ALPHABETIC_TABLE", trie_checksum(&ALPHABETIC_TABLE)),
+            ("EXT_PICT_TABLE", trie_checksum(&EXT_PICT_TABLE)),
+            ("LOWERCASE_TABLE", trie_checksum(&LOWERCASE_TABLE)),
+            ("NUM_TABLE", trie_checksum(&NUM_TABLE)),
+            ("WHITE_SPACE_TABLE", trie_checksum(&WHITE_SPACE_TABLE)),
+            ("UPPERCASE_TABLE", trie_checksum(&UPPERCASE_TABLE)),
+            ("XID_CONTINUE_TABLE", trie_checksum(&XID_CONTINUE_TABLE)),
+            ("XID_START_TABLE", trie_checksum(&XID_START_TABLE)),
+            ("LOWER_CASE_TABLE", case_table_checksum(&LOWER_CASE_TABLE)),
+            ("TITLE_CASE_TABLE", case_table_checksum(&TITLE_CASE_TABLE)),
+            ("UPPER_CASE_TABLE", case_table_checksum(&UPPER_CASE_TABLE)),
+            (
+                "GRAPHEME_CLUSTER_BREAK_TABLE",
+                grapheme_cluster_break_checksum(),
+            ),
+            (
+                "INDIC_CONJUNCT_BREAK_TABLE",
+                indic_conjunct_break_checksum(),
+            ),
+            ("SCRIPT_TABLE", script_checksum()),
+            ("SCRIPT_EXTENSIONS_TABLE", script_extensions_checksum()),
+        ];
+
+        assert_eq!(ucd_version(), UNICODE_VERSION);
+        assert_eq!(UCD_CHECKSUMS, tables);
+    }
+
+    fn trie_checksum(trie: &UCDTrie) -> u64 {
+        let mut checksum = CHECKSUM_OFFSET;
+
+        for chunk in trie.r1.iter() {
+            checksum = update_checksum(checksum, *chunk);
+        }
+
+        for chunk in trie.r2.iter() {
+            checksum = update_checksum(checksum, *chunk as u64);
+        }
+
+        for chunk in trie.r3.iter() {
+            checksum = update_checksum(checksum, *chunk);
+        }
+
+        for chunk in trie.r4.iter() {
+            checksum = update_checksum(checksum, *chunk as u64);
+        }
+
+        for chunk in trie.r5.iter() {
+            checksum = update_checksum(checksum, *chunk as u64);
+        }
+
+        for chunk in trie.r6.iter() {
+            checksum = update_checksum(checksum, *chunk);
+        }
+
+        checksum
+    }
+
+    fn case_table_checksum(table: &UCDCaseTable) -> u64 {
+        let mut checksum = CHECKSUM_OFFSET;
+
+        for (from, to) in table.simple {
+            checksum = update_checksum(checksum, *from as u64);
+            checksum = update_checksum(checksum, *to as u64);
+        }
+
+        for (from, to) in table.full {
+            checksum = update_checksum(checksum, *from as u64);
+
+            for ch in to {
+                checksum = update_checksum(checksum, *ch as u64);
+            }
+        }
+
+        checksum
+    }
+
+    fn grapheme_cluster_break_checksum() -> u64 {
+        let mut checksum = CHECKSUM_OFFSET;
+
+        for (from, to, value) in GRAPHEME_CLUSTER_BREAK_TABLE {
+            checksum = update_checksum(checksum, *from as u64);
+            checksum = update_checksum(checksum, *to as u64);
+            checksum = update_checksum(checksum, *value as u64);
+        }
+
+        checksum
+    }
+
+    fn indic_conjunct_break_checksum() -> u64 {
+        let mut checksum = CHECKSUM_OFFSET;
+
+        for (from, to, value) in INDIC_CONJUNCT_BREAK_TABLE {
+            checksum = update_checksum(checksum, *from as u64);
+            checksum = update_checksum(checksum, *to as u64);
+            checksum = update_checksum(checksum, *value as u64);
+        }
+
+        checksum
+    }
+
+    fn script_checksum() -> u64 {
+        let mut checksum = CHECKSUM_OFFSET;
+
+        for (from, to, script) in SCRIPT_TABLE {
+            checksum = update_checksum(checksum, *from as u64);
+            checksum = update_checksum(checksum, *to as u64);
+            checksum = update_checksum(checksum, *script as u64);
+        }
+
+        checksum
+    }
+
+    fn script_extensions_checksum() -> u64 {
+        let mut checksum = CHECKSUM_OFFSET;
+
+        for (from, to, scripts) in SCRIPT_EXTENSIONS_TABLE {
+            checksum = update_checksum(checksum, *from as u64);
+            checksum = update_checksum(checksum, *to as u64);
+            checksum = update_checksum(checksum, scripts.len() as u64);
+
+            for script in *scripts {
+                checksum = update_checksum(checksum, *script as u64);
+            }
+        }
+
+        checksum
+    }
+
+    const CHECKSUM_OFFSET: u64 = 0xCBF29CE484222325;
+    const CHECKSUM_PRIME: u64 = 0x100000001B3;
+
+    #[inline(always)]
+    fn update_checksum(checksum: u64, word: u64) -> u64 {
+        (checksum ^ word).wrapping_mul(CHECKSUM_PRIME)
+    }
 }
@@ -36,6 +36,7 @@
 #![allow(warnings)]
 #![allow(unused_unsafe)]
 #![deny(missing_docs)]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 //! # Lady Deirdre API Documentation
 //!
@@ -55,9 +56,49 @@
 //! version = "2.0"
 //! ```
 //!
-//! This crate does not have any configuration features or third-party
-//! dependencies, except for the Rust standard library and the accompanying
-//! macro derive crate. Therefore, no additional preparations are needed.
+//! This crate does not have any third-party dependencies, except for
+//! the Rust standard library and the accompanying macro derive crate.
+//! Therefore, no additional preparations are needed.
+//!
+//! ## Configuration Features
+//!
+//! - `std` (enabled by default). Enables the components that depend on
+//!   the Rust standard library: the [analysis] framework, the [sync]
+//!   primitives, the [format] tools, the compilation unit
+//!   [identifier names](arena::Id::set_name), and the [Snippet](format::Snippet)
+//!   based display functions of the lexis and syntax objects.
+//!
+//!   Without this feature, the crate is `#![no_std]` and only requires
+//!   the [alloc](https://doc.rust-lang.org/alloc/) crate. The lexical scanners,
+//!   the syntax parsers (including the code generated by the derive macros),
+//!   the [arena] module, and the [compilation units](units) remain available.
+//!
+//! - `serde` (disabled by default). Enables the serialization support of
+//!   the objects that can be persisted, such as [Id](arena::Id),
+//!   [Entry](arena::Entry), and the [Document](units::Document) (see
+//!   [Restored](units::Restored) for details).
+//!
+//! - `lsp` (disabled by default, requires `std`). Enables the [lsp] module
+//!   of the Language Server Protocol helpers.
+//!
+//! - `log` (disabled by default). Enables
+//!   the [LogInstrumentation](units::LogInstrumentation) adapter that
+//!   forwards the [instrumentation](units::InstrumentationSink) events of
+//!   the reparser and the analyzer to the [log](https://docs.rs/log) crate.
+//!
+//! - `testing` (disabled by default). Enables the [testing] module of
+//!   the grammar-driven random document and edit generators for the fuzz and
+//!   the differential testing of the incremental reparser, and the syntax
+//!   tree snapshot helpers including the [assert_tree] macro.
+//!
+//! - `large-pages` (disabled by default). Increases the branching factors of
+//!   the token storage of the [mutable](units::MutableUnit) compilation units.
+//!   By default, the storage is tuned for the documents that are being edited
+//!   frequently: the pages are small, which keeps the cost of the individual
+//!   edits low. With this feature, the storage tree becomes shallower, and
+//!   the pages hold more tokens, which speeds up the cursor iteration and
+//!   the random access to the tokens of large documents, but makes each edit
+//!   more expensive.
 //!
 //! ## Web Assembly Builds
 //!
@@ -97,6 +138,7 @@
 /// You can find the detailed specification of the framework features under the
 /// [Analyzer](analysis::Analyzer) object documentation. This object is an
 /// entry point of the framework.
+#[cfg(feature = "std")]
 pub mod analysis;
 
 /// Memory management utilities.
@@ -115,6 +157,13 @@ pub mod analysis;
 /// You can use the [Repo](arena::Repo) object for various purposes depending
 /// on the needs.
 ///
+/// The keys are versioned: a key of the removed value never addresses
+/// the values inserted into the repository later, even if the repository
+/// reuses the memory of the removed value. This guarantee holds for
+/// the entire lifetime of the repository. The repository version never wraps
+/// around; instead, a repository that exhausted its versions stops reusing
+/// the memory of removed values.
+///
 /// Additionally, the arena module has an [Id](arena::Id) object, instances of
 /// which are globally unique (within the current process) and identify
 /// individual compilation units.
@@ -133,6 +182,7 @@ pub mod arena;
 /// Finally, this module provides a set of features to stylize terminal strings
 /// within the [TerminalString](format::TerminalString) trait and related
 /// components.
+#[cfg(feature = "std")]
 pub mod format;
 
 /// Building blocks of the lexical structure of compilation units.
@@ -154,6 +204,31 @@ pub mod format;
 /// under the [LexisSession](lexis::LexisSession) trait.
 pub mod lexis;
 
+/// Helpers for the Language Server Protocol implementations.
+///
+/// This module provides conversions between the Lady Deirdre objects and
+/// plain structures in the shape of the LSP objects:
+///
+///  - [LspPosition](lsp::LspPosition) and [LspRange](lsp::LspRange)
+///    convert source code sites and spans into the LSP line-character
+///    positions and back, respecting the negotiated
+///    [PositionEncoding](lsp::PositionEncoding).
+///  - [LspDiagnostic](lsp::LspDiagnostic) converts
+///    the [Diagnostic](format::Diagnostic) objects and
+///    the [syntax errors](syntax::SyntaxError) into the LSP diagnostics.
+///  - [ContentChange](lsp::ContentChange) represents the LSP document text
+///    synchronization events. The [apply_changes](lsp::apply_changes) and
+///    the [write_changes_to_doc](lsp::write_changes_to_doc) functions apply
+///    batches of these events to the documents.
+///
+/// This module does not depend on any LSP library. When the `serde` feature
+/// is enabled, the structures are (de)serializable into the corresponding LSP
+/// JSON shapes.
+///
+/// This module is available under the `lsp` feature only.
+#[cfg(feature = "lsp")]
+pub mod lsp;
+
 /// Synchronization primitives useful for compilers.
 ///
 /// These primitives enrich the set of  [std::sync] objects, which you may find
@@ -168,6 +243,7 @@ pub mod lexis;
 ///    This object is similar to the DashMap from the dashmap crate.
 ///  - [Trigger](sync::Trigger) is an atomic bool flag that you can set only
 ///    once to signalize a task to gracefully finish its job.
+#[cfg(feature = "std")]
 pub mod sync;
 
 /// Building blocks of the syntax structure of compilation units.
@@ -188,6 +264,29 @@ pub mod sync;
 /// under the [SyntaxSession](syntax::SyntaxSession) trait.
 pub mod syntax;
 
+/// Grammar-driven random input generators for testing purposes.
+///
+/// The [GrammarGen](testing::GrammarGen) generates syntactically valid random
+/// documents of the programming language described by
+/// the [GenGrammar](testing::GenGrammar), and random incremental edits of
+/// these documents (insertions, deletions, and replacements) that keep
+/// the documents valid. The generator is deterministic with respect to
+/// the [seed](testing::GenConfig::seed) number.
+///
+/// The [check_reparse](testing::check_reparse) function compares
+/// an incrementally reparsed document with the same document parsed from
+/// scratch. Together with the generator, it enables the differential testing
+/// of the incremental reparsing of your grammar.
+///
+/// The [TreeSnapshot](testing::TreeSnapshot) is a canonical textual dump of
+/// the syntax tree for the golden tests of your grammar, and
+/// the [assert_tree] macro compares a compilation unit's syntax tree with
+/// the expected snapshot text.
+///
+/// This module is available under the `testing` feature only.
+#[cfg(feature = "testing")]
+pub mod testing;
+
 /// A set of objects to manage individual compilation units in memory.
 ///
 /// The primary object of interest is the [Document](units::Document).
@@ -208,4 +307,10 @@ pub mod units;
 mod mem;
 mod report;
 
+extern crate alloc;
 extern crate self as lady_deirdre;
+
+#[doc(hidden)]
+pub mod export {
+    pub use alloc::{string::String, vec::Vec};
+}
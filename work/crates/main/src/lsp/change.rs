@@ -0,0 +1,211 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use crate::{
+    analysis::{AnalysisResult, Grammar, MutationAccess, TaskHandle},
+    arena::Id,
+    lexis::{SiteSpan, SourceCode},
+    lsp::{LspRange, PositionEncoding},
+    sync::SyncBuildHasher,
+    syntax::Node,
+    units::Document,
+};
+
+/// An object in the shape of the LSP TextDocumentContentChangeEvent.
+///
+/// If the [range](Self::range) is None, the change replaces the entire
+/// text of the document. Otherwise, the change replaces the range of
+/// the document's text.
+///
+/// When the `serde` feature is enabled, this object deserializes from the LSP
+/// TextDocumentContentChangeEvent JSON shape. The deprecated "rangeLength"
+/// field is ignored.
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContentChange {
+    /// The range of the document's text that the change replaces.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub range: Option<LspRange>,
+
+    /// The new text of the range or the entire document.
+    pub text: String,
+}
+
+impl ContentChange {
+    /// Converts the [range](Self::range) of this change into the [SiteSpan]
+    /// of the `code`.
+    ///
+    /// If the range is None, returns the span of the entire text.
+    ///
+    /// The `encoding` parameter specifies the measurement unit of
+    /// the character offsets.
+    #[inline(always)]
+    pub fn to_span(&self, code: &impl SourceCode, encoding: PositionEncoding) -> SiteSpan {
+        match &self.range {
+            None => 0..code.length(),
+            Some(range) => range.to_span(code, encoding),
+        }
+    }
+}
+
+/// Applies a batch of the LSP content `changes` to the mutable `document`.
+///
+/// The changes are applied in order, and the range of each change refers to
+/// the document's text after applying the previous changes, as required by
+/// the LSP specification.
+///
+/// The `encoding` parameter specifies the measurement unit of the character
+/// offsets of the change ranges.
+///
+/// **Panic**
+///
+/// Panics if the Document is not mutable.
+pub fn apply_changes<N: Node>(
+    document: &mut Document<N>,
+    changes: &[ContentChange],
+    encoding: PositionEncoding,
+) {
+    for change in changes {
+        let span = change.to_span(document, encoding);
+
+        document.write(span, &change.text);
+    }
+}
+
+/// Applies a batch of the LSP content `changes` to the mutable document
+/// managed by the [Analyzer](crate::analysis::Analyzer).
+///
+/// This function is similar to the [apply_changes] function, but writes
+/// each change through the [MutationAccess::write_to_doc] function of
+/// the `task`.
+///
+/// The `id` parameter specifies the document's [identifier](Id).
+///
+/// Returns the first error returned by the
+/// [read_doc](crate::analysis::AbstractTask::read_doc) or
+/// the [write_to_doc](MutationAccess::write_to_doc) functions. In this case,
+/// the changes preceding the failed one remain applied.
+pub fn write_changes_to_doc<N: Grammar, H: TaskHandle, S: SyncBuildHasher>(
+    task: &mut impl MutationAccess<N, H, S>,
+    id: Id,
+    changes: &[ContentChange],
+    encoding: PositionEncoding,
+) -> AnalysisResult<()> {
+    for change in changes {
+        let span = {
+            let document = task.read_doc(id)?;
+
+            change.to_span(&*document, encoding)
+        };
+
+        task.write_to_doc(id, span, &change.text)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        lexis::{SourceCode, Token},
+        lsp::{apply_changes, ContentChange, LspPosition, LspRange, PositionEncoding},
+        syntax::VoidSyntax,
+        units::Document,
+    };
+
+    #[derive(Token, Clone, Copy, PartialEq, Eq, Debug)]
+    #[repr(u8)]
+    enum TestToken {
+        EOI = 0,
+        Mismatch = 1,
+        #[rule(^['\n']+)]
+        Text,
+        #[rule('\n')]
+        Newline,
+    }
+
+    fn change(start: (u32, u32), end: (u32, u32), text: &str) -> ContentChange {
+        ContentChange {
+            range: Some(LspRange::new(
+                LspPosition::new(start.0, start.1),
+                LspPosition::new(end.0, end.1),
+            )),
+            text: String::from(text),
+        }
+    }
+
+    #[test]
+    fn test_change_batch() {
+        let mut doc = Document::<VoidSyntax<TestToken>>::new_mutable("a😀b\nc𝕏d\n");
+
+        apply_changes(
+            &mut doc,
+            &[
+                change((0, 3), (0, 4), "B"),
+                change((1, 1), (1, 3), "X"),
+                change((2, 0), (2, 0), "e😀"),
+                change((2, 3), (2, 3), "f"),
+                change((0, 0), (0, 1), ""),
+            ],
+            PositionEncoding::Utf16,
+        );
+
+        assert_eq!(doc.substring(..), "😀B\ncXd\ne😀f");
+
+        apply_changes(
+            &mut doc,
+            &[change((0, 0), (0, 4), "a"), change((2, 1), (2, 6), "g")],
+            PositionEncoding::Utf8,
+        );
+
+        assert_eq!(doc.substring(..), "aB\ncXd\neg");
+
+        apply_changes(
+            &mut doc,
+            &[
+                ContentChange {
+                    range: None,
+                    text: String::from("x\ny"),
+                },
+                change((1, 0), (1, 1), "z"),
+            ],
+            PositionEncoding::Utf32,
+        );
+
+        assert_eq!(doc.substring(..), "x\nz");
+    }
+}
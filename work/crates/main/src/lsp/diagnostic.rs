@@ -0,0 +1,166 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use crate::{
+    format::{Diagnostic, DiagnosticSeverity},
+    lexis::{SourceCode, ToSpan},
+    lsp::{LspRange, PositionEncoding},
+    syntax::SyntaxError,
+    units::CompilationUnit,
+};
+
+/// An object in the shape of the LSP Diagnostic.
+///
+/// The character offsets of the object are measured in the negotiated
+/// [PositionEncoding] units.
+///
+/// When the `serde` feature is enabled, this object serializes into the LSP
+/// Diagnostic JSON shape.
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct LspDiagnostic {
+    /// The range of the annotated source code fragment.
+    pub range: LspRange,
+
+    /// A degree of the diagnostic importance.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_severity"))]
+    pub severity: DiagnosticSeverity,
+
+    /// A message of the diagnostic.
+    pub message: String,
+
+    /// A set of additional source code fragments related to this diagnostic.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "relatedInformation", skip_serializing_if = "Vec::is_empty")
+    )]
+    pub related: Vec<LspRelated>,
+}
+
+impl LspDiagnostic {
+    /// Converts the [Diagnostic] of the `code` into the LspDiagnostic.
+    ///
+    /// The `uri` parameter specifies the document URI of the related
+    /// information locations.
+    ///
+    /// The `encoding` parameter specifies the measurement unit of
+    /// the character offsets.
+    pub fn from_diagnostic(
+        code: &impl SourceCode,
+        diagnostic: &Diagnostic,
+        uri: &str,
+        encoding: PositionEncoding,
+    ) -> Self {
+        Self {
+            range: range_of(code, &diagnostic.span, encoding),
+            severity: diagnostic.severity,
+            message: diagnostic.message.clone(),
+            related: diagnostic
+                .related
+                .iter()
+                .map(|related| LspRelated {
+                    location: LspLocation {
+                        uri: String::from(uri),
+                        range: range_of(code, &related.span, encoding),
+                    },
+                    message: related.message.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Converts the [SyntaxError] of the compilation `unit` into
+    /// the LspDiagnostic.
+    ///
+    /// The diagnostic range is the [aligned span](SyntaxError::aligned_span)
+    /// of the error, the severity is [Error](DiagnosticSeverity::Error),
+    /// and the message is the full canonical [message](SyntaxError::message)
+    /// of the error.
+    ///
+    /// The `encoding` parameter specifies the measurement unit of
+    /// the character offsets.
+    pub fn from_syntax_error<U: CompilationUnit>(
+        unit: &U,
+        error: &SyntaxError,
+        encoding: PositionEncoding,
+    ) -> Self {
+        Self {
+            range: range_of(unit, error.aligned_span(unit), encoding),
+            severity: DiagnosticSeverity::Error,
+            message: format!("{:#}", error.message::<U::Node>(unit)),
+            related: Vec::new(),
+        }
+    }
+}
+
+/// An additional source code fragment related to the [LspDiagnostic].
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LspRelated {
+    /// The location of the related source code fragment.
+    pub location: LspLocation,
+
+    /// A message of the related fragment.
+    pub message: String,
+}
+
+/// A location of the source code fragment within the document.
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LspLocation {
+    /// The URI of the document.
+    pub uri: String,
+
+    /// The range of the fragment within the document.
+    pub range: LspRange,
+}
+
+#[inline(always)]
+fn range_of(code: &impl SourceCode, span: impl ToSpan, encoding: PositionEncoding) -> LspRange {
+    match LspRange::from_span(code, span, encoding) {
+        Some(range) => range,
+
+        None => panic!("Invalid diagnostic span."),
+    }
+}
+
+#[cfg(feature = "serde")]
+#[inline(always)]
+fn serialize_severity<S: serde::Serializer>(
+    severity: &DiagnosticSeverity,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u8(severity.lsp_code())
+}
@@ -0,0 +1,362 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use crate::lexis::{Site, SiteSpan, SourceCode, ToSite, ToSpan};
+
+/// A character offsets measurement unit of the [LspPosition].
+///
+/// The LSP client and the server negotiate the position encoding during
+/// the initialization. If the client does not specify the encoding,
+/// the [UTF-16](Self::Utf16) encoding is assumed.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PositionEncoding {
+    /// Character offsets count UTF-8 code units (bytes).
+    #[cfg_attr(feature = "serde", serde(rename = "utf-8"))]
+    Utf8,
+
+    /// Character offsets count UTF-16 code units.
+    ///
+    /// This is the default encoding of the LSP specification.
+    #[default]
+    #[cfg_attr(feature = "serde", serde(rename = "utf-16"))]
+    Utf16,
+
+    /// Character offsets count Unicode characters.
+    #[cfg_attr(feature = "serde", serde(rename = "utf-32"))]
+    Utf32,
+}
+
+impl PositionEncoding {
+    /// Parses the encoding from its LSP name: "utf-8", "utf-16",
+    /// or "utf-32".
+    ///
+    /// Returns None if the name is not recognized.
+    #[inline(always)]
+    pub fn from_lsp(name: &str) -> Option<Self> {
+        match name {
+            "utf-8" => Some(Self::Utf8),
+            "utf-16" => Some(Self::Utf16),
+            "utf-32" => Some(Self::Utf32),
+            _ => None,
+        }
+    }
+
+    /// Returns the LSP name of the encoding.
+    #[inline(always)]
+    pub fn as_lsp(&self) -> &'static str {
+        match self {
+            Self::Utf8 => "utf-8",
+            Self::Utf16 => "utf-16",
+            Self::Utf32 => "utf-32",
+        }
+    }
+
+    /// Returns the number of code units of this encoding required to encode
+    /// the `ch` character.
+    #[inline(always)]
+    pub fn width(&self, ch: char) -> u32 {
+        match self {
+            Self::Utf8 => ch.len_utf8() as u32,
+            Self::Utf16 => ch.len_utf16() as u32,
+            Self::Utf32 => 1,
+        }
+    }
+}
+
+/// A zero-based line-character index of the source code text in terms of
+/// the LSP specification.
+///
+/// In contrast to the [Position](crate::lexis::Position) object, both
+/// components of the LspPosition are zero-based, and the `character`
+/// component is measured in the code units of the negotiated
+/// [PositionEncoding].
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LspPosition {
+    /// A line number. This value is zero-based.
+    pub line: u32,
+
+    /// A code unit offset within the line. This value is zero-based.
+    pub character: u32,
+}
+
+impl LspPosition {
+    /// A constructor of the LspPosition object.
+    #[inline(always)]
+    pub fn new(line: u32, character: u32) -> Self {
+        Self { line, character }
+    }
+
+    /// Converts a [site](ToSite) of the `code` into the LspPosition.
+    ///
+    /// The `encoding` parameter specifies the measurement unit of
+    /// the [character](Self::character) offset.
+    ///
+    /// Returns None if the `site` is not valid for the `code`.
+    pub fn from_site(
+        code: &impl SourceCode,
+        site: impl ToSite,
+        encoding: PositionEncoding,
+    ) -> Option<Self> {
+        let site = site.to_site(code)?;
+        let lines = code.lines();
+
+        let (line, character) = match encoding {
+            PositionEncoding::Utf8 => {
+                let line = lines.line_of(site);
+                let start = lines.line_start(line);
+
+                (line, lines.byte_of(site) - lines.byte_of(start))
+            }
+
+            PositionEncoding::Utf16 => lines.to_utf16_position(site),
+
+            PositionEncoding::Utf32 => {
+                let line = lines.line_of(site);
+
+                (line, site - lines.line_start(line))
+            }
+        };
+
+        Some(Self {
+            line: line.checked_sub(1).unwrap_or_default() as u32,
+            character: character as u32,
+        })
+    }
+
+    /// Converts this LspPosition into the [Site] of the `code`.
+    ///
+    /// The `encoding` parameter specifies the measurement unit of
+    /// the [character](Self::character) offset.
+    ///
+    /// Following the LSP specification, if the line number exceeds the number
+    /// of lines in the text, the function resolves the position to the last
+    /// line, and if the character offset exceeds the line length (excluding
+    /// the line break characters), the function resolves the position to
+    /// the end of the line. If the character offset points inside
+    /// a character (e.g., between the two code units of the UTF-16 surrogate
+    /// pair), the function resolves the position to the beginning of this
+    /// character.
+    pub fn to_site(&self, code: &impl SourceCode, encoding: PositionEncoding) -> Site {
+        let line = (self.line as usize).saturating_add(1);
+        let lines = code.lines();
+
+        let start = lines.line_start(line);
+        let end = lines.from_utf16_position(line, usize::MAX);
+
+        let site = match encoding {
+            PositionEncoding::Utf8 => return self.scan_site(code, line, encoding),
+            PositionEncoding::Utf16 => lines.from_utf16_position(line, self.character as usize),
+            PositionEncoding::Utf32 => start.saturating_add(self.character as usize).min(end),
+        };
+
+        // The line index does not distinguish the `\r` character of the `\r\n`
+        // line break, so the position that exceeds the line length has to be
+        // moved before it.
+        if site == end && site > start && code.chars((site - 1)..site).next() == Some('\r') {
+            return site - 1;
+        }
+
+        site
+    }
+
+    fn scan_site(&self, code: &impl SourceCode, line: usize, encoding: PositionEncoding) -> Site {
+        let span = code.lines().line_span(line);
+
+        let mut site = span.start;
+        let mut offset = 0;
+
+        for ch in code.chars(span) {
+            if ch == '\n' || ch == '\r' {
+                break;
+            }
+
+            offset += encoding.width(ch);
+
+            if offset > self.character {
+                break;
+            }
+
+            site += 1;
+        }
+
+        site
+    }
+}
+
+/// A zero-based line-character range of the source code text in terms of
+/// the LSP specification.
+///
+/// The [end](Self::end) bound is exclusive.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LspRange {
+    /// The start bound of the range.
+    pub start: LspPosition,
+
+    /// The end bound of the range.
+    pub end: LspPosition,
+}
+
+impl LspRange {
+    /// A constructor of the LspRange object.
+    #[inline(always)]
+    pub fn new(start: LspPosition, end: LspPosition) -> Self {
+        Self { start, end }
+    }
+
+    /// Converts a [span](ToSpan) of the `code` into the LspRange.
+    ///
+    /// The `encoding` parameter specifies the measurement unit of
+    /// the character offsets.
+    ///
+    /// Returns None if the `span` is not valid for the `code`.
+    pub fn from_span(
+        code: &impl SourceCode,
+        span: impl ToSpan,
+        encoding: PositionEncoding,
+    ) -> Option<Self> {
+        let span = span.to_site_span(code)?;
+
+        Some(Self {
+            start: LspPosition::from_site(code, span.start, encoding)?,
+            end: LspPosition::from_site(code, span.end, encoding)?,
+        })
+    }
+
+    /// Converts this LspRange into the [SiteSpan] of the `code`.
+    ///
+    /// The `encoding` parameter specifies the measurement unit of
+    /// the character offsets.
+    ///
+    /// Each bound is resolved according to the [LspPosition::to_site] rules.
+    /// If the resolved end bound precedes the start bound, the function
+    /// returns an empty span at the start bound.
+    pub fn to_span(&self, code: &impl SourceCode, encoding: PositionEncoding) -> SiteSpan {
+        let start = self.start.to_site(code, encoding);
+        let end = self.end.to_site(code, encoding);
+
+        start..end.max(start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        lexis::{Token, TokenBuffer},
+        lsp::{LspPosition, LspRange, PositionEncoding},
+    };
+
+    #[derive(Token, Clone, Copy, PartialEq, Eq, Debug)]
+    #[repr(u8)]
+    enum TestToken {
+        EOI = 0,
+        Mismatch = 1,
+        #[rule(^['\n']+)]
+        Text,
+        #[rule('\n')]
+        Newline,
+    }
+
+    #[test]
+    fn test_surrogate_pairs() {
+        // "𝕏" and "😀" are encoded as UTF-16 surrogate pairs.
+        let code = TokenBuffer::<TestToken>::from("a𝕏b\n😀c\r\nd");
+
+        assert_eq!(
+            LspPosition::from_site(&code, 2, PositionEncoding::Utf16),
+            Some(LspPosition::new(0, 3)),
+        );
+        assert_eq!(
+            LspPosition::from_site(&code, 2, PositionEncoding::Utf8),
+            Some(LspPosition::new(0, 5)),
+        );
+        assert_eq!(
+            LspPosition::from_site(&code, 2, PositionEncoding::Utf32),
+            Some(LspPosition::new(0, 2)),
+        );
+        assert_eq!(
+            LspPosition::from_site(&code, 5, PositionEncoding::Utf16),
+            Some(LspPosition::new(1, 2)),
+        );
+        assert_eq!(
+            LspPosition::from_site(&code, 9, PositionEncoding::Utf16),
+            Some(LspPosition::new(2, 1)),
+        );
+
+        assert_eq!(
+            LspPosition::new(0, 1).to_site(&code, PositionEncoding::Utf16),
+            1
+        );
+        assert_eq!(
+            LspPosition::new(0, 2).to_site(&code, PositionEncoding::Utf16),
+            1
+        );
+        assert_eq!(
+            LspPosition::new(0, 3).to_site(&code, PositionEncoding::Utf16),
+            2
+        );
+        assert_eq!(
+            LspPosition::new(0, 4).to_site(&code, PositionEncoding::Utf16),
+            3
+        );
+        assert_eq!(
+            LspPosition::new(0, 99).to_site(&code, PositionEncoding::Utf16),
+            3
+        );
+        assert_eq!(
+            LspPosition::new(1, 2).to_site(&code, PositionEncoding::Utf16),
+            5
+        );
+        assert_eq!(
+            LspPosition::new(1, 9).to_site(&code, PositionEncoding::Utf16),
+            6
+        );
+        assert_eq!(
+            LspPosition::new(1, 4).to_site(&code, PositionEncoding::Utf8),
+            5
+        );
+        assert_eq!(
+            LspPosition::new(9, 9).to_site(&code, PositionEncoding::Utf16),
+            9
+        );
+
+        let range = LspRange::from_span(&code, 1..5, PositionEncoding::Utf16).unwrap();
+
+        assert_eq!(range.start, LspPosition::new(0, 1));
+        assert_eq!(range.end, LspPosition::new(1, 2));
+        assert_eq!(range.to_span(&code, PositionEncoding::Utf16), 1..5);
+    }
+}
@@ -32,7 +32,7 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::ptr::{copy, copy_nonoverlapping};
+use core::ptr::{copy, copy_nonoverlapping};
 
 use crate::report::{ld_assert, ld_assert_ne};
 
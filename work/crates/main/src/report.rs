@@ -40,12 +40,12 @@ macro_rules! ld_unreachable (
                 $crate::report::system_panic!($message);
             }
 
-            ::std::hint::unreachable_unchecked()
+            ::core::hint::unreachable_unchecked()
         }
     };
 
     ($message:expr, $($args:tt)*) => {
-        $crate::report::ld_unreachable!(::std::format!($message, $($args)*))
+        $crate::report::ld_unreachable!(::alloc::format!($message, $($args)*))
     };
 );
 
@@ -91,8 +91,8 @@ macro_rules! ld_assert_ne {
 
 macro_rules! system_panic (
     ($message:expr) => {{
-        if !::std::thread::panicking() {
-            ::std::panic!(
+        if !$crate::report::panicking() {
+            ::core::panic!(
                 "{}",
                 $crate::report::error_message!($message),
             );
@@ -100,13 +100,13 @@ macro_rules! system_panic (
     }};
 
     ($message:expr, $($args:tt)*) => {
-        $crate::report::system_panic!(::std::format!($message, $($args)*))
+        $crate::report::system_panic!(::alloc::format!($message, $($args)*))
     };
 );
 
 macro_rules! error_message (
     ($message:expr) => {
-        ::std::format!(
+        ::alloc::format!(
 r#" !! LADY DEIRDRE INTERNAL ERROR
  !!
  !! This is a bug.
@@ -118,14 +118,14 @@ r#" !! LADY DEIRDRE INTERNAL ERROR
  !! Column: {}
 "#,
             $message,
-            ::std::file!(),
-            ::std::line!(),
-            ::std::column!(),
+            ::core::file!(),
+            ::core::line!(),
+            ::core::column!(),
         )
     };
 
     ($message:expr, $($args:tt)*) => {
-        $crate::report::error_message!(::std::format!($message, $($args)*))
+        $crate::report::error_message!(::alloc::format!($message, $($args)*))
     };
 );
 
@@ -135,3 +135,15 @@ pub(crate) use ld_assert_eq;
 pub(crate) use ld_assert_ne;
 pub(crate) use ld_unreachable;
 pub(crate) use system_panic;
+
+#[cfg(feature = "std")]
+#[inline(always)]
+pub(crate) fn panicking() -> bool {
+    std::thread::panicking()
+}
+
+#[cfg(not(feature = "std"))]
+#[inline(always)]
+pub(crate) fn panicking() -> bool {
+    false
+}
@@ -32,7 +32,10 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::{ops::Deref, sync::OnceLock};
+use std::{
+    ops::Deref,
+    sync::{Mutex, OnceLock},
+};
 
 /// A value which is initialized on the first access.
 ///
@@ -96,3 +99,144 @@ impl<T: Send + Sync + 'static> Lazy<T> {
         }
     }
 }
+
+/// A value which is initialized on the first successful access.
+///
+/// Unlike the [Lazy] object, the initialization function of TryLazy is
+/// fallible. If the initialization function returns an error, the error is
+/// returned to the caller, and the TryLazy remains uninitialized: the next
+/// [get](TryLazy::get) call will try to initialize the value again.
+///
+/// TryLazy is thread-safe and can be used in statics.
+///
+/// ```
+/// use lady_deirdre::sync::TryLazy;
+///
+/// static CONFIG: TryLazy<usize, String> = TryLazy::new(|| {
+///     "42".parse::<usize>().map_err(|error| error.to_string())
+/// });
+///
+/// assert_eq!(CONFIG.get(), Ok(&42));
+/// ```
+///
+/// The initialization attempts are serialized: if several threads access
+/// the uninitialized TryLazy concurrently, only one of them runs
+/// the initialization function at a time, and the others are blocked until
+/// this attempt finishes. If the attempt succeeds, the blocked threads
+/// receive the initialized value. Otherwise, the failed attempt's error is
+/// returned to the thread that made this attempt only, and the next blocked
+/// thread makes its own attempt. As such, the initialization function could
+/// be called several times, but never concurrently, and never after
+/// the first success.
+pub struct TryLazy<T: Send + Sync + 'static, E> {
+    cell: OnceLock<T>,
+    lock: Mutex<()>,
+    init: fn() -> Result<T, E>,
+}
+
+impl<T: Send + Sync + 'static, E> TryLazy<T, E> {
+    /// A constructor of the object.
+    ///
+    /// The constructor is a const function, but the `init` function, which
+    /// initializes the TryLazy instance on the first successful access, is not
+    /// required to be const function.
+    ///
+    /// The `init` constructor should not access not-yet-initialized self
+    /// TryLazy directly or indirectly. Such recurrent access leads to
+    /// a deadlock.
+    #[inline(always)]
+    pub const fn new(init: fn() -> Result<T, E>) -> Self {
+        Self {
+            cell: OnceLock::new(),
+            lock: Mutex::new(()),
+            init,
+        }
+    }
+
+    /// Returns a reference to the initialized value.
+    ///
+    /// If the TryLazy is not initialized yet, calls the initialization
+    /// function and returns its error if the initialization fails.
+    ///
+    /// Blocks the current thread if another thread is currently trying to
+    /// initialize this TryLazy.
+    pub fn get(&self) -> Result<&T, E> {
+        if let Some(value) = self.cell.get() {
+            return Ok(value);
+        }
+
+        let _guard = self
+            .lock
+            .lock()
+            .unwrap_or_else(|poison| poison.into_inner());
+
+        if let Some(value) = self.cell.get() {
+            return Ok(value);
+        }
+
+        let value = (self.init)()?;
+
+        Ok(self.cell.get_or_init(|| value))
+    }
+
+    /// Returns a reference to the value if the TryLazy has been initialized
+    /// already.
+    ///
+    /// This function never calls the initialization function and never
+    /// blocks the current thread.
+    #[inline(always)]
+    pub fn get_initialized(&self) -> Option<&T> {
+        self.cell.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        thread::scope,
+    };
+
+    use crate::sync::TryLazy;
+
+    #[test]
+    fn test_try_lazy() {
+        static ATTEMPTS: AtomicUsize = AtomicUsize::new(0);
+        static ACTIVE: AtomicUsize = AtomicUsize::new(0);
+
+        static VALUE: TryLazy<usize, usize> = TryLazy::new(|| {
+            assert_eq!(ACTIVE.fetch_add(1, Ordering::SeqCst), 0);
+
+            let attempt = ATTEMPTS.fetch_add(1, Ordering::SeqCst);
+
+            let _ = ACTIVE.fetch_sub(1, Ordering::SeqCst);
+
+            match attempt < 3 {
+                true => Err(attempt),
+                false => Ok(100),
+            }
+        });
+
+        assert_eq!(VALUE.get_initialized(), None);
+        assert_eq!(VALUE.get(), Err(0));
+
+        let results = scope(|scope| {
+            let handles = (0..8)
+                .map(|_| scope.spawn(|| VALUE.get().copied()))
+                .collect::<Vec<_>>();
+
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+
+        assert_eq!(results.iter().filter(|result| result.is_err()).count(), 2);
+        assert_eq!(
+            results.iter().filter(|result| result == &&Ok(100)).count(),
+            6
+        );
+        assert_eq!(ATTEMPTS.load(Ordering::SeqCst), 4);
+        assert_eq!(VALUE.get_initialized(), Some(&100));
+    }
+}
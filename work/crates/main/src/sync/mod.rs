@@ -37,9 +37,10 @@ mod markers;
 mod shared;
 mod table;
 mod trigger;
+mod weak;
 
 pub use crate::sync::{
-    lazy::Lazy,
+    lazy::{Lazy, TryLazy},
     markers::SyncBuildHasher,
     shared::Shared,
     table::{
@@ -47,10 +48,12 @@ pub use crate::sync::{
         TableDrain,
         TableEntry,
         TableIntoIter,
+        TableMappedReadGuard,
         TableOccupiedEntry,
         TableReadGuard,
         TableVacantEntry,
         TableWriteGuard,
     },
     trigger::Trigger,
+    weak::{SharedWeak, SharedWeakable},
 };
@@ -62,7 +62,8 @@ const REF_MAX: usize = usize::MAX - 1;
 ///  1. Shared does not have a [Weak](std::sync::Weak) counterpart. All Shared
 ///     clones are "strong" references. Therefore, the clone and drop operations
 ///     are slightly cheaper, and Shared allocates one machine-word less than
-///     Arc.
+///     Arc. If you need weak references, use
+///     the [SharedWeakable](crate::sync::SharedWeakable) pointer instead.
 ///  2. Shared provides read-access through the [AsRef] implementation rather
 ///     than [Deref](std::ops::Deref). This makes its API more ergonomic to
 ///     use as a "builder" because [Shared::get_mut] and [Shared::make_mut]
@@ -345,6 +346,57 @@ impl<T> Shared<T> {
         None
     }
 
+    /// Takes data from this Shared instance if there are no other live Shared
+    /// instances to the same allocation.
+    ///
+    /// Otherwise, returns this Shared instance back as an error.
+    ///
+    /// Unlike [into_inner](Self::into_inner), this function does not drop
+    /// this Shared instance if it is not unique. Note that if two clones
+    /// of the same allocation call try_unwrap concurrently, both calls may
+    /// fail.
+    pub fn try_unwrap(self) -> Result<T, Self> {
+        let unique = {
+            // Safety: Shared owns a pointer to valid data leaked from the Box.
+            let inner = unsafe { self.inner.as_ref() };
+
+            inner
+                .counter
+                .compare_exchange(1, 0, atomic::Ordering::Relaxed, atomic::Ordering::Relaxed)
+                .is_ok()
+        };
+
+        if !unique {
+            return Err(self);
+        }
+
+        fence(atomic::Ordering::Acquire);
+
+        let this = ManuallyDrop::new(self);
+
+        // Safety:
+        //   1. Shared owns a pointer to valid data leaked from the Box.
+        //   2. The ownership transfer operation is ordered by the Acquire fence.
+        //   3. Shared will not be dropped twice because of the `ManuallyDrop` wrapper.
+        let inner = unsafe { Box::from_raw(this.inner.as_ptr()) };
+
+        Ok(inner.data)
+    }
+
+    /// Returns the number of live Shared instances to the same allocation,
+    /// including this instance.
+    ///
+    /// This function is intended for diagnostics. Other threads may clone or
+    /// drop the Shared instances concurrently, so the returning value could
+    /// become outdated immediately.
+    #[inline(always)]
+    pub fn strong_count(&self) -> usize {
+        // Safety: Shared owns a pointer to valid data leaked from the Box.
+        let inner = unsafe { self.inner.as_ref() };
+
+        inner.counter.load(atomic::Ordering::Relaxed)
+    }
+
     /// Returns the address of the Shared allocation.
     #[inline(always)]
     pub fn addr(&self) -> usize {
@@ -393,4 +445,50 @@ mod tests {
         assert!(shared1.get_mut().is_some());
         assert_eq!(shared1.into_inner(), Some(175));
     }
+
+    #[test]
+    fn test_shared_try_unwrap() {
+        let shared = Shared::new(String::from("foo"));
+        let clone = shared.clone();
+
+        assert_eq!(shared.strong_count(), 2);
+
+        let shared = shared.try_unwrap().unwrap_err();
+
+        assert_eq!(clone.strong_count(), 2);
+
+        drop(clone);
+
+        assert_eq!(shared.strong_count(), 1);
+        assert_eq!(shared.try_unwrap().unwrap(), "foo");
+
+        for _ in 0..100 {
+            let shared = Shared::new(vec![1, 2, 3]);
+
+            let unwrapped = std::thread::scope(|scope| {
+                let handles = (0..4)
+                    .map(|_| {
+                        let mut clone = shared.clone();
+
+                        scope.spawn(move || {
+                            if let Some(data) = clone.get_mut() {
+                                data.push(4);
+                            }
+
+                            clone.try_unwrap().ok()
+                        })
+                    })
+                    .collect::<Vec<_>>();
+
+                handles
+                    .into_iter()
+                    .filter_map(|handle| handle.join().unwrap())
+                    .collect::<Vec<_>>()
+            });
+
+            assert!(unwrapped.is_empty());
+            assert_eq!(shared.strong_count(), 1);
+            assert_eq!(shared.as_ref(), &[1, 2, 3]);
+        }
+    }
 }
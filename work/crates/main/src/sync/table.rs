@@ -178,6 +178,40 @@ impl<K: Hash + Eq, V, S: BuildHasher> Table<K, V, S> {
         Self::with_capacity_and_hasher(capacity, S::default())
     }
 
+    /// A Table constructor with a specified amount of `shards`.
+    ///
+    /// The `shards` value is rounded up to the nearest power of two. Zero
+    /// value is treated as one shard.
+    ///
+    /// By default, the Table estimates the shards amount based on
+    /// the number of CPUs. Higher amounts reduce contention between
+    /// concurrent writers, whereas lower amounts reduce memory footprint of
+    /// small tables.
+    #[inline(always)]
+    pub fn with_shards(shards: usize) -> Self
+    where
+        S: Default + Clone,
+    {
+        Self::with_capacity_and_shards(0, shards)
+    }
+
+    /// A Table constructor with a specified preallocated `capacity` of entries,
+    /// and the amount of `shards`.
+    ///
+    /// The `shards` value is rounded up to the nearest power of two. Zero
+    /// value is treated as one shard.
+    #[inline(always)]
+    pub fn with_capacity_and_shards(capacity: usize, shards: usize) -> Self
+    where
+        S: Default + Clone,
+    {
+        Self::with_capacity_and_hasher_and_shards(
+            capacity,
+            S::default(),
+            shards.max(1).next_power_of_two(),
+        )
+    }
+
     /// A Table constructor with a specified preallocated `capacity` of entries,
     /// and the key `hasher` instance.
     #[inline(always)]
@@ -490,6 +524,48 @@ impl<K: Hash + Eq, V, S: BuildHasher> Table<K, V, S> {
         }
     }
 
+    /// Removes all key-value entries that satisfy the `predicate`, and returns
+    /// the removed entries.
+    ///
+    /// The `predicate` tests each Table key-value pair, and if the predicate
+    /// returns true, the function removes this entry.
+    ///
+    /// Under the hood, the function sequentially locks each shard one by one
+    /// for write, and filters the shard's entries.
+    ///
+    /// Unlike the [retain](Self::retain), [clear](Self::clear), and
+    /// [drain](Self::drain) functions, the remove_if function **unlocks**
+    /// previously locked shard immediately after filtering. Hence, concurrent
+    /// access to the other shards is not blocked during the whole operation,
+    /// but the operation is not atomic: the entries inserted concurrently
+    /// into the already filtered shards survive the operation even if they
+    /// satisfy the predicate.
+    ///
+    /// In particular, `table.remove_if(|_, _| true)` is a non-blocking
+    /// alternative to the clear and drain functions.
+    pub fn remove_if<F>(&self, mut predicate: F) -> Vec<(K, V)>
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        let mut removed = Vec::new();
+        let mut kept = Vec::new();
+
+        for shard in self.shards.iter() {
+            let mut guard = shard.write().unwrap_or_else(|poison| poison.into_inner());
+
+            for (key, mut value) in guard.drain() {
+                match predicate(&key, &mut value) {
+                    true => removed.push((key, value)),
+                    false => kept.push((key, value)),
+                }
+            }
+
+            guard.extend(kept.drain(..));
+        }
+
+        removed
+    }
+
     /// Clears the Table, removing all key-value entries.
     ///
     /// This function keeps allocated memory for reuse.
@@ -513,6 +589,103 @@ impl<K: Hash + Eq, V, S: BuildHasher> Table<K, V, S> {
         }
     }
 
+    /// Returns the number of key-value entries in the Table.
+    ///
+    /// Under the hood, the function sequentially locks each shard one by one
+    /// for read, and sums up the lengths of the shards' HashMaps.
+    ///
+    /// The len function **unlocks** previously locked shard immediately after
+    /// reading its length. Hence, the returning value is not necessarily
+    /// accurate if the Table is being modified concurrently.
+    pub fn len(&self) -> usize {
+        self.shards
+            .iter()
+            .map(|shard| {
+                shard
+                    .read()
+                    .unwrap_or_else(|poison| poison.into_inner())
+                    .len()
+            })
+            .sum()
+    }
+
+    /// Returns true if the Table does not have key-value entries.
+    ///
+    /// Similarly to the [len](Self::len) function, the result is not
+    /// necessarily accurate if the Table is being modified concurrently.
+    pub fn is_empty(&self) -> bool {
+        self.shards.iter().all(|shard| {
+            shard
+                .read()
+                .unwrap_or_else(|poison| poison.into_inner())
+                .is_empty()
+        })
+    }
+
+    /// Returns an iterator over the clones of all key-value entries of
+    /// the Table.
+    ///
+    /// This function is useful for debugging and metrics purposes.
+    ///
+    /// Under the hood, the function sequentially locks each shard one by one
+    /// for read, and clones all entries of the shard into the resulting
+    /// vector.
+    ///
+    /// The iter_snapshot function **unlocks** previously locked shard
+    /// immediately after cloning its entries, and the returning iterator does
+    /// not hold any locks.
+    ///
+    /// Hence, the snapshot is atomic per each shard, but it is not globally
+    /// atomic: if the Table is being modified concurrently, the snapshot may
+    /// observe some of the concurrent changes in one shard and miss them in
+    /// another shard. The order of the entries is unspecified.
+    pub fn iter_snapshot(&self) -> vec::IntoIter<(K, V)>
+    where
+        K: Clone,
+        V: Clone,
+    {
+        let mut result = Vec::new();
+
+        for shard in self.shards.iter() {
+            let guard = shard.read().unwrap_or_else(|poison| poison.into_inner());
+
+            result.extend(
+                guard
+                    .iter()
+                    .map(|(key, value)| (key.clone(), value.clone())),
+            );
+        }
+
+        result.into_iter()
+    }
+
+    /// Returns the clones of all keys of the Table.
+    ///
+    /// Unlike the [iter_snapshot](Self::iter_snapshot) function, this function
+    /// does not clone the values.
+    ///
+    /// Under the hood, the function sequentially locks each shard one by one
+    /// for read, and clones all keys of the shard into the resulting vector.
+    /// The function **unlocks** previously locked shard immediately after
+    /// cloning its keys.
+    ///
+    /// Hence, the snapshot is atomic per each shard, but it is not globally
+    /// atomic. The order of the keys is unspecified.
+    pub fn keys_snapshot(&self) -> Vec<K>
+    where
+        K: Clone,
+    {
+        let mut result = Vec::new();
+
+        for shard in self.shards.iter() {
+            let guard = shard.read().unwrap_or_else(|poison| poison.into_inner());
+
+            result.extend(guard.keys().cloned());
+        }
+
+        result
+    }
+
     /// Shrinks the capacity of the Table as much as possible by locking each
     /// shard one by one for write and calling the [HashMap::shrink_to_fit]
     /// function on each of them.
@@ -586,6 +759,9 @@ impl<K: Hash + Eq, V, S: BuildHasher> Table<K, V, S> {
     }
 
     /// Provides access to the underlying shards array.
+    ///
+    /// The length of this array is the effective amount of shards of
+    /// the Table.
     #[inline(always)]
     pub fn shards(&self) -> &[RwLock<HashMap<K, V, S>>] {
         &self.shards
@@ -613,6 +789,58 @@ impl<'a, K, V, S> Deref for TableReadGuard<'a, K, V, S> {
     }
 }
 
+impl<'a, K, V, S> TableReadGuard<'a, K, V, S> {
+    /// Projects this guard to a component of the entry's value.
+    ///
+    /// The `f` function receives a reference to the entry's value and
+    /// returns a reference to its component (e.g., a field of the value).
+    ///
+    /// The returning guard keeps the corresponding Table shard locked for read
+    /// until the guard is dropped.
+    #[inline(always)]
+    pub fn map<T: ?Sized>(self, f: impl FnOnce(&V) -> &T) -> TableMappedReadGuard<'a, K, V, T, S> {
+        TableMappedReadGuard {
+            value: f(self.value),
+            _guard: self._guard,
+        }
+    }
+}
+
+/// A RAII guard, that provides read access to a component of the [Table]
+/// entry's value.
+///
+/// Created by the [TableReadGuard::map] method.
+///
+/// The guard keeps the corresponding Table shard locked for read until
+/// the guard is dropped.
+// Safety: Entries order reflects guards drop semantics.
+pub struct TableMappedReadGuard<'a, K, V, T: ?Sized, S = RandomState> {
+    value: &'a T,
+    _guard: RwLockReadGuard<'a, HashMap<K, V, S>>,
+}
+
+impl<'a, K, V, T: ?Sized, S> Deref for TableMappedReadGuard<'a, K, V, T, S> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.value
+    }
+}
+
+impl<'a, K, V, T: ?Sized, S> TableMappedReadGuard<'a, K, V, T, S> {
+    /// Projects this guard further to a component of the referred data.
+    ///
+    /// See [TableReadGuard::map] for details.
+    #[inline(always)]
+    pub fn map<U: ?Sized>(self, f: impl FnOnce(&T) -> &U) -> TableMappedReadGuard<'a, K, V, U, S> {
+        TableMappedReadGuard {
+            value: f(self.value),
+            _guard: self._guard,
+        }
+    }
+}
+
 /// A RAII guard, that provides read and write access to the [Table] entry's
 /// value.
 ///
@@ -36,15 +36,21 @@ use std::{
     fmt::{Debug, Formatter},
     hash::{Hash, Hasher},
     ptr::NonNull,
-    sync::atomic::{fence, AtomicUsize, Ordering},
+    sync::{
+        atomic::{fence, AtomicUsize, Ordering},
+        Condvar,
+        Mutex,
+    },
+    time::{Duration, Instant},
 };
 
 use crate::report::system_panic;
 
 const CHECK_MASK: usize = 1usize;
-const REF_MASK: usize = usize::MAX ^ CHECK_MASK;
+const WAIT_MASK: usize = 1usize << 1;
+const REF_MASK: usize = usize::MAX ^ CHECK_MASK ^ WAIT_MASK;
 const REF_MAX: usize = REF_MASK / 2;
-const REF_STEP: usize = 1 << 1;
+const REF_STEP: usize = 1 << 2;
 
 /// A shared boolean flag.
 ///
@@ -69,9 +75,17 @@ const REF_STEP: usize = 1 << 1;
 /// a job handle used outside of the job thread, and the job thread would
 /// periodically examine another clone of this Trigger for activation, which
 /// would be a signal for the worker to interrupt its job.
+///
+/// Additionally, the [wait](Trigger::wait) and
+/// [wait_timeout](Trigger::wait_timeout) functions block the current thread
+/// until the Trigger activation. For instance, a supervising thread could
+/// use these functions to wait for the worker's acknowledgement. The blocking
+/// mechanism does not affect the performance of the is_active function, and
+/// the activate function pays for the waiters notification only if there
+/// are blocked threads.
 #[repr(transparent)]
 pub struct Trigger {
-    data: NonNull<AtomicUsize>,
+    data: NonNull<TriggerInner>,
 }
 
 // Safety: Trigger's data access is guarded by the atomic operations.
@@ -117,7 +131,7 @@ impl Clone for Trigger {
     fn clone(&self) -> Self {
         let value = {
             // Safety: Trigger owns a pointer to valid data leaked from the Box.
-            let state = unsafe { self.data.as_ref() };
+            let state = unsafe { &self.data.as_ref().state };
 
             state.fetch_add(REF_STEP, Ordering::Relaxed)
         };
@@ -134,7 +148,7 @@ impl Drop for Trigger {
     fn drop(&mut self) {
         let value = {
             // Safety: Trigger owns a pointer to valid data leaked from the Box.
-            let state = unsafe { self.data.as_ref() };
+            let state = unsafe { &self.data.as_ref().state };
 
             state.fetch_sub(REF_STEP, Ordering::Release)
         };
@@ -153,7 +167,11 @@ impl Drop for Trigger {
 impl Trigger {
     /// Creates a new inactive Trigger.
     pub fn new() -> Self {
-        let data = Box::into_raw(Box::new(AtomicUsize::new(REF_STEP)));
+        let data = Box::into_raw(Box::new(TriggerInner {
+            state: AtomicUsize::new(REF_STEP),
+            lock: Mutex::new(()),
+            condvar: Condvar::new(),
+        }));
 
         // Safety: Box leaked pointer is never null.
         let data = unsafe { NonNull::new_unchecked(data) };
@@ -172,7 +190,7 @@ impl Trigger {
     /// observe activation.
     pub fn is_active(&self) -> bool {
         // Safety: Trigger owns a pointer to valid data leaked from the Box.
-        let state = unsafe { self.data.as_ref() };
+        let state = unsafe { &self.data.as_ref().state };
 
         let value = state.load(Ordering::Relaxed);
 
@@ -182,11 +200,52 @@ impl Trigger {
     /// Activates this Trigger.
     ///
     /// Already activated triggers cannot be deactivated.
+    ///
+    /// This function wakes up all threads blocked in the [wait](Self::wait)
+    /// and [wait_timeout](Self::wait_timeout) functions of this Trigger and
+    /// its clones.
     pub fn activate(&self) {
         // Safety: Trigger owns a pointer to valid data leaked from the Box.
-        let state = unsafe { self.data.as_ref() };
+        let inner = unsafe { self.data.as_ref() };
+
+        let value = inner.state.fetch_or(CHECK_MASK, Ordering::Release);
+
+        if value & (CHECK_MASK | WAIT_MASK) != WAIT_MASK {
+            return;
+        }
+
+        // Acquiring the lock ensures that the waiters are either parked on
+        // the condvar, or will observe the activation before parking.
+        let _guard = inner
+            .lock
+            .lock()
+            .unwrap_or_else(|poison| poison.into_inner());
+
+        inner.condvar.notify_all();
+    }
+
+    /// Blocks the current thread until this Trigger is activated by this
+    /// instance or any of its clones.
+    ///
+    /// Returns immediately if the Trigger is already active.
+    ///
+    /// Note that this function would block the current thread forever if
+    /// the Trigger will never be activated.
+    #[inline(always)]
+    pub fn wait(&self) {
+        let _ = self.wait_until(None);
+    }
 
-        state.fetch_or(CHECK_MASK, Ordering::Release);
+    /// Blocks the current thread until this Trigger is activated by this
+    /// instance or any of its clones, or until the `timeout` expires.
+    ///
+    /// Returns true if the Trigger has been activated, and false if
+    /// the timeout expired.
+    ///
+    /// Returns immediately if the Trigger is already active.
+    #[inline(always)]
+    pub fn wait_timeout(&self, timeout: Duration) -> bool {
+        self.wait_until(Instant::now().checked_add(timeout))
     }
 
     /// Returns the address of the Trigger's allocation.
@@ -194,10 +253,70 @@ impl Trigger {
     pub fn addr(&self) -> usize {
         self.data.as_ptr() as usize
     }
+
+    fn wait_until(&self, deadline: Option<Instant>) -> bool {
+        // Safety: Trigger owns a pointer to valid data leaked from the Box.
+        let inner = unsafe { self.data.as_ref() };
+
+        if inner.state.load(Ordering::Acquire) & CHECK_MASK == CHECK_MASK {
+            return true;
+        }
+
+        let mut guard = inner
+            .lock
+            .lock()
+            .unwrap_or_else(|poison| poison.into_inner());
+
+        let value = inner.state.fetch_or(WAIT_MASK, Ordering::Acquire);
+
+        if value & CHECK_MASK == CHECK_MASK {
+            return true;
+        }
+
+        loop {
+            guard = match deadline {
+                None => inner
+                    .condvar
+                    .wait(guard)
+                    .unwrap_or_else(|poison| poison.into_inner()),
+
+                Some(deadline) => {
+                    let Some(timeout) = deadline.checked_duration_since(Instant::now()) else {
+                        return false;
+                    };
+
+                    inner
+                        .condvar
+                        .wait_timeout(guard, timeout)
+                        .unwrap_or_else(|poison| poison.into_inner())
+                        .0
+                }
+            };
+
+            if inner.state.load(Ordering::Acquire) & CHECK_MASK == CHECK_MASK {
+                return true;
+            }
+        }
+    }
+}
+
+struct TriggerInner {
+    state: AtomicUsize,
+    lock: Mutex<()>,
+    condvar: Condvar,
 }
 
 #[cfg(test)]
 mod tests {
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        thread::{spawn, yield_now},
+        time::Duration,
+    };
+
     use crate::sync::Trigger;
 
     #[test]
@@ -214,4 +333,48 @@ mod tests {
 
         assert!(trigger2.is_active());
     }
+
+    #[test]
+    fn test_trigger_wait() {
+        let trigger = Trigger::new();
+
+        assert!(!trigger.wait_timeout(Duration::from_millis(10)));
+
+        let activated = Trigger::new();
+
+        activated.activate();
+        activated.wait();
+
+        assert!(activated.wait_timeout(Duration::ZERO));
+
+        for _ in 0..10 {
+            let trigger = Trigger::new();
+            let waiters = Arc::new(AtomicUsize::new(0));
+
+            let handles = (0..4)
+                .map(|_| {
+                    let trigger = trigger.clone();
+                    let waiters = waiters.clone();
+
+                    spawn(move || {
+                        let _ = waiters.fetch_add(1, Ordering::SeqCst);
+
+                        trigger.wait();
+
+                        assert!(trigger.is_active());
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            while waiters.load(Ordering::SeqCst) < 4 {
+                yield_now();
+            }
+
+            trigger.activate();
+
+            for handle in handles {
+                handle.join().unwrap();
+            }
+        }
+    }
 }
@@ -0,0 +1,320 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display, Formatter},
+    hash::{Hash, Hasher},
+    sync::{Arc, Weak},
+};
+
+/// A reference-counting pointer that supports weak references.
+///
+/// This object is an opt-in alternative to the [Shared](crate::sync::Shared)
+/// pointer for the cases where you need non-owning back-references (e.g.,
+/// parent pointers) that must not create reference cycles.
+///
+/// The API of SharedWeakable mimics the API of Shared, and additionally
+/// provides the [downgrade](SharedWeakable::downgrade) function that creates
+/// a [SharedWeak] reference to the same allocation. The weak reference does
+/// not keep the underlying data alive, and can be
+/// [upgraded](SharedWeak::upgrade) back to the SharedWeakable as long as
+/// at least one SharedWeakable instance to this allocation exists.
+///
+/// Under the hood, SharedWeakable is a wrapper of the standard
+/// [Arc](std::sync::Arc) pointer. It allocates one machine word more than
+/// Shared, and its clone and drop operations are slightly more expensive.
+/// Prefer Shared if you don't need weak references.
+#[repr(transparent)]
+pub struct SharedWeakable<T> {
+    inner: Arc<T>,
+}
+
+impl<T: Debug> Debug for SharedWeakable<T> {
+    #[inline(always)]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(self.as_ref(), formatter)
+    }
+}
+
+impl<T: Display> Display for SharedWeakable<T> {
+    #[inline(always)]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self.as_ref(), formatter)
+    }
+}
+
+impl<T: Eq> PartialEq for SharedWeakable<T> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        if self.addr().eq(&other.addr()) {
+            return true;
+        }
+
+        self.as_ref().eq(other.as_ref())
+    }
+}
+
+impl<T: Eq> Eq for SharedWeakable<T> {}
+
+impl<T: Ord> PartialOrd for SharedWeakable<T> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord> Ord for SharedWeakable<T> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.addr().eq(&other.addr()) {
+            return Ordering::Equal;
+        }
+
+        self.as_ref().cmp(other.as_ref())
+    }
+}
+
+impl<T: Hash> Hash for SharedWeakable<T> {
+    #[inline(always)]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_ref().hash(state)
+    }
+}
+
+impl<T: Default> Default for SharedWeakable<T> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> Clone for SharedWeakable<T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T> AsRef<T> for SharedWeakable<T> {
+    #[inline(always)]
+    fn as_ref(&self) -> &T {
+        self.inner.as_ref()
+    }
+}
+
+impl<T> SharedWeakable<T> {
+    /// Creates a new SharedWeakable.
+    #[inline(always)]
+    pub fn new(data: T) -> Self {
+        Self {
+            inner: Arc::new(data),
+        }
+    }
+
+    /// Creates a [weak reference](SharedWeak) to this allocation.
+    #[inline(always)]
+    pub fn downgrade(&self) -> SharedWeak<T> {
+        SharedWeak {
+            inner: Arc::downgrade(&self.inner),
+        }
+    }
+
+    /// Returns a mutable reference to the underlying data.
+    ///
+    /// Returns None if there are other live SharedWeakable instances or
+    /// [weak references](SharedWeak) to the same allocation.
+    #[inline(always)]
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        Arc::get_mut(&mut self.inner)
+    }
+
+    /// Makes a mutable reference to the underlying data.
+    ///
+    /// If there are no other live SharedWeakable instances to the same
+    /// allocation, returns a mutable reference to the current allocation,
+    /// and disassociates the weak references from this allocation.
+    ///
+    /// Otherwise, replaces this SharedWeakable instance with a new one by
+    /// cloning the underlying data into a new allocation. Then, returns
+    /// a mutable reference to this new independent allocation.
+    #[inline(always)]
+    pub fn make_mut(&mut self) -> &mut T
+    where
+        T: Clone,
+    {
+        Arc::make_mut(&mut self.inner)
+    }
+
+    /// Takes data from this SharedWeakable instance.
+    ///
+    /// Returns None if there are other live SharedWeakable instances to
+    /// the same allocation. The weak references don't prevent taking the data.
+    #[inline(always)]
+    pub fn into_inner(self) -> Option<T> {
+        Arc::into_inner(self.inner)
+    }
+
+    /// Returns the number of live SharedWeakable instances to the same
+    /// allocation, including this instance.
+    #[inline(always)]
+    pub fn strong_count(&self) -> usize {
+        Arc::strong_count(&self.inner)
+    }
+
+    /// Returns the number of live [weak references](SharedWeak) to the same
+    /// allocation.
+    #[inline(always)]
+    pub fn weak_count(&self) -> usize {
+        Arc::weak_count(&self.inner)
+    }
+
+    /// Returns the address of the SharedWeakable allocation.
+    #[inline(always)]
+    pub fn addr(&self) -> usize {
+        Arc::as_ptr(&self.inner) as usize
+    }
+}
+
+/// A weak reference to the [SharedWeakable] allocation.
+///
+/// Created by the [SharedWeakable::downgrade] function.
+///
+/// The weak reference does not keep the underlying data alive. Use
+/// the [upgrade](SharedWeak::upgrade) function to obtain a strong reference
+/// to the data if the data still exists.
+///
+/// The [Default] instance of SharedWeak does not refer to any allocation,
+/// and never upgrades.
+#[repr(transparent)]
+pub struct SharedWeak<T> {
+    inner: Weak<T>,
+}
+
+impl<T> Debug for SharedWeak<T> {
+    #[inline(always)]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str("SharedWeak")
+    }
+}
+
+impl<T> Default for SharedWeak<T> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self { inner: Weak::new() }
+    }
+}
+
+impl<T> Clone for SharedWeak<T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for SharedWeak<T> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.inner.ptr_eq(&other.inner)
+    }
+}
+
+impl<T> Eq for SharedWeak<T> {}
+
+impl<T> SharedWeak<T> {
+    /// Returns a strong reference to the allocation if at least one
+    /// [SharedWeakable] instance to this allocation is still alive.
+    ///
+    /// Returns None otherwise.
+    #[inline(always)]
+    pub fn upgrade(&self) -> Option<SharedWeakable<T>> {
+        Some(SharedWeakable {
+            inner: self.inner.upgrade()?,
+        })
+    }
+
+    /// Returns the number of live [SharedWeakable] instances to
+    /// the referred allocation.
+    ///
+    /// Returns zero if the referred data does not exist.
+    #[inline(always)]
+    pub fn strong_count(&self) -> usize {
+        self.inner.strong_count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sync::{SharedWeak, SharedWeakable};
+
+    #[test]
+    fn test_shared_weakable() {
+        struct Node {
+            parent: SharedWeak<Node>,
+            value: usize,
+        }
+
+        let parent = SharedWeakable::new(Node {
+            parent: SharedWeak::default(),
+            value: 10,
+        });
+
+        let mut child = SharedWeakable::new(Node {
+            parent: parent.downgrade(),
+            value: 20,
+        });
+
+        assert_eq!(parent.weak_count(), 1);
+        assert!(child.as_ref().parent.upgrade().is_some());
+        assert!(parent.as_ref().parent.upgrade().is_none());
+
+        child.get_mut().unwrap().value += 1;
+
+        assert_eq!(
+            child.as_ref().parent.upgrade().unwrap().as_ref().value + child.as_ref().value,
+            31,
+        );
+
+        let weak = parent.downgrade();
+
+        assert_eq!(weak.strong_count(), 1);
+        assert_eq!(parent.into_inner().map(|node| node.value), Some(10));
+        assert!(weak.upgrade().is_none());
+        assert!(child.as_ref().parent.upgrade().is_none());
+    }
+}
@@ -32,8 +32,9 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::{
-    fmt::{Display, Formatter},
+use alloc::vec::Vec;
+use core::{
+    fmt::{Debug, Display, Formatter},
     iter::{Flatten, FusedIterator, Map},
 };
 
@@ -55,7 +56,7 @@ pub enum Key<'a> {
 
 impl<'a> Display for Key<'a> {
     #[inline(always)]
-    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::Name(key) => Display::fmt(key, formatter),
             Self::Index(key) => Display::fmt(key, formatter),
@@ -77,6 +78,88 @@ impl<'a> From<usize> for Key<'a> {
     }
 }
 
+/// A statically typed [capture](AbstractNode::capture) key.
+///
+/// Unlike the untyped [Key], this object carries the type of the node `N`
+/// and the type of the capture value `V` (which is one of [NodeRef],
+/// [TokenRef], `Vec<NodeRef>`, or `Vec<TokenRef>`), such that the capture
+/// lookup returns a properly typed value without matching the [Capture]
+/// variants.
+///
+/// The [Node](lady_deirdre_derive::Node) derive macro generates an
+/// associated constant of this type for each `#[child]` field of
+/// the parsable enum variants. The name of the constant is the variant
+/// name followed by the field name in the upper snake case:
+///
+/// ```ignore
+/// #[derive(Node)]
+/// enum MyNode {
+///     #[rule(...)]
+///     SomeVariant {
+///         #[child]
+///         foo: Vec<NodeRef>,
+///     },
+/// }
+///
+/// // The type of `foos` is `Option<&Vec<NodeRef>>`.
+/// let foos = my_node.typed_capture(&MyNode::SOME_VARIANT_FOO);
+/// ```
+///
+/// The lookup returns None if the node instance is a variant other than
+/// the one to which the key belongs.
+pub struct TypedKey<N, V: ?Sized> {
+    key: Key<'static>,
+    getter: fn(&N) -> Option<&V>,
+}
+
+impl<N, V: ?Sized> Clone for TypedKey<N, V> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<N, V: ?Sized> Copy for TypedKey<N, V> {}
+
+impl<N, V: ?Sized> Debug for TypedKey<N, V> {
+    #[inline(always)]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        formatter.debug_tuple("TypedKey").field(&self.key).finish()
+    }
+}
+
+impl<N, V: ?Sized> TypedKey<N, V> {
+    /// Creates a new typed key.
+    ///
+    /// The `key` parameter is the name of the capture (the variant field's
+    /// name), and the `getter` is a function that borrows the capture value
+    /// from the node instance.
+    ///
+    /// Normally, you don't need to call this function manually, because
+    /// the Node derive macro generates the typed keys automatically.
+    #[inline(always)]
+    pub const fn new(key: &'static str, getter: fn(&N) -> Option<&V>) -> Self {
+        Self {
+            key: Key::Name(key),
+            getter,
+        }
+    }
+
+    /// Returns an untyped [Key] of this capture.
+    #[inline(always)]
+    pub const fn key(&self) -> Key<'static> {
+        self.key
+    }
+
+    /// Borrows the capture value from the `node`.
+    ///
+    /// Returns None if the `node` variant does not have this capture.
+    #[inline(always)]
+    pub fn get<'a>(&self, node: &'a N) -> Option<&'a V> {
+        (self.getter)(node)
+    }
+}
+
 /// A set of the node children grouped together.
 ///
 /// During the syntax tree node parsing, the parser usually captures
@@ -420,6 +503,79 @@ impl<'a, N: AbstractNode + ?Sized> CapturesIter<'a, N> {
     }
 }
 
+/// An iterator over all [captures](Capture) of the [Node](crate::syntax::Node)
+/// interface together with their [keys](Key).
+///
+/// This object is created by the [AbstractNode::captures] function.
+pub struct KeyedCapturesIter<'a, N: AbstractNode + ?Sized> {
+    front: usize,
+    back: usize,
+    keys: &'static [Key<'static>],
+    node: &'a N,
+}
+
+impl<'a, N: AbstractNode + ?Sized> Iterator for KeyedCapturesIter<'a, N> {
+    type Item = (Key<'static>, Capture<'a>);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        let index = self.front;
+
+        self.front += 1;
+
+        self.get(index)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, N: AbstractNode + ?Sized> DoubleEndedIterator for KeyedCapturesIter<'a, N> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+
+        self.get(self.back)
+    }
+}
+
+impl<'a, N: AbstractNode + ?Sized> ExactSizeIterator for KeyedCapturesIter<'a, N> {}
+
+impl<'a, N: AbstractNode + ?Sized> FusedIterator for KeyedCapturesIter<'a, N> {}
+
+impl<'a, N: AbstractNode + ?Sized> KeyedCapturesIter<'a, N> {
+    #[inline(always)]
+    pub(super) fn new(node: &'a N) -> Self {
+        let keys = node.capture_keys();
+
+        Self {
+            front: 0,
+            back: keys.len(),
+            keys,
+            node,
+        }
+    }
+
+    #[inline(always)]
+    fn get(&self, index: usize) -> Option<(Key<'static>, Capture<'a>)> {
+        let key = *self.keys.get(index)?;
+        let capture = self.node.capture(Key::Index(index))?;
+
+        Some((key, capture))
+    }
+}
+
 /// An iterator over all children of the [Node](crate::syntax::Node)
 /// interface.
 ///
@@ -0,0 +1,291 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+
+use crate::{
+    lexis::SiteSpan,
+    syntax::{
+        AbstractNode,
+        NodeRef,
+        ParseNode,
+        ParseNodeChild,
+        ParseTree,
+        PolyRef,
+        PolyVariant,
+        SyntaxTree,
+    },
+    units::CompilationUnit,
+};
+
+/// Checks that the syntax tree spans cover the source code tokens.
+///
+/// The [span](AbstractNode::span) of the syntax tree node is inferred from
+/// the node's captures. If the syntax parser consumes a token outside of
+/// the leftmost and the rightmost captured tokens of the node (including
+/// the tokens of the captured descendant nodes), the syntax tree silently
+/// drops this token: the tools that reproduce the source code text from
+/// the node spans (e.g., code formatters) lose the text of this token.
+///
+/// This function is a debugging utility for the authors of custom syntax
+/// parsers (e.g., the `#[parser(...)]` functions of
+/// the [Node](lady_deirdre_derive::Node) derive macro). It is recommended to
+/// run it in the tests of such grammars together with
+/// the [validate](crate::syntax::validate) function.
+///
+/// The function reparses the source code of the `unit` using
+/// the [ParseTree] to record which parse rule consumed each token,
+/// and reports the following [gaps](CoverageGap):
+///
+///  - [Dropped](CoverageGapKind::Dropped): a sequence of non-blank tokens
+///    consumed by the node's parse rule lies outside of the node's span.
+///  - [Overlap](CoverageGapKind::Overlap): the spans of two children of
+///    the node overlap.
+///  - [Escaped](CoverageGapKind::Escaped): a child's span extends beyond
+///    the span of its parent node.
+///
+/// The blank tokens (whitespaces and line breaks) are ignored, because they
+/// do not contribute to the meaningful source code text. The tokens consumed
+/// by the root node's rule (e.g., the comments at the beginning of the file)
+/// are not reported either, because the root node covers the entire source
+/// code.
+///
+/// Returns an empty vector if the syntax tree covers the source code tokens
+/// exactly.
+pub fn check_coverage<U: CompilationUnit>(unit: &U) -> Vec<CoverageGap> {
+    let mut gaps = Vec::new();
+
+    let parse = ParseTree::<U::Node, U>::new(unit, ..);
+
+    for child in &parse.parse_tree_root().children {
+        if let ParseNodeChild::Node(child) = child {
+            check_dropped(unit, &parse, child, &mut gaps);
+        }
+    }
+
+    let root = unit.root_node_ref();
+
+    if root.is_valid_ref(unit) {
+        check_children(unit, &root, &mut gaps);
+    }
+
+    gaps
+}
+
+/// A fragment of the source code that is not covered by the syntax tree
+/// spans exactly.
+///
+/// The gaps are detected by the [check_coverage] function.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct CoverageGap {
+    /// The site span of the uncovered or overlapped source code fragment.
+    pub span: SiteSpan,
+
+    /// The nearest node that encloses the gap.
+    ///
+    /// For the [Dropped](CoverageGapKind::Dropped) gaps, this is the deepest
+    /// node of the syntax tree whose span covers the gap. For other kinds of
+    /// gaps, this is the parent node of the offending children.
+    pub node_ref: NodeRef,
+
+    /// The kind of the gap.
+    pub kind: CoverageGapKind,
+}
+
+impl Display for CoverageGap {
+    #[inline(always)]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        formatter.write_fmt(format_args!(
+            "{:?} in {:?}: {}",
+            self.span, self.node_ref, self.kind,
+        ))
+    }
+}
+
+/// A kind of the [CoverageGap].
+///
+/// The [Display] implementation of this object prints a human-readable
+/// description of the gap.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum CoverageGapKind {
+    /// The tokens have been consumed by the node's parse rule, but they are
+    /// outside of the node's span.
+    Dropped,
+
+    /// The spans of two child nodes or tokens of the node overlap.
+    Overlap,
+
+    /// The span of the child node or token extends beyond the span of
+    /// the node.
+    Escaped,
+}
+
+impl Display for CoverageGapKind {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Dropped => formatter.write_str("The tokens are outside of the node's span."),
+            Self::Overlap => formatter.write_str("The node's children overlap."),
+            Self::Escaped => formatter.write_str("The child is outside of the node's span."),
+        }
+    }
+}
+
+fn check_dropped<U: CompilationUnit>(
+    unit: &U,
+    parse: &ParseTree<U::Node, U>,
+    node: &ParseNode,
+    gaps: &mut Vec<CoverageGap>,
+) {
+    let span = node.node_ref.span(parse);
+
+    let mut pending: Option<SiteSpan> = None;
+
+    for child in &node.children {
+        match child {
+            ParseNodeChild::Blank(..) => continue,
+
+            ParseNodeChild::Token(token) => {
+                let covered = match &span {
+                    Some(span) => {
+                        span.start <= token.site_span.start && token.site_span.end <= span.end
+                    }
+                    None => false,
+                };
+
+                if !covered {
+                    match &mut pending {
+                        Some(pending) => pending.end = token.site_span.end,
+                        None => pending = Some(token.site_span.clone()),
+                    }
+
+                    continue;
+                }
+            }
+
+            ParseNodeChild::Node(child) => check_dropped(unit, parse, child, gaps),
+        }
+
+        if let Some(span) = pending.take() {
+            report_dropped(unit, span, gaps);
+        }
+    }
+
+    if let Some(span) = pending {
+        report_dropped(unit, span, gaps);
+    }
+}
+
+fn report_dropped(unit: &impl CompilationUnit, span: SiteSpan, gaps: &mut Vec<CoverageGap>) {
+    let mut node_ref = unit.root_node_ref();
+
+    'outer: loop {
+        let Some(node) = node_ref.deref(unit) else {
+            break;
+        };
+
+        for child in node.children_iter() {
+            if !child.kind().is_node() {
+                continue;
+            }
+
+            let Some(child_span) = child.as_node_ref().span(unit) else {
+                continue;
+            };
+
+            if child_span.start <= span.start && span.end <= child_span.end {
+                node_ref = *child.as_node_ref();
+                continue 'outer;
+            }
+        }
+
+        break;
+    }
+
+    gaps.push(CoverageGap {
+        span,
+        node_ref,
+        kind: CoverageGapKind::Dropped,
+    });
+}
+
+fn check_children(unit: &impl CompilationUnit, node_ref: &NodeRef, gaps: &mut Vec<CoverageGap>) {
+    let Some(node) = node_ref.deref(unit) else {
+        return;
+    };
+
+    let span = node.span(unit);
+
+    let mut spans = Vec::new();
+
+    for child in node.children_iter() {
+        let child_span = match child.as_variant() {
+            PolyVariant::Node(child) => {
+                check_children(unit, &child, gaps);
+
+                child.span(unit)
+            }
+
+            PolyVariant::Token(child) => child.span(unit),
+        };
+
+        if let Some(child_span) = child_span {
+            spans.push(child_span);
+        }
+    }
+
+    if let Some(span) = &span {
+        for child_span in &spans {
+            if child_span.start < span.start || child_span.end > span.end {
+                gaps.push(CoverageGap {
+                    span: child_span.clone(),
+                    node_ref: *node_ref,
+                    kind: CoverageGapKind::Escaped,
+                });
+            }
+        }
+    }
+
+    spans.sort_by_key(|span| span.start);
+
+    for pair in spans.windows(2) {
+        if pair[1].start < pair[0].end {
+            gaps.push(CoverageGap {
+                span: pair[1].start..pair[0].end.min(pair[1].end),
+                node_ref: *node_ref,
+                kind: CoverageGapKind::Overlap,
+            });
+        }
+    }
+}
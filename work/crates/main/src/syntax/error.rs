@@ -32,17 +32,17 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::{
-    borrow::Cow,
+use alloc::{borrow::Cow, collections::BTreeSet, string::String, vec::Vec};
+use core::{
     cmp::Ordering,
-    collections::HashSet,
     fmt::{Debug, Display, Formatter},
     marker::PhantomData,
 };
 
+#[cfg(feature = "std")]
+use crate::format::{AnnotationPriority, Diagnostic, SnippetFormatter};
 use crate::{
     arena::{Entry, Id, Identifiable},
-    format::{AnnotationPriority, SnippetFormatter},
     lexis::{
         Length,
         SiteRefSpan,
@@ -54,7 +54,16 @@ use crate::{
         TokenRule,
         TokenSet,
     },
-    syntax::{AbstractNode, Node, NodeRule, NodeSet, RecoveryResult, SyntaxTree, ROOT_RULE},
+    syntax::{
+        AbstractNode,
+        Node,
+        NodeRule,
+        NodeSet,
+        RecoveryDetails,
+        RecoveryResult,
+        SyntaxTree,
+        ROOT_RULE,
+    },
     units::CompilationUnit,
 };
 
@@ -69,7 +78,11 @@ pub static NIL_ERROR_REF: ErrorRef = ErrorRef::nil();
 /// [infallible](crate::syntax::SyntaxSession#parsing-algorithm-considerations)
 /// process. Hence, the syntax error object represents a report of the parser's
 /// error recovery attempt.
+///
+/// The object is non-exhaustive: use the [new](Self::new) constructor to
+/// create an instance, and then set the optional fields if needed.
 #[derive(Clone, PartialEq, Eq, Debug)]
+#[non_exhaustive]
 pub struct SyntaxError {
     /// A [span of tokens](SiteRefSpan) where the error occurred.
     pub span: SiteRefSpan,
@@ -80,14 +93,72 @@ pub struct SyntaxError {
     /// A type of the recovery strategy that has been applied.
     pub recovery: RecoveryResult,
 
+    /// Details of the panic recovery outcome: how many tokens the parser has
+    /// skipped, and why the recovery has halted.
+    ///
+    /// The value is None if the parser did not run the panic recovery
+    /// (e.g., in case of the [insert recovery](RecoveryResult::InsertRecover)).
+    ///
+    /// See [Recovery::recover_with_details](crate::syntax::Recovery::recover_with_details)
+    /// for details.
+    pub recovery_details: Option<RecoveryDetails>,
+
     /// A set of tokens that the parser expected in the [span](Self::span).
     pub expected_tokens: &'static TokenSet,
 
     /// A set of nodes that the parser expected in the [span](Self::span).
     pub expected_nodes: &'static NodeSet,
+
+    /// A custom message of the grammar's error rule that matched the input
+    /// in the [span](Self::span).
+    ///
+    /// The value is None unless the parser has recognized the erroneous input
+    /// using an [error production](RecoveryResult::ErrorProduction).
+    ///
+    /// If specified, the [message](Self::message) function prints this string
+    /// instead of the canonical error message.
+    pub custom_message: Option<&'static str>,
+
+    /// A token that the parser has assumed missing in the input
+    /// (in case of the [insert recovery](RecoveryResult::InsertRecover)),
+    /// or a token that the parser has skipped
+    /// (in case of the [delete recovery](RecoveryResult::DeleteRecover)).
+    ///
+    /// The value is None if the recovery strategy did not involve a particular
+    /// token (e.g., if the parser has assumed a missing node).
+    pub recovery_token: Option<TokenRule>,
 }
 
 impl SyntaxError {
+    /// Creates a syntax error with the specified [span](Self::span),
+    /// [context](Self::context), [recovery](Self::recovery) strategy, and
+    /// the sets of [expected tokens](Self::expected_tokens) and
+    /// [expected nodes](Self::expected_nodes).
+    ///
+    /// The [recovery_details](Self::recovery_details),
+    /// [custom_message](Self::custom_message), and
+    /// [recovery_token](Self::recovery_token) fields of the created object
+    /// are None.
+    #[inline(always)]
+    pub fn new(
+        span: SiteRefSpan,
+        context: NodeRule,
+        recovery: RecoveryResult,
+        expected_tokens: &'static TokenSet,
+        expected_nodes: &'static NodeSet,
+    ) -> Self {
+        Self {
+            span,
+            context,
+            recovery,
+            recovery_details: None,
+            expected_tokens,
+            expected_nodes,
+            custom_message: None,
+            recovery_token: None,
+        }
+    }
+
     /// Returns a displayable object that prints a canonical title of
     /// this syntax error.
     #[inline(always)]
@@ -99,14 +170,14 @@ impl SyntaxError {
 
         impl<'error, N: Node> Debug for Title<'error, N> {
             #[inline(always)]
-            fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+            fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
                 Display::fmt(self, formatter)
             }
         }
 
         impl<'error, N: AbstractNode> Display for Title<'error, N> {
             #[inline(always)]
-            fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+            fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
                 match N::rule_description(self.error.context, true) {
                     Some(context) => formatter.write_fmt(format_args!("{context} syntax error.")),
                     None => formatter.write_str("Syntax error."),
@@ -138,13 +209,13 @@ impl SyntaxError {
 
         impl<'error, N: Node> Debug for Message<'error, N> {
             #[inline(always)]
-            fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+            fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
                 Display::fmt(self, formatter)
             }
         }
 
         impl<'error, N: Node> Display for Message<'error, N> {
-            fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+            fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
                 const LENGTH_MAX: Length = 80;
 
                 #[derive(PartialEq, Eq)]
@@ -188,7 +259,7 @@ impl SyntaxError {
 
                 struct OutString {
                     alt: bool,
-                    set: HashSet<&'static str>,
+                    set: BTreeSet<&'static str>,
                     empty_span: bool,
                     context: Cow<'static, str>,
                     recovery: RecoveryResult,
@@ -204,7 +275,7 @@ impl SyntaxError {
                         context: NodeRule,
                         recovery: RecoveryResult,
                     ) -> Self {
-                        let set = HashSet::with_capacity(capacity);
+                        let set = BTreeSet::new();
 
                         let context = N::rule_description(context, true)
                             .filter(|_| context != ROOT_RULE)
@@ -284,6 +355,17 @@ impl SyntaxError {
                         }
                     }
 
+                    #[inline(always)]
+                    fn unexpected_token_str(&self) -> &'static str {
+                        static STRING: &'static str = "unexpected";
+                        static ALT_STR: &'static str = "Unexpected";
+
+                        match self.alt {
+                            false => STRING,
+                            true => ALT_STR,
+                        }
+                    }
+
                     #[inline(always)]
                     fn in_str(&self) -> &'static str {
                         static STRING: &'static str = " in ";
@@ -342,6 +424,11 @@ impl SyntaxError {
                                 print_components = true;
                             }
 
+                            RecoveryResult::DeleteRecover if !self.components.is_empty() => {
+                                result.push_str(self.unexpected_token_str());
+                                print_components = true;
+                            }
+
                             RecoveryResult::PanicRecover if self.empty_span => {
                                 result.push_str(self.missing_str());
                                 print_components = true;
@@ -361,6 +448,11 @@ impl SyntaxError {
                                 result.push_str(self.missing_str());
                                 print_components = true;
                             }
+
+                            _ => {
+                                result.push_str(self.unexpected_str());
+                                print_components = false;
+                            }
                         };
 
                         if print_components {
@@ -403,6 +495,10 @@ impl SyntaxError {
                     }
                 }
 
+                if let Some(message) = self.error.custom_message {
+                    return formatter.write_str(message);
+                }
+
                 let mut out = OutString::new::<N>(
                     formatter.alternate(),
                     self.error.expected_tokens.len() + self.error.expected_nodes.len(),
@@ -411,12 +507,18 @@ impl SyntaxError {
                     self.error.recovery,
                 );
 
-                for rule in self.error.expected_nodes {
-                    out.push_node::<N>(rule);
-                }
+                match (self.error.recovery, self.error.recovery_token) {
+                    (RecoveryResult::DeleteRecover, Some(rule)) => out.push_token::<N>(rule),
+
+                    _ => {
+                        for rule in self.error.expected_nodes {
+                            out.push_node::<N>(rule);
+                        }
 
-                for rule in self.error.expected_tokens {
-                    out.push_token::<N>(rule);
+                        for rule in self.error.expected_tokens {
+                            out.push_token::<N>(rule);
+                        }
+                    }
                 }
 
                 out.components.sort();
@@ -459,43 +561,66 @@ impl SyntaxError {
 
         impl<'a, U: CompilationUnit> Debug for DisplaySyntaxError<'a, U> {
             #[inline(always)]
-            fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+            fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
                 Display::fmt(self, formatter)
             }
         }
 
         impl<'a, U: CompilationUnit> Display for DisplaySyntaxError<'a, U> {
             #[inline]
-            fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+            fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
                 let aligned_span = self.error.aligned_span(self.unit);
 
-                if !formatter.alternate() {
-                    formatter.write_fmt(format_args!("{}", aligned_span.display(self.unit)))?;
-                    formatter.write_str(": ")?;
-                    formatter.write_fmt(format_args!(
-                        "{:#}",
-                        self.error.message::<U::Node>(self.unit)
-                    ))?;
-
-                    return Ok(());
+                #[cfg(feature = "std")]
+                if formatter.alternate() {
+                    return formatter
+                        .snippet(self.unit)
+                        .set_caption(format!("Unit({})", self.unit.id()))
+                        .set_summary(self.error.title::<U::Node>().to_string())
+                        .annotate(
+                            aligned_span,
+                            AnnotationPriority::Primary,
+                            format!("{}", self.error.message::<U::Node>(self.unit)),
+                        )
+                        .finish();
                 }
 
-                formatter
-                    .snippet(self.unit)
-                    .set_caption(format!("Unit({})", self.unit.id()))
-                    .set_summary(self.error.title::<U::Node>().to_string())
-                    .annotate(
-                        aligned_span,
-                        AnnotationPriority::Primary,
-                        format!("{}", self.error.message::<U::Node>(self.unit)),
-                    )
-                    .finish()
+                formatter.write_fmt(format_args!("{}", aligned_span.display(self.unit)))?;
+                formatter.write_str(": ")?;
+                formatter.write_fmt(format_args!(
+                    "{:#}",
+                    self.error.message::<U::Node>(self.unit)
+                ))?;
+
+                Ok(())
             }
         }
 
         DisplaySyntaxError { error: self, unit }
     }
 
+    /// Returns a machine-readable [Diagnostic] representation of this syntax
+    /// error.
+    ///
+    /// The `unit` parameter provides access to the compilation unit's tokens
+    /// of where the error occurred.
+    ///
+    /// The diagnostic span is the [aligned span](Self::aligned_span) of
+    /// the error, the severity is [Error](crate::format::DiagnosticSeverity::Error),
+    /// and the message is the full canonical [message](Self::message) of
+    /// the error.
+    ///
+    /// This function is available under the `std` feature only.
+    #[cfg(feature = "std")]
+    pub fn to_diagnostic<U: CompilationUnit>(&self, unit: &U) -> Diagnostic {
+        Diagnostic::new(
+            unit,
+            self.aligned_span(unit),
+            AnnotationPriority::Primary,
+            format!("{:#}", self.message::<U::Node>(unit)),
+        )
+    }
+
     /// Computes a [token span](SiteRefSpan) from the syntax error's original
     /// span such that the new span would be properly aligned in regards to
     /// the whitespaces and the line breaks surrounding the original span.
@@ -673,7 +798,13 @@ impl SyntaxError {
 /// to any syntax error within any syntax tree).
 ///
 /// Two distinct instances of the nil ErrorRef are always equal.
+///
+/// When the `serde` feature of this crate is enabled, ErrorRef implements
+/// serde's Serialize and Deserialize traits. A deserialized ErrorRef is
+/// meaningful only against the same syntax tree instance of the same process.
+/// Use the [ErrorRef::is_valid_ref] function to check its validity.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ErrorRef {
     /// An identifier of the syntax tree.
     pub id: Id,
@@ -685,7 +816,7 @@ pub struct ErrorRef {
 
 impl Debug for ErrorRef {
     #[inline]
-    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
         match self.is_nil() {
             false => formatter.write_fmt(format_args!(
                 "ErrorRef(id: {:?}, entry: {:?})",
@@ -32,10 +32,12 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::{
+use alloc::vec::Vec;
+use core::{
     fmt::{Debug, Formatter},
     iter::FusedIterator,
     marker::PhantomData,
+    mem::size_of,
     ops::Range,
 };
 
@@ -49,10 +51,12 @@ use crate::{
         ErrorRef,
         Node,
         NodeRef,
+        NodeRule,
         Observer,
         SyntaxError,
         SyntaxSession,
         SyntaxTree,
+        TreeStats,
         ROOT_RULE,
     },
 };
@@ -81,7 +85,7 @@ impl<N: Node> Eq for ImmutableSyntaxTree<N> {}
 
 impl<N: Node> Debug for ImmutableSyntaxTree<N> {
     #[inline]
-    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
         formatter
             .debug_struct("SyntaxTree")
             .field("id", &self.id())
@@ -179,6 +183,16 @@ impl<N: Node> SyntaxTree for ImmutableSyntaxTree<N> {
 
         self.errors.get(entry.index)
     }
+
+    fn stats(&self) -> TreeStats<Self::Node> {
+        let mut stats = TreeStats::from_nodes(self.nodes.iter().flatten());
+
+        stats.errors = self.errors.len();
+        stats.max_depth = TreeStats::measure_depth(self);
+        stats.approx_heap_bytes = self.heap_size();
+
+        stats
+    }
 }
 
 impl<N: Node> ImmutableSyntaxTree<N> {
@@ -212,9 +226,47 @@ impl<N: Node> ImmutableSyntaxTree<N> {
         Self::parse_with_id_and_observer(SubId::new(), token_cursor, observer)
     }
 
+    /// Parses the token stream as a specific parsing rule.
+    ///
+    /// Unlike the [parse](Self::parse) constructor, which always starts
+    /// parsing from the [root rule](ROOT_RULE), this function descends
+    /// into the rule denoted by the `rule` parameter. The resulting syntax
+    /// tree's [root node](SyntaxTree::root_node_ref) is the node produced by
+    /// this rule.
+    ///
+    /// This function is useful to parse standalone code snippets (e.g.,
+    /// a single expression) without wrapping them into the complete
+    /// compilation unit.
+    ///
+    /// If the rule's parser expects some surrounding context, or if the rule
+    /// does not cover the whole token stream, the parser reports syntax
+    /// errors the same way as it would do in the middle of the file. The
+    /// tokens that the rule did not consume remain unparsed.
+    ///
+    /// **Panic**
+    ///
+    /// The function may panic if the `rule` parameter value is not valid for
+    /// the `N` programming language.
+    #[inline(always)]
+    pub fn parse_rule<'code>(
+        rule: NodeRule,
+        token_cursor: impl TokenCursor<'code, Token = <N as Node>::Token>,
+    ) -> Self {
+        Self::parse_rule_with_id(SubId::new(), rule, token_cursor)
+    }
+
+    #[inline(always)]
     pub(crate) fn parse_with_id<'code, 'observer>(
         id: SubId,
         token_cursor: impl TokenCursor<'code, Token = <N as Node>::Token>,
+    ) -> Self {
+        Self::parse_rule_with_id(id, ROOT_RULE, token_cursor)
+    }
+
+    pub(crate) fn parse_rule_with_id<'code>(
+        id: SubId,
+        rule: NodeRule,
+        token_cursor: impl TokenCursor<'code, Token = <N as Node>::Token>,
     ) -> Self {
         let mut session = ImmutableSyntaxSession {
             id: id.id(),
@@ -226,7 +278,7 @@ impl<N: Node> ImmutableSyntaxTree<N> {
             _phantom: PhantomData,
         };
 
-        let _ = session.descend(ROOT_RULE);
+        let _ = session.descend(rule);
 
         Self {
             id,
@@ -235,6 +287,50 @@ impl<N: Node> ImmutableSyntaxTree<N> {
         }
     }
 
+    // Creates a syntax tree without syntax errors from the nodes stored under
+    // the specified indices.
+    //
+    // Returns None if the indices are not unique, or if the tree does not have
+    // the root node.
+    #[cfg(feature = "serde")]
+    pub(crate) fn restore(id: SubId, nodes: impl IntoIterator<Item = (Entry, N)>) -> Option<Self> {
+        let mut slots = Vec::new();
+
+        for (entry, node) in nodes {
+            if entry.version != 0 {
+                return None;
+            }
+
+            if entry.index >= slots.len() {
+                slots.resize_with(entry.index + 1, || None);
+            }
+
+            // Safety: The vector has been extended to the index.
+            let slot = unsafe { slots.get_unchecked_mut(entry.index) };
+
+            if slot.replace(node).is_some() {
+                return None;
+            }
+        }
+
+        if !matches!(slots.first(), Some(Some(_))) {
+            return None;
+        }
+
+        Some(Self {
+            id,
+            nodes: slots,
+            errors: Vec::new(),
+        })
+    }
+
+    // Returns an approximate number of bytes allocated by the syntax tree.
+    #[inline(always)]
+    pub(crate) fn heap_size(&self) -> usize {
+        self.nodes.capacity() * size_of::<Option<N>>()
+            + self.errors.capacity() * size_of::<SyntaxError>()
+    }
+
     pub(crate) fn parse_with_id_and_observer<'code, 'observer>(
         id: SubId,
         token_cursor: impl TokenCursor<'code, Token = <N as Node>::Token>,
@@ -33,30 +33,52 @@
 ////////////////////////////////////////////////////////////////////////////////
 
 mod captures;
+mod coverage;
 mod error;
 mod immutable;
 mod morphism;
 mod node;
 mod observer;
 mod parse;
+mod path;
+mod pratt;
 mod recovery;
 mod rule;
 mod session;
+mod stats;
+mod trace;
 mod tree;
+mod validate;
 mod void;
 
-pub(crate) use crate::syntax::void::is_void_syntax;
+#[cfg(feature = "std")]
+pub use crate::syntax::observer::StdoutSink;
 pub use crate::syntax::{
-    captures::{Capture, CaptureIntoIter, CapturesIter, ChildrenIter, Key},
+    captures::{
+        Capture,
+        CaptureIntoIter,
+        CapturesIter,
+        ChildrenIter,
+        Key,
+        KeyedCapturesIter,
+        TypedKey,
+    },
+    coverage::{check_coverage, CoverageGap, CoverageGapKind},
     error::{ErrorRef, SyntaxError, NIL_ERROR_REF},
     immutable::ImmutableSyntaxTree,
     morphism::{PolyRef, PolyVariant, RefKind},
     node::{AbstractNode, Node, NodeRef, NIL_NODE_REF},
     observer::{DebugObserver, Observer, VoidObserver},
     parse::{ParseBlank, ParseNode, ParseNodeChild, ParseToken, ParseTree},
-    recovery::{Recovery, RecoveryResult, UNLIMITED_RECOVERY},
+    path::{NodePath, NodePathStep, PathPolicy},
+    pratt::{PrattDriver, PrattOperands},
+    recovery::{Recovery, RecoveryDetails, RecoveryHalt, RecoveryResult, UNLIMITED_RECOVERY},
     rule::{NodeRule, NodeSet, EMPTY_NODE_SET, NON_RULE, ROOT_RULE},
     session::SyntaxSession,
-    tree::{ErrorIter, NodeIter, SyntaxTree, Visitor},
+    stats::TreeStats,
+    trace::{ParseEvent, ParseTrace, TraceMismatch, TraceObserver},
+    tree::{ErrorIter, NodeIter, SyntaxTree, VisitControl, Visitor},
+    validate::{validate, Violation, ViolationKind},
     void::VoidSyntax,
 };
+pub(crate) use crate::syntax::{tree::traverse_in_order, void::is_void_syntax};
@@ -32,14 +32,20 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::{
+use alloc::{
+    borrow::ToOwned,
+    format,
+    string::{String, ToString},
+};
+use core::{
     borrow::Borrow,
     fmt::{Debug, Display, Formatter},
 };
 
+#[cfg(feature = "std")]
+use crate::format::{AnnotationPriority, SnippetConfig, SnippetFormatter};
 use crate::{
     arena::{Id, Identifiable},
-    format::{AnnotationPriority, SnippetConfig, SnippetFormatter},
     lexis::{SiteSpan, ToSpan, Token, TokenRef, NIL_TOKEN_REF},
     report::ld_unreachable,
     syntax::{AbstractNode, NodeRef, NIL_NODE_REF},
@@ -61,7 +67,7 @@ pub enum PolyVariant {
 
 impl Debug for PolyVariant {
     #[inline(always)]
-    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
         match self {
             Self::Token(variant) => Debug::fmt(variant, formatter),
             Self::Node(variant) => Debug::fmt(variant, formatter),
@@ -240,13 +246,13 @@ struct DisplayPolyRef<'unit, U: CompilationUnit> {
 
 impl<'unit, U: CompilationUnit> Debug for DisplayPolyRef<'unit, U> {
     #[inline(always)]
-    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
         Display::fmt(self, formatter)
     }
 }
 
 impl<'unit, U: CompilationUnit> Display for DisplayPolyRef<'unit, U> {
-    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
         let mut summary = String::new();
         let span;
 
@@ -307,14 +313,24 @@ impl<'unit, U: CompilationUnit> Display for DisplayPolyRef<'unit, U> {
             }
         }
 
-        static CONFIG: SnippetConfig = SnippetConfig::verbose();
+        #[cfg(not(feature = "std"))]
+        {
+            let _ = span;
+
+            formatter.write_str(&summary)
+        }
 
-        formatter
-            .snippet(self.unit)
-            .set_config(&CONFIG)
-            .set_caption(format!("Unit({})", self.unit.id()))
-            .set_summary(summary)
-            .annotate(span, AnnotationPriority::Default, "")
-            .finish()
+        #[cfg(feature = "std")]
+        {
+            static CONFIG: SnippetConfig = SnippetConfig::verbose();
+
+            formatter
+                .snippet(self.unit)
+                .set_config(&CONFIG)
+                .set_caption(format!("Unit({})", self.unit.id()))
+                .set_summary(summary)
+                .annotate(span, AnnotationPriority::Default, "")
+                .finish()
+        }
     }
 }
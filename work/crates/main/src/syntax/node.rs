@@ -34,10 +34,17 @@
 
 extern crate lady_deirdre_derive;
 
-use std::fmt::{Debug, Formatter};
+use alloc::string::String;
+use core::{
+    fmt::{self, Debug, Display, Formatter},
+    iter::successors,
+    marker::PhantomData,
+};
 
 pub use lady_deirdre_derive::Node;
 
+#[cfg(feature = "std")]
+use crate::syntax::StdoutSink;
 use crate::{
     arena::{Entry, Id, Identifiable, SubId},
     lexis::{Site, SiteSpan, SourceCode, Token, TokenBuffer, TokenRef, NIL_TOKEN_REF},
@@ -48,12 +55,14 @@ use crate::{
         DebugObserver,
         ImmutableSyntaxTree,
         Key,
+        KeyedCapturesIter,
         NodeRule,
         PolyRef,
         PolyVariant,
         RefKind,
         SyntaxSession,
         SyntaxTree,
+        TypedKey,
         NON_RULE,
     },
     units::CompilationUnit,
@@ -167,14 +176,79 @@ pub trait Node: AbstractNode + Sized {
     ///
     /// This function runs the parsing algorithm on the `text` source code
     /// and prints parsing steps to the terminal (stdout).
+    ///
+    /// This is a convenience function that writes the same output as the
+    /// [Node::debug_to] function.
+    ///
+    /// This function is available only when the `std` feature of this crate
+    /// is enabled.
+    #[cfg(feature = "std")]
     fn debug(text: impl AsRef<str>) {
+        let _ = Self::debug_to(text, &mut StdoutSink);
+    }
+
+    /// Debugs the syntax parsing algorithm for this node type writing parsing
+    /// steps into the `output` sink.
+    ///
+    /// This function runs the parsing algorithm on the `text` source code
+    /// and writes each parsing step into a separate line of the `output`
+    /// using the [DebugObserver].
+    ///
+    /// The output format is stable: it contains rule and token names only,
+    /// without any memory addresses or [Entry] numbers, such that you can use
+    /// the output in golden tests.
+    ///
+    /// Returns an error if the `output` sink failed to write.
+    fn debug_to(text: impl AsRef<str>, output: &mut impl fmt::Write) -> fmt::Result {
         let tokens = TokenBuffer::<Self::Token>::from(text);
 
+        let mut observer = DebugObserver::<Self, _>::new(output);
+
         ImmutableSyntaxTree::<Self>::parse_with_id_and_observer(
             SubId::fork(tokens.id()),
             tokens.cursor(..),
-            &mut DebugObserver::default(),
+            &mut observer,
         );
+
+        observer.result()
+    }
+
+    /// Debugs the syntax parsing algorithm for this node type returning
+    /// parsing steps as a String.
+    ///
+    /// The output is the same as the output of the [Node::debug_to] function.
+    #[inline(always)]
+    fn debug_string(text: impl AsRef<str>) -> String {
+        let mut output = String::new();
+
+        let _ = Self::debug_to(text, &mut output);
+
+        output
+    }
+
+    /// Returns an object that writes parsing steps of the `text` source code
+    /// through the [Display] interface.
+    ///
+    /// The syntax parser runs each time the object is formatted. The output is
+    /// the same as the output of the [Node::debug_to] function.
+    #[inline(always)]
+    fn debug_display(text: impl AsRef<str>) -> impl Display {
+        DebugDisplay::<Self> {
+            text: String::from(text.as_ref()),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+struct DebugDisplay<N: Node> {
+    text: String,
+    _phantom: PhantomData<N>,
+}
+
+impl<N: Node> Display for DebugDisplay<N> {
+    #[inline(always)]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        N::debug_to(&self.text, formatter)
     }
 }
 
@@ -371,6 +445,22 @@ pub trait AbstractNode: Send + Sync + 'static {
     /// ```
     fn capture(&self, key: Key) -> Option<Capture>;
 
+    /// Returns a statically typed set of children of this node.
+    ///
+    /// Unlike the [capture](Self::capture) function, the returning value is
+    /// typed according to the capture's [TypedKey]. The Node derive macro
+    /// generates such keys as associated constants of the node type
+    /// (e.g., `MyNode::VARIANT_CAPTURE_1`).
+    ///
+    /// Returns None if this node instance does not have the requested capture.
+    #[inline(always)]
+    fn typed_capture<V: ?Sized>(&self, key: &TypedKey<Self, V>) -> Option<&V>
+    where
+        Self: Sized,
+    {
+        key.get(self)
+    }
+
     /// Returns the first set of children of this node.
     ///
     /// Returns None if there are no known captures in this node instance.
@@ -412,6 +502,27 @@ pub trait AbstractNode: Send + Sync + 'static {
         CapturesIter::new(self)
     }
 
+    /// Returns an iterator over all capture values together with their
+    /// [keys](Key).
+    ///
+    /// The iterator yields the captures in the order of
+    /// the [capture_keys](Self::capture_keys) array, and the total number of
+    /// items is the [captures_len](Self::captures_len) value. For the nodes
+    /// generated by the [Node](lady_deirdre_derive::Node) derive macro,
+    /// this order is the declaration order of the `#[child]` fields of
+    /// the variant, and the keys are the [Key::Name] keys of these fields.
+    ///
+    /// This function is useful for generic tooling (e.g., serializers or
+    /// structural search) that inspects the node captures without knowing
+    /// their keys upfront.
+    #[inline(always)]
+    fn captures(&self) -> KeyedCapturesIter<Self>
+    where
+        Self: Sized,
+    {
+        KeyedCapturesIter::new(self)
+    }
+
     /// Returns an iterator over all children of this node.
     ///
     /// This is a version of the [captures_iter](Self::captures_iter) that
@@ -600,7 +711,18 @@ pub trait AbstractNode: Send + Sync + 'static {
 /// any syntax tree).
 ///
 /// Two distinct instances of the nil NodeRef are always equal.
+///
+/// When the `serde` feature of this crate is enabled, you can serialize and
+/// deserialize NodeRefs, for example, to persist an index of the syntax tree
+/// nodes or to send the references to another thread over IPC. A deserialized
+/// NodeRef is meaningful only against the same syntax tree instance in the same
+/// process, because the [Id] part is unique per process only. Since the
+/// [versioned index](Entry) round-trips exactly, a NodeRef of the node that has
+/// been removed after serialization remains invalid after deserialization.
+/// Check the deserialized NodeRef using the [NodeRef::is_valid_ref] function
+/// (or just [dereference](NodeRef::deref) it) before use.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NodeRef {
     /// An identifier of the syntax tree.
     pub id: Id,
@@ -611,7 +733,7 @@ pub struct NodeRef {
 
 impl Debug for NodeRef {
     #[inline]
-    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
         match self.is_nil() {
             false => formatter.write_fmt(format_args!(
                 "NodeRef(id: {:?}, entry: {:?})",
@@ -765,6 +887,51 @@ impl NodeRef {
         node.parent_ref()
     }
 
+    /// Returns an iterator over the ancestor nodes of the referred node.
+    ///
+    /// The iterator starts from the [parent](Self::parent) of the referred
+    /// node (the referred node itself is not included), and ends with the
+    /// root node of the `tree`.
+    ///
+    /// The iterator is empty if this NodeRef is not valid for the specified
+    /// `tree`, or if the node instance does not have a parent.
+    pub fn ancestors<'tree>(
+        &self,
+        tree: &'tree impl SyntaxTree,
+    ) -> impl Iterator<Item = NodeRef> + 'tree {
+        let parent = self.parent(tree);
+
+        successors((!parent.is_nil()).then_some(parent), move |current| {
+            let parent = current.parent(tree);
+
+            (!parent.is_nil()).then_some(parent)
+        })
+    }
+
+    /// Returns a reference to the closest ancestor node of the referred node
+    /// that has been parsed by the specified `rule`.
+    ///
+    /// Returns [nil](NodeRef::nil) if this NodeRef is not valid for
+    /// the specified `tree`, or if there is no such ancestor.
+    ///
+    /// See [ancestors](Self::ancestors) for details.
+    pub fn find_ancestor(&self, tree: &impl SyntaxTree, rule: NodeRule) -> NodeRef {
+        self.ancestors(tree)
+            .find(|ancestor| ancestor.rule(tree) == rule)
+            .unwrap_or_default()
+    }
+
+    /// Returns the number of ancestors of the referred node.
+    ///
+    /// The root node has a zero depth. The function also returns zero if
+    /// this NodeRef is not valid for the specified `tree`.
+    ///
+    /// See [ancestors](Self::ancestors) for details.
+    #[inline(always)]
+    pub fn depth(&self, tree: &impl SyntaxTree) -> usize {
+        self.ancestors(tree).count()
+    }
+
     /// Returns a reference to the first child node of the referred node.
     ///
     /// Returns [nil](NodeRef::nil) if this NodeRef is not valid for
@@ -855,9 +1022,20 @@ impl NodeRef {
     /// Returns a previous sibling node of the node referred to by this NodeRef
     /// within the node's parent.
     ///
+    /// The siblings are the node children of the parent node in the order of
+    /// the parent's [captures](AbstractNode::captures_iter). The token
+    /// children of the parent node are skipped regardless of their position
+    /// between the node children. The nodes stored in the multi-node captures
+    /// (e.g., `Vec<NodeRef>` fields) are siblings of each other as well as
+    /// of the nodes in the neighbouring captures.
+    ///
+    /// The [nil](NodeRef::nil) child references (e.g., the references left by
+    /// the error recovery in place of the missing nodes) are skipped too.
+    ///
     /// Returns [nil](NodeRef::nil) if this NodeRef is not valid for
-    /// the specified `tree`, or if the referred node does not have a preceded
-    /// sibling.
+    /// the specified `tree`, if the referred node is the root node (or any
+    /// other node without a parent), or if the referred node does not have
+    /// a preceded sibling.
     pub fn prev_sibling(&self, tree: &impl SyntaxTree) -> NodeRef {
         let Some(node) = self.deref(tree) else {
             return NodeRef::nil();
@@ -867,19 +1045,32 @@ impl NodeRef {
             return NodeRef::nil();
         };
 
-        let Some(sibling) = parent.prev_child_node(self) else {
+        let mut siblings = parent
+            .children_iter()
+            .rev()
+            .filter(|child| child.kind().is_node())
+            .map(|child| child.as_node_ref());
+
+        if !siblings.any(|sibling| sibling == self) {
             return NodeRef::nil();
-        };
+        }
 
-        *sibling
+        siblings
+            .find(|sibling| !sibling.is_nil())
+            .copied()
+            .unwrap_or_default()
     }
 
     /// Returns a next sibling node of the node referred to by this NodeRef
     /// within the node's parent.
     ///
+    /// See [prev_sibling](Self::prev_sibling) for the details on how
+    /// the function treats token children, nil children, and the root node.
+    ///
     /// Returns [nil](NodeRef::nil) if this NodeRef is not valid for
-    /// the specified `tree`, or if the referred node does not have a successive
-    /// sibling.
+    /// the specified `tree`, if the referred node is the root node (or any
+    /// other node without a parent), or if the referred node does not have
+    /// a successive sibling.
     pub fn next_sibling(&self, tree: &impl SyntaxTree) -> NodeRef {
         let Some(node) = self.deref(tree) else {
             return NodeRef::nil();
@@ -889,11 +1080,59 @@ impl NodeRef {
             return NodeRef::nil();
         };
 
-        let Some(sibling) = parent.next_child_node(self) else {
+        let mut siblings = parent
+            .children_iter()
+            .filter(|child| child.kind().is_node())
+            .map(|child| child.as_node_ref());
+
+        if !siblings.any(|sibling| sibling == self) {
             return NodeRef::nil();
-        };
+        }
 
-        *sibling
+        siblings
+            .find(|sibling| !sibling.is_nil())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns the index of the node referred to by this NodeRef among
+    /// the node children of its parent.
+    ///
+    /// The function counts the node children of the parent in the order of
+    /// the parent's [captures](AbstractNode::captures_iter) and skips
+    /// the token children. For example, in the following node the index of
+    /// the `items[0]` node is 0 despite the preceding `start` token capture:
+    ///
+    /// ```ignore
+    /// #[derive(Node)]
+    /// enum MyNode {
+    ///     #[rule(start: $Open items: Item* end: $Close)]
+    ///     List {
+    ///         #[parent]
+    ///         parent: NodeRef,
+    ///         #[child]
+    ///         start: TokenRef,
+    ///         #[child]
+    ///         items: Vec<NodeRef>,
+    ///         #[child]
+    ///         end: TokenRef,
+    ///     },
+    /// }
+    /// ```
+    ///
+    /// Returns None if this NodeRef is not valid for the specified `tree`,
+    /// if the referred node is the root node (or any other node without
+    /// a parent), or if the parent node does not capture the referred node
+    /// as a child.
+    pub fn child_index_in_parent(&self, tree: &impl SyntaxTree) -> Option<usize> {
+        let node = self.deref(tree)?;
+
+        let parent = node.parent_ref().deref(tree)?;
+
+        parent
+            .children_iter()
+            .filter(|child| child.kind().is_node())
+            .position(|child| child.as_node_ref() == self)
     }
 
     /// Returns true if the node referred to by this NodeRef exists in the specified
@@ -32,7 +32,8 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::{marker::PhantomData, mem::replace};
+use alloc::{string::String, vec::Vec};
+use core::{fmt, marker::PhantomData, mem::replace};
 
 use crate::{
     arena::{Entry, EntryIndex, Id, Identifiable},
@@ -50,7 +51,7 @@ use crate::{
 /// tree parsing process.
 ///
 /// In particular, the [DebugObserver] implementation of this trait prints
-/// such interactions to the stdio for debugging purposes.
+/// such interactions into a text sink for debugging purposes.
 pub trait Observer {
     /// Specifies a type of the Node that is currently being parsed.
     type Node: Node;
@@ -93,46 +94,77 @@ pub trait Observer {
     /// The `error_ref` parameter specifies an [ErrorRef] reference
     /// of the syntax tree's syntax error.
     fn syntax_error(&mut self, error_ref: ErrorRef);
+
+    /// The parser reported an error recovery decision.
+    ///
+    /// The `error` parameter specifies the syntax error that the parser
+    /// reports as an outcome of the recovery. The
+    /// [recovery](SyntaxError::recovery) field of the error describes
+    /// the applied recovery strategy.
+    ///
+    /// The parser calls this function before
+    /// the [syntax_error](Self::syntax_error) function. Note that the parser
+    /// does not report a syntax error if the previous error has been reported
+    /// at the same token, but it reports the recovery decision anyway.
+    ///
+    /// The default implementation does nothing.
+    #[inline(always)]
+    fn recover(&mut self, error: &SyntaxError) {
+        let _ = error;
+    }
 }
 
-/// An [observer](Observer) that prints parsing steps to stdin.
+/// An [observer](Observer) that writes parsing steps into a text sink.
 ///
 /// You can provide this function to
 /// the [ImmutableSyntaxTree::parse_with_observer](crate::syntax::ImmutableSyntaxTree::parse_with_observer)
 /// to test particular syntax parser steps against an arbitrary token input.
 ///
-/// Alternatively, consider using [Node::debug] where you can use arbitrary text
-/// instead of a predefined token stream.
-pub struct DebugObserver<N: Node> {
+/// The `W` generic parameter is an arbitrary [fmt::Write] sink where
+/// the observer writes its output. By default, the observer prints parsing
+/// steps to the terminal (stdout) through the [StdoutSink]. The output
+/// format is stable: each line is indented by the rule nesting depth and
+/// contains the token or rule name only, without any memory addresses
+/// or [Entry] numbers, such that the output is suitable for golden tests.
+///
+/// If the sink returns an error, the observer stops writing, and the error is
+/// available through the [DebugObserver::result] function.
+///
+/// Alternatively, consider using [Node::debug], [Node::debug_to], or
+/// [Node::debug_string] where you can use arbitrary text instead of
+/// a predefined token stream.
+pub struct DebugObserver<
+    N: Node,
+    #[cfg(feature = "std")] W: fmt::Write = StdoutSink,
+    #[cfg(not(feature = "std"))] W: fmt::Write,
+> {
     depth: usize,
+    output: W,
+    result: fmt::Result,
     _phantom: PhantomData<N>,
 }
 
+#[cfg(feature = "std")]
 impl<N: Node> Default for DebugObserver<N> {
     #[inline(always)]
     fn default() -> Self {
-        Self {
-            depth: 0,
-            _phantom: PhantomData,
-        }
+        Self::new(StdoutSink)
     }
 }
 
-impl<N: Node> Observer for DebugObserver<N> {
+impl<N: Node, W: fmt::Write> Observer for DebugObserver<N, W> {
     type Node = N;
 
     fn read_token(&mut self, token: <Self::Node as Node>::Token, _token_ref: TokenRef) {
-        let indent = self.indent();
         let name = token.name().unwrap_or("?");
 
-        println!("{indent} ${name}");
+        self.write_line(format_args!("${name}"));
     }
 
     fn enter_rule(&mut self, rule: NodeRule, _node_ref: NodeRef) {
-        let indent = self.indent();
         let name = N::rule_name(rule).unwrap_or("?");
 
-        println!("{indent} {name} {{");
+        self.write_line(format_args!("{name} {{"));
 
         self.depth += 1;
     }
@@ -140,27 +172,74 @@ impl<N: Node> Observer for DebugObserver<N> {
     fn leave_rule(&mut self, rule: NodeRule, _node_ref: NodeRef) {
         self.depth = self.depth.checked_sub(1).unwrap_or_default();
 
-        let indent = self.indent();
         let name = N::rule_name(rule).unwrap_or("?");
 
-        println!("{indent} }} {name}");
+        self.write_line(format_args!("}} {name}"));
     }
 
     fn lift_node(&mut self, _node_ref: NodeRef) {
-        let indent = self.indent();
-        println!("{indent} --- lift ---",);
+        self.write_line(format_args!("--- lift ---"));
     }
 
     fn syntax_error(&mut self, _error_ref: ErrorRef) {
-        let indent = self.indent();
-        println!("{indent} --- error ---",);
+        self.write_line(format_args!("--- error ---"));
     }
 }
 
-impl<N: Node> DebugObserver<N> {
+impl<N: Node, W: fmt::Write> DebugObserver<N, W> {
+    /// Creates a debug observer that writes parsing steps into
+    /// the `output` sink.
     #[inline(always)]
-    fn indent(&self) -> String {
-        "    ".repeat(self.depth)
+    pub fn new(output: W) -> Self {
+        Self {
+            depth: 0,
+            output,
+            result: Ok(()),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns an error if the underlying sink failed to write
+    /// some of the parsing steps.
+    #[inline(always)]
+    pub fn result(&self) -> fmt::Result {
+        self.result
+    }
+
+    /// Returns the underlying sink.
+    #[inline(always)]
+    pub fn into_output(self) -> W {
+        self.output
+    }
+
+    fn write_line(&mut self, line: fmt::Arguments) {
+        if self.result.is_err() {
+            return;
+        }
+
+        let indent = "    ".repeat(self.depth);
+
+        self.result = self.output.write_fmt(format_args!("{indent} {line}\n"));
+    }
+}
+
+/// A [fmt::Write] sink that prints everything to the terminal (stdout).
+///
+/// This is the default sink of the [DebugObserver].
+///
+/// This object is available only when the `std` feature of this crate
+/// is enabled.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct StdoutSink;
+
+#[cfg(feature = "std")]
+impl fmt::Write for StdoutSink {
+    #[inline(always)]
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        print!("{string}");
+
+        Ok(())
     }
 }
 
@@ -437,6 +516,8 @@ where
 
     #[inline(always)]
     fn failure(&mut self, error: SyntaxError) -> ErrorRef {
+        self.observer.recover(&error);
+
         if self.failing {
             return ErrorRef::nil();
         }
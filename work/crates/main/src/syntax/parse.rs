@@ -32,7 +32,8 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::{
+use alloc::{format, string::String, vec::Vec};
+use core::{
     fmt::{Debug, Formatter},
     marker::PhantomData,
     mem::replace,
@@ -52,6 +53,7 @@ use crate::{
         TokenBuffer,
         TokenRef,
         TokenRule,
+        MISMATCH,
     },
     report::ld_unreachable,
     syntax::{ErrorRef, ImmutableSyntaxTree, Node, NodeRef, NodeRule, Observer, ROOT_RULE},
@@ -136,7 +138,7 @@ impl<'a, N: Node, C: SourceCode<Token = N::Token>> CompilationUnit for ParseTree
 
 impl<'a, N: Node, C: SourceCode<Token = N::Token>> Debug for ParseTree<'a, N, C> {
     #[inline(always)]
-    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
         self.root.debug("", self, formatter)
     }
 }
@@ -186,10 +188,28 @@ impl<'a, N: Node, C: SourceCode<Token = N::Token>> ParseTree<'a, N, C> {
     }
 
     /// Grants mutable access to the root node of the parse tree.
+    ///
+    /// You can use this function together with the [ParseNode] editing
+    /// functions (e.g., [ParseNode::replace_child]) to rewrite the parse tree,
+    /// and then reproduce the source code text using
+    /// the [render](Self::render) function.
     #[inline(always)]
     pub fn parse_tree_root_mut(&mut self) -> &mut ParseNode {
         &mut self.root
     }
+
+    /// Reproduces the source code text covered by this parse tree.
+    ///
+    /// The original tokens (including whitespaces and comments) are printed
+    /// as they are in the source code, and the [text tokens](ParseToken::text)
+    /// inserted into the tree are printed from their text.
+    ///
+    /// If the parse tree has not been edited, the returning string equals
+    /// the text of the parsed source code fragment.
+    #[inline(always)]
+    pub fn render(&self) -> String {
+        self.root.render(self.code)
+    }
 }
 
 /// A child of the parse tree [node](ParseNode).
@@ -205,6 +225,27 @@ pub enum ParseNodeChild {
     Node(ParseNode),
 }
 
+impl From<ParseBlank> for ParseNodeChild {
+    #[inline(always)]
+    fn from(child: ParseBlank) -> Self {
+        Self::Blank(child)
+    }
+}
+
+impl From<ParseToken> for ParseNodeChild {
+    #[inline(always)]
+    fn from(child: ParseToken) -> Self {
+        Self::Token(child)
+    }
+}
+
+impl From<ParseNode> for ParseNodeChild {
+    #[inline(always)]
+    fn from(child: ParseNode) -> Self {
+        Self::Node(child)
+    }
+}
+
 impl ParseNodeChild {
     /// Returns the number of line breaks covered by this child.
     #[inline(always)]
@@ -246,12 +287,83 @@ impl ParseNodeChild {
         }
     }
 
+    /// Returns the column-line span covered by this child.
+    #[inline(always)]
+    pub fn position_span(&self) -> &PositionSpan {
+        match self {
+            ParseNodeChild::Blank(child) => &child.position_span,
+            ParseNodeChild::Token(child) => &child.position_span,
+            ParseNodeChild::Node(child) => &child.position_span,
+        }
+    }
+
+    /// Reproduces the source code text of this child.
+    ///
+    /// The `code` parameter is the source code from which the parse tree
+    /// has been created.
+    ///
+    /// See [ParseTree::render] for details.
+    #[inline(always)]
+    pub fn render(&self, code: &impl SourceCode) -> String {
+        let mut result = String::new();
+
+        self.render_into(code, &mut result);
+
+        result
+    }
+
+    fn render_into(&self, code: &impl SourceCode, target: &mut String) {
+        match self {
+            ParseNodeChild::Blank(child) => {
+                for token_ref in &child.children {
+                    if let Some(string) = token_ref.string(code) {
+                        target.push_str(string);
+                    }
+                }
+            }
+
+            ParseNodeChild::Token(child) => {
+                if let Some(text) = &child.text {
+                    target.push_str(text);
+                    return;
+                }
+
+                if let Some(string) = child.token_ref.string(code) {
+                    target.push_str(string);
+                }
+            }
+
+            ParseNodeChild::Node(child) => {
+                for child in &child.children {
+                    child.render_into(code, target);
+                }
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn site_span(&self) -> &SiteSpan {
+        match self {
+            ParseNodeChild::Blank(child) => &child.site_span,
+            ParseNodeChild::Token(child) => &child.site_span,
+            ParseNodeChild::Node(child) => &child.site_span,
+        }
+    }
+
+    #[inline(always)]
+    fn as_text_token_mut(&mut self) -> Option<&mut ParseToken> {
+        match self {
+            ParseNodeChild::Token(child) if child.text.is_some() => Some(child),
+            _ => None,
+        }
+    }
+
     fn debug<'a, N: Node, C: SourceCode<Token = N::Token>>(
         &self,
         indent: &str,
         tree: &ParseTree<'a, N, C>,
         formatter: &mut Formatter<'_>,
-    ) -> std::fmt::Result {
+    ) -> core::fmt::Result {
         match self {
             ParseNodeChild::Blank(child) => child.debug(indent, tree, formatter),
             ParseNodeChild::Token(child) => child.debug(indent, tree, formatter),
@@ -309,7 +421,7 @@ impl ParseBlank {
         indent: &str,
         tree: &ParseTree<'a, N, C>,
         formatter: &mut Formatter<'_>,
-    ) -> std::fmt::Result {
+    ) -> core::fmt::Result {
         let span = self.position_span.display(tree);
 
         formatter.write_fmt(format_args!("{indent}<blank> [{span}]"))
@@ -331,9 +443,51 @@ pub struct ParseToken {
 
     /// The column-line span covered by this token.
     pub position_span: PositionSpan,
+
+    // A user-defined text that substitutes the source code text of
+    // this token.
+    text: Option<String>,
 }
 
 impl ParseToken {
+    /// Creates a text token with a user-defined `text`.
+    ///
+    /// The created token does not refer to any token in the source code:
+    /// its [token_ref](Self::token_ref) is nil, and
+    /// its [rule](Self::rule) is [MISMATCH].
+    ///
+    /// The [site_span](Self::site_span) and
+    /// the [position_span](Self::position_span) of the created object are
+    /// empty spans, which will be updated by the editing functions
+    /// on insertion.
+    #[inline(always)]
+    pub fn from_text(text: impl Into<String>) -> Self {
+        Self {
+            rule: MISMATCH,
+            token_ref: TokenRef::nil(),
+            site_span: 0..0,
+            position_span: Position::default()..Position::default(),
+            text: Some(text.into()),
+        }
+    }
+
+    /// Returns the user-defined text that substitutes the source code text
+    /// of this token.
+    ///
+    /// The parse tree never sets this text by itself. You can insert
+    /// text tokens created by the [from_text](Self::from_text) constructor
+    /// using the [ParseNode] editing functions
+    /// (e.g., [ParseNode::replace_child]) to substitute the original source
+    /// code fragments with a new text.
+    ///
+    /// If this function returns Some, the [ParseTree::render] function prints
+    /// this text instead of the text of the [token_ref](Self::token_ref)
+    /// token.
+    #[inline(always)]
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
     /// Returns the number of line breaks covered by this token.
     #[inline(always)]
     pub fn breaks(&self) -> usize {
@@ -358,7 +512,11 @@ impl ParseToken {
         indent: &str,
         tree: &ParseTree<'a, N, C>,
         formatter: &mut Formatter<'_>,
-    ) -> std::fmt::Result {
+    ) -> core::fmt::Result {
+        if let Some(text) = &self.text {
+            return formatter.write_fmt(format_args!("{indent}<text> {text:?}"));
+        }
+
         let name = <N::Token as Token>::rule_name(self.rule).unwrap_or("?");
 
         let span = self.position_span.display(tree);
@@ -409,13 +567,135 @@ impl ParseNode {
         self.position_span.end.line
     }
 
+    /// Replaces a child of this node at the `index` position with
+    /// the `child` object.
+    ///
+    /// The surrounding children, including the blank children, remain
+    /// untouched.
+    ///
+    /// If the `child` is a [text token](ParseToken::text), the function sets
+    /// its site and column-line spans to the spans of the replaced child.
+    ///
+    /// Returns the replaced child, or None if the `index` is out of bounds.
+    /// In the latter case, the node remains unchanged.
+    pub fn replace_child(
+        &mut self,
+        index: usize,
+        child: impl Into<ParseNodeChild>,
+    ) -> Option<ParseNodeChild> {
+        let current = self.children.get_mut(index)?;
+
+        let mut child = child.into();
+
+        if let Some(text) = child.as_text_token_mut() {
+            text.site_span = current.site_span().clone();
+            text.position_span = current.position_span().clone();
+        }
+
+        Some(replace(current, child))
+    }
+
+    /// Inserts the `child` object into this node right after the child at
+    /// the `index` position.
+    ///
+    /// If the `child` is a [text token](ParseToken::text), the function sets
+    /// its site and column-line spans to the empty spans at the end of
+    /// the preceding child.
+    ///
+    /// Returns false if the `index` is out of bounds. In this case, the node
+    /// remains unchanged.
+    ///
+    /// To insert a child at the beginning of the node, use
+    /// the [insert_child_before](Self::insert_child_before) function.
+    pub fn insert_child_after(&mut self, index: usize, child: impl Into<ParseNodeChild>) -> bool {
+        let Some(current) = self.children.get(index) else {
+            return false;
+        };
+
+        let mut child = child.into();
+
+        if let Some(text) = child.as_text_token_mut() {
+            let site = current.site_span().end;
+            let position = current.position_span().end;
+
+            text.site_span = site..site;
+            text.position_span = position..position;
+        }
+
+        self.children.insert(index + 1, child);
+
+        true
+    }
+
+    /// Inserts the `child` object into this node right before the child at
+    /// the `index` position.
+    ///
+    /// If the `index` equals the number of children, the function appends
+    /// the `child` to the end of the node.
+    ///
+    /// If the `child` is a [text token](ParseToken::text), the function sets
+    /// its site and column-line spans to the empty spans at the insertion
+    /// point.
+    ///
+    /// Returns false if the `index` is out of bounds. In this case, the node
+    /// remains unchanged.
+    pub fn insert_child_before(&mut self, index: usize, child: impl Into<ParseNodeChild>) -> bool {
+        if index > self.children.len() {
+            return false;
+        }
+
+        let mut child = child.into();
+
+        if let Some(text) = child.as_text_token_mut() {
+            let (site, position) = match self.children.get(index) {
+                Some(current) => (current.site_span().start, current.position_span().start),
+                None => (self.site_span.end, self.position_span.end),
+            };
+
+            text.site_span = site..site;
+            text.position_span = position..position;
+        }
+
+        self.children.insert(index, child);
+
+        true
+    }
+
+    /// Removes a child of this node at the `index` position.
+    ///
+    /// Returns the removed child, or None if the `index` is out of bounds.
+    #[inline(always)]
+    pub fn remove_child(&mut self, index: usize) -> Option<ParseNodeChild> {
+        if index >= self.children.len() {
+            return None;
+        }
+
+        Some(self.children.remove(index))
+    }
+
+    /// Reproduces the source code text covered by this node.
+    ///
+    /// The `code` parameter is the source code from which the parse tree
+    /// has been created.
+    ///
+    /// See [ParseTree::render] for details.
+    pub fn render(&self, code: &impl SourceCode) -> String {
+        let mut result = String::new();
+
+        for child in &self.children {
+            child.render_into(code, &mut result);
+        }
+
+        result
+    }
+
     #[inline(always)]
     fn debug<'a, N: Node, C: SourceCode<Token = N::Token>>(
         &self,
         indent: &str,
         tree: &ParseTree<'a, N, C>,
         formatter: &mut Formatter<'_>,
-    ) -> std::fmt::Result {
+    ) -> core::fmt::Result {
         let name = N::rule_name(self.rule).unwrap_or("?");
 
         let span = self.position_span.display(tree);
@@ -522,6 +802,7 @@ where
             token_ref,
             site_span: start_site..end_site,
             position_span: start_position..end_position,
+            text: None,
         }));
     }
 
@@ -0,0 +1,244 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{
+    arena::Identifiable,
+    syntax::{AbstractNode, Capture, Key, NodeRef, NodeRule, PolyRef, SyntaxTree},
+};
+
+/// A structural address of the syntax tree node.
+///
+/// Unlike the [NodeRef], which refers to a particular node instance and
+/// becomes invalid once the incremental reparser replaces this instance,
+/// the NodePath describes a route to the node from the root of the syntax
+/// tree: a sequence of [steps](NodePathStep), each of which denotes
+/// the [capture](AbstractNode::capture) of the parent node and the index of
+/// the child within this capture.
+///
+/// As such, the NodePath remains meaningful across the document edits and
+/// even across the syntax tree instances (e.g., between editor sessions),
+/// and can be used to persist the external annotations (bookmarks, review
+/// comments, etc.) attached to the syntax tree nodes.
+///
+/// You create the NodePath from the NodeRef using the [NodePath::new]
+/// function, and later re-locate the node in the current syntax tree using
+/// the [NodePath::resolve] function.
+///
+/// ```ignore
+/// let path = NodePath::new(&doc, &entry_ref).unwrap();
+///
+/// doc.write(.., "...");
+///
+/// let entry_ref = path.resolve(&doc, PathPolicy::Nearest);
+/// ```
+///
+/// When the `serde` feature of this crate is enabled, the NodePath can be
+/// serialized and deserialized. In contrast to the NodeRef, the deserialized
+/// NodePath is meaningful against any syntax tree of the same grammar.
+#[derive(Clone, Default, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NodePath {
+    /// The steps of the path from the root node to the addressed node.
+    ///
+    /// An empty vector addresses the root node.
+    pub steps: Vec<NodePathStep>,
+}
+
+impl NodePath {
+    /// Computes a path from the root of the `tree` to the node referred to
+    /// by the `node_ref`.
+    ///
+    /// Returns None if the `node_ref` is not valid for the specified `tree`,
+    /// or if the node is not reachable from the root node through
+    /// the [captures](AbstractNode::capture) of its ancestors.
+    pub fn new(tree: &impl SyntaxTree, node_ref: &NodeRef) -> Option<Self> {
+        if node_ref.id != tree.id() {
+            return None;
+        }
+
+        let root = tree.root_node_ref();
+
+        let mut steps = Vec::new();
+        let mut current = *node_ref;
+
+        while current != root {
+            let node = current.deref(tree)?;
+            let parent = node.parent_ref().deref(tree)?;
+
+            let (key, index) = parent.captures().find_map(|(key, capture)| {
+                if !capture.kind().is_node() {
+                    return None;
+                }
+
+                let index = capture
+                    .into_iter()
+                    .position(|child| child.as_node_ref() == &current)?;
+
+                Some((key.to_string(), index))
+            })?;
+
+            steps.push(NodePathStep {
+                rule: node.rule(),
+                key,
+                index,
+            });
+
+            current = node.parent_ref();
+        }
+
+        steps.reverse();
+
+        Some(Self { steps })
+    }
+
+    /// Walks through the `tree` from the root node along the steps of this
+    /// path, and returns a reference to the node at the end of the route.
+    ///
+    /// The `policy` parameter specifies how the function treats
+    /// the divergences between the path and the current structure of
+    /// the syntax tree. See [PathPolicy] for details.
+    ///
+    /// Returns None if the function fails to follow the path according to
+    /// the policy.
+    pub fn resolve(&self, tree: &impl SyntaxTree, policy: PathPolicy) -> Option<NodeRef> {
+        let mut current = tree.root_node_ref();
+
+        for step in &self.steps {
+            let node = current.deref(tree)?;
+
+            let (_, capture) = node.captures().find(|(key, _)| step.matches_key(key))?;
+
+            if !capture.kind().is_node() {
+                return None;
+            }
+
+            current = match policy {
+                PathPolicy::Exact => {
+                    let child = *capture.get(step.index)?.as_node_ref();
+
+                    if child.rule(tree) != step.rule {
+                        return None;
+                    }
+
+                    child
+                }
+
+                PathPolicy::Nearest => step.nearest(tree, capture)?,
+            };
+        }
+
+        Some(current)
+    }
+
+    /// Returns the number of steps in this path.
+    ///
+    /// The root node has a zero depth.
+    #[inline(always)]
+    pub fn depth(&self) -> usize {
+        self.steps.len()
+    }
+}
+
+/// A single step of the [NodePath].
+///
+/// The step addresses a child node of the parent node, where the parent node
+/// is the node addressed by the previous steps of the path.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NodePathStep {
+    /// The [rule](AbstractNode::rule) of the child node.
+    pub rule: NodeRule,
+
+    /// The [key](Key) of the parent node's capture that contains the child
+    /// node.
+    ///
+    /// For the [Key::Name] keys, this is the capture name. For the
+    /// [Key::Index] keys, this is a decimal representation of the index.
+    pub key: String,
+
+    /// The index of the child node within the capture.
+    ///
+    /// For the captures of a single node, this index is always zero.
+    pub index: usize,
+}
+
+impl NodePathStep {
+    #[inline(always)]
+    fn matches_key(&self, key: &Key) -> bool {
+        match key {
+            Key::Name(name) => *name == self.key,
+            Key::Index(index) => self.key.parse::<usize>().ok() == Some(*index),
+        }
+    }
+
+    fn nearest(&self, tree: &impl SyntaxTree, capture: Capture) -> Option<NodeRef> {
+        capture
+            .into_iter()
+            .map(|child| *child.as_node_ref())
+            .enumerate()
+            .filter(|(_, child)| child.rule(tree) == self.rule)
+            .min_by_key(|(index, _)| index.abs_diff(self.index))
+            .map(|(_, child)| child)
+    }
+}
+
+/// A strategy of the [NodePath::resolve] function for handling divergences
+/// between the path and the current syntax tree structure.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum PathPolicy {
+    /// Each step of the path must match the syntax tree exactly: the parent
+    /// node must have a capture with the step's [key](NodePathStep::key),
+    /// the capture must have a node at the step's
+    /// [index](NodePathStep::index), and this node must be of the step's
+    /// [rule](NodePathStep::rule).
+    Exact,
+
+    /// Each step of the path must match the capture
+    /// [key](NodePathStep::key) of the parent node, but within the capture
+    /// the function picks the node of the step's [rule](NodePathStep::rule)
+    /// closest to the step's [index](NodePathStep::index).
+    ///
+    /// For example, if the user removes one of the three items of a list,
+    /// the path to the third item resolves to the last remaining item
+    /// rather than failing.
+    ///
+    /// If several nodes are equally close to the index, the function prefers
+    /// the one with the lower index.
+    Nearest,
+}
@@ -0,0 +1,745 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use crate::{
+    lexis::{Token, TokenRef, TokenRule, TokenSet, EMPTY_TOKEN_SET, EOI},
+    syntax::{
+        Node,
+        NodeRef,
+        NodeRule,
+        NodeSet,
+        PolyRef,
+        Recovery,
+        SyntaxError,
+        SyntaxSession,
+        EMPTY_NODE_SET,
+    },
+};
+
+const OPERATORS_LIMIT: u8 = 32;
+
+/// A configurable driver of the Pratt (top-down operator precedence) parsing
+/// algorithm for hand-written expression parsers.
+///
+/// The Pratt parser is a common approach to parse infix expressions with
+/// operators of different precedence and associativity. Almost every
+/// hand-written expression parser reimplements the same parsing loop: parse
+/// an operand, then repeatedly consume operators while their binding power is
+/// high enough, assembling left-leaning nodes on the way. The PrattDriver
+/// implements this loop once, including the syntax error recovery at the
+/// operand and the operator positions.
+///
+/// The driver's configuration consists of the table of operators, where each
+/// operator is described by the operator token, the binding powers, the
+/// [rule](NodeRule) of the node that the operator produces, and a function
+/// that constructs this node from the [PrattOperands].
+///
+///  - The [prefix](Self::prefix) operators (e.g., `-a`) have a single
+///    binding power of the right operand.
+///  - The [infix](Self::infix) operators (e.g., `a + b`) have the left and
+///    the right binding powers. If the left power is lower than the right
+///    power, the operator is left-associative; otherwise, it is
+///    right-associative.
+///  - The [postfix](Self::postfix) operators (e.g., `a?`) have a single
+///    binding power of the left operand.
+///
+/// Operators with higher binding powers take precedence over operators with
+/// lower binding powers.
+///
+/// The operands (the atoms of the expression) are parsed by the user-defined
+/// function passed to the [parse](Self::parse) function. The driver calls
+/// this function whenever the current token belongs to
+/// the [operands](Self::operands) set.
+///
+/// The construction methods of the PrattDriver object are the const
+/// functions. The object is assumed to be constructed in a const context
+/// as a static value upfront:
+///
+/// ```ignore
+/// static EXPR: PrattDriver<MyNode> = PrattDriver::new(MyNode::EXPR)
+///     .trivia(TokenSet::inclusive(&[MyToken::Whitespace as u8]))
+///     .operands(TokenSet::inclusive(&[MyToken::Num as u8, MyToken::ParenOpen as u8]))
+///     .infix(MyToken::Plus as u8, MyNode::ADD, 1, 2, |operands| MyNode::Add {
+///         node: operands.node,
+///         parent: operands.parent,
+///         left: operands.left,
+///         right: operands.right,
+///     })
+///     .infix(MyToken::Star as u8, MyNode::MUL, 3, 4, |operands| MyNode::Mul {
+///         node: operands.node,
+///         parent: operands.parent,
+///         left: operands.left,
+///         right: operands.right,
+///     });
+///
+/// fn parse_expr<'a>(session: &mut impl SyntaxSession<'a, Node = MyNode>) -> NodeRef {
+///     EXPR.parse(session, |session| parse_operand(session))
+/// }
+/// ```
+pub struct PrattDriver<N: Node> {
+    context: NodeRule,
+    trivia: TokenSet,
+    terminators: TokenSet,
+    operands: TokenSet,
+    operand_tokens: TokenSet,
+    operand_rules: NodeSet,
+    operator_tokens: TokenSet,
+    operand_recovery: Recovery,
+    operator_recovery: Recovery,
+    open_ended: bool,
+    operators: [Option<PrattOperator<N>>; OPERATORS_LIMIT as usize],
+    operators_len: u8,
+}
+
+impl<N: Node> PrattDriver<N> {
+    /// The maximum number of operators this driver can address.
+    ///
+    /// This number may be increased in future minor versions of Lady Deirdre.
+    pub const OPERATORS_LIMIT: u8 = OPERATORS_LIMIT;
+
+    const NO_OPERATOR: Option<PrattOperator<N>> = None;
+
+    /// Creates a new driver without operators.
+    ///
+    /// The `context` parameter specifies the parsing rule that will be
+    /// reported in the [syntax errors](SyntaxError::context) of the top
+    /// expression. The errors of the operator's right operand are reported
+    /// in the context of the operator's rule.
+    #[inline(always)]
+    pub const fn new(context: NodeRule) -> Self {
+        Self {
+            context,
+            trivia: TokenSet::empty(),
+            terminators: TokenSet::empty().include(EOI),
+            operands: TokenSet::empty(),
+            operand_tokens: TokenSet::empty(),
+            operand_rules: NodeSet::empty(),
+            operator_tokens: TokenSet::empty().include(EOI),
+            operand_recovery: Recovery::unlimited(),
+            operator_recovery: Recovery::unlimited(),
+            open_ended: false,
+            operators: [Self::NO_OPERATOR; OPERATORS_LIMIT as usize],
+            operators_len: 0,
+        }
+    }
+
+    /// Specifies a set of tokens that the driver skips between the operators
+    /// and the operands.
+    #[inline(always)]
+    pub const fn trivia(mut self, trivia: TokenSet) -> Self {
+        self.trivia = trivia;
+
+        self
+    }
+
+    /// Specifies a set of tokens that start the operands.
+    ///
+    /// When the driver expects an operand and encounters a token from this set,
+    /// it calls the operand parser function.
+    #[inline(always)]
+    pub const fn operands(mut self, operands: TokenSet) -> Self {
+        self.operands = operands;
+        self.operand_tokens = self.operand_tokens.union(operands);
+
+        self
+    }
+
+    /// Specifies a set of rules that the driver reports as expected in
+    /// the syntax errors at the operand position.
+    ///
+    /// By default, the driver reports the operand tokens instead.
+    #[inline(always)]
+    pub const fn operand_rules(mut self, rules: NodeSet) -> Self {
+        self.operand_rules = rules;
+
+        self
+    }
+
+    /// Specifies a set of tokens that finish the expression (e.g., the closing
+    /// parenthesis).
+    ///
+    /// The driver does not consume these tokens. The end-of-input token is
+    /// always a terminator.
+    #[inline(always)]
+    pub const fn terminators(mut self, terminators: TokenSet) -> Self {
+        self.terminators = self.terminators.union(terminators);
+        self.operator_tokens = self.operator_tokens.union(terminators);
+
+        self
+    }
+
+    /// Makes the driver finish the expression on the first token that is
+    /// neither an operator nor a [terminator](Self::terminators), leaving
+    /// this token to the caller.
+    ///
+    /// By default, the driver reports a syntax error on such tokens and
+    /// applies the [operator recovery](Self::operator_recovery).
+    ///
+    /// This mode is useful when the expression is a part of an enclosing rule
+    /// that handles the tokens following the expression.
+    #[inline(always)]
+    pub const fn open_ended(mut self) -> Self {
+        self.open_ended = true;
+
+        self
+    }
+
+    /// Specifies the syntax error recovery configuration that the driver
+    /// applies when it expects an operand but encounters an unexpected token.
+    ///
+    /// By default, the driver uses [Recovery::unlimited].
+    #[inline(always)]
+    pub const fn operand_recovery(mut self, recovery: Recovery) -> Self {
+        self.operand_recovery = recovery;
+
+        self
+    }
+
+    /// Specifies the syntax error recovery configuration that the driver
+    /// applies when it expects an operator but encounters an unexpected token.
+    ///
+    /// By default, the driver uses [Recovery::unlimited].
+    #[inline(always)]
+    pub const fn operator_recovery(mut self, recovery: Recovery) -> Self {
+        self.operator_recovery = recovery;
+
+        self
+    }
+
+    /// Adds a prefix operator.
+    ///
+    /// The `token` is the operator's token, the `rule` is the rule of
+    /// the node that the `constructor` function creates, and the `power` is
+    /// the binding power of the operator's right operand.
+    ///
+    /// The [left](PrattOperands::left) operand of the constructor's input
+    /// is always [nil](NodeRef::nil).
+    ///
+    /// **Panic**
+    ///
+    /// Panics if the driver already has
+    /// [OPERATORS_LIMIT](Self::OPERATORS_LIMIT) operators, or if the driver
+    /// already has a prefix operator with the same token.
+    #[inline(always)]
+    pub const fn prefix(
+        self,
+        token: TokenRule,
+        rule: NodeRule,
+        power: u8,
+        constructor: fn(PrattOperands) -> N,
+    ) -> Self {
+        let mut this = self.push(PrattOperator {
+            fixity: PrattFixity::Prefix,
+            token,
+            rule,
+            left: 0,
+            right: power,
+            recovery: None,
+            constructor,
+        });
+
+        this.operand_tokens = this.operand_tokens.include(token);
+
+        this
+    }
+
+    /// Adds an infix operator.
+    ///
+    /// The `token` is the operator's token, the `rule` is the rule of
+    /// the node that the `constructor` function creates, and the `left` and
+    /// the `right` are the binding powers of the operator's operands.
+    ///
+    /// **Panic**
+    ///
+    /// Panics if the driver already has
+    /// [OPERATORS_LIMIT](Self::OPERATORS_LIMIT) operators, or if the driver
+    /// already has an infix or a postfix operator with the same token.
+    #[inline(always)]
+    pub const fn infix(
+        self,
+        token: TokenRule,
+        rule: NodeRule,
+        left: u8,
+        right: u8,
+        constructor: fn(PrattOperands) -> N,
+    ) -> Self {
+        let mut this = self.push(PrattOperator {
+            fixity: PrattFixity::Infix,
+            token,
+            rule,
+            left,
+            right,
+            recovery: None,
+            constructor,
+        });
+
+        this.operator_tokens = this.operator_tokens.include(token);
+
+        this
+    }
+
+    /// Adds a postfix operator.
+    ///
+    /// The `token` is the operator's token, the `rule` is the rule of
+    /// the node that the `constructor` function creates, and the `power` is
+    /// the binding power of the operator's left operand.
+    ///
+    /// The [right](PrattOperands::right) operand of the constructor's input
+    /// is always [nil](NodeRef::nil).
+    ///
+    /// **Panic**
+    ///
+    /// Panics if the driver already has
+    /// [OPERATORS_LIMIT](Self::OPERATORS_LIMIT) operators, or if the driver
+    /// already has an infix or a postfix operator with the same token.
+    #[inline(always)]
+    pub const fn postfix(
+        self,
+        token: TokenRule,
+        rule: NodeRule,
+        power: u8,
+        constructor: fn(PrattOperands) -> N,
+    ) -> Self {
+        let mut this = self.push(PrattOperator {
+            fixity: PrattFixity::Postfix,
+            token,
+            rule,
+            left: power,
+            right: 0,
+            recovery: None,
+            constructor,
+        });
+
+        this.operator_tokens = this.operator_tokens.include(token);
+
+        this
+    }
+
+    /// Specifies the syntax error recovery configuration of the right operand
+    /// of the most recently added operator.
+    ///
+    /// By default, the driver applies
+    /// the [operand recovery](Self::operand_recovery) to the right operands.
+    ///
+    /// **Panic**
+    ///
+    /// Panics if the driver has no operators.
+    #[inline(always)]
+    pub const fn right_recovery(mut self, recovery: Recovery) -> Self {
+        if self.operators_len == 0 {
+            panic!("Missing operator.");
+        }
+
+        let index = self.operators_len as usize - 1;
+
+        if let Some(operator) = self.operators[index] {
+            self.operators[index] = Some(PrattOperator {
+                recovery: Some(recovery),
+                ..operator
+            });
+        }
+
+        self
+    }
+
+    /// Parses an expression starting from the current token of
+    /// the `session`, and returns a [NodeRef] reference of the expression's
+    /// top node.
+    ///
+    /// The `operand` function parses the operands of the expression. The driver
+    /// calls this function when the current token belongs to
+    /// the [operands](Self::operands) set. The function should parse
+    /// the operand (e.g., by [descending](SyntaxSession::descend) into
+    /// a rule) and return the operand's node.
+    ///
+    /// The driver enters a new node for each operator it encounters, lifts
+    /// the left operand into this node, and leaves the node constructed by
+    /// the operator's constructor function.
+    ///
+    /// If the driver encounters an unexpected token, it reports
+    /// a [SyntaxError] and runs the configured recovery procedure. If
+    /// the recovery fails, the driver returns the expression parsed so far.
+    /// The returning value could be a [nil](NodeRef::nil) reference if
+    /// the driver failed to parse any operand.
+    pub fn parse<'code, S: SyntaxSession<'code, Node = N>>(
+        &'static self,
+        session: &mut S,
+        mut operand: impl FnMut(&mut S) -> NodeRef,
+    ) -> NodeRef {
+        self.parse_power(
+            session,
+            &mut operand,
+            &mut |session| self.skip_trivia(session),
+            self.context,
+            &self.operand_recovery,
+            0,
+        )
+    }
+
+    /// Parses an expression in the parsing environment of the caller.
+    ///
+    /// Unlike the [parse](Self::parse) function, the errors at the top
+    /// expression's operand position are reported in the `context` rule and
+    /// recovered with the `recovery` configuration, the `trivia` function
+    /// skips the trivia between the operators and the operands instead of
+    /// the [trivia](Self::trivia) token set, and the driver consumes
+    /// the top-level operators whose left binding power is not lower than
+    /// the `power`.
+    ///
+    /// To finish the expression on the first token that does not continue it
+    /// without reporting an error, configure the driver
+    /// as [open-ended](Self::open_ended).
+    pub fn parse_with<'code, S: SyntaxSession<'code, Node = N>>(
+        &'static self,
+        session: &mut S,
+        context: NodeRule,
+        recovery: &Recovery,
+        power: u8,
+        mut operand: impl FnMut(&mut S) -> NodeRef,
+        mut trivia: impl FnMut(&mut S),
+    ) -> NodeRef {
+        self.parse_power(session, &mut operand, &mut trivia, context, recovery, power)
+    }
+
+    fn parse_power<'code, S: SyntaxSession<'code, Node = N>>(
+        &'static self,
+        session: &mut S,
+        operand: &mut impl FnMut(&mut S) -> NodeRef,
+        trivia: &mut impl FnMut(&mut S),
+        context: NodeRule,
+        recovery: &Recovery,
+        power: u8,
+    ) -> NodeRef {
+        let mut accumulator = loop {
+            let token = session.token(0).rule();
+
+            if let Some(operator) = self.find(PrattFixity::Prefix, token) {
+                break Self::operator(
+                    session,
+                    operator.rule,
+                    NodeRef::nil(),
+                    |session| {
+                        trivia(session);
+
+                        self.parse_power(
+                            session,
+                            &mut *operand,
+                            &mut *trivia,
+                            operator.rule,
+                            operator.recovery(&self.operand_recovery),
+                            operator.right,
+                        )
+                    },
+                    |_, operands| (operator.constructor)(operands),
+                );
+            }
+
+            if self.operands.contains(token) {
+                break operand(session);
+            }
+
+            let (expected_tokens, expected_rules) = match self.operand_rules.is_empty() {
+                true => (&self.operand_tokens, &EMPTY_NODE_SET),
+                false => (&EMPTY_TOKEN_SET, &self.operand_rules),
+            };
+
+            if !Self::recover(
+                session,
+                context,
+                recovery,
+                &self.operand_tokens,
+                expected_tokens,
+                expected_rules,
+            ) {
+                return NodeRef::nil();
+            }
+        };
+
+        loop {
+            trivia(session);
+
+            let token = session.token(0).rule();
+
+            let operator = match self.find(PrattFixity::Infix, token) {
+                Some(operator) => operator,
+
+                None => match self.find(PrattFixity::Postfix, token) {
+                    Some(operator) => operator,
+
+                    None => {
+                        if self.open_ended || self.terminators.contains(token) {
+                            return accumulator;
+                        }
+
+                        if !Self::recover(
+                            session,
+                            context,
+                            &self.operator_recovery,
+                            &self.operator_tokens,
+                            &self.operator_tokens,
+                            &EMPTY_NODE_SET,
+                        ) {
+                            return accumulator;
+                        }
+
+                        continue;
+                    }
+                },
+            };
+
+            if operator.left < power {
+                return accumulator;
+            }
+
+            accumulator = Self::operator(
+                session,
+                operator.rule,
+                accumulator,
+                |session| match operator.fixity {
+                    PrattFixity::Infix => {
+                        trivia(session);
+
+                        self.parse_power(
+                            session,
+                            &mut *operand,
+                            &mut *trivia,
+                            operator.rule,
+                            operator.recovery(&self.operand_recovery),
+                            operator.right,
+                        )
+                    }
+
+                    _ => NodeRef::nil(),
+                },
+                |_, operands| (operator.constructor)(operands),
+            );
+        }
+    }
+
+    /// Parses a single operator at the current token of the `session`,
+    /// and returns a [NodeRef] reference of the operator's node.
+    ///
+    /// This function is a building block of the Pratt parsing loop for
+    /// the parsers that implement the loop manually (e.g., the parsers
+    /// generated by the Node derive macro).
+    ///
+    /// The function enters a new node of the `rule`, lifts the `left` operand
+    /// into this node unless it is [nil](NodeRef::nil), consumes the operator's
+    /// token, and calls the `right` function that parses the right operand
+    /// (or returns nil if the operator does not have one). Finally, the function
+    /// leaves the node constructed by the `constructor` function.
+    pub fn operator<'code, S: SyntaxSession<'code, Node = N>>(
+        session: &mut S,
+        rule: NodeRule,
+        left: NodeRef,
+        right: impl FnOnce(&mut S) -> NodeRef,
+        constructor: impl FnOnce(&mut S, PrattOperands) -> N,
+    ) -> NodeRef {
+        let node = session.enter(rule);
+
+        if !left.is_nil() {
+            session.lift(&left);
+        }
+
+        let parent = session.parent_ref();
+        let token_ref = session.token_ref(0);
+
+        session.advance();
+
+        let right = right(session);
+
+        let node = constructor(
+            session,
+            PrattOperands {
+                node,
+                parent,
+                operator: token_ref,
+                left,
+                right,
+            },
+        );
+
+        session.leave(node)
+    }
+
+    /// Reports a [SyntaxError] at the current token of the `session` and
+    /// runs the `recovery` procedure that skips the tokens until a token
+    /// from the `until` set.
+    ///
+    /// The error is reported in the `context` rule with
+    /// the `expected_tokens` and the `expected_rules` sets.
+    ///
+    /// Returns true if the recovery succeeded, and the parser may proceed
+    /// from the current token.
+    ///
+    /// This function is a building block of the Pratt parsing loop for
+    /// the parsers that implement the loop manually.
+    pub fn recover<'code>(
+        session: &mut impl SyntaxSession<'code, Node = N>,
+        context: NodeRule,
+        recovery: &Recovery,
+        until: &TokenSet,
+        expected_tokens: &'static TokenSet,
+        expected_rules: &'static NodeSet,
+    ) -> bool {
+        let start_site_ref = session.site_ref(0);
+
+        let details = recovery.recover_with_details(session, until);
+        let result = details.result();
+
+        let end_site_ref = session.site_ref(0);
+
+        let mut error = SyntaxError::new(
+            start_site_ref..end_site_ref,
+            context,
+            result,
+            expected_tokens,
+            expected_rules,
+        );
+
+        error.recovery_details = Some(details);
+
+        session.failure(error);
+
+        result.recovered()
+    }
+
+    #[inline(always)]
+    fn skip_trivia<'code>(&self, session: &mut impl SyntaxSession<'code, Node = N>) {
+        while self.trivia.contains(session.token(0).rule()) {
+            if !session.advance() {
+                break;
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn find(&self, fixity: PrattFixity, token: TokenRule) -> Option<&PrattOperator<N>> {
+        self.operators[0..self.operators_len as usize]
+            .iter()
+            .flatten()
+            .find(|operator| operator.fixity == fixity && operator.token == token)
+    }
+
+    const fn push(mut self, operator: PrattOperator<N>) -> Self {
+        if self.operators_len >= Self::OPERATORS_LIMIT {
+            panic!("Operators limit exceeded.");
+        }
+
+        let mut index = 0;
+        while index < self.operators_len as usize {
+            if let Some(other) = &self.operators[index] {
+                let same_position = match (other.fixity, operator.fixity) {
+                    (PrattFixity::Prefix, PrattFixity::Prefix) => true,
+                    (PrattFixity::Prefix, _) | (_, PrattFixity::Prefix) => false,
+                    _ => true,
+                };
+
+                if same_position && other.token == operator.token {
+                    panic!("Duplicate operator token.");
+                }
+            }
+
+            index += 1;
+        }
+
+        self.operators[self.operators_len as usize] = Some(operator);
+        self.operators_len += 1;
+
+        self
+    }
+}
+
+/// An input of the [PrattDriver]'s node constructor function.
+///
+/// The driver creates this object when it finishes parsing of an operator.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct PrattOperands {
+    /// A reference of the node being constructed.
+    pub node: NodeRef,
+
+    /// A reference of the parent node of the node being constructed.
+    pub parent: NodeRef,
+
+    /// A reference of the operator's token.
+    pub operator: TokenRef,
+
+    /// The left operand of the operator.
+    ///
+    /// The value is [nil](NodeRef::nil) for the prefix operators, or if
+    /// the driver failed to parse the operand.
+    pub left: NodeRef,
+
+    /// The right operand of the operator.
+    ///
+    /// The value is [nil](NodeRef::nil) for the postfix operators, or if
+    /// the driver failed to parse the operand.
+    pub right: NodeRef,
+}
+
+struct PrattOperator<N> {
+    fixity: PrattFixity,
+    token: TokenRule,
+    rule: NodeRule,
+    left: u8,
+    right: u8,
+    recovery: Option<Recovery>,
+    constructor: fn(PrattOperands) -> N,
+}
+
+impl<N> Clone for PrattOperator<N> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<N> Copy for PrattOperator<N> {}
+
+impl<N> PrattOperator<N> {
+    #[inline(always)]
+    fn recovery<'a>(&'a self, default: &'a Recovery) -> &'a Recovery {
+        match &self.recovery {
+            Some(recovery) => recovery,
+            None => default,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum PrattFixity {
+    Prefix,
+    Infix,
+    Postfix,
+}
@@ -32,8 +32,10 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
+use alloc::vec::Vec;
+
 use crate::{
-    lexis::{Token, TokenRule, TokenSet, EOI},
+    lexis::{SiteRefSpan, Token, TokenCount, TokenCursor, TokenRule, TokenSet, EOI},
     syntax::SyntaxSession,
 };
 
@@ -88,6 +90,13 @@ pub static UNLIMITED_RECOVERY: Recovery = Recovery::unlimited();
 ///    interpreted as a whole; under which the halting rules should not
 ///    be applied.
 ///
+/// Additionally, the [insert_limit](Recovery::insert_limit) and
+/// the [delete_limit](Recovery::delete_limit) functions allow the parser to
+/// try cheaper single-token recovery strategies before entering panic mode:
+/// to assume that the end-user missed an expected token, or that the end-user
+/// typed an extra token that should be skipped. These strategies are disabled
+/// by default.
+///
 /// Note that the panic recovery algorithm takes into account groups nesting,
 /// and if a particular group is not properly balanced by the open and the close
 /// tokens, its content will be separated.
@@ -106,6 +115,8 @@ pub struct Recovery {
     groups: [(TokenRule, TokenRule); Self::GROUPS_LIMIT as usize],
     groups_len: u8,
     unexpected: TokenSet,
+    insert_limit: u8,
+    delete_limit: u8,
 }
 
 impl Recovery {
@@ -124,6 +135,8 @@ impl Recovery {
             groups: [(0, 0); Self::GROUPS_LIMIT as usize],
             groups_len: 0,
             unexpected: TokenSet::empty(),
+            insert_limit: 0,
+            delete_limit: 0,
         }
     }
 
@@ -208,6 +221,59 @@ impl Recovery {
         self
     }
 
+    /// Sets the maximum number of tokens that the parsing rule may assume
+    /// missing in the input before it falls back to panic recovery.
+    ///
+    /// When the parser encounters an unexpected token, but this token would be
+    /// expected right after one of the currently expected tokens, the parser
+    /// may pretend that the expected token was present, report
+    /// the [InsertRecover](RecoveryResult::InsertRecover) error, and continue
+    /// parsing from the unexpected token.
+    ///
+    /// The limit applies to each parsing rule's invocation individually.
+    /// The zero value (default) disables this recovery strategy.
+    #[inline(always)]
+    pub const fn insert_limit(mut self, limit: u8) -> Self {
+        self.insert_limit = limit;
+
+        self
+    }
+
+    /// Sets the maximum number of unexpected tokens that the parsing rule
+    /// may skip one by one before it falls back to panic recovery.
+    ///
+    /// When the parser encounters an unexpected token, but the token next to
+    /// it is expected, the parser may skip the unexpected token alone, report
+    /// the [DeleteRecover](RecoveryResult::DeleteRecover) error, and continue
+    /// parsing from the next token.
+    ///
+    /// The limit applies to each parsing rule's invocation individually.
+    /// The zero value (default) disables this recovery strategy.
+    #[inline(always)]
+    pub const fn delete_limit(mut self, limit: u8) -> Self {
+        self.delete_limit = limit;
+
+        self
+    }
+
+    /// Returns the maximum number of tokens that the parsing rule may assume
+    /// missing.
+    ///
+    /// See [insert_limit](Self::insert_limit) for details.
+    #[inline(always)]
+    pub const fn get_insert_limit(&self) -> u8 {
+        self.insert_limit
+    }
+
+    /// Returns the maximum number of unexpected tokens that the parsing rule
+    /// may skip one by one.
+    ///
+    /// See [delete_limit](Self::delete_limit) for details.
+    #[inline(always)]
+    pub const fn get_delete_limit(&self) -> u8 {
+        self.delete_limit
+    }
+
     /// Runs the recovery algorithm with this recovery configuration starting
     /// from the current token in the `syntax` [SyntaxSession].
     ///
@@ -224,23 +290,53 @@ impl Recovery {
     ///
     /// If the token cursor reaches the end of the input, the function returns
     /// [RecoveryResult::UnexpectedEOI].
-    #[inline]
+    ///
+    /// If you need more details about the recovery outcome, use
+    /// the [recover_with_details](Self::recover_with_details) function instead.
+    #[inline(always)]
     pub fn recover<'code>(
         &self,
         session: &mut impl SyntaxSession<'code>,
         until: &TokenSet,
     ) -> RecoveryResult {
+        self.recover_with_details(session, until).result()
+    }
+
+    /// Runs the recovery algorithm with this recovery configuration starting
+    /// from the current token in the `syntax` [SyntaxSession], and returns
+    /// the details of the recovery outcome.
+    ///
+    /// This function works exactly the same way as the [recover](Self::recover)
+    /// function, but in addition to the [RecoveryResult] (which is available
+    /// through the [RecoveryDetails::result] function) it reports
+    /// the number of skipped tokens, the span of the skipped source code
+    /// fragment, and the reason why the algorithm has halted.
+    ///
+    /// You can put the returning object into
+    /// the [SyntaxError::recovery_details](crate::syntax::SyntaxError::recovery_details)
+    /// field.
+    #[inline]
+    pub fn recover_with_details<'code>(
+        &self,
+        session: &mut impl SyntaxSession<'code>,
+        until: &TokenSet,
+    ) -> RecoveryDetails {
+        let start = session.site_ref(0);
+        let mut skipped = 0;
         let mut stack = GroupStack::new();
 
-        loop {
+        let halt = loop {
             let rule = session.token(0).rule();
 
             if until.contains(rule) {
-                return RecoveryResult::PanicRecover;
+                break RecoveryHalt::Expected;
             }
 
             if self.unexpected.contains(rule) {
-                return RecoveryResult::UnexpectedToken;
+                match self.is_group_bound(rule) {
+                    true => break RecoveryHalt::GroupBoundary,
+                    false => break RecoveryHalt::Unexpected,
+                }
             }
 
             let mut group_id = 0u8;
@@ -248,16 +344,40 @@ impl Recovery {
                 let open = self.groups[group_id as usize].0;
 
                 if open == rule {
-                    self.try_skip_group(session, &mut stack, group_id);
+                    skipped += self.try_skip_group(session, &mut stack, group_id);
                 }
 
                 group_id += 1;
             }
 
             if !session.advance() {
-                return RecoveryResult::UnexpectedEOI;
+                break RecoveryHalt::EndOfInput;
+            }
+
+            skipped += 1;
+        };
+
+        RecoveryDetails {
+            skipped,
+            span: start..session.site_ref(0),
+            halt,
+        }
+    }
+
+    #[inline(always)]
+    fn is_group_bound(&self, rule: TokenRule) -> bool {
+        let mut group_id = 0u8;
+        while group_id < self.groups_len {
+            let (open, close) = self.groups[group_id as usize];
+
+            if open == rule || close == rule {
+                return true;
             }
+
+            group_id += 1;
         }
+
+        false
     }
 
     #[inline(always)]
@@ -266,7 +386,7 @@ impl Recovery {
         session: &mut impl SyntaxSession<'code>,
         stack: &mut GroupStack,
         mut group_id: u8,
-    ) {
+    ) -> TokenCount {
         stack.clear();
         stack.push(group_id);
 
@@ -312,9 +432,13 @@ impl Recovery {
             }
         }
 
-        if stack.is_empty() {
-            session.skip(distance);
+        if !stack.is_empty() {
+            return 0;
         }
+
+        session.skip(distance);
+
+        distance
     }
 }
 
@@ -326,6 +450,12 @@ pub enum RecoveryResult {
     /// the end-user missed a required token.
     InsertRecover,
 
+    /// The parser successfully recovered from the syntax error by skipping
+    /// a single unexpected token that was followed by the expected one.
+    ///
+    /// See [Recovery::delete_limit] for details.
+    DeleteRecover,
+
     /// The parser successfully recovered from the syntax error by skipping
     /// a continuous sequence of unexpected tokens, but finally encountering
     /// the one that was expected and continued the parsing process normally.
@@ -347,6 +477,11 @@ pub enum RecoveryResult {
     /// In the end, the parsing rule has assembled the product node based on the
     /// data it was able to parse so far and returned control flow.
     UnexpectedToken,
+
+    /// The parser recognized a common mistake in the input using one of
+    /// the grammar's error rules, parsed the erroneous fragment into a node of
+    /// this error rule, and continued the parsing process normally.
+    ErrorProduction,
 }
 
 impl RecoveryResult {
@@ -354,12 +489,82 @@ impl RecoveryResult {
     #[inline(always)]
     pub fn recovered(&self) -> bool {
         match self {
-            Self::InsertRecover | Self::PanicRecover => true,
+            Self::InsertRecover
+            | Self::DeleteRecover
+            | Self::PanicRecover
+            | Self::ErrorProduction => true,
             _ => false,
         }
     }
 }
 
+/// Details of the panic recovery outcome.
+///
+/// Returned by the [Recovery::recover_with_details] function, and usually
+/// stored in the [SyntaxError::recovery_details](crate::syntax::SyntaxError::recovery_details)
+/// field of the syntax error reported by the parser.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct RecoveryDetails {
+    /// The number of tokens that the recovery algorithm has skipped.
+    ///
+    /// The recovery algorithm is unaware of the grammar's trivia. Therefore,
+    /// this number includes the trivia tokens (e.g., whitespaces and comments)
+    /// that the algorithm has skipped too. For example, the recovery that
+    /// skips the `: :` fragment reports three skipped tokens: two colons and
+    /// a whitespace between them.
+    pub skipped: TokenCount,
+
+    /// A [span of tokens](SiteRefSpan) that the recovery algorithm has
+    /// skipped.
+    ///
+    /// If the algorithm did not skip any token, the span is empty.
+    pub span: SiteRefSpan,
+
+    /// The reason why the recovery algorithm has stopped skipping tokens.
+    pub halt: RecoveryHalt,
+}
+
+impl RecoveryDetails {
+    /// Returns the recovery strategy outcome corresponding to the
+    /// [halting reason](Self::halt).
+    ///
+    /// The result is [RecoveryResult::PanicRecover] if the algorithm has halted
+    /// on the expected token, [RecoveryResult::UnexpectedEOI] if it has
+    /// reached the end of the input, or [RecoveryResult::UnexpectedToken]
+    /// otherwise.
+    #[inline(always)]
+    pub fn result(&self) -> RecoveryResult {
+        match self.halt {
+            RecoveryHalt::Expected => RecoveryResult::PanicRecover,
+            RecoveryHalt::Unexpected | RecoveryHalt::GroupBoundary => {
+                RecoveryResult::UnexpectedToken
+            }
+            RecoveryHalt::EndOfInput => RecoveryResult::UnexpectedEOI,
+        }
+    }
+}
+
+/// A reason why the panic recovery algorithm has stopped skipping tokens.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[non_exhaustive]
+pub enum RecoveryHalt {
+    /// The algorithm encountered a token that the parser expects.
+    Expected,
+
+    /// The algorithm encountered a halting token from
+    /// the [unexpected](Recovery::unexpected) set.
+    Unexpected,
+
+    /// The algorithm encountered a halting token from
+    /// the [unexpected](Recovery::unexpected) set that is also a bound of
+    /// one of the configured [groups](Recovery::group) (e.g., the closing
+    /// bracket of the enclosing group).
+    GroupBoundary,
+
+    /// The algorithm reached the end of the input.
+    EndOfInput,
+}
+
 enum GroupStack {
     Inline {
         vec: [u8; Self::INLINE],
@@ -32,7 +32,8 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::{
+use alloc::vec::Vec;
+use core::{
     fmt::{Debug, Display, Formatter},
     iter::FusedIterator,
     marker::PhantomData,
@@ -86,7 +87,7 @@ pub struct NodeSet {
 
 impl Debug for NodeSet {
     #[inline]
-    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
         let mut debug_list = formatter.debug_list();
 
         let mut entry = 0;
@@ -350,13 +351,13 @@ impl NodeSet {
 
         impl<'set, N: AbstractNode> Debug for DisplayNodeSet<'set, N> {
             #[inline(always)]
-            fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+            fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
                 Display::fmt(self, formatter)
             }
         }
 
         impl<'set, N: AbstractNode> Display for DisplayNodeSet<'set, N> {
-            fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+            fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
                 let mut vector = Vec::with_capacity(NodeSet::LIMIT);
 
                 for rule in self.set {
@@ -32,7 +32,8 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::{marker::PhantomData, mem::replace};
+use alloc::vec::Vec;
+use core::{marker::PhantomData, mem::replace};
 
 use crate::{
     arena::{Entry, EntryIndex, Id, Identifiable},
@@ -0,0 +1,331 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::{
+    fmt::{Debug, Display, Formatter},
+    marker::PhantomData,
+};
+
+use crate::{
+    lexis::TokenRef,
+    syntax::{AbstractNode, Node, NodeRef, NodeRule, SyntaxTree, Visitor},
+};
+
+/// A summary of the syntax tree shape.
+///
+/// This object is created by the [SyntaxTree::stats] function.
+///
+/// The [Display] implementation of this object prints a human-readable report
+/// where the parse rules are denoted by their [names](AbstractNode::rule_name).
+pub struct TreeStats<N: Node> {
+    /// The total number of nodes in the syntax tree.
+    pub nodes: usize,
+
+    /// The number of nodes per parse rule.
+    pub rules: BTreeMap<NodeRule, usize>,
+
+    /// The length of the longest path from the root node to a leaf node
+    /// (in nodes).
+    ///
+    /// The depth of a syntax tree with a single root node is 1.
+    pub max_depth: usize,
+
+    /// The total number of children (nodes and tokens) of all nodes.
+    pub children: usize,
+
+    /// The total number of syntax errors in the syntax tree.
+    pub errors: usize,
+
+    /// The total number of tokens in the source code of the compilation unit.
+    ///
+    /// This value is zero if the syntax tree does not own the source code
+    /// (e.g., the standalone [ImmutableSyntaxTree](crate::syntax::ImmutableSyntaxTree)).
+    pub tokens: usize,
+
+    /// The length of the source code text of the compilation unit in UTF-8
+    /// bytes.
+    ///
+    /// This value is zero if the syntax tree does not own the source code.
+    pub text_bytes: usize,
+
+    /// An approximate number of bytes that the syntax tree (and the source
+    /// code storage of the compilation unit) occupies in the heap.
+    ///
+    /// The estimation takes into account the allocated capacities of
+    /// the inner collections, but does not account for the heap data owned
+    /// by the nodes and the tokens themselves.
+    pub approx_heap_bytes: usize,
+
+    _phantom: PhantomData<N>,
+}
+
+impl<N: Node> Clone for TreeStats<N> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            nodes: self.nodes,
+            rules: self.rules.clone(),
+            max_depth: self.max_depth,
+            children: self.children,
+            errors: self.errors,
+            tokens: self.tokens,
+            text_bytes: self.text_bytes,
+            approx_heap_bytes: self.approx_heap_bytes,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<N: Node> PartialEq for TreeStats<N> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.nodes == other.nodes
+            && self.rules == other.rules
+            && self.max_depth == other.max_depth
+            && self.children == other.children
+            && self.errors == other.errors
+            && self.tokens == other.tokens
+            && self.text_bytes == other.text_bytes
+            && self.approx_heap_bytes == other.approx_heap_bytes
+    }
+}
+
+impl<N: Node> Eq for TreeStats<N> {}
+
+impl<N: Node> Debug for TreeStats<N> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        formatter
+            .debug_struct("TreeStats")
+            .field("nodes", &self.nodes)
+            .field("rules", &self.rules)
+            .field("max_depth", &self.max_depth)
+            .field("children", &self.children)
+            .field("errors", &self.errors)
+            .field("tokens", &self.tokens)
+            .field("text_bytes", &self.text_bytes)
+            .field("approx_heap_bytes", &self.approx_heap_bytes)
+            .finish()
+    }
+}
+
+impl<N: Node> Display for TreeStats<N> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        formatter.write_fmt(format_args!("Nodes: {}\n", self.nodes))?;
+        formatter.write_fmt(format_args!("Errors: {}\n", self.errors))?;
+        formatter.write_fmt(format_args!("Max depth: {}\n", self.max_depth))?;
+        formatter.write_fmt(format_args!(
+            "Avg children: {:.2}\n",
+            self.average_children(),
+        ))?;
+        formatter.write_fmt(format_args!("Tokens: {}\n", self.tokens))?;
+        formatter.write_fmt(format_args!("Text bytes: {}\n", self.text_bytes))?;
+        formatter.write_fmt(format_args!(
+            "Heap bytes (approx.): {}\n",
+            self.approx_heap_bytes,
+        ))?;
+        formatter.write_str("Rules:")?;
+
+        let mut rules = self.rules.iter().collect::<Vec<_>>();
+
+        rules.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+
+        for (rule, count) in rules {
+            let name = N::rule_name(*rule).unwrap_or("?");
+
+            formatter.write_fmt(format_args!("\n    {name}: {count}"))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<N: Node> TreeStats<N> {
+    /// Returns the average number of children (nodes and tokens) per node.
+    ///
+    /// Returns zero if the tree does not have any nodes.
+    #[inline(always)]
+    pub fn average_children(&self) -> f64 {
+        if self.nodes == 0 {
+            return 0.0;
+        }
+
+        self.children as f64 / self.nodes as f64
+    }
+
+    /// Returns the number of nodes parsed by the specified `rule`.
+    #[inline(always)]
+    pub fn rule_count(&self, rule: NodeRule) -> usize {
+        self.rules.get(&rule).copied().unwrap_or_default()
+    }
+
+    pub(super) fn new(tree: &impl SyntaxTree<Node = N>) -> Self {
+        struct StatsVisitor<'tree, T: SyntaxTree> {
+            tree: &'tree T,
+            stats: TreeStats<T::Node>,
+            depth: usize,
+        }
+
+        impl<'tree, T: SyntaxTree> Visitor for StatsVisitor<'tree, T> {
+            #[inline(always)]
+            fn visit_token(&mut self, _token_ref: &TokenRef) {}
+
+            fn enter_node(&mut self, node_ref: &NodeRef) -> bool {
+                let Some(node) = node_ref.deref(self.tree) else {
+                    return false;
+                };
+
+                self.depth += 1;
+                self.stats.max_depth = self.stats.max_depth.max(self.depth);
+                self.stats.nodes += 1;
+                self.stats.children += node.children_iter().count();
+
+                *self.stats.rules.entry(node.rule()).or_default() += 1;
+
+                true
+            }
+
+            #[inline(always)]
+            fn leave_node(&mut self, node_ref: &NodeRef) {
+                if !node_ref.is_valid_ref(self.tree) {
+                    return;
+                }
+
+                self.depth -= 1;
+            }
+        }
+
+        let mut visitor = StatsVisitor {
+            tree,
+            stats: Self {
+                nodes: 0,
+                rules: BTreeMap::new(),
+                max_depth: 0,
+                children: 0,
+                errors: tree.error_refs().count(),
+                tokens: 0,
+                text_bytes: 0,
+                approx_heap_bytes: 0,
+                _phantom: PhantomData,
+            },
+            depth: 0,
+        };
+
+        tree.traverse_tree(&mut visitor);
+
+        visitor.stats
+    }
+
+    // Creates a summary with all fields set to zero.
+    #[inline(always)]
+    pub(crate) fn empty() -> Self {
+        Self {
+            nodes: 0,
+            rules: BTreeMap::new(),
+            max_depth: 0,
+            children: 0,
+            errors: 0,
+            tokens: 0,
+            text_bytes: 0,
+            approx_heap_bytes: 0,
+            _phantom: PhantomData,
+        }
+    }
+
+    // Counts the nodes, the rules, and the children of the nodes stored in
+    // the tree's arena without traversing the tree.
+    //
+    // The caller is responsible for setting up the rest of the fields.
+    pub(crate) fn from_nodes<'a>(nodes: impl Iterator<Item = &'a N>) -> Self
+    where
+        N: 'a,
+    {
+        let mut stats = Self::empty();
+
+        for node in nodes {
+            stats.nodes += 1;
+            stats.children += node.children_iter().count();
+
+            *stats.rules.entry(node.rule()).or_default() += 1;
+        }
+
+        stats
+    }
+
+    // The nodes may be lifted to the new parents during parsing. Thus, the depth
+    // of the tree is only known after a traversal of the nodes.
+    pub(crate) fn measure_depth(tree: &impl SyntaxTree<Node = N>) -> usize {
+        struct DepthVisitor<'tree, T: SyntaxTree> {
+            tree: &'tree T,
+            depth: usize,
+            max_depth: usize,
+        }
+
+        impl<'tree, T: SyntaxTree> Visitor for DepthVisitor<'tree, T> {
+            #[inline(always)]
+            fn visit_token(&mut self, _token_ref: &TokenRef) {}
+
+            #[inline(always)]
+            fn enter_node(&mut self, node_ref: &NodeRef) -> bool {
+                if !node_ref.is_valid_ref(self.tree) {
+                    return false;
+                }
+
+                self.depth += 1;
+                self.max_depth = self.max_depth.max(self.depth);
+
+                true
+            }
+
+            #[inline(always)]
+            fn leave_node(&mut self, node_ref: &NodeRef) {
+                if !node_ref.is_valid_ref(self.tree) {
+                    return;
+                }
+
+                self.depth -= 1;
+            }
+        }
+
+        let mut visitor = DepthVisitor {
+            tree,
+            depth: 0,
+            max_depth: 0,
+        };
+
+        tree.traverse_tree(&mut visitor);
+
+        visitor.max_depth
+    }
+}
@@ -0,0 +1,659 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use alloc::{string::String, vec::Vec};
+use core::{fmt::Write, marker::PhantomData};
+
+use crate::{
+    arena::Entry,
+    lexis::{Site, SourceCode, Token, TokenCount, TokenRef, TokenRule},
+    syntax::{
+        AbstractNode,
+        ErrorRef,
+        Node,
+        NodeRef,
+        NodeRule,
+        Observer,
+        RecoveryHalt,
+        RecoveryResult,
+        SyntaxError,
+        SyntaxTree,
+        VisitControl,
+        Visitor,
+    },
+};
+
+/// A single step of the syntax parser recorded by the [TraceObserver].
+///
+/// Each variant includes the [site](Site) of the token input stream at which
+/// the step occurred.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ParseEvent {
+    /// The parser began parsing of a syntax rule.
+    ///
+    /// See [Observer::enter_rule] for details.
+    EnterRule {
+        /// The rule that the parser begins to parse.
+        rule: NodeRule,
+
+        /// A reference of the node that will be created by this rule.
+        node_ref: NodeRef,
+
+        /// The site of the token stream at which the rule begins.
+        site: Site,
+    },
+
+    /// The parser finished parsing of a syntax rule.
+    ///
+    /// See [Observer::leave_rule] for details.
+    LeaveRule {
+        /// The rule of the node produced by the parser.
+        rule: NodeRule,
+
+        /// A reference of the node created by this rule.
+        node_ref: NodeRef,
+
+        /// The site of the token stream at which the rule ends.
+        site: Site,
+    },
+
+    /// The parser consumed a token from the token stream.
+    ///
+    /// Consumed tokens include the tokens that the parser skips during
+    /// the error recovery.
+    ///
+    /// See [Observer::read_token] for details.
+    ReadToken {
+        /// The rule of the consumed token.
+        rule: TokenRule,
+
+        /// A reference of the consumed token in the source code.
+        token_ref: TokenRef,
+
+        /// The start site of the consumed token.
+        site: Site,
+    },
+
+    /// The parser lifted a sibling node to the current node.
+    ///
+    /// See [Observer::lift_node] for details.
+    LiftNode {
+        /// A reference of the node that has been lifted.
+        node_ref: NodeRef,
+
+        /// The site of the token stream at which the lifting occurred.
+        site: Site,
+    },
+
+    /// The parser made an error recovery decision.
+    ///
+    /// Unless the error is suppressed, the parser reports
+    /// the [SyntaxError](Self::SyntaxError) event right after this event.
+    ///
+    /// See [Observer::recover] for details.
+    Recover {
+        /// The rule that has applied the recovery strategy.
+        rule: NodeRule,
+
+        /// The applied recovery strategy.
+        result: RecoveryResult,
+
+        /// The token that the parser has assumed missing or has skipped,
+        /// if the strategy involved a particular token.
+        ///
+        /// See [SyntaxError::recovery_token](crate::syntax::SyntaxError::recovery_token)
+        /// for details.
+        token: Option<TokenRule>,
+
+        /// The number of tokens skipped by the panic recovery algorithm,
+        /// including the trivia tokens.
+        ///
+        /// The value is zero if the parser did not run the panic recovery.
+        ///
+        /// See [RecoveryDetails::skipped](crate::syntax::RecoveryDetails::skipped)
+        /// for details.
+        skipped: TokenCount,
+
+        /// The reason why the panic recovery algorithm has halted, or None if
+        /// the parser did not run the panic recovery.
+        halt: Option<RecoveryHalt>,
+
+        /// The site of the token stream at which the recovery has finished.
+        site: Site,
+    },
+
+    /// The parser reported a syntax error.
+    ///
+    /// See [Observer::syntax_error] for details.
+    SyntaxError {
+        /// A reference of the syntax error in the syntax tree.
+        error_ref: ErrorRef,
+
+        /// The site of the token stream at which the error has been reported.
+        site: Site,
+    },
+}
+
+impl ParseEvent {
+    /// Returns the site of the token stream at which this event occurred.
+    #[inline(always)]
+    pub fn site(&self) -> Site {
+        match self {
+            Self::EnterRule { site, .. } => *site,
+            Self::LeaveRule { site, .. } => *site,
+            Self::ReadToken { site, .. } => *site,
+            Self::LiftNode { site, .. } => *site,
+            Self::Recover { site, .. } => *site,
+            Self::SyntaxError { site, .. } => *site,
+        }
+    }
+
+    /// Returns true if this event and the `other` event describe the same
+    /// parsing step.
+    ///
+    /// Unlike the [Eq] implementation, this function ignores
+    /// the [identifiers](crate::arena::Id) of the compilation units
+    /// of the references, and compares their [entries](Entry) only. Thus,
+    /// the function could compare events of two distinct parsing sessions.
+    pub fn is_similar(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Self::EnterRule {
+                    rule: this_rule,
+                    node_ref: this_ref,
+                    site: this_site,
+                },
+                Self::EnterRule {
+                    rule: other_rule,
+                    node_ref: other_ref,
+                    site: other_site,
+                },
+            ) => {
+                this_rule == other_rule
+                    && this_ref.entry == other_ref.entry
+                    && this_site == other_site
+            }
+
+            (
+                Self::LeaveRule {
+                    rule: this_rule,
+                    node_ref: this_ref,
+                    site: this_site,
+                },
+                Self::LeaveRule {
+                    rule: other_rule,
+                    node_ref: other_ref,
+                    site: other_site,
+                },
+            ) => {
+                this_rule == other_rule
+                    && this_ref.entry == other_ref.entry
+                    && this_site == other_site
+            }
+
+            (
+                Self::ReadToken {
+                    rule: this_rule,
+                    token_ref: this_ref,
+                    site: this_site,
+                },
+                Self::ReadToken {
+                    rule: other_rule,
+                    token_ref: other_ref,
+                    site: other_site,
+                },
+            ) => {
+                this_rule == other_rule
+                    && this_ref.entry == other_ref.entry
+                    && this_site == other_site
+            }
+
+            (
+                Self::LiftNode {
+                    node_ref: this_ref,
+                    site: this_site,
+                },
+                Self::LiftNode {
+                    node_ref: other_ref,
+                    site: other_site,
+                },
+            ) => this_ref.entry == other_ref.entry && this_site == other_site,
+
+            (Self::Recover { .. }, Self::Recover { .. }) => self == other,
+
+            (
+                Self::SyntaxError {
+                    error_ref: this_ref,
+                    site: this_site,
+                },
+                Self::SyntaxError {
+                    error_ref: other_ref,
+                    site: other_site,
+                },
+            ) => this_ref.entry == other_ref.entry && this_site == other_site,
+
+            _ => false,
+        }
+    }
+}
+
+/// An [observer](Observer) that records parsing steps into a [ParseTrace].
+///
+/// Unlike the [DebugObserver](crate::syntax::DebugObserver), which prints
+/// parsing steps to the stdout, this object collects
+/// the structured [events](ParseEvent) that you can inspect, render, compare
+/// with another trace, or replay later on.
+///
+/// ```ignore
+/// let code = TokenBuffer::<MyToken>::from("foo bar");
+///
+/// let mut observer = TraceObserver::new(&code);
+///
+/// let tree = ImmutableSyntaxTree::<MyNode>::parse_with_observer(
+///     code.cursor(..),
+///     &mut observer,
+/// );
+///
+/// let trace = observer.into_trace();
+///
+/// println!("{}", trace.render());
+/// ```
+///
+/// The `code` parameter of the observer's constructor should be the same
+/// source code object which tokens are being parsed. The observer uses this
+/// object to infer the sites of the parsing steps.
+pub struct TraceObserver<'a, N: Node, C: SourceCode<Token = N::Token>> {
+    code: &'a C,
+    site: Site,
+    events: Vec<ParseEvent>,
+    _phantom: PhantomData<N>,
+}
+
+impl<'a, N, C> Observer for TraceObserver<'a, N, C>
+where
+    N: Node,
+    C: SourceCode<Token = N::Token>,
+{
+    type Node = N;
+
+    fn read_token(&mut self, token: <Self::Node as Node>::Token, token_ref: TokenRef) {
+        let site = self.site;
+
+        if let Some(length) = token_ref.length(self.code) {
+            self.site += length;
+        }
+
+        self.events.push(ParseEvent::ReadToken {
+            rule: token.rule(),
+            token_ref,
+            site,
+        });
+    }
+
+    #[inline(always)]
+    fn enter_rule(&mut self, rule: NodeRule, node_ref: NodeRef) {
+        self.events.push(ParseEvent::EnterRule {
+            rule,
+            node_ref,
+            site: self.site,
+        });
+    }
+
+    #[inline(always)]
+    fn leave_rule(&mut self, rule: NodeRule, node_ref: NodeRef) {
+        self.events.push(ParseEvent::LeaveRule {
+            rule,
+            node_ref,
+            site: self.site,
+        });
+    }
+
+    #[inline(always)]
+    fn lift_node(&mut self, node_ref: NodeRef) {
+        self.events.push(ParseEvent::LiftNode {
+            node_ref,
+            site: self.site,
+        });
+    }
+
+    #[inline(always)]
+    fn syntax_error(&mut self, error_ref: ErrorRef) {
+        self.events.push(ParseEvent::SyntaxError {
+            error_ref,
+            site: self.site,
+        });
+    }
+
+    fn recover(&mut self, error: &SyntaxError) {
+        let (skipped, halt) = match &error.recovery_details {
+            Some(details) => (details.skipped, Some(details.halt)),
+            None => (0, None),
+        };
+
+        self.events.push(ParseEvent::Recover {
+            rule: error.context,
+            result: error.recovery,
+            token: error.recovery_token,
+            skipped,
+            halt,
+            site: self.site,
+        });
+    }
+}
+
+impl<'a, N: Node, C: SourceCode<Token = N::Token>> TraceObserver<'a, N, C> {
+    /// Creates a new observer with an empty list of recorded events.
+    ///
+    /// The `code` parameter is the source code object which tokens will be
+    /// parsed.
+    #[inline(always)]
+    pub fn new(code: &'a C) -> Self {
+        Self {
+            code,
+            site: 0,
+            events: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns a slice of the events recorded so far.
+    #[inline(always)]
+    pub fn events(&self) -> &[ParseEvent] {
+        &self.events
+    }
+
+    /// Consumes this observer and returns a trace of all recorded events.
+    #[inline(always)]
+    pub fn into_trace(self) -> ParseTrace<N> {
+        ParseTrace {
+            events: self.events,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// A sequence of the syntax parser steps recorded by the [TraceObserver].
+///
+/// The trace object allows you to write regression tests for the syntax parser
+/// decisions rather than for the final syntax tree shape only:
+///
+///  - The [render](Self::render) function prints the trace as an indented
+///    text.
+///  - The [diff](Self::diff) function compares two traces and returns
+///    the first divergent step.
+///  - The [replay](Self::replay) function drives a [Visitor] through
+///    the recorded steps.
+///  - The [check](Self::check) function validates the trace against
+///    a syntax tree.
+pub struct ParseTrace<N: Node> {
+    events: Vec<ParseEvent>,
+    _phantom: PhantomData<N>,
+}
+
+impl<N: Node> Clone for ParseTrace<N> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            events: self.events.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<N: Node> PartialEq for ParseTrace<N> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.diff(other).is_none()
+    }
+}
+
+impl<N: Node> Eq for ParseTrace<N> {}
+
+impl<N: Node> ParseTrace<N> {
+    /// Returns a slice of the recorded events in the order of their
+    /// occurrences.
+    #[inline(always)]
+    pub fn events(&self) -> &[ParseEvent] {
+        &self.events
+    }
+
+    /// Returns the number of recorded events.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns true if the trace does not have any recorded events.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Renders the trace as an indented text.
+    ///
+    /// Each line of the output represents a single event accompanied by
+    /// the site at which the event occurred. The nested rules are indented
+    /// by four spaces.
+    pub fn render(&self) -> String {
+        let mut result = String::new();
+        let mut depth = 0usize;
+
+        for event in &self.events {
+            if let ParseEvent::LeaveRule { .. } = event {
+                depth = depth.saturating_sub(1);
+            }
+
+            for _ in 0..depth {
+                result.push_str("    ");
+            }
+
+            let _ = match event {
+                ParseEvent::EnterRule { rule, site, .. } => {
+                    let name = N::rule_name(*rule).unwrap_or("?");
+
+                    writeln!(result, "{name} @{site} {{")
+                }
+
+                ParseEvent::LeaveRule { rule, site, .. } => {
+                    let name = N::rule_name(*rule).unwrap_or("?");
+
+                    writeln!(result, "}} {name} @{site}")
+                }
+
+                ParseEvent::ReadToken { rule, site, .. } => {
+                    let name = <N::Token as Token>::rule_name(*rule).unwrap_or("?");
+
+                    writeln!(result, "${name} @{site}")
+                }
+
+                ParseEvent::LiftNode { site, .. } => writeln!(result, "--- lift @{site} ---"),
+
+                ParseEvent::Recover {
+                    result: recovery,
+                    skipped,
+                    site,
+                    ..
+                } => writeln!(result, "--- {recovery:?} ({skipped} skipped) @{site} ---"),
+
+                ParseEvent::SyntaxError { site, .. } => writeln!(result, "--- error @{site} ---"),
+            };
+
+            if let ParseEvent::EnterRule { .. } = event {
+                depth += 1;
+            }
+        }
+
+        result
+    }
+
+    /// Compares this trace with the `other` trace step by step.
+    ///
+    /// Returns None if both traces consist of
+    /// [similar](ParseEvent::is_similar) events. Otherwise, returns
+    /// a description of the first divergent step.
+    pub fn diff(&self, other: &Self) -> Option<TraceMismatch> {
+        let mut index = 0;
+
+        loop {
+            let this = self.events.get(index);
+            let that = other.events.get(index);
+
+            match (this, that) {
+                (None, None) => return None,
+
+                (Some(this), Some(that)) if this.is_similar(that) => (),
+
+                _ => {
+                    return Some(TraceMismatch {
+                        index,
+                        left: this.copied(),
+                        right: that.copied(),
+                    })
+                }
+            }
+
+            index += 1;
+        }
+    }
+
+    /// Re-drives the `visitor` through the recorded steps.
+    ///
+    /// The function calls [Visitor::visit_node] and [Visitor::leave_node] on
+    /// each rule entering and leaving event, and [Visitor::visit_token] on each
+    /// token consumption event.
+    ///
+    /// The replay process respects the [VisitControl] values returned by
+    /// the visit_node function: it skips all events nested in the rule
+    /// when the visitor returns [VisitControl::SkipChildren], and it stops
+    /// immediately when the visitor returns [VisitControl::Stop].
+    pub fn replay(&self, visitor: &mut impl Visitor) {
+        let mut skip = 0usize;
+
+        for event in &self.events {
+            match event {
+                ParseEvent::EnterRule { node_ref, .. } => {
+                    if skip > 0 {
+                        skip += 1;
+                        continue;
+                    }
+
+                    match visitor.visit_node(node_ref) {
+                        VisitControl::Continue => (),
+                        VisitControl::SkipChildren => skip = 1,
+                        VisitControl::Stop => return,
+                    }
+                }
+
+                ParseEvent::LeaveRule { node_ref, .. } => {
+                    if skip > 1 {
+                        skip -= 1;
+                        continue;
+                    }
+
+                    skip = 0;
+
+                    visitor.leave_node(node_ref);
+                }
+
+                ParseEvent::ReadToken { token_ref, .. } => {
+                    if skip > 0 {
+                        continue;
+                    }
+
+                    visitor.visit_token(token_ref);
+                }
+
+                _ => (),
+            }
+        }
+    }
+
+    /// Checks if the recorded steps are consistent with the syntax `tree`.
+    ///
+    /// Returns true if each node and each syntax error referred to by the trace
+    /// events exists in the `tree`, and if the rules of the leaving events
+    /// match the rules of the corresponding nodes.
+    ///
+    /// Typically, the `tree` is the syntax tree produced by the same parsing
+    /// session that the trace has been recorded from.
+    pub fn check(&self, tree: &impl SyntaxTree<Node = N>) -> bool {
+        for event in &self.events {
+            match event {
+                ParseEvent::EnterRule { node_ref, .. } | ParseEvent::LiftNode { node_ref, .. } => {
+                    if !node_ref.is_valid_ref(tree) {
+                        return false;
+                    }
+                }
+
+                ParseEvent::LeaveRule { rule, node_ref, .. } => {
+                    let Some(node) = node_ref.deref(tree) else {
+                        return false;
+                    };
+
+                    if &node.rule() != rule {
+                        return false;
+                    }
+                }
+
+                ParseEvent::SyntaxError { error_ref, .. } => {
+                    if !error_ref.is_valid_ref(tree) {
+                        return false;
+                    }
+                }
+
+                ParseEvent::ReadToken { .. } | ParseEvent::Recover { .. } => (),
+            }
+        }
+
+        true
+    }
+}
+
+/// A description of the first divergent step between two [ParseTrace]
+/// objects.
+///
+/// This object is created by the [ParseTrace::diff] function.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct TraceMismatch {
+    /// The index of the divergent event in both traces.
+    pub index: usize,
+
+    /// The event of the left trace, or None if the left trace ends at
+    /// the [index](Self::index).
+    pub left: Option<ParseEvent>,
+
+    /// The event of the right trace, or None if the right trace ends at
+    /// the [index](Self::index).
+    pub right: Option<ParseEvent>,
+}
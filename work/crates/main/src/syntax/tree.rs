@@ -32,12 +32,14 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::iter::FusedIterator;
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
 
 use crate::{
     arena::{Entry, Identifiable},
-    lexis::TokenRef,
-    syntax::{AbstractNode, ErrorRef, Node, NodeRef, RefKind, SyntaxError},
+    lexis::{Site, TokenRef},
+    syntax::{AbstractNode, ErrorRef, Node, NodeRef, PolyRef, RefKind, SyntaxError, TreeStats},
+    units::CompilationUnit,
 };
 
 /// An object that provides access to the syntax structure of
@@ -163,14 +165,24 @@ pub trait SyntaxTree: Identifiable {
     /// The `visitor` object will be called on each node entering and
     /// leaving events, as well as the token entering event.
     ///
-    /// The traverse algorithm will not visit descending nodes of the tree
-    /// branch if the [Visitor::enter_node] returns false. Thus, the visitor
-    /// can control the descending process.
+    /// The [Visitor::visit_node] function controls the traversal process:
+    ///
+    ///  - [VisitControl::Continue] tells the traverser to descend into
+    ///    the entered node's children.
+    ///  - [VisitControl::SkipChildren] tells the traverser to skip
+    ///    the entered node's children.
+    ///  - [VisitControl::Stop] aborts the whole traversal process.
     ///
     /// The algorithm relies on the [AbstractNode::children_iter] function
     /// to determine the node's children to descend to, which in turn relies on
     /// the node's captures description.
     ///
+    /// The children of each node are visited in the order of the node's
+    /// captures description, which does not necessarily match the source
+    /// code order of the children (e.g., if the captures of the parse rule
+    /// interleave). If the source code order matters, use
+    /// the [CompilationUnit::traverse_in_order] function instead.
+    ///
     /// In other words, the traverser will only visit the nodes for which you
     /// have specified `#[child]` attribute:
     ///
@@ -201,25 +213,41 @@ pub trait SyntaxTree: Identifiable {
     /// the branch.
     ///
     /// For details, see [SyntaxTree::traverse_tree].
+    #[inline(always)]
     fn traverse_subtree(&self, top: &NodeRef, visitor: &mut impl Visitor)
     where
         Self: Sized,
     {
-        if visitor.enter_node(top) {
-            let node: &Self::Node = match top.deref(self) {
-                Some(node) => node,
-                None => return,
-            };
-
-            for child in node.children_iter() {
-                match child.kind() {
-                    RefKind::Token => visitor.visit_token(child.as_token_ref()),
-                    RefKind::Node => self.traverse_subtree(child.as_node_ref(), visitor),
-                }
-            }
-        }
+        let _ = traverse(self, top, visitor);
+    }
 
-        visitor.leave_node(top)
+    /// Computes a summary of the syntax tree shape.
+    ///
+    /// The returning [TreeStats] object includes the node counts per parse
+    /// rule, the maximum depth of the tree, the total number of children,
+    /// the number of syntax errors, and the source code and memory usage
+    /// metrics if the syntax tree is a part of a compilation unit.
+    ///
+    /// The default implementation computes the summary in a single
+    /// depth-first [traversal](Self::traverse_tree) of the tree. Thus, only
+    /// the nodes reachable from the root node through the `#[child]` captures
+    /// are counted, and the source code and memory metrics are zeroes.
+    ///
+    /// The canonical implementations count all nodes of the tree directly in
+    /// the tree's storage rather than traversing the tree. The mutable
+    /// [Document](crate::units::Document) maintains the node, children, rule,
+    /// error, and token counts on each reparse, and measures
+    /// the [maximum depth](TreeStats::max_depth) of the tree by walking over
+    /// the depths of the node clusters that it records on reparse; the heap
+    /// size estimation walks over the pages of the source code storage.
+    /// The immutable syntax trees traverse the nodes to measure the depth of
+    /// the tree.
+    #[inline(always)]
+    fn stats(&self) -> TreeStats<Self::Node>
+    where
+        Self: Sized,
+    {
+        TreeStats::new(self)
     }
 
     /// Checks if the node referred to by the versioned index exists in this
@@ -338,11 +366,157 @@ pub trait Visitor {
     ///
     /// Returning false prevents the traverser further descending into
     /// the node's sub-branch.
-    fn enter_node(&mut self, node_ref: &NodeRef) -> bool;
+    ///
+    /// This function is called by the default implementation of
+    /// the [visit_node](Self::visit_node) function. If you implement
+    /// the visit_node function instead, you don't need to implement this
+    /// function.
+    #[inline(always)]
+    #[allow(unused_variables)]
+    fn enter_node(&mut self, node_ref: &NodeRef) -> bool {
+        true
+    }
+
+    /// Triggers when the traverser enters a node, and controls
+    /// the traversal process.
+    ///
+    /// This function is a more flexible version of
+    /// the [enter_node](Self::enter_node) function. The returning
+    /// [VisitControl] value tells the traverser whether to descend into
+    /// the node's sub-branch, to skip it, or to stop the traversal entirely.
+    ///
+    /// By default, this function delegates to the enter_node function and
+    /// interprets its result as [VisitControl::Continue] or
+    /// [VisitControl::SkipChildren] correspondingly.
+    #[inline(always)]
+    fn visit_node(&mut self, node_ref: &NodeRef) -> VisitControl {
+        self.enter_node(node_ref).into()
+    }
 
     /// Triggers when the traverser leaves a node.
     ///
     /// In practice, this function observes depth-first traversing
     /// in reverse order.
+    ///
+    /// The traverser calls this function for the entered nodes which
+    /// children have been skipped as well, but it does not call this function
+    /// once the traversal has been [stopped](VisitControl::Stop).
     fn leave_node(&mut self, node_ref: &NodeRef);
 }
+
+/// A value returned by the [Visitor::visit_node] function that controls
+/// the syntax tree traversal process.
+///
+/// See [SyntaxTree::traverse_tree] for details.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Debug)]
+pub enum VisitControl {
+    /// Descend into the children of the entered node.
+    #[default]
+    Continue,
+
+    /// Do not descend into the children of the entered node, but continue
+    /// the traversal of the rest of the tree.
+    SkipChildren,
+
+    /// Abort the traversal process.
+    Stop,
+}
+
+impl From<bool> for VisitControl {
+    #[inline(always)]
+    fn from(value: bool) -> Self {
+        match value {
+            true => Self::Continue,
+            false => Self::SkipChildren,
+        }
+    }
+}
+
+// Returns false if the traversal has been stopped.
+fn traverse<T: SyntaxTree>(tree: &T, top: &NodeRef, visitor: &mut impl Visitor) -> bool {
+    match visitor.visit_node(top) {
+        VisitControl::Continue => {
+            let node: &T::Node = match top.deref(tree) {
+                Some(node) => node,
+                None => return true,
+            };
+
+            for child in node.children_iter() {
+                match child.kind() {
+                    RefKind::Token => visitor.visit_token(child.as_token_ref()),
+                    RefKind::Node => {
+                        if !traverse(tree, child.as_node_ref(), visitor) {
+                            return false;
+                        }
+                    }
+                }
+            }
+        }
+
+        VisitControl::SkipChildren => (),
+
+        VisitControl::Stop => return false,
+    }
+
+    visitor.leave_node(top);
+
+    true
+}
+
+// Returns false if the traversal has been stopped.
+pub(crate) fn traverse_in_order<U: CompilationUnit>(
+    unit: &U,
+    top: &NodeRef,
+    visitor: &mut impl Visitor,
+) -> bool {
+    match visitor.visit_node(top) {
+        VisitControl::Continue => {
+            let node: &U::Node = match top.deref(unit) {
+                Some(node) => node,
+                None => return true,
+            };
+
+            // The children without the inferable span inherit the start site
+            // of the previous child such that the stable sort keeps them
+            // in place.
+            let mut site: Site = 0;
+
+            let mut children = node
+                .children_iter()
+                .map(|child| {
+                    let span = match child.kind() {
+                        RefKind::Token => child.as_token_ref().span(unit),
+                        RefKind::Node => child.as_node_ref().span(unit),
+                    };
+
+                    if let Some(span) = span {
+                        site = span.start;
+                    }
+
+                    (site, child)
+                })
+                .collect::<Vec<_>>();
+
+            children.sort_by_key(|(site, _)| *site);
+
+            for (_, child) in children {
+                match child.kind() {
+                    RefKind::Token => visitor.visit_token(child.as_token_ref()),
+                    RefKind::Node => {
+                        if !traverse_in_order(unit, child.as_node_ref(), visitor) {
+                            return false;
+                        }
+                    }
+                }
+            }
+        }
+
+        VisitControl::SkipChildren => (),
+
+        VisitControl::Stop => return false,
+    }
+
+    visitor.leave_node(top);
+
+    true
+}
@@ -0,0 +1,307 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use alloc::{collections::BTreeSet, vec::Vec};
+use core::fmt::{Display, Formatter};
+
+use crate::{
+    arena::{Id, Identifiable},
+    lexis::TokenRef,
+    syntax::{AbstractNode, Key, NodeRef, PolyRef, RefKind, SyntaxTree},
+};
+
+/// Checks the structural invariants of the syntax tree.
+///
+/// This function is a debugging utility for the authors of custom syntax
+/// parsers (e.g., the `#[parser(...)]` functions of
+/// the [Node](lady_deirdre_derive::Node) derive macro). It walks through
+/// the syntax tree starting from the root node and reports all detected
+/// [violations](Violation) of the following invariants:
+///
+///  - The [node_ref](AbstractNode::node_ref) of each node refers to the node
+///    itself.
+///  - The [parent_ref](AbstractNode::parent_ref) of each child node refers to
+///    the node that captures this child.
+///  - All captured [NodeRef] references belong to the `tree`, and all
+///    captured [TokenRef] references belong to the same source code.
+///  - Each node is captured at most once.
+///  - Each [capture key](AbstractNode::capture_keys) of the node addresses
+///    a capture.
+///  - Each node of the `tree` is reachable from the root node.
+///
+/// The nodes that have disabled node_ref or parent_ref features (the functions
+/// return [nil](NodeRef::nil) references) are not checked against
+/// the corresponding invariants. Similarly, the nil child references within
+/// the captures are permitted.
+///
+/// Returns an empty vector if the tree is well-formed.
+pub fn validate(tree: &impl SyntaxTree) -> Vec<Violation> {
+    let mut validator = Validator {
+        id: tree.id(),
+        token_id: None,
+        visited: BTreeSet::new(),
+        violations: Vec::new(),
+    };
+
+    let root = tree.root_node_ref();
+
+    if root.deref(tree).is_some() {
+        let _ = validator.visited.insert(root);
+        validator.check_node(tree, &root, &NodeRef::nil());
+    }
+
+    for node_ref in tree.node_refs() {
+        if !node_ref.is_valid_ref(tree) || validator.visited.contains(&node_ref) {
+            continue;
+        }
+
+        validator.violations.push(Violation {
+            node_ref,
+            kind: ViolationKind::Unreachable,
+        });
+    }
+
+    validator.violations
+}
+
+/// A violation of the syntax tree invariant detected by the [validate]
+/// function.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Violation {
+    /// A reference to the offending node.
+    pub node_ref: NodeRef,
+
+    /// The kind of the violated invariant.
+    pub kind: ViolationKind,
+}
+
+impl Display for Violation {
+    #[inline(always)]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        formatter.write_fmt(format_args!("{:?}: {}", self.node_ref, self.kind))
+    }
+}
+
+/// A kind of the [Violation].
+///
+/// The [Display] implementation of this object prints a human-readable
+/// description of the violated invariant.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ViolationKind {
+    /// The [node_ref](AbstractNode::node_ref) function of the node returns
+    /// a reference to another node.
+    NodeRefMismatch {
+        /// The reference returned by the node_ref function.
+        actual: NodeRef,
+    },
+
+    /// The [parent_ref](AbstractNode::parent_ref) function of the node
+    /// returns a reference to a node other than the node that captures it.
+    ParentMismatch {
+        /// The node that captures the offending node.
+        expected: NodeRef,
+
+        /// The reference returned by the parent_ref function.
+        actual: NodeRef,
+    },
+
+    /// The node captures a [NodeRef] that belongs to another syntax tree.
+    ForeignNode {
+        /// The captured reference.
+        child: NodeRef,
+    },
+
+    /// The node captures a [NodeRef] that does not refer to any node
+    /// within the syntax tree.
+    DanglingNode {
+        /// The captured reference.
+        child: NodeRef,
+    },
+
+    /// The node captures a [TokenRef] that belongs to another source code
+    /// than the other captured tokens of the syntax tree.
+    ForeignToken {
+        /// The captured reference.
+        child: TokenRef,
+    },
+
+    /// The node captures a child node that has already been captured
+    /// by this or another node.
+    DuplicateNode {
+        /// The captured reference.
+        child: NodeRef,
+    },
+
+    /// The [capture](AbstractNode::capture) function of the node returns None
+    /// for the key listed in the [capture_keys](AbstractNode::capture_keys).
+    MissingCapture {
+        /// The index of the capture key.
+        index: usize,
+    },
+
+    /// The node is not reachable from the root node of the syntax tree.
+    Unreachable,
+}
+
+impl Display for ViolationKind {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NodeRefMismatch { actual } => formatter.write_fmt(format_args!(
+                "The node's node_ref refers to another node {actual:?}."
+            )),
+
+            Self::ParentMismatch { expected, actual } => formatter.write_fmt(format_args!(
+                "The node's parent_ref is {actual:?}, but the node is captured by {expected:?}."
+            )),
+
+            Self::ForeignNode { child } => formatter.write_fmt(format_args!(
+                "The node captures {child:?} that belongs to another syntax tree."
+            )),
+
+            Self::DanglingNode { child } => formatter.write_fmt(format_args!(
+                "The node captures {child:?} that does not exist in the syntax tree."
+            )),
+
+            Self::ForeignToken { child } => formatter.write_fmt(format_args!(
+                "The node captures {child:?} that belongs to another source code."
+            )),
+
+            Self::DuplicateNode { child } => formatter.write_fmt(format_args!(
+                "The node captures {child:?} that has already been captured."
+            )),
+
+            Self::MissingCapture { index } => formatter.write_fmt(format_args!(
+                "The node does not have a capture for the capture key #{index}."
+            )),
+
+            Self::Unreachable => {
+                formatter.write_str("The node is not reachable from the root node.")
+            }
+        }
+    }
+}
+
+struct Validator {
+    id: Id,
+    token_id: Option<Id>,
+    visited: BTreeSet<NodeRef>,
+    violations: Vec<Violation>,
+}
+
+impl Validator {
+    fn check_node(&mut self, tree: &impl SyntaxTree, node_ref: &NodeRef, parent_ref: &NodeRef) {
+        let Some(node) = node_ref.deref(tree) else {
+            return;
+        };
+
+        let actual = node.node_ref();
+
+        if !actual.is_nil() && &actual != node_ref {
+            self.report(node_ref, ViolationKind::NodeRefMismatch { actual });
+        }
+
+        let actual = node.parent_ref();
+
+        if !actual.is_nil() && !parent_ref.is_nil() && &actual != parent_ref {
+            self.report(
+                node_ref,
+                ViolationKind::ParentMismatch {
+                    expected: *parent_ref,
+                    actual,
+                },
+            );
+        }
+
+        for (index, key) in node.capture_keys().iter().enumerate() {
+            if node.capture(*key).is_none() || node.capture(Key::Index(index)).is_none() {
+                self.report(node_ref, ViolationKind::MissingCapture { index });
+            }
+        }
+
+        let mut children = Vec::new();
+
+        for child in node.children_iter() {
+            if child.is_nil() {
+                continue;
+            }
+
+            match child.kind() {
+                RefKind::Token => {
+                    let child = *child.as_token_ref();
+
+                    match self.token_id {
+                        None => self.token_id = Some(child.id),
+                        Some(id) if id != child.id => {
+                            self.report(node_ref, ViolationKind::ForeignToken { child })
+                        }
+                        _ => (),
+                    }
+                }
+
+                RefKind::Node => {
+                    let child = *child.as_node_ref();
+
+                    if child.id != self.id {
+                        self.report(node_ref, ViolationKind::ForeignNode { child });
+                        continue;
+                    }
+
+                    if !child.is_valid_ref(tree) {
+                        self.report(node_ref, ViolationKind::DanglingNode { child });
+                        continue;
+                    }
+
+                    if !self.visited.insert(child) {
+                        self.report(node_ref, ViolationKind::DuplicateNode { child });
+                        continue;
+                    }
+
+                    children.push(child);
+                }
+            }
+        }
+
+        for child in children {
+            self.check_node(tree, &child, node_ref);
+        }
+    }
+
+    #[inline(always)]
+    fn report(&mut self, node_ref: &NodeRef, kind: ViolationKind) {
+        self.violations.push(Violation {
+            node_ref: *node_ref,
+            kind,
+        });
+    }
+}
@@ -32,7 +32,7 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::{
+use core::{
     any::TypeId,
     fmt::{Debug, Formatter},
     marker::PhantomData,
@@ -70,7 +70,7 @@ impl<T: Token> Default for VoidSyntax<T> {
 
 impl<T: Token> Debug for VoidSyntax<T> {
     #[inline(always)]
-    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
         formatter.write_str("NoSyntax")
     }
 }
@@ -0,0 +1,203 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use alloc::{borrow::ToOwned, format, string::String, vec::Vec};
+
+use crate::{
+    lexis::{SiteSpan, SourceCode, Token, TokenRef, TokenRule},
+    syntax::{Node, NodeRef, NodeRule, PolyRef, SyntaxTree, Visitor},
+    testing::TreeSnapshot,
+    units::{CompilationUnit, Document},
+};
+
+/// Checks that the incrementally reparsed `document` is identical to
+/// the document parsed from scratch.
+///
+/// The function parses the current text of the `document` into a new
+/// immutable Document, and compares the token streams, the syntax trees
+/// (the rules and the spans of the nodes and the tokens in the depth-first
+/// order), and the [snapshots](TreeSnapshot) of both documents including
+/// their syntax errors.
+///
+/// This function is intended for the differential testing of the incremental
+/// reparser together with the [GrammarGen](crate::testing::GrammarGen).
+///
+/// **Panic**
+///
+/// Panics with a description of the first difference if the documents
+/// are not identical.
+pub fn check_reparse<N: Node>(document: &Document<N>) {
+    if let Some(divergence) = find_divergence(document) {
+        panic!("{divergence}");
+    }
+}
+
+// Returns a description of the first difference between the `document` and
+// the document parsed from scratch, or None if the documents are identical.
+pub(super) fn find_divergence<N: Node>(document: &Document<N>) -> Option<String> {
+    let text = document.substring(..).into_owned();
+    let fresh = Document::<N>::new_immutable(&text);
+
+    let mut left = document.chunks(..);
+    let mut right = fresh.chunks(..);
+    let mut index = 0;
+
+    loop {
+        match (left.next(), right.next()) {
+            (None, None) => break,
+
+            (Some(left), Some(right)) => {
+                if left.token != right.token
+                    || left.site != right.site
+                    || left.string != right.string
+                {
+                    return Some(format!(
+                        "Token #{index} mismatch.\nIncremental: {:?} {:?} at {}.\nFresh: {:?} \
+                        {:?} at {}.",
+                        left.token.name().unwrap_or("?"),
+                        left.string,
+                        left.site,
+                        right.token.name().unwrap_or("?"),
+                        right.string,
+                        right.site,
+                    ));
+                }
+            }
+
+            (left, right) => {
+                return Some(format!(
+                    "Token #{index} mismatch.\nIncremental: {:?}.\nFresh: {:?}.",
+                    left.map(|chunk| chunk.string),
+                    right.map(|chunk| chunk.string),
+                ))
+            }
+        }
+
+        index += 1;
+    }
+
+    let left = SyntaxEvents::collect(document);
+    let right = SyntaxEvents::collect(&fresh);
+
+    for (index, (left, right)) in left.iter().zip(right.iter()).enumerate() {
+        if left != right {
+            return Some(format!(
+                "Syntax tree mismatch at event #{index}.\nIncremental: {}.\nFresh: {}.",
+                left.describe::<N>(),
+                right.describe::<N>(),
+            ));
+        }
+    }
+
+    if left.len() != right.len() {
+        return Some(format!(
+            "Syntax tree mismatch.\nIncremental tree events: {}.\nFresh tree events: {}.",
+            left.len(),
+            right.len(),
+        ));
+    }
+
+    let left = TreeSnapshot::new(document);
+    let right = TreeSnapshot::new(&fresh);
+
+    if let Some(diff) = left.diff(&right) {
+        return Some(format!("Syntax tree snapshot mismatch.\n{diff}"));
+    }
+
+    None
+}
+
+#[derive(PartialEq, Eq)]
+enum SyntaxEvent {
+    Enter(NodeRule, Option<SiteSpan>),
+    Leave,
+    Token(TokenRule, Option<SiteSpan>),
+}
+
+impl SyntaxEvent {
+    fn describe<N: Node>(&self) -> String {
+        match self {
+            Self::Enter(rule, span) => {
+                format!("enter {} {span:?}", N::rule_name(*rule).unwrap_or("?"))
+            }
+
+            Self::Leave => "leave".to_owned(),
+
+            Self::Token(rule, span) => format!(
+                "token {} {span:?}",
+                <N::Token as Token>::rule_name(*rule).unwrap_or("?"),
+            ),
+        }
+    }
+}
+
+struct SyntaxEvents<'unit, N: Node> {
+    unit: &'unit Document<N>,
+    events: Vec<SyntaxEvent>,
+}
+
+impl<'unit, N: Node> Visitor for SyntaxEvents<'unit, N> {
+    fn visit_token(&mut self, token_ref: &TokenRef) {
+        self.events.push(SyntaxEvent::Token(
+            token_ref.rule(self.unit),
+            token_ref.span(self.unit),
+        ));
+    }
+
+    fn enter_node(&mut self, node_ref: &NodeRef) -> bool {
+        self.events.push(SyntaxEvent::Enter(
+            node_ref.rule(self.unit),
+            node_ref.span(self.unit),
+        ));
+
+        true
+    }
+
+    fn leave_node(&mut self, _node_ref: &NodeRef) {
+        self.events.push(SyntaxEvent::Leave);
+    }
+}
+
+impl<'unit, N: Node> SyntaxEvents<'unit, N> {
+    fn collect(unit: &'unit Document<N>) -> Vec<SyntaxEvent> {
+        let mut visitor = Self {
+            unit,
+            events: Vec::new(),
+        };
+
+        unit.traverse_tree(&mut visitor);
+
+        visitor.events
+    }
+}
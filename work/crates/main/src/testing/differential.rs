@@ -0,0 +1,398 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use alloc::{string::String, vec::Vec};
+use core::fmt::{Debug, Display, Formatter};
+
+use crate::{
+    lexis::ToSpan,
+    syntax::Node,
+    testing::{check::find_divergence, GenConfig, GenEdit, GenGrammar, GrammarGen},
+    units::Document,
+};
+
+/// A configuration of the [check_differential] campaign.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct DiffConfig {
+    /// A configuration of the documents and edits generator.
+    ///
+    /// The [seed](GenConfig::seed) of this configuration is the seed of
+    /// the first run. Each next run uses the next seed number.
+    pub gen: GenConfig,
+
+    /// The number of independent runs, each starting from a new generated
+    /// document.
+    ///
+    /// The default value is 10.
+    pub runs: usize,
+
+    /// The maximum number of edits applied to the document within a single
+    /// run.
+    ///
+    /// The default value is 100.
+    pub edits: usize,
+
+    /// The checker compares the incrementally reparsed document with
+    /// the document parsed from scratch after every `period`-th edit, and
+    /// after the last edit of the run.
+    ///
+    /// Larger values speed up the campaign. Once the checker detects
+    /// a divergence, it replays the run edit by edit to find the first
+    /// divergent edit regardless of this value.
+    ///
+    /// The default value is 1.
+    pub period: usize,
+
+    /// If true, the checker tries to minimize the edit sequence that leads
+    /// to the divergence before reporting a failure.
+    ///
+    /// The default value is true.
+    pub shrink: bool,
+}
+
+impl Default for DiffConfig {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DiffConfig {
+    /// Creates a default configuration.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            gen: GenConfig::new(),
+            runs: 10,
+            edits: 100,
+            period: 1,
+            shrink: true,
+        }
+    }
+}
+
+/// A divergence detected by the [check_differential] function.
+///
+/// The object contains everything needed to reproduce the divergence:
+/// the initial text of the document and the sequence of edits such that
+/// the mutable Document diverges from the document parsed from scratch
+/// right after the last edit.
+///
+/// The [Display] and the [Debug] implementations of this object print
+/// the reproduction steps.
+#[derive(Clone, PartialEq, Eq)]
+pub struct DiffFailure {
+    /// The generator's seed of the failed run.
+    pub seed: u64,
+
+    /// The initial text of the mutable Document.
+    pub text: String,
+
+    /// The edits to apply to the mutable Document in order.
+    ///
+    /// If the list is empty, the divergence occurs in the initial document.
+    pub edits: Vec<GenEdit>,
+
+    /// A description of the difference between the documents.
+    pub divergence: String,
+}
+
+impl Debug for DiffFailure {
+    #[inline(always)]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, formatter)
+    }
+}
+
+impl Display for DiffFailure {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        formatter.write_fmt(format_args!(
+            "Incremental reparsing divergence (seed {}).\n{}\n\nInitial text: {:?}\n",
+            self.seed, self.divergence, self.text,
+        ))?;
+
+        for (index, edit) in self.edits.iter().enumerate() {
+            formatter.write_fmt(format_args!(
+                "Edit #{index} ({:?}): {}..{} => {:?}\n",
+                edit.kind, edit.span.start, edit.span.end, edit.text,
+            ))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl DiffFailure {
+    /// Replays the edits of this failure against a new mutable Document of
+    /// the `N` grammar.
+    ///
+    /// Returns a description of the first detected divergence, or None if
+    /// the divergence does not reproduce (e.g., if it has been fixed).
+    #[inline(always)]
+    pub fn reproduce<N: Node>(&self) -> Option<String> {
+        replay::<N>(&self.text, &self.edits).map(|(_, divergence)| divergence)
+    }
+}
+
+/// Runs a differential testing campaign of the incremental reparser.
+///
+/// For each run of the campaign, the function generates a random document of
+/// the `grammar` language using the [GrammarGen], and then applies a sequence
+/// of random edits to the mutable [Document]. After each edit (see
+/// [DiffConfig::period]), the function compares the mutable Document with
+/// the immutable Document parsed from the current text from scratch:
+/// the token chunks, the syntax trees, and the syntax errors of both
+/// documents must be identical (see [check_reparse](crate::testing::check_reparse)).
+///
+/// The campaign is deterministic: for the same grammar and configuration it
+/// produces the same documents and edits.
+///
+/// Returns a [DiffFailure] describing the first detected divergence together
+/// with the edit history needed to reproduce it. If the
+/// [DiffConfig::shrink] option is enabled, the edit history is minimized.
+///
+/// ```ignore
+/// let result = check_differential(&my_gen_grammar(), &DiffConfig::new());
+///
+/// if let Err(failure) = result {
+///     panic!("{failure}");
+/// }
+/// ```
+pub fn check_differential<N: Node>(
+    grammar: &GenGrammar<N>,
+    config: &DiffConfig,
+) -> Result<(), DiffFailure> {
+    let period = config.period.max(1);
+
+    for run in 0..config.runs {
+        let gen = GenConfig {
+            seed: config.gen.seed.wrapping_add(run as u64),
+            ..config.gen
+        };
+
+        let mut generator = GrammarGen::new(grammar, gen);
+        let text = generator.text();
+        let mut document = Document::<N>::new_mutable(&text);
+        let mut edits = Vec::new();
+        let mut diverged = find_divergence(&document).is_some();
+
+        while !diverged && edits.len() < config.edits {
+            let Some(edit) = generator.gen_edit() else {
+                break;
+            };
+
+            edit.apply_to_document(&mut document);
+            edits.push(edit);
+
+            if edits.len() % period == 0 {
+                diverged = find_divergence(&document).is_some();
+            }
+        }
+
+        if !diverged && edits.len() % period != 0 {
+            diverged = find_divergence(&document).is_some();
+        }
+
+        if !diverged {
+            continue;
+        }
+
+        let Some((mut edits, mut divergence)) = replay::<N>(&text, &edits) else {
+            panic!(
+                "Differential check failure does not reproduce (seed {}).",
+                gen.seed,
+            );
+        };
+
+        if config.shrink {
+            (edits, divergence) = shrink(edits, divergence, |edits| replay::<N>(&text, edits));
+        }
+
+        return Err(DiffFailure {
+            seed: gen.seed,
+            text,
+            edits,
+            divergence,
+        });
+    }
+
+    Ok(())
+}
+
+// Applies the `edits` to a new mutable Document of the `text`, and checks
+// the document after each edit. Skips the edits with the spans that are not
+// valid for the current document's text.
+//
+// Returns the applied edits up to the first divergence and the divergence
+// description, or None if the documents do not diverge.
+fn replay<N: Node>(text: &str, edits: &[GenEdit]) -> Option<(Vec<GenEdit>, String)> {
+    let mut document = Document::<N>::new_mutable(text);
+
+    if let Some(divergence) = find_divergence(&document) {
+        return Some((Vec::new(), divergence));
+    }
+
+    let mut applied = Vec::new();
+
+    for edit in edits {
+        if !edit.span.is_valid_span(&document) {
+            continue;
+        }
+
+        edit.apply_to_document(&mut document);
+        applied.push(edit.clone());
+
+        if let Some(divergence) = find_divergence(&document) {
+            return Some((applied, divergence));
+        }
+    }
+
+    None
+}
+
+// Removes the edits from the `edits` sequence one by one as long as
+// the `reproduce` function still detects a failure.
+fn shrink(
+    mut edits: Vec<GenEdit>,
+    mut divergence: String,
+    mut reproduce: impl FnMut(&[GenEdit]) -> Option<(Vec<GenEdit>, String)>,
+) -> (Vec<GenEdit>, String) {
+    let mut index = 0;
+
+    while index < edits.len() {
+        let mut candidate = edits.clone();
+
+        let _ = candidate.remove(index);
+
+        match reproduce(&candidate) {
+            Some((applied, reproduced)) if applied.len() < edits.len() => {
+                edits = applied;
+                divergence = reproduced;
+            }
+
+            _ => index += 1,
+        }
+    }
+
+    (edits, divergence)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{
+        string::{String, ToString},
+        vec::Vec,
+    };
+
+    use crate::{
+        lexis::Token,
+        syntax::{VoidSyntax, ROOT_RULE},
+        testing::{
+            check_differential,
+            differential::shrink,
+            DiffConfig,
+            GenEdit,
+            GenEditKind,
+            GenExpr,
+            GenGrammar,
+        },
+    };
+
+    #[derive(Token, Clone, Copy, PartialEq, Eq, Debug)]
+    #[repr(u8)]
+    enum TestToken {
+        EOI = 0,
+        Mismatch = 1,
+        #[rule(^['\n']+)]
+        Text,
+        #[rule('\n')]
+        Newline,
+    }
+
+    #[test]
+    fn test_differential() {
+        let mut grammar = GenGrammar::<VoidSyntax<TestToken>>::new();
+
+        grammar
+            .set_rule(
+                ROOT_RULE,
+                GenExpr::zero_or_more(GenExpr::token(TestToken::Text))
+                    .with_separator(GenExpr::token(TestToken::Newline)),
+            )
+            .set_token(TestToken::Text, &["foo", "bar", "ü"])
+            .set_token(TestToken::Newline, &["\n"])
+            .set_separators(&["", " "]);
+
+        let config = DiffConfig {
+            runs: 5,
+            edits: 30,
+            period: 3,
+            ..DiffConfig::new()
+        };
+
+        check_differential(&grammar, &config).unwrap();
+    }
+
+    #[test]
+    fn test_differential_shrink() {
+        let edits = ["a", "b", "bad", "c", "d"]
+            .into_iter()
+            .map(|text| GenEdit {
+                kind: GenEditKind::Insert,
+                span: 0..0,
+                text: text.to_string(),
+            })
+            .collect::<Vec<_>>();
+
+        let (edits, divergence) = shrink(edits, String::new(), |edits| {
+            let mut text = String::new();
+            let mut applied = Vec::new();
+
+            for edit in edits {
+                edit.apply_to_string(&mut text);
+                applied.push(edit.clone());
+
+                if text.contains("bad") {
+                    return Some((applied, text));
+                }
+            }
+
+            None
+        });
+
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].text, "bad");
+        assert_eq!(divergence, "bad");
+    }
+}
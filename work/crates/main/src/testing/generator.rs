@@ -0,0 +1,813 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use alloc::{boxed::Box, collections::BTreeMap, string::String, vec::Vec};
+use core::fmt::{Debug, Formatter};
+
+use crate::{
+    lexis::{Site, SiteSpan},
+    report::ld_unreachable,
+    syntax::{Node, NodeRule},
+    testing::{GenExpr, GenGrammar, GenRng},
+    units::Document,
+};
+
+/// A configuration of the [GrammarGen].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct GenConfig {
+    /// A seed number of the pseudo-random number generator.
+    ///
+    /// The generator produces the same document and the same sequence of
+    /// edits for the same seed, grammar, and configuration.
+    ///
+    /// The default value is 0.
+    pub seed: u64,
+
+    /// The maximum depth of the generated syntax trees.
+    ///
+    /// The generator prefers the shortest alternatives of the rules once
+    /// the depth limit is reached. The limit could be exceeded if the grammar
+    /// has no shorter derivations.
+    ///
+    /// The default value is 8.
+    pub max_depth: usize,
+
+    /// The maximum number of repetitions of the unlimited repetition
+    /// expressions.
+    ///
+    /// The default value is 5.
+    pub max_repeat: usize,
+
+    /// A relative probability of the [insertion](GenEditKind::Insert) edits.
+    ///
+    /// The default value is 1.
+    pub insert_weight: u32,
+
+    /// A relative probability of the [deletion](GenEditKind::Delete) edits.
+    ///
+    /// The default value is 1.
+    pub delete_weight: u32,
+
+    /// A relative probability of the [replacement](GenEditKind::Replace)
+    /// edits.
+    ///
+    /// The default value is 1.
+    pub replace_weight: u32,
+}
+
+impl Default for GenConfig {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GenConfig {
+    /// Creates a default configuration.
+    ///
+    /// The default configuration produces relatively small documents
+    /// (from tens to thousands of tokens) with uniformly distributed kinds of
+    /// edits.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            seed: 0,
+            max_depth: 8,
+            max_repeat: 5,
+            insert_weight: 1,
+            delete_weight: 1,
+            replace_weight: 1,
+        }
+    }
+}
+
+/// A kind of the [GenEdit].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum GenEditKind {
+    /// The edit inserts a new item into a repetition.
+    Insert,
+
+    /// The edit removes an item from a repetition.
+    Delete,
+
+    /// The edit replaces a node with a newly generated node of the same
+    /// rule.
+    Replace,
+}
+
+/// A text edit produced by the [GrammarGen].
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct GenEdit {
+    /// The kind of this edit.
+    pub kind: GenEditKind,
+
+    /// The span of the text fragment that this edit replaces.
+    pub span: SiteSpan,
+
+    /// The text that this edit inserts in place of the span.
+    pub text: String,
+}
+
+impl GenEdit {
+    /// Applies this edit to the `string`.
+    ///
+    /// **Panic**
+    ///
+    /// Panics if the edit's span is out of the string bounds.
+    pub fn apply_to_string(&self, string: &mut String) {
+        let start = byte_index(string, self.span.start);
+        let end = byte_index(string, self.span.end);
+
+        string.replace_range(start..end, &self.text);
+    }
+
+    /// Applies this edit to the `document` using
+    /// the [Document::write] function.
+    ///
+    /// **Panic**
+    ///
+    /// Panics if the document is immutable, or if the edit's span is not
+    /// valid for this document.
+    #[inline(always)]
+    pub fn apply_to_document<N: Node>(&self, document: &mut Document<N>) {
+        document.write(&self.span, &self.text);
+    }
+}
+
+/// A random generator of syntactically valid documents and edits.
+///
+/// The GrammarGen produces a document of the [GenGrammar] language, and then
+/// produces a sequence of random edits to this document such that
+/// the document remains syntactically valid after each edit:
+///
+///  - The [insertions](GenEditKind::Insert) add new items
+///    to the repetitions.
+///  - The [deletions](GenEditKind::Delete) remove items from
+///    the repetitions.
+///  - The [replacements](GenEditKind::Replace) replace the nodes with newly
+///    generated nodes of the same rule.
+///
+/// The generator is intended for the differential testing of the incremental
+/// reparser: apply the generated edits to a mutable [Document], and compare
+/// the result with the document parsed from scratch using
+/// the [check_reparse](crate::testing::check_reparse) function.
+///
+/// ```ignore
+/// let grammar = my_grammar();
+/// let mut generator = GrammarGen::new(&grammar, GenConfig::new());
+/// let mut doc = Document::<MyNode>::new_mutable(generator.text());
+///
+/// for _ in 0..100 {
+///     let Some(edit) = generator.gen_edit() else {
+///         break;
+///     };
+///
+///     edit.apply_to_document(&mut doc);
+///
+///     check_reparse(&doc);
+/// }
+/// ```
+pub struct GrammarGen<'grammar, N: Node> {
+    grammar: &'grammar GenGrammar<N>,
+    config: GenConfig,
+    heights: BTreeMap<NodeRule, usize>,
+    rng: GenRng,
+    document: Derivation<'grammar>,
+}
+
+impl<'grammar, N: Node> Debug for GrammarGen<'grammar, N> {
+    #[inline]
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter
+            .debug_struct("GrammarGen")
+            .field("grammar", &self.grammar)
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'grammar, N: Node> GrammarGen<'grammar, N> {
+    /// Creates a generator, and generates the initial document of
+    /// the `grammar` language starting from
+    /// the grammar's [root](GenGrammar::root) rule.
+    ///
+    /// **Panic**
+    ///
+    /// Panics if the grammar refers to undescribed rules or tokens, or if
+    /// some of its rules cannot produce finite texts.
+    pub fn new(grammar: &'grammar GenGrammar<N>, config: GenConfig) -> Self {
+        let heights = grammar.analyze();
+
+        let mut generator = Self {
+            grammar,
+            config,
+            heights,
+            rng: GenRng::new(config.seed),
+            document: Derivation::Group(Vec::new()),
+        };
+
+        generator.document = generator.gen_node(grammar.root(), 0);
+
+        generator
+    }
+
+    /// Returns the configuration of this generator.
+    #[inline(always)]
+    pub fn config(&self) -> &GenConfig {
+        &self.config
+    }
+
+    /// Returns the current text of the generated document.
+    ///
+    /// The text reflects all edits produced by this generator so far.
+    #[inline]
+    pub fn text(&self) -> String {
+        let mut result = String::new();
+
+        self.document.render(&mut result);
+
+        result
+    }
+
+    /// Generates a standalone text of the specified parsing `rule`.
+    ///
+    /// This function does not affect the current document of this
+    /// generator.
+    ///
+    /// **Panic**
+    ///
+    /// Panics if the rule is not described in the grammar.
+    #[inline]
+    pub fn gen_text(&mut self, rule: NodeRule) -> String {
+        let mut result = String::new();
+
+        self.gen_node(rule, 0).render(&mut result);
+
+        result
+    }
+
+    /// Generates a random edit of the current document, and applies this
+    /// edit to the generator's document.
+    ///
+    /// The kind of the edit is chosen randomly according to the weights in
+    /// the [GenConfig]. If the generator cannot produce an edit of the chosen
+    /// kind (e.g., if all repetitions of the document are full), it tries
+    /// other kinds.
+    ///
+    /// Returns None if the generator cannot produce any edit, or if all
+    /// weights are zero.
+    pub fn gen_edit(&mut self) -> Option<GenEdit> {
+        let mut kinds = Vec::with_capacity(3);
+
+        for (kind, weight) in [
+            (GenEditKind::Insert, self.config.insert_weight),
+            (GenEditKind::Delete, self.config.delete_weight),
+            (GenEditKind::Replace, self.config.replace_weight),
+        ] {
+            if weight > 0 {
+                kinds.push((kind, weight));
+            }
+        }
+
+        while !kinds.is_empty() {
+            let total = kinds
+                .iter()
+                .map(|(_, weight)| *weight as usize)
+                .sum::<usize>();
+            let mut pick = self.rng.gen_range(0..total);
+            let mut index = 0;
+
+            while pick >= kinds[index].1 as usize {
+                pick -= kinds[index].1 as usize;
+                index += 1;
+            }
+
+            let (kind, _) = kinds.remove(index);
+
+            if let Some(edit) = self.gen_edit_of(kind) {
+                return Some(edit);
+            }
+        }
+
+        None
+    }
+
+    fn gen_edit_of(&mut self, kind: GenEditKind) -> Option<GenEdit> {
+        let mut document = core::mem::replace(&mut self.document, Derivation::Group(Vec::new()));
+
+        let mut candidates = 0;
+
+        let _ = document.walk(&mut 0, 0, &mut |derivation, _, _| {
+            if self.is_candidate(kind, derivation) {
+                candidates += 1;
+            }
+
+            false
+        });
+
+        let mut result = None;
+
+        if candidates > 0 {
+            let mut target = self.rng.gen_range(0..candidates);
+
+            let _ = document.walk(&mut 0, 0, &mut |derivation, site, depth| {
+                if !self.is_candidate(kind, derivation) {
+                    return false;
+                }
+
+                if target > 0 {
+                    target -= 1;
+                    return false;
+                }
+
+                result = Some(self.edit(kind, derivation, site, depth));
+
+                true
+            });
+        }
+
+        self.document = document;
+
+        result
+    }
+
+    fn is_candidate(&self, kind: GenEditKind, derivation: &Derivation) -> bool {
+        match (kind, derivation) {
+            (GenEditKind::Replace, Derivation::Node(..)) => true,
+
+            (GenEditKind::Insert, Derivation::Repeat(expr, items)) => {
+                items.len() < self.repeat_bounds(expr).1
+            }
+
+            (GenEditKind::Delete, Derivation::Repeat(expr, items)) => {
+                items.len() > self.repeat_bounds(expr).0
+            }
+
+            _ => false,
+        }
+    }
+
+    fn edit(
+        &mut self,
+        kind: GenEditKind,
+        derivation: &mut Derivation<'grammar>,
+        site: Site,
+        depth: usize,
+    ) -> GenEdit {
+        match kind {
+            GenEditKind::Replace => {
+                let Derivation::Node(rule, _) = derivation else {
+                    // Safety: The edit candidates are filtered by the is_candidate function.
+                    unsafe { ld_unreachable!("Replacement of non-node derivation.") }
+                };
+
+                let replacement = self.gen_node(*rule, depth);
+
+                let span = site..(site + derivation.length());
+                let mut text = String::new();
+
+                replacement.render(&mut text);
+
+                *derivation = replacement;
+
+                GenEdit { kind, span, text }
+            }
+
+            GenEditKind::Insert => {
+                let Derivation::Repeat(
+                    GenExpr::Repeat {
+                        inner, separator, ..
+                    },
+                    items,
+                ) = derivation
+                else {
+                    // Safety: The edit candidates are filtered by the is_candidate function.
+                    unsafe { ld_unreachable!("Insertion into non-repetition derivation.") }
+                };
+
+                let index = self.rng.gen_range(0..(items.len() + 1));
+
+                let body = self.gen_expr(inner, depth);
+
+                let mut offset = site;
+                let mut text = String::new();
+
+                for item in &items[0..index] {
+                    offset += item.length();
+                }
+
+                let item = match index {
+                    0 => {
+                        body.render(&mut text);
+
+                        if let Some(first) = items.first_mut() {
+                            if let Some(separator) = separator {
+                                let separator = self.gen_expr(separator, depth);
+
+                                separator.render(&mut text);
+
+                                first.separator = Some(separator);
+                            }
+                        }
+
+                        RepeatItem {
+                            separator: None,
+                            body,
+                        }
+                    }
+
+                    _ => {
+                        let separator = match separator {
+                            Some(separator) => Some(self.gen_expr(separator, depth)),
+                            None => None,
+                        };
+
+                        let item = RepeatItem { separator, body };
+
+                        item.render(&mut text);
+
+                        item
+                    }
+                };
+
+                items.insert(index, item);
+
+                GenEdit {
+                    kind,
+                    span: offset..offset,
+                    text,
+                }
+            }
+
+            GenEditKind::Delete => {
+                let Derivation::Repeat(_, items) = derivation else {
+                    // Safety: The edit candidates are filtered by the is_candidate function.
+                    unsafe { ld_unreachable!("Deletion from non-repetition derivation.") }
+                };
+
+                let index = self.rng.gen_range(0..items.len());
+
+                let mut start = site;
+
+                for item in &items[0..index] {
+                    start += item.length();
+                }
+
+                let removed = items.remove(index);
+                let mut end = start + removed.length();
+
+                if index == 0 {
+                    if let Some(first) = items.first_mut() {
+                        if let Some(separator) = first.separator.take() {
+                            end += separator.length();
+                        }
+                    }
+                }
+
+                GenEdit {
+                    kind,
+                    span: start..end,
+                    text: String::new(),
+                }
+            }
+        }
+    }
+
+    fn gen_node(&mut self, rule: NodeRule, depth: usize) -> Derivation<'grammar> {
+        let grammar = self.grammar;
+
+        Derivation::Node(rule, Box::new(self.gen_expr(grammar.rule(rule), depth + 1)))
+    }
+
+    fn gen_expr(&mut self, expr: &'grammar GenExpr, depth: usize) -> Derivation<'grammar> {
+        let budget = self.config.max_depth.saturating_sub(depth);
+
+        match expr {
+            GenExpr::Token(rule) => Derivation::Token(self.grammar.gen_token(&mut self.rng, *rule)),
+
+            GenExpr::Node(rule) => self.gen_node(*rule, depth),
+
+            GenExpr::Seq(items) => Derivation::Group(
+                items
+                    .iter()
+                    .map(|item| self.gen_expr(item, depth))
+                    .collect(),
+            ),
+
+            GenExpr::Choice(alternatives) => {
+                let heights = alternatives
+                    .iter()
+                    .map(|alternative| alternative.height(&self.heights))
+                    .collect::<Vec<_>>();
+
+                let mut candidates = heights
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, height)| **height <= budget)
+                    .map(|(index, _)| index)
+                    .collect::<Vec<_>>();
+
+                if candidates.is_empty() {
+                    let shortest = heights.iter().copied().min().unwrap_or(usize::MAX);
+
+                    candidates = heights
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, height)| **height == shortest)
+                        .map(|(index, _)| index)
+                        .collect();
+                }
+
+                match self.rng.choose(&candidates) {
+                    Some(index) => self.gen_expr(&alternatives[*index], depth),
+                    None => panic!("Empty choice expression."),
+                }
+            }
+
+            GenExpr::Repeat {
+                inner, separator, ..
+            } => {
+                let (min, max) = self.repeat_bounds(expr);
+
+                let count = match inner.height(&self.heights) <= budget {
+                    true => self.rng.gen_range(min..(max + 1)),
+                    false => min,
+                };
+
+                let mut items = Vec::with_capacity(count);
+
+                for index in 0..count {
+                    let separator = match (index, separator) {
+                        (1.., Some(separator)) => Some(self.gen_expr(separator, depth)),
+                        _ => None,
+                    };
+
+                    let body = self.gen_expr(inner, depth);
+
+                    items.push(RepeatItem { separator, body });
+                }
+
+                Derivation::Repeat(expr, items)
+            }
+        }
+    }
+
+    fn repeat_bounds(&self, expr: &GenExpr) -> (usize, usize) {
+        let GenExpr::Repeat { min, max, .. } = expr else {
+            return (1, 1);
+        };
+
+        let max = match *max == usize::MAX {
+            true => (*min).max(self.config.max_repeat),
+            false => *max,
+        };
+
+        (*min, max)
+    }
+}
+
+#[inline]
+fn byte_index(string: &str, site: Site) -> usize {
+    if site == 0 {
+        return 0;
+    }
+
+    match string.char_indices().nth(site) {
+        Some((index, _)) => index,
+
+        None => {
+            let length = string.chars().count();
+
+            if site > length {
+                panic!("Site {site} is out of string bounds ({length}).");
+            }
+
+            string.len()
+        }
+    }
+}
+
+enum Derivation<'grammar> {
+    Token(String),
+    Group(Vec<Derivation<'grammar>>),
+    Node(NodeRule, Box<Derivation<'grammar>>),
+    Repeat(&'grammar GenExpr, Vec<RepeatItem<'grammar>>),
+}
+
+impl<'grammar> Derivation<'grammar> {
+    fn render(&self, target: &mut String) {
+        match self {
+            Self::Token(text) => target.push_str(text),
+
+            Self::Group(items) => {
+                for item in items {
+                    item.render(target);
+                }
+            }
+
+            Self::Node(_, inner) => inner.render(target),
+
+            Self::Repeat(_, items) => {
+                for item in items {
+                    item.render(target);
+                }
+            }
+        }
+    }
+
+    fn length(&self) -> usize {
+        match self {
+            Self::Token(text) => text.chars().count(),
+            Self::Group(items) => items.iter().map(Self::length).sum(),
+            Self::Node(_, inner) => inner.length(),
+            Self::Repeat(_, items) => items.iter().map(RepeatItem::length).sum(),
+        }
+    }
+
+    // Visits the Node and the Repeat derivations in the depth-first order
+    // until the visitor returns true. Returns true if the walk was stopped.
+    fn walk(
+        &mut self,
+        site: &mut Site,
+        depth: usize,
+        visitor: &mut impl FnMut(&mut Self, Site, usize) -> bool,
+    ) -> bool {
+        match self {
+            Self::Token(text) => {
+                *site += text.chars().count();
+                false
+            }
+
+            Self::Group(items) => {
+                for item in items {
+                    if item.walk(site, depth, visitor) {
+                        return true;
+                    }
+                }
+
+                false
+            }
+
+            Self::Node(..) => {
+                if visitor(self, *site, depth) {
+                    return true;
+                }
+
+                let Self::Node(_, inner) = self else {
+                    // Safety: The visitor does not change the derivation kind.
+                    unsafe { ld_unreachable!("Node derivation kind mismatch.") }
+                };
+
+                inner.walk(site, depth + 1, visitor)
+            }
+
+            Self::Repeat(..) => {
+                if visitor(self, *site, depth) {
+                    return true;
+                }
+
+                let Self::Repeat(_, items) = self else {
+                    // Safety: The visitor does not change the derivation kind.
+                    unsafe { ld_unreachable!("Repeat derivation kind mismatch.") }
+                };
+
+                for item in items {
+                    if let Some(separator) = &mut item.separator {
+                        if separator.walk(site, depth, visitor) {
+                            return true;
+                        }
+                    }
+
+                    if item.body.walk(site, depth, visitor) {
+                        return true;
+                    }
+                }
+
+                false
+            }
+        }
+    }
+}
+
+struct RepeatItem<'grammar> {
+    separator: Option<Derivation<'grammar>>,
+    body: Derivation<'grammar>,
+}
+
+impl<'grammar> RepeatItem<'grammar> {
+    fn render(&self, target: &mut String) {
+        if let Some(separator) = &self.separator {
+            separator.render(target);
+        }
+
+        self.body.render(target);
+    }
+
+    fn length(&self) -> usize {
+        let mut length = self.body.length();
+
+        if let Some(separator) = &self.separator {
+            length += separator.length();
+        }
+
+        length
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        lexis::{SourceCode, Token},
+        syntax::{VoidSyntax, ROOT_RULE},
+        testing::{check_reparse, GenConfig, GenExpr, GenGrammar, GrammarGen},
+        units::Document,
+    };
+
+    #[derive(Token, Clone, Copy, PartialEq, Eq, Debug)]
+    #[repr(u8)]
+    enum TestToken {
+        EOI = 0,
+        Mismatch = 1,
+        #[rule(^['\n']+)]
+        Text,
+        #[rule('\n')]
+        Newline,
+    }
+
+    #[test]
+    fn test_grammar_gen() {
+        let mut grammar = GenGrammar::<VoidSyntax<TestToken>>::new();
+
+        grammar
+            .set_rule(
+                ROOT_RULE,
+                GenExpr::zero_or_more(GenExpr::choice([
+                    GenExpr::one_or_more(GenExpr::token(TestToken::Text)),
+                    GenExpr::token(TestToken::Newline),
+                ]))
+                .with_separator(GenExpr::token(TestToken::Newline)),
+            )
+            .set_token(TestToken::Text, &["foo", "bar", "ü"])
+            .set_token(TestToken::Newline, &["\n"])
+            .set_separators(&["", " "]);
+
+        for seed in 0..20 {
+            let config = GenConfig {
+                seed,
+                ..GenConfig::new()
+            };
+
+            let mut generator = GrammarGen::new(&grammar, config);
+
+            assert_eq!(generator.text(), GrammarGen::new(&grammar, config).text());
+
+            let mut text = generator.text();
+            let mut doc = Document::<VoidSyntax<TestToken>>::new_mutable(&text);
+
+            for _ in 0..50 {
+                let Some(edit) = generator.gen_edit() else {
+                    break;
+                };
+
+                edit.apply_to_string(&mut text);
+                edit.apply_to_document(&mut doc);
+
+                assert_eq!(text, generator.text());
+                assert_eq!(text, doc.substring(..));
+
+                check_reparse(&doc);
+            }
+        }
+    }
+}
@@ -0,0 +1,539 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use alloc::{
+    boxed::Box,
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    fmt::{Debug, Formatter},
+    marker::PhantomData,
+};
+
+use crate::{
+    lexis::{Token, TokenRule},
+    syntax::{Node, NodeRule, ROOT_RULE},
+    testing::GenRng,
+};
+
+/// A generation-oriented description of a parsing rule expression.
+///
+/// This object mirrors the syntax of the `#[rule(...)]` expressions of
+/// the [Node](lady_deirdre_derive::Node) macro:
+///
+/// | Rule expression    | GenExpr                                                  |
+/// |--------------------|----------------------------------------------------------|
+/// | `$Foo`             | `GenExpr::token(MyToken::Foo)`                           |
+/// | `Foo`              | `GenExpr::node(MyNode::FOO)`                             |
+/// | `A B C`            | `GenExpr::seq([a, b, c])`                                |
+/// | `A \| B \| C`      | `GenExpr::choice([a, b, c])`                             |
+/// | `A?`               | `GenExpr::optional(a)`                                   |
+/// | `A*`               | `GenExpr::zero_or_more(a)`                               |
+/// | `A+`               | `GenExpr::one_or_more(a)`                                |
+/// | `A*{B}`            | `GenExpr::zero_or_more(a).with_separator(b)`             |
+///
+/// The recovery and trivia parts of the parsing rules don't have
+/// GenExpr counterparts, because the generator produces syntactically valid
+/// texts only.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum GenExpr {
+    /// A single token of the specified [TokenRule].
+    Token(TokenRule),
+
+    /// A single node of the specified [NodeRule].
+    Node(NodeRule),
+
+    /// A sequence of expressions.
+    Seq(Vec<GenExpr>),
+
+    /// A choice between alternative expressions.
+    Choice(Vec<GenExpr>),
+
+    /// A repetition of the `inner` expression interleaved with
+    /// the optional `separator`.
+    Repeat {
+        /// The repeated expression.
+        inner: Box<GenExpr>,
+
+        /// An optional separator between the repeated items.
+        separator: Option<Box<GenExpr>>,
+
+        /// The minimum number of repetitions (inclusive).
+        min: usize,
+
+        /// The maximum number of repetitions (inclusive).
+        ///
+        /// The [usize::MAX] value means "unlimited", in which case
+        /// the generator bounds the repetitions by
+        /// the [GenConfig::max_repeat](crate::testing::GenConfig::max_repeat)
+        /// value.
+        max: usize,
+    },
+}
+
+impl GenExpr {
+    /// Creates an expression of a single `token`.
+    #[inline(always)]
+    pub fn token(token: impl Token) -> Self {
+        Self::Token(token.rule())
+    }
+
+    /// Creates an expression of a single node parsed by the `rule`.
+    #[inline(always)]
+    pub fn node(rule: NodeRule) -> Self {
+        Self::Node(rule)
+    }
+
+    /// Creates a sequence of the `items` expressions.
+    #[inline(always)]
+    pub fn seq(items: impl IntoIterator<Item = GenExpr>) -> Self {
+        Self::Seq(items.into_iter().collect())
+    }
+
+    /// Creates a choice between the `alternatives` expressions.
+    #[inline(always)]
+    pub fn choice(alternatives: impl IntoIterator<Item = GenExpr>) -> Self {
+        Self::Choice(alternatives.into_iter().collect())
+    }
+
+    /// Creates a repetition of the `inner` expression repeated from `min`
+    /// to `max` times inclusive.
+    #[inline(always)]
+    pub fn repeat(inner: GenExpr, min: usize, max: usize) -> Self {
+        Self::Repeat {
+            inner: Box::new(inner),
+            separator: None,
+            min,
+            max,
+        }
+    }
+
+    /// Creates an expression that matches the `inner` expression zero or
+    /// one time.
+    #[inline(always)]
+    pub fn optional(inner: GenExpr) -> Self {
+        Self::repeat(inner, 0, 1)
+    }
+
+    /// Creates an expression that repeats the `inner` expression zero or
+    /// more times.
+    #[inline(always)]
+    pub fn zero_or_more(inner: GenExpr) -> Self {
+        Self::repeat(inner, 0, usize::MAX)
+    }
+
+    /// Creates an expression that repeats the `inner` expression one or
+    /// more times.
+    #[inline(always)]
+    pub fn one_or_more(inner: GenExpr) -> Self {
+        Self::repeat(inner, 1, usize::MAX)
+    }
+
+    /// Sets the separator of the repetition expression.
+    ///
+    /// **Panic**
+    ///
+    /// Panics if this expression is not a [Repeat](Self::Repeat) expression.
+    #[inline]
+    pub fn with_separator(self, separator: GenExpr) -> Self {
+        match self {
+            Self::Repeat {
+                inner, min, max, ..
+            } => Self::Repeat {
+                inner,
+                separator: Some(Box::new(separator)),
+                min,
+                max,
+            },
+
+            _ => panic!("Separator is applicable to the repetition expressions only."),
+        }
+    }
+
+    // Returns usize::MAX if the expression is not productive.
+    pub(super) fn height(&self, heights: &BTreeMap<NodeRule, usize>) -> usize {
+        match self {
+            Self::Token(..) => 0,
+
+            Self::Node(rule) => match heights.get(rule) {
+                Some(height) => height.saturating_add(1),
+                None => usize::MAX,
+            },
+
+            Self::Seq(items) => items
+                .iter()
+                .map(|item| item.height(heights))
+                .max()
+                .unwrap_or(0),
+
+            Self::Choice(alternatives) => alternatives
+                .iter()
+                .map(|alternative| alternative.height(heights))
+                .min()
+                .unwrap_or(usize::MAX),
+
+            Self::Repeat {
+                inner,
+                separator,
+                min,
+                ..
+            } => {
+                if *min == 0 {
+                    return 0;
+                }
+
+                let mut height = inner.height(heights);
+
+                if *min > 1 {
+                    if let Some(separator) = separator {
+                        height = height.max(separator.height(heights));
+                    }
+                }
+
+                height
+            }
+        }
+    }
+
+    fn for_each(&self, f: &mut impl FnMut(&GenExpr)) {
+        f(self);
+
+        match self {
+            Self::Token(..) | Self::Node(..) => (),
+
+            Self::Seq(items) | Self::Choice(items) => {
+                for item in items {
+                    item.for_each(f);
+                }
+            }
+
+            Self::Repeat {
+                inner, separator, ..
+            } => {
+                inner.for_each(f);
+
+                if let Some(separator) = separator {
+                    separator.for_each(f);
+                }
+            }
+        }
+    }
+}
+
+/// A description of the programming language grammar used by
+/// the [GrammarGen](crate::testing::GrammarGen) to produce syntactically valid
+/// random texts.
+///
+/// The `N` generic parameter is the syntax tree [Node] of the language.
+/// The description consists of:
+///
+///  - The [GenExpr] expressions of the parsing rules
+///    ([set_rule](Self::set_rule)). Usually, these expressions mirror
+///    the `#[rule(...)]` attributes of the Node's variants.
+///  - The text samples of the tokens ([set_token](Self::set_token) and
+///    [set_token_fn](Self::set_token_fn)).
+///  - The separators inserted in front of each token
+///    ([set_separators](Self::set_separators)).
+///
+/// ```ignore
+/// let mut grammar = GenGrammar::<JsonNode>::new();
+///
+/// grammar
+///     .set_rule(ROOT_RULE, GenExpr::node(JsonNode::OBJECT))
+///     .set_rule(
+///         JsonNode::OBJECT,
+///         GenExpr::seq([
+///             GenExpr::token(JsonToken::BraceOpen),
+///             GenExpr::zero_or_more(GenExpr::node(JsonNode::ENTRY))
+///                 .with_separator(GenExpr::token(JsonToken::Comma)),
+///             GenExpr::token(JsonToken::BraceClose),
+///         ]),
+///     )
+///     .set_token(JsonToken::BraceOpen, &["{"])
+///     .set_token(JsonToken::BraceClose, &["}"])
+///     .set_token(JsonToken::Comma, &[","]);
+/// ```
+///
+/// The tokens of the generated text are separated by the separators such
+/// that the lexical scanner would not merge adjacent tokens into a single
+/// one. By default, the only separator is a single whitespace. If you
+/// specify custom separators, make sure that the samples of any pair of
+/// adjacent tokens remain distinguishable with each separator.
+pub struct GenGrammar<N: Node> {
+    root: NodeRule,
+    rules: BTreeMap<NodeRule, GenExpr>,
+    tokens: BTreeMap<TokenRule, TokenSamples>,
+    separators: Vec<String>,
+    _node: PhantomData<fn() -> N>,
+}
+
+impl<N: Node> Debug for GenGrammar<N> {
+    #[inline]
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter
+            .debug_struct("GenGrammar")
+            .field("root", &self.root)
+            .field("rules", &self.rules.len())
+            .field("tokens", &self.tokens.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<N: Node> Default for GenGrammar<N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Node> GenGrammar<N> {
+    /// Creates an empty grammar description with the [ROOT_RULE] entry point
+    /// and the single whitespace separator.
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self {
+            root: ROOT_RULE,
+            rules: BTreeMap::new(),
+            tokens: BTreeMap::new(),
+            separators: Vec::from([String::from(" ")]),
+            _node: PhantomData,
+        }
+    }
+
+    /// Returns the rule from which the generator starts generating
+    /// the documents.
+    #[inline(always)]
+    pub fn root(&self) -> NodeRule {
+        self.root
+    }
+
+    /// Changes the rule from which the generator starts generating
+    /// the documents.
+    ///
+    /// By default, the generator starts from the [ROOT_RULE].
+    #[inline(always)]
+    pub fn set_root(&mut self, rule: NodeRule) -> &mut Self {
+        self.root = rule;
+
+        self
+    }
+
+    /// Describes the parsing `rule` of the `N` node by the `expr` expression.
+    ///
+    /// Overrides the previous description of this rule if any.
+    #[inline(always)]
+    pub fn set_rule(&mut self, rule: NodeRule, expr: GenExpr) -> &mut Self {
+        let _ = self.rules.insert(rule, expr);
+
+        self
+    }
+
+    /// Describes the texts of the `token` by the fixed set of
+    /// the `samples`.
+    ///
+    /// The generator randomly chooses one of the samples each time it
+    /// generates this token.
+    ///
+    /// **Panic**
+    ///
+    /// Panics if the `samples` slice is empty.
+    #[inline]
+    pub fn set_token(&mut self, token: N::Token, samples: &[&str]) -> &mut Self {
+        if samples.is_empty() {
+            panic!(
+                "Empty samples set of the {} token.",
+                token.name().unwrap_or("?"),
+            );
+        }
+
+        let samples = samples.iter().map(ToString::to_string).collect();
+
+        let _ = self
+            .tokens
+            .insert(token.rule(), TokenSamples::Fixed(samples));
+
+        self
+    }
+
+    /// Describes the texts of the `token` by the `generator` function.
+    ///
+    /// The generator calls this function each time it generates this token.
+    /// The function must return a string that the lexical scanner would
+    /// recognize as a single token of this kind.
+    #[inline(always)]
+    pub fn set_token_fn(
+        &mut self,
+        token: N::Token,
+        generator: fn(&mut GenRng) -> String,
+    ) -> &mut Self {
+        let _ = self
+            .tokens
+            .insert(token.rule(), TokenSamples::Generated(generator));
+
+        self
+    }
+
+    /// Sets the strings that the generator inserts in front of each token.
+    ///
+    /// The generator randomly chooses one of the separators each time it
+    /// generates a token.
+    ///
+    /// **Panic**
+    ///
+    /// Panics if the `separators` slice is empty.
+    #[inline]
+    pub fn set_separators(&mut self, separators: &[&str]) -> &mut Self {
+        if separators.is_empty() {
+            panic!("Empty separators set.");
+        }
+
+        self.separators = separators.iter().map(ToString::to_string).collect();
+
+        self
+    }
+
+    #[inline(always)]
+    pub(super) fn rule(&self, rule: NodeRule) -> &GenExpr {
+        match self.rules.get(&rule) {
+            Some(expr) => expr,
+            None => panic!("Rule {} is not described.", Self::rule_name(rule)),
+        }
+    }
+
+    #[inline]
+    pub(super) fn gen_token(&self, rng: &mut GenRng, rule: TokenRule) -> String {
+        let mut result = match rng.choose(&self.separators) {
+            Some(separator) => separator.clone(),
+            None => String::new(),
+        };
+
+        match self.tokens.get(&rule) {
+            Some(TokenSamples::Fixed(samples)) => {
+                if let Some(sample) = rng.choose(samples) {
+                    result.push_str(sample);
+                }
+            }
+
+            Some(TokenSamples::Generated(generator)) => result.push_str(&generator(rng)),
+
+            None => panic!(
+                "Token {} is not described.",
+                <N::Token as Token>::rule_name(rule).unwrap_or("?"),
+            ),
+        }
+
+        result
+    }
+
+    // Checks that all referred rules and tokens are described, and computes
+    // the minimal derivation heights of the rules.
+    pub(super) fn analyze(&self) -> BTreeMap<NodeRule, usize> {
+        let mut referred = Vec::from([GenExpr::Node(self.root)]);
+
+        for expr in self.rules.values() {
+            referred.push(expr.clone());
+        }
+
+        for expr in &referred {
+            expr.for_each(&mut |expr| match expr {
+                GenExpr::Token(rule) => {
+                    if !self.tokens.contains_key(rule) {
+                        panic!(
+                            "Token {} is not described.",
+                            <N::Token as Token>::rule_name(*rule).unwrap_or("?"),
+                        );
+                    }
+                }
+
+                GenExpr::Node(rule) => {
+                    if !self.rules.contains_key(rule) {
+                        panic!("Rule {} is not described.", Self::rule_name(*rule));
+                    }
+                }
+
+                _ => (),
+            });
+        }
+
+        let mut heights = BTreeMap::new();
+
+        loop {
+            let mut changed = false;
+
+            for (rule, expr) in &self.rules {
+                let height = expr.height(&heights);
+
+                if height == usize::MAX {
+                    continue;
+                }
+
+                match heights.get(rule) {
+                    Some(previous) if *previous <= height => (),
+
+                    _ => {
+                        let _ = heights.insert(*rule, height);
+                        changed = true;
+                    }
+                }
+            }
+
+            if !changed {
+                break;
+            }
+        }
+
+        for rule in self.rules.keys() {
+            if !heights.contains_key(rule) {
+                panic!(
+                    "Rule {} does not produce finite texts.",
+                    Self::rule_name(*rule),
+                );
+            }
+        }
+
+        heights
+    }
+
+    #[inline(always)]
+    fn rule_name(rule: NodeRule) -> &'static str {
+        N::rule_name(rule).unwrap_or("?")
+    }
+}
+
+enum TokenSamples {
+    Fixed(Vec<String>),
+    Generated(fn(&mut GenRng) -> String),
+}
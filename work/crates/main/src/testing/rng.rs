@@ -0,0 +1,110 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use core::ops::Range;
+
+/// A deterministic pseudo-random number generator of
+/// the [GrammarGen](crate::testing::GrammarGen).
+///
+/// The generator implements the SplitMix64 algorithm. It is not suitable for
+/// cryptographic purposes, but it is fast, has a tiny state, and produces
+/// the same sequence of numbers for the same seed on any platform. This makes
+/// the generated test cases reproducible by the seed number alone.
+#[derive(Clone, Debug)]
+pub struct GenRng {
+    state: u64,
+}
+
+impl GenRng {
+    /// Creates a generator initialized with the `seed` number.
+    #[inline(always)]
+    pub const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Returns the next pseudo-random 64-bit number.
+    #[inline]
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+
+        let mut result = self.state;
+
+        result = (result ^ (result >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        result = (result ^ (result >> 27)).wrapping_mul(0x94D049BB133111EB);
+
+        result ^ (result >> 31)
+    }
+
+    /// Returns a pseudo-random number within the `range`.
+    ///
+    /// **Panic**
+    ///
+    /// Panics if the range is empty.
+    #[inline]
+    pub fn gen_range(&mut self, range: Range<usize>) -> usize {
+        if range.start >= range.end {
+            panic!("Empty random range {}..{}.", range.start, range.end);
+        }
+
+        let width = (range.end - range.start) as u64;
+
+        range.start + (self.next_u64() % width) as usize
+    }
+
+    /// Returns true with the `numerator / denominator` probability.
+    ///
+    /// **Panic**
+    ///
+    /// Panics if the `denominator` is zero.
+    #[inline]
+    pub fn gen_ratio(&mut self, numerator: u32, denominator: u32) -> bool {
+        if denominator == 0 {
+            panic!("Zero ratio denominator.");
+        }
+
+        self.next_u64() % (denominator as u64) < (numerator as u64)
+    }
+
+    /// Returns a reference to a pseudo-randomly chosen item of the `slice`.
+    ///
+    /// Returns None if the slice is empty.
+    #[inline]
+    pub fn choose<'a, T>(&mut self, slice: &'a [T]) -> Option<&'a T> {
+        if slice.is_empty() {
+            return None;
+        }
+
+        slice.get(self.gen_range(0..slice.len()))
+    }
+}
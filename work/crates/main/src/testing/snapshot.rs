@@ -0,0 +1,617 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{Debug, Display, Formatter};
+
+use crate::{
+    lexis::{SiteSpan, ToSpan, Token, TokenRef},
+    syntax::{AbstractNode, Capture, NodeRef, PolyRef, SyntaxTree},
+    units::CompilationUnit,
+};
+
+/// Asserts that the syntax tree of a compilation unit matches
+/// the expected [TreeSnapshot] text.
+///
+/// The first argument is a [CompilationUnit](crate::units::CompilationUnit)
+/// (e.g., a [Document](crate::units::Document)), and the second argument is
+/// a string of the expected snapshot. The expected string is parsed by
+/// the [TreeSnapshot::parse] function, so it can be indented in the source
+/// code arbitrarily.
+///
+/// On mismatch, the macro panics with the [SnapshotDiff] description of
+/// the first divergent line, and prints the entire actual snapshot that you
+/// can copy into the test.
+///
+/// ```ignore
+/// let doc = Document::<JsonNode>::new_immutable("[true]");
+///
+/// assert_tree!(
+///     doc,
+///     r#"
+///     Root
+///       object: Array
+///         start: $BracketOpen "["
+///         items: True
+///           token: $True "true"
+///         end: $BracketClose "]"
+///     "#,
+/// );
+/// ```
+///
+/// This macro is available under the `testing` feature only.
+#[macro_export]
+macro_rules! assert_tree {
+    ($unit:expr, $expected:expr $(,)?) => {{
+        let actual = $crate::testing::TreeSnapshot::new(&$unit);
+        let expected = $crate::testing::TreeSnapshot::parse($expected);
+
+        if let Some(diff) = actual.diff(&expected) {
+            panic!(
+                "Syntax tree snapshot mismatch.\n\n{}\n\nActual snapshot:\n\n{}",
+                diff, actual,
+            );
+        }
+    }};
+}
+
+/// Asserts that the derive macro generated the implementation of the type
+/// with the expected content hash.
+///
+/// The first argument is a type annotated with the `#[dump(hash)]`
+/// attribute of the [Token](crate::lexis::Token) or
+/// the [Node](crate::syntax::Node) derive macro, and the second argument is
+/// the recorded `u64` value of the type's `DERIVE_HASH` constant.
+///
+/// The hash changes whenever the macro generates a different implementation
+/// (e.g., due to the changes in the grammar or in the macro's code generator).
+/// On mismatch, the macro panics with the actual hash value that you can
+/// record in the test after reviewing the changes.
+///
+/// ```ignore
+/// #[derive(Token, Clone, Copy, PartialEq, Eq)]
+/// #[repr(u8)]
+/// #[dump(hash)]
+/// enum MyToken {
+///     // ...
+/// }
+///
+/// #[test]
+/// fn test_my_token_output() {
+///     assert_derive_hash!(MyToken, 0x1234ABCD5678EF90);
+/// }
+/// ```
+///
+/// This macro is available under the `testing` feature only.
+#[macro_export]
+macro_rules! assert_derive_hash {
+    ($ty:ty, $expected:expr $(,)?) => {{
+        let actual: u64 = <$ty>::DERIVE_HASH;
+        let expected: u64 = $expected;
+
+        if actual != expected {
+            panic!(
+                "Derive macro output of {} has changed.\n\nExpected hash: \
+                {:#018X}.\nActual hash: {:#018X}.",
+                ::core::any::type_name::<$ty>(),
+                expected,
+                actual,
+            );
+        }
+    }};
+}
+
+/// A canonical textual dump of the compilation unit's syntax tree.
+///
+/// The snapshot is deterministic: it does not contain memory addresses or
+/// [Entry](crate::arena::Entry) numbers, and it is the same for
+/// the immutable and the incrementally reparsed documents with the same
+/// syntax structure. Therefore, you can use the snapshots in the golden
+/// (snapshot) tests of your grammar.
+///
+/// Each line of the snapshot denotes a node, a token, or a syntax error, and
+/// the indentation (two whitespaces per level) denotes the child structure.
+/// For example, the snapshot of the `{"a" 1}` JSON text could look like this:
+///
+/// ```text
+/// Root
+///   object: Object
+///     start: $BraceOpen "{"
+///     entries: Entry
+///       key: String
+///         value: $String "\"a\""
+///       value: Number
+///         value: $Number "1"
+///     end: $BraceClose "}"
+/// ! missing ':' in Entry [5..5]
+/// ```
+///
+///  - A node line consists of the capture key under which the parent node
+///    refers to this node, and the [name](AbstractNode::name) of the node.
+///  - A token line consists of the capture key, the token's
+///    [name](crate::lexis::Token::name) prefixed with `$`, and the quoted
+///    string of the token.
+///  - The error lines are prefixed with `!`, and follow the tree lines in
+///    the order of their positions. Each error line contains
+///    the [error message](crate::syntax::SyntaxError::message) and its
+///    [site span](SiteSpan).
+///
+/// The nil (omitted) captures are not shown.
+///
+/// The [Display] implementation of this object prints the snapshot text.
+/// The [TreeSnapshot::parse] function parses the text back. The [PartialEq]
+/// implementation compares the snapshots' texts, and the [diff](Self::diff)
+/// function pinpoints the first divergent line.
+#[derive(Clone, Default)]
+pub struct TreeSnapshot {
+    lines: Vec<SnapshotLine>,
+}
+
+impl PartialEq for TreeSnapshot {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.diff(other).is_none()
+    }
+}
+
+impl Eq for TreeSnapshot {}
+
+impl Debug for TreeSnapshot {
+    #[inline(always)]
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        Display::fmt(self, formatter)
+    }
+}
+
+impl Display for TreeSnapshot {
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        let mut first = true;
+
+        for line in &self.lines {
+            if !first {
+                formatter.write_str("\n")?;
+            }
+
+            first = false;
+
+            for _ in 0..line.depth {
+                formatter.write_str("  ")?;
+            }
+
+            formatter.write_str(&line.text)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl TreeSnapshot {
+    /// Creates a snapshot of the `unit`'s syntax tree.
+    pub fn new<U: CompilationUnit>(unit: &U) -> Self {
+        let mut snapshot = Self::default();
+
+        snapshot.dump_node(unit, &unit.root_node_ref(), None, 0);
+
+        let mut errors = unit
+            .errors()
+            .map(|error| {
+                let span = error.span.to_site_span(unit);
+                let message = error.message::<U::Node>(unit).to_string();
+
+                (span, message)
+            })
+            .collect::<Vec<_>>();
+
+        errors.sort_by(|(a_span, a_message), (b_span, b_message)| {
+            let a_span = a_span.as_ref().map(|span| (span.start, span.end));
+            let b_span = b_span.as_ref().map(|span| (span.start, span.end));
+
+            a_span.cmp(&b_span).then_with(|| a_message.cmp(b_message))
+        });
+
+        for (span, message) in errors {
+            let text = match &span {
+                Some(span) => format!("! {message} [{}..{}]", span.start, span.end),
+                None => format!("! {message}"),
+            };
+
+            snapshot.lines.push(SnapshotLine {
+                depth: 0,
+                text,
+                span,
+            });
+        }
+
+        snapshot
+    }
+
+    /// Parses a snapshot from the `text` in the format of this object's
+    /// [Display] output.
+    ///
+    /// The function ignores empty lines and the common indentation of
+    /// the non-empty lines, such that the text could be a raw string literal
+    /// indented with the surrounding Rust code. The nesting depth of each
+    /// line is its remaining indentation divided by two.
+    ///
+    /// The parsed snapshot does not have the [site spans](SiteSpan) of
+    /// the lines.
+    pub fn parse(text: &str) -> Self {
+        let indent = text
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.len() - line.trim_start().len())
+            .min()
+            .unwrap_or(0);
+
+        let lines = text
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                let line = &line[indent..];
+                let text = line.trim_start();
+
+                SnapshotLine {
+                    depth: (line.len() - text.len()) / 2,
+                    text: text.trim_end().to_string(),
+                    span: None,
+                }
+            })
+            .collect();
+
+        Self { lines }
+    }
+
+    /// Returns the number of lines in this snapshot.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns true if this snapshot does not have any lines.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Compares this snapshot with the `other` snapshot and returns
+    /// the description of the first divergent line.
+    ///
+    /// The lines are compared by their texts and nesting depths; the site spans
+    /// don't take part in the comparison.
+    ///
+    /// Returns None if the snapshots are equal.
+    pub fn diff(&self, other: &Self) -> Option<SnapshotDiff> {
+        let mut index = 0;
+
+        loop {
+            let left = self.lines.get(index);
+            let right = other.lines.get(index);
+
+            match (left, right) {
+                (None, None) => return None,
+
+                (Some(left), Some(right))
+                    if left.depth == right.depth && left.text == right.text =>
+                {
+                    index += 1;
+                }
+
+                _ => break,
+            }
+        }
+
+        let path = match index < self.lines.len() {
+            true => self.path(index),
+            false => other.path(index),
+        };
+
+        Some(SnapshotDiff {
+            line: index,
+            path,
+            left: self.lines.get(index).map(|line| line.text.clone()),
+            left_span: self.lines.get(index).and_then(|line| line.span.clone()),
+            right: other.lines.get(index).map(|line| line.text.clone()),
+            right_span: other.lines.get(index).and_then(|line| line.span.clone()),
+        })
+    }
+
+    fn path(&self, index: usize) -> Vec<String> {
+        let Some(target) = self.lines.get(index) else {
+            return Vec::new();
+        };
+
+        let mut path = Vec::<&SnapshotLine>::new();
+
+        for line in &self.lines[0..index] {
+            while let Some(last) = path.last() {
+                if last.depth < line.depth {
+                    break;
+                }
+
+                let _ = path.pop();
+            }
+
+            path.push(line);
+        }
+
+        path.into_iter()
+            .filter(|line| line.depth < target.depth)
+            .map(|line| line.text.clone())
+            .collect()
+    }
+
+    fn dump_node<U: CompilationUnit>(
+        &mut self,
+        unit: &U,
+        node_ref: &NodeRef,
+        key: Option<&str>,
+        depth: usize,
+    ) {
+        let Some(node) = node_ref.deref(unit) else {
+            self.lines.push(SnapshotLine {
+                depth,
+                text: Self::keyed(key, "?"),
+                span: None,
+            });
+
+            return;
+        };
+
+        self.lines.push(SnapshotLine {
+            depth,
+            text: Self::keyed(key, node.name().unwrap_or("?")),
+            span: node.span(unit),
+        });
+
+        let keys = node.capture_keys();
+
+        for (index, capture) in node.captures_iter().enumerate() {
+            let key = match keys.get(index) {
+                Some(key) => key.to_string(),
+                None => index.to_string(),
+            };
+
+            match capture {
+                Capture::SingleNode(child) => {
+                    if !child.is_nil() {
+                        self.dump_node(unit, child, Some(&key), depth + 1);
+                    }
+                }
+
+                Capture::ManyNodes(children) => {
+                    for child in children {
+                        self.dump_node(unit, child, Some(&key), depth + 1);
+                    }
+                }
+
+                Capture::SingleToken(child) => {
+                    if !child.is_nil() {
+                        self.dump_token(unit, child, &key, depth + 1);
+                    }
+                }
+
+                Capture::ManyTokens(children) => {
+                    for child in children {
+                        self.dump_token(unit, child, &key, depth + 1);
+                    }
+                }
+            }
+        }
+    }
+
+    fn dump_token<U: CompilationUnit>(
+        &mut self,
+        unit: &U,
+        token_ref: &TokenRef,
+        key: &str,
+        depth: usize,
+    ) {
+        let Some(chunk) = token_ref.chunk(unit) else {
+            self.lines.push(SnapshotLine {
+                depth,
+                text: Self::keyed(Some(key), "$?"),
+                span: None,
+            });
+
+            return;
+        };
+
+        let name = chunk.token.name().unwrap_or("?");
+
+        self.lines.push(SnapshotLine {
+            depth,
+            text: format!("{key}: ${name} {:?}", chunk.string),
+            span: chunk.to_site_span(unit),
+        });
+    }
+
+    #[inline(always)]
+    fn keyed(key: Option<&str>, name: &str) -> String {
+        match key {
+            Some(key) => format!("{key}: {name}"),
+            None => name.to_string(),
+        }
+    }
+}
+
+/// A description of the first divergent line between two [TreeSnapshot]s.
+///
+/// Returned by the [TreeSnapshot::diff] function.
+///
+/// The [Display] implementation of this object prints a human-readable
+/// report of the difference.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SnapshotDiff {
+    /// A zero-based index of the first divergent line.
+    pub line: usize,
+
+    /// The lines of the ancestor nodes of the divergent line starting from
+    /// the root node.
+    pub path: Vec<String>,
+
+    /// The text of the divergent line in the left snapshot.
+    ///
+    /// None if the left snapshot ends before this line.
+    pub left: Option<String>,
+
+    /// The site span of the divergent line's node, token, or error in
+    /// the left snapshot.
+    ///
+    /// None if the left snapshot does not have this line, or if the snapshot
+    /// was [parsed](TreeSnapshot::parse) from text.
+    pub left_span: Option<SiteSpan>,
+
+    /// The text of the divergent line in the right snapshot.
+    ///
+    /// None if the right snapshot ends before this line.
+    pub right: Option<String>,
+
+    /// The site span of the divergent line's node, token, or error in
+    /// the right snapshot.
+    ///
+    /// None if the right snapshot does not have this line, or if
+    /// the snapshot was [parsed](TreeSnapshot::parse) from text.
+    pub right_span: Option<SiteSpan>,
+}
+
+impl Display for SnapshotDiff {
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_fmt(format_args!("First difference at line {}.", self.line + 1))?;
+
+        if !self.path.is_empty() {
+            formatter.write_str("\nPath: ")?;
+
+            for (index, ancestor) in self.path.iter().enumerate() {
+                if index > 0 {
+                    formatter.write_str(" > ")?;
+                }
+
+                formatter.write_str(ancestor)?;
+            }
+        }
+
+        for (caption, line, span) in [
+            ("Left: ", &self.left, &self.left_span),
+            ("Right:", &self.right, &self.right_span),
+        ] {
+            formatter.write_fmt(format_args!("\n{caption} "))?;
+
+            match line {
+                Some(line) => formatter.write_str(line)?,
+                None => formatter.write_str("<end of snapshot>")?,
+            }
+
+            if let Some(span) = span {
+                formatter.write_fmt(format_args!(" at {}..{}", span.start, span.end))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+struct SnapshotLine {
+    depth: usize,
+    text: String,
+    span: Option<SiteSpan>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{lexis::Token, syntax::VoidSyntax, testing::TreeSnapshot, units::Document};
+
+    #[derive(Token, Clone, Copy, PartialEq, Eq, Debug)]
+    #[repr(u8)]
+    enum TestToken {
+        EOI = 0,
+        Mismatch = 1,
+        #[rule(^['\n']+)]
+        Text,
+        #[rule('\n')]
+        Newline,
+    }
+
+    #[test]
+    fn test_tree_snapshot() {
+        let expected = TreeSnapshot::parse(
+            r#"
+            Root
+              object: Array
+                start: $BracketOpen "["
+                items: True
+                  token: $True "true"
+                end: $BracketClose "]"
+            "#,
+        );
+
+        assert_eq!(expected.len(), 6);
+        assert_eq!(TreeSnapshot::parse(&expected.to_string()), expected);
+
+        let actual = TreeSnapshot::parse(
+            r#"
+            Root
+              object: Array
+                start: $BracketOpen "["
+                items: False
+                  token: $False "false"
+                end: $BracketClose "]"
+            "#,
+        );
+
+        let diff = actual.diff(&expected).expect("Snapshots are different.");
+
+        assert_eq!(diff.line, 3);
+        assert_eq!(diff.path, ["Root", "object: Array"]);
+        assert_eq!(diff.left.as_deref(), Some("items: False"));
+        assert_eq!(diff.right.as_deref(), Some("items: True"));
+
+        let diff = actual
+            .diff(&TreeSnapshot::parse("Root"))
+            .expect("Snapshots are different.");
+
+        assert_eq!(diff.line, 1);
+        assert_eq!(diff.right, None);
+
+        let doc = Document::<VoidSyntax<TestToken>>::new_mutable("foo\nbar");
+
+        assert_eq!(TreeSnapshot::new(&doc).to_string(), "?");
+
+        assert_tree!(doc, "?");
+    }
+}
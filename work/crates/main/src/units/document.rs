@@ -32,7 +32,7 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::{
+use core::{
     fmt::{Debug, Display, Formatter},
     iter::FusedIterator,
 };
@@ -40,10 +40,16 @@ use std::{
 use crate::{
     arena::{Entry, Id, Identifiable},
     lexis::{
+        Chunk,
+        Column,
         Length,
+        Line,
         LineIndex,
+        Position,
+        PositionSpan,
         Site,
         SiteRef,
+        SiteSpan,
         SourceCode,
         ToSpan,
         Token,
@@ -52,8 +58,20 @@ use crate::{
         TokenCursor,
         TokenRef,
     },
-    syntax::{ErrorRef, Node, NodeRef, SyntaxError, SyntaxTree},
-    units::{CompilationUnit, ImmutableUnit, MutableUnit, VoidWatcher, Watcher},
+    syntax::{ErrorRef, Node, NodeRef, SyntaxError, SyntaxTree, TreeStats},
+    units::{
+        Checkpoint,
+        CompilationUnit,
+        ImmutableUnit,
+        Instrumentation,
+        Lexis,
+        MutableUnit,
+        ReparseReport,
+        Syntax,
+        Transplant,
+        VoidWatcher,
+        Watcher,
+    },
 };
 
 /// The object that stores the content of an individual file within your
@@ -82,7 +100,7 @@ pub enum Document<N: Node> {
 
 impl<N: Node> Debug for Document<N> {
     #[inline(always)]
-    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
         match self {
             Self::Mutable(unit) => Debug::fmt(unit, formatter),
             Self::Immutable(unit) => Debug::fmt(unit, formatter),
@@ -92,7 +110,7 @@ impl<N: Node> Debug for Document<N> {
 
 impl<N: Node> Display for Document<N> {
     #[inline(always)]
-    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
         match self {
             Self::Mutable(unit) => Display::fmt(unit, formatter),
             Self::Immutable(unit) => Display::fmt(unit, formatter),
@@ -143,6 +161,8 @@ impl<N: Node> SourceCode for Document<N> {
 
     type Cursor<'document> = DocumentCursor<'document, N>;
 
+    type BackCursor<'document> = DocumentBackCursor<'document, N>;
+
     type CharIterator<'document> = DocumentCharIter<'document, N>;
 
     #[inline(always)]
@@ -201,6 +221,14 @@ impl<N: Node> SourceCode for Document<N> {
         }
     }
 
+    #[inline(always)]
+    fn cursor_back(&self, span: impl ToSpan) -> Self::BackCursor<'_> {
+        match self {
+            Self::Mutable(unit) => DocumentBackCursor::Mutable(unit.cursor_back(span)),
+            Self::Immutable(unit) => DocumentBackCursor::Immutable(unit.cursor_back(span)),
+        }
+    }
+
     #[inline(always)]
     fn length(&self) -> Length {
         match self {
@@ -296,6 +324,22 @@ impl<N: Node> SyntaxTree for Document<N> {
             Self::Immutable(unit) => unit.get_error(entry),
         }
     }
+
+    fn stats(&self) -> TreeStats<Self::Node> {
+        match self {
+            Self::Mutable(unit) => unit.stats(),
+
+            Self::Immutable(unit) => {
+                let mut stats = unit.syntax().stats();
+
+                stats.tokens = unit.tokens();
+                stats.text_bytes = unit.lines().byte_of(unit.length());
+                stats.approx_heap_bytes += unit.lexis().heap_size();
+
+                stats
+            }
+        }
+    }
 }
 
 impl<N: Node> CompilationUnit for Document<N> {
@@ -343,6 +387,14 @@ impl<N: Node> CompilationUnit for Document<N> {
             Self::Immutable(unit) => unit.cover(span),
         }
     }
+
+    #[inline(always)]
+    fn visit_errors_in(&self, span: &SiteSpan, visitor: &mut impl FnMut(&ErrorRef) -> bool) {
+        match self {
+            Self::Mutable(unit) => unit.visit_errors_in(span, visitor),
+            Self::Immutable(unit) => unit.visit_errors_in(span, visitor),
+        }
+    }
 }
 
 impl<N: Node> Document<N> {
@@ -422,6 +474,241 @@ impl<N: Node> Document<N> {
         unit.write_and_watch(span, text, watcher);
     }
 
+    /// Writes user-input edit into this document addressing the edited
+    /// fragment by line-column bounds.
+    ///
+    /// This function is similar to the [Document::write], but the bounds of
+    /// the `span` are resolved through the document's [LineIndex] such that
+    /// the columns never point inside the line breaks: a column that exceeds
+    /// the line's content is clamped to the end of the content, before
+    /// the `\n` or the `\r\n` line ending. Lines that exceed the total number
+    /// of lines are clamped to the end of the text.
+    ///
+    /// As a result, the edit never splits `\r\n` pairs of the source code
+    /// text, and the `text` may contain any line endings.
+    ///
+    /// **Panic**
+    ///
+    /// Panics if the Document is not mutable, or if the start bound of
+    /// the `span` is greater than the end bound.
+    #[inline(always)]
+    pub fn write_lines(&mut self, span: PositionSpan, text: impl AsRef<str>) {
+        let start = self.line_site(&span.start);
+        let end = self.line_site(&span.end);
+
+        self.write(start..end, text);
+    }
+
+    /// Replaces the content of the `line` with the `text`.
+    ///
+    /// The line break of the replaced line remains intact. If the `line`
+    /// exceeds the total number of lines, the `text` is appended to the end
+    /// of the source code.
+    ///
+    /// See [Document::write_lines] for details.
+    ///
+    /// **Panic**
+    ///
+    /// Panics if the Document is not mutable.
+    #[inline(always)]
+    pub fn replace_line(&mut self, line: Line, text: impl AsRef<str>) {
+        let start = self.line_site(&Position::new(line, 1));
+        let end = self.line_site(&Position::new(line, Column::MAX));
+
+        self.write(start..end, text);
+    }
+
+    fn line_site(&self, position: &Position) -> Site {
+        let lines = self.lines();
+
+        if position.line > lines.lines_count() {
+            return self.length();
+        }
+
+        let span = lines.line_span(position.line);
+
+        let content_end = {
+            let tail = self.substring(span.end.saturating_sub(2).max(span.start)..span.end);
+
+            match tail.ends_with("\r\n") {
+                true => span.end - 2,
+                false => match tail.ends_with('\n') {
+                    true => span.end - 1,
+                    false => span.end,
+                },
+            }
+        };
+
+        position
+            .column
+            .checked_sub(1)
+            .unwrap_or_default()
+            .saturating_add(span.start)
+            .min(content_end)
+    }
+
+    /// Creates a checkpoint of the document's edit history.
+    ///
+    /// Since the first checkpoint creation, the document records the inverse
+    /// of each subsequent [write](Self::write): the span of the written text
+    /// and the text that has been replaced. The memory footprint of
+    /// the history is proportional to the amount of the changed text.
+    ///
+    /// You can restore the document's source code text to the state of
+    /// the checkpoint later using the [revert](Self::revert) function.
+    ///
+    /// The history grows with every edit until you
+    /// [clear](Self::clear_history) it.
+    ///
+    /// **Panic**
+    ///
+    /// Panics if the Document is not mutable.
+    #[inline(always)]
+    pub fn checkpoint(&self) -> Checkpoint {
+        match self {
+            Self::Mutable(unit) => unit.checkpoint(),
+            Self::Immutable(..) => panic!("Specified Document is not mutable."),
+        }
+    }
+
+    /// Restores the source code text of the document to the state of
+    /// the `checkpoint`.
+    ///
+    /// The function writes the recorded inverse edits in reverse order.
+    /// Each of these writes is an ordinary incremental reparse of the edited
+    /// fragment, so the function does not reparse the entire document.
+    ///
+    /// The syntax tree nodes and tokens within the rewritten fragments are
+    /// recreated, and the [NodeRef] and [TokenRef] references obtained after
+    /// the checkpoint that point to them become invalid. The references to
+    /// the content outside of the rewritten fragments remain valid.
+    ///
+    /// The references that were valid at the checkpoint and pointed into
+    /// the rewritten fragments resolve again where the reverted content is
+    /// identical to the checkpoint's content: the tokens of the same kind and
+    /// length at the same sites, and the nodes of the same rule parsed from
+    /// the same tokens. Otherwise, the references into the fragment remain
+    /// invalid.
+    ///
+    /// The reverting writes are recorded in the history as well.
+    /// In particular, if you create a checkpoint right before the revert,
+    /// reverting to that checkpoint later would redo the reverted edits.
+    ///
+    /// The checkpoint remains valid after the revert, and you can revert to
+    /// it again.
+    ///
+    /// **Panic**
+    ///
+    /// Panics if the Document is not mutable, if the checkpoint was created
+    /// by another document, or if the document's history has been
+    /// [cleared](Self::clear_history) after the checkpoint creation.
+    #[inline(always)]
+    pub fn revert(&mut self, checkpoint: &Checkpoint) {
+        let unit = match self.as_mutable() {
+            Some(unit) => unit,
+            None => panic!("Specified Document is not mutable."),
+        };
+
+        unit.revert(checkpoint);
+    }
+
+    /// Discards the document's edit history and stops recording it until
+    /// the next [checkpoint](Self::checkpoint) creation.
+    ///
+    /// The checkpoints created before this call cannot be used for
+    /// the [revert](Self::revert) anymore, except the ones created after
+    /// the latest write.
+    ///
+    /// If the document is immutable, this function does nothing.
+    #[inline(always)]
+    pub fn clear_history(&mut self) {
+        if let Self::Mutable(unit) = self {
+            unit.clear_history();
+        }
+    }
+
+    /// Returns a summary of the syntax tree incremental reparsing caused by
+    /// the latest [write](Self::write) into this document.
+    ///
+    /// The returned [ReparseReport] object tells which syntax tree node
+    /// clusters the reparser has parsed anew, how many clusters it has reused
+    /// as they are, and which fragment of the source code it has re-covered.
+    ///
+    /// Returns None if the Document is immutable.
+    #[inline(always)]
+    pub fn last_reparse(&self) -> Option<&ReparseReport> {
+        match self {
+            Self::Mutable(unit) => Some(unit.last_reparse()),
+            Self::Immutable(..) => None,
+        }
+    }
+
+    /// Returns the site span of the source code fragment that has been
+    /// rescanned or reparsed during the latest [write](Self::write) into this
+    /// document.
+    ///
+    /// This is a shortcut for the [ReparseReport::damage] function of
+    /// the [last reparse](Self::last_reparse) report. The span covers both
+    /// the lexical damage (the tokens rescanned by the lexer) and the syntax
+    /// damage (the node clusters parsed anew). You can use this span to
+    /// refresh the syntax highlighting of the changed fragment.
+    ///
+    /// If the write did not change anything, the span is empty. If the write
+    /// caused the reparse of the root node, the span covers the entire
+    /// document.
+    ///
+    /// Returns None if the Document is immutable.
+    #[inline(always)]
+    pub fn last_damage(&self) -> Option<SiteSpan> {
+        Some(self.last_reparse()?.damage())
+    }
+
+    /// Returns a list of the syntax tree nodes that the incremental reparser
+    /// has attached to new parents during the latest [write](Self::write) into
+    /// this document.
+    ///
+    /// This is a shortcut for the
+    /// [ReparseReport::transplants](ReparseReport::transplants) list of
+    /// the [last reparse](Self::last_reparse) report. If the document is
+    /// immutable, the function returns an empty slice.
+    #[inline(always)]
+    pub fn last_transplants(&self) -> &[Transplant] {
+        match self.last_reparse() {
+            Some(report) => report.transplants.as_slice(),
+            None => &[],
+        }
+    }
+
+    /// Returns the [Instrumentation] handle of this document.
+    ///
+    /// If the document is immutable, returns [Instrumentation::none].
+    #[inline(always)]
+    pub fn instrumentation(&self) -> Instrumentation {
+        match self {
+            Self::Mutable(unit) => unit.instrumentation(),
+            Self::Immutable(..) => Instrumentation::none(),
+        }
+    }
+
+    /// Sets the [Instrumentation] handle of this document.
+    ///
+    /// The document reports the internal events of the incremental reparser
+    /// (rescanned fragments and reparsed clusters) caused by
+    /// the subsequent [writes](Self::write) into
+    /// the [InstrumentationSink](crate::units::InstrumentationSink) of
+    /// the handle.
+    ///
+    /// By default, the document does not have instrumentation.
+    ///
+    /// If the document is immutable, this function does nothing, because
+    /// immutable documents are never reparsed.
+    #[inline(always)]
+    pub fn set_instrumentation(&mut self, instrumentation: Instrumentation) {
+        if let Self::Mutable(unit) = self {
+            unit.set_instrumentation(instrumentation);
+        }
+    }
+
     /// A convenient function that returns a reference to the document's
     /// inner [MutableUnit] if the document is mutable. Otherwise returns None.
     #[inline(always)]
@@ -572,6 +859,25 @@ impl<'document, N: Node> TokenCursor<'document> for DocumentCursor<'document, N>
     }
 }
 
+pub enum DocumentBackCursor<'document, N: Node> {
+    Mutable(<MutableUnit<N> as SourceCode>::BackCursor<'document>),
+    Immutable(<ImmutableUnit<N> as SourceCode>::BackCursor<'document>),
+}
+
+impl<'document, N: Node> Iterator for DocumentBackCursor<'document, N> {
+    type Item = Chunk<'document, N::Token>;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Mutable(iterator) => iterator.next(),
+            Self::Immutable(iterator) => iterator.next(),
+        }
+    }
+}
+
+impl<'document, N: Node> FusedIterator for DocumentBackCursor<'document, N> {}
+
 pub enum DocumentCharIter<'document, N: Node> {
     Mutable(<MutableUnit<N> as SourceCode>::CharIterator<'document>),
     Immutable(<ImmutableUnit<N> as SourceCode>::CharIterator<'document>),
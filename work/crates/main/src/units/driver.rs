@@ -0,0 +1,383 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use alloc::string::String;
+use core::fmt::{Debug, Formatter};
+
+use crate::{
+    lexis::{SiteSpan, ToSpan, TokenBuffer},
+    report::ld_unreachable,
+    syntax::Node,
+    units::{
+        mutable::unit::{LexisUpdate, SyntaxUpdate},
+        CompilationUnit,
+        Document,
+        VoidWatcher,
+    },
+};
+
+/// A result of the [ParseDriver::step] function.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum StepResult {
+    /// The driver has more work to do. The caller should call
+    /// the [step](ParseDriver::step) function again later.
+    Pending,
+
+    /// The driver has finished its work, and
+    /// the [document](ParseDriver::document) is ready.
+    Done,
+}
+
+impl StepResult {
+    /// Returns true, if `self == Self::Pending`.
+    #[inline(always)]
+    pub fn is_pending(&self) -> bool {
+        match self {
+            Self::Pending => true,
+            _ => false,
+        }
+    }
+
+    /// Returns true, if `self == Self::Done`.
+    #[inline(always)]
+    pub fn is_done(&self) -> bool {
+        match self {
+            Self::Done => true,
+            _ => false,
+        }
+    }
+}
+
+/// A time-sliced builder of the [Document].
+///
+/// Creating a Document from a large source code text, or writing a large text
+/// into a mutable Document, is a blocking operation. In single-threaded
+/// environments such as the browser's main thread, this operation could
+/// freeze the user interface for a noticeable amount of time.
+///
+/// The ParseDriver splits this work into a series of bounded steps. Each call
+/// of the [step](Self::step) function processes about `budget` bytes of the
+/// source code text (but at least one unit of work), and returns
+/// [StepResult::Pending] if there is more work to do. The host could
+/// interleave these steps with other tasks, for instance, by calling one step
+/// per `requestAnimationFrame` callback in the WebAssembly environment.
+///
+/// ```ignore
+/// let mut driver = ParseDriver::<MyNode>::new(text);
+///
+/// while driver.step(64 * 1024).is_pending() {
+///     // Yield control to the host.
+/// }
+///
+/// let doc = driver.finish();
+/// ```
+///
+/// The driver has three modes:
+///
+///  - [ParseDriver::new] builds an immutable Document. The driver scans
+///    the text token by token, and then parses the syntax tree in
+///    the final step.
+///  - [ParseDriver::new_mutable] builds a mutable Document in the same way.
+///  - [ParseDriver::write] applies an edit to an existing mutable Document.
+///    The driver rescans the tokens affected by the edit step by step, then
+///    writes the text into the document's source code, and then resumes
+///    the incremental reparsing of the syntax tree.
+///
+/// The scanner sees the entire text on each step, and each step stops at
+/// a token boundary. Therefore, the scanned tokens are the same as if
+/// the text had been scanned at once. In the write mode, the document
+/// remains unchanged until the scanning is finished.
+///
+/// The incremental reparser reparses the syntax tree cluster by cluster.
+/// If the edit affects the enclosing clusters of the edited one, the reparser
+/// proceeds to them, and the driver resumes this process on the next step
+/// once the budget is spent. Each step reparses at least one cluster.
+///
+/// Note that the syntax parser is a recursive-descent parser that cannot be
+/// suspended in the middle of the parsing process of a single cluster. For
+/// this reason, in the first two modes, the syntax parsing stage takes
+/// a single step regardless of the budget, and in the write mode, a step
+/// that reparses a large cluster could take longer than the budget implies.
+///
+/// In all modes, the driver does not expose the document until the driver is
+/// [done](Self::is_done). In the write mode, the edit is applied atomically:
+/// it is a single edit of the document's [history](Document::checkpoint), and
+/// the [last_reparse](Document::last_reparse) report of the final document
+/// relates to the entire edit.
+pub struct ParseDriver<N: Node> {
+    text: String,
+    offset: usize,
+    mode: DriverMode<N>,
+    buffer: Option<TokenBuffer<N::Token>>,
+    document: Option<Document<N>>,
+}
+
+impl<N: Node> Debug for ParseDriver<N> {
+    #[inline]
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter
+            .debug_struct("ParseDriver")
+            .field("offset", &self.offset)
+            .field("length", &self.text.len())
+            .field("done", &self.is_done())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<N: Node> ParseDriver<N> {
+    /// Creates a driver that builds an immutable Document from
+    /// the source code `text`.
+    ///
+    /// The final Document is equivalent to
+    /// the [Document::new_immutable] result.
+    #[inline(always)]
+    pub fn new(text: impl Into<String>) -> Self {
+        Self::scan(text.into(), DriverMode::Immutable)
+    }
+
+    /// Creates a driver that builds a mutable Document from
+    /// the source code `text`.
+    ///
+    /// The final Document is equivalent to
+    /// the [Document::new_mutable] result.
+    #[inline(always)]
+    pub fn new_mutable(text: impl Into<String>) -> Self {
+        Self::scan(text.into(), DriverMode::Mutable)
+    }
+
+    /// Creates a driver that writes the `text` into the `span` of
+    /// the mutable `document`.
+    ///
+    /// The first steps scan the `text` in the context of the document, then
+    /// the driver replaces the `span` with the `text`, and the next steps
+    /// reparse the syntax tree. When the driver is done, the document is
+    /// the same as if the text had been [written](Document::write) into it.
+    ///
+    /// **Panic**
+    ///
+    /// Panics if the Document is not mutable, or if the specified span is not
+    /// valid for this document.
+    pub fn write(document: Document<N>, span: impl ToSpan, text: impl Into<String>) -> Self {
+        if !document.is_mutable() {
+            panic!("Specified Document is not mutable.");
+        }
+
+        let span = match span.to_site_span(&document) {
+            None => panic!("Specified span is invalid."),
+
+            Some(span) => span,
+        };
+
+        Self {
+            text: text.into(),
+            offset: 0,
+            mode: DriverMode::Write {
+                span: Some(span),
+                scan: None,
+                update: None,
+            },
+            buffer: None,
+            document: Some(document),
+        }
+    }
+
+    /// Performs a bounded amount of work.
+    ///
+    /// The `budget` parameter is the number of the source code text bytes to
+    /// scan, or the number of characters to reparse, during this step.
+    /// The driver always performs at least one unit of work (scans at least
+    /// one token, or reparses at least one cluster), and the scanning step
+    /// always stops at a token boundary, so the step could process slightly
+    /// more than the budget. The zero budget is a valid value too.
+    ///
+    /// Returns [StepResult::Done] if the driver has finished its work. Further
+    /// calls of this function have no effect and return StepResult::Done too.
+    pub fn step(&mut self, budget: usize) -> StepResult {
+        match &mut self.mode {
+            DriverMode::Immutable | DriverMode::Mutable => {
+                let Some(buffer) = &mut self.buffer else {
+                    return StepResult::Done;
+                };
+
+                if self.offset < self.text.len() {
+                    let limit = self.offset.saturating_add(budget.max(1));
+
+                    self.offset = buffer.scan_step(self.offset, limit);
+
+                    return StepResult::Pending;
+                }
+
+                let Some(buffer) = self.buffer.take() else {
+                    return StepResult::Done;
+                };
+
+                self.document = Some(match self.mode {
+                    DriverMode::Mutable => Document::new_mutable(buffer),
+                    _ => Document::new_immutable(buffer),
+                });
+
+                StepResult::Done
+            }
+
+            DriverMode::Write { span, scan, update } => {
+                let Some(unit) = self.document.as_mut().and_then(Document::as_mutable) else {
+                    return StepResult::Done;
+                };
+
+                let budget = budget.max(1);
+                let mut spent = 0;
+
+                if let Some(span) = span.take() {
+                    let pending = unit.begin_scan(span, &self.text, budget);
+
+                    spent = pending.scanned_bytes();
+
+                    *scan = Some(pending);
+                } else if let Some(pending) = scan {
+                    let before = pending.scanned_bytes();
+
+                    let _ = unit.resume_scan(pending, &self.text, budget);
+
+                    spent = pending.scanned_bytes() - before;
+                }
+
+                if let Some(pending) = scan {
+                    // The scanned text includes the rescanned tokens
+                    // surrounding the edit.
+                    self.offset = pending.scanned_bytes().min(self.text.len());
+
+                    if !pending.is_scanned() {
+                        return StepResult::Pending;
+                    }
+                }
+
+                if let Some(pending) = scan.take() {
+                    *update = unit.begin_write(&mut VoidWatcher, pending, &self.text);
+
+                    self.offset = self.text.len();
+                }
+
+                while let Some(pending) = update {
+                    if spent >= budget {
+                        return StepResult::Pending;
+                    }
+
+                    spent = spent.saturating_add(pending.cover_length().max(1));
+
+                    if unit.resume_write(&mut VoidWatcher, pending) {
+                        *update = None;
+                    }
+                }
+
+                StepResult::Done
+            }
+        }
+    }
+
+    /// Returns true if the driver has finished its work.
+    #[inline(always)]
+    pub fn is_done(&self) -> bool {
+        match &self.mode {
+            DriverMode::Immutable | DriverMode::Mutable => self.buffer.is_none(),
+            DriverMode::Write { span, scan, update } => {
+                span.is_none() && scan.is_none() && update.is_none()
+            }
+        }
+    }
+
+    /// Returns a ratio of the processed source code text bytes in
+    /// the range `0.0..=1.0`.
+    ///
+    /// This value is intended for progress indication. Note that the driver
+    /// may need more steps to finish the syntax parsing when the ratio
+    /// reaches `1.0`.
+    #[inline(always)]
+    pub fn progress(&self) -> f64 {
+        if self.text.is_empty() {
+            return 1.0;
+        }
+
+        self.offset as f64 / self.text.len() as f64
+    }
+
+    /// Returns the Document built by this driver.
+    ///
+    /// Returns None until the driver is [done](Self::is_done).
+    #[inline(always)]
+    pub fn document(&self) -> Option<&Document<N>> {
+        if !self.is_done() {
+            return None;
+        }
+
+        self.document.as_ref()
+    }
+
+    /// Finishes the remaining work at once, and returns the Document built by
+    /// this driver.
+    pub fn finish(mut self) -> Document<N> {
+        while self.step(usize::MAX).is_pending() {}
+
+        match self.document {
+            Some(document) => document,
+
+            // Safety: The document is always set when the driver is done.
+            None => unsafe { ld_unreachable!("Missing driver document.") },
+        }
+    }
+
+    #[inline(always)]
+    fn scan(text: String, mode: DriverMode<N>) -> Self {
+        let mut buffer = TokenBuffer::with_capacity(0, text.len());
+
+        buffer.append_unscanned(&text);
+
+        Self {
+            text,
+            offset: 0,
+            mode,
+            buffer: Some(buffer),
+            document: None,
+        }
+    }
+}
+
+enum DriverMode<N: Node> {
+    Immutable,
+    Mutable,
+    Write {
+        span: Option<SiteSpan>,
+        scan: Option<LexisUpdate<N>>,
+        update: Option<SyntaxUpdate<N>>,
+    },
+}
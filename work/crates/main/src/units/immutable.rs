@@ -32,13 +32,16 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::fmt::{Debug, Display, Formatter};
+use core::fmt::{Debug, Display, Formatter};
 
+#[cfg(feature = "serde")]
+use crate::arena::Entry;
+#[cfg(feature = "std")]
+use crate::format::SnippetFormatter;
 use crate::{
     arena::{Id, Identifiable, SubId},
-    format::SnippetFormatter,
     lexis::{SourceCode, Token, TokenBuffer},
-    syntax::{ImmutableSyntaxTree, Node},
+    syntax::{ImmutableSyntaxTree, Node, NodeRule},
     units::{CompilationUnit, Lexis, Syntax},
 };
 
@@ -70,7 +73,7 @@ impl<N: Node> Identifiable for ImmutableUnit<N> {
 
 impl<N: Node> Debug for ImmutableUnit<N> {
     #[inline]
-    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
         formatter
             .debug_struct("ImmutableUnit")
             .field("id", &self.lexis.id())
@@ -81,11 +84,19 @@ impl<N: Node> Debug for ImmutableUnit<N> {
 
 impl<N: Node> Display for ImmutableUnit<N> {
     #[inline(always)]
-    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
-        formatter
-            .snippet(self)
-            .set_caption(format!("ImmutableUnit({})", self.id()))
-            .finish()
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        #[cfg(feature = "std")]
+        {
+            formatter
+                .snippet(self)
+                .set_caption(format!("ImmutableUnit({})", self.id()))
+                .finish()
+        }
+
+        #[cfg(not(feature = "std"))]
+        {
+            formatter.write_str(self.substring(..).as_ref())
+        }
     }
 }
 
@@ -170,4 +181,45 @@ impl<N: Node> ImmutableUnit<N> {
 
         Self { lexis, syntax }
     }
+
+    /// Creates an ImmutableUnit from the source code `text` parsed as
+    /// a specific parsing `rule` rather than the root rule.
+    ///
+    /// The root node of the resulting unit is the node produced by
+    /// the `rule`. See [ImmutableSyntaxTree::parse_rule] for details.
+    ///
+    /// Note that this function only builds the lexical and syntax structure of
+    /// the snippet. The unit does not belong to any
+    /// [Analyzer](crate::analysis::Analyzer), and the semantics of its
+    /// nodes remain uninitialized.
+    ///
+    /// **Panic**
+    ///
+    /// The function may panic if the `rule` parameter value is not valid for
+    /// the `N` programming language.
+    pub fn parse_rule(rule: NodeRule, text: impl Into<TokenBuffer<N::Token>>) -> Self {
+        let lexis = text.into();
+
+        let syntax = ImmutableSyntaxTree::parse_rule_with_id(
+            SubId::fork(lexis.id()),
+            rule,
+            lexis.cursor(..),
+        );
+
+        Self { lexis, syntax }
+    }
+
+    // Creates an ImmutableUnit from the token buffer and the syntax tree nodes
+    // without parsing.
+    //
+    // Returns None if the nodes do not form a syntax tree storage.
+    #[cfg(feature = "serde")]
+    pub(crate) fn restore(
+        lexis: TokenBuffer<N::Token>,
+        nodes: impl IntoIterator<Item = (Entry, N)>,
+    ) -> Option<Self> {
+        let syntax = ImmutableSyntaxTree::restore(SubId::fork(lexis.id()), nodes)?;
+
+        Some(Self { lexis, syntax })
+    }
 }
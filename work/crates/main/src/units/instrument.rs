@@ -0,0 +1,279 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use core::{
+    cmp::Ordering,
+    fmt::{Debug, Formatter},
+    hash::{Hash, Hasher},
+    time::Duration,
+};
+
+#[cfg(feature = "std")]
+use crate::analysis::AttrRef;
+use crate::{
+    arena::Id,
+    lexis::{SiteSpan, TokenCount},
+    syntax::{NodeRef, NodeRule},
+};
+
+/// A receiver of the internal events of the incremental reparser and
+/// the semantic analyzer.
+///
+/// This trait is intended for diagnosing performance issues of
+/// the compiler front-end in production. All functions of this trait have
+/// empty default implementations, so you can implement only the events
+/// you are interested in.
+///
+/// You can install an instance of this trait into the mutable
+/// [Document](crate::units::Document::set_instrumentation) and into
+/// the [AnalyzerConfig](crate::analysis::AnalyzerConfig::instrumentation)
+/// through the [Instrumentation] handle.
+///
+/// The functions of this trait are called synchronously from within
+/// the reparser and the analyzer's internal procedures, and should return
+/// as quickly as possible.
+pub trait InstrumentationSink: Send + Sync + 'static {
+    /// Called when the mutable compilation unit with the `id` identifier
+    /// rescans a fragment of the source code text during the
+    /// [write](crate::units::Document::write) operation.
+    ///
+    /// The `span` parameter is the site span of the rescanned fragment
+    /// in the new version of the source code, and the `tokens` parameter is
+    /// the number of the rescanned tokens.
+    #[inline(always)]
+    #[allow(unused_variables)]
+    fn on_rescan(&self, id: Id, span: SiteSpan, tokens: TokenCount) {}
+
+    /// Called when the incremental reparser visits a syntax tree cluster
+    /// produced by the parsing `rule`.
+    ///
+    /// The `node_ref` parameter is a reference of the cluster's primary node.
+    ///
+    /// If the `reused` parameter is true, the reparser has reused the cluster
+    /// as it is. Otherwise, the reparser has parsed the cluster anew.
+    ///
+    /// See [ReparseReport](crate::units::ReparseReport) for details.
+    #[inline(always)]
+    #[allow(unused_variables)]
+    fn on_reparse(&self, node_ref: &NodeRef, rule: NodeRule, reused: bool) {}
+
+    /// Called when the [Analyzer](crate::analysis::Analyzer) has computed
+    /// the value of the attribute.
+    ///
+    /// The `duration` parameter is the time spent on the attribute's
+    /// [computable function](crate::analysis::Computable::compute) call,
+    /// including the time spent on the computation of the attributes this
+    /// function depends on. Under the wasm targets, this value is always zero.
+    ///
+    /// This function is available under the `std` feature only.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    #[allow(unused_variables)]
+    fn on_attr_computed(&self, attr_ref: &AttrRef, duration: Duration) {}
+
+    /// Called when the [Analyzer](crate::analysis::Analyzer) classifies
+    /// a created or an updated node of the document using the grammar's
+    /// [Classifier](crate::analysis::Classifier).
+    ///
+    /// If the `skipped` parameter is true, the classifier's
+    /// [denounce](crate::analysis::Classifier::denounce) function has
+    /// declared the node's classes unchanged, and the Analyzer skipped
+    /// the classification of this node.
+    ///
+    /// See [ClassificationStats](crate::analysis::ClassificationStats) for
+    /// the aggregated statistics of the classification pass.
+    ///
+    /// This function is available under the `std` feature only.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    #[allow(unused_variables)]
+    fn on_classify(&self, node_ref: &NodeRef, skipped: bool) {}
+}
+
+/// A handle of the optional [InstrumentationSink].
+///
+/// By default, the handle is [none](Self::none), and the instrumented
+/// procedures skip the reporting entirely.
+///
+/// The handle is a cheap copyable object. Two handles are equal if they refer
+/// to the same sink instance.
+#[derive(Clone, Copy, Default)]
+pub struct Instrumentation {
+    sink: Option<&'static dyn InstrumentationSink>,
+}
+
+impl Debug for Instrumentation {
+    #[inline]
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        match self.sink {
+            None => formatter.write_str("Instrumentation(None)"),
+            Some(..) => formatter.write_fmt(format_args!("Instrumentation({:#x})", self.addr())),
+        }
+    }
+}
+
+impl PartialEq for Instrumentation {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.addr() == other.addr()
+    }
+}
+
+impl Eq for Instrumentation {}
+
+impl PartialOrd for Instrumentation {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Instrumentation {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.addr().cmp(&other.addr())
+    }
+}
+
+impl Hash for Instrumentation {
+    #[inline(always)]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.addr().hash(state)
+    }
+}
+
+impl Instrumentation {
+    /// Returns a handle without the sink.
+    #[inline(always)]
+    pub const fn none() -> Self {
+        Self { sink: None }
+    }
+
+    /// Returns a handle of the specified `sink`.
+    #[inline(always)]
+    pub const fn new(sink: &'static dyn InstrumentationSink) -> Self {
+        Self { sink: Some(sink) }
+    }
+
+    /// Returns a handle of the [LogInstrumentation] sink that forwards
+    /// the events to the `log` crate.
+    ///
+    /// This function is available under the `log` feature only.
+    #[cfg(feature = "log")]
+    #[inline(always)]
+    pub const fn log() -> Self {
+        Self::new(&LogInstrumentation)
+    }
+
+    /// Returns true if the handle does not have a sink.
+    #[inline(always)]
+    pub fn is_none(&self) -> bool {
+        self.sink.is_none()
+    }
+
+    /// Returns the underlying sink if any.
+    #[inline(always)]
+    pub fn sink(&self) -> Option<&'static dyn InstrumentationSink> {
+        self.sink
+    }
+
+    #[inline(always)]
+    fn addr(&self) -> usize {
+        match self.sink {
+            None => 0,
+            Some(sink) => sink as *const dyn InstrumentationSink as *const () as usize,
+        }
+    }
+}
+
+/// An [InstrumentationSink] that forwards the events to
+/// the [log](https://docs.rs/log) crate's logger.
+///
+/// The rescan and the attribute computation events are logged with
+/// the "debug" level, and the reparse events are logged with the "trace"
+/// level. The target of the log records is "lady_deirdre".
+///
+/// This object is available under the `log` feature only.
+#[cfg(feature = "log")]
+#[derive(Clone, Copy, Default, Debug)]
+pub struct LogInstrumentation;
+
+#[cfg(feature = "log")]
+impl InstrumentationSink for LogInstrumentation {
+    fn on_rescan(&self, id: Id, span: SiteSpan, tokens: TokenCount) {
+        log::debug!(
+            target: "lady_deirdre",
+            "Unit({id}) rescan: {}..{} ({tokens} tokens).",
+            span.start,
+            span.end,
+        );
+    }
+
+    fn on_reparse(&self, node_ref: &NodeRef, rule: NodeRule, reused: bool) {
+        log::trace!(
+            target: "lady_deirdre",
+            "Unit({}) {} cluster {:?} of rule {rule}.",
+            node_ref.id,
+            match reused {
+                true => "reused",
+                false => "reparsed",
+            },
+            node_ref.entry,
+        );
+    }
+
+    #[cfg(feature = "std")]
+    fn on_attr_computed(&self, attr_ref: &AttrRef, duration: Duration) {
+        log::debug!(
+            target: "lady_deirdre",
+            "Unit({}) attribute {:?} computed in {duration:?}.",
+            attr_ref.id,
+            attr_ref.entry,
+        );
+    }
+
+    #[cfg(feature = "std")]
+    fn on_classify(&self, node_ref: &NodeRef, skipped: bool) {
+        log::trace!(
+            target: "lady_deirdre",
+            "Unit({}) node {:?} classification {}.",
+            node_ref.id,
+            node_ref.entry,
+            match skipped {
+                true => "skipped",
+                false => "computed",
+            },
+        );
+    }
+}
@@ -33,18 +33,32 @@
 ////////////////////////////////////////////////////////////////////////////////
 
 pub(super) mod document;
+pub(super) mod driver;
 pub(super) mod immutable;
+pub(super) mod instrument;
 pub(super) mod mutable;
+#[cfg(feature = "serde")]
+pub(super) mod persist;
 pub(super) mod unit;
 
 mod storage;
 
+#[cfg(feature = "log")]
+pub use crate::units::instrument::LogInstrumentation;
+#[cfg(feature = "std")]
+pub use crate::units::mutable::watcher::DebugWatcher;
+#[cfg(feature = "serde")]
+pub use crate::units::persist::{RefMap, Restored};
 pub use crate::units::{
     document::Document,
+    driver::{ParseDriver, StepResult},
     immutable::ImmutableUnit,
+    instrument::{Instrumentation, InstrumentationSink},
     mutable::{
+        history::Checkpoint,
+        reparse::{ReparseReport, Transplant},
         unit::MutableUnit,
-        watcher::{DebugWatcher, VoidWatcher, Watcher},
+        watcher::{VoidWatcher, Watcher},
     },
     unit::{CompilationUnit, Lexis, Syntax},
 };
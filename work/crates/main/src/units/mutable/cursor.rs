@@ -32,9 +32,11 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
+use core::{iter::FusedIterator, marker::PhantomData};
+
 use crate::{
     arena::{Id, Identifiable},
-    lexis::{Length, Site, SiteRef, SiteSpan, Token, TokenCount, TokenCursor, TokenRef},
+    lexis::{Chunk, Length, Site, SiteRef, SiteSpan, Token, TokenCount, TokenCursor, TokenRef},
     report::ld_assert,
     syntax::Node,
     units::{storage::ChildCursor, MutableUnit},
@@ -273,3 +275,72 @@ impl<'unit, N: Node> MutableCursor<'unit, N> {
         false
     }
 }
+
+pub struct MutableBackCursor<'unit, N: Node> {
+    next_chunk_cursor: ChildCursor<N>,
+    next_site: Site,
+    last_chunk_cursor: ChildCursor<N>,
+    _unit_lifetime: PhantomData<&'unit ()>,
+}
+
+impl<'unit, N: Node> Iterator for MutableBackCursor<'unit, N> {
+    type Item = Chunk<'unit, N::Token>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_chunk_cursor.is_dangling() {
+            return None;
+        }
+
+        let chunk_cursor = self.next_chunk_cursor;
+
+        let chunk = Chunk {
+            token: unsafe { chunk_cursor.token() },
+            site: self.next_site,
+            length: *unsafe { chunk_cursor.span() },
+            string: unsafe { chunk_cursor.string() },
+        };
+
+        match unsafe { chunk_cursor.same_chunk_as(&self.last_chunk_cursor) } {
+            true => self.next_chunk_cursor = ChildCursor::dangling(),
+
+            false => {
+                unsafe { self.next_chunk_cursor.back() };
+
+                ld_assert!(!self.next_chunk_cursor.is_dangling(), "Dangling back ref.");
+
+                self.next_site -= *unsafe { self.next_chunk_cursor.span() };
+            }
+        }
+
+        Some(chunk)
+    }
+}
+
+impl<'unit, N: Node> FusedIterator for MutableBackCursor<'unit, N> {}
+
+impl<'unit, N: Node> MutableBackCursor<'unit, N> {
+    pub(super) fn new(unit: &'unit MutableUnit<N>, mut span: SiteSpan) -> Self {
+        let mut next_chunk_cursor = unit.tree().lookup(&mut span.end);
+        let mut last_chunk_cursor = unit.tree().lookup(&mut span.start);
+
+        if next_chunk_cursor.is_dangling() {
+            next_chunk_cursor = unit.tree().last();
+        }
+
+        if last_chunk_cursor.is_dangling() {
+            last_chunk_cursor = unit.tree().last();
+        } else if span.start == 0 && unsafe { !last_chunk_cursor.is_first() } {
+            unsafe { last_chunk_cursor.back() };
+        }
+
+        let next_site = unsafe { unit.tree().site_of(&next_chunk_cursor) };
+
+        Self {
+            next_chunk_cursor,
+            next_site,
+            last_chunk_cursor,
+            _unit_lifetime: PhantomData::default(),
+        }
+    }
+}
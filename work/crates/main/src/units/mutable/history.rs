@@ -0,0 +1,273 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::{
+    mem::{size_of, take},
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use crate::{
+    arena::{Entry, Id},
+    lexis::{Length, Site, SiteSpan},
+    syntax::{Node, NodeRule, SyntaxError},
+};
+
+/// A point in the edit history of the mutable
+/// [Document](crate::units::Document).
+///
+/// You can create a checkpoint using
+/// the [Document::checkpoint](crate::units::Document::checkpoint) function,
+/// and restore the source code text of the document to the state at this
+/// point using the [Document::revert](crate::units::Document::revert)
+/// function.
+///
+/// The checkpoint is a lightweight index into the document's history.
+/// It does not hold the document's content.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Checkpoint {
+    pub(super) id: Id,
+    pub(super) index: usize,
+}
+
+impl Checkpoint {
+    /// Returns the [Id] of the document to which this checkpoint belongs.
+    #[inline(always)]
+    pub fn id(&self) -> Id {
+        self.id
+    }
+}
+
+// A log of the inverse edits applied to the MutableUnit since
+// the first checkpoint.
+//
+// The `base` is the total number of the edits discarded from the log, such
+// that the checkpoint's index is an absolute number of the edit in
+// the history.
+pub(super) struct History<N: Node> {
+    recording: AtomicBool,
+    base: usize,
+    edits: Vec<InverseEdit<N>>,
+}
+
+impl<N: Node> Default for History<N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            recording: AtomicBool::new(false),
+            base: 0,
+            edits: Vec::new(),
+        }
+    }
+}
+
+impl<N: Node> History<N> {
+    #[inline(always)]
+    pub(super) fn checkpoint(&self, id: Id) -> Checkpoint {
+        self.recording.store(true, Ordering::Relaxed);
+
+        Checkpoint {
+            id,
+            index: self.base + self.edits.len(),
+        }
+    }
+
+    #[inline(always)]
+    pub(super) fn is_recording(&mut self) -> bool {
+        *self.recording.get_mut()
+    }
+
+    #[inline(always)]
+    pub(super) fn record(&mut self, span: SiteSpan, text: String) {
+        self.edits.push(InverseEdit {
+            span,
+            text,
+            discarded: Discarded::default(),
+        });
+    }
+
+    // Attaches the components discarded by the most recent edit to
+    // the record of this edit.
+    #[inline(always)]
+    pub(super) fn attach(&mut self, discarded: Discarded<N>) {
+        let Some(edit) = self.edits.last_mut() else {
+            return;
+        };
+
+        edit.discarded = discarded;
+    }
+
+    // Returns the index range of the edits applied since the checkpoint.
+    #[inline(always)]
+    pub(super) fn since(&self, checkpoint: &Checkpoint) -> Option<(usize, usize)> {
+        if checkpoint.index < self.base {
+            return None;
+        }
+
+        let from = checkpoint.index - self.base;
+
+        if from > self.edits.len() {
+            return None;
+        }
+
+        Some((from, self.edits.len()))
+    }
+
+    #[inline(always)]
+    pub(super) fn get(&self, index: usize) -> (SiteSpan, String) {
+        let edit = &self.edits[index];
+
+        (edit.span.clone(), edit.text.clone())
+    }
+
+    // Temporarily takes the components discarded by the edit. The caller
+    // returns them back using the `restore` function.
+    #[inline(always)]
+    pub(super) fn take_discarded(&mut self, index: usize) -> Discarded<N> {
+        take(&mut self.edits[index].discarded)
+    }
+
+    #[inline(always)]
+    pub(super) fn restore(&mut self, index: usize, discarded: Discarded<N>) {
+        self.edits[index].discarded = discarded;
+    }
+
+    pub(super) fn heap_size(&self) -> usize {
+        self.edits.capacity() * size_of::<InverseEdit<N>>()
+            + self
+                .edits
+                .iter()
+                .map(|edit| edit.text.capacity() + edit.discarded.heap_size())
+                .sum::<usize>()
+    }
+
+    #[inline(always)]
+    pub(super) fn clear(&mut self) {
+        *self.recording.get_mut() = false;
+        self.base += self.edits.len();
+        self.edits = Vec::new();
+    }
+}
+
+struct InverseEdit<N: Node> {
+    span: SiteSpan,
+    text: String,
+    discarded: Discarded<N>,
+}
+
+// The tokens and the clusters of the syntax tree that an edit has removed
+// from the unit.
+//
+// When the edit is reverted, the revert reuses the entries of the removed
+// components for the identical components that the reverting edit creates
+// at the same places, such that the references obtained before the edit
+// become valid again.
+pub(crate) struct Discarded<N: Node> {
+    // The removed chunks in the order of their sites.
+    pub(crate) chunks: Vec<DiscardedChunk<N>>,
+
+    // The removed clusters by their anchor chunks and rules.
+    //
+    // The anchor chunk is the chunk to which the cluster's cache is attached,
+    // or a nil entry for the root cluster.
+    pub(crate) clusters: BTreeMap<(Entry, NodeRule), DiscardedCluster>,
+}
+
+impl<N: Node> Default for Discarded<N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            chunks: Vec::new(),
+            clusters: BTreeMap::new(),
+        }
+    }
+}
+
+impl<N: Node> Discarded<N> {
+    // Returns the entries of the removed chunks.
+    #[inline(always)]
+    pub(super) fn chunk_entries(&self) -> impl Iterator<Item = Entry> + '_ {
+        self.chunks.iter().map(|chunk| chunk.entry)
+    }
+
+    // Returns the entries of the removed nodes.
+    #[inline(always)]
+    pub(super) fn node_entries(&self) -> impl Iterator<Item = Entry> + '_ {
+        self.clusters.values().flat_map(|cluster| {
+            Some(cluster.primary_node)
+                .into_iter()
+                .chain(cluster.secondary_nodes.iter().map(|(_, entry)| *entry))
+        })
+    }
+
+    // Returns the entries of the removed syntax errors.
+    #[inline(always)]
+    pub(super) fn error_entries(&self) -> impl Iterator<Item = Entry> + '_ {
+        self.clusters
+            .values()
+            .flat_map(|cluster| cluster.errors.iter().map(|(_, entry)| *entry))
+    }
+
+    fn heap_size(&self) -> usize {
+        self.chunks.capacity() * size_of::<DiscardedChunk<N>>()
+            + self
+                .clusters
+                .values()
+                .map(|cluster| {
+                    size_of::<((Entry, NodeRule), DiscardedCluster)>()
+                        + cluster.secondary_nodes.capacity() * size_of::<(NodeRule, Entry)>()
+                        + cluster.errors.capacity() * size_of::<(SyntaxError, Entry)>()
+                })
+                .sum::<usize>()
+    }
+}
+
+pub(crate) struct DiscardedChunk<N: Node> {
+    pub(crate) site: Site,
+    pub(crate) length: Length,
+    pub(crate) token: N::Token,
+    pub(crate) entry: Entry,
+}
+
+pub(crate) struct DiscardedCluster {
+    // The cluster's primary node. If the cluster has been reparsed in place,
+    // this node has not been removed.
+    pub(crate) primary_node: Entry,
+
+    // The secondary nodes in the order of their creation.
+    pub(crate) secondary_nodes: Vec<(NodeRule, Entry)>,
+
+    // The syntax errors in the order of their creation.
+    pub(crate) errors: Vec<(SyntaxError, Entry)>,
+}
@@ -32,7 +32,7 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::{iter::FusedIterator, str::Chars};
+use core::{iter::FusedIterator, str::Chars};
 
 use crate::{
     arena::{Id, RepoEntriesIter},
@@ -32,10 +32,13 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
+use alloc::{string::String, vec::Vec};
+use core::mem::replace;
+
 #[cfg(debug_assertions)]
 use crate::report::system_panic;
 use crate::{
-    lexis::{ByteIndex, Length, LexisSession, Site, Token, TokenCount, CHUNK_SIZE},
+    lexis::{ByteIndex, Length, LexisSession, Site, Token, TokenCount, TokenRule, CHUNK_SIZE, EOI},
     report::{ld_assert, ld_assert_ne, ld_unreachable},
     syntax::Node,
     units::storage::ChildCursor,
@@ -48,6 +51,8 @@ pub(super) struct MutableLexisSession<'source, N: Node> {
     begin: Cursor<N>,
     end: Cursor<N>,
     current: Cursor<N>,
+    previous: TokenRule,
+    line_start: bool,
 }
 
 unsafe impl<'source, N: Node> LexisSession for MutableLexisSession<'source, N> {
@@ -96,6 +101,21 @@ unsafe impl<'source, N: Node> LexisSession for MutableLexisSession<'source, N> {
 
         self.end = self.current;
     }
+
+    #[inline(always)]
+    fn rewind(&mut self) {
+        self.current = self.begin;
+    }
+
+    #[inline(always)]
+    fn previous(&self) -> TokenRule {
+        self.previous
+    }
+
+    #[inline(always)]
+    fn line_start(&self) -> bool {
+        self.line_start
+    }
 }
 
 impl<'source, N: Node> MutableLexisSession<'source, N> {
@@ -105,66 +125,100 @@ impl<'source, N: Node> MutableLexisSession<'source, N> {
     // 3. `'source` does not outlive `tail`'s Tree.
     // 4. `input` is not empty.
     // 5. Each item in `input` is not empty.
+    // 6. `previous` is the rule of the last non-blank token preceding
+    //    the `input`, or EOI if there is no such token.
+    // 7. `line_start` is true if the `input` starts at the beginning of
+    //    the text or right after the `\n` character.
     #[inline]
     pub(super) unsafe fn run(
         product_capacity: TokenCount,
         input: SessionInput<'source>,
         tail: ChildCursor<N>,
+        previous: TokenRule,
+        line_start: bool,
     ) -> SessionOutput<N> {
+        let mut state = SessionState::new(product_capacity, tail, previous, line_start);
+
+        let _ = unsafe { Self::resume(&mut state, input, ByteIndex::MAX) };
+
+        state.output
+    }
+
+    // Scans the `input` from the point where the previous call with the same
+    // `state` has stopped until the end of the input, or until the length of
+    // the scanned text reaches the `limit` number of bytes. The scanner
+    // always stops at a token boundary.
+    //
+    // Returns true if the scanning is finished, and the state's output is
+    // complete.
+    //
+    //Safety:
+    // 1. The `state` has been created with the `tail`, `previous`, and
+    //    `line_start` values that meet the `run` function requirements for
+    //    the `input`.
+    // 2. The `input` and the `tail`'s Tree have not been changed since
+    //    the creation of the `state`.
+    pub(super) unsafe fn resume(
+        state: &mut SessionState<N>,
+        input: SessionInput<'source>,
+        limit: ByteIndex,
+    ) -> bool {
         let last = match input.len().checked_sub(1) {
             Some(last) => last,
             None => ld_unreachable!("Empty input buffer."),
         };
 
-        let cursor = Cursor {
-            index: 0,
-            byte: 0,
-            site: 0,
-            tail,
-            overlap: 0,
-        };
+        let output = replace(
+            &mut state.output,
+            SessionOutput::new(0, state.cursor.tail),
+        );
 
         let mut session = Self {
             input,
             last,
-            output: SessionOutput {
-                length: 0,
-                spans: Vec::with_capacity(product_capacity),
-                indices: Vec::with_capacity(product_capacity),
-                tokens: Vec::with_capacity(product_capacity),
-                text: String::with_capacity(product_capacity * CHUNK_SIZE),
-                tail,
-                overlap: 0,
-            },
-            begin: cursor,
-            end: cursor,
-            current: cursor,
+            output,
+            begin: state.cursor,
+            end: state.cursor,
+            current: state.cursor,
+            previous: state.previous,
+            line_start: state.line_start,
         };
 
+        let finished = session.scan(limit);
+
+        state.cursor = session.begin;
+        state.previous = session.previous;
+        state.line_start = session.line_start;
+        state.output = session.output;
+
+        finished
+    }
+
+    // Returns true if the parsing process supposed to stop
+    #[inline]
+    fn scan(&mut self, limit: ByteIndex) -> bool {
         loop {
-            let token = <N::Token as Token>::scan(&mut session);
+            let token = <N::Token as Token>::scan(self);
 
-            if session.begin.site != session.end.site {
-                session
-                    .output
-                    .push(session.input, token, &session.begin, &session.end);
+            if self.begin.site != self.end.site {
+                self.output.push(self.input, token, &self.begin, &self.end);
+                self.track(token);
+                self.track_line_start();
 
-                if session.finished() {
-                    break;
+                if self.finished() {
+                    return true;
                 }
 
-                session.begin = session.end;
-                session.current = session.end;
-
-                continue;
+                self.begin = self.end;
+                self.current = self.end;
+            } else if self.enter_mismatch_loop() {
+                return true;
             }
 
-            if session.enter_mismatch_loop() {
-                break;
+            if self.output.text.len() >= limit {
+                return false;
             }
         }
-
-        session.output
     }
 
     // Returns true if the parsing process supposed to stop
@@ -172,8 +226,12 @@ impl<'source, N: Node> MutableLexisSession<'source, N> {
     fn enter_mismatch_loop(&mut self) -> bool {
         let mismatch = self.begin;
 
+        self.track(<N::Token as Token>::mismatch());
+
         loop {
-            if self.begin.advance(self.input) == 0xFF {
+            let byte = self.begin.advance(self.input);
+
+            if byte == 0xFF {
                 self.output.push(
                     self.input,
                     <N::Token as Token>::mismatch(),
@@ -185,6 +243,8 @@ impl<'source, N: Node> MutableLexisSession<'source, N> {
 
             self.begin.consume(self.input);
 
+            self.line_start = byte == b'\n';
+
             self.end = self.begin;
             self.current = self.begin;
 
@@ -202,6 +262,8 @@ impl<'source, N: Node> MutableLexisSession<'source, N> {
             );
 
             self.output.push(self.input, token, &self.begin, &self.end);
+            self.track(token);
+            self.track_line_start();
 
             if self.finished() {
                 return true;
@@ -241,12 +303,72 @@ impl<'source, N: Node> MutableLexisSession<'source, N> {
             }
 
             if self.end.index > self.last {
-                unsafe { self.output.tail.next() };
+                let mut next = self.end.tail;
+
+                unsafe { next.next() };
+
+                // The tokens past the tail chunk were scanned in the context
+                // of the original previous token. If the context has changed,
+                // these tokens have to be rescanned as well.
+                if !next.is_dangling() && unsafe { previous_rule(self.end.tail) } != self.previous {
+                    return false;
+                }
+
+                self.output.tail = next;
             }
         }
 
         true
     }
+
+    #[inline(always)]
+    fn track(&mut self, token: N::Token) {
+        let rule = token.rule();
+
+        if !<N::Token as Token>::BLANK.contains(rule) {
+            self.previous = rule;
+        }
+    }
+
+    #[inline(always)]
+    fn track_line_start(&mut self) {
+        self.line_start = self.output.text.as_bytes().last() == Some(&b'\n');
+    }
+}
+
+// Returns the rule of the last non-blank token in the sequence of tokens
+// that ends with the `cursor`'s token inclusively, or EOI if there is no such
+// token.
+//
+// Safety:
+// 1. `cursor` is a Page reference(possibly dangling).
+// 2. `cursor`'s Tree is immutable during the call.
+pub(super) unsafe fn previous_rule<N: Node>(mut cursor: ChildCursor<N>) -> TokenRule {
+    while !cursor.is_dangling() {
+        let rule = unsafe { cursor.token() }.rule();
+
+        if !<N::Token as Token>::BLANK.contains(rule) {
+            return rule;
+        }
+
+        unsafe { cursor.back() };
+    }
+
+    EOI
+}
+
+// Returns true if the token next to the `cursor`'s token starts at
+// the beginning of a line, or if the `cursor` is dangling.
+//
+// Safety:
+// 1. `cursor` is a Page reference(possibly dangling).
+// 2. `cursor`'s Tree is immutable during the call.
+pub(super) unsafe fn line_start<N: Node>(cursor: ChildCursor<N>) -> bool {
+    if cursor.is_dangling() {
+        return true;
+    }
+
+    unsafe { cursor.string() }.as_bytes().last() == Some(&b'\n')
 }
 
 pub(super) type SessionInput<'source> = &'source [&'source str];
@@ -262,6 +384,19 @@ pub(super) struct SessionOutput<N: Node> {
 }
 
 impl<N: Node> SessionOutput<N> {
+    #[inline(always)]
+    pub(super) fn new(capacity: TokenCount, tail: ChildCursor<N>) -> Self {
+        Self {
+            length: 0,
+            spans: Vec::with_capacity(capacity),
+            indices: Vec::with_capacity(capacity),
+            tokens: Vec::with_capacity(capacity),
+            text: String::with_capacity(capacity * CHUNK_SIZE),
+            tail,
+            overlap: 0,
+        }
+    }
+
     #[inline(always)]
     pub(super) fn count(&self) -> TokenCount {
         self.spans.len()
@@ -284,6 +419,48 @@ impl<N: Node> SessionOutput<N> {
     }
 }
 
+// A state of the scanning process paused at a token boundary.
+pub(super) struct SessionState<N: Node> {
+    cursor: Cursor<N>,
+    previous: TokenRule,
+    line_start: bool,
+    output: SessionOutput<N>,
+}
+
+impl<N: Node> SessionState<N> {
+    #[inline(always)]
+    pub(super) fn new(
+        product_capacity: TokenCount,
+        tail: ChildCursor<N>,
+        previous: TokenRule,
+        line_start: bool,
+    ) -> Self {
+        Self {
+            cursor: Cursor {
+                index: 0,
+                byte: 0,
+                site: 0,
+                tail,
+                overlap: 0,
+            },
+            previous,
+            line_start,
+            output: SessionOutput::new(product_capacity, tail),
+        }
+    }
+
+    // Returns the number of the text bytes scanned so far.
+    #[inline(always)]
+    pub(super) fn scanned(&self) -> ByteIndex {
+        self.output.text.len()
+    }
+
+    #[inline(always)]
+    pub(super) fn into_output(self) -> SessionOutput<N> {
+        self.output
+    }
+}
+
 struct Cursor<N: Node> {
     index: usize,
     byte: ByteIndex,
@@ -32,10 +32,12 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
+pub(crate) mod history;
 pub(crate) mod unit;
 pub(crate) mod watcher;
 
 mod cursor;
 mod iters;
 mod lexis;
+pub(crate) mod reparse;
 mod syntax;
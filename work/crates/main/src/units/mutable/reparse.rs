@@ -0,0 +1,155 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use alloc::vec::Vec;
+
+use crate::{lexis::SiteSpan, syntax::NodeRef};
+
+/// A summary of the syntax tree incremental reparsing caused by the latest
+/// edit of the mutable compilation unit.
+///
+/// The mutable [Document](crate::units::Document) (and
+/// the [MutableUnit](crate::units::MutableUnit)) splits the syntax tree into
+/// clusters of nodes. Each cluster is a product of a single rule parsed by
+/// the [SyntaxSession::descend](crate::syntax::SyntaxSession::descend)
+/// function, and its "primary" node is the node produced by this rule.
+///
+/// When you edit the document, the reparser re-parses the clusters affected
+/// by the edit, and reuses the unaffected clusters as they are.
+///
+/// You can obtain this object using
+/// the [Document::last_reparse](crate::units::Document::last_reparse)
+/// or the [MutableUnit::last_reparse](crate::units::MutableUnit::last_reparse)
+/// functions after the [write](crate::units::Document::write) call.
+#[derive(Clone, Default, PartialEq, Eq, Hash, Debug)]
+pub struct ReparseReport {
+    /// The references of the primary nodes of the clusters that the reparser
+    /// has parsed anew.
+    ///
+    /// The nodes are listed in the order of their parsing.
+    pub nodes: Vec<NodeRef>,
+
+    /// The number of clusters that the reparser has reused from the previous
+    /// version of the syntax tree without reparsing.
+    pub reused: usize,
+
+    /// The site span of the source code that the reparser has re-covered.
+    ///
+    /// If the edit did not cause syntax reparsing, this span is empty.
+    pub span: SiteSpan,
+
+    /// The site span of the source code that the lexical scanner has
+    /// rescanned.
+    ///
+    /// Besides the edited fragment, this span includes the preceding tokens
+    /// that the scanner revisits due to
+    /// the [Token::LOOKBACK](crate::lexis::Token::LOOKBACK) value, and
+    /// the tokens split by the edit's bounds.
+    ///
+    /// If the edit did not cause rescanning, this span is empty.
+    pub rescanned: SiteSpan,
+
+    /// The list of the reused clusters that the reparser has attached to
+    /// a new parent node.
+    ///
+    /// See [Transplant] for details.
+    pub transplants: Vec<Transplant>,
+}
+
+impl ReparseReport {
+    /// Returns a ratio of the reused clusters to the total number of
+    /// clusters visited by the reparser.
+    ///
+    /// The value is within the `0.0..=1.0` range. Returns zero if the reparser
+    /// did not visit any cluster.
+    #[inline(always)]
+    pub fn reuse_ratio(&self) -> f64 {
+        let total = self.reused + self.nodes.len();
+
+        if total == 0 {
+            return 0.0;
+        }
+
+        self.reused as f64 / total as f64
+    }
+
+    /// Returns the site span of the source code fragment damaged by the edit.
+    ///
+    /// The returning span is the union of the [rescanned](Self::rescanned)
+    /// and the [reparsed](Self::span) spans. If the edit caused the full
+    /// reparse of the root node, the span covers the entire source code.
+    ///
+    /// If the edit did not change anything, the span is empty.
+    pub fn damage(&self) -> SiteSpan {
+        if self.rescanned.is_empty() {
+            return self.span.clone();
+        }
+
+        if self.span.is_empty() {
+            return self.rescanned.clone();
+        }
+
+        self.rescanned.start.min(self.span.start)..self.rescanned.end.max(self.span.end)
+    }
+}
+
+/// A record of the syntax tree node re-parenting that occurred during
+/// incremental reparsing.
+///
+/// When the reparser reuses a cluster of nodes from the previous version of
+/// the syntax tree, it attaches the cluster's primary node to the node that
+/// the reparser is currently parsing. If this node differs from the previous
+/// parent of the cluster, the reparser records a Transplant into
+/// the [ReparseReport::transplants] list.
+///
+/// This information is useful for the external indices that rely on
+/// the stability of the [NodeRef]'s parent chain.
+///
+/// The reparser records transplants of the nodes that store their parent
+/// references only (the node variants with the `#[parent]` field). For other
+/// nodes, the [NodeRef::parent] function always returns a nil reference
+/// regardless of the reparse.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Transplant {
+    /// A reference of the reused cluster's primary node.
+    pub node_ref: NodeRef,
+
+    /// A reference of the node's parent before the reparse.
+    ///
+    /// Note that this node may not exist anymore.
+    pub old_parent_ref: NodeRef,
+
+    /// A reference of the node's parent after the reparse.
+    pub new_parent_ref: NodeRef,
+}
@@ -32,7 +32,8 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::mem::replace;
+use alloc::{boxed::Box, collections::BTreeSet, vec::Vec};
+use core::mem::replace;
 
 use crate::{
     arena::{Entry, EntryIndex, Id, Identifiable},
@@ -49,7 +50,11 @@ use crate::{
         ROOT_RULE,
     },
     units::{
+        mutable::history::DiscardedCluster,
         storage::{Cache, ChildCursor, Tree, TreeRefs},
+        Instrumentation,
+        ReparseReport,
+        Transplant,
         Watcher,
     },
 };
@@ -58,8 +63,12 @@ pub struct MutableSyntaxSession<'unit, N: Node, W: Watcher> {
     tree: &'unit mut Tree<N>,
     refs: &'unit mut TreeRefs<N>,
     watcher: &'unit mut W,
+    report: &'unit mut ReparseReport,
+    instrumentation: Instrumentation,
     context: Vec<Entry>,
     pending: Pending,
+    // The keys of the removed clusters whose entries this session reuses.
+    restored: BTreeSet<(Entry, NodeRule)>,
     failing: bool,
     next_chunk_cursor: ChildCursor<N>,
     next_site: Site,
@@ -90,9 +99,10 @@ impl<'unit, N: Node, W: Watcher> TokenCursor<'unit> for MutableSyntaxSession<'un
         let has_cache = unsafe { self.next_chunk_cursor.cache() }.is_some();
 
         if has_cache {
+            let anchor = self.next_chunk_entry();
             let cache = unsafe { self.next_chunk_cursor.release_cache() };
 
-            cache.free(self.refs, self.watcher)
+            cache.free(anchor, self.refs, self.watcher)
         }
 
         unsafe { self.next_chunk_cursor.next() };
@@ -304,61 +314,29 @@ impl<'unit, N: Node, W: Watcher> SyntaxSession<'unit> for MutableSyntaxSession<'
             return NodeRef::nil();
         }
 
-        if let Some(cache) = unsafe { self.next_chunk_cursor.cache() } {
-            if cache.errors.is_empty() && cache.rule == rule {
-                let (end_site, end_chunk_cursor) =
-                    unsafe { cache.jump_to_end(self.tree, self.refs) };
-
-                self.pending.lookahead_end_site = self
-                    .pending
-                    .lookahead_end_site
-                    .max(end_site + cache.lookahead);
-                self.pending.leftmost = false;
-
-                self.next_chunk_cursor = end_chunk_cursor;
-                self.next_site = end_site;
-                self.peek_chunk_cursor = end_chunk_cursor;
-                self.peek_distance = 0;
-                self.peek_site = end_site;
-                self.peek_caches = 0;
-
-                {
-                    let parent_ref = self.node_ref();
-
-                    unsafe { self.refs.nodes.get_unchecked_mut(cache.primary_node) }
-                        .set_parent_ref(parent_ref);
-                }
-
-                let result = NodeRef {
-                    id: self.id(),
-                    entry: unsafe { self.refs.nodes.entry_of_unchecked(cache.primary_node) },
-                };
-
-                self.watcher.report_node(&result);
-
-                return result;
-            }
-
-            let _ = cache;
-
-            let cache = unsafe { self.next_chunk_cursor.release_cache() };
-
-            cache.free(self.refs, self.watcher);
-        };
+        if let Some(result) = self.reuse_cache(rule) {
+            return result;
+        }
 
         let inner_start_cursor = self.next_chunk_cursor;
+        let inner_start_site = self.next_site;
+        let anchor = self.next_chunk_entry();
+        let overlap_end_site = self.pending.overlap_end();
 
-        let entry_index = self.refs.nodes.reserve_entry();
+        let (entry_index, restoring) = self.reserve_primary(rule);
         let entry = unsafe { self.refs.nodes.entry_of_unchecked(entry_index) };
 
         let outer = replace(
             &mut self.pending,
             Pending {
                 lookahead_end_site: self.next_site,
+                overlap_end_site,
                 leftmost: true,
                 primary_node: entry_index,
                 secondary_nodes: Vec::new(),
                 errors: Vec::new(),
+                descended: Vec::new(),
+                restoring,
             },
         );
 
@@ -369,6 +347,12 @@ impl<'unit, N: Node, W: Watcher> SyntaxSession<'unit> for MutableSyntaxSession<'
 
         self.watcher.report_node(&node_ref);
 
+        self.report.nodes.push(node_ref);
+
+        if let Some(sink) = self.instrumentation.sink() {
+            sink.on_reparse(&node_ref, rule, false);
+        }
+
         self.context.push(entry);
 
         let node = N::parse(self, rule);
@@ -383,6 +367,8 @@ impl<'unit, N: Node, W: Watcher> SyntaxSession<'unit> for MutableSyntaxSession<'
 
         let inner = replace(&mut self.pending, outer);
 
+        self.pending.descended.push((entry_index, anchor));
+
         self.pending.lookahead_end_site = self
             .pending
             .lookahead_end_site
@@ -391,7 +377,10 @@ impl<'unit, N: Node, W: Watcher> SyntaxSession<'unit> for MutableSyntaxSession<'
         let parse_end = self.parse_end();
         let next_site = self.next_site;
 
-        let cache = unsafe { inner.into_cache(self.refs, rule, node, parse_end, next_site) };
+        let overlap = inner.overlap_end_site.saturating_sub(inner_start_site);
+
+        let cache =
+            unsafe { inner.into_cache(self.refs, rule, node, parse_end, next_site, overlap) };
 
         unsafe { inner_start_cursor.install_cache(cache) };
 
@@ -399,8 +388,8 @@ impl<'unit, N: Node, W: Watcher> SyntaxSession<'unit> for MutableSyntaxSession<'
     }
 
     #[inline(always)]
-    fn enter(&mut self, _rule: NodeRule) -> NodeRef {
-        let entry_index = self.refs.nodes.reserve_entry();
+    fn enter(&mut self, rule: NodeRule) -> NodeRef {
+        let entry_index = self.reserve_secondary(rule);
 
         self.pending.secondary_nodes.push(entry_index);
 
@@ -536,7 +525,7 @@ impl<'unit, N: Node, W: Watcher> SyntaxSession<'unit> for MutableSyntaxSession<'
 
         self.failing = true;
 
-        let entry_index = self.refs.errors.insert_raw(error);
+        let entry_index = self.insert_error(error);
 
         self.pending.errors.push(entry_index);
 
@@ -562,10 +551,13 @@ impl<'unit, N: Node, W: Watcher> MutableSyntaxSession<'unit, N, W> {
         tree: &'unit mut Tree<N>,
         refs: &'unit mut TreeRefs<N>,
         watcher: &'unit mut W,
+        report: &'unit mut ReparseReport,
+        instrumentation: Instrumentation,
         start: Site,
         head: ChildCursor<N>,
         rule: NodeRule,
         primary_node: EntryIndex,
+        overlap: Length,
     ) -> (Cache, Site) {
         if is_void_syntax::<N>() {
             unsafe { ld_unreachable!("An attempt to reparse void syntax") }
@@ -601,22 +593,67 @@ impl<'unit, N: Node, W: Watcher> MutableSyntaxSession<'unit, N, W> {
             context
         };
 
+        // The cluster reparsed in place keeps its primary node, but it can
+        // reuse the secondary nodes and the errors that the reverted edit
+        // has removed from this cluster.
+        let mut restored = BTreeSet::new();
+
+        let restoring = match &refs.restoring {
+            None => None,
+
+            Some(restoring) => {
+                let anchor = match rule == ROOT_RULE {
+                    true => Entry::nil(),
+                    false => unsafe { refs.chunks.entry_of_unchecked(head.chunk_entry_index()) },
+                };
+
+                let key = (anchor, rule);
+                let node_entry = unsafe { refs.nodes.entry_of_unchecked(primary_node) };
+
+                match restoring.clusters.get(&key) {
+                    Some(cluster) if cluster.primary_node == node_entry => {
+                        let _ = restored.insert(key);
+
+                        Some(Box::new(Restoring::new(key)))
+                    }
+                    _ => None,
+                }
+            }
+        };
+
         let pending = Pending {
             lookahead_end_site: start,
+            overlap_end_site: start + overlap,
             leftmost: rule != ROOT_RULE,
             primary_node,
             secondary_nodes: Vec::new(),
             errors: Vec::new(),
+            descended: Vec::new(),
+            restoring,
         };
 
         let length = tree.code_length();
 
+        let node_ref = NodeRef {
+            id: refs.id,
+            entry: unsafe { refs.nodes.entry_of_unchecked(primary_node) },
+        };
+
+        report.nodes.push(node_ref);
+
+        if let Some(sink) = instrumentation.sink() {
+            sink.on_reparse(&node_ref, rule, false);
+        }
+
         let mut session = Self {
             tree,
             refs,
             watcher,
+            report,
+            instrumentation,
             context,
             pending,
+            restored,
             failing: false,
             next_chunk_cursor: head,
             next_site: start,
@@ -634,11 +671,190 @@ impl<'unit, N: Node, W: Watcher> MutableSyntaxSession<'unit, N, W> {
         let parsed_end_site = session.next_site;
         let refs = session.refs;
 
-        let cache = unsafe { pending.into_cache(refs, rule, node, parse_end, parsed_end_site) };
+        let cache =
+            unsafe { pending.into_cache(refs, rule, node, parse_end, parsed_end_site, overlap) };
 
         (cache, parsed_end_site)
     }
 
+    // Reuses the cache of the next cluster if the cluster is compatible with
+    // the descending rule. Otherwise releases the cache.
+    //
+    // Kept out of the `descend` function to reduce the stack frame of
+    // the recursive descent.
+    #[inline(never)]
+    fn reuse_cache(&mut self, rule: NodeRule) -> Option<NodeRef> {
+        let cache = unsafe { self.next_chunk_cursor.cache() }?;
+
+        let overlap = self.pending.overlap_end().saturating_sub(self.next_site);
+
+        if cache.errors.is_empty() && cache.rule == rule && cache.overlap >= overlap {
+            let anchor = self.next_chunk_entry();
+
+            self.pending.descended.push((cache.primary_node, anchor));
+
+            let (end_site, end_chunk_cursor) = unsafe { cache.jump_to_end(self.tree, self.refs) };
+
+            self.pending.lookahead_end_site = self
+                .pending
+                .lookahead_end_site
+                .max(end_site + cache.lookahead);
+            self.pending.leftmost = false;
+
+            self.next_chunk_cursor = end_chunk_cursor;
+            self.next_site = end_site;
+            self.peek_chunk_cursor = end_chunk_cursor;
+            self.peek_distance = 0;
+            self.peek_site = end_site;
+            self.peek_caches = 0;
+
+            let result = NodeRef {
+                id: self.id(),
+                entry: unsafe { self.refs.nodes.entry_of_unchecked(cache.primary_node) },
+            };
+
+            {
+                let parent_ref = self.node_ref();
+
+                let node = unsafe { self.refs.nodes.get_unchecked_mut(cache.primary_node) };
+
+                let old_parent_ref = node.parent_ref();
+
+                node.set_parent_ref(parent_ref);
+
+                // The node variants without the parent field always return
+                // a nil parent reference. The reparser does not track
+                // the parents of such nodes.
+                let tracks_parent = node.parent_ref() == parent_ref;
+
+                if tracks_parent && old_parent_ref != parent_ref {
+                    self.report.transplants.push(Transplant {
+                        node_ref: result,
+                        old_parent_ref,
+                        new_parent_ref: parent_ref,
+                    });
+                }
+            }
+
+            self.watcher.report_node(&result);
+
+            self.report.reused += 1;
+
+            if let Some(sink) = self.instrumentation.sink() {
+                sink.on_reparse(&result, rule, true);
+            }
+
+            return Some(result);
+        }
+
+        let _ = cache;
+
+        let anchor = self.next_chunk_entry();
+        let cache = unsafe { self.next_chunk_cursor.release_cache() };
+
+        cache.free(anchor, self.refs, self.watcher);
+
+        None
+    }
+
+    // Reserves an entry for the primary node of a new cluster.
+    //
+    // If the current edit reverts an edit that has removed a cluster of
+    // the same rule at the same chunk, reuses the entry of the removed
+    // primary node and returns the restoring state of the new cluster.
+    //
+    // Kept out of the `descend` function to reduce the stack frame of
+    // the recursive descent.
+    #[inline(never)]
+    fn reserve_primary(&mut self, rule: NodeRule) -> (EntryIndex, Option<Box<Restoring>>) {
+        if let Some(restoring) = &self.refs.restoring {
+            let key = (self.next_chunk_entry(), rule);
+
+            if let Some(cluster) = restoring.clusters.get(&key) {
+                if !self.restored.contains(&key) && self.refs.nodes.claim(&cluster.primary_node) {
+                    let _ = self.restored.insert(key);
+
+                    return (
+                        cluster.primary_node.index,
+                        Some(Box::new(Restoring::new(key))),
+                    );
+                }
+            }
+        }
+
+        (self.refs.nodes.reserve_entry(), None)
+    }
+
+    // Reserves an entry for the secondary node of the current cluster.
+    //
+    // Reuses the entry of the removed cluster's secondary node while
+    // the rules of the secondary nodes follow the removed ones.
+    #[inline(never)]
+    fn reserve_secondary(&mut self, rule: NodeRule) -> EntryIndex {
+        if let Some(cluster) = self.restoring_cluster() {
+            if let Some(restoring) = &mut self.pending.restoring {
+                if let Some((discarded_rule, entry)) = cluster.secondary_nodes.get(restoring.nodes)
+                {
+                    if *discarded_rule == rule && self.refs.nodes.claim(entry) {
+                        restoring.nodes += 1;
+
+                        return entry.index;
+                    }
+                }
+
+                restoring.nodes = usize::MAX;
+            }
+        }
+
+        self.refs.nodes.reserve_entry()
+    }
+
+    // Inserts the syntax error of the current cluster.
+    //
+    // Reuses the entry of the removed cluster's error while the errors
+    // follow the removed ones.
+    //
+    // Kept out of the `failure` function to reduce the stack frames of
+    // the parse functions.
+    #[inline(never)]
+    fn insert_error(&mut self, error: SyntaxError) -> EntryIndex {
+        if let Some(cluster) = self.restoring_cluster() {
+            if let Some(restoring) = &mut self.pending.restoring {
+                if let Some((discarded_error, entry)) = cluster.errors.get(restoring.errors) {
+                    if discarded_error == &error && self.refs.errors.claim(entry) {
+                        restoring.errors += 1;
+
+                        unsafe { self.refs.errors.set_unchecked(entry.index, error) };
+
+                        return entry.index;
+                    }
+                }
+
+                restoring.errors = usize::MAX;
+            }
+        }
+
+        self.refs.errors.insert_raw(error)
+    }
+
+    #[inline(always)]
+    fn restoring_cluster(&self) -> Option<&'unit DiscardedCluster> {
+        let key = &self.pending.restoring.as_ref()?.key;
+
+        let cluster = self.refs.restoring.as_ref()?.clusters.get(key)?;
+
+        // Safety: The restoring components are not changed during the session.
+        Some(unsafe { &*(cluster as *const DiscardedCluster) })
+    }
+
+    // Safety: `self.next_chunk_cursor` is not dangling.
+    #[inline(always)]
+    fn next_chunk_entry(&self) -> Entry {
+        let chunk_entry_index = unsafe { self.next_chunk_cursor.chunk_entry_index() };
+
+        unsafe { self.refs.chunks.entry_of_unchecked(chunk_entry_index) }
+    }
+
     #[inline(always)]
     fn parse_end(&self) -> SiteRef {
         match self.next_chunk_cursor.is_dangling() {
@@ -677,6 +893,7 @@ impl<'unit, N: Node, W: Watcher> MutableSyntaxSession<'unit, N, W> {
                 self.peek_distance = 0;
                 self.peek_site = self.next_site;
                 self.peek_chunk_cursor = self.next_chunk_cursor;
+                self.peek_caches = 0;
                 return true;
             }
         }
@@ -726,32 +943,77 @@ impl<'unit, N: Node, W: Watcher> MutableSyntaxSession<'unit, N, W> {
 
 struct Pending {
     lookahead_end_site: Site,
+    overlap_end_site: Site,
     leftmost: bool,
     primary_node: EntryIndex,
     secondary_nodes: Vec<EntryIndex>,
     errors: Vec<EntryIndex>,
+    // The primary nodes of the clusters that this cluster has descended into,
+    // and the entries of the chunks to which their caches are attached.
+    descended: Vec<(EntryIndex, Entry)>,
+    // The state of reusing the entries of the removed cluster. Boxed to
+    // reduce the stack frame of the recursive descent.
+    restoring: Option<Box<Restoring>>,
+}
+
+// The state of reusing the entries of the removed cluster's secondary nodes
+// and errors in the order of their creation.
+struct Restoring {
+    // The key of the removed cluster.
+    key: (Entry, NodeRule),
+    nodes: usize,
+    errors: usize,
+}
+
+impl Restoring {
+    #[inline(always)]
+    fn new(key: (Entry, NodeRule)) -> Self {
+        Self {
+            key,
+            nodes: 0,
+            errors: 0,
+        }
+    }
 }
 
 impl Pending {
+    // Returns the site up to which this cluster and the enclosing clusters
+    // looked at the tokens.
+    #[inline(always)]
+    fn overlap_end(&self) -> Site {
+        self.lookahead_end_site.max(self.overlap_end_site)
+    }
+
     // Safety: `self.primary_node` points to occupied or reserved node.
     #[inline(always)]
     unsafe fn into_cache<N: Node>(
-        self,
+        mut self,
         refs: &mut TreeRefs<N>,
         rule: NodeRule,
         node: N,
         parse_end: SiteRef,
         parse_end_site: Site,
+        overlap: Length,
     ) -> Cache {
         unsafe { refs.nodes.set_unchecked(self.primary_node, node) };
 
-        Cache {
+        let mut cache = Cache {
             rule,
             parse_end,
             lookahead: self.lookahead_end_site - parse_end_site,
+            overlap,
             primary_node: self.primary_node,
             secondary_nodes: self.secondary_nodes,
             errors: self.errors,
-        }
+            children: 0,
+            depth: 0,
+            nested: Vec::new(),
+        };
+
+        self.descended.sort_unstable_by_key(|(primary_node, _)| *primary_node);
+
+        unsafe { cache.measure(refs, &self.descended) };
+
+        cache
     }
 }
@@ -32,14 +32,24 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::{
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "serde")]
+use core::iter::once;
+use core::{
     fmt::{Debug, Display, Formatter},
     mem::{replace, take, transmute_copy},
 };
 
+#[cfg(feature = "std")]
+use crate::format::SnippetFormatter;
+#[cfg(feature = "serde")]
+use crate::{
+    arena::Repo,
+    lexis::{SiteRefInner, TokenRef},
+    units::persist::ClusterImage,
+};
 use crate::{
     arena::{Entry, EntryIndex, Id, Identifiable},
-    format::SnippetFormatter,
     lexis::{
         Length,
         LineIndex,
@@ -52,28 +62,34 @@ use crate::{
         TokenBuffer,
         TokenCount,
         CHUNK_SIZE,
+        EOI,
     },
     report::{ld_assert, ld_assert_eq, ld_unreachable, system_panic},
     syntax::{
         is_void_syntax,
+        ErrorRef,
         Node,
         NodeRef,
         SyntaxError,
         SyntaxTree,
+        TreeStats,
         VoidSyntax,
         NON_RULE,
         ROOT_RULE,
     },
     units::{
         mutable::{
-            cursor::MutableCursor,
+            cursor::{MutableBackCursor, MutableCursor},
+            history::{Checkpoint, Discarded, DiscardedChunk, History},
             iters::{MutableCharIter, MutableErrorIter, MutableNodeIter},
-            lexis::{MutableLexisSession, SessionOutput},
+            lexis::{line_start, previous_rule, MutableLexisSession, SessionOutput, SessionState},
+            reparse::ReparseReport,
             syntax::MutableSyntaxSession,
             watcher::VoidWatcher,
         },
         storage::{Cache, ChildCursor, Tree, TreeRefs},
         CompilationUnit,
+        Instrumentation,
         Watcher,
     },
 };
@@ -95,6 +111,9 @@ pub struct MutableUnit<N: Node> {
     refs: TreeRefs<N>,
     lines: LineIndex,
     tokens: TokenCount,
+    last_reparse: ReparseReport,
+    instrumentation: Instrumentation,
+    history: History<N>,
 }
 
 // Safety: Tree instance stores data on the heap, and the References instance
@@ -112,13 +131,14 @@ impl<N: Node> Drop for MutableUnit<N> {
     fn drop(&mut self) {
         unsafe { self.tree.free() };
 
+        #[cfg(feature = "std")]
         self.id().clear_name();
     }
 }
 
 impl<N: Node> Debug for MutableUnit<N> {
     #[inline]
-    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
         formatter
             .debug_struct("MutableUnit")
             .field("id", &self.id())
@@ -129,11 +149,19 @@ impl<N: Node> Debug for MutableUnit<N> {
 
 impl<N: Node> Display for MutableUnit<N> {
     #[inline(always)]
-    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
-        formatter
-            .snippet(self)
-            .set_caption(format!("MutableUnit({})", self.id()))
-            .finish()
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        #[cfg(feature = "std")]
+        {
+            formatter
+                .snippet(self)
+                .set_caption(format!("MutableUnit({})", self.id()))
+                .finish()
+        }
+
+        #[cfg(not(feature = "std"))]
+        {
+            formatter.write_str(self.substring(..).as_ref())
+        }
     }
 }
 
@@ -149,6 +177,8 @@ impl<N: Node> SourceCode for MutableUnit<N> {
 
     type Cursor<'code> = MutableCursor<'code, N>;
 
+    type BackCursor<'code> = MutableBackCursor<'code, N>;
+
     type CharIterator<'code> = MutableCharIter<'code, N>;
 
     fn chars(&self, span: impl ToSpan) -> Self::CharIterator<'_> {
@@ -220,6 +250,17 @@ impl<N: Node> SourceCode for MutableUnit<N> {
         Self::Cursor::new(self, span)
     }
 
+    #[inline(always)]
+    fn cursor_back(&self, span: impl ToSpan) -> Self::BackCursor<'_> {
+        let span = match span.to_site_span(self) {
+            None => panic!("Specified span is invalid."),
+
+            Some(span) => span,
+        };
+
+        Self::BackCursor::new(self, span)
+    }
+
     #[inline(always)]
     fn length(&self) -> Length {
         ld_assert_eq!(
@@ -313,6 +354,34 @@ impl<N: Node> SyntaxTree for MutableUnit<N> {
     fn get_error(&self, entry: &Entry) -> Option<&SyntaxError> {
         self.refs.errors.get(entry)
     }
+
+    // The node, children, rule, error, and token counts are maintained by
+    // the reparser. The depth of the tree is combined from the depths of
+    // the clusters reachable from the root cluster, and the heap size is
+    // summed over the storage pages.
+    fn stats(&self) -> TreeStats<Self::Node> {
+        let mut stats = TreeStats::empty();
+
+        stats.nodes = self.refs.nodes.len();
+        stats.rules = self.refs.rules.clone();
+        stats.children = self.refs.children;
+        stats.errors = self.refs.errors.len();
+        stats.max_depth = match self.clusters_depth() {
+            Some(depth) => depth,
+            None => TreeStats::measure_depth(self),
+        };
+        stats.tokens = self.tokens;
+        stats.text_bytes = self.lines.byte_of(self.tree.code_length());
+        stats.approx_heap_bytes = self.tree.heap_size()
+            + self.root.as_ref().map(Cache::heap_size).unwrap_or_default()
+            + self.refs.chunks.heap_size()
+            + self.refs.nodes.heap_size()
+            + self.refs.errors.heap_size()
+            + self.lines.heap_size()
+            + self.history.heap_size();
+
+        stats
+    }
 }
 
 impl<N: Node> Default for MutableUnit<N> {
@@ -329,6 +398,9 @@ impl<N: Node> Default for MutableUnit<N> {
             refs,
             lines: LineIndex::new(),
             tokens: 0,
+            last_reparse: ReparseReport::default(),
+            instrumentation: Instrumentation::none(),
+            history: History::default(),
         }
     }
 }
@@ -375,6 +447,66 @@ impl<N: Node> CompilationUnit for MutableUnit<N> {
     fn into_mutable_unit(self) -> MutableUnit<N> {
         self
     }
+
+    fn visit_errors_in(&self, span: &SiteSpan, visitor: &mut impl FnMut(&ErrorRef) -> bool) {
+        let id = self.id();
+
+        let mut visit_cache = |cache: &Cache| {
+            for index in &cache.errors {
+                let error_ref = ErrorRef {
+                    id,
+                    entry: unsafe { self.refs.errors.entry_of_unchecked(*index) },
+                };
+
+                if !visitor(&error_ref) {
+                    return false;
+                }
+            }
+
+            true
+        };
+
+        let Some(root) = &self.root else {
+            unsafe { ld_unreachable!("Root cache unset.") };
+        };
+
+        // The root cluster covers the entire source code.
+        if !visit_cache(root) {
+            return;
+        }
+
+        let mut site = 0;
+        let mut chunk_cursor = self.tree.first();
+
+        while !chunk_cursor.is_dangling() && site <= span.end {
+            if let Some(cache) = unsafe { chunk_cursor.cache() } {
+                match unsafe { cache.end_site(&self.tree, &self.refs) } {
+                    // The lookahead of the cluster covers the lookahead of
+                    // its nested clusters, so the cluster that does not reach
+                    // the span is skipped together with its nested clusters.
+                    Some(end_site) if end_site + cache.lookahead < span.start => {
+                        if end_site > site {
+                            (site, chunk_cursor) =
+                                unsafe { cache.jump_to_end(&self.tree, &self.refs) };
+                            continue;
+                        }
+                    }
+
+                    // The parser of the cluster could report errors in
+                    // the tokens that it looked ahead.
+                    _ => {
+                        if !visit_cache(cache) {
+                            return;
+                        }
+                    }
+                }
+            }
+
+            site += unsafe { *chunk_cursor.span() };
+
+            unsafe { chunk_cursor.next() }
+        }
+    }
 }
 
 impl<N: Node> MutableUnit<N> {
@@ -383,35 +515,11 @@ impl<N: Node> MutableUnit<N> {
     /// The parameter could be a [TokenBuffer] or just an arbitrary string.
     #[inline(always)]
     pub fn new(text: impl Into<TokenBuffer<N::Token>>) -> Self {
-        let mut buffer = text.into();
+        let mut unit = Self::unparsed(Id::new(), text.into());
 
-        let count = buffer.tokens();
-        let spans = take(&mut buffer.spans).into_iter();
-        let indices = take(&mut buffer.indices).into_iter();
-        let tokens = take(&mut buffer.tokens).into_iter();
-        let lines = take(&mut buffer.lines);
-        let mut refs = TreeRefs::with_capacity(Id::new(), count);
+        unit.root = Some(MutableUnit::initial_parse(&mut unit.tree, &mut unit.refs));
 
-        let mut tree = unsafe {
-            Tree::from_chunks(
-                &mut refs,
-                count,
-                spans,
-                indices,
-                tokens,
-                buffer.text.as_str(),
-            )
-        };
-
-        let root = MutableUnit::initial_parse(&mut tree, &mut refs);
-
-        Self {
-            root: Some(root),
-            tree,
-            refs,
-            lines,
-            tokens: count,
-        }
+        unit
     }
 
     /// Writes user-input edit into this unit.
@@ -449,14 +557,145 @@ impl<N: Node> MutableUnit<N> {
         };
 
         let text = text.as_ref();
+        let scan = self.begin_scan(span, text, usize::MAX);
 
-        if span.is_empty() && text.is_empty() {
+        let Some(update) = self.begin_write(watcher, scan, text) else {
             return;
+        };
+
+        //todo consider removing Self::update_syntax return as it is currently unused
+        let _entry = self.update_syntax(watcher, update);
+    }
+
+    // Starts rescanning of the tokens affected by the edit that replaces
+    // the `span` with the `text`, and scans about `budget` bytes of
+    // the affected text.
+    //
+    // The unit remains unchanged until the scanned edit is applied by
+    // the `begin_write` function.
+    pub(crate) fn begin_scan(&self, span: SiteSpan, text: &str, budget: usize) -> LexisUpdate<N> {
+        if span.is_empty() && text.is_empty() {
+            return LexisUpdate {
+                edit: span.clone(),
+                span,
+                head: ChildCursor::dangling(),
+                tail: ChildCursor::dangling(),
+                state: None,
+                scanned: true,
+            };
+        }
+
+        let (input, rescan, head, tail) = self.lexis_input(span.clone(), text);
+
+        if input.is_empty() {
+            return LexisUpdate {
+                edit: span,
+                span: rescan,
+                head,
+                tail,
+                state: None,
+                scanned: true,
+            };
+        }
+
+        let (previous, line_start) = match head.is_dangling() {
+            true => (EOI, true),
+
+            false => {
+                let mut previous = head;
+
+                unsafe { previous.back() };
+
+                unsafe { (previous_rule(previous), line_start(previous)) }
+            }
+        };
+
+        let mut state = SessionState::new(text.len() / CHUNK_SIZE + 2, tail, previous, line_start);
+
+        // Safety: The input is not empty, and the state is created for
+        // the input's context.
+        let scanned = unsafe { MutableLexisSession::resume(&mut state, &input, budget) };
+
+        LexisUpdate {
+            edit: span,
+            span: rescan,
+            head,
+            tail,
+            state: Some(state),
+            scanned,
+        }
+    }
+
+    // Continues rescanning of the pending edit by about `budget` bytes of
+    // the affected text.
+    //
+    // Returns true if the edit is fully scanned.
+    pub(crate) fn resume_scan(
+        &self,
+        update: &mut LexisUpdate<N>,
+        text: &str,
+        budget: usize,
+    ) -> bool {
+        if update.scanned {
+            return true;
+        }
+
+        let Some(state) = &mut update.state else {
+            return true;
+        };
+
+        let (input, ..) = self.lexis_input(update.edit.clone(), text);
+        let limit = state.scanned().saturating_add(budget);
+
+        // Safety: The unit has not been changed since the beginning of
+        // the scan, so the input is the same.
+        update.scanned = unsafe { MutableLexisSession::resume(state, &input, limit) };
+
+        update.scanned
+    }
+
+    // Applies the scanned edit to the source code text and the lexical
+    // structure of this unit, and returns the pending update of the syntax
+    // tree.
+    //
+    // Until the update is finished by the `resume_write` function, the syntax
+    // tree of this unit is not consistent with its text.
+    pub(crate) fn begin_write(
+        &mut self,
+        watcher: &mut impl Watcher,
+        mut scan: LexisUpdate<N>,
+        text: &str,
+    ) -> Option<SyntaxUpdate<N>> {
+        self.last_reparse = ReparseReport::default();
+
+        let span = scan.edit.clone();
+
+        if span.is_empty() && text.is_empty() {
+            return None;
+        }
+
+        while !self.resume_scan(&mut scan, text, usize::MAX) {}
+
+        self.flush_discarded();
+
+        if self.history.is_recording() {
+            let inverse = span.start..(span.start + text.chars().count());
+            let replaced = self.substring(span.clone()).into_owned();
+
+            self.history.record(inverse, replaced);
+
+            self.refs.discarded = Some(Discarded::default());
         }
 
         unsafe { self.lines.write_unchecked(span.clone(), text) };
 
-        let cover = self.update_lexis(watcher, span, text);
+        let cover = self.update_lexis(watcher, scan);
+
+        if self.refs.restoring.is_some() {
+            self.restore_chunks(&cover);
+        }
+
+        self.last_reparse.rescanned = cover.span.clone();
 
         ld_assert_eq!(
             self.tree.code_length(),
@@ -465,11 +704,142 @@ impl<N: Node> MutableUnit<N> {
         );
 
         if is_void_syntax::<N>() {
-            return;
+            return None;
         }
 
-        //todo consider removing Self::update_syntax return as it is currently unused
-        let _entry = self.update_syntax(watcher, cover);
+        Some(SyntaxUpdate {
+            cover,
+            reparsed: false,
+        })
+    }
+
+    // Performs a single reparse iteration of the pending syntax tree update.
+    //
+    // Returns true if the syntax tree is up to date.
+    #[inline(always)]
+    pub(crate) fn resume_write(
+        &mut self,
+        watcher: &mut impl Watcher,
+        update: &mut SyntaxUpdate<N>,
+    ) -> bool {
+        self.update_syntax_step(watcher, update).is_some()
+    }
+
+    /// Creates a checkpoint of the edit history of this unit.
+    ///
+    /// See [Document::checkpoint](crate::units::Document::checkpoint) for
+    /// details.
+    #[inline(always)]
+    pub fn checkpoint(&self) -> Checkpoint {
+        self.history.checkpoint(self.id())
+    }
+
+    /// Restores the source code text of this unit to the state
+    /// of the `checkpoint`.
+    ///
+    /// See [Document::revert](crate::units::Document::revert) for details.
+    ///
+    /// **Panic**
+    ///
+    /// Panics if the checkpoint does not belong to this unit, or if
+    /// the history of this unit has been cleared after the checkpoint
+    /// creation.
+    pub fn revert(&mut self, checkpoint: &Checkpoint) {
+        let range = match checkpoint.id == self.id() {
+            true => self.history.since(checkpoint),
+            false => None,
+        };
+
+        let Some((from, to)) = range else {
+            panic!("Specified checkpoint is invalid.");
+        };
+
+        self.flush_discarded();
+
+        for index in (from..to).rev() {
+            let (span, text) = self.history.get(index);
+
+            let discarded = self.history.take_discarded(index);
+
+            // The claimed entries cannot be occupied by the unrelated
+            // components, but the reverting edit reuses them for
+            // the identical components.
+            let chunks = self.refs.chunks.reserve_vacant(discarded.chunk_entries());
+            let nodes = self.refs.nodes.reserve_vacant(discarded.node_entries());
+            let errors = self.refs.errors.reserve_vacant(discarded.error_entries());
+
+            self.refs.restoring = Some(discarded);
+
+            self.write(span, text);
+
+            if let Some(discarded) = self.refs.restoring.take() {
+                self.history.restore(index, discarded);
+            }
+
+            // Safety: The claimed entries that are still Reserved have not
+            //         been reused.
+            for entry in chunks {
+                if self.refs.chunks.is_reserved(&entry) {
+                    let _ = unsafe { self.refs.chunks.remove_unchecked(entry.index) };
+                }
+            }
+
+            for entry in nodes {
+                if self.refs.nodes.is_reserved(&entry) {
+                    let _ = unsafe { self.refs.nodes.remove_unchecked(entry.index) };
+                }
+            }
+
+            for entry in errors {
+                if self.refs.errors.is_reserved(&entry) {
+                    let _ = unsafe { self.refs.errors.remove_unchecked(entry.index) };
+                }
+            }
+        }
+
+        // The restored entries have the versions of the past, so the new
+        // entries must not reuse the versions of the reverted edits.
+        self.refs.chunks.commit(true);
+        self.refs.nodes.commit(true);
+        self.refs.errors.commit(true);
+    }
+
+    /// Discards the edit history of this unit.
+    ///
+    /// See [Document::clear_history](crate::units::Document::clear_history)
+    /// for details.
+    #[inline(always)]
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+        self.refs.discarded = None;
+    }
+
+    /// Returns a summary of the syntax tree incremental reparsing caused by
+    /// the latest edit of this unit.
+    ///
+    /// See [Document::last_reparse](crate::units::Document::last_reparse)
+    /// for details.
+    #[inline(always)]
+    pub fn last_reparse(&self) -> &ReparseReport {
+        &self.last_reparse
+    }
+
+    /// Returns the [Instrumentation] handle of this unit.
+    ///
+    /// See [Document::set_instrumentation](crate::units::Document::set_instrumentation)
+    /// for details.
+    #[inline(always)]
+    pub fn instrumentation(&self) -> Instrumentation {
+        self.instrumentation
+    }
+
+    /// Sets the [Instrumentation] handle of this unit.
+    ///
+    /// See [Document::set_instrumentation](crate::units::Document::set_instrumentation)
+    /// for details.
+    #[inline(always)]
+    pub fn set_instrumentation(&mut self, instrumentation: Instrumentation) {
+        self.instrumentation = instrumentation;
     }
 
     #[inline(always)]
@@ -482,12 +852,323 @@ impl<N: Node> MutableUnit<N> {
         &self.refs
     }
 
-    fn update_lexis(
+    // Returns the length of the longest path from the root node to a leaf
+    // node of the syntax tree walking through the clusters' caches.
+    //
+    // Returns None if a cluster refers to a nested cluster that is no longer
+    // attached to the recorded chunk.
+    fn clusters_depth(&self) -> Option<usize> {
+        let mut max_depth = 0;
+        let mut stack = Vec::from([(0, self.root.as_ref()?)]);
+
+        while let Some((offset, cache)) = stack.pop() {
+            max_depth = max_depth.max(offset + cache.depth);
+
+            for nested in &cache.nested {
+                let chunk_cursor = self.refs.chunks.get(&nested.anchor)?;
+
+                let nested_cache = unsafe { chunk_cursor.cache() }?;
+
+                if nested_cache.primary_node != nested.primary_node {
+                    return None;
+                }
+
+                stack.push((offset + nested.depth, nested_cache));
+            }
+        }
+
+        Some(max_depth)
+    }
+
+    // Creates a MutableUnit from the tokens of the `buffer` without
+    // the syntax tree.
+    fn unparsed(id: Id, mut buffer: TokenBuffer<N::Token>) -> Self {
+        let count = buffer.tokens();
+        let spans = take(&mut buffer.spans).into_iter();
+        let indices = take(&mut buffer.indices).into_iter();
+        let tokens = take(&mut buffer.tokens).into_iter();
+        let lines = take(&mut buffer.lines);
+        let mut refs = TreeRefs::with_capacity(id, count);
+
+        let tree = unsafe {
+            Tree::from_chunks(
+                &mut refs,
+                count,
+                spans,
+                indices,
+                tokens,
+                buffer.text.as_str(),
+            )
+        };
+
+        Self {
+            root: None,
+            tree,
+            refs,
+            lines,
+            tokens: count,
+            last_reparse: ReparseReport::default(),
+            instrumentation: Instrumentation::none(),
+            history: History::default(),
+        }
+    }
+
+    // Creates a MutableUnit from the token buffer and the components of
+    // the syntax tree without parsing.
+    //
+    // The `chunks` are the entries of the buffer's tokens in order. The first
+    // item of the `clusters` is the root cluster.
+    //
+    // Returns None if the components do not form a syntax tree storage of
+    // the unit.
+    #[cfg(feature = "serde")]
+    pub(crate) fn restore(
+        id: Id,
+        buffer: TokenBuffer<N::Token>,
+        chunks: Vec<Entry>,
+        nodes: Vec<(Entry, N)>,
+        clusters: Vec<ClusterImage>,
+    ) -> Option<Self> {
+        if chunks.len() != buffer.tokens() {
+            return None;
+        }
+
+        let mut unit = Self::unparsed(id, buffer);
+
+        let cursors = unit.refs.chunks.iter().copied().collect::<Vec<_>>();
+
+        unit.refs.chunks = Repo::restore(chunks.iter().copied().zip(cursors.iter().copied()))?;
+
+        for (entry, cursor) in chunks.iter().zip(&cursors) {
+            unsafe { cursor.set_chunk_entry_index(entry.index) };
+        }
+
+        unit.refs.nodes = Repo::restore(nodes)?;
+
+        // Each node belongs to exactly one cluster.
+        {
+            let mut members = clusters
+                .iter()
+                .flat_map(|cluster| once(&cluster.primary_node).chain(&cluster.secondary_nodes))
+                .map(|entry| match unit.refs.nodes.contains(entry) {
+                    true => Some(entry.index),
+                    false => None,
+                })
+                .collect::<Option<Vec<_>>>()?;
+
+            if members.len() != unit.refs.nodes.len() {
+                return None;
+            }
+
+            members.sort_unstable();
+            members.dedup();
+
+            if members.len() != unit.refs.nodes.len() {
+                return None;
+            }
+        }
+
+        let mut clusters = clusters.into_iter();
+
+        let root = clusters.next()?;
+
+        if root.anchor.is_some() || root.rule != ROOT_RULE {
+            return None;
+        }
+
+        let mut descended = clusters
+            .as_slice()
+            .iter()
+            .map(|cluster| Some((cluster.primary_node.index, cluster.anchor?)))
+            .collect::<Option<Vec<_>>>()?;
+
+        descended.sort_unstable_by_key(|(primary_node, _)| *primary_node);
+
+        for cluster in clusters {
+            let chunk_cursor = *unit.refs.chunks.get(&cluster.anchor?)?;
+
+            if unsafe { chunk_cursor.cache() }.is_some() {
+                return None;
+            }
+
+            let anchor_site = unsafe { unit.tree.site_of(&chunk_cursor) };
+
+            let cache = unit.restore_cache(cluster, &descended)?;
+
+            if unsafe { cache.end_site(&unit.tree, &unit.refs) }? < anchor_site {
+                return None;
+            }
+
+            unsafe { chunk_cursor.install_cache(cache) };
+        }
+
+        unit.root = Some(unit.restore_cache(root, &descended)?);
+
+        Some(unit)
+    }
+
+    // Returns the images of the clusters of the syntax tree starting with
+    // the root cluster.
+    #[cfg(feature = "serde")]
+    pub(crate) fn cluster_images(&self) -> Vec<ClusterImage> {
+        let mut images = Vec::new();
+
+        let Some(root) = &self.root else {
+            return images;
+        };
+
+        images.push(self.cluster_image(None, root));
+
+        let mut chunk_cursor = self.tree.first();
+
+        while !chunk_cursor.is_dangling() {
+            if let Some(cache) = unsafe { chunk_cursor.cache() } {
+                let anchor = unsafe {
+                    self.refs
+                        .chunks
+                        .entry_of_unchecked(chunk_cursor.chunk_entry_index())
+                };
+
+                images.push(self.cluster_image(Some(anchor), cache));
+            }
+
+            unsafe { chunk_cursor.next() };
+        }
+
+        images
+    }
+
+    #[cfg(feature = "serde")]
+    fn cluster_image(&self, anchor: Option<Entry>, cache: &Cache) -> ClusterImage {
+        ClusterImage {
+            anchor,
+            rule: cache.rule,
+            parse_end: match cache.parse_end.inner() {
+                SiteRefInner::ChunkStart(token_ref) => Some(token_ref.entry),
+                SiteRefInner::CodeEnd(_) => None,
+            },
+            lookahead: cache.lookahead,
+            overlap: cache.overlap,
+            primary_node: unsafe { self.refs.nodes.entry_of_unchecked(cache.primary_node) },
+            secondary_nodes: cache
+                .secondary_nodes
+                .iter()
+                .map(|index| unsafe { self.refs.nodes.entry_of_unchecked(*index) })
+                .collect(),
+        }
+    }
+
+    // Creates a cache of the restored cluster, and adds the cluster's nodes
+    // to the counters of the tree.
+    //
+    // Returns None if the cluster's parse end or rule is not valid.
+    #[cfg(feature = "serde")]
+    fn restore_cache(
         &mut self,
-        watcher: &mut impl Watcher,
+        cluster: ClusterImage,
+        descended: &[(EntryIndex, Entry)],
+    ) -> Option<Cache> {
+        let parse_end = match cluster.parse_end {
+            Some(entry) if self.refs.chunks.contains(&entry) => TokenRef {
+                id: self.id(),
+                entry,
+            }
+            .site_ref(),
+
+            Some(_) => return None,
+
+            None => SiteRef::end_of(self.id()),
+        };
+
+        if self.refs.nodes.get(&cluster.primary_node)?.rule() != cluster.rule {
+            return None;
+        }
+
+        let mut cache = Cache {
+            rule: cluster.rule,
+            parse_end,
+            lookahead: cluster.lookahead,
+            overlap: cluster.overlap,
+            primary_node: cluster.primary_node.index,
+            secondary_nodes: cluster
+                .secondary_nodes
+                .iter()
+                .map(|entry| entry.index)
+                .collect(),
+            errors: Vec::new(),
+            children: 0,
+            depth: 0,
+            nested: Vec::new(),
+        };
+
+        // Safety: The cluster's nodes are Occupied.
+        unsafe { cache.measure(&mut self.refs, descended) };
+
+        Some(cache)
+    }
+
+    // Moves the components discarded by the most recent edit to the history.
+    #[inline(always)]
+    fn flush_discarded(&mut self) {
+        if let Some(discarded) = self.refs.discarded.take() {
+            self.history.attach(discarded);
+        }
+    }
+
+    // Assigns the entries of the chunks removed by the reverted edit to
+    // the identical chunks at the same sites within the `cover`.
+    fn restore_chunks(&mut self, cover: &Cover<N>) {
+        let Some(restoring) = &self.refs.restoring else {
+            return;
+        };
+
+        let mut records = restoring.chunks.iter().peekable();
+        let mut chunk_cursor = cover.chunk_cursor;
+        let mut site = cover.span.start;
+
+        while !chunk_cursor.is_dangling() && site < cover.span.end {
+            let length = unsafe { *chunk_cursor.span() };
+
+            while records.next_if(|record| record.site < site).is_some() {}
+
+            let Some(record) = records.peek() else {
+                break;
+            };
+
+            if record.site == site
+                && record.length == length
+                && record.token == unsafe { chunk_cursor.token() }
+            {
+                let current = unsafe { chunk_cursor.chunk_entry_index() };
+
+                if current == record.entry.index {
+                    // Safety: The chunk entry is Occupied.
+                    unsafe { self.refs.chunks.set_version(current, record.entry.version) };
+                } else if self.refs.chunks.is_reserved(&record.entry) {
+                    let value = unsafe { *self.refs.chunks.get_unchecked(current) };
+
+                    // Safety: The chunk entry is Occupied, and the record's
+                    //         entry is Reserved.
+                    unsafe { self.refs.chunks.set_unchecked(record.entry.index, value) };
+                    let _ = unsafe { self.refs.chunks.remove_unchecked(current) };
+                    unsafe { chunk_cursor.set_chunk_entry_index(record.entry.index) };
+                }
+            }
+
+            site += length;
+
+            unsafe { chunk_cursor.next() };
+        }
+    }
+
+    // Returns the input of the lexical scanner that rescans the tokens
+    // affected by the edit, the site span of the rescanned text in the unit,
+    // the first rescanned chunk, and the first chunk following the input.
+    fn lexis_input<'a>(
+        &self,
         mut span: SiteSpan,
-        text: &str,
-    ) -> Cover<N> {
+        text: &'a str,
+    ) -> (Vec<&'a str>, SiteSpan, ChildCursor<N>, ChildCursor<N>) {
         let mut head;
         let mut lookback;
         let mut tail;
@@ -564,7 +1245,10 @@ impl<N: Node> MutableUnit<N> {
             input.push(text);
         }
 
-        if tail_offset > 0 {
+        // If nothing precedes the tail token, the tail token is rescanned
+        // anyway, because its lexical context (the previous token and
+        // the line start) could change.
+        if tail_offset > 0 || (input.is_empty() && !tail.is_dangling()) {
             ld_assert!(
                 !tail.is_dangling(),
                 "Dangling reference with non-zero offset.",
@@ -579,22 +1263,30 @@ impl<N: Node> MutableUnit<N> {
             unsafe { tail.next() }
         }
 
-        let mut product = match input.is_empty() {
-            false => unsafe { MutableLexisSession::run(text.len() / CHUNK_SIZE + 2, &input, tail) },
+        (input, span, head, tail)
+    }
 
-            true => SessionOutput {
-                length: 0,
-                spans: Vec::new(),
-                indices: Vec::new(),
-                tokens: Vec::new(),
-                text: String::new(),
-                tail,
-                overlap: 0,
-            },
+    fn update_lexis(&mut self, watcher: &mut impl Watcher, scan: LexisUpdate<N>) -> Cover<N> {
+        ld_assert!(scan.scanned, "Unfinished scan.");
+
+        let mut span = scan.span;
+        let mut head = scan.head;
+
+        let mut product = match scan.state {
+            Some(state) => state.into_output(),
+            None => SessionOutput::new(0, scan.tail),
         };
 
         span.end += product.overlap;
 
+        if let Some(sink) = self.instrumentation.sink() {
+            sink.on_rescan(
+                self.refs.id,
+                span.start..(span.start + product.length),
+                product.count(),
+            );
+        }
+
         let mut skip = 0;
 
         loop {
@@ -625,7 +1317,9 @@ impl<N: Node> MutableUnit<N> {
 
             let head_string = unsafe { head.string() };
 
-            if product_string == head_string {
+            let product_token = unsafe { *product.tokens.get_unchecked(skip) };
+
+            if product_string == head_string && product_token == unsafe { head.token() } {
                 let head_span = unsafe { *head.span() };
 
                 span.start += head_span;
@@ -672,7 +1366,12 @@ impl<N: Node> MutableUnit<N> {
 
             let last_string = unsafe { last.string() };
 
-            if product_string == last_string {
+            let product_token = match product.tokens.last() {
+                Some(token) => *token,
+                None => break,
+            };
+
+            if product_string == last_string && product_token == unsafe { last.token() } {
                 let last_span = unsafe { *last.span() };
 
                 span.end -= last_span;
@@ -693,6 +1392,29 @@ impl<N: Node> MutableUnit<N> {
             break;
         }
 
+        if let Some(discarded) = &mut self.refs.discarded {
+            let mut chunk_cursor = head;
+            let mut site = span.start;
+
+            while !chunk_cursor.is_dangling()
+                && !unsafe { chunk_cursor.same_chunk_as(&product.tail) }
+            {
+                let length = unsafe { *chunk_cursor.span() };
+                let chunk_entry_index = unsafe { chunk_cursor.chunk_entry_index() };
+
+                discarded.chunks.push(DiscardedChunk {
+                    site,
+                    length,
+                    token: unsafe { chunk_cursor.token() },
+                    entry: unsafe { self.refs.chunks.entry_of_unchecked(chunk_entry_index) },
+                });
+
+                site += length;
+
+                unsafe { chunk_cursor.next() };
+            }
+        }
+
         if head.is_dangling() {
             ld_assert!(
                 product.tail.is_dangling(),
@@ -820,189 +1542,261 @@ impl<N: Node> MutableUnit<N> {
         }
     }
 
-    fn update_syntax(&mut self, watcher: &mut impl Watcher, mut cover: Cover<N>) -> EntryIndex {
-        #[allow(unused_variables)]
-        let mut cover_lookahead = 0;
-
+    fn update_syntax(
+        &mut self,
+        watcher: &mut impl Watcher,
+        mut update: SyntaxUpdate<N>,
+    ) -> EntryIndex {
         loop {
-            let mut shift;
-            let mut rule;
-
-            match cover.chunk_cursor.is_dangling() {
-                false => match unsafe { cover.chunk_cursor.is_first() } {
-                    true => match unsafe { cover.chunk_cursor.cache().is_some() } {
-                        false => {
-                            shift = 0;
-                            rule = ROOT_RULE;
-                        }
+            if let Some(entry) = self.update_syntax_step(watcher, &mut update) {
+                return entry;
+            }
+        }
+    }
 
-                        true => {
-                            shift = 0;
-                            rule = NON_RULE
-                        }
-                    },
+    // Performs a single reparse iteration of the syntax tree update.
+    //
+    // Returns the primary node of the reparsed cluster if the syntax tree is
+    // up to date. Otherwise, the next iteration would reparse the cluster that
+    // covers the extended span.
+    fn update_syntax_step(
+        &mut self,
+        watcher: &mut impl Watcher,
+        update: &mut SyntaxUpdate<N>,
+    ) -> Option<EntryIndex> {
+        let cover = &mut update.cover;
 
-                    false => {
-                        unsafe { cover.chunk_cursor.back() };
+        #[allow(unused_variables)]
+        let mut cover_lookahead = 0;
 
-                        shift = unsafe { *cover.chunk_cursor.span() };
+        let mut shift;
+        let mut rule;
 
-                        rule = NON_RULE;
+        match cover.chunk_cursor.is_dangling() {
+            false => match unsafe { cover.chunk_cursor.is_first() } {
+                // If the cluster of the first chunk has been reparsed on
+                // the previous iteration, only the root cluster can cover
+                // the extended span.
+                true => match unsafe { cover.chunk_cursor.cache().is_some() } && !update.reparsed {
+                    false => {
+                        shift = 0;
+                        rule = ROOT_RULE;
                     }
-                },
 
-                true => match self.tree.code_length() == 0 {
                     true => {
                         shift = 0;
-                        rule = ROOT_RULE;
+                        rule = NON_RULE
                     }
+                },
 
-                    false => {
-                        cover.chunk_cursor = self.tree.last();
+                false => {
+                    unsafe { cover.chunk_cursor.back() };
 
-                        shift = unsafe { *cover.chunk_cursor.span() };
+                    shift = unsafe { *cover.chunk_cursor.span() };
 
-                        rule = NON_RULE;
-                    }
-                },
-            }
+                    rule = NON_RULE;
+                }
+            },
 
-            if rule != ROOT_RULE {
-                loop {
-                    {
-                        match unsafe { cover.chunk_cursor.cache() } {
-                            None => {
-                                unsafe { cover.chunk_cursor.back() };
-
-                                match cover.chunk_cursor.is_dangling() {
-                                    false => {
-                                        shift += unsafe { *cover.chunk_cursor.span() };
-                                        continue;
-                                    }
+            true => match self.tree.code_length() == 0 {
+                true => {
+                    shift = 0;
+                    rule = ROOT_RULE;
+                }
 
-                                    true => {
-                                        rule = ROOT_RULE;
-                                        break;
-                                    }
+                false => {
+                    cover.chunk_cursor = self.tree.last();
+
+                    shift = unsafe { *cover.chunk_cursor.span() };
+
+                    rule = NON_RULE;
+                }
+            },
+        }
+
+        if rule != ROOT_RULE {
+            loop {
+                {
+                    match unsafe { cover.chunk_cursor.cache() } {
+                        None => {
+                            unsafe { cover.chunk_cursor.back() };
+
+                            match cover.chunk_cursor.is_dangling() {
+                                false => {
+                                    shift += unsafe { *cover.chunk_cursor.span() };
+                                    continue;
                                 }
-                            }
 
-                            Some(cache) => {
-                                let parse_end_site =
-                                    unsafe { cache.end_site(&self.tree, &self.refs) };
+                                true => {
+                                    rule = ROOT_RULE;
+                                    break;
+                                }
+                            }
+                        }
 
-                                if let Some(parse_end_site) = parse_end_site {
-                                    if parse_end_site + cache.lookahead < cover.span.start {
-                                        unsafe { cover.chunk_cursor.back() };
+                        Some(cache) => {
+                            let parse_end_site = unsafe { cache.end_site(&self.tree, &self.refs) };
 
-                                        match cover.chunk_cursor.is_dangling() {
-                                            false => {
-                                                shift += unsafe { *cover.chunk_cursor.span() };
-                                                continue;
-                                            }
+                            if let Some(parse_end_site) = parse_end_site {
+                                if parse_end_site + cache.lookahead < cover.span.start {
+                                    unsafe { cover.chunk_cursor.back() };
 
-                                            true => {
-                                                rule = ROOT_RULE;
-                                                break;
-                                            }
+                                    match cover.chunk_cursor.is_dangling() {
+                                        false => {
+                                            shift += unsafe { *cover.chunk_cursor.span() };
+                                            continue;
                                         }
-                                    }
 
-                                    if parse_end_site >= cover.span.end {
-                                        cover.span.start -= shift;
-                                        cover.span.end = parse_end_site;
-
-                                        #[allow(unused_assignments)]
-                                        {
-                                            cover_lookahead = cache.lookahead;
+                                        true => {
+                                            rule = ROOT_RULE;
+                                            break;
                                         }
+                                    }
+                                }
 
-                                        rule = cache.rule;
-                                        break;
+                                // The enclosing clusters made their
+                                // decisions by looking into the cluster up
+                                // to the overlap site, so the cluster
+                                // cannot be reparsed alone if the change
+                                // affects this part of the cluster.
+                                if parse_end_site >= cover.span.end && cache.overlap <= shift {
+                                    cover.span.start -= shift;
+                                    cover.span.end = parse_end_site;
+
+                                    #[allow(unused_assignments)]
+                                    {
+                                        cover_lookahead = cache.lookahead;
                                     }
+
+                                    rule = cache.rule;
+                                    break;
                                 }
                             }
                         }
                     }
+                }
 
-                    let cache = unsafe { cover.chunk_cursor.release_cache() };
+                let cache = unsafe { cover.chunk_cursor.release_cache() };
 
-                    cache.free(&mut self.refs, watcher);
-                }
+                let anchor = unsafe {
+                    self.refs
+                        .chunks
+                        .entry_of_unchecked(cover.chunk_cursor.chunk_entry_index())
+                };
+
+                cache.free(anchor, &mut self.refs, watcher);
             }
+        }
 
-            if rule == ROOT_RULE {
-                let head = self.tree.first();
+        if rule == ROOT_RULE {
+            let head = self.tree.first();
 
-                let Some(root_cache) = take(&mut self.root) else {
-                    unsafe { ld_unreachable!("Missing root cache.") }
-                };
+            let Some(root_cache) = take(&mut self.root) else {
+                unsafe { ld_unreachable!("Missing root cache.") }
+            };
 
-                let (rule, primary_node) = root_cache.free_inner(&mut self.refs, watcher);
+            let (rule, primary_node) = root_cache.free_inner(Entry::nil(), &mut self.refs, watcher);
 
-                #[cfg(debug_assertions)]
-                if rule != ROOT_RULE {
-                    system_panic!("Root cache refers non-root rule.");
-                }
+            #[cfg(debug_assertions)]
+            if rule != ROOT_RULE {
+                system_panic!("Root cache refers non-root rule.");
+            }
 
-                let (root_cache, mut parse_end_site) = unsafe {
-                    MutableSyntaxSession::run(
-                        &mut self.tree,
-                        &mut self.refs,
-                        watcher,
-                        0,
-                        head,
-                        rule,
-                        primary_node,
-                    )
-                };
+            let (root_cache, mut parse_end_site) = unsafe {
+                MutableSyntaxSession::run(
+                    &mut self.tree,
+                    &mut self.refs,
+                    watcher,
+                    &mut self.last_reparse,
+                    self.instrumentation,
+                    0,
+                    head,
+                    rule,
+                    primary_node,
+                    0,
+                )
+            };
 
-                self.root = Some(root_cache);
+            self.root = Some(root_cache);
 
-                if self.tree.code_length() > 0 {
-                    let mut tail = self.tree.lookup(&mut parse_end_site);
+            self.last_reparse.span = 0..parse_end_site;
 
-                    ld_assert_eq!(parse_end_site, 0, "Incorrect span alignment.");
+            if self.tree.code_length() > 0 {
+                let mut tail = self.tree.lookup(&mut parse_end_site);
 
-                    while !tail.is_dangling() {
-                        let has_cache = unsafe { tail.cache().is_some() };
+                ld_assert_eq!(parse_end_site, 0, "Incorrect span alignment.");
 
-                        if has_cache {
-                            unsafe { tail.release_cache() }.free(&mut self.refs, watcher);
-                        }
+                while !tail.is_dangling() {
+                    let has_cache = unsafe { tail.cache().is_some() };
 
-                        unsafe { tail.next() }
+                    if has_cache {
+                        let anchor = unsafe {
+                            self.refs
+                                .chunks
+                                .entry_of_unchecked(tail.chunk_entry_index())
+                        };
+
+                        unsafe { tail.release_cache() }.free(anchor, &mut self.refs, watcher);
                     }
-                }
 
-                return primary_node;
+                    unsafe { tail.next() }
+                }
             }
 
-            let cache = unsafe { cover.chunk_cursor.release_cache() };
+            return Some(primary_node);
+        }
 
-            let (rule, primary_node) = cache.free_inner(&mut self.refs, watcher);
+        let cache = unsafe { cover.chunk_cursor.release_cache() };
 
-            let (cache, parse_end_site) = unsafe {
-                MutableSyntaxSession::run(
-                    &mut self.tree,
-                    &mut self.refs,
-                    watcher,
-                    cover.span.start,
-                    cover.chunk_cursor,
-                    rule,
-                    primary_node,
-                )
-            };
+        let overlap = cache.overlap;
+
+        let anchor = unsafe {
+            self.refs
+                .chunks
+                .entry_of_unchecked(cover.chunk_cursor.chunk_entry_index())
+        };
+
+        let (rule, primary_node) = cache.free_inner(anchor, &mut self.refs, watcher);
+
+        let (cache, parse_end_site) = unsafe {
+            MutableSyntaxSession::run(
+                &mut self.tree,
+                &mut self.refs,
+                watcher,
+                &mut self.last_reparse,
+                self.instrumentation,
+                cover.span.start,
+                cover.chunk_cursor,
+                rule,
+                primary_node,
+                overlap,
+            )
+        };
+
+        unsafe { cover.chunk_cursor.install_cache(cache) }
 
-            unsafe { cover.chunk_cursor.install_cache(cache) }
+        {
+            let span = &mut self.last_reparse.span;
+
+            match span.is_empty() {
+                true => *span = cover.span.start..parse_end_site,
 
-            //todo check lookahead too
-            if cover.span.end == parse_end_site {
-                return primary_node;
+                false => {
+                    span.start = span.start.min(cover.span.start);
+                    span.end = span.end.max(parse_end_site);
+                }
             }
+        }
 
-            cover.span.end = cover.span.end.max(parse_end_site);
+        //todo check lookahead too
+        if cover.span.end == parse_end_site {
+            return Some(primary_node);
         }
+
+        cover.span.end = cover.span.end.max(parse_end_site);
+        update.reparsed = true;
+
+        None
     }
 
     // Safety:
@@ -1014,14 +1808,22 @@ impl<N: Node> MutableUnit<N> {
                 transmute_copy::<VoidSyntax<<N as Node>::Token>, N>(&VoidSyntax::default())
             });
 
-            return Cache {
+            let mut root_cache = Cache {
                 rule: ROOT_RULE,
                 parse_end: SiteRef::nil(),
                 lookahead: 0,
+                overlap: 0,
                 primary_node,
                 secondary_nodes: Vec::new(),
                 errors: Vec::new(),
+                children: 0,
+                depth: 0,
+                nested: Vec::new(),
             };
+
+            unsafe { root_cache.measure(refs, &[]) };
+
+            return root_cache;
         }
 
         let head = tree.first();
@@ -1033,10 +1835,13 @@ impl<N: Node> MutableUnit<N> {
                 tree,
                 refs,
                 &mut VoidWatcher,
+                &mut ReparseReport::default(),
+                Instrumentation::none(),
                 0,
                 head,
                 ROOT_RULE,
                 primary_node,
+                0,
             )
         };
 
@@ -1063,6 +1868,49 @@ struct Cover<N: Node> {
     span: SiteSpan,
 }
 
+// A pending rescan of the tokens affected by the edit.
+//
+// The scanner refers to the chunks of the unit's Tree, so the unit must not
+// be changed until the edit is applied by the `begin_write` function.
+pub(crate) struct LexisUpdate<N: Node> {
+    edit: SiteSpan,
+    span: SiteSpan,
+    head: ChildCursor<N>,
+    tail: ChildCursor<N>,
+    state: Option<SessionState<N>>,
+    scanned: bool,
+}
+
+impl<N: Node> LexisUpdate<N> {
+    // Returns the number of the text bytes scanned so far.
+    #[inline(always)]
+    pub(crate) fn scanned_bytes(&self) -> usize {
+        match &self.state {
+            Some(state) => state.scanned(),
+            None => 0,
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn is_scanned(&self) -> bool {
+        self.scanned
+    }
+}
+
+pub(crate) struct SyntaxUpdate<N: Node> {
+    cover: Cover<N>,
+    reparsed: bool,
+}
+
+impl<N: Node> SyntaxUpdate<N> {
+    // Returns the length of the source code fragment that the next iteration
+    // would reparse at least.
+    #[inline(always)]
+    pub(crate) fn cover_length(&self) -> Length {
+        self.cover.span.end - self.cover.span.start
+    }
+}
+
 #[inline]
 fn split_left(string: &str, mut site: Site) -> &str {
     if site == 0 {
@@ -112,9 +112,14 @@ impl Watcher for VoidWatcher {
 
 /// A default implementation of the [Watcher] interface that prints each
 /// report invocation to the terminal.
+///
+/// This object is available only when the `std` feature of this crate
+/// is enabled.
+#[cfg(feature = "std")]
 #[repr(transparent)]
 pub struct DebugWatcher;
 
+#[cfg(feature = "std")]
 impl Default for DebugWatcher {
     #[inline(always)]
     fn default() -> Self {
@@ -122,6 +127,7 @@ impl Default for DebugWatcher {
     }
 }
 
+#[cfg(feature = "std")]
 impl Watcher for DebugWatcher {
     #[inline(always)]
     fn report_node(&mut self, node_ref: &NodeRef) {
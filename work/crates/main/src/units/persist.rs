@@ -0,0 +1,762 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use alloc::{borrow::Cow, collections::BTreeMap, vec::Vec};
+use core::{
+    fmt::{Formatter, Result as FmtResult},
+    marker::PhantomData,
+};
+
+use serde::{
+    de::{Error, IgnoredAny, MapAccess, SeqAccess, Visitor as DeVisitor},
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+
+use crate::{
+    arena::{Entry, Id, Identifiable},
+    lexis::{Length, SourceCode, Token, TokenBuffer, TokenCursor, TokenRef, TokenRule},
+    syntax::{is_void_syntax, Node, NodeRef, NodeRule, RefKind, SyntaxTree, Visitor},
+    units::{CompilationUnit, Document, ImmutableUnit, MutableUnit},
+};
+
+/// A compilation unit restored from the serialized data.
+///
+/// When the `serde` feature of this crate is enabled, the [Document] and
+/// the [TokenBuffer] objects implement serde's Serialize and Deserialize
+/// traits. The serialized data includes the source code text, the token
+/// stream, and the structure of the syntax tree.
+///
+/// The deserializer restores the token stream without rescanning the source
+/// code text. If the serialized data was produced by a different version of
+/// the lexical grammar (the token rule numbers or names do not match),
+/// or if the token type does not implement the
+/// [Token::from_rule](crate::lexis::Token::from_rule) function,
+/// the deserializer falls back to the full rescan of the source code text.
+///
+/// The Document serialization requires the syntax tree [Node] type to
+/// implement serde's Serialize and Deserialize traits. The deserializer
+/// restores the Document's syntax tree from the serialized nodes without
+/// parsing, such that the NodeRef and TokenRef references inside the restored
+/// nodes point to the restored Document. The deserializer falls back to
+/// parsing of the restored tokens if the serialized data was produced by
+/// a different version of the syntax grammar (the node rule numbers or names
+/// do not match), if the token stream has been rescanned, or if the original
+/// Document had syntax errors (the syntax errors are not serializable).
+/// Without the `std` feature of this crate, the deserializer always parses
+/// the syntax tree.
+///
+/// Deserializing into this object instead of the unit itself additionally
+/// provides the [RefMap] that translates the [NodeRef] and [TokenRef]
+/// references of the original unit into the references of the restored unit.
+///
+/// ```ignore
+/// let serialized = serde_json::to_string(&doc).unwrap();
+///
+/// let restored =
+///     serde_json::from_str::<Restored<Document<JsonNode>>>(&serialized).unwrap();
+///
+/// let node_ref = restored.refs.node_ref(&original_node_ref);
+///
+/// assert!(node_ref.is_valid_ref(&restored.unit));
+/// ```
+#[derive(Debug)]
+pub struct Restored<U> {
+    /// The restored compilation unit.
+    pub unit: U,
+
+    /// A mapping of the original unit's references into the restored unit.
+    pub refs: RefMap,
+
+    /// True if the deserializer rescanned the source code text because
+    /// the serialized token stream does not match the current lexical grammar.
+    pub rescanned: bool,
+
+    /// True if the deserializer parsed the syntax tree of the restored
+    /// [Document] instead of restoring the serialized syntax tree nodes.
+    ///
+    /// Always false for the compilation units without syntax trees.
+    pub reparsed: bool,
+}
+
+/// A mapping of the references of the serialized compilation unit into
+/// the references of the restored unit.
+///
+/// The restored unit receives a new [Id], and the [entries](Entry) of its
+/// tokens and nodes may differ from the original unit (e.g., if the original
+/// unit was a mutable [Document] that underwent a series of edits).
+/// Therefore, the NodeRef and TokenRef objects obtained from the original
+/// unit need to be translated using this object.
+///
+/// The mapping is established by matching the sequences of the tokens and
+/// the syntax tree nodes (in the depth-first order) of the original and
+/// the restored units. If the sequences do not match (e.g., because
+/// the syntax grammar has changed since the unit was serialized),
+/// the corresponding references are not translatable.
+///
+/// See [Restored] for details.
+#[derive(Clone, Debug)]
+pub struct RefMap {
+    source: Id,
+    target: Id,
+    tokens: BTreeMap<Entry, Entry>,
+    nodes: BTreeMap<Entry, Entry>,
+}
+
+impl RefMap {
+    /// Returns the identifier of the original (serialized) compilation unit.
+    #[inline(always)]
+    pub fn source_id(&self) -> Id {
+        self.source
+    }
+
+    /// Returns the identifier of the restored compilation unit.
+    #[inline(always)]
+    pub fn target_id(&self) -> Id {
+        self.target
+    }
+
+    /// Translates the `token_ref` of the original compilation unit into
+    /// the reference of the same token in the restored unit.
+    ///
+    /// Returns [nil](TokenRef::nil) if the `token_ref` does not belong to
+    /// the original unit, or if the token is not translatable.
+    pub fn token_ref(&self, token_ref: &TokenRef) -> TokenRef {
+        if token_ref.id != self.source {
+            return TokenRef::nil();
+        }
+
+        match self.tokens.get(&token_ref.entry) {
+            Some(entry) => TokenRef {
+                id: self.target,
+                entry: *entry,
+            },
+
+            None => TokenRef::nil(),
+        }
+    }
+
+    /// Translates the `node_ref` of the original compilation unit into
+    /// the reference of the same node in the restored unit.
+    ///
+    /// Returns [nil](NodeRef::nil) if the `node_ref` does not belong to
+    /// the original unit, or if the node is not translatable.
+    pub fn node_ref(&self, node_ref: &NodeRef) -> NodeRef {
+        if node_ref.id != self.source {
+            return NodeRef::nil();
+        }
+
+        match self.nodes.get(&node_ref.entry) {
+            Some(entry) => NodeRef {
+                id: self.target,
+                entry: *entry,
+            },
+
+            None => NodeRef::nil(),
+        }
+    }
+
+    /// Returns true if the tokens of the original compilation unit are
+    /// translatable.
+    #[inline(always)]
+    pub fn has_tokens(&self) -> bool {
+        !self.tokens.is_empty()
+    }
+
+    /// Returns true if the syntax tree nodes of the original compilation unit
+    /// are translatable.
+    #[inline(always)]
+    pub fn has_nodes(&self) -> bool {
+        !self.nodes.is_empty()
+    }
+}
+
+impl<T: Token> Serialize for TokenBuffer<T> {
+    #[inline(always)]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        LexisImage::new(self).serialize(serializer)
+    }
+}
+
+impl<'de, T: Token> Deserialize<'de> for TokenBuffer<T> {
+    #[inline(always)]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Restored::<Self>::deserialize(deserializer)?.unit)
+    }
+}
+
+impl<'de, T: Token> Deserialize<'de> for Restored<TokenBuffer<T>> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let image = LexisImage::deserialize(deserializer)?;
+
+        let (unit, rescanned) = image.restore::<T>();
+
+        let mut refs = RefMap {
+            source: image.id,
+            target: unit.id(),
+            tokens: BTreeMap::new(),
+            nodes: BTreeMap::new(),
+        };
+
+        image.map_tokens(&unit, &mut refs);
+
+        Ok(Self {
+            unit,
+            refs,
+            rescanned,
+            reparsed: false,
+        })
+    }
+}
+
+impl<N: Node + Serialize> Serialize for Document<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut collector = NodeCollector {
+            unit: self,
+            nodes: Vec::new(),
+        };
+
+        self.traverse_tree(&mut collector);
+
+        DocumentImage {
+            mutable: self.is_mutable(),
+            lexis: LexisImage::new(self),
+            nodes: collector.nodes,
+            grammar: grammar::<N>(),
+            syntax: SyntaxImage::new(self),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, N: Node + Deserialize<'de>> Deserialize<'de> for Document<N> {
+    #[inline(always)]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Restored::<Self>::deserialize(deserializer)?.unit)
+    }
+}
+
+impl<'de, N: Node + Deserialize<'de>> Deserialize<'de> for Restored<Document<N>> {
+    #[inline(always)]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct(
+            "DocumentImage",
+            &["mutable", "lexis", "nodes", "grammar", "syntax"],
+            DocumentVisitor(PhantomData),
+        )
+    }
+}
+
+struct DocumentVisitor<N>(PhantomData<N>);
+
+impl<'de, N: Node + Deserialize<'de>> DeVisitor<'de> for DocumentVisitor<N> {
+    type Value = Restored<Document<N>>;
+
+    #[inline(always)]
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_str("struct DocumentImage")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut state = DocumentState::<N>::default();
+
+        state.mutable = seq.next_element()?;
+        state.set_lexis(seq.next_element()?);
+        state.nodes = seq.next_element()?;
+        state.grammar = seq.next_element()?;
+
+        // If the syntax tree cannot be restored, the serialized nodes are left
+        // unread, because the nodes of a different syntax grammar are not
+        // necessarily deserializable into the current node type, and
+        // the non-self-describing formats cannot skip them.
+        #[cfg(feature = "std")]
+        if let Some((source, target)) = state.syntax_ids() {
+            let syntax = Id::remap(source, target, || {
+                seq.next_element::<Option<SyntaxImage<N>>>()
+            })?;
+
+            state.syntax = syntax.flatten().map(|syntax| (target, syntax));
+        }
+
+        state.finish()
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut state = DocumentState::<N>::default();
+
+        while let Some(field) = map.next_key()? {
+            match field {
+                DocumentField::Mutable => state.mutable = Some(map.next_value()?),
+                DocumentField::Lexis => state.set_lexis(Some(map.next_value()?)),
+                DocumentField::Nodes => state.nodes = Some(map.next_value()?),
+                DocumentField::Grammar => state.grammar = Some(map.next_value()?),
+
+                DocumentField::Syntax => {
+                    #[cfg(feature = "std")]
+                    if let Some((source, target)) = state.syntax_ids() {
+                        let syntax = Id::remap(source, target, || {
+                            map.next_value::<Option<SyntaxImage<N>>>()
+                        })?;
+
+                        state.syntax = syntax.map(|syntax| (target, syntax));
+
+                        continue;
+                    }
+
+                    let _ = map.next_value::<IgnoredAny>()?;
+                }
+
+                DocumentField::Other => {
+                    let _ = map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        state.finish()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum DocumentField {
+    Mutable,
+    Lexis,
+    Nodes,
+    Grammar,
+    Syntax,
+    #[serde(other)]
+    Other,
+}
+
+// The Document components deserialized so far.
+struct DocumentState<N: Node> {
+    mutable: Option<bool>,
+    lexis: Option<(LexisImage<'static>, TokenBuffer<N::Token>, bool)>,
+    nodes: Option<Vec<NodeImage>>,
+    grammar: Option<u64>,
+    syntax: Option<(Id, SyntaxImage<N>)>,
+}
+
+impl<N: Node> Default for DocumentState<N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            mutable: None,
+            lexis: None,
+            nodes: None,
+            grammar: None,
+            syntax: None,
+        }
+    }
+}
+
+impl<N: Node> DocumentState<N> {
+    #[inline(always)]
+    fn set_lexis(&mut self, image: Option<LexisImage<'static>>) {
+        self.lexis = image.map(|image| {
+            let (buffer, rescanned) = image.restore::<N::Token>();
+
+            (image, buffer, rescanned)
+        });
+    }
+
+    // Returns the Id of the original Document and the Id of the restored
+    // Document if the syntax tree can be restored from the serialized nodes.
+    #[cfg(feature = "std")]
+    fn syntax_ids(&self) -> Option<(Id, Id)> {
+        let (image, buffer, rescanned) = self.lexis.as_ref()?;
+
+        if *rescanned || self.grammar? != grammar::<N>() {
+            return None;
+        }
+
+        match self.mutable? {
+            true => Some((image.id, Id::new())),
+            false => Some((image.id, buffer.id())),
+        }
+    }
+
+    fn finish<E: Error>(self) -> Result<Restored<Document<N>>, E> {
+        let mutable = self.mutable.ok_or_else(|| E::missing_field("mutable"))?;
+        let (image, buffer, rescanned) = self.lexis.ok_or_else(|| E::missing_field("lexis"))?;
+        let nodes = self.nodes.ok_or_else(|| E::missing_field("nodes"))?;
+
+        if self.grammar.is_none() {
+            return Err(E::missing_field("grammar"));
+        }
+
+        let restored = match self.syntax {
+            Some((id, syntax)) => syntax.restore(id, mutable, &image, buffer),
+            None => Err(buffer),
+        };
+
+        let (unit, reparsed) = match restored {
+            Ok(unit) => (unit, false),
+
+            Err(buffer) => match mutable {
+                true => (Document::new_mutable(buffer), true),
+                false => (Document::new_immutable(buffer), true),
+            },
+        };
+
+        let mut refs = RefMap {
+            source: image.id,
+            target: unit.id(),
+            tokens: BTreeMap::new(),
+            nodes: BTreeMap::new(),
+        };
+
+        image.map_tokens(&unit, &mut refs);
+
+        let mut collector = NodeCollector {
+            unit: &unit,
+            nodes: Vec::with_capacity(nodes.len()),
+        };
+
+        unit.traverse_tree(&mut collector);
+
+        let matches = collector.nodes.len() == nodes.len()
+            && collector
+                .nodes
+                .iter()
+                .zip(nodes.iter())
+                .all(|(restored, original)| restored.rule == original.rule);
+
+        if matches {
+            for (restored, original) in collector.nodes.into_iter().zip(nodes) {
+                let _ = refs.nodes.insert(original.entry, restored.entry);
+            }
+        }
+
+        Ok(Restored {
+            unit,
+            refs,
+            rescanned,
+            reparsed,
+        })
+    }
+}
+
+const FNV_OFFSET: u64 = 0xCBF29CE484222325;
+const FNV_PRIME: u64 = 0x100000001B3;
+
+// Computes a fingerprint of the lexical grammar from the token rule numbers
+// and names.
+fn fingerprint<T: Token>() -> u64 {
+    let mut hash = FNV_OFFSET;
+
+    for rule in 0..=TokenRule::MAX {
+        let Some(name) = T::rule_name(rule) else {
+            continue;
+        };
+
+        for byte in [rule].iter().chain(name.as_bytes()).chain(&[0]) {
+            hash ^= *byte as u64;
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    hash
+}
+
+// Computes a fingerprint of the syntax grammar from the node rule numbers
+// and names.
+fn grammar<N: Node>() -> u64 {
+    let mut hash = FNV_OFFSET;
+
+    for rule in 0..=NodeRule::MAX {
+        let Some(name) = N::rule_name(rule) else {
+            continue;
+        };
+
+        for byte in rule.to_le_bytes().iter().chain(name.as_bytes()).chain(&[0]) {
+            hash ^= *byte as u64;
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    hash
+}
+
+#[derive(Serialize, Deserialize)]
+struct LexisImage<'a> {
+    fingerprint: u64,
+    id: Id,
+    text: Cow<'a, str>,
+    tokens: Vec<TokenImage>,
+}
+
+impl<'a> LexisImage<'a> {
+    fn new<C: SourceCode>(code: &'a C) -> Self {
+        let mut tokens = Vec::with_capacity(code.tokens());
+        let mut cursor = code.cursor(..);
+
+        while let Some(length) = cursor.length(0) {
+            tokens.push(TokenImage {
+                rule: cursor.token(0).rule(),
+                length,
+                entry: cursor.token_ref(0).entry,
+            });
+
+            let _ = cursor.advance();
+        }
+
+        Self {
+            fingerprint: fingerprint::<C::Token>(),
+            id: code.id(),
+            text: code.substring(..),
+            tokens,
+        }
+    }
+
+    // Restores the token buffer without rescanning if possible. The second
+    // value is true if the source code text has been rescanned.
+    fn restore<T: Token>(&self) -> (TokenBuffer<T>, bool) {
+        let text = self.text.as_ref();
+
+        if self.fingerprint == fingerprint::<T>() {
+            let chunks = self.tokens.iter().map(|token| (token.rule, token.length));
+
+            if let Some(buffer) = TokenBuffer::restore(text.into(), chunks) {
+                return (buffer, false);
+            }
+        }
+
+        (TokenBuffer::from(text), true)
+    }
+
+    fn map_tokens<C: SourceCode>(&self, code: &C, refs: &mut RefMap) {
+        if code.tokens() != self.tokens.len() {
+            return;
+        }
+
+        let mut cursor = code.cursor(..);
+        let mut entries = Vec::with_capacity(self.tokens.len());
+
+        for original in &self.tokens {
+            if cursor.length(0) != Some(original.length) {
+                return;
+            }
+
+            if cursor.token(0).rule() != original.rule {
+                return;
+            }
+
+            entries.push((original.entry, cursor.token_ref(0).entry));
+
+            let _ = cursor.advance();
+        }
+
+        refs.tokens.extend(entries);
+    }
+}
+
+#[derive(Serialize)]
+struct DocumentImage<'a, N> {
+    mutable: bool,
+    lexis: LexisImage<'a>,
+    nodes: Vec<NodeImage>,
+    grammar: u64,
+    syntax: Option<SyntaxImage<&'a N>>,
+}
+
+// The storage of the syntax tree nodes.
+//
+// The nodes of the immutable Document are stored under the indices of
+// the Vec, and the nodes of the mutable Document are stored in clusters.
+#[derive(Serialize, Deserialize)]
+struct SyntaxImage<M> {
+    nodes: Vec<(Entry, M)>,
+    clusters: Vec<ClusterImage>,
+}
+
+impl<'a, N: Node> SyntaxImage<&'a N> {
+    // Returns None if the syntax tree cannot be restored from the nodes.
+    fn new(document: &'a Document<N>) -> Option<Self> {
+        if is_void_syntax::<N>() || document.error_refs().next().is_some() {
+            return None;
+        }
+
+        let nodes = document
+            .node_refs()
+            .filter_map(|node_ref| Some((node_ref.entry, node_ref.deref(document)?)))
+            .collect();
+
+        let clusters = match document {
+            Document::Mutable(unit) => unit.cluster_images(),
+            Document::Immutable(_) => Vec::new(),
+        };
+
+        Some(Self { nodes, clusters })
+    }
+}
+
+impl<N: Node> SyntaxImage<N> {
+    // Returns the token buffer back if the nodes do not form the syntax tree
+    // of the Document.
+    fn restore(
+        self,
+        id: Id,
+        mutable: bool,
+        lexis: &LexisImage,
+        buffer: TokenBuffer<N::Token>,
+    ) -> Result<Document<N>, TokenBuffer<N::Token>> {
+        let root = match (mutable, self.clusters.first()) {
+            (true, Some(cluster)) => cluster.primary_node,
+            (true, None) => return Err(buffer),
+            (false, _) => Entry {
+                index: 0,
+                version: 0,
+            },
+        };
+
+        if !is_tree(id, root, &self.nodes) {
+            return Err(buffer);
+        }
+
+        let restored = match mutable {
+            true => MutableUnit::restore(
+                id,
+                buffer,
+                lexis.tokens.iter().map(|token| token.entry).collect(),
+                self.nodes,
+                self.clusters,
+            )
+            .map(Document::Mutable),
+
+            false => ImmutableUnit::restore(buffer, self.nodes).map(Document::Immutable),
+        };
+
+        // The buffer has been consumed by the failed attempt.
+        restored.ok_or_else(|| lexis.restore::<N::Token>().0)
+    }
+}
+
+// The syntax tree nodes of the mutable Document that have been parsed by
+// the same parse procedure, together with the parse procedure metadata.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct ClusterImage {
+    // The entry of the chunk to which the cluster is attached,
+    // or None for the root cluster.
+    pub(crate) anchor: Option<Entry>,
+    pub(crate) rule: NodeRule,
+    // The entry of the chunk at which the parser stopped, or None if
+    // the parser reached the end of the code.
+    pub(crate) parse_end: Option<Entry>,
+    pub(crate) lookahead: Length,
+    pub(crate) overlap: Length,
+    pub(crate) primary_node: Entry,
+    pub(crate) secondary_nodes: Vec<Entry>,
+}
+
+// Returns true if each node is reachable from the `root` node through
+// exactly one path of the child references.
+fn is_tree<N: Node>(id: Id, root: Entry, nodes: &[(Entry, N)]) -> bool {
+    let mut visited = nodes
+        .iter()
+        .map(|(entry, node)| (*entry, (node, false)))
+        .collect::<BTreeMap<_, _>>();
+
+    if visited.len() != nodes.len() {
+        return false;
+    }
+
+    let mut stack = Vec::from([root]);
+    let mut count = 0;
+
+    while let Some(entry) = stack.pop() {
+        let Some((node, visit)) = visited.get_mut(&entry) else {
+            return false;
+        };
+
+        if *visit {
+            return false;
+        }
+
+        *visit = true;
+        count += 1;
+
+        for child in node.children_iter() {
+            if child.kind() != RefKind::Node {
+                continue;
+            }
+
+            let node_ref = child.as_node_ref();
+
+            if node_ref.id == id && !node_ref.entry.is_nil() {
+                stack.push(node_ref.entry);
+            }
+        }
+    }
+
+    count == nodes.len()
+}
+
+#[derive(Serialize, Deserialize)]
+struct TokenImage {
+    rule: TokenRule,
+    length: Length,
+    entry: Entry,
+}
+
+#[derive(Serialize, Deserialize)]
+struct NodeImage {
+    rule: NodeRule,
+    entry: Entry,
+}
+
+struct NodeCollector<'a, U: CompilationUnit> {
+    unit: &'a U,
+    nodes: Vec<NodeImage>,
+}
+
+impl<'a, U: CompilationUnit> Visitor for NodeCollector<'a, U> {
+    #[inline(always)]
+    fn visit_token(&mut self, _token_ref: &TokenRef) {}
+
+    #[inline(always)]
+    fn enter_node(&mut self, node_ref: &NodeRef) -> bool {
+        self.nodes.push(NodeImage {
+            rule: node_ref.rule(self.unit),
+            entry: node_ref.entry,
+        });
+
+        true
+    }
+
+    #[inline(always)]
+    fn leave_node(&mut self, _node_ref: &NodeRef) {}
+}
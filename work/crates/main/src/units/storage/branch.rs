@@ -32,7 +32,8 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::{marker::PhantomData, ptr::NonNull};
+use alloc::boxed::Box;
+use core::{array, marker::PhantomData, mem::size_of, ptr::NonNull};
 
 use crate::{
     lexis::Length,
@@ -197,8 +198,8 @@ impl<ChildLayer: Layer, N: Node> Branch<ChildLayer, N> {
             inner: BranchInner {
                 parent: ChildCursor::dangling(),
                 occupied,
-                spans: Default::default(),
-                children: Default::default(),
+                spans: [0; BRANCH_CAP],
+                children: array::from_fn(|_| ItemRefVariant::dangling()),
             },
             child_layer: PhantomData::default(),
         };
@@ -322,6 +323,41 @@ impl<ChildLayer: Layer, N: Node> Branch<ChildLayer, N> {
         }
     }
 
+    // Returns the number of bytes allocated by the subtree of this branch.
+    //
+    // Safety:
+    // 1. `height >= 2`.
+    // 2. `height` fits to `ChildLayer`.
+    pub(crate) unsafe fn heap_size(&self, height: Height) -> usize {
+        let mut size = size_of::<Self>();
+
+        for index in 0..self.inner.occupied {
+            let child = unsafe { self.inner.children.get_unchecked(index) };
+
+            size += match height {
+                0 | 1 => unsafe { ld_unreachable!("Incorrect height.") },
+
+                2 => unsafe { child.as_page_ref().as_ref().heap_size() },
+
+                3 => unsafe {
+                    child
+                        .as_branch_ref::<PageLayer>()
+                        .as_ref()
+                        .heap_size(height - 1)
+                },
+
+                _ => unsafe {
+                    child
+                        .as_branch_ref::<BranchLayer>()
+                        .as_ref()
+                        .heap_size(height - 1)
+                },
+            };
+        }
+
+        size
+    }
+
     // Safety:
     // 1. `ChildLayer` correctly describes children layer.
     // 2. `count > 0`
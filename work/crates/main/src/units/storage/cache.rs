@@ -32,12 +32,16 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
+use alloc::{collections::btree_map, vec::Vec};
+use core::{iter::once, mem::size_of};
+
 use crate::{
-    arena::EntryIndex,
+    arena::{Entry, EntryIndex},
     lexis::{Length, Site, SiteRef, SiteRefInner},
     report::ld_unreachable,
-    syntax::{ErrorRef, Node, NodeRef, NodeRule},
+    syntax::{AbstractNode, ErrorRef, Node, NodeRef, NodeRule, RefKind},
     units::{
+        mutable::history::DiscardedCluster,
         storage::{ChildCursor, Tree, TreeRefs},
         Watcher,
     },
@@ -47,14 +51,47 @@ pub(crate) struct Cache {
     pub(crate) rule: NodeRule,
     pub(crate) parse_end: SiteRef,
     pub(crate) lookahead: Length,
+    // The number of characters from the start of the cluster that the
+    // enclosing clusters looked at.
+    pub(crate) overlap: Length,
     pub(crate) primary_node: EntryIndex,
     pub(crate) secondary_nodes: Vec<EntryIndex>,
     pub(crate) errors: Vec<EntryIndex>,
+    // The total number of children (nodes and tokens) of this cluster's
+    // nodes.
+    pub(crate) children: usize,
+    // The length of the longest path (in nodes) from the primary node down to
+    // the nodes of this cluster.
+    pub(crate) depth: usize,
+    // The clusters whose primary nodes are the children of this cluster's
+    // nodes.
+    pub(crate) nested: Vec<NestedCluster>,
+}
+
+// A reference from the cluster to the nested cluster.
+#[derive(Clone, Copy)]
+pub(crate) struct NestedCluster {
+    // The depth of the enclosing cluster's node that refers to the nested
+    // cluster's primary node.
+    pub(crate) depth: usize,
+    // The entry of the chunk to which the nested cluster's cache is attached.
+    pub(crate) anchor: Entry,
+    pub(crate) primary_node: EntryIndex,
 }
 
 impl Cache {
+    // The `anchor` is the entry of the chunk to which this cache is attached,
+    // or a nil entry if this is the root cache.
     #[inline(always)]
-    pub(crate) fn free<N: Node>(self, refs: &mut TreeRefs<N>, watcher: &mut impl Watcher) {
+    pub(crate) fn free<N: Node>(
+        self,
+        anchor: Entry,
+        refs: &mut TreeRefs<N>,
+        watcher: &mut impl Watcher,
+    ) {
+        self.discard(anchor, refs);
+        self.uncount(refs);
+
         watcher.report_node(&NodeRef {
             id: refs.id,
             entry: unsafe { refs.nodes.remove_unchecked(self.primary_node) },
@@ -78,9 +115,13 @@ impl Cache {
     #[inline(always)]
     pub(crate) fn free_inner<N: Node>(
         self,
+        anchor: Entry,
         refs: &mut TreeRefs<N>,
         watcher: &mut impl Watcher,
     ) -> (NodeRule, EntryIndex) {
+        self.discard(anchor, refs);
+        self.uncount(refs);
+
         watcher.report_node(&NodeRef {
             id: refs.id,
             entry: unsafe { refs.nodes.entry_of_unchecked(self.primary_node) },
@@ -103,6 +144,138 @@ impl Cache {
         (self.rule, self.primary_node)
     }
 
+    // Records this cache's nodes and errors if the unit records the edit
+    // history.
+    #[inline(always)]
+    fn discard<N: Node>(&self, anchor: Entry, refs: &mut TreeRefs<N>) {
+        let Some(discarded) = &mut refs.discarded else {
+            return;
+        };
+
+        let key = (anchor, self.rule);
+
+        // The first removal within the edit is the one of the cluster that
+        // existed before the edit.
+        if discarded.clusters.contains_key(&key) {
+            return;
+        }
+
+        let secondary_nodes = self
+            .secondary_nodes
+            .iter()
+            .map(|index| {
+                let rule = unsafe { refs.nodes.get_unchecked(*index) }.rule();
+                let entry = unsafe { refs.nodes.entry_of_unchecked(*index) };
+
+                (rule, entry)
+            })
+            .collect();
+
+        let errors = self
+            .errors
+            .iter()
+            .map(|index| {
+                let error = unsafe { refs.errors.get_unchecked(*index) }.clone();
+                let entry = unsafe { refs.errors.entry_of_unchecked(*index) };
+
+                (error, entry)
+            })
+            .collect();
+
+        let _ = discarded.clusters.insert(
+            key,
+            DiscardedCluster {
+                primary_node: unsafe { refs.nodes.entry_of_unchecked(self.primary_node) },
+                secondary_nodes,
+                errors,
+            },
+        );
+    }
+
+    // Computes the children, the depth, and the nested clusters of this
+    // cluster, and adds this cluster's nodes to the counters of the tree.
+    //
+    // The `descended` slice contains the primary nodes of the clusters that
+    // the parser has descended into from this cluster, and the entries of
+    // the chunks to which their caches are attached. The slice is sorted by
+    // the primary nodes.
+    //
+    // Safety: The nodes of this cache are Occupied.
+    pub(crate) unsafe fn measure<N: Node>(
+        &mut self,
+        refs: &mut TreeRefs<N>,
+        descended: &[(EntryIndex, Entry)],
+    ) {
+        let mut stack = Vec::from([(self.primary_node, 1)]);
+
+        while let Some((index, depth)) = stack.pop() {
+            self.depth = self.depth.max(depth);
+
+            let node = unsafe { refs.nodes.get_unchecked(index) };
+
+            for child in node.children_iter() {
+                if child.kind() != RefKind::Node {
+                    continue;
+                }
+
+                let node_ref = child.as_node_ref();
+
+                if node_ref.id != refs.id || !refs.nodes.contains(&node_ref.entry) {
+                    continue;
+                }
+
+                let index = node_ref.entry.index;
+
+                match descended.binary_search_by_key(&index, |(primary_node, _)| *primary_node) {
+                    Ok(position) => self.nested.push(NestedCluster {
+                        depth,
+                        anchor: unsafe { descended.get_unchecked(position) }.1,
+                        primary_node: index,
+                    }),
+
+                    Err(_) => stack.push((index, depth + 1)),
+                }
+            }
+        }
+
+        for index in once(&self.primary_node).chain(&self.secondary_nodes) {
+            let node = unsafe { refs.nodes.get_unchecked(*index) };
+
+            self.children += node.children_iter().count();
+
+            *refs.rules.entry(node.rule()).or_default() += 1;
+        }
+
+        refs.children += self.children;
+    }
+
+    // Returns the number of bytes allocated by this cache, including the cache
+    // object itself.
+    #[inline(always)]
+    pub(crate) fn heap_size(&self) -> usize {
+        size_of::<Self>()
+            + (self.secondary_nodes.capacity() + self.errors.capacity()) * size_of::<EntryIndex>()
+            + self.nested.capacity() * size_of::<NestedCluster>()
+    }
+
+    // Removes this cluster's nodes from the counters of the tree.
+    #[inline(always)]
+    fn uncount<N: Node>(&self, refs: &mut TreeRefs<N>) {
+        refs.children -= self.children;
+
+        for index in once(&self.primary_node).chain(&self.secondary_nodes) {
+            let rule = unsafe { refs.nodes.get_unchecked(*index) }.rule();
+
+            if let btree_map::Entry::Occupied(mut entry) = refs.rules.entry(rule) {
+                *entry.get_mut() -= 1;
+
+                if *entry.get() == 0 {
+                    let _ = entry.remove();
+                }
+            }
+        }
+    }
+
     // Safety:
     // 1. Cache belongs to specified `tree` and `refs` pair.
     #[inline(always)]
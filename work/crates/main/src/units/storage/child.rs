@@ -32,7 +32,8 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::{
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::{
     mem::{replace, take},
     ops::Deref,
     str::from_utf8_unchecked,
@@ -371,6 +372,27 @@ impl<N: Node> ChildCursor<N> {
         unsafe { *page.chunks.get_unchecked(self.index) }
     }
 
+    // Safety:
+    // 1. `self` is not dangling.
+    // 2. `self.item` is a Page reference.
+    // 3. `entry_index` refers to the chunk entry that points to `self`.
+    #[inline(always)]
+    pub(crate) unsafe fn set_chunk_entry_index(&self, entry_index: EntryIndex) {
+        ld_assert!(
+            !self.is_dangling(),
+            "An attempt to access dangling ChildCursor.",
+        );
+
+        let page = unsafe { self.item.as_page_ref().as_external_mut() };
+
+        ld_assert!(
+            self.index < page.occupied,
+            "ChildCursor index out of bounds.",
+        );
+
+        unsafe { *page.chunks.get_unchecked_mut(self.index) = entry_index };
+    }
+
     // Safety:
     // 1. `self` is not dangling.
     // 2. `self.item` is a Page reference.
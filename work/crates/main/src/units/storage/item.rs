@@ -32,7 +32,8 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::mem::transmute;
+use alloc::boxed::Box;
+use core::mem::transmute;
 
 use crate::{
     lexis::Length,
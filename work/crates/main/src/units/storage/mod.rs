@@ -51,10 +51,23 @@ pub(crate) use crate::units::storage::{
 };
 use crate::{lexis::CHUNK_SIZE, units::storage::spread::capacity};
 
+// The branching factors of the storage tree.
+//
+// The "large-pages" feature trades the cost of the page splits and the in-page
+// shifts on each edit for the shallower tree with the denser pages.
+
+#[cfg(not(feature = "large-pages"))]
 const BRANCH_B: usize = 6;
+#[cfg(feature = "large-pages")]
+const BRANCH_B: usize = 8;
 const BRANCH_CAP: usize = capacity(BRANCH_B);
 
+#[cfg(not(feature = "large-pages"))]
 const PAGE_B: usize = 16;
+#[cfg(feature = "large-pages")]
+const PAGE_B: usize = 64;
 const PAGE_CAP: usize = capacity(PAGE_B);
 
+// The inline string buffer of the page fits all page's tokens if their lengths
+// are about the average.
 const STRING_INLINE: usize = PAGE_CAP * CHUNK_SIZE;
@@ -32,8 +32,9 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::{
-    mem::{replace, take, MaybeUninit},
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::{
+    mem::{replace, size_of, take, MaybeUninit},
     ptr::NonNull,
     str::from_utf8_unchecked,
 };
@@ -213,10 +214,10 @@ impl<N: Node> Page<N> {
             previous: None,
             next: None,
             occupied,
-            spans: Default::default(),
+            spans: [0; PAGE_CAP],
             string: PageString::default(),
             tokens: unsafe { MaybeUninit::uninit().assume_init() },
-            chunks: Default::default(),
+            chunks: [0; PAGE_CAP],
             caches: unsafe { MaybeUninit::uninit().assume_init() },
         };
 
@@ -272,12 +273,12 @@ impl<N: Node> Page<N> {
 
             let chunk_index = *unsafe { self.chunks.get_unchecked(index) };
 
-            let _ = unsafe { refs.chunks.remove_unchecked(chunk_index) };
+            let chunk_entry = unsafe { refs.chunks.remove_unchecked(chunk_index) };
 
             let cache = take(unsafe { self.caches.get_unchecked_mut(index).assume_init_mut() });
 
             if let Some(cache) = cache {
-                cache.free(refs, watcher);
+                cache.free(chunk_entry, refs, watcher);
             }
         }
 
@@ -293,6 +294,22 @@ impl<N: Node> Page<N> {
             let _ = take(unsafe { self.caches.get_unchecked_mut(index).assume_init_mut() });
         }
     }
+
+    // Returns the number of bytes allocated by this page, including the page
+    // object itself and the caches of its chunks.
+    pub(super) fn heap_size(&self) -> usize {
+        let mut size = size_of::<Self>() + self.string.heap_size();
+
+        for index in 0..self.occupied {
+            let cache = unsafe { self.caches.get_unchecked(index).assume_init_ref() };
+
+            if let Some(cache) = cache {
+                size += cache.heap_size();
+            }
+        }
+
+        size
+    }
 }
 
 #[repr(transparent)]
@@ -586,11 +603,13 @@ impl<N: Node> PageRef<N> {
             *span = new_span;
             let _ = replace(token, new_token);
 
-            unsafe { refs.chunks.upgrade(chunk_index) };
-
             if let Some(cache) = cache {
-                cache.free(refs, watcher);
+                let chunk_entry = unsafe { refs.chunks.entry_of_unchecked(chunk_index) };
+
+                cache.free(chunk_entry, refs, watcher);
             }
+
+            unsafe { refs.chunks.upgrade(chunk_index) };
         }
 
         (dec, inc)
@@ -631,12 +650,12 @@ impl<N: Node> PageRef<N> {
 
             let chunk_index = unsafe { *page.chunks.get_unchecked(index) };
 
-            let _ = unsafe { refs.chunks.remove_unchecked(chunk_index) };
+            let chunk_entry = unsafe { refs.chunks.remove_unchecked(chunk_index) };
 
             let cache = take(unsafe { page.caches.get_unchecked_mut(index).assume_init_mut() });
 
             if let Some(cache) = cache {
-                cache.free(refs, watcher);
+                cache.free(chunk_entry, refs, watcher);
             }
 
             length += span;
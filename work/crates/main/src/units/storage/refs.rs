@@ -32,10 +32,12 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
+use alloc::collections::BTreeMap;
+
 use crate::{
     arena::{Id, Repo},
-    syntax::{Node, SyntaxError},
-    units::storage::child::ChildCursor,
+    syntax::{Node, NodeRule, SyntaxError},
+    units::{mutable::history::Discarded, storage::child::ChildCursor},
 };
 
 pub(crate) struct TreeRefs<N: Node> {
@@ -43,6 +45,15 @@ pub(crate) struct TreeRefs<N: Node> {
     pub(crate) chunks: Repo<ChildCursor<N>>,
     pub(crate) nodes: Repo<N>,
     pub(crate) errors: Repo<SyntaxError>,
+    // The total number of children of the nodes, and the number of nodes per
+    // rule. Maintained by the caches of the clusters.
+    pub(crate) children: usize,
+    pub(crate) rules: BTreeMap<NodeRule, usize>,
+    // The components removed by the current edit if the unit records its
+    // edit history.
+    pub(crate) discarded: Option<Discarded<N>>,
+    // The components removed by the edit that the current edit reverts.
+    pub(crate) restoring: Option<Discarded<N>>,
 }
 
 impl<N: Node> TreeRefs<N> {
@@ -53,6 +64,10 @@ impl<N: Node> TreeRefs<N> {
             chunks: Repo::new(),
             nodes: Repo::new(),
             errors: Repo::new(),
+            children: 0,
+            rules: BTreeMap::new(),
+            discarded: None,
+            restoring: None,
         }
     }
 
@@ -65,6 +80,10 @@ impl<N: Node> TreeRefs<N> {
             chunks: Repo::with_capacity(capacity),
             nodes: Repo::new(),
             errors: Repo::new(),
+            children: 0,
+            rules: BTreeMap::new(),
+            discarded: None,
+            restoring: None,
         }
     }
 }
@@ -32,7 +32,8 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::mem::take;
+use alloc::vec::Vec;
+use core::mem::take;
 
 use crate::{
     lexis::ByteIndex,
@@ -114,6 +115,14 @@ impl PageString {
         slice
     }
 
+    #[inline(always)]
+    pub(super) fn heap_size(&self) -> usize {
+        match &self.bytes {
+            Bytes::Inline(..) => 0,
+            Bytes::Heap(vec) => vec.capacity(),
+        }
+    }
+
     #[inline(always)]
     pub(super) unsafe fn bytes(&self) -> &[u8] {
         match &self.bytes {
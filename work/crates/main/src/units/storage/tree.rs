@@ -32,7 +32,8 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::mem::replace;
+use alloc::vec::Vec;
+use core::mem::replace;
 
 use crate::{
     lexis::{ByteIndex, Length, Site, TokenCount},
@@ -97,6 +98,7 @@ impl<N: Node> Tree<N> {
         let mut length = 0;
 
         let mut spread = Spread::new::<Page<N>>(count);
+        let mut cursors = Vec::with_capacity(count);
         let mut first_page = None;
         let mut last_page = None;
         let mut layer_size = spread.layer_size();
@@ -153,7 +155,7 @@ impl<N: Node> Tree<N> {
 
             match &mut last_page {
                 Some(page_ref) => {
-                    let entry_index = refs.chunks.insert_raw(ChildCursor {
+                    cursors.push(ChildCursor {
                         item: unsafe { page_ref.into_variant() },
                         index,
                     });
@@ -165,7 +167,6 @@ impl<N: Node> Tree<N> {
                     unsafe { *page.spans.get_unchecked_mut(index) = span };
                     unsafe { page.string.set_byte_index(index, byte_index - first_byte) };
                     unsafe { page.tokens.get_unchecked_mut(index).write(token) };
-                    unsafe { *page.chunks.get_unchecked_mut(index) = entry_index };
                     unsafe { page.caches.get_unchecked_mut(index).write(None) };
                 }
 
@@ -180,6 +181,15 @@ impl<N: Node> Tree<N> {
             page.string.append(slice);
         }
 
+        let entries = refs.chunks.extend(cursors.iter().copied());
+
+        for (cursor, entry) in cursors.iter().zip(entries) {
+            let mut page_ref = *unsafe { cursor.item.as_page_ref() };
+            let page = unsafe { page_ref.as_mut() };
+
+            unsafe { *page.chunks.get_unchecked_mut(cursor.index) = entry.index };
+        }
+
         let mut first_item = None;
         let mut last_item = None;
 
@@ -874,6 +884,30 @@ impl<N: Node> Tree<N> {
         self.height = 0;
     }
 
+    // Returns the number of bytes allocated by the pages and the branches of
+    // the tree.
+    pub(crate) fn heap_size(&self) -> usize {
+        let root = &self.root;
+
+        match self.height {
+            0 => 0,
+
+            1 => unsafe { root.as_page_ref().as_ref().heap_size() },
+
+            2 => unsafe {
+                root.as_branch_ref::<PageLayer>()
+                    .as_ref()
+                    .heap_size(self.height)
+            },
+
+            _ => unsafe {
+                root.as_branch_ref::<BranchLayer>()
+                    .as_ref()
+                    .heap_size(self.height)
+            },
+        }
+    }
+
     //Safety:
     // 1. `self.height >= 2`.
     // 2. All references belong to `refs` instance.
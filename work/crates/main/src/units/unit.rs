@@ -32,7 +32,8 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::fmt::{Debug, Display, Formatter};
+use alloc::{format, string::ToString, vec::Vec};
+use core::fmt::{Debug, Display, Formatter};
 
 use crate::{
     arena::{Entry, Identifiable},
@@ -43,13 +44,26 @@ use crate::{
         Site,
         SiteSpan,
         SourceCode,
+        ToSite,
         ToSpan,
         Token,
         TokenBuffer,
         TokenCount,
         TokenCursor,
     },
-    syntax::{Capture, Node, NodeRef, PolyRef, PolyVariant, SyntaxError, SyntaxTree},
+    syntax::{
+        traverse_in_order,
+        Capture,
+        ErrorRef,
+        Node,
+        NodeRef,
+        PolyRef,
+        PolyVariant,
+        SyntaxError,
+        SyntaxTree,
+        TreeStats,
+        Visitor,
+    },
     units::{Document, ImmutableUnit, MutableUnit},
 };
 
@@ -166,6 +180,135 @@ pub trait CompilationUnit:
         }
     }
 
+    /// Returns an iterator over the syntax errors whose source code spans
+    /// intersect with the specified [span](ToSpan).
+    ///
+    /// An error with an empty span is considered to be within the `span` if
+    /// it is located at any site within the `span` bounds (inclusive). An empty
+    /// `span` matches all errors that cover its site (inclusive).
+    ///
+    /// The order of iteration is not specified.
+    ///
+    /// The function tests only the errors visited by
+    /// the [visit_errors_in](Self::visit_errors_in) function. For
+    /// the [MutableUnit], these are the errors of the syntax tree clusters that
+    /// intersect with the `span`.
+    ///
+    /// **Panic**
+    ///
+    /// Panics if the specified span is not valid for this compilation unit.
+    fn errors_in(&self, span: impl ToSpan) -> impl Iterator<Item = &SyntaxError> + '_
+    where
+        Self: Sized,
+    {
+        let span = match span.to_site_span(self) {
+            None => panic!("Specified span is invalid."),
+
+            Some(span) => span,
+        };
+
+        let mut errors = Vec::new();
+
+        self.visit_errors_in(&span, &mut |error_ref| {
+            if let Some(error) = error_within(self, error_ref, &span) {
+                errors.push(error);
+            }
+
+            true
+        });
+
+        errors.into_iter()
+    }
+
+    /// Returns true if the compilation unit has at least one syntax error
+    /// within the specified [span](ToSpan).
+    ///
+    /// This function is a shortcut for `errors_in(span).next().is_some()` that
+    /// stops on the first matching error.
+    ///
+    /// **Panic**
+    ///
+    /// Panics if the specified span is not valid for this compilation unit.
+    fn has_errors_in(&self, span: impl ToSpan) -> bool
+    where
+        Self: Sized,
+    {
+        let span = match span.to_site_span(self) {
+            None => panic!("Specified span is invalid."),
+
+            Some(span) => span,
+        };
+
+        let mut found = false;
+
+        self.visit_errors_in(&span, &mut |error_ref| {
+            found = error_within(self, error_ref, &span).is_some();
+
+            !found
+        });
+
+        found
+    }
+
+    /// Visits the syntax errors of this compilation unit that could intersect
+    /// with the specified site `span`.
+    ///
+    /// The `visitor` function receives the [ErrorRef] reference of each
+    /// visited error, and returns false to stop the visiting.
+    ///
+    /// The visited errors are the candidates only: the function does not
+    /// resolve the spans of the errors, and some of them may be located
+    /// outside of the `span`.
+    ///
+    /// The default implementation visits all errors of this compilation unit.
+    /// The [MutableUnit] stores the syntax errors per cluster of the syntax
+    /// tree, and visits the errors of the clusters that intersect with
+    /// the `span` only.
+    ///
+    /// The [errors_in](Self::errors_in) and
+    /// the [has_errors_in](Self::has_errors_in) functions are based on
+    /// this function.
+    fn visit_errors_in(&self, span: &SiteSpan, visitor: &mut impl FnMut(&ErrorRef) -> bool)
+    where
+        Self: Sized,
+    {
+        let _ = span;
+
+        for error_ref in self.error_refs() {
+            if !visitor(&error_ref) {
+                break;
+            }
+        }
+    }
+
+    /// Performs a depth-first traverse of a branch of the syntax tree visiting
+    /// the children of each node in the source code order.
+    ///
+    /// The `top` parameter specifies a reference into the top node of
+    /// the branch. To traverse the entire syntax tree, use
+    /// the [SyntaxTree::root_node_ref] reference.
+    ///
+    /// Unlike the [SyntaxTree::traverse_subtree] function, which visits
+    /// the node's children in the order of the node's captures description,
+    /// this function sorts the token and node children of each node by their
+    /// start sites. For example, if the parse rule is
+    /// `(items: Item)*{separators: $Comma}`, the traverser interleaves
+    /// the items and the separators as they appear in the source code.
+    ///
+    /// The children whose span cannot be inferred keep their position
+    /// relative to the previous child.
+    ///
+    /// The [Visitor::visit_node] function controls the traversal process
+    /// in the same way as described in the [SyntaxTree::traverse_tree]
+    /// function.
+    #[inline(always)]
+    fn traverse_in_order(&self, top: &NodeRef, visitor: &mut impl Visitor)
+    where
+        Self: Sized,
+    {
+        let _ = traverse_in_order(self, top, visitor);
+    }
+
     /// Returns an object that prints the underlying grammar structure.
     ///
     /// The `poly_ref` parameter specifies a reference to a particular grammar
@@ -177,6 +320,13 @@ pub trait CompilationUnit:
     ///
     /// The default implementation is infallible regardless of the `poly_ref`
     /// validity.
+    ///
+    /// The returned object implements [Display], so you can write it into
+    /// any [fmt::Write](core::fmt::Write) sink, or turn it into a String using
+    /// the [ToString] interface. For valid references, the output format is
+    /// stable: it contains node and token names, their captures, and their
+    /// source code spans, but does not contain memory addresses or [Entry]
+    /// numbers, such that you can use the output in golden tests.
     #[inline(always)]
     fn display(&self, poly_ref: &(impl PolyRef + ?Sized)) -> impl Debug + Display + '_
     where
@@ -232,6 +382,10 @@ impl<F: Lexis> SourceCode for F {
     type Cursor<'code> = <F::Lexis as SourceCode>::Cursor<'code>
         where Self: 'code;
 
+    type BackCursor<'code> = <F::Lexis as SourceCode>::BackCursor<'code>
+    where
+        Self: 'code;
+
     type CharIterator<'code> = <F::Lexis as SourceCode>::CharIterator<'code>
     where
         Self: 'code;
@@ -271,6 +425,11 @@ impl<F: Lexis> SourceCode for F {
         self.lexis().cursor(span)
     }
 
+    #[inline(always)]
+    fn cursor_back(&self, span: impl ToSpan) -> Self::BackCursor<'_> {
+        self.lexis().cursor_back(span)
+    }
+
     #[inline(always)]
     fn length(&self) -> Length {
         self.lexis().length()
@@ -379,6 +538,11 @@ impl<F: Syntax> SyntaxTree for F {
     fn get_error(&self, entry: &Entry) -> Option<&SyntaxError> {
         self.syntax().get_error(entry)
     }
+
+    #[inline(always)]
+    fn stats(&self) -> TreeStats<Self::Node> {
+        self.syntax().stats()
+    }
 }
 
 struct DisplayTree<
@@ -398,7 +562,7 @@ where
     U: CompilationUnit<Cursor<'unit> = C, Node = N>,
 {
     #[inline(always)]
-    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
         Display::fmt(self, formatter)
     }
 }
@@ -409,7 +573,7 @@ where
     C: TokenCursor<'unit>,
     U: CompilationUnit<Cursor<'unit> = C, Node = N>,
 {
-    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, formatter: &mut Formatter) -> core::fmt::Result {
         match &self.variant {
             PolyVariant::Token(variant) => {
                 let chunk: Chunk<U::Token> = match variant.chunk(self.unit) {
@@ -419,8 +583,7 @@ where
 
                 let name = chunk.token.name().unwrap_or("TokenRef");
 
-                let mut debug_struct =
-                    formatter.debug_struct(&format!("${name}(chunk_entry: {:?})", variant.entry));
+                let mut debug_struct = formatter.debug_struct(&format!("${name}"));
 
                 debug_struct.field("string", &chunk.string);
                 debug_struct.field("length", &chunk.length);
@@ -447,8 +610,7 @@ where
 
                 let alternate = formatter.alternate();
 
-                let mut debug_struct =
-                    formatter.debug_struct(&format!("{name}(entry: {:?})", variant.entry));
+                let mut debug_struct = formatter.debug_struct(name);
 
                 for key in node.capture_keys() {
                     let Some(capture) = node.capture(*key) else {
@@ -549,3 +711,27 @@ impl NodeCoverage {
         Self::Fit(*node_ref)
     }
 }
+
+// Returns the syntax error referred to by the `error_ref` if it belongs to
+// the `unit` and intersects with the `span`.
+#[inline(always)]
+fn error_within<'unit>(
+    unit: &'unit impl CompilationUnit,
+    error_ref: &ErrorRef,
+    span: &SiteSpan,
+) -> Option<&'unit SyntaxError> {
+    let error = error_ref.deref(unit)?;
+
+    let start = error.span.start.to_site(unit)?;
+    let end = error.span.end.to_site(unit)?;
+
+    let within = match start == end || span.is_empty() {
+        true => start <= span.end && span.start <= end,
+        false => start < span.end && span.start < end,
+    };
+
+    match within {
+        true => Some(error),
+        false => None,
+    }
+}
@@ -38,15 +38,17 @@
 use std::time::{Duration, Instant};
 
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
-use lady_deirdre::{syntax::VoidSyntax, units::Document};
+use lady_deirdre::{arena::Repo, sync::Table, syntax::VoidSyntax, units::Document};
+use lady_deirdre_bench::BenchCommand;
 use lady_deirdre_examples::json_grammar::{lexis::JsonToken, syntax::JsonNode};
 use lady_deirdre_tests::{
-    data::{BenchCommand, BenchData},
+    data,
     lines::LineToken,
     logos::LogosJsonToken,
     nom::nom_parse,
     scan::LDStatelessScanner,
     ts::TSParser,
+    unicode::UnicodeJsonToken,
 };
 use logos::Logos;
 use ropey::Rope;
@@ -55,6 +57,8 @@ const PARSE: bool = true;
 const REPARSE: bool = true;
 const STORAGE: bool = true;
 const SCAN: bool = true;
+const REPO: bool = true;
+const TABLE: bool = true;
 
 const SMALL: bool = true;
 const LARGE: bool = true;
@@ -70,7 +74,7 @@ pub fn bench_parsing(criterion: &mut Criterion) {
         return;
     }
 
-    let (small_file, large_file) = BenchData::load();
+    let (small_file, large_file) = data::load();
 
     let Some(BenchCommand::Init { text: small_text }) = small_file.iter().next() else {
         panic!("Missing Small File init command.");
@@ -246,7 +250,7 @@ pub fn bench_reparsing(criterion: &mut Criterion) {
         return;
     }
 
-    let (small_file, large_file) = BenchData::load();
+    let (small_file, large_file) = data::load();
 
     let mut group = criterion.benchmark_group("Keystrokes Reparsing");
 
@@ -410,7 +414,7 @@ pub fn bench_storage(criterion: &mut Criterion) {
         return;
     }
 
-    let (small_file, large_file) = BenchData::load();
+    let (small_file, large_file) = data::load();
 
     let Some(BenchCommand::Init { text: small_text }) = small_file.iter().next() else {
         panic!("Missing Small File init command.");
@@ -692,7 +696,7 @@ pub fn bench_scanning(criterion: &mut Criterion) {
         return;
     }
 
-    let (small_file, large_file) = BenchData::load();
+    let (small_file, large_file) = data::load();
 
     let Some(BenchCommand::Init { text: small_text }) = small_file.iter().next() else {
         panic!("Missing Small File init command.");
@@ -748,6 +752,56 @@ pub fn bench_scanning(criterion: &mut Criterion) {
         });
     }
 
+    if LD && SMALL {
+        group.bench_function(
+            BenchmarkId::new("Lady Deirdre (Unicode path)", "Small File"),
+            |bencher| {
+                bencher.iter_custom(|iters| {
+                    let mut time = Duration::ZERO;
+
+                    for _ in 0..iters {
+                        let scanner = LDStatelessScanner::<UnicodeJsonToken>::new(small_text);
+
+                        let start = Instant::now();
+                        let mut last = scanner.last();
+                        time += start.elapsed();
+
+                        assert!(last.is_some());
+
+                        black_box(last);
+                    }
+
+                    time
+                });
+            },
+        );
+    }
+
+    if LD && LARGE {
+        group.bench_function(
+            BenchmarkId::new("Lady Deirdre (Unicode path)", "Large File"),
+            |bencher| {
+                bencher.iter_custom(|iters| {
+                    let mut time = Duration::ZERO;
+
+                    for _ in 0..iters {
+                        let scanner = LDStatelessScanner::<UnicodeJsonToken>::new(large_text);
+
+                        let start = Instant::now();
+                        let mut last = scanner.last();
+                        time += start.elapsed();
+
+                        assert!(last.is_some());
+
+                        black_box(last);
+                    }
+
+                    time
+                });
+            },
+        );
+    }
+
     if LOGOS && SMALL {
         group.bench_function(BenchmarkId::new("Logos", "Small File"), |bencher| {
             bencher.iter_custom(|iters| {
@@ -795,11 +849,114 @@ pub fn bench_scanning(criterion: &mut Criterion) {
     group.finish();
 }
 
+pub fn bench_repo(criterion: &mut Criterion) {
+    if !REPO {
+        return;
+    }
+
+    const COUNT: usize = 100_000;
+
+    let mut group = criterion.benchmark_group("Repo Insertion");
+
+    group.bench_function(BenchmarkId::new("One by One", COUNT), |bencher| {
+        bencher.iter_custom(|iters| {
+            let mut time = Duration::ZERO;
+
+            for _ in 0..iters {
+                let start = Instant::now();
+                let mut repo = Repo::new();
+                let mut entries = Vec::with_capacity(COUNT);
+
+                for value in 0..COUNT {
+                    entries.push(repo.insert(value));
+                }
+
+                time += start.elapsed();
+
+                black_box(entries);
+                black_box(repo);
+            }
+
+            time
+        });
+    });
+
+    group.bench_function(BenchmarkId::new("Bulk", COUNT), |bencher| {
+        bencher.iter_custom(|iters| {
+            let mut time = Duration::ZERO;
+
+            for _ in 0..iters {
+                let start = Instant::now();
+                let mut repo = Repo::new();
+                let entries = repo.extend(0..COUNT);
+                time += start.elapsed();
+
+                black_box(entries);
+                black_box(repo);
+            }
+
+            time
+        });
+    });
+
+    group.finish();
+}
+
+pub fn bench_table(criterion: &mut Criterion) {
+    if !TABLE {
+        return;
+    }
+
+    const THREADS: usize = 8;
+    const WRITES: usize = 10_000;
+
+    let mut group = criterion.benchmark_group("Table Concurrent Writes");
+
+    for shards in [1, 4, 16, 64] {
+        group.bench_function(
+            BenchmarkId::new(format!("{THREADS} Writers"), format!("{shards} Shards")),
+            |bencher| {
+                bencher.iter_custom(|iters| {
+                    let mut time = Duration::ZERO;
+
+                    for _ in 0..iters {
+                        let table = Table::<usize, usize>::with_shards(shards);
+
+                        let start = Instant::now();
+
+                        std::thread::scope(|scope| {
+                            for thread in 0..THREADS {
+                                let table = &table;
+
+                                scope.spawn(move || {
+                                    for key in 0..WRITES {
+                                        *table.entry(key * THREADS + thread).or_default() += 1;
+                                    }
+                                });
+                            }
+                        });
+
+                        time += start.elapsed();
+
+                        black_box(table);
+                    }
+
+                    time
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_parsing,
     bench_reparsing,
     bench_storage,
     bench_scanning,
+    bench_repo,
+    bench_table,
 );
 criterion_main!(benches);
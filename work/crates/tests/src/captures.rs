@@ -0,0 +1,87 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{cell::RefCell, mem::take};
+
+use lady_deirdre::lexis::Token;
+
+thread_local! {
+    static CAPTURES: RefCell<Vec<(CaptureToken, Vec<String>)>> = RefCell::new(Vec::new());
+}
+
+#[derive(Token, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum CaptureToken {
+    EOI = 0,
+    Mismatch = 1,
+
+    #[rule('"' body: ^['"']* '"')]
+    #[constructor(CaptureToken::String.record([fragment, body]))]
+    String,
+
+    #[rule(int: ['0'..'9']+ ('.' frac: ['0'..'9']+)?)]
+    #[constructor(CaptureToken::Number.record([fragment, int, frac]))]
+    Number,
+
+    #[rule('<' item: (['a'..'z']+) (',' item: (['a'..'z']+))* '>')]
+    #[constructor(CaptureToken::List.record([fragment, item]))]
+    List,
+
+    #[rule('#' head: (['a'..'z']*) ['a'..'z']*)]
+    #[constructor(CaptureToken::Tag.record([fragment, head]))]
+    Tag,
+
+    #[rule('@' name: $alpha+)]
+    #[constructor(CaptureToken::Mention.record([fragment, name]))]
+    Mention,
+
+    #[rule([' ']+)]
+    Whitespace,
+}
+
+impl CaptureToken {
+    pub fn take_captures() -> Vec<(Self, Vec<String>)> {
+        CAPTURES.with(|captures| take(&mut *captures.borrow_mut()))
+    }
+
+    fn record<const N: usize>(self, strings: [&str; N]) -> Self {
+        CAPTURES.with(|captures| {
+            captures
+                .borrow_mut()
+                .push((self, strings.into_iter().map(String::from).collect()))
+        });
+
+        self
+    }
+}
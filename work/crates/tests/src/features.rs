@@ -0,0 +1,120 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use lady_deirdre::{
+    analysis::{
+        AnalysisResult,
+        Attr,
+        AttrContext,
+        Computable,
+        Feature,
+        Semantics,
+        Slot,
+        TaskHandle,
+    },
+    lexis::TokenRef,
+    sync::SyncBuildHasher,
+    syntax::{Node, NodeRef},
+};
+use lady_deirdre_examples::expr_parser::lexis::BoolToken;
+
+#[derive(Node)]
+#[token(BoolToken)]
+#[trivia($Whitespace)]
+pub enum FeatureNode {
+    #[root]
+    #[rule(token: $True)]
+    Root {
+        #[node]
+        node: NodeRef,
+        #[parent]
+        parent: NodeRef,
+        #[child]
+        token: TokenRef,
+        #[semantics]
+        semantics: Semantics<RootSemantics>,
+    },
+}
+
+#[derive(Feature)]
+#[node(FeatureNode)]
+pub struct RootSemantics {
+    pub kind: KindFeature,
+    pub shared: Shared<usize>,
+    pub computed: Computed<Constant>,
+    pub empty: EmptyFeature,
+}
+
+// The Feature::new function constructs the #[default] variant.
+#[derive(Feature)]
+#[node(FeatureNode)]
+pub enum KindFeature {
+    Def {
+        #[scoped]
+        def: Attr<Constant>,
+        shared: Shared<String>,
+    },
+
+    #[default]
+    Use(Attr<Constant>, #[scoped] Slot<FeatureNode, usize>),
+
+    Unresolved,
+}
+
+#[derive(Feature)]
+#[node(FeatureNode)]
+pub struct Shared<T: Default + Send + Sync + 'static>(pub Slot<FeatureNode, T>);
+
+#[derive(Feature)]
+#[node(FeatureNode)]
+pub struct Computed<C>(pub Attr<C>)
+where
+    C: Computable<Node = FeatureNode> + Eq;
+
+#[derive(Feature)]
+#[node(FeatureNode)]
+pub struct EmptyFeature;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Constant(pub usize);
+
+impl Computable for Constant {
+    type Node = FeatureNode;
+
+    fn compute<H: TaskHandle, S: SyncBuildHasher>(
+        _context: &mut AttrContext<Self::Node, H, S>,
+    ) -> AnalysisResult<Self> {
+        Ok(Self(10))
+    }
+}
@@ -36,10 +36,11 @@ use std::{mem::take, ops::Range};
 
 use lady_deirdre::{
     lexis::{Length, Site, SiteSpan, SourceCode, ToSpan},
-    syntax::{NodeRef, PolyRef, SyntaxTree},
+    syntax::{NodeRef, PolyRef, SyntaxTree, ROOT_RULE},
+    testing::{GenExpr, GenGrammar},
     units::{CompilationUnit, Document},
 };
-use lady_deirdre_examples::json_grammar::syntax::JsonNode;
+use lady_deirdre_examples::json_grammar::{lexis::JsonToken, syntax::JsonNode};
 use petname::{Generator, Petnames};
 use rand::{
     distributions::{uniform::SampleRange, Distribution, WeightedIndex},
@@ -201,6 +202,8 @@ pub struct JsonEditsGen {
     key_names: Petnames<'static>,
     value_names: Petnames<'static>,
     content: String,
+    reparsed: usize,
+    reused: usize,
 }
 
 impl JsonEditsGen {
@@ -233,6 +236,8 @@ impl JsonEditsGen {
             key_names: Petnames::small(),
             value_names: Petnames::large(),
             content,
+            reparsed: 0,
+            reused: 0,
         }
     }
 
@@ -250,6 +255,16 @@ impl JsonEditsGen {
         }
     }
 
+    pub fn reuse_ratio(&self) -> f64 {
+        let total = self.reparsed + self.reused;
+
+        if total == 0 {
+            return 1.0;
+        }
+
+        self.reused as f64 / total as f64
+    }
+
     pub fn take_edits(&mut self) -> Vec<JsonEdit> {
         let result = take(&mut self.edits);
 
@@ -750,6 +765,11 @@ impl JsonEditsGen {
         for edit in &self.edits[self.committed..self.edits.len()] {
             edit.apply_to_doc(&mut self.doc);
 
+            if let Some(report) = self.doc.last_reparse() {
+                self.reparsed += report.nodes.len();
+                self.reused += report.reused;
+            }
+
             if self.config.checks {
                 edit.apply_to_string(&mut self.content);
             }
@@ -1065,3 +1085,79 @@ impl Action {
         }
     }
 }
+
+pub fn json_gen_grammar() -> GenGrammar<JsonNode> {
+    let any = || {
+        GenExpr::choice([
+            GenExpr::node(JsonNode::OBJECT),
+            GenExpr::node(JsonNode::ARRAY),
+            GenExpr::node(JsonNode::STRING),
+            GenExpr::node(JsonNode::NUMBER),
+            GenExpr::node(JsonNode::TRUE),
+            GenExpr::node(JsonNode::FALSE),
+            GenExpr::node(JsonNode::NULL),
+        ])
+    };
+
+    let mut grammar = GenGrammar::new();
+
+    grammar
+        .set_rule(ROOT_RULE, GenExpr::node(JsonNode::OBJECT))
+        .set_rule(
+            JsonNode::OBJECT,
+            GenExpr::seq([
+                GenExpr::token(JsonToken::BraceOpen),
+                GenExpr::zero_or_more(GenExpr::node(JsonNode::ENTRY))
+                    .with_separator(GenExpr::token(JsonToken::Comma)),
+                GenExpr::token(JsonToken::BraceClose),
+            ]),
+        )
+        .set_rule(
+            JsonNode::ENTRY,
+            GenExpr::seq([
+                GenExpr::node(JsonNode::STRING),
+                GenExpr::token(JsonToken::Colon),
+                any(),
+            ]),
+        )
+        .set_rule(
+            JsonNode::ARRAY,
+            GenExpr::seq([
+                GenExpr::token(JsonToken::BracketOpen),
+                GenExpr::zero_or_more(any()).with_separator(GenExpr::token(JsonToken::Comma)),
+                GenExpr::token(JsonToken::BracketClose),
+            ]),
+        )
+        .set_rule(JsonNode::STRING, GenExpr::token(JsonToken::String))
+        .set_rule(JsonNode::NUMBER, GenExpr::token(JsonToken::Number))
+        .set_rule(JsonNode::TRUE, GenExpr::token(JsonToken::True))
+        .set_rule(JsonNode::FALSE, GenExpr::token(JsonToken::False))
+        .set_rule(JsonNode::NULL, GenExpr::token(JsonToken::Null))
+        .set_token(JsonToken::BraceOpen, &["{"])
+        .set_token(JsonToken::BraceClose, &["}"])
+        .set_token(JsonToken::BracketOpen, &["["])
+        .set_token(JsonToken::BracketClose, &["]"])
+        .set_token(JsonToken::Comma, &[","])
+        .set_token(JsonToken::Colon, &[":"])
+        .set_token(JsonToken::True, &["true"])
+        .set_token(JsonToken::False, &["false"])
+        .set_token(JsonToken::Null, &["null"])
+        .set_token_fn(JsonToken::String, |rng| {
+            const WORDS: [&str; 6] = ["foo", "bar", "baz", "key", "value", "\\u00FC"];
+
+            let word = rng.choose(&WORDS).copied().unwrap_or_default();
+
+            format!("\"{word}{}\"", rng.gen_range(0..100))
+        })
+        .set_token_fn(JsonToken::Number, |rng| {
+            let integer = rng.gen_range(0..10000);
+
+            match rng.gen_ratio(1, 4) {
+                true => format!("{integer}.{}", rng.gen_range(0..100)),
+                false => format!("{integer}"),
+            }
+        })
+        .set_separators(&[" ", "\n", "\n    "]);
+
+    grammar
+}
@@ -35,32 +35,121 @@
 //TODO check warnings regularly
 #![allow(warnings)]
 
+pub mod binary;
+pub mod captures;
+pub mod comments;
+pub mod context;
 pub mod data;
+pub mod ebnf;
+pub mod features;
 pub mod gen;
+pub mod golden;
+pub mod keywords;
 pub mod lines;
 pub mod logos;
 pub mod nom;
 pub mod scan;
+pub mod slow;
 pub mod ts;
+pub mod unicode;
 
 #[cfg(test)]
 mod tests {
+    use std::{
+        fs::read_to_string,
+        path::Path,
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc,
+            Mutex,
+        },
+        thread,
+        time::{Duration, Instant},
+    };
+
     use lady_deirdre::{
-        lexis::{SourceCode, TokenBuffer},
-        syntax::VoidSyntax,
-        units::Document,
+        analysis::{
+            AbstractFeature,
+            AbstractTask,
+            AnalysisError,
+            AnalysisTask,
+            Analyzer,
+            AnalyzerConfig,
+            MutationAccess,
+            TaskHandle,
+            TriggerHandle,
+        },
+        arena::{Entry, Id, Identifiable, Repo, TypedEntry, WeakEntry},
+        assert_derive_hash,
+        assert_tree,
+        format::{
+            AnnotationPriority,
+            Color,
+            Diagnostic,
+            DiagnosticReport,
+            DiagnosticSeverity,
+            Style,
+        },
+        lexis::{
+            Graphemes,
+            Position,
+            SiteSpan,
+            SourceCode,
+            Token,
+            TokenBuffer,
+            TokenCursor,
+            TokenRef,
+            UNICODE_VERSION,
+        },
+        lsp::{LspPosition, PositionEncoding},
+        sync::Table,
+        syntax::{
+            AbstractNode,
+            Capture,
+            Key,
+            NodePath,
+            NodeRef,
+            ParseNode,
+            ParseNodeChild,
+            ParseToken,
+            ParseTree,
+            PathPolicy,
+            PolyRef,
+            SyntaxTree,
+            VoidSyntax,
+        },
+        testing::{
+            check_differential,
+            check_reparse,
+            DiffConfig,
+            GenConfig,
+            GenEditKind,
+            GrammarGen,
+            TreeSnapshot,
+        },
+        units::{CompilationUnit, Document, ParseDriver, Restored},
     };
+    use lady_deirdre_bench::{BenchCommand, BenchData, BenchRecorder, BenchStepKind};
     use lady_deirdre_examples::json_grammar::{lexis::JsonToken, syntax::JsonNode};
     use logos::Logos;
     use rand::prelude::*;
 
     use crate::{
-        data::{BenchCommand, BenchData},
-        gen::{JsonBootstrapGen, JsonEditsGen, JsonGenConfig},
+        binary::BinaryNode,
+        captures::CaptureToken,
+        comments::CommentToken,
+        context::{ContextToken, DirectiveToken},
+        data,
+        features::{Constant, FeatureNode, KindFeature},
+        gen::{json_gen_grammar, JsonBootstrapGen, JsonEditsGen, JsonGenConfig},
+        golden,
+        keywords::KeywordToken,
         lines::LineToken,
         logos::LogosJsonToken,
         scan::LDStatelessScanner,
+        slow::SlowNode,
         ts::TSParser,
+        unicode::UnicodeJsonToken,
     };
 
     #[test]
@@ -101,148 +190,2438 @@ mod tests {
                 config.code_max_length
             );
             println!("    Total edits: {}", edits.len());
+            println!("    Reuse ratio: {:.2}", edits_gen.reuse_ratio());
+
+            assert!(edits_gen.reuse_ratio() > 0.25);
 
             edits_gen.check();
         }
     }
 
     #[test]
-    fn test_bench_data() {
-        let (small, large) = BenchData::load();
+    fn test_json_grammar_generation() {
+        const SEED: u64 = 1000;
+        const ITERATIONS: u64 = 100;
+        const EDITS_PER_ITERATION: usize = 100;
+
+        let grammar = json_gen_grammar();
+
+        for iteration in 1..=ITERATIONS {
+            println!("Iteration: {iteration}:");
+
+            let config = GenConfig {
+                seed: SEED + iteration,
+                ..GenConfig::new()
+            };
+
+            let mut generator = GrammarGen::new(&grammar, config);
+            let mut text = generator.text();
+            let mut doc = Document::<JsonNode>::new_mutable(&text);
+
+            println!("    Init length: {}", doc.length());
+
+            let mut counts = [0; 3];
+
+            for _ in 0..EDITS_PER_ITERATION {
+                let Some(edit) = generator.gen_edit() else {
+                    break;
+                };
+
+                counts[match edit.kind {
+                    GenEditKind::Insert => 0,
+                    GenEditKind::Delete => 1,
+                    GenEditKind::Replace => 2,
+                }] += 1;
+
+                edit.apply_to_string(&mut text);
+                edit.apply_to_document(&mut doc);
+
+                assert_eq!(text, doc.substring(..));
+                assert_eq!(doc.errors().count(), 0);
 
-        small.check();
-        large.check();
+                check_reparse(&doc);
+            }
+
+            println!("    Length after edits: {}", doc.length());
+            println!(
+                "    Edits (insert/delete/replace): {}/{}/{}",
+                counts[0], counts[1], counts[2],
+            );
+        }
     }
 
     #[test]
-    fn test_ts_parser() {
-        let (small, _) = BenchData::load();
+    fn test_document_checkpoints() {
+        const SEED: u64 = 4000;
+        const ITERATIONS: u64 = 50;
+        const EDITS_BEFORE: usize = 20;
+        const EDITS_AFTER: usize = 30;
 
-        let mut ld_parser = Document::new_mutable("");
-        let mut ts_parser = TSParser::new();
+        fn token_refs(doc: &Document<JsonNode>) -> Vec<TokenRef> {
+            let mut cursor = doc.cursor(..);
+            let mut result = Vec::with_capacity(doc.tokens());
 
-        for (index, command) in small.iter().enumerate() {
-            match command {
-                BenchCommand::Init { text } => {
-                    ld_parser.write(.., text);
-                    ts_parser.parse(text);
+            loop {
+                let token_ref = cursor.token_ref(0);
 
-                    ts_parser.check_length();
-                    ts_parser.compare_trees(&ld_parser);
+                if token_ref.is_nil() {
+                    break;
                 }
 
-                BenchCommand::Edit {
-                    site_span,
-                    position_span,
-                    new_end_position,
-                    text,
-                } => {
-                    ld_parser.write(&site_span, text);
-                    ts_parser.reparse(site_span, position_span, new_end_position, text);
+                result.push(token_ref);
+
+                if !cursor.advance() {
+                    break;
                 }
+            }
 
-                BenchCommand::Wait => {
-                    ts_parser.check_length();
-                    ts_parser.compare_trees(&ld_parser);
+            assert_eq!(result.len(), doc.tokens());
+
+            result
+        }
+
+        fn check_text(doc: &Document<JsonNode>, text: &str) {
+            let fresh = Document::<JsonNode>::new_immutable(text);
+
+            assert_eq!(doc.substring(..), text);
+            assert_eq!(
+                doc.chunks(..).collect::<Vec<_>>(),
+                fresh.chunks(..).collect::<Vec<_>>(),
+            );
+
+            check_reparse(doc);
+        }
+
+        let grammar = json_gen_grammar();
+
+        for iteration in 1..=ITERATIONS {
+            let config = GenConfig {
+                seed: SEED + iteration,
+                ..GenConfig::new()
+            };
+
+            let mut generator = GrammarGen::new(&grammar, config);
+            let mut doc = Document::<JsonNode>::new_mutable(generator.text());
+
+            for _ in 0..EDITS_BEFORE {
+                let Some(edit) = generator.gen_edit() else {
+                    break;
+                };
+
+                edit.apply_to_document(&mut doc);
+            }
+
+            let checkpoint = doc.checkpoint();
+            let checkpoint_text = doc.substring(..).to_string();
+            let checkpoint_refs = token_refs(&doc)
+                .into_iter()
+                .map(|token_ref| {
+                    let site = token_ref.site(&doc).unwrap();
+                    let string = token_ref.string(&doc).unwrap().to_string();
+
+                    (token_ref, site, string)
+                })
+                .collect::<Vec<_>>();
+
+            for _ in 0..EDITS_AFTER {
+                let Some(edit) = generator.gen_edit() else {
+                    break;
+                };
+
+                edit.apply_to_document(&mut doc);
+            }
+
+            let edited_text = doc.substring(..).to_string();
+            let edited_refs = token_refs(&doc)
+                .into_iter()
+                .filter(|token_ref| {
+                    !checkpoint_refs
+                        .iter()
+                        .any(|(checkpoint_ref, _, _)| checkpoint_ref == token_ref)
+                })
+                .collect::<Vec<_>>();
+
+            let redo = doc.checkpoint();
+
+            doc.revert(&checkpoint);
+
+            check_text(&doc, &checkpoint_text);
+
+            for token_ref in &edited_refs {
+                assert!(!token_ref.is_valid_ref(&doc));
+            }
+
+            for (token_ref, site, string) in &checkpoint_refs {
+                if !token_ref.is_valid_ref(&doc) {
+                    continue;
                 }
+
+                assert_eq!(token_ref.site(&doc), Some(*site));
+                assert_eq!(token_ref.string(&doc), Some(string.as_str()));
             }
 
-            println!("Command {index} OK.");
+            doc.revert(&redo);
+
+            check_text(&doc, &edited_text);
+
+            doc.revert(&checkpoint);
+
+            check_text(&doc, &checkpoint_text);
+
+            doc.clear_history();
+
+            let checkpoint = doc.checkpoint();
+
+            doc.revert(&checkpoint);
+
+            check_text(&doc, &checkpoint_text);
         }
     }
 
     #[test]
-    fn test_line_lexer() {
-        let (small, _) = BenchData::load();
+    fn test_document_checkpoint_refs() {
+        fn token_ref(doc: &Document<JsonNode>, string: &str) -> TokenRef {
+            let mut cursor = doc.cursor(..);
 
-        let mut json_doc = Document::<VoidSyntax<JsonToken>>::new_mutable("");
-        let mut line_doc = Document::<VoidSyntax<LineToken>>::new_mutable("");
+            loop {
+                let token_ref = cursor.token_ref(0);
 
-        for (index, command) in small.iter().enumerate() {
-            match command {
-                BenchCommand::Init { text } => {
-                    json_doc.write(.., text);
-                    line_doc.write(.., text);
+                if token_ref.string(doc) == Some(string) {
+                    return token_ref;
                 }
-                BenchCommand::Edit {
-                    site_span, text, ..
-                } => {
-                    json_doc.write(&site_span, text);
-                    line_doc.write(site_span, text);
+
+                if !cursor.advance() {
+                    panic!("Missing {string:?} token.");
                 }
-                BenchCommand::Wait => (),
             }
+        }
 
-            assert_eq!(json_doc.substring(..), line_doc.substring(..));
-            assert_eq!(line_doc.lines().lines_count(), line_doc.tokens() + 1);
+        let mut doc = Document::<JsonNode>::new_mutable("{\"a\": [1, 2, 3], \"b\": [4}");
 
-            println!("Command {index} OK.");
-        }
+        let root = doc.root_node_ref();
+        let two = token_ref(&doc, "2");
+        let four = token_ref(&doc, "4");
+        let nodes = doc.node_refs().collect::<Vec<_>>();
+        let errors = doc.error_refs().collect::<Vec<_>>();
+
+        assert!(!errors.is_empty());
+
+        let checkpoint = doc.checkpoint();
+
+        doc.write(10..11, "20");
+        doc.write(25..25, ", 5]");
+
+        let twenty = token_ref(&doc, "20");
+        let five = token_ref(&doc, "5");
+
+        doc.revert(&checkpoint);
+
+        assert_eq!(doc.substring(..), "{\"a\": [1, 2, 3], \"b\": [4}");
+
+        // The references valid at the checkpoint resolve again, because
+        // the reverted content is identical.
+
+        assert!(root.is_valid_ref(&doc));
+        assert_eq!(two.string(&doc), Some("2"));
+        assert_eq!(four.string(&doc), Some("4"));
+        assert_eq!(token_ref(&doc, "2"), two);
+        assert_eq!(doc.node_refs().collect::<Vec<_>>(), nodes);
+        assert_eq!(doc.error_refs().collect::<Vec<_>>(), errors);
+
+        // The references obtained after the checkpoint become invalid.
+
+        assert!(!twenty.is_valid_ref(&doc));
+        assert!(!five.is_valid_ref(&doc));
     }
 
     #[test]
-    fn test_stateless_scanner() {
-        let (small, large) = BenchData::load();
+    fn test_parse_driver() {
+        const SEED: u64 = 5000;
+        const ITERATIONS: u64 = 20;
+        const EDITS: usize = 20;
+        const BUDGET: usize = 16;
 
-        let Some(BenchCommand::Init { text }) = small.iter().next() else {
-            panic!("Missing Small File init command.");
-        };
+        fn drive_write(doc: Document<JsonNode>, span: SiteSpan, text: &str) -> Document<JsonNode> {
+            let mut driver = ParseDriver::write(doc, span, text);
 
-        let tokens = LDStatelessScanner::<JsonToken>::new(text).collect::<Vec<_>>();
-        let buffer = TokenBuffer::<JsonToken>::parse(text);
+            while driver.step(BUDGET).is_pending() {
+                assert!(driver.document().is_none());
+            }
 
-        assert_eq!(tokens.len(), buffer.tokens());
+            assert!(driver.is_done());
+            assert!(driver.document().is_some());
 
-        for (a, b) in tokens.iter().zip(buffer.chunks(..)) {
-            assert_eq!(a, &b.token);
+            driver.finish()
         }
 
-        let Some(BenchCommand::Init { text }) = large.iter().next() else {
-            panic!("Missing Small File init command.");
-        };
+        fn reparse_spans(doc: &Document<JsonNode>) -> Option<(SiteSpan, SiteSpan)> {
+            let report = doc.last_reparse()?;
 
-        let tokens = LDStatelessScanner::<JsonToken>::new(text).collect::<Vec<_>>();
-        let buffer = TokenBuffer::<JsonToken>::parse(text);
+            Some((report.rescanned.clone(), report.span.clone()))
+        }
 
-        assert_eq!(tokens.len(), buffer.tokens());
+        let grammar = json_gen_grammar();
 
-        for (a, b) in tokens.iter().zip(buffer.chunks(..)) {
-            assert_eq!(a, &b.token);
+        for iteration in 1..=ITERATIONS {
+            let config = GenConfig {
+                seed: SEED + iteration,
+                ..GenConfig::new()
+            };
+
+            let mut generator = GrammarGen::new(&grammar, config);
+
+            let mut driver = ParseDriver::<JsonNode>::new_mutable(generator.text());
+
+            while driver.step(BUDGET).is_pending() {
+                assert!(driver.document().is_none());
+            }
+
+            let mut doc = driver.finish();
+            let mut twin = Document::<JsonNode>::new_mutable(generator.text());
+
+            assert_eq!(doc.substring(..), twin.substring(..));
+
+            check_reparse(&doc);
+
+            for _ in 0..EDITS {
+                let Some(edit) = generator.gen_edit() else {
+                    break;
+                };
+
+                let checkpoint = doc.checkpoint();
+                let before = doc.substring(..).to_string();
+
+                doc = drive_write(doc, edit.span.clone(), &edit.text);
+                edit.apply_to_document(&mut twin);
+
+                assert_eq!(doc.substring(..), twin.substring(..));
+                assert_eq!(reparse_spans(&doc), reparse_spans(&twin));
+
+                check_reparse(&doc);
+
+                // The edit is a single entry in the document's history.
+
+                doc.revert(&checkpoint);
+
+                assert_eq!(doc.substring(..), before);
+
+                doc = drive_write(doc, edit.span.clone(), &edit.text);
+
+                check_reparse(&doc);
+
+                doc.clear_history();
+            }
+
+            // An unbalanced bracket makes the reparser escalate to
+            // the enclosing clusters.
+
+            let span = doc
+                .substring(..)
+                .chars()
+                .position(|ch| ch == '[')
+                .map_or(0, |start| start + 1);
+
+            doc = drive_write(doc, span..span, "[");
+            twin.write(span..span, "[");
+
+            assert_eq!(doc.substring(..), twin.substring(..));
+
+            check_reparse(&doc);
+
+            // A large edit is scanned in several steps.
+
+            let text = format!("[{}1]", "1, ".repeat(100));
+            let mut driver = ParseDriver::write(doc, .., text.as_str());
+
+            assert!(driver.step(BUDGET).is_pending());
+            assert!(driver.progress() < 1.0);
+
+            doc = driver.finish();
+            twin.write(.., &text);
+
+            assert_eq!(doc.substring(..), twin.substring(..));
+            assert_eq!(reparse_spans(&doc), reparse_spans(&twin));
+
+            check_reparse(&doc);
         }
     }
 
     #[test]
-    fn test_logos() {
-        let (small, large) = BenchData::load();
+    fn test_json_differential() {
+        let grammar = json_gen_grammar();
 
-        let Some(BenchCommand::Init { text }) = small.iter().next() else {
-            panic!("Missing Small File init command.");
-        };
+        let configs = [
+            DiffConfig {
+                gen: GenConfig {
+                    seed: 2000,
+                    max_depth: 4,
+                    ..GenConfig::new()
+                },
+                runs: 50,
+                edits: 50,
+                ..DiffConfig::new()
+            },
+            DiffConfig {
+                gen: GenConfig {
+                    seed: 3000,
+                    ..GenConfig::new()
+                },
+                runs: 20,
+                edits: 200,
+                period: 10,
+                ..DiffConfig::new()
+            },
+        ];
 
-        let tokens = LogosJsonToken::lexer(text)
-            .map(|result| result.unwrap())
-            .collect::<Vec<_>>();
-        let buffer = TokenBuffer::<JsonToken>::parse(text);
+        for config in &configs {
+            if let Err(failure) = check_differential(&grammar, config) {
+                panic!("{failure}");
+            }
+        }
+    }
 
-        assert_eq!(tokens.len(), buffer.tokens());
+    #[test]
+    fn test_document_line_edits() {
+        fn model_site(model: &[char], position: &Position) -> usize {
+            let mut starts = vec![0];
 
-        for (a, b) in tokens.iter().zip(buffer.chunks(..)) {
-            assert_eq!(a.into_ld(), b.token);
+            for (index, ch) in model.iter().enumerate() {
+                if *ch == '\n' {
+                    starts.push(index + 1);
+                }
+            }
+
+            if position.line > starts.len() {
+                return model.len();
+            }
+
+            let line = position.line.max(1) - 1;
+            let start = starts[line];
+            let end = starts.get(line + 1).copied().unwrap_or(model.len());
+
+            let content_end = match &model[start..end] {
+                [.., '\r', '\n'] => end - 2,
+                [.., '\n'] => end - 1,
+                _ => end,
+            };
+
+            start
+                .saturating_add(position.column.max(1) - 1)
+                .min(content_end)
         }
 
-        let Some(BenchCommand::Init { text }) = large.iter().next() else {
-            panic!("Missing Large File init command.");
-        };
+        static FRAGMENTS: [&str; 9] = [
+            "",
+            "a",
+            "\n",
+            "\r\n",
+            "\r",
+            "{\"a\": 1}",
+            "x\r\ny",
+            "字\n😀",
+            "[1,\r\n2]\n",
+        ];
 
-        let tokens = LogosJsonToken::lexer(text)
-            .map(|result| result.unwrap())
+        let text = "{\r\n  \"a\": [1, 2],\n  \"b\": \"字\"\r\n}\n";
+
+        let mut rng = StdRng::seed_from_u64(data::DEFAULT_SEED);
+        let mut doc = Document::<JsonNode>::new_mutable(text);
+        let mut model = text.chars().collect::<Vec<_>>();
+
+        for _ in 0..1000 {
+            let lines = doc.lines().lines_count();
+            let fragment = FRAGMENTS[rng.gen_range(0..FRAGMENTS.len())];
+
+            let (start, end) = match rng.gen_bool(0.3) {
+                true => {
+                    let line = rng.gen_range(0..=lines + 1);
+
+                    doc.replace_line(line, fragment);
+
+                    (
+                        model_site(&model, &Position::new(line, 1)),
+                        model_site(&model, &Position::new(line, usize::MAX)),
+                    )
+                }
+
+                false => {
+                    let from = Position::new(rng.gen_range(0..=lines + 1), rng.gen_range(0..20));
+                    let to = Position::new(rng.gen_range(0..=lines + 1), rng.gen_range(0..20));
+                    let mut span = from..to;
+
+                    if model_site(&model, &span.start) > model_site(&model, &span.end) {
+                        span = span.end..span.start;
+                    }
+
+                    doc.write_lines(span.clone(), fragment);
+
+                    (
+                        model_site(&model, &span.start),
+                        model_site(&model, &span.end),
+                    )
+                }
+            };
+
+            let _ = model.splice(start..end, fragment.chars());
+
+            let expected = model.iter().collect::<String>();
+
+            assert_eq!(doc.substring(..), expected);
+            assert_eq!(
+                doc.lines().lines_count(),
+                expected.matches('\n').count() + 1,
+            );
+        }
+    }
+
+    #[test]
+    fn test_negated_class_alphabet() {
+        let buffer = TokenBuffer::<CommentToken>::parse("// ab\nxy //я\nюя//\n");
+
+        let tokens = buffer
+            .chunks(..)
+            .map(|chunk| (chunk.token, chunk.string))
             .collect::<Vec<_>>();
-        let buffer = TokenBuffer::<JsonToken>::parse(text);
 
-        assert_eq!(tokens.len(), buffer.tokens());
+        assert_eq!(
+            tokens,
+            [
+                (CommentToken::Comment, "// ab"),
+                (CommentToken::Whitespace, "\n"),
+                (CommentToken::Ident, "xy"),
+                (CommentToken::Whitespace, " "),
+                (CommentToken::Comment, "//я"),
+                (CommentToken::Whitespace, "\n"),
+                (CommentToken::Ident, "юя"),
+                (CommentToken::Comment, "//"),
+                (CommentToken::Whitespace, "\n"),
+            ],
+        );
+    }
 
-        for (a, b) in tokens.iter().zip(buffer.chunks(..)) {
-            assert_eq!(a.into_ld(), b.token);
+    #[test]
+    fn test_bench_data() {
+        let (small, large) = data::load();
+
+        small.check::<JsonNode>();
+        large.check::<JsonNode>();
+    }
+
+    #[test]
+    fn test_cursor_back() {
+        fn check(code: &impl SourceCode<Token = JsonToken>, span: SiteSpan) {
+            let forward = code.chunks(span.clone()).collect::<Vec<_>>();
+            let backward = code.cursor_back(span.clone()).collect::<Vec<_>>();
+
+            assert_eq!(forward.len(), backward.len(), "Span: {span:?}.");
+
+            for (forward, backward) in forward.iter().zip(backward.iter().rev()) {
+                assert_eq!(forward, backward, "Span: {span:?}.");
+            }
         }
+
+        fn check_spans(code: &impl SourceCode<Token = JsonToken>, rng: &mut StdRng) {
+            let length = code.length();
+
+            check(code, 0..length);
+            check(code, 0..0);
+            check(code, length..length);
+
+            for _ in 0..20 {
+                let start = rng.gen_range(0..=length);
+                let end = rng.gen_range(start..=length);
+
+                check(code, start..end);
+            }
+        }
+
+        let mut rng = StdRng::seed_from_u64(data::DEFAULT_SEED);
+
+        let (small, large) = data::load();
+
+        for bench in [&small, &large] {
+            let mut doc = Document::<JsonNode>::new_mutable("");
+
+            for command in bench.iter() {
+                match command {
+                    BenchCommand::Init { text } => {
+                        check_spans(&TokenBuffer::<JsonToken>::parse(text), &mut rng);
+
+                        doc = Document::new_mutable(text);
+
+                        check_spans(&doc, &mut rng);
+                    }
+
+                    BenchCommand::Edit {
+                        site_span, text, ..
+                    } => doc.write(site_span, text),
+
+                    BenchCommand::Wait => (),
+                }
+            }
+
+            check_spans(&doc, &mut rng);
+        }
+
+        check_spans(&Document::<JsonNode>::new_mutable(""), &mut rng);
+        check_spans(&TokenBuffer::<JsonToken>::parse(""), &mut rng);
+    }
+
+    #[test]
+    fn test_line_index_encodings() {
+        fn check(code: &impl SourceCode) {
+            let text = code.substring(..);
+            let lines = code.lines();
+
+            let mut byte = 0;
+            let mut line = 1;
+            let mut column = 0;
+            let mut line_byte = 0;
+
+            for (site, ch) in text.chars().enumerate() {
+                assert_eq!(lines.byte_of(site), byte);
+                assert_eq!(lines.to_utf16_position(site), (line, column));
+                assert_eq!(lines.from_utf16_position(line, column), site);
+
+                if ch.len_utf16() == 2 {
+                    assert_eq!(lines.from_utf16_position(line, column + 1), site);
+                }
+
+                byte += ch.len_utf8();
+                column += ch.len_utf16();
+
+                if ch == '\n' {
+                    assert_eq!(lines.from_utf16_position(line, column + 10), site);
+                    assert_eq!(lines.line_byte_range(line), line_byte..byte);
+
+                    line += 1;
+                    column = 0;
+                    line_byte = byte;
+                }
+            }
+
+            let length = code.length();
+
+            assert_eq!(lines.byte_of(length), text.len());
+            assert_eq!(lines.to_utf16_position(length), (line, column));
+            assert_eq!(lines.from_utf16_position(line, column + 10), length);
+            assert_eq!(lines.line_byte_range(line), line_byte..text.len());
+            assert_eq!(lines.lines_count(), line);
+        }
+
+        let (small, large) = data::load();
+
+        for bench in [&small, &large] {
+            let mut doc = Document::<JsonNode>::new_mutable("");
+
+            for command in bench.iter() {
+                match command {
+                    BenchCommand::Init { text } => {
+                        check(&TokenBuffer::<JsonToken>::parse(text));
+
+                        doc = Document::new_mutable(text);
+
+                        check(&doc);
+                    }
+
+                    BenchCommand::Edit {
+                        site_span, text, ..
+                    } => doc.write(site_span, text),
+
+                    BenchCommand::Wait => (),
+                }
+            }
+
+            check(&doc);
+        }
+
+        const FRAGMENTS: [&str; 10] = [
+            "",
+            "a",
+            "\n",
+            "\r\n",
+            "щ",
+            "字",
+            "😀",
+            "𝕏𝕐",
+            "\"日本語\"",
+            "🎉\n字",
+        ];
+
+        let text = "{\n  \"名前\": \"😀 テスト 𝕏\",\r\n  \"a\": [\"Привет\", \"🎉🎉\"]\n}";
+
+        let mut rng = StdRng::seed_from_u64(data::DEFAULT_SEED);
+        let mut doc = Document::<JsonNode>::new_mutable(text);
+
+        check(&doc);
+        check(&TokenBuffer::<JsonToken>::parse(text));
+
+        for _ in 0..500 {
+            let length = doc.length();
+            let start = rng.gen_range(0..=length);
+            let end = rng.gen_range(start..=(start + 5).min(length));
+            let fragment = FRAGMENTS[rng.gen_range(0..FRAGMENTS.len())];
+
+            doc.write(start..end, fragment);
+
+            check(&doc);
+        }
+
+        check(&TokenBuffer::<JsonToken>::parse(doc.substring(..).as_ref()));
+    }
+
+    #[test]
+    fn test_bench_recorder() {
+        let mut recorder = BenchRecorder::<JsonNode>::new("Session");
+
+        recorder.set_meta("meta");
+        recorder.init("{\"a\": [1, 2]}");
+        recorder.edit(11..11, ", 3");
+        recorder.wait();
+        recorder.edit(Position::new(1, 3)..Position::new(1, 4), "b");
+        recorder.edit(1..1, "\n");
+        recorder.wait();
+
+        assert_eq!(recorder.document().substring(..), "{\n\"b\": [1, 2, 3]}");
+
+        let data = recorder.finish();
+
+        assert_eq!(data.title(), "Session");
+        assert_eq!(data.meta(), "meta");
+        assert_eq!(data.ops(), 6);
+        assert_eq!(data.edits(), 2);
+
+        data.check::<JsonNode>();
+
+        let data = BenchData::from_json(&data.to_json().unwrap()).unwrap();
+
+        let Some(BenchCommand::Edit {
+            site_span,
+            position_span,
+            new_end_position,
+            text,
+        }) = data.iter().nth(4)
+        else {
+            panic!("Missing edit command.");
+        };
+
+        assert_eq!(site_span, 1..1);
+        assert_eq!(position_span, Position::new(1, 2)..Position::new(1, 2));
+        assert_eq!(new_end_position, Position::new(2, 1));
+        assert_eq!(text, "\n");
+
+        let report = data.replay::<JsonNode>();
+
+        assert_eq!(report.steps.len(), 6);
+        assert_eq!(report.steps[0].kind, BenchStepKind::Init);
+        assert_eq!(report.steps[2].kind, BenchStepKind::Wait);
+        assert_eq!(report.edits().count(), 3);
+        assert!(report.steps[1].reparsed > 0);
+
+        let (small, _) = data::load();
+
+        let report = small.replay::<JsonNode>();
+
+        assert_eq!(report.steps.len(), small.ops());
+        assert!(report.mean_reuse_ratio() > 0.0);
+    }
+
+    #[test]
+    fn test_ts_parser() {
+        let (small, _) = data::load();
+
+        let mut ld_parser = Document::new_mutable("");
+        let mut ts_parser = TSParser::new();
+
+        for (index, command) in small.iter().enumerate() {
+            match command {
+                BenchCommand::Init { text } => {
+                    ld_parser.write(.., text);
+                    ts_parser.parse(text);
+
+                    ts_parser.check_length();
+                    ts_parser.compare_trees(&ld_parser);
+                }
+
+                BenchCommand::Edit {
+                    site_span,
+                    position_span,
+                    new_end_position,
+                    text,
+                } => {
+                    ld_parser.write(&site_span, text);
+                    ts_parser.reparse(site_span, position_span, new_end_position, text);
+                }
+
+                BenchCommand::Wait => {
+                    ts_parser.check_length();
+                    ts_parser.compare_trees(&ld_parser);
+                }
+            }
+
+            println!("Command {index} OK.");
+        }
+    }
+
+    #[test]
+    fn test_line_lexer() {
+        let (small, _) = data::load();
+
+        let mut json_doc = Document::<VoidSyntax<JsonToken>>::new_mutable("");
+        let mut line_doc = Document::<VoidSyntax<LineToken>>::new_mutable("");
+
+        for (index, command) in small.iter().enumerate() {
+            match command {
+                BenchCommand::Init { text } => {
+                    json_doc.write(.., text);
+                    line_doc.write(.., text);
+                }
+                BenchCommand::Edit {
+                    site_span, text, ..
+                } => {
+                    json_doc.write(&site_span, text);
+                    line_doc.write(site_span, text);
+                }
+                BenchCommand::Wait => (),
+            }
+
+            assert_eq!(json_doc.substring(..), line_doc.substring(..));
+            assert_eq!(line_doc.lines().lines_count(), line_doc.tokens() + 1);
+
+            println!("Command {index} OK.");
+        }
+    }
+
+    #[test]
+    fn test_stateless_scanner() {
+        let (small, large) = data::load();
+
+        let Some(BenchCommand::Init { text }) = small.iter().next() else {
+            panic!("Missing Small File init command.");
+        };
+
+        let tokens = LDStatelessScanner::<JsonToken>::new(text).collect::<Vec<_>>();
+        let buffer = TokenBuffer::<JsonToken>::parse(text);
+
+        assert_eq!(tokens.len(), buffer.tokens());
+
+        for (a, b) in tokens.iter().zip(buffer.chunks(..)) {
+            assert_eq!(a, &b.token);
+        }
+
+        let Some(BenchCommand::Init { text }) = large.iter().next() else {
+            panic!("Missing Small File init command.");
+        };
+
+        let tokens = LDStatelessScanner::<JsonToken>::new(text).collect::<Vec<_>>();
+        let buffer = TokenBuffer::<JsonToken>::parse(text);
+
+        assert_eq!(tokens.len(), buffer.tokens());
+
+        for (a, b) in tokens.iter().zip(buffer.chunks(..)) {
+            assert_eq!(a, &b.token);
+        }
+    }
+
+    #[test]
+    fn test_context_guards() {
+        let buffer = TokenBuffer::<ContextToken>::parse("x = -a / (b - 1) /c d/ - 2");
+
+        assert_eq!(
+            buffer
+                .chunks(..)
+                .filter(|chunk| chunk.token != ContextToken::Whitespace)
+                .map(|chunk| chunk.token)
+                .collect::<Vec<_>>(),
+            [
+                ContextToken::Ident,
+                ContextToken::Assign,
+                ContextToken::Negate,
+                ContextToken::Ident,
+                ContextToken::Div,
+                ContextToken::Open,
+                ContextToken::Ident,
+                ContextToken::Minus,
+                ContextToken::Number,
+                ContextToken::Close,
+                ContextToken::Div,
+                ContextToken::Ident,
+                ContextToken::Ident,
+                ContextToken::Div,
+                ContextToken::Negate,
+                ContextToken::Number,
+            ],
+        );
+
+        let text = "x = a // comment\n /b c/ = -1";
+
+        let tokens = LDStatelessScanner::<ContextToken>::new(text).collect::<Vec<_>>();
+        let buffer = TokenBuffer::<ContextToken>::parse(text);
+
+        assert_eq!(
+            tokens,
+            buffer
+                .chunks(..)
+                .map(|chunk| chunk.token)
+                .collect::<Vec<_>>(),
+        );
+
+        let mut doc = Document::<VoidSyntax<ContextToken>>::new_mutable(text);
+
+        let edits = [
+            (4..5, "("),
+            (4..5, "a"),
+            (4..6, "= "),
+            (5..5, " 1"),
+            (21..21, "/"),
+            (0..0, "/ "),
+            (0..2, ""),
+        ];
+
+        for (span, edit) in edits {
+            doc.write(span, edit);
+
+            check_reparse(&doc);
+
+            let fresh = TokenBuffer::<ContextToken>::parse(doc.substring(..));
+
+            assert!(doc
+                .chunks(..)
+                .map(|chunk| (chunk.token, chunk.string))
+                .eq(fresh.chunks(..).map(|chunk| (chunk.token, chunk.string))));
+        }
+    }
+
+    #[test]
+    fn test_line_anchors() {
+        fn tokens(code: &impl SourceCode<Token = DirectiveToken>) -> Vec<(DirectiveToken, &str)> {
+            code.chunks(..)
+                .filter(|chunk| chunk.token != DirectiveToken::Whitespace)
+                .map(|chunk| (chunk.token, chunk.string))
+                .collect()
+        }
+
+        assert_eq!(DirectiveToken::LOOKBACK, 3);
+
+        let text = "#include <a.b>\nx #define\n#if end\nend x\\\n\\ y";
+
+        let buffer = TokenBuffer::<DirectiveToken>::parse(text);
+
+        assert_eq!(
+            tokens(&buffer),
+            [
+                (DirectiveToken::Directive, "#include"),
+                (DirectiveToken::Punct, "<"),
+                (DirectiveToken::Ident, "a"),
+                (DirectiveToken::Punct, "."),
+                (DirectiveToken::Ident, "b"),
+                (DirectiveToken::Punct, ">"),
+                (DirectiveToken::Newline, "\n"),
+                (DirectiveToken::Ident, "x"),
+                (DirectiveToken::Mismatch, "#"),
+                (DirectiveToken::Ident, "define"),
+                (DirectiveToken::Newline, "\n"),
+                (DirectiveToken::Directive, "#if"),
+                (DirectiveToken::End, "end"),
+                (DirectiveToken::Newline, "\n"),
+                (DirectiveToken::Ident, "end"),
+                (DirectiveToken::Ident, "x"),
+                (DirectiveToken::Continuation, "\\"),
+                (DirectiveToken::Newline, "\n"),
+                (DirectiveToken::Mismatch, "\\"),
+                (DirectiveToken::Ident, "y"),
+            ],
+        );
+
+        assert_eq!(
+            LDStatelessScanner::<DirectiveToken>::new(text).collect::<Vec<_>>(),
+            buffer
+                .chunks(..)
+                .map(|chunk| chunk.token)
+                .collect::<Vec<_>>(),
+        );
+
+        let mut buffer = TokenBuffer::<DirectiveToken>::parse("#if x end");
+
+        assert_eq!(
+            tokens(&buffer),
+            [
+                (DirectiveToken::Directive, "#if"),
+                (DirectiveToken::Ident, "x"),
+                (DirectiveToken::End, "end"),
+            ],
+        );
+
+        buffer.append("s\n#x");
+
+        assert_eq!(
+            tokens(&buffer),
+            tokens(&TokenBuffer::<DirectiveToken>::parse("#if x ends\n#x")),
+        );
+
+        let mut doc = Document::<VoidSyntax<DirectiveToken>>::new_mutable(text);
+
+        let edits = [
+            (0..0, "x"),
+            (0..1, ""),
+            (15..17, ""),
+            (15..15, " "),
+            (14..16, ""),
+            (14..14, "\n"),
+            (30..30, "d"),
+            (30..31, ""),
+            (34..36, ""),
+            (27..28, ""),
+            (26..27, "\ne"),
+        ];
+
+        for (span, edit) in edits {
+            doc.write(span, edit);
+
+            check_reparse(&doc);
+
+            let fresh = TokenBuffer::<DirectiveToken>::parse(doc.substring(..));
+
+            assert_eq!(tokens(&doc), tokens(&fresh), "{:?}", doc.substring(..));
+        }
+
+        assert_eq!(
+            tokens(&doc),
+            [
+                (DirectiveToken::Directive, "#include"),
+                (DirectiveToken::Punct, "<"),
+                (DirectiveToken::Ident, "a"),
+                (DirectiveToken::Punct, "."),
+                (DirectiveToken::Ident, "b"),
+                (DirectiveToken::Punct, ">"),
+                (DirectiveToken::Newline, "\n"),
+                (DirectiveToken::Directive, "#define"),
+                (DirectiveToken::Newline, "\n"),
+                (DirectiveToken::Directive, "#if"),
+                (DirectiveToken::Newline, "\n"),
+                (DirectiveToken::End, "end"),
+                (DirectiveToken::Newline, "\n"),
+                (DirectiveToken::Ident, "end"),
+                (DirectiveToken::Continuation, "\\"),
+                (DirectiveToken::Newline, "\n"),
+                (DirectiveToken::Mismatch, "\\"),
+                (DirectiveToken::Ident, "y"),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_token_captures() {
+        let _ = CaptureToken::take_captures();
+
+        let buffer = TokenBuffer::<CaptureToken>::parse(
+            r#""abc" "" 12 3.45 <ab> <ab,cd,ef> # #abc @zпривет"#,
+        );
+
+        assert_eq!(
+            buffer
+                .chunks(..)
+                .filter(|chunk| chunk.token != CaptureToken::Whitespace)
+                .map(|chunk| chunk.token)
+                .collect::<Vec<_>>(),
+            [
+                CaptureToken::String,
+                CaptureToken::String,
+                CaptureToken::Number,
+                CaptureToken::Number,
+                CaptureToken::List,
+                CaptureToken::List,
+                CaptureToken::Tag,
+                CaptureToken::Tag,
+                CaptureToken::Mention,
+            ],
+        );
+
+        let expected: [(CaptureToken, &[&str]); 9] = [
+            // A capture inside a repetition spans all of its matches.
+            (CaptureToken::String, &[r#""abc""#, "abc"]),
+            // The repetition without matches leaves the capture empty.
+            (CaptureToken::String, &[r#""""#, ""]),
+            // The optional capture that never matched is empty.
+            (CaptureToken::Number, &["12", "12", ""]),
+            (CaptureToken::Number, &["3.45", "3", "45"]),
+            (CaptureToken::List, &["<ab>", "ab"]),
+            // The same key captured in several places spans from
+            // the first match to the last match.
+            (CaptureToken::List, &["<ab,cd,ef>", "ab,cd,ef"]),
+            (CaptureToken::Tag, &["#", ""]),
+            // The capture takes as many characters as possible.
+            (CaptureToken::Tag, &["#abc", "abc"]),
+            (CaptureToken::Mention, &["@zпривет", "zпривет"]),
+        ];
+
+        let captures = CaptureToken::take_captures();
+
+        assert_eq!(captures.len(), expected.len());
+
+        for (capture, expected) in captures.into_iter().zip(expected) {
+            assert_eq!(capture.0, expected.0);
+            assert_eq!(capture.1, expected.1);
+        }
+    }
+
+    #[test]
+    fn test_logos() {
+        let (small, large) = data::load();
+
+        let Some(BenchCommand::Init { text }) = small.iter().next() else {
+            panic!("Missing Small File init command.");
+        };
+
+        let tokens = LogosJsonToken::lexer(text)
+            .map(|result| result.unwrap())
+            .collect::<Vec<_>>();
+        let buffer = TokenBuffer::<JsonToken>::parse(text);
+
+        assert_eq!(tokens.len(), buffer.tokens());
+
+        for (a, b) in tokens.iter().zip(buffer.chunks(..)) {
+            assert_eq!(a.into_ld(), b.token);
+        }
+
+        let Some(BenchCommand::Init { text }) = large.iter().next() else {
+            panic!("Missing Large File init command.");
+        };
+
+        let tokens = LogosJsonToken::lexer(text)
+            .map(|result| result.unwrap())
+            .collect::<Vec<_>>();
+        let buffer = TokenBuffer::<JsonToken>::parse(text);
+
+        assert_eq!(tokens.len(), buffer.tokens());
+
+        for (a, b) in tokens.iter().zip(buffer.chunks(..)) {
+            assert_eq!(a.into_ld(), b.token);
+        }
+    }
+
+    #[test]
+    fn test_ascii_fallback() {
+        static INPUT: &str = "{\"ключ\": \"значение\", // комментарий 🙂\n\
+            \"a\": [1, 2.5e-3, true, null], \"🙂\": \"\\u00E9 é\" ж, x}";
+
+        let buffer = TokenBuffer::<JsonToken>::parse(INPUT);
+        let reference = TokenBuffer::<UnicodeJsonToken>::parse(INPUT);
+
+        assert_eq!(buffer.tokens(), reference.tokens());
+
+        for (a, b) in buffer.chunks(..).zip(reference.chunks(..)) {
+            assert_eq!(a.token, b.token.into_ld());
+            assert_eq!(a.site, b.site);
+            assert_eq!(a.length, b.length);
+            assert_eq!(a.string, b.string);
+        }
+
+        let chunks = buffer
+            .chunks(..)
+            .filter(|chunk| chunk.token != JsonToken::Whitespace)
+            .map(|chunk| (chunk.token, chunk.site, chunk.length))
+            .collect::<Vec<_>>();
+
+        assert_eq!(chunks[1], (JsonToken::String, 1, 6));
+        assert_eq!(chunks[3], (JsonToken::String, 9, 10));
+        assert_eq!(chunks[5], (JsonToken::Comment, 21, 16));
+        assert_eq!(chunks[18], (JsonToken::String, 68, 3));
+        assert_eq!(chunks[20], (JsonToken::String, 73, 10));
+        assert_eq!(chunks[21], (JsonToken::Mismatch, 84, 1));
+        assert_eq!(chunks[23], (JsonToken::Mismatch, 87, 1));
+
+        for chunk in buffer.chunks(..) {
+            assert_eq!(chunk.string.chars().count(), chunk.length);
+        }
+
+        let (small, _) = data::load();
+
+        let Some(BenchCommand::Init { text }) = small.iter().next() else {
+            panic!("Missing Small File init command.");
+        };
+
+        let buffer = TokenBuffer::<JsonToken>::parse(text);
+        let reference = TokenBuffer::<UnicodeJsonToken>::parse(text);
+
+        assert_eq!(buffer.tokens(), reference.tokens());
+
+        for (a, b) in buffer.chunks(..).zip(reference.chunks(..)) {
+            assert_eq!(a.token, b.token.into_ld());
+            assert_eq!(a.site, b.site);
+        }
+    }
+
+    #[test]
+    fn test_parse_tree_render() {
+        let (small, _) = data::load();
+
+        let mut doc = Document::<JsonNode>::new_mutable("");
+
+        for (index, command) in small.iter().enumerate() {
+            match command {
+                BenchCommand::Init { text } => doc.write(.., text),
+                BenchCommand::Edit {
+                    site_span, text, ..
+                } => {
+                    doc.write(site_span, text);
+                    continue;
+                }
+                BenchCommand::Wait => (),
+            }
+
+            let mut tree = ParseTree::<JsonNode, _>::new(&doc, ..);
+
+            assert_eq!(tree.render(), doc.substring(..));
+
+            let root = tree.parse_tree_root_mut();
+
+            if let Some(child) = root.children.first().cloned() {
+                assert!(root.replace_child(0, child).is_some());
+            }
+
+            assert_eq!(tree.render(), doc.substring(..));
+
+            println!("Command {index} OK.");
+        }
+
+        let buffer = TokenBuffer::<JsonToken>::from("[1,  2 , 3]");
+        let mut tree = ParseTree::<JsonNode, _>::new(&buffer, ..);
+
+        fn replace_token(
+            node: &mut ParseNode,
+            from: &str,
+            to: &str,
+            code: &impl SourceCode,
+        ) -> bool {
+            for index in 0..node.children.len() {
+                match &mut node.children[index] {
+                    ParseNodeChild::Token(token) => {
+                        if token.token_ref.string(code) == Some(from) {
+                            let _ = node.replace_child(index, ParseToken::from_text(to));
+                            return true;
+                        }
+                    }
+
+                    ParseNodeChild::Node(child) => {
+                        if replace_token(child, from, to, code) {
+                            return true;
+                        }
+                    }
+
+                    _ => (),
+                }
+            }
+
+            false
+        }
+
+        assert!(replace_token(
+            tree.parse_tree_root_mut(),
+            "2",
+            "two",
+            &buffer
+        ));
+        assert_eq!(tree.render(), "[1,  two , 3]");
+
+        let root = tree.parse_tree_root_mut();
+        let last = root.children.len() - 1;
+        assert!(root.insert_child_after(last, ParseToken::from_text("\n")));
+        assert_eq!(tree.render(), "[1,  two , 3]\n");
+
+        let root = tree.parse_tree_root_mut();
+        assert!(root.remove_child(last + 1).is_some());
+        assert_eq!(tree.render(), "[1,  two , 3]");
+    }
+
+    #[test]
+    fn test_diagnostics_export() {
+        let doc = Document::<JsonNode>::new_immutable("{\n    \"a\": [1 2]\n}");
+
+        let error = doc.errors().next().unwrap();
+        let diagnostic = error.to_diagnostic(&doc);
+
+        assert_eq!(diagnostic.severity, DiagnosticSeverity::Error);
+        assert_eq!(diagnostic.message, "Missing ',' in Array.");
+
+        let lsp = diagnostic.to_lsp(&doc, "file:///a.json", PositionEncoding::Utf16);
+        let lsp = serde_json::to_value(lsp).unwrap();
+
+        assert_eq!(
+            lsp,
+            serde_json::json!({
+                "range": {
+                    "start": {"line": 1, "character": 11},
+                    "end": {"line": 1, "character": 12},
+                },
+                "severity": 1,
+                "message": "Missing ',' in Array.",
+            }),
+        );
+
+        let mut diagnostic =
+            Diagnostic::new(&doc, 0..1, AnnotationPriority::Secondary, "Object start.");
+
+        diagnostic.relate(&doc, 17..18, "Object end.");
+
+        let sarif = serde_json::to_value(diagnostic.to_sarif("a.json")).unwrap();
+
+        assert_eq!(
+            sarif,
+            serde_json::json!({
+                "level": "warning",
+                "message": {"text": "Object start."},
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": {"uri": "a.json"},
+                        "region": {"startLine": 1, "startColumn": 1, "endLine": 1, "endColumn": 2},
+                    },
+                }],
+                "relatedLocations": [{
+                    "id": 0,
+                    "physicalLocation": {
+                        "artifactLocation": {"uri": "a.json"},
+                        "region": {"startLine": 3, "startColumn": 1, "endLine": 3, "endColumn": 2},
+                    },
+                    "message": {"text": "Object end."},
+                }],
+            }),
+        );
+
+        // The character offsets are measured in the negotiated encoding units
+        // on the line with an astral-plane character.
+
+        let astral = Document::<JsonNode>::new_immutable("{\"🙂\": [1 2]}");
+
+        let error = astral.errors().next().unwrap();
+        let diagnostic_16 =
+            error
+                .to_diagnostic(&astral)
+                .to_lsp(&astral, "file:///b.json", PositionEncoding::Utf16);
+        let diagnostic_32 =
+            error
+                .to_diagnostic(&astral)
+                .to_lsp(&astral, "file:///b.json", PositionEncoding::Utf32);
+
+        assert_eq!(diagnostic_16.range.start, LspPosition::new(0, 9));
+        assert_eq!(diagnostic_32.range.start, LspPosition::new(0, 8));
+
+        let mut report = DiagnosticReport::new(&doc);
+
+        report
+            .annotate(6..9, AnnotationPriority::Default, "Key.")
+            .add(diagnostic.clone());
+
+        let name = doc.id().name();
+        let log = report.to_sarif_string("json-lint");
+
+        let results = [
+            serde_json::to_string(&report.diagnostics()[0].to_sarif(&name)).unwrap(),
+            serde_json::to_string(&diagnostic.to_sarif(&name)).unwrap(),
+        ];
+
+        assert!(log.ends_with(&format!("\"results\":[{}]}}]}}", results.join(","))));
+        assert!(results[1].contains("\"relatedLocations\":[{\"id\":0,"));
+    }
+
+    #[test]
+    fn test_style_serde() {
+        static THEME: [Style; 3] = [
+            Style::new().red().bold(),
+            Style::new().fg_rgb(255, 128, 0).bg_indexed(17),
+            Style::new().grayscale(0.5).underline(),
+        ];
+
+        let serialized = serde_json::to_value(&THEME).unwrap();
+
+        assert_eq!(
+            serialized,
+            serde_json::json!([
+                {"fg": "red", "bold": true},
+                {"fg": "#ff8000", "bg": 17},
+                {"fg": {"grayscale": 0.5}, "underline": true},
+            ]),
+        );
+
+        let deserialized = serde_json::from_value::<Vec<Style>>(serialized).unwrap();
+
+        assert_eq!(deserialized, THEME);
+
+        assert!(serde_json::from_str::<Style>(r#"{"fg": "purple"}"#).is_err());
+        assert!(serde_json::from_str::<Style>(r#"{"color": "red"}"#).is_err());
+
+        let mut sorted = THEME.to_vec();
+
+        sorted.sort();
+        sorted.dedup();
+
+        assert_eq!(sorted.len(), 3);
+        assert!(Color::Red < Color::BrightRed);
+        assert_eq!(
+            Color::RGB {
+                red: 0.5,
+                green: 0.5,
+                blue: 0.5
+            },
+            Color::RGB {
+                red: 0.5,
+                green: 0.5,
+                blue: 0.5
+            }
+        );
+    }
+
+    #[test]
+    fn test_repo_maintenance() {
+        let mut repo = Repo::new();
+
+        let entries = (0..6).map(|value| repo.insert(value)).collect::<Vec<_>>();
+
+        assert_eq!(repo.len(), 6);
+
+        repo.retain(|_, value| {
+            *value *= 10;
+            *value % 20 == 0
+        });
+
+        assert_eq!(repo.len(), 3);
+        assert!(repo.contains(&entries[0]));
+        assert!(!repo.contains(&entries[1]));
+        assert_eq!(repo.iter().copied().collect::<Vec<_>>(), [0, 20, 40]);
+
+        let reinserted = repo.insert(7);
+
+        assert_eq!(reinserted.index, entries[5].index);
+        assert_ne!(reinserted.version, entries[5].version);
+        assert!(!repo.contains(&entries[5]));
+
+        let _ = repo.remove(&entries[4]);
+        let _ = repo.remove(&reinserted);
+
+        assert!(repo.capacity() >= 6);
+        assert!(repo.shrink_to_fit() > 0);
+        assert_eq!(repo.capacity(), 3);
+        assert!(repo.contains(&entries[2]));
+
+        let reinserted = repo.insert(7);
+
+        assert_eq!(reinserted.index, entries[1].index);
+
+        let extended = repo.extend([8, 9]);
+
+        assert_eq!(extended[0].index, 3);
+        assert_eq!(extended[1].index, 4);
+        assert_eq!(repo.len(), 5);
+
+        let _ = repo.remove(&extended[0]);
+        let _ = repo.remove(&extended[1]);
+
+        let drained = repo.drain().collect::<Vec<_>>();
+
+        assert!(repo.is_empty());
+        assert_eq!(
+            drained,
+            [(entries[0], 0), (reinserted, 7), (entries[2], 20)]
+        );
+    }
+
+    #[test]
+    fn test_ref_serde() {
+        let mut doc = Document::<JsonNode>::new_mutable(r#"{"foo": [1, 2]}"#);
+
+        let JsonNode::Root { object, .. } = doc.root() else {
+            panic!("Root expected.");
+        };
+
+        let serialized = serde_json::to_string(object).unwrap();
+        let deserialized = serde_json::from_str::<NodeRef>(&serialized).unwrap();
+
+        assert_eq!(&deserialized, object);
+        assert!(deserialized.is_valid_ref(&doc));
+
+        doc.write(.., "[]");
+
+        let stale = serde_json::from_str::<NodeRef>(&serialized).unwrap();
+
+        assert!(!stale.is_valid_ref(&doc));
+        assert!(!stale.is_valid_ref(&Document::<JsonNode>::new_mutable("{}")));
+    }
+
+    #[test]
+    fn test_node_captures() {
+        let doc = Document::<JsonNode>::new_immutable(r#"{"foo": 1, "bar": 2}"#);
+
+        let JsonNode::Root { object, .. } = doc.root() else {
+            panic!("Root expected.");
+        };
+
+        let node = object.deref(&doc).unwrap();
+
+        let JsonNode::Object {
+            start,
+            entries,
+            end,
+            ..
+        } = node
+        else {
+            panic!("Object expected.");
+        };
+
+        assert_eq!(
+            node.captures().collect::<Vec<_>>(),
+            [
+                (Key::Name("start"), Capture::SingleToken(start)),
+                (Key::Name("entries"), Capture::ManyNodes(entries)),
+                (Key::Name("end"), Capture::SingleToken(end)),
+            ],
+        );
+
+        assert_eq!(node.captures().len(), node.captures_len());
+
+        assert_eq!(
+            node.captures()
+                .rev()
+                .map(|(key, _)| key)
+                .collect::<Vec<_>>(),
+            [Key::Name("end"), Key::Name("entries"), Key::Name("start")],
+        );
+
+        assert!(node
+            .captures()
+            .zip(node.captures_iter())
+            .all(|((_, left), right)| left == right));
+    }
+
+    #[test]
+    fn test_node_path() {
+        static TEXT: &str = r#"{"foo": 1, "bar": {"a": 1, "b": 2, "c": 3}}"#;
+
+        let mut doc = Document::<JsonNode>::new_mutable(TEXT);
+
+        let JsonNode::Root { object, .. } = doc.root() else {
+            panic!("Root expected.");
+        };
+
+        let Some(JsonNode::Object { entries, .. }) = object.deref(&doc) else {
+            panic!("Object expected.");
+        };
+
+        let Some(JsonNode::Entry { value, .. }) = entries[1].deref(&doc) else {
+            panic!("Entry expected.");
+        };
+
+        let Some(JsonNode::Object { entries, .. }) = value.deref(&doc) else {
+            panic!("Object expected.");
+        };
+
+        let entry_ref = entries[2];
+
+        let path = NodePath::new(&doc, &entry_ref).unwrap();
+
+        assert_eq!(path.depth(), 4);
+        assert_eq!(path.steps[1].key, "entries");
+        assert_eq!(path.steps[1].index, 1);
+        assert_eq!(path.steps[3].index, 2);
+
+        assert_eq!(path.resolve(&doc, PathPolicy::Exact), Some(entry_ref));
+        assert_eq!(path.resolve(&doc, PathPolicy::Nearest), Some(entry_ref));
+
+        assert_eq!(
+            NodePath::new(&doc, &doc.root_node_ref()),
+            Some(NodePath::default()),
+        );
+        assert_eq!(
+            NodePath::default().resolve(&doc, PathPolicy::Exact),
+            Some(doc.root_node_ref()),
+        );
+        assert_eq!(
+            NodePath::new(&Document::<JsonNode>::new_mutable("{}"), &entry_ref),
+            None,
+        );
+
+        let serialized = serde_json::to_string(&path).unwrap();
+        let path = serde_json::from_str::<NodePath>(&serialized).unwrap();
+
+        let start = TEXT.find(r#""a""#).unwrap();
+
+        doc.write(start..(start + 8), "");
+
+        assert_eq!(path.resolve(&doc, PathPolicy::Exact), None);
+
+        let resolved = path.resolve(&doc, PathPolicy::Nearest).unwrap();
+
+        assert_eq!(doc.substring(resolved.span(&doc).unwrap()), r#""c": 3"#);
+
+        doc.write(.., TEXT);
+
+        assert_eq!(
+            path.resolve(&doc, PathPolicy::Exact)
+                .map(|node_ref| doc.substring(node_ref.span(&doc).unwrap())),
+            Some(r#""c": 3"#.into()),
+        );
+    }
+
+    #[test]
+    fn test_repo_typed_entry() {
+        let mut numbers = Repo::<usize>::new();
+        let mut strings = Repo::<&'static str>::new();
+
+        let number = numbers.insert_typed(10);
+        let string = TypedEntry::<&'static str>::new(strings.insert("foo"));
+
+        assert_eq!(number.get(&numbers), Some(&10));
+        assert_eq!(string.get(&strings), Some(&"foo"));
+        assert_eq!(Entry::from(number), string.untyped());
+
+        *number.get_mut(&mut numbers).unwrap() += 1;
+
+        assert_eq!(number.remove(&mut numbers), Some(11));
+        assert!(!number.contains(&numbers));
+        assert!(TypedEntry::<usize>::default().is_nil());
+    }
+
+    #[test]
+    fn test_id_registry() {
+        let doc = Document::<JsonNode>::new_immutable("{}");
+        let id = Id::new();
+
+        doc.id().set_name("test_id_registry.json");
+        id.set_name("test_id_registry.json");
+
+        assert_eq!(Id::find_by_name("test_id_registry.json"), [doc.id(), id]);
+        assert!(Id::enumerate().contains(&(id, String::from("test_id_registry.json"))));
+
+        drop(doc);
+
+        assert_eq!(Id::find_by_name("test_id_registry.json"), [id]);
+
+        let _ = id.clear_name();
+
+        assert!(Id::find_by_name("test_id_registry.json").is_empty());
+        assert!(Id::enumerate()
+            .iter()
+            .all(|(candidate, _)| candidate != &id));
+    }
+
+    #[test]
+    fn test_repo_chunks() {
+        assert!(Repo::<usize>::new().chunks(4).is_empty());
+
+        let mut repo = (0..1000).collect::<Repo<usize>>();
+
+        repo.retain(|_, value| *value % 3 != 0);
+
+        let expected = repo.iter().sum::<usize>();
+
+        for count in [1, 3, 7, 2000] {
+            let chunks = repo.chunks(count);
+
+            assert!(chunks.len() <= count);
+
+            let total = std::thread::scope(|scope| {
+                chunks
+                    .iter()
+                    .map(|chunk| scope.spawn(move || chunk.iter().sum::<usize>()))
+                    .collect::<Vec<_>>()
+                    .into_iter()
+                    .map(|handle| handle.join().unwrap())
+                    .sum::<usize>()
+            });
+
+            assert_eq!(total, expected);
+
+            let entries = chunks
+                .iter()
+                .flat_map(|chunk| chunk.enumerate().map(|(entry, _)| entry))
+                .collect::<Vec<_>>();
+
+            assert_eq!(entries, repo.entries().collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_repo_weak_entry() {
+        let mut repo = Repo::new();
+
+        let first = repo.insert("foo");
+        let weak = repo.downgrade(&first);
+
+        assert_eq!(weak.upgrade(&repo), Some(first));
+
+        let _ = repo.remove(&first);
+        let second = repo.insert("bar");
+
+        assert_eq!(second.index, first.index);
+        assert_eq!(weak.upgrade(&repo), None);
+        assert!(repo.downgrade(&first).is_nil());
+        assert_eq!(WeakEntry::default().upgrade(&repo), None);
+    }
+
+    #[test]
+    fn test_table_snapshot() {
+        let table = Table::<usize, usize>::with_shards(3);
+
+        assert_eq!(table.shards().len(), 4);
+        assert!(table.is_empty());
+
+        std::thread::scope(|scope| {
+            for thread in 0..4 {
+                let table = &table;
+
+                scope.spawn(move || {
+                    for key in 0..100 {
+                        *table.entry(key % 10).or_default() += thread;
+                    }
+                });
+            }
+        });
+
+        assert_eq!(table.len(), 10);
+
+        table.retain(|key, _| key % 2 == 0);
+
+        let mut snapshot = table.iter_snapshot().collect::<Vec<_>>();
+
+        snapshot.sort();
+
+        assert_eq!(snapshot, [(0, 60), (2, 60), (4, 60), (6, 60), (8, 60)]);
+
+        let mut removed = table.remove_if(|key, _| *key >= 6);
+
+        removed.sort();
+
+        assert_eq!(removed, [(6, 60), (8, 60)]);
+        assert_eq!(table.len(), 3);
+
+        let mut keys = table.keys_snapshot();
+
+        keys.sort();
+
+        assert_eq!(keys, [0, 2, 4]);
+
+        let strings = Table::<usize, (String, usize)>::new();
+
+        let _ = strings.insert(1, (String::from("foo"), 2));
+
+        let name = strings.get(&1).unwrap().map(|(name, _)| name.as_str());
+
+        assert_eq!(&*name, "foo");
+        assert_eq!(&*name.map(|name| &name[1..]), "oo");
+    }
+
+    #[test]
+    fn test_source_graphemes() {
+        let buffer = TokenBuffer::<JsonToken>::parse("[\"e\u{301}\", \"\u{1F1FA}\u{1F1E6}\"]\r\n");
+
+        let spans = buffer.graphemes(..).collect::<Vec<_>>();
+
+        assert_eq!(
+            spans,
+            [
+                0..1,
+                1..2,
+                2..4,
+                4..5,
+                5..6,
+                6..7,
+                7..8,
+                8..10,
+                10..11,
+                11..12,
+                12..14,
+            ],
+        );
+
+        assert_eq!(
+            buffer.graphemes(3..9).collect::<Vec<_>>(),
+            [3..4, 4..5, 5..6, 6..7, 7..8, 8..9]
+        );
+        assert_eq!(buffer.graphemes(2..2).count(), 0);
+    }
+
+    #[test]
+    fn test_grapheme_break_conformance() {
+        static TEST_FILE: &str = include_str!("ucd/GraphemeBreakTest.txt");
+
+        let (major, minor, update) = UNICODE_VERSION;
+
+        assert_eq!(
+            TEST_FILE.lines().next(),
+            Some(format!("# GraphemeBreakTest-{major}.{minor}.{update}.txt").as_str()),
+            "The conformance test file does not match the UCD tables version.",
+        );
+
+        let mut cases = 0;
+
+        for line in TEST_FILE.lines() {
+            let line = match line.split_once('#') {
+                Some((data, _)) => data.trim(),
+                None => line.trim(),
+            };
+
+            if line.is_empty() {
+                continue;
+            }
+
+            let mut string = String::new();
+            let mut clusters = Vec::<String>::new();
+
+            for part in line.split_whitespace() {
+                match part {
+                    "÷" => clusters.push(String::new()),
+                    "×" => (),
+
+                    _ => {
+                        let code_point = u32::from_str_radix(part, 16).unwrap();
+                        let ch = char::from_u32(code_point).unwrap();
+
+                        string.push(ch);
+                        clusters.last_mut().unwrap().push(ch);
+                    }
+                }
+            }
+
+            let _ = clusters.pop();
+
+            assert_eq!(
+                Graphemes::new(&string).collect::<Vec<_>>(),
+                clusters,
+                "Test case: {line}",
+            );
+
+            let buffer = TokenBuffer::<JsonToken>::parse(&string);
+
+            let mut site = 0;
+            let spans = clusters
+                .iter()
+                .map(|cluster| {
+                    let start = site;
+                    site += cluster.chars().count();
+                    start..site
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                buffer.graphemes(..).collect::<Vec<_>>(),
+                spans,
+                "Test case: {line}",
+            );
+
+            cases += 1;
+        }
+
+        assert!(cases > 0);
+    }
+
+    #[test]
+    fn test_derive_hash() {
+        assert_derive_hash!(
+            golden::copy::GoldenToken,
+            golden::original::GoldenToken::DERIVE_HASH,
+        );
+        assert_derive_hash!(
+            golden::copy::GoldenNode,
+            golden::original::GoldenNode::DERIVE_HASH,
+        );
+
+        assert_ne!(
+            golden::changed::GoldenToken::DERIVE_HASH,
+            golden::original::GoldenToken::DERIVE_HASH,
+        );
+        assert_ne!(
+            golden::changed::GoldenNode::DERIVE_HASH,
+            golden::original::GoldenNode::DERIVE_HASH,
+        );
+    }
+
+    #[test]
+    fn test_ebnf_dump() {
+        let dir = Path::new(env!("LADY_DEIRDRE_DUMP_DIR"));
+
+        for (file, snapshot) in [
+            ("JsonToken.ebnf", include_str!("ebnf/JsonToken.ebnf")),
+            ("JsonNode.ebnf", include_str!("ebnf/JsonNode.ebnf")),
+        ] {
+            let dump = read_to_string(dir.join(file)).expect("Missing EBNF dump file.");
+
+            assert_eq!(dump, snapshot, "{file} differs from the snapshot.");
+        }
+    }
+
+    #[test]
+    fn test_case_insensitive_literals() {
+        let buffer = TokenBuffer::<KeywordToken>::parse(
+            "SELECT Select sElEcT select selects WHERE Where from FROM From",
+        );
+
+        let tokens = buffer
+            .chunks(..)
+            .map(|chunk| chunk.token)
+            .filter(|token| *token != KeywordToken::Whitespace)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            tokens,
+            [
+                KeywordToken::Select,
+                KeywordToken::Select,
+                KeywordToken::Select,
+                KeywordToken::Select,
+                KeywordToken::Ident,
+                KeywordToken::Where,
+                KeywordToken::Where,
+                KeywordToken::From,
+                KeywordToken::Ident,
+                KeywordToken::Ident,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_document_serde() {
+        static INPUT: &'static str = r#"{"foo": [1, 2.5, true], "bar": {"baz": null}}"#;
+
+        fn assert_same(restored: &Document<JsonNode>, original: &Document<JsonNode>) {
+            assert_eq!(restored.substring(..), original.substring(..));
+            assert!(restored
+                .chunks(..)
+                .map(|chunk| (chunk.token, chunk.string))
+                .eq(original.chunks(..).map(|chunk| (chunk.token, chunk.string))));
+            assert_eq!(TreeSnapshot::new(restored), TreeSnapshot::new(original));
+
+            let restored_stats = restored.stats();
+            let original_stats = original.stats();
+
+            assert_eq!(restored_stats.nodes, original_stats.nodes);
+            assert_eq!(restored_stats.rules, original_stats.rules);
+            assert_eq!(restored_stats.max_depth, original_stats.max_depth);
+            assert_eq!(restored_stats.children, original_stats.children);
+        }
+
+        let mut doc = Document::<JsonNode>::new_mutable(INPUT);
+
+        doc.write(9..9, "0, ");
+        doc.write(0..0, "\n");
+
+        let JsonNode::Root { object, .. } = doc.root() else {
+            panic!("Root expected.");
+        };
+
+        let entry = object.first_child(&doc);
+        let token = entry.get_child(&doc, "key").get_token(&doc, "value");
+
+        let serialized = serde_json::to_string(&doc).unwrap();
+        let restored = serde_json::from_str::<Restored<Document<JsonNode>>>(&serialized).unwrap();
+
+        assert!(!restored.rescanned);
+        assert!(!restored.reparsed);
+        assert!(restored.unit.is_mutable());
+        assert_ne!(restored.unit.id(), doc.id());
+        assert_eq!(restored.refs.source_id(), doc.id());
+        assert_same(&restored.unit, &doc);
+
+        let restored_entry = restored.refs.node_ref(&entry);
+
+        assert!(restored_entry.is_valid_ref(&restored.unit));
+        assert_eq!(restored_entry.entry, entry.entry);
+        assert_eq!(restored_entry.rule(&restored.unit), JsonNode::ENTRY);
+        assert_eq!(restored_entry.span(&restored.unit), entry.span(&doc));
+        assert_eq!(
+            restored_entry.parent(&restored.unit),
+            restored.refs.node_ref(&entry.parent(&doc)),
+        );
+
+        let restored_token = restored.refs.token_ref(&token);
+
+        assert!(restored_token.is_valid_ref(&restored.unit));
+        assert_eq!(restored_token.string(&restored.unit), token.string(&doc));
+
+        let mut restored = restored.unit;
+
+        doc.write(20..20, r#", "qux": [null]"#);
+        restored.write(20..20, r#", "qux": [null]"#);
+
+        assert_same(&restored, &doc);
+        assert_same(&restored, &Document::new_mutable(doc.substring(..)));
+
+        let mut value = serde_json::to_value(&doc).unwrap();
+
+        value["grammar"] = serde_json::Value::from(0);
+
+        let restored = serde_json::from_value::<Restored<Document<JsonNode>>>(value).unwrap();
+
+        assert!(!restored.rescanned);
+        assert!(restored.reparsed);
+        assert_same(&restored.unit, &doc);
+        assert!(restored.refs.node_ref(&entry).is_valid_ref(&restored.unit));
+
+        let doc = Document::<JsonNode>::new_mutable(r#"{"foo": , "bar": [1 2]}"#);
+
+        let serialized = serde_json::to_string(&doc).unwrap();
+        let restored = serde_json::from_str::<Restored<Document<JsonNode>>>(&serialized).unwrap();
+
+        assert!(restored.reparsed);
+        assert_eq!(restored.unit.errors().count(), doc.errors().count());
+        assert_same(&restored.unit, &doc);
+
+        let doc = Document::<JsonNode>::new_immutable(INPUT);
+
+        let serialized = bincode::serialize(&doc).unwrap();
+        let restored = bincode::deserialize::<Restored<Document<JsonNode>>>(&serialized).unwrap();
+
+        assert!(!restored.reparsed);
+        assert!(!restored.unit.is_mutable());
+        assert_same(&restored.unit, &doc);
+
+        let mut value = serde_json::to_value(&doc).unwrap();
+
+        value["lexis"]["fingerprint"] = serde_json::Value::from(0);
+
+        let restored = serde_json::from_value::<Restored<Document<JsonNode>>>(value).unwrap();
+
+        assert!(restored.rescanned);
+        assert!(restored.reparsed);
+        assert_same(&restored.unit, &doc);
+
+        let buffer = TokenBuffer::<JsonToken>::parse(INPUT);
+
+        let serialized = bincode::serialize(&buffer).unwrap();
+        let restored = bincode::deserialize::<TokenBuffer<JsonToken>>(&serialized).unwrap();
+
+        assert_eq!(restored.substring(..), buffer.substring(..));
+        assert!(restored
+            .chunks(..)
+            .map(|chunk| (chunk.token, chunk.string))
+            .eq(buffer.chunks(..).map(|chunk| (chunk.token, chunk.string))));
+    }
+
+    #[test]
+    fn test_binary_operators() {
+        let doc = Document::<BinaryNode>::new_immutable("true & false | true & (false | true)");
+
+        assert_tree!(
+            doc,
+            r#"
+            Root
+              expr: Or
+                left: And
+                  left: Operand
+                    value: True
+                      token: $True "true"
+                  op: $And "&"
+                  right: Operand
+                    value: False
+                      token: $False "false"
+                op: $Or "|"
+                right: And
+                  left: Operand
+                    value: True
+                      token: $True "true"
+                  op: $And "&"
+                  right: Operand
+                    value: Or
+                      left: Operand
+                        value: False
+                          token: $False "false"
+                      op: $Or "|"
+                      right: Operand
+                        value: True
+                          token: $True "true"
+            "#,
+        );
+
+        assert_eq!(doc.errors().count(), 0);
+
+        let doc = Document::<BinaryNode>::new_immutable("true & false & true");
+
+        assert_tree!(
+            doc,
+            r#"
+            Root
+              expr: And
+                left: And
+                  left: Operand
+                    value: True
+                      token: $True "true"
+                  op: $And "&"
+                  right: Operand
+                    value: False
+                      token: $False "false"
+                op: $And "&"
+                right: Operand
+                  value: True
+                    token: $True "true"
+            "#,
+        );
+
+        let doc = Document::<BinaryNode>::new_immutable("true");
+
+        assert_tree!(
+            doc,
+            r#"
+            Root
+              expr: Operand
+                value: True
+                  token: $True "true"
+            "#,
+        );
+
+        let doc = Document::<BinaryNode>::new_immutable("(false  true) & | false");
+
+        assert_eq!(doc.errors().count(), 2);
+
+        let messages = doc
+            .errors()
+            .map(|error| error.display(&doc).to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            messages,
+            [
+                "1:9 (4 chars): Unexpected input in <expr>.",
+                "1:17 (1 char): Unexpected input in <and op>.",
+            ],
+        );
+
+        let doc = Document::<BinaryNode>::new_immutable("true &");
+        let error = doc.errors().next().unwrap();
+
+        assert_eq!(BinaryNode::rule_name(error.context), Some("And"));
+        assert!(error.expected_tokens.is_empty());
+        assert_eq!(
+            error
+                .expected_nodes
+                .into_iter()
+                .map(|rule| BinaryNode::rule_name(rule).unwrap())
+                .collect::<Vec<_>>(),
+            ["Operand"],
+        );
+
+        let mut doc = Document::<BinaryNode>::new_mutable("true & false | (true | false) & true");
+
+        doc.write(7..12, "(true & false)");
+        doc.write(0..0, "false | ");
+        doc.write(30..31, "&");
+
+        assert_eq!(doc.errors().count(), 0);
+
+        check_reparse(&doc);
+
+        doc.write(10..10, "| ");
+
+        check_reparse(&doc);
+    }
+
+    #[test]
+    fn test_attr_interruption() {
+        fn assert_interrupted(
+            analyzer: &Analyzer<SlowNode>,
+            trigger_after: Option<Duration>,
+            snapshot: impl Fn(&AnalysisTask<SlowNode>, &SlowNode) -> AnalysisError,
+        ) {
+            let doc_id = {
+                let handle = TriggerHandle::new();
+                let mut task = analyzer.mutate(&handle, 1).unwrap();
+
+                task.add_mutable_doc("true")
+            };
+
+            let handle = TriggerHandle::new();
+            let task = analyzer.analyze(&handle, 1).unwrap();
+            let doc_read = task.read_doc(doc_id).unwrap();
+
+            thread::scope(|scope| {
+                if let Some(delay) = trigger_after {
+                    let handle = &handle;
+
+                    let _ = scope.spawn(move || {
+                        thread::sleep(delay);
+                        handle.trigger();
+                    });
+                }
+
+                let time = Instant::now();
+                let error = snapshot(&task, doc_read.root());
+
+                assert_eq!(error, AnalysisError::Interrupted);
+                assert!(time.elapsed() < Duration::from_secs(2));
+            });
+
+            assert_eq!(handle.is_triggered(), trigger_after.is_some());
+        }
+
+        let analyzer = Analyzer::<SlowNode>::new(AnalyzerConfig::new());
+
+        let delay = Some(Duration::from_millis(50));
+
+        assert_interrupted(&analyzer, delay, |task, root| {
+            let SlowNode::Root { semantics, .. } = root;
+
+            semantics.get().unwrap().spin.snapshot(task).unwrap_err()
+        });
+
+        assert_interrupted(&analyzer, delay, |task, root| {
+            let SlowNode::Root { semantics, .. } = root;
+
+            semantics.get().unwrap().poll.snapshot(task).unwrap_err()
+        });
+
+        let mut config = AnalyzerConfig::new();
+
+        config.attr_deadline = Some(Duration::from_millis(50));
+
+        let analyzer = Analyzer::<SlowNode>::new(config);
+
+        assert_interrupted(&analyzer, None, |task, root| {
+            let SlowNode::Root { semantics, .. } = root;
+
+            semantics.get().unwrap().spin.snapshot(task).unwrap_err()
+        });
+
+        assert_interrupted(&analyzer, None, |task, root| {
+            let SlowNode::Root { semantics, .. } = root;
+
+            let semantics = semantics.get().unwrap();
+
+            assert!(semantics.leaf.snapshot(task).is_ok());
+
+            semantics.poll.snapshot(task).unwrap_err()
+        });
+    }
+
+    #[test]
+    fn test_task_fairness() {
+        fn wait_queue(analyzer: &Analyzer<SlowNode>, depth: usize) {
+            let time = Instant::now();
+
+            while analyzer.queue_depth() < depth {
+                assert!(time.elapsed() < Duration::from_secs(10));
+                thread::yield_now();
+            }
+
+            assert_eq!(analyzer.queue_depth(), depth);
+        }
+
+        let analyzer = Analyzer::<SlowNode>::new(AnalyzerConfig::new());
+        let events = Mutex::new(Vec::new());
+
+        let analysis_handle = TriggerHandle::new();
+        let analysis_task = analyzer.analyze(&analysis_handle, 1).unwrap();
+
+        thread::scope(|scope| {
+            let _ = scope.spawn(|| {
+                let handle = TriggerHandle::new();
+                let task = analyzer.mutate(&handle, 1).unwrap();
+
+                events.lock().unwrap().push("mutation");
+
+                drop(task);
+            });
+
+            wait_queue(&analyzer, 1);
+
+            assert!(!analysis_handle.is_triggered());
+
+            // The analysis of equal priority fits the active mode.
+            let granted = scope.spawn(|| {
+                let handle = TriggerHandle::new();
+
+                let result = analyzer.try_analyze(&handle, 1);
+
+                result.is_ok()
+            });
+
+            assert!(granted.join().unwrap());
+
+            // The analysis of lower priority requested by another thread waits
+            // behind the queued mutation.
+            let rejected = scope.spawn(|| {
+                let handle = TriggerHandle::new();
+
+                let result = analyzer.try_analyze(&handle, 0);
+
+                result.err()
+            });
+
+            assert_eq!(rejected.join().unwrap(), Some(AnalysisError::Interrupted));
+
+            // The same thread does not bypass the queue with an unrelated
+            // handle.
+            let handle = TriggerHandle::new();
+
+            assert_eq!(
+                analyzer.try_analyze(&handle, 0).err(),
+                Some(AnalysisError::Interrupted),
+            );
+
+            // The nested task requested with the handle of the active task is
+            // granted, because the mutation waits for the outer task.
+            let nested_task = analyzer.analyze(&analysis_handle, 0).unwrap();
+
+            drop(nested_task);
+
+            // The analysis of higher priority takes precedence.
+            let high_granted = scope.spawn(|| {
+                let handle = TriggerHandle::new();
+
+                let result = analyzer.try_analyze(&handle, 2);
+
+                result.is_ok()
+            });
+
+            assert!(high_granted.join().unwrap());
+
+            for _ in 0..3 {
+                let _ = scope.spawn(|| {
+                    let handle = TriggerHandle::new();
+                    let task = analyzer.analyze(&handle, 0).unwrap();
+
+                    events.lock().unwrap().push("analysis");
+
+                    drop(task);
+                });
+            }
+
+            wait_queue(&analyzer, 4);
+
+            drop(analysis_task);
+        });
+
+        // The analysis tasks of lower priority requested after the mutation
+        // are granted after the mutation.
+        assert_eq!(
+            events.into_inner().unwrap(),
+            ["mutation", "analysis", "analysis", "analysis"],
+        );
+
+        assert_eq!(analyzer.queue_depth(), 0);
+    }
+
+    #[test]
+    fn test_task_custom_handle() {
+        #[derive(Default, Clone)]
+        struct FlagHandle(Arc<AtomicBool>);
+
+        impl TaskHandle for FlagHandle {
+            fn is_triggered(&self) -> bool {
+                self.0.load(Ordering::Relaxed)
+            }
+
+            fn trigger(&self) {
+                self.0.store(true, Ordering::Relaxed)
+            }
+
+            fn is_same(&self, other: &Self) -> bool {
+                Arc::ptr_eq(&self.0, &other.0)
+            }
+        }
+
+        let analyzer = Analyzer::<SlowNode, FlagHandle>::new(AnalyzerConfig::new());
+
+        let analysis_handle = FlagHandle::default();
+        let analysis_task = analyzer.analyze(&analysis_handle, 1).unwrap();
+
+        thread::scope(|scope| {
+            let mutation = scope.spawn(|| {
+                let handle = FlagHandle::default();
+                let task = analyzer.mutate(&handle, 1);
+
+                task.is_ok()
+            });
+
+            let time = Instant::now();
+
+            while analyzer.queue_depth() < 1 {
+                assert!(time.elapsed() < Duration::from_secs(10));
+                thread::yield_now();
+            }
+
+            // The nested task requested with a clone of the active task's
+            // handle does not wait behind the queued mutation.
+            let nested_handle = analysis_handle.clone();
+            let nested_task = analyzer.analyze(&nested_handle, 0).unwrap();
+
+            drop(nested_task);
+
+            assert_eq!(
+                analyzer.try_analyze(&FlagHandle::default(), 0).err(),
+                Some(AnalysisError::Interrupted),
+            );
+
+            drop(analysis_task);
+
+            assert!(mutation.join().unwrap());
+        });
+
+        assert_eq!(analyzer.queue_depth(), 0);
+    }
+
+    #[test]
+    fn test_feature_derive() {
+        let analyzer = Analyzer::<FeatureNode>::new(AnalyzerConfig::new());
+
+        let doc_id = {
+            let handle = TriggerHandle::new();
+            let mut task = analyzer.mutate(&handle, 1).unwrap();
+
+            task.add_mutable_doc("true")
+        };
+
+        let handle = TriggerHandle::new();
+        let task = analyzer.analyze(&handle, 1).unwrap();
+        let doc_read = task.read_doc(doc_id).unwrap();
+
+        let FeatureNode::Root { semantics, .. } = doc_read.root();
+        let semantics = semantics.get().unwrap();
+
+        assert_eq!(
+            semantics.feature_keys(),
+            &[
+                &Key::Name("kind"),
+                &Key::Name("shared"),
+                &Key::Name("computed"),
+                &Key::Name("empty"),
+            ],
+        );
+
+        let kind = semantics.feature(Key::Name("kind")).unwrap();
+
+        assert!(matches!(&semantics.kind, KindFeature::Use(..)));
+        assert_eq!(kind.feature_keys(), &[&Key::Index(0), &Key::Index(1)]);
+        assert_eq!(
+            kind.feature(Key::Name("def")).err(),
+            Some(AnalysisError::MissingFeature),
+        );
+
+        let (_, constant) = kind
+            .feature(Key::Index(0))
+            .unwrap()
+            .attr_ref()
+            .snapshot::<Constant, _, _>(&task)
+            .unwrap();
+
+        assert_eq!(constant, Constant(10));
+        assert!(!kind.feature(Key::Index(1)).unwrap().slot_ref().is_nil());
+
+        let shared = semantics.feature(Key::Name("shared")).unwrap();
+
+        assert_eq!(shared.feature_keys(), &[&Key::Index(0)]);
+        assert_eq!(semantics.shared.0.snapshot(&task).unwrap().1, 0);
+
+        let computed = semantics.feature(Key::Name("computed")).unwrap();
+
+        assert_eq!(computed.feature_keys(), &[&Key::Index(0)]);
+        assert_eq!(
+            semantics.computed.0.snapshot(&task).unwrap().1,
+            Constant(10)
+        );
+
+        let empty = semantics.feature(Key::Name("empty")).unwrap();
+
+        assert!(empty.feature_keys().is_empty());
+        assert_eq!(
+            empty.feature(Key::Index(0)).err(),
+            Some(AnalysisError::MissingFeature),
+        );
     }
 }
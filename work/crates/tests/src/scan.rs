@@ -34,13 +34,15 @@
 
 use std::mem::take;
 
-use lady_deirdre::lexis::{ByteIndex, LexisSession, Site, Token};
+use lady_deirdre::lexis::{ByteIndex, LexisSession, Site, Token, TokenRule, EOI};
 
 pub struct LDStatelessScanner<'a, T: Token> {
     input: &'a str,
     begin: Cursor,
     end: Cursor,
     current: Cursor,
+    previous: TokenRule,
+    line_start: bool,
     pending: Option<T>,
 }
 
@@ -73,6 +75,21 @@ unsafe impl<'a, T: Token> LexisSession for LDStatelessScanner<'a, T> {
 
         self.end = self.current;
     }
+
+    #[inline(always)]
+    fn rewind(&mut self) {
+        self.current = self.begin;
+    }
+
+    #[inline(always)]
+    fn previous(&self) -> TokenRule {
+        self.previous
+    }
+
+    #[inline(always)]
+    fn line_start(&self) -> bool {
+        self.line_start
+    }
 }
 
 impl<'a, T: Token> LDStatelessScanner<'a, T> {
@@ -83,9 +100,25 @@ impl<'a, T: Token> LDStatelessScanner<'a, T> {
             begin: Cursor::default(),
             end: Cursor::default(),
             current: Cursor::default(),
+            previous: EOI,
+            line_start: true,
             pending: None,
         }
     }
+
+    #[inline(always)]
+    fn track(&mut self, token: T) {
+        let rule = token.rule();
+
+        if !T::BLANK.contains(rule) {
+            self.previous = rule;
+        }
+    }
+
+    #[inline(always)]
+    fn track_line_start(&mut self) {
+        self.line_start = self.input.as_bytes()[self.end.byte - 1] == b'\n';
+    }
 }
 
 impl<'a, T: Token> Iterator for LDStatelessScanner<'a, T> {
@@ -106,17 +139,25 @@ impl<'a, T: Token> Iterator for LDStatelessScanner<'a, T> {
         if self.begin.byte != self.end.byte {
             self.begin = self.end;
             self.current = self.end;
+            self.track(token);
+            self.track_line_start();
 
             return Some(token);
         }
 
+        self.track(T::mismatch());
+
         loop {
-            if self.begin.advance(self.input) == 0xFF {
+            let byte = self.begin.advance(self.input);
+
+            if byte == 0xFF {
                 return Some(T::mismatch());
             }
 
             self.begin.consume(self.input);
 
+            self.line_start = byte == b'\n';
+
             self.end = self.begin;
             self.current = self.begin;
 
@@ -127,6 +168,8 @@ impl<'a, T: Token> Iterator for LDStatelessScanner<'a, T> {
             }
 
             self.pending = Some(token);
+            self.track(token);
+            self.track_line_start();
 
             self.begin = self.end;
             self.current = self.end;
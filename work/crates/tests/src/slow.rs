@@ -0,0 +1,144 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{ops::Deref, thread::sleep, time::Duration};
+
+use lady_deirdre::{
+    analysis::{
+        AnalysisResult,
+        AnalysisResultEx,
+        Attr,
+        AttrContext,
+        Computable,
+        Feature,
+        Semantics,
+        TaskHandle,
+    },
+    lexis::TokenRef,
+    sync::SyncBuildHasher,
+    syntax::{Node, NodeRef, PolyRef},
+};
+use lady_deirdre_examples::expr_parser::lexis::BoolToken;
+
+// Upper bound of the slow computations. The tests expect these computations
+// to be interrupted long before this number of iterations is reached.
+const MAX_ITERATIONS: usize = 10_000;
+
+#[derive(Node)]
+#[token(BoolToken)]
+#[trivia($Whitespace)]
+pub enum SlowNode {
+    #[root]
+    #[rule(token: $True)]
+    Root {
+        #[node]
+        node: NodeRef,
+        #[parent]
+        parent: NodeRef,
+        #[child]
+        token: TokenRef,
+        #[semantics]
+        semantics: Semantics<SlowSemantics>,
+    },
+}
+
+#[derive(Feature)]
+#[node(SlowNode)]
+pub struct SlowSemantics {
+    pub leaf: Attr<Leaf>,
+    pub spin: Attr<Spin>,
+    pub poll: Attr<Poll>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Leaf;
+
+impl Computable for Leaf {
+    type Node = SlowNode;
+
+    fn compute<H: TaskHandle, S: SyncBuildHasher>(
+        _context: &mut AttrContext<Self::Node, H, S>,
+    ) -> AnalysisResult<Self> {
+        Ok(Self)
+    }
+}
+
+// Calls the proceed function explicitly on each iteration.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Spin(pub usize);
+
+impl Computable for Spin {
+    type Node = SlowNode;
+
+    fn compute<H: TaskHandle, S: SyncBuildHasher>(
+        context: &mut AttrContext<Self::Node, H, S>,
+    ) -> AnalysisResult<Self> {
+        for _ in 0..MAX_ITERATIONS {
+            context.proceed()?;
+
+            sleep(Duration::from_millis(1));
+        }
+
+        Ok(Self(MAX_ITERATIONS))
+    }
+}
+
+// Relies on the interruption checks of the attribute reads.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Poll(pub usize);
+
+impl Computable for Poll {
+    type Node = SlowNode;
+
+    fn compute<H: TaskHandle, S: SyncBuildHasher>(
+        context: &mut AttrContext<Self::Node, H, S>,
+    ) -> AnalysisResult<Self> {
+        let root_ref = context.node_ref();
+        let doc_read = context.read_doc(root_ref.id).unwrap_abnormal()?;
+
+        let Some(SlowNode::Root { semantics, .. }) = root_ref.deref(doc_read.deref()) else {
+            return Ok(Self(0));
+        };
+
+        let semantics = semantics.get().unwrap_abnormal()?;
+
+        for _ in 0..MAX_ITERATIONS {
+            let _ = semantics.leaf.read(context)?;
+
+            sleep(Duration::from_millis(1));
+        }
+
+        Ok(Self(MAX_ITERATIONS))
+    }
+}
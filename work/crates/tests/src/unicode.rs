@@ -0,0 +1,121 @@
+////////////////////////////////////////////////////////////////////////////////
+// This file is part of "Lady Deirdre", a compiler front-end foundation       //
+// technology.                                                                //
+//                                                                            //
+// This work is proprietary software with source-available code.              //
+//                                                                            //
+// To copy, use, distribute, or contribute to this work, you must agree to    //
+// the terms of the General License Agreement:                                //
+//                                                                            //
+// https://github.com/Eliah-Lakhin/lady-deirdre/blob/master/EULA.md           //
+//                                                                            //
+// The agreement grants a Basic Commercial License, allowing you to use       //
+// this work in non-commercial and limited commercial products with a total   //
+// gross revenue cap. To remove this commercial limit for one of your         //
+// products, you must acquire a Full Commercial License.                      //
+//                                                                            //
+// If you contribute to the source code, documentation, or related materials, //
+// you must grant me an exclusive license to these contributions.             //
+// Contributions are governed by the "Contributions" section of the General   //
+// License Agreement.                                                         //
+//                                                                            //
+// Copying the work in parts is strictly forbidden, except as permitted       //
+// under the General License Agreement.                                       //
+//                                                                            //
+// If you do not or cannot agree to the terms of this Agreement,              //
+// do not use this work.                                                      //
+//                                                                            //
+// This work is provided "as is", without any warranties, express or implied, //
+// except where such disclaimers are legally invalid.                         //
+//                                                                            //
+// Copyright (c) 2024 Ilya Lakhin (Илья Александрович Лахин).                 //
+// All rights reserved.                                                       //
+////////////////////////////////////////////////////////////////////////////////
+
+use lady_deirdre::lexis::Token;
+use lady_deirdre_examples::json_grammar::lexis::JsonToken;
+
+// A copy of the JsonToken scanner with one extra rule matching the byte order
+// mark. This rule brings a non-ASCII character into the scanner's alphabet,
+// so the generated scanner does not use the ASCII transitions table, and
+// reads every byte through the generic per-state transitions.
+#[derive(Token, Clone, Copy, PartialEq, Eq, Debug)]
+#[define(DEC = ['0'..'9'])]
+#[define(HEX = DEC | ['A'..'F'])]
+#[define(POSITIVE = ['1'..'9'] DEC*)]
+#[define(ESCAPE = '\\' (
+    | ['"', '\\', '/', 'b', 'f', 'n', 'r', 't']
+    | ('u' HEX HEX HEX HEX)
+))]
+#[lookback(2)]
+#[repr(u8)]
+pub enum UnicodeJsonToken {
+    EOI = 0,
+
+    Mismatch = 1,
+
+    #[rule("true")]
+    True,
+
+    #[rule("false")]
+    False,
+
+    #[rule("null")]
+    Null,
+
+    #[rule('{')]
+    BraceOpen,
+
+    #[rule('}')]
+    BraceClose,
+
+    #[rule('[')]
+    BracketOpen,
+
+    #[rule(']')]
+    BracketClose,
+
+    #[rule(',')]
+    Comma,
+
+    #[rule(':')]
+    Colon,
+
+    #[rule('"' (ESCAPE | ^['"', '\\'])* '"')]
+    String,
+
+    #[rule('-'? ('0' | POSITIVE) ('.' DEC+)? (['e', 'E'] ['-', '+']? DEC+)?)]
+    Number,
+
+    #[rule([' ', '\t', '\n', '\x0c', '\r']+)]
+    Whitespace,
+
+    #[rule("//" ^['\n']*)]
+    Comment,
+
+    #[rule('\u{FEFF}')]
+    ByteOrderMark,
+}
+
+impl UnicodeJsonToken {
+    pub fn into_ld(self) -> JsonToken {
+        match self {
+            UnicodeJsonToken::EOI => JsonToken::EOI,
+            UnicodeJsonToken::Mismatch => JsonToken::Mismatch,
+            UnicodeJsonToken::True => JsonToken::True,
+            UnicodeJsonToken::False => JsonToken::False,
+            UnicodeJsonToken::Null => JsonToken::Null,
+            UnicodeJsonToken::BraceOpen => JsonToken::BraceOpen,
+            UnicodeJsonToken::BraceClose => JsonToken::BraceClose,
+            UnicodeJsonToken::BracketOpen => JsonToken::BracketOpen,
+            UnicodeJsonToken::BracketClose => JsonToken::BracketClose,
+            UnicodeJsonToken::Comma => JsonToken::Comma,
+            UnicodeJsonToken::Colon => JsonToken::Colon,
+            UnicodeJsonToken::String => JsonToken::String,
+            UnicodeJsonToken::Number => JsonToken::Number,
+            UnicodeJsonToken::Whitespace => JsonToken::Whitespace,
+            UnicodeJsonToken::Comment => JsonToken::Comment,
+            UnicodeJsonToken::ByteOrderMark => JsonToken::Mismatch,
+        }
+    }
+}
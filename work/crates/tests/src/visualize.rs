@@ -47,11 +47,12 @@ use lady_deirdre::{
     syntax::SyntaxTree,
     units::Document,
 };
+use lady_deirdre_bench::{BenchCommand, BenchData};
 use lady_deirdre_examples::json_grammar::syntax::JsonNode;
-use lady_deirdre_tests::data::{BenchCommand, BenchData};
+use lady_deirdre_tests::data;
 
 fn main() {
-    let (small, _) = BenchData::load();
+    let (small, _) = data::load();
 
     let mut doc = Document::<JsonNode>::new_mutable("");
 
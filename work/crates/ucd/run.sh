@@ -36,4 +36,4 @@
 
 rm -rf downloads
 rm -f ucd_gen.txt
-cargo run -- download && cargo run -- generate
+cargo run -- download && cargo run -- generate "$@"
@@ -38,7 +38,7 @@ use std::{
     process::{exit, Command},
 };
 
-use crate::{UCD_DOWNLOADS_DIR, UCD_RESOURCES, UCD_URL};
+use crate::{UCD_DOWNLOADS_DIR, UCD_RESOURCES, UCD_URL, UCD_VERSION};
 
 pub(super) fn download() {
     println!("Downloading UCD data...");
@@ -59,11 +59,18 @@ pub(super) fn download() {
 
     println!("Downloads dir {downloads_dir:?} created.");
 
+    let (major, minor, update) = UCD_VERSION;
+
     for resource in UCD_RESOURCES {
-        let url = UCD_URL.to_owned() + resource;
+        let url = format!("{UCD_URL}{major}.{minor}.{update}/ucd/{resource}");
+
+        let output = match Command::new("curl").arg("--fail").arg(&url).output() {
+            Ok(output) if output.status.success() => output,
 
-        let output = match Command::new("curl").arg(&url).output() {
-            Ok(output) => output,
+            Ok(output) => {
+                eprintln!("Curl failed to fetch {url:?}: {}", output.status);
+                exit(1);
+            }
 
             Err(error) => {
                 eprintln!("Curl failed to fetch {url:?}: {error}",);
@@ -73,6 +80,13 @@ pub(super) fn download() {
 
         let file_name = downloads_dir.join(resource);
 
+        if let Some(parent) = file_name.parent() {
+            if let Err(error) = create_dir_all(parent) {
+                eprintln!("Failed to created downloads dir {parent:?}: {error}");
+                exit(1);
+            }
+        }
+
         match write(file_name.as_path(), output.stdout) {
             Ok(()) => {
                 println!("Remote file {url} saved to {file_name:?}.");
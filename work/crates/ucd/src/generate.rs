@@ -68,21 +68,79 @@
 // distinct from those pertaining to the original "Rust" work.                                        //
 ////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-use std::{fs::write, path::PathBuf, process::exit};
+use std::{
+    fs::{read_to_string, write},
+    path::PathBuf,
+    process::exit,
+};
 
 use ahash::{AHashMap, AHashSet};
 
-use crate::{parse::parse_raw_data, PropDesc, GENERATED_FILE};
+use crate::{
+    parse::{
+        parse_case_mappings,
+        parse_grapheme_cluster_breaks,
+        parse_indic_conjunct_breaks,
+        parse_raw_data,
+        parse_scripts,
+        parse_version,
+        RawCaseMapping,
+        RawScripts,
+    },
+    BreakDesc,
+    CaseDesc,
+    PropDesc,
+    COMMITTED_FILE,
+    GENERATED_FILE,
+    GRAPHEME_CLUSTER_BREAKS,
+    INDIC_CONJUNCT_BREAKS,
+};
 
 const TOTAL: usize = 0x110000;
 const CHUNK: usize = 64;
 
-pub(super) fn generate() {
-    let input = parse_raw_data();
+const CHECKSUM_OFFSET: u64 = 0xCBF29CE484222325;
+const CHECKSUM_PRIME: u64 = 0x100000001B3;
 
-    println!("Starting UCD module generation...");
+// The first line of the generated module. The committed file consists of
+// the license headers followed by the generated module starting from
+// this line.
+static NOTICE: &str = r#"// This file is generated by "crates/ucd". Do not edit manually."#;
+
+pub(super) fn generate(force: bool) {
+    let version = parse_version();
+
+    for existing in [GENERATED_FILE, COMMITTED_FILE] {
+        let Some(existing_version) = read_existing_version(existing) else {
+            continue;
+        };
+
+        if existing_version <= version {
+            continue;
+        }
+
+        let (major, minor, update) = existing_version;
 
-    let output = Emitter::generate(input);
+        match force {
+            true => {
+                println!(
+                    "Downgrading tables of {existing:?} from version \
+                    {major}.{minor}.{update}.",
+                );
+            }
+
+            false => {
+                eprintln!(
+                    "The tables of {existing:?} have been generated from a newer \
+                    Unicode version {major}.{minor}.{update}. Use the \"--force\" \
+                    flag to overwrite them.",
+                );
+                exit(1);
+            }
+        }
+    }
+
+    let output = emit_module(version);
 
     let path = PathBuf::from(GENERATED_FILE);
 
@@ -99,13 +157,80 @@ pub(super) fn generate() {
     println!("UCD module generation finished.");
 }
 
+pub(super) fn check() {
+    let version = parse_version();
+
+    let output = emit_module(version);
+
+    let committed = match read_to_string(COMMITTED_FILE) {
+        Ok(content) => content,
+
+        Err(error) => {
+            eprintln!("Failed to read {COMMITTED_FILE:?}: {error}");
+            exit(1);
+        }
+    };
+
+    let Some(start) = committed.find(NOTICE) else {
+        eprintln!("Missing generated module in {COMMITTED_FILE:?}.");
+        exit(1);
+    };
+
+    if committed[start..] != output {
+        eprintln!(
+            "The tables of {COMMITTED_FILE:?} differ from the generated module. \
+            Use the \"generate\" command to regenerate them.",
+        );
+        exit(1);
+    }
+
+    println!("The tables of {COMMITTED_FILE:?} match the generated module.");
+}
+
+fn emit_module(version: (u8, u8, u8)) -> String {
+    let input = parse_raw_data();
+    let cases = parse_case_mappings();
+    let breaks = parse_grapheme_cluster_breaks();
+    let conjuncts = parse_indic_conjunct_breaks();
+    let scripts = parse_scripts();
+
+    println!("Starting UCD module generation...");
+
+    Emitter::generate(version, input, cases, breaks, conjuncts, scripts)
+}
+
+fn read_existing_version(path: &str) -> Option<(u8, u8, u8)> {
+    static PREFIX: &str = "pub const UNICODE_VERSION: (u8, u8, u8) = (";
+
+    let content = read_to_string(path).ok()?;
+
+    let line = content.lines().find(|line| line.starts_with(PREFIX))?;
+    let mut components = line[PREFIX.len()..]
+        .trim_end_matches(");")
+        .split(", ")
+        .map(|component| component.parse::<u8>().ok());
+
+    let major = components.next()??;
+    let minor = components.next()??;
+    let update = components.next()??;
+
+    Some((major, minor, update))
+}
+
 struct Emitter {
     output: String,
     line_length: usize,
 }
 
 impl Emitter {
-    fn generate(input: Vec<(&'static PropDesc, AHashSet<u32>)>) -> String {
+    fn generate(
+        version: (u8, u8, u8),
+        input: Vec<(&'static PropDesc, AHashSet<u32>)>,
+        cases: Vec<(&'static CaseDesc, RawCaseMapping)>,
+        breaks: Vec<(u32, u32, &'static BreakDesc)>,
+        conjuncts: Vec<(u32, u32, &'static BreakDesc)>,
+        scripts: RawScripts,
+    ) -> String {
         let mut emitter = Self {
             output: String::new(),
             line_length: 0,
@@ -113,20 +238,60 @@ impl Emitter {
 
         emitter.emit_notice();
         emitter.emit_char_properties_object(&input);
-        emitter.emit_char_trait(&input);
+        emitter.emit_char_trait(&input, &cases);
+        emitter.emit_case_mapping_type();
+        emitter.emit_grapheme_cluster_break_type();
+        emitter.emit_indic_conjunct_break_type();
+        emitter.emit_script_types(&scripts);
         emitter.emit_trie_type();
 
+        let mut checksums = Vec::new();
+
         for (prop, code_points) in &input {
-            emitter.emit_table(*prop, code_points);
+            checksums.push((prop.table_name, emitter.emit_table(*prop, code_points)));
         }
 
-        emitter.emit_tests();
+        emitter.emit_case_table_type();
+
+        for (desc, mapping) in &cases {
+            checksums.push((desc.table_name, emitter.emit_case_table(*desc, mapping)));
+        }
+
+        checksums.push((
+            "GRAPHEME_CLUSTER_BREAK_TABLE",
+            emitter.emit_break_table(
+                "GRAPHEME_CLUSTER_BREAK_TABLE",
+                "GraphemeClusterBreak",
+                GRAPHEME_CLUSTER_BREAKS,
+                &breaks,
+            ),
+        ));
+
+        checksums.push((
+            "INDIC_CONJUNCT_BREAK_TABLE",
+            emitter.emit_break_table(
+                "INDIC_CONJUNCT_BREAK_TABLE",
+                "IndicConjunctBreak",
+                INDIC_CONJUNCT_BREAKS,
+                &conjuncts,
+            ),
+        ));
+
+        checksums.push(("SCRIPT_TABLE", emitter.emit_script_table(&scripts)));
+
+        checksums.push((
+            "SCRIPT_EXTENSIONS_TABLE",
+            emitter.emit_script_extensions_table(&scripts),
+        ));
+
+        emitter.emit_version(version, &checksums);
+        emitter.emit_tests(&input, &cases);
 
         emitter.output
     }
 
     fn emit_notice(&mut self) {
-        self.write_ln(r#"// This file is generated by "crates/ucd". Do not edit manually."#);
+        self.write_ln(NOTICE);
         self.blank_ln();
     }
 
@@ -148,7 +313,7 @@ impl Emitter {
         );
         self.write_ln("///");
         self.write_ln("/// By default, this object does not have any configured properties.");
-        self.write_ln("/// Therefore, the has_properties function returns false: ");
+        self.write_ln("/// Therefore, the has_properties function returns false:");
         self.write_ln("/// `assert!(!'b'.has_properties(&CharProperties::new()))`.");
         self.write_ln("///");
         self.write_ln("/// **Note**: This object is not stabilized yet. New members may be");
@@ -193,39 +358,45 @@ impl Emitter {
             self.write_ln(": bool,");
         }
 
+        self.blank_ln();
+        self.write_ln("    /// Includes characters of the specified scripts. A character belongs");
+        self.write_ln(
+            "    /// to a script if its `Script_Extensions` property contains this script.",
+        );
+        self.write_ln("    pub scripts: ScriptSet,");
         self.write_ln("}");
         self.blank_ln();
 
-        self.write_ln("impl std::fmt::Display for CharProperties {");
+        self.write_ln("impl core::fmt::Display for CharProperties {");
         self.write_ln("    #[inline(always)]");
-        self.write("    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>)");
-        self.write_ln(" -> std::fmt::Result {");
+        self.write("    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>)");
+        self.write_ln(" -> core::fmt::Result {");
         self.write_ln("        formatter.write_str(\"$\")?;");
         self.blank_ln();
-        self.write_ln("        let mut props = Vec::new();");
+        self.write_ln("        let props = [");
 
         for (prop, _) in input {
-            self.blank_ln();
-            self.write("        if self.");
+            self.write("            (self.");
             self.write(prop.field_name);
-            self.write_ln(" {");
-            self.write("            props.push(\"");
+            self.write(", \"");
             self.write(prop.field_name);
-            self.write_ln("\");");
-            self.write_ln("        }");
+            self.write_ln("\"),");
         }
 
+        self.write_ln("        ];");
         self.blank_ln();
-        self.write_ln("        if props.len() == 1 {");
-        self.write_ln("            formatter.write_str(props[0])?;");
-        self.write_ln("            return Ok(());");
-        self.write_ln("        }");
+        self.write_ln("        let count =");
+        self.write_ln(
+            "            props.iter().filter(|(enabled, _)| *enabled).count() + self.scripts.iter().count();",
+        );
         self.blank_ln();
-        self.write_ln("        formatter.write_str(\"{\")?;");
+        self.write_ln("        if count != 1 {");
+        self.write_ln("            formatter.write_str(\"{\")?;");
+        self.write_ln("        }");
         self.blank_ln();
         self.write_ln("        let mut first = true;");
         self.blank_ln();
-        self.write_ln("        for prop in props {");
+        self.write_ln("        for (_, prop) in props.iter().filter(|(enabled, _)| *enabled) {");
         self.write_ln("            match first {");
         self.write_ln("                true => first = false,");
         self.write_ln("                false => formatter.write_str(\" | \")?,");
@@ -234,7 +405,20 @@ impl Emitter {
         self.write_ln("            formatter.write_str(prop)?;");
         self.write_ln("        }");
         self.blank_ln();
-        self.write_ln("        formatter.write_str(\"}\")?;");
+        self.write_ln("        for script in self.scripts.iter() {");
+        self.write_ln("            match first {");
+        self.write_ln("                true => first = false,");
+        self.write_ln("                false => formatter.write_str(\" | \")?,");
+        self.write_ln("            }");
+        self.blank_ln();
+        self.write_ln(
+            "            formatter.write_fmt(format_args!(\"script({})\", script.name()))?;",
+        );
+        self.write_ln("        }");
+        self.blank_ln();
+        self.write_ln("        if count != 1 {");
+        self.write_ln("            formatter.write_str(\"}\")?;");
+        self.write_ln("        }");
         self.blank_ln();
         self.write_ln("        Ok(())");
         self.write_ln("    }");
@@ -263,6 +447,8 @@ impl Emitter {
             self.write_ln(": false,");
         }
 
+        self.write_ln("            scripts: ScriptSet::new(),");
+
         self.write_ln("        }");
         self.write_ln("    }");
 
@@ -301,6 +487,17 @@ impl Emitter {
             self.write_ln("    }");
         }
 
+        self.blank_ln();
+        self.write_ln("    /// Includes characters of the specified script.");
+        self.write_ln("    ///");
+        self.write_ln("    /// See [CharProperties::scripts] for details.");
+        self.write_ln("    #[inline(always)]");
+        self.write_ln("    pub const fn with_script(mut self, script: Script) -> Self {");
+        self.write_ln("        self.scripts = self.scripts.with(script);");
+        self.blank_ln();
+        self.write_ln("        self");
+        self.write_ln("    }");
+
         self.blank_ln();
         self.write_ln("    /// Creates a union of two property configurations.");
         self.write_ln("    ///");
@@ -319,6 +516,8 @@ impl Emitter {
             self.write_ln(";");
         }
 
+        self.write_ln("        self.scripts = self.scripts.union(other.scripts);");
+
         self.blank_ln();
         self.write_ln("        self");
         self.write_ln("    }");
@@ -341,6 +540,8 @@ impl Emitter {
             self.write_ln(";");
         }
 
+        self.write_ln("        self.scripts = self.scripts.intersect(other.scripts);");
+
         self.blank_ln();
         self.write_ln("        self");
         self.write_ln("    }");
@@ -349,7 +550,11 @@ impl Emitter {
         self.blank_ln();
     }
 
-    fn emit_char_trait(&mut self, input: &[(&'static PropDesc, AHashSet<u32>)]) {
+    fn emit_char_trait(
+        &mut self,
+        input: &[(&'static PropDesc, AHashSet<u32>)],
+        cases: &[(&'static CaseDesc, RawCaseMapping)],
+    ) {
         self.write_ln("/// An extension trait that provides functions to reveal a character's");
         self.write_ln("/// Unicode properties.");
         self.write_ln("///");
@@ -398,6 +603,71 @@ impl Emitter {
         self.write_ln("    /// properties. See [CharProperties] for details.");
         self.write_ln("    fn has_properties(self, props: &CharProperties) -> bool;");
 
+        for (desc, _) in cases {
+            self.blank_ln();
+            self.write("    /// Returns the `");
+            self.write(desc.simple_name);
+            self.write_ln("` of the character.");
+            self.write_ln("    ///");
+            self.write("    /// If the character does not have a ");
+            self.write(desc.word);
+            self.write_ln(" mapping, the function");
+            self.write_ln("    /// returns the character itself.");
+            self.write("    fn to_");
+            self.write(desc.fn_name);
+            self.write_ln("_simple(self) -> char;");
+            self.blank_ln();
+            self.write("    /// Returns an iterator over the characters of the `");
+            self.write(desc.full_name);
+            self.write_ln("`");
+            self.write_ln("    /// of the character.");
+            self.write_ln("    ///");
+            self.write("    /// Unlike [Char::to_");
+            self.write(desc.fn_name);
+            self.write_ln("_simple], the full mapping may map a");
+            self.write_ln(
+                "    /// character to several characters. The conditional (language-sensitive",
+            );
+            self.write_ln("    /// and context-sensitive) mappings of the SpecialCasing.txt file");
+            self.write_ln("    /// are not applied.");
+            self.write("    fn to_");
+            self.write(desc.fn_name);
+            self.write_ln("_full(self) -> CaseMapping;");
+        }
+
+        self.blank_ln();
+        self.write_ln(
+            "    /// Returns the `Grapheme_Cluster_Break` property value of the character.",
+        );
+        self.write_ln("    ///");
+        self.write_ln("    /// If the character does not have any specific value, the function");
+        self.write_ln("    /// returns [GraphemeClusterBreak::Other].");
+        self.write_ln("    fn grapheme_cluster_break(self) -> GraphemeClusterBreak;");
+        self.blank_ln();
+        self.write_ln(
+            "    /// Returns the `Indic_Conjunct_Break` property value of the character.",
+        );
+        self.write_ln("    ///");
+        self.write_ln("    /// If the character does not have any specific value, the function");
+        self.write_ln("    /// returns [IndicConjunctBreak::None].");
+        self.write_ln("    fn indic_conjunct_break(self) -> IndicConjunctBreak;");
+        self.blank_ln();
+        self.write_ln("    /// Returns the `Script` property value of the character.");
+        self.write_ln("    ///");
+        self.write_ln("    /// If the character is not assigned to any script, the function");
+        self.write_ln("    /// returns [Script::Unknown].");
+        self.write_ln("    fn script(self) -> Script;");
+        self.blank_ln();
+        self.write_ln("    /// Returns the `Script_Extensions` property value of the character.");
+        self.write_ln("    ///");
+        self.write_ln("    /// If the character does not have explicit script extensions,");
+        self.write_ln("    /// the function returns a single [Char::script] value.");
+        self.write_ln("    fn script_extensions(self) -> &'static [Script];");
+        self.blank_ln();
+        self.write_ln("    /// Returns true if the `Script_Extensions` property value of");
+        self.write_ln("    /// the character contains the specified `script`.");
+        self.write_ln("    fn has_script(self, script: Script) -> bool;");
+
         self.write_ln("}");
         self.blank_ln();
 
@@ -441,103 +711,512 @@ impl Emitter {
             self.write_ln("        }");
         }
 
+        self.blank_ln();
+        self.write_ln("        if !props.scripts.is_empty() {");
+        self.write_ln("            for script in self.script_extensions() {");
+        self.write_ln("                if props.scripts.contains(*script) {");
+        self.write_ln("                    return true;");
+        self.write_ln("                }");
+        self.write_ln("            }");
+        self.write_ln("        }");
         self.blank_ln();
         self.write_ln("        false");
         self.write_ln("    }");
+
+        for (desc, _) in cases {
+            self.blank_ln();
+            self.write_ln("    #[inline(always)]");
+            self.write("    fn to_");
+            self.write(desc.fn_name);
+            self.write_ln("_simple(self) -> char {");
+            self.write("        ");
+            self.write(desc.table_name);
+            self.write_ln(".simple(self)");
+            self.write_ln("    }");
+            self.blank_ln();
+            self.write_ln("    #[inline(always)]");
+            self.write("    fn to_");
+            self.write(desc.fn_name);
+            self.write_ln("_full(self) -> CaseMapping {");
+            self.write("        ");
+            self.write(desc.table_name);
+            self.write_ln(".full(self)");
+            self.write_ln("    }");
+        }
+
+        self.blank_ln();
+        self.write_ln("    #[inline(always)]");
+        self.write_ln("    fn grapheme_cluster_break(self) -> GraphemeClusterBreak {");
+        self.write_ln("        match lookup_range(GRAPHEME_CLUSTER_BREAK_TABLE, self) {");
+        self.write_ln("            Some(value) => value,");
+        self.write_ln("            None => GraphemeClusterBreak::Other,");
+        self.write_ln("        }");
+        self.write_ln("    }");
+        self.blank_ln();
+        self.write_ln("    #[inline(always)]");
+        self.write_ln("    fn indic_conjunct_break(self) -> IndicConjunctBreak {");
+        self.write_ln("        match lookup_range(INDIC_CONJUNCT_BREAK_TABLE, self) {");
+        self.write_ln("            Some(value) => value,");
+        self.write_ln("            None => IndicConjunctBreak::None,");
+        self.write_ln("        }");
+        self.write_ln("    }");
+        self.blank_ln();
+        self.write_ln("    #[inline(always)]");
+        self.write_ln("    fn script(self) -> Script {");
+        self.write_ln("        match lookup_range(SCRIPT_TABLE, self) {");
+        self.write_ln("            Some(script) => script,");
+        self.write_ln("            None => Script::Unknown,");
+        self.write_ln("        }");
+        self.write_ln("    }");
+        self.blank_ln();
+        self.write_ln("    #[inline(always)]");
+        self.write_ln("    fn script_extensions(self) -> &'static [Script] {");
+        self.write_ln("        match lookup_range(SCRIPT_EXTENSIONS_TABLE, self) {");
+        self.write_ln("            Some(scripts) => scripts,");
+        self.write_ln(
+            "            None => core::slice::from_ref(&SCRIPTS[self.script() as usize]),",
+        );
+        self.write_ln("        }");
+        self.write_ln("    }");
+        self.blank_ln();
+        self.write_ln("    #[inline(always)]");
+        self.write_ln("    fn has_script(self, script: Script) -> bool {");
+        self.write_ln("        self.script_extensions().contains(&script)");
+        self.write_ln("    }");
+        self.write_ln("}");
+        self.blank_ln();
+        self.write_ln("#[inline(always)]");
+        self.write_ln(
+            "fn lookup_range<T: Copy>(table: &[(char, char, T)], ch: char) -> Option<T> {",
+        );
+        self.write_ln("    let index = table.binary_search_by(|(from, to, _)| {");
+        self.write_ln("        if *to < ch {");
+        self.write_ln("            return core::cmp::Ordering::Less;");
+        self.write_ln("        }");
+        self.blank_ln();
+        self.write_ln("        if *from > ch {");
+        self.write_ln("            return core::cmp::Ordering::Greater;");
+        self.write_ln("        }");
+        self.blank_ln();
+        self.write_ln("        core::cmp::Ordering::Equal");
+        self.write_ln("    });");
+        self.blank_ln();
+        self.write_ln("    match index {");
+        self.write_ln("        Ok(index) => Some(table[index].2),");
+        self.write_ln("        Err(_) => None,");
+        self.write_ln("    }");
         self.write_ln("}");
         self.blank_ln();
     }
 
-    fn emit_trie_type(&mut self) {
-        self.write_ln("struct UCDTrie {");
-        self.write_ln("    r1: [u64; 32],");
-        self.write_ln("    r2: [u8; 992],");
-        self.write_ln("    r3: &'static [u64],");
-        self.write_ln("    r4: [u8; 256],");
-        self.write_ln("    r5: &'static [u8],");
-        self.write_ln("    r6: &'static [u64],");
+    fn emit_case_mapping_type(&mut self) {
+        self.write_ln("/// An iterator over the characters of a full case mapping.");
+        self.write_ln("///");
+        self.write_ln("/// This object is created by the [Char::to_lower_full],");
+        self.write_ln("/// [Char::to_title_full], and [Char::to_upper_full] functions.");
+        self.write_ln("///");
+        self.write_ln("/// The [Display](core::fmt::Display) implementation of this object prints");
+        self.write_ln("/// the remaining characters of the mapping:");
+        self.write_ln("/// `assert_eq!('\\u{DF}'.to_upper_full().to_string(), \"SS\")`.");
+        self.write_ln("#[derive(Clone, Debug)]");
+        self.write_ln("pub struct CaseMapping {");
+        self.write_ln("    chars: [char; 3],");
+        self.write_ln("    next: u8,");
+        self.write_ln("    len: u8,");
         self.write_ln("}");
-        self.write_ln("");
-        self.write_ln("impl UCDTrie {");
-        self.write_ln("    #[inline(always)]");
-        self.write_ln("    fn lookup(&self, code_point: usize) -> bool {");
-        self.write_ln("        if code_point < 0x800 {");
-        self.write("            ");
-        self.write_ln("return Self::check_chunk(self.r1[code_point >> 6], code_point);");
+        self.blank_ln();
+        self.write_ln("impl Iterator for CaseMapping {");
+        self.write_ln("    type Item = char;");
+        self.blank_ln();
+        self.write_ln("    #[inline]");
+        self.write_ln("    fn next(&mut self) -> Option<Self::Item> {");
+        self.write_ln("        if self.next >= self.len {");
+        self.write_ln("            return None;");
         self.write_ln("        }");
-        self.write_ln("");
-        self.write_ln("        if code_point < 0x10000 {");
-        self.write_ln("            let child = self.r2[(code_point >> 6) - 0x20];");
-        self.write_ln("");
-        self.write_ln("            return Self::check_chunk(self.r3[child as usize], code_point);");
+        self.blank_ln();
+        self.write_ln("        let ch = self.chars[self.next as usize];");
+        self.blank_ln();
+        self.write_ln("        self.next += 1;");
+        self.blank_ln();
+        self.write_ln("        Some(ch)");
+        self.write_ln("    }");
+        self.blank_ln();
+        self.write_ln("    #[inline(always)]");
+        self.write_ln("    fn size_hint(&self) -> (usize, Option<usize>) {");
+        self.write_ln("        let remaining = (self.len - self.next) as usize;");
+        self.blank_ln();
+        self.write_ln("        (remaining, Some(remaining))");
+        self.write_ln("    }");
+        self.write_ln("}");
+        self.blank_ln();
+        self.write_ln("impl ExactSizeIterator for CaseMapping {}");
+        self.blank_ln();
+        self.write_ln("impl core::iter::FusedIterator for CaseMapping {}");
+        self.blank_ln();
+        self.write_ln("impl core::fmt::Display for CaseMapping {");
+        self.write_ln("    #[inline]");
+        self.write("    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>)");
+        self.write_ln(" -> core::fmt::Result {");
+        self.write_ln("        for ch in self.clone() {");
+        self.write_ln("            core::fmt::Write::write_char(formatter, ch)?;");
         self.write_ln("        }");
-        self.write_ln("");
-        self.write_ln("        let child = self.r4[(code_point >> 12) - 0x10];");
-        self.write("        ");
-        self.write_ln("let leaf = self.r5[((child as usize) << 6) + ((code_point >> 6) & 0x3f)];");
-        self.write_ln("");
-        self.write_ln("        Self::check_chunk(self.r6[leaf as usize], code_point)");
+        self.blank_ln();
+        self.write_ln("        Ok(())");
         self.write_ln("    }");
-        self.write_ln("");
+        self.write_ln("}");
+        self.blank_ln();
+        self.write_ln("impl CaseMapping {");
         self.write_ln("    #[inline(always)]");
-        self.write_ln("    fn check_chunk(chunk: u64, code_point: usize) -> bool {");
-        self.write_ln("        ((chunk >> (code_point & 63)) & 1) != 0");
+        self.write_ln("    fn new(chars: [char; 3]) -> Self {");
+        self.write_ln(
+            "        let len = 1 + chars[1..].iter().take_while(|ch| **ch != '\\0').count();",
+        );
+        self.blank_ln();
+        self.write_ln("        Self {");
+        self.write_ln("            chars,");
+        self.write_ln("            next: 0,");
+        self.write_ln("            len: len as u8,");
+        self.write_ln("        }");
         self.write_ln("    }");
         self.write_ln("}");
         self.blank_ln();
     }
 
-    fn emit_table(&mut self, prop: &'static PropDesc, code_points: &AHashSet<u32>) {
-        let mut chunks = Vec::new();
-
-        for chunk_index in 0..TOTAL / CHUNK {
-            let mut chunk = 0u64;
+    fn emit_grapheme_cluster_break_type(&mut self) {
+        self.write_ln("/// A value of the `Grapheme_Cluster_Break` Unicode character property.");
+        self.write_ln("///");
+        self.write_ln("/// This value is returned by the [Char::grapheme_cluster_break] function,");
+        self.write_ln("/// and is used to split text into extended grapheme clusters according");
+        self.write_ln("/// to the [UAX #29](https://www.unicode.org/reports/tr29/) rules.");
+        self.write_ln("#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]");
+        self.write_ln("#[non_exhaustive]");
+        self.write_ln("pub enum GraphemeClusterBreak {");
+        self.write_ln("    /// The `Other` value of characters without any specific value.");
+        self.write_ln("    Other,");
 
-            for bit in 0..64 {
-                let code_point =
-                    <u32>::try_from(chunk_index * 64 + bit).expect("Invalid code point.");
+        for desc in GRAPHEME_CLUSTER_BREAKS {
+            self.blank_ln();
+            self.write("    /// The `");
+            self.write(desc.raw_name);
+            self.write_ln("` value.");
+            self.write("    ");
+            self.write(desc.variant_name);
+            self.write_ln(",");
+        }
 
-                if !code_points.contains(&code_point) {
-                    continue;
-                }
+        self.write_ln("}");
+        self.blank_ln();
+    }
 
-                chunk |= 1 << bit;
-            }
+    fn emit_indic_conjunct_break_type(&mut self) {
+        self.write_ln("/// A value of the `Indic_Conjunct_Break` Unicode character property.");
+        self.write_ln("///");
+        self.write_ln("/// This value is returned by the [Char::indic_conjunct_break] function,");
+        self.write_ln("/// and is used by the [UAX #29](https://www.unicode.org/reports/tr29/)");
+        self.write_ln("/// rule GB9c that keeps the Indic conjuncts in a single grapheme cluster.");
+        self.write_ln("#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]");
+        self.write_ln("#[non_exhaustive]");
+        self.write_ln("pub enum IndicConjunctBreak {");
+        self.write_ln("    /// The `None` value of characters without any specific value.");
+        self.write_ln("    None,");
 
-            chunks.push(chunk);
+        for desc in INDIC_CONJUNCT_BREAKS {
+            self.blank_ln();
+            self.write("    /// The `");
+            self.write(desc.raw_name);
+            self.write_ln("` value.");
+            self.write("    ");
+            self.write(desc.variant_name);
+            self.write_ln(",");
         }
 
-        let r1 = &chunks[0..(0x800 / CHUNK)];
-        assert_eq!(r1.len(), 32);
-
-        let (r2, r3) = Self::compute_trie(&chunks[0x800 / CHUNK..0x10000 / CHUNK], 64 / CHUNK);
-        assert_eq!(r2.len(), 992);
+        self.write_ln("}");
+        self.blank_ln();
+    }
 
-        let (mid, r6) = Self::compute_trie(&chunks[0x10000 / CHUNK..0x110000 / CHUNK], 64 / CHUNK);
-        let (r4, r5) = Self::compute_trie(&mid, 64);
+    fn emit_script_types(&mut self, scripts: &RawScripts) {
+        let count = scripts.names.len();
 
-        self.write_ln("#[rustfmt::skip]");
-        self.write_ln(&format!("static {}: UCDTrie = UCDTrie {{", prop.table_name));
+        self.write_ln("/// A value of the `Script` Unicode character property.");
+        self.write_ln("///");
+        self.write_ln("/// This value is returned by the [Char::script] function. The");
+        self.write_ln("/// [Char::script_extensions] function returns the set of scripts");
+        self.write_ln("/// a character is commonly used with.");
+        self.write_ln("#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]");
+        self.write_ln("#[non_exhaustive]");
+        self.write_ln("#[repr(u8)]");
+        self.write_ln("pub enum Script {");
 
-        self.write("    r1: ");
-        self.write_chunk_array(r1, true);
-        self.write_ln(",");
+        let mut first = true;
 
-        self.write("    r2: ");
-        self.write_chunk_array(&r2, false);
-        self.write_ln(",");
+        for (long, short) in &scripts.names {
+            match first {
+                true => first = false,
+                false => self.blank_ln(),
+            }
 
-        self.write("    r3: &");
-        self.write_chunk_array(&r3, true);
-        self.write_ln(",");
+            self.write("    /// The `");
+            self.write(long);
+            self.write("` script (`");
+            self.write(short);
+            self.write_ln("`).");
+            self.write("    ");
+            self.write(&Self::script_variant(long));
+            self.write_ln(",");
+        }
 
-        self.write("    r4: ");
-        self.write_chunk_array(&r4, false);
-        self.write_ln(",");
+        self.write_ln("}");
+        self.blank_ln();
 
-        self.write("    r5: &");
-        self.write_chunk_array(&r5, false);
-        self.write_ln(",");
+        self.write_ln("impl core::fmt::Display for Script {");
+        self.write_ln("    #[inline(always)]");
+        self.write("    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>)");
+        self.write_ln(" -> core::fmt::Result {");
+        self.write_ln("        formatter.write_str(self.name())");
+        self.write_ln("    }");
+        self.write_ln("}");
+        self.blank_ln();
+
+        self.write_ln("impl Script {");
+        self.write_ln("    /// Returns the long name of the script as specified in the Unicode");
+        self.write_ln("    /// Character Database (e.g. `Old_Italic`).");
+        self.write_ln("    #[inline(always)]");
+        self.write_ln("    pub const fn name(self) -> &'static str {");
+        self.write_ln("        SCRIPT_NAMES[self as usize].0");
+        self.write_ln("    }");
+        self.blank_ln();
+        self.write_ln(
+            "    /// Returns the four-letter [ISO 15924](https://www.unicode.org/iso15924/)",
+        );
+        self.write_ln("    /// code of the script (e.g. `Ital`).");
+        self.write_ln("    #[inline(always)]");
+        self.write_ln("    pub const fn code(self) -> &'static str {");
+        self.write_ln("        SCRIPT_NAMES[self as usize].1");
+        self.write_ln("    }");
+        self.blank_ln();
+        self.write_ln("    /// Looks up a script by its long name (`Old_Italic`), by its");
+        self.write_ln("    /// [code](Self::code) (`Ital`), or by the name of the enum variant");
+        self.write_ln("    /// (`OldItalic`).");
+        self.write_ln("    ///");
+        self.write_ln("    /// Returns None if there is no script with the specified name.");
+        self.write_ln("    pub fn from_name(name: &str) -> Option<Self> {");
+        self.write_ln("        SCRIPTS.iter().copied().find(|script| {");
+        self.write_ln("            let (long, code) = SCRIPT_NAMES[*script as usize];");
+        self.blank_ln();
+        self.write_ln("            long == name");
+        self.write_ln("                || code == name");
+        self.write_ln(
+            "                || long.bytes().filter(|byte| *byte != b'_').eq(name.bytes())",
+        );
+        self.write_ln("        })");
+        self.write_ln("    }");
+        self.write_ln("}");
+        self.blank_ln();
+
+        self.write_ln(&format!("static SCRIPTS: [Script; {count}] = ["));
+
+        for (long, _) in &scripts.names {
+            self.write("    Script::");
+            self.write(&Self::script_variant(long));
+            self.write_ln(",");
+        }
+
+        self.write_ln("];");
+        self.blank_ln();
+
+        self.write_ln(&format!("const SCRIPT_NAMES: [(&str, &str); {count}] = ["));
+
+        for (long, short) in &scripts.names {
+            self.write_ln(&format!("    (\"{long}\", \"{short}\"),"));
+        }
+
+        self.write_ln("];");
+        self.blank_ln();
+
+        self.write_ln("/// A set of [scripts](Script).");
+        self.write_ln("///");
+        self.write_ln("/// This object is used by the [CharProperties::scripts] configuration.");
+        self.write_ln("#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]");
+        self.write_ln("pub struct ScriptSet {");
+        self.write_ln("    bits: [u64; 4],");
+        self.write_ln("}");
+        self.blank_ln();
+        self.write_ln("impl core::fmt::Debug for ScriptSet {");
+        self.write_ln("    #[inline(always)]");
+        self.write("    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>)");
+        self.write_ln(" -> core::fmt::Result {");
+        self.write_ln("        formatter.debug_set().entries(self.iter()).finish()");
+        self.write_ln("    }");
+        self.write_ln("}");
+        self.blank_ln();
+        self.write_ln("impl FromIterator<Script> for ScriptSet {");
+        self.write_ln("    #[inline(always)]");
+        self.write_ln("    fn from_iter<I: IntoIterator<Item = Script>>(iter: I) -> Self {");
+        self.write_ln("        iter.into_iter()");
+        self.write_ln("            .fold(Self::new(), |set, script| set.with(script))");
+        self.write_ln("    }");
+        self.write_ln("}");
+        self.blank_ln();
+        self.write_ln("impl ScriptSet {");
+        self.write_ln("    /// Returns an empty set.");
+        self.write_ln("    #[inline(always)]");
+        self.write_ln("    pub const fn new() -> Self {");
+        self.write_ln("        Self { bits: [0; 4] }");
+        self.write_ln("    }");
+        self.blank_ln();
+        self.write_ln("    /// Returns a copy of this set with the `script` included.");
+        self.write_ln("    #[inline(always)]");
+        self.write_ln("    pub const fn with(mut self, script: Script) -> Self {");
+        self.write_ln("        let index = script as usize;");
+        self.blank_ln();
+        self.write_ln("        self.bits[index >> 6] |= 1 << (index & 63);");
+        self.blank_ln();
+        self.write_ln("        self");
+        self.write_ln("    }");
+        self.blank_ln();
+        self.write_ln("    /// Returns true if the set includes the `script`.");
+        self.write_ln("    #[inline(always)]");
+        self.write_ln("    pub const fn contains(&self, script: Script) -> bool {");
+        self.write_ln("        let index = script as usize;");
+        self.blank_ln();
+        self.write_ln("        (self.bits[index >> 6] >> (index & 63)) & 1 != 0");
+        self.write_ln("    }");
+        self.blank_ln();
+        self.write_ln("    /// Returns true if the set does not include any script.");
+        self.write_ln("    #[inline(always)]");
+        self.write_ln("    pub const fn is_empty(&self) -> bool {");
+        self.write_ln("        self.bits[0] == 0 && self.bits[1] == 0 && self.bits[2] == 0 && self.bits[3] == 0");
+        self.write_ln("    }");
+        self.blank_ln();
+        self.write_ln("    /// Creates a union of two sets.");
+        self.write_ln("    #[inline(always)]");
+        self.write_ln("    pub const fn union(mut self, other: Self) -> Self {");
+        self.write_ln("        let mut index = 0;");
+        self.blank_ln();
+        self.write_ln("        while index < 4 {");
+        self.write_ln("            self.bits[index] |= other.bits[index];");
+        self.write_ln("            index += 1;");
+        self.write_ln("        }");
+        self.blank_ln();
+        self.write_ln("        self");
+        self.write_ln("    }");
+        self.blank_ln();
+        self.write_ln("    /// Creates an intersection of two sets.");
+        self.write_ln("    #[inline(always)]");
+        self.write_ln("    pub const fn intersect(mut self, other: Self) -> Self {");
+        self.write_ln("        let mut index = 0;");
+        self.blank_ln();
+        self.write_ln("        while index < 4 {");
+        self.write_ln("            self.bits[index] &= other.bits[index];");
+        self.write_ln("            index += 1;");
+        self.write_ln("        }");
+        self.blank_ln();
+        self.write_ln("        self");
+        self.write_ln("    }");
+        self.blank_ln();
+        self.write_ln("    /// Returns an iterator over the scripts of this set in the order");
+        self.write_ln("    /// of the [Script] enum variants.");
+        self.write_ln("    #[inline(always)]");
+        self.write_ln("    pub fn iter(&self) -> impl Iterator<Item = Script> + '_ {");
+        self.write_ln("        SCRIPTS");
+        self.write_ln("            .iter()");
+        self.write_ln("            .copied()");
+        self.write_ln("            .filter(|script| self.contains(*script))");
+        self.write_ln("    }");
+        self.write_ln("}");
+        self.blank_ln();
+    }
+
+    fn emit_trie_type(&mut self) {
+        self.write_ln("struct UCDTrie {");
+        self.write_ln("    r1: [u64; 32],");
+        self.write_ln("    r2: [u8; 992],");
+        self.write_ln("    r3: &'static [u64],");
+        self.write_ln("    r4: [u8; 256],");
+        self.write_ln("    r5: &'static [u8],");
+        self.write_ln("    r6: &'static [u64],");
+        self.write_ln("}");
+        self.write_ln("");
+        self.write_ln("impl UCDTrie {");
+        self.write_ln("    #[inline(always)]");
+        self.write_ln("    fn lookup(&self, code_point: usize) -> bool {");
+        self.write_ln("        if code_point < 0x800 {");
+        self.write("            ");
+        self.write_ln("return Self::check_chunk(self.r1[code_point >> 6], code_point);");
+        self.write_ln("        }");
+        self.write_ln("");
+        self.write_ln("        if code_point < 0x10000 {");
+        self.write_ln("            let child = self.r2[(code_point >> 6) - 0x20];");
+        self.write_ln("");
+        self.write_ln("            return Self::check_chunk(self.r3[child as usize], code_point);");
+        self.write_ln("        }");
+        self.write_ln("");
+        self.write_ln("        let child = self.r4[(code_point >> 12) - 0x10];");
+        self.write("        ");
+        self.write_ln("let leaf = self.r5[((child as usize) << 6) + ((code_point >> 6) & 0x3f)];");
+        self.write_ln("");
+        self.write_ln("        Self::check_chunk(self.r6[leaf as usize], code_point)");
+        self.write_ln("    }");
+        self.write_ln("");
+        self.write_ln("    #[inline(always)]");
+        self.write_ln("    fn check_chunk(chunk: u64, code_point: usize) -> bool {");
+        self.write_ln("        ((chunk >> (code_point & 63)) & 1) != 0");
+        self.write_ln("    }");
+        self.write_ln("}");
+        self.blank_ln();
+    }
+
+    fn emit_table(&mut self, prop: &'static PropDesc, code_points: &AHashSet<u32>) -> u64 {
+        let mut chunks = Vec::new();
+
+        for chunk_index in 0..TOTAL / CHUNK {
+            let mut chunk = 0u64;
+
+            for bit in 0..64 {
+                let code_point =
+                    <u32>::try_from(chunk_index * 64 + bit).expect("Invalid code point.");
+
+                if !code_points.contains(&code_point) {
+                    continue;
+                }
+
+                chunk |= 1 << bit;
+            }
+
+            chunks.push(chunk);
+        }
+
+        let r1 = &chunks[0..(0x800 / CHUNK)];
+        assert_eq!(r1.len(), 32);
+
+        let (r2, r3) = Self::compute_trie(&chunks[0x800 / CHUNK..0x10000 / CHUNK], 64 / CHUNK);
+        assert_eq!(r2.len(), 992);
+
+        let (mid, r6) = Self::compute_trie(&chunks[0x10000 / CHUNK..0x110000 / CHUNK], 64 / CHUNK);
+        let (r4, r5) = Self::compute_trie(&mid, 64);
+
+        self.write_ln("#[rustfmt::skip]");
+        self.write_ln(&format!("static {}: UCDTrie = UCDTrie {{", prop.table_name));
+
+        self.write("    r1: ");
+        self.write_chunk_array(r1, true);
+        self.write_ln(",");
+
+        self.write("    r2: ");
+        self.write_chunk_array(&r2, false);
+        self.write_ln(",");
+
+        self.write("    r3: &");
+        self.write_chunk_array(&r3, true);
+        self.write_ln(",");
+
+        self.write("    r4: ");
+        self.write_chunk_array(&r4, false);
+        self.write_ln(",");
+
+        self.write("    r5: &");
+        self.write_chunk_array(&r5, false);
+        self.write_ln(",");
 
         self.write("    r6: &");
         self.write_chunk_array(&r6, true);
@@ -545,12 +1224,280 @@ impl Emitter {
 
         self.write_ln("};");
         self.blank_ln();
+
+        let mut checksum = CHECKSUM_OFFSET;
+
+        for array in [r1, &r2, &r3, &r4, &r5, &r6] {
+            for chunk in array {
+                checksum = Self::update_checksum(checksum, *chunk);
+            }
+        }
+
+        checksum
+    }
+
+    fn emit_case_table_type(&mut self) {
+        self.write_ln("struct UCDCaseTable {");
+        self.write_ln("    simple: &'static [(char, char)],");
+        self.write_ln("    full: &'static [(char, [char; 3])],");
+        self.write_ln("}");
+        self.blank_ln();
+        self.write_ln("impl UCDCaseTable {");
+        self.write_ln("    #[inline(always)]");
+        self.write_ln("    fn simple(&self, ch: char) -> char {");
+        self.write_ln("        match self.simple.binary_search_by_key(&ch, |(key, _)| *key) {");
+        self.write_ln("            Ok(index) => self.simple[index].1,");
+        self.write_ln("            Err(_) => ch,");
+        self.write_ln("        }");
+        self.write_ln("    }");
+        self.blank_ln();
+        self.write_ln("    #[inline(always)]");
+        self.write_ln("    fn full(&self, ch: char) -> CaseMapping {");
+        self.write_ln("        match self.full.binary_search_by_key(&ch, |(key, _)| *key) {");
+        self.write_ln("            Ok(index) => CaseMapping::new(self.full[index].1),");
+        self.write_ln("            Err(_) => CaseMapping::new([self.simple(ch), '\\0', '\\0']),");
+        self.write_ln("        }");
+        self.write_ln("    }");
+        self.write_ln("}");
+        self.blank_ln();
+    }
+
+    fn emit_case_table(&mut self, desc: &'static CaseDesc, mapping: &RawCaseMapping) -> u64 {
+        self.write_ln("#[rustfmt::skip]");
+        self.write_ln(&format!(
+            "static {}: UCDCaseTable = UCDCaseTable {{",
+            desc.table_name
+        ));
+
+        self.write_ln("    simple: &[");
+        self.write("        ");
+
+        for (from, to) in &mapping.simple {
+            self.write_separator();
+            self.write("(");
+            self.write_char_literal(*from);
+            self.write(", ");
+            self.write_char_literal(*to);
+            self.write("),");
+        }
+
+        self.blank_ln();
+        self.write_ln("    ],");
+
+        self.write_ln("    full: &[");
+        self.write("        ");
+
+        for (from, to) in &mapping.full {
+            self.write_separator();
+            self.write("(");
+            self.write_char_literal(*from);
+            self.write(", [");
+
+            for index in 0..3 {
+                if index > 0 {
+                    self.write(", ");
+                }
+
+                self.write_char_literal(to.get(index).copied().unwrap_or(0));
+            }
+
+            self.write("]),");
+        }
+
+        self.blank_ln();
+        self.write_ln("    ],");
+
+        self.write_ln("};");
+        self.blank_ln();
+
+        let mut checksum = CHECKSUM_OFFSET;
+
+        for (from, to) in &mapping.simple {
+            checksum = Self::update_checksum(checksum, *from as u64);
+            checksum = Self::update_checksum(checksum, *to as u64);
+        }
+
+        for (from, to) in &mapping.full {
+            checksum = Self::update_checksum(checksum, *from as u64);
+
+            for index in 0..3 {
+                let code_point = to.get(index).copied().unwrap_or(0);
+
+                checksum = Self::update_checksum(checksum, code_point as u64);
+            }
+        }
+
+        checksum
+    }
+
+    fn emit_break_table(
+        &mut self,
+        table_name: &str,
+        type_name: &str,
+        descs: &'static [BreakDesc],
+        breaks: &[(u32, u32, &'static BreakDesc)],
+    ) -> u64 {
+        self.write_ln("#[rustfmt::skip]");
+        self.write("static ");
+        self.write(table_name);
+        self.write(": &[(char, char, ");
+        self.write(type_name);
+        self.write_ln(")] = &[");
+        self.write("    ");
+
+        let mut checksum = CHECKSUM_OFFSET;
+
+        for (from, to, desc) in breaks {
+            self.write_range_separator();
+
+            self.write("(");
+            self.write_char_literal(*from);
+            self.write(", ");
+            self.write_char_literal(*to);
+            self.write(", ");
+            self.write(type_name);
+            self.write("::");
+            self.write(desc.variant_name);
+            self.write("),");
+
+            let variant = descs
+                .iter()
+                .position(|candidate| candidate == *desc)
+                .expect("Unknown break property value.")
+                + 1;
+
+            checksum = Self::update_checksum(checksum, *from as u64);
+            checksum = Self::update_checksum(checksum, *to as u64);
+            checksum = Self::update_checksum(checksum, variant as u64);
+        }
+
+        self.blank_ln();
+        self.write_ln("];");
+        self.blank_ln();
+
+        checksum
     }
 
-    fn emit_tests(&mut self) {
+    fn emit_script_table(&mut self, scripts: &RawScripts) -> u64 {
+        self.write_ln("#[rustfmt::skip]");
+        self.write_ln("static SCRIPT_TABLE: &[(char, char, Script)] = &[");
+        self.write("    ");
+
+        let mut checksum = CHECKSUM_OFFSET;
+
+        for (from, to, script) in &scripts.scripts {
+            self.write_range_separator();
+            self.write("(");
+            self.write_char_literal(*from);
+            self.write(", ");
+            self.write_char_literal(*to);
+            self.write(", Script::");
+            self.write(&Self::script_variant(&scripts.names[*script].0));
+            self.write("),");
+
+            checksum = Self::update_checksum(checksum, *from as u64);
+            checksum = Self::update_checksum(checksum, *to as u64);
+            checksum = Self::update_checksum(checksum, *script as u64);
+        }
+
+        self.blank_ln();
+        self.write_ln("];");
+        self.blank_ln();
+
+        checksum
+    }
+
+    fn emit_script_extensions_table(&mut self, scripts: &RawScripts) -> u64 {
+        self.write_ln("#[rustfmt::skip]");
+        self.write_ln("static SCRIPT_EXTENSIONS_TABLE: &[(char, char, &[Script])] = &[");
+        self.write("    ");
+
+        let mut checksum = CHECKSUM_OFFSET;
+
+        for (from, to, extension) in &scripts.extensions {
+            self.write_range_separator();
+            self.write("(");
+            self.write_char_literal(*from);
+            self.write(", ");
+            self.write_char_literal(*to);
+            self.write(", &[");
+
+            checksum = Self::update_checksum(checksum, *from as u64);
+            checksum = Self::update_checksum(checksum, *to as u64);
+            checksum = Self::update_checksum(checksum, extension.len() as u64);
+
+            let mut first = true;
+
+            for script in extension {
+                match first {
+                    true => first = false,
+                    false => self.write(", "),
+                }
+
+                self.write("Script::");
+                self.write(&Self::script_variant(&scripts.names[*script].0));
+
+                checksum = Self::update_checksum(checksum, *script as u64);
+            }
+
+            self.write("]),");
+        }
+
+        self.blank_ln();
+        self.write_ln("];");
+        self.blank_ln();
+
+        checksum
+    }
+
+    fn emit_version(&mut self, version: (u8, u8, u8), checksums: &[(&'static str, u64)]) {
+        let (major, minor, update) = version;
+
+        self.write_ln(
+            "/// The version of the [Unicode Character Database](https://www.unicode.org/ucd/)",
+        );
+        self.write_ln("/// that the character tables of this module have been generated from.");
+        self.write_ln("///");
+        self.write_ln("/// The value is a triple of the major, minor, and update version numbers.");
+        self.write_ln(&format!(
+            "pub const UNICODE_VERSION: (u8, u8, u8) = ({major}, {minor}, {update});"
+        ));
+        self.blank_ln();
+        self.write_ln("/// The checksums of the character tables of this module.");
+        self.write_ln("///");
+        self.write_ln("/// Each entry is a pair of the table name and the checksum of the table's");
+        self.write_ln(
+            "/// data. A checksum changes whenever the Unicode Character Database update",
+        );
+        self.write_ln("/// changes the content of the corresponding table, even if");
+        self.write_ln("/// the [UNICODE_VERSION] stays the same.");
+        self.write_ln("pub const UCD_CHECKSUMS: &[(&str, u64)] = &[");
+
+        for (table_name, checksum) in checksums {
+            self.write_ln(&format!("    (\"{table_name}\", 0x{checksum:016X}),"));
+        }
+
+        self.write_ln("];");
+        self.blank_ln();
+        self.write_ln("/// Returns the version of the Unicode Character Database that");
+        self.write_ln("/// the [Char] functions are based on.");
+        self.write_ln("///");
+        self.write_ln("/// This function returns the [UNICODE_VERSION] value.");
+        self.write_ln("#[inline(always)]");
+        self.write_ln("pub const fn ucd_version() -> (u8, u8, u8) {");
+        self.write_ln("    UNICODE_VERSION");
+        self.write_ln("}");
+        self.blank_ln();
+    }
+
+    fn emit_tests(
+        &mut self,
+        input: &[(&'static PropDesc, AHashSet<u32>)],
+        cases: &[(&'static CaseDesc, RawCaseMapping)],
+    ) {
         self.write_ln("#[cfg(test)]");
         self.write_ln("mod tests {");
-        self.write_ln("    use super::Char;");
+        self.write_ln("    use super::*;");
         self.blank_ln();
         self.write_ln("    #[test]");
         self.write_ln("    fn test_char_properties() {");
@@ -576,8 +1523,279 @@ impl Emitter {
         self.write_ln("            }");
         self.write_ln("        }");
         self.write_ln("    }");
-        self.write_ln("}");
         self.blank_ln();
+        self.write_ln("    #[test]");
+        self.write_ln("    fn test_case_mappings() {");
+        self.write_ln("        assert_eq!('a'.to_upper_simple(), 'A');");
+        self.write_ln("        assert_eq!('A'.to_lower_simple(), 'a');");
+        self.write_ln("        assert_eq!('1'.to_upper_simple(), '1');");
+        self.write_ln("        assert_eq!('\\u{1C6}'.to_title_simple(), '\\u{1C5}');");
+        self.write_ln("        assert_eq!('\\u{1C6}'.to_upper_simple(), '\\u{1C4}');");
+        self.write_ln("        assert_eq!('\\u{DF}'.to_upper_simple(), '\\u{DF}');");
+        self.write_ln("        assert_eq!('\\u{DF}'.to_upper_full().to_string(), \"SS\");");
+        self.write_ln("        assert_eq!('\\u{DF}'.to_title_full().to_string(), \"Ss\");");
+        self.write_ln("        assert_eq!('\\u{DF}'.to_lower_full().to_string(), \"\\u{DF}\");");
+        self.write_ln("        assert_eq!('\\u{130}'.to_lower_simple(), 'i');");
+        self.write_ln("        assert_eq!('\\u{130}'.to_lower_full().to_string(), \"i\\u{307}\");");
+        self.write_ln("        assert_eq!('\\u{1F80}'.to_upper_full().len(), 2);");
+        self.write_ln(
+            "        assert_eq!('\\0'.to_lower_full().collect::<Vec<_>>(), vec!['\\0']);",
+        );
+        self.blank_ln();
+        self.write_ln("        for ch in '\\0'..'\\u{10ffff}' {");
+        self.write_ln("            let mut full = ch.to_upper_full();");
+        self.blank_ln();
+        self.write_ln("            if full.len() == 1 {");
+        self.write_ln("                assert_eq!(full.next(), Some(ch.to_upper_simple()));");
+        self.write_ln("            }");
+        self.blank_ln();
+        self.write_ln("            let mut full = ch.to_lower_full();");
+        self.blank_ln();
+        self.write_ln("            if full.len() == 1 {");
+        self.write_ln("                assert_eq!(full.next(), Some(ch.to_lower_simple()));");
+        self.write_ln("            }");
+        self.write_ln("        }");
+        self.write_ln("    }");
+        self.blank_ln();
+        self.write_ln("    #[test]");
+        self.write_ln("    fn test_grapheme_cluster_break() {");
+        self.write_ln(
+            "        assert_eq!('a'.grapheme_cluster_break(), GraphemeClusterBreak::Other);",
+        );
+        self.write_ln(
+            "        assert_eq!('\\r'.grapheme_cluster_break(), GraphemeClusterBreak::CR);",
+        );
+        self.write_ln(
+            "        assert_eq!('\\n'.grapheme_cluster_break(), GraphemeClusterBreak::LF);",
+        );
+        self.write_ln(
+            "        assert_eq!('\\0'.grapheme_cluster_break(), GraphemeClusterBreak::Control);",
+        );
+        self.write_ln("        assert_eq!(");
+        self.write_ln("            '\\u{301}'.grapheme_cluster_break(),");
+        self.write_ln("            GraphemeClusterBreak::Extend,");
+        self.write_ln("        );");
+        self.write_ln("        assert_eq!(");
+        self.write_ln("            '\\u{200D}'.grapheme_cluster_break(),");
+        self.write_ln("            GraphemeClusterBreak::ZWJ,");
+        self.write_ln("        );");
+        self.write_ln(
+            "        assert_eq!('\\u{1100}'.grapheme_cluster_break(), GraphemeClusterBreak::L);",
+        );
+        self.write_ln("        assert_eq!(");
+        self.write_ln("            '\\u{AC00}'.grapheme_cluster_break(),");
+        self.write_ln("            GraphemeClusterBreak::LV,");
+        self.write_ln("        );");
+        self.write_ln("        assert_eq!(");
+        self.write_ln("            '\\u{AC01}'.grapheme_cluster_break(),");
+        self.write_ln("            GraphemeClusterBreak::LVT,");
+        self.write_ln("        );");
+        self.write_ln("        assert_eq!(");
+        self.write_ln("            '\\u{1F1E6}'.grapheme_cluster_break(),");
+        self.write_ln("            GraphemeClusterBreak::RegionalIndicator,");
+        self.write_ln("        );");
+        self.write_ln("        assert!('\\u{1F600}'.is_ext_pict());");
+        self.write_ln("        assert!(!'a'.is_ext_pict());");
+        self.write_ln("    }");
+        self.blank_ln();
+        self.write_ln("    #[test]");
+        self.write_ln("    fn test_indic_conjunct_break() {");
+        self.write_ln("        assert_eq!('a'.indic_conjunct_break(), IndicConjunctBreak::None);");
+        self.write_ln("        assert_eq!(");
+        self.write_ln("            '\\u{915}'.indic_conjunct_break(),");
+        self.write_ln("            IndicConjunctBreak::Consonant,");
+        self.write_ln("        );");
+        self.write_ln(
+            "        assert_eq!('\\u{94D}'.indic_conjunct_break(), IndicConjunctBreak::Linker);",
+        );
+        self.write_ln(
+            "        assert_eq!('\\u{93C}'.indic_conjunct_break(), IndicConjunctBreak::Extend);",
+        );
+        self.write_ln("        assert_eq!(");
+        self.write_ln("            '\\u{200D}'.indic_conjunct_break(),");
+        self.write_ln("            IndicConjunctBreak::Extend,");
+        self.write_ln("        );");
+        self.write_ln("    }");
+        self.blank_ln();
+        self.write_ln("    #[test]");
+        self.write_ln("    fn test_scripts() {");
+        self.write_ln("        assert_eq!('a'.script(), Script::Latin);");
+        self.write_ln("        assert_eq!('\\u{430}'.script(), Script::Cyrillic);");
+        self.write_ln("        assert_eq!('1'.script(), Script::Common);");
+        self.write_ln("        assert_eq!('\\u{301}'.script(), Script::Inherited);");
+        self.write_ln("        assert_eq!('\\u{10FFFF}'.script(), Script::Unknown);");
+        self.write_ln("        assert_eq!('a'.script_extensions(), &[Script::Latin]);");
+        self.write_ln("        assert_eq!('\\u{640}'.script(), Script::Common);");
+        self.write_ln("        assert!('\\u{640}'.has_script(Script::Arabic));");
+        self.write_ln("        assert!('\\u{640}'.has_script(Script::Syriac));");
+        self.write_ln("        assert!(!'\\u{640}'.has_script(Script::Latin));");
+        self.blank_ln();
+        self.write_ln("        assert_eq!(Script::OldItalic.name(), \"Old_Italic\");");
+        self.write_ln("        assert_eq!(Script::OldItalic.code(), \"Ital\");");
+        self.write_ln(
+            "        assert_eq!(Script::from_name(\"Old_Italic\"), Some(Script::OldItalic));",
+        );
+        self.write_ln(
+            "        assert_eq!(Script::from_name(\"OldItalic\"), Some(Script::OldItalic));",
+        );
+        self.write_ln("        assert_eq!(Script::from_name(\"Ital\"), Some(Script::OldItalic));");
+        self.write_ln("        assert_eq!(Script::from_name(\"Foo\"), None);");
+        self.blank_ln();
+        self.write_ln("        for (index, script) in SCRIPTS.iter().enumerate() {");
+        self.write_ln("            assert_eq!(*script as usize, index);");
+        self.write_ln("        }");
+        self.blank_ln();
+        self.write_ln("        let props = CharProperties::new().with_script(Script::Cyrillic);");
+        self.blank_ln();
+        self.write_ln("        assert!('\\u{430}'.has_properties(&props));");
+        self.write_ln("        assert!(!'a'.has_properties(&props));");
+        self.write_ln("        assert_eq!(props.to_string(), \"$script(Cyrillic)\");");
+        self.write_ln("    }");
+        self.blank_ln();
+        self.write_ln("    #[test]");
+        self.write_ln("    fn test_ucd_checksums() {");
+        self.write_ln("        let tables = [");
+
+        for (prop, _) in input {
+            self.write("            (\"");
+            self.write(prop.table_name);
+            self.write("\", trie_checksum(&");
+            self.write(prop.table_name);
+            self.write_ln(")),");
+        }
+
+        for (desc, _) in cases {
+            self.write("            (\"");
+            self.write(desc.table_name);
+            self.write("\", case_table_checksum(&");
+            self.write(desc.table_name);
+            self.write_ln(")),");
+        }
+
+        self.write_ln("            (");
+        self.write_ln("                \"GRAPHEME_CLUSTER_BREAK_TABLE\",");
+        self.write_ln("                grapheme_cluster_break_checksum(),");
+        self.write_ln("            ),");
+        self.write_ln("            (");
+        self.write_ln("                \"INDIC_CONJUNCT_BREAK_TABLE\",");
+        self.write_ln("                indic_conjunct_break_checksum(),");
+        self.write_ln("            ),");
+        self.write_ln("            (\"SCRIPT_TABLE\", script_checksum()),");
+        self.write_ln("            (\"SCRIPT_EXTENSIONS_TABLE\", script_extensions_checksum()),");
+        self.write_ln("        ];");
+        self.blank_ln();
+        self.write_ln("        assert_eq!(ucd_version(), UNICODE_VERSION);");
+        self.write_ln("        assert_eq!(UCD_CHECKSUMS, tables);");
+        self.write_ln("    }");
+        self.blank_ln();
+        self.write_ln("    fn trie_checksum(trie: &UCDTrie) -> u64 {");
+        self.write_ln("        let mut checksum = CHECKSUM_OFFSET;");
+        self.blank_ln();
+
+        for (field, is_leaf) in [
+            ("r1", true),
+            ("r2", false),
+            ("r3", true),
+            ("r4", false),
+            ("r5", false),
+            ("r6", true),
+        ] {
+            self.write("        for chunk in trie.");
+            self.write(field);
+            self.write_ln(".iter() {");
+
+            match is_leaf {
+                true => self.write_ln("            checksum = update_checksum(checksum, *chunk);"),
+                false => self
+                    .write_ln("            checksum = update_checksum(checksum, *chunk as u64);"),
+            }
+
+            self.write_ln("        }");
+            self.blank_ln();
+        }
+
+        self.write_ln("        checksum");
+        self.write_ln("    }");
+        self.blank_ln();
+        self.write_ln("    fn case_table_checksum(table: &UCDCaseTable) -> u64 {");
+        self.write_ln("        let mut checksum = CHECKSUM_OFFSET;");
+        self.blank_ln();
+        self.write_ln("        for (from, to) in table.simple {");
+        self.write_ln("            checksum = update_checksum(checksum, *from as u64);");
+        self.write_ln("            checksum = update_checksum(checksum, *to as u64);");
+        self.write_ln("        }");
+        self.blank_ln();
+        self.write_ln("        for (from, to) in table.full {");
+        self.write_ln("            checksum = update_checksum(checksum, *from as u64);");
+        self.blank_ln();
+        self.write_ln("            for ch in to {");
+        self.write_ln("                checksum = update_checksum(checksum, *ch as u64);");
+        self.write_ln("            }");
+        self.write_ln("        }");
+        self.blank_ln();
+        self.write_ln("        checksum");
+        self.write_ln("    }");
+        self.blank_ln();
+        self.write_ln("    fn grapheme_cluster_break_checksum() -> u64 {");
+        self.write_ln("        let mut checksum = CHECKSUM_OFFSET;");
+        self.blank_ln();
+        self.write_ln("        for (from, to, value) in GRAPHEME_CLUSTER_BREAK_TABLE {");
+        self.write_ln("            checksum = update_checksum(checksum, *from as u64);");
+        self.write_ln("            checksum = update_checksum(checksum, *to as u64);");
+        self.write_ln("            checksum = update_checksum(checksum, *value as u64);");
+        self.write_ln("        }");
+        self.blank_ln();
+        self.write_ln("        checksum");
+        self.write_ln("    }");
+        self.blank_ln();
+        self.write_ln("    fn indic_conjunct_break_checksum() -> u64 {");
+        self.write_ln("        let mut checksum = CHECKSUM_OFFSET;");
+        self.blank_ln();
+        self.write_ln("        for (from, to, value) in INDIC_CONJUNCT_BREAK_TABLE {");
+        self.write_ln("            checksum = update_checksum(checksum, *from as u64);");
+        self.write_ln("            checksum = update_checksum(checksum, *to as u64);");
+        self.write_ln("            checksum = update_checksum(checksum, *value as u64);");
+        self.write_ln("        }");
+        self.blank_ln();
+        self.write_ln("        checksum");
+        self.write_ln("    }");
+        self.blank_ln();
+        self.write_ln("    fn script_checksum() -> u64 {");
+        self.write_ln("        let mut checksum = CHECKSUM_OFFSET;");
+        self.blank_ln();
+        self.write_ln("        for (from, to, script) in SCRIPT_TABLE {");
+        self.write_ln("            checksum = update_checksum(checksum, *from as u64);");
+        self.write_ln("            checksum = update_checksum(checksum, *to as u64);");
+        self.write_ln("            checksum = update_checksum(checksum, *script as u64);");
+        self.write_ln("        }");
+        self.blank_ln();
+        self.write_ln("        checksum");
+        self.write_ln("    }");
+        self.blank_ln();
+        self.write_ln("    fn script_extensions_checksum() -> u64 {");
+        self.write_ln("        let mut checksum = CHECKSUM_OFFSET;");
+        self.blank_ln();
+        self.write_ln("        for (from, to, scripts) in SCRIPT_EXTENSIONS_TABLE {");
+        self.write_ln("            checksum = update_checksum(checksum, *from as u64);");
+        self.write_ln("            checksum = update_checksum(checksum, *to as u64);");
+        self.write_ln("            checksum = update_checksum(checksum, scripts.len() as u64);");
+        self.blank_ln();
+        self.write_ln("            for script in *scripts {");
+        self.write_ln("                checksum = update_checksum(checksum, *script as u64);");
+        self.write_ln("            }");
+        self.write_ln("        }");
+        self.blank_ln();
+        self.write_ln("        checksum");
+        self.write_ln("    }");
+        self.blank_ln();
+        self.write_ln("    const CHECKSUM_OFFSET: u64 = 0xCBF29CE484222325;");
+        self.write_ln("    const CHECKSUM_PRIME: u64 = 0x100000001B3;");
+        self.blank_ln();
+        self.write_ln("    #[inline(always)]");
+        self.write_ln("    fn update_checksum(checksum: u64, word: u64) -> u64 {");
+        self.write_ln("        (checksum ^ word).wrapping_mul(CHECKSUM_PRIME)");
+        self.write_ln("    }");
+        self.write_ln("}");
     }
 
     fn write_chunk_array(&mut self, chunks: &[u64], is_leaf: bool) {
@@ -616,6 +1834,49 @@ impl Emitter {
         self.write("    ]");
     }
 
+    #[inline(always)]
+    fn update_checksum(checksum: u64, word: u64) -> u64 {
+        (checksum ^ word).wrapping_mul(CHECKSUM_PRIME)
+    }
+
+    fn write_range_separator(&mut self) {
+        match self.line_length >= 80 {
+            true => {
+                self.blank_ln();
+                self.write("    ");
+            }
+
+            false => {
+                if self.line_length > 4 {
+                    self.write(" ");
+                }
+            }
+        }
+    }
+
+    fn script_variant(long: &str) -> String {
+        long.replace('_', "")
+    }
+
+    fn write_separator(&mut self) {
+        match self.line_length >= 80 {
+            true => {
+                self.blank_ln();
+                self.write("        ");
+            }
+
+            false => {
+                if self.line_length > 8 {
+                    self.write(" ");
+                }
+            }
+        }
+    }
+
+    fn write_char_literal(&mut self, code_point: u32) {
+        self.write(&format!("'\\u{{{code_point:04X}}}'"));
+    }
+
     fn write(&mut self, string: &str) {
         self.output.push_str(&string);
         self.line_length += string.len();
@@ -43,17 +43,29 @@ use std::{env::args, process::exit};
 
 static UCD_DOWNLOADS_DIR: &str = "downloads";
 
-static UCD_URL: &str = "https://www.unicode.org/Public/UCD/latest/ucd/";
+// The UCD release the committed tables are generated from. To move
+// the tables to another Unicode version, change this value and regenerate
+// the tables.
+static UCD_VERSION: (u8, u8, u8) = (17, 0, 0);
+
+static UCD_URL: &str = "https://www.unicode.org/Public/";
 
 static UCD_RESOURCES: &[&str] = &[
     "DerivedCoreProperties.txt",
     "PropList.txt",
     "UnicodeData.txt",
     "SpecialCasing.txt",
+    "auxiliary/GraphemeBreakProperty.txt",
+    "emoji/emoji-data.txt",
+    "PropertyValueAliases.txt",
+    "Scripts.txt",
+    "ScriptExtensions.txt",
 ];
 
 static GENERATED_FILE: &str = "ucd_gen.txt";
 
+static COMMITTED_FILE: &str = "../main/src/lexis/ucd.rs";
+
 static RAW_PROPERTIES: &[PropDesc] = &[
     PropDesc {
         raw_names: &["Alphabetic"],
@@ -90,6 +102,11 @@ static RAW_PROPERTIES: &[PropDesc] = &[
         table_name: "XID_CONTINUE_TABLE",
         field_name: "xid_continue",
     },
+    PropDesc {
+        raw_names: &["Extended_Pictographic"],
+        table_name: "EXT_PICT_TABLE",
+        field_name: "ext_pict",
+    },
 ];
 
 #[derive(PartialEq, Eq, Hash)]
@@ -99,23 +116,147 @@ struct PropDesc {
     field_name: &'static str,
 }
 
+static CASE_MAPPINGS: &[CaseDesc] = &[
+    CaseDesc {
+        kind: CaseKind::Lower,
+        simple_name: "Simple_Lowercase_Mapping",
+        full_name: "Lowercase_Mapping",
+        table_name: "LOWER_CASE_TABLE",
+        fn_name: "lower",
+        word: "lowercase",
+    },
+    CaseDesc {
+        kind: CaseKind::Title,
+        simple_name: "Simple_Titlecase_Mapping",
+        full_name: "Titlecase_Mapping",
+        table_name: "TITLE_CASE_TABLE",
+        fn_name: "title",
+        word: "titlecase",
+    },
+    CaseDesc {
+        kind: CaseKind::Upper,
+        simple_name: "Simple_Uppercase_Mapping",
+        full_name: "Uppercase_Mapping",
+        table_name: "UPPER_CASE_TABLE",
+        fn_name: "upper",
+        word: "uppercase",
+    },
+];
+
+#[derive(PartialEq, Eq, Hash)]
+struct CaseDesc {
+    kind: CaseKind,
+    simple_name: &'static str,
+    full_name: &'static str,
+    table_name: &'static str,
+    fn_name: &'static str,
+    word: &'static str,
+}
+
+static GRAPHEME_CLUSTER_BREAKS: &[BreakDesc] = &[
+    BreakDesc {
+        raw_name: "CR",
+        variant_name: "CR",
+    },
+    BreakDesc {
+        raw_name: "LF",
+        variant_name: "LF",
+    },
+    BreakDesc {
+        raw_name: "Control",
+        variant_name: "Control",
+    },
+    BreakDesc {
+        raw_name: "Extend",
+        variant_name: "Extend",
+    },
+    BreakDesc {
+        raw_name: "ZWJ",
+        variant_name: "ZWJ",
+    },
+    BreakDesc {
+        raw_name: "Regional_Indicator",
+        variant_name: "RegionalIndicator",
+    },
+    BreakDesc {
+        raw_name: "Prepend",
+        variant_name: "Prepend",
+    },
+    BreakDesc {
+        raw_name: "SpacingMark",
+        variant_name: "SpacingMark",
+    },
+    BreakDesc {
+        raw_name: "L",
+        variant_name: "L",
+    },
+    BreakDesc {
+        raw_name: "V",
+        variant_name: "V",
+    },
+    BreakDesc {
+        raw_name: "T",
+        variant_name: "T",
+    },
+    BreakDesc {
+        raw_name: "LV",
+        variant_name: "LV",
+    },
+    BreakDesc {
+        raw_name: "LVT",
+        variant_name: "LVT",
+    },
+];
+
+static INDIC_CONJUNCT_BREAKS: &[BreakDesc] = &[
+    BreakDesc {
+        raw_name: "Consonant",
+        variant_name: "Consonant",
+    },
+    BreakDesc {
+        raw_name: "Extend",
+        variant_name: "Extend",
+    },
+    BreakDesc {
+        raw_name: "Linker",
+        variant_name: "Linker",
+    },
+];
+
+#[derive(PartialEq, Eq, Hash)]
+struct BreakDesc {
+    raw_name: &'static str,
+    variant_name: &'static str,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum CaseKind {
+    Lower,
+    Title,
+    Upper,
+}
+
 fn main() {
     let mut arg = match args().skip(1).next() {
         Some(arg) => arg,
 
         None => {
-            eprintln!("Missing command. Available commands are: \"download\", \"generate\"");
+            eprintln!(
+                "Missing command. Available commands are: \"download\", \"generate\", \"check\"",
+            );
             exit(1);
         }
     };
 
     match arg.as_str() {
         "download" => download::download(),
-        "generate" => generate::generate(),
+        "generate" => generate::generate(args().skip(2).any(|arg| arg == "--force")),
+        "check" => generate::check(),
 
         other => {
             eprintln!(
-                "Unknown command {other}. Available commands are: \"download\", \"generate\"",
+                "Unknown command {other}. Available commands are: \"download\", \"generate\", \
+                \"check\"",
             );
             exit(1);
         }
@@ -32,12 +32,103 @@
 // All rights reserved.                                                       //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::process::exit;
+use std::{fs::read_to_string, path::Path, process::exit};
 
 use ahash::{AHashMap, AHashSet};
-use ucd_parse::{parse, UnicodeDataExpander};
+use ucd_parse::{parse, Codepoints, UnicodeDataExpander};
 
-use crate::{PropDesc, RAW_PROPERTIES, UCD_DOWNLOADS_DIR};
+use crate::{
+    BreakDesc,
+    CaseDesc,
+    CaseKind,
+    PropDesc,
+    CASE_MAPPINGS,
+    GRAPHEME_CLUSTER_BREAKS,
+    INDIC_CONJUNCT_BREAKS,
+    RAW_PROPERTIES,
+    UCD_DOWNLOADS_DIR,
+    UCD_RESOURCES,
+    UCD_VERSION,
+};
+
+pub(super) fn parse_version() -> (u8, u8, u8) {
+    let mut result = None;
+
+    for resource in UCD_RESOURCES {
+        let path = Path::new(UCD_DOWNLOADS_DIR).join(resource);
+
+        let content = match read_to_string(&path) {
+            Ok(content) => content,
+
+            Err(error) => {
+                eprintln!("Failed to read {path:?}: {error}");
+                exit(1);
+            }
+        };
+
+        // Versioned files start with a "# <Name>-<Major>.<Minor>.<Update>.txt"
+        // header line.
+        let Some(header) = content.lines().next() else {
+            continue;
+        };
+
+        let Some(version) = header
+            .strip_prefix("# ")
+            .and_then(|header| header.strip_suffix(".txt"))
+            .and_then(|header| header.rsplit_once('-'))
+            .map(|(_, version)| version)
+        else {
+            continue;
+        };
+
+        let mut components = version
+            .split('.')
+            .map(|component| component.parse::<u8>().ok());
+
+        let (Some(Some(major)), Some(Some(minor)), Some(Some(update)), None) = (
+            components.next(),
+            components.next(),
+            components.next(),
+            components.next(),
+        ) else {
+            continue;
+        };
+
+        let version = (major, minor, update);
+
+        match result {
+            None => result = Some(version),
+
+            Some(previous) if previous != version => {
+                eprintln!("Inconsistent Unicode versions of the downloaded UCD files.");
+                exit(1);
+            }
+
+            _ => (),
+        }
+    }
+
+    let Some(version) = result else {
+        eprintln!("Failed to determine the Unicode version of the downloaded UCD files.");
+        exit(1);
+    };
+
+    let (major, minor, update) = version;
+
+    println!("Unicode version {major}.{minor}.{update} detected.");
+
+    if version != UCD_VERSION {
+        let (major, minor, update) = UCD_VERSION;
+
+        eprintln!(
+            "The downloaded UCD files do not match the pinned Unicode version \
+            {major}.{minor}.{update}.",
+        );
+        exit(1);
+    }
+
+    version
+}
 
 pub(super) fn parse_raw_data() -> Vec<(&'static PropDesc, AHashSet<u32>)> {
     println!("Parsing raw UCD data...");
@@ -96,6 +187,32 @@ pub(super) fn parse_raw_data() -> Vec<(&'static PropDesc, AHashSet<u32>)> {
         }
     }
 
+    let raw_emoji_properties = match parse::<_, ucd_parse::EmojiProperty>(&UCD_DOWNLOADS_DIR) {
+        Ok(props) => {
+            println!("Raw Emoji Properties parsed.");
+            props
+        }
+        Err(error) => {
+            eprintln!("Emoji Properties parse error: {error}");
+            exit(1);
+        }
+    };
+
+    for raw in raw_emoji_properties {
+        let Some(desc) = RAW_PROPERTIES
+            .iter()
+            .find(|desc| desc.raw_names.contains(&raw.property.as_str()))
+        else {
+            continue;
+        };
+
+        let code_points = data.entry(desc).or_insert_with(AHashSet::new);
+
+        for code_point in raw.codepoints {
+            let _ = code_points.insert(code_point.value());
+        }
+    }
+
     let raw_unicode_data = match parse::<_, ucd_parse::UnicodeData>(&UCD_DOWNLOADS_DIR) {
         Ok(props) => {
             println!("Unicode Data parsed.");
@@ -128,3 +245,409 @@ pub(super) fn parse_raw_data() -> Vec<(&'static PropDesc, AHashSet<u32>)> {
 
     sorted
 }
+
+pub(super) struct RawCaseMapping {
+    pub(super) simple: Vec<(u32, u32)>,
+    pub(super) full: Vec<(u32, Vec<u32>)>,
+}
+
+pub(super) fn parse_case_mappings() -> Vec<(&'static CaseDesc, RawCaseMapping)> {
+    println!("Parsing raw UCD case mappings...");
+
+    let raw_unicode_data = match parse::<_, ucd_parse::UnicodeData>(&UCD_DOWNLOADS_DIR) {
+        Ok(props) => {
+            println!("Unicode Data parsed.");
+            props
+        }
+        Err(error) => {
+            eprintln!("Unicode Data parse error: {error}");
+            exit(1);
+        }
+    };
+
+    let raw_special_casing = match parse::<_, ucd_parse::SpecialCaseMapping>(&UCD_DOWNLOADS_DIR) {
+        Ok(props) => {
+            println!("Special Casing parsed.");
+            props
+        }
+        Err(error) => {
+            eprintln!("Special Casing parse error: {error}");
+            exit(1);
+        }
+    };
+
+    let mut result = Vec::with_capacity(CASE_MAPPINGS.len());
+
+    for desc in CASE_MAPPINGS {
+        let mut simple = AHashMap::new();
+
+        for raw in UnicodeDataExpander::new(raw_unicode_data.iter().cloned()) {
+            let mapping = match desc.kind {
+                CaseKind::Lower => raw.simple_lowercase_mapping,
+
+                // An empty Simple_Titlecase_Mapping field defaults to
+                // the Simple_Uppercase_Mapping value.
+                CaseKind::Title => raw
+                    .simple_titlecase_mapping
+                    .or(raw.simple_uppercase_mapping),
+
+                CaseKind::Upper => raw.simple_uppercase_mapping,
+            };
+
+            let Some(mapping) = mapping else {
+                continue;
+            };
+
+            if mapping == raw.codepoint {
+                continue;
+            }
+
+            let _ = simple.insert(raw.codepoint.value(), mapping.value());
+        }
+
+        let mut full = Vec::new();
+
+        for raw in &raw_special_casing {
+            // Language-sensitive and context-sensitive mappings
+            // are not supported.
+            if !raw.conditions.is_empty() {
+                continue;
+            }
+
+            let mapping = match desc.kind {
+                CaseKind::Lower => &raw.lowercase,
+                CaseKind::Title => &raw.titlecase,
+                CaseKind::Upper => &raw.uppercase,
+            };
+
+            let code_point = raw.codepoint.value();
+
+            let single = simple.get(&code_point).copied().unwrap_or(code_point);
+
+            if mapping.len() == 1 && mapping[0].value() == single {
+                continue;
+            }
+
+            assert!(
+                !mapping.is_empty() && mapping.len() <= 3,
+                "Unsupported {} length.",
+                desc.full_name,
+            );
+
+            full.push((
+                code_point,
+                mapping
+                    .iter()
+                    .map(|code_point| code_point.value())
+                    .collect(),
+            ));
+        }
+
+        let mut simple = simple.into_iter().collect::<Vec<_>>();
+
+        simple.sort();
+        full.sort();
+
+        println!("{} and {} collected.", desc.simple_name, desc.full_name);
+
+        result.push((desc, RawCaseMapping { simple, full }));
+    }
+
+    println!("Raw UCD case mappings parsing finished.");
+
+    result
+}
+
+pub(super) fn parse_grapheme_cluster_breaks() -> Vec<(u32, u32, &'static BreakDesc)> {
+    println!("Parsing raw Grapheme Cluster Break data...");
+
+    let raw_breaks = match parse::<_, ucd_parse::GraphemeClusterBreak>(&UCD_DOWNLOADS_DIR) {
+        Ok(props) => {
+            println!("Grapheme Cluster Break parsed.");
+            props
+        }
+        Err(error) => {
+            eprintln!("Grapheme Cluster Break parse error: {error}");
+            exit(1);
+        }
+    };
+
+    let mut code_points = Vec::new();
+
+    for raw in raw_breaks {
+        let Some(desc) = GRAPHEME_CLUSTER_BREAKS
+            .iter()
+            .find(|desc| desc.raw_name == raw.value.as_str())
+        else {
+            eprintln!("Unknown Grapheme Cluster Break value {:?}.", raw.value);
+            exit(1);
+        };
+
+        for code_point in raw.codepoints {
+            code_points.push((code_point.value(), desc));
+        }
+    }
+
+    let ranges = into_break_ranges(code_points);
+
+    println!("Raw Grapheme Cluster Break data parsing finished.");
+
+    ranges
+}
+
+pub(super) fn parse_indic_conjunct_breaks() -> Vec<(u32, u32, &'static BreakDesc)> {
+    println!("Parsing raw Indic Conjunct Break data...");
+
+    let path = Path::new(UCD_DOWNLOADS_DIR).join("DerivedCoreProperties.txt");
+
+    let content = match read_to_string(&path) {
+        Ok(content) => content,
+
+        Err(error) => {
+            eprintln!("Failed to read {path:?}: {error}");
+            exit(1);
+        }
+    };
+
+    let mut code_points = Vec::new();
+
+    // The InCB property is the only enumerated property of this file. Its
+    // lines have an extra value field ("0915..0939 ; InCB; Consonant # ..."),
+    // which the CoreProperty parser does not preserve.
+    for line in content.lines() {
+        let line = match line.split_once('#') {
+            Some((data, _)) => data,
+            None => line,
+        };
+
+        let mut fields = line.split(';').map(str::trim);
+
+        let (Some(raw_code_points), Some("InCB"), Some(value), None) =
+            (fields.next(), fields.next(), fields.next(), fields.next())
+        else {
+            continue;
+        };
+
+        let Some(desc) = INDIC_CONJUNCT_BREAKS
+            .iter()
+            .find(|desc| desc.raw_name == value)
+        else {
+            eprintln!("Unknown Indic Conjunct Break value {value:?}.");
+            exit(1);
+        };
+
+        let raw_code_points = match raw_code_points.parse::<Codepoints>() {
+            Ok(code_points) => code_points,
+
+            Err(error) => {
+                eprintln!("Indic Conjunct Break parse error: {error}");
+                exit(1);
+            }
+        };
+
+        for code_point in raw_code_points {
+            code_points.push((code_point.value(), desc));
+        }
+    }
+
+    if code_points.is_empty() {
+        eprintln!("Missing Indic Conjunct Break data.");
+        exit(1);
+    }
+
+    let ranges = into_break_ranges(code_points);
+
+    println!("Raw Indic Conjunct Break data parsing finished.");
+
+    ranges
+}
+
+fn into_break_ranges(
+    mut code_points: Vec<(u32, &'static BreakDesc)>,
+) -> Vec<(u32, u32, &'static BreakDesc)> {
+    code_points.sort_by_key(|(code_point, _)| *code_point);
+
+    let mut ranges = Vec::<(u32, u32, &'static BreakDesc)>::new();
+
+    for (code_point, desc) in code_points {
+        if let Some((_, last, last_desc)) = ranges.last_mut() {
+            if *last + 1 == code_point && *last_desc == desc {
+                *last = code_point;
+                continue;
+            }
+        }
+
+        ranges.push((code_point, code_point, desc));
+    }
+
+    ranges
+}
+
+pub(super) struct RawScripts {
+    pub(super) names: Vec<(String, String)>,
+    pub(super) scripts: Vec<(u32, u32, usize)>,
+    pub(super) extensions: Vec<(u32, u32, Vec<usize>)>,
+}
+
+pub(super) fn parse_scripts() -> RawScripts {
+    println!("Parsing raw Script data...");
+
+    let raw_aliases = match parse::<_, ucd_parse::PropertyValueAlias>(&UCD_DOWNLOADS_DIR) {
+        Ok(props) => {
+            println!("Property Value Aliases parsed.");
+            props
+        }
+        Err(error) => {
+            eprintln!("Property Value Aliases parse error: {error}");
+            exit(1);
+        }
+    };
+
+    let raw_scripts = match parse::<_, ucd_parse::Script>(&UCD_DOWNLOADS_DIR) {
+        Ok(props) => {
+            println!("Scripts parsed.");
+            props
+        }
+        Err(error) => {
+            eprintln!("Scripts parse error: {error}");
+            exit(1);
+        }
+    };
+
+    let raw_extensions = match parse::<_, ucd_parse::ScriptExtension>(&UCD_DOWNLOADS_DIR) {
+        Ok(props) => {
+            println!("Script Extensions parsed.");
+            props
+        }
+        Err(error) => {
+            eprintln!("Script Extensions parse error: {error}");
+            exit(1);
+        }
+    };
+
+    let aliases = raw_aliases
+        .into_iter()
+        .filter(|alias| alias.property == "sc")
+        .collect::<Vec<_>>();
+
+    let find_alias = |name: &str| {
+        let Some(alias) = aliases.iter().find(|alias| {
+            alias.long == name
+                || alias.abbreviation == name
+                || alias.aliases.iter().any(|alias| alias == name)
+        }) else {
+            eprintln!("Unknown script {name:?}.");
+            exit(1);
+        };
+
+        (alias.long.clone(), alias.abbreviation.clone())
+    };
+
+    // The Unknown, Common, and Inherited scripts go first, followed by
+    // the rest of the scripts in alphabetical order.
+    let mut names = vec![
+        find_alias("Unknown"),
+        find_alias("Common"),
+        find_alias("Inherited"),
+    ];
+
+    let mut specific = AHashSet::new();
+
+    for raw in &raw_scripts {
+        let _ = specific.insert(find_alias(&raw.script));
+    }
+
+    for raw in &raw_extensions {
+        for script in &raw.scripts {
+            let _ = specific.insert(find_alias(script));
+        }
+    }
+
+    let mut specific = specific
+        .into_iter()
+        .filter(|name| !names.contains(name))
+        .collect::<Vec<_>>();
+
+    specific.sort();
+
+    names.append(&mut specific);
+
+    if names.len() > 256 {
+        eprintln!("Too many scripts.");
+        exit(1);
+    }
+
+    let index_of = |name: &str| {
+        let name = find_alias(name);
+
+        names
+            .iter()
+            .position(|candidate| candidate == &name)
+            .expect("Unknown script.")
+    };
+
+    let mut code_points = Vec::new();
+
+    for raw in raw_scripts {
+        let script = index_of(&raw.script);
+
+        for code_point in raw.codepoints {
+            code_points.push((code_point.value(), script));
+        }
+    }
+
+    code_points.sort();
+
+    let mut scripts = Vec::<(u32, u32, usize)>::new();
+
+    for (code_point, script) in code_points {
+        if let Some((_, last, last_script)) = scripts.last_mut() {
+            if *last + 1 == code_point && *last_script == script {
+                *last = code_point;
+                continue;
+            }
+        }
+
+        scripts.push((code_point, code_point, script));
+    }
+
+    let mut code_points = Vec::new();
+
+    for raw in raw_extensions {
+        let mut extension = raw
+            .scripts
+            .iter()
+            .map(|script| index_of(script))
+            .collect::<Vec<_>>();
+
+        extension.sort();
+        extension.dedup();
+
+        for code_point in raw.codepoints {
+            code_points.push((code_point.value(), extension.clone()));
+        }
+    }
+
+    code_points.sort();
+
+    let mut extensions = Vec::<(u32, u32, Vec<usize>)>::new();
+
+    for (code_point, extension) in code_points {
+        if let Some((_, last, last_extension)) = extensions.last_mut() {
+            if *last + 1 == code_point && *last_extension == extension {
+                *last = code_point;
+                continue;
+            }
+        }
+
+        extensions.push((code_point, code_point, extension));
+    }
+
+    println!("Raw Script data parsing finished.");
+
+    RawScripts {
+        names,
+        scripts,
+        extensions,
+    }
+}